@@ -0,0 +1,120 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::traits::{GenesisBuild, OnFinalize, OnInitialize};
+use hex_literal::hex;
+use hydra_dx_runtime::constants::currency::HDX;
+use hydra_dx_runtime::pallet_claims::EthereumAddress;
+use hydra_dx_runtime::{AccountId, Balance, BlockNumber, Claims, Exchange, MultiTransactionPayment, Runtime, System};
+use orml_utilities::OrderedSet;
+use primitives::{AssetId, CORE_ASSET_ID};
+use sp_runtime::AccountId32;
+
+pub const DOT: AssetId = 1;
+
+/// Native currency claimable by `ALICE_ETH`.
+pub const CLAIM_AMOUNT: Balance = 1_000 * HDX;
+/// Initial balance of DOT of every account.
+pub const DOT_BALANCE: Balance = 1_000 * HDX;
+
+pub const ALICE: [u8; 32] = hex!["d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"];
+pub const BOB: [u8; 32] = [2u8; 32];
+
+/// Ethereum address of Alice with signed claim message.
+pub const ALICE_ETH: EthereumAddress = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
+/// Signature of the claim message of the runtime by `ALICE_ETH` for `ALICE`.
+pub const ALICE_CLAIM_SIGNATURE: [u8; 65] = hex!["bcae7d4f96f71cf974c173ae936a1a79083af7f76232efbf8a568b7f990eceed73c2465bba769de959b7f6ac5690162b61eb90949901464d0fa158a83022a0741c"];
+
+pub fn account(id: [u8; 32]) -> AccountId {
+	AccountId32::from(id)
+}
+
+pub struct ExtBuilder {
+	token_balances: Vec<(AccountId, AssetId, Balance)>,
+	claims: Vec<(EthereumAddress, Balance)>,
+	fee_currencies: Vec<AssetId>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			token_balances: vec![(account(ALICE), DOT, DOT_BALANCE), (account(BOB), DOT, DOT_BALANCE)],
+			claims: vec![(ALICE_ETH, CLAIM_AMOUNT)],
+			fee_currencies: vec![DOT],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			endowed_accounts: self.token_balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		pallet_asset_registry::GenesisConfig::<Runtime> {
+			core_asset_id: CORE_ASSET_ID,
+			next_asset_id: DOT + 1,
+			asset_ids: vec![(b"DOT".to_vec(), DOT)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		pallet_claims::GenesisConfig::<Runtime> {
+			claims: self.claims,
+			claims_root: None,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		pallet_transaction_multi_payment::GenesisConfig::<Runtime> {
+			currencies: OrderedSet::from(self.fee_currencies),
+			authorities: vec![],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+/// Finalize current block and initialize the next one, running hooks of pallets taking part in the journeys.
+pub fn next_block() {
+	let now = System::block_number();
+
+	Exchange::on_finalize(now);
+
+	let next = now + 1;
+	System::set_block_number(next);
+
+	Claims::on_initialize(next);
+	MultiTransactionPayment::on_initialize(next);
+	Exchange::on_initialize(next);
+}
+
+pub fn run_to_block(n: BlockNumber) {
+	while System::block_number() < n {
+		next_block();
+	}
+}
@@ -0,0 +1,113 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Simulation of transaction fee multiplier under sustained spam of exchange intentions.
+
+use crate::builder::*;
+use frame_support::{
+	assert_ok,
+	traits::OnFinalize,
+	weights::{DispatchClass, GetDispatchInfo, Weight},
+};
+use hydra_dx_runtime::constants::currency::HDX;
+use hydra_dx_runtime::constants::time::{DAYS, HOURS};
+use hydra_dx_runtime::{BlockWeights, Call, FeeMultiplier, Origin, System, TransactionPayment};
+use pallet_fee_multiplier::FeeMultiplierParams;
+use pallet_transaction_payment::Multiplier;
+use primitives::CORE_ASSET_ID;
+use sp_runtime::{FixedPointNumber, Perquintill};
+
+/// Weight of normal dispatch class of a block filled with sell intentions.
+fn intention_spam_weight() -> Weight {
+	let max_normal = BlockWeights::get()
+		.get(DispatchClass::Normal)
+		.max_total
+		.expect("Normal dispatch class has a weight limit configured; qed");
+
+	let intention = Call::Exchange(pallet_exchange::Call::sell(DOT, CORE_ASSET_ID, HDX, 0, false, None, 0))
+		.get_dispatch_info()
+		.weight;
+
+	(max_normal / intention) * intention
+}
+
+/// Finalize `blocks` blocks with `weight` of normal dispatch class and return fee multipliers after each of them.
+fn run_blocks(blocks: u32, weight: Weight) -> Vec<Multiplier> {
+	(0..blocks)
+		.map(|_| {
+			let now = System::block_number();
+
+			System::set_block_limits(weight, 0);
+			TransactionPayment::on_finalize(now);
+			System::set_block_number(now + 1);
+
+			TransactionPayment::next_fee_multiplier()
+		})
+		.collect()
+}
+
+#[test]
+fn fee_multiplier_should_grow_under_sustained_intention_spam() {
+	ExtBuilder::default().build().execute_with(|| {
+		let spam = intention_spam_weight();
+
+		let multipliers = run_blocks(HOURS, spam);
+
+		assert!(multipliers.windows(2).all(|pair| pair[0] < pair[1]));
+		assert!(multipliers[0] > Multiplier::from(1));
+
+		run_blocks(DAYS - HOURS, spam);
+
+		assert!(TransactionPayment::next_fee_multiplier() > Multiplier::from(10));
+	});
+}
+
+#[test]
+fn fee_multiplier_should_not_collapse_after_trading_burst() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_blocks(HOURS, intention_spam_weight());
+
+		let peak = TransactionPayment::next_fee_multiplier();
+
+		run_blocks(HOURS, 0);
+
+		assert!(TransactionPayment::next_fee_multiplier() > peak * Multiplier::saturating_from_rational(9, 10));
+
+		// Fee pressure of the burst fades out, but fees never drop below the base fee
+		run_blocks(DAYS, 0);
+
+		assert_eq!(TransactionPayment::next_fee_multiplier(), Multiplier::from(1));
+	});
+}
+
+#[test]
+fn fee_multiplier_should_follow_params_set_by_governance() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(FeeMultiplier::set_params(
+			Origin::root(),
+			FeeMultiplierParams {
+				target_block_fullness: Perquintill::from_percent(50),
+				adjustment_variable: Multiplier::saturating_from_rational(1, 1_000),
+				minimum_multiplier: Multiplier::from(2),
+			}
+		));
+
+		run_blocks(1, 0);
+
+		assert_eq!(TransactionPayment::next_fee_multiplier(), Multiplier::from(2));
+	});
+}
@@ -0,0 +1,176 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Journey of a new user - claim of native currency, fee payment in another currency, liquidity provision,
+//! trade via exchange intention and liquidity removal.
+
+use crate::builder::*;
+use codec::Encode;
+use frame_support::{assert_ok, weights::GetDispatchInfo};
+use hydra_dx_runtime::constants::currency::HDX;
+use hydra_dx_runtime::{
+	Balances, Call, ClaimDelay, Claims, Currencies, Event, Exchange, MultiTransactionPayment, Origin, Runtime, System,
+	XYK,
+};
+use orml_traits::MultiCurrency;
+use pallet_claims::EcdsaSignature;
+use pallet_transaction_payment::ChargeTransactionPayment;
+use primitives::{asset::AssetPair, traits::AMM, Balance, IntentionType, Price, CORE_ASSET_ID};
+use sp_runtime::traits::SignedExtension;
+use test_utils::{assert_balance, assert_event_emitted, assert_pool_reserves};
+
+const POOL_LIQUIDITY: Balance = 500 * HDX;
+const SELL_AMOUNT: Balance = 10 * HDX;
+
+#[test]
+fn claim_trade_and_remove_liquidity_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let alice = account(ALICE);
+		let bob = account(BOB);
+
+		// Claim is executed after the claim delay
+		assert_ok!(Claims::claim(
+			Origin::signed(alice.clone()),
+			EcdsaSignature(ALICE_CLAIM_SIGNATURE),
+			None
+		));
+
+		let execute_at = System::block_number() + ClaimDelay::get();
+		assert_event_emitted!(
+			Runtime,
+			pallet_claims::Event::ClaimScheduled(alice.clone(), ALICE_ETH, CLAIM_AMOUNT, execute_at)
+		);
+		assert_balance!(Balances, alice, 0);
+
+		run_to_block(execute_at);
+
+		assert_event_emitted!(
+			Runtime,
+			pallet_claims::Event::Claim(alice.clone(), ALICE_ETH, CLAIM_AMOUNT)
+		);
+		assert_balance!(Balances, alice, CLAIM_AMOUNT);
+
+		// Claimed native currency is provided as liquidity
+		assert_ok!(XYK::create_pool(
+			Origin::signed(alice.clone()),
+			CORE_ASSET_ID,
+			DOT,
+			POOL_LIQUIDITY,
+			Price::from(1)
+		));
+
+		let pool = XYK::get_pair_id(AssetPair {
+			asset_in: CORE_ASSET_ID,
+			asset_out: DOT,
+		});
+		let share_token = XYK::share_token(&pool);
+
+		assert_event_emitted!(
+			Runtime,
+			pallet_xyk::Event::PoolCreated(alice.clone(), CORE_ASSET_ID, DOT, POOL_LIQUIDITY)
+		);
+		assert_pool_reserves!(Currencies, pool, (CORE_ASSET_ID, POOL_LIQUIDITY), (DOT, POOL_LIQUIDITY));
+		assert_balance!(Balances, alice, CLAIM_AMOUNT - POOL_LIQUIDITY);
+		assert_balance!(
+			Currencies,
+			alice,
+			share_token,
+			POOL_LIQUIDITY - pallet_xyk::MINIMUM_LIQUIDITY
+		);
+
+		// Fee price of DOT is recorded at the start of the block
+		next_block();
+
+		// Bob holds no native currency and pays transaction fees in DOT
+		assert_ok!(MultiTransactionPayment::set_currency(Origin::signed(bob.clone()), DOT));
+		assert_event_emitted!(
+			Runtime,
+			pallet_transaction_multi_payment::Event::CurrencySet(bob.clone(), DOT)
+		);
+
+		let sell = Call::Exchange(pallet_exchange::Call::sell(
+			DOT,
+			CORE_ASSET_ID,
+			SELL_AMOUNT,
+			0,
+			false,
+			None,
+			0,
+		));
+		let info = sell.get_dispatch_info();
+
+		assert_ok!(ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(&bob, &sell, &info, sell.encode().len()));
+
+		let fee_in_dot = DOT_BALANCE - Currencies::free_balance(DOT, &bob);
+		assert!(fee_in_dot > 0, "Fee should be paid in DOT");
+		// Native currency bought for the fee is withdrawn as the fee
+		assert_balance!(Balances, bob, 0);
+
+		// Sell intention is resolved by AMM trade at the end of the block
+		assert_ok!(Exchange::sell(
+			Origin::signed(bob.clone()),
+			DOT,
+			CORE_ASSET_ID,
+			SELL_AMOUNT,
+			0,
+			false,
+			None,
+			0
+		));
+
+		next_block();
+
+		let bought = System::events()
+			.into_iter()
+			.find_map(|record| match record.event {
+				Event::pallet_exchange(pallet_exchange::Event::IntentionResolvedAMMTrade(
+					who,
+					IntentionType::SELL,
+					_,
+					SELL_AMOUNT,
+					bought,
+				)) if who == bob => Some(bought),
+				_ => None,
+			})
+			.expect("Sell intention should be resolved by AMM trade");
+
+		assert!(bought > 0);
+		assert_balance!(Currencies, bob, DOT, DOT_BALANCE - fee_in_dot - SELL_AMOUNT);
+		assert_balance!(Balances, bob, bought);
+
+		// Liquidity including collected fees returns to the provider, locked shares stay in the pool
+		let shares = POOL_LIQUIDITY - pallet_xyk::MINIMUM_LIQUIDITY;
+
+		assert_ok!(XYK::remove_liquidity(
+			Origin::signed(alice.clone()),
+			CORE_ASSET_ID,
+			DOT,
+			shares,
+			0,
+			0
+		));
+
+		assert_event_emitted!(
+			Runtime,
+			pallet_xyk::Event::LiquidityRemoved(alice.clone(), CORE_ASSET_ID, DOT, shares)
+		);
+		assert_balance!(Currencies, alice, share_token, 0);
+		assert_eq!(XYK::total_liquidity(&pool), pallet_xyk::MINIMUM_LIQUIDITY);
+		assert!(Currencies::free_balance(DOT, &alice) > DOT_BALANCE);
+		assert!(Balances::free_balance(&alice) < CLAIM_AMOUNT);
+	});
+}
@@ -0,0 +1,28 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests of the HydraDX runtime.
+//!
+//! Tests run user journeys across pallets against the real runtime configuration, so wiring regressions
+//! which pallet mocks cannot observe - configuration types, weights, hooks - are caught before release.
+
+#![cfg(test)]
+
+mod builder;
+mod fee_multiplier;
+mod journey;
+mod weights;
@@ -0,0 +1,99 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dispatch info of calls of the journeys is checked against weights configured in the runtime.
+
+use crate::builder::*;
+use frame_support::weights::{DispatchClass, GetDispatchInfo, Pays};
+use hydra_dx_runtime::constants::currency::HDX;
+use hydra_dx_runtime::{BlockWeights, Call, Runtime};
+use pallet_claims::weights::WeightInfo as ClaimsWeight;
+use pallet_claims::EcdsaSignature;
+use pallet_exchange::weights::WeightInfo as ExchangeWeight;
+use pallet_transaction_multi_payment::weights::WeightInfo as MultiPaymentWeight;
+use pallet_xyk::weights::WeightInfo as XykWeight;
+use primitives::{Price, CORE_ASSET_ID};
+
+#[test]
+fn journey_calls_should_use_runtime_weights() {
+	let calls = vec![
+		(
+			Call::Claims(pallet_claims::Call::claim(EcdsaSignature(ALICE_CLAIM_SIGNATURE), None)),
+			<Runtime as pallet_claims::Config>::WeightInfo::claim(),
+			Pays::No,
+		),
+		(
+			Call::MultiTransactionPayment(pallet_transaction_multi_payment::Call::set_currency(DOT)),
+			<Runtime as pallet_transaction_multi_payment::Config>::WeightInfo::set_currency(),
+			Pays::No,
+		),
+		(
+			Call::XYK(pallet_xyk::Call::create_pool(CORE_ASSET_ID, DOT, HDX, Price::from(1))),
+			<Runtime as pallet_xyk::Config>::WeightInfo::create_pool(),
+			Pays::Yes,
+		),
+		(
+			Call::XYK(pallet_xyk::Call::remove_liquidity(CORE_ASSET_ID, DOT, HDX, 0, 0)),
+			<Runtime as pallet_xyk::Config>::WeightInfo::remove_liquidity(),
+			Pays::Yes,
+		),
+	];
+
+	for (call, weight, pays_fee) in calls {
+		let info = call.get_dispatch_info();
+
+		assert_eq!(info.weight, weight, "Unexpected weight of {:?}", call);
+		assert_eq!(info.pays_fee, pays_fee, "Unexpected fee payment of {:?}", call);
+	}
+}
+
+#[test]
+fn sell_intention_should_pay_for_its_resolution() {
+	let call = Call::Exchange(pallet_exchange::Call::sell(DOT, CORE_ASSET_ID, HDX, 0, false, None, 0));
+
+	let amm_trade = <Runtime as pallet_exchange::Config>::WeightInfo::on_finalize_for_one_sell_extrinsic()
+		.saturating_sub(<Runtime as pallet_exchange::Config>::WeightInfo::known_overhead_for_on_finalize());
+
+	assert!(
+		call.get_dispatch_info().weight
+			>= <Runtime as pallet_exchange::Config>::WeightInfo::sell_intention() + amm_trade
+	);
+}
+
+#[test]
+fn journey_calls_should_fit_into_block() {
+	let max_extrinsic = BlockWeights::get()
+		.get(DispatchClass::Normal)
+		.max_extrinsic
+		.expect("Normal extrinsics have a weight limit configured; qed");
+
+	let calls = vec![
+		Call::Claims(pallet_claims::Call::claim(EcdsaSignature(ALICE_CLAIM_SIGNATURE), None)),
+		Call::MultiTransactionPayment(pallet_transaction_multi_payment::Call::set_currency(DOT)),
+		Call::XYK(pallet_xyk::Call::create_pool(CORE_ASSET_ID, DOT, HDX, Price::from(1))),
+		Call::Exchange(pallet_exchange::Call::sell(DOT, CORE_ASSET_ID, HDX, 0, false, None, 0)),
+		Call::XYK(pallet_xyk::Call::remove_liquidity(CORE_ASSET_ID, DOT, HDX, 0, 0)),
+	];
+
+	for call in calls {
+		assert!(
+			call.get_dispatch_info().weight <= max_extrinsic,
+			"{:?} does not fit into block",
+			call
+		);
+	}
+}
@@ -1,19 +1,21 @@
 #![allow(clippy::or_fun_call)]
 
+use codec::Decode;
 use hydra_dx_runtime::constants::currency::{Balance, HDX};
 use hydra_dx_runtime::opaque::SessionKeys;
-use hydra_dx_runtime::pallet_claims::EthereumAddress;
+use hydra_dx_runtime::pallet_claims::{merkle, EthereumAddress};
 use hydra_dx_runtime::{
 	AccountId, AssetRegistryConfig, AuthorityDiscoveryConfig, BabeConfig, BalancesConfig, ClaimsConfig, CouncilConfig,
 	ElectionsConfig, FaucetConfig, GenesisConfig, GenesisHistoryConfig, GrandpaConfig, ImOnlineConfig, Perbill,
-	SessionConfig, Signature, StakerStatus, StakingConfig, SudoConfig, SystemConfig, TechnicalCommitteeConfig,
-	TokensConfig, CORE_ASSET_ID, WASM_BINARY,
+	PricePublisherConfig, SessionConfig, Signature, StakerStatus, StakingConfig, SudoConfig, SystemConfig,
+	TechnicalCommitteeConfig, TokensConfig, XYKConfig, CORE_ASSET_ID, WASM_BINARY,
 };
 use pallet_staking::Forcing;
+use primitives::Price;
 use sc_service::ChainType;
 use sc_telemetry::TelemetryEndpoints;
 use serde_json::map::Map;
-use sp_core::{crypto::UncheckedInto, sr25519, Pair, Public};
+use sp_core::{crypto::UncheckedInto, sr25519, Pair, Public, H256};
 use sp_finality_grandpa::AuthorityId as GrandpaId;
 use sp_runtime::traits::{IdentifyAccount, Verify};
 
@@ -69,7 +71,7 @@ pub fn authority_keys_from_seed(
 	)
 }
 
-fn session_keys(
+pub(crate) fn session_keys(
 	grandpa: GrandpaId,
 	babe: BabeId,
 	im_online: ImOnlineId,
@@ -83,8 +85,8 @@ fn session_keys(
 	}
 }
 
-const STASH: Balance = 100 * HDX;
-const DEFAULT_PROTOCOL_ID: &str = "hdx";
+pub(crate) const STASH: Balance = 100 * HDX;
+pub(crate) const DEFAULT_PROTOCOL_ID: &str = "hdx";
 
 pub fn development_config() -> Result<ChainSpec, String> {
 	let wasm_binary = WASM_BINARY.ok_or("Development wasm binary not available".to_string())?;
@@ -351,6 +353,7 @@ fn testnet_genesis(
 			rampage: true,
 			mint_limit: 5,
 			mintable_currencies: vec![0, 1, 2],
+			drip_bundle: vec![(0, 1_000u128 * HDX), (1, 1_000u128 * HDX), (2, 1_000u128 * HDX)],
 		},
 		pallet_babe: BabeConfig {
 			authorities: vec![],
@@ -400,8 +403,33 @@ fn testnet_genesis(
 		},
 		pallet_claims: ClaimsConfig {
 			claims: create_testnet_claims(),
+			claims_root: claims_root(),
 		},
 		pallet_genesis_history: GenesisHistoryConfig::default(),
+		pallet_referrals: Default::default(),
+		pallet_fee_discount: Default::default(),
+		pallet_xyk: XYKConfig {
+			pools: vec![
+				(
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					CORE_ASSET_ID,
+					1,
+					10_000u128 * HDX,
+					Price::from(1),
+				),
+				(
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					CORE_ASSET_ID,
+					2,
+					10_000u128 * HDX,
+					Price::from(1),
+				),
+			],
+		},
+		pallet_price_publisher: PricePublisherConfig {
+			pairs: vec![(CORE_ASSET_ID, 1), (CORE_ASSET_ID, 2)],
+			publishers: vec![get_account_id_from_seed::<sr25519::Public>("Alice")],
+		},
 	}
 }
 
@@ -477,6 +505,7 @@ fn lerna_genesis(
 			rampage: false,
 			mint_limit: 5,
 			mintable_currencies: vec![],
+			drip_bundle: vec![],
 		},
 		pallet_babe: BabeConfig {
 			authorities: vec![],
@@ -532,7 +561,15 @@ fn lerna_genesis(
 			],
 			phantom: Default::default(),
 		},
-		pallet_claims: ClaimsConfig { claims: vec![] },
+		pallet_claims: ClaimsConfig {
+			claims: vec![],
+			claims_root: claims_root(),
+		},
+		pallet_xyk: XYKConfig { pools: vec![] },
+		pallet_price_publisher: PricePublisherConfig {
+			pairs: vec![],
+			publishers: vec![],
+		},
 		pallet_genesis_history: GenesisHistoryConfig {
 			previous_chain: Chain {
 				genesis_hash: hex!["0ed32bfcab4a83517fac88f2aa7cbc2f88d3ab93be9a12b6188a036bf8a943c2"]
@@ -543,6 +580,8 @@ fn lerna_genesis(
 					.into(),
 			},
 		},
+		pallet_referrals: Default::default(),
+		pallet_fee_discount: Default::default(),
 	}
 }
 
@@ -584,3 +623,10 @@ fn create_testnet_claims() -> Vec<(EthereumAddress, Balance)> {
 	claims.push(claim_address_3);
 	claims
 }
+
+/// Root of the tree of claims of the LBP claims snapshot.
+fn claims_root() -> Option<H256> {
+	let claims = Vec::<(EthereumAddress, Balance)>::decode(&mut &include_bytes!("../res/claims.scale")[..])
+		.expect("claims snapshot is valid; qed");
+	merkle::merkle_root(&claims)
+}
@@ -15,6 +15,10 @@ pub enum Subcommand {
 	/// Build a chain specification.
 	BuildSpec(sc_cli::BuildSpecCmd),
 
+	/// Build a chain specification of a custom chain from a genesis description file.
+	#[structopt(name = "build-spec-hydra")]
+	BuildSpecHydra(crate::spec_builder::BuildSpecHydraCmd),
+
 	/// Validate blocks.
 	CheckBlock(sc_cli::CheckBlockCmd),
 
@@ -36,4 +40,28 @@ pub enum Subcommand {
 	/// The custom benchmark subcommmand benchmarking runtime pallets.
 	#[structopt(name = "benchmark", about = "Benchmark runtime pallets.")]
 	Benchmark(frame_benchmarking_cli::BenchmarkCmd),
+
+	/// Generate, insert and verify session keys.
+	Key(crate::key::KeyCmd),
+
+	/// Re-execute an extrinsic against its parent state and report XYK trade details.
+	#[structopt(name = "replay-trade")]
+	ReplayTrade(crate::replay::ReplayTradeCmd),
+
+	/// Run the node as a collator or a full node of the HydraDX parachain.
+	#[structopt(name = "parachain")]
+	Parachain(crate::parachain::cli::ParachainCmd),
+
+	/// Export the genesis state of the parachain.
+	#[structopt(name = "export-genesis-state")]
+	ExportGenesisState(crate::parachain::cli::ExportGenesisStateCmd),
+
+	/// Export the genesis wasm of the parachain.
+	#[structopt(name = "export-genesis-wasm")]
+	ExportGenesisWasm(crate::parachain::cli::ExportGenesisWasmCmd),
+
+	/// Dry-run runtime upgrade and migrations against the state of a live chain.
+	#[cfg(feature = "try-runtime")]
+	#[structopt(name = "try-runtime")]
+	TryRuntime(try_runtime_cli::TryRuntimeCmd),
 }
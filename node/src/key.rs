@@ -0,0 +1,244 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `key` command managing the session key set in the node keystore.
+//!
+//! Each session key is derived from a single secret URI as `<suri>//<key name>`, so the whole set can be
+//! restored from one secret.
+
+use crate::session_keys::{inspect_session_keys, key_type_name, verify_ownership, SessionKeysInfo};
+use codec::Encode;
+use hydra_dx_runtime::{opaque::SessionKeys, AccountId};
+use sc_cli::{KeystoreParams, SharedParams, SubstrateCli};
+use sc_keystore::LocalKeystore;
+use sc_service::config::{BasePath, KeystoreConfig};
+use serde::Serialize;
+use sp_core::{crypto::key_types, ed25519, sr25519, Bytes, Pair};
+use sp_keystore::{SyncCryptoStore, SyncCryptoStorePtr};
+use std::sync::Arc;
+use structopt::StructOpt;
+
+/// The `key` command used to generate, insert and verify session keys.
+#[derive(Debug, StructOpt)]
+pub enum KeyCmd {
+	/// Generate the full session key set, insert it into the keystore and verify it.
+	#[structopt(name = "generate-session-keys")]
+	GenerateSessionKeys(GenerateSessionKeysCmd),
+
+	/// Verify that the keystore holds private keys of encoded session keys.
+	#[structopt(name = "verify-session-keys")]
+	VerifySessionKeys(VerifySessionKeysCmd),
+}
+
+/// The `key generate-session-keys` command.
+#[derive(Debug, StructOpt)]
+pub struct GenerateSessionKeysCmd {
+	/// Secret URI the session keys are derived from. A new secret phrase is generated if not provided.
+	#[structopt(long)]
+	pub suri: Option<String>,
+
+	/// Account whose ownership of the session keys is proven by signing it with each key.
+	#[structopt(long)]
+	pub owner: Option<AccountId>,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub keystore_params: KeystoreParams,
+}
+
+/// The `key verify-session-keys` command.
+#[derive(Debug, StructOpt)]
+pub struct VerifySessionKeysCmd {
+	/// Hex encoded session keys, as returned by `author_rotateKeys`.
+	#[structopt(long)]
+	pub keys: Bytes,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub keystore_params: KeystoreParams,
+}
+
+/// Result of the `key generate-session-keys` command.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GeneratedSessionKeys {
+	#[serde(flatten)]
+	info: SessionKeysInfo,
+	/// Generated secret phrase, only present when no secret URI was provided.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	secret_phrase: Option<String>,
+	/// Whether signatures of the owner account are valid.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	ownership_verified: Option<bool>,
+}
+
+/// Session key derived from the secret URI, to be inserted into the keystore.
+struct DerivedKey {
+	key_type: sp_core::crypto::KeyTypeId,
+	suri: String,
+	public: Vec<u8>,
+}
+
+fn derive_ed25519(suri: &str, name: &str) -> sc_cli::Result<(ed25519::Public, String)> {
+	let suri = format!("{}//{}", suri, name);
+	let pair = ed25519::Pair::from_string(&suri, None).map_err(|e| format!("Invalid secret URI: {:?}", e))?;
+	Ok((pair.public(), suri))
+}
+
+fn derive_sr25519(suri: &str, name: &str) -> sc_cli::Result<(sr25519::Public, String)> {
+	let suri = format!("{}//{}", suri, name);
+	let pair = sr25519::Pair::from_string(&suri, None).map_err(|e| format!("Invalid secret URI: {:?}", e))?;
+	Ok((pair.public(), suri))
+}
+
+/// Derive the whole session key set from `suri`.
+fn derive_session_keys(suri: &str) -> sc_cli::Result<(SessionKeys, Vec<DerivedKey>)> {
+	let (grandpa, grandpa_suri) = derive_ed25519(suri, "grandpa")?;
+	let (babe, babe_suri) = derive_sr25519(suri, "babe")?;
+	let (im_online, im_online_suri) = derive_sr25519(suri, "im_online")?;
+	let (authority_discovery, authority_discovery_suri) = derive_sr25519(suri, "authority_discovery")?;
+
+	let derived = vec![
+		DerivedKey {
+			key_type: key_types::GRANDPA,
+			suri: grandpa_suri,
+			public: grandpa.to_vec(),
+		},
+		DerivedKey {
+			key_type: key_types::BABE,
+			suri: babe_suri,
+			public: babe.to_vec(),
+		},
+		DerivedKey {
+			key_type: key_types::IM_ONLINE,
+			suri: im_online_suri,
+			public: im_online.to_vec(),
+		},
+		DerivedKey {
+			key_type: key_types::AUTHORITY_DISCOVERY,
+			suri: authority_discovery_suri,
+			public: authority_discovery.to_vec(),
+		},
+	];
+
+	let keys = SessionKeys {
+		grandpa: grandpa.into(),
+		babe: babe.into(),
+		im_online: im_online.into(),
+		authority_discovery: authority_discovery.into(),
+	};
+
+	Ok((keys, derived))
+}
+
+fn open_keystore<C: SubstrateCli>(
+	cli: &C,
+	shared_params: &SharedParams,
+	keystore_params: &KeystoreParams,
+) -> sc_cli::Result<SyncCryptoStorePtr> {
+	let base_path = shared_params
+		.base_path()
+		.unwrap_or_else(|| BasePath::from_project("", "", &C::executable_name()));
+	let chain_id = shared_params.chain_id(shared_params.is_dev());
+	let chain_spec = cli.load_spec(&chain_id)?;
+	let config_dir = base_path.config_dir(chain_spec.id());
+
+	match keystore_params.keystore_config(&config_dir)? {
+		(_, KeystoreConfig::Path { path, password }) => Ok(Arc::new(
+			LocalKeystore::open(path, password).map_err(|e| format!("Failed to open keystore: {:?}", e))?,
+		)),
+		_ => Err("Only local keystore is supported".into()),
+	}
+}
+
+fn print_json<T: Serialize>(value: &T) -> sc_cli::Result<()> {
+	let json = serde_json::to_string_pretty(value).map_err(|e| format!("Failed to serialize result: {:?}", e))?;
+	println!("{}", json);
+	Ok(())
+}
+
+impl KeyCmd {
+	/// Run the key command
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> sc_cli::Result<()> {
+		match self {
+			KeyCmd::GenerateSessionKeys(cmd) => cmd.run(cli),
+			KeyCmd::VerifySessionKeys(cmd) => cmd.run(cli),
+		}
+	}
+}
+
+impl GenerateSessionKeysCmd {
+	/// Run the generate-session-keys command
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> sc_cli::Result<()> {
+		let (suri, secret_phrase) = match self.suri.as_ref() {
+			Some(suri) => (suri.clone(), None),
+			None => {
+				let (_, phrase, _) = sr25519::Pair::generate_with_phrase(None);
+				(phrase.clone(), Some(phrase))
+			}
+		};
+
+		let (keys, derived) = derive_session_keys(&suri)?;
+
+		let keystore = open_keystore(cli, &self.shared_params, &self.keystore_params)?;
+
+		for key in derived.iter() {
+			SyncCryptoStore::insert_unknown(&*keystore, key.key_type, &key.suri, &key.public)
+				.map_err(|_| format!("Failed to insert {} key into the keystore", key_type_name(key.key_type)))?;
+		}
+
+		let owner = self.owner.as_ref().map(|owner| owner.encode());
+		let info = inspect_session_keys(&keystore, &keys.encode(), owner.as_deref())?;
+
+		if !info.verified {
+			return Err("Session keys were not found in the keystore after insertion".into());
+		}
+
+		let ownership_verified = owner.map(|owner| info.session_keys.iter().all(|key| verify_ownership(key, &owner)));
+
+		print_json(&GeneratedSessionKeys {
+			info,
+			secret_phrase,
+			ownership_verified,
+		})
+	}
+}
+
+impl VerifySessionKeysCmd {
+	/// Run the verify-session-keys command
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> sc_cli::Result<()> {
+		let keystore = open_keystore(cli, &self.shared_params, &self.keystore_params)?;
+
+		let info = inspect_session_keys(&keystore, &self.keys, None)?;
+
+		print_json(&info)?;
+
+		if info.verified {
+			Ok(())
+		} else {
+			Err("Keystore does not hold all session keys".into())
+		}
+	}
+}
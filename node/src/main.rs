@@ -6,7 +6,13 @@ mod chain_spec;
 mod service;
 mod cli;
 mod command;
+mod key;
+mod metrics;
+mod parachain;
+mod replay;
 mod rpc;
+mod session_keys;
+mod spec_builder;
 
 fn main() -> sc_cli::Result<()> {
 	command::run()
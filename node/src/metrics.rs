@@ -0,0 +1,239 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prometheus metrics of DEX activity.
+//!
+//! Events and extrinsics of each imported best block are scanned and DEX-specific metrics are updated.
+//! Metrics are registered in the node's Prometheus registry alongside the standard Substrate metrics.
+//! Blocks of a retracted fork are not subtracted, counters can slightly overcount after reorgs.
+
+use codec::{Decode, Encode};
+use frame_support::metadata::{DecodeDifferent, RuntimeMetadata};
+use futures::StreamExt;
+use hydra_dx_runtime::{opaque::Block, Call, Event, Hash, Runtime, UncheckedExtrinsic};
+use prometheus_endpoint::{register, Counter, CounterVec, Gauge, Opts, PrometheusError, Registry, U64};
+use sc_client_api::{Backend, BlockBackend, BlockchainEvents, StorageProvider};
+use sp_core::{hashing::twox_128, storage::StorageKey};
+use sp_runtime::{generic::BlockId, traits::Block as BlockT, DispatchError};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+type EventRecord = frame_system::EventRecord<Event, Hash>;
+
+/// Storage key of a storage value or prefix of a storage map.
+fn storage_prefix(pallet: &[u8], storage: &[u8]) -> StorageKey {
+	StorageKey([twox_128(pallet), twox_128(storage)].concat())
+}
+
+/// Names of all runtime errors as `Pallet::Error` by pallet index and error index.
+fn error_names() -> HashMap<(u8, u8), String> {
+	let mut names = HashMap::new();
+
+	if let RuntimeMetadata::V12(metadata) = Runtime::metadata().1 {
+		if let DecodeDifferent::Encode(modules) = metadata.modules {
+			for module in modules.iter() {
+				if let (DecodeDifferent::Encode(pallet), DecodeDifferent::Encode(errors)) =
+					(&module.name, &module.errors)
+				{
+					for (index, error) in (errors.0)().iter().enumerate() {
+						if let DecodeDifferent::Encode(name) = error.name {
+							names.insert((module.index, index as u8), format!("{}::{}", pallet, name));
+						}
+					}
+				}
+			}
+		}
+	}
+
+	names
+}
+
+/// Return true if the call executes or registers a trade.
+fn is_trade(call: &Call) -> bool {
+	matches!(
+		call,
+		Call::XYK(pallet_xyk::Call::sell(..))
+			| Call::XYK(pallet_xyk::Call::buy(..))
+			| Call::Exchange(pallet_exchange::Call::sell(..))
+			| Call::Exchange(pallet_exchange::Call::buy(..))
+			| Call::Exchange(pallet_exchange::Call::submit_intentions_batch(..))
+	)
+}
+
+/// DEX metrics.
+#[derive(Clone)]
+pub struct DexMetrics {
+	block_trades: Gauge<U64>,
+	trades: Counter<U64>,
+	intention_resolutions: CounterVec<U64>,
+	pools: Gauge<U64>,
+	failed_trades: CounterVec<U64>,
+	error_names: Arc<HashMap<(u8, u8), String>>,
+}
+
+impl DexMetrics {
+	/// Register DEX metrics in `registry`.
+	pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			block_trades: register(
+				Gauge::new(
+					"hydradx_block_trades",
+					"Number of trades executed in the last imported block",
+				)?,
+				registry,
+			)?,
+			trades: register(
+				Counter::new("hydradx_trades_total", "Total number of executed trades")?,
+				registry,
+			)?,
+			intention_resolutions: register(
+				CounterVec::new(
+					Opts::new(
+						"hydradx_intention_resolutions_total",
+						"Number of intention resolutions by kind - direct trade, AMM trade or order book trade",
+					),
+					&["kind"],
+				)?,
+				registry,
+			)?,
+			pools: register(Gauge::new("hydradx_pools", "Number of AMM pools")?, registry)?,
+			failed_trades: register(
+				CounterVec::new(
+					Opts::new("hydradx_failed_trades_total", "Number of failed trades by error"),
+					&["error"],
+				)?,
+				registry,
+			)?,
+			error_names: Arc::new(error_names()),
+		})
+	}
+
+	fn error_label(&self, error: &DispatchError) -> String {
+		match error {
+			DispatchError::Module { index, error, .. } => self
+				.error_names
+				.get(&(*index, *error))
+				.cloned()
+				.unwrap_or_else(|| format!("Module({})::Error({})", index, error)),
+			other => <&'static str>::from(*other).to_string(),
+		}
+	}
+
+	fn trade_failed(&self, error: &DispatchError) {
+		self.failed_trades.with_label_values(&[&self.error_label(error)]).inc();
+	}
+
+	/// Update metrics by `events` of a block whose extrinsics at `trade_extrinsics` indices are trades.
+	fn observe_events(&self, events: &[EventRecord], trade_extrinsics: &HashSet<u32>) {
+		let mut block_trades = 0u64;
+
+		for record in events.iter() {
+			match &record.event {
+				Event::pallet_xyk(pallet_xyk::Event::SellExecuted(..))
+				| Event::pallet_xyk(pallet_xyk::Event::BuyExecuted(..)) => {
+					block_trades += 1;
+				}
+				Event::pallet_exchange(pallet_exchange::Event::IntentionResolvedDirectTrade(..)) => {
+					block_trades += 1;
+					self.intention_resolutions.with_label_values(&["direct"]).inc();
+				}
+				Event::pallet_exchange(pallet_exchange::Event::IntentionResolvedOrderBookTrade(..)) => {
+					block_trades += 1;
+					self.intention_resolutions.with_label_values(&["order_book"]).inc();
+				}
+				// AMM trade itself is counted by the XYK event
+				Event::pallet_exchange(pallet_exchange::Event::IntentionResolvedAMMTrade(..)) => {
+					self.intention_resolutions.with_label_values(&["amm"]).inc();
+				}
+				Event::pallet_exchange(pallet_exchange::Event::IntentionResolveErrorEvent(.., error))
+				| Event::pallet_exchange(pallet_exchange::Event::InsufficientAssetBalanceEvent(.., error)) => {
+					self.trade_failed(error);
+				}
+				Event::frame_system(frame_system::Event::ExtrinsicFailed(error, _)) => {
+					if let frame_system::Phase::ApplyExtrinsic(index) = record.phase {
+						if trade_extrinsics.contains(&index) {
+							self.trade_failed(error);
+						}
+					}
+				}
+				_ => {}
+			}
+		}
+
+		self.block_trades.set(block_trades);
+		self.trades.inc_by(block_trades);
+	}
+}
+
+/// Indices of trade extrinsics of a block.
+fn trade_extrinsics(extrinsics: &[<Block as BlockT>::Extrinsic]) -> HashSet<u32> {
+	extrinsics
+		.iter()
+		.enumerate()
+		.filter_map(|(index, opaque)| {
+			UncheckedExtrinsic::decode(&mut &opaque.encode()[..])
+				.ok()
+				.filter(|xt| is_trade(&xt.function))
+				.map(|_| index as u32)
+		})
+		.collect()
+}
+
+/// Update `metrics` on each imported best block.
+pub async fn run<B, C>(client: Arc<C>, metrics: DexMetrics)
+where
+	B: Backend<Block>,
+	C: BlockchainEvents<Block> + BlockBackend<Block> + StorageProvider<Block, B>,
+{
+	let events_key = storage_prefix(b"System", b"Events");
+	let pools_prefix = storage_prefix(b"XYK", b"PoolAssets");
+
+	let mut imports = client.import_notification_stream();
+
+	while let Some(notification) = imports.next().await {
+		if !notification.is_new_best {
+			continue;
+		}
+
+		let id = BlockId::Hash(notification.hash);
+
+		let events = match client.storage(&id, &events_key) {
+			Ok(Some(data)) => Vec::<EventRecord>::decode(&mut &data.0[..]).unwrap_or_else(|e| {
+				log::debug!(target: "dex-metrics", "Failed to decode events of {:?}: {:?}", notification.hash, e);
+				Vec::new()
+			}),
+			Ok(None) => Vec::new(),
+			Err(e) => {
+				log::debug!(target: "dex-metrics", "Failed to read events of {:?}: {:?}", notification.hash, e);
+				continue;
+			}
+		};
+
+		let trades = client
+			.block_body(&id)
+			.ok()
+			.flatten()
+			.map(|extrinsics| trade_extrinsics(&extrinsics))
+			.unwrap_or_default();
+
+		metrics.observe_events(&events, &trades);
+
+		if let Ok(pools) = client.storage_keys(&id, &pools_prefix) {
+			metrics.pools.set(pools.len() as u64);
+		}
+	}
+}
@@ -0,0 +1,171 @@
+//! Chain specifications of the HydraDX parachain.
+
+#![allow(clippy::or_fun_call)]
+
+use crate::chain_spec::{get_account_id_from_seed, DEFAULT_PROTOCOL_ID};
+use cumulus_primitives_core::ParaId;
+use hydra_dx_parachain_runtime::{
+	AccountId, AssetRegistryConfig, BalancesConfig, GenesisConfig, ParachainInfoConfig, SudoConfig, SystemConfig,
+	CORE_ASSET_ID, HDX, WASM_BINARY,
+};
+use sc_chain_spec::{ChainSpecExtension, ChainSpecGroup};
+use sc_service::ChainType;
+use serde::{Deserialize, Serialize};
+use serde_json::map::Map;
+use sp_core::sr25519;
+
+/// Specialized `ChainSpec` of the parachain.
+pub type ChainSpec = sc_service::GenericChainSpec<GenesisConfig, Extensions>;
+
+/// Default parachain id used when neither the chain spec nor the command line specify one.
+pub const DEFAULT_PARA_ID: u32 = 2034;
+
+/// Relay chain and parachain id the collators of the chain connect to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ChainSpecGroup, ChainSpecExtension)]
+#[serde(deny_unknown_fields)]
+pub struct Extensions {
+	/// The relay chain of the parachain.
+	pub relay_chain: String,
+	/// The id of the parachain.
+	pub para_id: u32,
+}
+
+impl Extensions {
+	/// Try to get the extension from the given `ChainSpec`.
+	pub fn try_get(chain_spec: &dyn sc_service::ChainSpec) -> Option<&Self> {
+		sc_chain_spec::get_extension(chain_spec.extensions())
+	}
+}
+
+/// Load parachain chain specification of given id or from given json file.
+pub fn load_spec(id: &str, para_id: ParaId) -> Result<Box<dyn sc_service::ChainSpec>, String> {
+	Ok(match id {
+		"" | "dev" => Box::new(development_config(para_id)?),
+		"local" => Box::new(local_testnet_config(para_id)?),
+		path => Box::new(ChainSpec::from_json_file(std::path::PathBuf::from(path))?),
+	})
+}
+
+fn properties() -> Map<String, serde_json::Value> {
+	let mut properties = Map::new();
+	properties.insert("tokenDecimals".into(), 12.into());
+	properties.insert("tokenSymbol".into(), "HDX".into());
+	properties.insert("ss58Format".into(), 63.into());
+	properties
+}
+
+pub fn development_config(para_id: ParaId) -> Result<ChainSpec, String> {
+	let wasm_binary = WASM_BINARY.ok_or("Development wasm binary not available".to_string())?;
+
+	Ok(ChainSpec::from_genesis(
+		// Name
+		"HydraDX Parachain Development",
+		// ID
+		"parachain_dev",
+		ChainType::Development,
+		move || {
+			parachain_genesis(
+				wasm_binary,
+				// Sudo account
+				get_account_id_from_seed::<sr25519::Public>("Alice"),
+				// Pre-funded accounts
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+				],
+				para_id,
+			)
+		},
+		// Bootnodes
+		vec![],
+		// Telemetry
+		None,
+		// Protocol ID
+		Some(DEFAULT_PROTOCOL_ID),
+		// Properties
+		Some(properties()),
+		// Extensions
+		Extensions {
+			relay_chain: "rococo-dev".into(),
+			para_id: para_id.into(),
+		},
+	))
+}
+
+pub fn local_testnet_config(para_id: ParaId) -> Result<ChainSpec, String> {
+	let wasm_binary = WASM_BINARY.ok_or("Development wasm binary not available".to_string())?;
+
+	Ok(ChainSpec::from_genesis(
+		// Name
+		"HydraDX Parachain Local Testnet",
+		// ID
+		"parachain_local_testnet",
+		ChainType::Local,
+		move || {
+			parachain_genesis(
+				wasm_binary,
+				// Sudo account
+				get_account_id_from_seed::<sr25519::Public>("Alice"),
+				// Pre-funded accounts
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+					get_account_id_from_seed::<sr25519::Public>("Charlie"),
+					get_account_id_from_seed::<sr25519::Public>("Dave"),
+					get_account_id_from_seed::<sr25519::Public>("Eve"),
+					get_account_id_from_seed::<sr25519::Public>("Ferdie"),
+				],
+				para_id,
+			)
+		},
+		// Bootnodes
+		vec![],
+		// Telemetry
+		None,
+		// Protocol ID
+		Some(DEFAULT_PROTOCOL_ID),
+		// Properties
+		Some(properties()),
+		// Extensions
+		Extensions {
+			relay_chain: "rococo-local".into(),
+			para_id: para_id.into(),
+		},
+	))
+}
+
+/// Foreign assets start without liquidity, they are minted only by incoming XCM reserve transfers
+/// once governance assigns them their native locations.
+fn parachain_genesis(
+	wasm_binary: &[u8],
+	root_key: AccountId,
+	endowed_accounts: Vec<AccountId>,
+	para_id: ParaId,
+) -> GenesisConfig {
+	GenesisConfig {
+		frame_system: SystemConfig {
+			// Add Wasm runtime to storage.
+			code: wasm_binary.to_vec(),
+			changes_trie_config: Default::default(),
+		},
+		pallet_balances: BalancesConfig {
+			balances: endowed_accounts
+				.iter()
+				.cloned()
+				.map(|k| (k, 1_000_000u128 * HDX))
+				.collect(),
+		},
+		pallet_sudo: SudoConfig {
+			// Assign network admin rights.
+			key: root_key,
+		},
+		parachain_info: ParachainInfoConfig { parachain_id: para_id },
+		orml_tokens: Default::default(),
+		pallet_asset_registry: AssetRegistryConfig {
+			core_asset_id: CORE_ASSET_ID,
+			asset_ids: vec![(b"DOT".to_vec(), 1)],
+			next_asset_id: 2,
+		},
+		pallet_xyk: Default::default(),
+	}
+}
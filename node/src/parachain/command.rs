@@ -0,0 +1,132 @@
+//! Commands of the parachain node.
+
+use super::chain_spec::{self, DEFAULT_PARA_ID};
+use super::cli::{ExportGenesisStateCmd, ExportGenesisWasmCmd, ParachainCmd, RelayChainCli};
+use super::service;
+use codec::Encode;
+use cumulus_client_service::genesis::generate_genesis_block;
+use cumulus_primitives_core::ParaId;
+use hydra_dx_parachain_runtime::Block;
+use log::info;
+use polkadot_parachain::primitives::AccountIdConversion;
+use sc_cli::{ChainSpec, RuntimeVersion, SubstrateCli};
+use sp_core::hexdisplay::HexDisplay;
+use sp_runtime::traits::Block as BlockT;
+use std::io::Write;
+
+impl SubstrateCli for ParachainCmd {
+	fn impl_name() -> String {
+		"HydraDX Parachain Collator".into()
+	}
+
+	fn impl_version() -> String {
+		env!("SUBSTRATE_CLI_IMPL_VERSION").into()
+	}
+
+	fn description() -> String {
+		env!("CARGO_PKG_DESCRIPTION").into()
+	}
+
+	fn author() -> String {
+		env!("CARGO_PKG_AUTHORS").into()
+	}
+
+	fn support_url() -> String {
+		"hydradx.io".into()
+	}
+
+	fn copyright_start_year() -> i32 {
+		2019
+	}
+
+	fn load_spec(&self, id: &str) -> Result<Box<dyn sc_service::ChainSpec>, String> {
+		chain_spec::load_spec(id, self.parachain_id.unwrap_or(DEFAULT_PARA_ID).into())
+	}
+
+	fn native_runtime_version(_: &Box<dyn ChainSpec>) -> &'static RuntimeVersion {
+		&hydra_dx_parachain_runtime::VERSION
+	}
+}
+
+/// Run the parachain node together with the embedded relay chain node.
+pub fn run(cmd: &ParachainCmd) -> sc_cli::Result<()> {
+	let runner = cmd.create_runner(&cmd.run)?;
+
+	runner.run_node_until_exit(|config| async move {
+		// Collator key is used only to identify the collator to relay chain validators.
+		let key = sp_core::Pair::generate().0;
+
+		let extensions = chain_spec::Extensions::try_get(&*config.chain_spec);
+		let id = ParaId::from(
+			cmd.parachain_id
+				.or_else(|| extensions.map(|e| e.para_id))
+				.unwrap_or(DEFAULT_PARA_ID),
+		);
+
+		let polkadot_cli = RelayChainCli::new(
+			config.base_path.as_ref().map(|x| x.path().join("polkadot")),
+			extensions.map(|e| e.relay_chain.clone()),
+			[RelayChainCli::executable_name()]
+				.iter()
+				.chain(cmd.relaychain_args.iter()),
+		);
+
+		let parachain_account = AccountIdConversion::<polkadot_primitives::v0::AccountId>::into_account(&id);
+
+		let block: Block = generate_genesis_block(&config.chain_spec).map_err(|e| format!("{:?}", e))?;
+		let genesis_state = format!("0x{:?}", HexDisplay::from(&block.header().encode()));
+
+		let task_executor = config.task_executor.clone();
+		let polkadot_config = SubstrateCli::create_configuration(&polkadot_cli, &polkadot_cli, task_executor)
+			.map_err(|err| format!("Relay chain argument error: {}", err))?;
+
+		let collator = cmd.run.validator || cmd.collator;
+
+		info!("Parachain id: {:?}", id);
+		info!("Parachain Account: {}", parachain_account);
+		info!("Parachain genesis state: {}", genesis_state);
+		info!("Is collating: {}", if collator { "yes" } else { "no" });
+
+		service::start_node(config, key, polkadot_config, id, collator)
+			.await
+			.map(|r| r.0)
+			.map_err(Into::into)
+	})
+}
+
+fn write_output(output: &Option<std::path::PathBuf>, raw: bool, bytes: &[u8]) -> sc_cli::Result<()> {
+	let output_buf = if raw {
+		bytes.to_vec()
+	} else {
+		format!("0x{:?}", HexDisplay::from(&bytes)).into_bytes()
+	};
+
+	if let Some(output) = output {
+		std::fs::write(output, output_buf)?;
+	} else {
+		std::io::stdout().write_all(&output_buf)?;
+	}
+
+	Ok(())
+}
+
+/// Export genesis header of the parachain.
+pub fn export_genesis_state(cmd: &ExportGenesisStateCmd) -> sc_cli::Result<()> {
+	let para_id = cmd.parachain_id.unwrap_or(DEFAULT_PARA_ID).into();
+	let spec = chain_spec::load_spec(&cmd.chain.clone().unwrap_or_default(), para_id)?;
+	let block: Block = generate_genesis_block(&spec)?;
+
+	write_output(&cmd.output, cmd.raw, &block.header().encode())
+}
+
+/// Export runtime code of the parachain genesis.
+pub fn export_genesis_wasm(cmd: &ExportGenesisWasmCmd) -> sc_cli::Result<()> {
+	let spec = chain_spec::load_spec(&cmd.chain.clone().unwrap_or_default(), DEFAULT_PARA_ID.into())?;
+	let raw_wasm_blob = spec
+		.build_storage()?
+		.top
+		.remove(sp_core::storage::well_known_keys::CODE)
+		.ok_or("Could not find wasm file in genesis state!")?;
+
+	write_output(&cmd.output, cmd.raw, &raw_wasm_blob)
+}
@@ -0,0 +1,9 @@
+//! HydraDX deployed as a Polkadot parachain.
+//!
+//! The node runs the parachain runtime next to an embedded relay chain node. Collators build parachain blocks and
+//! submit them to relay chain validators, full nodes follow the parachain.
+
+pub mod chain_spec;
+pub mod cli;
+pub mod command;
+pub mod service;
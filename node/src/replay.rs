@@ -0,0 +1,271 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deterministic replay of a single extrinsic.
+//!
+//! Re-executes selected extrinsic against the state of its parent block - including block initialization
+//! and all preceding extrinsics of the same block - and prints a report of the XYK trade math steps. Trade is
+//! quoted by the runtime with the pool weights and the fee applied to the signer of the extrinsic.
+
+use codec::{Decode, Encode};
+use hydra_dx_runtime::{opaque::Block, AccountId, BlockNumber, Call, Runtime, UncheckedExtrinsic};
+use pallet_xyk::AssetPairAccountIdFor;
+use pallet_xyk_rpc::XYKRuntimeApi;
+use primitives::{AssetId, Balance};
+use sc_cli::{CliConfiguration, ImportParams, SharedParams};
+use sc_client_api::BlockBackend;
+use sp_api::ProvideRuntimeApi;
+use sp_block_builder::BlockBuilder;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, Header as HeaderT},
+};
+use std::sync::Arc;
+use structopt::StructOpt;
+
+/// The `replay-trade` command used to re-execute an extrinsic and report trade details.
+#[derive(Debug, StructOpt)]
+pub struct ReplayTradeCmd {
+	/// Number of the block which contains the extrinsic.
+	#[structopt(long)]
+	pub block: BlockNumber,
+
+	/// Index of the extrinsic within the block.
+	#[structopt(long)]
+	pub extrinsic: usize,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub import_params: ImportParams,
+}
+
+/// Trade parameters extracted from the replayed extrinsic.
+enum Trade {
+	Sell {
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount: Balance,
+		min_bought: Balance,
+		discount: bool,
+	},
+	Buy {
+		asset_out: AssetId,
+		asset_in: AssetId,
+		amount: Balance,
+		max_sold: Balance,
+		discount: bool,
+	},
+}
+
+impl Trade {
+	fn from_call(call: &Call) -> Option<Self> {
+		match call {
+			Call::XYK(pallet_xyk::Call::sell(asset_in, asset_out, amount, min_bought, discount)) => Some(Trade::Sell {
+				asset_in: *asset_in,
+				asset_out: *asset_out,
+				amount: *amount,
+				min_bought: *min_bought,
+				discount: *discount,
+			}),
+			Call::XYK(pallet_xyk::Call::buy(asset_out, asset_in, amount, max_sold, discount)) => Some(Trade::Buy {
+				asset_out: *asset_out,
+				asset_in: *asset_in,
+				amount: *amount,
+				max_sold: *max_sold,
+				discount: *discount,
+			}),
+			_ => None,
+		}
+	}
+
+	fn assets(&self) -> (AssetId, AssetId) {
+		match self {
+			Trade::Sell {
+				asset_in, asset_out, ..
+			}
+			| Trade::Buy {
+				asset_in, asset_out, ..
+			} => (*asset_in, *asset_out),
+		}
+	}
+}
+
+fn reserve_of(balances: &[pallet_xyk_rpc::BalanceInfo<AssetId, Balance>], asset: AssetId) -> Balance {
+	balances
+		.iter()
+		.find(|b| b.asset == Some(asset))
+		.map(|b| b.amount)
+		.unwrap_or_default()
+}
+
+impl ReplayTradeCmd {
+	/// Run the replay command
+	pub fn run<C>(&self, client: Arc<C>) -> sc_cli::Result<()>
+	where
+		C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockBackend<Block>,
+		C::Api: BlockBuilder<Block> + XYKRuntimeApi<Block, AccountId, AssetId, Balance>,
+	{
+		let hash = client
+			.hash(self.block)?
+			.ok_or_else(|| format!("Block #{} not found", self.block))?;
+
+		let (mut header, extrinsics) = client
+			.block(&BlockId::Hash(hash))?
+			.ok_or_else(|| format!("Block body of #{} not found", self.block))?
+			.block
+			.deconstruct();
+
+		let opaque = extrinsics
+			.get(self.extrinsic)
+			.cloned()
+			.ok_or_else(|| format!("Extrinsic {} not found in block #{}", self.extrinsic, self.block))?;
+
+		let xt = UncheckedExtrinsic::decode(&mut &opaque.encode()[..])
+			.map_err(|e| format!("Failed to decode extrinsic: {:?}", e))?;
+
+		let parent = BlockId::Hash(*header.parent_hash());
+
+		// Seal is not part of the executed block.
+		header.digest_mut().logs.retain(|item| item.as_seal().is_none());
+
+		let api = client.runtime_api();
+
+		api.initialize_block(&parent, &header)
+			.map_err(|e| format!("Failed to initialize block: {:?}", e))?;
+
+		// Preceding extrinsics are applied the same way as during block import, their results are not relevant.
+		for preceding in extrinsics.iter().take(self.extrinsic) {
+			let _ = api
+				.apply_extrinsic(&parent, preceding.clone())
+				.map_err(|e| format!("Failed to apply preceding extrinsic: {:?}", e))?;
+		}
+
+		println!("Replaying extrinsic {}-{} ({:?})", self.block, self.extrinsic, hash);
+		println!("Signer: {:?}", xt.signature.as_ref().map(|s| &s.0));
+		println!("Call: {:?}", xt.function);
+
+		let trade = Trade::from_call(&xt.function);
+
+		let pool = trade.as_ref().map(|t| {
+			let (asset_in, asset_out) = t.assets();
+			pallet_xyk::AssetPairAccountId::<Runtime>::from_assets(asset_in, asset_out)
+		});
+
+		if let (Some(trade), Some(pool)) = (trade.as_ref(), pool.as_ref()) {
+			let balances = api
+				.get_pool_balances(&parent, pool.clone())
+				.map_err(|e| format!("Failed to retrieve pool balances: {:?}", e))?;
+
+			let (asset_in, asset_out) = trade.assets();
+			let in_reserve = reserve_of(&balances, asset_in);
+			let out_reserve = reserve_of(&balances, asset_out);
+
+			println!("Pool: {:?}", pool);
+			println!(
+				"Reserves before: asset {} = {}, asset {} = {}",
+				asset_in, in_reserve, asset_out, out_reserve
+			);
+
+			let signer = xt.signature.as_ref().map(|s| s.0.clone()).unwrap_or_default();
+
+			match *trade {
+				Trade::Sell {
+					amount,
+					min_bought,
+					discount,
+					..
+				} => {
+					let quote = api
+						.calculate_sell_price_for(&parent, signer, asset_in, asset_out, amount, discount)
+						.map_err(|e| format!("Failed to calculate sell price: {:?}", e))?;
+
+					println!(
+						"Sell amount: {}, min bought: {}, discount: {}",
+						amount, min_bought, discount
+					);
+					println!("Fee: {:?}", quote.as_ref().map(|q| q.fee));
+					println!(
+						"Amount without fee: {:?}",
+						quote.as_ref().and_then(|q| amount.checked_sub(q.fee))
+					);
+					println!("Calculated sale price: {:?}", quote.as_ref().map(|q| q.amount));
+					println!("Price impact: {:?}", quote.as_ref().map(|q| q.price_impact));
+				}
+				Trade::Buy {
+					amount,
+					max_sold,
+					discount,
+					..
+				} => {
+					let quote = api
+						.calculate_buy_price_for(&parent, signer, asset_in, asset_out, amount, discount)
+						.map_err(|e| format!("Failed to calculate buy price: {:?}", e))?;
+
+					println!("Buy amount: {}, max sold: {}, discount: {}", amount, max_sold, discount);
+					println!(
+						"Calculated buy price: {:?}",
+						quote.as_ref().map(|q| q.amount.saturating_sub(q.fee))
+					);
+					println!("Fee: {:?}", quote.as_ref().map(|q| q.fee));
+					println!("Buy price with fee: {:?}", quote.as_ref().map(|q| q.amount));
+					println!("Price impact: {:?}", quote.as_ref().map(|q| q.price_impact));
+				}
+			}
+		} else {
+			println!("Extrinsic is not a XYK trade, only dispatch result is reported.");
+		}
+
+		let result = api
+			.apply_extrinsic(&parent, opaque)
+			.map_err(|e| format!("Failed to apply extrinsic: {:?}", e))?;
+
+		println!("Result: {:?}", result);
+
+		if let (Some(trade), Some(pool)) = (trade, pool) {
+			let balances = api
+				.get_pool_balances(&parent, pool)
+				.map_err(|e| format!("Failed to retrieve pool balances: {:?}", e))?;
+
+			let (asset_in, asset_out) = trade.assets();
+
+			println!(
+				"Reserves after: asset {} = {}, asset {} = {}",
+				asset_in,
+				reserve_of(&balances, asset_in),
+				asset_out,
+				reserve_of(&balances, asset_out)
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for ReplayTradeCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+}
@@ -66,19 +66,25 @@ pub fn create_full<C, P, SC, B>(deps: FullDeps<C, P, SC, B>) -> jsonrpc_core::Io
 where
 	C: ProvideRuntimeApi<Block>,
 	C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError>,
+	C: sc_client_api::ProofProvider<Block>,
+	C: sc_client_api::BlockchainEvents<Block>,
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BabeApi<Block>,
 	C::Api: BlockBuilder<Block>,
 	C::Api: pallet_xyk_rpc::XYKRuntimeApi<Block, AccountId, AssetId, Balance>,
+	C::Api: pallet_asset_registry_rpc::AssetRegistryRuntimeApi<Block, AssetId>,
+	C::Api: sp_session::SessionKeys<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 	SC: SelectChain<Block> + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::StateBackend<sp_runtime::traits::HashFor<Block>>,
 {
+	use crate::session_keys::{SessionKeysApi, SessionKeysRpc};
+	use pallet_asset_registry_rpc::{AssetRegistry, AssetRegistryApi};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
-	use pallet_xyk_rpc::{XYKApi, XYK};
+	use pallet_xyk_rpc::{XYKApi, XYKPoolChanges, XYKPoolChangesApi, XYKWatchlist, XYKWatchlistApi, XYK};
 	use substrate_frame_rpc_system::{FullSystem, SystemApi};
 
 	let mut io = jsonrpc_core::IoHandler::default();
@@ -121,6 +127,24 @@ where
 
 	io.extend_with(XYKApi::to_delegate(XYK::new(client.clone())));
 
+	io.extend_with(XYKWatchlistApi::to_delegate(XYKWatchlist::<_, _, AccountId>::new(
+		client.clone(),
+		subscription_executor.clone(),
+	)));
+
+	io.extend_with(XYKPoolChangesApi::to_delegate(XYKPoolChanges::new(
+		client.clone(),
+		subscription_executor.clone(),
+	)));
+
+	io.extend_with(AssetRegistryApi::to_delegate(AssetRegistry::new(client.clone())));
+
+	io.extend_with(SessionKeysApi::to_delegate(SessionKeysRpc::new(
+		client.clone(),
+		keystore.clone(),
+		deny_unsafe,
+	)));
+
 	io.extend_with(sc_consensus_babe_rpc::BabeApi::to_delegate(BabeRpcHandler::new(
 		client,
 		shared_epoch_changes,
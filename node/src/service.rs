@@ -246,6 +246,13 @@ pub fn new_full(
 		telemetry: telemetry.as_mut(),
 	})?;
 
+	if let Some(registry) = prometheus_registry.as_ref() {
+		let metrics = crate::metrics::DexMetrics::register(registry)?;
+		task_manager
+			.spawn_handle()
+			.spawn("dex-metrics", crate::metrics::run(client.clone(), metrics));
+	}
+
 	let (block_import, grandpa_link, babe_link) = import_setup;
 
 	if role.is_authority() {
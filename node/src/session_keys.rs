@@ -0,0 +1,244 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Session keys management.
+//!
+//! The whole session key set is handled at once and in the order defined by runtime `SessionKeys`, so that
+//! the encoded keys can be submitted to `session.setKeys` as they are. Ownership of the keys is proven by
+//! signing the SCALE encoded owner account with each of the session keys.
+
+use codec::Encode;
+use hydra_dx_runtime::{
+	opaque::{Block, SessionKeys},
+	AccountId,
+};
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sc_rpc_api::DenyUnsafe;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+	crypto::{CryptoTypeId, KeyTypeId},
+	ed25519, sr25519, Bytes, Pair,
+};
+use sp_keystore::{SyncCryptoStore, SyncCryptoStorePtr};
+use sp_runtime::generic::BlockId;
+use sp_session::SessionKeys as SessionKeysRuntimeApi;
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+/// Public key of one key type of the session key set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionKey {
+	/// Key type identifier, e.g. `gran` or `babe`.
+	pub key_type: String,
+	/// Crypto scheme of the key, `ed25519` or `sr25519`. Empty if the key is not in the keystore.
+	pub crypto: String,
+	/// Public key.
+	pub public: Bytes,
+	/// Whether the keystore holds the private key.
+	pub present: bool,
+	/// Signature of the SCALE encoded owner account made by the key.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub signature: Option<Bytes>,
+}
+
+/// Session key set with the state of each key in the keystore.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionKeysInfo {
+	/// SCALE encoded session keys as expected by `session.setKeys`.
+	pub keys: Bytes,
+	/// Individual keys in the order they are encoded in `keys`.
+	pub session_keys: Vec<SessionKey>,
+	/// Whether the keystore holds private keys of the whole set.
+	pub verified: bool,
+}
+
+/// Name of a supported crypto scheme.
+pub fn crypto_name(id: CryptoTypeId) -> Option<&'static str> {
+	if id == ed25519::CRYPTO_ID {
+		Some("ed25519")
+	} else if id == sr25519::CRYPTO_ID {
+		Some("sr25519")
+	} else {
+		None
+	}
+}
+
+/// Key type identifier as a string, e.g. `gran`.
+pub fn key_type_name(key_type: KeyTypeId) -> String {
+	String::from_utf8_lossy(&key_type.0).into_owned()
+}
+
+/// Check the encoded session `keys` against the keystore and sign `owner` with each key found.
+///
+/// Fails if `keys` are not a valid encoding of runtime `SessionKeys`.
+pub fn inspect_session_keys(
+	keystore: &SyncCryptoStorePtr,
+	keys: &[u8],
+	owner: Option<&[u8]>,
+) -> std::result::Result<SessionKeysInfo, String> {
+	let raw_keys = SessionKeys::decode_into_raw_public_keys(keys).ok_or("Invalid session keys encoding")?;
+
+	let session_keys: Vec<SessionKey> = raw_keys
+		.into_iter()
+		.map(|(public, key_type)| {
+			let pair = SyncCryptoStore::keys(&**keystore, key_type)
+				.unwrap_or_default()
+				.into_iter()
+				.find(|pair| pair.1 == public && crypto_name(pair.0).is_some());
+			let present = pair
+				.as_ref()
+				.map(|pair| SyncCryptoStore::has_keys(&**keystore, &[(pair.1.clone(), key_type)]))
+				.unwrap_or(false);
+			let signature = match (owner, pair.as_ref()) {
+				(Some(owner), Some(pair)) if present => SyncCryptoStore::sign_with(&**keystore, key_type, pair, owner)
+					.ok()
+					.map(Bytes),
+				_ => None,
+			};
+
+			SessionKey {
+				key_type: key_type_name(key_type),
+				crypto: pair.and_then(|pair| crypto_name(pair.0)).unwrap_or_default().into(),
+				public: Bytes(public),
+				present,
+				signature,
+			}
+		})
+		.collect();
+
+	Ok(SessionKeysInfo {
+		keys: Bytes(keys.to_vec()),
+		verified: session_keys.iter().all(|key| key.present),
+		session_keys,
+	})
+}
+
+/// Verify that `key` signed `owner`.
+pub fn verify_ownership(key: &SessionKey, owner: &[u8]) -> bool {
+	let signature = match key.signature.as_ref() {
+		Some(signature) => signature,
+		None => return false,
+	};
+
+	match key.crypto.as_str() {
+		"ed25519" => match (
+			ed25519::Signature::try_from(&signature[..]),
+			ed25519::Public::try_from(&key.public[..]),
+		) {
+			(Ok(signature), Ok(public)) => ed25519::Pair::verify(&signature, owner, &public),
+			_ => false,
+		},
+		"sr25519" => match (
+			sr25519::Signature::try_from(&signature[..]),
+			sr25519::Public::try_from(&key.public[..]),
+		) {
+			(Ok(signature), Ok(public)) => sr25519::Pair::verify(&signature, owner, &public),
+			_ => false,
+		},
+		_ => false,
+	}
+}
+
+/// Session keys RPC methods.
+#[rpc(server)]
+pub trait SessionKeysApi {
+	/// Generate new session keys in the node keystore and sign `owner` with each of them.
+	#[rpc(name = "author_rotateKeysWithProof")]
+	fn rotate_keys_with_proof(&self, owner: AccountId) -> Result<SessionKeysInfo>;
+
+	/// Check that the node keystore holds private keys of encoded session `keys`.
+	#[rpc(name = "author_verifySessionKeys")]
+	fn verify_session_keys(&self, keys: Bytes) -> Result<SessionKeysInfo>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// Session keys are not valid.
+	InvalidSessionKeys,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::InvalidSessionKeys => 2,
+		}
+	}
+}
+
+/// Session keys RPC handler.
+pub struct SessionKeysRpc<C> {
+	client: Arc<C>,
+	keystore: SyncCryptoStorePtr,
+	deny_unsafe: DenyUnsafe,
+}
+
+impl<C> SessionKeysRpc<C> {
+	/// Create new session keys RPC handler.
+	pub fn new(client: Arc<C>, keystore: SyncCryptoStorePtr, deny_unsafe: DenyUnsafe) -> Self {
+		Self {
+			client,
+			keystore,
+			deny_unsafe,
+		}
+	}
+
+	fn inspect(&self, keys: &[u8], owner: Option<&[u8]>) -> Result<SessionKeysInfo> {
+		inspect_session_keys(&self.keystore, keys, owner).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::InvalidSessionKeys.into()),
+			message: "Invalid session keys.".into(),
+			data: Some(e.into()),
+		})
+	}
+}
+
+impl<C> SessionKeysApi for SessionKeysRpc<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: SessionKeysRuntimeApi<Block>,
+{
+	fn rotate_keys_with_proof(&self, owner: AccountId) -> Result<SessionKeysInfo> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let at = BlockId::hash(self.client.info().best_hash);
+
+		let keys = self
+			.client
+			.runtime_api()
+			.generate_session_keys(&at, None)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to generate session keys.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})?;
+
+		self.inspect(&keys, Some(&owner.encode()))
+	}
+
+	fn verify_session_keys(&self, keys: Bytes) -> Result<SessionKeysInfo> {
+		self.deny_unsafe.check_if_safe()?;
+
+		self.inspect(&keys, None)
+	}
+}
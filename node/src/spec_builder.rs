@@ -0,0 +1,324 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Chain spec builder.
+//!
+//! Builds a chain spec of a custom network from a JSON file describing its genesis - authorities, balances,
+//! registered assets, initial pools and claims - so custom testnets can be spun up without recompiling the node.
+
+use crate::chain_spec::{authority_keys_from_seed, session_keys, ChainSpec, DEFAULT_PROTOCOL_ID, STASH};
+use codec::Decode;
+use hydra_dx_runtime::pallet_claims::{merkle, EthereumAddress};
+use hydra_dx_runtime::{
+	AccountId, AssetRegistryConfig, AuthorityDiscoveryConfig, BabeConfig, BalancesConfig, ClaimsConfig, CouncilConfig,
+	ElectionsConfig, FaucetConfig, GenesisConfig, GenesisHistoryConfig, GrandpaConfig, ImOnlineConfig, Perbill,
+	PricePublisherConfig, SessionConfig, StakerStatus, StakingConfig, SudoConfig, SystemConfig,
+	TechnicalCommitteeConfig, TokensConfig, XYKConfig, CORE_ASSET_ID, WASM_BINARY,
+};
+use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
+use pallet_staking::Forcing;
+use primitives::{AssetId, Balance, Price};
+use sc_service::ChainType;
+use serde::Deserialize;
+use serde_json::map::Map;
+use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
+use sp_consensus_babe::AuthorityId as BabeId;
+use sp_core::H256;
+use sp_finality_grandpa::AuthorityId as GrandpaId;
+use sp_runtime::FixedPointNumber;
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+
+/// The `build-spec-hydra` command used to build a chain spec from a genesis description file.
+#[derive(Debug, StructOpt)]
+pub struct BuildSpecHydraCmd {
+	/// Path to the JSON file describing genesis of the chain.
+	#[structopt(long, parse(from_os_str))]
+	pub config: PathBuf,
+
+	/// Force raw genesis storage output.
+	#[structopt(long)]
+	pub raw: bool,
+}
+
+impl BuildSpecHydraCmd {
+	/// Build the chain spec and print it to stdout.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let spec = GenesisSpec::from_file(&self.config)?;
+		let chain_spec = spec.into_chain_spec()?;
+
+		let json = sc_service::chain_ops::build_spec(&chain_spec, self.raw)?;
+
+		println!("{}", json);
+
+		Ok(())
+	}
+}
+
+/// Session keys of a genesis authority.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Authority {
+	/// All keys derived from a seed, e.g. `Alice`. Suitable for testnets only.
+	Seed(String),
+	/// Explicit stash and controller accounts and session keys.
+	#[serde(rename_all = "camelCase")]
+	Keys {
+		stash: AccountId,
+		controller: AccountId,
+		grandpa: GrandpaId,
+		babe: BabeId,
+		im_online: ImOnlineId,
+		authority_discovery: AuthorityDiscoveryId,
+	},
+}
+
+impl Authority {
+	fn keys(
+		&self,
+	) -> (
+		AccountId,
+		AccountId,
+		GrandpaId,
+		BabeId,
+		ImOnlineId,
+		AuthorityDiscoveryId,
+	) {
+		match self {
+			Authority::Seed(seed) => authority_keys_from_seed(seed),
+			Authority::Keys {
+				stash,
+				controller,
+				grandpa,
+				babe,
+				im_online,
+				authority_discovery,
+			} => (
+				stash.clone(),
+				controller.clone(),
+				grandpa.clone(),
+				babe.clone(),
+				im_online.clone(),
+				authority_discovery.clone(),
+			),
+		}
+	}
+}
+
+/// Initial XYK pool created at genesis.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Pool {
+	pub owner: AccountId,
+	pub asset_a: AssetId,
+	pub asset_b: AssetId,
+	/// Amount of `asset_a` provided to the pool.
+	pub amount: Balance,
+	/// Price of `asset_a` in `asset_b` as a `[numerator, denominator]` fraction.
+	pub price: (u128, u128),
+}
+
+/// Description of genesis of a custom chain.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GenesisSpec {
+	pub name: String,
+	pub id: String,
+	pub chain_type: ChainType,
+	#[serde(default)]
+	pub boot_nodes: Vec<String>,
+	pub authorities: Vec<Authority>,
+	pub sudo: AccountId,
+	/// Native currency balances. Stash accounts of authorities must be funded with at least the staked amount.
+	#[serde(default)]
+	pub balances: Vec<(AccountId, Balance)>,
+	/// Registered assets as `(name, asset id)`.
+	#[serde(default)]
+	pub assets: Vec<(String, AssetId)>,
+	/// Non-native currency balances as `(account, asset id, amount)`.
+	#[serde(default)]
+	pub tokens: Vec<(AccountId, AssetId, Balance)>,
+	#[serde(default)]
+	pub pools: Vec<Pool>,
+	#[serde(default)]
+	pub claims: Vec<(EthereumAddress, Balance)>,
+	/// SCALE encoded `Vec<(EthereumAddress, Balance)>` of claims which are claimed with a Merkle proof.
+	/// Only the root of their tree is stored in the genesis.
+	#[serde(default)]
+	pub claims_file: Option<PathBuf>,
+	#[serde(default)]
+	pub council: Vec<AccountId>,
+	#[serde(default)]
+	pub technical_committee: Vec<AccountId>,
+	#[serde(default)]
+	pub price_publishers: Vec<AccountId>,
+}
+
+impl GenesisSpec {
+	/// Load genesis description from a JSON file.
+	pub fn from_file(path: &Path) -> Result<Self, String> {
+		let file = std::fs::File::open(path).map_err(|e| format!("Error opening genesis spec file: {}", e))?;
+
+		serde_json::from_reader(file).map_err(|e| format!("Error parsing genesis spec file: {}", e))
+	}
+
+	/// Build chain spec of the described chain.
+	pub fn into_chain_spec(self) -> Result<ChainSpec, String> {
+		let wasm_binary = WASM_BINARY.ok_or_else(|| "Wasm binary not available".to_string())?;
+
+		let boot_nodes = self
+			.boot_nodes
+			.iter()
+			.map(|node| node.parse().map_err(|e| format!("Invalid boot node {}: {}", node, e)))
+			.collect::<Result<Vec<_>, String>>()?;
+
+		let pools = self
+			.pools
+			.iter()
+			.map(|pool| {
+				let (n, d) = pool.price;
+				Price::checked_from_rational(n, d)
+					.map(|price| (pool.owner.clone(), pool.asset_a, pool.asset_b, pool.amount, price))
+					.ok_or_else(|| format!("Invalid price of pool {} - {}", pool.asset_a, pool.asset_b))
+			})
+			.collect::<Result<Vec<_>, String>>()?;
+
+		let claims_root = match &self.claims_file {
+			Some(path) => {
+				let blob = std::fs::read(path).map_err(|e| format!("Error reading claims file: {}", e))?;
+				let claims = Vec::<(EthereumAddress, Balance)>::decode(&mut &blob[..])
+					.map_err(|e| format!("Error decoding claims file: {}", e))?;
+				merkle::merkle_root(&claims)
+			}
+			None => None,
+		};
+
+		let mut properties = Map::new();
+		properties.insert("tokenDecimals".into(), 12.into());
+		properties.insert("tokenSymbol".into(), "HDX".into());
+		properties.insert("ss58Format".into(), 63.into());
+
+		let name = self.name.clone();
+		let id = self.id.clone();
+		let chain_type = self.chain_type.clone();
+
+		Ok(ChainSpec::from_genesis(
+			&name,
+			&id,
+			chain_type,
+			move || custom_genesis(wasm_binary, &self, pools.clone(), claims_root),
+			boot_nodes,
+			None,
+			Some(DEFAULT_PROTOCOL_ID),
+			Some(properties),
+			None,
+		))
+	}
+}
+
+/// Configure initial storage state for FRAME modules from genesis description.
+fn custom_genesis(
+	wasm_binary: &[u8],
+	spec: &GenesisSpec,
+	pools: Vec<(AccountId, AssetId, AssetId, Balance, Price)>,
+	claims_root: Option<H256>,
+) -> GenesisConfig {
+	let initial_authorities = spec.authorities.iter().map(Authority::keys).collect::<Vec<_>>();
+
+	GenesisConfig {
+		frame_system: SystemConfig {
+			// Add Wasm runtime to storage.
+			code: wasm_binary.to_vec(),
+			changes_trie_config: Default::default(),
+		},
+		pallet_balances: BalancesConfig {
+			balances: spec.balances.clone(),
+		},
+		pallet_grandpa: GrandpaConfig { authorities: vec![] },
+		pallet_sudo: SudoConfig { key: spec.sudo.clone() },
+		pallet_asset_registry: AssetRegistryConfig {
+			core_asset_id: CORE_ASSET_ID,
+			asset_ids: spec
+				.assets
+				.iter()
+				.map(|(name, id)| (name.as_bytes().to_vec(), *id))
+				.collect(),
+			next_asset_id: spec.assets.iter().map(|(_, id)| id + 1).max().unwrap_or(1),
+		},
+		orml_tokens: TokensConfig {
+			endowed_accounts: spec.tokens.clone(),
+		},
+		pallet_faucet: FaucetConfig {
+			rampage: false,
+			mint_limit: 5,
+			mintable_currencies: vec![],
+			drip_bundle: vec![],
+		},
+		pallet_babe: BabeConfig {
+			authorities: vec![],
+			epoch_config: Some(hydra_dx_runtime::BABE_GENESIS_EPOCH_CONFIG),
+		},
+		pallet_authority_discovery: AuthorityDiscoveryConfig { keys: vec![] },
+		pallet_im_online: ImOnlineConfig { keys: vec![] },
+		pallet_treasury: Default::default(),
+		pallet_session: SessionConfig {
+			keys: initial_authorities
+				.iter()
+				.map(|x| {
+					(
+						x.0.clone(),
+						x.0.clone(),
+						session_keys(x.2.clone(), x.3.clone(), x.4.clone(), x.5.clone()),
+					)
+				})
+				.collect::<Vec<_>>(),
+		},
+		pallet_staking: StakingConfig {
+			validator_count: initial_authorities.len() as u32,
+			minimum_validator_count: initial_authorities.len() as u32,
+			stakers: initial_authorities
+				.iter()
+				.map(|x| (x.0.clone(), x.1.clone(), STASH, StakerStatus::Validator))
+				.collect(),
+			invulnerables: initial_authorities.iter().map(|x| x.0.clone()).collect(),
+			force_era: Forcing::ForceNone,
+			slash_reward_fraction: Perbill::from_percent(10),
+			..Default::default()
+		},
+		pallet_elections_phragmen: ElectionsConfig { members: vec![] },
+		pallet_collective_Instance1: CouncilConfig {
+			members: spec.council.clone(),
+			phantom: Default::default(),
+		},
+		pallet_collective_Instance2: TechnicalCommitteeConfig {
+			members: spec.technical_committee.clone(),
+			phantom: Default::default(),
+		},
+		pallet_claims: ClaimsConfig {
+			claims: spec.claims.clone(),
+			claims_root,
+		},
+		pallet_genesis_history: GenesisHistoryConfig::default(),
+		pallet_referrals: Default::default(),
+		pallet_fee_discount: Default::default(),
+		pallet_xyk: XYKConfig { pools },
+		pallet_price_publisher: PricePublisherConfig {
+			pairs: spec.pools.iter().map(|pool| (pool.asset_a, pool.asset_b)).collect(),
+			publishers: spec.price_publishers.clone(),
+		},
+	}
+}
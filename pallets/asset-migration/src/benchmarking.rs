@@ -0,0 +1,112 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+use crate::Pallet as AssetMigrationPallet;
+
+const SEED: u32 = 1;
+
+const AMOUNT: Balance = 1_000_000_000_000;
+
+fn register_asset<T: Config>(name: &[u8]) -> Result<AssetId, DispatchError> {
+	Ok(pallet_asset_registry::Pallet::<T>::get_or_create_asset(name.to_vec())?.into())
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32, assets: &[AssetId]) -> T::AccountId {
+	let caller: T::AccountId = account(name, index, SEED);
+	for asset in assets {
+		T::MultiCurrency::deposit(*asset, &caller, 1_000_000_000_000_000).unwrap();
+	}
+	caller
+}
+
+fn register_migration<T: Config>() -> Result<(AssetId, AssetId), DispatchError> {
+	let asset_old = register_asset::<T>(b"OLD")?;
+	let asset_new = register_asset::<T>(b"NEW")?;
+
+	AssetMigrationPallet::<T>::register_migration(RawOrigin::Root.into(), asset_old, asset_new, Price::from(2))
+		.map_err(|e| e.error)?;
+
+	Ok((asset_old, asset_new))
+}
+
+benchmarks! {
+	register_migration {
+		let asset_old = register_asset::<T>(b"OLD")?;
+		let asset_new = register_asset::<T>(b"NEW")?;
+
+	}: _(RawOrigin::Root, asset_old, asset_new, Price::from(2))
+	verify {
+		assert!(AssetMigrationPallet::<T>::migrations(asset_old).is_some());
+	}
+
+	remove_migration {
+		let (asset_old, _) = register_migration::<T>()?;
+
+	}: _(RawOrigin::Root, asset_old)
+	verify {
+		assert!(AssetMigrationPallet::<T>::migrations(asset_old).is_none());
+	}
+
+	migrate {
+		let (asset_old, asset_new) = register_migration::<T>()?;
+		let caller = funded_account::<T>("caller", 0, &[asset_old]);
+
+	}: _(RawOrigin::Signed(caller.clone()), asset_old)
+	verify {
+		assert_eq!(T::MultiCurrency::free_balance(asset_old, &caller), 0);
+		assert_eq!(T::MultiCurrency::free_balance(asset_new, &caller), 2_000_000_000_000_000);
+	}
+
+	migrate_pool {
+		let (asset_old, asset_new) = register_migration::<T>()?;
+		let asset_other = <T as pallet_xyk::Config>::NativeAssetId::get();
+		let creator = funded_account::<T>("creator", 0, &[asset_old, asset_other]);
+		let caller = funded_account::<T>("caller", 0, &[]);
+
+		pallet_xyk::Pallet::<T>::do_create_pool(creator, asset_old, asset_other, AMOUNT, Price::from(1))?;
+
+	}: _(RawOrigin::Signed(caller), asset_old, asset_other)
+	verify {
+		assert!(!pallet_xyk::Pallet::<T>::exists(AssetPair::new(asset_old, asset_other)));
+		assert!(pallet_xyk::Pallet::<T>::exists(AssetPair::new(asset_new, asset_other)));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_register_migration::<Test>());
+			assert_ok!(test_benchmark_remove_migration::<Test>());
+			assert_ok!(test_benchmark_migrate::<Test>());
+			assert_ok!(test_benchmark_migrate_pool::<Test>());
+		});
+	}
+}
@@ -0,0 +1,309 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! # Asset Migration Pallet
+//!
+//! ## Overview
+//!
+//! Asset migration pallet provides governance approved replacement of one asset by another at a fixed ratio,
+//! e.g. when contract of a bridged token changes and the token is re-issued under a new asset id.
+//!
+//! Once a migration of an asset is registered, holders migrate their whole balance of the old asset - it is burned
+//! and amount of the new asset given by the migration ratio is minted. Pools holding the old asset are migrated
+//! atomically - reserve of the old asset is replaced the same way and the pool is moved to the new asset,
+//! liquidity providers keep their shares.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{ensure, transactional};
+use frame_system::ensure_signed;
+use orml_traits::MultiCurrency;
+use primitives::{
+	asset::AssetPair,
+	audit::PrivilegedAction,
+	traits::{AuditLog, AMM},
+	AssetId, Balance, Price,
+};
+use sp_runtime::{traits::Zero, DispatchError, FixedPointNumber, RuntimeDebug};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// Registered migration of an asset.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct AssetMigration {
+	/// Asset which replaces the migrated asset
+	pub asset_new: AssetId,
+	/// Amount of the new asset issued for one unit of the migrated asset
+	pub ratio: Price,
+}
+
+impl AssetMigration {
+	/// Return amount of the new asset issued for `amount` of the migrated asset, rounded down.
+	pub fn convert(&self, amount: Balance) -> Option<Balance> {
+		self.ratio.checked_mul_int(amount)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_xyk::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Multi currency for burning migrated and minting new assets
+		type MultiCurrency: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// Origin which registers and removes asset migrations
+		type MigrationOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Asset cannot be migrated to itself.
+		SameAsset,
+
+		/// Migration ratio must be greater than zero.
+		ZeroRatio,
+
+		/// New asset is not registered in the asset registry.
+		AssetNotRegistered,
+
+		/// Migration of the asset is already registered.
+		MigrationAlreadyRegistered,
+
+		/// New asset is being migrated itself.
+		NewAssetMigrated,
+
+		/// Migration of the asset is not registered.
+		MigrationNotFound,
+
+		/// There is no balance of the asset to migrate.
+		NothingToMigrate,
+
+		/// Migrated amount is too low to issue any amount of the new asset.
+		MigratedAmountTooLow,
+
+		/// Amount of the new asset overflows.
+		Overflow,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Migration of an asset was registered. [old asset, new asset, ratio]
+		MigrationRegistered(AssetId, AssetId, Price),
+
+		/// Migration of an asset was removed. [old asset]
+		MigrationRemoved(AssetId),
+
+		/// Balance of an account was migrated. [who, old asset, new asset, old amount, new amount]
+		BalanceMigrated(T::AccountId, AssetId, AssetId, Balance, Balance),
+
+		/// Pool was moved to the new asset. [old asset, new asset, other asset, new pool]
+		PoolMigrated(AssetId, AssetId, AssetId, T::AccountId),
+	}
+
+	/// Registered migrations by migrated asset.
+	#[pallet::storage]
+	#[pallet::getter(fn migrations)]
+	pub type Migrations<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, AssetMigration, OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register migration of `asset_old` to `asset_new`.
+		///
+		/// Holders of `asset_old` receive `ratio` of `asset_new` for each unit of `asset_old` they migrate.
+		///
+		/// Can be performed only by `MigrationOrigin`.
+		///
+		/// Emits `MigrationRegistered` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::register_migration())]
+		#[transactional]
+		pub fn register_migration(
+			origin: OriginFor<T>,
+			asset_old: AssetId,
+			asset_new: AssetId,
+			ratio: Price,
+		) -> DispatchResultWithPostInfo {
+			T::MigrationOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_old != asset_new, Error::<T>::SameAsset);
+			ensure!(!ratio.is_zero(), Error::<T>::ZeroRatio);
+			ensure!(
+				pallet_asset_registry::Pallet::<T>::asset_name(asset_new.into()).is_some(),
+				Error::<T>::AssetNotRegistered
+			);
+			ensure!(
+				!<Migrations<T>>::contains_key(asset_old),
+				Error::<T>::MigrationAlreadyRegistered
+			);
+			ensure!(!<Migrations<T>>::contains_key(asset_new), Error::<T>::NewAssetMigrated);
+
+			<Migrations<T>>::insert(asset_old, AssetMigration { asset_new, ratio });
+
+			Self::deposit_event(Event::MigrationRegistered(asset_old, asset_new, ratio));
+
+			<T as pallet_xyk::Config>::AuditLog::record(
+				None,
+				PrivilegedAction::AssetMigrationRegistered(asset_old, asset_new),
+			);
+
+			Ok(().into())
+		}
+
+		/// Remove migration of `asset_old`.
+		///
+		/// Balances which have been already migrated are not affected.
+		///
+		/// Can be performed only by `MigrationOrigin`.
+		///
+		/// Emits `MigrationRemoved` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_migration())]
+		#[transactional]
+		pub fn remove_migration(origin: OriginFor<T>, asset_old: AssetId) -> DispatchResultWithPostInfo {
+			T::MigrationOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				<Migrations<T>>::take(asset_old).is_some(),
+				Error::<T>::MigrationNotFound
+			);
+
+			Self::deposit_event(Event::MigrationRemoved(asset_old));
+
+			<T as pallet_xyk::Config>::AuditLog::record(None, PrivilegedAction::AssetMigrationRemoved(asset_old));
+
+			Ok(().into())
+		}
+
+		/// Migrate whole free balance of `asset_old` of the origin.
+		///
+		/// Balance of `asset_old` is burned and amount of the new asset given by the migration ratio is minted.
+		///
+		/// Emits `BalanceMigrated` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::migrate())]
+		#[transactional]
+		pub fn migrate(origin: OriginFor<T>, asset_old: AssetId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let migration = Self::migrations(asset_old).ok_or(Error::<T>::MigrationNotFound)?;
+
+			Self::migrate_balance(&who, asset_old, &migration)?;
+
+			Ok(().into())
+		}
+
+		/// Migrate pool of `asset_old` and `asset_other` to the new asset.
+		///
+		/// Reserve of `asset_old` in the pool is migrated the same way as balances of holders and the pool is moved
+		/// to the pool of the new asset and `asset_other`, which must not exist yet. Liquidity providers keep their
+		/// shares.
+		///
+		/// Can be performed by anyone once migration of `asset_old` is registered.
+		///
+		/// Emits `BalanceMigrated` for the pool account and `PoolMigrated` events when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_pool())]
+		#[transactional]
+		pub fn migrate_pool(
+			origin: OriginFor<T>,
+			asset_old: AssetId,
+			asset_other: AssetId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let migration = Self::migrations(asset_old).ok_or(Error::<T>::MigrationNotFound)?;
+
+			let old_assets = AssetPair::new(asset_old, asset_other);
+
+			ensure!(
+				pallet_xyk::Pallet::<T>::exists(old_assets),
+				pallet_xyk::Error::<T>::TokenPoolNotFound
+			);
+
+			let old_pool = pallet_xyk::Pallet::<T>::get_pair_id(old_assets);
+
+			Self::migrate_balance(&old_pool, asset_old, &migration)?;
+
+			let new_pool = pallet_xyk::Pallet::<T>::migrate_pool_asset(asset_old, migration.asset_new, asset_other)?;
+
+			Self::deposit_event(Event::PoolMigrated(
+				asset_old,
+				migration.asset_new,
+				asset_other,
+				new_pool,
+			));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Burn whole free balance of `asset_old` of `who` and mint the new asset of `migration` instead.
+	fn migrate_balance(
+		who: &T::AccountId,
+		asset_old: AssetId,
+		migration: &AssetMigration,
+	) -> Result<(), DispatchError> {
+		let amount_old = T::MultiCurrency::free_balance(asset_old, who);
+
+		ensure!(!amount_old.is_zero(), Error::<T>::NothingToMigrate);
+
+		let amount_new = migration.convert(amount_old).ok_or(Error::<T>::Overflow)?;
+
+		ensure!(!amount_new.is_zero(), Error::<T>::MigratedAmountTooLow);
+
+		T::MultiCurrency::withdraw(asset_old, who, amount_old)?;
+		T::MultiCurrency::deposit(migration.asset_new, who, amount_new)?;
+
+		Self::deposit_event(Event::BalanceMigrated(
+			who.clone(),
+			asset_old,
+			migration.asset_new,
+			amount_old,
+			amount_new,
+		));
+
+		Ok(())
+	}
+}
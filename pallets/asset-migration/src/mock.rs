@@ -0,0 +1,231 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate as asset_migration;
+use crate::Config;
+use frame_support::parameter_types;
+use frame_system as system;
+use orml_traits::parameter_type_with_key;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup, Zero},
+};
+
+use frame_support::traits::GenesisBuild;
+use pallet_xyk::AssetPairAccountIdFor;
+use primitives::{fee, AssetId, Balance};
+
+pub type Amount = i128;
+pub type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+pub const HDX: AssetId = 1000;
+pub const DOT: AssetId = 2000;
+pub const NEW_DOT: AssetId = 3000;
+pub const UNREGISTERED: AssetId = 4000;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+	 Block = Block,
+	 NodeBlock = Block,
+	 UncheckedExtrinsic = UncheckedExtrinsic,
+	 {
+		 System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		 AssetMigration: asset_migration::{Pallet, Call, Storage, Event<T>},
+		 XYK: pallet_xyk::{Pallet, Call, Storage, Event<T>},
+		 Currency: orml_tokens::{Pallet, Event<T>},
+		 AssetRegistry: pallet_asset_registry::{Pallet, Storage, Event<T>},
+		 Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
+	 }
+
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+
+	pub const HDXAssetId: AssetId = HDX;
+
+	pub ExchangeFeeRate: fee::Fee = fee::Fee::default();
+	pub ProtocolFeeRate: fee::Fee = fee::Fee { numerator: 0, denominator: 1 };
+	pub const TreasuryAccount: AccountId = 100;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		Zero::zero()
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+}
+
+impl pallet_asset_registry::Config for Test {
+	type Event = Event;
+	type AssetId = AssetId;
+	type AssetNativeLocation = primitives::asset::AssetLocation;
+	type RegistryOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+pub struct AssetPairAccountIdTest();
+
+impl AssetPairAccountIdFor<AssetId, u64> for AssetPairAccountIdTest {
+	fn from_assets(asset_a: AssetId, asset_b: AssetId) -> u64 {
+		let mut a = asset_a as u128;
+		let mut b = asset_b as u128;
+		if a > b {
+			let tmp = a;
+			a = b;
+			b = tmp;
+		}
+		return (a * 1000 + b) as u64;
+	}
+}
+
+impl pallet_xyk::Config for Test {
+	type Event = Event;
+	type AssetPairAccountId = AssetPairAccountIdTest;
+	type Currency = Currency;
+	type NativeAssetId = HDXAssetId;
+	type WeightInfo = ();
+	type GetExchangeFee = ExchangeFeeRate;
+	type DefaultProtocolFee = ProtocolFeeRate;
+	type TreasuryAccount = TreasuryAccount;
+	type ProtocolFeeOrigin = frame_system::EnsureRoot<AccountId>;
+	type PoolOwnerInactivityPeriod = PoolOwnerInactivityPeriod;
+	type MaxPoolDust = MaxPoolDust;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type PoolGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type Scheduler = Scheduler;
+	type AuditLog = ();
+	type AMMHandler = ();
+	type FlashLoanFee = ExchangeFeeRate;
+	type NonDustableWhitelistHandler = ();
+	type FeeDiscount = ();
+}
+
+parameter_types! {
+	pub const PoolOwnerInactivityPeriod: u64 = 100;
+	pub const MaxPoolDust: Balance = 1_000;
+	pub const MinPoolLiquidity: Balance = 2_000;
+	pub const MaximumSchedulerWeight: u64 = 1_000_000_000;
+	pub const MaxScheduledPerBlock: u32 = 50;
+}
+
+impl pallet_scheduler::Config for Test {
+	type Event = Event;
+	type Origin = Origin;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type WeightInfo = ();
+}
+
+impl Config for Test {
+	type Event = Event;
+	type MultiCurrency = Currency;
+	type MigrationOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			endowed_accounts: vec![
+				(ALICE, HDX, 1000_000_000_000_000u128),
+				(BOB, HDX, 1000_000_000_000_000u128),
+				(ALICE, DOT, 1000_000_000_000_000u128),
+				(BOB, DOT, 1000_000_000_000_000u128),
+			],
+		}
+	}
+}
+
+impl ExtBuilder {
+	// builds genesis config
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			endowed_accounts: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		pallet_asset_registry::GenesisConfig::<Test> {
+			core_asset_id: HDX,
+			next_asset_id: 3,
+			asset_ids: vec![
+				(b"HDX".to_vec(), HDX),
+				(b"DOT".to_vec(), DOT),
+				(b"NEW_DOT".to_vec(), NEW_DOT),
+			],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		t.into()
+	}
+}
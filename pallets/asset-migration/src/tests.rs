@@ -0,0 +1,224 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+pub use crate::mock::{
+	AssetMigration as AssetMigrationPallet, Currency, ExtBuilder, Origin, System, Test, TreasuryAccount, ALICE, BOB,
+	DOT, HDX, NEW_DOT, UNREGISTERED, XYK,
+};
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use sp_runtime::DispatchError::BadOrigin;
+use test_utils::assert_event_emitted;
+
+const INITIAL_BALANCE: Balance = 1_000_000_000_000_000;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn register_dot_migration(ratio: Price) {
+	assert_ok!(AssetMigrationPallet::register_migration(
+		Origin::root(),
+		DOT,
+		NEW_DOT,
+		ratio
+	));
+}
+
+#[test]
+fn register_migration_should_work() {
+	new_test_ext().execute_with(|| {
+		register_dot_migration(Price::from(2));
+
+		assert_eq!(
+			AssetMigrationPallet::migrations(DOT),
+			Some(AssetMigration {
+				asset_new: NEW_DOT,
+				ratio: Price::from(2)
+			})
+		);
+
+		assert_event_emitted!(Test, Event::MigrationRegistered(DOT, NEW_DOT, Price::from(2)));
+	});
+}
+
+#[test]
+fn register_migration_should_not_work_with_invalid_parameters() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetMigrationPallet::register_migration(Origin::signed(ALICE), DOT, NEW_DOT, Price::from(1)),
+			BadOrigin
+		);
+		assert_noop!(
+			AssetMigrationPallet::register_migration(Origin::root(), DOT, DOT, Price::from(1)),
+			Error::<Test>::SameAsset
+		);
+		assert_noop!(
+			AssetMigrationPallet::register_migration(Origin::root(), DOT, NEW_DOT, Price::zero()),
+			Error::<Test>::ZeroRatio
+		);
+		assert_noop!(
+			AssetMigrationPallet::register_migration(Origin::root(), DOT, UNREGISTERED, Price::from(1)),
+			Error::<Test>::AssetNotRegistered
+		);
+
+		register_dot_migration(Price::from(1));
+
+		assert_noop!(
+			AssetMigrationPallet::register_migration(Origin::root(), DOT, HDX, Price::from(1)),
+			Error::<Test>::MigrationAlreadyRegistered
+		);
+		assert_noop!(
+			AssetMigrationPallet::register_migration(Origin::root(), HDX, DOT, Price::from(1)),
+			Error::<Test>::NewAssetMigrated
+		);
+	});
+}
+
+#[test]
+fn remove_migration_should_work() {
+	new_test_ext().execute_with(|| {
+		register_dot_migration(Price::from(1));
+
+		assert_noop!(
+			AssetMigrationPallet::remove_migration(Origin::signed(ALICE), DOT),
+			BadOrigin
+		);
+
+		assert_ok!(AssetMigrationPallet::remove_migration(Origin::root(), DOT));
+
+		assert_eq!(AssetMigrationPallet::migrations(DOT), None);
+		assert_event_emitted!(Test, Event::MigrationRemoved(DOT));
+
+		assert_noop!(
+			AssetMigrationPallet::remove_migration(Origin::root(), DOT),
+			Error::<Test>::MigrationNotFound
+		);
+		assert_noop!(
+			AssetMigrationPallet::migrate(Origin::signed(ALICE), DOT),
+			Error::<Test>::MigrationNotFound
+		);
+	});
+}
+
+#[test]
+fn migrate_should_burn_old_and_mint_new_asset() {
+	new_test_ext().execute_with(|| {
+		register_dot_migration(Price::from(2));
+
+		assert_ok!(AssetMigrationPallet::migrate(Origin::signed(ALICE), DOT));
+
+		assert_eq!(Currency::free_balance(DOT, &ALICE), 0);
+		assert_eq!(Currency::free_balance(NEW_DOT, &ALICE), 2 * INITIAL_BALANCE);
+		assert_eq!(Currency::total_issuance(DOT), INITIAL_BALANCE);
+		assert_eq!(Currency::total_issuance(NEW_DOT), 2 * INITIAL_BALANCE);
+
+		assert_event_emitted!(
+			Test,
+			Event::BalanceMigrated(ALICE, DOT, NEW_DOT, INITIAL_BALANCE, 2 * INITIAL_BALANCE)
+		);
+
+		assert_noop!(
+			AssetMigrationPallet::migrate(Origin::signed(ALICE), DOT),
+			Error::<Test>::NothingToMigrate
+		);
+	});
+}
+
+#[test]
+fn migrate_should_not_work_when_nothing_would_be_minted() {
+	new_test_ext().execute_with(|| {
+		register_dot_migration(Price::saturating_from_rational(1, 2 * INITIAL_BALANCE));
+
+		assert_noop!(
+			AssetMigrationPallet::migrate(Origin::signed(ALICE), DOT),
+			Error::<Test>::MigratedAmountTooLow
+		);
+	});
+}
+
+#[test]
+fn migrate_pool_should_move_pool_to_new_asset() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			DOT,
+			HDX,
+			100_000_000,
+			Price::from(10)
+		));
+
+		let old_pool = XYK::get_pair_id(AssetPair::new(DOT, HDX));
+		let share_token = XYK::share_token(&old_pool);
+		let shares = Currency::free_balance(share_token, &ALICE);
+
+		register_dot_migration(Price::from(2));
+
+		assert_ok!(AssetMigrationPallet::migrate_pool(Origin::signed(BOB), DOT, HDX));
+
+		let new_pool = XYK::get_pair_id(AssetPair::new(NEW_DOT, HDX));
+
+		assert!(!XYK::exists(AssetPair::new(DOT, HDX)));
+		assert!(XYK::exists(AssetPair::new(NEW_DOT, HDX)));
+		assert_eq!(Currency::free_balance(NEW_DOT, &new_pool), 200_000_000);
+		assert_eq!(Currency::free_balance(HDX, &new_pool), 1_000_000_000);
+		assert_eq!(Currency::free_balance(DOT, &old_pool), 0);
+		assert_eq!(XYK::share_token(&new_pool), share_token);
+
+		assert_event_emitted!(
+			Test,
+			Event::BalanceMigrated(old_pool, DOT, NEW_DOT, 100_000_000, 200_000_000)
+		);
+		assert_event_emitted!(Test, Event::PoolMigrated(DOT, NEW_DOT, HDX, new_pool));
+
+		// Liquidity providers keep their shares in the new pool
+		assert_ok!(XYK::remove_liquidity(Origin::signed(ALICE), NEW_DOT, HDX, shares, 0, 0));
+
+		assert!(!XYK::exists(AssetPair::new(NEW_DOT, HDX)));
+		assert_eq!(
+			Currency::free_balance(NEW_DOT, &ALICE) + Currency::free_balance(NEW_DOT, &TreasuryAccount::get()),
+			200_000_000
+		);
+	});
+}
+
+#[test]
+fn migrate_pool_should_not_work_without_pool_or_migration() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			DOT,
+			HDX,
+			100_000_000,
+			Price::from(10)
+		));
+
+		assert_noop!(
+			AssetMigrationPallet::migrate_pool(Origin::signed(BOB), DOT, HDX),
+			Error::<Test>::MigrationNotFound
+		);
+
+		register_dot_migration(Price::from(2));
+
+		assert_noop!(
+			AssetMigrationPallet::migrate_pool(Origin::signed(BOB), DOT, NEW_DOT),
+			pallet_xyk::Error::<Test>::TokenPoolNotFound
+		);
+	});
+}
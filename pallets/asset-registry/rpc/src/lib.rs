@@ -0,0 +1,164 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use primitives::asset::AssetPair;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::sync::Arc;
+
+pub use self::gen_client::Client as AssetRegistryClient;
+pub use pallet_asset_registry_rpc_runtime_api::AssetRegistryApi as AssetRegistryRuntimeApi;
+
+/// Registered asset with its name.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetInfo<AssetId> {
+	/// Asset id
+	pub asset_id: AssetId,
+	/// Asset name
+	pub name: String,
+}
+
+#[rpc]
+pub trait AssetRegistryApi<BlockHash, AssetId> {
+	#[rpc(name = "assetRegistry_getAssetByName")]
+	fn get_asset_by_name(&self, name: String, at: Option<BlockHash>) -> Result<Option<AssetId>>;
+
+	#[rpc(name = "assetRegistry_getAssetName")]
+	fn get_asset_name(&self, asset_id: AssetId, at: Option<BlockHash>) -> Result<Option<String>>;
+
+	#[rpc(name = "assetRegistry_listAssets")]
+	fn list_assets(&self, at: Option<BlockHash>) -> Result<Vec<AssetInfo<AssetId>>>;
+
+	/// Return human readable symbol of asset pair, e.g. `"HDX-DOT"` for `"0-1"`.
+	#[rpc(name = "assetRegistry_getAssetPairSymbol")]
+	fn get_asset_pair_symbol(&self, asset_pair: AssetPair, at: Option<BlockHash>) -> Result<String>;
+}
+
+/// A struct that implements the [`AssetRegistryApi`].
+pub struct AssetRegistry<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> AssetRegistry<C, B> {
+	/// Create new `AssetRegistry` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		AssetRegistry {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: message.into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+impl<C, Block, AssetId> AssetRegistryApi<<Block as BlockT>::Hash, AssetId> for AssetRegistry<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: AssetRegistryRuntimeApi<Block, AssetId>,
+	AssetId: Codec + From<primitives::AssetId>,
+{
+	fn get_asset_by_name(&self, name: String, at: Option<<Block as BlockT>::Hash>) -> Result<Option<AssetId>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.get_asset_by_name(&at, name.into_bytes())
+			.map_err(|e| runtime_error("Unable to retrieve asset by name.", e))
+	}
+
+	fn get_asset_name(&self, asset_id: AssetId, at: Option<<Block as BlockT>::Hash>) -> Result<Option<String>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.get_asset_name(&at, asset_id)
+			.map(|name| name.map(|n| String::from_utf8_lossy(&n).into_owned()))
+			.map_err(|e| runtime_error("Unable to retrieve asset name.", e))
+	}
+
+	fn list_assets(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<AssetInfo<AssetId>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.list_assets(&at)
+			.map(|assets| {
+				assets
+					.into_iter()
+					.map(|(asset_id, name)| AssetInfo {
+						asset_id,
+						name: String::from_utf8_lossy(&name).into_owned(),
+					})
+					.collect()
+			})
+			.map_err(|e| runtime_error("Unable to list assets.", e))
+	}
+
+	fn get_asset_pair_symbol(&self, asset_pair: AssetPair, at: Option<<Block as BlockT>::Hash>) -> Result<String> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		let mut names = Vec::new();
+		for asset in [asset_pair.asset_in, asset_pair.asset_out].iter() {
+			let name = api
+				.get_asset_name(&at, (*asset).into())
+				.map_err(|e| runtime_error("Unable to retrieve asset name.", e))?;
+			names.push((*asset, name));
+		}
+
+		let symbol = asset_pair.symbol(|asset| {
+			names
+				.iter()
+				.find(|(id, _)| *id == asset)
+				.and_then(|(_, name)| name.clone())
+		});
+
+		Ok(String::from_utf8_lossy(&symbol).into_owned())
+	}
+}
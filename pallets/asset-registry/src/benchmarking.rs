@@ -0,0 +1,59 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+use crate::Pallet as AssetRegistry;
+
+benchmarks! {
+	set_location {
+		let asset_id = AssetRegistry::<T>::get_or_create_asset(b"DOT".to_vec())?;
+		let location = T::AssetNativeLocation::default();
+
+	}: _(RawOrigin::Root, asset_id, location.clone())
+	verify {
+		assert_eq!(AssetRegistry::<T>::location_assets(location), Some(asset_id));
+	}
+
+	set_minimal_balance {
+		let asset_id = AssetRegistry::<T>::get_or_create_asset(b"DOT".to_vec())?;
+
+	}: _(RawOrigin::Root, asset_id, 1_000)
+	verify {
+		assert_eq!(AssetRegistry::<T>::minimal_balance(asset_id), 1_000);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_set_location::<Test>());
+			assert_ok!(test_benchmark_set_minimal_balance::<Test>());
+		});
+	}
+}
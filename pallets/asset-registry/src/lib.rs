@@ -19,14 +19,23 @@
 
 use frame_support::dispatch::DispatchError;
 use frame_support::sp_runtime::traits::{AtLeast32Bit, CheckedAdd, One};
+use primitives::{traits::AssetRegistryTrait, Balance};
 use sp_std::vec::Vec;
 
+mod migration;
+
 #[cfg(test)]
 mod mock;
 
 #[cfg(test)]
 mod tests;
 
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
@@ -34,26 +43,112 @@ pub use pallet::*;
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
 		/// Asset type
 		type AssetId: Parameter + Member + Into<u32> + AtLeast32Bit + Default + Copy + MaybeSerializeDeserialize;
+
+		/// Location of an asset on its native chain
+		type AssetNativeLocation: Parameter + Member + Default;
+
+		/// Origin which can set asset locations and minimal balances
+		type RegistryOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_runtime_upgrade() -> frame_support::weights::Weight {
+			migration::populate_asset_names::<T>()
+		}
+	}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {}
+	impl<T: Config> Pallet<T> {
+		/// Set location of an existing asset on its native chain.
+		///
+		/// Previous location of the asset is replaced. Location can be assigned to single asset only.
+		///
+		/// Emits `LocationSet` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::set_location())]
+		pub fn set_location(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			location: T::AssetNativeLocation,
+		) -> DispatchResultWithPostInfo {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			ensure!(<AssetNames<T>>::contains_key(asset_id), Error::<T>::AssetNotFound);
+
+			ensure!(
+				!<LocationAssets<T>>::contains_key(&location),
+				Error::<T>::LocationAlreadyRegistered
+			);
+
+			if let Some(previous) = <AssetLocations<T>>::get(asset_id) {
+				<LocationAssets<T>>::remove(previous);
+			}
+
+			<AssetLocations<T>>::insert(asset_id, &location);
+			<LocationAssets<T>>::insert(&location, asset_id);
+
+			Self::deposit_event(Event::LocationSet(asset_id, location));
+
+			Ok(().into())
+		}
+
+		/// Set minimal balance of an existing asset.
+		///
+		/// Accounts holding less than minimal balance of the asset can be dusted. Zero disables dusting of the asset.
+		///
+		/// Emits `MinimalBalanceSet` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::set_minimal_balance())]
+		pub fn set_minimal_balance(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			amount: Balance,
+		) -> DispatchResultWithPostInfo {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			ensure!(<AssetNames<T>>::contains_key(asset_id), Error::<T>::AssetNotFound);
+
+			<AssetMinimalBalances<T>>::insert(asset_id, amount);
+
+			Self::deposit_event(Event::MinimalBalanceSet(asset_id, amount));
+
+			Ok(().into())
+		}
+	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Asset Id is not available. This only happens when it reaches the MAX value of given id type.
 		NoIdAvailable,
+
+		/// Asset does not exist.
+		AssetNotFound,
+
+		/// Location is already assigned to an asset.
+		LocationAlreadyRegistered,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Native location of asset was set. [asset id, location]
+		LocationSet(T::AssetId, T::AssetNativeLocation),
+
+		/// Minimal balance of asset was set. [asset id, amount]
+		MinimalBalanceSet(T::AssetId, Balance),
 	}
 
 	/// Core Asset Id
@@ -71,6 +166,27 @@ pub mod pallet {
 	#[pallet::getter(fn asset_ids)]
 	pub type AssetIds<T: Config> = StorageMap<_, Twox64Concat, Vec<u8>, Option<T::AssetId>, ValueQuery>;
 
+	/// Names of created assets
+	#[pallet::storage]
+	#[pallet::getter(fn asset_names)]
+	pub type AssetNames<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, Vec<u8>, OptionQuery>;
+
+	/// Native locations of assets
+	#[pallet::storage]
+	#[pallet::getter(fn asset_locations)]
+	pub type AssetLocations<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, T::AssetNativeLocation, OptionQuery>;
+
+	/// Assets of native locations, reverse map of AssetLocations
+	#[pallet::storage]
+	#[pallet::getter(fn location_assets)]
+	pub type LocationAssets<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetNativeLocation, T::AssetId, OptionQuery>;
+
+	/// Minimal balances of assets below which accounts can be dusted
+	#[pallet::storage]
+	#[pallet::getter(fn minimal_balance)]
+	pub type AssetMinimalBalances<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, Balance, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub core_asset_id: T::AssetId,
@@ -95,6 +211,7 @@ pub mod pallet {
 			NextAssetId::<T>::put(self.next_asset_id);
 			self.asset_ids.iter().for_each(|(name, asset_id)| {
 				AssetIds::<T>::insert(name, Some(asset_id));
+				AssetNames::<T>::insert(asset_id, name);
 			})
 		}
 	}
@@ -109,8 +226,34 @@ impl<T: Config> Pallet<T> {
 			let asset_id = Self::next_asset_id();
 			let next_id = asset_id.checked_add(&One::one()).ok_or(Error::<T>::NoIdAvailable)?;
 			<NextAssetId<T>>::put(next_id);
-			<AssetIds<T>>::insert(name, Some(asset_id));
+			<AssetIds<T>>::insert(&name, Some(asset_id));
+			<AssetNames<T>>::insert(asset_id, name);
 			Ok(asset_id)
 		}
 	}
+
+	/// Return asset id of asset with given name.
+	pub fn asset_by_name(name: &[u8]) -> Option<T::AssetId> {
+		<AssetIds<T>>::get(name)
+	}
+
+	/// Return name of given asset.
+	pub fn asset_name(asset_id: T::AssetId) -> Option<Vec<u8>> {
+		<AssetNames<T>>::get(asset_id)
+	}
+
+	/// Return all created assets with their names.
+	pub fn list_assets() -> Vec<(T::AssetId, Vec<u8>)> {
+		<AssetNames<T>>::iter().collect()
+	}
+}
+
+impl<T: Config> AssetRegistryTrait<T::AssetId, T::AssetNativeLocation> for Pallet<T> {
+	fn asset_to_location(asset_id: T::AssetId) -> Option<T::AssetNativeLocation> {
+		Self::asset_locations(asset_id)
+	}
+
+	fn location_to_asset(location: T::AssetNativeLocation) -> Option<T::AssetId> {
+		Self::location_assets(location)
+	}
 }
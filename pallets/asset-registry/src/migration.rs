@@ -0,0 +1,58 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::traits::{GetPalletVersion, PalletVersion};
+use frame_support::weights::Weight;
+
+/// Populate reverse map of asset names for assets created before it was introduced.
+pub fn populate_asset_names<T: Config>() -> Weight {
+	let version = <Pallet<T> as GetPalletVersion>::storage_version();
+	if version.map_or(true, |v| v < PalletVersion::new(3, 2, 0)) {
+		let mut count: Weight = 0;
+		for (name, asset_id) in AssetIds::<T>::iter() {
+			if let Some(asset_id) = asset_id {
+				AssetNames::<T>::insert(asset_id, name);
+				count += 1;
+			}
+		}
+		T::DbWeight::get().reads_writes(count + 1, count)
+	} else {
+		0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{new_test_ext, Test};
+
+	#[test]
+	fn asset_names_migration_should_work() {
+		new_test_ext().execute_with(|| {
+			AssetIds::<Test>::insert(b"HDX".to_vec(), Some(0u32));
+			AssetIds::<Test>::insert(b"DOT".to_vec(), Some(1u32));
+
+			assert_eq!(AssetNames::<Test>::get(0), None);
+
+			populate_asset_names::<Test>();
+
+			assert_eq!(AssetNames::<Test>::get(0), Some(b"HDX".to_vec()));
+			assert_eq!(AssetNames::<Test>::get(1), Some(b"DOT".to_vec()));
+		})
+	}
+}
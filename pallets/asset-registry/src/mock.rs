@@ -26,6 +26,7 @@ use sp_runtime::{
 };
 
 use crate::{self as asset_registry, Config};
+use primitives::asset::AssetLocation;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -37,7 +38,7 @@ frame_support::construct_runtime!(
 	 UncheckedExtrinsic = UncheckedExtrinsic,
 	 {
 		 System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		 Registry: asset_registry::{Pallet, Call, Storage},
+		 Registry: asset_registry::{Pallet, Call, Storage, Event<T>},
 	 }
 
 );
@@ -60,7 +61,7 @@ impl system::Config for Test {
 	type AccountId = u64;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type Header = Header;
-	type Event = ();
+	type Event = Event;
 	type BlockHashCount = BlockHashCount;
 	type DbWeight = ();
 	type Version = ();
@@ -73,10 +74,16 @@ impl system::Config for Test {
 	type OnSetCode = ();
 }
 impl Config for Test {
+	type Event = Event;
 	type AssetId = u32;
+	type AssetNativeLocation = AssetLocation;
+	type RegistryOrigin = frame_system::EnsureRoot<u64>;
+	type WeightInfo = ();
 }
 pub type AssetRegistryPallet = crate::Pallet<Test>;
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut ext: sp_io::TestExternalities = system::GenesisConfig::default().build_storage::<Test>().unwrap().into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
 }
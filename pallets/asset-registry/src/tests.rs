@@ -15,8 +15,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::*;
 use crate::mock::*;
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
+use primitives::asset::AssetLocation;
+use sp_runtime::DispatchError::BadOrigin;
+use test_utils::expect_events;
 
 #[test]
 fn create_asset() {
@@ -41,3 +45,108 @@ fn create_asset() {
 		assert_eq!(AssetRegistryPallet::asset_ids(b"AAA".to_vec()).is_none(), true);
 	});
 }
+
+#[test]
+fn asset_lookup_should_work() {
+	new_test_ext().execute_with(|| {
+		let hdx = AssetRegistryPallet::get_or_create_asset(b"HDX".to_vec()).unwrap();
+		let dot = AssetRegistryPallet::get_or_create_asset(b"DOT".to_vec()).unwrap();
+
+		assert_eq!(AssetRegistryPallet::asset_by_name(b"DOT"), Some(dot));
+		assert_eq!(AssetRegistryPallet::asset_by_name(b"BTC"), None);
+
+		assert_eq!(AssetRegistryPallet::asset_name(hdx), Some(b"HDX".to_vec()));
+		assert_eq!(AssetRegistryPallet::asset_name(dot), Some(b"DOT".to_vec()));
+		assert_eq!(AssetRegistryPallet::asset_name(100), None);
+
+		let mut assets = AssetRegistryPallet::list_assets();
+		assets.sort();
+		assert_eq!(assets, vec![(hdx, b"HDX".to_vec()), (dot, b"DOT".to_vec())]);
+	});
+}
+
+#[test]
+fn set_location_should_work() {
+	new_test_ext().execute_with(|| {
+		let dot = AssetRegistryPallet::get_or_create_asset(b"DOT".to_vec()).unwrap();
+		let location = AssetLocation(b"parent".to_vec());
+		let new_location = AssetLocation(b"parent/parachain".to_vec());
+
+		assert_ok!(AssetRegistryPallet::set_location(Origin::root(), dot, location.clone()));
+
+		assert_eq!(AssetRegistryPallet::asset_to_location(dot), Some(location.clone()));
+		assert_eq!(AssetRegistryPallet::location_to_asset(location.clone()), Some(dot));
+
+		// Previous location is released when the location is changed
+		assert_ok!(AssetRegistryPallet::set_location(
+			Origin::root(),
+			dot,
+			new_location.clone()
+		));
+
+		assert_eq!(AssetRegistryPallet::asset_to_location(dot), Some(new_location.clone()));
+		assert_eq!(AssetRegistryPallet::location_to_asset(new_location.clone()), Some(dot));
+		assert_eq!(AssetRegistryPallet::location_to_asset(location.clone()), None);
+
+		expect_events::<Test>(vec![
+			crate::Event::<Test>::LocationSet(dot, location).into(),
+			crate::Event::<Test>::LocationSet(dot, new_location).into(),
+		]);
+	});
+}
+
+#[test]
+fn set_location_should_not_work() {
+	new_test_ext().execute_with(|| {
+		let hdx = AssetRegistryPallet::get_or_create_asset(b"HDX".to_vec()).unwrap();
+		let dot = AssetRegistryPallet::get_or_create_asset(b"DOT".to_vec()).unwrap();
+		let location = AssetLocation(b"parent".to_vec());
+
+		assert_noop!(
+			AssetRegistryPallet::set_location(Origin::signed(1), dot, location.clone()),
+			BadOrigin
+		);
+		assert_noop!(
+			AssetRegistryPallet::set_location(Origin::root(), 100, location.clone()),
+			Error::<Test>::AssetNotFound
+		);
+
+		assert_ok!(AssetRegistryPallet::set_location(Origin::root(), dot, location.clone()));
+
+		assert_noop!(
+			AssetRegistryPallet::set_location(Origin::root(), hdx, location),
+			Error::<Test>::LocationAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn set_minimal_balance_should_work() {
+	new_test_ext().execute_with(|| {
+		let dot = AssetRegistryPallet::get_or_create_asset(b"DOT".to_vec()).unwrap();
+
+		assert_eq!(AssetRegistryPallet::minimal_balance(dot), 0);
+
+		assert_ok!(AssetRegistryPallet::set_minimal_balance(Origin::root(), dot, 1_000));
+
+		assert_eq!(AssetRegistryPallet::minimal_balance(dot), 1_000);
+
+		expect_events::<Test>(vec![crate::Event::<Test>::MinimalBalanceSet(dot, 1_000).into()]);
+	});
+}
+
+#[test]
+fn set_minimal_balance_should_not_work() {
+	new_test_ext().execute_with(|| {
+		let dot = AssetRegistryPallet::get_or_create_asset(b"DOT".to_vec()).unwrap();
+
+		assert_noop!(
+			AssetRegistryPallet::set_minimal_balance(Origin::signed(1), dot, 1_000),
+			BadOrigin
+		);
+		assert_noop!(
+			AssetRegistryPallet::set_minimal_balance(Origin::root(), 100, 1_000),
+			Error::<Test>::AssetNotFound
+		);
+	});
+}
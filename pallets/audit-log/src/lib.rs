@@ -0,0 +1,123 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! # Audit Log Pallet
+//!
+//! ## Overview
+//!
+//! Audit log pallet keeps bounded log of privileged actions affecting economic parameters of the chain -
+//! fee changes, pool governance or asset listings - together with their proposer and enactment block.
+//!
+//! Pallets performing privileged actions record them via `AuditLog` trait. Once the log is full,
+//! the oldest entries are overwritten.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use primitives::{
+	audit::{AuditEntry, PrivilegedAction},
+	traits::AuditLog,
+};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Maximum number of entries kept in the log
+		#[pallet::constant]
+		type MaxEntries: Get<u32>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Privileged action was recorded. [index, proposer, action]
+		ActionRecorded(u32, Option<T::AccountId>, PrivilegedAction),
+	}
+
+	/// Sequence number of the next entry.
+	#[pallet::storage]
+	#[pallet::getter(fn next_index)]
+	pub type NextIndex<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Recorded entries stored in a ring buffer of `MaxEntries` slots.
+	#[pallet::storage]
+	#[pallet::getter(fn entry)]
+	pub type Entries<T: Config> =
+		StorageMap<_, Twox64Concat, u32, AuditEntry<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Return recorded entries ordered from the oldest one.
+	pub fn entries() -> Vec<AuditEntry<T::AccountId, T::BlockNumber>> {
+		let max_entries = T::MaxEntries::get();
+		let next_index = Self::next_index();
+		let len = next_index.min(max_entries);
+
+		(next_index.wrapping_sub(len)..next_index)
+			.filter_map(|index| Self::entry(index % max_entries))
+			.collect()
+	}
+}
+
+impl<T: Config> AuditLog<T::AccountId> for Pallet<T> {
+	fn record(proposer: Option<T::AccountId>, action: PrivilegedAction) {
+		let max_entries = T::MaxEntries::get();
+
+		if max_entries == 0 {
+			return;
+		}
+
+		let index = Self::next_index();
+
+		<Entries<T>>::insert(
+			index % max_entries,
+			AuditEntry {
+				index,
+				proposer: proposer.clone(),
+				enacted: <frame_system::Pallet<T>>::block_number(),
+				action: action.clone(),
+			},
+		);
+		<NextIndex<T>>::put(index.wrapping_add(1));
+
+		Self::deposit_event(Event::ActionRecorded(index, proposer, action));
+	}
+}
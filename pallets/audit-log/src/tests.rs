@@ -0,0 +1,95 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+pub use crate::mock::{AuditLog as AuditLogPallet, Event as TestEvent, ExtBuilder, System, Test, ALICE};
+use primitives::fee::Fee;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn expect_events(e: Vec<TestEvent>) {
+	let events: Vec<TestEvent> = frame_system::Pallet::<Test>::events()
+		.into_iter()
+		.map(|e| e.event)
+		.collect();
+	assert_eq!(events[events.len() - e.len()..].to_vec(), e);
+}
+
+#[test]
+fn record_should_work() {
+	new_test_ext().execute_with(|| {
+		let fee = Fee {
+			numerator: 1,
+			denominator: 6,
+		};
+
+		AuditLogPallet::record(None, PrivilegedAction::ProtocolFeeSet(fee));
+
+		System::set_block_number(2);
+
+		AuditLogPallet::record(Some(ALICE), PrivilegedAction::FeeCurrencyAdded(1));
+
+		assert_eq!(
+			AuditLogPallet::entries(),
+			vec![
+				AuditEntry {
+					index: 0,
+					proposer: None,
+					enacted: 1,
+					action: PrivilegedAction::ProtocolFeeSet(fee),
+				},
+				AuditEntry {
+					index: 1,
+					proposer: Some(ALICE),
+					enacted: 2,
+					action: PrivilegedAction::FeeCurrencyAdded(1),
+				},
+			]
+		);
+
+		expect_events(vec![
+			Event::ActionRecorded(0, None, PrivilegedAction::ProtocolFeeSet(fee)).into(),
+			Event::ActionRecorded(1, Some(ALICE), PrivilegedAction::FeeCurrencyAdded(1)).into(),
+		]);
+	});
+}
+
+#[test]
+fn oldest_entries_should_be_overwritten() {
+	new_test_ext().execute_with(|| {
+		for asset in 0..5 {
+			AuditLogPallet::record(None, PrivilegedAction::FeeCurrencyAdded(asset));
+		}
+
+		assert_eq!(AuditLogPallet::next_index(), 5);
+		assert_eq!(
+			AuditLogPallet::entries()
+				.into_iter()
+				.map(|e| (e.index, e.action))
+				.collect::<Vec<_>>(),
+			vec![
+				(2, PrivilegedAction::FeeCurrencyAdded(2)),
+				(3, PrivilegedAction::FeeCurrencyAdded(3)),
+				(4, PrivilegedAction::FeeCurrencyAdded(4)),
+			]
+		);
+	});
+}
@@ -0,0 +1,81 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+use crate::Pallet as Bonds;
+
+const SEED: u32 = 1;
+
+const BONDED_ASSET: AssetId = 1;
+
+benchmarks! {
+	create_program {
+	}: _(RawOrigin::Root, BONDED_ASSET, Permill::from_percent(10), T::BlockNumber::from(100u32), 1_000_000_000)
+	verify {
+		assert!(Bonds::<T>::programs(0).is_some());
+	}
+
+	close_program {
+		Bonds::<T>::create_program(RawOrigin::Root.into(), BONDED_ASSET, Permill::from_percent(10), T::BlockNumber::from(100u32), 1_000_000_000).map_err(|e| e.error)?;
+
+	}: _(RawOrigin::Root, 0)
+	verify {
+		assert!(Bonds::<T>::programs(0).is_none());
+	}
+
+	claim {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let payout: Balance = 1_000_000_000;
+
+		T::Currency::deposit(T::NativeAssetId::get(), &Bonds::<T>::pot_account(), payout)?;
+		crate::Bonds::<T>::insert(0, Bond {
+			owner: caller.clone(),
+			payout,
+			claimed: 0,
+			start: T::BlockNumber::from(1u32),
+			end: T::BlockNumber::from(10u32),
+		});
+
+		frame_system::Pallet::<T>::set_block_number(T::BlockNumber::from(100u32));
+
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+	verify {
+		assert_eq!(T::Currency::free_balance(T::NativeAssetId::get(), &caller), payout);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_create_program::<Test>());
+			assert_ok!(test_benchmark_close_program::<Test>());
+			assert_ok!(test_benchmark_claim::<Test>());
+		});
+	}
+}
@@ -0,0 +1,403 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! # Bonds Pallet
+//!
+//! ## Overview
+//!
+//! Bonds pallet acquires protocol-owned liquidity. Users deposit LP share tokens or listed assets to the treasury
+//! in exchange for native asset sold at a discount to the oracle price and vested linearly over the term
+//! of the bond program.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{ensure, transactional, PalletId};
+use frame_system::ensure_signed;
+use orml_traits::MultiCurrency;
+use primitives::{traits::PriceProvider, AssetId, Balance, Price};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
+	traits::{AccountIdConversion, One, SaturatedConversion, Saturating, Zero},
+	DispatchError, FixedPointNumber, PerThing, Permill, RuntimeDebug,
+};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// Bond program identifier
+pub type ProgramId = u32;
+
+/// Bond identifier
+pub type BondId = u64;
+
+/// Program selling native asset for bonded asset.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct BondProgram<BlockNumber> {
+	/// Asset accepted by the program, e.g. LP share token
+	pub asset: AssetId,
+	/// Discount of native asset to the oracle price
+	pub discount: Permill,
+	/// Number of blocks over which bought native asset is vested
+	pub vesting_period: BlockNumber,
+	/// Remaining amount of native asset which can be sold by the program
+	pub capacity: Balance,
+}
+
+/// Native asset bought by a bond and vested to its owner.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Bond<AccountId, BlockNumber> {
+	/// Account which bought the bond
+	pub owner: AccountId,
+	/// Total amount of native asset vested over the vesting period
+	pub payout: Balance,
+	/// Amount already claimed by owner
+	pub claimed: Balance,
+	/// Block from which the payout is vested
+	pub start: BlockNumber,
+	/// Block at which the whole payout is vested
+	pub end: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Multi currency for transferring bonded assets and payouts
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// Native Asset Id, bonds are paid out in native asset
+		#[pallet::constant]
+		type NativeAssetId: Get<AssetId>;
+
+		/// Oracle price of bonded assets in native asset
+		type PriceProvider: PriceProvider<AssetId, Price>;
+
+		/// Account which receives bonded assets and funds payouts
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Pallet id of the account holding payouts until they are vested and claimed
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Origin which can create and close bond programs
+		type ProgramOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Native asset cannot be bonded.
+		CannotBondNativeAsset,
+
+		/// Discount must be less than 100%.
+		InvalidDiscount,
+
+		/// Vesting period must not be zero.
+		InvalidVestingPeriod,
+
+		/// Capacity of a program must not be zero.
+		ZeroCapacity,
+
+		/// Bond program does not exist.
+		ProgramNotFound,
+
+		/// Oracle price of bonded asset is not available.
+		PriceNotAvailable,
+
+		/// It is not allowed to bond zero amount.
+		ZeroAmount,
+
+		/// Payout is below minimum given by the bonder.
+		PayoutBelowMinimum,
+
+		/// Payout exceeds remaining capacity of the program.
+		CapacityExceeded,
+
+		/// Bond does not exist.
+		BondNotFound,
+
+		/// Account is not owner of the bond.
+		NotBondOwner,
+
+		/// No vested payout is available to claim.
+		NothingToClaim,
+
+		/// Overflow
+		Overflow,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Bond program was created. [program id, asset, discount, vesting period, capacity]
+		ProgramCreated(ProgramId, AssetId, Permill, T::BlockNumber, Balance),
+
+		/// Bond program was closed. [program id]
+		ProgramClosed(ProgramId),
+
+		/// Asset was bonded. [bond id, who, program id, amount, payout]
+		Bonded(BondId, T::AccountId, ProgramId, Balance, Balance),
+
+		/// Vested payout was claimed. [bond id, who, amount]
+		Claimed(BondId, T::AccountId, Balance),
+	}
+
+	/// Next available bond program id.
+	#[pallet::storage]
+	#[pallet::getter(fn next_program_id)]
+	pub type NextProgramId<T: Config> = StorageValue<_, ProgramId, ValueQuery>;
+
+	/// Open bond programs.
+	#[pallet::storage]
+	#[pallet::getter(fn programs)]
+	pub type Programs<T: Config> = StorageMap<_, Blake2_128Concat, ProgramId, BondProgram<T::BlockNumber>, OptionQuery>;
+
+	/// Next available bond id.
+	#[pallet::storage]
+	#[pallet::getter(fn next_bond_id)]
+	pub type NextBondId<T: Config> = StorageValue<_, BondId, ValueQuery>;
+
+	/// Bonds whose payout was not fully claimed yet.
+	#[pallet::storage]
+	#[pallet::getter(fn bonds)]
+	pub type Bonds<T: Config> =
+		StorageMap<_, Blake2_128Concat, BondId, Bond<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create new bond program selling up to `capacity` of native asset for `asset`.
+		///
+		/// Native asset is sold with `discount` to the oracle price and vested over `vesting_period` blocks.
+		///
+		/// Emits `ProgramCreated` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::create_program())]
+		pub fn create_program(
+			origin: OriginFor<T>,
+			asset: AssetId,
+			discount: Permill,
+			vesting_period: T::BlockNumber,
+			capacity: Balance,
+		) -> DispatchResultWithPostInfo {
+			T::ProgramOrigin::ensure_origin(origin)?;
+
+			ensure!(asset != T::NativeAssetId::get(), Error::<T>::CannotBondNativeAsset);
+			ensure!(discount < Permill::from_percent(100), Error::<T>::InvalidDiscount);
+			ensure!(!vesting_period.is_zero(), Error::<T>::InvalidVestingPeriod);
+			ensure!(!capacity.is_zero(), Error::<T>::ZeroCapacity);
+
+			let program_id = Self::next_program_id();
+			let next_id = program_id.checked_add(1).ok_or(Error::<T>::Overflow)?;
+
+			<Programs<T>>::insert(
+				program_id,
+				BondProgram {
+					asset,
+					discount,
+					vesting_period,
+					capacity,
+				},
+			);
+			<NextProgramId<T>>::put(next_id);
+
+			Self::deposit_event(Event::ProgramCreated(
+				program_id,
+				asset,
+				discount,
+				vesting_period,
+				capacity,
+			));
+
+			Ok(().into())
+		}
+
+		/// Close bond program. Existing bonds of the program keep vesting.
+		///
+		/// Emits `ProgramClosed` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::close_program())]
+		pub fn close_program(origin: OriginFor<T>, program_id: ProgramId) -> DispatchResultWithPostInfo {
+			T::ProgramOrigin::ensure_origin(origin)?;
+
+			ensure!(<Programs<T>>::take(program_id).is_some(), Error::<T>::ProgramNotFound);
+
+			Self::deposit_event(Event::ProgramClosed(program_id));
+
+			Ok(().into())
+		}
+
+		/// Bond `amount` of the program asset.
+		///
+		/// Bonded asset is transferred to the treasury account. Payout in native asset is funded by the treasury
+		/// account and vested to origin over the vesting period of the program.
+		///
+		/// `min_payout` - minimum amount of native asset bought by the bond.
+		///
+		/// Emits `Bonded` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::bond())]
+		#[transactional]
+		pub fn bond(
+			origin: OriginFor<T>,
+			program_id: ProgramId,
+			amount: Balance,
+			min_payout: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			let mut program = Self::programs(program_id).ok_or(Error::<T>::ProgramNotFound)?;
+
+			let payout = Self::calculate_payout(&program, amount)?;
+
+			ensure!(
+				!payout.is_zero() && payout >= min_payout,
+				Error::<T>::PayoutBelowMinimum
+			);
+			ensure!(payout <= program.capacity, Error::<T>::CapacityExceeded);
+
+			let bond_id = Self::next_bond_id();
+			let next_id = bond_id.checked_add(1).ok_or(Error::<T>::Overflow)?;
+
+			let treasury = T::TreasuryAccount::get();
+
+			T::Currency::transfer(program.asset, &who, &treasury, amount)?;
+			T::Currency::transfer(T::NativeAssetId::get(), &treasury, &Self::pot_account(), payout)?;
+
+			program.capacity = program.capacity.saturating_sub(payout);
+			<Programs<T>>::insert(program_id, program.clone());
+
+			let now = <frame_system::Pallet<T>>::block_number();
+
+			<Bonds<T>>::insert(
+				bond_id,
+				Bond {
+					owner: who.clone(),
+					payout,
+					claimed: Balance::zero(),
+					start: now,
+					end: now.saturating_add(program.vesting_period),
+				},
+			);
+			<NextBondId<T>>::put(next_id);
+
+			Self::deposit_event(Event::Bonded(bond_id, who, program_id, amount, payout));
+
+			Ok(().into())
+		}
+
+		/// Claim vested payout of the bond.
+		///
+		/// Bond is removed once the whole payout is claimed.
+		///
+		/// Emits `Claimed` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::claim())]
+		#[transactional]
+		pub fn claim(origin: OriginFor<T>, bond_id: BondId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let mut bond = Self::bonds(bond_id).ok_or(Error::<T>::BondNotFound)?;
+
+			ensure!(bond.owner == who, Error::<T>::NotBondOwner);
+
+			let amount = Self::vested_payout(&bond)?.saturating_sub(bond.claimed);
+
+			ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+			T::Currency::transfer(T::NativeAssetId::get(), &Self::pot_account(), &who, amount)?;
+
+			bond.claimed = bond.claimed.saturating_add(amount);
+
+			if bond.claimed == bond.payout {
+				<Bonds<T>>::remove(bond_id);
+			} else {
+				<Bonds<T>>::insert(bond_id, &bond);
+			}
+
+			Self::deposit_event(Event::Claimed(bond_id, who, amount));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account holding payouts until they are claimed.
+	pub fn pot_account() -> T::AccountId {
+		T::PalletId::get().into_account()
+	}
+
+	/// Return amount of native asset bought by bonding `amount` of the program asset.
+	///
+	/// Payout is value of `amount` in native asset at the oracle price divided by `1 - discount`.
+	pub fn calculate_payout(program: &BondProgram<T::BlockNumber>, amount: Balance) -> Result<Balance, DispatchError> {
+		let price =
+			T::PriceProvider::price(program.asset, T::NativeAssetId::get()).ok_or(Error::<T>::PriceNotAvailable)?;
+
+		let value = price.checked_mul_int(amount).ok_or(Error::<T>::Overflow)?;
+
+		multiply_by_rational(
+			value,
+			Permill::ACCURACY.into(),
+			program.discount.left_from_one().deconstruct().into(),
+		)
+		.map_err(|_| Error::<T>::Overflow.into())
+	}
+
+	/// Return payout of the bond vested up to current block.
+	pub fn vested_payout(bond: &Bond<T::AccountId, T::BlockNumber>) -> Result<Balance, DispatchError> {
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		if now >= bond.end {
+			return Ok(bond.payout);
+		}
+
+		let elapsed: u128 = now.saturating_sub(bond.start).saturated_into();
+		let duration: u128 = bond
+			.end
+			.saturating_sub(bond.start)
+			.max(T::BlockNumber::one())
+			.saturated_into();
+
+		multiply_by_rational(bond.payout, elapsed, duration).map_err(|_| Error::<T>::Overflow.into())
+	}
+}
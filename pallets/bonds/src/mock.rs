@@ -0,0 +1,178 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate as bonds;
+use crate::Config;
+use frame_support::{parameter_types, traits::Get, PalletId};
+use frame_system as system;
+use orml_traits::parameter_type_with_key;
+use primitives::{traits::PriceProvider, AssetId, Balance, Price};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup, Zero},
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+pub type Amount = i128;
+pub type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const TREASURY: AccountId = 100;
+
+pub const HDX: AssetId = 0;
+pub const DOT: AssetId = 1;
+pub const SHARE: AssetId = 2;
+
+pub const INITIAL_BALANCE: Balance = 1_000_000_000_000;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+	 Block = Block,
+	 NodeBlock = Block,
+	 UncheckedExtrinsic = UncheckedExtrinsic,
+	 {
+		 System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		 Bonds: bonds::{Pallet, Call, Storage, Event<T>},
+		 Currency: orml_tokens::{Pallet, Event<T>},
+	 }
+
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub const NativeAssetId: AssetId = HDX;
+	pub const TreasuryAccount: AccountId = TREASURY;
+	pub const BondsPalletId: PalletId = PalletId(*b"py/bonds");
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		Zero::zero()
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+}
+
+thread_local! {
+	static PRICES: RefCell<HashMap<AssetId, Price>> = RefCell::new(HashMap::new());
+}
+
+/// Price of assets in native asset set by `set_price`.
+pub struct OraclePrice;
+
+impl PriceProvider<AssetId, Price> for OraclePrice {
+	fn price(asset_a: AssetId, asset_b: AssetId) -> Option<Price> {
+		if asset_b != NativeAssetId::get() {
+			return None;
+		}
+		PRICES.with(|v| v.borrow().get(&asset_a).copied())
+	}
+}
+
+pub fn set_price(asset: AssetId, price: Price) {
+	PRICES.with(|v| v.borrow_mut().insert(asset, price));
+}
+
+impl Config for Test {
+	type Event = Event;
+	type Currency = Currency;
+	type NativeAssetId = NativeAssetId;
+	type PriceProvider = OraclePrice;
+	type TreasuryAccount = TreasuryAccount;
+	type PalletId = BondsPalletId;
+	type ProgramOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			endowed_accounts: vec![
+				(ALICE, DOT, INITIAL_BALANCE),
+				(ALICE, SHARE, INITIAL_BALANCE),
+				(BOB, DOT, INITIAL_BALANCE),
+				(TREASURY, HDX, INITIAL_BALANCE),
+			],
+		}
+	}
+}
+
+impl ExtBuilder {
+	// builds genesis config
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			endowed_accounts: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		PRICES.with(|v| {
+			let mut prices = v.borrow_mut();
+			prices.clear();
+			prices.insert(DOT, Price::from(2));
+		});
+
+		t.into()
+	}
+}
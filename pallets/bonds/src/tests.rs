@@ -0,0 +1,243 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+pub use crate::mock::{
+	set_price, Bonds, Currency, ExtBuilder, Origin, System, Test, ALICE, BOB, DOT, HDX, INITIAL_BALANCE, SHARE,
+	TREASURY,
+};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError::BadOrigin;
+use test_utils::{assert_balance, expect_events};
+
+const CAPACITY: Balance = 1_000_000;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn create_dot_program() {
+	assert_ok!(Bonds::create_program(
+		Origin::root(),
+		DOT,
+		Permill::from_percent(20),
+		10,
+		CAPACITY
+	));
+}
+
+#[test]
+fn create_program_should_work() {
+	new_test_ext().execute_with(|| {
+		create_dot_program();
+
+		assert_eq!(
+			Bonds::programs(0),
+			Some(BondProgram {
+				asset: DOT,
+				discount: Permill::from_percent(20),
+				vesting_period: 10,
+				capacity: CAPACITY,
+			})
+		);
+		assert_eq!(Bonds::next_program_id(), 1);
+
+		expect_events::<Test>(vec![Event::ProgramCreated(
+			0,
+			DOT,
+			Permill::from_percent(20),
+			10,
+			CAPACITY,
+		)
+		.into()]);
+	});
+}
+
+#[test]
+fn create_invalid_program_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Bonds::create_program(Origin::signed(ALICE), DOT, Permill::from_percent(20), 10, CAPACITY),
+			BadOrigin
+		);
+		assert_noop!(
+			Bonds::create_program(Origin::root(), HDX, Permill::from_percent(20), 10, CAPACITY),
+			Error::<Test>::CannotBondNativeAsset
+		);
+		assert_noop!(
+			Bonds::create_program(Origin::root(), DOT, Permill::from_percent(100), 10, CAPACITY),
+			Error::<Test>::InvalidDiscount
+		);
+		assert_noop!(
+			Bonds::create_program(Origin::root(), DOT, Permill::from_percent(20), 0, CAPACITY),
+			Error::<Test>::InvalidVestingPeriod
+		);
+		assert_noop!(
+			Bonds::create_program(Origin::root(), DOT, Permill::from_percent(20), 10, 0),
+			Error::<Test>::ZeroCapacity
+		);
+	});
+}
+
+#[test]
+fn close_program_should_work() {
+	new_test_ext().execute_with(|| {
+		create_dot_program();
+
+		assert_ok!(Bonds::close_program(Origin::root(), 0));
+
+		assert_eq!(Bonds::programs(0), None);
+
+		expect_events::<Test>(vec![Event::ProgramClosed(0).into()]);
+
+		assert_noop!(Bonds::close_program(Origin::root(), 0), Error::<Test>::ProgramNotFound);
+	});
+}
+
+#[test]
+fn bond_should_work() {
+	new_test_ext().execute_with(|| {
+		create_dot_program();
+
+		assert_ok!(Bonds::bond(Origin::signed(ALICE), 0, 1_000, 2_500));
+
+		assert_balance!(Currency, ALICE, DOT, INITIAL_BALANCE - 1_000);
+		assert_balance!(Currency, TREASURY, DOT, 1_000);
+		assert_balance!(Currency, TREASURY, HDX, INITIAL_BALANCE - 2_500);
+		assert_balance!(Currency, Bonds::pot_account(), HDX, 2_500);
+
+		assert_eq!(Bonds::programs(0).unwrap().capacity, CAPACITY - 2_500);
+		assert_eq!(
+			Bonds::bonds(0),
+			Some(Bond {
+				owner: ALICE,
+				payout: 2_500,
+				claimed: 0,
+				start: 1,
+				end: 11,
+			})
+		);
+
+		expect_events::<Test>(vec![Event::Bonded(0, ALICE, 0, 1_000, 2_500).into()]);
+	});
+}
+
+#[test]
+fn bond_should_not_work_when_not_allowed() {
+	new_test_ext().execute_with(|| {
+		create_dot_program();
+		assert_ok!(Bonds::create_program(
+			Origin::root(),
+			SHARE,
+			Permill::from_percent(20),
+			10,
+			CAPACITY
+		));
+
+		assert_noop!(
+			Bonds::bond(Origin::signed(ALICE), 2, 1_000, 0),
+			Error::<Test>::ProgramNotFound
+		);
+		assert_noop!(Bonds::bond(Origin::signed(ALICE), 0, 0, 0), Error::<Test>::ZeroAmount);
+		assert_noop!(
+			Bonds::bond(Origin::signed(ALICE), 1, 1_000, 0),
+			Error::<Test>::PriceNotAvailable
+		);
+		assert_noop!(
+			Bonds::bond(Origin::signed(ALICE), 0, 1_000, 2_501),
+			Error::<Test>::PayoutBelowMinimum
+		);
+		assert_noop!(
+			Bonds::bond(Origin::signed(ALICE), 0, 400_001, 0),
+			Error::<Test>::CapacityExceeded
+		);
+	});
+}
+
+#[test]
+fn bond_share_token_should_use_its_price() {
+	new_test_ext().execute_with(|| {
+		set_price(SHARE, Price::saturating_from_rational(1, 2));
+		assert_ok!(Bonds::create_program(
+			Origin::root(),
+			SHARE,
+			Permill::from_percent(50),
+			10,
+			CAPACITY
+		));
+
+		assert_ok!(Bonds::bond(Origin::signed(ALICE), 0, 1_000, 0));
+
+		assert_eq!(Bonds::bonds(0).unwrap().payout, 1_000);
+	});
+}
+
+#[test]
+fn claim_should_vest_payout_linearly() {
+	new_test_ext().execute_with(|| {
+		create_dot_program();
+		assert_ok!(Bonds::bond(Origin::signed(ALICE), 0, 1_000, 0));
+
+		assert_noop!(Bonds::claim(Origin::signed(ALICE), 0), Error::<Test>::NothingToClaim);
+
+		System::set_block_number(5);
+
+		assert_ok!(Bonds::claim(Origin::signed(ALICE), 0));
+
+		assert_balance!(Currency, ALICE, HDX, 1_000);
+		assert_eq!(Bonds::bonds(0).unwrap().claimed, 1_000);
+		expect_events::<Test>(vec![Event::Claimed(0, ALICE, 1_000).into()]);
+
+		System::set_block_number(20);
+
+		assert_ok!(Bonds::claim(Origin::signed(ALICE), 0));
+
+		assert_balance!(Currency, ALICE, HDX, 2_500);
+		assert_balance!(Currency, Bonds::pot_account(), HDX, 0);
+		assert_eq!(Bonds::bonds(0), None);
+		expect_events::<Test>(vec![Event::Claimed(0, ALICE, 1_500).into()]);
+	});
+}
+
+#[test]
+fn claim_should_not_work_when_not_allowed() {
+	new_test_ext().execute_with(|| {
+		create_dot_program();
+		assert_ok!(Bonds::bond(Origin::signed(ALICE), 0, 1_000, 0));
+
+		System::set_block_number(5);
+
+		assert_noop!(Bonds::claim(Origin::signed(ALICE), 1), Error::<Test>::BondNotFound);
+		assert_noop!(Bonds::claim(Origin::signed(BOB), 0), Error::<Test>::NotBondOwner);
+	});
+}
+
+#[test]
+fn closing_program_should_not_stop_vesting() {
+	new_test_ext().execute_with(|| {
+		create_dot_program();
+		assert_ok!(Bonds::bond(Origin::signed(ALICE), 0, 1_000, 0));
+		assert_ok!(Bonds::close_program(Origin::root(), 0));
+
+		System::set_block_number(11);
+
+		assert_ok!(Bonds::claim(Origin::signed(ALICE), 0));
+
+		assert_balance!(Currency, ALICE, HDX, 2_500);
+	});
+}
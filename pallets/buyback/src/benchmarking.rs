@@ -0,0 +1,67 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+use crate::Pallet as Buyback;
+
+const SEED: u32 = 1;
+
+const ASSET_ID: AssetId = 1;
+
+benchmarks! {
+	remove_buyback_asset {
+		crate::BuybackAssets::<T>::insert(ASSET_ID, BuybackLimit {
+			portion: Permill::from_percent(10),
+			max_amount: 1_000_000_000_000,
+		});
+		crate::BuybackAssetCount::<T>::put(1);
+
+	}: _(RawOrigin::Root, ASSET_ID)
+	verify {
+		assert!(Buyback::<T>::buyback_assets(ASSET_ID).is_none());
+		assert_eq!(Buyback::<T>::buyback_asset_count(), 0);
+	}
+
+	set_disposal {
+		let receiver: T::AccountId = account("receiver", 0, SEED);
+
+	}: _(RawOrigin::Root, Disposal::Transfer(receiver.clone()))
+	verify {
+		assert_eq!(Buyback::<T>::disposal(), Disposal::Transfer(receiver));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_remove_buyback_asset::<Test>());
+			assert_ok!(test_benchmark_set_disposal::<Test>());
+		});
+	}
+}
@@ -0,0 +1,338 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! # Buyback Pallet
+//!
+//! ## Overview
+//!
+//! Buyback pallet periodically sells a capped portion of non-native assets held by the treasury for native asset
+//! via AMM pool. Bought native asset is burned or transferred to a configured account.
+//!
+//! Every buyback emits an event with sold and bought amounts and the average price, so the whole program
+//! can be audited from chain events.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{dispatch::DispatchResult, ensure, traits::Get, transactional, weights::Weight};
+use orml_traits::{MultiCurrency, MultiCurrencyExtended};
+use primitives::{asset::AssetPair, traits::AMM, AssetId, Balance, Price};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchError, FixedPointNumber, PerThing, Permill, RuntimeDebug,
+};
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// Portion of treasury balance of an asset sold in one buyback.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct BuybackLimit {
+	/// Part of the treasury balance sold in one buyback
+	pub portion: Permill,
+	/// Maximal amount sold in one buyback
+	pub max_amount: Balance,
+}
+
+impl BuybackLimit {
+	/// Return amount sold from treasury balance `balance`.
+	pub fn amount(&self, balance: Balance) -> Balance {
+		self.portion.mul_floor(balance).min(self.max_amount)
+	}
+}
+
+/// What happens with native asset bought back.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum Disposal<AccountId> {
+	/// Bought amount is burned
+	Burn,
+	/// Bought amount is transferred to the account
+	Transfer(AccountId),
+}
+
+impl<AccountId> Default for Disposal<AccountId> {
+	fn default() -> Self {
+		Disposal::Burn
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let period = T::BuybackPeriod::get();
+
+			if period.is_zero() || !(now % period).is_zero() {
+				return 0;
+			}
+
+			let mut count = 0u32;
+
+			for (asset_id, limit) in <BuybackAssets<T>>::iter() {
+				Self::buyback(asset_id, limit);
+				count = count.saturating_add(1);
+			}
+
+			<T as Config>::WeightInfo::on_initialize(count)
+				.saturating_add(T::AMMSellWeight::get().saturating_mul(count.into()))
+		}
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Multi currency holding treasury assets
+		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// AMM pool executing the buybacks
+		type AMMPool: AMM<Self::AccountId, AssetId, AssetPair, Balance>;
+
+		/// Weight of AMM sell of a single buyback
+		#[pallet::constant]
+		type AMMSellWeight: Get<Weight>;
+
+		/// Native Asset Id, asset which is bought back
+		#[pallet::constant]
+		type NativeAssetId: Get<AssetId>;
+
+		/// Account holding the assets used for buybacks
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Number of blocks between two buybacks
+		#[pallet::constant]
+		type BuybackPeriod: Get<Self::BlockNumber>;
+
+		/// Maximal number of assets used for buybacks
+		#[pallet::constant]
+		type MaxBuybackAssets: Get<u32>;
+
+		/// Maximal difference of amount bought by a buyback from the spot price
+		#[pallet::constant]
+		type MaxSlippage: Get<Permill>;
+
+		/// Origin which can configure buybacks
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Native asset can not be used for buybacks.
+		NativeAssetNotAllowed,
+
+		/// Pool of the asset and native asset does not exist.
+		PoolNotFound,
+
+		/// Maximal number of buyback assets has been reached.
+		TooManyAssets,
+
+		/// Asset is not used for buybacks.
+		AssetNotFound,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Asset is used for buybacks with the limit. [asset id, limit]
+		BuybackAssetSet(AssetId, BuybackLimit),
+
+		/// Asset is not used for buybacks anymore. [asset id]
+		BuybackAssetRemoved(AssetId),
+
+		/// Disposal of bought native asset was changed. [disposal]
+		DisposalSet(Disposal<T::AccountId>),
+
+		/// Treasury asset was sold for native asset. [asset id, amount sold, amount bought, average price]
+		BoughtBack(AssetId, Balance, Balance, Price),
+
+		/// Bought native asset was burned. [amount]
+		Burned(Balance),
+
+		/// Bought native asset was transferred. [who, amount]
+		Transferred(T::AccountId, Balance),
+
+		/// Buyback of the asset failed. [asset id, amount, error]
+		BuybackFailed(AssetId, Balance, DispatchError),
+	}
+
+	/// Assets used for buybacks and their limits.
+	#[pallet::storage]
+	#[pallet::getter(fn buyback_assets)]
+	pub type BuybackAssets<T: Config> = StorageMap<_, Twox64Concat, AssetId, BuybackLimit, OptionQuery>;
+
+	/// Number of assets used for buybacks.
+	#[pallet::storage]
+	#[pallet::getter(fn buyback_asset_count)]
+	pub type BuybackAssetCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Disposal of bought native asset.
+	#[pallet::storage]
+	#[pallet::getter(fn disposal)]
+	pub type BuybackDisposal<T: Config> = StorageValue<_, Disposal<T::AccountId>, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Use treasury balance of `asset_id` for buybacks or change its limit.
+		///
+		/// Emits `BuybackAssetSet` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::set_buyback_asset())]
+		pub fn set_buyback_asset(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			limit: BuybackLimit,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_id != T::NativeAssetId::get(), Error::<T>::NativeAssetNotAllowed);
+			ensure!(
+				T::AMMPool::exists(Self::buyback_pair(asset_id)),
+				Error::<T>::PoolNotFound
+			);
+
+			if !<BuybackAssets<T>>::contains_key(asset_id) {
+				let count = Self::buyback_asset_count();
+				ensure!(count < T::MaxBuybackAssets::get(), Error::<T>::TooManyAssets);
+				<BuybackAssetCount<T>>::put(count + 1);
+			}
+
+			<BuybackAssets<T>>::insert(asset_id, limit);
+
+			Self::deposit_event(Event::BuybackAssetSet(asset_id, limit));
+
+			Ok(().into())
+		}
+
+		/// Stop using treasury balance of `asset_id` for buybacks.
+		///
+		/// Emits `BuybackAssetRemoved` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_buyback_asset())]
+		pub fn remove_buyback_asset(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			ensure!(<BuybackAssets<T>>::contains_key(asset_id), Error::<T>::AssetNotFound);
+
+			<BuybackAssets<T>>::remove(asset_id);
+			<BuybackAssetCount<T>>::mutate(|count| *count = count.saturating_sub(1));
+
+			Self::deposit_event(Event::BuybackAssetRemoved(asset_id));
+
+			Ok(().into())
+		}
+
+		/// Set whether bought native asset is burned or transferred to an account.
+		///
+		/// Emits `DisposalSet` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::set_disposal())]
+		pub fn set_disposal(origin: OriginFor<T>, disposal: Disposal<T::AccountId>) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			<BuybackDisposal<T>>::put(disposal.clone());
+
+			Self::deposit_event(Event::DisposalSet(disposal));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Return asset pair selling `asset_id` for native asset.
+	fn buyback_pair(asset_id: AssetId) -> AssetPair {
+		AssetPair {
+			asset_in: asset_id,
+			asset_out: T::NativeAssetId::get(),
+		}
+	}
+
+	/// Sell limited amount of treasury balance of `asset_id` for native asset and dispose of the bought amount.
+	///
+	/// Nothing is sold if the amount is zero. Failed buyback is reported by `BuybackFailed` event.
+	fn buyback(asset_id: AssetId, limit: BuybackLimit) {
+		let treasury = T::TreasuryAccount::get();
+		let amount = limit.amount(T::Currency::free_balance(asset_id, &treasury));
+
+		if amount.is_zero() {
+			return;
+		}
+
+		if let Err(error) = Self::sell_and_dispose(&treasury, asset_id, amount) {
+			Self::deposit_event(Event::BuybackFailed(asset_id, amount, error));
+		}
+	}
+
+	/// Sell `amount` of `asset_id` for at least spot price amount decreased by `MaxSlippage` and dispose of
+	/// the bought native asset.
+	///
+	/// Nothing is changed if any step fails.
+	#[transactional]
+	fn sell_and_dispose(treasury: &T::AccountId, asset_id: AssetId, amount: Balance) -> DispatchResult {
+		let native_asset = T::NativeAssetId::get();
+		let assets = Self::buyback_pair(asset_id);
+
+		let spot_amount = T::AMMPool::get_spot_price_unchecked(asset_id, native_asset, amount);
+		let min_bought = T::MaxSlippage::get().left_from_one().mul_floor(spot_amount);
+
+		let transfer = T::AMMPool::validate_sell(treasury, assets, amount, min_bought, false)?;
+		T::AMMPool::execute_sell(&transfer)?;
+
+		let bought = transfer.amount_out;
+		let price = Price::checked_from_rational(amount, bought).unwrap_or_default();
+
+		Self::deposit_event(Event::BoughtBack(asset_id, amount, bought, price));
+
+		match Self::disposal() {
+			Disposal::Burn => {
+				T::Currency::withdraw(native_asset, treasury, bought)?;
+				Self::deposit_event(Event::Burned(bought));
+			}
+			Disposal::Transfer(who) => {
+				T::Currency::transfer(native_asset, treasury, &who, bought)?;
+				Self::deposit_event(Event::Transferred(who, bought));
+			}
+		}
+
+		Ok(())
+	}
+}
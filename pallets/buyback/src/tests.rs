@@ -0,0 +1,319 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+pub use crate::mock::{
+	AccountId, Buyback, Currency, ExtBuilder, Origin, System, Test, ALICE, BOB, CHARLIE, DOT, ETH, HDX, TREASURY,
+	TREASURY_BALANCE, XYK,
+};
+use frame_support::{assert_noop, assert_ok, traits::OnInitialize};
+use primitives::traits::{TradeFees, TradeSource};
+use sp_runtime::DispatchError;
+use test_utils::{assert_balance, expect_events};
+
+const POOL_LIQUIDITY: Balance = 100_000_000_000_000;
+const MAX_AMOUNT: Balance = 1_000_000_000_000;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			DOT,
+			POOL_LIQUIDITY,
+			Price::from(1)
+		));
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			ETH,
+			POOL_LIQUIDITY,
+			Price::from(2)
+		));
+	});
+	ext
+}
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		Buyback::on_initialize(System::block_number());
+	}
+}
+
+fn limit(portion: Permill) -> BuybackLimit {
+	BuybackLimit {
+		portion,
+		max_amount: MAX_AMOUNT,
+	}
+}
+
+/// Return amount of native asset bought by selling `amount` of `asset_id` from the treasury.
+fn expected_bought(asset_id: AssetId, amount: Balance) -> Balance {
+	let assets = AssetPair {
+		asset_in: asset_id,
+		asset_out: HDX,
+	};
+
+	<XYK as AMM<AccountId, AssetId, AssetPair, Balance>>::validate_sell(&TREASURY, assets, amount, 0, false)
+		.unwrap()
+		.amount_out
+}
+
+#[test]
+fn set_buyback_asset_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buyback::set_buyback_asset(
+			Origin::root(),
+			DOT,
+			limit(Permill::from_percent(10))
+		));
+
+		assert_eq!(Buyback::buyback_assets(DOT), Some(limit(Permill::from_percent(10))));
+		assert_eq!(Buyback::buyback_asset_count(), 1);
+
+		// Limit of the asset can be changed
+		assert_ok!(Buyback::set_buyback_asset(
+			Origin::root(),
+			DOT,
+			limit(Permill::from_percent(20))
+		));
+
+		assert_eq!(Buyback::buyback_assets(DOT), Some(limit(Permill::from_percent(20))));
+		assert_eq!(Buyback::buyback_asset_count(), 1);
+
+		expect_events::<Test>(vec![
+			Event::<Test>::BuybackAssetSet(DOT, limit(Permill::from_percent(10))).into(),
+			Event::<Test>::BuybackAssetSet(DOT, limit(Permill::from_percent(20))).into(),
+		]);
+	});
+}
+
+#[test]
+fn set_buyback_asset_should_not_work_with_invalid_asset() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Buyback::set_buyback_asset(Origin::signed(ALICE), DOT, limit(Permill::from_percent(10))),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Buyback::set_buyback_asset(Origin::root(), HDX, limit(Permill::from_percent(10))),
+			Error::<Test>::NativeAssetNotAllowed
+		);
+		assert_noop!(
+			Buyback::set_buyback_asset(Origin::root(), 4000, limit(Permill::from_percent(10))),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn set_buyback_asset_should_not_work_when_too_many_assets() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buyback::set_buyback_asset(
+			Origin::root(),
+			DOT,
+			limit(Permill::from_percent(10))
+		));
+
+		assert_noop!(
+			Buyback::set_buyback_asset(Origin::root(), ETH, limit(Permill::from_percent(10))),
+			Error::<Test>::TooManyAssets
+		);
+
+		assert_ok!(Buyback::remove_buyback_asset(Origin::root(), DOT));
+		assert_ok!(Buyback::set_buyback_asset(
+			Origin::root(),
+			ETH,
+			limit(Permill::from_percent(10))
+		));
+	});
+}
+
+#[test]
+fn remove_buyback_asset_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Buyback::remove_buyback_asset(Origin::root(), DOT),
+			Error::<Test>::AssetNotFound
+		);
+
+		assert_ok!(Buyback::set_buyback_asset(
+			Origin::root(),
+			DOT,
+			limit(Permill::from_percent(10))
+		));
+		assert_ok!(Buyback::remove_buyback_asset(Origin::root(), DOT));
+
+		assert_eq!(Buyback::buyback_assets(DOT), None);
+		assert_eq!(Buyback::buyback_asset_count(), 0);
+
+		expect_events::<Test>(vec![Event::<Test>::BuybackAssetRemoved(DOT).into()]);
+
+		run_to_block(10);
+
+		assert_balance!(Currency, TREASURY, DOT, TREASURY_BALANCE);
+	});
+}
+
+#[test]
+fn buyback_should_burn_bought_native_asset() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buyback::set_buyback_asset(
+			Origin::root(),
+			DOT,
+			limit(Permill::from_percent(1))
+		));
+
+		let amount = TREASURY_BALANCE / 100;
+		let bought = expected_bought(DOT, amount);
+		let issuance = Currency::total_issuance(HDX);
+
+		run_to_block(9);
+
+		assert_balance!(Currency, TREASURY, DOT, TREASURY_BALANCE);
+
+		run_to_block(10);
+
+		assert_balance!(Currency, TREASURY, DOT, TREASURY_BALANCE - amount);
+		assert_balance!(Currency, TREASURY, HDX, 0);
+		assert_eq!(Currency::total_issuance(HDX), issuance - bought);
+
+		expect_events::<Test>(vec![
+			pallet_xyk::Event::<Test>::SellExecuted(
+				TREASURY,
+				DOT,
+				HDX,
+				amount,
+				bought,
+				TradeSource::Router,
+				TradeFees {
+					lp_fee: amount * 2 / 1000,
+					protocol_fee: 0,
+					discount: 0,
+				},
+			)
+			.into(),
+			Event::<Test>::BoughtBack(
+				DOT,
+				amount,
+				bought,
+				Price::checked_from_rational(amount, bought).unwrap(),
+			)
+			.into(),
+			Event::<Test>::Burned(bought).into(),
+		]);
+	});
+}
+
+#[test]
+fn buyback_should_be_capped_by_max_amount() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buyback::set_buyback_asset(
+			Origin::root(),
+			DOT,
+			limit(Permill::from_percent(10))
+		));
+
+		run_to_block(10);
+
+		assert_balance!(Currency, TREASURY, DOT, TREASURY_BALANCE - MAX_AMOUNT);
+
+		run_to_block(20);
+
+		assert_balance!(Currency, TREASURY, DOT, TREASURY_BALANCE - 2 * MAX_AMOUNT);
+	});
+}
+
+#[test]
+fn buyback_should_transfer_bought_native_asset() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buyback::set_buyback_asset(
+			Origin::root(),
+			ETH,
+			limit(Permill::from_percent(1))
+		));
+		assert_ok!(Buyback::set_disposal(Origin::root(), Disposal::Transfer(CHARLIE)));
+
+		let amount = TREASURY_BALANCE / 100;
+		let bought = expected_bought(ETH, amount);
+		let issuance = Currency::total_issuance(HDX);
+		let charlie_balance = Currency::free_balance(HDX, &CHARLIE);
+
+		run_to_block(10);
+
+		assert_balance!(Currency, TREASURY, ETH, TREASURY_BALANCE - amount);
+		assert_balance!(Currency, CHARLIE, HDX, charlie_balance + bought);
+		assert_eq!(Currency::total_issuance(HDX), issuance);
+
+		expect_events::<Test>(vec![
+			Event::<Test>::BoughtBack(
+				ETH,
+				amount,
+				bought,
+				Price::checked_from_rational(amount, bought).unwrap(),
+			)
+			.into(),
+			Event::<Test>::Transferred(CHARLIE, bought).into(),
+		]);
+	});
+}
+
+#[test]
+fn failed_buyback_should_not_change_treasury_balance() {
+	new_test_ext().execute_with(|| {
+		// Whole treasury balance exceeds maximal ratio of the pool reserve sold in one trade
+		assert_ok!(Buyback::set_buyback_asset(
+			Origin::root(),
+			DOT,
+			BuybackLimit {
+				portion: Permill::from_percent(100),
+				max_amount: Balance::MAX,
+			}
+		));
+
+		run_to_block(10);
+
+		assert_balance!(Currency, TREASURY, DOT, TREASURY_BALANCE);
+
+		expect_events::<Test>(vec![Event::<Test>::BuybackFailed(
+			DOT,
+			TREASURY_BALANCE,
+			pallet_xyk::Error::<Test>::MaxInRatioExceeded.into(),
+		)
+		.into()]);
+	});
+}
+
+#[test]
+fn set_disposal_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Buyback::disposal(), Disposal::Burn);
+
+		assert_noop!(
+			Buyback::set_disposal(Origin::signed(BOB), Disposal::Transfer(BOB)),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Buyback::set_disposal(Origin::root(), Disposal::Transfer(CHARLIE)));
+
+		assert_eq!(Buyback::disposal(), Disposal::Transfer(CHARLIE));
+
+		expect_events::<Test>(vec![Event::<Test>::DisposalSet(Disposal::Transfer(CHARLIE)).into()]);
+	});
+}
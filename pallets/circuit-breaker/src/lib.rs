@@ -0,0 +1,259 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Circuit Breaker Pallet
+//!
+//! ## Overview
+//!
+//! Circuit breaker limits amount of an asset sold into an AMM pool in a single block to `MaxVolumePerBlock`
+//! of the pool reserve of the asset before the first trade of the block. Trades exceeding the limit fail.
+//!
+//! Keepers - accounts of the mechanisms restoring pool prices, e.g. arbitrage or protocol-owned liquidity - are not
+//! limited by the regular volume. Their volume is tracked separately and limited by `KeeperVolumePerBlock`, so they
+//! can act while regular trading of the pool is stopped by the breaker.
+//!
+//! Volumes are updated by AMM handlers before each trade. Volume of previous blocks is overwritten by the first trade
+//! of the pool in a block, so no explicit pruning is needed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{dispatch::DispatchResult, ensure, traits::Get, weights::Weight};
+use orml_traits::MultiCurrency;
+use primitives::{
+	asset::AssetPair,
+	traits::{AMMHandlers, AMMTransfer, LiquidityChange},
+	AssetId, Balance, IntentionType,
+};
+use sp_runtime::RuntimeDebug;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// Amount of an asset sold into a pool in a single block.
+#[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct BlockVolume<BlockNumber> {
+	/// Block the volume was traded in
+	pub block: BlockNumber,
+	/// Pool reserve of the asset before the first trade of the block
+	pub reserve: Balance,
+	/// Amount sold by regular accounts
+	pub volume: Balance,
+	/// Amount sold by keepers
+	pub keeper_volume: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+	use sp_runtime::Permill;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Multi currency used to read pool reserves
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// Maximal amount of an asset sold into a pool by regular accounts in a single block,
+		/// relative to the pool reserve of the asset
+		#[pallet::constant]
+		type MaxVolumePerBlock: Get<Permill>;
+
+		/// Maximal amount of an asset sold into a pool by keepers in a single block,
+		/// relative to the pool reserve of the asset
+		#[pallet::constant]
+		type KeeperVolumePerBlock: Get<Permill>;
+
+		/// Origin which can add and remove keepers
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Trade exceeds the volume allowed in a single block.
+		MaxVolumeExceeded,
+
+		/// Trade exceeds the keeper volume allowed in a single block.
+		KeeperVolumeExceeded,
+
+		/// Account is already a keeper.
+		AlreadyKeeper,
+
+		/// Account is not a keeper.
+		NotKeeper,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Keeper was added. [who]
+		KeeperAdded(T::AccountId),
+
+		/// Keeper was removed. [who]
+		KeeperRemoved(T::AccountId),
+	}
+
+	/// Accounts trading with the keeper allowance.
+	#[pallet::storage]
+	pub type Keepers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Volume sold into pools in the last block the pool was traded, keyed by pool and sold asset.
+	#[pallet::storage]
+	#[pallet::getter(fn block_volume)]
+	pub type BlockVolumes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		AssetId,
+		BlockVolume<T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Add `who` to keepers.
+		///
+		/// Trades of keepers are limited by `KeeperVolumePerBlock` instead of `MaxVolumePerBlock`.
+		///
+		/// Emits `KeeperAdded` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::add_keeper())]
+		pub fn add_keeper(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			ensure!(!Keepers::<T>::contains_key(&who), Error::<T>::AlreadyKeeper);
+
+			Keepers::<T>::insert(&who, ());
+
+			Self::deposit_event(Event::KeeperAdded(who));
+
+			Ok(().into())
+		}
+
+		/// Remove `who` from keepers.
+		///
+		/// Emits `KeeperRemoved` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_keeper())]
+		pub fn remove_keeper(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			ensure!(Keepers::<T>::take(&who).is_some(), Error::<T>::NotKeeper);
+
+			Self::deposit_event(Event::KeeperRemoved(who));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Return true if `who` trades with the keeper allowance.
+	pub fn is_keeper(who: &T::AccountId) -> bool {
+		Keepers::<T>::contains_key(who)
+	}
+
+	/// Add `amount` of `asset` sold into `pool` by `who` to the volume of the current block.
+	///
+	/// Fail if the volume exceeds the allowance of `who`.
+	fn record_volume(pool: &T::AccountId, who: &T::AccountId, asset: AssetId, amount: Balance) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		let is_keeper = Self::is_keeper(who);
+
+		BlockVolumes::<T>::try_mutate(pool, asset, |maybe_volume| -> DispatchResult {
+			let mut volume = match maybe_volume {
+				Some(volume) if volume.block == now => *volume,
+				_ => BlockVolume {
+					block: now,
+					reserve: T::Currency::free_balance(asset, pool),
+					..Default::default()
+				},
+			};
+
+			if is_keeper {
+				volume.keeper_volume = volume.keeper_volume.saturating_add(amount);
+				ensure!(
+					volume.keeper_volume <= T::KeeperVolumePerBlock::get().mul_floor(volume.reserve),
+					Error::<T>::KeeperVolumeExceeded
+				);
+			} else {
+				volume.volume = volume.volume.saturating_add(amount);
+				ensure!(
+					volume.volume <= T::MaxVolumePerBlock::get().mul_floor(volume.reserve),
+					Error::<T>::MaxVolumeExceeded
+				);
+			}
+
+			*maybe_volume = Some(volume);
+			Ok(())
+		})
+	}
+}
+
+/// Sold amount is paid in `asset_in` of the transfer for both sells and buys.
+impl<T: Config> AMMHandlers<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
+	fn on_before_trade(
+		pool: &T::AccountId,
+		trade_type: IntentionType,
+		transfer: &AMMTransfer<T::AccountId, AssetPair, Balance>,
+	) -> DispatchResult {
+		let amount_in = match trade_type {
+			IntentionType::SELL => transfer.amount,
+			IntentionType::BUY => transfer.amount_out,
+		};
+
+		Self::record_volume(pool, &transfer.origin, transfer.assets.asset_in, amount_in)
+	}
+
+	fn on_trade(
+		_pool: &T::AccountId,
+		_trade_type: IntentionType,
+		_transfer: &AMMTransfer<T::AccountId, AssetPair, Balance>,
+	) {
+	}
+
+	fn on_liquidity_changed(_pool: &T::AccountId, _change: &LiquidityChange<T::AccountId, AssetId, Balance>) {}
+
+	/// Keeper, block volume and pool reserve are read, block volume is stored.
+	fn on_trade_weight() -> Weight {
+		T::DbWeight::get().reads_writes(3, 1)
+	}
+}
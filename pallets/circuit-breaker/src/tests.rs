@@ -0,0 +1,199 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+pub use crate::mock::{
+	CircuitBreaker, Currency, ExtBuilder, Origin, System, Test, ALICE, DOT, HDX, INITIAL_RESERVE, KEEPER, POOL,
+};
+use frame_support::{assert_noop, assert_ok};
+use primitives::traits::TradeSource;
+use sp_runtime::DispatchError::BadOrigin;
+use test_utils::expect_events;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Notify the handler and move reserves of the pool as the trade would if it was not rejected.
+fn trade(
+	who: u64,
+	trade_type: IntentionType,
+	asset_in: AssetId,
+	asset_out: AssetId,
+	amount: Balance,
+	amount_out: Balance,
+) -> DispatchResult {
+	CircuitBreaker::on_before_trade(
+		&POOL,
+		trade_type,
+		&AMMTransfer {
+			origin: who,
+			assets: AssetPair { asset_in, asset_out },
+			amount,
+			amount_out,
+			discount: false,
+			discount_amount: 0,
+			fee: 0,
+			source: TradeSource::Direct,
+			fees: None,
+		},
+	)?;
+
+	let (paid, received) = match trade_type {
+		IntentionType::SELL => (amount, amount_out),
+		IntentionType::BUY => (amount_out, amount),
+	};
+	assert!(Currency::deposit(asset_in, &POOL, paid).is_ok());
+	assert!(Currency::withdraw(asset_out, &POOL, received).is_ok());
+
+	Ok(())
+}
+
+#[test]
+fn add_keeper_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CircuitBreaker::add_keeper(Origin::root(), KEEPER));
+
+		assert!(CircuitBreaker::is_keeper(&KEEPER));
+		assert!(!CircuitBreaker::is_keeper(&ALICE));
+
+		expect_events::<Test>(vec![Event::<Test>::KeeperAdded(KEEPER).into()]);
+	});
+}
+
+#[test]
+fn add_keeper_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(CircuitBreaker::add_keeper(Origin::signed(ALICE), KEEPER), BadOrigin);
+
+		assert_ok!(CircuitBreaker::add_keeper(Origin::root(), KEEPER));
+
+		assert_noop!(
+			CircuitBreaker::add_keeper(Origin::root(), KEEPER),
+			Error::<Test>::AlreadyKeeper
+		);
+	});
+}
+
+#[test]
+fn remove_keeper_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CircuitBreaker::add_keeper(Origin::root(), KEEPER));
+
+		assert_noop!(CircuitBreaker::remove_keeper(Origin::signed(ALICE), KEEPER), BadOrigin);
+
+		assert_ok!(CircuitBreaker::remove_keeper(Origin::root(), KEEPER));
+
+		assert!(!CircuitBreaker::is_keeper(&KEEPER));
+
+		expect_events::<Test>(vec![Event::<Test>::KeeperRemoved(KEEPER).into()]);
+
+		assert_noop!(
+			CircuitBreaker::remove_keeper(Origin::root(), KEEPER),
+			Error::<Test>::NotKeeper
+		);
+	});
+}
+
+#[test]
+fn volume_should_be_limited_per_block() {
+	new_test_ext().execute_with(|| {
+		let max_volume = INITIAL_RESERVE / 10;
+
+		assert_ok!(trade(ALICE, IntentionType::SELL, HDX, DOT, max_volume / 2, 1_000));
+		// Amount paid into the pool is counted for buys
+		assert_ok!(trade(ALICE, IntentionType::BUY, DOT, HDX, 1_000, max_volume / 2));
+
+		assert_eq!(
+			CircuitBreaker::block_volume(POOL, HDX),
+			Some(BlockVolume {
+				block: 1,
+				reserve: INITIAL_RESERVE,
+				volume: max_volume,
+				keeper_volume: 0,
+			})
+		);
+
+		// Limit is relative to the reserve before the first trade of the block
+		assert_noop!(
+			trade(ALICE, IntentionType::SELL, HDX, DOT, 1, 0),
+			Error::<Test>::MaxVolumeExceeded
+		);
+
+		// Volume of the other asset is tracked separately
+		assert_ok!(trade(ALICE, IntentionType::SELL, DOT, HDX, max_volume, 1_000));
+	});
+}
+
+#[test]
+fn volume_should_be_reset_in_next_block() {
+	new_test_ext().execute_with(|| {
+		let max_volume = INITIAL_RESERVE / 10;
+
+		assert_ok!(trade(ALICE, IntentionType::SELL, HDX, DOT, max_volume, 1_000));
+
+		System::set_block_number(2);
+
+		assert_ok!(trade(ALICE, IntentionType::SELL, HDX, DOT, 1_000, 1_000));
+
+		assert_eq!(
+			CircuitBreaker::block_volume(POOL, HDX),
+			Some(BlockVolume {
+				block: 2,
+				reserve: INITIAL_RESERVE + max_volume,
+				volume: 1_000,
+				keeper_volume: 0,
+			})
+		);
+	});
+}
+
+#[test]
+fn keeper_should_trade_with_separate_allowance() {
+	new_test_ext().execute_with(|| {
+		let max_volume = INITIAL_RESERVE / 10;
+		let keeper_volume = INITIAL_RESERVE / 50;
+
+		assert_ok!(CircuitBreaker::add_keeper(Origin::root(), KEEPER));
+
+		assert_ok!(trade(ALICE, IntentionType::SELL, HDX, DOT, max_volume, 1_000));
+		assert_noop!(
+			trade(ALICE, IntentionType::SELL, HDX, DOT, 1, 0),
+			Error::<Test>::MaxVolumeExceeded
+		);
+
+		// Keeper is not stopped by the exhausted regular volume
+		assert_ok!(trade(KEEPER, IntentionType::SELL, HDX, DOT, keeper_volume, 1_000));
+
+		assert_noop!(
+			trade(KEEPER, IntentionType::SELL, HDX, DOT, 1, 0),
+			Error::<Test>::KeeperVolumeExceeded
+		);
+
+		assert_eq!(
+			CircuitBreaker::block_volume(POOL, HDX),
+			Some(BlockVolume {
+				block: 1,
+				reserve: INITIAL_RESERVE,
+				volume: max_volume,
+				keeper_volume,
+			})
+		);
+	});
+}
@@ -20,10 +20,24 @@
 use super::*;
 
 use codec::Decode;
-use frame_benchmarking::benchmarks;
+use frame_benchmarking::{account, benchmarks};
 use frame_system::RawOrigin;
 use hex_literal::hex;
 
+/// Build a proof of the longest accepted path and set `ClaimsRoot` to the root it proves.
+fn max_depth_proof<T: Config>(address: EthereumAddress, amount: Balance) -> ClaimProof {
+	let path: Vec<H256> = (0..merkle::MAX_PROOF_DEPTH)
+		.map(|i| H256::repeat_byte(i as u8))
+		.collect();
+
+	let root = path.iter().fold(merkle::leaf_hash(&address, amount), |hash, sibling| {
+		merkle::node_hash(&hash, sibling)
+	});
+	ClaimsRoot::<T>::put(root);
+
+	ClaimProof { amount, path }
+}
+
 benchmarks! {
 	claim {
 		let alice_id = hex!["d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"];
@@ -36,8 +50,9 @@ benchmarks! {
 
 		let caller = T::AccountId::decode(&mut &alice_id[..]).unwrap_or_default();
 		let eth_address = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
-		Claims::<T>::insert(eth_address, T::CurrencyBalance::from(1_000_000_000_000_000_000_u128).into());
-	}: _(RawOrigin::Signed(caller.clone()), EcdsaSignature(signature))
+		Claims::<T>::remove(eth_address);
+		let proof = max_depth_proof::<T>(eth_address, 1_000_000_000_000_000_000_u128);
+	}: _(RawOrigin::Signed(caller.clone()), EcdsaSignature(signature), Some(proof))
 	verify {
 		let expected_balance = T::CurrencyBalance::from(2_000_000_000_000_000_000_u128);
 
@@ -46,6 +61,109 @@ benchmarks! {
 
 		assert_eq!(T::Currency::free_balance(&caller), expected_balance.into());
 		assert_eq!(Claims::<T>::get(eth_address), T::CurrencyBalance::from(0u128).into());
+		assert!(Pallet::<T>::merkle_claimed(eth_address));
+	}
+
+	claim_for {
+		let alice_id = hex!["d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"];
+		let signature = hex!["bcae7d4f96f71cf974c173ae936a1a79083af7f76232efbf8a568b7f990eceed73c2465bba769de959b7f6ac5690162b61eb90949901464d0fa158a83022a0741c"];
+
+		#[cfg(test)]
+		let alice_id = hex!["2a00000000000000"];
+		#[cfg(test)]
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+
+		let relayer: T::AccountId = account("relayer", 0, 0);
+		let dest = T::AccountId::decode(&mut &alice_id[..]).unwrap_or_default();
+		let eth_address = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
+		Claims::<T>::remove(eth_address);
+		let proof = max_depth_proof::<T>(eth_address, 1_000_000_000_000_000_000_u128);
+	}: _(RawOrigin::Signed(relayer), dest.clone(), EcdsaSignature(signature), Some(proof))
+	verify {
+		let expected_balance = T::CurrencyBalance::from(2_000_000_000_000_000_000_u128);
+
+		#[cfg(test)]
+		let expected_balance = T::CurrencyBalance::from(1_000_000_000_000_000_000_u128);
+
+		assert_eq!(T::Currency::free_balance(&dest), expected_balance.into());
+		assert_eq!(Claims::<T>::get(eth_address), T::CurrencyBalance::from(0u128).into());
+		assert!(Pallet::<T>::merkle_claimed(eth_address));
+	}
+
+	freeze_claims {
+	}: _(RawOrigin::Root)
+	verify {
+		assert!(Pallet::<T>::frozen());
+	}
+
+	unfreeze_claims {
+		Frozen::<T>::put(true);
+	}: _(RawOrigin::Root)
+	verify {
+		assert!(!Pallet::<T>::frozen());
+	}
+
+	unfreeze_address {
+		let eth_address = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
+	}: _(RawOrigin::Root, eth_address)
+	verify {
+		assert!(Pallet::<T>::unfrozen_addresses(eth_address));
+	}
+
+	cancel_claim {
+		let dest: T::AccountId = account("dest", 0, 0);
+		let eth_address = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
+		let amount: BalanceOf<T> = T::CurrencyBalance::from(1_000_000_000_000_000_000_u128).into();
+		let execute_at = T::BlockNumber::from(10u32);
+
+		PendingClaims::<T>::insert(eth_address, PendingClaim { dest, amount, execute_at });
+		ClaimSchedule::<T>::append(execute_at, eth_address);
+	}: _(RawOrigin::Root, eth_address)
+	verify {
+		assert_eq!(Claims::<T>::get(eth_address), amount);
+	}
+
+	execute_claim {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let dest: T::AccountId = account("dest", 0, 0);
+		let eth_address = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
+		let amount: BalanceOf<T> = T::CurrencyBalance::from(1_000_000_000_000_000_000_u128).into();
+
+		PendingClaims::<T>::insert(eth_address, PendingClaim { dest: dest.clone(), amount, execute_at: T::BlockNumber::from(0u32) });
+	}: _(RawOrigin::Signed(caller), eth_address)
+	verify {
+		assert_eq!(T::Currency::free_balance(&dest), amount);
+		assert!(Pallet::<T>::pending_claims(eth_address).is_none());
+	}
+
+	set_end_claim_period {
+		let end = T::BlockNumber::from(10u32);
+	}: _(RawOrigin::Root, Some(end))
+	verify {
+		assert_eq!(Pallet::<T>::end_claim_period(), Some(end));
+	}
+
+	sweep_unclaimed {
+		let n in 1 .. T::MaxClaimsSweptPerCall::get();
+
+		for i in 0..n {
+			let mut address = [0u8; 20];
+			address[..4].copy_from_slice(&i.to_le_bytes());
+			Claims::<T>::insert(EthereumAddress(address), T::CurrencyBalance::from(1_000_000_000_000_u128).into());
+		}
+
+		EndClaimPeriod::<T>::put(T::BlockNumber::from(0u32));
+		frame_system::Pallet::<T>::set_block_number(T::BlockNumber::from(1u32));
+	}: _(RawOrigin::Root)
+	verify {
+		assert!(!T::Currency::free_balance(&T::TreasuryAccount::get()).is_zero());
+	}
+
+	set_claims_root {
+		let root = H256::repeat_byte(1);
+	}: _(RawOrigin::Root, Some(root))
+	verify {
+		assert_eq!(Pallet::<T>::claims_root(), Some(root));
 	}
 }
 
@@ -61,5 +179,32 @@ mod tests {
 		new_test_ext().execute_with(|| {
 			assert_ok!(test_benchmark_claim::<Test>());
 		});
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_claim_for::<Test>());
+		});
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_freeze_claims::<Test>());
+		});
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_unfreeze_claims::<Test>());
+		});
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_unfreeze_address::<Test>());
+		});
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_cancel_claim::<Test>());
+		});
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_execute_claim::<Test>());
+		});
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_set_end_claim_period::<Test>());
+		});
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_sweep_unclaimed::<Test>());
+		});
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_set_claims_root::<Test>());
+		});
 	}
 }
@@ -1,5491 +0,0 @@
-use sp_std::vec;
-lazy_static::lazy_static! {
-pub static ref CLAIMS_DATA: vec::Vec<(&'static str, u128)> = vec![
-    ("0x0000000484f2217f1a64eb6d24b5cee446faeae5", 228411278824),
-    ("0x9760a49499470c9538b9a754c167e8a204ed7cbf", 359454329927636421),
-    ("0x4bb62f75328b096d9cbdecfdfa158a6e03ed1666", 37270841493938493),
-    ("0xb57cae748c618ab8685a8143890c61c62fcd0f8b", 4998236228359111),
-    ("0xf6e454836daf89466dd9aa98dc550b7afa183e4d", 7041811746698349),
-    ("0xc52dc5d3511bb6e4f16ac55f055a2bc8e97b14fa", 16925768534137674),
-    ("0xbf1f4dbbc3ce58179ad839bcdd784bfdafe4126d", 4751101489754626),
-    ("0xf4ed8aafc9bcae35db4ca9705c950f190b7d1e92", 24521796264838673),
-    ("0xd11eb5db7cfbb9ecae4b62e71ec0a461f6baf669", 104974731368241508),
-    ("0x526fe4f6f03cbd9e580537af29d01295da707f4b", 217879788441727600),
-    ("0x6165fd87c1bc73a4c44b23934e9136fd92df5b01", 3535233849213440049),
-    ("0xec87a3f293123c0a4b5e158ebade8c849d197339", 121749676519933854),
-    ("0x92ebb63e76972944c5d9ff19ee8fe035a6ed2150", 117005325540342062),
-    ("0xd3474bd2f558b12e83810ec7d8af14f2d1d08137", 317066067847271783),
-    ("0xfce0413bad4e59f55946669e678eccfe87777777", 107762991480259341),
-    ("0x84098271fd08223af4f15154d33dcc51fb717f53", 66032217991949584),
-    ("0xf6168297046ca6fa514834c30168e63a47256af4", 155518391198233224),
-    ("0x502bb5979a85d2721d5025569164516f45c6c01d", 2712321403220093),
-    ("0x2ca3a2b525e75b2f20f59deccae3ffa4bdf3eaa2", 3680834996051646570),
-    ("0xd17615f52820abe624c387b497d3ff322f9c692e", 10930273758979734),
-    ("0x4af4f95603f1b14f78bb5e73606965cfe5e0b322", 91887333068642308),
-    ("0x63acb57b970af5bc4b5b23ecf5da4f60cd93ee00", 16368452001092704),
-    ("0x6c8a70bae601e97f2c885f640d5c35b2a1e805e3", 99033806332180769),
-    ("0x36cf7f9c05a686935ef6208d362ae3cb0746f7af", 39707033075305250),
-    ("0xca0eeb0cfa3435e819fae63a7a8f420a2906f6ec", 68103224933553336),
-    ("0x6a9ee69b6781c18164ee9f7c58f1763bcffc7c51", 131387245623683436),
-    ("0xa143d719a4236204e09f69c3c25e221242abe570", 105404800354468286),
-    ("0x1bf8ba061664339baf1e1d016d8f6cea9455f404", 29440847969808156),
-    ("0x7fbd935c9972b6a4c0b6f7c6f650996677bf6e0a", 46642930049616358),
-    ("0xc1d43fa553b9fbaa95637d28d39862dc339db0ea", 52025919389824257),
-    ("0x560cd6b5772c69efe8c36ec3e1f8af3b95c66b44", 82089748506121832),
-    ("0x8b5a490f6c17449d44994b403df5ddac1e0b1294", 72196822837711619),
-    ("0xe5ef008a6ed9beab51e6a23bd0eeb2a27f38a74b", 45208604175354064),
-    ("0x17807d5c8024aff6b1bd7516f230a77ecf63e89b", 19361232079722546),
-    ("0x004c01bf15cac0f93b1ea7a24f416b0daf3cdcef", 87760988101214163),
-    ("0x9617292336b588b5bb174c5e845455400d907471", 91870209091921564),
-    ("0x7a1483dd96f48d292fa61afbd279fafcc63d7c56", 114951987362727312),
-    ("0x1bb6e0eb3cc94d321789237bb267fd15f44930e9", 33480838457171032),
-    ("0x6f4e3e11cc67dc66ea3108f0ab50fd3c1b8722a9", 16360764741112286),
-    ("0xbd28d5136ce33fb3fb1fad3b37510a025e90a892", 4555668926491171),
-    ("0xb6b90acd30b33ec6737003abc2b4aa4206ef05e7", 38916695280260908),
-    ("0x91aa505769bcea5dca318e2a2f3a1095a804e865", 11270995497432013),
-    ("0xe64c25478f0ebd9b62f8e73cd0ed20d0ad54131f", 75064787365997523),
-    ("0xd7fde832ddb98357e899ef0a27fb2631ce775026", 30484914883004026),
-    ("0x8bc6b3fd67be8d0ad383b71e6f6b1fbafe0716e8", 17065076075259180),
-    ("0x616b0b2d92dc4140390af3f6336445ca96ff6424", 658781540158961869),
-    ("0xd2d9c47eb5a5089bbcc16540f829fcc42c4d50f0", 26262254249262394),
-    ("0x150d4f19e2f26e067fbb3e3fd6f56fc6bcb9d5b6", 77525399550106411),
-    ("0x0cb12233a9b7f0b7d20ea803165934fe9190631e", 31409150384650794),
-    ("0x1f6e925125bc90d812afb0b17b5cb48f040c112d", 163985391992521263),
-    ("0x083b751a531df05228cbbd419113f8892a39d8f5", 199100900717178716),
-    ("0xeb959caad3bb6e28ba3d9312a8a7ce745767249d", 134935577988806485),
-    ("0x9965ed3f7397cfa36efe78f6bf0547ae59dc673e", 152870306256781425),
-    ("0x3b7e93200d37d838ea466e8ced97752a6dff994e", 21356963850535615),
-    ("0x941c9b91165f3deced2d9b29cc96fc3dbb809879", 1091383386141065982),
-    ("0x9777daabf322d38af7a49a66dc55f3086127baea", 155057810099160250),
-    ("0x80a20e73bb079299215d2da5482636070ef9dfe7", 42147664747197622),
-    ("0xc47bca726471967b06105873f03c9e1261321664", 107805137713063985),
-    ("0x26c16d75345e10f663c10b7b5808480164ffd64d", 2889636144454871),
-    ("0x69d2bc658490c7ddda797d398e862ed4eed2eb80", 178286093814461021),
-    ("0x38c848ab1c28f503d2a7bed108419fd60a47c292", 21639824967095972),
-    ("0x78d79b027e11b21e13a1887fde77347790b8bca6", 20254439650588456),
-    ("0xf1c425105bc866227004f53aecf28c77db48625f", 7567282584762895),
-    ("0x29dcf70568785401574c8cd06c07b39e26618bb7", 5941406902079832),
-    ("0xecc072714365ee2fbd2e90c752c07a95641a5fe6", 34107847343785588),
-    ("0xdaa80a4f8237f6728feb901c04e7ab29c48e9e1d", 28648405821491227),
-    ("0x64ec24675d7bbc80f954ff15edd57d381f5b3e1a", 260717770735167850),
-    ("0xb78fd9e442eef5f34c939ac9209319146654a361", 18404926669212856),
-    ("0xf2670ebdd32ead8c41bc627cbb95f3e9c4a68393", 6990157593526726),
-    ("0x973b79656f9a2b6d3f9b04e93f3c340c9f7b4c6c", 4277673099916606),
-    ("0x5d56a3e85c52b8d3ac595a3922ea7e1a3ea948a0", 7693882040107652),
-    ("0x79fa12e26e5d7c93dcb3d759cf62d7b6067a9444", 32760563353021669),
-    ("0x835a4044291c94e60f71ba6bacc437dd0d080d1a", 22802645179866969),
-    ("0xbbe167e5c3379de355430dc168d098d7fdcbd2d3", 34687926494468151),
-    ("0x6d553fb941ec883fa2484e80bde1ad50231e42ff", 91589554246442394),
-    ("0x51679136e1a3407912f8fa131bc5f611c52d9fee", 18881594132229980),
-    ("0xdb41171af6c39ad4785cad0e683f9a61a2129e8c", 95424890887842893),
-    ("0xd879ca8459d31cc512542bf6d0b64467de97ab59", 23287151909998997),
-    ("0x4b37af112c62232280c87a5e71fb0ce21b7ef407", 226885399381508561),
-    ("0x81178b143737677629d61d47199d76a32b537d96", 159572097536994946),
-    ("0x2debdf4427ccbcfdbc7f29d63964499a0ec184f6", 2509948499234911825),
-    ("0x381c57356b68fa4ccfb9804003034b0477cf56dc", 31035989594069527),
-    ("0x4f70533a500f0d9dcc3bc92a2e74e5a998f4f3a3", 46262595583055957),
-    ("0x5e1dd88ffb9a3e7abd2e92998f6e9e13bc266ac8", 94276602703574448),
-    ("0xa4802ffc08dc8cba1643c69829cabd705e1891d2", 21508920450288684),
-    ("0x72d0d9c1026494e07189589e1b531462f4deeff0", 69302803099789375),
-    ("0xd9116827b463ab5886262a6b705c858dcb75a32a", 20760560321319291),
-    ("0xeda39af25b25f179bed410ecc6d33060952b7db3", 210428483946741339),
-    ("0xedc2fdcc98b72e6e0406e795e485f1665ec8cfc6", 63060606476750735),
-    ("0xfd432fde40256cf0afad2a9353431c62f0c0f8cc", 80443506715303408),
-    ("0x68a335b5ebdcb62567ef548dc08c8e61ac07b153", 21593851060738908),
-    ("0xb7f6227f09cf8bd2c8897c71d3b980f42ac95544", 26833860533681509),
-    ("0xbdc4d451dcab410dca2699fce2f25cf20f7ab61d", 32632911260174133),
-    ("0x9b73f904995230750f92c8a4d26a6cf42c0aad51", 8860018521461507),
-    ("0xf2adad1e054f788412af20b18f3a0cdf8b2e208e", 11348404194811187),
-    ("0xc96206fb6e4f75734148577dcf137186bed2fb2d", 89568113895287736),
-    ("0xdcf81f0b32984c32433cb5f58365543244bd134c", 39736403462905963),
-    ("0x19c1500e74f915333bd249e613ee2fadc6348845", 19687905994013235),
-    ("0x7e21d40ba5ed1a483cb02a8ef4066c81a1cd6937", 118513852098420089),
-    ("0xe99609abe4594b94f6c4cd85aee2c5f14f420eab", 21069708222193539),
-    ("0x5cc688704bd00fac6e9081cbc011810adfcced02", 103715710656254498),
-    ("0xba46868f49182b521b71bf9894d24da7e969e286", 109450307070182623),
-    ("0xf8b51712b236b53c458429e9642743956f11b98b", 199167384443477205),
-    ("0x5b71746b96e9d2986f8a4305b20e1ca6f9247d69", 40753879889491413),
-    ("0x22cab81e0fee2bad5b015dbbeb6a374a1b8738de", 10659111056097415),
-    ("0x1ea0d4d1f38700ff7102dee483d6ca3279be441a", 41240337723481369),
-    ("0xbe888884c76d0e5d30ed0651fd45483203d550b2", 5006477183134666905),
-    ("0x336d4afd4c1e0b82a2bb38859c234c54edf0a983", 130971885068412528),
-    ("0x636310779abdd82fd261d442df1a4865cfb20f88", 6662036496552376),
-    ("0x16f1760ed9321eac4788514b1e5cce598ab3c43a", 109655946274469943),
-    ("0xc16042528e780c05b23c722fe787b30b83300d95", 234403827219352672),
-    ("0x963d071201275fd5fa3dc9bb34fd3d0275ba97a7", 108143725034498470),
-    ("0x4085e9fb679dd2f60c2e64afe9533107fa1c18f2", 103755609232930275),
-    ("0xfaf6feffee93ef663583c140a4f44f08faf07609", 4754701715903831),
-    ("0x9954c7a44b705366f0347c36ffe692e65c3accfd", 36633247972841996),
-    ("0x1f458a9be58e2f8585baa4b4fa07c5b2ae255752", 95799293303935091),
-    ("0x058a021861fad3c57a389e89b52b8734c0d9685a", 18989919942423388),
-    ("0xd71a83e47d5eead6dfecf0d1b479f5787d6e00ee", 40229132922420436),
-    ("0x9f64d53981e317c102b3c591d75aa1ab28baf599", 304970371060085666),
-    ("0xa47c0b73ec45a428ab6902c027481082d6db1132", 28399746428023419),
-    ("0x35c3ccbbfcd78f2b3581260c5464a74e58d27049", 4349505059037663),
-    ("0x156a0ea9035bfe5a712d4fd88a39a5d1ff64f472", 2624282157116711),
-    ("0x4d801db56cfa5eaba84f6bc79e5cc6d8f2b5c4d3", 19037502151498221),
-    ("0x6b0e004266b722d05b3f79a573efe9c44bb909cd", 28515642115445127),
-    ("0x86f0f2c5500420cc751af409b3a8a24bc5ddfe45", 133136602283913622),
-    ("0x93123e0394ca6323611c910957553876a9629571", 9711058991993891),
-    ("0xba87af3ae788b0e97536592f49d8e2824c011553", 12984040405566923),
-    ("0xe3a656b2d84b391f4c1a8d8ab53f05c9c98c76a8", 11917663954701673),
-    ("0x72ea125dae279a481afd0af45afb1c6209224b4a", 358053915261725769),
-    ("0xa80a99987b81d4e2090f9b4a2a789ee13f2d2a8e", 23005334184925313),
-    ("0x2792453292fdd1d52594a783f131462f12bde7e5", 183501449505474566),
-    ("0x872a95acc5fdddd76c444dedec1effb131afda7f", 6739484349944142),
-    ("0x652022b1f234aa39f5dd7a5ff1162574fd4a9646", 13289578960013101),
-    ("0x5f071f2ce06084811be5b2ef2e9d2773f53d32cb", 43444817805626840),
-    ("0xb9888dd3fa1069220ed53089230cb543d13526e6", 35586414263624233),
-    ("0xe764cbbd0cf7fe67095bf13a118fa4b577945d6b", 8162296307886648),
-    ("0x5d8b5ecc36c110420fb7dd1a6695d65c7cc4d807", 6724380031522506),
-    ("0x8d36e1c11acc474a8f852ab3e931e7db82c93a5b", 19812099594541743),
-    ("0xb27ad28f060acd52b01a8d16f379c698459d89ce", 16492816819867553),
-    ("0x301481d22a64e7dea6c44c68ad3030a4132c81c3", 11959116025774279),
-    ("0x213f6b2680dce3e4d0924acff3e4e34520ef9ba1", 78632366193480755),
-    ("0x0c40a880ab0c51f17b62405bc0c6173e9f11d3cd", 32361974462359612),
-    ("0x9d2beea6625c1991c796c498b93842d9e09cb6d6", 74581486118034373),
-    ("0x5b9e49d85aafe77c192d4f03b58d7c3e40ffc08b", 28607734476230330),
-    ("0xd2be76743fe4ddc1bf8caed6b122969e31529e25", 41162287263434331),
-    ("0xa6f8f43b44011e5ff0ba15d8a4b678a8a81cce23", 11365261087421583),
-    ("0x8a089e873a99aa35846b5136724799c24f8e5887", 6437578710949753),
-    ("0x86a529f970a530c22392c8e920a745030838737f", 5792265477104779),
-    ("0x34eff58803541d210a7694137d5b9717b73b68ac", 27939599182512870),
-    ("0x4468dca9fce9f4c5b39851de46af225b7e24e11c", 65342905395838442),
-    ("0xead17040b9a9c489cfa8357b02923e7bc551b4ce", 3313319498859664),
-    ("0x03d6968f256d052e9e7777361c3ae4bd266088af", 4971383008542787),
-    ("0x42a1c589f45089650119a1c24a763b668e7219c6", 7285099791128846),
-    ("0xeb73058499ed6ec323c4da4ac6769844c30bd9a8", 161891635708641998),
-    ("0x518d59f99e4c21901e0f0d9f3b00d93bb0058d69", 15655285422094474),
-    ("0xf8ef378fd1a213c0a54a271a153cbc32424485e7", 319442444039711332),
-    ("0xa97704ea492e379aa443f2109cf322fa0146ddf9", 22245312566956851),
-    ("0xd2a67a1b56d9e2a60548960359bf525a252c1780", 36082747980415921),
-    ("0x7d4c4d5380ca2f9c7a091bb622b80613da7eae8c", 152331371329876175),
-    ("0x125eae40d9898610c926bb5fcee9529d9ac885af", 10725029316038233),
-    ("0xe3bdda386209ca1a1d615e917e1284b995a832a9", 6652913714147531),
-    ("0xc277513999546d44b08413381c01c094e56ec053", 103935595799047968),
-    ("0x1fc79953d547394f90ca739f178ef79fbd46423b", 11306696314156015),
-    ("0xd8d1bcdb29fb7f87db3e14c488588171a27db2be", 31245200756136983),
-    ("0x91376a70557829ba5d5c85360f786452b5886329", 12988316408883526),
-    ("0x27f33f92b16b88d7e9730d325f5eae8920c75ac6", 31488503956971217),
-    ("0xacf63ef8e8aea1228bf5f59d0bd9869bd3bc3a67", 31387104124377579),
-    ("0x74f6573bef82a6e8791f43a1a16e19345b144bfa", 10603955209749511),
-    ("0x8f978e7278da5576166b8588a4ded540d41af64a", 56922728775391700),
-    ("0xda3715f7f82e0769ca118680f79bb48dc312d40d", 8356942242777788),
-    ("0x9b362014da9e2d24e7d744de791faa00e6a8f9a1", 12557481491538813),
-    ("0x672926d500e20ffc44ec712aa86277a85f72f4c6", 7700720955515807),
-    ("0xea225fd04ab56e89f8c993064e6979fd9527441d", 24150429510874109),
-    ("0x1b906f26c0d33d9c08aead2ac4d0c1c79e807b18", 124693765495760658),
-    ("0x88317874cfbfc01771cad6d10789029ce8ec9e2e", 55047444840869165),
-    ("0x192f6a7e05e51a5b6167f799fc22f7b9c5dccaf9", 4920012405849142),
-    ("0x00a61d30ad2c5cc3d5ada38734c980540b404184", 38883203481306720),
-    ("0xb59d676f5971d86eb1ca8573b0b0102c8deb6851", 20919973021702977),
-    ("0x3c37c0738c273756d508ebef1010a77f9a1bd181", 11263500267079953),
-    ("0x49a08f0981939669767301838566de5b1fe67633", 10338739294652248),
-    ("0x0c80b51912c089bea4c5dc8accba11cb0e8aaf41", 12746630648576131),
-    ("0xe6cd9e35bacf53e81cc1c9ac272c4e5a02cafd7a", 5344918726101586),
-    ("0x56db2160eafc73050fad08fdb8159c08d11634ea", 21588007464508685),
-    ("0xd9d5437eabee0cac2f4f28c993316016d745377d", 2457173930076202),
-    ("0xd04c527562a68943eec8766e159341005c830fa9", 34912517628989025),
-    ("0x01e99b277d529bd08675294560abf604c301f278", 56947911276245997),
-    ("0xfd68e3ef807230c2b7859a47c8a33e81a439bbc6", 100011640613864992),
-    ("0x6cdbc995a956beae9fc950490ac106f9e00476d2", 24418014500013700),
-    ("0x1a9ac2fd00e6ec96094549807fb96fd26ae84e2c", 22269586539650934),
-    ("0xe72f5f91e693e36684270299b73575540141dd2a", 69693482764533843),
-    ("0x5d38594a188ee4cd60371a33fea4da82ef9b342d", 7638086043549655),
-    ("0xfab5fd0a6df3f2777bb78a3e775e9d00609a1711", 103547153266184331),
-    ("0xad4489f64a8be791294116d3e99d2721c7f0a72a", 69171124559700326),
-    ("0xc6d947c08ba8a703c15dcc8692ddd105ca43ff27", 892656112002795198),
-    ("0x6aca5d4890ccc8a2d153bf16a9a5b0c5560a62ff", 23068767441592161),
-    ("0x8fb66b276481ad68928056c1ed18c6e37f1bdb66", 33884977919792139),
-    ("0xe15d89aa40ee740ab5f708d80691ea03ab1028c8", 47243088957068566),
-    ("0xa186727fdaf90cd7d9972572e32c618ce04206f8", 299325364443488203),
-    ("0x9c0af141661a24e2c53cb5919ef897d934b800f9", 1152075948674093),
-    ("0x80e199bc1b92c44e015307949db4cfa4b65873d8", 59454558184634949),
-    ("0x7923d1ba6e7776d5e096bdab45223eb4dae0c114", 13279339616289394),
-    ("0x81c3715eeb07f41ade441c2158feb98fdc138c2e", 61988097312754095),
-    ("0x056c23c029da306b0f73f3e005f7a31bfad7af4f", 117424780485795237),
-    ("0x2d15dd63adcabc12100707b62ecbf43b136074a3", 147672716243859532),
-    ("0xd91b73c3594d36c0e68677478270901fe8ec35c4", 8229340500787028),
-    ("0xc6f9b2b619811a7a5b00162066b2e3f9e9a4aacd", 65679301235138613),
-    ("0xbb5d838ddcc55b287e290622d229a360bc3130ee", 2509090038840980954),
-    ("0x6c4cd639a31c658549824559ecc2d04bed1a9ab9", 42072212561048193),
-    ("0x9b4f3de5ecffd6b9ecebd20001400a9e194113bb", 8500000000000000),
-    ("0x2851857a4a55db6d462016f8787fab9d9e710b60", 88191249144028211),
-    ("0xb52d3213e93383b0ddaca721388885a662866f2c", 76213704374505469),
-    ("0x3cadd2d8984768490cb54ae11750383207160455", 13969920796889411),
-    ("0x25abdaf98e95b8da3b43d0b19920bff7a5301195", 277083507352180702),
-    ("0x43f824adfac29a2bd611545540965efc8ec2e4ac", 115527098318558182),
-    ("0x843d261b740f97bf31d09846f9d96dcc5fd2a0d0", 24521906790904691),
-    ("0xde5d8bb61f7088e84bfd5844315da684f7a4052b", 36068557878795484),
-    ("0x9e895cfa8d7cee3e77191b81a0ac76a6932c8e28", 24387424396769862),
-    ("0xaadfefe64f5aff4d27a5191eccb74009ae0d4b83", 10177321641552786),
-    ("0x8df5d18f91333f5591e9331b460d5e501c43b4b3", 10330522394628536),
-    ("0x6d330800687ee1b85d4af30bff6bc41638f21524", 504331590574529831),
-    ("0xffe026112ff700f2c3d1e47423c22288cf85ac16", 90186217592144014),
-    ("0x1946102fa977780a0c265b6073f206c2d6d3bfa3", 869740425702733684),
-    ("0x58507cd924340530cbea0883786396e1e9b2c0e3", 71272703552491836),
-    ("0x0c593dd4642da8e7500abfe868a064b7d220d69e", 128964762316391024),
-    ("0x790bd34732b6c820f9ae9fa3837546045133095a", 183029392148227467),
-    ("0x2f5fbfade4b0de5a4df6d9661b077a7faff54120", 22002154178121734),
-    ("0x3054f483ea0746c497c44bf4ab2468f133ee4190", 403537362562024216),
-    ("0xb97a69ac4f71c5d025a38fa5b6d640c68a685699", 12519383690933433),
-    ("0x82a1d1bae02fce7f5d311bb74767282b5830c0a7", 12631000000000000),
-    ("0xde92b4eb8cb2c1e76d6ce9a14e9235f133327878", 48750431539670002),
-    ("0x2e3b5867065352203a5f8d90f60cf43a41722447", 92256953236230512),
-    ("0x7cd92676ec1a98a50530dfc2f9ecfd522c9c288e", 24500391764448314),
-    ("0x1296d4a9fa924a289c270c4fa09e5332484c489c", 11237623054664821),
-    ("0x1415240783a2c2467b0df1e6be63c14220891f6d", 11292153059564173),
-    ("0xbc5a2786142f884f2c42be1499473d078c0cff02", 183341822166996651),
-    ("0x97d439665012177b7502100dd4100c68c017f507", 11371937848346170),
-    ("0x2675a71b1ca94f10b4bc5838ecf887de7593a28a", 2932372318743051486),
-    ("0xfd4520753366d35e5dc2f76d52dc58a5261ed263", 2300819367705775),
-    ("0x39c42c33d506d94a8996d1c2d0c7a6457f5502a7", 23691644158326389),
-    ("0xb87d11540b4d7575ad940b9ad5c99c7ad9c1340b", 12407092006580637),
-    ("0x07986b0366a2672a87c924823e648a3665bc595e", 73613182390364470),
-    ("0x15b61f9a30e27d0e236613d1fcc83f9aba9cda64", 11894741451687148),
-    ("0x42670df323739b9ade7ace6bcd8e1110b2579d22", 18933006357868997),
-    ("0x6f75869687be7376c4fd99077bc7c3c7d73e6a67", 266889066404140904),
-    ("0x82029eb50096a301bc7d3d16d32442739298e147", 13379405535120159),
-    ("0x68c8e1ebac4b602ac41436050e2f5bb09728d458", 25424556654394344),
-    ("0xad254603ee2710d2c6906fedfb9bc3717a246b87", 76984945377706760),
-    ("0x826568e11040e52770d0c05611bc463184c8525f", 88327250280237343),
-    ("0xd79a3323119dfe910c6ac7c506036be6ebd44417", 222743604401829960),
-    ("0x35593f99f9e8b7bad37319129954ac155288710a", 13452525505424671),
-    ("0x6dc0489ccb74975ac64a344aae21a8a89be6c9c5", 19496055990058540),
-    ("0x1a76bbb3cdaaaa675e97e99d95851829eb3e661d", 8872548411650264),
-    ("0x6007837ce3d2a76781faf6893cdc256372b5250e", 96022493432329721),
-    ("0x804ab9423083a80ba5e8bdad1cbf2c9f2c6adb80", 130055230072684613),
-    ("0xc25b5099fa45cfe09109d7fe82e3598ea09c9930", 13473979059221295),
-    ("0x88efdec6d2663c48952f537e0ca46419a6896a6a", 162415561865701452),
-    ("0x62f22afb5a4deb1a47bb6c81667a9e1fce6639d5", 4462485363289967),
-    ("0x99433e31de712efaa0ba64cc9411fbbdb9434c5e", 13724282412308232),
-    ("0xee5093fe0bb1cff37ca4b51f151f7d609ade9867", 20848409047130666),
-    ("0x619822aece3c440d6990408af27cca4118c10ed3", 202483194285939941),
-    ("0x2c5b8d5897cba509f1e0b0e184423194c5ff5f79", 31330628824325428),
-    ("0x3bc5773a5b79971102797b16e23b3eafc3c32f78", 12869115001351169),
-    ("0x8cd3bec93d81f900f4faef7f40259d392b616a7e", 86996299965707256),
-    ("0xcb1703717e125d4db28f6e95afec2d8d3c2d9ff2", 458887249360144405),
-    ("0xd4001093f8be54248dca00bf5bb7a4ec1f5d61e6", 1181706320001515005),
-    ("0xa508654ea91a30a68274387376804ddf33ba8fae", 11501759392928392),
-    ("0x307cd984562561ff9b4f8bb91453522c94d40dd6", 10218020949152965),
-    ("0xba181deb98afc2202202c9aebf26b18f46d70497", 23229850522152654),
-    ("0xccd05dd44c4c3425c4fcd174a88a2da7aa3f5a37", 136638423756140530),
-    ("0x6fcc02c3fd00d9e90295f4b0e60562fc2bba6a0d", 38993522005258719),
-    ("0x244fe02fbcf4db4ad96063b161f00e444fc54011", 55081066544223359),
-    ("0x2173948d935857564805a48b9c878da29fae380d", 17542301416940649),
-    ("0x49192a0111911792896fb7351686faddafdd75da", 113246942413550539),
-    ("0xf581cd874a1bfc0536a90c456ce44621cf500309", 17278996990882806),
-    ("0x29fb08a81867d8f61bce689d2dc366687291e747", 18640640768660886),
-    ("0xd8fcfcdc37ac8f381c46f491732be4b31dc5d0e3", 42542048191561969),
-    ("0x0337dcbc6f5f2c2ca665fdd487f7d8f23c305b08", 48598374862843331),
-    ("0xa911ccf5f6c7bd5fb25188d41a536890c3c8fae5", 1190764950745032568),
-    ("0x8bc50222b6f497017d41f9157571beee825b0b84", 10011795542855215809),
-    ("0xb707bbb632d01a6901fc8fb66d076b9453570bdf", 4546894781087123),
-    ("0x062ecd1694b747c82ecf93ad285cc084b384d9e8", 58774234940193870),
-    ("0x7708da81382aee4e535c16fbb87154c3b80ed1ea", 11200502019312843),
-    ("0x3fe444f6007d02fa8b6d8526f9cf1d300b7f43ed", 40473820465749678),
-    ("0x4cc9e35bb7355f7b07e2bbe6c1af7780763d1b9b", 67131030570009008),
-    ("0xb9952be3dc8c62de8a9da206de5b2c63aa43653f", 25107312575229707),
-    ("0x335c0552eb130f3dfbe6efcb4d2895aed1e9938b", 488062498388625176),
-    ("0x320ce8452e013d3c74c1fd31ef8d61748a9d2e6c", 38177994148411401),
-    ("0x9d3724a89423b9d6931a349817a81c7bba463ed4", 174960839773568656),
-    ("0xf9107317b0ff77ed5b7adea15e50514a3564002b", 28763909160826995),
-    ("0x40998d3cd7b03216b3a0d473926a9d634b66c7a4", 2166152956560895),
-    ("0x843dda384784e9b243e2867f8be40e22b583ff40", 21175426535793538),
-    ("0x075019ba7ed50f0f7dc37f49f6c7b204ec9106cb", 1954832916609886),
-    ("0x7df6378d01ebad5d41f76ced301d913d443f2c7f", 17288668157281152),
-    ("0x63a7401bd39218d4f00500c63acd562fc94dceec", 423684128747036060),
-    ("0x6537315610bcd9bb343d163b148301d88c8d6ae5", 24680433527565851),
-    ("0xbcde8cc87a500d087cd44efafb70490572d1bace", 160),
-    ("0x9c15f6f362f4da6b192adb5f1d54aae0a4748c28", 23214342788323321),
-    ("0xd36d6dae59bc99516b14eedd4710fe8452d3c409", 48628052872588870),
-    ("0x4a9c8cf5e21ec072c0a390bf0117aa0702039621", 492600504440480742),
-    ("0x83ea79b9f4cf4860862eabd858a9f82c571ea551", 747441342439128639),
-    ("0xa4c206f4aea1d0bbf1ac4eead8eaee66f97c081f", 25067196038048628),
-    ("0x679820c8efae41962f1697999f824a8b5ed3d3ff", 148081283727752237),
-    ("0x35bbf75e3a9dcd8ae9af485740697d289c6f643c", 54636759711981500),
-    ("0x128b1dec802ddf81681e3d6f113bd83dd852311e", 120001762278105273),
-    ("0x4e14171a451884c44edbe412b03a128d7e473e55", 13717832609374757),
-    ("0x0f6d31e844b168c0a433e22f553893c3d4d4a587", 21481775419144753),
-    ("0x29aff93c997bd45da5bc5eb6d95476be80e35fcd", 29189526501986681),
-    ("0x92c7e1a9a8d73cca2214d2fc291b6f6818a342bc", 2544649579556080),
-    ("0x635c5169468e652dfa1949b5244b79f722bf6fb7", 10166322338682431),
-    ("0x1bbb8bb26e71f414a98d9dc43e20acc7d1dbb481", 19446833756120504),
-    ("0xaa51ff4997ce7ed36e311b54486bd940a60f814b", 18768972437896231),
-    ("0xc65b01e0e2ba49eed974dfa31daa003eea8e4c16", 49142506733031427),
-    ("0x52a38af7d4000a763da8e6164286b59aaf11c51d", 11379899358409359),
-    ("0x1f82d2d48e69e2d46c547c0ceff646797ecaf6dc", 269544381561565131),
-    ("0x6be79f4203ad40772000375b4ab3fefdc608be6a", 5746859736591568),
-    ("0x344f89f76e9c8b09f409cdb6c6b42256c8b7c160", 77918656264853609),
-    ("0xe5a8654631b3729f73ca3503ba09a5d3e11b46da", 34306231245134584),
-    ("0x8ee63cbd6c20235f49fbf08c430d5ba1d889584e", 5582826367133019),
-    ("0xb29fe00cef00bcc11412ff605408eac835d93bce", 27047783781045301),
-    ("0x57d4d5e1b75d4b855adb0701b35b4182846024f2", 77286587449163162),
-    ("0xf486d56cce70c481b3455af901fcc4f03fee8107", 369732894146223645),
-    ("0xb52c1fc731089aa81e9b63df97815fc59a430c06", 25326726652676388),
-    ("0x7225c84e37474738ccfb094093fada5fd5c5f29c", 10854172665106320),
-    ("0x494f556f9b18e4f3f016ba809fb4e5483e1a0155", 18746055534263010),
-    ("0xeea36ad4123db561f4023099c237d20d69febb3f", 51379083153006451),
-    ("0x8b14d68927ccb4e079496ed1afdb1c23e6eabc7b", 13372820463648934),
-    ("0xe3d2283e983d9ec973d1e773358981a7e9c62409", 24929075590575153),
-    ("0xbae060f91a2152a4d2da3a7ca453ebc415934498", 34732802409066526),
-    ("0x880f8df4d7e3ce23db1cd4882684d110337c42f3", 1320994726509607),
-    ("0x5298ae7fff85fd522a5d2a96f41ab83487761eb6", 19058021203905096),
-    ("0xcf5e65df7abd58ed95703d7158afeda42477e8ec", 28947643839095203),
-    ("0xfa31f12bfe9a283bdc4e79e3695a397088da4050", 47394525850185802),
-    ("0xfe5c81b51c7b05bb6aff9965974b2e18177743ee", 13992648757050783),
-    ("0x4fab345549c6c184b8b867a31a122c1bfb2d085a", 23382901467543979),
-    ("0x9fbd26e2006e1d9efad3c222cb9d1ef73209716e", 12752159942140238),
-    ("0x655d259a8db1bcfc17d863c03bcb6d81710b4372", 74285541529530807),
-    ("0x31e75753f353abcf2f083f2a5c31891f51509ba0", 15798175477042741),
-    ("0x0d1bc890b355823943b7be8d73e3df39999fbe4f", 482872132493438),
-    ("0x23257dbb57c69b9334a2f26615dd0e086637964a", 80897892920690387),
-    ("0x289c1a136eeab35fb96fd1a5823ee350ee15cd55", 84308242743507328),
-    ("0x0fcf62edec5e1de0572dd10c2e6ca17a5037f6db", 16769179867207412),
-    ("0x1465b21e4233de13f634387b7330ab76392ebaca", 13333418875595810),
-    ("0xd111077f5b19ce9df42b92b8960a4b145117d43c", 80506763243865133),
-    ("0xe1c688427a6133a051dfd3835e80c8e8dc6d9baa", 2204228322199400286),
-    ("0xa298d4d2c1aa820667ed07dc4520e2cb09326de0", 3097887598438010),
-    ("0xb2551fe9ebec15ce44bb2e73ca0daa585ebeb40e", 25073929956954246),
-    ("0xda898dbfa486eb1223d5ef61b37f5f1424c5a6e8", 500000000000000),
-    ("0xa5b361d167c4f9927b1cc282065228388df45b19", 49032011636785698),
-    ("0x0000000000007f150bd6f54c40a34d7c3d5e9f56", 1),
-    ("0x0ee4e6d826e94d92a24cc4ca2e481773aa724b25", 44201020312454193),
-    ("0x40d80168b6663700b6ae55d71a8c2cf61d0c1225", 237664599411882481),
-    ("0x8065a40f0594285168e51535b4156b02523bfd8e", 9247239991629709),
-    ("0x38988bcff556b5fe5aafcad4f6298b4fa7b89475", 12656482274697895),
-    ("0xb90781ceb500f80a9c101225ed5451449a3aa5bc", 546858952157742),
-    ("0x187676c869ff7b7c72d48a22b469e37e6ccd0250", 59531009888650625),
-    ("0x63ed43dba5c2d9a1d1ffe30ac6b6f6b94e414ca4", 100004035688271397),
-    ("0x5545b118f689ab47da683112bfeb740a93ecce8d", 139334085171621062),
-    ("0x31cb7095a4af5824dbabd7871b95afdc657e16a2", 30759374863690051),
-    ("0x2f3670747f374e4fc0e9de5769a7167937496e19", 16822380797195739),
-    ("0x8705b8f1602d5a0de0790fce1b5a371aaa888826", 22798153274436356),
-    ("0xd073a15d57f958f8098ac87e421a2c9983885ac8", 5939018108663506),
-    ("0x5022f13ee9d464362a7abb083634174933db566f", 416251811526489659),
-    ("0xedd40c76c6cda091395fba06134c1b70f6309a36", 27593426115011002),
-    ("0xcf5f5e11960b2ff16a6af06397596e5fc2bfc89a", 102298330246870449),
-    ("0x7fdd760907a96aebfc7fb2633845cec228509ef2", 35745746846803532),
-    ("0x000006eee6e39015cb523aebdd4d0b1855aba682", 201623902628040733),
-    ("0xd0dd40aa51479b832381fb8403da28be753d4fab", 40719060089193344),
-    ("0xcee1b5df292f5ea23798271f8e1374d911fb4de0", 87254442276122804),
-    ("0x76b2a1c922b9739da4251ebc98d540a40ff8e05e", 327053563057910201),
-    ("0x61b0880f1fe67da38e5fb0817afcb991a27ef450", 46362567824),
-    ("0x6a2e363b31d5fd9556765c8f37c1ddd2cd480fa3", 15039213025221840),
-    ("0x70892d799f350b97106ae8b9ae603bcaf650a943", 80118129976170826),
-    ("0x514d51e4b6c01d56f02458d4d3ca008c4618bf8c", 87199564285844045),
-    ("0x3a35c9d30157b1c18fe72cd44815d639c1b37366", 10877933057239736),
-    ("0x6662a2c6a019aede9ea6046b5fc0b03887fc5e7f", 50166557125130655),
-    ("0x7345e98d015b2f56435080d4158aead8c992b4c7", 10868995372688854),
-    ("0xf714dc96969dc60eb6a74739d9d607294df2b70b", 27361054036828567),
-    ("0xae667ed58c0d9198fc0b9261156d48296c1bb3da", 102648336326560603),
-    ("0x6a21c7cdff24ee5b3e4063f80cb59a78d6945d42", 913820471944436434),
-    ("0x915fdc7b58155c95477e98d1271357242ede7538", 28850265020705425),
-    ("0x2281bb8de864ee1afefc026fb022c8bc2fc9ff3d", 9221395249694972),
-    ("0xd3b1e84a2fa4c9c9cc02cf3538c30fcb71d55e98", 11785201898357842),
-    ("0x9c63e6a4380b2681821cc58e0d5c4a090d082747", 225736853334779930),
-    ("0x39936bb7e8ca24b0edc2cc45a08656e3391eb548", 12453745247436031),
-    ("0x31243dfd425f118dbd32bad4103993edfbe521f4", 43618482481150491),
-    ("0xbd8152fc7f060c6555809f6eada3ce64cba4d723", 144204152075008763),
-    ("0x43e403ab4d1f9d0d6e61c44e9c389c6c5e7a8e29", 38389396392081351),
-    ("0x07b847a9148cb84537fb69800ec1c5ee682373cc", 7355763623946806),
-    ("0x324caeb861f9734572a2c7bc48022d1c57742fce", 71011281998687823),
-    ("0x37aa15e99aaa83107d8c912fd29699c60da5bf82", 6829834538501292),
-    ("0xa992375dc77f77886b028bfadd45c2945f353da9", 31971521985739660),
-    ("0x6965f17d620ef37f603a89f18bb32e8ac85e47ca", 28804278270810759),
-    ("0xb67794f4b0e001ee8341744ae96595e997ada9dc", 8003201466021336880),
-    ("0x37343fcdb16cae9eba2ac0017a60b653983653b4", 30762737840675208),
-    ("0x59e45131e64bf57e71fe8c86421664337f28e9ab", 28843399006991062),
-    ("0x941c7609b8e1cd890c970070bace790f97721be2", 6313050701195886),
-    ("0xa7c865d8dc45f67eebea6d994cd95a765cdd802d", 755273125119629008),
-    ("0x6ef2f8d260ee634e1176782006127230e841ea64", 17571203741531039),
-    ("0x32c909157c5889ed6890da21055e4cea8ea91a1c", 90531911826300825),
-    ("0x5d2ecf419cc2bb3875ed4a1da69fa597469eabc3", 31252463627151597),
-    ("0x4e695843b8057987f2d432a7a89bb1b0570beb79", 40699130038258907),
-    ("0xf6145ffabc0c9474b84ed4fbc5f5f0b0e4022d59", 642958647860726118),
-    ("0x394c82976a57c4309138c072149ee57aea16560b", 55240572129686579),
-    ("0xa5fe4b4c9bd04ce7d79fc3cd5dc0d2f9b6b9c9a6", 12221022552519625),
-    ("0x5dca5555bcc2b57df97ee9368e001c3e7cdd2959", 14498962590293628),
-    ("0x32fef0524259f332c216c461e4ee4067bdac11d8", 140011316057715110),
-    ("0x0bf8e20be9313469fd41be142596e015c363b36d", 142823190222658145),
-    ("0x68da60f556c325586d31a1c8f5ff67e8e38a9900", 204865236890469560),
-    ("0xf06a4cbe42362a55b535bf14a129f96fff087ae5", 81202768596298300),
-    ("0xcaf43b095c2ad696f1a460edc6b63ccba213b890", 38824430115915735),
-    ("0x7e70db89d673f6cdc8a3810fe9bd851fd6b7652c", 5949640899926620),
-    ("0x8767c5124e80d6528bd3d46067f0b68ab8e2dcb3", 11729078524888308),
-    ("0xbafea82eb6886b43b3b4fc0d162902dcf944ed09", 8784073067272701),
-    ("0x2dee20e1c217da40086fc2427567307ea9353b45", 13716284223522872),
-    ("0xe50e18e7310e7dbf46c4ad924be6a03b3ccb034a", 10420592545075109),
-    ("0xd70a0b9e5f1717d2bb09a4aacea03fe4630133e9", 13994832132361712),
-    ("0x85ce0755f672fea5f67b8157e446d106f6f92465", 7426861641051575),
-    ("0x668d82fefd4aebf29ceccb8161a138a259f18696", 81324368271940240),
-    ("0x832cdf3c7fb0c38f95c8119b2b887d2db65daa9e", 41049229365936804),
-    ("0xafaf810821d9dde8baf2a2e504b0e6e5e0a3d2f1", 187563200473986353),
-    ("0x3addd5a240c77f7556f407de03e52c33ff4c4304", 11122375689830502),
-    ("0xc80abbc38c1deb2ed7ce2ae2b58400878e88f9ac", 43078478600553389),
-    ("0x02272654ca4fc2d4915d4ea63e04b9253a9ca6a2", 27557161790725973),
-    ("0x8f7b84ccbb4ac207a0063365b019d16c8dd2797b", 27000099862178396),
-    ("0xe79235caff6930244a09e0fd9b2fbfdf9781dbaa", 8371923340991465),
-    ("0xb8851ccce2a9e2e1b31336a32d67a7e694b92e03", 47261915775321184),
-    ("0x8f36258b7a8da6cf23eb6ff3f73f8216b6614d3c", 53320745286651059),
-    ("0x0ec0ac8696ad75943f9b51c49553ed3d8c786a3f", 10548163399631585),
-    ("0x64c0f51a9755a4638e1e890d1aafb04bb8e3cee7", 5903655920469096),
-    ("0x6cf3c0bb276292ebd829ffac229fe350821eaac6", 26968901421832949),
-    ("0xf74e28289476a5ac5cfe2885d367fdb1fcb7a7bd", 74616168384650956),
-    ("0xa670ebdaaa258311a7c33a5bf795f07b97c83430", 6350143879924276),
-    ("0x5e35fd90ae2978334b6672536e751f30aff2f4d9", 66824085494166741),
-    ("0x071e72900cb375114288dee22a23169950901bf4", 9968004790925594),
-    ("0x8ca8d2cbc07edf6512cada0d2573c8f4802a1989", 2005827959579953247),
-    ("0x09be9ab0eb61327d26904bae69efdc6633d5eec8", 26796283729890939),
-    ("0x72e3c536bd62ba9544c4f26218232aa2706058ae", 19145124988098644),
-    ("0x34ecfca1dd05f10bdfb2a6ea4678388a00791aef", 20787711204515188),
-    ("0x8be2828b1094fd6b9a4f90223046acad28e86e55", 2002095112217791277),
-    ("0x7c5abfe9aaee17094983e925ef8f5a26b2fa2afe", 11842150454473937),
-    ("0x7546ff64cc71df6dac61c865bea298da152eed87", 46876239453433534),
-    ("0x5c780a9b51d8358b230c4c92342cb57b1bcf1900", 40367457994061438),
-    ("0x4df10bf356e151142b4a9e08d6b5860c5a01044f", 28483920702508498),
-    ("0x04ba5167c8738ab997c991e195a2403977e3679a", 7307288703552995),
-    ("0xa902f0dd640ce8d96d5d6bdbee249b6969539c97", 4658537450385289),
-    ("0x6046d412b45dace6c963c7c3c892ad951ec97e57", 18712095212918941),
-    ("0xeb716f1aa892d5a849c5eb3b5f3fb82b747c865c", 11282585860730722),
-    ("0x1c9f32ea53f26da70e5936709a09c2d6b01bcebd", 3237363249954527132),
-    ("0xea8765488eeb718f44234cce744d277a98a96dc5", 506051540236781574),
-    ("0xc17caad48069eabfc58b89bb62d94913bc532e65", 315605082704976973),
-    ("0xec578146b22d7ef5f6e5c69fdde00bdaa5f0b33b", 272515418070382225),
-    ("0x5f3e1bf780ca86a7ffa3428ce571d4a6d531575d", 30802828608053856),
-    ("0xe179b1f58705f218f739e118079907a07a6072c8", 132366843788936221),
-    ("0xb48f8022dd9f3fc4c7db40d379a3241167853741", 80882449491822072),
-    ("0x4ddd87d956375aad8f239d955d5580fa6aa1f8b3", 31793414480044565),
-    ("0x586f1eb9a2007f4abea12398b8854bcdf897ca59", 67636365802375126),
-    ("0x93682caf7148a7bc8911437122265ceb2832ca1e", 126486285672099041),
-    ("0xf5a6e01a681aac715653fb64c7a3d6113768d816", 45083380400824872),
-    ("0x86515e78e4300f46af86d9b204170d51aee6ad91", 32080909072996084),
-    ("0xae8b31e6236bec753d01922bd7b6773287490c79", 52830785822433182),
-    ("0x49155bdaf5e05bf79b3338fce488f84a466d424e", 766343152861685336),
-    ("0x1c3ac1eff28301c1490d329292c01dcf9082576a", 540845727548278680),
-    ("0x847215ec9cf9b2b74bd0b430a556da7eb25f6307", 27050066504831503),
-    ("0x6d9c3ed804fafc846bd00e3c0c30648029ebd80d", 21683575960445740),
-    ("0x74fc308e9d275c589d5537dec29c9949fed8093b", 5717001476341393950),
-    ("0x960322065518ef4e43fa53d3b9735a677071c8b7", 23721201720346277),
-    ("0x0ddbb2076a2f79b349b2692e2abade0d08b8cf67", 34586863179843978),
-    ("0x7450b5314370e420c770d61c00a462cea805dfc4", 7772061496783377),
-    ("0xe59bda0fb4856a0bb81c15f20e48e362aaa6a9ff", 27275270046552404),
-    ("0xecb83a4432ed3b6e1412e7681673dfe12facab45", 16634037298732846),
-    ("0x6b40ada4c4937758d3117076a4220d940b2b4745", 395215251622544800),
-    ("0x1cbd45ba9d6efbea238ffdc265b701dd1a4d52e5", 24723089926483391),
-    ("0x059839b5a2727776cc8f94d659dab1bf3a2f6081", 65020086146629513),
-    ("0x55040be8471b5dab774dfb6911a5e38ee085ae34", 5856500887909929),
-    ("0x1dd57e5db5a756003039ff41e79334df22168d4a", 187691231492582342),
-    ("0x6b87d62cb77e8eb4f0531619356bbc68550063a5", 11781172953567738),
-    ("0x192be5dd81b8bfdc253ea10e4f51bf9c8ea2c08b", 27349370094672474),
-    ("0xdbbe3b0943bc52a6e9093d95887108df2de71588", 14642451145367834),
-    ("0xa451fd5fcc0d389e0c27ff22845c0e17153f7dc8", 102051482177179332),
-    ("0xdfd2a4ac3c206836244d6f43b69c8231b99d7275", 28014625838456007),
-    ("0x2ee66b4d036376e86cef87f450f87f37781fab88", 26428368977368023),
-    ("0x237ec64cc27196f6a4c16abdbb558b4c518400c2", 40430443087792483),
-    ("0x9186ddcf3b5243984a9a94e4dfa44f70e087048c", 9318583979536844),
-    ("0x0c7bf0f39e32105359e2b6bed91d7c3538c39f3c", 7099559070991867),
-    ("0x26f43e511ac2045cddf15332081f297ac539641c", 12166843759932426),
-    ("0xe489098f4fab641f09f48debe90321ec5a8567ff", 31354855667294010),
-    ("0x89b8992aec28d34ed8579fde2b10b872dd26e617", 1749345688347203),
-    ("0xac2cff4eb116a4d492c3bcfc1c629c4f1a24909f", 61030000554848184),
-    ("0x4fb7d1ebad509ddf63fe88ef6e0509950a1c7cae", 25693935643844069),
-    ("0x7f36fdb4cd6114bad86941dade3e38a46bebcbbe", 10422670578048856),
-    ("0x564fdb453d4a07fa61356f6bf4bccefcd6497bda", 12331897066729622),
-    ("0x3ea4c7dc4aabb87267073729ff4d1b529469931c", 139027834623869857),
-    ("0x798df22c5b481562d6104c38e2e62aac631f159a", 9207697207390703),
-    ("0x5d31a87df3d096be2f2b397f475d077b55a71d93", 30589868398592140),
-    ("0x3a60db96add6e41864b23afed27a050ff44cdf60", 52155394083362862),
-    ("0xe87faa61f500a65366e71aaeb2688e414ff2b427", 78754847944838432),
-    ("0x9bb2b10e005d3f319caffa898c56b27c455fee38", 636716554751756221),
-    ("0x725147c2bfa26fca5984b5ac1f7abf8719dc795d", 24880027990855716),
-    ("0xa48bda48cdcbdd257fa55b7b7985a1ba61d9e1f3", 32240369230480385),
-    ("0xce103add66435dd59f4e04645d2ffc663d874d5d", 14134543479530313),
-    ("0x65ab6bb9e3c14d6ac221a17fe66f01e3ce6566d4", 101471454072789076),
-    ("0x7de140657de51196257db7d296bb5005ef114d73", 168109733966672082),
-    ("0x3b21b13b99a903c37b62001d63864cb405f103bf", 69011122681435094),
-    ("0x5972bf72ace697e6ada7f8a80e9ba1dab4ba3b77", 75673015902108881),
-    ("0x3f66153d19e126da59ff24ee0ba238ef202e9eb1", 25890291707663477),
-    ("0x1e5d6cfb3eeac9c21065296927bf4d155b3d64c8", 97188173352286893),
-    ("0xad03ba0db46620f77489393a7e62e598cd7ea988", 248035786279656180),
-    ("0xcbadcc027d80d68771b915e557bf4b6ed67da678", 53398515899419519),
-    ("0xe88f42474046183f2367066cd940e74ded8085fe", 25824852381094905),
-    ("0x9721a3bb565a30e58aa06147d4450e28406f17f5", 74642137334109094),
-    ("0x68a6139688d905c3d24950cb8ba5adbd9c61eff3", 14510829865576355),
-    ("0x07cabf2e060d29dc56e570c6eaf1290eef36bf8a", 26652034481238355),
-    ("0xeb27ddd144bcd1e1f6e8eea3046d4cc7ef0e31e1", 28044096998444783),
-    ("0xb7c7f4c74d34c73e910c702bf68f3b9c887483ec", 76132565412451564),
-    ("0x55804f4c986c8198a0808adf90dfcce1910ba7bc", 183484248425108957),
-    ("0x89967857dd60633cd4e34c597a5e2ccc6da58b7d", 98726446534045507),
-    ("0xd79a1d430d4546ac2f0d9cd4169a560e3cc35c3b", 70657903929920664),
-    ("0x7f3d1a9da43233f889b62e312c66cec367a80583", 5672105363668692),
-    ("0x2bcb9b6927333d6c078500a7c6e546b209eca3ff", 33840644813826657),
-    ("0x830862f98399520f351273b12fd3c622a226bdfe", 10686204670315605),
-    ("0x5fc8daba8401cb6cc3ba3703beb3ea37c1e72522", 5325162025328535),
-    ("0xd878a0a545dcc7751caf6d796c0267c202a957db", 27777868085445496),
-    ("0x2d42213d8e6328cff4644921b54f121ec6b5ec62", 13374354800917877),
-    ("0x236c2b432044da8c93d366462fb140239dc17d25", 13962292265531632),
-    ("0x9fa301bbf7f397740a225cf49541b32217210b89", 11174815330779631),
-    ("0x5888b4f0a087a32182ce1a726102a830617338e9", 2553438548922858),
-    ("0xd49daa7d2837b395b4d346f6378d9e9c18206c1c", 27256299352087748),
-    ("0x1b75c72d84898b37ade13fcb403cc7984a3d7aa2", 8278533131783840),
-    ("0xdcbc6db42a9e897e4ed1940ce055ddf450f4ae09", 6701610958562218),
-    ("0x8fb13a76c33f6bb51c076146e6777dc364ddee30", 299242242810754577),
-    ("0xb7787193c514b3c6f54da6a40b71b063477d10b4", 33037292725233204),
-    ("0x7770ac31eb402899abe0c93e3e5acd66bef8a1a6", 33627615377954465),
-    ("0x835f2d0f509737a10f56a4ccd6169d406840774c", 2648523942697456),
-    ("0x86656de4018f5c0734bb375aaee65cded76fefcf", 185133237557352297),
-    ("0x3ef09778eb097d6a48c62c7262d040d5eebbbc6d", 26366225920517704),
-    ("0x7ab29ddf2c2493e02457ad1049c77f1cfdb275b6", 26846309617212261),
-    ("0x329c997ee2d34dbce26923b9e4d5f79433fecec2", 13128346941213926),
-    ("0x666751d89543da35c97403cefbb7abb51fcb8005", 53234755440049821),
-    ("0x024df1eec6991ed17a6ee4d860531b06c6d70759", 71808622136290749),
-    ("0x41442c49219910ab09fe96e76fca852f6be3a802", 26703135934708434),
-    ("0xcc30099a57decbfc55a9d19336c762b7744c2c1f", 8943530037104767),
-    ("0x708f998f460ef527514456565860dd5de3155472", 26891111515481746),
-    ("0xb86b7e3cd923c9d27673ac61c1c7faebc4378e45", 5862102447981140),
-    ("0xf86325ff271cd3ad9020404bf9fe176b1bed95f7", 510579866137813391),
-    ("0xf5fa6278242b4a5410e032ca44476b37702f3bd8", 27091637655935288),
-    ("0xeee847b5e3a0a9f96a136fce48002eec996e1480", 116260522723714727),
-    ("0x38a4d889a1979133fbc1d58f970f0953e3715c26", 27631821442081774),
-    ("0xe126d2d8b6ad713cc6e29254110f6b6af394758e", 14417369108892955),
-    ("0x7ba7ba1133a060b18c59775c8ec9a8b9e9fe4aa8", 8334200242527519),
-    ("0x4c795bd3bed9d6e747e73fc41fe97db49198d717", 21650914367397560),
-    ("0x577ee133e45ad2a2cdab82f55f38cbfd8c0f6b53", 98170126917768526),
-    ("0x3b8cb6de8a4692ad81de9c5f102fa27a14d5291e", 27861628078774612),
-    ("0x6c40a42b24c82d10bf0fc65decb9ae6d73346d9f", 8494984502424950),
-    ("0x7ba4ba2b7a930e487e3f0a1d66339d56aadd311b", 7355439335115419),
-    ("0x55dc2cde9388102c7c3dd58884cfa67540f7465c", 67643376842319746),
-    ("0xba58a414211d2230e3c17e562dfef43d1dbedebe", 37758021698744956),
-    ("0x5bc8aa2f6f8ef423e56dbb4cc617f5931bfdbb7e", 529133904844822718),
-    ("0x93e76e36b311faf57f34ce14a71b61a9ee35562d", 70103155220736395),
-    ("0xb3fd692d4843f23392f309bde17e2b1c4ee87fda", 16829327515940805),
-    ("0x105f6e9136c6851991300c546a36c4678fdbac53", 8394070120919048),
-    ("0x6c570e193a86b1f8dbc5b03d382233f134f30c11", 66040198362995125),
-    ("0xfc11ff3052d1cd0db3f0fbaea71e631a2527c830", 117006840513484292),
-    ("0xed97361f590186881e9f415435d22dbcc2be173a", 131004903966578642),
-    ("0x05eaf3564c72506ac18981890f1b1e9873a43a4e", 759203991440156),
-    ("0x5fed415386f0fcf91bc021711f7b42f942413261", 21254759199026471),
-    ("0xf9605d8c4c987d7cb32d0d11fbcb8eeeb1b22d5d", 208594518843358225),
-    ("0xef58868d460c4d5784406960e1ef8aae1512be55", 25062975552088270),
-    ("0x726176dc6877744e9bd9e7707e458ac8c35eef46", 8337865890396028),
-    ("0xcdfa6ce596caa0c6714b48bf88a732443f247955", 134822780259698018),
-    ("0x0975c2e82af9eb16c68f682fe50e864ae557de72", 27679159089872986),
-    ("0x3e73b363036bddfedb2e026fbbf9bc77f76994b2", 13098275120310919),
-    ("0xb9b237e7d96c6135e3df3ca4804945a8e56ff46c", 26353559155661141),
-    ("0xc5eeb9ce22c76738dd3f13a75e92ca663ffd436c", 41351281312161904),
-    ("0x23cb1d6a25d68205f6a3e3639b9b4f7abab365e7", 18986877798338285),
-    ("0x8358fc2dd62c00c1a9cd0fcb577309a4cead2471", 137240167165417478),
-    ("0xb150c9958f9e6bf5897d08f5872363c9499565db", 2984899803221506),
-    ("0xd3b0c47ef1e750650d7182424a7e0b7c443fe36c", 30667558815128877),
-    ("0xb4b96c97141f8ec69b510ab00a8ab0f93a95f16c", 26004150219332952),
-    ("0x03fbebc9545bfda1850380654b6488ca6a6b01e8", 38326531381262310),
-    ("0xaad62dca2dd1be6d0a66308c22a480de659d614f", 27330201716027906),
-    ("0xff142ef8826b4e683d32ae6a40cb82051bbc07d5", 91987000000000000),
-    ("0x91cb962347171c4459a1d6a1e87074da9da417a4", 34838700079808577),
-    ("0xa2fa6144168751d116336b58c5288feaf8bb12c1", 24026340138369149),
-    ("0x007e363726dfe9491bb48a2c0b91aa1f1d9b43a9", 62261531397978635),
-    ("0x0485a925b92f64d195b5f65d0b4c3b72004e98a4", 749380831732276974),
-    ("0x6fa3659a15e9264e43ec67fe4bb5d31d38109a42", 42937331630154165),
-    ("0xdf15ffcedeb8595395e5af55088c70d69a002158", 19472814053927818),
-    ("0x0cc5a223e1cf7b11e5c2eecc92bfd6120904d235", 8899268736921326),
-    ("0x475abc90c3cab0d1e0be8411e5df5ab5cef76017", 1715673855254910),
-    ("0x73e459aa16acddfbc7cb9c4992973129dd12318f", 198808798573813594),
-    ("0x7ab417afd459335213601f160dda165b789b0193", 80180909485517670),
-    ("0xf43c75b8af6cbe076918069ba5d5fc9d1afac954", 61156824783824443),
-    ("0x87d6bdf56d2a247829559d008826e1073e68201f", 25532827406236965),
-    ("0x3f2986549e4a6d8486b64bee434a3978c3e5a1bc", 4099477711956579520),
-    ("0xff61818ddb008b4f63a48c3ab393b9e2066e343d", 24976391510056387),
-    ("0x6d3cd864271864f2f32a9a58631f22b384bf9a17", 44157795025706712),
-    ("0x6c92ff3b02dc8b67576000b7cad5949075781d59", 186431733333977338),
-    ("0x5b191f5a2b4a867c4ed71858daccc51fc59c69c0", 91989784043138584),
-    ("0xd9944f2200ef4fddcf78b6ce3156f2bce5d11935", 34070224936963239),
-    ("0xa0d802ef2c5dd652fa3f14e950887317e35c87a2", 88133875036243498),
-    ("0x53eca40c8e24009ec3f9b1cd6249dd8568235f08", 51308421025294371),
-    ("0x5dfc833b3e853f3e0d8d7b5bb2d2a66dd2b9d061", 26270608160578082),
-    ("0x0e3bfe2a138a55a49a4cc7e0a956b747a46cba43", 10522844869173567),
-    ("0x16d15aad232573f1a8239e85101245f6b7fefca6", 25610526328682559),
-    ("0xca241c81f27161c753fd11dded3b8738e3d1b62e", 51116724241223790),
-    ("0xe18b44104a984ddbf9a71fb67be9d27c5281d359", 629188826638649709),
-    ("0x4b11f86a69ed65e8e2266d936c55dc66f43da055", 4840237744132888150),
-    ("0x8b6c42ce7b17839c101a49b66d2b3248033fabaf", 11415766560863658),
-    ("0xb965d68052b849b29fe25497c51659bf9678bc9b", 14698385698699004),
-    ("0xa93c50f5b351d5c961fc7b147a01f8068b272712", 31720199336676432),
-    ("0x08628bd9330185d93fc4215eaf8320bcf52d3a80", 23480000000000000),
-    ("0x089a180a1fdf7bef50d1ba45b5456e14f6e44255", 53134690304007609),
-    ("0x7c3c0a890fd34f6ab5cfaa98a4a698b0ff1e26f0", 9108305538646985),
-    ("0xc240ab324139d8083dab74d4394702995cc04317", 10663252792308359),
-    ("0xbc159b71c296c21a1895a8ddf0aa45969c5f17c2", 35481498089045248),
-    ("0x64e5624790084f8fb0e0cdf3e400828f2b68eae3", 10850031189661099),
-    ("0xfeedfa911d4940b0bd117b4b1d8b3e5508a9355a", 52715011151780107),
-    ("0x1ef8a0024942433871ba035bf14e4015e7583a77", 95440804804758579),
-    ("0x28ed88c5f72c4e6dfc704f4cc71a4b7756bc4dbc", 374726137954977602),
-    ("0x3985f46e0c518f5de26938c073280e6b37307054", 251412782252834772),
-    ("0xb57b13b4fe511c27ea5a658e938bff7dd43f6c53", 28927417716914293),
-    ("0x0345d0b280338bcfcff55ea5ddfee5c2949e5e7e", 11967349019978592),
-    ("0xc8326db2f1f0a6b53f81d60885f65ef39bc10a0e", 13920506138940059),
-    ("0xb0899bd165e5c15a69226a7aa6d7556dabc3f8f4", 48410503579427929),
-    ("0xd0b936e2a1f4cd31677cd1e2c20253e7de655777", 136035626157964587),
-    ("0x8b36637032cfc4e7e4b43eff37f1cbe13452199c", 20175647141743058),
-    ("0x4a28eda2b125525d0d6addc06852d94da6c35103", 7397478952712495),
-    ("0xa41451a4073cdeca52a656fa49c56ef924e33f6b", 113262930897040686),
-    ("0xa79e9ed62c4f68d9401519d5bc8bdd015b2f570d", 46092656761523642),
-    ("0xe9bd415d5d543b5d4fe12ac2baa52cd9ca026f46", 35919955208720497),
-    ("0x9c8fb1027c3ea0f9e818e532473c8b51329b632d", 7957592895200251),
-    ("0xb1275e42f8620a07ca2196fc153fedf764b6b673", 20191497774902144),
-    ("0xd9dc877ca121a9a67a4bb62959204d575975fce3", 27062964509443317),
-    ("0x3b23a09f73519daf6b8ffe7c8f429079e46ee27b", 45512181562510505),
-    ("0x6a0a139f4962b33359a7867f40a8e29244aaac29", 179732047491268551),
-    ("0x79a71b809a5211e5d54380a484241b9e37375f71", 159295372012014222),
-    ("0x67e83a5fadc963e78b82ef342358a02d089aaea0", 23573413480125193),
-    ("0xf99e753144afdce048e33f50395dbb43dd84aeca", 19517270414457926),
-    ("0xb0823fffac86c709c658db3f8765e9da8c7d9647", 279884450493836296),
-    ("0xd93caa2eddd5e7a671da4083ccdba066cfa69442", 43270341300692316),
-    ("0x809cecdac1d8992ccdc237803a400e6c688e57c5", 14911284436080893),
-    ("0x2030f32bc08f51c91f5c0f6b3a906fc188da520c", 26334542903103135),
-    ("0x999560e0ab80adee192f0e34c1a91dc7d4b06e79", 24149025971444817),
-    ("0x058674f2586d3edb05db65a7b85da67aeb83ce39", 53370118631155150),
-    ("0x3fc8199df24224d1b14f30e036c4f866d0bfc9fa", 22551424823427812),
-    ("0xe0fff9c0c1a54c9e606ad5366e8f00048c5b6257", 224120878813073115),
-    ("0x182fdb27d6aae88388ad38a2d7f0d1ac87a981dd", 12285948811790495),
-    ("0xb41167f0729aa2b8adb237474a134659e8f279e7", 16334883425390169),
-    ("0xbfdcaa7a7ba3962a9b31923819f2c6f5e324bfe0", 388383439398893078),
-    ("0x6d994a9199c1400263472ae6515119b4ccb2a3c2", 31427961401231299),
-    ("0xa4377495c7cf9e8772a4dec1b905b822028c641e", 8989549201403533),
-    ("0x9428e55418755b2f902d3b1f898a871ab5634182", 242215437364998642),
-    ("0x3e7f3db02662bfad0b9b09044488c5154b8f08aa", 272129070988112806),
-    ("0xd29e339fedd05c5d373db0e01ac6833d38bc1c29", 11330154068198578),
-    ("0x75815cbdf74be8d5117b33f655434366340a6f93", 19951829224251313),
-    ("0x380e1c91c7d7e205e51ba305e7d45a3ea92d1f5e", 210271386316250460),
-    ("0x056c957c5875c3badc4a73526806a644a35ce50d", 65608979082311266),
-    ("0x119bf9938f3b64af351d347aec57aa0c2592de5a", 22510383559647648),
-    ("0x38c6b7386abf1dc913322defcc9807cbecfa80c6", 44300423283306305),
-    ("0xeaad37adf83ac571556553e4a2a401ea93cfdb52", 119458646886966328),
-    ("0x8ac511db64b6cc839718ecd3a6c3ef4acd8afb03", 131305471378659259),
-    ("0x44c59fe5b163232974e07cca5a488a7d323a1200", 67283250701445333),
-    ("0x6c88ad01aef7088ec0f8b2217c63dadc8f136749", 25562360259215549),
-    ("0x30a9925705190e5b298f94b3f3bad4589e241a4e", 139134692131408756),
-    ("0x15e5297f5c77753bade219848ccbedf0f7b69060", 53893795067767281),
-    ("0xdc802badcdbc63dd535dc0c210942a7f7c73fa87", 27285908727195379),
-    ("0xbd76b49ba3f3a9cc9aa419b0225624ee4c4d18f2", 6494826544442926),
-    ("0xa8162e67b831a06df36943ff0221b4dc52ddbca0", 21610851530996925),
-    ("0x94327b1b3d37fcce057bf332ae791f41a0fa3f2e", 10363968084932601),
-    ("0x89d11c7c7bd8699ac6e089c79b2faac66509ccbf", 32367702007653861),
-    ("0xdf4da6201a64410268590067333d51faf515448d", 5135381772371295),
-    ("0x49c3feafddaefc3bed06f4ff87ce86610c2c1076", 100824085805074715),
-    ("0x4d36a8e5e11a1338ebe2935df5dd07e07615cb3d", 270660163590179263),
-    ("0x0e9da1e09e5521604b758cec95e42d8468968bc9", 18450970156362614),
-    ("0x29f777cf8a97895d20098c250cadb8270cc12355", 14255630634178395),
-    ("0x368d16e5b0df0b33312541ba2c6b3f0a453b6fbf", 13278453634936795),
-    ("0x290789aed2d6fae51191bce9cce498d0c336dd89", 35620948558390411),
-    ("0x0855ac154a804effe24b7ba4cabe5dd369425a89", 44146031332567697),
-    ("0x45486718baa0d0e59832b996477201ce75168220", 20543468144165608),
-    ("0x25ee148c4243af994a1ea0efa1948c4e0489a957", 7077482849392914),
-    ("0x10395903bfbf4ef877e43f8e6a56591d9cd1a201", 536651414661751185),
-    ("0x7d83a1dce7065ff788ae889ba37fd9727accf65a", 8541122586402230),
-    ("0xab8f6dfb422b3272e0cecbae391a56339883be8b", 19353918367932190),
-    ("0x6e01c987ad157ff0e2ae0a31e4eecb3a16535dec", 35775007785173773),
-    ("0x62cfb1876755f124813efdd6f4f43b12236179c3", 10660091651073491),
-    ("0xd8765750a0297a213776d18be28c666fb0fc4b31", 20913434934281328),
-    ("0x58a7c99b91b4402b6ddb6229caaa097cb4aed93e", 5939750841240171),
-    ("0xc7c99f4a3008021aa5af1ef79f8110cc9b6a1bf3", 94466690088923368),
-    ("0xfd97b288ddd0012bd95ffed61b16d1c80ef4e6c3", 7861162659459123),
-    ("0x43b64d5f06101ba3d039774637f5c370572672f7", 11541602712795447),
-    ("0x0465b0e661200362a1130de7f9e1256bc61cf5a5", 23829428257027697),
-    ("0x32b332be60a3dd69387329890467e5703f9bb50b", 64354613763563888),
-    ("0xb5eddff4b730b6a266fa4ca869277a02a6d73ffe", 11399878315851686),
-    ("0x0f02253f52dcc313d77b0e78c44e4d9a3503b50c", 24542612981728947),
-    ("0x89323c85b57f9b008656ee7cf6fb5ad74e9e03c7", 14401338095407110),
-    ("0x6d11937490234176e6229c4b80b27fedf237465f", 263912599832212266),
-    ("0xb4264e181207e2e701f72331e0998c38e04c8512", 23611801961980388),
-    ("0xc69e8275592f10a7ebef418f8743a12e645953f0", 10237276075955902),
-    ("0x712e43ed13844a634a21018ae4e41512ce392ec7", 16429363150212696),
-    ("0x9982af3ba98a6e178c6a2ccf2ca464df39a3d4d1", 17832586184627203),
-    ("0xd415b349226cbf2905ec596695f5a6907a308b54", 5240329453099341),
-    ("0xaaa3f05f25eed87ee3a268f4582ec914e6245577", 81680507565947435),
-    ("0xfbc21ad837deb3cb0ab888f8e20bdbba14f87a1a", 8750017415592194),
-    ("0x6be0030a28630c8f39c751189a066cc2495214cc", 18942215285353905),
-    ("0xbee03cb4c0bda1724ac7ea52ea34fc60fb8a5543", 72099157767172868),
-    ("0x3b2b37b7ee790e30c5ae014ea607bd7e64e825d4", 28928708470915546),
-    ("0xa66522d79f572fd5c4245b5f18d994027b9994fa", 31114654128226621),
-    ("0x73073a915f8a582b061091368486feca640552ba", 22836828963251447),
-    ("0x9ba28e4ef3c6aa7eb3adcc0f205d336f977eb823", 1068531937381417980),
-    ("0x08bf056c728966c8d6d3f2acb0ad4be521ed0631", 34976687408575740),
-    ("0xc85f1ae611343ea0e19ca191adbd387204aea321", 695843211377341571),
-    ("0x521319fc66afb0959cd53e62e4c945b710a7bfb2", 34139452815523456),
-    ("0xf84cc4ed45f00c98e1739fd31f7533cafc249544", 26174816276488537),
-    ("0x9a6b6462ab6f70e5a3c87c71c7f7922e24b04654", 5591819235842485),
-    ("0x8912701389daebf890c95d26e475595cd4bc7814", 117805272335828967),
-    ("0xc62785b58724d744b843e479d76d47897e61ca7e", 100098502560093632),
-    ("0x50b56579482f42f4625cf68b46f91444498caaaa", 4963226154952160),
-    ("0xd4588f95fdf89e5c2d5e83d89ecc5c1aa5309c3c", 154005684011996419),
-    ("0x084ba866e2a6c97ae494ead8fee755c41e325c01", 47918090478361453),
-    ("0xabe06a6724deab150b98980476b7a4dde020df61", 24398288558405565),
-    ("0xab4382aadae5bf4b37b90b9dc07fc72d52e2a28a", 19168888649255998),
-    ("0x2f74262c865f5cf6bd83d6f4d3f5034529f6dcd2", 19075946896610451),
-    ("0xc8281f082f6b18d3daf7c4940f126b60a19f138d", 22903733066370197),
-    ("0x66ddba49fd43f1ede7f4ebeaedbbeba180a58629", 18214835434498151),
-    ("0xa5c708da07d1d8e2fdb3378a776ad050639228bc", 5798426569482062),
-    ("0x1c973c0ce5783ada52ed856af83f0436cfb8b89d", 462879971849335603),
-    ("0xd7fcd7c92d184c2c3d84721df201f9bc95b32f4e", 12554813387796945),
-    ("0x355c3ffc1714e29a6ac56cbda2f2ae932c9d4652", 57453837194995709),
-    ("0x9b1e37be2659fcf010cadf27cc0592daf60f2b86", 16732432382489098),
-    ("0x3df5e4da4b80c6b24aa3575d3a6ede5dc13ffd38", 47066146850867061),
-    ("0xf927807021280a74c1c0a3e40b31db26fb022742", 3259608701532860),
-    ("0xaa9170486ec1b1a27ad4c82a5d50f7b9c7c3086f", 1128679767377040227),
-    ("0x41339d9825963515e5705df8d3b0ea98105ebb1c", 11140066433122839589),
-    ("0x41dc360daa78c6677cf90b35fcbcf5cd559cafce", 3293157518832396),
-    ("0xdad1923909b639ea7a44102956c7358b134cdbda", 21183976265947626),
-    ("0x2c2f3d8e6ec64ec0d0c60d2c3a91a32f05f6e04d", 21800181658327310),
-    ("0x71ac145c930c28daa73cbb9d99171b1191ad947d", 78592928148341450),
-    ("0xacc0d4dd91d1d472ffb7942dbb93f34809c6f482", 6882901060038775),
-    ("0x190746c3c80d5f9f161cd6ea355e7a3aff8c8e10", 12266822988169867),
-    ("0x4b927edd059d99f9ef103ad93b50dde4421d27a2", 20321300748758305),
-    ("0xdd4bc51496dc93a0c47008e820e0d80745476f22", 853104587437328416),
-    ("0x511f69944b4e249ed8929aec23376b904764b157", 48118065372389106),
-    ("0x99dcfb0e41bef20dc9661905d4abbd92267095ee", 5087772875631834),
-    ("0x332d726b98f69cf41aa487951915d8110edad86c", 11605186990674462),
-    ("0x2a28f5b0b063d1da41e8b3d0e0ab3b5cbb3e7c63", 87824763807573826),
-    ("0x984c5d268b220784e87fbe8edbb5c6b9f7ba9fc4", 44170394085456328),
-    ("0x31852784d2066618d35d2bb2a021b334a9d7f002", 71709490597995066),
-    ("0x438ecea947dee485feb3144df2a1ade22ca73325", 10865525980159593),
-    ("0x31e99699bccde902afc7c4b6b23bb322b8459d22", 44318420926357162),
-    ("0x691a28c3dbbd21b488140cfb047151b4b6b51a9b", 554215336934862),
-    ("0xe12cc54441e96ab74815d72724ac8b9c5306e6fc", 42534975232115807),
-    ("0x9106c0a3be4cd41ef840843bf01a1a7ae8190adb", 8223628969604898),
-    ("0xc4bc22e0800ec508c610e40c3724882dda01282d", 2259453588616679),
-    ("0x8af1d8a65628bab0c0e30a4e76931a9755d01b09", 119019964107448033),
-    ("0x62dbf7cd748015bd762e04edc0941c00a77cca3e", 21580580260370909),
-    ("0x160f713d638f7c849f807b97c6b71a3581b01dc3", 39315855869091773),
-    ("0x8abb7f3754d576869906c033b20df1864811e2b4", 46010510723374528),
-    ("0x4072dfd96af1a544527a27ab5dcb534c825f11de", 86941126800409287),
-    ("0x1bb0e6d293c5b29dc4986a2b80cc22865e5e68f8", 7482475373469644),
-    ("0x125377dbdb4ed6b49f57a569a3d9282f8e3f5366", 21938290465155141),
-    ("0x7e7984f355be36c3bcc18ebd1ac6de3b8de34913", 19939367445291992),
-    ("0x42d02f4cd48bdac116b7e3a4895f586777948fae", 286100535157369371),
-    ("0xe10aa5fb316417250fb856988da47fe0442666b3", 8276169664401871),
-    ("0xde01a8e2f3b5f24c4c907acab50b5bad5363be07", 100626051758301320),
-    ("0x60d3513f6dafa7117f2560e4032727e6d829db9e", 73803385338724645),
-    ("0x9186bb7053bc9ff73d1647712309dfc2c8699049", 451125986946847836),
-    ("0xea45e6db26504c6506d0800ac7fef5d84fe2941b", 2208174270831609),
-    ("0x8c93b313eaa22c8cddcd1ba634591f5c5eca53d4", 109270391133076642),
-    ("0xbdf88809e65329aba22531ba68901edbd060517c", 21902207060032248),
-    ("0x09612f0a743f75ef592f4d3f5e6c224035246ec2", 7242694763638938),
-    ("0x966d3eea0d60aabb240d98515f2ff1f913248466", 1996079208766324),
-    ("0x1462f46477cdc2ffbfac6b2295e9760d776da93f", 4857564526997517),
-    ("0xdedb02b1a0e2c302536e3fb1e19a581727035ee1", 7246193207376448),
-    ("0x2b49ce21ad2004cfb3d0b51b2e8ec0406d632513", 104091706455613420),
-    ("0x2a6145a40b07fbfdd8b2333b50f82f3aefd593f6", 35955001201370934),
-    ("0x3a6e0b0855d1e5342ac9e0d83d30f8dc5e1b55f9", 52120178140925851),
-    ("0x4cd939dd8563cbbee5a8ff47f911c127a2f20176", 18782565235617662),
-    ("0x9e747fc985bedda32211b7a93e347cef8dd22805", 43937501374128608),
-    ("0x09b561ee1e38d03ee596ec562e23483b7940a273", 37968631783315951),
-    ("0x038b2c71c5fa92e46e6c7317806906a206e05176", 159702364941155435),
-    ("0xa3811153ed49e676fd34ac295dcf59cfa2824dde", 4420483817659076),
-    ("0x773338f432a608d09f7099045509a7c9be34edb0", 23390229283347160),
-    ("0x47a0a7653a39643240d7b11854e68b2daea9002b", 22070327957119433),
-    ("0xe7da15e968a04b6639d3e7a28ecd6d56d0de3112", 72232258804298980),
-    ("0xd4762e5e4033b44a227eba428c6fd02dd2c2dae4", 1196493198558818),
-    ("0xef88b3257bdd439d7cb81aa9d04c05b90d68197a", 21325306539425335),
-    ("0x971ef1f39352abd927f900750ee2231c47d65e88", 47307470440695255),
-    ("0xc418fd33ef4e3e1937b053f927d23bf650cdf368", 20892448276591170),
-    ("0x9cb6388f1fc5bb4727eb1f18b1a90a4d636d2b78", 63923795165959039),
-    ("0x040012d26d55de959da4228d4f29de599e863b28", 22958684532133200),
-    ("0x9c9411ec47a85dea243fb605e1743ccd702d408a", 11808056633922498),
-    ("0x3eb9f5d1194756aa9e490f2b78ad2bc1c3726ed3", 160667993945692783),
-    ("0x14dc0e60338281feb4788b72e5735f812672c3c0", 22128758960711925),
-    ("0x1283eadd3307f0e66938fff4e13c48fdaa91505c", 44887249776565288),
-    ("0xb601180c14fb752d873d5afe33836bb155bc0f0d", 44280841315612918),
-    ("0xb26da2730f0ae66e9d33d5c819e8d6a4d8d4918c", 24840561249411236),
-    ("0xbfe9b3715f474275eae7ff28e32e1fbb7231bd24", 40930712837470580),
-    ("0x40f789590872fed258a1553cbbb84c183ed571f5", 7134046934168064),
-    ("0x0a6883aca247aedbac6428302da876642b13476f", 13492499498572874),
-    ("0xc13f6892f2536f027529747d9fbd9214a528db64", 5509564297006353),
-    ("0x761a282f313933e19561a0f64999ce6e340a1deb", 444628548732841179),
-    ("0x685658529cd88dc9f5c55aeaa905297e5d78becb", 153664982242730149),
-    ("0xa49a894bf16d748f021698c24dfa3810ee5e91af", 1427611991374184932),
-    ("0xd113a6a043c7c284de9a570171859d12c43a4f83", 7878734911242370),
-    ("0x9af906c01f75154dd3402dfa441c7a4251c3201f", 29064485101334494),
-    ("0x4b581deda2f2c0650c3dfc506c86a8c140d9f699", 1792287687330391688),
-    ("0x1e5aebcfdc780da4fb506e93a0b398490fd94864", 2556720412005526482),
-    ("0x51fbc8ab8cb2fdc38e1757ccd1e19eecb1bef487", 13234965243325461),
-    ("0xb8d9bec8cd69ac1b9c8e56735f07fc5b6392689a", 99237295673021622),
-    ("0x8aa3d2cffdcab54c52bb8857fa54c00c33847c3e", 13489476747081424),
-    ("0xa5ad84a47d1564cd333f1556381759666e201252", 47085344934370194),
-    ("0xd69517e958ecc2ae83077341cd829849962241cc", 22745687504376082),
-    ("0x714b868d043615e4b5c304375d1835630fb8d30b", 11121121556467074),
-    ("0x6e92f8b308f57e148084c82c5c0b41671272a678", 2372103910023693),
-    ("0x2132ac2ad4ab01c177deb6724ec82537a0ab97e3", 27611621264787002),
-    ("0x0ec1daea7f40bce5c522c7458596abb3092ff9e2", 10774040427265588),
-    ("0x51be63a19bc2911d0f396546896f5524e9b57cc5", 10665407786528180),
-    ("0x3363399f60619ab954f590ba80db5d4757718fec", 22667210084032715),
-    ("0xa9b0c16b23f036e5a5caa249c301f1ff431e2a60", 25487150482715251),
-    ("0x8b3881dc136eea8794e798e1916a1cc3c0b88a3a", 127557671953953064),
-    ("0x42b3202b505e29000bbbd6409085c9a8334d7e47", 44515306340639578),
-    ("0x351065c66ff0119c270ed2735959e8d88cd7c05b", 62906521865642736),
-    ("0x3f09f472cb70d38076120164d9310176851e6535", 44048007270005347),
-    ("0x5ddd6438aa2791240b55a568e5841833532e66d6", 80795981428318199),
-    ("0x579ff2f526dbdf9fb4f14bb555c0e427fd9b5d8d", 22493204043980402),
-    ("0xbf6d989ad2ac8ff6ac21875611e205401bb57bc7", 122944450361642458),
-    ("0xb78faab3fc6ab3691aaddf94847ca53481e6410e", 19680256241127594),
-    ("0x31a719cce260964ce5d749949255ee69be349f33", 12366839739580036),
-    ("0xfdbf6bf47190ad975c721f16263a10479f63bb81", 8973920225073605),
-    ("0x58e4123e785099b5710c1f245050f40adfca9b33", 915754373134504),
-    ("0x39fb5c9b7e300b1f5e6c6fea9248b99c8d1bcdee", 34834649446526258),
-    ("0xfee7ae7942b748332e7d66154af5627b2c9d07ef", 22792429998413090),
-    ("0xf286e07ed6889658a3285c05c4f736963cf41456", 10521755323685047),
-    ("0x1579deaf5488a135d4bf23ef6e2f465b779867d1", 106103713426653521),
-    ("0x727a135496153cd990d0ffa7c5524067b571b109", 24949540517668035),
-    ("0xe90ceb04250d08830ead0e75075ac5ca888eac11", 12551986260547028),
-    ("0x26bc042e5b75727e175139cd5da47660cb9dcbfd", 23212043458048316),
-    ("0x33df87108537d4d45f814aae11a408908302cc92", 2067875364879818),
-    ("0x5a886057a5eb3c526b0315dba17c107209ea9574", 12071791490967896),
-    ("0x3b335c5901de233f06c8263da33dfa367b83e329", 14309337346626766),
-    ("0x5e35994dfb6b2494428d1919b15b7fd3a7de0c3a", 25211560819110565),
-    ("0xfc264830450005e7a99e95bd0e539fa12f3b9eee", 5544907057166124),
-    ("0x3d088b87c048e5fd4e3a6f9cc04490501b1dbae1", 2296654080779278),
-    ("0x2b0666f128374ce8f30d7560bdaf2bc14e079da8", 22531955208284088),
-    ("0x876b648c734835bb4f9e912504b7fbb90f1369a0", 13125312602283987),
-    ("0x809676df9f7ef012b1201e767575bc9b5aebd108", 109748043622747116),
-    ("0xcd07ace06bcf08350fb66313395f731b53925232", 81853225295601365),
-    ("0x20997325098692337a03961317ebf912bf913b65", 55924813717955074),
-    ("0x3585a728d6e4223fe83541a56ce8cbe334ba3118", 8408370473107486),
-    ("0x3f9517ac3ccc311c84826fb000bc8df1e45c4b72", 112018089460900408),
-    ("0xcf43e9a2f9ed4810de89ae08d88445d8ccf63ab1", 39934430698271089),
-    ("0xa6a55a61e201792334a7ea8613c521d7ffb173c9", 9896131145604309),
-    ("0x274fb5e71b1824fce44827d0aea33a9cd53b8a63", 4976308229909037),
-    ("0xcbc3906efe25ed7cf06265f6b02e83db67ef41ac", 222988709062564951),
-    ("0xf7a489bbd270e60c8c53df31c36aeffa14a9260b", 57068336736670609),
-    ("0x3745188506f94e0089e4205e9eaa89832f6ca51e", 35903323627110439),
-    ("0x25954b95ea02df586e07688de9543eea5bae31d7", 31629859307651228),
-    ("0x17c1cf2eefda3f339996c67cd18d4389d132d033", 453371785669961030),
-    ("0xe5ebaa73d9982fac65d85dc732f75a921e5badd9", 18338165904894927),
-    ("0x201b5abfd44a8f9b75f0fe1bae74cdac7675e54b", 558374865262414296),
-    ("0x4e68b6ae775962b60e153beca671d16e60cb2ee7", 20315068166365086),
-    ("0x0d0ea68195b46a43f6fc2c1bd96ccaecd55fb569", 5634333500415296),
-    ("0x1da75fb0da32653fdab9ddecdb42f2ed61f8a28b", 67461610736467858),
-    ("0x391fed3582e2cbb40a3c6eb45a8cd34e3b01c360", 56437035712430931),
-    ("0xec83e4768e314cdd88f910e397affcde78a52f92", 20993000258630073),
-    ("0x96a1500bac47b278f19e122cf674204986bada69", 112117319486840099),
-    ("0xeeb35037e00dbe0291790b1b1bd5315150be5812", 23660252574589307),
-    ("0x13fefdd563a930f07b1ac8a2227acc27c3c12946", 1785724577298426833),
-    ("0x392027fdc620d397ca27f0c1c3dcb592f27a4dc3", 101859086388584990),
-    ("0xa37d20cd446898508ab961c6be8d3fe1c15413fa", 67865820422785011),
-    ("0x2d0c3044bf148a1897424ca23449bd68e49ee239", 91048954340536073),
-    ("0x748a6a09380e9086c357e9634b205411b88881c4", 5001913294123303),
-    ("0x26d1e86560b3196d40cb6e4b2eb52e1e87b4066f", 3005399943440480),
-    ("0x02483ab8b7eb1804db8da2b734a15f5813615f85", 66213138250193905),
-    ("0x618bd84ccc24e386fc95a349c1815381a793feb0", 81686064385671237),
-    ("0x22a1f944f412c3ea51ad108275c9979f0b02a739", 23827293006422007),
-    ("0xee3cf8612456e5289f98e649453d279e88817d18", 28360105188538660),
-    ("0xdaf780714ce4931f98129060445fc6eb4ab73bb1", 7453152456367973),
-    ("0x540d97eebf6d787eeab1ac2928103296e3a543c1", 4633945812178248),
-    ("0xfbd4e1d0d89632c279f06a1d9bb9438647d99a29", 36972700505739779),
-    ("0xb38cbf22881253a04ffbcdc1f566c3379018ac06", 16865186535397882),
-    ("0x9071ea83845ecff60b3f17fc5afca9a4b361e7f3", 5477257988705566),
-    ("0xc1169b51f43ff93e5b40e941c83e026ae774daa6", 113329820327371071),
-    ("0x96b1122c54f2a550e2931f065f09499ca1bfbe24", 57057629906335867),
-    ("0x9708eee9be8722a5069c58369edd69ca0e4635e7", 19780553407133994),
-    ("0x2ca40605844f48c60431e2820ffa84526e795f9f", 61307241158459422),
-    ("0xd3e84cfe50fba4697728ed573e13e42feb74fb5e", 8354198966415659),
-    ("0x2386b340d46d85057e2a6ec7b93f39b10f44cbe9", 160802363185317712),
-    ("0x3c0d50dd7688d23f30dcd7333069bef8f5787d86", 8684957712741159),
-    ("0x33fdcfbe28b05e64d0c2e1b378400e61f2bc68c9", 11889988686791234),
-    ("0x4fee40110623ad02ba4d76c76157d01e22dfba72", 10783687266073832),
-    ("0x4ddce06e259a2e583a3b2425e0a135f45b323e75", 1072896087229089227),
-    ("0xa53a13a80d72a855481de5211e7654fabdfe3526", 371807258912903471),
-    ("0xec948d9eef6f1dac95b590048d0ed8d99e5fdda6", 32671162524726860),
-    ("0x478f25e0856aa133f7f9f68f1dab2505b6ef9bd1", 304687340768698589),
-    ("0xe3de117345f832f8c9c4a1fb070ee91234dff91f", 59618674387634199),
-    ("0xb785f2676e8b56044fe69e4fa23af037c387cec7", 11555987128055793),
-    ("0x37a59493980295eec5c495b62367cd005bb9eb9a", 25291724905499387),
-    ("0x1eec60f7bf9894ca9973f26c27e0f544b8f4729a", 52751870213108289),
-    ("0xe421f41e3bba9fa595e6256847c1910edb3a5b72", 15300000000000000),
-    ("0xc44d7fd052634a4fed213938d1c0fa3c0f46cce5", 59687033415269548),
-    ("0xbbf08c90c76996777eadfa2d220b136d91b43fa4", 13863099949401956),
-    ("0xca5a712d84004491b9f9e7492dfbb7ad206f49c8", 9751514301759418),
-    ("0x9ad4196e45ca230d69c0f3433d340f1dd5f9b828", 42898022816617954),
-    ("0x0e2c7260327e3609d65690779ad077b9b967612a", 2500000000000000),
-    ("0x4cae72709a265720e979e05ae70e6d5a0631b834", 8216362417023992),
-    ("0xe6129d4f0c659880a66a6bb7ebcc578cf81f051d", 215352690303823512),
-    ("0xbef716e7fbc844020925dc372879becd2f932bf9", 96867579242788180),
-    ("0x2784ab7cf677dd88adbf0cf8e7062fc9dbff5d25", 11278186356133779),
-    ("0x71a094175ba3f5d638510e2d6fccaaa9f30b18f0", 22240348251258096),
-    ("0x998aaec3728e8562f5a7bafbd5e870a8ba4767cb", 44044860316332430),
-    ("0xa2cd20a592f26c907df72e23653f28cba2b5c5f8", 3600791339080911363),
-    ("0x18fc0099cc649f53825f60fdda5f10f68965ed0f", 11671846823028446),
-    ("0xc7bfcfb1a531917855e7d1bab80cf855b7c12da9", 22365267253546111),
-    ("0x5eb366650afc512bf8b8b4ecc0e012e93651a34e", 4758462187635609),
-    ("0x63968e995e962375d3bc514bef7fd9b78aaa550b", 19112490501735815),
-    ("0xd73cc8b36cd84252711beff17faf8f9797642920", 11480259809448465),
-    ("0x12c5118d468c20a117e8ef7e229a9c7b56e1df46", 29829254498704975),
-    ("0x2a8600bbdaab254a2f8a8e00912799295c3dd601", 451922322912189808),
-    ("0x74488ac2ea37096e9f85668f6b66e9cae1f5c696", 146024358869324331),
-    ("0x73d550883eb6938898f93f94b0d55e1a010ced75", 16230050029286367),
-    ("0x1600770e70097d24346f08bbbbcd9b7c9cdf3e9f", 4989542046904818),
-    ("0x869cf77f0c9fcaabbea2f6d4626cb970a109c42f", 6825057517945481),
-    ("0x5ec2e5aa1d208e7ddc6d44e2ea7ac3e3aeac29df", 111893993217288190),
-    ("0x447218712fb6472ea43e1d7d280a3aa4c43f754e", 470414615843863461),
-    ("0x53b3f9ce1c4cb22502b9cec9673742d5b2cb4052", 89666574397936049),
-    ("0xcc65f0ca6e5bc87cf4e383e5684ad8226825e332", 700726435818385137),
-    ("0x91d1db2bc8fd8f6bed72b160aeddb22019560223", 215217324133046547),
-    ("0x2c8e10eac50cff25e2f71b671dd9a0c4324ada0c", 19650862315645350),
-    ("0x7ce0a598d22c24eecd03a9b545883760d6266c9e", 17473573615393384),
-    ("0x6fc14ae9374eaec0f621d6fbf8724bd5d8613ef0", 25732995511616886),
-    ("0x1b163704d48bf578339ad8e66cbc29bb34d0f92b", 22695236162885491),
-    ("0x9611ec39d8b9beb8ab4d0ccc2b7f923180ad61f3", 11352821906133811),
-    ("0xb309207c54432b96a97e6bf58d89fdfb493dcfd9", 3404404793404840),
-    ("0x5f64676b7b96d2836b5d88cdc729d9f1703f0a0e", 23672940232202348),
-    ("0xcc6c5b999738a74305d60030b250cd35d66eeec6", 9803637623892406),
-    ("0x1cb7f2c79248be2ca9518a585a4c45e8480621d1", 13604673283980692),
-    ("0x984d0ae13dd78611f918b43de33f957e4f79b146", 20093240546981281),
-    ("0xdb534bab6b7d0690f412395b18bd3df078ecfe2d", 11577582499397809),
-    ("0x9f3e6825d3677f0228153f01da1da9f9a856d688", 11144306842393286),
-    ("0x9055afe5cf229c8e4987168ad53148bc58a6a44b", 11662499044030677),
-    ("0x0db1911f0d2c9d5a2234111c30b41925e97d4de3", 30774398047696031),
-    ("0x0f9cd17395efd5472dc37aaafe5bdd3c9c15d066", 43769208465131967),
-    ("0xeac603e8f037d92c9da618b7368e01509e0b54ea", 21724670115804136),
-    ("0xcdb1503d987f0a5b0b230eab34cafab68e1b5bb5", 11185745408334245),
-    ("0xed90ec2f4da580fff816ddab812865d759855138", 3903513289463247),
-    ("0xbbe8a1421d8583194bd959a640cb82225650c748", 38534365987977166),
-    ("0xc95af0482ecf32e993f0d9355e38e4a652984390", 1342042849098688),
-    ("0xbe9c53c6b26ddcc6213ec744fddc5015531ced72", 22619036695424715),
-    ("0x7f21f9aece800cfaefe7e44135be6f88d3a4c4e5", 38780392453339658),
-    ("0xbd25bb3cf74ab26a0ec863150f3a0e1110608896", 27427621172046403),
-    ("0x71b4bd1d6c0fe3ae5466a775396f7529f1d3e17f", 105381726412725533),
-    ("0x3d1fa9923ee63c97a0f867e98b3892d7319ed6fb", 9906231132501561),
-    ("0xd06f3a515409637e6c2808f393af3e0c3ba698bd", 23847395101046025),
-    ("0x58ff099b5624a8ebf9b6d27f9fcad4fd88a6b9f1", 63974509255698932),
-    ("0xd3b1f76fd25c679f441d4686adaefdaabd070e3b", 5057180471178332),
-    ("0xb8bd72ed01e102a9d00446a159f747b5d44c5a1f", 57991764774727890),
-    ("0x731773eb516e29d9fa37710d9e5dcdc25411d5af", 7509169910832194),
-    ("0x393a285deecfadd50bc94110f37eb5887c353521", 5000000000000000),
-    ("0xfe6fc5f5d89693d02dc938ec5be5f5b1f8b955e7", 32632991715013264),
-    ("0x386ba84a592571bd5d376aa7cccd53db8b45eea3", 1081756976225720425),
-    ("0x6b00136cc313edef40c04414eed10490f56f708f", 24362421663262099),
-    ("0xae3ee144e2c7ae4336919ca199f1a3b9dc9e0bb6", 16747870238707300),
-    ("0xe0e7dd6c59855b6e852cd17de00143ebd6f71ea2", 11334947101350327),
-    ("0xb97a85e57aec469a48deecbdb0dd71d7b7898401", 24991684751552207),
-    ("0x46f75a3e9702d89e3e269361d9c1e4d2a9779044", 25424812840041669),
-    ("0x3922315057742ee040e3c64987ea90c9cf9df7b5", 58402743506311466),
-    ("0x7c298217323d17aacbd134a3ff378a71786a9915", 8330447027032581),
-    ("0x272b48a578075d3b63d7a2ce8d9f6fd55fc84bb5", 14810736764779058),
-    ("0x69c4f490345d30f9b08c1ced4ee30eecbceeb5b9", 33316948704164664),
-    ("0x8bd431fff72b55231d562482e7661997a16e0a3b", 76809167330644611),
-    ("0x8ff40906884c34fb9d20b1b38841795aa0cdbf21", 206204476872619647),
-    ("0x889afd58eb83ad4ded14c72652db884db57bde66", 11500297224566752),
-    ("0x38e56f8ee578ac927d809cb4fb0495006648cf0d", 112936411610356118),
-    ("0x29dd604a8c8c484ad7da59968cd9000cab660d02", 57028390730467035),
-    ("0x5cd9af1813489b83bf2d97471d8d2ad26b1a9450", 21365656173443406),
-    ("0x6037c58aa448d2d8387b16aa835113a27a790966", 15881271703524945),
-    ("0x47bcb86e42e0365d96f164d49d828d06ec99d41d", 794426987256196827),
-    ("0x83e5041baa8308531fda413d8d313a39071b4bc3", 2118640919819686),
-    ("0x8c40ddb93163446e105009aa47e9a5bc18cfb0e5", 95232105127733820),
-    ("0x75e2b7c53ce773a25f2d0ab99a44130b219c6990", 5150064350649391),
-    ("0x82b393e5e20fc5ce9d22ee1406bd71370f95de00", 294930924964375993),
-    ("0xbd9ab4828f677ffe34f90c08b304911253bbd3cb", 28660672202406713),
-    ("0xc23ef3adf050f4ca50b30998d37eb6464e387577", 3670450145000000000),
-    ("0xab48070ea1375f90e80b1f26b2fbb9520da62dc5", 27954853396711604),
-    ("0xd4b1d917c6cc979488b962ac25e2d671722f4acb", 9669069100477319),
-    ("0xa267f3a4a92531f47811e05e890e977a0fe375b4", 119152452869733401),
-    ("0x675b3b5525df6dcbec7e92204bb4b882a1dc1419", 1837346359538397),
-    ("0xe6ce9038bb811dde465639ef9a86b5462433b61b", 24438786720039081),
-    ("0x2ad7df126372fc2648df7876a9ae5065c9bd2118", 40970470725898293),
-    ("0x2bb417abdbf3bed6de3151a72a44d8444242323c", 37332272597087907),
-    ("0x136aea6a78d2014e1e5263b98dcfbbcb49f2b012", 50911979623421990),
-    ("0x8664e763266054f57faa8e62f7fbda16722178a6", 5619995232901243),
-    ("0xfbb3b7d03e93f4987317eae03ed70ae32ff12237", 7524905508876968),
-    ("0x37f5bd02927e00e9bd96ed0531d2748db221aa34", 4717806165582065),
-    ("0x3e81ea3a8a2d0b71a85468b02be83d09bfffe476", 137666209293911187),
-    ("0x5f05f2a9a0e48316649cf2f94161f0eaf4ee9f20", 26833604239491823),
-    ("0xab8c50c672fe44a5068589db2f197b006f9424da", 132409427216281373),
-    ("0x37336db0c5a91fbb9acbec28017690f59413ddf5", 91903689579192066),
-    ("0xb329748ed21105b08fbfddcdbf7d1751e78f3950", 5003712109722948),
-    ("0x24ca41860adad0371213e14c0a8c74e22180ff3d", 7471064037297804),
-    ("0x19ba3eeda313577888c145a5d7053df05471b460", 12193405537995818),
-    ("0xa38cdb63c943e9481c9b87db5c80f5ac333d16ed", 371329073764388003),
-    ("0xaf90d5221d7deac84b57e984b5908e329e4fd70b", 14255165897095086),
-    ("0xdf334440141b711c78c41263245989cd10982505", 18585516694714259),
-    ("0xda65363561bf0fdae1a2c53abaae3528b080d23d", 755404704078949077),
-    ("0x26cabc7528854005635a7f29c6929a691bbd07ef", 3859564347236711),
-    ("0xfe97b38192cb30add0bbe5e01e6a617562cc8318", 366588985512239793),
-    ("0x978cd538ca53d143c91ff335f4ee73b3fc312f3c", 21100000000000000),
-    ("0x85624c7959fae5580337ea7afcdfaeff42a6a66e", 21726970830564683),
-    ("0xc4a00668136e174f79939220997097512b23066d", 23648646774459777),
-    ("0x52d3750fa7e9ad7af929921dfe4ced11dd402f5c", 15160565532975677),
-    ("0x72c3b58fa0f3de58a105b47c1bae3afc954a8262", 12106241114792657),
-    ("0xc0b51b1bde57e670077ab0e0a68c0d47f54e15bd", 3095729101062841),
-    ("0x17f93fa267c156e73c2e9e10e89e3deaca1d198d", 6009922974282907),
-    ("0x72c7fbcf83fa22f6f588ea04c7d6919722fc395a", 21624900252998733),
-    ("0x3f6df9f528db4cda10c369561b8525b86bafc89c", 5827824395233556),
-    ("0x9fb465df844bbee88631bebfc8b51a4ce4b41607", 206465135841278112),
-    ("0xd88e9d71ea4feab866cd33a69177b9729cf403de", 62863668209783391),
-    ("0x43c00ee1fe922f1426f38fb023b4bd76dc84a872", 12705965672519002),
-    ("0xd0ac061dceec076a1f80c6f1c0b9a09cce44df70", 46847794983430210),
-    ("0xb3f11416cc560bbb69eddeeca904a2630deb60aa", 22014537824619423),
-    ("0xa114c3d89b3251ba7e4558c2cbc73664d8d4cd30", 12077403586894465),
-    ("0x5ff1cd65d1fd63c19b84293d0611ac452b6c3995", 52778122323583973),
-    ("0xba4321fa8fa8b2ac960eb9d4e59e0e286e2d3c6d", 7586979153294068),
-    ("0x939c2105933bf9e01a570b74fffedb9fb9b0fbe3", 340454278524126169),
-    ("0x60d65b6ec265058f6aac497461bbacdec8fb00f8", 104706836017252149),
-    ("0xc2b5a349d26a42a8949ab2c3f9415fd01a90db7a", 46927401571015383),
-    ("0x8195a2e548e81e33f59638636100f8b36cc33071", 52880011570171121),
-    ("0x6d43c7629195876a78cfe7bcc97ac45b879087e3", 7368196562490343),
-    ("0xae02280bde4fd36b1ff2ffbc42d1f69ea3710c17", 16836052267864013),
-    ("0xc6d37e6f2ca53fd636142ba4127865b98842034e", 1701673716239282898),
-    ("0xbe54f9b8b10e39ccdc4c434b2fdaf4bc0d175345", 6230851666037864),
-    ("0xea6bc9033d86c1af32a615917d46ca1e8910586f", 114921573780757388),
-    ("0x5f5db2947ef11212dc36e28d1ac57d9e6da88c11", 5320111349776286),
-    ("0x37f3c26d25ec7d4ad5f03508cd8433b6971d4f77", 39230073396405806),
-    ("0x01928f5eaae0032b5e2836336ffd97fb82b8cc0a", 40627611690947537),
-    ("0xdf847beba76c77481cc4062ba1ee87caa9f40f8a", 8432914846066116),
-    ("0xb0e2a79aefb1a6281d8d81915f2a826ef96391dc", 37747105931701710),
-    ("0x296897c5b419c2217719dc699d244e595d675d07", 11592739558269284),
-    ("0xc22d2a90be89264a6a71963e577b9286f7966688", 108948984259512144),
-    ("0xac2ef04e02b132543836d950c3d6caa3548172c9", 45249411426807187),
-    ("0x2bc52d804490fabc71e49a2eb9da37c6516b97fc", 50282081199366304),
-    ("0x6f6b420f0eff16a89302d1bd7999fed8d57c6b1a", 235040889624445562),
-    ("0x1726eb2500518f25b740c4ec7a13a11873f5ab86", 10746460285262677),
-    ("0x41141041a119975a462c8a6d84eb75af4b31c51d", 36612454718079991),
-    ("0xdf879669b3eb2192b1289b08fb8dc8602993ea52", 1018848803115860385),
-    ("0x8163260bee568950866eb1df931eb1726ca300aa", 24595400420596542),
-    ("0x30cf8aa72c4e69e35e0e48e785176704d6d70c7d", 88917303626090123),
-    ("0xb5d876b4ea4c8a921ab25936584c6813642e51b4", 15943127224108074),
-    ("0x82484000ffb1fee86588c531e4a9fb9ec739c02c", 7000000000000000),
-    ("0xeaf7d511a1956c9d297efbb2d81b528b37d1d8d7", 41104372092787394),
-    ("0x58654f05384e90004ec571ce732598c0f0b0ed79", 51297691245588403),
-    ("0x0577207634a90e6f48d7a279f398653b74128540", 11683790179565814),
-    ("0xfcfe4380a6592abb74ab7a3d270f87acaafe118d", 193858855423336605),
-    ("0x1b62dc1fd4e5bf526bea4a8d8eba106e7c79d87f", 24338405800614979),
-    ("0x293d9eab4a6b6dcd263d71e74c1bd7d83fef5063", 20139047692449781),
-    ("0x2b611ea087700cf2582be54bd36f29b17a71e937", 22588856830341090),
-    ("0x9241f27daffd0bb1df4f2a022584dd6c77843e64", 4086500483232613),
-    ("0xc7f78348738fe1e745079319aa98974afbcf154b", 43796568179380426),
-    ("0xfbe442a95dfb7a583b77247cbcfc3a7e1429fa94", 6097245570330558),
-    ("0x2769dcf7b37bf6ee5149b4860968b14328e9c831", 7958165437214184),
-    ("0xef576ca32ede5e117a9f8dc6b60654a10072fffd", 2137680693658921238),
-    ("0x6db0a48eab1b4330c1b76a4e9af62f26325daec4", 479304369973011049),
-    ("0x7e05382a7463bffd77fd09b44ed261dd72d74aa1", 2742714931510686),
-    ("0xaa27188afcc314a75a2bd7109d3095f0d6ae7a4a", 8984368513436906),
-    ("0x744280bd523229b7bb0d4af66749e74eae300934", 3052536252257095),
-    ("0x03aec62437e9f1485410654e5daf4f5ad707f395", 6709251869024934),
-    ("0xdb18f514f52595f7710d9cc16e3fac64592be772", 2402965961438915),
-    ("0xdb687064091b8d2df1a02273411a03b707e20003", 31929571720255456),
-    ("0x29546e006d49b07351bca26701e473facdcfc087", 38570040113683006),
-    ("0x62bf34035fb9623c528df44b82e8ae1c631d98ff", 43499103562450677),
-    ("0xe07226315b223032ef745781870c9a155ae0c88a", 57555022494152472),
-    ("0xa3d57ffa8d44d3bba3addae8f3e84953d21d57c9", 27552538348152400),
-    ("0xd9d24d5b8b6e649d696297bdef3bffb5be90ff49", 27418611547852037),
-    ("0x799ebc63ef253b8e805a3bdc7a9c7a2b1ffe23a5", 37626151787164509),
-    ("0x5e3b085623206da05df75c32be10bba162a9c379", 329242033341391414),
-    ("0x286ac6c8237a719d1c7117e1ef8c7cd8719e7412", 76692539534091580),
-    ("0xe3e0145de4bd4cb72597e66163ca8283aaf8387b", 188680300809033789),
-    ("0x34a8fbfdd1257c62a2d3dcd87d5f2ff57bb35bd8", 113318998957410502),
-    ("0x07f1456c836380adcb4ee16e3519c6bfea0da35a", 76350383244060180),
-    ("0x6abd9631407fd8757d9d596c4601286fde491847", 8762474283296162),
-    ("0x41810ac81d7f628b3aece8ede0571922e14b066b", 25089037395050546),
-    ("0xdb67c935ab8ff936a5c4dc548e7094a9d458eac7", 9489604788217828),
-    ("0xf2717a4817b87cf41335e8393bc97ca2781ccdbf", 23619454989165095),
-    ("0x72eec5c04fdf4e1a2dcb1b0e5d828ded15dc629f", 14307020023993376),
-    ("0xc51fbd4b259b3a9b7121e928927e99638a4065b4", 30173235408780746),
-    ("0xd579c971b4147686aabbdc42b8537add298e6451", 77771293335503525),
-    ("0x937b3050c8384f65ae4b26c70b75bea549411255", 131609451865422936),
-    ("0x852d674dcb87ce02dbe8a93e81cd04f976b1beac", 18028336775606232),
-    ("0xe75a7ee6f84d47ec22e11a667af051504e471f68", 12426227013211768),
-    ("0x5c586d4593b7cabfe74d80b979ccb9c49410ff54", 15117184466062743),
-    ("0x08547c1530a5fcb31b40e76a3de5ab3610e548cc", 103774161692725627),
-    ("0x99a5e13e307867c809183fce9d56162825358678", 23922750961950577),
-    ("0x2118b4d853d7e5c031a7bb9b0f92d499bddf48e1", 54854556709209024),
-    ("0xc80005ab8ea04ce48c535272a5f60476d3ba3475", 406861800054086365),
-    ("0x2821581fe39a58177a0533f7ae378f7c56ebaaa7", 45881494065596669),
-    ("0xf4ae2b95b22750d29aeac083dd7ad4e1fea5a995", 291692714542454602),
-    ("0x8b4d239104e5f571dd8d792c7de2a8e1d53b28a2", 23930139195588860),
-    ("0xb5f8ead6ff9bcd104b950664ecab2810e30541de", 2171929054486131205),
-    ("0xb92989fc5d472be76a46b242120dc3da6f7ad1a7", 11835724384244956),
-    ("0x86dc89df3823616d04a415e274a33017fd76318d", 46472482633715556),
-    ("0xed962353733322898e4fe8a90540784c75237d2c", 56380784972857266),
-    ("0x630623549b9f2cf5c79da412547f98189dfc6c0b", 51411024804988132),
-    ("0x0821c9f1c0e902f7ee24798391a61acc01081dd2", 3077588726229710840),
-    ("0xf620db5523d486bcb03f8c4afecefad61f11dc03", 17153357852641715),
-    ("0xedc535907d20fa080c76dea3f0be2db050dde074", 221301841510753174),
-    ("0xabe01da1e93cfae0a9098154ac9cac3ebe3a3e5c", 296161057408368446),
-    ("0x162284452b81d2dd73d35223507c9d01b2fb5fb7", 38054530121703618),
-    ("0x792882e0fcd3949e931a5a03531c627baddb2e28", 26939404732300642),
-    ("0xfb961aca9c575c53e02895f995efe3ef26605e14", 21689579560407559),
-    ("0x52a92a6c111629e2020ccecafba5547f884b332c", 2358416174777558),
-    ("0xc43fc9106e6940e123bffdeb82963a0734c10b16", 20917519912164914),
-    ("0x9a5650371bee1cba75b47b2e995e16e946653f10", 15692010757245315),
-    ("0x95d1da3b73be6e1d19c4ce7f8f460f3194f047b0", 7994896844434447),
-    ("0x225711a28e0543a48417245c0b502e75d8de4eaf", 9360608261742017),
-    ("0x6f063e8eb78db2ce88d68148710997cb884e5db8", 43475357438262235),
-    ("0xc509d36fa17bae3417f2e85aa5ff1a20ff91f945", 29784392293059015),
-    ("0x350f2383aee8c607df7bc10b94fd064d1bfb3904", 37651111606429539),
-    ("0x0f6143783b2891bfb0330239e823e24cd30dbe0e", 17638962157582594),
-    ("0x6e50b763a45bb0583ee73f3104907df31e2d65a3", 27413211369401973),
-    ("0x71c1a6723964b5db1a193b3bec100aaeea389170", 82114336566564510),
-    ("0xb3c2b715eb6c7f45d51ed937a55ef3e9ed213e07", 8312228375626520),
-    ("0x49deaba124de991b47fd7709f380a99cecdece1b", 11852158664248648),
-    ("0x72e0c3d996fae3bcb2107f7dc0363f0681ae0f7f", 22255334628470937),
-    ("0xc367066d357b85a7c4aa4bafc0834f0079f09f35", 210376311287783778),
-    ("0xe277759d9dd2113490674aab0601319a65444d1a", 17196714930971685),
-    ("0x953c5424be51b9816be2de94834e8d1a81e3b869", 36629294164865256),
-    ("0x13dabad6a1a25e3ca2f08f01a86a5ec39f1c1a8f", 16283318834521606),
-    ("0x979f575ef9b26b80179cbb223288f3ccc0212d89", 26468616445765057),
-    ("0x9bc50ca7ccb9193f3f1c7853446c9588265e79b4", 13527116728436940),
-    ("0xfdc03c88f4cb228b10cf3a53ba05da9dea6858c7", 14593873158561089),
-    ("0x74b5e2e0814c4f5df291f9c43acebea407d4e45d", 8410237162983845),
-    ("0x04e2184b22fec9fa16c7bfab78eee250ed5234c6", 22607264764162833),
-    ("0x00baf9c1942a1da57e5918915ef19363af7c3831", 45246380981493951),
-    ("0xba9fcb4ea0172ccc4643009b53900ac0179f481a", 11777415911389670),
-    ("0x9e2af98600193f60307d298cc9eea1755052de9b", 540612653172551967),
-    ("0xddaa179115831b5c835bad1443a6882668f01cae", 15593191050448146),
-    ("0x79b8ee625dbd7e052d8e4c888f377cd843dc3cbc", 49536315015190414),
-    ("0xea927fc6ce46152a6dc9d09c7fb04ec024922901", 15035069191138439),
-    ("0x970879751d9053f6c0c3168b853df5fde2870ba5", 5110750826070405),
-    ("0x2ae9c12d8356f11d1be6c4e91fe64c10783d42e5", 34559911887289355),
-    ("0x48e715bcaaf729e8aa37a85e9ce3fe9a6ccc8092", 11258878537898841),
-    ("0x44f6d4b38e3c9bbd74d6d1fce79a54c58bd1d282", 70898005803251907),
-    ("0xebc93e20a53dae815e69a7a94bc5e49094c441bc", 15531537516813295),
-    ("0xab797dac842ec13f3bee576b7bf4817591b72c52", 26629294164865256),
-    ("0x96dabd277c9533249fe3f175c9f02ca63ad7eae2", 96112721730523975),
-    ("0x74b2efa5f40782cfc7b51f4d88102187e2ac7f72", 16904196746132307),
-    ("0xa4a1b9d7c31584ec45f68606fa3390a324da28e7", 18543435344078771),
-    ("0x12f0d2cd01b4ceaf295956174339b6238697c42d", 14630790875965946),
-    ("0xf7a6365d342f6794ea2773a533995556ade0cc1e", 28641366775344070),
-    ("0x69dc076352d459b680ea51f65d28d516dd9a3f74", 121054579585589170),
-    ("0x3ab39a28043d53b92ef6746b2479acb178d56b53", 10567726761304503),
-    ("0x2ea02c3971e8a04577939832a88d4c9c4ec2e98d", 16984298862808162),
-    ("0xf2a2d9e07a818528af5778a8cc37708f51740514", 24977295685874112),
-    ("0x9ba8c659a42fb7e33a1392fdde11c364649bdadc", 15353269643306860),
-    ("0x274d23dc91996cfaf8f14082d0b9692c312cd30f", 13913139870732714),
-    ("0xfbf712df1d81578117e0fc83bc75785831def6f5", 30159492608333421),
-    ("0xc34867498bd2d6366e67e1b02c405a1859a4a10a", 8620906368119769),
-    ("0xe83becb1929d1ab57e75686ff4bb498a90fad2f8", 60858847932151341),
-    ("0xa397eb4360826e175314923b97d67e9075b8d2fc", 16957514664986325),
-    ("0x25738f692c9a743c431fe7cdf5021a6d3beead95", 81074905715197683),
-    ("0x008ab049959cf875bea7165da3e1f74b5c2dae77", 76400132384250567),
-    ("0xab78c1cb60c9180d24a1fc717ff0782a1978cd88", 20181808937850508),
-    ("0x90f3c74571289a540f496215a2ee4dccd32686e1", 8634955839975112),
-    ("0x302c04313da15b46cd8b5109a29b8230760151c5", 59311892770161532),
-    ("0xe5dcbbd07e814c7751ad5c10ff540fb8fb79a543", 46644719491174502),
-    ("0xab29b0fa76166235f7e280b5f4b3920dfc99b2f5", 24456508633205494),
-    ("0x5715c82fdf7c6125f08afc9d15919d5a91dccc8a", 12737413846469382),
-    ("0x757e90d986c72f136f0c7364d11d089ca68a3d20", 26654044371319628),
-    ("0xba55eeb10a46707077fffb1b0d918b4f59964f14", 19811429480123337),
-    ("0x81b88bc4a45242e9cc61c147b02f63c9559623d5", 35948121431713597),
-    ("0xcb6ea5f79a6ad76c9bb6ddd99af6e6d2f086e8e7", 2379313632955113),
-    ("0x39f6e1a9d0c0bfddf54334401650973d4f2a5382", 82793087926351033),
-    ("0xa54323352884a6316b8ecc439806c2f7cecdb5a3", 36174101721245387),
-    ("0xea6c7e4bf604bc7b362b556575923e0c5e1c6f1f", 53779400569005528),
-    ("0xb15f228e4e1d626ed9f4d7acd7dc6798fd4542c9", 34484641065764162),
-    ("0x5a181b418407cde1adacb05a412f45632c3406c2", 19136470374975703),
-    ("0x2de92f42391e9d26a6bffbddbeaf1ccc7e492237", 8776697225340790),
-    ("0x5e033b628c584cacee248cdd55874930852c9c08", 11899815652344036),
-    ("0x302f39155f77ce9d765699bfc2bb73a012fcc8ff", 109605342983014006),
-    ("0x1b1d0b5417190c1243e9092a63669aaeae7ee24c", 21240489350646351),
-    ("0x6cb0ae57acc68eb8806beb943e164854239ed8e0", 16597647200366824),
-    ("0xe23aa57055fa831b4220aad87a3ad7a0a51adddc", 1727750678384688),
-    ("0x42d5708b40c50664cac301a65131003479f24232", 12717864479914643),
-    ("0xc012a13f5b189386100693e6384910a09bdc5d05", 68445317805503665),
-    ("0x4dd4e01fa3319f7ba8e3c914815ae34949357d49", 121865295757410078),
-    ("0xa949eeedfd08ad9c91ccf579080f59ec4cb25315", 24723856565811337),
-    ("0xaadddf5ea033bd2fc83945d24419e2f1a3d24e1e", 14463612099321780),
-    ("0xc5a73956a122b5e133dfa7a9632501d3af58f6cd", 51248971660675970),
-    ("0xc886e5e6b8cb6e8e3bcc973126b5caf571eb5c4d", 15201259040472186),
-    ("0x304953afedf3b133c51dfe50e74f4bcce1496ee2", 28172811955149180),
-    ("0x3b8b740bec683847c6850094160e479e8e205ce6", 11445924211692314),
-    ("0xd7b72ead65bfefc0f97a9058cdbf32ae13dfe9ef", 93529697576419855),
-    ("0x8942b8a239e374b3ca9664c37448604a7fb3cea8", 192121826056770676),
-    ("0xb4ef73cfc39a6c5e7caae2a895058c0977d2c919", 22192449314520961),
-    ("0xa9ae8ac50d28cd697d1a817e76c81245855f6999", 10831096908884255),
-    ("0xefaf05b3382298a2047b17f5f09572eaf6477247", 11587501795740593),
-    ("0x737d1be798efa2278fb46807b2ea3ec36397f5da", 11591279665846123),
-    ("0xd429fb20bbfa3c37e347a74a061f4568c721bb56", 82330503406227819),
-    ("0x7e1612cb14a2d89badec09defb2bd463935b57e4", 12629882490572301),
-    ("0x979ddb08d484a6bfcbc227b56e23d05ab3565e92", 4542322546628064),
-    ("0x8eeb040d57a53131677e3875cf7c648709b12108", 4542161368637907),
-    ("0x01cf9da36bdb01a3fcaf2a09927d43137b7ea860", 13640167132828193),
-    ("0x8f334fade3a461dae41b34c98a0cdca0d879f0c7", 13870604870355940),
-    ("0xe32fd8fc212fc8440b6034fd61d709b06d910735", 16046669898516550),
-    ("0x44b3e0d239dee0120ebac141408c449a475515e4", 333341169153579241),
-    ("0x3c53816c3bf75d641430bc689f7a90c107379e36", 368830750404464095),
-    ("0x75d0daf086a64b4e6870c7e5eaef79d858a29565", 12346012229596965),
-    ("0x9226b173ffa353cac6ee7faa0b9dbbba375247e4", 19969624128081989),
-    ("0xf84b9e57628da8a8167c0f84c266f70ec0863510", 22858081253554170),
-    ("0x29d78f315b4f612a500cc3bf4d7d6d1d64218e58", 17385119277986893),
-    ("0xc0ffee37964a6e044863f3fc0ec0ae4907a0fc7a", 104246777756744733),
-    ("0x3cb372d164bfd744905451daaf25a6bacf3fa962", 9833301089443089),
-    ("0xb72733a034cb3ecf3db33106c8a2136b6f33958a", 7073281494920382),
-    ("0x2980cdaa6df94ed9ac630784a68a86477a4480cb", 8372318860278238),
-    ("0x03069d347d647922b9acb3a73642118ffbdff42f", 31506104083271452),
-    ("0x11f40780cf849d0fd77d70add51acb36665d998b", 25714617561464027),
-    ("0xeccf36330123dee521e39dc1cd600c1750497824", 28086477641471229),
-    ("0xc754bceb13d663cd7911028daa7d4efec2b76d9d", 12917661331231172),
-    ("0x870937c3fd7e2c86f1783a087886f3d0fe5aaf31", 232235216613592189),
-    ("0x569feaa77cd01782a93d8533ace4400efd746d4a", 39512590606066183),
-    ("0x5818976d8629f02c440a28d3ed41bb662dc3b614", 23182050575245710),
-    ("0x1173a4a3258817006e2508adcae32279a0436954", 208967574900193610),
-    ("0xd89187da9c93f238002534faec2043ea028077d7", 3929665081445961),
-    ("0xe1bbe96adf13d338d2cd84133c1e43017330dcc4", 190670976475144078),
-    ("0x493c8525b7b0604ec681a919b5b1dd96e5949769", 8484779308604486),
-    ("0x482f17a8e12eb90967918833c053ba8e97d869d9", 39076929033598006),
-    ("0x0d02efba1e54b4f4557be17603b140fb7d2f9c7b", 23937174007426807),
-    ("0x1b66e63bc0ae466ab06e56c2346050ab9e45ce8a", 15065103535031935),
-    ("0xb17d7054246e6ad7cc8c18dfb1648ce5a7e1741b", 12000012197036360),
-    ("0x3b9dc60663d746bcffbfd35aa88953269c84f68b", 19835367928914456),
-    ("0x8708ec40c759a6b5cf8b211fb87a762ab84ef4b5", 192174285456450908),
-    ("0xf1690d005edd1646686a09f7e9a27acc00e424cc", 42504290503828927),
-    ("0x17966af411c70b572a058917300ad5bd92de0e72", 8792636025012332),
-    ("0xe0c9fdd4355d0a154b162ef4bac645fdc0213b0b", 17165276231957201),
-    ("0xf2c54e247492de97bed250525e2880e48506b01e", 13858047766921938),
-    ("0x5ffaace27e191c2c91709ee4495eb8bd7462ee98", 11620730435802965),
-    ("0x666be76ffcf607b75a2d98669198873b19561777", 6615541941348200),
-    ("0x6fae4ffd319b672bb8445c5a9a357f6034868e21", 15036387502546558),
-    ("0x2ecd36fad6d4e68a384e1928c252b03c6370cd90", 12243348831578006),
-    ("0xf14a04d18cb5ba0fe293f6157cd4593404c12ece", 150937429005725881),
-    ("0xd041f2dc17323b6e6ab32e3887dc36b94e4c03fc", 1672087284424),
-    ("0x162c9aff9286217702fcb683541b68b00e6aa2a9", 20581900911953333),
-    ("0x16ecb7ca4445b28211631d5b5eaf088c4c3a1045", 15360041282365213),
-    ("0x979c45b32d9d97793a8b65944485dec85a2ecbe2", 19866615531172727),
-    ("0x0b96e093d3c94ba3396ffe86158bb9cdfea4ddd5", 18387132279187566),
-    ("0x4c2a9d689d257c3519db824a2539c94165783ac3", 268064867473360831),
-    ("0xcddf6378ebae46396269b85eb58ff26fd1f6b6ac", 304038921902401146),
-    ("0x1c144dec3e6a22709d62f9783cfdd5d40d108be4", 23826748952473234),
-    ("0x0cdc67a39d4f0951f414cb508801e54e019d5171", 104376995747821769),
-    ("0x2fb965447d395cd6dd9ac004e997099a7697b0bd", 42118156266743030),
-    ("0x44e1a0c4550caad358657174f84be8c6bb303fd7", 33929646247964259),
-    ("0x902c5c1ea4374a3996ea35896a9129f16ba1e26b", 3250904313922696),
-    ("0x2401f38b7258e8cc75ea0cf6e23d9a632cb96cbe", 108603440211467794),
-    ("0x28278e1dfff99f8afc86abd9dd86899a16d09839", 19761192457968829),
-    ("0xe1fc65f3dae174188475ad866ae5bda3b8bd2d0f", 35666826450974426),
-    ("0xdd1160ae1ae6cdf0263b68d295bbeb36caef2bc9", 95710624827336989),
-    ("0xff07aa7b012ab214bfa287f8c679a00b2b6101df", 14001505812287855),
-    ("0xb1c5a159ab88600f29ff99d69f19cfb4bf2515b2", 11921683829735846),
-    ("0x68c57f637b92db1f3c323b1fb4651091c4f0d229", 30849440673888747),
-    ("0x4aa0f9dd1dc25aaf193e9e06532816143e1adf96", 42019670096285028),
-    ("0x299ace548ef2885f8e38ec10c55b052d1cdc121e", 19708784762836426),
-    ("0xae8ed9db9337da2484784399031c7daa26e6b84e", 98855099672181502),
-    ("0xc7cbc2b8b6ead19bde69590b0cd06d7ccc602736", 39857810635767521),
-    ("0xa180f92e941f317952466e2b932f4386beb48c59", 112671489475553981),
-    ("0x9d3e7c85e1594492215770942df30e7a93fe9e6c", 71983522029753554),
-    ("0x804604f55fe79ad0237696465c57d3de997c14c1", 39905651385703816),
-    ("0xc3a873a39d2a382e5fb4cd34ecc20792b03be6d6", 31725898023040936),
-    ("0x9c6856657ad6df9815efb2fe6f4d95263b69fb9b", 36292573163511239),
-    ("0xa5657b1aac2cfe5ad811a6eb06c6f2d05f555f17", 77544166481972407),
-    ("0xcc95c2e1b98842bfbefdcebe3430087dd3edc9f4", 81422950847610758),
-    ("0x71a8c7e97683227a82443bb94481fe8d5cc5db61", 53111915910399604),
-    ("0xf06e77fd9289763c1efda7b6901f388f47656102", 11953816496958245),
-    ("0xe569d2e298ca4ac8228954a985949436cd65259b", 20639818823589821),
-    ("0xa9c9e19d0776c94ea17e33a9fba524f65ed9dc3b", 7498023775815824),
-    ("0x0b2bc27be4e2be46acaee245383f7fdd0402a874", 507076674176868210),
-    ("0x05f830473795f214ed77005777374d6e9e4191d7", 27581375401537774),
-    ("0x30f484e2e7b5d37e3dca0aec19ff07892dfc2610", 13326856309359084),
-    ("0xf5d78d81ad72d585492186f5164bbf9449429c2f", 31659408496828853),
-    ("0x1cea244a27655ab086da77e9b2b15859f133ebde", 43151722323964002),
-    ("0xcb61958fa37a1a22eee572d8ba721e8496403bad", 108733167138678411),
-    ("0xa5f7b6ed26de95f1a2c18d6760c8df9996daa0a5", 2852614120543337),
-    ("0x26026fec6af3404a2d00918891966330bc2f36c8", 254810167223927339),
-    ("0x8737a79388ed91f87f1f4fe252b5617afe74b348", 15513071860677593),
-    ("0x9a1332a6e6905b2f0791565e30785e0ecb7bf2b7", 210198485749802559),
-    ("0x12d618852c82e30805615b28c89cfc39d9faaffc", 20904726335645274),
-    ("0x48b58f0317956e24253d8a4e9121b686ba559eed", 39355514408753479),
-    ("0xad8c1018f1313e0f18d8cb63ef95cb6a5764b674", 24860887294601015),
-    ("0xff1b9e075ba9f3a32c5af4836923897f7f473875", 135369146880630684),
-    ("0x384b079dc97824a9a130b9fdffa13c3ed2b863b1", 11025183911193224),
-    ("0xd3319441e09101fa48debd7ef95df768aafb9794", 20660013626189587),
-    ("0x0b58680e185e65f8742ec91bf53e1a6d23027ab3", 34603508441783271),
-    ("0x1800956b77ac0d7fdea46cbb5d375a172ce7bcc1", 32116957834173172),
-    ("0x9558702bd1b2d047988fafdb84bff0ddebdf942d", 55244389666625789),
-    ("0x6ab6cab82bc5e620c4134b616c80615653827364", 374788458038416022),
-    ("0x006a5c0bbc6ff6344a37c5ea5c979c57bd3912a7", 11332335915120488),
-    ("0x9b0060427e271f8fa67ab5a63e9b7fbfc3f16612", 134864515199719266),
-    ("0x2f8899a45af336f38f795097c8d61f65bfdceed6", 11409585449767096),
-    ("0xb7fe065d3289765b6d54cfec1f6c191425365d01", 75619613923581353),
-    ("0xe69c796be34ac496c744bfa79d442ba64ba85676", 69378739790380060),
-    ("0xd62d31234b947bd7c68e5cbe1ff4f8f65e958a1c", 13742223054816554),
-    ("0x56881e662560db7e58fd9cfefa13bf8359b6c7d0", 38361114642144378),
-    ("0x24e0faabf11b75c53528b613bbd07bf8955b6e58", 33039485371306546),
-    ("0xa7494548faba8ee06cedbb61c3fa5f77b15a0517", 22699897153428980),
-    ("0x1ecef218af3a246fc32875b53c90dc919f853872", 154529460503309462),
-    ("0x41ceebaeb36e7c4755de8c0aec18ece2aa25b1b0", 67370529507334529),
-    ("0xde00c80b99e09dd3c0978fa2a850c0ab560b3ef7", 10486375768410948),
-    ("0xba81988adeb9761e51dd6a3bc8aa773561a5d7c5", 429714794254807804),
-    ("0xd8b705adaefb16b6cb3b62f4faa0836086f2298f", 457469083304731543),
-    ("0xead2f27a0363efea7b417790dcdcf1c935ba15f4", 11562331402182185),
-    ("0xa24a213a42ada487fcd429678cfda7b6f7419ddc", 11006706363676530),
-    ("0x69a35492113d8dece5de61835402f92de39f6753", 9259887551201188),
-    ("0xf5eaf9a0086067933b056946cb768d3d78033d8f", 55793076045233678),
-    ("0x58b625399cf5f4ee2a734a258e115b856a0e415a", 106981080931940579),
-    ("0xe93dc9641ab0d5f6d28d5719620e4aa9fa5c17b5", 84839603770573272),
-    ("0xe4914b8cd5a24b1e2b6d42ba887bbfdbd5439705", 16195448359243217),
-    ("0x2d3ff726873341ebc87095dd9c9660f5df3ecdd6", 9020774888865535),
-    ("0x7bbae228f2aa321ee03b33ebf67c7782c3d9cd0a", 20000421500734755),
-    ("0xde8d5ab8312b45012a5a54dd47cfda0676b83f86", 66170231601643121),
-    ("0xce9a64d8fd62f515befea0dfb58376c03b111f73", 21489178035715102),
-    ("0xa511ef4147866663b25b957256aad11a6e979c57", 44280625091999394),
-    ("0xd245230d44d943f64efcb9877af605c732e28844", 10791642935222979),
-    ("0x38cf2669667b75b9fc2e58c1b9bbd4b342f60935", 27628902791154559),
-    ("0xbf7c104eb664111f570500a1f69d1df19ca17e00", 24336361463326786),
-    ("0x5f98fb3bfa6d49c87c88be9ad646d03992578243", 17319609708559575),
-    ("0x31454ad3b6478c3af6a8e4ee78c373a1acfc569f", 11802755101860864),
-    ("0xa8c569dc9f99170c1fde93242f2a86fa1d0c9047", 28975361000349815),
-    ("0xbf234ce9f90b22ac6d328b876cfee0af02de762d", 25117894269936635),
-    ("0x2b96e690177e4ada38318e281defd048b1d59f78", 74727854999269054),
-    ("0xfd0b62d39878bd9966883db6d4e984d7e35233a7", 7404911803929937),
-    ("0x0aa83783360de0d9e9339b06ab78632dca36dbf2", 141201648124763962),
-    ("0x4ba2d23d5f04c90ccd424afd32bed0ed721b6cf8", 96266246692626673),
-    ("0xd85ae55864bb2f4079b0e2ebd8ea0a8cd1e6db1c", 68916949404808124),
-    ("0x2722f4be5dcb735752c8b2c5c7dba16b36972e76", 32612444112577271),
-    ("0x80459d9feb2b1ca5ba0814f3abde503296e8bc66", 14751524867845363),
-    ("0xbd3244fe20e6a4897f3454287da7ec125e9e9abf", 12297222579567245),
-    ("0x5b75841b2240a49a48c1bf5afd9eb9047b716216", 235260440824923469),
-    ("0x4cd4f1d77641e5cee823032fcf83aae591e790ee", 19743932446930133),
-    ("0xf03abdc9039400ed2434218415895ee2b7da8b7b", 26946204472567624),
-    ("0x2504a804502afbf28245e02dc93fd36639a9f3fc", 15000000000000000),
-    ("0xc47c4c896afe31e0c37ecdb54df7836313b6504c", 13580599569198246),
-    ("0xc0f9797f8887fd6c1d153f78bcbd7dd558499e38", 5659123336291794),
-    ("0x1c2d247445588a8d4bf6c238c2a4131d4199f6a7", 75912787162677961),
-    ("0x896db7c827e6dc3635f659dae77cd04b8d6cd5e3", 17984887598665791),
-    ("0x1b494fd2e63f7a2e959f80905ec15cbc1813c3a5", 11377952397902773),
-    ("0xf8236d7e881d273047b0f53bc6998f18171991a2", 215667000901727051),
-    ("0x9376b71f8327ebed33d46c2722d213eeb67b635f", 2004436863197947),
-    ("0x0c40db5638389c726924ae5c24089b5fcb1b7a14", 54863504964418666),
-    ("0x54ab009ccce6cc1c093acc0570cb12d9dd6a3688", 51495071788831735),
-    ("0x0e3843a33072db8f97ed2ea32fb9122a0b0b17e9", 106557540801006315),
-    ("0xfba48807e041791950489be9dd7b884723fce2a1", 8066994212964826),
-    ("0xdbaf073a508411e652d23e655b473191a0d3356d", 11874470137232247),
-    ("0xdd701212f6fe99e550ae650a13990de34816ed7f", 11163466577827840),
-    ("0x343a19a57399e3b9fd5a0793c1b35514fa8460ca", 40966719920723345),
-    ("0x618d57d0ae73c32c01906ce9c7a30e0cb4a0909c", 6419152733304202),
-    ("0x2e6e21188cda157bbaf6f86b63a18be204a346ad", 11824099841082695),
-    ("0x140bb589a0906c6c0d623f5715c6da8772186bb1", 21788176796699199),
-    ("0x1ed61368f6d1402acf2d3ac59f1c64136e6b9070", 123596321920881947),
-    ("0xe72fafd6859b6e496e3c380be3ab99472086ce17", 12110427483641211),
-    ("0x80822460737a424ed96f18ee95e75d8bf99514ae", 11182328918930523),
-    ("0x500144b9948f68f6efb7451ebb58ea2b833ffa60", 19684320553251835),
-    ("0x484f4defdaed9404ca7e53756c75b0b2335032eb", 10397957062887810),
-    ("0xcf8a2a1f291bf2fbb656389e62a616621cda48ac", 31667299904263546),
-    ("0xfae069be0bf6bf0a82cbf3b472ee77373aaba2ca", 21672237051663187),
-    ("0x22310275a38f0c403177772ab8e53347942eb623", 26427179283699299),
-    ("0x1316202007eabd3a7497af6aa8a432aef93c0388", 222892832090791647),
-    ("0x540389b43efcca02853120d62012c2a8ba962c4c", 76113316887907319),
-    ("0xe1d79b0faa2de1b1a4de6202e10965585ed69cb2", 8854209354320778),
-    ("0x73f72cb9f5ac80fa6a763823998956e734dff980", 54583818350217928),
-    ("0x0d6b1c75deed4a973e26882d4dd34a76c25518d8", 63948283442341498),
-    ("0xb5d2e4d4d1f10791188fc32255ffa42dcc3c216e", 12921914224674523),
-    ("0x94076dabe197081b1a981be19b0b55cb456a71d6", 15820009420887627),
-    ("0x6ca2c5c95208d82749bdcd265533dc7893c6203b", 12092305192947057),
-    ("0x8b76657931d04e34749a82dfedab60a7f9606ff2", 103290372878458893),
-    ("0xe8cdc180c47069ca4adff90c9a9a0caec1dcc7a1", 765138460812567435),
-    ("0x3b6011e49a629edbfdc5e9708861ff4d5979507c", 15790318607481324),
-    ("0x6bf7550bf182b8428e2cf4fc640ed884ff8c7a57", 31109055462182732),
-    ("0x8577421a783ecdb4fb7b3d182f5c09e8d25d3e4a", 183590056916935920),
-    ("0xd082013989e81b8fb058c8cff5981f006d590d71", 19261128254701673),
-    ("0x8b926963260c0b0c563214f097b2837d175860c8", 100149748515891544),
-    ("0xb617e8396484da041911aca60511279c9babae22", 25049423878917557),
-    ("0xd12140fd712cb5638549b10f77e97fc4ad3ebb17", 49241337199856392),
-    ("0xbfd24f1db15bf6d7e86c2fe984208f07cc538d80", 37669376825969952),
-    ("0x150d539ab35803f5cd755fb5a1b3a760d014344e", 23478879431597370),
-    ("0xf83691ae70f81ad41742bb2f36f00d1f2ca17985", 43550289535404244),
-    ("0x51ffca733b69c8b6497169add36feec36545acbf", 21779056092629452),
-    ("0xc3207fd7a7d4188eed57a89eacb93c59e2b84083", 40667060138139926),
-    ("0x9f85065905823ce12ab51da16b6dadf47c8c9be6", 5619346867536000),
-    ("0x3f96c16c31fce6999baf0f910c820cb4276d233a", 37616030444503428),
-    ("0x041bd8cc03c47c024d5ba917e26f3ad325546459", 14595265314777396),
-    ("0x1c2875bb7564ed2c77c39e5528a3825879dce4c5", 5418268709236110),
-    ("0x6a4f199ad1f2f61f96ac371700eed0ca58415a7d", 59164022882691673),
-    ("0xbad2ab52a70a7735efe69050bf8f5def097997e2", 21745537536504698),
-    ("0xd4924261323dac5faad8524864d35d43d7190f92", 44093202367941998),
-    ("0xe9051d5f510e97186f8580bab799a138bd974772", 30617996397422783),
-    ("0x26b7eb97b9be22e96cdeabe53e6a6be8d5ed4a6d", 42476807976360284),
-    ("0x9daad031005a1d664e8555f53ef406b32c729bf0", 40202220509343669),
-    ("0xd62591f4e6a6bffa4e5256524f2659d2ea0e9b6e", 92365062863584347),
-    ("0x3f7865b5ec3e4e6f22650816e8aa39d5f625893d", 22182595594701712),
-    ("0x8d3ab912ded3c92de60dbe1c785462e79b5d1b15", 73936929128942363),
-    ("0x79fb9867e8be703585a12ac819f79f6479619369", 17311169845681512),
-    ("0xf05015ad192744ce3d42f835e5c92c6103ebfaa8", 11966359656658751),
-    ("0xc093ec9735d470948778f2f334f5099f49a97242", 21373730725344643),
-    ("0xb0c34a221dcc38f6ce112b7cdbb39e8c4e9e5a08", 4228554629287522),
-    ("0x6b35c6e653b4ce3db40e9547fc4a1882e5be98bd", 17212587669101358),
-    ("0x7af46522bb66db6eb4e38af4574020ec6e05c10b", 16349096153495958),
-    ("0xc84f3a2543087545b02ec44a9282cc6b7ae10887", 12427944332213351),
-    ("0xa62fd86a4e1f8691736489dd648ad735461f4282", 73782908229900676),
-    ("0xd1b81213599f5ea2b1ba53738425e80da7b57b53", 56802577767204326),
-    ("0xbb9038d927c2918ac21a9891bc4c9808341c1a41", 24939793200612178),
-    ("0x90545595635f14a5034f953b5e1e983f6049b77d", 1534034299705293543),
-    ("0x0afd570ac9db7d75122c39daa453c104cea9d53c", 34428303758209377),
-    ("0xfe42f815a772a257917a11eaf6b86adfd9f9543c", 35124690949754506),
-    ("0xb289806a6e99cca9b190e5a9bc7d5729928237f6", 109784739417184595),
-    ("0x4dd35a41a33c84f6b9f7cf26a94143ea993ecf1d", 36629894206780373),
-    ("0xe25d56df6edbcc226b611cb95fddeff9cd6d88b6", 1048831837146783718),
-    ("0xee9fff4e526b869e864945c77e946a6d629cf1bd", 28131555561410053),
-    ("0x32a8fe9f3c41a60a5f7e872853f799cef5d81639", 182021779477306700),
-    ("0xee0c15548c22ef9ba84059b6e746b412845a1c84", 21091610426143676),
-    ("0xf2a81c91897fd63d60e46ca141fe37c281330324", 22534696555570710),
-    ("0xc91fd88bacb7f6d128217ab7a6aba55f56bdd7db", 36908206371424625),
-    ("0xaf65337eadcbc60a94cccab61f187c2025339ec5", 11189251716581661),
-    ("0x662e7e450e3d1d2c7dafb8d8ff37b53da327df97", 258317769261606975),
-    ("0xc9c4327ee7793c9f98bc36ae976d9b78c5650c2a", 20563909098365537),
-    ("0x66dddea496f63104fb3adeff07198273af831363", 11584686130715933),
-    ("0xaebf28abe2725ecd4d0222e4a8359ee7500978e2", 10918195979198561),
-    ("0xdb998fb07518815828200f6c8d79eb40b2f982cb", 21915739074459191),
-    ("0x5e53f5539803b633fb77f3b476cb88e1225b3418", 37463320414097609),
-    ("0xf14c579ccd5a476cb6fb2388c88841ad7f0971e3", 13272815817018730),
-    ("0x41b72ebc7d6c9f54f8405964897184b0ec9b9814", 33645342959838707),
-    ("0x839cbbaea05af3c350ee8f727d202257abff9160", 36640604025465162),
-    ("0x6f101b799d9eb41e7f03fe3f193b65f592a11ad6", 11560098264697400),
-    ("0x7c0f8a8b90320a2e42758dd5acb08ee97f9d4f14", 20904983534051460),
-    ("0x0e566891fd3da715c4e6d43f7a079973bf126995", 17515724069466992),
-    ("0x3eb3e087520238d1b8d5bd8720fd352db18b541d", 42129032338631963),
-    ("0x7fe17709dd77a41eb90ace61903bb1dda7e8642c", 50538131312031357),
-    ("0xa48751430218c41f42504b8dc5d4f47ef4a9ddb7", 25579691782249644),
-    ("0xae7bc056ebadb7dacb71ca5897776e4be1045044", 14063894834749080),
-    ("0xb3fb6e847338fa5690d0c601094d7804986f5c72", 21648385412918785),
-    ("0xfd64a097f72382e6d32a0712cdfdd45cc72b1a03", 17259421910787886),
-    ("0xcd6922ed12a53ade89c3a853c0da3be00959dab2", 19191648414184104),
-    ("0xb27789c3bfda95c9013a3d0d4fa7593c6f8ddb53", 13860649462187560),
-    ("0x6895755e525a6c3c1270479bc958bc17bbd18aff", 17130768377960084),
-    ("0x3643962417da87156836052af92647810dc65d49", 43447120447164477),
-    ("0x529406133350897f241aa73d81119ffa0b88a368", 10769649032858552),
-    ("0x5ecb233fc65dc2d0e1312d3b25da112130f793bc", 37515649138274641),
-    ("0xe4e52c6a0e560397412e0cf995044ac2377a480d", 13193950093432264),
-    ("0xda23a6a62fb951140d7f4e58b1140fddb6d41ca9", 2302470922375996),
-    ("0x902e1e580e0869705f94d638fc9d5df7ad011d45", 35443513282490353),
-    ("0x478323fb45ff494700d22493ad8a1b29de6606cc", 12035618701749771),
-    ("0xc74e5768437290da47ea99757526af7eb445301c", 36017274823518751),
-    ("0xfc874d67b624f93086e64cb55e806c96a8b25a92", 45246006927932544),
-    ("0x03d0836e75e913b9d01e2d096aed6d83d52107b0", 1551629887019167),
-    ("0x5cc69fcee4e4166c46c9da786b91d0628b4a6381", 26079836313878002),
-    ("0x4f9a772c5f934d9656d6263a2f556031279b9925", 54506106856619820),
-    ("0xe7f47716974382f9ab86303a4af6d4b2986df017", 15797038753659081),
-    ("0x255984e224942259140f722e745e611ca3701861", 13522349005826073),
-    ("0xe9cdc93af10dab2a19a64095fddeda19d05d90c5", 30019657991051368),
-    ("0x760fd8bc09188d801d446d85c9e619e5e794c1d6", 64732340073131093),
-    ("0xcc83ca391a0fb68fa1283dec71a94d875e8a770e", 63218238120993384),
-    ("0x53bac8e44cfea780545057c2bcd6cb86619131b2", 10006857807987266),
-    ("0xcf3ea2759b667a9e11e25dbab5761b367bf9268a", 8285663684568238),
-    ("0x124a3889d67b3c852afa155c583d7c02448ef325", 26317459013875793),
-    ("0xfcb1ac7f1ad0a9cb4385ba88c700aacccd02610c", 12787891634920112),
-    ("0x9970566f52a71f5ae8882931a4d5b05e71d2c62f", 205923012136107783),
-    ("0x09ecf3f9423d2c60869eece947ec8199f788d1f8", 18125248703432742),
-    ("0xa181108a7d90035639c83ee73c377cde39565186", 58756840427636440),
-    ("0x73de9385b9209f0759918406cdb0d52a5a73b6b9", 50980856730226995),
-    ("0x2fefb597aebfee18ab9e7905044cb98fee4f5f12", 8567345025769128),
-    ("0x22cd620226ea46ff812e21e4b213581c6c17c3ce", 55472272234087150),
-    ("0x8f63277257a7eead6de9cf22f809be1de12a5e12", 19062021437970466),
-    ("0xd0f6cb50266f433db5e5b1f079c804011185b4d3", 20883758386842662),
-    ("0xe32b87283e44c75fa19a256bf1c20abcd44465c8", 20671558194625038),
-    ("0x87c40bba9cc586463d416561edc0c02dcc5c3d47", 13396752585934858),
-    ("0xb81200d313ca552b237fb8fd2419ffdd6e3f0b36", 19089780587514098),
-    ("0x3ce40df24f8d80d892b44932f5b84dc1a885f934", 8735724839327460),
-    ("0x858a1c033936980aa002a0e09ec9d770f8ec0cda", 163552352728291947),
-    ("0x94c88ba7f530a65b32619a9110d6f0fbaf344a14", 15218704508892063),
-    ("0x8a3145eb3c794597115d2ccff0f0908e73a01d2b", 11058557587653366),
-    ("0x01c2ad8753aff72257029399fee3e975fc112021", 71521500710510076),
-    ("0x85d56d68e516760529a6a35a0a39c9448f3643fc", 16285825235850034),
-    ("0x59a0823e48d88cca26cf831b1dadd3dfedc0b29e", 11161210281982429),
-    ("0x4803d7c3db77ceedd192975c3c422c0260fad0dc", 6168699993274654),
-    ("0x497d32a3f4e9a4aced0a485c905a167b0517fe0d", 22614559609461365),
-    ("0x5ea469ef7e41315c7505163c16bd9ce77db5e704", 6167396805659136),
-    ("0x99c2f0e5e1acd9b15b1319b3cc0c9d4cd0c6b572", 15166819347050658),
-    ("0xff78faa55f34cbf373184f8fbe8fb85ce272ca43", 103054350104065398),
-    ("0xc58f7b43569b70a9b638ff05b7bffaf515394b0f", 11095647316535479),
-    ("0xf1cf8c7ac4350ed9c8755462521369d7efc52c7d", 8107479236066948),
-    ("0xbc3285dd06284b28c19351f7c0635fc5e0f74d36", 102255618074401430),
-    ("0x371756e957e1982c984eabfe8b0554c449f38bbd", 3572085814470057),
-    ("0xeaa098cebb1964d677e0032b64749ddd3280b6ce", 14960397597813870),
-    ("0xa760420793da1a539198ef2617990d67a213ff0e", 43526018075501880),
-    ("0xb2534c40a25951d4719a1a30f52c3c55910c2570", 4144463249507827),
-    ("0xa3e28f22e145a7b672bd76e99e8ecbb8239ebd09", 101594906563228145),
-    ("0x4a3a00645e2cf4752f927ed41c0df8e7827518e4", 11476046599080811),
-    ("0xf389f8a2cb2dd4786c94d6bdb860b613c1f1e6c1", 9350643149439504),
-    ("0x34126805f6d99c6117181a5e0b0a2dca5a49a4ae", 211063604057424683),
-    ("0x02c52d0ad9be7181dfb12cd2e8f5f7d33e48787a", 7806093056368505),
-    ("0xd75bed21658508275ba7ea78956788859d80d238", 21352347962440848),
-    ("0x0356f06f0a7c77bf7c1dc546c706a7483f931b65", 20199123649261108),
-    ("0xafecd7e4724932971f877ecdacc1b1de9959c56f", 19488761691920820),
-    ("0x6a644fe0f5f2391f41c2107b74a03a3262bff83c", 9699720556801682),
-    ("0xe080b9043c6b736c9d34e5f30dbdac9a0efdf9d8", 35372942711223883),
-    ("0x61a0f7f075b7f305068229b1946d268a78a215be", 21029677258130735),
-    ("0x06fff6dee76735f8a45ef942198e914b370ee2b1", 17146339047848514),
-    ("0x1a338f821180e8cde231ef4b394a6a58e8e7706e", 57609684040506288),
-    ("0x119bf6564786bf3546e65b96173621a9e422c31d", 18409306813381530),
-    ("0x2c8a3d21c197797197cd590eb8ab2a0676dc648e", 19194341517301926),
-    ("0x9251c4abb67502d53d7e5341ecfe9c7e81ffb622", 8635214335548306),
-    ("0x12b10d19683a1f60d2bd7ee74280ec3a98defd17", 36028755708205801),
-    ("0xf72784fbe9fac9e1a7f7332ec0e5359ea5f0d0d6", 11347760501726111),
-    ("0xbb64002bede22d46ac9697cc398c289abae45ea2", 57146351265529825),
-    ("0xbe2d0ec6603b50a1a6ce4f8826cd4c62a389b9f7", 21904553157798456),
-    ("0x68a177d872b68ea99482503a5b3a58a9ffdb51c7", 21048835303617621),
-    ("0x26ce8f32260f05e91faaf4f26569703cf5c52373", 87066121128473939),
-    ("0x8acc8715f50092387413dc78173ec21dbb3d9c7d", 11394688415084320),
-    ("0x0b4e1eb73b5ba177ad0af45f97bd19f9925aef65", 72489050579184061),
-    ("0xf77a35541e12aaea6bd5c51f266b742d403bc149", 37903196341400277),
-    ("0x806a01f32333f55c8e4061b7d4ddc02b02f525c7", 79726515221659446),
-    ("0x69fab6a69f63577338d44d58af7d7b5889a26100", 37539460837860862),
-    ("0xf2a4518c1f71ca1f7cbf4a1c597f5718fdb34426", 11563044245443055),
-    ("0x9754c855415f9f8af3de65280aa2e0111ac9f904", 47050463128793159),
-    ("0x505ad011b47efa28993cd0e6a11fe4508f5c8e7e", 18818236759602870),
-    ("0x8e09f821a8cb0af0cb95fbe1324ff1ca81fc660f", 106549164214321500),
-    ("0xa5978536738e4f02bec619619cb1ea99d92df6dc", 11824586252255629),
-    ("0x8f47c4ac59b78a73b48b543404054b4cadf9f92b", 23814178491972676),
-    ("0x12cdb276dfbf563cd97ddf754d588d61838623fa", 8403633773990161),
-    ("0x94469eb832a83b6acca5879099041116a6ba860e", 199195399547313638),
-    ("0x1df1e9f19f4f030f5ccb0687833edb69ee6128c1", 35901999354002790),
-    ("0x0e8c6df27a29de2066e0ca6d7b6797a9f173d1d7", 20113273198032664),
-    ("0x54c98dc42d9b6f62ad325b14170ea64dfc2837f8", 14467777600585912),
-    ("0x4c21db46131d1aa493b71ce1a681a745736ee67c", 29722961653627026),
-    ("0xf7a3658b0c2e492e896f203a98fcc4a493afae4f", 10563890391130061),
-    ("0x3fce2619ddeb35d18ab1c1b4172d86401b6a8e24", 106044761804916133),
-    ("0x9c1702184e9cb88afcf0e3732a45bf354d6fae14", 33670009129462814),
-    ("0x0000000094acb89a43eac2fbb3a07973efc2435c", 1),
-    ("0xa54f21bef10dd027e739f9e66dd69459047c7ae9", 3770546073165325),
-    ("0x4b14d2c0d6aaaca81947839b8293f626c16d0863", 21763726422009199),
-    ("0xb9f10a0d1c23b29cb35b83ce7d526c00ab8775ff", 10468834460492152),
-    ("0x473c3552e15ca2d7ebbb6ba0af9bcc478c8a2b72", 41543047510893594),
-    ("0xa8175034d62fb624842549e9bd281869ba8c672a", 5001282821306132),
-    ("0xa4c92f833936b3313056652eb8ece4b76f88d600", 62883736718217949),
-    ("0x29f32adef3626b42494fa3cd1cd7cdfc3e9cce61", 37679875055172359),
-    ("0x9ed80eda7f55054db9fb5282451688f26bb374c1", 30180964768360210),
-    ("0x9a1768f92c57e7b0f609364185c92404049f4f3b", 41536805923797411),
-    ("0x47620e7c82f065fcd949517df52044ec4997c439", 89949687106310523),
-    ("0x98c045dcc378e578a40d9c9f000aa60a2bd12942", 15505548581806716),
-    ("0xc73fe4a11a1d0f6f8a326c9f214f196928e495de", 194918422980399811),
-    ("0xfc467b94d7142ce05b4777f145ec96963c0eac19", 68107094702292635),
-    ("0x58d0b741122267ec49af9f068b3b2f115e844d98", 58719014091974213),
-    ("0x53687fd7defcd7daacd50151ad9e221b623188ae", 25409286455000842),
-    ("0x449e56bd89bd12fae88515501a6e0cd50d8a0cbf", 35049929049939681),
-    ("0x5445d8f1d05b94578a1c32eafa3a80db59a42358", 36203818515039545),
-    ("0x1a347db3d372a5514a9f2cb7dda60267ea2cf922", 13017006871701870),
-    ("0xf9a86f06504162f8922ab4397081f5288374823b", 5281138040000000),
-    ("0x4c3f8bd7613e0b4ff0c27cc0dfdc812577859796", 9020401951721672),
-    ("0x232f62bbcbb6a8622b4b7ae286ed1152d40b514c", 35270239985431492),
-    ("0xe07eb4d0f4b617f7f3cfbf2bddc23739f43e5a5e", 9655817522158811),
-    ("0xcca8d641bfd2b4f3816d9374bc097f494f04ce64", 20798401298240004),
-    ("0xe221e551a8291300791f2993bd2da99a9a7b4a85", 892218637132529978),
-    ("0x499bef111e38a4914fa79564c3677935e0fcfcf7", 20107683141405549),
-    ("0x53bdffd6dec1049514a18332de3a1b9cddbd73d4", 89825988583122653),
-    ("0x4c3ac06e10c7aebd4daa17832f724fcc7b19b850", 14608778414854858),
-    ("0xaca91e79d98240d85f6ae566227effb085d9aded", 20459245013602002),
-    ("0x79e254f70854b204996266bf42cca24f258d1805", 16519314950533641),
-    ("0xe1403327e133ba40619c4ec045b71eac9f2a5582", 7604869426323826),
-    ("0x5b0261a4c63dfc571f287649907ef1256ad03370", 14911659043407779),
-    ("0xcce8c6e169bfad163c29952fab1cf4fef71744b1", 57762746307554887),
-    ("0xd65799d418905b5eb00463be54539a10f0275512", 34812450934806630),
-    ("0x67204f5c627a617887ad623892e120432add17cf", 27183013803622828),
-    ("0x16bb19c0e8d46c0512f6ed8f29e317f4291dc0dd", 102601158047961710),
-    ("0xe851bc9ed361cabc4a3a64a96f30d2747ffa7914", 35910687151941554),
-    ("0x6a7cb078f0f0d70bd348300ad6a6a735ca8c96f1", 27915736736153936),
-    ("0x47ee020fb32e0ae0ef36a429d36ad3c495a80928", 10483306455104846),
-    ("0x5a996162064559e226adb652fd3024207cfaea14", 230983872660096664),
-    ("0x9ae603328957c7e94faa0e271383dae536565b89", 15961461900192152),
-    ("0xc5822ba39771c774759ee4cf9010a6084ccc2134", 6978225153410915),
-    ("0x44096e9e76dcd3d4bb0261d1a92651f4f7811d40", 10431813738465037),
-    ("0x5c7ca6a93a46ae786d99d91ed423a91d6fa13879", 25340894748641129),
-    ("0xe8633d3c4723f1ff05cc3fe497789b5a05d90f28", 8051962619218103),
-    ("0x523e2ce4851b9b352e149fcfadc5195dc1511c87", 64796103764782594),
-    ("0x20f765df18e77422a9f10ab5aa26876cb9028103", 21790400037336743),
-    ("0xb293641a9d1e5129c9a9d4f701b6c47f28a4f26f", 7348429832291877),
-    ("0xc5d4b9b678e558c0e536a29fe8adc1f622448e17", 4626828170532003),
-    ("0x3ebc0136fbfc281a8f8dd5e7b9d235175fa02951", 33583500551369872),
-    ("0x42f653a1849b05bb6390e7aeb6bc15ff1a926760", 10868553184457692),
-    ("0xc4f6196acfdbd510f37fe3859141053f9633ffa3", 9235874609307958),
-    ("0xa17a1eb1a022fec732367cea9c66eaa98617e9a1", 7569530440104970),
-    ("0xba12fd150b97e25426c4952952465bd2d9547cd7", 105117164199561963),
-    ("0x2c38adfd7a3726769f6754e511c6ac561f1af1c6", 174483958903650922),
-    ("0x0ca05dd0b885b785854ae5db1236988da6f7259f", 26009634700048829),
-    ("0xca4b599a1deac69df773d11a9fbb697e53cb0328", 27201191610224835),
-    ("0x31ac4e706b8a13e65ba94ff9509ca7e1a62b0c8e", 103504479868403736),
-    ("0x40977234caf7c7278d552a6ab3861c271591733e", 49186890749718951),
-    ("0x5b1292fd99929d43443ce17547013b74074bde06", 28484700462726188),
-    ("0x36f28379fab2f5fbc0ea4255556eecbc7c9ee0af", 66669909986422013),
-    ("0xd9bd8b5e612a0d99ab0df32c1a24e7e0193f02f7", 33756360084913060),
-    ("0x3117fee8078c470a40d3f092ddcf364ba27c3ceb", 104782398394529270),
-    ("0x405a5dbbedc677e25414c90a65216f16256c1391", 14706491427394943),
-    ("0x7ee19bebcb2203f5b42de6f2637ebde2e39a213c", 29421741547655575),
-    ("0xe35342502b6662454fe242e15141465a369e8470", 103807062069268372),
-    ("0xf249b54d9bdc58906516e3094caf9ac3daaa9999", 49719522477356284),
-    ("0xc4973f786e644989f32a741e699b5fb720befa19", 19709646314706212),
-    ("0xe1191342e673ba423639ad095f9d079b398f65cc", 24057450329757036),
-    ("0x29aa0df2556c851c7de9a2634718c3b313d6df88", 1290756959820756651),
-    ("0xf5e681ca3d8ee9d5cf58756127d65559bd02ee80", 103102613050635209),
-    ("0xdc192aa785edc64e1256efc1736f0e348b9c0022", 5180600000751929),
-    ("0x0e66814abab2148017eb57e9359c847255b832f0", 274799612680985597),
-    ("0xc0b1fa36da647caf44be44a011bf8b3e2386fc8b", 43434615022543620),
-    ("0x8d2c24592aa58ec10b97d08cb56900030c950599", 17798834719011791),
-    ("0xd31e8ff785168d5a010f43887350edd38eeccd1d", 31236672795544165),
-    ("0x46f1667b7b452a91c9d77a1a67c6bc0f2d4db427", 17183271298479309),
-    ("0xbac7fe1787906e7f8eb3302779cc78c2201ca5af", 172319867455395692),
-    ("0x1169a6c4357b47c60fe158e0b2347fc251bc0985", 13788598910948995),
-    ("0xd1c585b40b2d97c97d9aa02e30efa8992fb989bf", 7471508525767461),
-    ("0x4890ae58845cee70b04f7b54dd2d4bbfd8b697d4", 21093667422207423),
-    ("0xeaf3d1cf11144d12ca36e1af344e6af13dd84429", 10090350411444946),
-    ("0xeb9d7174317679e7c49c4c7c1b0ad08033c43997", 1594385457653142607),
-    ("0xdd41abb3974b78bbc80c34c27833d667ae938459", 18760435665762889),
-    ("0x8ae6fd8eb06b3a02e3688196cac66de3f4edfe9e", 21647899902853237),
-    ("0xb8480c956d1ea1ba57e0f32f93be688f766e53b7", 305877969614141502),
-    ("0x5f56227e2b593c7495b574899a1e3d9618cb84a2", 34547833602794019),
-    ("0xb165d71858f949ad7272e69de5424de704c4ea56", 9328671340186077),
-    ("0x4c7758c21d442df571585f4920707202dd5ed029", 24796718956804806),
-    ("0x63207e3dcd6cc9da6648b2ded0ea074f160eccaf", 9996255765598795),
-    ("0xcf41cd6c06bb1e78e6d0dec4c696b9a483d41561", 45383001285261280),
-    ("0x2544f859827a03b044b772b02d63f42ac2c1f096", 486915370626721588),
-    ("0xa824fd41b10c08d4fdf88bf7d6e87b0847faacff", 33561699405739283),
-    ("0xd7000148d46c0b4e05dcc20f45d0b5d8de74e147", 95309209176939707),
-    ("0x497a5746765651c98fbb67d0e96292b3f0880ce1", 68318906858866285),
-    ("0x1fab34460301e1d2055a4aba818dcd623e5537a7", 84772196982383136),
-    ("0x1a8fd71ecf90e70e2fc6d9d85324eb9098689774", 7113911943642976),
-    ("0x6397188270455bf88fe7b7573a4f7bd6d8c37e06", 28239703486113490),
-    ("0xbf597d849fd8778c4568d0948c2317106445c835", 29198268183450871),
-    ("0xbce0aa70871ee887a34d099cf3d1ec6e732dcae1", 10571761294084477),
-    ("0xc493ad18075bbfd6aef1d82407b57b8feb269fb8", 17794714509749151),
-    ("0xcb363b95e8ab321c57e34f732cecd67300f20bed", 29131037515244184),
-    ("0x56ecb0ecb1117a73ee086bbaedbbb921e572a506", 8173077818471011),
-    ("0xa978f493e0bd8bdef6810d65c1c695762e1e709f", 11759088444722869),
-    ("0x70da71a956bf90ad7b844d6998eb89e31f98a416", 20872154833580035),
-    ("0x4776b8f09dd315c830ac73f60b0826b34cc39b96", 29803565400892074),
-    ("0xfd0c52eadd4d4c575bc190a670191dc358842661", 11171363028221219),
-    ("0x2543f5a371fa9aedc33741519641b1bb9a7e94ff", 22798790468924574),
-    ("0xad061a1a5e741527963324da37cbaf7fe1aee308", 95758398877844980),
-    ("0x3c2acf5040506dcb385cbcee0c7017e3d33d8f14", 14484142281658379),
-    ("0x17d884198ed7005b8c48469871082d20bef9de7f", 2044781015679296),
-    ("0x209a2f251eb0fa2548cd59c53a06ee3a1b1d4d26", 31411481155376955),
-    ("0x6b94ae71b6ca3ebff2cb1707f9c9f8de0eb64b6b", 8406701240229495),
-    ("0x5e561a4ad5bb8d0c20a979272c5a75286688ac47", 100829266758174809),
-    ("0x68bb39fb176e92bbeabec5e4a3dc70058e7842d1", 233059697387912388),
-    ("0x04192127c5483a86cd7a1624d9ee3b2a53de3a1e", 122761062484892560),
-    ("0x22a3df7e6b7d3cc1a022e213698ed20f91d9f73b", 10155893205855215),
-    ("0x74a10918c189b229dca183a58fec99eda54a72c3", 96921881957321997),
-    ("0x45bdb819da0e18faa95674390ce6505d43f9190e", 5068573250567941),
-    ("0x4f0defbfe3130e4ae4f8674ba3e4d2fea01c3c97", 79259012683013692),
-    ("0xbb91fb2c6d7f77282901ceb355403c8e91dfcdae", 15926427590224562),
-    ("0x4f15fec6d26fe0b9f8baa0648892344f261999d9", 12925050520046125),
-    ("0x01c03cfbf086583672afb774f6939e352bbfc4e6", 106355905200955366),
-    ("0x19543a13d8f5706c245b758de4a07dd760c2796e", 4462411476876270),
-    ("0x188cc7286af105be6f7a11585e2f3ae2f23d27ca", 64438377151102612),
-    ("0x93d556a9fa3b8f4453fa0ce4c4ee250475cbbbf2", 82651226440923851),
-    ("0x8dba03f9b5018effc8d7620ef235760383c17f8f", 20400000771950951),
-    ("0x8dcbb8c082f2bdf1a9befc3bbd482e10a4adbdf4", 435655182174772018),
-    ("0x41782e1ca81b485e222e2dfdec0f08e0b5078b19", 8227736882868581),
-    ("0xe85b8703ec8961b95a8ebe34e4bad2bc2a8427a9", 21167755923841188),
-    ("0x341af87095055919234e5a872c75c77872cc7be5", 135980898831008005),
-    ("0x0906f0bfc523bcd447c709821b3da3b60d692369", 17996469832867275),
-    ("0x34543aa177d10f4507014409126e499e28ac122d", 21138651010789544),
-    ("0x48c19aa66cf220d26308632896685e72ab5ed645", 17893579335372078),
-    ("0x69cfa5c20dc5f3f6fca1e40db15cf9bf7cef9209", 5987548108415170),
-    ("0x143f431a5b32f271807c11e7166c225a9837c0c9", 24556581027894412),
-    ("0xdd2742648aeafe75a1a9b76041935ad72d02cd16", 39722275356587139),
-    ("0x22b79c38bfd98a304e94f282e1331d515d61d73d", 14539244999762997),
-    ("0xcac0c54d116599d75146cd0ecc8afa717455e5d2", 7883048978762280),
-    ("0x82e6bcb9ba88113248255a69b4bde101ad8b821e", 13276917963393459),
-    ("0xff3f44c581264fcdaf2db0732d50a893df3bde44", 30765786369872357),
-    ("0x06527f9b1836cefb88ecaa31ff9854220e2173cd", 7883134411374877),
-    ("0x9f8c3fa0b0f64ee1f20fd14cc8b490111dc74422", 33271051540184729),
-    ("0xda02bd76bf4eef83fdb90c424dfa8cd6c1717538", 19007551660845612),
-    ("0xb6269ce4ede8d9b098d17bef3c16c285bbdf61c4", 7780568393482517),
-    ("0x679db7d89e3c6c5873a6272c58a26f98229b3a38", 11125039762449639),
-    ("0x8cbc715b40605e4334204430d1b7e95c251f8e23", 12122987298951655),
-    ("0xb45ed32b01a9f73d8330eee6c74e29269a667ae0", 19346188869853701),
-    ("0xfb79742da1ce5f73b3e0a1a7f81f70a4e0379f7a", 13629052447850678),
-    ("0x47d9b44d09a7b4c9dac547be7139f65653d7ea65", 60135488790988446),
-    ("0xb0b793ec27dc2a2bb2889559f912dede4cb7fa01", 67618740130508874),
-    ("0xd99a71e68b961cbbbb4050bfac148dee9ecdf619", 10052579826396610),
-    ("0x2e8c338a40c5dd1ca75271a586e45205bc562641", 4340431754826006),
-    ("0x4f1e9675aaf8eff4c98b59ee2b757e200ff7fb09", 10182331180464646),
-    ("0x49391f8ed8b6cefa8680966496ef0eb8675f38c6", 8674800496292391),
-    ("0xe649ff6029ec3f930d99dde15d73f8f08f0d21e2", 9261980841485594),
-    ("0xc9ceab0a03df4d682f8c547d5607e552e0a5acb0", 27840992518732805),
-    ("0x76965cebc3476a28d5b2f8bc3004c2bfe13cbd61", 11384920042229773),
-    ("0x693475e0fd3a94fd80cb457d13435b25c0c935c0", 7626637554977216),
-    ("0x11f10f87e180f5dfeb4a05be1143d8b500654b31", 12049389813657502),
-    ("0x706ff9d2e4f2976b7e3b1b961e95776b489bde6a", 162983324659417502),
-    ("0x285cc68666e052c2475bcadc1d8ccadf56c5b351", 73408433503173370),
-    ("0xbc10e89c51281ce497241661bc7c0b9ae94ed93b", 10036716941283959),
-    ("0x75f993552a3d9c3d79829a088a4c8de1c065e9ef", 100803250863380408),
-    ("0x00ec14aff4218fbc2403fea859243b3793a2abbf", 2599952488474641),
-    ("0xaf606a25895469a77931df91c9918a1c34f3d449", 35798914935258477),
-    ("0xb3006ca913360432881496ae1389850a32416ccd", 9560660022856385),
-    ("0xb0ced8a3e8fb8d674f7e3fc3b7afdefd07c7f90f", 68712290980127469),
-    ("0x084356d705bdfad6c8dfcd0bedf0102fc86d27af", 58217657827408956),
-    ("0xa651b10a9547d86e86f86c0b8cb2e0d1e408aef0", 66409436221413369),
-    ("0xdb6726d482cb8af672398ef238cce8591980a9e9", 220392994346386611),
-    ("0x8b8af1ebe52ec13afa88c16a0f6159291b7e87c8", 4162763129126674),
-    ("0xdf2503116a1dc9af476e1efd099b432b35331d25", 32683708235119548),
-    ("0x74383089f0e0e8e6267bf9793cfb10b7cbb76093", 15418299987290876),
-    ("0x0e34f7f36fdf80a612a5709839605463f59d7e83", 21221928086711867),
-    ("0x3d99021cc2480dc1288338696538786c45ce3af6", 20702116785431309),
-    ("0x92e1ca88f16b0e72bd7283b760bfa8b1aeb0a417", 47490515628473791),
-    ("0xba8de405851463c49cb4e371da847969d5495cf4", 211530267794372928),
-    ("0xa0844eb0b1817440b2c3e619857ed410b9e6e220", 16848494309681605),
-    ("0xa108e766c1fee92d213b1a9e62ff9f5708a348e4", 25128500342412816),
-    ("0x5d9f21357f5a7accc9abc13d8932c8bb1ba0d900", 37433732960226200),
-    ("0xa63201370e791695c75af88ba2ae4221fefa78a5", 16000000000000000),
-    ("0xb508af07a8e2d880ede266e796f7041217165495", 71055052831543883),
-    ("0x49282990c7da84e14354c6dfed258b8d00d6f4d2", 2902183564744690),
-    ("0x63051bc0e37c2d12dfd5d597c8086413c23f6650", 50190424109594567),
-    ("0x13e1138b46c822d8268ff37022bd073384df4ec3", 10961184679387133),
-    ("0x82df46c9047f3218b4df0589e44f71e21f7800cb", 238744535418117802),
-    ("0xd1cb63835ee46439b9c9047123d691e5a1f258c3", 11334991485815848),
-    ("0x2cc99afaf23aa95f03c4aa2989881aa1bf5f6ba9", 7262654786206039),
-    ("0x4bafea7c1452a3a8568d96d99d66b2228b8f7604", 40040764926011114),
-    ("0xa7174d4a8d81c7dd9a7ea65d5f6b87df3c50e37f", 56946725343175914),
-    ("0x34273309416278b22eee207cb9ff24bfd298e2f9", 29311414874215556),
-    ("0xf956f3712c123f4bc4691c856284a2cc03d938f5", 32109669619929943),
-    ("0xcc9f4b88d14bc1add1464ef414a681e35e2aca2f", 27544963488590316),
-    ("0x40697b5367e417b97c9853622644fb8aa023c539", 20627763695488505),
-    ("0x7f0413995ecf9e921cd9c0658afeca39d59289b3", 27128637928406841),
-    ("0x9ac70d88758de35d290c85a9f5b183bfc4df6898", 10581762634891931),
-    ("0xd96bcd5c00d4b1fabe17c78be94e5763f7ebf8ba", 398165308879188558),
-    ("0x847259af2b696d4a7b90091b80e580ceb76a67b1", 24440974109754944),
-    ("0x5f55a040603af3b4dcc9a205375dce325d61a559", 8237414791358210),
-    ("0x04ca8c94e03a35f68f8333a60833a1bb0254b5a7", 19480709319429151),
-    ("0xe459ae0dc31194fa0fd626020a25d9a1cef0047d", 9793390098922832),
-    ("0x0d936250edd40f83b80d49c3799c591f7130e4c9", 20391143019233542),
-    ("0x5f8ab6b20ce7f2bbca05ad2bfe81e42dcb13327b", 13425087566348365),
-    ("0xd622e4cb95548704b82b2b0e600d154a5c308666", 23285643489557055),
-    ("0x0e6dfef62194ebc7906700c936e5c6a4be94e4b4", 10313474838059730),
-    ("0xbe7d5106d80d114d4da3d64895339e06ce434332", 67907120236495602),
-    ("0x198002db529f868d1c21b4e29ca0dc3002cf1347", 27488737687888987),
-    ("0x277b704cf008b1aa036c1b3a98bb3895eb185016", 5652125309163817),
-    ("0x6edda30de5f93ab182d6dc0da900cb6e72b0f33c", 55311458920803931),
-    ("0xb96e6a1fe5962646d82a94c28714d3176c0054a8", 16240540509681719),
-    ("0x3e7393c1717b59544517579d224317197b71346b", 78600423424955310),
-    ("0x248a973d64978c3501176ffd7fe62249bd83e877", 8790878904706709),
-    ("0x58e1fd367a2e11b174c68ffc79fdbe78a7ba74cf", 68397720664653304),
-    ("0x83bb3992bdf488ac7ea194a63918852a40547fcc", 152883885110068947),
-    ("0xbd235d7aaa1171b25c52b097f1daa36cd527c550", 336095363269486649),
-    ("0xb221c223a6071fd601c87c8a84d0a0e0b094be32", 3742386009185159),
-    ("0xda145155e9d4c0df216c299f4c40b02b88df153c", 68177075218753679),
-    ("0xbb4d7848382715673d485b5a623be83d94a93880", 17549716473383616),
-    ("0xf6ef0aba93c5c98915b5adf705a2226307f3f072", 48006380255141817),
-    ("0xafa0fa3d6c39cfd425c8a854504f68c012e72452", 16241224596155445),
-    ("0xed70784c94a48a3d7adac39d57bc13ad0f365f63", 4685634764575497),
-    ("0x55921ad5cf93a5fac40284f61cc901b2fd36b4e2", 5009760488881512),
-    ("0xeac613c2750167e3ce64fee5aa07150203af519d", 14284628423898968),
-    ("0xbb34894d6e4001518594de7ee5edf80512df2076", 17509810962584307),
-    ("0x815d769145128fa0cbbccd681b3e23edd0db7ef9", 47632653626001465),
-    ("0x2a61d792b2f164c2a9e258a2f0b8407d130baaa9", 18175494425402535),
-    ("0x443010f27f924b127ff2d320001a8fb0bca38f4a", 35757308517564932),
-    ("0x33be9ac9aa42b07c8ab5652a9d1b3ae5bcca493a", 23694368498483814),
-    ("0x8c9aa28b014b2023b5aa5dab90d20dccc974ddf8", 20560501679999020),
-    ("0x7f495702b1e60826bc19297b4753c1cff972f46f", 54393066663898288),
-    ("0x131e464a5002963cb46c135f21785e639cc193f7", 151745075086916674),
-    ("0x82e5363bb0e04643cb58a1dbce067eae07b90829", 28659125651380574),
-    ("0xb4d4ece62ef15e4081c2ea7494cd95bce2667e9c", 99343902202017607),
-    ("0x84ce4e128372df63ded17dadacb0f12894ea9cf3", 27679904798068591),
-    ("0x702e76d3d38f81673ed6a199a104a954fb755d59", 48123443319977844),
-    ("0xac9356ea58183e32b9c8d6f8f4407843b1c8fe78", 9420997557495730),
-    ("0xb69426422dcbad9aec2007787aa88463621d27f5", 12571106810122596),
-    ("0x2bdfed98443908cb9c261f11819681f3cb65c45a", 102815997113796402),
-    ("0xfebe7d6783544dd82a32dbabfe08b80003716bd2", 104096669444328561),
-    ("0xfe0cd830ea61941a417bef3df4d50759e2cf828a", 32921022376624872),
-    ("0xa139fcba4ad3d26266710c0904dbdbe930c9d739", 150604484972787374),
-    ("0x7b772c2e367607c6a9f21c3e792b63be1dbc0eb6", 9541785837015648),
-    ("0x915af533bfc63d46ffd38a0589af6d2f5ac86b23", 51388199379791634),
-    ("0xb10653b6c5d870efa418fd018d32f5b6b53aeb15", 8134493302754140),
-    ("0x64309df36f926613492e3677f6ae8548031553e0", 10943272884687413),
-    ("0xa1f15db6c8e630e8f39e0fa276c3d76345703679", 78196531494483217),
-    ("0xf31696f8ed0b7748d898423ce61685d7800c1982", 10075322257008828),
-    ("0xe3dcc51de6b75d8945302fc4b6e328d9a65ae0c1", 11053188415763599),
-    ("0xdbc993ae3c8b71d230852c9b9e5d30d474e0c912", 19771429738727710),
-    ("0xedab73200e6118a7e92b8c7300b4d6ea9e6c22f5", 377529047817774327),
-    ("0x7464ba1c2b16b9ec28ca36afc2828425817520ab", 11916028577348340),
-    ("0x63905d4e92dfa5a0828e8388a4a7e07ae7583690", 34056463789914603),
-    ("0x8950fa87a542ee0c0c3c81ceb76b0c65ceb74a62", 13123419026009581),
-    ("0x1124f5885d55fca49a8b5d1bb67d40f14560844f", 12163320553085648),
-    ("0x02795d3c58e337e5febdee820bfa81149d33d529", 35392300989489491),
-    ("0xaee2ebf88b961952765d6be92dff4e10f42b2fbb", 36566049568228259),
-    ("0x9b2b4f9b09fd435dc5400bc3bd26f915eb8ee592", 2932790670449581),
-    ("0x15ef7042dca5a226b0adde57cf43664b55041d0e", 24378360877434142),
-    ("0xef750879322c175b75c9d9d15a3e51d4fbe4bff1", 14611646093637480),
-    ("0x9c2ca06953b478c772d52cd31d1335d996e6c3fb", 61350155095147112),
-    ("0x826c16170456d68ae5b75689a19f5cb7d4c621b7", 189046931948238548),
-    ("0x63c341daf9529585820c1573ecec52d87e820a24", 10172476468979997),
-    ("0xef93b3fe19749650f933e75e3dc07ef3c657cd16", 9891202587670439),
-    ("0x1523a2b71b6a0ddebfe9491c0112503b64658032", 30352104776378586),
-    ("0x0cb628f70e5d7ff75420c7335d3052bc74be844d", 11025621995123190),
-    ("0x910bf2d50fa5e014fd06666f456182d4ab7c8bd2", 7900562278836023),
-    ("0x772f2b04835b4d0386df74cd56ea27e4ada8393d", 188664115693911282),
-    ("0x4250e7eac803168891ed8a00b6a5d60963c934da", 27737020940190284),
-    ("0x3b2efa291d29b84c3b7f39ec52df19e748448643", 6193502882533664),
-    ("0x6f618e4fe055b77eb75fee30df2348e9657e2ab6", 77190517926063272),
-    ("0xfb4967f60effda0cbbd84c62a209967fc8188a52", 91455673382345929),
-    ("0xa4ce98f321db3ad8d69dfd726fbcdda2e98a0f10", 7905336298568002),
-    ("0x3b1ffa0b9c7e6c5b2804ed144e47aa7ce100abad", 14306419461836470),
-    ("0xf02549595e8444aceb4dd662ba715c1df7b1cc2b", 14762907523076866),
-    ("0xfba40f3d6877878df2126777250109f4f05f72b2", 6237411651103617),
-    ("0xe4e7a0b0334cd97f04af40fdce6dfe58c92dc2e4", 18566550313130521),
-    ("0xcb07091c0870c0fa2c9918de56d5b30e9d1f12ee", 24818474857994618),
-    ("0x747f304e9c98808f88c88997b5a0541a9751917c", 34916096097216213),
-    ("0x63dd8223916d5a9c039073fc1539e8d7a0c6b16f", 23157338258650990),
-    ("0x2dde95472535c603b8e825e90c93d8eb2c846f79", 27619660283282734),
-    ("0x6c329f46b616f799ec4fff6021bd101d80053a5e", 9990485160401988),
-    ("0xd71bd3ef02d68bc4f0b7ba38f6d1120a05d96d58", 2875231501948934),
-    ("0x2b2d4a049eac8adf22d4f6a24ab44906669df904", 54596087198588961),
-    ("0x3dbffbe1a485c1655d3917e25f6faad687715859", 55404906191507400),
-    ("0x468a1b0b585bafe48bd9980245bb114520798584", 10827116770443894),
-    ("0xb6252f3686814d965bb20191922d006e64b7fd2a", 264441942806723406),
-    ("0xc120882668346c48ebe3fc5b4245e068d6c23648", 26869558614858137),
-    ("0x11c4ae63ddcd8b494aa7ee36670800e3db0011eb", 12129793941281347),
-    ("0x843bb80f1e5f07a2d82455d1f93a7db9a80af2c3", 197836636989591020),
-    ("0x07de6d295098d4ba07a1b32dc0643573abab58f2", 33232152238532304),
-    ("0x396a942301b345984c60f288d0c7c6b9fbf06ec2", 1196155279507254901),
-    ("0x0da1863867b986bd44230d47aa328bcce59aa6a4", 10395976242632917),
-    ("0xc0fc7dc21c29bf718c94af83ffc6f41c2b3aa5b6", 33891449194934901),
-    ("0x22871be5a81f8bba0e231015931019e79b6d67a0", 9592979596369062),
-    ("0x3741b82cf6ee90cc06b2aa6bc1115a6ff9177e6d", 34090217368044248),
-    ("0xe160fe85a96b4739b1566d66ac44bfa559da3d19", 11011910349776836),
-    ("0xc712b68473449f16fe3af6d680d52be937963cba", 19324204560413963),
-    ("0xa140ab1c85423099308378748666b975ea7b4272", 7289920183966166),
-    ("0xafd2870c519ab634990c2f8ecea427e1e0349aae", 98420627568036828),
-    ("0x7f932eb5194104716377cbd4a1b335210c4f9fac", 113007724745318035),
-    ("0x98019323f3bc0bcf81a7eb507e43e5045b455dbd", 38826624408056919),
-    ("0x5c542743222a492e45b159b4d39086449a29e55d", 22540853247826130),
-    ("0x1828bd8a22ffbcc7a030f09a5a21b621148ab8a2", 166474274791890620),
-    ("0x9bb6f9cb51d6b2ffa445be8d2e0c41c07d5bb354", 6332685593828754),
-    ("0xba7ed11ae26defc54f5edbb84c8cca46e46d4875", 14498193551133225),
-    ("0xfef9a89bf224b707010bcec21fc07f672990e8c5", 26022715224036791),
-    ("0xb99b50630dd7d6e7630811d9cab67be75fcba268", 22372831194469799),
-    ("0x2e62174c79b893b4cfd12b9a3f9c1cca463297c5", 9177656767762347),
-    ("0x45db0c27530f1b7570c5f1be303b7d8ab3a1a9df", 6747369307652746),
-    ("0x91c9015def6f20849788f5d9a3d734c2792b3d72", 12784322420813105),
-    ("0x46239ceb94ebb46f8c800f89b0d817eabb207309", 111964856970470415),
-    ("0x0fe6983801a44b45a7dafcdb2a974a43f5208c95", 15490229486932241),
-    ("0x025db86eb6e1537a935f338244e22ea2d9967693", 17386431734807649),
-    ("0xde7ce2b51b409a986ff3ff9b13b8b74bf5d2e9c0", 138261917182174651),
-    ("0xdc61fda7bd33971990d80cfc81ee761a8746dfe9", 13241830234014380),
-    ("0x4bb8820da92b44ff1a90916d3c56310e2c95c639", 42952507436268097),
-    ("0x74fae191f05867b4bd240741f33edd606241e16a", 13082290700604784),
-    ("0x8a7b731fa2d0081e91bb214fba53199fb425eca7", 115267495717229105),
-    ("0xbcd5eb62c3d08926c315484139175d7c4fad5918", 36440970503837123),
-    ("0x2d4f3e1862fbb635d8c9d1976eefa522dea5b26a", 19303192673623653),
-    ("0x5b5dcc58786a3fed3441b073864379bb4af51190", 18213956198263669),
-    ("0xf1d4ca7bcad09889ecdeae340a703a4ee21dd983", 2481252561588707),
-    ("0x11c488d433fd9998e9f3f564c6e65bcb07a874b4", 21579566330287477),
-    ("0x7b5259b0423674ce18a41b8efc3b310cefcf12e7", 43420528938328825),
-    ("0x162a46145b88ceb91ac1b4f44a10176f2a311889", 6512365619840591),
-    ("0x8506fd93b2beb3a16b9c1c2020ea3642e39a5228", 5895463839866685),
-    ("0x15db53cf2cd2a09a24428cbc978aad54e2421324", 16437667102692624),
-    ("0x4870282dc61e2ff8cd393fdc87da0298177b9abb", 5333759970854069),
-    ("0x133490f767bd5ae1b7860b8e413ecaa7af9ade0f", 50600150282619016),
-    ("0x24449d3d8aaa16a33af1a00977a55f762d6c1a34", 26060882620943142),
-    ("0xeb689a53a10fd0755e9f019fd53f48eec16ddc63", 97702080977070763),
-    ("0x1205050022d8aaeff0dfa79c607b7fb20087cf85", 29080360756322980),
-    ("0x2ed6cbbea79ad1c2cf9fe8c2b7935e354940dd9b", 5646019133853831),
-    ("0x47bbfee60fd0bfb1a78d1e069cd167836732d0ba", 27211823902968822),
-    ("0x09f44fe6ad938bae7495e0b4939f83b4bff0feb8", 42941223098787189),
-    ("0xb5e19ac191ba4fd120d92b9495ea6cc07adff37b", 95430786017945084),
-    ("0x182e796304a1cb7df3c0d829dd70b92cf2a5ee84", 11730473562136195),
-    ("0x6cdd304626076c9c2db0af5e71a110ad372c0083", 17940245050431048),
-    ("0x6ea365ad57b404e54826a786fa98c2e5323004b6", 18235689058229220),
-    ("0x92e619d4198d31b2be1c352760904b30a6985dee", 18230838993734462),
-    ("0xf189c48b6a862697b85500da0e4f1036e596f029", 21308208453112538),
-    ("0x35913d596082e46d60aec2bd3d02d27569b7ce6b", 19702481244197766),
-    ("0x62558d5882ff75177da4054c43662824a1267621", 40944476346732187),
-    ("0x6049c0e5c4ef4463dba8b80b6515195f7462c65e", 2640456973448028),
-    ("0x044c90b70c9b70e7597d3af830f456b9a00ab37f", 454561824466452736),
-    ("0x86ffbbb6426a8a574a3630b0fd3230a067dcc005", 6749575025648074),
-    ("0xe915bcc9bd87d92d442bde0d2390bff23485dde4", 295464813479155364),
-    ("0x3e26cd62a1e4b49f78c27fe15065f30b3eb1e63d", 96618552620998878),
-    ("0xb691a1bc1f72ce376295990643040fb1d2188990", 7989100943558099),
-    ("0xea247327dab36d5ffa423e83852ecbc419325e8a", 52839981550907068),
-    ("0x786f63b089616f9175e0ebe58065dbeee7d3ae2f", 20463660986099140),
-    ("0xd0e59bb3d6c786a3bce8e1f1294ba06c05bd13d3", 17388878084969703),
-    ("0xbe4dafc2d31ccdcd6fec93156503d09cd5a1cef6", 9771903345132977),
-    ("0x8a859955ea6a2bdbe71648070b2aca8e8b0c6db1", 32333765598725997),
-    ("0x26ce6d0b20a1d86fda78a7c6a3b00b04b51d1270", 33104576425953893),
-    ("0x978d32b5dc21836a58a3aa17e7b0aae5f3daaceb", 25109934418967256),
-    ("0x74b569dc37108e7736a5629d9c0d5feed3d49de4", 39139148455144235),
-    ("0x0586a8dcef20275b6025091af63c4536c7d5ae9b", 9738224211046483),
-    ("0x5f0a073de9a46accd59d60a0c640df098791ada1", 192837963642331543),
-    ("0xb95c0f5263858a18c69065391a4484fd93571e4b", 8351983880000000),
-    ("0x79c325c034cbae09e58251d1d4217215594d5e46", 11169370312681932),
-    ("0xc83788564f1325b3aa26bfa0feab6bc1ef12ca0d", 11320792959124316),
-    ("0xbc43c99a76bb0fa6b420e39d78af50f8d5912975", 73824355108306081),
-    ("0x2aa8899a4502ccc63a75f48eef06327e6a64ff19", 5914943026552284),
-    ("0x2929d73388332c92e2cf93b894a32b0a07bd5586", 648672972832),
-    ("0xd4b757a9914031fe08c9063b5fa22f66d78ce487", 11448696050627416),
-    ("0x85f5b414aaf84ec70cb54cc84682cfe9e69d3c29", 293635686095569556),
-    ("0x001edf4c5e8e5cd8a187260730ec9b97e4e84491", 42910961017625858),
-    ("0x8e0a2e20756af937c9eed136708a77d13982b5ff", 14829596463299814),
-    ("0x5d2c5d061c64c9d2639b4f8e316129b98a7d39a1", 74034614604710774),
-    ("0x086979ecc52ba52e4f7389fa863609f162239947", 57617096101911191),
-    ("0x2a050db5ef975dbea2c2c15e4bcdb3541f6d56cb", 54622737100318455),
-    ("0xcc0a94e4a04e34fa987ba059df9c8cae704633d7", 31459743286892427),
-    ("0x4d7e1efbca2d8f54937d86afe82700594b047432", 29293256110787043),
-    ("0x8e7e0eae566ca47f7ddb1dd32559f35bcbe08260", 19777110590526077),
-    ("0x065e7f4fe6042364968d08cdc5abff88caa3b009", 3776547049138346),
-    ("0xd79ec46fa2162ecba658150f714875144bcb9188", 10750284677719667),
-    ("0x47c7901b7eb9db736da98b957ca3a7f8e6c984e1", 40472899766816430),
-    ("0x8b4d171883f2840300bd95858dea5b373c19a8d9", 204324490537378190),
-    ("0x99cb0d2306c0f787676f32087a1cba49a27286c4", 16157180697938425),
-    ("0x5a94b5b73f552aeff2e84f5cbe40ce9e34a55ee2", 18806803366563475),
-    ("0x7708c60cc22f13bc73a4cb748bc6802d8dcef9de", 15230691059403161),
-    ("0x252bfe043ea513ad3c8bcfe6f6eb096e0d942c6a", 24592012005459893),
-    ("0x41060ba86ede33e35a5eaa2f2c6ec52eaeb925b7", 21282270202095022),
-    ("0x204bc0dce80addfa5ed3a701cadf9c6086e363a0", 22549290441048093),
-    ("0x26996b2929970d0ab5d65b5d4004f9a9958c9b84", 96675589790395211),
-    ("0xb1f6a12c76bb4071e7fc0ca0941f45d65ad0f78d", 195269293535588450),
-    ("0x8b04cfad26ceb0431ab19544a025bf625f3a5879", 55712874253461931),
-    ("0x4870ef7d08c59b472f124f87b16f623f05911848", 21195019081377483),
-    ("0x6e1cbd0f8e05d8036e140f90d60b115917c96e01", 10596477020750626),
-    ("0xba37cdc1d2755382479826692073fc1bfcba2af4", 71486324777615559),
-    ("0xccd862fc46cfabc489e472c6f6a2c5c9b7dd972f", 13113874420893421),
-    ("0x9207253342aa7727c7a96b2b1ac7ec6172bcd368", 10787399308266671),
-    ("0x87951b9e6f89ea4d7d86139252a31e2efc309b29", 36228145565610431),
-    ("0xe9f2df33e051771fa5d23595f11dc3125241ffcb", 10097239639167825),
-    ("0xe41984cdd5c83c088615c8e4f4f55576b985ec64", 19680401356025858),
-    ("0xafabae9d0b75d9ec31cfc1016b8085ace18399ac", 21959833046625914),
-    ("0x259d211921491f89bfd46b9e8869a6fd4835ae52", 7817234512006357),
-    ("0xb9302f58415a5a8dba9678570cd8150407b7f644", 31871022638457935),
-    ("0x3a2aa0a4c869dee808675d86d5f58d34f3fc2377", 6369744298966419),
-    ("0xee22ee448a3a2b6b8f01a45da1476e2d01e24f3a", 10332188117327451),
-    ("0xf4547dfb27793e56c803faff916a7b6093373717", 10381015928605094),
-    ("0xb9ec26e087507334127781b64b0657ea9f6525a9", 8588065895719560),
-    ("0x858b865e098e2fe0ffd33a589ad1d190e95a313b", 10047639649412937),
-    ("0x38f1485ad0edfc779b5d47a320f9ef647f597f64", 38054934453106387),
-    ("0x777c7db4f995a5c3f5da352860346dba815d8c89", 8032137433277877),
-    ("0xbe2b5d4a8d68ac8eb2113a34c0da7258785ece69", 3198978679557660),
-    ("0x71e161dbadd43fa296dbf644f98a433dbdca1a62", 43030647058303767),
-    ("0xbd62bd5c7be571209d86ac00257ceb199ae7cc33", 6314583584534419),
-    ("0x785ec68fc030189a15f2b7fb4aecb19da70367f9", 13037140613089576),
-    ("0x2ce736f4b143997b4c307799dbf426265ccfb533", 11729878669724178),
-    ("0x88d7e4a0fba5890101e8bd226f53daa1075fdddc", 11485249215975751),
-    ("0x86a53a1c54cd2bea4f6cd5537e4555d4b05139c3", 20061834881877827),
-    ("0x4663c1c1bd8eb215e980f82288dad3695434f1ca", 22205722816162644),
-    ("0x9bd6d82162f9481c2172ca002bac68bdf26bc861", 88323768667438414),
-    ("0x2c3545ceaed6d3cb326486ce01f156282fc7f9a5", 28778521281471773),
-    ("0x478b6dbb373023336c3626718c0bd89c4f80b51b", 25154921601741620),
-    ("0xdf40e1e5f44fec30ec7bb6cab16c273c05a3abfe", 17416359392903260),
-    ("0xb509bc6d29cd2a994026205a4ad806de7bb74f72", 25357630988291022),
-    ("0x78613bcf8351cc3da2ee23bc1e22087ce9afae04", 7939269360303238),
-    ("0xc6c1729a25792690b1d034f46ac0ccf5d3cff69b", 301974952588012053),
-    ("0xc8186846760f2a8d9d4d9b723bc3d62447958e55", 11548553056950449),
-    ("0x2eb32ed7781218af5fe0baf669201cfdfbfbb1c5", 6449830176257435),
-    ("0xf359a5405f3749938895162d33cb9b35325f51b0", 14909876461406222),
-    ("0x452b9b008bd6baf8b69eef37a4113785d3bf4301", 30900650405739008),
-    ("0xcf9dc66251ca1c680969156c4c31762ab3e49193", 23053260197541655),
-    ("0xcd48cee1145d1776a66875985f5e48a5a30d1d29", 70358655092758739),
-    ("0x852de13d82179aff91a52a214735a6b7298eafad", 11174462522176847),
-    ("0x6dcabe5167ba508b0fb8f5e25a6d514ed04d72dd", 28488791884916240),
-    ("0x384ce9fae617a8d8e801d2478d276b2949c1b092", 31532068186541591),
-    ("0x2be6792414d997a9f3fe66e813ff2f30c9334715", 83301872773664883),
-    ("0x4b2131165de0c322ae0c73e20043555f9d786edd", 94909889121880653),
-    ("0xf40d7040d194cca48b96e593a21d2dd6afac3d5a", 238342832286885928),
-    ("0x46ff9ca21f26750e6e80633aa9d390ed12aabc28", 3541676992169161),
-    ("0x454351377afb489b28ed5cc6140c72413ad3c502", 54434248097500817),
-    ("0xdc05bec504a35aad28bbd2a8d6f8990d9cb00645", 87605621619105379),
-    ("0x231b99c6caf1cb753941d174ea4423788502f24e", 46029233768177194),
-    ("0xeea06434f8aba78b2cc8d461ccb87a873145099f", 2282273394931881),
-    ("0x978af9e91e4a4ae36c53e2cdc903870fec070d27", 48579131575317799),
-    ("0x933161b8ceed9e50a84e612a2a2137666d80f4a2", 11435154203111996),
-    ("0x078edd760ff5b5b4604fb338d3cf7b6d5355c7ec", 16952835282864352),
-    ("0x2b077f7730f7a77d4e91013c5f54b0f925210c55", 347208525973880834),
-    ("0xbcace70fd93a8637d99961e9e2e254c8e8e48d97", 19070159808649424),
-    ("0xd02f4950522ae0c23005ae25b689d7a8e683175d", 48205221282187213),
-    ("0x93b723f140bf4316c0b92d15e7032eb46af62f8d", 28299275347802234),
-    ("0x4164825bf954e6c6401dab8d01741885f1ca28d8", 36566116290319786),
-    ("0xfe26b0812ede6c2c9d4a80a6a1a43680a71cd275", 11521829812823141),
-    ("0xf02046076ac91ca5dfd702b04755200f2bc9b8fb", 2404706793814550),
-    ("0xfcce91dc5ce28eedd7b5ac61dfa52d4327b4624a", 21741846758710063),
-    ("0x89f4e70c9113a4432eb09fec2fa1ac3439d58854", 24918483860815074),
-    ("0x64fadb1ea33963ffdccc6b99466f1033e1379cc6", 20904190440517780),
-    ("0x4b63c44f66f8775cf8991f05c808c73c6caf1f54", 30948474557870002),
-    ("0x83df3346d6e20307285798f76df15065e5a7a951", 6106532265557451),
-    ("0x0ad813c743426a5912de80c0b0e1e3409b4de97b", 17945992544732372),
-    ("0xd58c5363335458f66b732b1f6bfed924822d2e21", 18783565193371300),
-    ("0x284b999d7de8026873dbf537b8e37d0f18f6bcc0", 6712103432746442),
-    ("0x688b5f10e56e9c9a5f86fb7b5915120bdddccbcc", 52497678769915235),
-    ("0x8a14d7e2f705ddf57e4fa1e7b5e2e47189eeef51", 9333295441518546),
-    ("0xb59c65084a2914345ef4aea727611b1ff84e7ff2", 5371422696723293),
-    ("0x387db7390c1ec0507620780d574cf485b28dde32", 9205386803760874),
-    ("0x9615ae81182b950348188531bcb798e86deb4d60", 25607985518732654),
-    ("0x1b8046cdc199c1897f13306ccf180e58a64f749e", 282908212668738977),
-    ("0xe992a2c76b43978303be7cde9a72ed24c7e09b24", 3420288235284235),
-    ("0x28124cb7b896ea1ff9250c1576f08312446f702d", 10153507270050223),
-    ("0x42999defb9eeae8cc547b186ebaf6ca9cd62138b", 21403416938537610),
-    ("0xad001052d0435e06ba64602da4c97268780fdc52", 17937731206006880),
-    ("0x46ce39f7850f7dbb15df7fc48d0ebf0e3657a6f2", 24938272243332555),
-    ("0x2049ea2d1dce1f75299fbb9b20aa730d771ec0aa", 9286025537020629),
-    ("0xc8a2b6ee9d360bc96414da8877d7194e96a29652", 11887174425105714),
-    ("0xfc0ac081405ad9b335d6a2d5a0812a4959ad4372", 100599642337543760),
-    ("0x0b6f213bb1dd9f7378100d5021101e3dd0a02df6", 90315371322334462),
-    ("0x14f13f150a810e70a168bf377a452b9c741a0641", 7345165425607418),
-    ("0xc7dc921871b84ea0589501f6b08a0567ccc3c83f", 5103644601450932),
-    ("0xb25c37ae96f9e859d3252b153a98527a2e584991", 36719644071092978),
-    ("0x9318af62a5ba1a3eddd8e771293d8c8d2cef6df3", 34918904284568902),
-    ("0x559e28d324a7b4db0d7a7da24fabc96c6a786cfd", 50609431843557045),
-    ("0x72befca9b248b4848fc710f5975e2799e2d065d7", 72021135485540270),
-    ("0x2c0189a83fd5aaf947e19a3d0c2906004ef0a8f7", 10903802508395618),
-    ("0xdfe23b95764ccdfde8e716e449347cfbb6947c2b", 52609991801891409),
-    ("0x818cd7a661e824a251714910497d7d1ed703356b", 79424875683424583),
-    ("0x5edf93917e7c501f6a2a0e262f5466793720f92d", 17107810668921215),
-    ("0xe361e42e40495ed25d6e09552ecd58f3482fbe88", 10202212731772380),
-    ("0x12c5cd1b464acd9c4f05934ddd7ebd8bd00056b4", 59165213813603852),
-    ("0x5d0231925c873929e53efcea37d95eab4e9fffb4", 350437762543015758),
-    ("0xdf38b5d252dfe44b898273eb97af91babac53836", 1499236263629085),
-    ("0xf4570c2e0035f3d696afe6c26ed3292efbdd3874", 15808435309973692),
-    ("0x7fe69ddb729de8bc41e2f35028efce4fe3ec7b93", 5867774543954706),
-    ("0x299cd1c791464827ddfb147612244a2c59da91a0", 321818413873245862),
-    ("0x969182761838bb1d9c125db23548994c3f717e78", 14371463385073154),
-    ("0xfbf5342fb08243da3e3255b93bd40e6fc489016a", 19033953691232176),
-    ("0xe2bf2995e30f72e5635605825dc94fe88ad64a17", 97094973447064073),
-    ("0x92f3203c1b2d4d077bc3729b85979bb403279905", 6610204429061938),
-    ("0x9081e7ad50188df1589b8b9fb7ad0f26e3b3028a", 45384178013074027),
-    ("0x6df12deb0a85f17bcae0fb2e46276eaf5bf526f9", 9067058914900479),
-    ("0xd02672e829f073198bee8b56a33d4af2308616c1", 11187541129532707),
-    ("0xe7221e56dca35f4e5f7badc3ad81aa03cdcbde14", 26138470779283412),
-    ("0x320280e54bd13c585e7ef2bd07990ab635c76868", 10037542971733802),
-    ("0x4019b01cd9445ced9bb304589f0a7ca08622fa02", 3812798436814221),
-    ("0x64210cde8eb0c175c1e222c130a01b9ca07575cd", 6865476287351936),
-    ("0xa9a850232de24b44184192fc2b3c63f7f1aa6049", 36732194006780332),
-    ("0xb0fd96c9537ce06b94681a8bc44ff3c5816cc992", 9263547518885218),
-    ("0xc4bd0d7346219b12c3dcd290e5d37013a0708929", 11469500477914921),
-    ("0x62f976feefb3f9f5f9efc15cadf37bc1ad5667b6", 107316331592472733),
-    ("0x10bb62e1cb807ae6dc20293ac27c15521775fbdf", 10128428539530183),
-    ("0x3305658f7f06c5cb2f272846a6fc562b2c0a5a94", 54122724244215062),
-    ("0xc0702d75d19ed1d2a614d033c1d4a0e8c4f52634", 268739278484994105),
-    ("0x2493dfaaab8e8dda71696b351c3669c473f8658c", 32042694140635432),
-    ("0xededeae6d249d6f26195fef5d3ef25d1d8a4c5f7", 29003781269549349),
-    ("0x642fe4e8834a3dd1934a5a2b0ceb97a97f69e0ea", 20553283685567796),
-    ("0xdb68c62244cad827bac44ea174305d98f4c24eda", 40124509573232990),
-    ("0x645b28857b19c0046b10caa3bd93fcd31ce15905", 105241210653433870),
-    ("0xb0e1bd3a262dfb2dd87eab7cc86ec65c56376861", 36330700290119220),
-    ("0xa0d555ecdb35eaf69331c200954b00d394d2189d", 21206842543148256),
-    ("0x4fced9b9474fbe94d4fc3d9a5e3af5b31f655982", 11541365013945059),
-    ("0x2c8a0f341ccdccfe36b5be8fe9bbfa2fdd4330c9", 12075178818611698),
-    ("0x4dd30367cab7eaaa8c6b54f0de8ca8b0e2c0d874", 178814761781427683),
-    ("0xde08263ca23b49a9d76a27faf9896b1d454b0213", 72169107735640013),
-    ("0xfc8fe993d667f5e29257e73a9e44be836a81162a", 15150744796817820),
-    ("0xc3c95a7a6996fc62d3abb143b6758d4f9bb5def9", 20643649190935663),
-    ("0xc00499e389ca2d2c1e6999b217d796e483405480", 99199047676450303),
-    ("0xd8b896b3f6cb4edfa3353efc5ac59443ab50c596", 291445374837809875),
-    ("0x461db82e70d96d0a3d30a75b80538645bd94b55d", 8039753216098717),
-    ("0xf666ce96fa7f79bd224bd852973b57e807430277", 2063374596386329),
-    ("0x676a5f9c0d0d8bab378ae3bac47cc4e2d8026e1f", 47552008135575491),
-    ("0x483ef8853c2b126aa3c0f86183e7f32a198ea992", 47088740873853688),
-    ("0x25b9219cab83d382a1b42667ca3497f3c44d634c", 13445134315760935),
-    ("0x7c2c33f3bfbc61b3557ecafea1a3d3b3a5cf2829", 103340058978499601),
-    ("0x097eb9ccfbfa46c3f90c66ac32b010fa6e7f046d", 71308513433315432),
-    ("0xf0b85cf62eeb95985f76d115fd22c107623f10c2", 33303415237822131),
-    ("0x468e4eb62eec82b51aea08439db9c2618d2bfab8", 73369585796573527),
-    ("0x75efb8143e9f5f48382784dc5b2ce58e9b7a8604", 398348816538686575),
-    ("0x4375184563f64211a67b21958fa3a7f35a9376d1", 19051428729783449),
-    ("0xe02b4fb40f9467559f19419735e0b85241f7ee3e", 36553863462142518),
-    ("0xbf42da864f9c248fd963e197f00b07a1e572addd", 19058511864410422),
-    ("0x8ee4219378c25ca2023690a71f2d337a29d67a89", 10420106821364329),
-    ("0x6dd121d67ac13467d8cf594bbcaa9f126293b1a0", 7890170529312514),
-    ("0x2c4d7643c54200031c1bde554633b033a456d842", 80773626369067336),
-    ("0xc07c7a221f9e26f3fd77deb2a5255d7dd1a63ec0", 108361054962000858),
-    ("0x5687f2e4055088cd2bfcc535e2fd849589a31c1e", 8628238644813617),
-    ("0x7a6588392d843eaa69373d85b3314d3ecb3fe9f3", 22575001613305304),
-    ("0x06f13088002ccd840d37a6ed051a4c82d73538df", 96498194234058516),
-    ("0x3cb533e0eaa9ab49a966dfbb70b854737466f696", 64844612156450495),
-    ("0x8ef04330c8111956451f80ae1d452c14ccd29c74", 36836436768218801),
-    ("0xdea5037f5a5090b83845964062a3105441e5da34", 33770535562735750),
-    ("0x20872b4966dcbb7de161de3f001841fe0c175ce7", 10731607393888014),
-    ("0xc04abbc9ff0e6630c22a349915ae37e2b8a3753d", 100730340916286505),
-    ("0xb01e4f6042590061c511d422b1f8e38302778b4d", 5053786276736160),
-    ("0x0a5e30883c62d82ecce7e8aef9a7c3f26d5a2d1a", 4775855839492978),
-    ("0x4590b09026cd4b59f9c4608f734c7f40a6b7c618", 10271726091734506),
-    ("0xcab27aa8fca982f3dd811a624867a75375318ba3", 10803934724181809),
-    ("0x513fda7f8cfcbfe84f6ad5f591a49b06e65836a1", 19004568871287010),
-    ("0xd145b4dd6419ce5d3e65027faa3097bc739f341e", 114642626875288285),
-    ("0xa23cf56b1b32279ebecb74eb8cd513e0457a83cc", 10752468892480260),
-    ("0xcccd81ea8eaa52743dd629f828824dcec20dd645", 205549214075267154),
-    ("0x907eeb32ed6b47c063778a68faf0aa9f59221ab1", 29550678679502308),
-    ("0x7c710933562e6ecb6e1d9c2e4bdc7fc0f33ca442", 12738149357739277),
-    ("0x8bf45a62c8d9d957495bb44a6b802741139e68ce", 98385366423847960),
-    ("0x9d4bda6ff8f4779d0cb6a428ce70888a126ee322", 20700116896054112),
-    ("0x9065a8b8527646e6897a34ae58cee679dcdad255", 36303961330138977),
-    ("0xf3be6b865ac66d4f0f21916f28595d4a6d0f3dd5", 45060913589103929),
-    ("0x775d84d09356f998264734d8813b85432fdfcc16", 22747340814529026),
-    ("0x616a41a6646789aac20aa15eac3cb31071367252", 5650436440235572),
-    ("0xe89890fd12adda305300b7a46be354e1d1e4da92", 159403741895024779),
-    ("0x98ebc93c913057965a2b277f71f946f3a08363de", 118450866588833469),
-    ("0xc6345ef60b5a1da0fad8d5f1a2380a9c22522b88", 28136593482604787),
-    ("0xd71c71899ef98ba3c95a831f08fa153ebf7d6e4f", 20380064099323925),
-    ("0xf50ab4dd05fa9c3db363a450adf46b8ac4e4f59a", 22415340302863120),
-    ("0x2a03bbb620b0f0d6d2df692b5ee450c69a0dcee5", 4568557972172142),
-    ("0x15b30fb3da30a538ed6d09de890e870193eac12b", 17640810722695053),
-    ("0xbf99644397d9d124eaf62fc4ea29002a86b2df79", 14729111458864375),
-    ("0xcb47e581cfd9c7e7503b2e757c675807fa398801", 40066633779263546),
-    ("0xf01701518c20b79b0f1816093b14e24a7f286684", 101702377008161674),
-    ("0x22e3201bf5717920487605a76ba5e3250106dc65", 23221269655016095),
-    ("0xf2fa059494a74b21d0b658d1f387af334107f65e", 12469343613934932),
-    ("0xda86a12698234e05da7d8975893a98de72e15b16", 2394944830537083),
-    ("0x6af4604ae45b153caff582e23cb1aa8c1ccab3f5", 51830981660290785),
-    ("0x3d3f22770fe570ccab63c280c2e6702087735675", 351897267028996428),
-    ("0x8669d2a4a5c5ef2c1cb3a0f44c527f9ad73e5d00", 40955196901079323),
-    ("0xe9404dc46cb03e1ce11252e2b3e6c0efb98a34d6", 14811085283855502),
-    ("0x04d3931d909af1a1bb62d1dfad8fe933dab374ec", 36462541278307969),
-    ("0x9ba621207dd8ee34a8a798216f0448fc2c35bba0", 28626891835523563),
-    ("0x4809e83d0ddee9d53dcee1cf9e32c2df7420790a", 9682949670258602),
-    ("0x86eda5e8c685a949199317b7903542d80e1c8aec", 130303634395032852),
-    ("0x9d4902c1c5dea821426ad757fd963f71c62a96a2", 38394108052503965),
-    ("0x230bf2331bb0929d74459d5b05608fdfbd77fed9", 16228400510019365),
-    ("0x282103a7c6611f6c8fdf38deb1ec91c13f52a892", 36786551461207013),
-    ("0x49224a1183d33ce6ba83bfd6540f8ac90055d81a", 89091926442530287),
-    ("0x71d8609c91f662e02d8dcddc20ca59e005b2ba29", 9101462388362616),
-    ("0x467d629a836d50abecec436a615030a845fed378", 24257282088382235),
-    ("0x11d66dcc826c4d7167525157bd6b1fea8c613a07", 70953308225088715),
-    ("0xce44681331000ee8f9576e3a5cdd191be2b8b63d", 39616523823487363),
-    ("0xb965107cbbf38817e834f74b09be2049145d6b68", 18214007146437363),
-    ("0xcca178a04d83af193330c7927fb9a42212fb1c25", 202112121532474548),
-    ("0x85e9a235f911cd1593cc1f3e837ca5b005d5891f", 17984053808110220),
-    ("0xb4a07fa213812bc04fc44387b4eab85fa8cfd0ff", 10176798706141842),
-    ("0x120609da75cf68f9aacf4d7e8b627eb9186ba27b", 136436599120801136),
-    ("0xfb3ddf0cdb97bebae2d6f7b162c6485b296be5d2", 10952859457545905),
-    ("0x078e88e465f2a430399e319d57543a7a76e97668", 4007462076840854052),
-    ("0x9a94e6737ba8f6a5c28ae5dc0f71e312a05fd1e2", 19868880518017569),
-    ("0x1ae99691336b1862499fd5cc8f1476efbc6f3970", 21885730832214249),
-    ("0x892a287da3ebc5978d4ef203967e23f415f1690f", 11223773633903789),
-    ("0xc83baf16e446bc68fd78b01bdb3c806d5fca5df7", 17867448282219928),
-    ("0x5d9aba64ee73abfc03455732e2a8bcec5b2819a0", 10434914495467702),
-    ("0xb4cbc89475c4bcf6ab6a2e735c5c913c9b4ff671", 37915011146918238),
-    ("0x8a14416efab582c8482f7fe4efe47a3791f6d0f5", 20778485263993615),
-    ("0xb1bb091b1a115d95aaa856a54706ea14289e546d", 4027711996396407),
-    ("0x1457d2a377e7866951db5ade83e5e3cf9f9fd1b9", 10213908021132216),
-    ("0xc7c0213c686236e49717f59e4c7f876ca9398357", 5216943865067844),
-    ("0x6c316a0a7a72783557ace9e22479715392dbe050", 11640063495499056),
-    ("0xb39cc120ac8943ff101752ad148bd4cb1173a819", 58706352812435639),
-    ("0x43f427c4f983bda13358ad44739cb6622a576fe6", 31938908907115046),
-    ("0xfd6ed8b66c5831e9e2e932404caf04cd288fc988", 211902784994836610),
-    ("0xb0d600a23558562cdbd55886f682ac64f88e395c", 40504337419935667),
-    ("0xdc1edc1d264e7e36a995649d7d0e213eafaa9d2c", 197519093750550908),
-    ("0x819970381f9cfb73b14365099cfb8275a6b6bfa9", 15947645189253183),
-    ("0x0c113f3362f591a18e7e9e17ae9a7aee20a975b0", 77400685415735420),
-    ("0x62ae6147ba1721675e25b22197ee9faab3e9478c", 107071171342668986),
-    ("0x99e2a6b3aa40bd79f18e7e7e525292649e39b033", 39905004225647264),
-    ("0x61c203ced2c1e10728b1ab5d6ab315384541ad61", 21470188702579322),
-    ("0x921df14cc5705ca278397fef4a84474d060cd071", 10180673506958428),
-    ("0x52121056d26205277a31cc6909f5418b75e76797", 11012388294528989),
-    ("0x763174935371c7feb14e2a1696eea3bcb5c8cf05", 9970110868778910),
-    ("0x506b7512d1f90daf7ef1981a7e3903cd28e949b9", 22626271467228830),
-    ("0xc674c53df7272c80834adc9452c0193d897c5de1", 14522843027785858),
-    ("0x1d015fcc3128237ecd10b0627f8a383eef976ccc", 10368165782163809),
-    ("0x57985e22ae7906cc693b44cc16473643f294ff07", 28749492868989475),
-    ("0xc51f4126ccf4e83b199589b6f15989f285f47221", 29985683980565938),
-    ("0xefeae10e3744b9a66d449628d60ff3c2ca26ab15", 8524880164407524),
-    ("0xd79f45f3c7e315d36fc2d3215326968cfbb47cb5", 5276866971180468),
-    ("0x2ccd94e5b78d667c0f4f16c62d257932fee79443", 81391889387658297),
-    ("0xbd9a8d629f7545f4ea1b35c1856244ac51fedcd0", 11331057950145496),
-    ("0x78a17cb849df04fd2750471c9d5edc3793eb96e3", 140850463400971856),
-    ("0xfa80b0966e17f9722dce50c30aeaf780e3745aaf", 18163702835403039),
-    ("0x87b6c1e90087d6a0fc392e5250a6203eb09e1b54", 70670033912236736),
-    ("0xddbf205ee9a6d294fc4b3a4ad6d3ab413da7970b", 10153971044619464),
-    ("0x53e00ce5b36330ab12e011dee091bc7e6522fbf5", 86250820487411301),
-    ("0x0d8a4e52ae25251eaf831edf453ea8137b6dbc87", 19353094487045498),
-    ("0x0758f5c1c8ee2e007e21c33b78643a7c5d882e13", 7104930694003414),
-    ("0x2481e9c8091033f216f5c5cbc0a56c8c03e316de", 11710936944073184),
-    ("0x2b5756b921a3b273d7324b94899e7631f39eb65f", 13197838361187870),
-    ("0x617a84590b3a8553df43ee223d5794a06e521b0c", 13212599470492669),
-    ("0x18c162f83a3a8a4bee9d735abd35b105700dde34", 27794984519234815),
-    ("0xe7a740ae8282b3bbb8391bd1b511978d06384dc6", 49501388046523596),
-    ("0x5795dc26a4f4ac25084eb721c9f32812e2038294", 10537050735644285),
-    ("0xdb8d9a4fc8368dba0a527cf7c7c6d795c3e40971", 51434751824921211),
-    ("0xde72301aa4ac85b1c4002ce1e383d6a369a0d570", 1805910753836904),
-    ("0x6fcb6408499a7c0f242e32d77eb51ffa1dd28a7e", 1000000000000000),
-    ("0x6c850be85c455c4190a5100cc8d8d5fb72722468", 40636837193406299),
-    ("0x256620e41f5ff1fd39a57a1a450bef5182611fdf", 20776210179008951),
-    ("0x891d4f0d6f5232dec42af5266bc4f9becbead690", 16255707403239992),
-    ("0xad4838395b18619b19b9a8a592c082a074220c8c", 28419661212663761),
-    ("0x8a69c1977fdf9fb6f44579fa1381e0fea6f4315d", 3356658478054757),
-    ("0x6f5bd5ddf2103198028716cf0071ae08f47a72cd", 70579027406339446),
-    ("0x11e9b064a6d482c76b770baa12d144eec438234c", 44488853783640568),
-    ("0x24122ec185b3309563bcfd2e0c219039ed43808a", 12574698097941605),
-    ("0x9de9df7940aba38be6e57dd026ebb5bec75f4292", 11160866763916792),
-    ("0x6a1a4b4b4a791b21e2a4654d760a057ac153927b", 7864027077797891),
-    ("0x232ed3065e03b3ff35a029641ed80bf3a015d1ca", 15825993906337815),
-    ("0x0d64e1082be85f7e9c39d7da8e3efd4c1204acd5", 26806942067184313),
-    ("0x08e89248c86fbfddc30ab8130e136d2d66600a12", 200000000000000),
-    ("0xb8decb403a6a57f211c607fc818548de41eee5c5", 200000000000000),
-    ("0x6dff64676cbf88ba26f1b5b2c5fcccb0c638e3d1", 200000000000000),
-    ("0xc48e388c48b110570e5034e112b176039bce7eb2", 200000000000000),
-    ("0x908a697774b5cf33a7e7fc8f67bebcedc7b3544a", 200000000000000),
-    ("0x07f82cea5770723207f8544a00023deb8968755e", 200000000000000),
-    ("0xe60350b53303d90c52e088e060bcb00ec7464bcc", 200000000000000),
-    ("0x0f44c0df3455047c1896db7a564e5338943a51ae", 200000000000000),
-    ("0x4288449c0eef073d42c780769fe7f616c1af7e1d", 15510732088508965),
-    ("0x085fe2d13a9284828a721111612a2b69ea4db4d5", 193041411332130793),
-    ("0x1f490858b99624dfe29227805c134bf5e0a0a997", 1088926964444990),
-    ("0x311373e8f6ea53ec73f57ca2efacefe072159b74", 9974325404252805),
-    ("0x2b9b53ec31b253cc9a6c42b81139f4f4f1cef2d9", 1478434067711924),
-    ("0x46a929e95c585e23d411b452ca14568da143901b", 23223117909029656),
-    ("0xdefd8be983a95d24e7b4eee210249b0a53ecec83", 18008518845101205),
-    ("0x5315a1c257fd6266f9608f31ac9b6501c98c5750", 200688687003644299),
-    ("0x272fcde0475981d2ae5fa60ff23df30c4602b2af", 174764278662613079),
-    ("0xc39ec3448021452e1ed47e3e3265c0a047b53be1", 23312727907576152),
-    ("0xec4723dd088789b75f0f91b203c52164c2f6e224", 60564937988086666),
-    ("0x077ad784f85620a63d67509884d255f4af1f6f37", 35133579982024788),
-    ("0x464d19bb970ff3a27d2f5404656c4915564dfa41", 16939617095404910),
-    ("0x1d864d1a4221f8591fdde2a7c72cc12399802ddf", 20496110234799686),
-    ("0x7d6dd0bb8e705cb48d26f1658e44e18ca2d75157", 11060496000825231),
-    ("0xa6ab9088094b074a6b1075d04676ca53a82c95ca", 11052931742233183),
-    ("0xcb45adccd82d5379124702ba72904a43a5b6f584", 176691013048429038),
-    ("0x404df15b36a2ee78112def9c19dc350ec0c00dfa", 21157801740485318),
-    ("0x3f67b94312cab5238e18696f84c33fe466f49fb2", 33212606051854973),
-    ("0x2a0cf104c29c1b5141f92f500d2a0cf77e2dcff3", 40469382031151185),
-    ("0xf7ba8e391cf7b99e875c6afb3fce60378411b7f9", 18562876710503027),
-    ("0x9ccafa5f35d98c808698c3ca4df0247fce106ee5", 3832029797920861),
-    ("0xb1ead214506d0cd73785dd09b3efc2307133bf69", 3223588657303955),
-    ("0x4b270d91d1ea679e632ae7dbe99b430723eed9a5", 12197651958934498),
-    ("0x06a296bdeca9b1a1eca290cd4f319a4a7e4461be", 26406252633890613),
-    ("0x7bc01d2a051374e731bf03e7e7a88a4d3a738b39", 3643752907738827),
-    ("0xf898a30648c917d99d039654687e2bbb3c1a4ca1", 5489811082104067),
-    ("0x357da76bbb3e8207e5ee5703fc90a8c56c9c65a8", 39251151172861821),
-    ("0x331addef94ab0232c02f1fe89969e667fea7dae6", 82430635777027843),
-    ("0x7c7f413050470bf25caa257128f81a18b0a62fec", 25406042975374423),
-    ("0x7a9f87b7f10b12d05160a7a2add4b022934d843a", 8125332935581069),
-    ("0x1f194c6a5b535393d28ddf8025f7f2d87dc68467", 93682286100235305),
-    ("0xc4fed92c5aa879efa764c2a322f04f9c803c2655", 18803767378862045),
-    ("0xec0b9eb8e1377d7038332dd9dbc0f3f763c7a675", 49803182794583881),
-    ("0x1b4825d66d58a8128a1716022d8ab5262e9b146b", 30253476454379749),
-    ("0x12469146cfaf7990363840cc8161c8dfa9f76275", 28482048955816661),
-    ("0xd47fb4cec876afc4bbdda6965c006a30a4bb447f", 16650997181032785),
-    ("0xac96aed784ec6c2fd83b159ab0a5f4ba1dc890bb", 254418688772628452),
-    ("0x5412bdc2ad6bcd3535bf1ece5aed3a9c7c8f9529", 11221298131100469),
-    ("0x1b57342572932e153022240c65792eb085abe6b5", 21176671479569550),
-    ("0x1d40222c2af979449adcfc42c3f7d3ee9d4ade7d", 40474283093108676),
-    ("0xb05b5f3297bbb0ba05483147eaf107e513fb2094", 200000000000000),
-    ("0xc70de4959718c79bf6bb24e24401b697f5abaace", 10427601128733725),
-    ("0x1f56b9d1b9e1745f69510ac87a5f7686bc014876", 5909088737756789),
-    ("0xbaff35e77e6230374c4e6120a2045ae10fe30731", 11052543055905336),
-    ("0xad0677b7c46de5ea176b4b56fc79bb0329412ecf", 10745286548127417),
-    ("0x630cfd0ed2c34cc84c96ab5721c9b28ac56c3e7b", 19522297222715276),
-    ("0xf3b808f2cf65a9822d8c70b0c870f527fd1e202c", 40461833943880770),
-    ("0xd985741ec68b7d085d416f63e4362550eecb56fc", 5368716858196049),
-    ("0xfe007c619ef1e21eb1c967ebcffeade71a7ec02f", 37295993009419771),
-    ("0x17a6eb329fee2a10d5e6fe1d5dc39c531af8bc5a", 62090190631614944),
-    ("0x37717e559aedee92b7b5ed40a26e28212bc2cc17", 264289823441995499),
-    ("0x48f2881f8a92cd135de981d0fb60a8b7c67619e9", 6368509539724051),
-    ("0x9d7dbd4416faaca8db256b852ed65228dc6e0a5a", 18596985533513108),
-    ("0xf03c33473291e9a0247cd1a70de048554986a707", 4061169973096641),
-    ("0x336efbf4063f9d4e3d14aec58123e252bc1e2186", 12009062385003147),
-    ("0x2e963314b87163bcd906788ac13465a30f89c7a9", 57308729663963099),
-    ("0x5fde93cb4d71d67adae945f78f59d5049fd1d8f0", 10409316167196357),
-    ("0xa30c27bcc7a75045385941c7cf9415893ff45b1a", 3000000000000000),
-    ("0x35d3125c6dd324ed796fa91b53ad328a9f41190a", 15369283403052529),
-    ("0x239af1fe6a90f304ab666e411e56292ba876a802", 22259360691313293),
-    ("0xb8dce84955c95d55cca56a0ce2d14eab0774a22a", 14848889999119141),
-    ("0x55f3be904e3742018515498e42a74c71bb94f4bc", 11053319508348313),
-    ("0x0264727fcb63f2cc6f3e92e5688a946959dcdfb3", 31312808555502315),
-    ("0x625da4feb31ea7619c80cc69ed0f548d5ec5cf47", 180000000000000000),
-    ("0x54c92e19063768454d6443db671e4ef6cfe7a7c7", 16244113462515969),
-    ("0x39172a1291e13b882b0f1d79d5eb160333720177", 31128054074555212),
-    ("0xb71d05cf5cdf7a9b15b20b9aab5e91332c271c96", 99105164972765642),
-    ("0x6fc873549977314d204a1e35c181f4b9392246a3", 37158441063487435),
-    ("0xd891769ecec9dc911441a15b1a090cc9af1d9eee", 102027213549635069),
-    ("0x2028411ad3056b220e3552b1817e24da54b48f6a", 7542824507146043),
-    ("0x358ab77888e536e6426e4ee27a7120ed17425172", 7317852248311905),
-    ("0x75884f27910b3c640f913963187f3c1b0c33f4d1", 36236390165718392),
-    ("0xaa504202187c620eeb0b1434695b32a2ee24e043", 20533394910122870),
-    ("0xde47ba11c50efcfea2d4bdadbfb2cc7830fbe767", 2083219139872169),
-    ("0x982dd304cf5d140b5c1673999ccb4e69dc1e7e53", 11879946926559414),
-    ("0x38caf2d24a26a7f14000860198642e6cae834764", 1753431608452255256),
-    ("0x31c5b0e0f4437a04e4e1e35db99dc6ce65046f9d", 14058592404477815),
-    ("0x8e5f61f656747202fb7f8fbb784cd7dcaf0f79ed", 11807228673314032),
-    ("0x35642de97d6376dd55240ffd35fafd2619515e54", 15847005202767891),
-    ("0xaca4bd77e459b9898a0de9ad7c1cac34ff540d0b", 21889576857448564),
-    ("0x7508ce9b74c4e5e18c0e0140f8be52b5fb2d7071", 13612775137122657),
-    ("0xd970bb0fdd90a3cb0e72b1424ecc82b7f45c8b6b", 55785480707937959),
-    ("0x29ed191612485ed6c73144dd14dbfab5bf96e4ff", 16412408903614899),
-    ("0xa61f32f921f35c22eb74696b14e97cd1ff2adffa", 38846915298202569),
-    ("0xb25523151e1da082f4b1b243a95025090ed1d16f", 13243744141560936),
-    ("0x903435bb40a3955aba055488321d0016525f1c2b", 35051462098700847),
-    ("0x7bb1a08bf07caefc532d6a7c969d649dca58c8db", 37763347260036071),
-    ("0x4427263872cda3c53a0509381583164144810223", 3258590879011585),
-    ("0x8490f401d295591df944bd85aaa904fd0ef572af", 20448773321626238),
-    ("0xa362a6b387a80180286ef40d8e6ec6ca2657a9fa", 368221089245202067),
-    ("0xee39c70efb6af381c15e949c53a2025e0303340d", 1314040848016927),
-    ("0x7ccc7d2d5a660288c3ba5bfb4237d86227e9887a", 18818523243283617),
-    ("0x0aed1878803e2f86b6ddb83f5523f0a793e973fc", 116709733798035317),
-    ("0xd5527f4d4408e17c90fa22126fbbe6719e703c0a", 70620360279011402),
-    ("0xd2033db9c5370ac76abd80823b5c5adc097e2fbf", 41066437116174626),
-    ("0x0d624133af67e78ea4fd520c480a4ed5caa3184e", 14667288369604515),
-    ("0x296276068fdf7b92eb62e18362de33dfd8383c3d", 125240382625556264),
-    ("0x453aaa1aa89bb1039bcfdcc09981ccf3e87edf33", 16443457708107196),
-    ("0x818bd34ad2912fac9082f2d51718327d5a45991a", 11575160547574361),
-    ("0x519497f8b29f0fb27eb720a6ae4fdeca4016bcff", 436845189310214143),
-    ("0x47f80f8e3deb6d006628661035397d4f3533653b", 201423044451646706),
-    ("0x23a70957d494d02c93b4bf497e486636c43850e8", 7582870539843615),
-    ("0x32a98304f0d4d80f01d63acda40a5c38795cc118", 40151710658280761),
-    ("0xa357cb3ce710a4f90fb9d56979c2c3634e3965ba", 203177559978128044),
-    ("0xc910c7c425bbad5e733eb7a8ad4b9c4848321131", 11428451290157241),
-    ("0xe05b184607746bea3eb73ff16a7e307fec7b7dba", 110390754342716520),
-    ("0x05552468898ba593963454560b845ab4fb70ad58", 11029069098113758),
-    ("0xccb3957c3a63f114f3bf2b4e3358da8032b433f3", 62370864853599629),
-    ("0x8f2f3afc713a2399f1872470fb52bc7f0bdccb89", 43488460303996638),
-    ("0x57d98609d623b9885721a58b896d40c7871e1573", 34192764565204135),
-    ("0x7519c7ac335731f4679be27cdcb8e4b3e2b253d7", 31525340653378704),
-    ("0x49f9307536739580deb16f2dae82ba0d25a23b47", 41391459081575123),
-    ("0x08bba49acda81fbdf2ea7f1903860ac83988c75e", 30414223567174664),
-    ("0x9d6a3b9871fc03536467ea1b77dce142065a102f", 10360742649525804),
-    ("0x981944c99a7ebe9f741f28ff5cfe2a7008a5bae7", 734469762211163964),
-    ("0xb6acc90462d79105d6b9011e8f70370bb91afce5", 30176184513062664),
-    ("0x2a92a7edf8bf75b3aa9c9832b3b3eeafe34ba095", 40790550545000881),
-    ("0x84e889f59e135ff5d0f29b94447d191450f5f029", 30621634891143114),
-    ("0xa885bb15908b2bb3b8405baa840c6d19af3b3b32", 13877308394586443),
-    ("0x54fd8237dafac2ad6d8a84fc44b882a109f8cca9", 72926839952979492),
-    ("0x88c3b6f2682c43cff0a1050abd504a1b8671a649", 11138266474178467),
-    ("0xf1d3098c6eb79f9bb07949076457b5bbd05b29c1", 1082286620890880691),
-    ("0x4868839cd58241825be7681314d66c9432e9d6c5", 5804667783905246),
-    ("0xf9b2ed98435db651b7fd6675fba8151defe7f397", 15732480151631559),
-    ("0xe60d9c72c5ec15136b942525407d07527f2df2b7", 186553233495338245),
-    ("0xb2d1446ecd6cd538114aa7be476c53fa53aff0f9", 30919934646553960),
-    ("0x0bbdd27e2da45d2cf8bcbb43487a19ad08b393c1", 223475135035541245),
-    ("0x2003e1732fa4b08bc0c7fc6ccda78a85a6528184", 47300934091370880),
-    ("0xaa8b2788d816bfd11066642f34636972e0d464cb", 22852048933547251),
-    ("0x9ca9930dd7c7f5ff459a657157450a43a413fa91", 6310767958876167),
-    ("0x9448f21ab7f9839e537c2f91283f7744bc5a4db6", 35000000000000000),
-    ("0xf8894e21fc7155b9d474383386261a41afa9beb8", 12430264573427761),
-    ("0xe6c0a5b185909296d185c0bbca65fd3d3825a4aa", 20551978380823736),
-    ("0x827f089b99accd2458029201f56f0ce24822e0ec", 8604574045362119),
-    ("0x43cc54d120580bae7574fb38a8a2891def383d3f", 10652593216296865),
-    ("0xeaccf1b9d6c2076705020f813bb65fc0dcdf5474", 51002032061370139),
-    ("0xc92968a3dd7a46212b1455fcbeb382905e441722", 40958956587629278),
-    ("0xff4c1a97f79b55df26c7c349f2915946f7913dcd", 20541862413512172),
-    ("0x0a251df99a88a20a93876205fb7f5faf2e85a481", 59550763),
-    ("0xfc6bc96f0715596c61f2e6165f726b0d2f5a3c05", 437934486198861980),
-    ("0x2c5acd0d7ed19a54d27787600ba6aa3797e10201", 6204194401925421),
-    ("0xa5b0171529d5fcece0218b2f9fcd8b9afd6c89ad", 20355514547936822),
-    ("0x9e31fc445c984e4af7338ab92c3d98dae7f2c3be", 1000000000000000),
-    ("0x485064b874428be1f82e47f8cc9b1122631a2051", 8544049589557127),
-    ("0xbdfe089acbf73464b3c1153166b59a341614e7be", 60472359339757394),
-    ("0xc9aadb8759f908accc574cefdd031425254e4af1", 28569632780915758),
-    ("0x4285ac31c65fa8c64655151de9403bdd148f50bb", 216453346986445732),
-    ("0x35a3971701ea5f9f5e1a1c10c479416d529c238e", 4081223907328140),
-    ("0xd7db6d5d6269943b65381d832edb6e1e015424bb", 9223410631505530),
-    ("0x9637dad460effd258788d6d0b4e4fb1a6272b18f", 143585610233059258),
-    ("0xd5338045a25f4cd42a7af427e55e52dcb03624f1", 36687238907613940),
-    ("0x5002479748805ce20a44de8d4d08386d6f7085cf", 12745415286062090),
-    ("0xa72e7aa0cbd676b925f6b9bdd174293250f43894", 72586555760534861),
-    ("0x36c7d586efd531fb964c9c0d7affacace3b08d37", 53573676527608289),
-    ("0xd092a3bc551ca61f48107e902ec70641574140a6", 40335960839741875),
-    ("0x54f7997189344ab15dde79844b65e91e138eb324", 19451057123131725),
-    ("0x85ed65e145812167f4bf27c8d84416e691a7270c", 9261583462016685),
-    ("0x1de66924def43b5a02ba2501bc36c18a728ee641", 8049827353740625),
-    ("0xe54ffdf9f137e0bcfc9cf1f3ab8cd3d31c46eeeb", 10608460347598252),
-    ("0x17c63868e3ab7da20adcf8c27d4ee46fdec1c325", 33357697861041789),
-    ("0xcc5bcd3ff0465eb22d4b6d06149d964abcef434b", 5490072253756806),
-    ("0x9b7bed561f48ad8d08b56b40a7addfb6be6b377b", 21909415570944393),
-    ("0xdf6ba544370a949a831de1e8401d45920f0cd6ff", 105285270699420356),
-    ("0x8b47e0a9d8a8cbbe5d382874f43d5bd198ba52f7", 4775064659006335),
-    ("0x4cd4bc363458b52380d695b5c923b8994a245281", 177651543284834104),
-    ("0x38c1a42ec8564eb3a62966a831a5fa45e42b5455", 20196582479410887),
-    ("0xb87175bccf3667f2db84ea6891a53cbcd7627695", 16651761511176614),
-    ("0x10ab86371f80a462d7d905e9a1eeed6a3ab3a02c", 8328585597680913),
-    ("0x48a61bdfb3c5b21a64408b823aa1c8295584f58e", 9141956415719334),
-    ("0x27c2943985d0beeaa850a5bc13b1fc34b797b8d2", 40102449258922877),
-    ("0xb120f496a1012ae8ad8dda5a44b5a911b59a38d1", 9257050220605354),
-    ("0x86ff96435f69637ff15db5382788bcf680858327", 99020582732926846),
-    ("0x937cd537aa1cf944a936d4378852218ce6ce525b", 19269530853215605),
-    ("0xff8b0749dc744f8087f16c874c7fb3b662d435bd", 10314583584534419),
-    ("0x38400b6bbd2b19d7b4a4c3559bcbb0fe1ef45ec3", 39976560094190493),
-    ("0x6f41611e5518bd83840a766e67aaed2cce309e73", 15574508926394898),
-    ("0x8889be149bb4b04d89a2cdc6e69abd0816a74b0a", 15637335082815959),
-    ("0x28fad3430eca42e3f89ed585eb10ceb9be35f7b9", 78016963500161884),
-    ("0xdeaa9ca0a9c652b9f88bc4921fdddfce5478d860", 36070068327163021),
-    ("0x4994779612165862d7936c6351def8bcc1c6ccec", 19478125244152948),
-    ("0x3298492ee2689911866ebb01a1774db060ad6a7c", 112827777925773345),
-    ("0x6c384326070900fcdcee18b0161a0f8b631146a2", 35891375966482542),
-    ("0x87b26868d1042cbc2e8f998503b5d4c7416f713f", 5617974234162199),
-    ("0x0fb45cbc730fc51740830938cbb5bb8a4c0076aa", 11948603762201808),
-    ("0x3b3bb85ad43ff155db8b1271db3f4f442bfebafb", 20658722149979872),
-    ("0x3f2ae751d704a3600b24002481ec6aa49b3260bd", 21071746976194336),
-    ("0x88cea61067a56a967f0e0c671b5690648899341f", 17109040323583254),
-    ("0xe718bb18d8176659606b3d7d3f705906a9d3e1bd", 89960883747427423),
-    ("0x7d95d724c44675526383ef0c69b2745e251fd58b", 30824247168865963),
-    ("0xf85743720a20ad9870bbaf8cb3e5cca46d1351f8", 12204432510855078),
-    ("0xc0f1ed58c0ff0813c1d9071170cab1057bb51359", 268810704923659529),
-    ("0x72edb05f4e78255446eb4b6a89830d0ba4dfa09d", 40339507963056118),
-    ("0x7e52a00554bfc3ca7845a271e769065ab0de86b2", 48118060408358273),
-    ("0x92c7a381dfe5c4d533bb3f2a35e8380c97d3188a", 34526714117470620),
-    ("0x33ebb62dc9ddbf6b8f3c0efdf5bccc2e7ac60211", 315157010393160571),
-    ("0x6dd1ab21d458ef79c7889909bc5aacd10d46df7a", 142122750933611797),
-    ("0xc8f36f1e28735064d8e6b2509a9562f7de18a4c8", 3669243119773594),
-    ("0xcb8f7421fcc99c7975f165305c7ad375016eb071", 6897128159620084),
-    ("0xdecc3c83acf01158310619b0d6f7bfc8743d474f", 51484482688857287),
-    ("0x8c94b11f460481f86363563e7eeb447225fdb61e", 2930391152331142371),
-    ("0x440abb8d7f82c8e5f7d98f828dd890d06fbcd09c", 10357803199349619),
-    ("0x904ef42db3d19dc45a4c73bc313615a6baa4dd41", 27203054119832438),
-    ("0xcdf940dc2d4bccb1fec64a6e45f377fc8666ebd5", 61851214006020992),
-    ("0x20d709fd3dee24c743fe00e906f958f7beffea54", 2164139797787175800),
-    ("0x5027310081e318d4521abd4967be4515ad8a023d", 30971584869483684),
-    ("0x7723a759db7738e2ce5ce4c96428ae87fc4ef058", 26207621601512207),
-    ("0x5ba17aa52a822834936b268be8148a76fcd16884", 80469294549001781),
-    ("0x31184394273fbfd3e1b96dd18658b574327d386d", 33158478642526575),
-    ("0x71b77606190d9162f7fc671dcb92820b1b4bf7c5", 20729418486107259),
-    ("0x717493adafec83d434fd27118ce49bb3a01b4258", 8659167930492278),
-    ("0xcf703ff0fa3c4ed2606d9b5f2258a28931be9879", 14977639745295034),
-    ("0x8efea56c2a4512c3e6c5e64cea718aa3a1153a87", 19694193115109200),
-    ("0x2b8d21f3493de4f46f458126441ffec22a17235e", 433995433999011907),
-    ("0x361aba3cf622bd8e972204a7943edc1d693ca8eb", 37810729254185315),
-    ("0x16fb0384d76855457bc72c95cdcdd984c0b8976c", 53950193437794591),
-    ("0x7a3054d31fe2b8809899ffa5f3fa84cd8a98a60c", 101695508093864369),
-    ("0x5801f0b3288765fb6aa8b29e57bec86e561b0bb3", 41339908125227275),
-    ("0xe2d9d6a92bdf631d47c0717d0fea32ea28773f88", 21103164005367689),
-    ("0x18d2bdfe9616229e1d7b62f5c7fdf5c611fe87d7", 9575100000000000),
-    ("0x663a779574ee63b80ee4dd70f4b557dfa0644bdd", 26722700545028231),
-    ("0x9c9851b3423883f71e7d8b5948605f34b24aee49", 56402121072586798),
-    ("0x223ff7cdda3c230a28b684dd51c15ef250086e53", 53365762152017575),
-    ("0xed5584681bafa7533f34076899936406f7d01fbd", 11622075920966765),
-    ("0x6a8cafd89ebf4a77a19960d34073b1145613769c", 90538709575739220),
-    ("0xcb592ea1b7b01a8a91229d5dabfbc5408b5cc39b", 20092492378795995),
-    ("0x98cb737101c377eef5abd43f940c988cf9e503b5", 12295944081315901),
-    ("0xf1d95b16ad45114e2703e79269835888b58ac6c9", 21583980837882087),
-    ("0x41bb596edc8a2f7c3261b67e0535010f44fc6982", 2809528482666978),
-    ("0x60360c35bb3ae091c45479d52e84fc7209cb8f11", 71052070346673981),
-    ("0x5f6d14fe014ea28a3d0bcdf5014b3a274fa8b571", 14242689347049709),
-    ("0xf486dc6f93eed2ea686372c9b8bfeefa267f5118", 89589221024882286),
-    ("0xb0e1505d9c8eff6936b927816a819b1ebaabd67e", 188062509092252400),
-    ("0xa7a44563a8eadf668644cec5b214f5940cdc36bb", 2800000000000000),
-    ("0xd71a432725a5d7382b644f9c6746d76686b6c3c5", 10638352733605650),
-    ("0xc2e7d100f29d8a0dd2af67cffa51407e0433d28b", 3249570891382149),
-    ("0xb835a1a303a15389019023f813df5507f710534c", 9442889769102760),
-    ("0xf1e72e83cae27f59eac891a8572141e266bc83c6", 21693807606618316),
-    ("0x7f71bd175c9b0cba92c9759fdfedd375be10cf3c", 40428382760452784),
-    ("0xc25dd14ed329f366760cd6676ce0528472d6f57d", 13622411617209469),
-    ("0x14d1f69952f0939c16f00d68ea2c3f040c4217a6", 111087954016698557),
-    ("0x9dd6204bd060b3195cbc16c45589447ca09b4f40", 20310619427181600),
-    ("0x5466fcc303e35b9b667f05f6b3e0e992414166b8", 10084230142570172),
-    ("0x4ff754674cbd75b2d4f36a785511cc4193b21f85", 6383700223175802),
-    ("0x823382048fd922743beb7fa6a6fc235e5f27cfae", 142972739020128486),
-    ("0xa5062b4938f054d9b4143193310b1a5d438f6dd9", 76585260947708669),
-    ("0xd5b9c80b734806b986e66af8ab277e1c443b1a2d", 40821714494158147),
-    ("0xe2b98e20872347fdcf6c18bc07b01e15b81132bc", 48830340134940542),
-    ("0x443b29e221b54f7be8c0805859f57028cff0a1ca", 213101993234519053),
-    ("0x72a5c6cbd753149b728b47156ce633a87e352e1d", 600000000000000),
-    ("0x8ccf9e6b59866aca7528deaafeac41895aa294a6", 23535445772394653),
-    ("0x63fdb65cca5a9e66b7f8d925758cb935281b0ad2", 31476516467515108),
-    ("0x726ad4bdaf04ed2eb26c43881f8d8648f7abc047", 15153662687495978),
-    ("0xa40959c40aa65c530d510cc8fb3204d1ba13997a", 20625977010612158),
-    ("0x0d8c44854055f4becdfee63d2f6286548ace0c34", 9903507899930243),
-    ("0x35f382d9daa602a23ad988d5bf837b8e6a01d002", 31125374760696249),
-    ("0x8b7bdb4db9072902873556d60534a05a1a551c65", 11665025054691130),
-    ("0x36cc35145dbea5a020ddde8c37818996c0f3480c", 20871157697789795),
-    ("0x0065ec01a6284a1656b9de586884d8180babe622", 229582019500569968),
-    ("0x0354459e8bbfede144d4dde1b549278c8631cead", 13673047547530494),
-    ("0x6db296a6721212b43474fcbb5cbce4736299acfb", 40656036894509028),
-    ("0x04d03da530d45038a4dcfbcb104084ebff1d2052", 116057528355546834),
-    ("0x7c79759edbae856c0eb329ef930321938bbe32a6", 3096297602715970107),
-    ("0xbc1fb080bc94d08ecdfd856efe89ab99dda46c4d", 10799179378704639),
-    ("0xf6179303a642bf7656f597f10848968f6c8bb9ca", 40677680503231319),
-    ("0x14bd7ca23817d50faecbd0cc09701a1246cbce8a", 21065178774065230),
-    ("0x2d97b313f6a506f6c58279bb67a214459bfeca2d", 61711210185602102),
-    ("0xbf49b454818783d12bf4f3375ff17c59015e66cb", 203983848517520442),
-    ("0x6b7b67db255d52b58ebfdc149363a113ed00832b", 20023553472669834),
-    ("0xa6b0fd6c6809b71376bdf450c529501e41d30f43", 54693599185922007),
-    ("0xda281ff5f86d3700784519e9835d58f98f193c12", 2757404993391898),
-    ("0x439ee72e9f85ec484cc60aa5f68d40aa7bbbd6a0", 21478382115356614),
-    ("0x998ca2be6cbb678bedaab295f323ed223d0f0516", 20435881171798532),
-    ("0x9f572ac4a20eb83b067cf9fb8d4452041a302acb", 27558053173493719),
-    ("0x600a6b9a82e5d304918b77cf6ba6b1048699ded7", 25607879457705219),
-    ("0xc35448d7f12483385aa7bb2a41f8c4e8c73c1bd3", 78515674896842992),
-    ("0x30155c458c794be826f63b4ba56718409d282673", 32818675970544670),
-    ("0x14647973d093256920ce9c6c8a6646b3a4e43869", 247414924562901652),
-    ("0xc471b2791cbc9f28a3f4deab119fba766139e4b2", 20155775864874314),
-    ("0x72f73d7a57cee8c9c2dacfc12aa9c26f9056404c", 21066457197416791),
-    ("0x4b674f0e2faad464291fbecffe1923215761dc89", 5269993882949764),
-    ("0x645155c20d74d8c641b4584cb100d5cc14609711", 1163191372358959125),
-    ("0x00933dc8f06a7473d0e7f348e84f204a456e9d70", 20402799355123294),
-    ("0xfed66e69b454062d9e56f49e8ffc5e8f2b1f4500", 14852097915344608),
-    ("0x3fe25fb31f9e33938f2a530a298e995499879c5e", 34362265684623147),
-    ("0x14430084a1033f4366aafd9e7be3fd95fd4c71c1", 20254677181457939),
-    ("0x3ea1f2181be5f8c1c6a2e26c7d19a51eb8f6966e", 99031596445691274),
-    ("0x5de94c6bc5c0b3ccecd19837018d86b30736ad7f", 44257417010130609),
-    ("0x403c3dd64e86ddb5916a8b01998ae3026e34b30a", 29098728150325827),
-    ("0x8d1cbc225f0f2e0d149a7adb2a4a109094afea36", 15722892211390577),
-    ("0x71fb314cb44d43dc2ae4be1fc9ebeff787da001d", 11603072979208945),
-    ("0x5a04d42a55e7ac81fb6e0919de1108a2075589f0", 21013538735758500),
-    ("0xbdf7699a76a04e22eb8a7db866e7d0771e0281c9", 7954212717954748),
-    ("0xbf9bf6f7bd3c90059c3bf105bdc3575f7eb2b4b4", 33000000000000000),
-    ("0x0ea3db6d182d1ca573808b9d45d04f04227f2167", 13614098335608425),
-    ("0x04659d48685fce6fd2814d703c5a56d31d29acc9", 398168418819279264),
-    ("0xa5418ae01259ee1fde78b274f889912f19396d19", 8753310279139599),
-    ("0xf34c60c0a1062eb812fcfad8555bf85bd89260c5", 4071854692806977),
-    ("0x8ba8d28bd424d9febafdb65f7ad06c6f820703ad", 21110289442479864),
-    ("0xd6f82c5b7af5a6ba2a60421d304c93abd2a98bb6", 39693444710510052),
-    ("0x09ec11c143f39eaa86506b4085df9b94f9b3e0d7", 2596121524092997),
-    ("0x57b0be0fedb99029a80e6cd1e79ea53b957983dd", 14601486723942534),
-    ("0x68930044a37b17a14c76f7401da72820c0af5066", 10214696572622831),
-    ("0x5c940136f1a30dd9caca529b865ffc6990a9dddf", 16664468952008713),
-    ("0x5af5b2622c1213198411dcb34e8ffef51db3a857", 19799204737038331),
-    ("0xcb87186061c7afbdc45141f43f24c736f861641e", 25411560087193450),
-    ("0xb3a00c3ca08a1bd29341519a4f1eaedbba82ca39", 39646743356669562),
-    ("0x98adeb3f5f6aa1c9a74046a29e5231f007a0f12c", 21365919798066365),
-    ("0xef8e32768ce58891d2ab7aa7ca2c87ac4f5dab56", 14142500540335780),
-    ("0x54cdd27399ecb738e670b3fe078f3419e1ffc7f7", 8536771119062366),
-    ("0x658056a1b198395b3d254e2f80e7d160bca58f5f", 5241725057386062),
-    ("0x72e1ad02195a93442c33c56702a53325ee749e9d", 101808398910205775),
-    ("0xab2442a75ce2afe7b069fd457ccd22e51f472079", 6761313347337981),
-    ("0x5d968381598badaf55b1337d389f2ec92a0cc519", 7937651856485406),
-    ("0x43cc26add823f6703ac06b3f5247fe6be813166e", 211147549831417379),
-    ("0x00a6acada6fce2905c3dc3142ce96d68d8ab35ce", 18057461404490166),
-    ("0x727d79108b6c104ef5ceb5afd63f80e9c0dd442a", 35147820170479540),
-    ("0x96b215ceb2c5118141bc0c94c1401e377c6f2bfd", 106192192441955813),
-    ("0x56035e871439dc50c95cc0fa88b3b3971bec2814", 8589603005736288),
-    ("0xe97a60200d259a919240cee697443090b74fb455", 3261534925280022),
-    ("0x8853c3147c7487a0c09349db8ec4d2dc53f6cd4e", 29582069325216996),
-    ("0x859eb35a7b64257eb9ccc72e90e48fa1b0cee4ef", 15846051084932729),
-    ("0x7511b5381f6d06725709f416ea97f9cf7c8fceb2", 6161622313859734),
-    ("0x07801e4425369afc04a62052ada18e890af0db36", 5883792181780577),
-    ("0x51241798cee0ff6f4d25bbc36426e88e42a82840", 40055276877293773),
-    ("0x927a03b6606380147e38e88b1b491c7d29a62eea", 79987557707251329),
-    ("0xfb3950b8247518c745affad59b9ec9c9b485dcfe", 30406160194541536),
-    ("0xd7f721c220a4ecaca26f8eb14ee161d380d871ce", 3849735075748158),
-    ("0x33b576fe5bdefb8affb846d48ea68b9636a109af", 20745246710586903),
-    ("0xa9dbcf27af5eed865425352709a5a84d64d2d9ed", 51012407654453615),
-    ("0x4d8465c701e9b3873abeb5ff2fde6bf7f55fb242", 164046173234010826),
-    ("0xe15aa7f7de1f1bb51aed5d7c28bdcb59987f020a", 28976623607888049),
-    ("0x846f1b46354b4e1e5dc90c83d5139571ab1ca8ab", 59965943358729487),
-    ("0x07022938e17988d2df8fac3bc3bb44422a8afafe", 16531730830716963),
-    ("0x57b31dad7a6788513c1a6731bd08e1f6a6f49839", 40534709439641322),
-    ("0x409b9ea62a0831f26d09ed3cf40f1fd37510afdc", 25311598275698591),
-    ("0xd9d208471b5d2ce51570788db2d5ad09f96e1771", 101162854216575493),
-    ("0xd4e5a39a1d85dbe86998daeb7e4a8bfb5b4d9212", 802261571980156280),
-    ("0x1e135f50e9d1d424d6de4def147f71a59c8260af", 29851587810456966),
-    ("0x29460e16c5f7bbfabb37ec0428a4cca3337064ee", 124611250134565667),
-    ("0x9953181ce2901c1fb946c29c3e1f66d70395963f", 638600763375693708),
-    ("0x7c1c3c0d538736128e1ec9de82e4da9e7dc94a75", 39574546685840200),
-    ("0x624d6405b61db6491042461e609981f253b23caa", 23175694837422846),
-    ("0x4561b169326e24f9e9ac0f5fd2867afb782cadb1", 16079330831431376),
-    ("0xf205a765fbd80fdc1c7901e1550e51a42827f932", 6476267114863389),
-    ("0xbc63d38648cff8e8e4e47cd56f8352759c416c61", 3277660843199616),
-    ("0x4740f1136283fee975028dbcec0ed8154f48f3ab", 21372613658043769),
-    ("0x280a2aeefe83c54989708c268d1993ad966f6a9b", 292838091765308897),
-    ("0xdf19adeb97a91e74cf4893c6909d93d2331c291b", 18798224751506900),
-    ("0xd6cb382f220691a537c760900486f4dd99cdf712", 381408046981083248),
-    ("0x8bbf77d75749528258ea322588704a79dc30a8d1", 105359090071855806),
-    ("0xa81a6dfbcf161b419c8488907dac6d1cd11d15f6", 85743916013243567),
-    ("0x955ebb45951c3baf2d39c253cd9a828c21767a2e", 216704282681155805),
-    ("0xb9ea83a143dc15e0f7b70cfbc634e827da65482d", 115556830778505566),
-    ("0xcc8e7cd2bcda858ba4b8d352b06f9b85d239513a", 32367054629611353),
-    ("0x09a1a849974d021a0f74366e5020884ff73e3abb", 16078745607142740),
-    ("0x882f7afcb53e5c19530d65d19f73fb21d3868c0f", 19187160749418106),
-    ("0x008c6c629ab34de42f364cc782c42179b24788f2", 9827374103471767),
-    ("0x32cd06a20b528bced096945d6d165a61e75ccd2a", 198217194745388237),
-    ("0x27d205c70be488f983299bdf2574d862eb6fe136", 21128896187516651),
-    ("0x416e4198485f7944fe149f1b244d801217a57dc2", 10583055877446958),
-    ("0x92ff579190246f7f7325d5fffdb09a67c1b58278", 6317249557288503),
-    ("0x6ad97071e9a7652dae92cb5c77c2d024e50f7d68", 11857734279263303),
-    ("0xb120edcf3a168154ad78e9e73de45b87364276cd", 4112963657647939),
-    ("0x41cbb683390da764c50743a61673f7f1e8381744", 37917757803650333),
-    ("0x89e6ef193a28b6181a7c4cf0c16164e173df19f2", 138687566457857478),
-    ("0xd09e4db82efc5d35afdd1af248a9533ca1fe58e1", 35403915626667862),
-    ("0x82f32ca6004c0e163f471241ec27d6893516f054", 8825426118255222),
-    ("0x00917c372fa5e0c7fe8ecc04ceea2670e18d3786", 343885045286408654),
-    ("0x486ecfca4d234881847b7e6777ece72e7d188ca9", 28792951142755033),
-    ("0x8ec387c7fa534f99e1132472a7be5061d7cd9bdd", 10272099608085101),
-    ("0xb23272c5c16ab2908438be2effc4bbde132d85c4", 52341365092243907),
-    ("0xfe75b3f6a83faab707f0775f59e4be5f01550643", 14461285433867307),
-    ("0xe4a5938b1a5dbcf4d48c6bad09a90c25145478c0", 4854392818384476),
-    ("0x3c736983e5f473f41690abe6432ab34288587e0d", 34864098286183120),
-    ("0x9cf4229518ae517a2c743215e15f9072b48898ab", 5619860946899085),
-    ("0xc45369f53672578d8b52cba6e8d0f2b7c5f31e67", 13435896412399908),
-    ("0x24e8b6aaa7596392f097e2d9454904e1a7ae0425", 97323657692442240),
-    ("0x949f4c27e32f694f743b050856aa5771f38f1287", 40439374606009750),
-    ("0x6fdee8f1fb8f8e7a2d58b9b11261693fa946fa8f", 3462825051146987),
-    ("0x94d9936aaceda478a0b5d2374a29f0d61a584791", 15583621436331485),
-    ("0x51ff8f7307124765872d5cc36a2239610931c3cf", 10733607024080502),
-    ("0x5989ed76aa0ffc2d6b554d3867ae503e1e83f980", 10323645983867510),
-    ("0xfab7fccb5c14dc6c06cc4db2ada804e178b7aa94", 11843804155688594),
-    ("0x0cc1abec2316ec10b2d3c4be22724ba46dc0656f", 48429339282777504),
-    ("0x503d2966cd4a26040c111824dbdd1537a34e9fa2", 10397970738869057),
-    ("0x9f48e089e0c9a89813eecf1b001d4510f44e7aad", 2628831957313392),
-    ("0x6199e5fa5f627ffe703291418df9683c3608ab8b", 19702084313623977),
-    ("0x51f411027421215838445796aa6911578bebbf94", 10553382160730083),
-    ("0x1fd0a0403fea1c33da2d236049b5335864d35139", 137325635713970076),
-    ("0x1c4a7dd98529290ca7fca5d676f075e994b7bdd3", 14677809262569671),
-    ("0xf7f4368c57f94e9133337a9f35da203e2be10925", 15238315053419143),
-    ("0xefb4415c905a7ef8b6bfdad16f43be3a358ef869", 23006215135339876),
-    ("0xc4f8959933bcc4860f82792982be0c8b2940aed5", 3355233893697554),
-    ("0x30dc141bddf641c1276a9ec3d59d9c610923649d", 928058306060801603),
-    ("0x84b6abc668858beb5cd85c1983e211e968185040", 4922266689690892),
-    ("0x9ad2fdc0c515c2fe5358acd8b641f799e64e0c5c", 55925277751574650),
-    ("0x0066023b24edd70b7def47effbf2fd7617f9c66b", 38757684981656997),
-    ("0x0cfed926f5712243e0c57bc9e38a4811b9ff73a6", 464788783334291852),
-    ("0x540cc6c1b84d9c1a1936e1e24610920fcec4121f", 1960022179876522),
-    ("0xf41605d3a9062b868b6637b910b6f61a6b9b8527", 15781877734382081),
-    ("0xd72708e95577f80056fd6a44ec30d644b5a86486", 1239349008569351558),
-    ("0xb75e33e2ebecca0bc7972c5b32c2b39ffb473fdf", 9711972055599302),
-    ("0x462d9bb4710b29431394c04467ac345c7c55c1be", 101777806531912857),
-    ("0x5fcef2ec11beb7cfd7a9faa61143892894ba446b", 35000000000000000),
-    ("0x31e4d5b11dc06ba506a849db15974f5a7eb36676", 43057738925385201),
-    ("0xfe55cd6fd0bdcdd53fe36727d240f88c428deb73", 12335915464769130),
-    ("0x64763309212982a769df4d3966b4ddff5e1b219e", 58876663126232848),
-    ("0x4281eb38f66be4cdbc43030af01bb722b787a2a6", 100757612252251891),
-    ("0x8f41af62d3121f07713ce1a5b19f9b55ff4b748a", 22414534413407148),
-    ("0x497a2566f804e36eec34c3dfff79d66761d9b330", 26308343107893200),
-    ("0x8a08554b00de89c268d0fd0271091e294c65bf3b", 74398678888865507),
-    ("0x11822eaa9cabc0c851e4bc2882c1324dfb5f0b15", 11935767279662692),
-    ("0xfd43dd630d0d9f335bd24d4ba636f9c4ad411d02", 421476384011238191),
-    ("0xe32b8238bca33a3cd019c69e27942f2e78b46bcc", 34762081932768979),
-    ("0x6d8ecb612c9836a9b274f27f7dc5e2834c241c7a", 6139023498494364),
-    ("0xcdb2c18eaa364b0d1be092925582653a766d4fce", 27579107465773899),
-    ("0x759b232e7e77e355486d366fdc312be5aa3f21b2", 2845000000000000),
-    ("0xe56a192ab8571628ab1ade27dfc2f0cf47221eae", 951088802790530223),
-    ("0x8fc1dcbddac44ab29bbb7a015b6c8991c3d264a7", 48246385657882643),
-    ("0xfeb42f070efbb8f87a88a0eb1e47ede3bf0f19ff", 10498392480026649),
-    ("0x57bd6eca6619f19cb486e696f0dd0b4d1d56a12f", 132572715970730494),
-    ("0xe512c8a11acd3487cf69b8f5bf0de7e8759e203a", 8361273824908270),
-    ("0xa3f43fdc47598e2dd1cc6795eb24fd67e69a35ba", 15047716684424175),
-    ("0xadcbed7afc57ebe10015a893a8ea04ddcb014990", 1000000000000000),
-    ("0xb99d45612548a9ddd4ed063bd1638ccb28957a6c", 107485985282217273),
-    ("0xe1561fa8445d7c9d625d8c2fd8efbe3ba571b7a8", 12956587257115110),
-    ("0x014de91eba3911d1fad189149fb3e567bbd9446b", 58236585046737831),
-    ("0x6920c73e6edc6851d55f16a6b3f6d3e2b961ceb5", 9265139388254319),
-    ("0xd87df765113510e72199e5764f27ba32cf3c2a27", 8666548839860347),
-    ("0x22645019e947d04ca9101185b03987d39a7bb49e", 95988441796257303),
-    ("0xa9d04a12587d177f53d65064490ae39475b75b53", 6989529512465383),
-    ("0xd4ca986136bae9b436a532e096fed7fae00909d0", 27016345334957244),
-    ("0xf54277596f4bca662e11415a84740678dffd5a4c", 51544891225459412),
-    ("0x59a472362e4b8289b369bc370940e1fc78532226", 19831521773639102),
-    ("0x4df21b25e252cb44a701c8519280cce73237d3f8", 33789523447141296),
-    ("0xb3254852d34f1137da306f177bec7c4d8b5db7e2", 94934235868537098),
-    ("0x7520a0e732e1b01cc789a4ac41d7200d8495a1c7", 4163960668870371),
-    ("0x1ac5ac44b5ddc7ae9a0d4db12c3d257446e8d4e0", 140315211217070596),
-    ("0x9d6d254287922f9a96b23b2b9f74c735c9f655cf", 33523319180193292),
-    ("0xc3c29d099207380e2a2fcffe0de30111fe16b6d9", 23682172937111912),
-    ("0x62d589bcaccaa636827c5e0209468080ff4577b1", 51207449031286850),
-    ("0x652151c8521a46bea762624726214fadd2e666cc", 916217668692255190),
-    ("0x2bcd087576cb955113418ced0a8c2be1b7a3dac5", 20044387063454536),
-    ("0x829f2997e8103ee14ff6e69e0bc2c4ef4092c178", 44327314970214142),
-    ("0xc14b3e49dff0886b07a9c316d0755a8e159cdc7d", 84464300436273136),
-    ("0x24cdf11059ebc2162ebe103a8d3d4d52eb86ec2b", 16364620177285639),
-    ("0x307b074ab368ba4331ecdea9ce3cdc91d1c30c60", 182252485871612345),
-    ("0x3d44391b47eacae8bc01fa00b3ee3cf2e48348be", 10454986808459442),
-    ("0xa3222b8b56e003ae89757f55bf760d49f042dbac", 33409465646520845),
-    ("0x57ca52b8be3b25b5092d498a3b0e55a0c5bc4012", 7072350420604001),
-    ("0x220c95b191ecb4652824865e6c12f4957c8d2083", 15755167219811141),
-    ("0x4f9ebc78810951317625736c9783635c343d2f82", 37609137664153147),
-    ("0x6ee88e9a2104c468e4aef42d456c87d28198d277", 10145933949289741),
-    ("0xd8ceadf85ea3d7e6f423d3e6c6231e5839049945", 19969567240535048),
-    ("0x2f1264dca7397b768ff567db2d4bf6f409594286", 17985758428652335),
-    ("0x6de9153f5192b30a1e3a85e41c60739e111a027b", 28734421639048524),
-    ("0xd10405914d1feb8b0307955d0d83da8c9a8a95f0", 31728888250223449),
-    ("0xa665cfb63edfd85a8d0dfa4275b873132f2c4f57", 97073668510283595),
-    ("0xa5d9af40f920465febb9d86ba6b2ac92f44d52e3", 21915594911501279),
-    ("0x0ce2d5a67389a2d0adefb20806a5c0843b1aa761", 90645749570656548),
-    ("0xa0f133e08ee00a197af7b40b9497424df768790f", 181535322700555123),
-    ("0x4ac4ab29f4a87150d89d3fdd5cbc46112606e5e8", 32816890727007487),
-    ("0x1532bdca7b913d8bddc6450647f75d268fe75c4f", 7367432625049683),
-    ("0x0642542633441195934743796335194433861803", 2608481454610805),
-    ("0xaf86899ba3730700b4c8a4e87da6358202b98aea", 1200000000000000),
-    ("0xdc4d0d92ae598ae497a7fa824bff7f9492df691b", 90549672596073631),
-    ("0x041fa6a56cc203b210dbad9ee45dc08c1f88aba3", 21741083687194669),
-    ("0xa39a2073cd0ce7c027d6766bd00a09699d08b412", 18293711437350657),
-    ("0x583d6bb8b40600e5d2c41ded9bde1d4aa81f0f04", 91031370709487571),
-    ("0xd29c57985a71cee8fd6f8550688b4e8e576f0a99", 179782985492066909),
-    ("0x1a1a64ac35ea174cda812bc9e6b65ac846c59236", 34505124139852054),
-    ("0x227ae1d320b68ef0228f67178c9ec6a6b263bf57", 23157613505281399),
-    ("0x695858ea057f3d12c37ae77deb8c77f07845380e", 37563586485084822),
-    ("0x89fbade3f8805c4460e4fb33409fbd04223d02cc", 9275177927882738),
-    ("0x768dd15df8f0c35346740bffc036567e60c8cb92", 76396570537061352),
-    ("0xecfb495f95364110f4992663d8280ad7c8cd2d26", 7930287623587744),
-    ("0x6d7918b2a461808c89e6b9462de42c3df01c8998", 89767711415439305),
-    ("0x54974b6ff1cc1ba97172e4a4f679efb5cf5e51e2", 71741235669029744),
-    ("0x4e220beddc16c1df88d58664cffcde9d2e482971", 178968652991347887),
-    ("0x7f326331f51cc51743aa7831aefd910f79b34aa5", 21129851182070674),
-    ("0xae1b3eb4e8b5f5c9e8213b87e4da844b61faa48d", 25163398092135900),
-    ("0x406cbfc2d391bed42078138165465128b4e0cb06", 14257837954463156),
-    ("0xe8c81d66fe442ddba38ad17d6e1cfae45697819c", 179137434314465276),
-    ("0x510b10c11ef6092b422664194585740093f83317", 17376286273221963),
-    ("0x16efa79798245378aa50ec23020a638a96439372", 182338611653116619),
-    ("0x0c4debe5a1f2fd0a5e36bac289eefcefe0758d36", 258697847845684779),
-    ("0xd9677209f97a31899bd00430a6da7a97fda38047", 11588260029872670),
-    ("0x8c44c2aa39c82b75f2f5945f36a2bf6784db109b", 9046237929038349),
-    ("0x79ce2b8d43c424d6cb9eaca1913d2482c6d81ab8", 24720923162508592),
-    ("0xb6f8d10f1bfff3631c5a617ed2fefa42e4f5aa12", 20564985589319959),
-    ("0x17fb74e16c179d9e2c545f21e168d0eb8e5c1c1f", 30110653476066895),
-    ("0x2f1454a5bd2d9a2a7d9d9bfa21619cfbb1c5afbd", 12237634660348022),
-    ("0x5cd60fac774e5d1092ea54c235b20fbab7b68498", 159658181371036148),
-    ("0x319140a0e916168bfb11a3609ef7195e7e650586", 10099855884900314),
-    ("0x105badbbd9fe1ea1039dcaf217b5537c7bd53c20", 102724132450162796),
-    ("0x5db0dad880b20bc65e1f0d1ac92a78ca5dee1963", 29731739250272863),
-    ("0x94ebc0177c6519863ad6dee35cb4945771fc0267", 51734936836882680),
-    ("0xfe934e659f63c6ff4d3332c82729eb88c41e7ac5", 17155711953396373),
-    ("0xff4d5b7c3751dc9a1933a35e36db71913afaeb16", 21726917839351551),
-    ("0x64088f44c5109b9a2117cfd16a19adae678d5b35", 18376290095952868),
-    ("0x0077f2c8452eb223d573bc9931f341fb1711bdd5", 34244820999838113),
-    ("0x3f8ebeba36c87a95a3861649e79d9778a9dd04ab", 6815128025425283),
-    ("0xc68ffd478f285949c86d521ed6222a40c10a7327", 45383197530688126),
-    ("0x1c75ce69e7fdea31bd412bd1f64d948bf24de2a9", 21126958290630529),
-    ("0xcb16459aa736ac3a361b7bcf64ede4104987827f", 613781665577794805),
-    ("0x8214e4ab4fd9212b7e14d97cc0bba6b9f4c37feb", 9655478545588563),
-    ("0x419a8a51f0affe2cfd5f939704faa3e10361c373", 7472793502866753),
-    ("0xca8eec7090c38ff7c73f81615eba32a39814bd3d", 5982558157461722),
-    ("0x1546f09161bbd49bf1deecba1b0d2be8795d0d81", 31000000000000000),
-    ("0x70e259a3cb4768f741d22368b825dd15c136ef49", 22275615699152491),
-    ("0xd0176a6a5a9956f9a5d2d891faf3bb215b68bdef", 68438825313348960),
-    ("0xe9e3af00d12ecfcd79c4dcc1cf7d780a6c9fe87d", 5894183683575221),
-    ("0x8e82c898355da069d63a7d77a39e781ccc8d5ee6", 19972992403232511),
-    ("0x5337ec13bf8b8b0b323b92f83793b47e6778645d", 89471997821549144),
-    ("0x919ab2fac961d88660750a93475add1901786782", 9132690162316642),
-    ("0xe224e41b8d07f99fd880dfad8ea8bb822125a92c", 8943200223378724),
-    ("0x070169f83218ee243950aacbd35a9b85dc2be05e", 25225374277129903),
-    ("0xed3217fee5b128682b022323724d1239f286645b", 5604935420764617),
-    ("0x78d12d815816675584017271e1cbed00af32ba54", 32495860076544513),
-    ("0x63d1690bfabb3998094fc9c3b01392d8b7f21705", 9613275129533183),
-    ("0xb26032054707502a8236eacaa1e8726d03985a0c", 20409934940228644),
-    ("0xe43873ed698085d92fefab766dee5262ad98b2e3", 5370845716421954),
-    ("0x5ae5e5278ce81a34b1fdfb737f7a6adaf8d110c4", 36403019470033599),
-    ("0xab48272303c177e18d63605cb78aeecf38aae93d", 175076245854404824),
-    ("0x1df40a73156738aa168e5aed8247f90c50571a7b", 2770100448059432),
-    ("0x2aaed0d1bf9714def1cbfec07289f6e69a18ba36", 2002063199925937480),
-    ("0x2b148b105e984ae6aee2d13f30fc1445e6e8a3f7", 31836635218453183),
-    ("0x41d61fe85967ec538106b20a7757a1e405f5c1f6", 34319903513452884),
-    ("0xe42adf8ba8582d274fec614834803ad176a3b2e5", 10807288053952601),
-    ("0x64ef7acc775238e72c37b87bdd8f8469e00796d1", 7100220708452544),
-    ("0x1ffb2bc45d28fadfdc2dd569c5f5b6e506047829", 178057017938095432),
-    ("0xbf39bb5a9d64a78de42eb1525579fc00063f9fa2", 60377075781411043),
-    ("0xd14f5e4cff771d0e5625a7dd0bbe2ef312659315", 9910663507384136),
-    ("0xe2c551017dc8ad0f63c51cbb98d30de2276c8c33", 3430900174369283),
-    ("0xd871aa49ed68e021219cba4eca065122fe8e307c", 32891431281605563),
-    ("0x977ba1e5de9210bcd5e9a20fcc5b5e634fa8b860", 157255036761309405),
-    ("0xad75bfcaae5302a7c4c6fbc28927a274a2bb7d5d", 32169966819179466),
-    ("0xbef102292ef88c20ad61f26a858ade25bb06407d", 121387932680268763),
-    ("0x0428f36cd04d709a5815bf33c1022af06277ba17", 15709754639139671),
-    ("0x4a5f392d13ce168276d3f8b8f32f4d6b584bb20a", 68743698185096857),
-    ("0xb32e5803e0964f392b648f1538e92efd0f25c083", 19163644434501542),
-    ("0x3935b8d9183192127ea42de420f8e771043b3764", 34743663036086685),
-    ("0x4c73ec69ca6851cc489450363cdc9a88529dfd29", 16746295212976593),
-    ("0x504007dab7db1e526e040714e5ef1f6e4d5f4c1d", 11203295984746490),
-    ("0x2eb63fa9b23558e57a031dcccc7408a04fee23e1", 16810560143275903),
-    ("0x810cbff555cd28a250b53886df35a14ff2ae7f2a", 3135731787977842),
-    ("0xeebaff9c8dd6576bfe01e35a804746c486803cd9", 22376535366392988),
-    ("0x984a01f59da6f60ee21c2342b82b6247d9a5c314", 40050000000000000),
-    ("0x0c655d8b5e3e70248366a8f22cb7f28ad5ee3070", 17800737175003653),
-    ("0x4148a6c1d1f4b4db2fa3179f2df7f70e3a3d1d3f", 56363433995535278),
-    ("0x6a821dfbf538d75c0aa16c6516b0a00f1a74ff1b", 33000000000000000),
-    ("0xdb33e85224b11765eb1f1dd81c3af181ae975962", 88821975187416494),
-    ("0xdf5d7312c229dd782bc1311cd98b57749e343c48", 86053319996565317),
-    ("0x0e76a6d7093aad90c4bcf71935d7ea65f867a4d9", 37295062194724625),
-    ("0xc18912ebada22936bc2bae4e056e0aef8f718807", 28621983171060672),
-    ("0x901bc1add7cecae1c353e25e1b5eb91a2ff3c4c9", 89364846499214048),
-    ("0x9c51b2f620850ecfb0e3886b004c45f4202a33ba", 35274823625132375),
-    ("0x11c2c8ccce21b07274917880b67bf6f6a797a905", 35953844358483700),
-    ("0x66b8c2f780710874fb21d2795404102010d7a034", 16295777917403892),
-    ("0x3fef61237ff56c8b66fe8cf90146be4ff771f7b8", 35578844254638420),
-    ("0xaffdaade128017daca8bbc793ed54bd05faf18df", 38053409781454622),
-    ("0x24e4d0ee23e6fea7c298ed366677f1b7ffa5cbfb", 18043393201908257),
-    ("0x53702e971f89f537a68c2a0476ac184ecd7f79e9", 18837022891308867),
-    ("0x2a1049062c6cfd69bd38fbaf3b0559df1dbbc92c", 35325134162203165),
-    ("0x426984d3be8d6236e49b69ec716bd9b83347250c", 14566775105483072),
-    ("0xa11c4abe9cc1c4dee10d2f4093d68c0dfb1dc42a", 15303700755926251),
-    ("0xa9e4fe5a26c0974ef5bf6976110146788acd23e8", 23523016102950150),
-    ("0x5ed04523dfa5ae4b00852c6f34f462d485e47e08", 21899902942561198),
-    ("0xe57b5538ed089e9376851c639598859aae3bc9fe", 62469512397279573),
-    ("0xfffc1f3a01eb622c5ca226c99c7710926ecff2cd", 9463350975258648),
-    ("0xd2d20eca03c09ee761e9e7a5cbf81d0781f71640", 10853476078192784),
-    ("0x5062b397d2c7cab55581b38c554ad00d772d8f83", 18794926198617097),
-    ("0x7008b1234b13920c3d37ecd735364995174cecec", 14680658657744487),
-    ("0x979408835b4971557f2eb9d53f33cf899e5229b8", 44061925927665191),
-    ("0xac55adfd480642e314433c0ac6eba3c6cdded735", 10447816402673103),
-    ("0x5ca47f7f2e7eaf1cc353e56e180363e5360491b7", 15959923899413782),
-    ("0x158275965837d6f85ac7a1758e08d2eccb64459f", 11138007019766605),
-    ("0x919b7bc25b60b98111e4604b8529dc06944ad416", 10236320690740478),
-    ("0xf84c118b7d4c6e5dbe4b2a877a18edca0170a4a3", 4960143287557985),
-    ("0xb8d865d9c168d7e6eb28becebdd33447d0fe5e42", 8329029347352909),
-    ("0xa22560faf6e405717bc39e9d9439eeaa637ca092", 123512838590427040),
-    ("0x045a24d7942b3789e0dc5efaaf69a6b60c0de14a", 16253416733209745),
-    ("0x96011db5f2b5e06fc1a8668b9a202bd07a91be2a", 22631738512840409),
-    ("0x68e9496f98652a2fcfca5a81b44a03d177567844", 6150328049034953),
-    ("0xb435871b0959561226b4d903b1abf79528177e81", 126306237111341871),
-    ("0x8042ec74b174535dcc7b7694f2fa6ed88bb54a30", 16150709242925561),
-    ("0xada7673b48d656a87cad01bcbafe6b1ee6d621ed", 100137150622097106),
-    ("0xd095632dbfd7f33ac747ef5c482bb7868e0d94c7", 19153583842599506),
-    ("0xf39a704549ed88e38b13ab45e2c483fe45d0de3c", 53664839268993127),
-    ("0x16abcb006fab3071069578a39ba65189012516e1", 101796511298124707),
-    ("0xeb7aebe858c64450e3144512189a0ffebb838d59", 35601788153340197),
-    ("0x562d1588ba0a59f8a54cbd69728e7593333ac875", 61801402671340977),
-    ("0x717d52e84ef30875de52834603c112675cedb7ca", 53555075671333288),
-    ("0x18b29b260be911a36201a09ba2ae366b662cadcf", 10580757736565048),
-    ("0x4623eb52499dd278218a20a23706857c0e269387", 16033372730277314),
-    ("0x064494a175db9b860b47e0e4ba0f89d62b31758f", 52769504466590148),
-    ("0x9ede008165cb094c530043e087a5ae03a6596472", 21372286430395599),
-    ("0x455753ba3eebee8224c7e947f7cfc64a9ec1aa7d", 55367436420553492),
-    ("0xbc6839ff35cef154866e044ce798239c04cdc810", 10840319262147560),
-    ("0x7ab19ab563ec3e0aa8f2e283513286a58d3a4ae0", 53131220558967201),
-    ("0x78447c11ec2f093ad0fe0a66f163500c2b04cc0c", 3819399340842604),
-    ("0x2e039299209de1419280127d6823a3db1e7e1ee6", 33146811473674682),
-    ("0x07b7ecf56f99d33079f8a88fe20b36bc103ce130", 137235057388442509),
-    ("0xf9555e8a3e93f67b7ae02d1fe17ed4088b3fac2a", 21032017300033319),
-    ("0xc9704f8dd1e76de9d1456cd0bbb54c922d7a5682", 5201607416920085),
-    ("0x3909899a6eb22a59890de400241fb74389a680bd", 52005797074264740),
-    ("0x127563d0b37872e4956bf9b033e3cc03c6bf7e45", 19948694940703000),
-    ("0x542ba498011a50bb5581d2890eedb72786f13318", 35442186980171132),
-    ("0xc964135c65779e56a45d6ede6653334776e51d7b", 4312792655206187),
-    ("0x7933999290bab20a483f58683654266648af4e97", 4048920823303533),
-    ("0xcbf6a8f242a9afe58b015ddfaeed25a2eae950d8", 50084520335799133),
-    ("0x0e617074d18517611f03716c467e06df2c1cbfff", 11533580502801052),
-    ("0x06f9558dec45515d8e656b863404ce3ee8728d5a", 70721840831836999),
-    ("0x6c578fa174070cb8f5786b2e82458b63040c8429", 11928283253187235),
-    ("0xf8e61bad7fca9310fd978733ca66587b3c66f165", 7801424437238672),
-    ("0x8a68a8e1d1f6dc55e20fe8210859a215cb350301", 6234528334441113),
-    ("0x440fceb26295efa64d1a091c70432c6a61199e3d", 201176821713291043),
-    ("0x4d4f70942c4d526ad800c0bd4b9c61250418bc09", 12873298056007310),
-    ("0x48dbdbb7e78465b15a6f3a92286eda8bd77410af", 16499571657983325),
-    ("0x30fe0d8d2d81ea568ff60b6e28ab74eb7e548cb0", 38850115158204539),
-    ("0xe3ff98f12d80aa7474997510a32c1bbf76f8b7fb", 30971382246419614),
-    ("0xa4666f79c688a3de94951232d00e428bf7f24ec3", 7583453598725898),
-    ("0xee85ae5daab560731243403f56c51654def8bc6e", 25936255758854456),
-    ("0xd92b07359df63d8b9d2e4ee9c87ca453c6a1237f", 13020935638029596),
-    ("0x4fe880ae3a4be68be8695fd836b03ebb49ac6cf9", 4957980045645446),
-    ("0xd3ae31b669ab51391f9ba970773a414c0ae152af", 9659868655090390),
-    ("0x126343cc89b71e14e9cd7319ab48058846675f0b", 10969244117082569),
-    ("0x5c5abb7047b27c70abec5a840f3d0c4c7759ce8f", 1046557351297973883),
-    ("0xcaa37d61240a9627bd0ac503cb5fa55bab8e3df2", 46104115409962877),
-    ("0x805abdbefce62e6bb68e427b6209d2434512ec26", 9923895230018932),
-    ("0xb25a5657838004321b3cf7676a34954979415dd6", 90373773510616946),
-    ("0x765ce21d34164b4ef7b362c3835f60a711b5f554", 11391611404220712),
-    ("0x5a9d37291d786e6f292975b431574c2e9d05cefa", 10747708065419228),
-    ("0x695d52f2e0bdfbd1415a6e0bec3b0900c9d4b7c5", 8430550024609431),
-    ("0xf9ec15aa5af95a0620a0480bb4f70bc501b790d9", 37053969052449455),
-    ("0x869ebafacee7a88e9d912e14a4b5dcfc055d2e65", 211245032047270015),
-    ("0x1218cf3fad0c4ae07e285016586c72f31edfbbe1", 7851393928147065),
-    ("0xb14d56ce29dade0316ea5df06d491636a7c4f3c8", 181925698427889519),
-    ("0x70bc96fd8b1e4264b138dcf2265dcdf749579590", 30337023682254784),
-    ("0xd962a9cf49710197ff8b02cec45ad80ba80c05c4", 42729093910301212),
-    ("0x9fc34d7aafa38c58689cca6060c4e178eda4940b", 17575099162324227),
-    ("0xc29c6f38ac761fbb2ac65f42aa911b48838e6afb", 42974683102040678),
-    ("0x5d5119df5db720fd12bff0d14096db5762f523f8", 31571821916349646),
-    ("0x113885d77d03f60693bc511b5b31f80b90b09fc9", 9974545005939122),
-    ("0xa81e7e084edd2d1e5b425ea922b730a0c4210e4f", 12107278504242137),
-    ("0xa0126ced77451368aec1c8fe84066f5f22d1d2d0", 115351671743788856),
-    ("0x60d90a2fd9afcf1eda3ecad19adfd8332dc0198d", 19531956538767378),
-    ("0x9ed0e98e94ff81b7011fb2aeba8518e867c7093b", 16194696119429286),
-    ("0xd8f3b47030dc6f13c651f77d39b8c5593f6af5d8", 26169237274172922),
-    ("0xe6b532e63f228087e26a5897131f2e1d043e27f2", 4641432876913810),
-    ("0xc86e15edf575c511a0d7af9552003edd31a03ac9", 84804890118336041),
-    ("0x5652f20344c1657a5475890c4da3cdb94e580509", 9792681877946811),
-    ("0x6455c07be90f40d51b041e128b2c4b00c56de508", 69098414689284840),
-    ("0x79ce08961d3f775cee5c8556513ee8fbfc7ad553", 10987137545291982),
-    ("0x4c7367c3f88616606bc7b1c98d7788caa32a39ed", 36136756297626546),
-    ("0x07e22dc8c102c764ee1677176beb77592bc31bd1", 30708024977314785),
-    ("0x716df75f158c83ec36753e38a76ac6417c0aae2a", 31182358123593957),
-    ("0x0321e89a7b9484f47e8ccd4daf97ee8066783c04", 17814405385876487),
-    ("0x9631733931b9990a74675f456b94cfd7daad0c0e", 2995133202945664),
-    ("0x3f98fe29a7788a87fe3effa2b3985b20c6129e1e", 30268084929200964),
-    ("0x3641f2bcc1db81321173859b57596b47ee902e6a", 18952237868616839),
-    ("0xbe3fb7d46d7abd3c3863204552c7da7e6f173c32", 121293507886942026),
-    ("0x6f603d408b7d76e57cf88f9fc612ba33e30a0458", 10636649799945514),
-    ("0x0d277979cba73e3233d239b7a44740ec84d1c2cf", 4855019920846930),
-    ("0x82173afeefe3e0b09cf3f00e7b1979a2c8776921", 10181872315259383),
-    ("0xa73e5597e7df0c7300f4657165c0a67e0b8dcf9e", 18821173363253705),
-    ("0xae1c4fc7fc876571e99a655a97f9ce3f0134cc46", 18179363240012002),
-    ("0x3666396a83fca511cb6eccc9730fa440b32187e0", 21493554320446829),
-    ("0xff0acd67df8c4b0958da0ef4b82ea3e10e591c38", 10974642526233818),
-    ("0x86cb4e31e8ce5e7b4d3d5c0046fadc41524c1ee4", 20204009454344088),
-    ("0xb97a9fecd3c1cebe364b57dc396474101dd89d4c", 89606932147820890),
-    ("0xcb9a4d56dd661a8944c3c58419479c7398e16c58", 17020057627539373),
-    ("0x52011e4546b9fe412157e15be074d33f371dd04d", 4525844523949404),
-    ("0x3742284add5706ae90caff01f84dcaa76633e9da", 40373032050052235),
-    ("0xcede95943ff490c3688bab61ec383abea711067c", 18609501140203588),
-    ("0x8ca85a1552dd26c01869f2db46403dc0477e71cd", 20234331666182213),
-    ("0x904bba7ad34f41dd04c1b0404e914556b385b310", 5361963581806307),
-    ("0x1e7127c81c8a58661a0811f026b6be66533934be", 101709382927404648),
-    ("0x98c4ac17edb537d2e50056205fd9cad00b8f7d70", 13568002593085298),
-    ("0xe968e4101269ea219e5b9004123431d7fb7837e4", 15722862364937519),
-    ("0x878d4139ad77ca3927259c9deb2c7de3079e287a", 30310901941507365),
-    ("0x5a3e569c54dcdec76cacbcf3a01790f9095fb1f2", 88870029837437253),
-    ("0xeef39f3ef4b26c2044384a82fc7227b61f2d6700", 210193231190861822),
-    ("0x9e4d6a6d107201f3b771268ffbb2e41455371181", 14045908650645080),
-    ("0xff32bb605e92b3fa6f97b3104d61056787fa584d", 40569266736129824),
-    ("0x8cf03556ece605a978e2b2d3648fed551260a60c", 12974033203575373),
-    ("0x0407f9f0d0c07cf7cf3d6cb13bec9b49be354f22", 16576240092812907),
-    ("0x089cf64671ebb9cb5fee04b52335c9c2b67f222b", 17283931913071630),
-    ("0x7c740fc38e03b6016c8217ace3f4b91f48644224", 346252440691353735),
-    ("0xbab529eb7b11ed64fdf812e5a61f15f77b101988", 67524207514828497),
-    ("0x8de5ed6f959c476a731afb3394dc694dc4ad4f55", 51442445833718330),
-    ("0xac37aa4ad53fd4e9b7621d690991bc9609eab45e", 4085182746958721),
-    ("0x553ba5af3aaeec896b309786decf50b1552d5858", 4281930811811518),
-    ("0x2485499b80788e8f8d4dadb8a53c78583c6767a8", 8557470407456728),
-    ("0xec3a10214b80a79b89092eafb7b2b32ab84bb3ae", 17297851217519919),
-    ("0x88088b4ba792bf8505f34cc0506e77701a4eadcd", 2121957536236501),
-    ("0xfc85c878120e34668061852e3eebb1a7ca415347", 52045395894377585),
-    ("0xa203b0fd254e08d7369cddcc61be5ade37a32728", 158405579883266761),
-    ("0x6db8960e76ffea327f0cd2281d530dc19fa8b4ac", 24666842371730507),
-    ("0x6f5473bf6f4518037784206000f4d5347c48e98b", 135365599833543117),
-    ("0xfd7b9c42166d3bb2243eeb6e699b47cfc17c69fa", 11297608481908493),
-    ("0x36cac74a5a3ef38cf66e672b1cd54099b218fd9e", 245413172281056458),
-    ("0x12f7309789427577eddb088c7f36098d57a50074", 10095727724609423),
-    ("0x9ee8a90af16115622f1eff930f20b66964f35bbd", 21811123205739588),
-    ("0xfd22e70cb42a0fa315c82e0aa95a6dc19f8b4934", 7877379409467159),
-    ("0x7975d8d23511254dc0cfd87e75f93d7c5bb6880a", 53660561690900928),
-    ("0xaa2b10dae9f0fe82499b90dcfe431266270baa36", 7353304478675747),
-    ("0xa719c0e3c2623dd2aa3f18635b0b9114b7243a56", 55128588378694814),
-    ("0xb805deddc4d0b57757e74b3429b778cf695adf6a", 21982686060672864),
-    ("0x8adf85b30949a90f0974cc5ebd2dc6d27a4d6cd4", 375903866615437443),
-    ("0xf9e80d6e7ce5b2b575617416cdb70bda7eb32650", 53628694791575282),
-    ("0xdf90a56fede1dd8c2f89060dc8d649cae1a026fc", 25808917132270178),
-    ("0xfe7da7572b29c2dafe38c3425ad1582058a70a92", 51787739262353023),
-    ("0x1d5b7eaab0805edf5178ce8cfdae7cd20c8fa5ba", 5464516243203192),
-    ("0x79e332667bac24cf14e5a28d4cc120a30a325903", 9339295132489663),
-    ("0x426f98f22faa9670a81d7ad055417d6e102692da", 109848209940772402),
-    ("0x8ff815fc9abadc1f39a186cd365f1584514425d0", 16839989757585319),
-    ("0x5919d15110feb1e89f3017cacd3cb969894f47e6", 3993785140670972),
-    ("0x6c406f2256729b973f3487157a53fec9de80add8", 4012679720623609),
-    ("0x9a6701ed143f43db1f0305eda28be169f8c12618", 11428534461506553),
-    ("0x4025b31f99171ae3fac2fca3c032e0a6a82f235b", 102659074010336131),
-    ("0x0f4873301a80dd6323431cda9d8f8506866a7d7f", 123467005705323937),
-    ("0xbe04eb9e9b9f5ddecdce6e95b686a6e59f27a4e4", 17397038520528221),
-    ("0x337324a0d19c98f20e86fc235bd0c9d22d803986", 9986620524871413),
-    ("0x0c4139a8ca162812bb4f305d4b571d05fba17754", 49656189258345731),
-    ("0x6eb0ebf8b940adaacf65e7b4424f1587ba30d77d", 7566095886251308),
-    ("0xb7718e1729bf8c88f3bffc05d19cc11647959966", 19503662712049512),
-    ("0x343330b576023a48f8523b5169c424e5ec50c8cd", 372527478902273813),
-    ("0x9a3f16061c62f7946e60cb973df2882e66ceeee8", 14229338967330064),
-    ("0x29cd7b923a23fa37ec23fbff05d3ee22163c5dfa", 29799479233069237),
-    ("0x0b27702713ad1bba192a26e756d5b1018b173b50", 16930468144057560),
-    ("0x86722028e1e778cfdfaed0767bd9816a7bc67f76", 203298610733214419),
-    ("0x350a4b5ac6216902e095ef47f3bd5fdc9d387e23", 84700272308357619),
-    ("0xd61414451be387ef52a34bcdfe4e6684e2e8b438", 17995737853957762),
-    ("0x6be7a95ba705c90d67623f506920052c73824437", 19066050083895781),
-    ("0x3a23a1912cfaa42def606dd6b0a67521af64aed6", 6677026390468797),
-    ("0x67c18fcceaa22f45419684caadbecd583067a03b", 19965592298746301),
-    ("0xd2fb7a9b9d1ecb201033346ed757bc43e8d9bf96", 8497202594448955),
-    ("0x8de518f04038a7b3e5873252358045f9151ac0e2", 2257570206069385),
-    ("0x97f857dbe12c4524c21efa5a5e166beb8ff196de", 74405452667875306),
-    ("0xcfadd6da5d5ab3251e1457bbfeea49ba15e52bbc", 4338901709961811),
-    ("0xc6d22a5e82b0e86a24b995587ec9406af66938a9", 6822955567379291),
-    ("0xde9f069da454a6b8e6c0ff712bb5779bb57a6f47", 6537105074871842),
-    ("0x9787b954b09668faf1da74977855f97adff1406c", 8919389702587972),
-    ("0x00290ffc9e9d19bda7b25c6e44d8adf55dfbf2dd", 358732288668423135),
-    ("0xc94d831b5354029170aab211476af6556c815d74", 3803378088718621),
-    ("0x44b6937e438aed72cf4a154ecd25ac7498a04ed6", 15993298662202295),
-    ("0x8888888888e9997e64793849389a8faf5e8e547c", 314905588194480201),
-    ("0x179fc4e708ad63e2217046f6e7b25b5b69f0ab46", 18444556041226820),
-    ("0x70a43c9d001b8ee05d58bf2eacbcf832b1f11ec0", 48878312466037709),
-    ("0xd4d6fdd7260b2aaf36b3211bde0573ded0fea92b", 15134239143280073),
-    ("0x8363d88d7b81d4f85d5c2a646e45491aed0223be", 22510000000000000),
-    ("0x9700c58e1c6a1e5fca5db5f62e477eb182fe3da4", 8110808855088096),
-    ("0xe94e25544c8d476f1bdbc8b1ff29a6e6921a00bd", 17456939300692458),
-    ("0xc0819e1e01204bcb9cb5a0a3be826afedad6edef", 14873070868034043598),
-    ("0x395a45ebb22e929beb64c60a15a6dbc49e7291e0", 21080652621488439),
-    ("0x6d89c9851970ba4e9eb987f66058e5b1a975b2a2", 3463913642961692),
-    ("0x4f0896aadd5b52c4e268d0224b9ed8dc61a68cea", 2018079221322775),
-    ("0xb44e2d5a20e775a024a82c7c7900da0831f43cf8", 22405782247427249),
-    ("0x548f4f9a3715e4e5ef86460d5431ab7ca8aa301e", 140476476312845330),
-    ("0xd5f63f09e675ffa9d78700c30818cd5842c69e7c", 50236574500665894),
-    ("0x07cd53cac6326ee8c00405bccca20bd6ec99b026", 30068027175643667),
-    ("0xace9c83441712359402d0eac940343826310784f", 29171291346097706),
-    ("0x62a3bb6f763d54a7f4b4fc24270550a895feede9", 82409776793735539),
-    ("0x922655bd043ace3d2ee2a54e3d192eaa2bbe240c", 165469491398246243),
-    ("0x39006629f195e64ca70dc30cd67c0c43e5cf99a9", 11544700859778072),
-    ("0x1525f2062ed88eb0e700b7f11fc791fda0ddc80d", 301774494246862344),
-    ("0xa8b29c2131c42c998510f20b3640ca83648d2639", 23407347680421113),
-    ("0x51f80c672f2f5105a8d4c09c484f9891749a0b2e", 4817299342586606),
-    ("0x68035e1cb742b76509cb8fe2acdf022ccb33e97f", 6967974747531137),
-    ("0x999599f33f56bbe6e2cd64db47ae19e4abbe2b63", 728932838378672770),
-    ("0xba21901bcb858258d52708e43f6233d4e4f7bf3e", 10306315408103558),
-    ("0xf796eadd92f1cf19807c43cad4a76b3c994a049f", 34147742534619745),
-    ("0xe3b7c86d2eba48400987216c5fd420890eeaee89", 85547227646828430),
-    ("0x9600997cd68b4ae0f5b9683ff6f1dfa8bc4b7070", 5914697522726700),
-    ("0x7448ed184303b8e341cbee69db6f85c418ebbdff", 23637630788905525),
-    ("0xafc42f329fc51827dbc1724c6bed8cf332d6f73d", 9870624357451769),
-    ("0xe6f1f2f9201f2460d188435992ab5349868b6c71", 162611688279177481),
-    ("0x135740c85e807d32bb012ab16a204913ddda103b", 16794165286526373),
-    ("0x8ceb3c71fbf22f53f85903f986ae2fac8824a779", 5423202469766718),
-    ("0x8a218aaf5ba1ee9a745e84cf8f363782d9b885e9", 11398703781726924),
-    ("0x62225924d4239311f2a8983dc3ecaa9f6ee11a90", 64411635477560794),
-    ("0x55fcb0e499e763fa53ae26cd053d76c828ef4c9f", 35401696389940905),
-    ("0xb7d96351f2b0416c5f679eb4adce85605558d42e", 2773900259913288),
-    ("0x6a9db1be0c0f3df506bd3833a1f8706873b8a6e7", 7924258142360908),
-    ("0xeb74c60b1e50becf7e1de8af7839a13381ba8af3", 17194413813890202),
-    ("0x683df35bad32336453007bc3dd5d27e30051ce9b", 1949320261056273),
-    ("0x7f709bc5be1c1721a50ac1867bfef62679986f5c", 9389870034150570),
-    ("0x8b3944b4f14a49f499ab1bdec51f209201e8f51d", 39333922911272007),
-    ("0x720630637f632c553beb610a2bbb3542ac35c741", 13610618201047876),
-    ("0xa5e810565b45d526a33a3666c61fef4a263b2277", 68542020321419910),
-    ("0x1d945e9ea2da9cb9a36b3e53e78b5e22bea1e3d9", 161291475018664232),
-    ("0xac10e326fb497f22be3bceefbd050e74cbc2e394", 17526917568596109),
-    ("0xff0d9b4b6653e8e18f2a20e46908d7f34c862d4e", 27900783616398639),
-    ("0x09eeb474e90c00e5ba1c9e1581749ccb40fb8c77", 59520351096650419),
-    ("0x012ad6b36e16db7d337fd553de535aea1e5c7a6a", 30457660079727714),
-    ("0x9c0f7716acd31be82fad0791eeed40e45f770438", 37376967601506582),
-    ("0x8a47198c9aa339414992b59abe04d4e7c8178b13", 9604484911514803),
-    ("0x7f2dd012aeabf577f6f6daebd2947c97139a12de", 170537620079317338),
-    ("0x46ffba88b5fb7e38203a8cb330535a0b42ec5731", 26888803889318000),
-    ("0x449a574d5a60ea6f907e586ce4cc64537b7b620d", 15495118468748561),
-    ("0xed3d9638b4e0967274061ee22320c96ca72aa49b", 16519858511755541),
-    ("0xf9d5c9a7200e1ac4a0fd41b2a3d28ff5cf3f2284", 18959544755730654),
-    ("0x89a0681a8bd9e38af1c21f76bfe04a0a4ad6d2fb", 37243020025150203),
-    ("0x9dc256b82ecd89c164ddf3017f77d252e341e9f1", 18253179991402126),
-    ("0x21dd395a1357cfe813caa8b6bb59462813960058", 47293515870860635),
-    ("0x1ded82e6b6bd7fde8ae31fc73f1ee6c267af19cc", 135138200894127611),
-    ("0xd8cf405be5d300bb3579445ecbe74a9fa76a8044", 8716169941118993),
-    ("0x3b3f80287eea061f43989cd9f7a3e0712c584564", 39125668856230991),
-    ("0x0013827e0ef1b7418660138d29e515485b2a98da", 50755240298903898),
-    ("0x0414d0a3b55042ed7de07fd503a0f1a935aae45e", 16249348788787301),
-    ("0xdee72af419ec5d7d0a93b5bdf89968ba11e3cfb6", 48636981432656748),
-    ("0x0013aa2fb5ec916660b38f1d53d4fc9bf8ef8a84", 32055484328755343),
-    ("0xd7dedcf8ffddceed9ad20a41a5f88dc1268b3ad4", 81529909925063667),
-    ("0x5f99a5f1ecca66e3e319cc792e54ee8422701d44", 10734749729454088),
-    ("0x631ebb12f94b4e0f8759e09cc4dbf26484de64d7", 16046776620411349),
-    ("0x941486a59e8f52032ab623b6453594c8f800c835", 31971819059569188),
-    ("0xc2adf8a5259cc912909c7a3373f1eedbf441898d", 8533105865978804),
-    ("0x6f3b62c5fe9ab06b321ab23488e18fa02ea79594", 761645750116040915),
-    ("0x8b1f2d19651f1aef610dacf48004e6fa15a95763", 13624667391368823),
-    ("0x8f3b566f10db11b682942dbda74f90279104d0a1", 10609590584246353),
-    ("0x395065ac023a29f72756f11b952e43efa1045d80", 17161540966861428),
-    ("0x2c2e225dc5f8e1b694648f79d689cc4b13369b2c", 24075059843006954),
-    ("0x1cf2e42e3c1edbf35b370e55cc75158f6f73626b", 9576981198088925),
-    ("0x61ef560b6e2f09b9319445ffaf300504985bd7bc", 27262711106491615),
-    ("0xa952f5e4a722cdfb962cf6e2e917eff51b973aac", 77619709023526056),
-    ("0xd3260d4442d466f147f6e663cde4f43e8a4ee463", 7245755535885536),
-    ("0x59b7dbbdcc1ed1f48f18e9a5cb76f96f6fe00ffe", 37699087006868771),
-    ("0xb7647e14a1a2f5977f1b19aeceade99e4ea0124b", 19426315486998376),
-    ("0x823399e032e22688a948ce1ba42f49a52d96e288", 20623000000000000),
-    ("0xf4d27d54ce2bb7fd2c248cef58247da6e1d3c0b4", 9566920934769674),
-    ("0x3c4a75ba84757e25d59a00419365058d85738ab2", 133287889675836154),
-    ("0x4d722063a658bbba81c3fc62645c384e6b343c65", 5626368593590172),
-    ("0x1161c3f3ef0ca754a9cb5a990e2895dddf9a14eb", 27598381258919964),
-    ("0x1d3747629160e8e7a69dee1efa3d89a7828d4d08", 9023248701594620),
-    ("0x81dc6a66a458e1f49b2705b803724aab877f0427", 68389000000000000),
-    ("0x77edce1fccbcff14d32998539b635a58bd03eafc", 8933129932075694),
-    ("0xa8509bb3883094ff7e208f21149bbc5b0d743df3", 106287408141116027),
-    ("0xb6f7f41cc317412a40e8992ae1d815ef93a1a132", 3591675520408656),
-    ("0xb7625a34dd4b0c0d10615825500ea928ac8ce841", 12011433948454751),
-    ("0x2dba976150079f2461cb5745f85f4cf1a4b61042", 13525583236061173),
-    ("0x9f058f9cb67dd035f66b7a0a7d9f28dc4fdd24a7", 15006134011968170),
-    ("0x90c53b769df4b89141f37d345e895117996949a1", 121698685520000000),
-    ("0x766bd57dc08214c0505c5a5a406b38790d14f617", 15753226216549396),
-    ("0x30bbc5ba79afbe8f63af111ae9ce2f00b4da246c", 26200000000000000),
-    ("0x34774d1b0872d0be82f0940bf1e1705f5cdb1ed5", 10756555341294207),
-    ("0x11ced69ef922e9f8ab36e9655b5a654ef53b3409", 8916515271990673),
-    ("0x27c1ceeecd1cbce7c45e472f5cfeec6525a7d754", 37936904757498613),
-    ("0x53e492215d73ec612aecca3d64397ef4b1c063ba", 91412596983633690),
-    ("0x46f8b8d97aa29ed884cc3e51b5878e8f8d15ac07", 16886133846123468),
-    ("0xa9a19194490083459cc1134a9d1c179bde9e983f", 5462473557390530),
-    ("0xf078059c0d54f1ed03c77d9f8a42a70950831f07", 11277923387477963),
-    ("0x013aa278d0624c0e87d60184cdcfdfb9c6b38ab4", 36001320259152313),
-    ("0xf41bde88c4769d4e308a7aec87c9d565866057cd", 8813968734912466),
-    ("0x0000e9ef000000a15f1f02c1008d21eb66001534", 1),
-    ("0x20438c2f91d2bdfc3aa224f1a00e4111b297a392", 7104594225676230),
-    ("0xb6ac889ac44dd2f65a1835de9983eee33462c198", 67929790863248464),
-    ("0xabd955cf5c20d9add46c7308d5c22c207f55b20b", 23869393334741649),
-    ("0x7471c75c081463349defadf7ae038308a951b554", 12940000000000000),
-    ("0x34f8d4614102ee1619f3e2a1626e4dcec13f6e55", 140633515528586528),
-    ("0x5f93d3d64e416c3e3e63db631072bcfa8321a0ad", 19204263978846467),
-    ("0xffb3ad2b555da15eb45cfdc76c43932262f18e68", 13809570190010278),
-    ("0xc70562b8bbfeba06db91f8def44e95d9702f0bb2", 6924000000000000),
-    ("0x9e5363b0440cdaedc906f237368328f87522bcd3", 156651985350065411),
-    ("0x7469872d2d4346c9b7b40c3aef2060cadb36a6e4", 88167644233301122),
-    ("0x74c312f005e13f897fdd2ff2806a662135bce7fc", 8027159535173886),
-    ("0xcf24e649261b0d2ea3b5882f092e006185c579ae", 8589069274887929),
-    ("0x30363bcc31b5ad74f5cf4a523c3640eb25433289", 16319267895201165),
-    ("0xf0363e31d8cb57b1effea73779617a1c36ed9218", 27484053814231296),
-    ("0x832137705dc30f061d8775696687a42f2400ef8e", 4000000000000000),
-    ("0xf12367718c6b63679148bcf6371e01a7dfd13ad9", 29520912034902887),
-    ("0xfcff532345e2790bdcd18be6efada5ac7d6a90c0", 10905288567663700),
-    ("0x25d421dc4d1144247bfaabeb744ab1a7ec6ae7e7", 10356803815243046),
-    ("0x244c050d0f82e55419db3b08475c4360983c5ed0", 9509536371391769),
-    ("0x8a914a3ca73d5adf682841715a3f24942f35f955", 16259970054631169),
-    ("0x3c10b90e5e3d109a4e5468d6f378d0c80a4aeb9f", 17645045228461041),
-    ("0x183ac48d1a19bb9368e50f6e7a8750b4724a865a", 51735448897077936),
-    ("0xb5ff368b64647055dbc268f9293c29e7778527c3", 43931811771113037),
-    ("0x6c1b6df0525fe2bd068f7a451f05fff856745b7b", 13707083805572907),
-    ("0xa737a9e68ef3a773c8aa69f58b34b01ef8eeb227", 22463474060410671),
-    ("0x6426c9145b566315328bebdab42040d0c70c67a1", 5396777801814812),
-    ("0x0f89f37a0bb0bdfd6796a5247fd2152da755f81e", 9174795550459776),
-    ("0x7c7c63070372f67b49a269940aa6853e1dccf295", 9251939625336462),
-    ("0xd94c11fc208901d512c5d238bbcb967eef60e8f1", 26536303947472568),
-    ("0x887a1e648c11f727b60559662fcb0ad460868eb3", 16479028442074655),
-    ("0xa5fc3b41a1b4b2d6a9e02cbb2d7578797022f164", 1232248748132504),
-    ("0x010c8cd59f189ea773f65809f498eeee78beabee", 34368325016946760),
-    ("0x3967edc66b6a7cfd3661b7416cfe0fdb706e5225", 8719370014367072),
-    ("0x1eb97c580dac58219cdbc5497aa861dba6f31ab5", 16484506656057299),
-    ("0x14032c5510f488674355a82d7ecd3694ae56193a", 26367667669260741),
-    ("0xf6fe7616200e2f1561be71a6030c34457a40c72e", 272026005515394608),
-    ("0x36c913b9bba5163d9c4b26ac70f75c735bf4f249", 6900884224200771),
-    ("0x4ced8ed8cca4306e04e445a8648e7d8e6244b826", 28257100150072676),
-    ("0xfb368c7962f08ca5b32c23bbdd648669c98c0cdf", 154754083273415602),
-    ("0xefe9cdca25c2d4851ebf9c2b103fa7b6769033b7", 460036329772272785),
-    ("0xca7f92bbf65e6a30706a036f4211cde31a51fd40", 16655816318591789),
-    ("0x953287b8b0636d6a1d87ec5829aa5708ec6073c2", 8514543420896334),
-    ("0x990b94b3fcece351a4d02a509a90556965556e9f", 85505391247174090),
-    ("0x4e8d86e49c6efb1d47607efe7a2362d092b10a1f", 41770254808864521),
-    ("0x8bd80394747d95ef60dbb92b53aa35b6c3a9b425", 7313130410930698),
-    ("0x67fadc72ad91843f00eb8b027669552c799b9128", 108643054645875698),
-    ("0x3f67371f904cd237f6defb0762d5ac608f7bffed", 28808084891461985),
-    ("0x80ffebfc84ff49e4631180b29e1125c4e42b6733", 27997791121363403),
-    ("0x9c1aa1bee92cd2dac44c69b79a958372f83c492d", 32035154697758208),
-    ("0xcb72ffd77431824f37d1cfb6022fd926afc08344", 8651153249256664),
-    ("0x651e7b9b250986a7749db5dca5ee4152422ef88f", 15721443419315876),
-    ("0xa519b1a7d7cde5a762070126bcfb74fef0230be7", 138203961297579030),
-    ("0xc863629aa9c11b5dd83b753fa88ce17e0df89ce0", 8029143123424524),
-    ("0x356b5025470cc8ee135c519dbad4a5369da0b4a8", 27811044917490926),
-    ("0xfcb564d48c8d3a9416f1a8109af5a4ed8ca96fc7", 4571688393781579),
-    ("0xe5b218700e71f05acea6a0eac74f5d2a221839da", 8313563036577174),
-    ("0x3ece622ad8861c946e7aa0708a78a9cc30a9ccb0", 2690042044909885),
-    ("0x5514bd5c2968efed717c413175f965f5e2c74d3e", 28134711396240316),
-    ("0xd220df609462ed0e9ce688fc31e8253348a1e5e5", 31777103520449141),
-    ("0x7e3a64e015dd08130a17afc9aea8b85e7aeff178", 56244902064916236),
-    ("0x3818919ceb284e0ef790b739b63d35ec824eeda1", 4084270657973989),
-    ("0x66a0c36f7d9e1b7578e2b30b95bdcdc2fd39de89", 2409158675201629),
-    ("0x5c3e0c43a13544a81730a2cf5ae94cbc282f2ec2", 509722541626123588),
-    ("0x91e244da7f66009245719439f84bf4fc4be9d38e", 17736942278311118),
-    ("0xd1d0899de0481fbf988373c40eec9171c50a0e09", 8229921644929302),
-    ("0xd216fc5a81df3ac014e61bad5176f757fbac356d", 14510438837288985),
-    ("0x2705ca8601ac809d34e4dc07c34dc54bf1a94e3e", 9226651462970714),
-    ("0x6711fbc3405b47f97e759716e1dea1e1cc4a111f", 5303626242330353),
-    ("0x41f436ab1af12fbeb7786b346e57b07c5a6d405f", 13104185212882526),
-    ("0xebb036de2e26aa0e4a6411c8e0bd98df2a113b48", 18360153946230222),
-    ("0x5a42368110ef991e7efbe9a0b82d5a766690bed4", 12339861756427032),
-    ("0x067b6c86137088e6d063b789a7ea2f5218a57875", 27745024206270167),
-    ("0x84f39a200f71d63a3524e75f655193af4f702a63", 15980888831983920),
-    ("0x2251da7a62102191d126c933d0a7d42d94fdc359", 115925290995353610),
-    ("0xfad534797744bfe8afff4d679cbf3e771884acf2", 35777819816456660),
-    ("0x3d416ac49948422d53c9ae6ca5e7dfda08c1ac4b", 16191580932052750),
-    ("0xe276bf86799419b8b372e29ed3411d2ab8371a63", 32764331736830890),
-    ("0xc0d2713b3cff8df167684e2624e7c50badb7438e", 3367606109070578),
-    ("0x548f227e4a316e3b2d2e7b6a9c6c69a60aa34ffb", 15588101807616260),
-    ("0x9c6deda95110208576146b0662d82365269bfba8", 10193747335437496),
-    ("0x4f52d4cf3b9cb4e2f2502a036da3fc0ef2e42e7b", 57858397542268073),
-    ("0xc9a75ce3a1254f796e91915a7ac97e8ccab82ea9", 30347079754891502),
-    ("0x794a34ae1755acebb064500f0745a391592fbfac", 9855552637160249),
-    ("0x8d2c28822f8160ff5b635faa39c9b7db74ffef9d", 12449065688334259),
-    ("0x35fad8ef938cbcd5ea3414b38602e9dd489b5c8a", 14578991096089271),
-    ("0x5f0e619d09f01892e08fb11abd37b69cb2d9c219", 36514861172860955),
-    ("0x706a4a80bc30a98117deb30c3906490dc72c0d01", 111125581170538587),
-    ("0x34ae6b485ae1a085f09d3c24ab913afc550b1291", 9182995646609465),
-    ("0xaa37095938df3eed0a9dfa2da5625bd0b3364254", 8269754634543698),
-    ("0x053bfdad9216092f1d40d2e4f37c3746878fb2b3", 19156802778383705),
-    ("0xfeb041dec59236203606973b679248b0e4d3f91c", 28491663556495087),
-    ("0xab00f9dca7e8cc8b9cecf69f1af77d45cb4718d4", 5064105056309105),
-    ("0x9741e4290b9458a186ab1aa277d83648f570f2b4", 6165664387316177),
-    ("0x7b650798e05e5ee4d9df844d79bfc55a63e48130", 59746964405215223),
-    ("0xafb51b2ce9d5a34011e958011831ceebb10cdd11", 3118606342203012),
-    ("0x0fc727c2a8086b74f8af025194d7ee241ea71b3f", 2982611080855542),
-    ("0x1bfb871708c83ef373141ed75dea8ea4b738bc17", 13869408739933548),
-    ("0x6be117ece5219b475c400e9d63f2116c578ce8b3", 12422858444649850),
-    ("0xf2cf5e638ae5133aef7ad668177dcb97503e5384", 10048578168460970),
-    ("0x87f73bb5150e13d34cd457a054b3cb11166a88eb", 56283231589113722),
-    ("0x3c997e9bb75dc1bd952f8c8100205c7169701da5", 29109062990364014),
-    ("0x69eef3e0f28881385d8d376c5686d10b84e2f21f", 17321394826805785),
-    ("0xabd58a63b049fd659c0e2a5578d1e56de1a78b82", 17028695183160397),
-    ("0x2ca23462ae82fe257b605c67f6ecb1cebb62ecbd", 147134143820024838),
-    ("0xa8989ea4954fdd6bffff70bea88e1e09452c338e", 41808056815416717),
-    ("0x4c571c28f24e5df873887c29b2494fb5ee78a739", 41400713689128486),
-    ("0x0a78c6cce26b32f60c81e473b2953955b7a6c9eb", 9760827325741680),
-    ("0x83539c51899366bbe7eb4dc3085dcc091c5e062d", 28235912523293606),
-    ("0x81de7a877e9c4a8496240fe83db2eda9f6f42585", 6952860799648659),
-    ("0xca23ffcba70127e62c186c66f834c6c25e30e211", 125810447113408707),
-    ("0xd3b8b9959ff0720334f34dc0497ab90c6e9350d8", 83832108953964596),
-    ("0xb7280a46a4709e1389b8df6130bce9f2ee97f626", 95799478003543645),
-    ("0x7bbc3381dbdc772d971dce682829620794897414", 36413745284199226),
-    ("0xff6d70a4a6c54c251c1446ec750913cf09dc1c10", 7849580452515462),
-    ("0x523559d588d40932310b60efe1fd2389e7f586e9", 10588507919529474),
-    ("0x20b83fa0e46f0d3560c2446ffad166dde292a786", 9432482861153094),
-    ("0xfe93e2712f5a282467eb59cb6559225adbe2454b", 17399159390049179),
-    ("0x8f190e90fa701f64f84c026562f98d9b2e9a0ba0", 26775486032274335),
-    ("0xbc481abdb3f999a6670824b45ba97037f7623fab", 17296579934679161),
-    ("0x064de04b37366de9ed0dba72d212bad8d9dcc651", 41994859250461393),
-    ("0xa7459690835a82a1054789152a15e57af867280b", 26674466554863721),
-    ("0x7477b936f81301ba9c738336d7daed6cca3c2086", 4355894704387205),
-    ("0x5cbbb4bab3bf135a795ccdf9387d9bc233bfc4c2", 34607469320426845),
-    ("0xe8846c8154197e5987581a2155ef09b1e86af053", 1637052204228800926),
-    ("0x9a7055504a77b0d2f3b6a346e9e026b14386877c", 20100491529893216),
-    ("0x7c879abaf5dd8f6a78e3b693b485809d6842ada0", 32476364059043370),
-    ("0xe2f51f5303be049cd8149e0146fada77397b9893", 8890367186175857),
-    ("0x97dffc512a8825189c5f63613bdbf96d10279ceb", 84737178507630856),
-    ("0x81d1119732de065b65903b38ffc1a69d40d523ad", 18036207502410332),
-    ("0x53a30c7058f4a40b67e75779dee431c1f5791f41", 86569003635078066),
-    ("0x8bd3e188b66cc17d9170127c694858f5ab13bb87", 21961244853381046),
-    ("0xaa6b40de3356036b5b3e19472ba128041cfe8481", 13782837648630069),
-    ("0xe78c330c8fa1cdb71647daaa1e1547ba2f865e42", 17161316444020339),
-    ("0x0fc96663592e92e25832d6f5485eb63866cc020a", 57910454700925268),
-    ("0x3d083e62e7265897180be94c5402b4b3196d564a", 6306141848803327),
-    ("0xfc2b54c56721c689ebe221fba9cf3bf38070009e", 259725914777254050),
-    ("0x1c815c6e5430ac20cd52b787237265bab56d0783", 14912682032562435),
-    ("0x1c94157a6b6aa827ca7d8e0f50f09c9497f13616", 36016496039951700),
-    ("0x321935f47738e11b84c1a561c476da1a9e20fb3d", 33327836517320427),
-    ("0x9b28c54cbd44d9ac4c37a34880af0f2cf266f073", 12149845262373530),
-    ("0xa8ebe6dcda66bb74a8db994554d2ad277cb54ada", 29079043564588697),
-    ("0x70b9e55c16a1e659fefac6f6ad526d47d03adaf9", 16650588659354200),
-    ("0x9d20974d6835ec6112153e2b9cafa5717ecc565d", 6898524303172574),
-    ("0xe87fd1985bcd1fbc114c8cf5ff3f53246e34d4a2", 6972742641944778),
-    ("0x451796c464957518cb0baa3457c05d9da79466ca", 12042782012366148),
-    ("0x0820ca934e4ead413f5181fed24a57382f37d915", 23594271700000000),
-    ("0x80b3e8c29f671dfdf91894cec2818ee0a0cf38d0", 9946608210233027),
-    ("0x2d9131a6ef0793f8fb633337c0cad784a1a0d525", 19418832823741169),
-    ("0xb55047207ae50e9642346cfc249a9b62e5a3a16c", 8977456496595446),
-    ("0x20c34894ef4ecbb1d0e1caa52095ca98971739c1", 6043579424753386),
-    ("0x93973b0dc20beff165c087cb2a319640c210f30e", 3739295359180968),
-    ("0xf72eabe83129719b5bd535eea24edb1ac71b5c48", 8260504500977787),
-    ("0xb6292a5eb77f646ff28d8bf007fa2c75094f7443", 10775962746080577),
-    ("0xe14016d61f1e784f49c2bfd27f5f1a0155d0d4d5", 8804472348990189),
-    ("0x8c826b392a06080ffba654b2f99237edc8a29038", 8707901014913991),
-    ("0xd47d93cfd7e2b7b89b3792d73722a9ca522f69e5", 3142871719596603),
-    ("0x858215bfac0689d211a4ad4f1d14827057a452f0", 8399805730618059),
-    ("0x3c6f38c48636f4d5f8c3ef85d64534d183264477", 13606125822136577),
-    ("0xb87d9edb23e88bc7c1e6f2cd3aa65fae6397670f", 21099647452306740),
-    ("0x952f2c4b1a9c0938b71b9db486211fe90fa89c0d", 57773255806092234),
-    ("0x7fa7d4dfd24e0b2e133a8bbdd1e6539b6a9b9c9b", 5705403656925131),
-    ("0x0b940a8a41bd954c55342ac7a9e9821d0a43e095", 62394809243408673),
-    ("0x1302019844845518329cb59b3aba6c03925908f3", 162664223430889396),
-    ("0x33f170717b9f75e4ebfc7c3e52f97af4645ea731", 3650270812210675),
-    ("0x68ee3f5b87b1fe3aa562f46820855b1354d019a7", 29808994264660007),
-    ("0x158e8b9ac7b1ba2373e250f9b321f8dc395d9d56", 9423267895113156),
-    ("0x38b99c888510c411cb5ef0a74c0dcfec0664d724", 27690942160279118),
-    ("0x31f02e88bd6110723e767af9e7186e61fc9e88f8", 29308139133312353),
-    ("0x233715254461f5dd4d5bb8df6ef5a52d461cc463", 24610525903135146),
-    ("0x1c1fd2461c14d4e25de4c66aa8f41bc3284984dd", 21669558903396421),
-    ("0x41647a74673c90f4a13884bee4f8a89034fb85e8", 33565315404726847),
-    ("0x0a035c7fcbfa48b407c7e7071d3e5c13f64cb121", 27181268032950221),
-    ("0x32527ca6ec2b85abaca0fb2dd3878e5b7bb5b370", 11794675815818647),
-    ("0xb8f657161bc707a5d9a6aa2412346e1fa608936c", 9825911670368759),
-    ("0x44bdb19db1cd29d546597af7dc0549e7f6f9e480", 228462273788437558),
-    ("0xeb986d1ef6a4acc231380885f1771802251dc6b8", 36352776066077243),
-    ("0xae25aead3d49ecb0ab2eeaf61e6a7421825a341b", 11116828890084546),
-    ("0xb61413550628ee5349268bac9b2015ef7395efe8", 34725839290911436),
-    ("0x74b08f24b8af08309aaf8cdcff69069101dac3ad", 8671353134551381),
-    ("0xbfd5699bf0c57e6201146fd33f057364f9e1a17c", 11453108804985112),
-    ("0xa43d9d2cc1245e797c4afafd81278502b557b3aa", 12563690302189732),
-    ("0xf70ab830a43c90c5f30e86f3da472d9fe83978fc", 27874318877687368),
-    ("0xfc7a8db5b4726691717b928cdb762abf89893b92", 16932865189361486),
-    ("0x21c45b96f9d19d74f36ae368ec49ce5bb517366e", 1801721286709226),
-    ("0xab0d6f45cb4e74910830cf9ffe3ee3eefd87498c", 58584891767866210),
-    ("0x304610af5e7b105c28c8aa8a72235c1f86f657a1", 82622366372900779),
-    ("0x52588bc0fa6bb2d68f0489d5b0ff82fb2232e553", 5970539599684680),
-    ("0x0a53e28f2f7b27971e18a6305c2c74a449badd2e", 328149622949143506),
-    ("0xfd94fb2f07a4a0cb5c8b88c7a4346dded36f2b1d", 36170260644269845),
-    ("0xb0b975ac6a2a8c8d6829880be6a9f2b209311827", 33091531418176908),
-    ("0x4cbcdefeccd99db8f1d185e626042cb25fe8f1ff", 58984796035052844),
-    ("0x8775dfdfa97836057419e2af6af855f3bb30495f", 9652987963991778),
-    ("0x845e55cc09ab3339420fe5d72e495f1af84d1790", 13989096069473151),
-    ("0xee113b5073b088d53a4931842834d77c4aacc365", 64723776425224254),
-    ("0x17ca8a69f5c604b503a87aa9e84794104966b067", 31620215712802089),
-    ("0x65cea85d38a8f0f127e61f6212a033e6be75d8e3", 9486318583004036),
-    ("0x773ab693779c7b57052efcb1e9489fea82448b87", 8413851976137113),
-    ("0x2b7f81348bb86fbaac0b53cd333504f248a52b25", 8890819907852476),
-    ("0x422b828fbd619e731680d0d4c3e0feb02138fc16", 105220491197371001),
-    ("0x7ba58f14a4c8598875f34356aef07df01707d6c4", 34205174855676030),
-    ("0x46d6b8271ee4e254d595315f99920c617e13940d", 10652053671996544),
-    ("0x10c0458faddb77c9c6fb35595666cf5f9c055036", 6061691544170368),
-    ("0x7ba9df65be2ce2aa7a4747033c1897ccdf842b32", 9524452497694603),
-    ("0xfaba9ec172a5f2cffe3df65adab55a102b1e107d", 3123628304083484),
-    ("0x3ba3345e5657854a67331c091efef8c26b7833f0", 8561806893889843),
-    ("0xe9313298a00375aece990e394d7716bfd40a1981", 12460096115484336),
-    ("0xa3d0ea1877cbd2d2354abd790298b47955b0f16a", 56035742412255969),
-    ("0xfec2536992c76ea35fe8703497394c1799ccff1b", 26086336689422842),
-    ("0x2bf1e788ea7599df0e62826c2a718f5a12f1c1fe", 11278009226025425),
-    ("0x787a0ac7d0d5eb5900aa9a9a44780b98f833604b", 1426268402868035),
-    ("0x550e511c6b3892cc18bf5a035705508d48696cf0", 30070456289548182),
-    ("0x0c00865590c0c9fe986bededf365688b5190c86e", 59318855392159697),
-    ("0xc9ddd5bd26f9a1260e6f24d0644c140c390c8a08", 21208212084165868),
-    ("0x307140f0ebbbdee739faa25da1a831c5602585b8", 22550392129382092),
-    ("0x426cc58de74ff5df335651198e2ef939f73b0aee", 2389887766771323),
-    ("0x6f3132060581c01eed77fb03ca3191d64280d173", 6725748949867869),
-    ("0xe63435672687cbbf1caf3c767c45ac5248bfde16", 1423129077793368),
-    ("0x37d5402204f47dc40d2102126bac74eb6d89a919", 5154148651747829),
-    ("0xf16d8d189b9066ffe94ba9df555c8dcc852b2dcb", 52969301492641014),
-    ("0xbc419b42e80403e0f3af04c4b4dfc581e18a3be3", 10943245451477527),
-    ("0x45e37f6c32df82e128565f24ef2ab9cd27d065ef", 1930588123216154137),
-    ("0xeb32051ff4cfcf7ff0ba950f456f72b18ef69dc4", 5856589583621184),
-    ("0xe05ea7988feac96c440aadb81bc30ddc73091a5a", 21693806212487811),
-    ("0xf75c01b91ac5c896eb096408d7d1ce387179cdcd", 148886289320539345),
-    ("0xe2591d5f60bb9c2fd1cd927b4c6ea960c4382405", 39251780730240641),
-    ("0xb333d970caeaed692645776a640521b012f43432", 117961614497095349),
-    ("0x396784fc1ca3bc221b0444bc6613db0d640f6ac2", 17264450202870359),
-    ("0xde77b585a85a0e10710b2a3362b326cae329035d", 335484541748870603),
-    ("0x69a2041ab26bb7fd6e1f9019994d46c6434d2467", 8204482765565762),
-    ("0x237638300c12efd9ac1aed2f4369977acb051caa", 334504896359631242),
-    ("0x9b921fad875b06183d3992a125026c89915d71f6", 60270725165938577),
-    ("0xc36b456736c465ff0d56a929101728c56f8f78e7", 49128149767308921),
-    ("0x0b35c2cf852942ca847948100210687a3470c1f1", 22189659551783867),
-    ("0x620bdd183ec570e4de54001a6e3cbf4215d6d326", 5722742523835470),
-    ("0x1d5b052eedaf5087fda6af266743e722ba62421d", 43296204185588906),
-    ("0x9ffb7ee36a73c3948bca9aa5440ecd71c9419bfc", 21166588605215374),
-    ("0xf5281a193fed7231d85e93bcace3b28a6d56af19", 93046179748463225),
-    ("0x2bd1d4ff3edf4215b0a0e3f6d20d202ce5b93252", 10970901577748357),
-    ("0xa9f678b4f7c18b0b69fe61fa4f046beea4e72153", 27794022469974983),
-    ("0x00622116402f303f22d38f3ec202774f183f6468", 26354808521291383),
-    ("0xe3950cd4d791fc4a8cfe1339c2f87b9b721f85da", 6456143229596070),
-    ("0xdab10e250bf4582f65e865cba294274405e5c87c", 60646614219809798),
-    ("0x58c37e3e10ac7537b4eda221a41ac2a4378ead74", 12979538548867867),
-    ("0x34fae068e62ada8161a05b26096c425bbf771319", 30658277358849555),
-    ("0x8f9966ea7fa0adca825dcc3688f67d54f1e2f06d", 16521519528028532),
-    ("0xd9746987ea5ff02ff90467ea454f5c001010c069", 26236597926479446),
-    ("0xa06e43de929779682dcac06f22e78ad536b04b52", 60911367569638709),
-    ("0x4d38c1d5f66ea0307be14017dec6a572017acfe4", 9247270808316618),
-    ("0x82fabeb88312f3c56c4ea7e7cf7a8550ac94f8a3", 150000000000000),
-    ("0xa89afee838324d0cf629ac3586b30a065a487fe2", 6585713844752214),
-    ("0xbc4c299b611a296349f054a35344fb40938649d7", 13298100459109505),
-    ("0x21b91d9e00ce88cdc189569ee189e8c52e64a670", 37943202775646921),
-    ("0x11a9e5803077e73249825faddc14db0d5a3fe50e", 16140199385757005),
-    ("0x479c215cc59a1b7fdf534b2d4f1187ee67291cad", 75692327877406324),
-    ("0xccebb0a712da43ed062086d2215cab8f4c6dcaeb", 2456545948959592),
-    ("0x79ac66a9f6952d7e659813f2f1d4aed1dbe59a91", 14779444188417959),
-    ("0x8acb2d4afcca1540c69a32224ba5f2d7795e1e6a", 63492555264626429),
-    ("0x40304dca2fc7b173dc1591a66d8036d0b694a3e9", 6317416164105117),
-    ("0x7839b196a6922c612775b97a9977545cd634f89d", 18453923346948388),
-    ("0x43d6f9e082104a18a2e46a815770ae8617180188", 8962982033323066),
-    ("0x6893593c695d23f002f9278fa75ae1367ce78d96", 84739833361565304),
-    ("0xac700cf90bc8c650614afb3bf0e6a1762cfe7a87", 36425594464013672),
-    ("0x725043b2c1c7a6ff873db35091d80f9aec899158", 88992379749790316),
-    ("0x046337bd78a22c3fcfa318cf3e21a37d69954fe8", 8496687708080973),
-    ("0x65200a8dabf72e5838ca102615a52de9a0253aa6", 27287818719447882),
-    ("0xc5037d2f907a50472bd014bbc65635c0137dd9fe", 16776720937792952),
-    ("0x861c743a56ad723aab405230da1a8c454002b5fe", 8420608021528783),
-    ("0x8091587f4dd6019196e89fe82a5ee40400fa8bf9", 5383290866990342),
-    ("0x42ff3066bb3a92da3218bf888110a91213ac1b4e", 9217046598317320),
-    ("0x538a49aebf0a026b887e4ce1d34b879f1f5a15f5", 4653952903832119),
-    ("0xbffd292517032885fe8906c07ed5132f8a18eed2", 17519288140896832),
-    ("0x732b691398ac09f7f5cdff58a45e3e1082b043ec", 8531847195650475),
-    ("0x4f9eab0394e41fc5d43f3bf99e92148399da77a3", 341293643693627765),
-    ("0x58ae6bdd79ed6de5c3ff389ec90ad57367f3a025", 29868784054178828),
-    ("0xce5d647821447cf3ae3b15fc8f90933094e4d57f", 4812810507819844),
-    ("0x50373d3f83e93bb34e1d4ad5b02c1160848434d3", 18944701726239096),
-    ("0xdd91dcbee971ad05b7f186def0933b68f338f8d1", 2455348943350761),
-    ("0x217361d53221fbbaf78494a1f874711f5c70eb01", 116948192043482930),
-    ("0xad6362f152bae8f1634bc9cb2fb777675190b652", 169920143507820534),
-    ("0xdffb517a06650442421fc097944f8c066b6f3445", 3681326135743710),
-    ("0xe7090c2155ceeeae391b61e1693d50ff8e131c31", 39819536232568530),
-    ("0x257313f82843a4fb2905e7a51416451269dad97c", 8795845623260766),
-    ("0x9de1d5972786466f2426802134562b1fb10bf750", 2063535574547722),
-    ("0x3ed2c5277dbfc6213c773bddf91456c27ef2414d", 6148289829624183),
-    ("0xebf3cfc8de5c55474d9a0db69de1d3a74e1a95e4", 16755724087260789),
-    ("0x78c27f96898b0586b2fd33e67d4416df4fe4df45", 5930273546149384),
-    ("0xb47df78dd4905785c124c5098620041dc5a67c7d", 18265861320135397),
-    ("0x8849f520ba63251fe21a7dc0fafab28e4ba5fee8", 11573000820514617),
-    ("0x367c8507367d6e786592619289080913569fb1dc", 25437237071059119),
-    ("0x0afb182ac65b53e45fe217bf335ef582f70e2b1a", 89334699484505358),
-    ("0x4385a2ae536c4709723a584710b19241ef0ac653", 104000481990763356),
-    ("0x5e7bfd93a2f01facf5f9c44fc3130e2fbdfe186d", 10859401570625302),
-    ("0x6544850d9ea94f00893b8ea5b8c9d22e250a86b8", 10451807980535356),
-    ("0xee338a4307544f7ac4e8569311dccb7fcc59a00d", 205790533146469061),
-    ("0xce172fa8175afabf69dc8a0c8853747de9b71498", 10951418911799096),
-    ("0xab3f32822bab44c3eb0af93fba09f4e3705d0273", 6380506956036469),
-    ("0xd5bcf82f912b87859731a2222453dae69695014d", 35326455562510527),
-    ("0x6f707d9f213667800f0fab1f2927cfaa9368e9d7", 1574247500000000),
-    ("0xe6d49571728f49284cc2ccaaf3659515a20bb679", 88897952102867679),
-    ("0xd75a7e60e3866d0fb1e0876016795c77e73ea801", 6857707598646973),
-    ("0x2cc0b05b1c4f2578550bdf5eb6ea170d1bea2ba8", 17738910650559347),
-    ("0x7f3f0dabbcc190d3994c6d8c0a9fe94205ad779e", 3495600621139549),
-    ("0x356657e2a892452e9568918f3c663fdf4578ef0f", 10483660369163591),
-    ("0xcfd304af61576f06ed8a89ed40d3905ff77b7d64", 8421065475074221),
-    ("0x948c9eb384c8a003e85117969911599fe16eb1b9", 18760787776685718),
-    ("0xc44fb46de84353344d337b7cb32ac11bbb759d45", 16058529401014315),
-    ("0x6ab36837417f488da17f3c937643aa96cd0c0e04", 9696012158747238),
-    ("0x624dd909c8aedf260cd62a27b0e284e4d832f686", 8041229506591665),
-    ("0x4cd3af5663866ae9cf98047b24a8f94b14f4860a", 25406227087930229),
-    ("0xbe3992063b7f4c4c2b70fd59ae1609bd1c147ef5", 27262667530486758),
-    ("0x0a1c360dbb23dbcc9280f6b8827bdc3544f41f05", 98428126148898868),
-    ("0x475ca140be1d3181472df27928dbccd5f9bb5840", 31540392121776932),
-    ("0xdc308f2d2f00889041e74b9c3d6e6b8ad57d81de", 34440103537564904),
-    ("0x155a95e5723b338e4ed46a08214d2df69e75b984", 7369579985323975),
-    ("0x7cc3b0a311f5226ab0c55dda58795af730b75969", 9076237174406700),
-    ("0x2cc3ccc5fe83830290756c66199d6a2e25577708", 9967575206236062),
-    ("0xa2384fece956a0906b5a514ca142250c4f2ff922", 423233957827155245),
-    ("0x5fd4fa07178f49b2a4d599a0c82d2d73c3433a3d", 168872243689098212),
-    ("0x9b93b36a861f261a2a296dd642b6d89057007a81", 103393726248015502),
-    ("0x1d8f9f4974b44da477c5216c45362187c34780cd", 33696963393114631),
-    ("0x0fd24de881b0e85996327d5fb1e9dcedfe4e84dd", 35834140247810834),
-    ("0x9fe0a07bfc3356ec35c9a940dc3ba8831937c076", 10463932865109554),
-    ("0x48f4c04aade368c0ac517e46d6b3c51a8ee2ddb9", 43594680083556419),
-    ("0x76c9646cea7e16be5ff6cba4bf3d59df8f3ea018", 60170175534396758),
-    ("0x4d5af8cb8fb232ebb2cd051f147fde2af00327b0", 79024781589911997),
-    ("0x163b8b888aa9816c3701eac6229975960994ad83", 175153072538407971),
-    ("0x0fc84e15aeb7907a76a221169d80d3eb06cdb44b", 165647060461417840),
-    ("0x414b55e346426072a42eb8c440098323632151e1", 161893656042665250),
-    ("0x7d4326b581615643d70a68f2d2ba0cfdf381c81f", 9750474240250756),
-    ("0x45e905963352357a8fb4b0421e40a31907bcb814", 10595325562951223),
-    ("0x3ad9b71882e1661e8ebdc5a37ebf3a7617d1a09a", 13737348060134059),
-    ("0x249bbb4d948b9243a2408f135276b9f7bf306002", 12462371903753661),
-    ("0x63aad656449dab29b8b771b7528072f415eb0975", 12042966329414697),
-    ("0xc3a58beefed55cedc2ec869ef17c790b1c5575f0", 10552807509429352),
-    ("0x4b6fae58214766089b24ec379d314daa7c1d5d7b", 19971785017198898),
-    ("0x6c124faca797c5d46c3f393e1c973f44b6f1de5e", 3787176206466373),
-    ("0x806aa3fd6704fd5b802e469d48b3707d5f3b4bed", 2720186764907898),
-    ("0x1359669ddc1b3e23275c4542ce82680527c02f71", 420568511626122859),
-    ("0xc35bc6069b3785e72183b9ec75d78da0c959c05d", 18947146387709040),
-    ("0x24de61c0642db049c2e544add68fdc6dba7de2c7", 3857309141072008),
-    ("0x0ad560242a69b7e4adfef96bfebc01c3d12228bb", 7191021631815239),
-    ("0xd6e1b5e5985c5c0762e6cca1e3eb90040d51acf0", 107178509047196058),
-    ("0x1f5c041724f2d05bbc31418e7e7f2aa455bffe60", 11166179405663153),
-    ("0x59fb62bc94ef3592949898c948c199abc3990ac6", 8649185541530720),
-    ("0x2ecd1864ccd99ad8a0afc93538b052c9674a95c9", 8676793752543933),
-    ("0xdfd7f96ac23161bf6f384596e99d22f10f645dfe", 2131637509631277),
-    ("0x9140b49a75eeac4a1da2f9617502b87eadff89a5", 9846824272756091),
-    ("0x0f31c6013e3e123bf0a0c4df6ff1fcfa55d2197c", 84624625774913282),
-    ("0x16547e1b675d5927900920ab08fcb65e9592cc24", 43857923175964910),
-    ("0x41b33f795db2a51d00c29b2944ed67e91090744c", 17455494029506004),
-    ("0xdc816dfb1f29b2330608dd67ebef35a4d1128514", 22822116349097308),
-    ("0xd9215a154cb0148451fd418da07d8842f3cd7e59", 2974423732692705),
-    ("0x060b0b766b18e1543f02ad90e125e841709f94e3", 32877577191037042),
-    ("0x658d777dadc32b2385655913dacccf70e687ccf8", 13548907863477412),
-    ("0x926f18f792b4360f25b770b28265f92d8e93654e", 19264872452614636),
-    ("0xf2ff48b08ce5a73bf2c54d54a733154b274496cb", 40902835937569288),
-    ("0x8570ea89b47fb12675e8e8fff9642ee3cea467b1", 24827676774534255),
-    ("0xc9a7a7c7065e61eedb1a6d4360bbf622e818fcc2", 5610149366542615),
-    ("0x1e0b4e82bd847dae91d2e0bf6255522b24622485", 14853702712815427),
-    ("0x9a858f8763d2b4eb9846be8ad7fab2e958ab777e", 19440646832253976),
-    ("0x7ebbe8c7a628849f4305780177a0a6cf69b6362c", 34178783174650977),
-    ("0x64fd8b49016ed55d61c4f131faae820acf711d8b", 8659899583894088),
-    ("0x4ab9b759a5653577643fbeb4ed81b2f423c9da5c", 17922964590040662),
-    ("0x48f49f4892f603a4a2fd99abe734cd4da5eaafff", 4020309357992663149),
-    ("0xb10d1e24ea7b92fc7eb305813c5197b373c364fd", 39717244763257156),
-    ("0x88363cae13357492d3749badc35797ab195a019f", 31324148333351871),
-    ("0x9cbbc8fd4fd68a1b642888e754f65c257378ebd9", 45935336600283969),
-    ("0xb018943bcb4bc3be0dc0284fc0180aa79e167f56", 3000083239601747),
-    ("0xd97ebc5186ec9ce84b3c3f1f97401f63d3867df4", 101208653677968978),
-    ("0xb31ffe96f07338206e22eb3601e6671342dbb452", 18497316229206460),
-    ("0x3e4f5be6d007d4d6d1f6a12d72f994bca3cd9c46", 8667089051488966),
-    ("0x3ab7fcd5798713a03887661dc495b5f82092feab", 3861608910097631),
-    ("0x9e14353162badce0f1155472820c44075bbe48cf", 17094930571732693),
-    ("0x8384fc66e89641a2e71b5ea1fb9ca556743b0bf0", 8639627583801950),
-    ("0x089f879f1647cc213d517444b7c21ac4aac9980c", 4801247450806628),
-    ("0xe31790666fa6de7e28c31af224eaea36590e38e5", 5060795151039646),
-    ("0xe03491a8fdbf1f9f59db8243a04c8a6c55171c75", 7899092058241196),
-    ("0x6c19ab69bf2ad90391b3e81cfc769c04722f0389", 12819087005371025),
-    ("0x0586a6ab81904094b0db05c70eb1a91e1ca42542", 26957800835427104),
-    ("0xeb423dcc9b346ee4ddd3a9273854d22599566a3f", 84263043172335374),
-    ("0x273701aec3581a1e9c18f49c9ea12145440b2a4d", 7617153554487057),
-    ("0x093075758e21e406a24440cb034555a92cb0f6c9", 16877849656590133),
-    ("0xadd413d8459cf457683f894e55cf4d02b0068818", 2507927211374102),
-    ("0x269c5fe7b460ae612620c65dd0d4e33e8493cc0d", 30348353926498566),
-    ("0x86f016cf484ceab34fb2be743df06e2c963e05de", 107044999785466485),
-    ("0xb1ce052ddf46b77215a87a1f67895faf780f7d81", 17187581555122185),
-    ("0xea821d29a719a73af89a94d7f7c91dbc07aaf70f", 10830805568402495),
-    ("0x0b7a18cae874ecefaa6ca112ff34fa4b0cc015e5", 7671347180517888),
-    ("0x9c3bacade6bbe81a6238110a28628512abf4ec4a", 11509221176193476),
-    ("0x47edc47fb259cde3573ed5c7f772d5b77f569df8", 2195774682964705),
-    ("0x9ac4ec2a1a3df3fb570fb4de74430c1552f3dfbb", 6289608668255776),
-    ("0x9fb559e12ed71c072e0c639b70298b9e726f1e75", 8596589418624321),
-    ("0xc048da5a32fc8300f8cb8ac0ea209e10703f05c2", 1445899825988784),
-    ("0x44c60ba8f3b68509d63d5f941fc34f2c35d14e69", 35074854432554609),
-    ("0x346fd38c2a76d4ad5b8623401e358cde097dd198", 53896561015689779),
-    ("0xfd1efe4686caa38e56ba6d7f2dca9422d4466a13", 16469722478878909),
-    ("0xfc5e3c80fbfd13b70c690ae82c45138e0c02720d", 136427203217618079),
-    ("0x3af9b992ea26c43f4f3b019a3c5c7fe4e059af25", 18948786095015627),
-    ("0x92a8477347ce40383301d879f130d81fed62235c", 8641540209996786),
-    ("0x95733f5abb8b295098067b2a4e897f9abc2ba7cd", 79925882535345456),
-    ("0xfc055b45215309742739951ad1b2b38ac9aa8574", 38970323867450030),
-    ("0x83f625cbfa75394e2fe9c563861b463c79dd931e", 29645361974021566),
-    ("0x9f4aaba7c11859c16a672cd22a21440ff30f66f2", 25297207482951885),
-    ("0xb1d8f34bdd7d6e05d58e8438b6a43901c563f0e1", 115439246725378383),
-    ("0x679e21a0ed6a30ee97a33e04d8009654f1d763e2", 25978085686807886),
-    ("0x61e40201fb5cdb1488f7c94c6c3191b30bcb743a", 7586517560461614),
-    ("0x7b4da7389e86db1061476ce8dcd6972529279beb", 15699422616131461),
-    ("0x072df52f5895098ddcded915d3e8c83bff61104a", 8778107390776456),
-    ("0xe0cdb30bba9eb7fb0ad09d7bc8f7d32e72a93b22", 2589130654148336575),
-    ("0xe2313ab106ffb9196b29f5b8880ab474355deb90", 42615612453102066),
-    ("0x3793c1337604610b68c0a1ffbdeda4d71adb4016", 10455859749046762),
-    ("0x93bc6c0cf6a7411eff6febdf99647877a8249aa3", 17803480934817993),
-    ("0x200489778ac87196e7bcd812b1c62ce22df39afc", 8097183853491223),
-    ("0x14007449b25ff24e7b386b2ee7ddd38847cbb7a2", 39443721134313881),
-    ("0x600998631dda5ed54ae481a9fface8a6e65b1582", 18038293167286456),
-    ("0x232b8b3f94042d7495a6de2affb25a29a868615b", 33234010974624068),
-    ("0x84bb8f4998527aaf4cf5633bb2743533310600bb", 20875011622079960),
-    ("0xd415d2f890bff9b238aa4fc893f6a254f06cb4d9", 20037008798132460),
-    ("0x5d22db0ddc365dbdc0983441e2906d51855a142d", 102202109102715730),
-    ("0x67770b782cfab1da42a0079c35ee47e49d89c584", 4946882846646973),
-    ("0xa69d0729fabb345158a639b879f65e35222f7fcd", 19761530757109393),
-    ("0x5279502d192b97c684ad32d283a9b5d1b1200049", 10628658814542823),
-    ("0xf1a1602c90d8e510bc583eabb53667e3afae4b52", 2873083298227020),
-    ("0x113a235c688a5d3f6e69181eae18f7c1891c618b", 341399709018553095),
-    ("0x4cd83636301c5afd96efc0eee091fb1b71bf36d2", 298502751989479),
-    ("0xd0aa42c8a55a333f2ac046ebc30c308f679836ea", 10935622780584217),
-    ("0x427142e1a08d2713806c59c9b4ae9c8611f9add5", 16542894686904929),
-    ("0xdaae7d5038e98a26d4762cc35b1a726a5f6b375f", 8236470746881022),
-    ("0x1ae3d567efa78eb59a24e05ec3391c701d5a17eb", 83030343841086222),
-    ("0x1b36d22786fa4a9524c31c11aeb082086c041fbc", 3169456981179658),
-    ("0x67b2b70ef66bd65dec29557390d2ba2ba6b4a36f", 239969343464761095),
-    ("0x48562010e075dd5156b5264d87d2cc2cf7b6e3be", 8494722544811750),
-    ("0xecdd3eea32f491f7d28120e70459da8a000f3e4b", 9350573856510968),
-    ("0x4e13015ceafb442fa90dc62c1314447711ec7758", 13975468752024266),
-    ("0x856f7b07ca7085e29c55d01fe3325f1a07d3af6c", 2751103808118089),
-    ("0xb4a1ef27d224b88725134d743d16354d1885b090", 4205256921605020),
-    ("0x318c66e50f193988f586b2c41b6c6a17df42c6ac", 5053356695599924),
-    ("0xd312de48b6f555405727498c15f00c00d46a5ecd", 389580342856589853),
-    ("0xacc1df99d1b1d6a894e72ab25455508cef1cc3f8", 9829865913356059),
-    ("0xe3b3459e491a855ba16089cdb3d3c7603d467238", 5248222297393039),
-    ("0x9a585c10f5e8d39ada781fac488a3c17a609cfdc", 11241478133124499),
-    ("0x1094acecf44b72d4f54cdf0665689e86d6c9ab54", 14191408786051654),
-    ("0x4e57de2be83249720e3b0cf8594c9056290aaf30", 97810865550103308),
-    ("0xae55c8b8387af435f86b1deda10d6e757b48486f", 931735567570110),
-    ("0x6457be605bedac02e8cbb9b9ff7da5f176cccd2b", 11627767745173242),
-    ("0x95530ed77fa83acfcc0e40e4c1545767e60b7099", 8249537732869565),
-    ("0x3b297a81da764b459276ef185fa8a8b4d2d27b59", 2838934322529263),
-    ("0xa27d91d1e21dc9edafce626f9498627703bd2e6c", 27852447741695152),
-    ("0x741b401fea98d2704c94ce2f73b3728dc715038c", 67087652554325997),
-    ("0xc36e5a80823f74c12ee4a2eb588d25dde220ee73", 3398011431338144),
-    ("0x58fcd1b293c68d6cf1050f2f907bcb326cf06f43", 9966929686350601),
-    ("0x7690c95d94c4e54881b30ebd06acfa315c005692", 24531525283304553),
-    ("0xe5cf1b2120da9ca9e18d97389b14676129d042c0", 18714119133882549),
-    ("0x005dc47789293f06c2ca8891d19a656a390a8abf", 77682058159162285),
-    ("0x735c29f1cf438f6b84cce8e206de0498e3f49131", 18654277537554473),
-    ("0xaf82a37fa0febd276c378c92524675d3f91be8a4", 8574804884799038),
-    ("0x67eeae0a8946f5456002a7ea1a2d0547975336d8", 56888503970199590),
-    ("0x542e35ac7fbe2e04f8758b243e042a072eaca12a", 83915321030224200),
-    ("0x3d6d20e10d5b09641ab2d9aad2d8cbc19239bba9", 2318090740789874),
-    ("0x600f97186a9dbda9878d4c4806286f3695749012", 11306130749899239),
-    ("0x9831f55adc5c8e0322f295b817219a5500425afe", 32990414397369942),
-    ("0x493374100e511b1b091fa5bace1929dc154fee80", 16744053323367132),
-    ("0x750f4ce2b30fe266f34e2baffd4dce880dfaae91", 33537064210286980),
-    ("0xf82c6df64656170413b3ab585c21ed2bffe4d93a", 7640910157676432),
-    ("0x8b40447193bb7ad289b86e66b7c4d51da81a7215", 19046349148414532),
-    ("0x81ac7441353f59c386be36cbaef5f0c2c9b4ddba", 17296117277095897),
-    ("0x83a3dbe0ffb6cc74222f6e51c296cba6b9787f33", 18427846353053351),
-    ("0x475e53adfd1ca7eaa710aaab573a8f8d385af8b4", 16819640675415345),
-    ("0x97ec9fdc60123eed0924950b47b3747c686454ed", 10036598642332820),
-    ("0x9da89e29ab90c95efb8b4aa30825c7bfdbab80b8", 8436840783340954),
-    ("0x96dd8d0f5da211f90cdcc2d9ea04d66dc15225b3", 26599847105411444),
-    ("0x1f411a5f0e1aaad5221803fe9159ce09a17af772", 38710729842467778),
-    ("0x8dbcbd5a3936091bd881d3c52edb14fe689633a1", 202059314806507280),
-    ("0x442f4d7fc4e2a7ac390584cfda566e7e29fe20e0", 20660124708913209),
-    ("0xb688642a93548d96d54098f95fe8aa620efccede", 16239524656358558),
-    ("0x8d03d6d1c6a546d899e241a2c4c44b8c8c3f29c6", 12885067139176698),
-    ("0xc2c10e2a3bf5991b4d2efaf409501e2f38284d3f", 2388897980814927),
-    ("0xa0a78665ba0dbfe3afa30ea27f311435109d0bb3", 26737789945567704),
-    ("0x10328bb8e388095a1026f465bc75f709f6a1170c", 10291365573836228),
-    ("0x3a958651f43031e400fb5991fdbec2d9c36bc0cb", 22985425472025145),
-    ("0xdb09c9c5a8985aa85ae6c09135897ec7a2f67d07", 105892991373790284),
-    ("0x298f403b7737baf08157c547f7e6f708fd21f538", 8541846212995221),
-    ("0xf7d79a460a70af1d8f674b28ce44163ae51ef1de", 19479838552588986),
-    ("0xce3421a3624ff9986a2d0ae0f7a5f88c79ccda61", 16692368194967493),
-    ("0x5ad09914ce092a737e4a97416714854c99118c7a", 15876422517710839),
-    ("0x6f9bb7e454f5b3eb2310343f0e99269dc2bb8a1d", 22471500763613401),
-    ("0xceb8fa8652a2b3de9f0caf9209ca5e28b24cd249", 33907370731385287),
-    ("0xaa3e6e22073e4bd19f57ddaf1d498756b96fbd8d", 13133236007885887),
-    ("0x70afdf391be9444dd3e91a24463ec6828eded9e6", 12478591577362423),
-    ("0x736f0776b3eac253fd9034ce507f6ef529e4baf8", 16483391410682655),
-    ("0xe3bbe1e6b81ee96200f10fc0433d933f0012a506", 3794896517308321),
-    ("0x62dc03174e8cc3388fd1c731323eb5a787696d72", 15586033720062280),
-    ("0x097740d9607b15474f0b184b34425afc2777c4eb", 9110235392875326),
-    ("0x74b7f843b4218e0b5325d292e1cd668079e496eb", 6038203943201660),
-    ("0xd461b51e655379eb205f411a949c8bc56f2ff92e", 16390186480771578),
-    ("0x6b51121cbea3ab7b7f1d5465afa98200b8e88364", 5442046419895443),
-    ("0x090dfb1e431d5ab67ffcdc8954a8129ba66ed44c", 5389103302187561),
-    ("0xf7554b7b0b3797e3a02eb3a563227857336f7e81", 8006053819000000),
-    ("0x299fe93caa4bcf2dd0e7adc46a72a92c0faaeff6", 19112233532442627),
-    ("0x8b299920eba7576f34c8a9df49aa3c2126e08d2c", 1955774334101351),
-    ("0x442eb80be7d2a5e6d3c48cd6692529a61c20f6ef", 15127762713058911),
-    ("0xf1a66f3b09ce56891263161045daa695508268c0", 6249270500139424),
-    ("0xffc02940b6f297bb3aaca6d790f65c1d28e891f7", 16841434746700950),
-    ("0xdcd89f21f26d6d07fb9df95ef66935338b7224cc", 17097425229180867),
-    ("0xad2beb4e6e3b0221434c8364d7cd718e1f3bcd87", 15845803867778483),
-    ("0x30e28648e903f077f120e48330b005822760ddbb", 107379770647901224),
-    ("0x44d57efad3f64b1e5d0be2bfeb8b251c65032cc6", 19725756784167434),
-    ("0x8acd5adf38d97f0c29a8374b3c3cf8a184de4976", 67917627231476924),
-    ("0x00d26d56252d7770e7d101f63af32dd5f27f7c06", 11970608735706925),
-    ("0xfdd1fda5ca4e77ac4668192bfcac74885fabed65", 3866813807977543),
-    ("0x98992e1dc67b8a640f1c1045fbfb6fc766374847", 79713484867282521),
-    ("0x5205ef2516cf8b8c3da55d8853f49d56db9ff903", 11370886641699260),
-    ("0x5669f5d60fbc27b4296618415ee54dc710ebbb7c", 175573466533016247),
-    ("0x3d8d23b84d9670e571afffad947eba9056e77a21", 10155309029531489),
-    ("0x21ea0a2c6e4cd678f58fffa8198c0dea93a16df1", 7727181079078853),
-    ("0x262d93271e64bb424e9a1c7da652faccdbd230fd", 12697669680622238),
-    ("0xc6d01bdd2d238cb3fce9197f3b4b9c5539647f40", 1865504426748639),
-    ("0xf6a9e0d4a0ec8b3ea63da016eb595d751a62a55e", 14908467657359793),
-    ("0x11b94e299012175280b39631c0b506cf66e9efb5", 15636139245798652),
-    ("0x571061b1bff2bc8f0967f5edbafefff36c932cdb", 16465344544476052),
-    ("0xf9d3785f76b6801993df8588bf258cadaedfa643", 9034188222350660),
-    ("0x521e5e0044ca0eb6440667243ab01dc016e40db2", 47038323291024047),
-    ("0x22ef0ca0ae7542df5f7705e192879be71cfeed37", 14589727460009029),
-    ("0xdae6ca75bb2afd213e5887513d8b1789122eaaea", 28343244433314062),
-    ("0xe28f9bbf9694205a6a6001f3083d90fbc0e9c090", 62265140021013987),
-    ("0x84a02ffdc597ac85fad97ff3305fd7d1febffc33", 162385711617651361),
-    ("0xfc017360272a4cdf33691f584e9e0b07d049a97c", 5712162125665730),
-    ("0xf7e220fbce58aa70e27c022904fa78fa07f887cd", 10904884220666694),
-    ("0xcab5ae64218bb57c7ff37d74d2f77f87f70259e4", 40641796707244088),
-    ("0xb66999b1f7006d95a92f9e44bf64cb1c8bba20b9", 3808247941548030),
-    ("0x7b68e8d6a0755c684293241c0e60ae92e519784e", 8565141463332950),
-    ("0x44f4f44b91a050f0d5002089ac44998c4a24722f", 85558488048468215),
-    ("0x332f49f3758352d56934a23dcfecb763db9b103f", 141982005843787674),
-    ("0xf464a33a180411b8224b81f6e32258c2a216c35f", 9022221012675644),
-    ("0xbdf2051a4d56b857ed1dd0fc881041899d378835", 8617994535456595),
-    ("0x36fcd9f92d79b45bdcef267e834e276f45f0d27d", 6726081833402801),
-    ("0x6cf18ee8701414b21a62913f44d19444f88ffd5b", 8264808864945302),
-    ("0xd16bc6288b330956b2a600f370f35f02b8b1c654", 13922385167696539),
-    ("0x60ad4efb93078558ed662d42a21c96f77db4c5bb", 29159848334707400),
-    ("0x6c7e7fbfa69f4b5a5ade1c27bc74fa4290a3b8a3", 126276333009666384),
-    ("0x8e1758f8113380739a796384db3bf73ce0196654", 3377503161949222),
-    ("0xdc27f935c2f011aaa1671181a2609309c28af989", 84294671947675652),
-    ("0x4f0275b9de3c5bc15bee68f0557e98b2ffefe602", 95871169495291448),
-    ("0xbc1e08f72f204d9f7769b8041e84c88f76479458", 5642827861853040),
-    ("0xe50b4f3c528f0cf8c4143813a9f41d98adc17332", 5866263965980124),
-    ("0xea1a20cdfc3e7bced30574030e37b0724d529a81", 29181047481994745),
-    ("0xb90b0f323c960617dff7fa025e715c712ddc5383", 3726083571744540),
-    ("0x1e2c8fd4164a11cf36c5cbd70aecf41c2195596e", 100131309018538603),
-    ("0x6b336e0a5266cc4778a86e6a8e0ac3d4c0e760e5", 71611301185903024),
-    ("0x002365d282b01f6ca682bda1ec0f6139dddc3100", 12908287340811897),
-    ("0x37eb7a34b802672bc1bcbc401545d0b46b0310f8", 4586186673284387),
-    ("0x50b60501b7ffe66e49460f7b5c54c4dab26ae233", 8383336727106898),
-    ("0x5f5d9acc799034242b8bf6dd58297a6c2c3eac5b", 7830876246414148),
-    ("0xe880f9abac3b6293ef0107ecd04b83d9766716e2", 10629129666969533),
-    ("0xccd55bd2fec878dcd749c166e55e42bf41a17d1b", 8736421877865576),
-    ("0x8161f99b408a9a6a2a6a3563747dddc141ba209e", 7488361133213582),
-    ("0x52a2cafd943eb5fd6555efb6c57c66ccc9951cf3", 20963148636853353),
-    ("0x5db48d76d6179cced1cfc7ef79145c5681c46dfe", 19347942785097041),
-    ("0xdd386b40042cd6cbf19ea98bff485a5dd2ced988", 13636168623975004),
-    ("0xb97f24b6e58ea8cc2be1dd2a96595733cf6baea5", 82212995335774608),
-    ("0xb2c63b9e16c3c319124a1be4cf9f9f60ae26a3df", 6671065147757583),
-    ("0x89baa268e6c7bbc83b8651bc63890ae92e14d579", 3705814183630163),
-    ("0xd03990b42724e5cf54cebe41c37b48973e71c31f", 16233715581366343),
-    ("0xc52e63830e6528b5d77cc1f58b02f15208af8d3a", 31548577375179804),
-    ("0xc9ad252fddc0aa4407484d14e547664d4eb57522", 4527633558179713),
-    ("0xfa4dbd193a8970cb04cfe7f169a1429b6946db30", 29630554204528990),
-    ("0x253c5d65dcd792f6c46dbc78dfa676de940180ef", 155566203420780635),
-    ("0x4427df42db1233d6b0a73e8b176423fb6a61e008", 7535160550824154),
-    ("0x7d2cc906fec523bb64c4a03c106203732251e891", 6408242726281345),
-    ("0xb2d528caf5d70855087ce1bfca99230da338e722", 32196800946597753),
-    ("0x8b416b031fcdb9cb0c7ac43787c899c370ea3197", 3664436769353743),
-    ("0x57385709366c0db059a9eb25f32c0bf948a250f9", 7137312333047217),
-    ("0x69455d305e52bc0174476a791835f20372403dab", 10006282180857644),
-    ("0x228d5cb43d060c36ffc18f17ee1048edbadaa0ba", 42962784507807921),
-    ("0x85b409d90fa0af677adb6baf2cc3eec2614fa7b3", 43345517754136851),
-    ("0xf8a4492666284dfa7ac4c08b1e36e012fc8964f4", 6297005765793081),
-    ("0x216c6eb164b4af7bb292279c6886258722b7f2fb", 38380661224770435),
-    ("0x5314b117053076a43962c82b654c2f89d35a0a6d", 8637388117103281),
-    ("0x58aabf03c79cc4f81933aae863d1222f3391c153", 3850629928210787),
-    ("0x5ac0cb943477c5d40ff755987640218fe97cc18d", 1246855645860736),
-    ("0x665a659941e33bb7e7e5d876fa0dc07b180f9e4a", 61292982202949378),
-    ("0x60fef54feee3aa08c71e8d0d33b7a4e0f3f0c8f9", 31481210892675920),
-    ("0x743c9d620d2b1943d3347e2120ff9998637042ac", 50184978845052249),
-    ("0x60b4bd313fb7466c79eee7c3c2f8c5d168922070", 1684357164068760383),
-    ("0xe3803f7010b5581bbed1dc50ce09b7e14a05bed9", 11001357007558835),
-    ("0x05551234603b315d6cf089d041f170179e1d34ca", 15279239015339667),
-    ("0xdeebe1d5f4eb971145e7fb2a5b85a681f354c314", 8035453869875579),
-    ("0x4cd67cc4aede471e20bbfeab92eff2b88141a48f", 66576163429780741),
-    ("0xf5a38e7152bb16c5a8241139c16ba5f069003cb7", 9907239482296617),
-    ("0x0c5223e45a168d8dbd4a320638bbd1d4a682e9c0", 3388322213907714),
-    ("0x537037c5ae805b9d4cecab5ee07f12a8e59a15b2", 885176009698765678),
-    ("0xb4e9ffbf8a5ba5bb4e9ed67c3aa240521fc608c0", 8413312382248732),
-    ("0x4a604be86c7b5f2b62b2bad6d9f8b2ea25e6e34f", 29008680839650411),
-    ("0x886fc43f1329c456bb6b5809877fbc03198c5580", 9965208476369526),
-    ("0xb28c411b5d7ebc61b4200fdad770425da15b0158", 18320466861953092),
-    ("0xdd38620521c66d9c9ed1c37ee7af7344b2b15f5e", 9175625958861913),
-    ("0xbe2c7bcc8e91ff7252561b5cedd72094df5a9a29", 16722209175836952),
-    ("0x346fbc0273f9bde41002f1e5a6c6ace827473e62", 8389856968064264),
-    ("0x7a2f78809f8f1b18f355502209f04bddc03c84b2", 24198566013989518),
-    ("0x87c373ff701ee0d8dbe624be49b73e6781758d62", 6159506023951517),
-    ("0x814dcd4f54a8f2e5ec50fe4de860fb3274632572", 2105161012917475),
-    ("0xad206599e4f928d3de073aeb90841c901c943f37", 31285241462187267),
-    ("0x5af7140c215c85408db1ecd969793c1e958930ae", 8478461676888694),
-    ("0xc19e51a53fd2457aa4d3e3e811b5823750c9429b", 64881485625205183),
-    ("0x06ea4eb457345d15a34445b863ed64cf0093ebf2", 7913125209167979),
-    ("0x1dacb657ddb3d571f70187cae585b59df1709998", 90980219721667202),
-    ("0x492059918a8d8c9435768b56ec848062e6a27bc9", 9125955928608072),
-    ("0x907a331f31daa91def1e673b25f4c1ab10e22e8e", 32597080577147061),
-    ("0x1b9d3966b4546df32639df911c781795e7ae0c8e", 12816890396594894),
-    ("0xe2e2b41f44b72b601fa2a5467edb661a2ac27906", 23290557158983492),
-    ("0x3b6a66017b75f04e55c73664dd6a9cf2c8027e0e", 8517302444694599),
-    ("0x8de02b898091a2401f2d89f6cf3c50307c329492", 14604299954614938),
-    ("0x71381763dca433eb2e3221f3afdf09d9aeb3342b", 33044236164939663),
-    ("0xfd624dd83aabb1bcf8022e991a1932a20cb7347f", 16600681712442202),
-    ("0x6174759d8a0376652f783cb45026b902482211ca", 13530763690958973),
-    ("0x73e7966aa9d2948d095b5ad18fc4e4078440bac6", 128483086113811759),
-    ("0xe4c140a67a3a95913a6e2fcfc6d6434d94c07641", 8594899578910407),
-    ("0xbdc5edc5177742595883be51996b8d791e6ff8e4", 159097862833974688),
-    ("0xd73aeb9a7383a251cb3a811258926a866c7f672e", 6280257988066597),
-    ("0xae4cc02eef25f4144809e824bf5eeb89d40a24df", 2171227899597245),
-    ("0x3b0fde6628c1533780df4fa2852448f28522c73d", 168308880629036213),
-    ("0x30bc71fe82cb20382d9f8fbf3d28b3018ab48c9c", 2474267218557349),
-    ("0x81b3a83026535e380afdbc8f1756fad60f0efcc6", 30945213882879249),
-    ("0xf6739a814f143ee7dd62a7eccbe3c6ac7dd3ce38", 66749119772510201),
-    ("0xa16e3aadd14dc380d3f8e3c32b6314d08df8e989", 16904383424771091),
-    ("0xbe4fab496d0f40873c9b771fe1612b3a3e1011fd", 6881369952412386),
-    ("0x1b8f6ffd178080117a866e8535df6f3fe50738f4", 1000000000000000),
-    ("0x7f480a5b95b26a31c7306e8532ea765735f56874", 13068020802702165),
-    ("0x87a2e8be3c259d9eedbcf9def5e226725649b7a9", 1000000000000),
-    ("0x855c6543502569b52d39580a1a84cc5b0e7bda42", 18856707241161145),
-    ("0x05765c2d7c07595f317fbd20f03e4f2b1eff0c24", 2556982100458208),
-    ("0x596572fb0e85ed15c2e5fc0f126878cf6b6b95f4", 167458293153680960),
-    ("0xfbb21f601c66030f3337fa8c1875702ff82846f2", 2870331339926301),
-    ("0x77a39186c8f08761035c3205b66df2f8a504e84c", 20302287814675733),
-    ("0x4e9553ca9a28cfee3af081c9d6834ecef537fb2c", 17960261706634145),
-    ("0x2401a082360623da074784ae9f361668935a690b", 1001284132834248987),
-    ("0x1020fab919fc9fc1b8f4a366d319fed65ff21259", 16895681707721040),
-    ("0x029cb21098ac4cbd93b0fb41f592af72c6e18744", 15350755735776798),
-    ("0xf436f85a9c26fc71afff2e9dd36c0eee66b8e1de", 56908629476423799),
-    ("0x7052922ddbd21fecb9fa21f8689a4b7f13c8729c", 63033600663231079),
-    ("0xbac462c8d7473a01f11378b65e395dc7ea57c9e9", 11918890747532638),
-    ("0x0a603823fa80f8dca641827bc7ccfe425251725a", 3933336910159300),
-    ("0xd7a053c90a98df6252f358d5e2267306b96366d3", 497181919007391775),
-    ("0xb8b7fbd673700f46215d2bf60baf20621b4412f4", 9109987381995021),
-    ("0xfb9413b035def99901f0e438ea297a9032896851", 13980335459090191),
-    ("0x046536c29d82eeb87899bc577f3a85fd8a2ce527", 3268254247597779),
-    ("0xe104364d4088239ddb46d59a7c04c237dd26639d", 8594855775208227),
-    ("0xfeb2f2f0a111a95bac689023daa8d886ad413fbb", 164762863576109389),
-    ("0x2ef0782745b9890c2d1047cbd33be98e22ec35a2", 95164469643816191),
-    ("0x4ad6f5fb3acfc7366fa0c45cede54c399558fc20", 10630572919942209),
-    ("0xe4957d0691f3aaeb414c693c1e8acde0bf4a22c3", 50781134150264186),
-    ("0x478ae5823e1d4a52eaa4460252b678350100d88d", 335370267243148968),
-    ("0x02d619a940c1e9a705e7a21bda7a535379e66122", 20559884787013322),
-    ("0xafc325e3126697cd028211d6670791ccd8b8c441", 1997167983455705),
-    ("0x8cc5e32865f7b2ee79e3facdfb2df0b3e542ea9e", 16212005821132994),
-    ("0xf2828b2b9a3348fa5eccd7d2ab4031e7c12d9527", 12654272166025905),
-    ("0xf23b73e857f607979934b64f38192582ad4e2818", 2684239296436998),
-    ("0x2976a1719e2fcbc69caef98da238e45c50e09b18", 22814290314973717),
-    ("0x66b007d71e9d8868887deef22982ae5ba164b63d", 13448883489015320),
-    ("0xa98b254d9e2b9e2c11f20780d5e021ef3c45c23d", 139516887607773489),
-    ("0x64569f857512c63268f67c914df351d30d1d6322", 17094582358965086),
-    ("0x6269a1771b3f4cde115eb59b9383d3645cbdd217", 5985822249436758),
-    ("0x9871ca7b337a8f4da5c836d60b15baa3315e71b1", 39770743602450595),
-    ("0x1e898058a3404b9cb4a1ebe0190c45bf84226ce6", 32799871600306783),
-    ("0xd89b10327ab558416e8a8657872c0608aab84c8c", 2981121336489941),
-    ("0x4c1fd2c73662de4615bad4ecd55bab161395aaba", 3967132554823895),
-    ("0xe01cbf933e01bd9c162ba5d4f6bf593da542f3eb", 30782311396300928),
-    ("0x6220b3344a1763bdc9ba9a7f71db810cdfdd98ec", 1688560877239115),
-    ("0xa366a0362ba5cd68962097fc6c1b84ac42fe5d60", 16457635167795720),
-    ("0xc34f53f232dac3ecb4216601182f2e4f88d18dc8", 83553322988057185),
-    ("0x47cf7c5154374eb8c5d84db958b704f66f168f5f", 129203436124881857),
-    ("0x4e49cc7460a1224bd21ce3fce540025f2abc4e05", 5507869961247117),
-    ("0x96c96e695f1ecfd6b932a0b81a223b59d48e2c36", 12537329920949501),
-    ("0x62139b7b68181c954e9cc87df7cd0a3eec4c5887", 16433015659829003),
-    ("0xce8f13fba191cda04aec84416e00ad2b346b7f7d", 160241859894865909),
-    ("0x0559a40c957e288f3fbd49deafe6ee96453b2079", 28578411905134881),
-    ("0xaa6010476cec7658da06bac24d937b073845e92e", 141238894088055318),
-    ("0xa4b0a77c312535b8a5863732acc42cfa7b6af9da", 40645311982118530),
-    ("0x745f2e894ceb45a0f41bd7e2fcb9cf868671b30c", 8812329102569575),
-    ("0x401460b90748b2d571c094a46feebf5990dd8e43", 62075269989966987),
-    ("0x3be7fe9be7a924a51564715a1c00d141eeb045f1", 8408147455786574),
-    ("0x93ca9be109defbfe5f6ef66f4eccd7f838c8c3dd", 136808561589756548),
-    ("0xb6e34a8a93031a24c264be59d0bac00bcaef9051", 603276226638361),
-    ("0x5740f1bf2cedc2aa060a5ab8e7a61fe451426572", 3896787456838149),
-    ("0xd1d80c4a0861d2f3aad234a3bd80c5b333e9f58b", 32130530087907271),
-    ("0xa61813c05a45cafac19e466df345ba6017d0b117", 10562561618230576),
-    ("0x1ac7bab956e22da4abc4054db13e5fa5b9d04d0b", 15910607687322441),
-    ("0xfdad95e005ef053248e88778eb62b8828dff3040", 65517385013077988),
-    ("0x8f786ff75a5da2ee8a7ee2549db464a5cbcdf718", 15924734276312004),
-    ("0xbffd3edf9839d52b216e7c43ba40713ad7723a8b", 10064444739329895),
-    ("0x6ba90280852b8bf746e2390f727d6aef9247a163", 24534597785162124),
-    ("0x9407a95adf5e9481c3321126afb72241b89de296", 31977313718097197),
-    ("0xfa8fe2881804b1ae1962d64f6a05afc02b630ebe", 53983639928491431),
-    ("0x2f53a6d820d72fd3e90ac624ef3585c0dfe4d8d8", 102801748559522971),
-    ("0xda5d1a436f2af26ed478051b3b987e7e225e541e", 9728139507530160),
-    ("0x6f0d99bdd929591f70565134e99100994836a98c", 2273660273638593),
-    ("0xbd7cf3da6d19ce4ee136a0da6d3caec73f73971f", 21093726103366566),
-    ("0x90649b737fcd77331ec4e114777bd1c6fca852af", 16812869510077764),
-    ("0x5688cb639684736ed9da1ea0757896fbbf528a5b", 14955888993002413),
-    ("0x8d839361fd59c8895efaa202073db0acb25318a5", 97334878813073838),
-    ("0x82a7071884c2eaeca464d9b8394119f83d48c7bf", 16551967400090495),
-    ("0xf13081c2fd2b9fece2e084174ef35dac9a05267c", 17450379214148591),
-    ("0x17b4a2b8d77e8237dbc3a7e2dbc9de7c35d97b86", 11666545943429352),
-    ("0x4f7fd02c9ba416f1f8fb90d438ba5cfefcdde70e", 36998356750727361),
-    ("0x3892f90a681192ff8b5f11f247e1b3870b5f4087", 8752829382003517),
-    ("0x83eb5adcbd809d732e443078a2311be77f765040", 15445859425992699),
-    ("0x6648b61f5032c10722e3c5dd15bbbd8166be311b", 29913126624648077),
-    ("0x501af03225e0d74cb722a296e0e8e0eae90f11d8", 96280555558878206),
-    ("0x217109687feda2fb543c9fda73adda825a1a55f1", 16038943694712900),
-    ("0x39b243becc1ee504fe4296f7ee91a46739ec6ac6", 16835217255142604),
-    ("0x328c2cb042fe460e73cb1c0e5565db0c548bb0a9", 7057960888049105),
-    ("0x7b8e1768fb8caf73237d04aa33bb97589370b520", 24212828527483759),
-    ("0x035520af7f8062574d694e71e7507a365087710b", 6095509220494839),
-    ("0xf7071cb6385d7122452789311980d47c43b5dbe1", 80229182644395508),
-    ("0x24ea5f00bbebdc39130915c19bfba05be7333e59", 7920112407177198),
-    ("0xab3ac8814b228b8e465d85555a64ef5e973f1fb9", 13945942491829136),
-    ("0xe4083f1e4ecde085bf7cfa1ac4f1f9a02905698c", 48699940464372911),
-    ("0xc0dcd17c474b21f3f59bec03a06cd30735551957", 6837332217077839),
-    ("0x5f6cbc5a243338a65dde1df78a47cd415f771a6c", 26676171831642540),
-    ("0x02c529d7f5b5b10d497e2f6b4b69a754500544a8", 11278540276145627),
-    ("0x43638006802776f6c86c34a892688cc58cdfaefd", 49422607845519952),
-    ("0xdb5a776d0c8a6ca481fd7c8ca933b5fb0820a923", 3639627631626994),
-    ("0xc106905443d60547546e9913055c297b7bb9c481", 361487860449578176),
-    ("0x4702a6b1969590a77efd39c55a8297c5421d5839", 14187467911431671),
-    ("0x08a30fe5cde74f0c5f255c48df0a67400e7c5ac9", 16541915767135240),
-    ("0x35fa605869870e4bd433db49fe0817292a7c8740", 127706342533791040),
-    ("0x6c7bdc3ee8ef7b9e1db29e5a9c9cef919376e37d", 26695987142893379),
-    ("0x606d65cfc0631a637827afbd2fd90b80f2a58bcf", 20066172271916304),
-    ("0x056f2f34ae9f48c1622eab9cf12a0b156e34acc1", 42156194310102528),
-    ("0x76eda1b7cf325365feeb18f750e7f7ff63a23cab", 13260942101820519),
-    ("0x6caa5c6b9b690879bb229e6b19cb2b755c4efe5b", 122589918777486582),
-    ("0xb14ce82f37a6108691c7f659174fbe4572f3e757", 20809749147270847),
-    ("0xfe782139cb2c634c0aa8ccfba71046bc225dac0f", 8096600775775907),
-    ("0x153e5893d24be28ac3b37875732afae3308614fe", 76559696729956446),
-    ("0x4ceccb1061557cbbde9917a3bb582dafa3802a20", 22876995966451228),
-    ("0xa91bee535867cd3e2ba3b5fa363067e1ef22b9d6", 24011453158570254),
-    ("0xc14d4aadea832e8ae4720ce5c6adf25ffa16d942", 32388856155116980),
-    ("0xfb19fe38ebceea95d2a8cc1a44bc032150fb17b0", 6234850447601557),
-    ("0xa314921b83db11d8139eb977880809b96bb09d1e", 27480143816290028),
-    ("0x16e51abdccfe7a8418e3394ad13b6373c683286f", 22325177824795008),
-    ("0xf41649bfca3a215c19372d5886a176cc83a6349e", 1703283511243498),
-    ("0x5110d388a252fabddf5feec1718164308607700c", 1777000000000000),
-    ("0xca1fe4a17600da42d26a68c30bd4618e5e3918e5", 15999090549269074),
-    ("0x474a487c49fb21ea0f6e6b1120a60f15a6d2ae8d", 12169300356271932),
-    ("0xc03f6a53dfc434dbd78ffed528fcdd3f406576a0", 16095787867372124),
-    ("0x33c5356067d62423090d252307c2c81b06499c07", 12416118051172657),
-    ("0x4141ec9f8acfd636e7b037eb3171f4452656da35", 43501528132316899),
-    ("0xc88dfc8b5df7d3f65778701f33078271f40375d3", 15037220457783767),
-    ("0xb04c9d6d966bf6ba63318563b67201332945080a", 20694487536196565),
-    ("0x67c255734b182336bed8a31bc2a8ef3cffa8354c", 11978250590848631),
-    ("0x0c1fbe26476e34476eefe018e8dff7d8d5080fc1", 80653996458732425),
-    ("0xf289ad2fcc8d6f56fc4560503ec787c17320e372", 144389335403308955),
-    ("0x5e1bf75f03e4d87d82398db889554ba0e75f3879", 5924813264838496),
-    ("0xc7677a84f84354c5560a7fccd538593b46a6ce4d", 54268346628498659),
-    ("0x3630a2d8d0fbd269133ab77632f88eb4665476cb", 199816827081479480),
-    ("0xbc4919d3789e14467cd6e019a5dd394b109c1bf7", 2350000000000000),
-    ("0x84ebe18107847916da0babe1c8672e40c2c8a161", 288401855054892021),
-    ("0x356ad8e7824c7b16b5e1903e6edf02e89138dc5c", 3592992831145038),
-    ("0x9e21f829ff2cdc729d762eab6799bff50e96510b", 3777000000000000),
-    ("0xd9cf02d35ddb52f4bebd3c3a16f4e5d840cd2022", 8554207741254171),
-    ("0x29fcf46b4bec93d582be01b3ecc573daacbffc0e", 16409876003244294),
-    ("0x04bd258e2a94f65d49d6a7ff0b39ac02acfd3826", 16406841381291097),
-    ("0x69107f2651dd73e85ad4f9020e91776d9610f627", 8935781268011664),
-    ("0xcedd0bbda24292d7cd0e1141e2522a1d392b90ab", 1977000000000000),
-    ("0xa70f3b2e33e2bd7bca669083774b41b448e128f0", 16899063894043057),
-    ("0xa52d77de921b6b2f317aa2d806a656bf52458bb0", 16867386715558556),
-    ("0xf312364bde6fbbfbf1a410d460d7ce290e271d8e", 14072965662180338),
-    ("0x6960591aafc760f2a30e37dcd0704639dffa8776", 29113978381919713),
-    ("0xb760bf9679cba3e495cf2c21cc09a972f75d690d", 26348004331184687),
-    ("0x787ce2124730dbb6e02b964068efafa5df404d72", 11337916579584960),
-    ("0xaf0b8cd47c640776f7e0debeb422511a451fd447", 7457777506243470),
-    ("0xb719ba7c98bb42d89402470a351acaeb97e00657", 43279006409902743),
-    ("0x0f62b147dc4962f05454611566215e58071335de", 48616238408351326),
-    ("0x4bca758a3aa9e025f30e8810140866d6fa64b03c", 6859388809131740),
-    ("0x966c2c55a2ce8265e862a63e98f44599f3688923", 10115133906744773),
-    ("0xc58bcad7f918b1fabd0e6dfc5707eaa042d5fefd", 1384859680097545),
-    ("0xc377fca91b340c5609a7d269500369ce436abdac", 28920643822136538),
-    ("0x986339a7b01d3ef79b35d10d0517c5b776b33666", 56733951652069914),
-    ("0x196e0975c5da295e6d15610788901af9d36c00bf", 2720370623460245),
-    ("0x38567f34e6154f923a96f1eca2cf42be9db1ef4d", 12873805144788721),
-    ("0x3b8af3a14da9ce22303554a9fad0456011592222", 16941625831483410),
-    ("0x9d6986e19a5d50fa8700c8310dd2329a8e2874c1", 8857120687873573),
-    ("0xb620c73f87f4c1ca9a9f984c840c2de94ef9d54a", 28980568711229782),
-    ("0x321957a7cb6e391c4d5f15de33db1030ebcb8254", 13171830658171746),
-    ("0x11619d7d52b35980e2a6c40e76e13cc06b6858d1", 93954917764260913),
-    ("0xf22aa7f5b00c738749c3ff100cd3c1f6ba42c0b4", 4699896934535108),
-    ("0x07df7775902c31c24eddac2c462b30cf988d7e19", 3283948256318752),
-    ("0xa87ac1db389fad6a88f9369f9050b523889daef8", 137937026249305973),
-    ("0x0b8a7d2ea081845275f5978dacd3375c3f5ca3ea", 3645520820751410),
-    ("0x4dd1af0bdc8c8b49ca133392aed73f7250ca4030", 136189896021745466),
-    ("0x604dc5f6a4af5be909db787ab56c5562fb22bbe7", 47128231071511776),
-    ("0x020d19fc01e9abaf7d392a42878a435ef2095487", 16016709761431257),
-    ("0xbbb8829ff88de74a100c22ee4a2d1810ed6eceaf", 23848255762258467),
-    ("0xfb1e328949157e94e49a4f6e357eaf2fc4801cc8", 32407182968944031),
-    ("0x61c738a9e149a28039255c34c68df06634916055", 80023241172172986),
-    ("0x717c05bb13b3b50c0996ca3067c7bf93528353a4", 18378219539524917),
-    ("0x90bb65524063892651d1e437248bd5f7cefb9276", 6157197574924389),
-    ("0x9d3cc7a278404e302bdee90c9ed9cd71c69dae86", 28680884203788581),
-    ("0x63499b49e22ffb48d77618d5865b6b8179600bc1", 1999729837438217),
-    ("0xbdb4ff66a1f1cb4c8b114f1c986dfb58df27dafb", 27785735426843026),
-    ("0x2dcf32cc4a1486d4d8a686ce92a367e1856b55df", 2354177482115650),
-    ("0x6f514b61ab030907bd19eb92d2edcd7f68bb38bd", 151016735594828879),
-    ("0x3445f1d68f55cc30679c35c62350fb43b701765f", 4545036171205251),
-    ("0xd9ff99d7d37cc95014c12f1eeb54cd935c14e369", 68390737339887229),
-    ("0x87d7e2bfa58e9e45074f06348b5a09687d2b680a", 3570251395117688),
-    ("0x8842ae488a93436977c027ed84e357e073b96f90", 66984724664923282),
-    ("0x76e80b2f3ad2f85966c2a7b94adf1a2a6363f5f8", 20712398245587301),
-    ("0x5b2b4b59276217400469916fceefc89acab7548f", 16121701172802414),
-    ("0xd71da3bdf3442b65243cbf29aa026a5221fbae4f", 29411326801785029),
-    ("0x769aa34c5266da82662f2b5d5ccd6ecd064b6f37", 6262678226480377),
-    ("0x556a28abf1d087c491deda6aa41880668d6f63f4", 41235121696341081),
-    ("0x19deffb99210e6d02b15ea3bd0845ea41f7ad4a7", 6915073478538954),
-    ("0x714ae506405ba00a97038f1fd02f935288ac1be4", 13021708813193127),
-    ("0x8820066a0d1058626148140edd147eb279ad815e", 15176967771648256),
-    ("0x7e9bdec49168230e6b5055784d9b74cbd9f3242f", 31744724695218029),
-    ("0xa2fd76d3a5a7f556ca94a196f4b573e9a108e0e4", 8720805158570420),
-    ("0x9f205dc3cfbd2cef860c0ed3f5dea5de76b11e28", 138626427104839836),
-    ("0x8e8125bfa265a9dd7599040f83582b17f181993e", 27347503077943002),
-    ("0x3919c29bcf6e454133389929c65c2351cbf77524", 60095385934934883),
-    ("0x8a2096a678e16d879f3e32c335af937a70c69217", 20841604883936102),
-    ("0x9b8afb5f6cbd0cb9f61a2d3765acbd5a8f1b911a", 157028251607583801),
-    ("0xd0e912ae5099b3a1038e95d97b9e2c282a65860c", 15875301758765875),
-    ("0x49f29c0f423480deca9b663abdf509694f795298", 14410743851387295),
-    ("0x558829c93a2d1bb708033e236b328b8adb0fa5ab", 6344531612942704),
-    ("0xc4aa91a6c3d517f162fe2b01154d4e512707505e", 14018284386962485),
-    ("0x662d6983695b3ab93e5de5652f57c4e817fd4e6d", 7225652735662574),
-    ("0xf1b867bbc9a36320866d51cd020cd356d2cfb767", 38830590316618162),
-    ("0xce626ffb91e87a76671b61d2eaa0d7a889bf2ce1", 5991966155686620),
-    ("0x9a6c353abd6236ceec791092035b36e5fe6ead63", 20754166750604043),
-    ("0x1e20dd2f2be5144e6be5f1b6cdb4c2fe132ab216", 11969979888541508),
-    ("0x062aae68080e2426b169b7ab990567855b5e3d52", 191961861753512938),
-    ("0xb51f3bda12b818399e8ba48d5034daf20d8bede9", 4666880552283591),
-    ("0x9afdc22702a1407afb4ea76257a677ce1295c786", 27355275974587104),
-    ("0x8dcbd606f2648bb60b6735da95ecd2c854c0b26d", 15477541793929686),
-    ("0x3dccd3bd797061cadffab6f195123e590628d560", 13938613624892738),
-    ("0x00f00853ec4bc2b8793557c8d0c4ebf9bcf62a64", 11165670327711481),
-    ("0x2e9713df5abe13885fa99423b0a8d39c0796d07d", 71669369087035904),
-    ("0x4ad80c71f0850ee8ffa6ee059540c3ac5502d167", 8610912202420933),
-    ("0x09cb15d83e904818ee567fe2d7f7f2f430966434", 31265210944445060),
-    ("0xa2faf19a9ad8953bc99ef0e1612848673b0a350a", 12599186691102857),
-    ("0x333c12be4ac9949bc7e77224d1542f5f347b15f9", 30954512932406215),
-    ("0x009c3cb9e9f6aeef7330d0c82232efa73b21fed8", 13564765416331335),
-    ("0x58701c0b2fd872376b817bbbb3521e79728dd034", 48538958414910141),
-    ("0x49c46597ab1949798c47638e60da49ef054866db", 3393188467897584),
-    ("0xd80e214d86399d966b5d0a0f647e48eb8e73a24b", 75740354079677610),
-    ("0xab62590244efff64e55f4dd50c833487f09d19c1", 16801652064201927),
-    ("0x2292d0dd9f6770d670dcdb8ed1a99a23acaa43f1", 12407680213534539),
-    ("0x94e2d6bd83a9d53f5c3ae7594f8e883c9c112a58", 60599157129409176),
-    ("0x0817a2145eebe5f6a855c2ab878b24c8e6142871", 39948714604226497),
-    ("0xe159279941ad081fecded7b482131913075398b0", 15851112817341432),
-    ("0xc4a2d017ae62d3253d1d291325d88210a1a02928", 50000000000000),
-    ("0x4aa7e2c2bb2c98085b8aed6848b308a3e93ea637", 15000392346452574),
-    ("0xd71ac7f721e1d94007dabf3b6969302200829a0f", 2243477948638609),
-    ("0x66815f7b049fcd9d9b0ad4fe31e14c11b0e41540", 27179948321076162),
-    ("0x3c50849d873cf92f04f0ee7e624b384ca4ad586f", 46063304814928169),
-    ("0xc4ff9f88076c5e675286b3175b11a2c585cd2e99", 37748753398961776),
-    ("0xe5bc64f1f3537b5a27441a4251ec6c80a82a10ee", 2214990480856386),
-    ("0x777bc040bae1dac45fd4a00684ab8f03d434ac3d", 1194552901707286),
-    ("0x34ce401afb4298a999662fe4cd54fad48b26428c", 16224476734176977),
-    ("0x995a59a532a1d95c5b32cab76999765a0b22dbc7", 14930732658696357),
-    ("0x624184afe26ddf9dae075c3fb34604d28d1d9d5f", 31189429690264950),
-    ("0x3691a85b2758740a4aaf49068b11757cc3e0b1fc", 7485414158507400),
-    ("0x392365d5954a9b8bce72cc6b55bc206120145220", 64998923052968074),
-    ("0x3eefe776a5e78c03bc73b2c332819d848168579b", 6685977572938143),
-    ("0xd7a8ca3a59d292477d3c1f6d547231c7142d1cf2", 1047994922241907),
-    ("0x2b407a49c00079bfb92401282dac2fa5bc534069", 27980720604926465),
-    ("0x06cbf471bb3204d7b79f03da7e700bd3d2452a47", 22646502746196556),
-    ("0xb42dcf33207a7b283e3890805555364906bae311", 8097769428026342),
-    ("0xf815a566e42b0d8ddd5d77f91409a7d9ceb10b92", 33868185128881814),
-    ("0x8df1b16368e6c81e30a90cdb5093d3e8825b0bc1", 3220062454453028),
-    ("0x2401270543d683c1c428426e6a40e0f1d3168bef", 14372917995789513),
-    ("0x389c46c3ec6106a5053740cebf5796ac9caca8c8", 14821825886924715),
-    ("0x780432eabda6f7db5742149d3915605f9049fe3f", 9797543132306783),
-    ("0xbbb7f087ed09f44476d8429cea9adad894d41455", 3254435928263183),
-    ("0xd41255db215c4a23918a55bd10f7bfcd34191643", 14682932853368853),
-    ("0x77761f1e121ff609554212aded3ca950041f7582", 10494014570707620),
-    ("0x620167d5d83279f6cd0940b04f5d4aced734807c", 7134309989048974),
-    ("0x474c84b08e35662e5460f53c1f91e2597d42aa03", 47751796017378833),
-    ("0x9e42fd37e21c0ed235fa31d2f49a7fbdea961f5c", 45965752799733793),
-    ("0x0da6a4a175795e372378f56db8bcfbb55ce9889e", 4086253445667306),
-    ("0x181ade4c0e35a34d78aadc4c81526d2b3e377f13", 2975577015488764),
-    ("0x3ed1a70913c789d94a6269b51c916ab62f3c15cb", 35598506849245811),
-    ("0x812a5d6b5621f07fb286bc0daeafe975c78619bc", 34459585068171722),
-    ("0x65279c2c209c6bd212d6e2d66ed15397eded2f18", 16162016254179429),
-    ("0x9969181c2d7851fffa212b77557ddcd2a669f6ae", 3910880188724626),
-    ("0x165755cddd70f294f9d5352114ff9bfd0c7377ea", 173782969068720807),
-    ("0x5fbb914225fe6929e119cec400dfa4031780c3ff", 1754782482571194),
-    ("0xf2c36adbcba7aae311d9e98b7b5e1dd183f103f9", 16963363920213279),
-    ("0xd62bd0221346f6d7631c9dbfe978cc88fad1fae0", 3537224188569196),
-    ("0xca99a0d5ab94226fe9e67934792b6b5c900ff37d", 8206128086423251),
-    ("0x958f098518caf0b721cccb07b3dea8591cc6196d", 25247543282365763),
-    ("0x5204717593d144436ab4d5e8570301ce302fbb5d", 40728284430752116),
-    ("0x8b49f2ccb8cf8a23bda84d7ce4a5e36739afcc8a", 16365706489823101),
-    ("0xc89f9c00c559239993845d0cbe91aab4ba7685d3", 792490652115287002),
-    ("0xd30391e21741c54c32987bcfca3d880e6d261cb0", 61250699026440771),
-    ("0x1694d569083a211514b4aaef42c756dc8fd036a9", 19518186872110348),
-    ("0x4aa3ef0c74b522d8c20327f8423252627801eeec", 68754170527801011),
-    ("0xe55c69cfd20cfa25651c72b84383de6104104eb4", 31858189005185392),
-    ("0x0f5c47bdeffeb8bfc5abbf8dde342cf7c1cc1f85", 2650474989882821),
-    ("0x825aa4fdcd2575770335d1b77901cde9b9e97caf", 48814082771178060),
-    ("0x8181d7c3d6fcb9b5cf04bc25b177345f23a75bb7", 16198464598545165),
-    ("0x344ec4de333761a449c1e371468ff671d5aae0e5", 27588607411533209),
-    ("0x9efcaed64763cd402d1a9d4d503374dc55a667f3", 20716604345668933),
-    ("0x0e22bef8343f7e20ffb0948908275969ec62dcdc", 1859830345377512),
-    ("0xa958598d228dd5fe7d4d895e8fc2ba6153fe3e6f", 58022425825730200),
-    ("0xead32ad21f0817023f8e52402805c158e2ef007a", 6008420606265859),
-    ("0xef6ea8ef9b73706b73944c31430673c464205ee9", 11087824258570759),
-    ("0x8aad23bb7e2e22506f5b1519432c5c1f7a1fbf15", 7855122797273220),
-    ("0x530b32f2f84ce97c030c220e61547a191716e329", 12980557654768116),
-    ("0x69dedb7456038123fdf10bcc9b0b64d52424b764", 28219699191822039),
-    ("0xce41185674dec86cf7d309f3f158c507ad8343c6", 4352495272796297),
-    ("0xe9eaf9fe16622a152c8fd3afb7ad0434d8ac11a3", 78169409262215133),
-    ("0x775a477997cd8767481a95d6e89e0e0a3b0eb643", 3100000000000000),
-    ("0xe72d57b1abc49686369abba97b2b8ce4d3095c13", 637721096114764755),
-    ("0x991e0bdc1eaaf232b18523444d59a7cfc491166b", 7735222761677529),
-    ("0xfcb78fa7de003d02765306a3ab878ddcda85975b", 4380010715902581),
-    ("0x0b20b9bd6ea6f9d75c3eb676e703056e1b7538e3", 109851684389099667),
-    ("0xdc9a35cde6ea42d529370ce9da3f42c28711714e", 192715059550505427),
-    ("0x8ca4cdb8af3bdd94b852d77f9c0575150045491b", 18707976142515332),
-    ("0xd0506af4fca9e78360d541bc03cb9b1ab3ab2a59", 9300747254015031),
-    ("0xf7c41ad406295a46cf5c1e6b9232bc3a6440ad4a", 30527608290725617),
-    ("0x011ec937afbd6a0cdcdbce0ec5b1c661cf1cc9ec", 86625019782009150),
-    ("0x7a3887607f0d115e195f30b69b3720053ef5636d", 29429689999593426),
-    ("0x881cd09e56d4cce5c26cbc680d8384acce985c6d", 6805263737288391),
-    ("0x55677a29012a21c8b3b24a62ae0aee9d1f039590", 7443794619476540),
-    ("0x8a747a19b9b41a3738d62a966c511389900ed06a", 38908010770000000),
-    ("0xa373a5e1b69a0155059791d7e23e662ac977201c", 48523339795259158),
-    ("0x4b15c572720263769be1b192ec83c910a3886bf7", 3154190486386904),
-    ("0x97ac47063ed1490578ef6452c17e0d22ac737f82", 9970915126506464),
-    ("0xccbeb5370d9ca93a13c80cabf8dbcc9ecee2947a", 11605114163212671),
-    ("0x8b9e965109c522471f27c45f1355d5edfd89177e", 13244565741259708),
-    ("0x09c2c71734166e2ef5bde16dd02c5d224d88c505", 27565921025394995),
-    ("0xc07059d489ca3213615420bc23d17d3ab72b1c6c", 10854911651282293),
-    ("0xff812cc30e130634fa8027e31810c8ba43b50f47", 2193037861951036),
-    ("0x4dbc24beb46fc22cd2322a9ff9e5a99ce0f0c3eb", 2638878454855556),
-    ("0x045f6357db854fc2705defabedada3ae5fff194f", 31172071649417651),
-    ("0xe1eab77cf7420b886d7992738f6a2a92c9ed3cb6", 1325780429306458),
-    ("0xf7463ad9dbf2d12ecf820574d1d7918b721e462a", 50484008809535581),
-    ("0x4ff344c8628990da38fc45ed1e5447f92d234f22", 6076787077451777),
-    ("0xa91c173412015bb9742f8c84196558765231cbe8", 82866045315203760),
-    ("0x6f454fa5c8c9dc56209f6f5d4c7df32c735c4946", 101063665779320751),
-    ("0x44a03946c8e690c6ecdb254b3744690a42e1ed17", 5241506537469531),
-    ("0xb38c57a70625a070eafe4628905de36d2ffdf7d1", 1257543455869120),
-    ("0x344f9f6441c425cf6e6469e72777d35b48a91062", 123070856440090110),
-    ("0x6bd6c0e39c8c30fa7732a7c58480c73c9314783d", 19938723238300743),
-    ("0x04c4706f1feb63c0a1f84ddda17a344defa4f858", 82831901867483094),
-    ("0xa126998292e3183dd6712914678262e799636348", 20680192441343505),
-    ("0x9224e1892fc4062f8604163b6d6a6924b8564c35", 8039325975394064),
-    ("0xbf17fe365b6a160b48d8b2ba76f8a55becf4c7c1", 49948333177223551),
-    ("0x866a396705e325b683bbed2d424ffe3e37c1a0a6", 4637416326737437),
-    ("0x8e8ed9aa7c01c4d98089906b39a1fc0918ba34f5", 20354219676528089),
-    ("0x967db9dd888ffe87480ce68d4b33301126fc783b", 34455262262731393),
-    ("0x0649f30c95c27a6b76db30806aa2762aa924b4fe", 9475692664556808),
-    ("0x5298f2941f0a5f5010deca60f52990f0b5a3de0a", 5882938979003479),
-    ("0x12332fe522dbd07bfc2cc65f7766945d9eb5fd34", 156536273738027096),
-    ("0xddf767f258adf0af89896621349cadcf8722f771", 200488741332063686),
-    ("0xd57722111412bf8fd260827426202a04de47899f", 49033418784005321),
-    ("0xaddff81c9b0b9c3a918d215eb3bbdae80676528c", 8965794772404056),
-    ("0xf527726378d7e10e1e322d1e22a06a8263d64544", 49188693184041970),
-    ("0xbc641f6c6957096857358cc70df3623715a2ae45", 28567727837836084),
-    ("0xa24854658a993e133c1220a19d27d43445acddd7", 10768032354813419),
-    ("0x55f6823de9642f47e80ed4845a55aa8430cb4ec6", 2948497619741916471),
-    ("0x8b36e7e37be6e10f7a24b0000b0602b575fbb54f", 14536077683775593),
-    ("0x096c2b6ee3588247db23258dec29ed72c66ff231", 24464511489663698),
-    ("0x739de9033d1db578f3ca960cc15025e8972aa8b3", 16918313355256671),
-    ("0x742d371a686a20ed116346e7315aee73515eadca", 8189245246197117),
-    ("0x0ae3d4c995ba20e6a26176dae9c483070bc8296c", 24094890094783646),
-    ("0x4a90f5add15147188149a5727cf39d7f71768559", 27579678397376996),
-    ("0xec09f7884de51e7d7620eb02c5a5d8d912db51f5", 11387642294723980),
-    ("0xa9ee9b9620556054d5e400d0c12ba6393efbc05e", 2950177202694603),
-    ("0xa788c59880f31d6a9c3511a8064286682b01756b", 135551681726673875),
-    ("0x8ecac3596e5af81f4dd8811f67612560252bc1ce", 134915617258444849),
-    ("0xf1d6f1170f7c43ec61f0afac89a17c71618594c7", 127950026463285318),
-    ("0x54f3f0632e179449bb3cdd1d0e09b3ebaf16d22d", 29373138420518968),
-    ("0xf47abeac7b251609d78e176975d800a6154af9b4", 51941350178372432),
-    ("0x367bc1a9d1ef225307666e330e127eaf19b2160a", 19001784282744509),
-    ("0x93cd4c16c5463c0d1fa0de0817666de985f3312e", 33708747848221936),
-    ("0x92917666b0ffbd39d4ce4fcf2df1c6c4808cfdb6", 31386850131977169),
-    ("0x28e451413bcc9d5a1611f94760c1512cdd84bfe8", 14786477488378991),
-    ("0x17001ab05c4a1bb98d84cdd15058b27cb454bbf4", 13803187729304841),
-    ("0x8c8482c59227fe37ab065ad8cd882e811398eadd", 19998386156812972),
-    ("0x6719959b0e01bb3de88571adafe640c02694f147", 13748676878748627),
-    ("0x435758baa74aa98d2199d3d8c34c80e4342bebdf", 15864665987094300),
-    ("0xfa4b85fedafc0120e39645ee57a50604f60b4140", 8313053299768258),
-    ("0xa67a20dfc73c86f6ba5f4438d8914e3f6780d397", 5363042843660383),
-    ("0x7bfbc6b0af3625203b4f404e52b713892cf0b42c", 6560584354764785),
-    ("0xa8bc4aa37edf027afc3ac29bb85106cec2ce9c8d", 22418057629027602),
-    ("0x96bcf741c8784f0523558a761438043e21bf5edf", 49393936635266242),
-    ("0x707e4a05b3ddc2049387bf7b7cfe82d6f09e986e", 12551275569081684),
-    ("0x34a7df36e5a098dea126072661fe09958f72f8d5", 13440527614990670),
-    ("0x996439f5e170e4ec4ebd398201f68d2e6396fb20", 4446670754860594),
-    ("0xd4c5fe9c18a3e9272702a35dd4da5f0cb7ccc62e", 151349049323750378),
-    ("0x949e43fc153b4b6b04c270594bf864ecd25d8c36", 15756467079729288),
-    ("0x3822e09aa579c509cf8dece53de60fbce41309ee", 45123174342735895),
-    ("0x562a6c4316478008ca76f8f7c4b364e9b4e54aeb", 16007810998815897),
-    ("0x556789a19e324f7b385595bf0016c2f5a041fe0f", 17450039607037492),
-    ("0x7a786bc576ba63fc5bf248b87763507884a83a0b", 31217397870542596),
-    ("0x078d011fe3182515cf3367426c59297e59469703", 27592386370729599),
-    ("0xefd5bc0e8448b5c786059e58fb6940c0df6462c0", 13824727889159455),
-    ("0xca3866beb6320301942773520d830241ab543762", 27561964654808239),
-    ("0xdf7495fc2eaf599a8105044d480ad5c97488a5f1", 16262856936020582),
-    ("0xf154f283691a3dd15a396f0e090585e85296e171", 18023911740599035),
-    ("0xcbd79b90a9b6a09b22d463494a474062f47cc4cf", 56302527681592444),
-    ("0x6516f67345e46f2c1a69d0ae8c8dcb8f245d5dae", 8982584650376129),
-    ("0x4202c1cfbdf5efb873f5fdbad4c9cdf45b76cc75", 139720400389215828),
-    ("0xf596f9420234ad8ad4486518113680fbf4054420", 12854349137609542),
-    ("0x945813970115558e0fba48330f7adb768ccfd556", 95787174932243188),
-    ("0x4613eeaa10746596db2043046d47b52f00604cf4", 44026466122663465),
-    ("0x8d9336f81fcd1c3512ec33ec67720acc97d09b53", 16947274988329406),
-    ("0x931d722cdf1d25b55d72655eea7e34667918cc40", 93221411401568500),
-    ("0x32e8e8b95e21ab7993214db968d14ffb9304febc", 16040088072233765),
-    ("0xae1e1f5a074f462596004fed5624b8f4c40cd18d", 8274397340245412),
-    ("0x00a1b46319be9163b8ae30dbe506235608a563dc", 325685207692315933),
-    ("0x04e1674a6bd75385efeaf0958f2346ff8debce6a", 174437790237419173),
-    ("0xdc4221be9dcd88fa62c2212af1bfb92e625aa2ce", 80584663190254583),
-    ("0x22797541d421c3c061b4e6474e84159363e75ee8", 9717850738946370),
-    ("0xe9473bcb6a38f54a0683707b88f26f88ac043bbe", 8570983061068977),
-    ("0x875736d184d1ffdad2bff96be0b7d6fab5973736", 4985291393210442),
-    ("0x782165cd7b35fe08ccd5e6140c49022185d77fd3", 5363705222821861),
-    ("0x8964335938d19fbf6f7d3989acb80f59f59f59d3", 5935344336167808),
-    ("0x64987e6ce47a887659461073e0893ad4ad7819c2", 3991688926261068),
-    ("0xbc99e7262738efc74c88769fc893f34cccf98b47", 19479776166146851),
-    ("0xc6521481b7db3a707ac846a0e6fff68e3d643c4f", 17761840463275247),
-    ("0x7a381bd661e5442f10de73700dcac29be4077fae", 7980702992241134),
-    ("0xa4c6db0a39db92b006bfb7b96617e960d80c4d22", 4613498965481606),
-    ("0xff1af0a53e270d9b2a5b206aa08edaeae58d3387", 4096450573250939),
-    ("0xc2a67b844c8f7743535a033407150e390c08abff", 5280669472639660),
-    ("0x92f15cbce433fdca0a87fd778e5bbbd0b3d7ee0f", 23810528688356565),
-    ("0x1ac7b8e0f4aaf3c13d9e5dc7ee7669defc37580d", 157078641213713035),
-    ("0xd3aecf9e0856822bd320873e905ae9f78a2977e7", 5947518101129138),
-    ("0xdc3002c1455d7241898facc4c522e14ba56d27a3", 1155291359680722021),
-    ("0x6c348388696571f7c7fc35346104a6ea587ef71a", 1745179162106691),
-    ("0x3c4a2de0412b409e62bdbe2cf3147e4916d084e7", 13410059037560212),
-    ("0xa7b3b7ce980b245dbf7a0c799da11d24cf3e579a", 11579880379437279),
-    ("0x2412b5303fc318994ddecf3a32b76a3f7747f8e9", 16650789815675270),
-    ("0x765eb49301d502cb077b677f42e0cdc8f9537274", 41490559581148687),
-    ("0xd2bade5f4ece4814f77b5dcb218fe273c4e223d9", 14678500691716971),
-    ("0x4c41426370b0f3091606101bdf619991f0a4c51b", 10863620550264185),
-    ("0xdcdb8ccbde02e50b292b283c1bbf12272861758e", 16147302669871586),
-    ("0xfa55de00c27cd62683aa34574e9dbe4fd332fa15", 10193629760853374),
-    ("0x61c92cd5bb35acfc45e12625c48e15f7970efc86", 13205212881186768),
-    ("0x4d8cf13aa418e969c63870c5b7bfe38f91a1f87d", 40101688197574698),
-    ("0x49e85648494fe05ff50b696a291cec9fd05401c1", 2516426924567583),
-    ("0x3288338837dc90afbb81a93e5a9c3c5688436e10", 16846721806873894),
-    ("0x61e0841d103d77325e7743d1ff7117efe7c2c9f6", 37517687664560638),
-    ("0x8f8a0f11640e63b12acc1317f3271c71631fbbe1", 6759397771372564),
-    ("0xc9b30b13f82ebc646e85ac9e005a73b93c9f6f6c", 19314735735348143),
-    ("0x226993746f13b2af6417bc2edf70f96c8f2c379a", 17761703421997203),
-    ("0x3b9b62d8a31ab5263c3cb78919f9594a730e2576", 6386916938612335),
-    ("0x978c64e04c0e4b18d0b998d75ba237ff5f9c177a", 2532692018605661),
-    ("0xe9061db349e0925084e99c02769a119736552ba7", 6178386823485413),
-    ("0x359453245edbe33d7f7cbf5723a681d6557f1c1b", 16130739771933489),
-    ("0x0957e08cdbbc0e99ad1b2874a88af2133b5a4d42", 812475798889887775),
-    ("0xd1222845bcea727c38808d685426ec1ecaaf48b2", 7161639928610866),
-    ("0x0b5d1a5a58ca0586cd33050dbc51927a25f32081", 202018342346459713),
-    ("0xa2ef23f731d07f318d3f4613737278327565568c", 5155367443453920),
-    ("0x84a8bfb33a2657ff62e9872305a2cd4833ab8820", 32146984588326176),
-    ("0x82de8dfae77b2601d7b3f95864a01e9ec50bf42c", 3802923459167281),
-    ("0xe531cb0eff850d43fad34642bf8b7828994767e3", 12293701757519984),
-    ("0xdd49a4386831edf87e3e34ee6baee4ffd0167f80", 27723622098765073),
-    ("0x4aec3f18c8ee06fab59f129fe253a83e79534921", 34198865339072073),
-    ("0x10f24fbc2a4addf445adf143beb5e4236319f50b", 14258142207223015),
-    ("0x2585c961df82972196023a0bd4404b66b2fc72de", 13147992117483386),
-    ("0xa0f0809cd5c8ceada1ed79bfe8141b5b164c02d7", 10065140115666507),
-    ("0xc04be3860e35d34d12995f36d41306fefdf63455", 14461752443314070),
-    ("0x6a0709f934304d64dda6c207326f7a401be9ee9a", 10208000000000000),
-    ("0x31446adc6b6f116a8361c14d8a60109878fa39cb", 8877046985417091),
-    ("0xad8d4346aaac2efec4d08f5d2ded139dc9591115", 12014738072982329),
-    ("0xe7a480f147004cc112b9761691e0f22c3ce243a9", 512753445871258933),
-    ("0xbf32356c1ea7a568fdd94bc2265bd046a651ed41", 53527917337338430),
-    ("0x4d16bd2770e94528febf85d2ec9d54902f199508", 1952525879424371),
-    ("0x3db9ba4395c9a4412c3b0adc9464db5f664d910d", 24180097132979355),
-    ("0x2eb15511994346520f18316fd115a4e17f061501", 3206910472071277),
-    ("0x365c9173e25bc7cfd1525e7383142c6bf63e8532", 41111880366034325),
-    ("0x53b653348d15effb54e8cfbf53d2987b711b331b", 2478571739590742),
-    ("0xf8ef2f73de4d254cab7d37e4e3f63d9919a85cc5", 174706649453550011),
-    ("0x3e9c2ee838072b370567efc2df27602d776b341c", 5128657448157966),
-    ("0x6f7bf13e057305d04abd72e637e491cc30569f43", 24088095222131639),
-    ("0xcbd082b658deb44b6793c50fae897688496533e4", 16176773810254101),
-    ("0xf1785ebcd3b403505065ad46a946def169d30aed", 8708540124150158),
-    ("0x6ed5c6ab89b0fbd92cc4a81ae63623aa83bec7e5", 8320128891922064),
-    ("0x648be78112f11f9a3d0afcf304d963b3b962bf56", 101725433063958899),
-    ("0x3941a2ef69c116a505646109dbb918d665c4c58d", 22303628447229902),
-    ("0x0e7a887ee74486d3ebfae647a9adfb3ed5270e29", 16478689380278238),
-    ("0x954cd09cf2868d8b1154adfd65c054706e5d5847", 16745607944795736),
-    ("0x45b4dd0d46f49a08a4d1941c473efa3c727e6dc7", 20213756101945845),
-    ("0xcca08e9c4d454751069db63d2b6aad22c48f55d4", 14279185030809944),
-    ("0x850bec9166fe87528064648d54568b9a13cc7fb8", 4883703285984816),
-    ("0x6695f50eada6069c8494d65654e52f4c8e43b0bf", 163151882514554162),
-    ("0xce7ce61413a1d7be3ea83af7fbc59662edb6aefe", 11017746370900177),
-    ("0x08a17ceea329b1e97dc0bad32f8dd44c46ffd423", 15733642053408466),
-    ("0x10d06df6dc1e8a9166b10522c0e8ba86965ec57c", 160797905107474838),
-    ("0xabfa659012b816c620cdddf70cb262ca9f29cbe8", 223647686130049196),
-    ("0x570eeb522cfd8064ac13d39a8231ce5803c4e28e", 15818863503959147),
-    ("0x9d9bb109d2fd190ce0dca0c445c881b18f2c7a27", 48914380718010531),
-    ("0x18f768455e7f5fb09fc491fd86bcc282bcdd5973", 79653117891477984),
-    ("0x78fd37f7653bd8f5d7c13978333dcd868f8804cf", 9222770169310843),
-    ("0x9c20f1319f36019963057b8c3e5ad388cf42a9a0", 15964044185015293),
-    ("0x994067c8fe8c84548c4a0c8838afa905d339851a", 42525902497373798),
-    ("0x7f87b62d12e54082bffc61a52f194632fd7fb7b3", 60916449164074939),
-    ("0xaa391b954f9b201d12e316db2f979cd7c460d0f0", 30141212728037083),
-    ("0x9a64a1302a5171ed4863c4c3962745e9deb0a900", 45207783478573855),
-    ("0x046c8e2dcc43ca92d1a1b11f23bb0fb99fc9df14", 24394232746924792),
-    ("0xd2174c91f66f5e949fa54b05f8a056ffc745c9db", 32529854813664724),
-    ("0xecfeecaf65a2494f241060a2c22815c99d5ffae5", 16405446834915256),
-    ("0x35d2eaf6f3a3780717a61f56d30102e929fc4362", 21408606696369116),
-    ("0x3433c17a157ea1eda6a5f286dee99bd1ca4ff091", 1239580695696767),
-    ("0x28b5800be3e6688f244fe2eaf6fbc298f8908c01", 39246803162213673),
-    ("0x966f6fe682c3d7d6fde575f77d27232beb871bcf", 8491977994025133),
-    ("0x00db9c054da9e66e34810d274f3319c217ef871f", 85190985269277467),
-    ("0xbf4ba0539634b1d9fa73676e87bbee674ea8ecc7", 24653786863026472),
-    ("0xa37f735256c8247c18415cd67da71dd10888e392", 8096700921866238),
-    ("0x902dce77c84e6e781a577cc93e03084be6e61cf9", 7885000000000000),
-    ("0xd2d184d29b8c8e646bd9a3570a7c6b0e6d88d701", 50355433684459839),
-    ("0xced29ba48490c51e4348e654c313ac97762beccc", 13098257018662925),
-    ("0xfdd4186383fafd6bd41d0c59ed84708dfde004ff", 2648940730650796),
-    ("0xdfbeea9ca56c75bba42b9bafad767689b9135c56", 16215913857560371),
-    ("0xf8aa34d345500fff8b52367ef3a534945a65d5cd", 56460218988425016),
-    ("0xd66aef419e23d5e67e059896befca58bd57859b6", 1607659615786399),
-    ("0x6e87b9580d7407644311d11e9b3914689ec6e5bb", 18216632353764725),
-    ("0xd7ae7f0a87780c5cf2962a6a878e0f557f56e0ba", 30978652749323909),
-    ("0x1dabd98e0047ef2ec1f3983b77910762d01051c0", 14507452838451718),
-    ("0x92c7c13672e4d83ee53d07fb651a46bc1951e6c7", 3860130761144304),
-    ("0x40da9490cf493685a42356ebcb7aa7bb40deea18", 15735940937996618),
-    ("0x01053597869b519460a2ac9bd30de44d23ba2b38", 3563882359638497),
-    ("0x0093e5f2a850268c0ca3093c7ea53731296487eb", 622958099062226150),
-    ("0x03168f12ac9b5f4c1c8eab557f5617643cdc63e3", 27272623627678907),
-    ("0xe83c766d06099a3bac2bcc815d839b095665b244", 15723489607533876),
-    ("0xb39c00f0ad385812d9053337541dd6eab58e29af", 2556711076235801038),
-    ("0xef528a38e3f80c2f643eb29e0bf57a45fbb610c0", 1358663473640000),
-    ("0x8d8e40a25bfb24aa4d89643d0f220ef13cac6526", 17056680057075015),
-    ("0xdb1d5d5acdd6a2233610884715440c92cb0e85ca", 9463000000000000),
-    ("0x8da4e44498727a66163febb5b28063a630966a87", 7446501103158532),
-    ("0x3893df6745fa8364886b4769fe461c672e4d6bd3", 27765812779873602),
-    ("0x2e7567661eeb4ee1ddd6a694d214060c43d849cc", 3872246000873439),
-    ("0xdf0ee2099705bf218be09db0f786ca1b8cb54a94", 4072576165371382),
-    ("0x4f62e05540daa84417e49e7b06be2c48482d909e", 14594038266341698),
-    ("0xdc67d5b1ae434e5754a3b6309f50c482adecca0a", 5229829448864968),
-    ("0x2484bdeb7a6f29def89a631379d528b941ce6794", 1369367073251034),
-    ("0xbcdc432e0b63dadd48693047f5381abc488989c0", 3154000000000000),
-    ("0x427e4ef7ec9bdec63087fb0c023c115319cb427f", 9722060721247164),
-    ("0x4fe686b423de03d88c96e93c90fafa9567a010da", 20176215307197952),
-    ("0x32e49dda638696b15ea3199dbc7441d18431be46", 32387232533733035),
-    ("0xadaceff1e46c01588a763fcf933f5858a076030b", 3963839968722421),
-    ("0xdde39a0150a5fb4a92cf2936d5c5428687335a15", 3726059645250792),
-    ("0x7e6f711e46aa8a02980831b7efac0fac5a5317dd", 50405345288785739),
-    ("0xaac260d3600418e4cb03fed1e01f8aef49e08b94", 329561487623058121),
-    ("0x15510a9e0cfc01b620f816d398fb9289f359f2bd", 3606280658546964),
-    ("0x8e33c8a86a5146fb7657192c00df6e50c7316072", 1962360102418531),
-    ("0xdb21e1b8a67bbd0a25588005ad0a1d2714ba4fdf", 107639689307657908),
-    ("0x15a9db352f88464e435f72ce575bfefb313828f0", 7740650462914352),
-    ("0x6744f8aacfd3886685156e15d6a91bee9d70ea4e", 57189794597112303),
-    ("0x1a6c5fb4e3850ae7e0a9e78b45d6cc5d71710bd1", 10000000000000000),
-    ("0x30086c39930c0708b83e2c31ee080505d843ac8c", 17000000000000000),
-    ("0xdfea78f8f63f81517ebac75ce20c0102593f8a6f", 20610055718767853),
-    ("0x3f74f5f20a133b4e8fe8f7b3ef76cfe6e3269f13", 10000000000000000),
-    ("0x2a07ce9aa0a3ab2674fe08e08e65a60502300c4d", 46304379727341196),
-    ("0x36a36cbf4d14d820385b1e6c160633319dacb546", 8231340000000000),
-    ("0xd8d10abf300356c7915c8d5db33f0fbdcfd603c7", 8242880000000000),
-    ("0x8c898bdd72eb377f7c17497e6bdfcf6e50ba799c", 16536910000000000),
-    ("0xcf8329bf7738d00bb3bf752458f5b6752f6bd235", 23057691395958933),
-    ("0x91e16d64069d795c07ebcfbd02af9f2a896d945c", 324896923355427319),
-    ("0xefb5ee1ca5bcdbaad95020b7c1877a7db39382fe", 16495670000000000),
-    ("0x959ac217c8bbd4650b1eba5b927f329e37ffeaa9", 21833925853550348),
-    ("0xfe202706e36f31afbaf4b4543c2a8bba4ddb2dee", 1254825835887563067),
-    ("0x1d0c47e61d3d3a755f841943852a472309dff6ee", 80135871362032166),
-    ("0xf196a5f93e9902eedb6df7754347d4e55326bbe9", 48720914073552243),
-    ("0xed96eaa7d15951ab128abf8918a4d69f0eef9905", 8660647095670065),
-    ("0xab3454f4198940432f89872023ebbd64495293b0", 19728177746268442),
-    ("0xbbdaf6743d64f8a94dad7a3be733d54794ccc730", 7361013540326820),
-    ("0x214f029e872a59cfc223b5cc5959c96fd53cf4ba", 16613564842194588),
-    ("0x07037fb5c7b83d18464500d6d03992c633077cea", 16660340581855409),
-    ("0xe8bb7407a2a675f2a5a40e39b09c872360f3e571", 25481377472118818),
-    ("0x318a11fb434333de60b3585428cc888ea1e22c67", 3946535386392235),
-    ("0xc79d919df128d9a708b0362a7feb429b3bb0ec37", 35003487509919936),
-    ("0x61f9d3ba171f20a1d3ab4ec35d19427ab60ab49a", 11423037958844653),
-    ("0xdbb2481bba6a9e03b7b5e125dd8d9f471f83f44f", 28531301213336882),
-    ("0xa7d22bd87bf819c4597c2d6cd18f52c0c4a489ee", 31338140069233537),
-    ("0x46fa034716531f54a1bd5fb9cd9242adb27fd37c", 5342331858235931),
-    ("0x31d22271f26ab965f95c19f954dcf4134bfd607c", 16309445387398673),
-    ("0x859b35e32e45c54d5cf8e64acd6ee0e529578c33", 16910139235416407),
-    ("0x4129b4337d5a2f7278a02ff15bb1e4e548d8ee60", 229435403995963778),
-    ("0x0517c32e3f3a727100278abe8421cd1208018cf8", 8328513832828053),
-    ("0x0892f8fa07086b8b326cc50d6987414b59a921e0", 28946278079710292),
-    ("0x003b2597112328adaab0d1dfa26ebf352ca2860c", 28393358077326225),
-    ("0x7417fed8a599b7272db01850b2d2c44cdc7d3e88", 4225917434544352),
-    ("0x19c7951f1d679b8d9547e1f4c30502d7072a72c5", 125840873282048533),
-    ("0x4371e619e9a4a15600a09b1e17a13f0699c78838", 9078511752470924),
-    ("0x60d94f2c48e212f1a35e1fc7ed28e6b2cc630691", 9636302755537810),
-    ("0xf6f26015f0760f7a4b7f93e765bc48883daf79a5", 407217580142514541),
-    ("0x637f73d1021c5dcc42a0d4d64192692706582127", 13485388951053141),
-    ("0x174b8dbe56ea91e2a448c1cd55a7d96a9fd45c5d", 237525472235480462),
-    ("0x200c233de55c1e2ab271edb87e5abce150d637a0", 130632334413659633),
-    ("0xc9b390c17fac328f9dcd35cfb786304b446c8500", 10820353513173172),
-    ("0x4f5c500029e772efeb07e2041f1369d8e9600820", 57924085782388407),
-    ("0x473e370fe873c82cab9038b6b8ada27391782c24", 201532873707157081),
-    ("0x39713aa1558c48dd795b53122c8daf4489dd4475", 21048962219092418),
-    ("0x016b6e1a9d05ac99b8f7bb5680a20e06489d43c1", 28435284432498979),
-    ("0x77fb402baeb27d6a5ba82e636dcbe045b3865708", 6237718676089827),
-    ("0x424c0921a76e8afecb0956ed4bb0dfb945b30b69", 129484321794390265),
-    ("0x362738d7fd4481cc57e744a2b1c3ef2a772b8016", 5240202119492078),
-    ("0xd5f6d074c4a2a14df7fa8b06925f50bf447e561c", 7182500000000000),
-    ("0xaa987b74093a631049f4734b63d34e188c27e662", 49095437045620194),
-    ("0xc82fe0236e0b66e0fa1ece288e4276f9b0bb0952", 16588824254218678),
-    ("0xc23aa2b67b97230e169210b7af23b22e3b8d97ce", 11616169275291371),
-    ("0x556bc0428d14474bfd6ea346a66e7d9bc1857945", 16602427920120845),
-    ("0xfe69fb531698b534a2bc895940c5d37876b04db3", 16732417304571186),
-    ("0x4036c440e7ac10589f517e03339ac50f930218f8", 19438141869603064),
-    ("0xe83f16235faa296e53402296517eeacfa479fdb0", 2244149446740693),
-    ("0x1d7116e7839cbb1305861e98b06faa4100a34fea", 13535663944805074),
-    ("0xdcb7bf063d73fa67c987f459d885b3df86061548", 2482948738325214),
-    ("0x80440c2b29d490fae0b8e4ab09e6bc8492a285be", 8292522025835294),
-    ("0xea89139d7a424a46014d4a3a51504b4c93811888", 1786668417341197),
-    ("0x820b45a13c455bec7ef1c287ce77c474343214ff", 16234919082582877),
-    ("0x54b7f8fe8d3aaeeb115d2ae1ac9ea5d6b824e2dc", 9755003322173792),
-    ("0x08b89d9dfda473b1209f0f5e31a365fef8a25365", 21251791103125772),
-    ("0x3f124fdbf6429ba171080c8683db5ab10e818bda", 7885000000000000),
-    ("0x68320b59d83b9fe7186ed6850ffcecebb9f3efec", 14579892679018522),
-    ("0x167e3cf750db30be0bcea3f462d221dc8213019f", 111921476530158543),
-    ("0xd0abe36e36f7aadf2d0642e4dc868e0a679eb2b7", 51276691097118763),
-    ("0x413f9f014f62c4ad3a44bd8812d1990b2ce13fa2", 11725090630065388),
-    ("0x0024ec56c9ae9279ad4458e3d0dffbe2afd5f4ae", 15712239095719243),
-    ("0x644c23a5933e99c8e07cc392ce9e142afd808ccb", 12742372780275126),
-    ("0x20f965fcf307ac1edebb89bf16c319fa4a29eb5f", 26389738232457674),
-    ("0x3bdbc4e816d873d893909a9a3fe3759195ebe393", 17397747194197684),
-    ("0xdb0ed2cef8c83d6d7974855bfb33d744b74ace8c", 25618223505973789),
-    ("0x7e5399b7a9b6c29870e4cb919d407ac2d4cfd1bf", 16315131402830040),
-    ("0xfc1199a5a915a162542ca155d432955151202f0c", 49239942755416578),
-    ("0x49ab4c577dbe72f58e9357948a6858a65d208aaa", 16415086028807938),
-    ("0x9e2cecaa997354b01888cf2f43946ae446bc1c34", 191418821564213386),
-    ("0xf601ec0cf1320f49a653e9cd62ce51a66fe1b77e", 1516355007974243),
-    ("0xdca4acb26334bcb24b35a634bf7b81cfe6d03109", 16537404663224837),
-    ("0x267366675978218816c53804e4a0c1b13cc089ab", 80495696441078761),
-    ("0x93ec9a7e759b05a3c581902d53fc5a8c6a792650", 7100009055021298),
-    ("0x094363100ff589b142ee74c8c90340c388edecfa", 158790701931430086),
-    ("0x276720680d749cfc956fa7e791b4fc86e042ecfd", 96788424630829424),
-    ("0xa056f7cfe978ddceb9b4189ca80a354e1dd0fd54", 2430448796327991),
-    ("0x75db1343f179e8aa09b7ceaecc40a1baa8f49fc2", 8803116649371914),
-    ("0x1bb6b58d409e1dc16858bace727ba8d07526475f", 70319234605135421),
-    ("0x9538b89ebfd04a97113a2773656b844e367b1c7d", 57917768030536952),
-    ("0xe3d809058c84fd0decedb0a334124f2bd9201c15", 5882971902658071),
-    ("0xe5bc0291f77a4fc56f96cf3fd6494ffb1cbf2014", 126408951737463306),
-    ("0x1d6861d1915fec9bc26f7aa1a705a92e20ad7193", 4268905720583236),
-    ("0xe5ffb254312cdf584c2f4c29a786e71fcaf46d53", 10592140887009919),
-    ("0x8f2d02c7fa2af2d98e7df90b37b436056a2c3cfc", 80883604890376750),
-    ("0xedee3518c7559b6b0ffc2fbc8c113055aef5d2dc", 28405595127183310),
-    ("0x7a64d3372ea2c7d9ee8f691cd56881fa28353d9c", 14000000000000000),
-    ("0xfd74c809bcb407c2ef7dd38baabd793918425923", 16382258528818184),
-    ("0x459e978d5af10211181c5d8e75193170ea1ab89d", 2173740375985820),
-    ("0x5141aadfa0021b617b387310df717dcf9abb0fb8", 6147530681488249),
-    ("0xbce0d1bec3b8da0962382e4da1cbd8bb3a000e28", 111751973889636401),
-    ("0xa40d518c421f55f866034554f8ea70cfcf44e7ce", 15931549404275603),
-    ("0xaadc98efd5bc1bbe532e679336b195a28ec1aca2", 69502436578192599),
-    ("0x7dfc616f887218c7df9895126df3e527ccbf9282", 58397404956293048),
-    ("0xa89247b5e264fa73b9cdc3f624c0494b64534c59", 34164248404689633),
-    ("0x87f4c56fe8ce7ce613596ed6e150b5716b4c102a", 9755723648763617),
-    ("0x6554453eda389db9eb84637fdc6c634dbb752c44", 18105726730144418),
-    ("0xaf9fbcd63b1badb9101cbb49be04713f09f15dec", 84590829083197684),
-    ("0x12bf116b4866ebf559c391a78ece0ff44f06073f", 5312343340346540),
-    ("0xe740a2e455e276e709e6206f4c819d782fb25a29", 51071037717812503),
-    ("0x08ebc24acf55520a8567d179cd8aedb429a5159d", 82764726714234456),
-    ("0x5aeb7a8fc9d56f8cfd98078adc0b4923dcac3b56", 4360243096152966),
-    ("0xfe73ee2acbbe1b9bc4f5ee6b322ed5b7b7bacf8a", 16748420677273291),
-    ("0x8ba2c419e63f57e4cc3c9f92625824af8356803a", 8646952008310141),
-    ("0xbfbeb53513e995137afa2848de8a99c0e47ea0e4", 3573357743840851),
-    ("0xaa0a640938dc8b80e99958bc3dcd730142c47318", 102247032351260621),
-    ("0xe19a1468964f564de70f49c42e4964cc33e3ac53", 17713854213638377),
-    ("0x49373c9b31644bae4bb62c13e5fb8ba237b4256b", 11061713128546124),
-    ("0x1d3fd985f710584f606bc29bfb1e3bdb7ed82de6", 7908473238991998),
-    ("0xc550fffca4523f06341d5d11a02f9e7dc8cc3ab1", 10502446171338812),
-    ("0xa698245a5a828decb780594c1d5ee24c8b467214", 8472212756179333),
-    ("0x3a5b97c5a85a60dd32501f8054de57e84128dde4", 49816834763930177),
-    ("0x39f90a849fdb412c03c762be37e96557848b162a", 8616831440240917),
-    ("0x205b5a3cc04bb25f5d07b22b8a1a4575704f6af6", 16424006561519163),
-    ("0x0cc73c95f50fbe01f6afeac47810663433f5766d", 281780012568321718),
-    ("0xfe9c1de5e9a01766d5c9ce53c600db867c67f11e", 2913792161928376),
-    ("0x96ac830238f4909da346ddc9e1dbdc2bf8041b31", 13404525795425251),
-    ("0x4fecc48f1a3e992ac4963bad15a3abe08a14ac59", 142792237844146037),
-    ("0x5e927d6d547254dcfab48c18b2314757ab931150", 6087823585156796),
-    ("0xe55d5228f9cc5b5740a56923a57c456b177ddd51", 644334705707262),
-    ("0x35b9d617c218ff3a067094de99c93b22f63d04b6", 706082812076041),
-    ("0xaa809aa3ea7304d596181c762410c3c94a889cf1", 706081602466470),
-    ("0x684371f2eb461553b4664817c4b1fb191569b6e4", 719080249857053),
-    ("0x588b3592a300f9554915e51d002176e543e53923", 9199323132044738),
-    ("0xbd02378ef2798f3c087711edf20a1cf8e9eac3ca", 719047348079046),
-    ("0x24731ad877f183fbf7a401f8c8613c2a60039be6", 706046281640487),
-    ("0xcd2a25309a1cad20854051c34c7e1e0d4f7b8c99", 719044929197543),
-    ("0xf45e70de588969921f9f7a9cefd9a7e30cf7aa1f", 672569231007955),
-    ("0xf3f6d0aee59d93945119097696ec7b838d696416", 670293046607319),
-    ("0x10fad0e01a5c0e34f70d4a6c44fe8dbd3710f4c3", 670291837186092),
-    ("0x48394f944a130a86aa303f5c913edeec041a0a72", 8046919245448076),
-    ("0x2196c31cff0a216a4bb0aac69832b3d575c58ba5", 644263139741943),
-    ("0xe6562df2147cb241f80a5ac8e9a711ba75345f35", 644261930463166),
-    ("0x751261004fc7706b828df3b4e60efa10797cf45b", 719009894169015),
-    ("0xc5a6c7f042c680e3eedd0658ea70d67a4b0e6a03", 644259511922190),
-    ("0xd77f94f246b2817242872d225a345c4c70d09d09", 3478335074748843),
-    ("0x919f9325dcaf9896111444d11080de645d68db73", 644246573114279),
-    ("0x77c60650199d07ca6b3a90ab90aebbf75122f0cf", 644245363913270),
-    ("0xffa7fa47a24c49c03bb97fc253daa621b6c94d13", 644244149976208),
-    ("0xb69c2b724d774ba45295aa1bed29a976150218eb", 34789934878258842),
-    ("0x825483c3e50568dc4722627e6d390c2061e2eb52", 16497200365162353),
-    ("0x72eba2ba9008ae8c3868c35a02e72140407b0672", 682422911500363),
-    ("0x5768ee6c89daeba8bdcc9ea292521886d2cd909f", 11231888920363539),
-    ("0x8ffd66baeb5dec29fc1e44251dbb67d5298c453f", 640765243652768),
-    ("0x79e01aa66256690ac174c090d342863c72fbf55d", 696013427040503),
-    ("0x76268e7b124f7e1562fc3396c27894b98cb6ab47", 26652953920364150),
-    ("0x6b2b050f9d08b409c3d19f4cf394b9429a06f132", 689163675108999),
-    ("0xba21325681ffd7d623993a2a564f6390654e2948", 13566099107268295),
-    ("0x8e7bcbeb89023189e3b99d0f34c9bac58043ce66", 700329503991463),
-    ("0xae0b2edc170b970ee3e9aeb9025ab26956f39f68", 700328224600237),
-    ("0xdcb136df4af0ba9d4927b76aad7a71bf197ad050", 84432507150487917),
-    ("0x026279c987fb443967da35b82d8ef1b05ff25258", 685886133503667),
-    ("0x9312ce1825e070fdd04a9a71f27701758a78e997", 685884857915799),
-    ("0xb9e944f60d7fefd9a8f4e7c7333de4b15ad309fe", 4120686587536485),
-    ("0x13b2ee4b9b1242bc15b31981efb7a16323574329", 685870160086366),
-    ("0x8677b668c0096d845bee91be727b48b93f9c966e", 4321652181393640),
-    ("0x4b22ab661618de235bbcf8e8358dc4cdb9b1beae", 685602645330113),
-    ("0x370138bd28d27dbb0a438f553e056217835b6363", 16970249226534394),
-    ("0xc097236f9333f238eb9726e1407c726e22b0f2c4", 686189060433939),
-    ("0xf3d048b2914c66be26b901fb47b9dd1c74b69159", 680005506739881),
-    ("0xa7bc6b9e04b80e5d948d05bef5bea725260b039c", 1288898361788305),
-    ("0x7b2818ceed128dd779f5567eb39d96920c9b9244", 174470586835932667),
-    ("0xaafdd768fa83ef7af0e19707f2be9d1db1924766", 3663450133509021),
-    ("0x9f71164820780818bd95d98b265a0f261502df8c", 13971036386588988),
-    ("0xdb1057846f3a4c62e8f8545b1db50231028253bc", 22057060033472417),
-    ("0x2037255afc8effc5c321190d611938491e4cc83d", 5774201068141483),
-    ("0x27e70d62185a78e9811632c1ce8bfa35606538fe", 17587232364218353),
-    ("0x351e17de911fdb2c0fb4bd6950857e0d8e0a84be", 82966050452498792),
-    ("0xd7516c3266bb42c79b87e47c389e37f08fc26c79", 29434252759256262),
-    ("0x2d78c393caf3f1b45fcf44aeb8444db5bfcfa623", 7214342598341654),
-    ("0x28f26cba9ac6baea9fa3341038ac5e1d64fcf1fa", 15099294823016969),
-    ("0x4c4725db955e69d4284e4466eefe0a38bddf4a90", 1554382873697201),
-    ("0x0d4a203081651eded62ba05b49e175c48b47714b", 20307450746819658),
-    ("0x26bfc7857f58525c95183a20d11ce23b83762211", 3785598748986100),
-    ("0x7a3518fe642b11be76db809ac5df64a4ed0855ba", 39214880597374901),
-    ("0xa6cf399da04e528ff5997da8b8bb42e1bf36a7b9", 9830543302914344),
-    ("0x4d7ced07c32680f507cb763e6eafb462b416fef7", 9402538999749049),
-    ("0x8fc2236ac32307df8ab5116024b5c73827c37ceb", 5591128546145870),
-    ("0x290e22391f2cfc3a21ce2b75c77408f50b138868", 8555058399861768),
-    ("0x44de68871ca3dc1693cb58658813584b6db70e32", 6384904109921614),
-    ("0x52b5870a84402edfdb6ac24e4ddb8f8083f2edea", 16189093977065567),
-    ("0x0c31b6605686aa26df47eb45af0e4aa6639a5fd6", 24939679371435904),
-    ("0x487980156e97896ccbbfaa8957e76e02ed1855c5", 16643006201453064),
-    ("0x91fefef3b24fbbed50389db0b7fc7bd7f21e0c8e", 16761863567500655),
-    ("0xfc3628072a4566a412ea93937169dec1ac4d64de", 8444209531971330),
-    ("0x67f92f85e1fd52cd21fdc7a589011e61a8d23a88", 12015929345912945),
-    ("0x5bc124b3f28c301f497d3d44eb5c3225651143cb", 29435154849094258),
-    ("0x48518cf252e51872a57b872d41357c4e563b0413", 32460847519762077),
-    ("0xab2c57668cb7ecd1cf27f360a89516b6a9987ef5", 16787681060752050),
-    ("0xd74fde529a513d5e4b572d1c419d67044308118f", 82826511219647909),
-    ("0x238066cce6492c3ca978e2fad053b0a39274e754", 1629771628427957),
-    ("0xbe3fa236c2467ce30861504b5dfc13b3e358dcd0", 14980657130651346),
-    ("0x64cea862ccb0395f7b0f2ad5bc63b0f299a56637", 37699160383010376),
-    ("0xa512b9728c30a855b5bb4a8ed1fa633994600caa", 25389145598897332),
-    ("0xa92cd453795b42e2886c4b6a6b7538ac911a8829", 156192159190910782),
-    ("0x07e1468a309b15f34ebc35db738d3e99d6d51a81", 58340931811879927),
-    ("0x6480ea310eae4097e985355da300f8712ff5b327", 10691711423466331),
-    ("0x39de77e0d16d007e916a86edc71fdc451af10b74", 33066026111959437),
-    ("0x4f1b0edf3aed704040f5f8e6437993d5eb278c01", 19811530729595510),
-    ("0xc1f92c14d8a6ca7f5d8dfc3e4bbd6b5aa6783caf", 2056633193007901),
-    ("0xd9baa3a44ad6f1c10a619a0db3a4ca75e384d62b", 123461588665700825),
-    ("0x086f51fced9ff6c742d706c319112359e3658b08", 21210553735486379),
-    ("0x6fa98a4254c7e9ec681cceb3cb8d64a70dbea256", 23517987012241154),
-    ("0x356580ed0926fadb3772a99378171e719f7f55c3", 9038214447282061),
-    ("0xcd11afe8d677aa74ddc67b4d840c4b59a240b2d7", 3360424830130489),
-    ("0x320e883913fe1a156ec61d48667d76fa5bffd992", 620086027558880),
-    ("0xf6357dbb1be91ec480c12d0740a709f4a5a84eb4", 5688501096238928),
-    ("0x11292d984f5511ca77a8710d0817d69318fabc7c", 17154670246872764),
-    ("0x088dc94cb4c0bc85541057482fa73be125c5d611", 76925057203533697),
-    ("0x239a583f92587e630416035e1017447284152f35", 42566922587236285),
-    ("0xd5378408191913a2298ee1292eb3c1c1b3d34b2c", 9660074386309078),
-    ("0xd1d16d63e2ca9b2c5c955ba34ec610b7ed509268", 68206165997742217),
-    ("0x42afe93a50c9fba4bf55de264da7bfacdbd2ec70", 12879785116927185),
-    ("0x66f0b8e98047923774f722e41dffbe688c494080", 5947089435866575),
-    ("0x84bc9b47ac8947bc9da1e0fb7680b50ac19a7356", 22318440875482261),
-    ("0xef39dc68c223650c69d5c6919113dd0062b4fbee", 10020347792181127),
-    ("0xe8b3feb2f9361e23da109213a8ee812cdf7bc5b9", 30815305830440908),
-    ("0xe72bf4da69a9248dea2afe6fe9529c09b345ae95", 88364707450516724),
-    ("0x9866241b15c06ed46305b2fa1ef15b169aa313ca", 8721045743171043),
-    ("0x2b10919b3e6c3c4fdff0f5d677c3d8b40055921b", 9024514467793456),
-    ("0x76f53f40943b3a88d9c8f916237647172d955b7e", 15667959739839790),
-    ("0x52fe6e33121a5489c9831d17d00588d5284f06e1", 10795379296441204),
-    ("0x5a9650f4eb72717f12c1d173347c094aa129d108", 8819912035771270),
-    ("0x6676ae96a46458f7307e432a86a26533ee970d1d", 15614168969513210),
-    ("0x031049f28b2d1927eb353ba072e8c37516c64524", 3638966352024210),
-    ("0xcb36fd9bf9e6be4bb1af8e47dbf1f0e120f08435", 330769258152434103),
-    ("0x8559941d6e93712dea6600b5521e0f9e821698c3", 16774796457422232),
-    ("0x1dafd495694950669484b79272744dccd98be966", 8980696889610763),
-    ("0x5a2cf5b6061de4cd3c5936a781621fa8e4d1fdcf", 17212979305931940),
-    ("0xa49c960f42d77ebdba21d6c831b44728cc71e6aa", 578738166958331559),
-    ("0xc47398815b6f3f4f4eecfb8f38fba6bb6cca1f54", 8676806119608896),
-    ("0x709296bbd1feffcfef090f3d66cd336ca0505a1c", 1767434524200936),
-    ("0xef2382c757a0f6f69a08e02157e0e78cf7a11110", 34189707754633602),
-    ("0x8b470694f988acf13996371099281edfe1ea0e1a", 50634543296072977),
-    ("0x92bef08ba97937a996d619671ac01901635cebaf", 2384632985422256),
-    ("0x0b78773225fbb1d14536ba12778f1d0599ed4aec", 34080713977239909),
-    ("0x9bf87ed3d81b03221df7f14a270ae7e5801be058", 84349523794945783),
-    ("0xff244fbe261d55c591dccc24cbd7cc52d8a224b9", 5728682207313022),
-    ("0x812f1f434fae0346764687e6069e8176c8601991", 31269535302233932),
-    ("0x1fb8c7c29b27690985ffb4a207b963323d4f32b7", 81771343209220362),
-    ("0xaa89d361ab13cdba221d9eb31804cf2e46373305", 15822237825063539),
-    ("0x5f042a4c12f598f777ffdf763125326d83e808ce", 7588691575816395),
-    ("0x0d6a9963f7b362c3d50cfdfdc9207b4d8e766c13", 23010832240690179),
-    ("0xf73745d6a9224acafefc7c484b5e65e23297d4f3", 25403986302264553),
-    ("0x2b6501b74149cbe243bf5897ed780253791ee009", 17419057313347545),
-    ("0x3e1ec4d12472f7ca877fa5e922ee220f7f615990", 2855716390530507),
-    ("0xd205535ce3cba632ba4455c29990190a8a43bf8d", 27665172992158113),
-    ("0xef9c13ea6b615fa61cbdec56c7b4978b9dd8bb46", 121869345887472245),
-    ("0x37a7093d0f588078c3cba105b8cdcf5c38086643", 8742676118904140),
-    ("0x83f9f8bc0777152bfc7ef885fa631ee7a92ff3bc", 902204299323413),
-    ("0xa9405926aa26d16e5bfe3b24ca905d8b4d0a6cd4", 285117255528388327),
-    ("0xd974a0df907cfa6c9413bcc79bf6b32bce412c83", 10482507552067843),
-    ("0x6ce4851d62452bbb846eca2151340501b86d1e38", 84899397224468784),
-    ("0xb59e333d0779096d8e57138533c7e5335d458377", 23912715068268406),
-    ("0xcfe871a19085b18c2d57d0810a2b43f027c68deb", 11962999703948782),
-    ("0x7c33403b57bdc4b74ef68bc4dea5d76740bd84e5", 14079559941530676),
-    ("0xe8f4c5afc8582f381d2cf5b4ffc652b04177d982", 2887333518764629),
-    ("0x718c8e761d26514e7b1d4f989438b9cb41b9e31a", 31964278689283586),
-    ("0x8f9cd88407f47d5d998352184219e0b7bf7b74b7", 8503837700314845),
-    ("0xd97bdf7e9919eebf05b187bba8985c78bdd5c3b8", 5842979018225545),
-    ("0x0eeeff44e8f65db5cc841e3a3d591499b74f85e6", 15771000000000000),
-    ("0xdc856ebde12fa43023a87702413ff13b3520320d", 3388889856072464),
-    ("0x057538b942e27e83ca97f09df2218c3197f20de8", 4987755536531659),
-    ("0xc0d95503a3d02583bd8d4e7521f459fcd6cb6262", 8744211668246599),
-    ("0x81f5229fa6267163f9169da276df49e9224d1f15", 29854238107921020),
-    ("0x70dc393f1f90fa02e9eda1ea138a7aad1a4c3d54", 15166957855578663),
-    ("0x571d5050e35b3350fe374791281aa1ca9e7d16ff", 2911675047263856),
-    ("0xd11e3e8815ab3bc592a032bd1b56b01a89d83a66", 4112932560842200),
-    ("0x1658d60cbb2d04bb6ba4c15a43394f85a9a0887a", 30844838807525479),
-    ("0xbcc22f7d6a49df2b081df31924a668959adea52e", 2081415817063113),
-    ("0xb2c4d35cb20b0d6a7538da5683d03037068b26dd", 7900397147182356),
-    ("0xf4e934d33e0994b8b63a9339c34365cefd453790", 6425532451700564),
-    ("0xd3884d878aa40ee715f42fd061695cdc7d77f402", 12084109586018329),
-    ("0x3b5e33914100a2aa5543fd03aec6b938feba75e6", 26189326583155601),
-    ("0xb5ab290e152290b6bd5bdb04e23236d3f9d4aaec", 101260892938712273),
-    ("0xeafe686acede4d00791473eef1a9b1842d107d82", 32832930966340783),
-    ("0x8cbc1b6787146038db66478590bf56ea004e0723", 7997252337928726),
-    ("0x3aac424c9da138e76ad999239cceeef80452b82e", 8769180670276154),
-    ("0xde0bbdb9b1787f09f9ff0f7cd222691f434a0abf", 42131718206026316),
-    ("0x43422b2dc244b6b6224d2951d640bd3a3f346b63", 30408181606266136),
-    ("0xdbae4bbf2b47aaecd46ea240add300ee5543b1af", 41034820196450530),
-    ("0xfceebfc42775d38427c4078a9d1a8160b6ff9a2e", 18773630337809248),
-    ("0x312d9a7df4905434bbd5fd8fff32658fbde04ba5", 12224383301488023),
-    ("0xf0cc234869095e18e6cbdf858959954386b14b03", 5264652178213616),
-    ("0x889b683a17942d093d796c815d32c253145fe87a", 30188268201399595),
-    ("0x7449716c37a646f2bcc63e40327dd9ea11763f4c", 17237120424585285),
-    ("0x9babecb6b416fb356c93208376f3566c57c2e269", 15334264104914668),
-    ("0x80137cbd0e76a035a78f501fda5529856ba1fcf3", 16970039914909424),
-    ("0xb28e5c62659e9bac95af79b277c4ebfcb2e9f7dd", 4723925067485960),
-    ("0x643ec336f96482e515ed516ada53febc107526fc", 16409523521154748),
-    ("0x523e34d215485740a7bc604c7dc117eeca71a5c8", 61040962102129608),
-    ("0x9fa621bc83f503feb6a85381867a06d7261b1379", 121143354373605167),
-    ("0xbcd89370db524b91f910a445f5aca6acebf58420", 7230463614096738),
-    ("0x8953c798a6f54ea8907875c51e5d466de76a1b26", 1312358828962400778),
-    ("0xd7fa1265b3dfe434b3d5884de665e96ca914f1b2", 914119620992809),
-    ("0x2d284d86b1845d7a6ab3f761ef3cecc88a971dd0", 84199085445371684),
-    ("0x1e1ceff4b03ddbc543922856ad870a58e32921a7", 9234670607176144),
-    ("0xc16362397cd34c1a9380a3cd613f9cbbaea75bef", 3348957496879523),
-    ("0x387c50f4767b04e7a41f0c365b9d563ae787ec40", 822977885107318909),
-    ("0x0103a4966daba5a947df52b0b892d8b3fdef5a4f", 200000000027119086),
-    ("0x04564e004c8718289e6313b1742d2859fa3994c8", 81347067932390410),
-    ("0xb2dc53ca5121e427f450106526918353a2e48353", 24965185868854964),
-    ("0x002ee33092457900515a4249e3ec1cd2b2dc8c32", 57051589750496699),
-    ("0xfba875200bb004cce4d47a741f4f1b69c0f15461", 77317382089659438),
-    ("0xd0f2a26181bf98c6a3aa0e0e5754c9d3bcae3d33", 12583574588907939),
-    ("0x39d6d81e0c1ed93b0a9bb29b31240180609e9f49", 24312801340726498),
-    ("0x847dcfdbd8bc8a512a93fca1938ee57df8a9932a", 3927799997220049888),
-    ("0xd8259ad6b36d4ff52761201616aadc8c41c07326", 7006887522232947),
-    ("0xb1869052fe6e055b45ae79b0b59d15a14bba05fa", 36000000000000000),
-    ("0x996e3841361201686963684e7553d9b4f634c893", 11366394843972802),
-    ("0xd5d39c5d46d973c1c824877d9a583fd2e4a39c11", 36000000000000000),
-    ("0x0fbe38e90b42782e91de726c7d2b213c006521d3", 405145720566941679),
-    ("0xedef5d47b72b6199cf5dbafbcbc753d7e00205d9", 34227608240651451),
-    ("0x87715066daf3c2e0a05085a2f1b6087b90aeaf82", 24531472009572107),
-    ("0x188ada1c2362431a7a1fdc9392fa8c0d8983e3db", 7601984160906785),
-    ("0xc7e3720358944ad21a695a037c21ac26b80366b6", 36000000000000000),
-    ("0xfb4848595072930ce4a6f03c2558838bfc361171", 16633975092178908),
-    ("0xebf919b0dafb9ea4bf324108a142c64a69052d8f", 473584635111286029),
-    ("0x9844ec697c53b74362afe46524719083b56f93d4", 41541764288477167),
-    ("0x07b0ecea5e6187ebf264eb37d3cca3c2b526259a", 29387512383255638),
-    ("0xcb306220be77e99baa64fe5d4a72717b16077bf1", 6199202259466580),
-    ("0x3f971f1f709a3ad58b679938e637726d002406c7", 11545027564232278),
-    ("0x22ab49827f60acd19334e24cbb36a9d9b5d63905", 8291835347764622),
-    ("0xd8d3d8ab22e30c5402ab2a2e216a4a53f4e09e9e", 73603496894510769),
-    ("0x7c3536b7a0862c288e920dfa8a27f02032b99bd6", 27240171037304717),
-    ("0x849e449b67e8a58e1b267d74ad39581631d11374", 16257474934254914),
-    ("0xc70aa0bc5c372ca2006204c91af480dacf621bac", 39776376498787003),
-    ("0xd37634244b9eaa3c8501b92c9ee5ee02f39d2fb3", 16691056757289785),
-    ("0x7056443005620bd9bfdc294ba9dcec6d94296322", 9982874110279166),
-    ("0x7be25e4713044a35fedb3fffd5e62400da8a99cb", 3757170765334874),
-    ("0xf84dd26a280baa17a4ad3ae84eaaf4934fab34fd", 16108889554898947),
-    ("0xd5ba7fcfaefcb9084272c7af4f74c3b10971e144", 28582271805814011),
-    ("0x49798f0987673038117fd16d370e7ceeb120b8f9", 74521489017408271),
-    ("0x07f31beb58d538b92aba6682469751e02cbb08d9", 21583788934285773),
-    ("0x77b34f1f8b4b0af763028452efe8c774205c5f2e", 3946853996374744),
-    ("0xfc2ad7f0017ad5c4258bef521268925f90577711", 16255149650556762),
-    ("0xc105a696ecc97ab7d6655bf0e16d03f8ba9fb536", 157003797493158194),
-    ("0xe9b726d33d869ec037184c58eb211457dc31dcfc", 23832667145922338),
-    ("0xe73a1d0f64222e161a410a82484eeee4a4094d07", 16085181082507473),
-    ("0xd3a2b2db8908c902685e80e5f09b6dfa586aed3a", 31245069066032104),
-    ("0x642e22d043473f5ebc3bedf091bb2bca49943a6a", 13248295534884188),
-    ("0x882ce90065e26142b214391363166a7d470ae0cb", 28485335158673244),
-    ("0x92054ad5051c5b944bfca7600bfdecc6a5504264", 62191034905321988),
-    ("0x75b25a519dfedfb1c47b611ba2d186c67a5dd751", 17237019658939067),
-    ("0x73730f8abf9cf198d79e3d574933a9017fb6e5e3", 5037913519143130),
-    ("0x357401e7b6cce28b01d3ecd900bdf5ef168aca19", 619846136328972781),
-    ("0x4a6e297b23b9389380987e901481811d83e945c4", 207564989901620839),
-    ("0x04635cec919cca10780a8a4bc4feed4457a1c3f4", 61789323807880592),
-    ("0xd0f853b3c9c621c64b5b8c2067317b2afc008d03", 12010209910874987),
-    ("0xddf8eb9abe01c6baf5f1308a3173a69f95fa6ce2", 31079212656237686),
-    ("0xc34f0f4cf2ffd0f91db7dfbd81b432580019f1a8", 69498226595379136),
-    ("0xe0171438425e96ae5bc05640c1ed2170261a7b58", 8709930525915828),
-    ("0x6b23341132580fec3169022f48403664b4a17a18", 48534621500613135),
-    ("0x3d54bfe0aa00caaa22330f96eb31ea976ed881b2", 3475987584854209),
-    ("0x14fe1322209eaccafe3f7545195bd163359cdf0d", 32579086127656792),
-    ("0x2dab41f30a18816b778aee9277ed9059994bff9e", 18292237622462251),
-    ("0x53c0fa070e310ce1690b3c83b2ab7f20d74d4922", 6759435570663940),
-    ("0x67ae7d2503078b6a9fad5326cc071b9b59246f7a", 7867194672672339),
-    ("0xbeb06916e3ccd2d0d36d013f833b227d057709bc", 27935179670715181),
-    ("0x2aa2c037b1b68e543da36876b9aca6cf7e21fc40", 17349472747355830),
-    ("0xa17ebb49474c4debb99ebca5b3b7d34ad468ef13", 2679295807052510),
-    ("0x3c42e2d1b4f954b95fd012ad6b057d78da2dec60", 17304427677331444),
-    ("0x3b769067130f56c1b98e8b8a273b46a69ea2c760", 2547451352595650),
-    ("0xfd040bb74eb6953a3a19ff9b1f032c1beb00981f", 94432659105733114),
-    ("0x72a773aadc0e928c9248ae71260120e6e73bd447", 16132903502275764),
-    ("0x8b1ea47e37a70e7feefc6a6945a4a7272a7934db", 112647666711047591),
-    ("0xc38a74f654178aafda3849dbfd4ff8a5050774d6", 4456013449883957),
-    ("0x88dda47d3402589d9971ef8ef5af9355f1df2121", 9312514125795789),
-    ("0x2a2820d424ed72e9466c89e9c7737df344be75dd", 12137823255421444),
-    ("0x8ea1250b44435be3cd5841d2256ff3356c517fd2", 171511110668863232),
-    ("0x21a5805f639ea222df1900aff8aaa0530d63a05b", 28425046800522853),
-    ("0xaa0f04ee13166b27a89b169f519abb4bb8e1d1a6", 312627645802833109),
-    ("0x280aff4f29412dc8e8047a6d20d9ba5d5b810205", 17301762357745981),
-    ("0x9a59904dd43c42ca2340da86d1257cdaabb3bda9", 9481411299608225),
-    ("0x43f08ec0c0bfc1cc292383fb2103a8b3e1ba8e22", 7985078224220817),
-    ("0xde75f09a694e4573f2fb222579cc0a23d912cbe4", 156361644991863320),
-    ("0x83de6760f2c3fc77cbaeb25ad6d52198aa3790a0", 12926594387116143),
-    ("0xb6675da3fef6a7a7e7bb4159562dbea7a6a03e19", 2891269066058079),
-    ("0xa0f80bccb86638e2796819fb6c79bbf198a36148", 2577598400811125),
-    ("0x3f4c6f9907a834922f7a4042bd2b5c2d81e9a2ab", 18741402426374649),
-    ("0xdbd31fcbe67bd0416c650dd4a3139312c247369d", 73135813895186753),
-    ("0xd6fd3926750041866fd020e0b20c8f40b597e1cd", 2089433155105679),
-    ("0x78b416dffa838b89d382e49ae9ac5488ca827689", 55765420734532732),
-    ("0x5acc33f7e7b77b4608c39cd30e95d4568d5038dd", 54483146336827766),
-    ("0xf9a081366042dac2c3078a260ddfbe833573661f", 31116733046526246),
-    ("0x18dcd5fa9844b837d619ce21ec672e9bb8d9b511", 9342019100634922),
-    ("0xecdca6f9debdf211504df8777dee79e529a3a474", 6341658155328181),
-    ("0x9cedbb930d1276227e824b5594e18785661541a2", 820927817533131),
-    ("0x80a790bfbe841521735abd8e577f09f87968d144", 5999917170900047),
-    ("0x8f962a3a5efd612d187961d76f05acb980d3233c", 7303560762231745),
-    ("0xbfa9fa9fdab9a262fd219a973819c783bb391958", 3961674876080704),
-    ("0x71cc25e270560ab31d5d2e15e5bf896ebfc4a640", 160836102432888491),
-    ("0xb2d377db35a267ed1768d41018be61ca7b65e2b4", 8689214238982418),
-    ("0x7399df14b409227b24cd8edba72bcda1e4f7732a", 5712766819307083),
-    ("0xf31d0d4ec6c727527460ea6fb55c15b73756fb78", 1317218293807648),
-    ("0xe816c2932724655782a81009cab64bc45446afb0", 165720374782330429),
-    ("0x84e1939515ae254abe7d6c1c874a78597a62fe49", 7665308710600549),
-    ("0x492ca52057fcf4f9e11a9619dae1855aad6b1c81", 65467634968395880),
-    ("0x67fca6083745339cc8fb3d8bec011cc7dda13a17", 10979854798558528),
-    ("0x6ea496c0524732c976815c09c536c95136028350", 28791967526273709),
-    ("0x1f391756122c08df29a226f9c7803c3ae50544b2", 10772484271881685),
-    ("0xdb976f329f6d4d31f7661a2b5aa60dd24b6956f4", 12016296353408822),
-    ("0x9bd33297019bb2400019041555d9100fd9178668", 515828183766476593),
-    ("0x86e15c097e5ac39cf050d1e1b9abd0d2885a44f2", 77603656083931614),
-    ("0x91b71054bb6850f91d6d61241eac3c1becc4403a", 42498738236161524),
-    ("0xb093234d734db835ac32833b86a471244862bd6b", 24753630806326314),
-    ("0x3c494547d0d5ab343fcc094ff21a1ac306b19a9e", 461097270216413537),
-    ("0xa8a5b4e535268a91d330a1b26e11030831eed35f", 9395679869836532),
-    ("0xf2cf9cb8d39eeaa22fae8400c523710e3a2702cb", 29984910525851708),
-    ("0xa97578dd7ad20ba12d42cb4100616f7d3797a72f", 27793920577965646),
-    ("0xb5388a3235cd0ac7f50b25e83b85d736291df901", 93973397464644567),
-    ("0xf099a483ed260747d67a29157a924a3e49b6802b", 14994451811645943),
-    ("0x9e91a40f39e4c49305b11bf588ed738457a2cee1", 28858644349959027),
-    ("0x5930d7b8c425dfb3a576dc1ef72eac8bf238d257", 39057179698665829),
-    ("0xb473a2b4ef84613faa016093056d341841c1b89c", 53990181808876847),
-    ("0x189efceeb15b39ab09a28a9224b3c1fb7efe741e", 35068000000000000),
-    ("0xfaea3a707103562400900b0e0aebe35d9d960c59", 18192000000000000),
-    ("0x22a573f1155bcbd48b8f9f69bc236dfa0b8297ed", 9365026399651258),
-    ("0x9a24082168fc72a006249fcc3c5a35f927ccb10e", 6606529494786954),
-    ("0xe149dd0e0118e73c7319de81cb60d2cd9c4dbc4b", 2252587085484294),
-    ("0xf78cd8e33d9c5507701148b74c57594dc390b297", 73456431228435859),
-    ("0x474c2a4031addeac0d1dd4e4e4df77e0cbad53ff", 25169196563986154),
-    ("0x4ddcbc5709e55b9ae5bf1bccf3e33b561d9fd952", 7835352322150274),
-    ("0xf140ae354248ef23a48321496e215ff171fd34a6", 15622315429510973),
-    ("0xc63a7794b034471c7ea446d33bf344ab76f7c833", 44692680227186938),
-    ("0xde0e14e314ec13cabc21272f9dd27dec0c92b504", 311305016434634591),
-    ("0x90316d1650062ed0322a185d80b17ad0f6227e7d", 2952031954781723),
-    ("0x256df198de0eb5204c941496718e8c3f537f1ea4", 11138521183282984),
-    ("0x3aeb6392f86408afa6d433dca70975737c483f0d", 14892338437516013),
-    ("0x36f26e2e5bed062968c17fc770863fd740713205", 15288100140513671),
-    ("0x38a4e6eaff63bea0617bafb28bee8be193897122", 481516880643882320),
-    ("0xcb40624d1aa71918c809ea82617040b4021abb74", 4655295955444035),
-    ("0xc53fbb4b3314ac6136cae11fbe2667420f0f1898", 11519150731544504),
-    ("0xb933ad0949273d3316d103ff50cde998ed6e35a8", 15096869452662009),
-    ("0x87bd582fce36ed7a7fd2f901a0dd50e2f0e233b5", 17736101431375519),
-    ("0xba9784f77c2400bb3352fc231b6320d710725ae6", 29722503303603562),
-    ("0x707912e7cd61891f4165cdf89c1034f543b99c10", 262673598661026405),
-    ("0x16a1708187cf1717744aa1e8bdb47a93ce1d2e69", 14979763947931102),
-    ("0xd7267ccb1ef37d769df80856aae9914149ac087d", 73064299532981628),
-    ("0x1c7d965572a70238ad4badde9fbdd23f1f1a44b4", 30482562232704558),
-    ("0x4dc0a736d0b2ff9222fd6d347b03f0c010c5390e", 41674503322295180),
-    ("0xcec6ba8aa1fc9423b5a480dcc7f9352f26894aac", 2157324798415644),
-    ("0x8280d028d6546f737818ed9580b66afa8fde101c", 14961152163839605),
-    ("0x09a5531be46a79fc1d1738ea362895e1f231ff17", 10575551691899416),
-    ("0xc7ea72f3640343f219e78cca1166f2d907ef96fe", 150000000000000),
-    ("0x11fa4f109a1601bd1afdb509b3bdb1e23fd6a675", 6408712979894010),
-    ("0xa136a3abc184bf70de3614822b2a6e6d8df018e5", 51494926317127825),
-    ("0x40e830c4d5a6a99992ae26203553dfa781e5b4bb", 47005887970042664),
-    ("0xcd8771ae2d6cb344663b8da7253c944f0801edd8", 14922492329104648),
-    ("0xe266ede9308372d493d679830bdb8ed3dbd19b86", 15668491977750650),
-    ("0x5a86d6c0f577880a270ce795cc199582f86cbb0b", 539765444752967588),
-    ("0xf782f0bf9b741fde0e7c7da4f71c7e33554f8397", 11183624903956664),
-    ("0x735aad4b53dab69cfdd69bb3919dac08fb9b8abf", 14950145136600162),
-    ("0xf57a0cd7b43ea040ad52ecd9a92976ae4ece6320", 9277024366061412),
-    ("0x52193d821aa18c41f00e788251b1f3c32efa808d", 102977090267820013),
-    ("0x923418407228dac05a6fd6bd600a059c33d05edd", 29476398072293972),
-    ("0xbd19e46f8283a08ebed305a22812a924a0b321bf", 16695887177433129),
-    ("0xede57789e9f33a12449fa34f155e10c4fd9f8567", 13981841610244580),
-    ("0x68f8ef1792689006d7c1495ffa0c6e05f8fdf1eb", 52732805070549038),
-    ("0xec48794d7ad819a633471858f1ceafee1d7d1121", 148220198346725137),
-    ("0xc831b2d869f49a64452527fbe6a9bac595a9248b", 24312294514871583),
-    ("0x66238ac1cac8787f00c35cc500b1b187a8deea87", 45011332130405378),
-    ("0x27bc84b754b0b1a9a732b30d507dde33afb73cc8", 42542410126261037),
-    ("0x3e2dea91d1a67c5eac598ffc4c6676ca2c640360", 14997862302387226),
-    ("0x0a1f71e6f762899a37cb6264cbe7b591537a5d4b", 28726399287591659),
-    ("0x33b0d5f06ae3f46ee345eb0f8a16cdb25e98b92d", 22107149061495073),
-    ("0x46d94cc9ebee7cd16c559b18597bfb62334d17b3", 3391264802158364),
-    ("0x5e726dab3118fe37dfc68feae7e5f79872e9f095", 51027775613004208),
-    ("0xd764c62998e41c915f3a3b09cb10bd55804c8552", 8835359920194129),
-    ("0x838e9a728097a78188b3ef6747a5229fd17951da", 14945832022438602),
-    ("0x11bd774aa47cae4b99308cc6b9711791a4701f64", 15224809382670848),
-    ("0xeeb69fbf3f3038e9f8d7bec045b7ff719005ccb9", 292703923751959133),
-    ("0x2e0473e3a5687e0b992dad250da005fee6bd83d1", 3131824615264050),
-    ("0xcc166bfb587a958e41b800c3f874ee7f2f786569", 12997434484815632),
-    ("0xec4a20128beed764a08d240fe93ad0dbd18fd294", 9805809591497463),
-    ("0xd03dad0c3ec5f564945ba92eddc536b6b78240c0", 1052579842855192),
-    ("0x725cc8251bcb2eb851fa3d5cd88972ab7a08554e", 15433981773099903),
-    ("0x5f2941c2752b4ba3748d1c82db65f78ba57c5855", 53784418671746050),
-    ("0xfe754d46489151a614bb548c0395f399ac253338", 14963094804883988),
-    ("0x26921cb019126a14cad7b4433c4a9d8cb09bf491", 1812390711118208),
-    ("0x936a0553c34b90597ae300fb349e50c33f44511f", 2890176095659850),
-    ("0xa1114a64fad2ab7145bad473947eb3c3390ade30", 15021611047795358),
-    ("0xe3afc6b73b3e2eb36186f2e4bbc01b8aff5cb773", 15149697265840614),
-    ("0xa89009aa7ac4a27a9c463d88c6b3d7d15382b25d", 2091340242980459),
-    ("0x9a2f4bd5f474ae25e49fe5659153603c41b618a9", 13975075413436148),
-    ("0xd7fdf077f7ec8367a57616360d020c3d2fc21160", 76293611078377915),
-    ("0x996877fd71e61afad7e7ea3961615a126de1f9ef", 221679147826132046),
-    ("0xa584f712ad5ca229f0a565d59f3eb652c3d753af", 13849444334855842),
-    ("0xd75d3f718013c4e03e5d1fc32292ae9d8f6c6ad0", 2495354937195636),
-    ("0x9a06513abe6323f88135c7ce62385431dd01f7d6", 8070135217230471),
-    ("0xf084fe6182122e5a5ced95194cdb31c9fd384a6c", 10630563962486147),
-    ("0x933bc9bea8790c0513db85c90ab2aea60133cd55", 27034679645645277),
-    ("0x474186c406b5cab0d8a2fc2da1fbc61adb55588b", 37505163954212696),
-    ("0xb27c64751cdc012113021d127a604df5dc3dc845", 2827678498513295),
-    ("0x13534f4b45b21756b661710469099883ed9b793f", 6350018773511030),
-    ("0xb6e2831af922dfb15389ec5d2ee7d8d894b9bb0f", 51968434683336516),
-    ("0xfba289c3f89ca5e2545f701aa4eb904d1db48394", 5117220085728571),
-    ("0x24d598c2cfd76cf6a3084b0d52fd3081eb87ce30", 26616987387223564),
-    ("0x185541a045ed6fe9ff1142419b647cccbd62a04a", 15401118094596223),
-    ("0x4e42ce66a74578c98612bbbbf63f48828056c1e8", 2820919638755911),
-    ("0x99e7258538030eb46b1548c2490067c48c47ae37", 2000000000000000),
-    ("0x3f87cb2bce58205692d2f0b2304a5b9037c1ef76", 23608966461133808),
-    ("0xab509a53b8ff1fe8fcd6b1a99f5fdbd2ada27c3b", 18685759473583831),
-    ("0x6e625fca400d1a6fba2881816b8b3bf8ebc66659", 6349897400849222),
-    ("0x81cafae3398efb2a915de5ce056f8ec6dafd4e28", 14383431212638512),
-    ("0x35cdb56e95791feba3e20491692be657898aab74", 13739975727272494),
-    ("0x59040330c01112a37c7edc4f8c89d5eda7a7208f", 14557062440480735),
-    ("0x4e1bb7648e3dbb86f073d03b82f28eccae82b327", 15173650834923603),
-    ("0x2cbb1b094dafb2beb3bfaa2724587c1c7911faf5", 3503131436131983),
-    ("0x28057f888602daaa089b33f6491d26370a297cf5", 10035706164022579),
-    ("0x7fe122e35e1e63cd8acb4efac755743ee00daffa", 26493076112946038),
-    ("0xf6dc43ba328affec2afebda472ac6977200da957", 16087283463738834),
-    ("0xb9a5c45296f748a5bc1a965f4377fa75ee231995", 8347567919537488),
-    ("0xae9f31f3eb977f7eb889ed0c2fb136a721628ec4", 12863014526668851),
-    ("0x2e9b3f71a3c4b47342889451a0c262e21025c3cc", 21941864563058506),
-    ("0x94fe2807ce337b29ce726a4a8ccaa47ec43f6af0", 29078682574129948),
-    ("0x683d4b70bd44ad2459154ec02273b05b370d2dc4", 42533498364407956),
-    ("0x6ca96666d5cc6afd63cdd53a75e8373a5bd27591", 14550445521031342),
-    ("0x4a02f0fa58634269d5a12c98fd6b13213105f78e", 6380180658492098),
-    ("0x3662651d054d4a4309bb54590d4b8629bc30159f", 2763883306108709),
-    ("0xb29cc8a719333d423757fbfc1591fcf13686d8a1", 14280434960610870),
-    ("0x248a25df3d1f7182947b4f7d63df299b9121f858", 28264996913098747),
-    ("0x788875723c732d8f0326cd18c5631ec247149a98", 5911409615710877),
-    ("0xd3fdcf8dd2408165f121c02f9d3d8b1bd926ad71", 3481098941160680),
-    ("0x7001c9c592b8b6ee43c9ef2287a8b7f3346b9e2e", 2422326987373183),
-    ("0x4e00eb454b169d5e02962bd6c84e038f24053cc1", 7490504176628482),
-    ("0x4f930a15c700710dd98c827d3e32ff686f56108c", 3290098564513055),
-    ("0x35ef703bec08d5353464eae367b126033b605065", 2046663319269329),
-    ("0x0b7d15d4f9d5bf95b2db6c583e3db2d261990287", 67745998176012443),
-    ("0x610dc41afca9203194c03b45d887f06ff3eed9f4", 247596490260122173),
-    ("0x63f0d7dbc13b396501b14116253b8e292ebe8021", 744230105130117),
-    ("0x250629395a031094763f010098bc56a352e5cd5c", 25409993912043792),
-    ("0xb1ed1666cc20e232bc99c7d11a71106291653c66", 42897882073428927),
-    ("0x80d7ca80191bce0097f73a132c623f0b4fd26efe", 7118766397102682),
-    ("0x7df67073c591dfe102295a8492dde45f9b0a112f", 28956179065602000),
-    ("0x93b2767069da9fbef98bf715cdadacc8ae08dca6", 13101000000000000),
-    ("0x196a37946c3d93e9a5309f539ae28b774a32411f", 50875336953378952),
-    ("0xa87a902341ee4c9f31ae2ff65bdd86a419502d74", 8721477911521147),
-    ("0x0de6716111df03b5fef262154d4850a4f7455b3b", 15056851216971740),
-    ("0x5e4016d8c70c3f87f39047ec43d24b48c67769b4", 24993578930498585),
-    ("0x22eac4a5381adaf34b748f10a4b57d25d8981963", 78492795388224668),
-    ("0x4baac6507bdcb0c9d059284c539bcf35d433d22e", 77023428875014895),
-    ("0x93a3a85331b1fcd3e6eba583c88e6fc91a1ce5a7", 27756805264504249),
-    ("0x6a19891e91a3d4d690cc8f9627290d25bfd34df6", 49642026328317094),
-    ("0x019155c1872b3e0044178af89e1f5274d738895d", 10835857829647095),
-    ("0x3ebdedcbd7a6a013282a6842d638519d1df4e74b", 7817593150764179),
-    ("0x517303565e4a94b48095c3b0c4c63a4cca95f2bf", 7293030400661630),
-    ("0x49b0459da94be66c1416cdd69cc00c980e26658c", 3975912380869000),
-    ("0xcf093e98dabd073f722835ee3ab756e0a9b52e42", 7650385142143984),
-    ("0x806db6c5cbdaa7dee05c001863fae510a5fe6871", 29633744999015747),
-    ("0xe789992e638cf7f25cd2fc66bde2f55205d65a9c", 13965270138081189),
-    ("0xb8b3a71d75c851f18df5667387cfd9d09e7541e3", 26591188891861544),
-    ("0x99785427f334cc8b2b010ae2a9d754fd0078f150", 7380712066352444),
-    ("0x61d53edf32d58bdd4ff847094dd24e400feb141f", 200000000000000),
-    ("0x8c64e8bbc424867e3aa026054301346017ba2197", 20254125577734982),
-    ("0x3b4dd313a27e70a61d0b16b29c2c60faf4274687", 68130320722504812),
-    ("0xb242e6a249f3f63472091224bce0d23e96632c6f", 14105921862809870),
-    ("0xef8081f035c5dc02c06dd8c4e911c361bc2f8ca3", 4527907474851239),
-    ("0x75428661ad4f91f609451b72de2603d875ff1a56", 8275626347872102),
-    ("0x84c0190af5e489a7537e9011e8dfc38cb39cc6c6", 10972942197347265),
-    ("0xd76fe9f1c000a750f20c1b651d4b8c46b534c583", 17870376378116788),
-    ("0x65d211d46c24de440ab70fcc26227b05958ae89b", 46555947289116229),
-    ("0xbfd2d9d705f9bb3e7c1dafe886d8e8519de84c57", 3541151017065225),
-    ("0xa650fee15be3f07fd450ed3c56b8059ac03dbed8", 36016693365010927),
-    ("0x22ee867df1d6032d05ce4a080b080a9d8db95689", 13343314345080599),
-    ("0x42c456fd39c9dd948154b1fa0f11e1773ac16a7f", 7458387076178601),
-    ("0x4c1b730b520ccc662752ffa8b9fce34f78f95255", 6183855630358548),
-    ("0x48cb3fe67a68e693c4a6de195a7d3b0dba7b6a29", 50488954112426936),
-    ("0xb558031b5b41bae6c3eed20d7f3f95191059afe5", 29192413826951507),
-    ("0x8c3198dd1560a68d3d6e4c4400faceb6160c9ab7", 25103570692330157),
-    ("0x1672721774d77d9d75b3e20e5b42f3134551856a", 68784179761985871),
-    ("0x0f84fbebc227283454cddab8a27fdc15b3d6a09f", 21528684188829161),
-    ("0x17a5907d12a1a95ebc2aa9b73c67248f1321345d", 76751485009358463),
-    ("0xd5378ddf3c3740b3ad2b3f62ec8f3446b9a47746", 4218797970046021),
-    ("0xd72d2db358b7b40b0d5ecec311d75dfdf5cabf88", 19740023886430117),
-    ("0x8fe28e117f1cb662e2178069e6c17a9d9ef73135", 9309692493761931),
-    ("0x77f989136c95da89f70b2c5c0ed9f9b2cf63a11b", 8959070573404058),
-    ("0x22d652c809cb5d21d0a485ec8e09470e56637570", 13347792064743975),
-    ("0xc1bd28391331390c97cc9df961098c79995273ce", 26918776743362308),
-    ("0x39446c6743d9188b846813819161e9d66020a0ed", 15855407319617578),
-    ("0xbf19e7b473824223da60e61d4d395dbee33b0888", 5837017096496373),
-    ("0x07f40c9d0b187567184c48c3f5406f85e6ba40aa", 15172541203232279),
-    ("0x9a394789c55ab1de0a21120eee4296729b008af4", 4902232291477823),
-    ("0xefdc9e736bf5f3e5d76ad90b34c5ac507df0c882", 161794789510809484),
-    ("0x0a6cca14cc94a2c8f34eb303561dc535ee92380e", 25964974321612886),
-    ("0x9eca9a9524ecc05042c023918041449b2452b3e7", 6328769066785537),
-    ("0x70f52b922e9eabf97c8d39828a045f8f81eb467b", 14515053485779001),
-    ("0x6979396c4b10cae0977c088e1eeb215da7c47a1d", 308974507961777318),
-    ("0xa56d08a359c9aaf5e025c874b55acdf8723672d1", 16047976056673736),
-    ("0x94644fb9ff3f6821219c917961ccdff753709969", 13665229563799097),
-    ("0xfb60ea660af138e1d831a0ff11260e2bf110cb6d", 14249674732835458),
-    ("0x35e6555adfa59819c008c267291b98eb15df82c8", 38299998291415528),
-    ("0x8200f91ff7a65926c4eb0750b095e79384f66f6a", 29533933261627139),
-    ("0x21ab95c6079c3db7583a37048f6f866459fc66a7", 11210540621667114),
-    ("0x765dc1c6f388d20fa880fecfcbe6aabe1485f8ba", 125612341186100063),
-    ("0x86c85f4664581c7d18da18aee9581ded2f0f7c39", 28423629387731832),
-    ("0xe5b0e06f101155efe1db648e15c3c1161d0035df", 6443891968980807),
-    ("0x49fcb015c5ee730ddeaa25c43fd770326f2625d2", 10308226703745355),
-    ("0x0f9ff5028ef1aa963c965632f0bc113a140e63d2", 16261382862853958),
-    ("0x981988cf0e64b62d84a451cc3fa2f40364333b50", 68658000893382580),
-    ("0x1b4e082e50cd55f144ac95d30ad134b9f3594a1a", 163597503015525088),
-    ("0x41b216f7e211b395ef8416ea92cb8d54756b79ef", 27551085486409560),
-    ("0x2fea68b6083b4b215adaad7faf6ae3a696f09137", 10739321000441632),
-    ("0xd1818a80dac94fa1db124b9b1a1bb71dc20f228d", 68372626414591623),
-    ("0xecc03538d9af264725dabd36417441f7971c91f6", 21152766169870964),
-    ("0xa8c5156c687e7254c1dcd486f760e35bb6bf122c", 6855358640571170),
-    ("0x3e025504b92babf4bbbc20cbb28f2d04c1608247", 69170863501672606),
-    ("0x35d778ee271770f906167c87c6ec3919b1a9a729", 9382354717887295),
-    ("0x0281881b17b78a0164cee93530dbf5db5e5b4f38", 13290176789703183),
-    ("0x02b6bd2420ccadc38726bd34bb7f5c52b3f4f3ff", 17680978274424449),
-    ("0xfc55a72ec00ea3595ba5119882c7e7adb52985ca", 25416107462239089),
-    ("0xdbbb35ff8efcd8967da5dcb4df2bb5313f7040d7", 128321158358169456),
-    ("0xd5257a3909f522ce44f1aee6ecf52b5f99422581", 101599214852417158),
-    ("0x892b7e6c66e1d26833469520bd588fa0ced4e48a", 16987066847725026),
-    ("0xae7d283420111ab1768b62cfe8e22c4042709676", 7391949735120594),
-    ("0x87e6888935180a9b27a9b48b75c9b779bfec1f76", 1340513849706501377),
-    ("0x66943316565fe60619ef3517c9ab80072b71223b", 38951938354444017),
-    ("0x68ea15bdfb38e96c0236937dff5a613f0da64542", 5272259487830616),
-    ("0x09b4ae8ca1450df0b6fce72d1d587af03bae01ec", 4462993402373717),
-    ("0xc4e910e717aa8ab17095e8bb7c3f3566219954a9", 8140273275576062),
-    ("0xfc7753c71a5e7d2b71a73238685f8ecf3b7c17d9", 20843067001492548),
-    ("0x92db7e8d9b281c9470749a83eeb51de21ff6dde6", 9598300504266219),
-    ("0x737a3254a810443bc2c59a7dec60180c82882942", 20606145618219530),
-    ("0x7ae87729a49d8679f3d7fd10b00c5970397bbbb7", 53990325880588380),
-    ("0xb014bd30cac84ee0da4565f875affee41c801d88", 1898808545531058),
-    ("0x51f5e990dbbca2065bcd0b5f6fd84cea1cecd992", 10615219645919165),
-    ("0xb5481d6cbf55128df6177a62dbfb27b5da79f632", 7437924315326277),
-    ("0x69636cfd9e64c6b4d00050f5b9ed9e59fc311421", 14048572097988901),
-    ("0x83080b341fab2e494f0018ccf201b7ffb028ab2c", 50711347197461925),
-    ("0x35faedece484e6a6d37e07b949e2abe036b52bb3", 27410143380534839),
-    ("0x785a80a8c590527e120453210e02792e4da1dbe5", 11968962221101350),
-    ("0x66e3e0403efdc4928a4a5dc606692b22ddf6930a", 38432176864422724),
-    ("0xb23414acb48af4aa4013e0aaeefd0432c31965c6", 40837896382797593),
-    ("0x9ab6133b4f073dbdb99967ebace039b7362d8306", 3356994646746804),
-    ("0x006217c11f51611d9848d17550bc4b03fe02deb0", 3065937729968691),
-    ("0x873bbf13c42800aafc0b6ccc443557b8941325ff", 11545542799716492),
-    ("0xaafa12fda2169f708b08fc97598839427ea069d4", 10792582411293890),
-    ("0x6a562049957e9f4667bbe7b6fab5b65d71e988b9", 5769019691034349),
-    ("0x3db5743b936cb7280fdd475562b0942e31843337", 26157695339472343),
-    ("0x7a1585ea7db0030baf1d1ecea87eae75536b58f6", 14144061441243573),
-    ("0x70224088129b2c7cfc58c701c70a6d88df01a015", 2628392347970637),
-    ("0x79e0f5fcb6da6c40f3ff66e9b41ac5f0a5a7ea1a", 134551083628909987),
-    ("0xb253b4c559452070d76569840d51dfc7c3075be5", 14118457273921268),
-    ("0x3ae00a272c3c8381e335e59f1566114dae24ed62", 7589381379485532),
-    ("0x5def348505539a4291e6f0e94942ec98e3eb66f4", 84303932399126083),
-    ("0xf66850af4da8bf46ce6c4dc1686e135c3c78ed22", 49826340983916490),
-    ("0x8146ac15542b470770d49bbd3402741d6febf62f", 8430693735663294),
-    ("0x1f73b9aeefac0d4fa808be6f797243553d14849d", 5950419406950161),
-    ("0x13f1054513b270a85b84b7f3640d63c6d4a3dd57", 75552408990145389),
-    ("0x39424c9467848094d5afd6768b48d10172345693", 38978101337048346),
-    ("0x1eab0709a00abc5e0d2d47c5557946d39af0f32b", 10771352786429591),
-    ("0x0529235303fae45d19601e4aefeef8272239645f", 12969541339840017),
-    ("0xc3818f8fd6e80f8477e90073cbbb687f37f889fb", 32222555534943106),
-    ("0x69ae44bc92bc344d69457d0a00d396590a8b1a03", 8582157447922383),
-    ("0xcb30d9d51a96d10c2562f5a79b33c7d15980a567", 14583767825081147),
-    ("0xefc25cc802f442003282fb108bf8988a167e3b6e", 40830534673633165),
-    ("0x5a9ada4d2ce4e585f77974dd436cf2f72c6a7ef4", 140228034254882332),
-    ("0xc0220349d63349162ef27a9c68cff0e8f8a5fa46", 1339601638982509),
-    ("0xdeea7be92cb8a1449a2bc5bbdccdc44925f92cde", 25236810249803166),
-    ("0xb9c779e67e1a2f7b42eb5b6edb333b7a50858773", 500000000000000),
-    ("0x8b1fd8e2a49b06573c59b6da602d13fb78e31de0", 500000000000000),
-    ("0x49fd2a09c7c4d63aed1671fe89a919014b394c60", 11423550591389976),
-    ("0xeb73022473140cb8f97737acd04c9e5f0821b8b1", 14454341045731333),
-    ("0xfa0563b9bc96f61d79fa024a15e73e63dc411828", 222048751553746978),
-    ("0x44ca5fba28c94b6b23067619c052f8af9175c953", 48775283439350321),
-    ("0x9e4a7bf0f8fe8e73e40d8711501242766d8d752c", 2731345148838112),
-    ("0x62a08f0535f01623890613f6acbedf19591a9ec3", 6834508920434408),
-    ("0x97fe41ff09be5fc730a73c44d2fe8ba249f789b8", 21332548946180884),
-    ("0x47b4d3546892237c55bcf9078b10b6be5e922551", 32906016368038757),
-    ("0x0aaf38602f6f5778ddd5f4781592b9574d5fbb7e", 14136083659209975),
-    ("0xb567f578a9c124dce08a8313b92e6e5135da2fe8", 4073170066266871),
-    ("0x92e0b4537388a8ed0007e753d26f28094f9f62dc", 5256309050361714),
-    ("0xd1a59dccb20ee4750b4840eaaa2b8a3796581dcd", 65768796032924847),
-    ("0x6a2644ccc7486515ffd7c434793a45917a6ce255", 170415696031496522),
-    ("0x4733684bd1ca2661d0bdfc1fe7f887e1629f4fef", 20826652306289832),
-    ("0x356630b3a424ef3e0329257ac7f401a21ab9b0b1", 132481699914342557),
-    ("0xf7ed3244004ed18d4dd2dee47a74d5c5bf126130", 15820888982279803),
-    ("0x0841fbc93fa8777427374c45f54e0738ef8be340", 111918511924176289),
-    ("0xa2fea96a03690519493bae7e85ebf893abd8db67", 31615996975600118),
-    ("0x34297442954044611eb51ec1e513224a9a354ac7", 45280141060467373),
-    ("0x7d0e096c7cc8294c6ff344313565d1363f58b136", 26378484926409695),
-    ("0xb8953930e19d0004a7f25710827e8ef21964a09c", 8411026677306511),
-    ("0x4266f46db664cc5e4cdb965b9f374402d5a4e1de", 101694317000464349),
-    ("0x14f4ee68019f6ee0d3abbb56f6404c9a4671c57f", 23787345137756889),
-    ("0xa90988c37a31ed3e7b432a2e0b3054a7974e272f", 7157340795450083),
-    ("0x96c8702b3bec2033d23085357883a9fe3ee74ba7", 38829315288659437),
-    ("0xd2b5cc848704315502a9220aa2025b1c0789404a", 12841349446020531),
-    ("0x95537347963b2cf20d2073af268480112fae7ca8", 23507500654407554),
-    ("0x00a44a7c53f6919c8e1befa2119e72c054e7425a", 153309650591701283),
-    ("0x7c356d5723a70f9d37c01ec7bc62c63e64c98e53", 14082109606655224),
-    ("0x6cc9afbabf474f6723eddf73500210e0e42961fe", 5998645746900164),
-    ("0x0b991774a21dae5c6b6342f77aa8d7a92b288a6a", 15470838553546790),
-    ("0x3ae11db0369b33be004fa1434191ad1eb25f9557", 2760659727277691),
-    ("0x378feda987d7e7787cf9dd0f5cd7c2c26f51472f", 68784179761985000),
-    ("0x549956701a4624ee627926bdc6eee7e8af02dd6b", 10639506001356424),
-    ("0x093af85561f0560a98e2f1e0e05204bea2d209b6", 64388968134227872),
-    ("0x2ebbdf8c79d3d9653d555eeff63523ffb8080078", 124310751192070815),
-    ("0x16bd8b83a993c9529d96c871f94d5e1ee174f4c4", 10167762077827352),
-    ("0x658036854242498de344cf92bc434de5b9afdd29", 28234351612231910),
-    ("0x5adbbdeeecb88fbc62f29b21b1f5ba6c9ca04aa5", 7214838009321675),
-    ("0xc9bf31844bb2eb5639520a593b08fcf1eb73da6a", 1981522755952484),
-    ("0xbbce57c4604149f97a10eaa4a718bc8f88b2e2b4", 27214289159004266),
-    ("0xfa4ff40b0854573e9922efc26c5ab43e57db480f", 10000000000000000),
-    ("0xc4362381cd174daab512bf52cb64dc675671e009", 4206218830456418),
-    ("0xe85a04cc499d97826254d76cbb97ffc150f0a44c", 10000000000000000),
-    ("0xe3d05e27546a15239300ca9e49d17d676c230688", 13920806512427136),
-    ("0x689603288e5d27bd8ed281104bb1f71c79b14d13", 12437151170038369),
-    ("0x9c9cbb0ad3a241582368ba3483f44a89fe6bb9c3", 13817792781835445),
-    ("0xda98605b29a08e06bac2583d845d7a58873b1ed7", 7823029309888225),
-    ("0x9caa041550a2e141cc80a2a79584799ec8c5ea68", 8265756999650864),
-    ("0x629fad392bcf377efc8a6ca3549e97a399e419da", 16058381608581454),
-    ("0xfbc2a74f06934651a30d190c02d0aeeddc30670a", 57887202568465431),
-    ("0x40979d7433ec9be919c161c49098d64e06d813e1", 12000000000000000),
-    ("0x8584a36e5aac8097d7f3e42391ccea88044d6c4b", 11661158816165678),
-    ("0x94727e6cce1a30e147297c79595d5bd7672d7a18", 29511847050162336),
-    ("0x2e88addbe05fd79d38bfcba2d0a89b50a38b9d55", 25561208670184203),
-    ("0xb23c0019e9e788e9fa478bd4e8a74f07f3eca9b4", 31321748984459181),
-    ("0xa562a1596e54282c92ced13359e9c30a8c8a7e94", 6327790364347279),
-    ("0xb6c9c3026b7ddc62b68ef9579fc3430756a9c44c", 10888824851096817),
-    ("0xd9cfab54f1234aeea22b2818ab919866a2809c1c", 79329655292445362),
-    ("0x23d208c963d9945695882ba278e1769918a241fd", 10300000000000000),
-    ("0xd22a32c00225e01015695dd544dea23682a4091a", 8536679347567143),
-    ("0x6e6fc05100fb491526af1b273c59f5890ffc1ba3", 12789951777351661),
-    ("0x215244e6916df668d8a8ea857a409cde435ab878", 15017458303778454),
-    ("0x010255c022c7b3db22e7418aca9165e7cc155760", 3128831265987609),
-    ("0xb8638d34c22dec498c6692ec76ae989f5afc1288", 4349903618791071),
-    ("0x7faa8d1cb6ab8a9fc039b66fe844575557389cd4", 137450190279981284),
-    ("0xad16b9e45af4f1c5072daaa74dbcacf1a63a6777", 29327778127940262),
-    ("0x9db12e7a291bac60747fa4922e6c905cd873cb7d", 11170069306210107),
-    ("0xeab44dd5e7e1c9dbbe3eb6ebc121c3cdd0cc3579", 14832233064652047),
-    ("0xe6fa31aa7ebc6de1cece0a0f2a042dd94cc9e3ad", 6117610309342037),
-    ("0x924f92f75837b792652908819c3b8082139c88e8", 17025783806658560),
-    ("0x3b5f199efdd46c53a767860ec438f406695e5799", 10931194280481048),
-    ("0xa6b7092879673a7f4b8c8b4655f567083be56b3f", 19723337682167882),
-    ("0xec920601a41973a80d27bf14a7d1c969969d15ef", 3187370326734564),
-    ("0x806b9e450869b316d8a865801bc9d478da51ab6b", 2699876365601730),
-    ("0xe8b2d43eb45074b1dfac494641e15791b32d27a6", 19320619647015180),
-    ("0xfbd1b537c4c890472c2e6511ac6f05a67430b91b", 68158181600983872),
-    ("0x34f11dde65763eb10bd340df8a76ab80a48cdff8", 16623194593941113),
-    ("0x71a9a4a74cc6ec04e453e2db8c9b575e16bf4ab7", 62486829192346866),
-    ("0x076da4cde049e6f1ae07b995b100e43e45406c38", 5514239759813184),
-    ("0xfd7bd29e1050932829c1fc080ea42d7394c42847", 10877335508172569),
-    ("0xabee5597eb23afde43f0b6654cd60ab8329556c7", 122806589921138605),
-    ("0x3b7617514540f7968d893684d1b3fabfc6ceecdc", 48438496462026045),
-    ("0x10817a5e4400515e85d2e0e8c2b190116b653c4d", 9531000000000000),
-    ("0x4877678fcf2f064c54f2a2d2a3f326be7dc96016", 22128164842462962),
-    ("0x815227fa1d025ab89f36566dee4348eee3a4e8e3", 28028231883779836),
-    ("0x9cb8f13741527dee19c8e63ec7afc9ff58ef9876", 348864754927778321),
-    ("0x2bcddb9c94a3392f4a6e8229c42443c128866f75", 92290913853350772),
-    ("0xa0b5838653a9e5f564ca760d89b57d21c5256694", 14551546302356522),
-    ("0xf02de05b6c21d01f1a223a8f0fc2f032b24fc57b", 52310654418901497),
-    ("0x3a1a5533a52d2004d2f6150c708981d28bcd3eb3", 20558831941251637),
-    ("0x85c2339653a7232dbac2085318ff8d0df17d1282", 154393441528622779),
-    ("0xdc7f3c5fc1ce22e8d8f2c35029055bcc06ea1dfc", 12416275244412765),
-    ("0x39fe649694bf75ddaddde7f2629bfff2709b532d", 6743632403254506),
-    ("0x1d840c4dde769ca6d65ab55398d1f60277f1963d", 1764037491474083),
-    ("0x3d1d397acd3b0989d9e633d6dbf7e6f8f5c03a2d", 42661156490917739),
-    ("0x44fa7d5b20c9288651811caefd976e8dc0c26416", 2786639055542888),
-    ("0x82f06c4ba5e9ba5df21d26413a2d817990731515", 14828101089034443),
-    ("0x29ec750f3ca2ac5f875c4ab7a2fcc9f5ee7478c9", 14869418957359513),
-    ("0xcb7c6fa166b77dad28a82c43f9e29d8d44ef29e3", 20228302649740000),
-    ("0x8fe9c787995d12b6ef3a9448aa944593dac93c6c", 135666055228842391),
-    ("0x989f13da94458e9ce23b93fac30a1a33706968bc", 50000000000000000),
-    ("0x77481544f3b083f77d9d0608de93b64b22dd7f5e", 34017821877357053),
-    ("0xd74ac01226c02c32ba66d2929be5735f1367d648", 6621655815707231),
-    ("0x0611a3e88fb4387902b411ebd15cb1b5416982fa", 30142774332759408),
-    ("0x84de4eb6f08a305ae6f054b5e5e8750310007a13", 28541701162974628),
-    ("0xb7e7f835b3bdb281cddef600479f91018beb1270", 807586646861132059),
-    ("0x304016db3eea6acccd988037fc9734a18d9dbea1", 7367113340777517),
-    ("0xc1f6fd9a19e425f72b2ee6293971535e39d7e4dc", 49684030610844183),
-    ("0x1f8a9aa97618c77fad4be22fba26d4ea0507119c", 33632018220326118),
-    ("0x5f399aaf88796f51207aaae3f4c4ec9546c95517", 13801807111854553),
-    ("0x1e3abc249d0f31b23d8d6b64606895d76ad8fca5", 37885155797882147),
-    ("0x62a32ea089109e7b8f0fe29d839736ddb0c753f6", 73094531337782255),
-    ("0xb990b8b21cc1ec7c0704789ccd21bd516fe52ba3", 14998845896304961),
-    ("0xa0d1f848d57d3263058fca4dd6830821e7f790f0", 85886028228298255),
-    ("0xc4eaec1554038d9cbd628ca16da97268350641da", 13915838570871783),
-    ("0x97fc1fc5cac8367f08b3fc92187d4a898c785168", 13270135112848104),
-    ("0xe55ed2c7734ebb3e7e9ddaae18b1ee592a6c8a7b", 462886854586560617),
-    ("0xb34d352ff533fe9669dda12da8b00bab8f033033", 58266535447501336),
-    ("0x4bb27053ca095471f2a184c12c904896e184f74f", 7391332561168440),
-    ("0xa3b4817245ce62dc062672110d873f70325c26cb", 58731511457524466),
-    ("0x32821fa3b0da555f1a5b898c5ab16af4ccd82f8c", 231336625190961818),
-    ("0x98a9d8d485fef62d9a92b6ffd7220db7c85a9017", 5899371255483804),
-    ("0xbec351a88fa58498b86e42500dc36436db65a752", 15132915506888618),
-    ("0x2027112604ae49e464cc6db78b4c58c539bcff4a", 231925623012193557),
-    ("0x6d8b09ebcd47489c77f50599c30c6726e38aff6a", 5748211204981406),
-    ("0x36884cd3ff07558cd5bc4f73adc76cff4677609e", 4858840207159650),
-    ("0x2de8ff0749fd129da6449d869b5d741ae9748ee6", 14914243856865933),
-    ("0xe9328491df19d94166b5baf80fed06e6746d7280", 15530845864908942),
-    ("0xc0be966f1bbc14e72db9a5f17e3f6f5407d0cb60", 61575698487319328),
-    ("0x8982c92337c387f114dc43cc71aeeb310e5c26bb", 1775531410423387),
-    ("0x5edd21bd3fb958c4bebbc0b2dc3db37f035505c0", 7565153766427770),
-    ("0x4de65236f43f6c671096b07d040394edde828f12", 4555265779424842),
-    ("0xe80e7a53881e7e180b7885273590f4e8d5c87c0c", 62433830045665553),
-    ("0x0aca04609e28d9ebc088571134a89e5c272f1108", 143210796500776485),
-    ("0x64e8fd78ea7741c4a193efb221e98fcc2d1348ea", 1000000000000000),
-    ("0x12f8d3b452f96b154daae55d73577e9721459a76", 1000000000000000),
-    ("0x55412f2ad63f6fed7606e64cf0dd986a88241cf3", 1000000000000000),
-    ("0x616453504a7ee36f9541ffe55f00d31f5ee46704", 1000000000000000),
-    ("0xe2bd1fc9c11876eab9098e564f6d2e3ab09c243b", 1000000000000000),
-    ("0xdcfcbece2f6a38d7fc54f75c552021df67082ece", 38503553942000000),
-    ("0xdbd124e898839ec8c829d806c0328f069d1bb810", 500000000000000),
-    ("0xf3af48e6ce100172338c318caa4dd0ac649460fa", 7569699329518616),
-    ("0xd01130a8e4e9fee1bba2fe966a7ee6166c7ea3a7", 56540894426801010),
-    ("0x8860ce7e55519484eac883f3abbf8947ccf9e3c5", 1260439787030558131),
-    ("0x9e353fbdc3ec7290290bda31a8001cb609858adf", 71736639048321151),
-    ("0xb0f011fcb64df9e3ac2b3a64cbadb281362d600f", 35296668173481937),
-    ("0x85b2566ade4a8f7b15a09cdf7711561c4e420e71", 10002343113415235),
-    ("0x1aa205bbc76f7fe53501ce1e6ae551f46bf93bfa", 94703505519712844),
-    ("0xd3b2df96c968680ca5c5c3ca1dd66cdde591a351", 2764633123043229),
-    ("0x101faa2b54a7c3e74ae165f966115d96931db7e4", 15022749864905806),
-    ("0x46e9b39248b8edbc78cd8ee550f71875e5297319", 7538642940317936),
-    ("0xfc5737a0fe325d6b2e0c9dc2ac1d81f549fd662c", 259805475499109320),
-    ("0x3594b221e1c6248c713ad9eaae1fe6a367978851", 4289309272263900),
-    ("0x48fbbeab6d9ff085f9611fdcbd2835109cde6bdf", 27847298809071121),
-    ("0x83fab3353da89d881613d596e61c46420799ce36", 462251764227660462),
-    ("0xdadac68f07a1d73f388d2f50494397715409ee0c", 33221039298778307),
-    ("0x620cb6f64c2c66b8632c045979b28d14c5dd605e", 14022015898036423),
-    ("0x0bfb19be28bffffc5dc6ae771f5b253242d1771f", 3799331110914326),
-    ("0x382670da56abcdb1055f9343fae64a159a1b1321", 65389902536564653),
-    ("0x76db54f7db9a0fc37de66f67c970b4ac072646b7", 92972663668962936),
-    ("0x44acd0ff3be9fdfb932453c82b5df5739d28b276", 143132696836479368),
-    ("0xe2155b15a8a422e4ce354be0bc1284b8385399be", 20589900417428604),
-    ("0xda5837254053a9ce462b1570d89f855a366e0b35", 345618400217019023),
-    ("0x9684736aeed193da78f5068ca979b23dac7f3d5b", 3059700000000000),
-    ("0x3d2b0f95dbacff1d1d782074918e89e2fe184a0c", 128703406620501028),
-    ("0x92380a1e535c77b2bde8efc5b21f658e04416e1e", 12632531830309863),
-    ("0x6c2b4491ed676950abb902b1020c6d65487c5b0b", 64396368047645701),
-    ("0x3b1cdc976979c4383322658a1afc50f027598b94", 26299211417010993),
-    ("0x3d4ed774386e7e81fffb27d0ceaa554e2d529311", 16829665562664741),
-    ("0xabd9f6f945f51c8cdb5385a2cddc886c0c5d1cf7", 2826552137348112),
-    ("0x1113c54cdb2ef5b41d6f48280cea00205415e6b3", 139215779042634559),
-    ("0x67958494e70c77a537101ad0b35b44ad3b072dc5", 132014912987897817),
-    ("0xc0607575df410411a06833affc54b424a56b385d", 36727336450494849),
-    ("0x15427c44b1ab3e14555c4b193ffc804a451f7182", 5457067322152035),
-    ("0xa53a92ead40841535ff9129e0bd68e83b3e271c9", 23013265421263274),
-    ("0xad6ee7c40d7d80a6c404e82e77cde5c5088e98a5", 64405539199066192),
-    ("0x60e2a272b6233d4fe08908bfa6ea44518501732a", 49052721274654896),
-    ("0xacce74f9dd9f3133f160417a8b554cd3cc8a3b95", 26278019658472543),
-    ("0x118385b5752f7190317fd526d665db8a6242973d", 13071120137250827),
-    ("0x13cdf1e6f543b2a78ff2a5be9b139e3a96a4deb9", 500000000000000000),
-    ("0x71e738d4bcde3afc603591adcb1baca115defcc7", 3560566555273513),
-    ("0xcd793814531b98c3f9d60b3c18839510224d1069", 13525523658684037),
-    ("0x4365bbcbc6ec28e992269ccd1a5fb46bfc7bd2fc", 7382577419626867),
-    ("0x67c47b1af7d8b7352fde8d9b07b7e2ce0a22508c", 47274418588356941),
-    ("0xca6b7e28c88b30b04bf6c6ac2108c72520804fee", 13802945394767980),
-    ("0xdae80117fd91a7064e7e7ef3341513230c3c94e6", 20121000119702314),
-    ("0xa5c07b2c9d55de33d3b9d9bf1c36a8915140fd4d", 12038817529806838),
-    ("0x63ab0a35537834e8213072be7637258409666741", 7260257032970324),
-    ("0x76732bbc532308837c111aaee514837f22065ffa", 500000000000000),
-    ("0xf4a25ef4df76e0cab20c92464c6c43762cf50d05", 24938329119114821),
-    ("0x3c8691b2d91934ae0053e1c6ac95f16afabaa639", 13663916650663972),
-    ("0xb65b11637e00dedf0cb9744bc2114775011366e4", 6860225424973710),
-    ("0x6e69e29cdae9e82a9abc8a8d8b7953d8d8e4d6a5", 21281053419697851),
-    ("0xf4888f701bbc96c711fc8bdb307c5b482f21290d", 155498670586665087),
-    ("0xf11d632d9e3fc469adb3230e6cfa4a90354d2f11", 101022151389066969),
-    ("0xdc3b2dce95ebd1e7c18eaa37780d10d240146660", 125920921624624690),
-    ("0x8317e737ce3e1beb00a967748e6ac5e982d0dee3", 18960944361941113),
-    ("0x36e523bff03716d430d265d71b9521dce25db8be", 3878805452985394),
-    ("0x1c7c7b93f7d17a0faabfe6f99557c89493ccb9e4", 11198062815091517),
-    ("0xc31db2e710192791b65de43d4b84886a6d770322", 55070108453759102),
-    ("0xaca7236d6f5af4c848f8b52a4d4b7fe73f2a6273", 155151985272312918),
-    ("0x82646aa083bd1a83b02ecc172a169e39cb87ef9e", 61977954771095008),
-    ("0xe3c84c395457244d5d90da3b3cf59c268c654fce", 6644885674380015),
-    ("0x9df8c21e4e482bd1e4a26fb3493c365293b31a9a", 34368936327008115),
-    ("0xdedaa514b89fee98d5147ef963e800d4390b6384", 26480324488896923),
-    ("0x19bdaced45799f0a3b0c94f2f385bc4f921975c9", 48725778632580180),
-    ("0x987fb317888cf344da7fc3836bcf62a9f6718858", 651598069494266151),
-    ("0x907b9c10ce7604ac5a40f7c8e71d6538b1fd76d4", 13834139682918385),
-    ("0xd5400fbd57be428ae186cde13f5ce70b3199c7fb", 57190000000000000),
-    ("0x7e6aca05a45c39ca59d976f34e87875a2805766c", 37397684945718786),
-    ("0x89808c49f858b86e80b892506cf11606fb25fcdc", 1002776993134003016),
-    ("0xb28340110fa820b3750e43bfd821e24b1e72eec4", 10758876522944344),
-    ("0x811b0fbad40bf38c07dfe48687e199118815499b", 33751334973239894),
-    ("0x09737b691c2a04dbff7d27efdca6d57725fa0b0a", 79981000594994685),
-    ("0x19256dff4fb712818c693dd6b097bdcd79dc5381", 19144768559790832),
-    ("0x7aa69b5ee49c4d3170e037960ca6c83f61241395", 2642488650890412),
-    ("0xa7137da63b53138a6dcdc9d9a010f42f951f1113", 7700003116191176),
-    ("0x24fb9f6d2c72a00224ce6ebf95e007a26bea8d4c", 16572054284736062),
-    ("0x1d3426051b62906a30ebc852d925088e1129d959", 25983083291095169),
-    ("0x011596798fb59ddf7fee604a8a07097eaf341520", 4904794988206396),
-    ("0x64a80f83dfad7419f9217a82af37b24b46df1262", 15258281626945559),
-    ("0x984253cad96157b89255f4674e5362fc0feb69ee", 81685434022134380),
-    ("0xdc0773a69d61a2d10ad4aba7f3d8cfb250156744", 60246635786384967),
-    ("0xac1b80e06f970879bfb8f2b92b59442593f332b6", 17156472977189436),
-    ("0x4a23abf4eb73af80af932ba9abf89f4efe1b4c96", 4185529411513177),
-    ("0x50759c1b1a6d236c5aafb689aa8427ebe4544224", 26013796005410352),
-    ("0x01740c9fcff3607531b363dc940378d2a9fe837d", 23137702050307311),
-    ("0x06f33e10e2d04a306332dcddb82afb071d2bc51c", 37722198729685325),
-    ("0xac3bd1ce1f6bd693e2c87b7b015e47f0b66a07ff", 21701860364991028),
-    ("0x901e4b9392e37ca534ae66e851d4a17b8b067c81", 27215644718543567),
-    ("0x2f9389affdb2b2ca43cc72747fb789453b93a5a0", 3376936644216104),
-    ("0x984ea9b9d18c802019b515319444e774a8e7d658", 111245815259972659),
-    ("0x7f5e86ca7d5df855ebcc281fdeb39022256907bc", 13323061027591993),
-    ("0x056fecdcba9dd9006598446557f0bbeb92bb2cfe", 7443820633106495),
-    ("0x0a04907422c1c70d26df8770bff1b17743d65cf1", 13202262165599765),
-    ("0x3b2e08243aac72b3c77db6a6a43ebc2185deb75f", 22953661014625304),
-    ("0xeb3760ee877beac5852d3e514db703ae59f8b084", 25966962407772164),
-    ("0xe15494e529d8ce5a9b37ad51bd082a884cf3fa74", 123067702381941201),
-    ("0x0b1877f6810371a0b995d7c83829b0b9cf974448", 25353837205383043),
-    ("0xb38f4b98242e13461b2eaabc37a2faba50aa9dba", 7384949333063716),
-    ("0xfd867fa6fa9dea501dd21d84d218b9dfecc29678", 17211500000000000),
-    ("0x4a40bc93b4c8724b9ba9267587f37e6e80cdb358", 111228495997695278),
-    ("0xacaa6b5d4fd11a09be764a1e35397906db2cac26", 94072687122179679),
-    ("0x547bb6dea364ae3e1fc706f092bbc6e781b26d08", 6726708109506476),
-    ("0xa155f0c13156bfab50ea12ba671a0f378bbdc102", 39593786429742355),
-    ("0xb3aad5a4b88a8111ffd021d6d26d9464a06471af", 50000000000000000),
-    ("0xa284ea907e102dc60d7ab9fab58fa046565b436d", 12808304965220534),
-    ("0xf58465f19b14129ac0b9701358dbb3050f17e505", 2054358884232705),
-    ("0x7809e5ae00fb01be17d15cc484b2c89fa9f731ed", 500000000000000),
-    ("0xcec0fc02254026466b30fadf727b632bb5b17f6d", 13276542701923159),
-    ("0xde9046e9256aa08b6701eb9a729ad35097e10a37", 5536284205708098),
-    ("0xa4a0237be9cb23084edb9eb186aca79dad7c7e96", 8311331300131625),
-    ("0x8dc490643c58795e5c4dfb9b24682aa6d9dec6c0", 16361500000000000),
-    ("0xd1b14591d533de37d6d004ac8c32f6a9c5a09014", 5765119438010157),
-    ("0x8db41cafb23dd81cb624f33ff590906c77ecb8ef", 3161172004202372),
-    ("0xaa039ccea6f5d6a15feaed241a3f2ecaed62db10", 20000000000000000),
-    ("0xb5e1f96aeadd29bb2d363ecc7a0b8bfb6e9f4ebd", 125725650705304937),
-    ("0xadf56ed28c73aa814545938dbb46789611e343a0", 5638700995739358),
-    ("0x42ad79b6b9dfb804f71529fd590b2b33bbebb84e", 10280821618651431),
-    ("0x44df82f84c955b185bd1b55d4813217504c0ffee", 11170991926997031),
-    ("0xeff5b3bbc33c1c2835fcc75e77103c866ab0fc62", 15000000000000000),
-    ("0x93e1da1f2ac332068ab6b9f1a554f621221a82ec", 6108468169403786),
-    ("0xac5c9e93719dba220a8fadbf5c415a40dac5776f", 40442234528309462),
-    ("0xe3c12dbed8112f7c3a192df337a0b744786e294b", 15352659278949009),
-    ("0xf40f8cc5808a4f3f6661c8f1dfd5e0b4d1495307", 11599051676946490),
-    ("0x72933f1865ab7211848e09bebbf54ac93815a87b", 52719905735976749),
-    ("0x560c8110b2c2f85261c0f761e329235194daa6b9", 10956619394182455),
-    ("0x18f97f0c6497cbd4d9644ef78e90222e217ead3c", 4825314033809653),
-    ("0x43e7d33f026ae402a87700f314f8fd1556e3f0de", 27505844624615275),
-    ("0x809873497721511bdf5075d441fd755bd70d8089", 17000000000000000),
-    ("0xfd0cbe567f860ee35a79b72bd8850b7d9c13b568", 17475358045208726),
-    ("0x8078a5bdd8991767e4bcff4f0e08c550fbac2376", 1321075909319115470),
-    ("0x17ba0c93587be8dbbce33ea408760b5ead8fd059", 23889954962332359),
-    ("0x6c36a2acd3cfc6c76610f3659fd8ddea4876b4d4", 3726134266674539),
-    ("0xc4f91cdd498a30f8ed1dea3883cba314a7a2a022", 21726750614781091),
-    ("0x4b8c40a9df7ce40a9d63b660f7fe64109722490b", 68215329564664863),
-    ("0x8a282ed6a364e4217bdd7e1856918b80b2b764a5", 4490100000000000),
-    ("0x9b0e7954e36f9262dde23f46624d746be965c234", 31441036940806724),
-    ("0xa4d8e21f63e875c1b544d324908f5babd4d1960d", 93112984028914284),
-    ("0x1e1cc06e40cbd2e5373bea0bda2949a437c92041", 12032522397247570),
-    ("0x2669a7565899ab4bd7b7642aa2725e751de6035c", 61806125673600565),
-    ("0x000f4432a40560bbff1b581a8b7aded8dab80026", 6969942649741204),
-    ("0xd7dc9b1bc54ddb735a3823951c037a6b324c4d2d", 39564349821657403),
-    ("0x42a3312a9ef4ee8ddc1fb06429f7cd332fba2cf0", 39035783548865909),
-    ("0x5d2f29aa18aef827317c48bd2b4f05fa24880038", 24070479083634023),
-    ("0x8e159d960c6c724f02c899f62198f9f0d7c261f5", 28050918704985901),
-    ("0x30ade1d27482fe3b36421f1a80d996e4c09a920a", 16500000000000000),
-    ("0x19b7d13803a7db495630b1fb6396a488b065d13a", 19341182837498673),
-    ("0xb20c87f3ce6b8e2e2493e0fa7b1b3aee2d618153", 6987517243187357),
-    ("0x21339313dd4d44713fc075fc4ab8dd6b8e9724c0", 25925001112520066),
-    ("0x6dba3f038becc02f4fc81ef25b3059d55a28cabc", 8549737521032840),
-    ("0x4138574878c133d3a12009d6f54b8f26de700834", 13253851654600914),
-    ("0x97c2394763d61ffa46581bd68ca8385a32f71aed", 94167693570009786),
-    ("0xe6053414f9b27ba19f980796b574086abe5548d2", 6481983790294824),
-    ("0x4766b44ece0eb77db06b8f78f99a31ffa83cf60a", 5153613180831891),
-    ("0x2ffa2d2b6d66bb11ba332f3ab26e3ccef3b76724", 14693830258598807),
-    ("0xb7a0738523de5e8c2f40e5ea1009c68c25004aa7", 4463306282847827),
-    ("0xc6e1f6b6ef7cd7ce40e5ddc679956a202d377cf7", 10977418315767641),
-    ("0xf9167d935ab22cc34006c33046b87bf7e5dc8e92", 31430107102876305),
-    ("0x1682135756404355f58811f8e495d999ef3ca0ec", 13584360975544732),
-    ("0x3af30b956f1737005b6e3146dbbd35b70f30df91", 15597432368191406),
-    ("0x514fba61fc76d94094463969d13ed1b8706c9118", 17000000000000000),
-    ("0xc740d49eedce4db3db0bd17363e58ccbc2fa3f18", 13244880795615187),
-    ("0x919124cea4742eb8bcf28dd94129f84f72505b98", 5512515609534342),
-    ("0x7171341c9761a254b758dc86d2d2f7025c243a3e", 1898880959491857),
-    ("0x081076bc1b250650f009bf802425ec4979569a59", 17350000000000000),
-    ("0x14b46ced7c91bd5b57e566fe2bc0ad64a04775a4", 6776845999413460),
-    ("0x55eab065b4ecc768eec3907a9ba70cad135dc633", 82867196475028411),
-    ("0x9f1d7f9fb560c16703103b2f42384be760baf889", 16661307549446878),
-    ("0x288134424520caadf321ad1a7dbd4f11665ea26c", 8943988551707640),
-    ("0xfb7c1d49e006eaddff2385c7ef8b0c5cf49d038a", 21579018817672398),
-    ("0xed380970f4f0746c56c033f00507634a985ec330", 39919847787140407),
-    ("0x502b1ec117d6777e92c8144a6d762206346734f7", 224427788001117639),
-    ("0x107638bcb5b2aee58fe5d112e14aa0166ad29345", 15482334340566431),
-    ("0xb20ab93983e7f6e9e11f424cdeafdea7c99180c6", 8296427150484654),
-    ("0x69d93f3b4a25ea4dfafd782d9b4f26e9d9ade837", 76360108335084235),
-    ("0xa3a89931799ba449a7bd1658efd9c8662dd22bca", 7086152169340609),
-    ("0xe87081eb0a3cc4140798a272de655bbcc8462521", 18630290195628337),
-    ("0x284edac35636a72e8bb99f33b12d0fe521c7fc47", 9414308673379865),
-    ("0xb4d39242f35bdd370908de3ac2630db3bc4c8c64", 26392625736418169),
-    ("0xf8664f0c1cf0d74750d53a6b8c0fbce4582cb5af", 7077228484817838),
-    ("0x6f4440719569d61571f50c7e2b33b17e191b0654", 23985756168569370),
-    ("0x91b212c9e476f299333e1dbe510a3e518bbbd21b", 1100000000000000),
-    ("0x56ddae77252ae19f48882cc2153a974e39fb03ab", 4859580940735550),
-    ("0x360affea3c8a6d2179b025722d316298bd21cb84", 6336538557753218),
-    ("0x1852b09a22842fd8511b1d017ebfaa59b7d0877d", 3490746804672099),
-    ("0xbf46fe8f19c8dcfe54813a5f334adb22ea44255c", 13150108375710962),
-    ("0xa965e9251c74e1df7de39947a00bb3da393c3f8c", 64912290971424580),
-    ("0x9c9c02e8ca06dd83db000516187e6e96ee4e609d", 26286754762342761),
-    ("0x18bdd6f71341c8bc169aa734647df0638a684a0a", 28052726188195236),
-    ("0x81409e4c1a55c034ec86f64a75d18d911a8b0071", 23959771103007872),
-    ("0xc387eae2d5df14fdd68a94acb3af957be431d0d8", 39059613533271968),
-    ("0x5de853cb6813f9aefd25635776139d8c0aaaf77a", 65664789460598693),
-    ("0xf396923a852cb675aa52604ecd6489385da4b9cf", 13355050781818246),
-    ("0x20b2ef3736845a6b75397ebe78c88469752af4b6", 1854702572124680),
-    ("0x1092d7cfdb86d44efaded83102c7c746d3ad3dcd", 6980201423212406),
-    ("0xf22b6c344b4388dca6b311f4c876c9722a69ce3a", 26641609557111454),
-    ("0x1fecb2e6ed1e930879d86d4734489442f2f80952", 26310552089229520),
-    ("0x7d179311766e8b2d8571b4dcb00b47f5371cada1", 64778126559168911),
-    ("0x584fce8146c600c24e5156c7c4949cc565c00b15", 39622573375996508),
-    ("0x0ca0e250626602aae1fd3eae436d41bfe775a442", 64139670772476585),
-    ("0x8c26aadce492c21d914d4e024da03768c59124bf", 80792998814673458),
-    ("0xf6c81b31e909218765e1733994170a5127b31141", 6674339032567101),
-    ("0xa01a71bfff2d6f46be588d5e695d7b98c2cf999f", 5677345717827270),
-    ("0x0cc0fa29c509633f382354bc1b6538bc131bbcd6", 127868613240463226),
-    ("0xde272f9c3185d0f8720988b77dc38501f2cf9ff6", 13662232503550154),
-    ("0x7502870924e43c71bedf0093a33f4b8f98046b88", 14245923024133917),
-    ("0xc3685642cf9c2f622e2969895c06dff35c38ff4b", 4072480096413043),
-    ("0x62cfc31f574f8ec9719d719709bcce9866becacd", 7584089137298866),
-    ("0xdca821e335fd379432ac8ba0608052412ca99c6a", 25270926374213622),
-    ("0x78a18ddecda44f7d298d9b37512ed8a7f6b226c7", 65483893585225245),
-    ("0xdabfcc3dcac0020036ba15014ea38950e0e744fd", 76856495269124177),
-    ("0x107cd74419dfc9ff01975e652597c5a4b6706460", 13618540560099762),
-    ("0xc070b4384b472df637a389def078706905edad07", 19535000000000000),
-    ("0x4615bfd8ada0dec0f3a994e518703728055e072e", 11776908460304331),
-    ("0x72e6631adf75e813c09ecb4ec69b45f461e4fc2a", 13988943556596111),
-    ("0xc06ce5b9d8b762df1877621b1b3e59d9089e2dd4", 38180701948127829),
-    ("0x7ef34907ff0e84ed776f6c0294bb1668c0b9c8a6", 14412116292881658),
-    ("0xa06506edd9868e40587125c458c6ed4c0b4fed3e", 33879893607642435),
-    ("0xeb6b51d158a5fdf67ba74d66dfe44b396e4e227d", 14259179109318267),
-    ("0xccf9a2f4b558d0a23d2f678adf2de1dc52fa343e", 13586643783123680),
-    ("0xd23d8163a35780480852662996508689c4178192", 50347488111330016),
-    ("0x02970c31a80f7c4b022cd30c6f9e6c1c5b6e5ef4", 41161145358500817),
-    ("0xd5cbf47478516508998dad875f0cd95a5c3b572e", 30024592796439766),
-    ("0xb06773492ad69b23116022b25ff0fdafa58b70ec", 13254025768220675),
-    ("0xac5d145e99cc5a92500d9acb753a9ac28d7537cb", 6959940091985091),
-    ("0x82403914d311d9773c71cc33edf31dea460d0605", 98910476244231736),
-    ("0xd2c991cc5bdbb00195b8c814abc1cb195dbba910", 3516745228972301),
-    ("0x1121f4ca9fb820c6b6731cb15efc8f0b77b1c5f9", 7477290759452102),
-    ("0xd6fe21da1deed013c4722391749e545c9987e8e8", 5952759361545910),
-    ("0x49fac2e62a9f47d99b3a25adce12e9cf056086f9", 32499827263838564),
-    ("0xafcfec3e8e9add711973654198166f9256c7aab3", 15083204055969727),
-    ("0x09efd0d038dab6adf8436118dbd51b117b50d4f3", 161281302767005100),
-    ("0x593cb13b5bbcb2ba8456aa15cda4c96418373faa", 25980405933088523),
-    ("0xb78662d83b2337dccabb73c6e2f868e1d312dcbb", 144549267096898630),
-    ("0x1988c7d5957f5ad250874206e5bf92913124e5d5", 5205000000000000),
-    ("0x30ac10d17b1ec32c74948f3651ff4a073c782479", 11866503993473918),
-    ("0x5c9cd3ca797fc1cc48766235986ab7074b202962", 73778899694347837),
-    ("0x640c8a6fd4f4a26ddfb383b2a30bacc052da9dc7", 4864224746082854),
-    ("0xc9d4f265d008b0fc42829e80dcf3d5f14835d0b4", 14593060090921561),
-    ("0xf56036f6a5d9b9991c209dcbc9c40b2c1cd46540", 26982247151757490),
-    ("0x69067224dc9b06f2e28a8db9e0599e2724966b47", 5091548343599535),
-    ("0xb83f97e471fac857f03064c54d77b96856b79288", 19171629268108711),
-    ("0xc87df217f43930125cab2a808d0ac76771714fc3", 34156452881597454),
-    ("0x6597941ce634514ba13f04b21fe677c93a87f209", 26568407787491039),
-    ("0x5ed7e457a6786062466c5a2e68b073e79570c0f6", 11634391408556607),
-    ("0xbf7d83ae0873f147ae1432f2ebc0c116daa9c919", 13526242237308814),
-    ("0xb22d74a9873d98da7b5df53261f042bbd92dcac4", 2082000000000000),
-    ("0xe3a531713fdacc52f076bfbcf71194a9c921ff4f", 4831594600472893),
-    ("0x1b433368353d434cc22310fa0b3a3a2ac6f8ea99", 1238700000000000),
-    ("0x9eb08102c1eaae6335dc47e56ec90a0aae9849c8", 2196451283584503),
-    ("0x67cda0e16f96ab6ec66295159615df54d6076612", 1323832238577871),
-    ("0x99999100d65c816e6a7bb972a79961e0b97dc0e8", 2909084284872595),
-    ("0x0af90fee025ebc11b2944b748cd8c7f063d7bdef", 1708225701435337),
-    ("0x1451986c1adeeafa32576fd0df2d4b7941f1cbdf", 1484884363897008),
-    ("0x861c73fd04dcbc760ed3147c3a6ab8463a79dc8a", 1352600131454659),
-    ("0x2be4367a5a5afac1963a7801c946e6f6e0dea365", 1361851088031256),
-    ("0x2869daeb6298e2df78f7a19535d24ecc5c6f85a2", 2149432199775741),
-    ("0xbea4749947b782a9e9ed98230859bf9784a1eb2d", 1531552158190914),
-    ("0x98640a6de21f7a39a7823f21c8255162520e3b76", 1366093614785247),
-    ("0xb089425c9c078b70cf96cc6051850f37f86b1426", 1370851125401858),
-    ("0x310213d70d81f300aee4fddeb8f67fd9ef9fca50", 1371188363336119),
-    ("0x29d6d6d84c9662486198667b5a9fbda3e698b23f", 1362019706998387),
-    ("0x8920377b85f8a015bd0fa79006791537b3ea9588", 2510819854572091),
-    ("0xcda1f3e00cbb29b8e4dcc5c62c1840628f1d0524", 1408431683731568),
-    ("0xbcff81420d024627edd07ab9468aff7881805d57", 2578523258985104),
-    ("0x966e39e346f84ea85177aedb840c9e7b47a46626", 1277158666063908),
-    ("0x1b21080407c712aed1ca783873f759b14c83f332", 1224055345723424),
-    ("0xddc570bafd31c4a3545a3d742b9d022e78a9cb7f", 175097577498151),
-    ("0x1d3f704d84e1b6228b685e9eecabc043dcde9007", 1586894571007642),
-    ("0x1040a43093b700d9bfe90346990afaa4d1151508", 873960511322239),
-    ("0xe85b703f6905e4230c00c4ab54c9753c0e9e0ac7", 1518073733251991),
-    ("0xacc04da53c239f0fd1b7034b98ebe985609c05a1", 1385638881873478),
-    ("0xf014b746d7f8169f0d14886277e5dd0dd49718d8", 1130832334561154),
-    ("0x26af7ca751f7f572c891a0969ea8d75098d335fd", 626696948776563),
-    ("0x88e76ef0e8b99b2b8f215f42b1352719fa2c42f4", 1459566830068973),
-    ("0xb8c04c44f59cff3dddac1573c0b603158d9cfc1f", 717659308573359),
-    ("0xa11066df189fe861277dfdec66cfa1481cdda34b", 899192159788511),
-    ("0x355b8f6059f5414ab1f69fca34088c4adc554b7f", 539324614611211),
-    ("0x1f4c3236781107eb1f456ce25b8d35e7717e7d1c", 1166839312849085),
-    ("0xdf04cd17ec3b30292b5a96d481d1bbbbb24c3b00", 3391802041398373),
-    ("0xbf1e656074d596e65966bc2bf3af427d3cf8c9e8", 372957840071061),
-    ("0xa69310cf42039a2b751823cb09364536d1879e30", 2347609445870636),
-    ("0x92cdae68a81be72c8313dafa44679693c2db7843", 1347399764874766),
-    ("0x02226f548ec48106bef062cb1c53408085dc3122", 1165583184715310),
-    ("0x769893a94e66297943b728a52bee02afbdd5e4cb", 2953508507942903),
-    ("0x3ab83a7a5e56a39c94fe39b96432bcfb56f9e567", 2934059284064649),
-    ("0x0eab40f395678001134329bb01553fe5c405a20e", 1477291257448801),
-    ("0x3aac43eb0198c8018c38d4c4638a0864ef8fc768", 3275331028767702),
-    ("0x99fd7fd0946673edef1d6ae560269b6262c13824", 1751509822592880),
-    ("0x26a2ae500fba1c7dcc5a52f0f580a01e1ccd4308", 1326643861362388),
-    ("0x44e45c4af784da8ac5455d4148ac870ab6fc72bb", 3067996170614775),
-    ("0x1077c2fecff01fa36e5f69fbc4a7380561dc83d8", 2302040550840790),
-    ("0x5c81d10ccce8e71307afe936bf4e0bfdb784c986", 1619468301657581),
-    ("0x231f8f1c84d5b6aa07e763690b15d7468763e146", 1523475519782446),
-    ("0x5831d55448a03033cca4b5066278beb590b9000f", 1322350913072237),
-    ("0x89f6ecd7281a9c7e85956b555bbe4a4927e82153", 1691982255932148),
-    ("0xd6aa6c49cf2853da668f6dd0f114320e7227f240", 2298146967294116),
-    ("0xd31d65e45b8a72c522a9e1c027abdf163e203992", 1163722685405604),
-    ("0x49101930325d488c746296c65f60b0a0a373f6cf", 1159226507923409),
-    ("0x5469abff736240a0f9be50c13828964e27eee03b", 2262344187801063),
-    ("0xc9bd52730e2442f6389d3ad0d4ce93574a5f8dae", 1016317811920894),
-    ("0x85f0b64e42ea6d1a0092e530a1426a1081a60d57", 1287853105182202),
-    ("0xf196411e32d99e77620e5d4d88a167342b8e5493", 1461932409059362),
-    ("0xcc46e07fb44b0d29f19f9d8f2b13b343a273fd76", 746715881545956),
-    ("0x28fd24db79a0996f78730beb64043085a6bee272", 1677064417281484),
-    ("0xf629639a14fef9ae7862ddf903533de047a362d9", 1512671824153232),
-    ("0x9f9360dad9cd76a21d61de16db13800597816a86", 1474158521028190),
-    ("0xbf4982c52a301fc624a9138fdb0a3f29dacf0ead", 2172282346090461),
-    ("0x3b0c3b4b8219433dd5faaf75faa4f1b04ada0757", 846343771204075),
-    ("0x7aaf01f3405f670e24db57bf1e9e73ec4203cba2", 505896298347095),
-    ("0xa4b47d97221f828a12dbdb7dc195a4c7976d6c09", 2933682364641887),
-    ("0x2d53ee3e8a8b74f5b2a905426d5f11a925187cc4", 1408518882325162),
-    ("0x38c556deb5e0717a47ac621a5891252f50d9c87c", 1410572952006793),
-    ("0x3a63817848b6b7272a6a3ba9575cfd1bbd9be27e", 1086141173045230),
-    ("0x8e7c96bcc2d73b4baa56bbc4ebf67f87bb9476dd", 1405228098453662),
-    ("0x9859d12fd8b3cdfa7e7e57856b02e1ca8d592c93", 1083935118666331),
-    ("0x446f3c3ae7ac16db80fa60321bfe9f7efc677171", 3328952166736031),
-    ("0x6d91343712ea2214c7dadcf423d9a7e065b6f27f", 1062866719337118),
-    ("0x38e4bb80563fecf9f11904d89a0a4149db76a4c6", 164153978904516),
-    ("0x891fa040c6926e14b906930ccf06f945580905e4", 1039038326932833),
-    ("0xf76f751d9c7cd6825a3d1269f2e0f0e073d35cf7", 1024075963156931),
-    ("0x10b6f0bc34ad2f231c3a3938ddf3d13fc39b5efd", 1075858392709074),
-    ("0x6149a51a0ab0ca12786fe3af59e80c8d6e4d1247", 892187392144296),
-    ("0xc64c6ccc24fa1c3a12c1809fd9fe32b3b9fa0520", 2400074576500004),
-    ("0x92e087cc8d79b2403b0f923fd4fa0fb09aead9f7", 2536865617036222),
-    ("0x3dfe6524963a99d030cda681450e4792b7847163", 944118061494268),
-    ("0x6ed0e6178b5bad549d7348a895c04a9773435cd5", 1884988719541862),
-    ("0x7224b305a2949a4ec28289b7afe8c3d784f80bcf", 666804379644103),
-    ("0x114b32c82776a87eb85b62850eb5e7df8961b1ef", 1459943005327030),
-    ("0x9b58304902c0b13a900949e7da707397316e7535", 779352164708229),
-    ("0xaadbd59753bd39f9e92e347daf03a7a2db3c85a7", 650599562855230),
-    ("0xf13d9d6e5a65cd6e02974a3e66a2df7ef28b5755", 1768640966850871),
-    ("0xf829be3d31735e3957f7e9068573597be2298f36", 1597600255767851),
-    ("0x114f759c9cca9c59d97866461cd70be87e3dc007", 1144238155289567),
-    ("0x179644e56c7c9ce05b9e41877ca9bfb2eb5b3549", 747484999082859),
-    ("0x2ee3878ba9d834fc08153f363a3dcaeef96c7058", 2546262799788507),
-    ("0x08d054d1f5f67684fa2d710bb89435990f411339", 561709673306740),
-    ("0xd19eda1a6b5b2176fb3ba1a536cfaf19b4af6aed", 1543637502425822),
-    ("0xfe74d0db5534f082ead31bd98a1c19e6918e8113", 2824469202396534),
-    ("0xb6f878f298a37cf5c5b87567de6adba9c01b3ed8", 707998386247186),
-    ("0x9f0d44d6778835b7d57e63d6f1c03cba7ec315f8", 2723454027018037),
-    ("0xe0d2eeef4ea3bfa8ce4d1d82299cc3f97b4c89d2", 509166245606873),
-    ("0x30e7b5f2f943b040edcd137b9365fbfc111a0733", 505833263219196),
-    ("0xad6d126000936f88ebdcca9775512fb185c77b1a", 1112857449243441),
-    ("0x6a8a1a7af701809f0a3105f9b8cc83fcd0421eb0", 920355591139191),
-    ("0x0cbff3df2007524b8a397fe2f79a38db8a118d99", 1302768744169548),
-    ("0x8a976783a5f40a54fb5ca78edd9dd1fd9994e4c3", 927352315238440),
-    ("0x53722c89a520c69bdd31b2b99e98c192e15b3709", 1345700792834358),
-    ("0x828e1ae13b138959aa8b370aab42e273fc66ab7b", 1512343069696600),
-    ("0xf6df31bb885c251fe3fe99403115eac63bfde7b1", 1208368168340998),
-    ("0x21056b8ba56cbf2f6293ac3cd322041f74c9672d", 1692417975310153),
-    ("0x8d13043a7eae37f1011b220ea73f67f8b1af361f", 853433712876145),
-    ("0x9ebfdad48eca2e68a0b3e55107b6221bd82ef630", 3090559266338384),
-    ("0x0072117c17f9902906f3d643dd114f55c39aba97", 1713856784156280),
-    ("0xf16c0b25498aa6dd0352014640942fa5b9942c2a", 1320873264615730),
-    ("0x1b31dcede2380b73e5eb05dd2b8832d2fd24c805", 1280813609122210),
-    ("0x290e2442f187475a4edcfa13a2bc4e652eae18ab", 1241977841921007),
-    ("0xe953f509f4701f6c73ead84ccdbdd9d48ed8a21b", 723394148205973),
-    ("0x7922904822af2d7ae52b10d43dea3785769d15f0", 1330554357802945),
-    ("0x3e536024433a028f4e19ce2ab9f13653dc031386", 1524511003081375),
-    ("0x2fa3a6e0530428b9ca1c6c6ed59d7e1e97e5a29c", 1327799905909585),
-    ("0xae18331c1f200c12ff884bef2e34042fb9acfd63", 826552448245065),
-    ("0x6cb88f58ec7a16de7a32032bfa4427fdc59e96f6", 1383722876631705),
-    ("0x203df0de3294a5f904acd3135beb1ba8cf92a54c", 1167523375309976),
-    ("0x9686fdb8863dc699d44499df6ae57c6cc1e170d7", 1150854332841858),
-    ("0x7bd2954ff83a382a34c8fa375a404aa42358aaa2", 4077066032584137),
-    ("0xbfbe7f5a061c980019ab20f60e3506b139eba4b0", 1697307288439506),
-    ("0x467c7433f6312172829e4d15087c1c8b7b1b31f3", 1170876975437404),
-    ("0x10a07aee71e04545883d99ecbeb5518194b194eb", 1939477619217075),
-    ("0xa4875824062df16a8db6db477477262460a31a3c", 1965012295928405),
-    ("0x3eb9056d853253ee3ce29c23331e0824931d7a46", 1405079265512788),
-    ("0xeb43dd49ab47dceb1ac0447f9580fdf5d2b8f92b", 1112724618584183),
-    ("0xc41d28ce0089c9b9015d2c3672bb7486c30b77b2", 1454257039805736),
-    ("0x8f260fdb612cb231b5f0d3faac138f93a1a9bf84", 883907060535204),
-    ("0xa76483296b902d2beac706a072f467219219575b", 1442971348697504),
-    ("0xca1f4807e791b7ede522468ac2a6d4fb64687bdc", 379511742396817),
-    ("0xb41a2b8242f656f473f2fe9509638649c0d16b15", 1027892333049368),
-    ("0xd04d32bd9923dc5c979da4cfbf0b8d703b35b7d2", 1064949529097376),
-    ("0xdd7f2c2e28d5e491b5d9713ed1f5c16dcf495003", 1082351361077860),
-    ("0x0c3a7320b3706e69d0799ef58c2aa52bc51eafd2", 1179158478011545),
-    ("0x59c628a96d97374dbe72285cd0b7035a8d5ef1ff", 1074745415599783),
-    ("0xfdc4d37a46e529e7e3ffc25354d2878c82b4cbfa", 1334838894186602),
-    ("0x14950686d0af073448d3239adb8bb3a95de1cc0e", 781068416444873),
-    ("0x014edf60f8a5ac95f82cb3158b3cb50627d4ec03", 1287974255196073),
-    ("0x0088423d95f2ba2e9f5396c5bd8c64871e378bb5", 2255728117080417),
-    ("0x135c70f3086e77dbbbc5568bd3b206fbc3d4b5d4", 888887185834407),
-    ("0xeb220dbdd1ddbc427210483ab2f9f6396d43cda9", 2044434499685031),
-    ("0x877ba7559693f185abf9bd0f3bff059ba22252b8", 389412634916450),
-    ("0x584938629eb4a0e597c15d11d59d96317639b2a7", 973417095362640),
-    ("0x22ca65532f059393eb57893ee4d9b66b918dd9cc", 1426155454495480),
-    ("0xdbe3670b58d0bc01172ef8e4feea90845a84fee8", 1023806282245508),
-    ("0xb07b5a5e146abdfa84a9afe38ab8f642b8c8cc8c", 1442061208999426),
-    ("0x920acbb88d0c40fc800d8ae8564f75a3d3270858", 1139762967629475),
-    ("0x4a0eed6376bb307bf8d08243d48aa49d9e802bc4", 622638145344481),
-    ("0x2fa0af527f9ecba898ae9eae5349da7857efc75a", 507273769102076),
-    ("0x110d61e62bea29ba3d5a19f6f6a80e8c74ae4707", 931510281884347),
-    ("0xd817c4026500e76bec25218ebb81283c2b1f618b", 1613790121412326),
-    ("0x5e3aff8e9e56e4fc5b8b3c7ee117607ca069be6f", 463401035550576),
-    ("0x0cf1a7a326d1fab385ae21f99379ccd2b7b28e6f", 424179134416596),
-    ("0x6c45e9078c8e02f86ea7b569dc4729566d1478fc", 920033744281992),
-    ("0xe437bed0ad838d8958d589584b65cbcf7d832c23", 1147244055402478),
-    ("0x6c3df4b8a243b6f98602b9de222129aa85f860dc", 58548252475944),
-    ("0x69bc4d78761120423ee8fa204bf83a9d765b8e26", 921590773225258),
-    ("0x8ad7c1edb8ca0552e1345528d1d7e50162300b65", 1020244764688400),
-    ("0x31c0db7c95d904e9539e76d60e73a4bbf61a7742", 549252209511412),
-    ("0x1647811d902db3e4d6122a192aea1bfa8d9181d6", 730496806339608),
-    ("0xd65b42f5ca248b4f6b9ac354044bed78715b4dd7", 557046918932912),
-    ("0x8ebed0ff2b0232b0aedbfe6e9c0f72ac72577869", 703535500228415),
-    ("0xce716837e7b9469e7da0816a8a7f3e487a119186", 612402026324008),
-    ("0x003a96de96e0a059b4a6238b9e2af804f6b25da6", 607845077428331),
-    ("0x7dd8c17f7c2652f23001afa302e5c4da71745ea5", 804904705749904),
-    ("0x2222822d81de5b2630189ca18e0977431a951ac8", 646507970213042),
-    ("0xd0b6ea11fe544308b3045473ec125ad787c7da98", 2252730330113759),
-    ("0x6f3377622651c99a498a406f3be2c2ed41e593f1", 576292318287359),
-    ("0xa0286d80e5a9fcd1b435cb40a5fb29ba6ae02369", 1660212774751248),
-    ("0x79e83f1a8bd5abecc23198758d2d9dbcdb5e9e2b", 3085596825894509),
-    ("0x8aa7486d26bc9d1d24c83a02c69abb02ecbf82d7", 697127804379870),
-    ("0x294e624c0d3366b03041fb5fd228500814fa6f28", 1551492905045122),
-    ("0xa1dcfa38274ef94894bbf107bb657f6982599f44", 1772924212546510),
-    ("0xac0e1a6e3ea9df79bda037c7f7a75c5f56c27253", 2894952082486301),
-    ("0x0bfc5aa096b5cd4bb6fe86eefc99b18b9820f87d", 1071835919835603),
-    ("0xe61c09429481525d0caf03b6fef2a0c5db8adf71", 2498630830684107),
-    ("0x5a4fbbed57fd0ae3409a72999664548342cbb6f9", 3135843921093237),
-    ("0x013f39705c115093ccadbfbaaa83ed102bbcc2e1", 1261157986785760),
-    ("0xd68dd78378e1feea558f04f04cc392e7a7153d3b", 986346541244272),
-    ("0xe49a0068b68cb41538508a1bcc496e1ed6342a1a", 5888127953233886),
-    ("0xe935c1902749c7582f2a4d5a22e8194f06915255", 2443356771584195),
-    ("0x1e6ef9520f22d9ad4272bcdea32d8a6518d9e1af", 1098454341115661),
-    ("0x21c91cb247c7300c341b97d19fd324d900f1f93d", 731647897814081),
-    ("0x76e489641d6866a6ef022dc644f6dab87c49b88e", 3313195004413790),
-    ("0x48cbbfd36fdcdb24941ae4288a9071b8e0380509", 1281530064634938),
-    ("0xb5b1d914c7434126f98734e176d5a1e1dbe4ce27", 1408274796302129),
-    ("0x13fd3eb27bb25ff8c27da82efb1e81dd20bf4d4d", 1081737950489490),
-    ("0x89b87931dc5075a0fefc5745087ea7e1c71ce5f9", 1977286955451554),
-    ("0x32802f989b4348a51dd0e61d23b78be1a0543469", 729776315864189),
-    ("0x8f5dcf727f75b5876a59bc4ec9b6c9a52a7970c0", 1630050586400048),
-    ("0xe686c86a2cf340a96e087ed70e28a72025112bb4", 4080975655294439),
-    ("0xbbe1125d37a30d11af73c18e618dcf96e0910a67", 2264305009267797),
-    ("0x0230304de82e316ac309fb43b8a826ae7ffccb40", 1556143649913853),
-    ("0xbadae55bd07310854bb71fde52e7412357ed2703", 2232163485100716),
-    ("0x941863056ab255d2dc16910f1a3f9d6ce000587b", 754235398754857),
-    ("0x2c112977530c8a087477850b5e25e9a7c4e96e6e", 2218840449392371),
-    ("0x77dd6748bb7bfda0b3d70b584c5212a097bb29b6", 1142521542414170),
-    ("0x9a94c823c07e4971494c24fd847aace954156e22", 1428160912712158),
-    ("0xb3a26f9ce86cfe2ab3a17965c173dc37029867b8", 3112541300751065),
-    ("0x3ca5022d0cbfd9367d80c4daa80e3be3250cf357", 1278964688139815),
-    ("0xa23332705073ecad93d0553464132daa81a1606c", 1128990707487222),
-    ("0xe7d8d7e2cc897b49acad310f521872feee25322e", 895182666022973),
-    ("0x386768fcc67a600d743219a2fc839adb334e38c4", 3956541018797976),
-    ("0xe0fa8c55dd27753be81fefc954ed9e5cd5df9fe4", 1401669787370941),
-    ("0x70b985a651e0e55c42e87fd16f31550f08ae0016", 1218157698801342),
-    ("0xbcbde3c8d8aac62473212ebadc01a3f9711eaa92", 1817376278320360),
-    ("0x865faffd5bce7e79433214a562c4bb78686bf769", 821950489938864),
-    ("0x2e82c77bbb334564d59946337882bb0cec0cfe88", 1209502275350453),
-    ("0x4924f904c380a266ad712796fb61e53b3fa5ef77", 1464476408554201),
-    ("0xfceac8f15bd957043f923e564b56bf0b49921053", 1390439138186196),
-    ("0xb52d1d3fa331c1d568028a5438e1ea20d69442dc", 829386008567323),
-    ("0x8a2bf6335d11437441e08d0007681ba667553539", 5087732062633833),
-    ("0x9483a669f2a267ffb66497d6811331323cdb7c68", 766680501857629),
-    ("0x5a7fb70e55af8a6a2caed12941ab7a52ddf50ba2", 1178738834779873),
-    ("0xb03b5db5115ee1da2c9cd31f47a3c79ca953934f", 639878775928962),
-    ("0xbe0eeae94453d49de644bcd13e3dd9af5d77da60", 1298226275265302),
-];
-}
@@ -27,29 +27,56 @@ use frame_support::{
 		transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction},
 	},
 	traits::{Currency, Get, Imbalance, IsSubType},
-	weights::{DispatchClass, Pays},
+	weights::{DispatchClass, Pays, Weight},
+};
+use frame_system::{ensure_root, ensure_signed};
+use primitives::{claim::claim_message, Balance};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	RuntimeDebug,
 };
-use frame_system::ensure_signed;
-use primitives::Balance;
-use sp_runtime::traits::Zero;
 use sp_std::{marker::PhantomData, prelude::*, vec::Vec};
 pub use traits::*;
 use weights::WeightInfo;
 
 mod benchmarking;
-mod claims_data;
-mod migration;
+pub mod merkle;
+pub mod migration;
 mod traits;
 pub mod weights;
 
 #[cfg(test)]
 mod mock;
 
+#[cfg(test)]
+mod signature_fixtures;
+
 #[cfg(test)]
 mod tests;
 
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// Claim waiting for the delay window to pass before it is executed.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct PendingClaim<AccountId, Balance, BlockNumber> {
+	/// Account which receives the claimed amount
+	pub dest: AccountId,
+	/// Claimed amount
+	pub amount: Balance,
+	/// Block from which the claim can be executed
+	pub execute_at: BlockNumber,
+}
+
+/// Proof that a claim of `amount` is included in the tree of claims with root `ClaimsRoot`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ClaimProof {
+	/// Claimed amount
+	pub amount: Balance,
+	/// Sibling hashes from the leaf up to the root
+	pub path: Vec<H256>,
+}
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
@@ -64,8 +91,26 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
-		fn on_runtime_upgrade() -> frame_support::weights::Weight {
-			migration::import_initial_claims::<T>(&claims_data::CLAIMS_DATA)
+		/// Executes at most `MaxClaimsPerBlock` scheduled claims.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let scheduled = ClaimSchedule::<T>::take(now);
+
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+			for address in scheduled.iter() {
+				weight = weight.saturating_add(Self::execute_pending_claim(address));
+			}
+
+			weight
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			migration::set_claims_root::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::do_try_state()
 		}
 	}
 
@@ -82,12 +127,60 @@ pub mod pallet {
 		// This type is needed to convert from Currency to Balance
 		type CurrencyBalance: From<Balance>
 			+ Into<<Self::Currency as Currency<<Self as frame_system::Config>::AccountId>>::Balance>;
+
+		/// Origin which can freeze and unfreeze claims
+		type FreezeOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Origin which can cancel pending claims
+		type CancelOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Number of blocks between claim submission and its execution. Zero executes claims immediately.
+		#[pallet::constant]
+		type ClaimDelay: Get<Self::BlockNumber>;
+
+		/// Maximum number of claims scheduled for execution in one block
+		#[pallet::constant]
+		type MaxClaimsPerBlock: Get<u32>;
+
+		/// Account which receives unclaimed balances after the claim period has ended
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Maximum number of addresses whose unclaimed balance is swept by one `sweep_unclaimed` call
+		#[pallet::constant]
+		type MaxClaimsSweptPerCall: Get<u32>;
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
 		Claim(T::AccountId, EthereumAddress, BalanceOf<T>),
+
+		/// Claim was submitted and will be executed after the delay window. [dest, address, amount, execute at]
+		ClaimScheduled(T::AccountId, EthereumAddress, BalanceOf<T>, T::BlockNumber),
+
+		/// Pending claim was cancelled and its amount returned to the claimable balance. [dest, address, amount]
+		ClaimCancelled(T::AccountId, EthereumAddress, BalanceOf<T>),
+
+		/// All claims were frozen.
+		ClaimsFrozen,
+
+		/// Claims freeze was lifted.
+		ClaimsUnfrozen,
+
+		/// Address was exempted from the claims freeze. [address]
+		AddressUnfrozen(EthereumAddress),
+
+		/// End of the claim period was set. None if the claim period is unlimited. [end]
+		EndClaimPeriodSet(Option<T::BlockNumber>),
+
+		/// Unclaimed balance of an address expired and was swept to the treasury. [address, amount]
+		ClaimExpired(EthereumAddress, BalanceOf<T>),
+
+		/// Unclaimed balances were swept to the treasury. [treasury, total amount]
+		UnclaimedSwept(T::AccountId, BalanceOf<T>),
+
+		/// Root of the tree of claims was set. [root]
+		ClaimsRootSet(Option<H256>),
 	}
 
 	#[pallet::error]
@@ -98,6 +191,30 @@ pub mod pallet {
 		NoClaimOrAlreadyClaimed,
 		/// Value reached maximum and cannot be incremented further
 		BalanceOverflow,
+		/// Claims are frozen
+		ClaimsFrozen,
+		/// Claims are not frozen
+		ClaimsNotFrozen,
+		/// There is no pending claim of the address
+		NoPendingClaim,
+		/// Pending claim cannot be executed yet
+		ClaimDelayNotPassed,
+		/// Maximum number of claims scheduled for the block has been reached
+		ClaimScheduleFull,
+		/// Claim period has ended
+		ClaimPeriodEnded,
+		/// Claim period has not ended yet
+		ClaimPeriodNotEnded,
+		/// There are no unclaimed balances left
+		NothingToSweep,
+		/// Recovery id of the Ethereum signature is not 0, 1, 27 or 28
+		InvalidSignatureRecoveryId,
+		/// Ethereum signature is malleable, its `s` value lies in the upper half of the curve order
+		HighSSignature,
+		/// Merkle proof does not match the root of the tree of claims
+		InvalidMerkleProof,
+		/// Root of the tree of claims is not set
+		NoClaimsRoot,
 	}
 
 	/// Asset id storage for each shared token
@@ -105,15 +222,60 @@ pub mod pallet {
 	#[pallet::getter(fn claims)]
 	pub type Claims<T: Config> = StorageMap<_, Blake2_128Concat, EthereumAddress, BalanceOf<T>, ValueQuery>;
 
+	/// Whether claims are frozen.
+	#[pallet::storage]
+	#[pallet::getter(fn frozen)]
+	pub type Frozen<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Addresses which can claim while claims are frozen.
+	#[pallet::storage]
+	#[pallet::getter(fn unfrozen_addresses)]
+	pub type UnfrozenAddresses<T: Config> = StorageMap<_, Blake2_128Concat, EthereumAddress, bool, ValueQuery>;
+
+	/// Submitted claims waiting for the delay window to pass.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_claims)]
+	pub type PendingClaims<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		EthereumAddress,
+		PendingClaim<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// Addresses of pending claims to be executed at given block.
+	#[pallet::storage]
+	#[pallet::getter(fn claim_schedule)]
+	pub type ClaimSchedule<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, Vec<EthereumAddress>, ValueQuery>;
+
+	/// Root of the tree of claims which can be claimed with a Merkle proof.
+	#[pallet::storage]
+	#[pallet::getter(fn claims_root)]
+	pub type ClaimsRoot<T: Config> = StorageValue<_, H256, OptionQuery>;
+
+	/// Addresses whose claim from the tree of claims has been submitted.
+	#[pallet::storage]
+	#[pallet::getter(fn merkle_claimed)]
+	pub type MerkleClaimed<T: Config> = StorageMap<_, Blake2_128Concat, EthereumAddress, bool, ValueQuery>;
+
+	/// Last block in which claims can be submitted. None if the claim period is unlimited.
+	#[pallet::storage]
+	#[pallet::getter(fn end_claim_period)]
+	pub type EndClaimPeriod<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub claims: Vec<(EthereumAddress, BalanceOf<T>)>,
+		pub claims_root: Option<H256>,
 	}
 
 	#[cfg(feature = "std")]
 	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
-			GenesisConfig { claims: vec![] }
+			GenesisConfig {
+				claims: vec![],
+				claims_root: None,
+			}
 		}
 	}
 
@@ -122,20 +284,208 @@ pub mod pallet {
 		fn build(&self) {
 			self.claims.iter().for_each(|(eth_address, initial_balance)| {
 				Claims::<T>::mutate(eth_address, |amount| *amount += *initial_balance)
-			})
+			});
+
+			if let Some(root) = self.claims_root {
+				ClaimsRoot::<T>::put(root);
+			}
 		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Claim xHDX by providing signed message with Ethereum address.
+		///
+		/// Claims from the tree of claims require `proof` of the claimed amount. Claims stored on-chain are claimed
+		/// without proof.
 		#[pallet::weight((<T as Config>::WeightInfo::claim(), DispatchClass::Normal, Pays::No))]
-		pub fn claim(origin: OriginFor<T>, ethereum_signature: EcdsaSignature) -> DispatchResultWithPostInfo {
+		pub fn claim(
+			origin: OriginFor<T>,
+			ethereum_signature: EcdsaSignature,
+			proof: Option<ClaimProof>,
+		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 
-			let (balance_due, address) = Self::validate_claim(&sender, &ethereum_signature)?;
+			let (balance_due, address) = Self::validate_claim(&sender, &ethereum_signature, proof.as_ref())?;
+
+			Self::submit_claim(sender, balance_due, address, proof.is_some())?;
+
+			Ok(().into())
+		}
+
+		/// Claim xHDX on behalf of `dest` by providing message signed with Ethereum address.
+		///
+		/// Signed message must contain `dest` account, not the origin. This allows relayers to submit claims
+		/// for accounts which do not hold any HDX to pay the fees.
+		#[pallet::weight((<T as Config>::WeightInfo::claim_for(), DispatchClass::Normal, Pays::No))]
+		pub fn claim_for(
+			origin: OriginFor<T>,
+			dest: T::AccountId,
+			ethereum_signature: EcdsaSignature,
+			proof: Option<ClaimProof>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let (balance_due, address) = Self::validate_claim(&dest, &ethereum_signature, proof.as_ref())?;
+
+			Self::submit_claim(dest, balance_due, address, proof.is_some())?;
+
+			Ok(().into())
+		}
+
+		/// Freeze all claims.
+		///
+		/// While frozen, only addresses unfrozen by `unfreeze_address` can claim and pending claims of other
+		/// addresses are not executed.
+		///
+		/// Emits `ClaimsFrozen` event when successful.
+		#[pallet::weight((<T as Config>::WeightInfo::freeze_claims(), DispatchClass::Operational))]
+		pub fn freeze_claims(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			T::FreezeOrigin::ensure_origin(origin)?;
+
+			ensure!(!Self::frozen(), Error::<T>::ClaimsFrozen);
+
+			Frozen::<T>::put(true);
+
+			Self::deposit_event(Event::ClaimsFrozen);
+
+			Ok(().into())
+		}
+
+		/// Lift the freeze of all claims.
+		///
+		/// Emits `ClaimsUnfrozen` event when successful.
+		#[pallet::weight((<T as Config>::WeightInfo::unfreeze_claims(), DispatchClass::Operational))]
+		pub fn unfreeze_claims(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			T::FreezeOrigin::ensure_origin(origin)?;
 
-			Self::process_claim(sender, balance_due, address)?;
+			ensure!(Self::frozen(), Error::<T>::ClaimsNotFrozen);
+
+			Frozen::<T>::put(false);
+
+			Self::deposit_event(Event::ClaimsUnfrozen);
+
+			Ok(().into())
+		}
+
+		/// Allow `address` to claim while claims are frozen.
+		///
+		/// Emits `AddressUnfrozen` event when successful.
+		#[pallet::weight((<T as Config>::WeightInfo::unfreeze_address(), DispatchClass::Operational))]
+		pub fn unfreeze_address(origin: OriginFor<T>, address: EthereumAddress) -> DispatchResultWithPostInfo {
+			T::FreezeOrigin::ensure_origin(origin)?;
+
+			UnfrozenAddresses::<T>::insert(address, true);
+
+			Self::deposit_event(Event::AddressUnfrozen(address));
+
+			Ok(().into())
+		}
+
+		/// Cancel pending claim of `address`.
+		///
+		/// Claimed amount is returned to the claimable balance of the address.
+		///
+		/// Emits `ClaimCancelled` event when successful.
+		#[pallet::weight((<T as Config>::WeightInfo::cancel_claim(), DispatchClass::Operational))]
+		pub fn cancel_claim(origin: OriginFor<T>, address: EthereumAddress) -> DispatchResultWithPostInfo {
+			T::CancelOrigin::ensure_origin(origin)?;
+
+			let pending = PendingClaims::<T>::take(address).ok_or(Error::<T>::NoPendingClaim)?;
+
+			ClaimSchedule::<T>::mutate(pending.execute_at, |scheduled| scheduled.retain(|a| *a != address));
+			Claims::<T>::mutate(address, |bal| *bal += pending.amount);
+
+			Self::deposit_event(Event::ClaimCancelled(pending.dest, address, pending.amount));
+
+			Ok(().into())
+		}
+
+		/// Execute pending claim of `address` whose delay window has passed.
+		///
+		/// Pending claims are executed automatically at the end of the delay window. This call executes claims
+		/// which were skipped because claims were frozen at that time.
+		///
+		/// Emits `Claim` event when successful.
+		#[pallet::weight((<T as Config>::WeightInfo::execute_claim(), DispatchClass::Normal, Pays::No))]
+		pub fn execute_claim(origin: OriginFor<T>, address: EthereumAddress) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let pending = Self::validate_pending_claim(&address)?;
+
+			Self::process_claim(pending.dest, pending.amount, address)?;
+
+			PendingClaims::<T>::remove(address);
+
+			Ok(().into())
+		}
+
+		/// Set the last block in which claims can be submitted. `None` makes the claim period unlimited.
+		///
+		/// Claims submitted before the end of the claim period are executed even if their delay window passes
+		/// after the end.
+		///
+		/// Emits `EndClaimPeriodSet` event when successful.
+		#[pallet::weight((<T as Config>::WeightInfo::set_end_claim_period(), DispatchClass::Operational))]
+		pub fn set_end_claim_period(origin: OriginFor<T>, end: Option<T::BlockNumber>) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			EndClaimPeriod::<T>::set(end);
+
+			Self::deposit_event(Event::EndClaimPeriodSet(end));
+
+			Ok(().into())
+		}
+
+		/// Move unclaimed balances to the treasury account after the claim period has ended.
+		///
+		/// At most `MaxClaimsSweptPerCall` addresses are swept by one call, the call has to be repeated until
+		/// no unclaimed balances are left. Pending claims are not affected.
+		///
+		/// Emits `ClaimExpired` event for each swept address and `UnclaimedSwept` event with the total amount.
+		#[pallet::weight((<T as Config>::WeightInfo::sweep_unclaimed(T::MaxClaimsSweptPerCall::get()), DispatchClass::Operational))]
+		pub fn sweep_unclaimed(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			ensure!(Self::claim_period_ended(), Error::<T>::ClaimPeriodNotEnded);
+			ensure!(Claims::<T>::iter().next().is_some(), Error::<T>::NothingToSweep);
+
+			let mut swept: u32 = 0;
+			let mut total = BalanceOf::<T>::zero();
+
+			for (address, amount) in Claims::<T>::drain().take(T::MaxClaimsSweptPerCall::get() as usize) {
+				swept = swept.saturating_add(1);
+
+				if amount.is_zero() {
+					continue;
+				}
+
+				total = total.saturating_add(amount);
+
+				Self::deposit_event(Event::ClaimExpired(address, amount));
+			}
+
+			let treasury = T::TreasuryAccount::get();
+
+			let _ = <T::Currency as Currency<T::AccountId>>::deposit_creating(&treasury, total);
+
+			Self::deposit_event(Event::UnclaimedSwept(treasury, total));
+
+			Ok(Some(<T as Config>::WeightInfo::sweep_unclaimed(swept)).into())
+		}
+
+		/// Set the root of the tree of claims. `None` disables claims with Merkle proof.
+		///
+		/// Addresses which have already claimed from the previous tree cannot claim from the new one.
+		///
+		/// Emits `ClaimsRootSet` event when successful.
+		#[pallet::weight((<T as Config>::WeightInfo::set_claims_root(), DispatchClass::Operational))]
+		pub fn set_claims_root(origin: OriginFor<T>, root: Option<H256>) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			ClaimsRoot::<T>::set(root);
+
+			Self::deposit_event(Event::ClaimsRootSet(root));
 
 			Ok(().into())
 		}
@@ -143,29 +493,165 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Check that every scheduled claim has a pending claim executed at the scheduled block.
+	#[cfg(feature = "try-runtime")]
+	pub fn do_try_state() -> Result<(), &'static str> {
+		for (execute_at, addresses) in ClaimSchedule::<T>::iter() {
+			for address in addresses.iter() {
+				let pending = PendingClaims::<T>::get(address).ok_or("Scheduled claim is not pending")?;
+				ensure!(
+					pending.execute_at == execute_at,
+					"Scheduled claim is pending for another block"
+				);
+			}
+		}
+
+		Ok(())
+	}
+
 	/// Check if a claim is valid.
 	///
 	/// Recovers Ethereum address from a message signature and checks whether such address
 	/// can make a valid claims and has not been already claimed.
+	///
+	/// If `proof` is provided, the proven amount is added to the amount stored on-chain. Proof can be used
+	/// only once per address.
 	fn validate_claim(
 		who: &T::AccountId,
 		signature: &EcdsaSignature,
+		proof: Option<&ClaimProof>,
 	) -> Result<(BalanceOf<T>, EthereumAddress), Error<T>> {
-		let sender_hex = who.using_encoded(to_ascii_hex);
+		let message = who.using_encoded(|account_id| claim_message(T::Prefix::get(), account_id));
+
+		let signer = signature.recover(&message);
 
-		let signer = signature.recover(&sender_hex, T::Prefix::get());
+		if Self::claim_period_ended() {
+			return Err(Error::<T>::ClaimPeriodEnded);
+		}
 
 		match signer {
-			Some(address) => {
-				let balance_due = Claims::<T>::get(&address);
+			Ok(address) => {
+				if Self::is_frozen(&address) {
+					return Err(Error::<T>::ClaimsFrozen);
+				}
+
+				let mut balance_due = Claims::<T>::get(&address);
+
+				if let Some(proof) = proof {
+					let root = Self::claims_root().ok_or(Error::<T>::NoClaimsRoot)?;
+
+					ensure!(!Self::merkle_claimed(&address), Error::<T>::NoClaimOrAlreadyClaimed);
+					ensure!(
+						merkle::verify_proof(&root, &address, proof.amount, &proof.path),
+						Error::<T>::InvalidMerkleProof
+					);
+
+					balance_due = balance_due.saturating_add(T::CurrencyBalance::from(proof.amount).into());
+				}
 
 				if balance_due == Zero::zero() {
 					return Err(Error::<T>::NoClaimOrAlreadyClaimed);
 				};
 				Ok((balance_due, address))
 			}
-			None => Err(Error::<T>::InvalidEthereumSignature),
+			Err(error) => Err(error.into()),
+		}
+	}
+
+	/// Return true if the end of the claim period is set and has passed.
+	pub fn claim_period_ended() -> bool {
+		Self::end_claim_period().map_or(false, |end| <frame_system::Pallet<T>>::block_number() > end)
+	}
+
+	/// Return true if claims of `address` are frozen.
+	pub fn is_frozen(address: &EthereumAddress) -> bool {
+		Self::frozen() && !Self::unfrozen_addresses(address)
+	}
+
+	/// Checks whether pending claim of `address` exists and can be executed now.
+	fn validate_pending_claim(
+		address: &EthereumAddress,
+	) -> Result<PendingClaim<T::AccountId, BalanceOf<T>, T::BlockNumber>, Error<T>> {
+		let pending = Self::pending_claims(address).ok_or(Error::<T>::NoPendingClaim)?;
+
+		ensure!(
+			pending.execute_at <= <frame_system::Pallet<T>>::block_number(),
+			Error::<T>::ClaimDelayNotPassed
+		);
+		ensure!(!Self::is_frozen(address), Error::<T>::ClaimsFrozen);
+
+		Ok(pending)
+	}
+
+	/// Submit previously verified claim.
+	///
+	/// Claim is processed immediately if there is no claim delay, otherwise it is scheduled for execution
+	/// after the delay window.
+	///
+	/// Fails if `MaxClaimsPerBlock` claims are already scheduled for the execution block.
+	///
+	/// Address is marked as claimed from the tree of claims if the claim was `proven`.
+	///
+	/// Emits `ClaimScheduled` when claim is scheduled.
+	fn submit_claim(
+		dest: T::AccountId,
+		balance_due: BalanceOf<T>,
+		address: EthereumAddress,
+		proven: bool,
+	) -> DispatchResult {
+		let delay = T::ClaimDelay::get();
+
+		if delay.is_zero() {
+			Self::process_claim(dest, balance_due, address)?;
+			if proven {
+				MerkleClaimed::<T>::insert(address, true);
+			}
+			return Ok(());
+		}
+
+		let execute_at = <frame_system::Pallet<T>>::block_number().saturating_add(delay);
+
+		ensure!(
+			ClaimSchedule::<T>::decode_len(execute_at).unwrap_or(0) < T::MaxClaimsPerBlock::get() as usize,
+			Error::<T>::ClaimScheduleFull
+		);
+
+		Claims::<T>::mutate(address, |bal| *bal = Zero::zero());
+
+		PendingClaims::<T>::insert(
+			address,
+			PendingClaim {
+				dest: dest.clone(),
+				amount: balance_due,
+				execute_at,
+			},
+		);
+		ClaimSchedule::<T>::append(execute_at, address);
+
+		if proven {
+			MerkleClaimed::<T>::insert(address, true);
+		}
+
+		Self::deposit_event(Event::ClaimScheduled(dest, address, balance_due, execute_at));
+
+		Ok(())
+	}
+
+	/// Execute pending claim of `address` unless the address is frozen.
+	///
+	/// Failed claim is returned to the claimable balance of the address.
+	fn execute_pending_claim(address: &EthereumAddress) -> Weight {
+		if Self::is_frozen(address) {
+			return T::DbWeight::get().reads(2);
+		}
+
+		if let Some(pending) = PendingClaims::<T>::take(address) {
+			if Self::process_claim(pending.dest, pending.amount, *address).is_err() {
+				Claims::<T>::mutate(address, |bal| *bal += pending.amount);
+			}
 		}
+
+		<T as Config>::WeightInfo::execute_claim()
 	}
 
 	/// Process previously verified claim.
@@ -188,18 +674,18 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
-/// Converts the given binary data into ASCII-encoded hex. It will be twice the length.
-fn to_ascii_hex(data: &[u8]) -> Vec<u8> {
-	let mut r = Vec::with_capacity(data.len() * 2);
-	let mut push_nibble = |n| r.push(if n < 10 { b'0' + n } else { b'a' - 10 + n });
-	for &b in data.iter() {
-		push_nibble(b / 16);
-		push_nibble(b % 16);
+impl<T> From<SignatureError> for Error<T> {
+	fn from(error: SignatureError) -> Self {
+		match error {
+			SignatureError::InvalidRecoveryId => Error::<T>::InvalidSignatureRecoveryId,
+			SignatureError::HighS => Error::<T>::HighSSignature,
+			SignatureError::RecoveryFailed => Error::<T>::InvalidEthereumSignature,
+		}
 	}
-	r
 }
 
-/// Signed extension that checks for the `claim` call and in that case, it verifies an Ethereum signature
+/// Signed extension that checks for the `claim` and `claim_for` calls and in that case, it verifies an Ethereum signature
+/// Free `execute_claim` calls are rejected from the pool unless the pending claim can be executed.
 #[derive(Encode, Decode, Clone, Eq, PartialEq)]
 pub struct ValidateClaim<T: Config + Send + Sync>(PhantomData<T>);
 
@@ -231,7 +717,17 @@ where
 		_len: usize,
 	) -> TransactionValidity {
 		match call.is_sub_type() {
-			Some(Call::claim(signature)) => match Pallet::<T>::validate_claim(who, &signature) {
+			Some(Call::claim(signature, proof)) => match Pallet::<T>::validate_claim(who, &signature, proof.as_ref()) {
+				Ok(_) => Ok(ValidTransaction::default()),
+				Err(error) => InvalidTransaction::Custom(error.as_u8()).into(),
+			},
+			Some(Call::claim_for(dest, signature, proof)) => {
+				match Pallet::<T>::validate_claim(dest, &signature, proof.as_ref()) {
+					Ok(_) => Ok(ValidTransaction::default()),
+					Err(error) => InvalidTransaction::Custom(error.as_u8()).into(),
+				}
+			}
+			Some(Call::execute_claim(address)) => match Pallet::<T>::validate_pending_claim(address) {
 				Ok(_) => Ok(ValidTransaction::default()),
 				Err(error) => InvalidTransaction::Custom(error.as_u8()).into(),
 			},
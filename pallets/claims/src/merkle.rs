@@ -0,0 +1,164 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Merkle tree of claims.
+//!
+//! Leaves are `keccak_256` hashes of SCALE encoded `(EthereumAddress, Balance)` pairs. Inner nodes are `keccak_256`
+//! hashes of concatenated child hashes ordered so that the lower hash is first, which makes a proof a plain list of
+//! sibling hashes without position flags.
+
+use crate::EthereumAddress;
+use codec::Encode;
+use primitives::Balance;
+use sp_core::H256;
+use sp_io::hashing::keccak_256;
+use sp_std::vec::Vec;
+
+/// Maximum number of sibling hashes in a proof. Enough for a tree of 2^32 claims.
+pub const MAX_PROOF_DEPTH: usize = 32;
+
+/// Hash of a claim leaf.
+pub fn leaf_hash(address: &EthereumAddress, amount: Balance) -> H256 {
+	H256(keccak_256(&(address, amount).encode()))
+}
+
+/// Hash of an inner node.
+pub fn node_hash(a: &H256, b: &H256) -> H256 {
+	let (first, second) = if a <= b { (a, b) } else { (b, a) };
+
+	let mut data = [0u8; 64];
+	data[..32].copy_from_slice(first.as_bytes());
+	data[32..].copy_from_slice(second.as_bytes());
+
+	H256(keccak_256(&data))
+}
+
+/// Return true if `path` proves that `amount` of `address` is a leaf of the tree with `root`.
+pub fn verify_proof(root: &H256, address: &EthereumAddress, amount: Balance, path: &[H256]) -> bool {
+	if path.len() > MAX_PROOF_DEPTH {
+		return false;
+	}
+
+	let computed = path
+		.iter()
+		.fold(leaf_hash(address, amount), |hash, sibling| node_hash(&hash, sibling));
+
+	computed == *root
+}
+
+/// Build a layer of the tree from the layer below. Odd node is carried up unchanged.
+fn next_layer(layer: &[H256]) -> Vec<H256> {
+	layer
+		.chunks(2)
+		.map(|pair| match pair {
+			[a, b] => node_hash(a, b),
+			[a] => *a,
+			_ => unreachable!("chunks of two are never empty; qed"),
+		})
+		.collect()
+}
+
+/// Compute the root of the tree built from `claims`. None if there are no claims.
+pub fn merkle_root(claims: &[(EthereumAddress, Balance)]) -> Option<H256> {
+	let mut layer: Vec<H256> = claims
+		.iter()
+		.map(|(address, amount)| leaf_hash(address, *amount))
+		.collect();
+
+	if layer.is_empty() {
+		return None;
+	}
+
+	while layer.len() > 1 {
+		layer = next_layer(&layer);
+	}
+
+	layer.first().copied()
+}
+
+/// Compute the proof of the claim at `index` of `claims`. None if `index` is out of bounds.
+pub fn merkle_proof(claims: &[(EthereumAddress, Balance)], index: usize) -> Option<Vec<H256>> {
+	if index >= claims.len() {
+		return None;
+	}
+
+	let mut layer: Vec<H256> = claims
+		.iter()
+		.map(|(address, amount)| leaf_hash(address, *amount))
+		.collect();
+	let mut index = index;
+	let mut path = Vec::new();
+
+	while layer.len() > 1 {
+		if let Some(sibling) = layer.get(index ^ 1) {
+			path.push(*sibling);
+		}
+		layer = next_layer(&layer);
+		index /= 2;
+	}
+
+	Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn claims(n: u8) -> Vec<(EthereumAddress, Balance)> {
+		(0..n)
+			.map(|i| (EthereumAddress([i; 20]), 1_000 * i as Balance))
+			.collect()
+	}
+
+	#[test]
+	fn proofs_of_all_leaves_should_verify() {
+		for n in 1..=9 {
+			let claims = claims(n);
+			let root = merkle_root(&claims).unwrap();
+
+			for (index, (address, amount)) in claims.iter().enumerate() {
+				let path = merkle_proof(&claims, index).unwrap();
+				assert!(verify_proof(&root, address, *amount, &path));
+			}
+		}
+	}
+
+	#[test]
+	fn proof_of_different_amount_should_not_verify() {
+		let claims = claims(5);
+		let root = merkle_root(&claims).unwrap();
+		let path = merkle_proof(&claims, 3).unwrap();
+
+		assert!(!verify_proof(&root, &claims[3].0, claims[3].1 + 1, &path));
+		assert!(!verify_proof(&root, &claims[2].0, claims[3].1, &path));
+	}
+
+	#[test]
+	fn too_long_proof_should_not_verify() {
+		let claims = claims(2);
+		let root = merkle_root(&claims).unwrap();
+		let path = vec![H256::zero(); MAX_PROOF_DEPTH + 1];
+
+		assert!(!verify_proof(&root, &claims[0].0, claims[0].1, &path));
+	}
+
+	#[test]
+	fn empty_claims_should_have_no_root() {
+		assert_eq!(merkle_root(&[]), None);
+		assert_eq!(merkle_proof(&[], 0), None);
+	}
+}
@@ -16,25 +16,30 @@
 // limitations under the License.
 
 use super::*;
-use frame_support::traits::GetPalletVersion;
-use hex::FromHex;
-use primitives::Balance;
+use frame_support::traits::{GetPalletVersion, PalletVersion};
+use hex_literal::hex;
 
-pub fn import_initial_claims<T: Config>(claims_data: &[(&'static str, Balance)]) -> frame_support::weights::Weight {
+/// Root of the tree of claims built from the claims snapshot in `node/res/claims.scale`.
+pub const CLAIMS_ROOT: H256 = H256(hex!["6de58bd5ad8781df77728477dc21576e9ab163a96549d9e5e108a8e9ea74f09d"]);
+
+/// Set root of the tree of claims on chains which imported the claims snapshot into `Claims` storage.
+///
+/// Addresses with a claim stored on-chain are marked as claimed from the tree, so the same snapshot amount
+/// cannot be claimed twice. Their on-chain balances are claimed without proof as before.
+pub fn set_claims_root<T: Config>() -> Weight {
 	let version = <Pallet<T> as GetPalletVersion>::storage_version();
-	if version == None {
-		for (addr, amount) in claims_data.iter() {
-			let balance: BalanceOf<T> = T::CurrencyBalance::from(*amount).into();
-
-			Claims::<T>::insert(
-				EthereumAddress(<[u8; 20]>::from_hex(&addr[2..]).unwrap_or_else(|addr| {
-					frame_support::log::warn!("Error encountered while migrating Ethereum address: {}", addr);
-					EthereumAddress::default().0
-				})),
-				balance,
-			);
+	if version.map_or(true, |v| v < PalletVersion::new(3, 2, 0)) {
+		let mut count: Weight = 0;
+		for (address, _) in Claims::<T>::iter() {
+			MerkleClaimed::<T>::insert(address, true);
+			count += 1;
+		}
+
+		if ClaimsRoot::<T>::get().is_none() {
+			ClaimsRoot::<T>::put(CLAIMS_ROOT);
 		}
-		T::DbWeight::get().reads_writes(2, 3)
+
+		T::DbWeight::get().reads_writes(count.saturating_add(2), count.saturating_add(1))
 	} else {
 		0
 	}
@@ -43,33 +48,49 @@ pub fn import_initial_claims<T: Config>(claims_data: &[(&'static str, Balance)])
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::mock::Test;
+	use crate::mock::{ExtBuilder, Test};
+
+	#[test]
+	fn claims_root_should_match_claims_snapshot() {
+		let blob = include_bytes!("../../../node/res/claims.scale");
+		let claims = Vec::<(EthereumAddress, Balance)>::decode(&mut &blob[..]).unwrap();
+
+		assert_eq!(merkle::merkle_root(&claims), Some(CLAIMS_ROOT));
+	}
+
+	#[test]
+	fn set_claims_root_should_work() {
+		ExtBuilder::default().build().execute_with(|| {
+			let alice_eth_addr = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
+
+			assert_eq!(Pallet::<Test>::claims_root(), None);
+
+			set_claims_root::<Test>();
+
+			assert_eq!(Pallet::<Test>::claims_root(), Some(CLAIMS_ROOT));
+			assert!(Pallet::<Test>::merkle_claimed(&alice_eth_addr));
+		})
+	}
 
 	#[test]
-	fn data_migration_should_work() {
-		sp_io::TestExternalities::default().execute_with(|| {
-			let claims_data: [(&'static str, Balance); 4] = [
-				("0x8202c0af5962b750123ce1a9b12e1c30a4973557", 555),
-				("0xb3e7104ea029874c36da42ca115c8c90b5938ef5", 666),
-				("0x30503adcd76c9bf9d068a15be4a8cf6e874fef6c", 777),
-				("0x19ad3978b233a91a30f9ddda6c6f6c92ba97b8f2", 888),
-			];
-			let (first_addr, first_balance) = claims_data[0];
-			let (second_addr, second_balance) = claims_data[1];
-			let (last_addr, last_balance) = claims_data.last().copied().unwrap();
-
-			let first_addr = EthereumAddress(<[u8; 20]>::from_hex(&first_addr[2..]).unwrap());
-			let second_addr = EthereumAddress(<[u8; 20]>::from_hex(&second_addr[2..]).unwrap());
-			let last_addr = EthereumAddress(<[u8; 20]>::from_hex(&last_addr[2..]).unwrap());
-			assert_eq!(Claims::<Test>::get(first_addr), 0);
-			assert_eq!(Claims::<Test>::get(second_addr), 0);
-			assert_eq!(Claims::<Test>::get(last_addr), 0);
-
-			import_initial_claims::<Test>(&claims_data);
-
-			assert_eq!(Claims::<Test>::get(first_addr), first_balance);
-			assert_eq!(Claims::<Test>::get(second_addr), second_balance);
-			assert_eq!(Claims::<Test>::get(last_addr), last_balance);
+	fn set_claims_root_should_not_override_existing_root() {
+		ExtBuilder::default().build().execute_with(|| {
+			let root = H256::repeat_byte(1);
+			ClaimsRoot::<Test>::put(root);
+
+			set_claims_root::<Test>();
+
+			assert_eq!(Pallet::<Test>::claims_root(), Some(root));
+		})
+	}
+
+	#[test]
+	fn set_claims_root_should_not_run_twice() {
+		ExtBuilder::default().build().execute_with(|| {
+			PalletVersion::new(3, 2, 0).put_into_storage::<<Test as frame_system::Config>::PalletInfo, Pallet<Test>>();
+
+			assert_eq!(set_claims_root::<Test>(), 0);
+			assert_eq!(Pallet::<Test>::claims_root(), None);
 		})
 	}
 }
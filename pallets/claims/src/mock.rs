@@ -17,8 +17,8 @@
 
 use crate as claims;
 use crate::{Config, EthereumAddress};
-use frame_support::parameter_types;
-use frame_system;
+use frame_support::{parameter_types, traits::Get};
+use frame_system::{self, EnsureRoot};
 use hex_literal::hex;
 use primitives::Balance;
 use sp_core::H256;
@@ -26,6 +26,7 @@ use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
 };
+use std::cell::RefCell;
 
 use frame_support::traits::GenesisBuild;
 
@@ -86,6 +87,25 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
 	pub Prefix: &'static [u8] = b"I hereby claim all my xHDX tokens to wallet:";
+	pub const MaxClaimsPerBlock: u32 = 1;
+	pub const TreasuryAccount: AccountId = TREASURY;
+	pub const MaxClaimsSweptPerCall: u32 = 2;
+}
+
+thread_local! {
+	static CLAIM_DELAY: RefCell<u64> = RefCell::new(0);
+}
+
+pub struct ClaimDelay;
+
+impl Get<u64> for ClaimDelay {
+	fn get() -> u64 {
+		CLAIM_DELAY.with(|v| *v.borrow())
+	}
+}
+
+pub fn set_claim_delay(delay: u64) {
+	CLAIM_DELAY.with(|v| *v.borrow_mut() = delay);
 }
 
 impl Config for Test {
@@ -94,12 +114,19 @@ impl Config for Test {
 	type Prefix = Prefix;
 	type WeightInfo = ();
 	type CurrencyBalance = Balance;
+	type FreezeOrigin = EnsureRoot<AccountId>;
+	type CancelOrigin = EnsureRoot<AccountId>;
+	type ClaimDelay = ClaimDelay;
+	type MaxClaimsPerBlock = MaxClaimsPerBlock;
+	type TreasuryAccount = TreasuryAccount;
+	type MaxClaimsSweptPerCall = MaxClaimsSweptPerCall;
 }
 
 pub type AccountId = u64;
 pub const ALICE: AccountId = 42;
 pub const BOB: AccountId = 43;
 pub const CHARLIE: AccountId = 44;
+pub const TREASURY: AccountId = 45;
 
 pub const CLAIM_AMOUNT: Balance = 1_000_000_000_000;
 
@@ -123,6 +150,7 @@ impl ExtBuilder {
 				EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]),
 				CLAIM_AMOUNT,
 			)],
+			claims_root: None,
 		}
 		.assimilate_storage(&mut t)
 		.unwrap();
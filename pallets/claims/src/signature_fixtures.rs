@@ -0,0 +1,118 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Ethereum signatures of claim messages used as test vectors.
+//!
+//! All valid signatures sign `claim_message(Prefix, account_id)` of the mock runtime, i.e.
+//! "I hereby claim all my xHDX tokens to wallet:" followed by the hex encoded account id.
+//!
+//! Signatures are deterministic (RFC 6979), so every vector can be reproduced from the listed key by any secp256k1
+//! implementation. MetaMask vectors are signed by the key of the mock claims genesis, Ledger and Trezor vectors by
+//! dedicated keys and encoded the way the device returns them.
+
+use crate::mock::{AccountId, ALICE, BOB};
+use crate::{EthereumAddress, SignatureError};
+use hex_literal::hex;
+
+pub struct SignatureFixture {
+	/// Description of the vector
+	pub name: &'static str,
+	/// Account id included in the signed message
+	pub account: AccountId,
+	/// Signature as returned by the wallet
+	pub signature: [u8; 65],
+	/// Expected result of the address recovery
+	pub expected: Result<EthereumAddress, SignatureError>,
+}
+
+/// Address of the key 0xdd75dd5f4a9e964d1c4cc929768947859a98ae2c08100744878a4b6b6d853cc0.
+pub const METAMASK_ADDRESS: EthereumAddress = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
+
+/// Address of the key 0xd20cfa013c5d0ba82dcefca744d6c13dea831d91b08dee8f9138f5d153331e99.
+pub const LEDGER_ADDRESS: EthereumAddress = EthereumAddress(hex!["e8a4b9d186902ed3fa6a7d1acb489f6322286a62"]);
+
+/// Address of the key 0x2d41a1874ee3a410f8ceb944108bdfd1d893152efa947392243598bf438aa996.
+pub const TREZOR_ADDRESS: EthereumAddress = EthereumAddress(hex!["451d2a10b2f39957ce3f45d84a3c26c0fbe5ef89"]);
+
+/// keccak-256 hash of the claim message of `ALICE`.
+pub const ALICE_MESSAGE_HASH: [u8; 32] = hex!["5c864cb5173d4d1fb213d36cc95d86c38ed6940bd234e497fd75695086ed1312"];
+
+pub fn signature_fixtures() -> Vec<SignatureFixture> {
+	vec![
+		SignatureFixture {
+			name: "MetaMask personal_sign, v = 27",
+			account: ALICE,
+			signature: hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"],
+			expected: Ok(METAMASK_ADDRESS),
+		},
+		SignatureFixture {
+			name: "MetaMask personal_sign of another account, v = 27",
+			account: BOB,
+			signature: hex!["b789a50855638b92bcba27c68e9e57fa9a59360ba325b0f795a697918668bcff4918937a66e042e928b5f2c9da7f1ec3064bb545a55516ae97a39a67a4c8f9ad1b"],
+			expected: Ok(METAMASK_ADDRESS),
+		},
+		SignatureFixture {
+			name: "Ledger raw recovery id, v = 0",
+			account: BOB,
+			signature: hex!["941a3a3fde20cb300169ca5a90a3076a4b0959395147748fd3af26b79358ed4a2eba163991baee336ddc86340dbed593936ecff5862c49cf8272a513b9f0af5400"],
+			expected: Ok(LEDGER_ADDRESS),
+		},
+		SignatureFixture {
+			name: "Ledger raw recovery id, v = 1",
+			account: 47,
+			signature: hex!["1a615dce2fa810b8e145cc1aec37629ddb6073a628382307ad90c0d31f8c2e685119c2260c46fd0d9550b2c0c92f6845a54d00dc297abb7411f98c5f5e16c51e01"],
+			expected: Ok(LEDGER_ADDRESS),
+		},
+		SignatureFixture {
+			name: "Trezor, v = 28",
+			account: BOB,
+			signature: hex!["060947836157f3905bfc7e710b2d90d1504b447937a5be6969fe4fe530abd6176097a61bd0bbe7929bd1c61dc95db4dafc717b035e7804877e8ad6ae4086564a1c"],
+			expected: Ok(TREZOR_ADDRESS),
+		},
+		SignatureFixture {
+			name: "Trezor, v = 27",
+			account: ALICE,
+			signature: hex!["943bd80aee80856751196bdcd4c643c936ff2e10c42f7c5412994557ec23f344727c42478a0afb545b8c8c2bc2b45815673d5ca8740fb06c969077bf2a5ee3d81b"],
+			expected: Ok(TREZOR_ADDRESS),
+		},
+		SignatureFixture {
+			// (r, n - s) with flipped v recovers the MetaMask address as well
+			name: "malleated MetaMask signature with high s",
+			account: ALICE,
+			signature: hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a35bcfbefd1219d68845aa90f4fc1988ef997da25947a00da0bf20cb1a2766ca69d1c"],
+			expected: Err(SignatureError::HighS),
+		},
+		SignatureFixture {
+			name: "EIP-155 encoded v = 37",
+			account: ALICE,
+			signature: hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa425"],
+			expected: Err(SignatureError::InvalidRecoveryId),
+		},
+		SignatureFixture {
+			name: "recovery id 2, v = 29",
+			account: ALICE,
+			signature: hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41d"],
+			expected: Err(SignatureError::InvalidRecoveryId),
+		},
+		SignatureFixture {
+			name: "zero r",
+			account: ALICE,
+			signature: hex!["000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011b"],
+			expected: Err(SignatureError::RecoveryFailed),
+		},
+	]
+}
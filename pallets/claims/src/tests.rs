@@ -17,9 +17,14 @@
 
 use super::*;
 use crate::mock::*;
-use frame_support::dispatch::DispatchInfo;
+use crate::signature_fixtures::*;
+use frame_support::dispatch::{DispatchInfo, GetDispatchInfo};
+use frame_support::traits::OnInitialize;
 use frame_support::{assert_err, assert_noop, assert_ok};
 use hex_literal::hex;
+use primitives::claim::claim_message;
+use sp_io::hashing::keccak_256;
+use test_utils::{assert_balance, assert_event_emitted};
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut ext = ExtBuilder::default().build();
@@ -34,15 +39,15 @@ fn claiming_works() {
 		// "I hereby claim all my xHDX tokens to wallet:2a00000000000000"
 		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
 
-		assert_eq!(Balances::free_balance(&ALICE), 0);
-		assert_eq!(Balances::free_balance(&BOB), 0);
+		assert_balance!(Balances, ALICE, 0);
+		assert_balance!(Balances, BOB, 0);
 
 		// Signature not consistent with origin
-		assert_noop!(ClaimsPallet::claim(Origin::signed(BOB), EcdsaSignature(signature)), Error::<Test>::NoClaimOrAlreadyClaimed);
+		assert_noop!(ClaimsPallet::claim(Origin::signed(BOB), EcdsaSignature(signature), None), Error::<Test>::NoClaimOrAlreadyClaimed);
 
-		assert_ok!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature)));
+		assert_ok!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None));
 
-		assert_eq!(Balances::free_balance(&ALICE), CLAIM_AMOUNT);
+		assert_balance!(Balances, ALICE, CLAIM_AMOUNT);
 	})
 }
 
@@ -50,7 +55,7 @@ fn claiming_works() {
 fn invalid_signature_fail() {
 	new_test_ext().execute_with(|| {
 		let invalid_signature = hex!["a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1"];
-		assert_noop!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(invalid_signature)), Error::<Test>::InvalidEthereumSignature);
+		assert_noop!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(invalid_signature), None), Error::<Test>::InvalidSignatureRecoveryId);
 	})
 }
 
@@ -65,7 +70,7 @@ fn claim_cant_overflow() {
 		assert_eq!(Claims::<Test>::get(&charlie_eth_addr), CLAIM_AMOUNT);
 		assert_eq!(Balances::free_balance(&CHARLIE), primitives::Balance::MAX - 1);
 
-		assert_noop!(ClaimsPallet::claim(Origin::signed(CHARLIE), EcdsaSignature(signature)), Error::<Test>::BalanceOverflow);
+		assert_noop!(ClaimsPallet::claim(Origin::signed(CHARLIE), EcdsaSignature(signature), None), Error::<Test>::BalanceOverflow);
 
 		assert_eq!(Claims::<Test>::get(&charlie_eth_addr), CLAIM_AMOUNT);
 		assert_eq!(Balances::free_balance(&CHARLIE), primitives::Balance::MAX - 1);
@@ -81,7 +86,7 @@ fn zeroize_claimed_balance_works() {
 		let alice_eth_addr = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
 
 		assert_eq!(Claims::<Test>::get(&alice_eth_addr), CLAIM_AMOUNT);
-		assert_ok!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature)));
+		assert_ok!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None));
 		assert_eq!(Claims::<Test>::get(&alice_eth_addr), 0);
 	})
 }
@@ -91,8 +96,8 @@ fn double_claim_fail() {
 	new_test_ext().execute_with(|| {
 		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
 
-		assert_ok!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature)));
-		assert_noop!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature)), Error::<Test>::NoClaimOrAlreadyClaimed);
+		assert_ok!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None));
+		assert_noop!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None), Error::<Test>::NoClaimOrAlreadyClaimed);
 	})
 }
 
@@ -101,7 +106,7 @@ fn unsigned_claim_fail() {
 	new_test_ext().execute_with(|| {
 		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
 		assert_err!(
-			ClaimsPallet::claim(Origin::none(), EcdsaSignature(signature)),
+			ClaimsPallet::claim(Origin::none(), EcdsaSignature(signature), None),
 			sp_runtime::traits::BadOrigin,
 		);
 	});
@@ -112,7 +117,7 @@ fn signed_extention_success() {
 	new_test_ext().execute_with(|| {
 		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
 
-		let call = <crate::Call<Test>>::claim(EcdsaSignature(signature)).into();
+		let call = <crate::Call<Test>>::claim(EcdsaSignature(signature), None).into();
 		let info = DispatchInfo::default();
 
 		assert_eq!(
@@ -127,12 +132,12 @@ fn signed_extention_invalid_sig() {
 	new_test_ext().execute_with(|| {
 		let invalid_signature = hex!["a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1"];
 
-		let call = <crate::Call<Test>>::claim(EcdsaSignature(invalid_signature)).into();
+		let call = <crate::Call<Test>>::claim(EcdsaSignature(invalid_signature), None).into();
 		let info = DispatchInfo::default();
 
 		assert_eq!(
 			ValidateClaim::<Test>(PhantomData).validate(&ALICE, &call, &info, 150),
-			InvalidTransaction::Custom(Error::<Test>::InvalidEthereumSignature.as_u8()).into()
+			InvalidTransaction::Custom(Error::<Test>::InvalidSignatureRecoveryId.as_u8()).into()
 		);
 	});
 }
@@ -142,7 +147,7 @@ fn signed_extention_no_claim_error() {
 	new_test_ext().execute_with(|| {
 		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
 
-		let call = <crate::Call<Test>>::claim(EcdsaSignature(signature)).into();
+		let call = <crate::Call<Test>>::claim(EcdsaSignature(signature), None).into();
 		let info = DispatchInfo::default();
 
 		assert_eq!(
@@ -151,3 +156,587 @@ fn signed_extention_no_claim_error() {
 		);
 	});
 }
+
+#[test]
+fn claim_for_works() {
+	new_test_ext().execute_with(|| {
+		// Alice (account id = 42) signs a msg:
+		// "I hereby claim all my xHDX tokens to wallet:2a00000000000000"
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+		let alice_eth_addr = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
+
+		// Signature not consistent with destination
+		assert_noop!(ClaimsPallet::claim_for(Origin::signed(BOB), BOB, EcdsaSignature(signature), None), Error::<Test>::NoClaimOrAlreadyClaimed);
+
+		assert_ok!(ClaimsPallet::claim_for(Origin::signed(BOB), ALICE, EcdsaSignature(signature), None));
+
+		assert_balance!(Balances, ALICE, CLAIM_AMOUNT);
+		assert_balance!(Balances, BOB, 0);
+		assert_eq!(Claims::<Test>::get(&alice_eth_addr), 0);
+
+		assert_noop!(ClaimsPallet::claim_for(Origin::signed(BOB), ALICE, EcdsaSignature(signature), None), Error::<Test>::NoClaimOrAlreadyClaimed);
+	})
+}
+
+#[test]
+fn claim_for_is_feeless() {
+	new_test_ext().execute_with(|| {
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+
+		let call = <crate::Call<Test>>::claim_for(ALICE, EcdsaSignature(signature), None);
+
+		assert_eq!(call.get_dispatch_info().pays_fee, Pays::No);
+	});
+}
+
+#[test]
+fn signed_extention_claim_for_success() {
+	new_test_ext().execute_with(|| {
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+
+		let call = <crate::Call<Test>>::claim_for(ALICE, EcdsaSignature(signature), None).into();
+		let info = DispatchInfo::default();
+
+		assert_eq!(
+			ValidateClaim::<Test>(PhantomData).validate(&BOB, &call, &info, 150),
+			Ok(ValidTransaction::default())
+		);
+	});
+}
+
+#[test]
+fn signed_extention_claim_for_no_claim_error() {
+	new_test_ext().execute_with(|| {
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+
+		let call = <crate::Call<Test>>::claim_for(BOB, EcdsaSignature(signature), None).into();
+		let info = DispatchInfo::default();
+
+		assert_eq!(
+			ValidateClaim::<Test>(PhantomData).validate(&ALICE, &call, &info, 150),
+			InvalidTransaction::Custom(Error::<Test>::NoClaimOrAlreadyClaimed.as_u8()).into()
+		);
+	});
+}
+
+#[test]
+fn frozen_claims_fail() {
+	new_test_ext().execute_with(|| {
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+
+		assert_noop!(ClaimsPallet::freeze_claims(Origin::signed(ALICE)), sp_runtime::traits::BadOrigin);
+
+		assert_ok!(ClaimsPallet::freeze_claims(Origin::root()));
+		assert_noop!(ClaimsPallet::freeze_claims(Origin::root()), Error::<Test>::ClaimsFrozen);
+
+		assert_noop!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None), Error::<Test>::ClaimsFrozen);
+		assert_noop!(ClaimsPallet::claim_for(Origin::signed(BOB), ALICE, EcdsaSignature(signature), None), Error::<Test>::ClaimsFrozen);
+
+		let call = <crate::Call<Test>>::claim(EcdsaSignature(signature), None).into();
+		let info = DispatchInfo::default();
+
+		assert_eq!(
+			ValidateClaim::<Test>(PhantomData).validate(&ALICE, &call, &info, 150),
+			InvalidTransaction::Custom(Error::<Test>::ClaimsFrozen.as_u8()).into()
+		);
+
+		assert_ok!(ClaimsPallet::unfreeze_claims(Origin::root()));
+		assert_noop!(ClaimsPallet::unfreeze_claims(Origin::root()), Error::<Test>::ClaimsNotFrozen);
+
+		assert_ok!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None));
+
+		assert_balance!(Balances, ALICE, CLAIM_AMOUNT);
+	})
+}
+
+#[test]
+fn unfrozen_address_can_claim() {
+	new_test_ext().execute_with(|| {
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+		let alice_eth_addr = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
+
+		assert_ok!(ClaimsPallet::freeze_claims(Origin::root()));
+
+		assert_noop!(
+			ClaimsPallet::unfreeze_address(Origin::signed(ALICE), alice_eth_addr),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_ok!(ClaimsPallet::unfreeze_address(Origin::root(), alice_eth_addr));
+
+		assert_ok!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None));
+
+		assert_balance!(Balances, ALICE, CLAIM_AMOUNT);
+		assert!(ClaimsPallet::frozen());
+	})
+}
+
+#[test]
+fn delayed_claim_is_executed_after_delay() {
+	new_test_ext().execute_with(|| {
+		set_claim_delay(5);
+
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+		let alice_eth_addr = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
+
+		assert_ok!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None));
+
+		assert_balance!(Balances, ALICE, 0);
+		assert_eq!(Claims::<Test>::get(&alice_eth_addr), 0);
+		assert_eq!(
+			ClaimsPallet::pending_claims(&alice_eth_addr),
+			Some(PendingClaim {
+				dest: ALICE,
+				amount: CLAIM_AMOUNT,
+				execute_at: 6
+			})
+		);
+		assert_eq!(ClaimsPallet::claim_schedule(6), vec![alice_eth_addr]);
+
+		assert_noop!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None), Error::<Test>::NoClaimOrAlreadyClaimed);
+		assert_noop!(ClaimsPallet::execute_claim(Origin::signed(BOB), alice_eth_addr), Error::<Test>::ClaimDelayNotPassed);
+
+		ClaimsPallet::on_initialize(5);
+
+		assert_balance!(Balances, ALICE, 0);
+
+		System::set_block_number(6);
+		ClaimsPallet::on_initialize(6);
+
+		assert_balance!(Balances, ALICE, CLAIM_AMOUNT);
+		assert_eq!(ClaimsPallet::pending_claims(&alice_eth_addr), None);
+		assert!(ClaimsPallet::claim_schedule(6).is_empty());
+	})
+}
+
+#[test]
+fn cancel_claim_works() {
+	new_test_ext().execute_with(|| {
+		set_claim_delay(5);
+
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+		let alice_eth_addr = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
+
+		assert_ok!(ClaimsPallet::claim_for(Origin::signed(BOB), ALICE, EcdsaSignature(signature), None));
+
+		assert_noop!(
+			ClaimsPallet::cancel_claim(Origin::signed(BOB), alice_eth_addr),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_ok!(ClaimsPallet::cancel_claim(Origin::root(), alice_eth_addr));
+		assert_noop!(
+			ClaimsPallet::cancel_claim(Origin::root(), alice_eth_addr),
+			Error::<Test>::NoPendingClaim
+		);
+
+		assert_eq!(Claims::<Test>::get(&alice_eth_addr), CLAIM_AMOUNT);
+		assert_eq!(ClaimsPallet::pending_claims(&alice_eth_addr), None);
+		assert!(ClaimsPallet::claim_schedule(6).is_empty());
+
+		System::set_block_number(6);
+		ClaimsPallet::on_initialize(6);
+
+		assert_balance!(Balances, ALICE, 0);
+	})
+}
+
+#[test]
+fn frozen_pending_claim_is_not_executed() {
+	new_test_ext().execute_with(|| {
+		set_claim_delay(5);
+
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+		let alice_eth_addr = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
+
+		assert_ok!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None));
+		assert_ok!(ClaimsPallet::freeze_claims(Origin::root()));
+
+		System::set_block_number(6);
+		ClaimsPallet::on_initialize(6);
+
+		assert_balance!(Balances, ALICE, 0);
+		assert!(ClaimsPallet::pending_claims(&alice_eth_addr).is_some());
+
+		assert_noop!(ClaimsPallet::execute_claim(Origin::signed(BOB), alice_eth_addr), Error::<Test>::ClaimsFrozen);
+
+		assert_ok!(ClaimsPallet::unfreeze_claims(Origin::root()));
+		assert_ok!(ClaimsPallet::execute_claim(Origin::signed(BOB), alice_eth_addr));
+
+		assert_balance!(Balances, ALICE, CLAIM_AMOUNT);
+		assert_eq!(ClaimsPallet::pending_claims(&alice_eth_addr), None);
+
+		assert_noop!(ClaimsPallet::execute_claim(Origin::signed(BOB), alice_eth_addr), Error::<Test>::NoPendingClaim);
+	})
+}
+
+#[test]
+fn claim_should_not_be_scheduled_when_schedule_is_full() {
+	new_test_ext().execute_with(|| {
+		set_claim_delay(5);
+
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+		let alice_eth_addr = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
+		let other_eth_addr = EthereumAddress(hex!["b3e7104ea029874c36da42ca115c8c90b5938ef5"]);
+
+		ClaimSchedule::<Test>::append(6, other_eth_addr);
+
+		assert_noop!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None), Error::<Test>::ClaimScheduleFull);
+
+		System::set_block_number(2);
+
+		assert_ok!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None));
+		assert_eq!(ClaimsPallet::claim_schedule(7), vec![alice_eth_addr]);
+	});
+}
+
+#[test]
+fn claim_should_fail_after_claim_period_ended() {
+	new_test_ext().execute_with(|| {
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+
+		assert_noop!(
+			ClaimsPallet::set_end_claim_period(Origin::signed(ALICE), Some(1)),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_ok!(ClaimsPallet::set_end_claim_period(Origin::root(), Some(1)));
+
+		System::set_block_number(2);
+
+		let call = <crate::Call<Test>>::claim(EcdsaSignature(signature), None).into();
+		let info = DispatchInfo::default();
+
+		assert_eq!(
+			ValidateClaim::<Test>(PhantomData).validate(&ALICE, &call, &info, 150),
+			InvalidTransaction::Custom(Error::<Test>::ClaimPeriodEnded.as_u8()).into()
+		);
+		assert_noop!(
+			ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None),
+			Error::<Test>::ClaimPeriodEnded
+		);
+
+		assert_ok!(ClaimsPallet::set_end_claim_period(Origin::root(), None));
+		assert_ok!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None));
+
+		assert_balance!(Balances, ALICE, CLAIM_AMOUNT);
+	})
+}
+
+#[test]
+fn sweep_unclaimed_should_move_unclaimed_balances_to_treasury() {
+	new_test_ext().execute_with(|| {
+		set_claim_delay(5);
+
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+		let first_eth_addr = EthereumAddress([1u8; 20]);
+		let second_eth_addr = EthereumAddress([2u8; 20]);
+
+		Claims::<Test>::insert(first_eth_addr, CLAIM_AMOUNT);
+		Claims::<Test>::insert(second_eth_addr, 2 * CLAIM_AMOUNT);
+
+		assert_ok!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None));
+
+		assert_noop!(
+			ClaimsPallet::sweep_unclaimed(Origin::root()),
+			Error::<Test>::ClaimPeriodNotEnded
+		);
+
+		assert_ok!(ClaimsPallet::set_end_claim_period(Origin::root(), Some(1)));
+
+		assert_noop!(
+			ClaimsPallet::sweep_unclaimed(Origin::root()),
+			Error::<Test>::ClaimPeriodNotEnded
+		);
+
+		System::set_block_number(2);
+
+		assert_noop!(
+			ClaimsPallet::sweep_unclaimed(Origin::signed(ALICE)),
+			sp_runtime::traits::BadOrigin
+		);
+
+		// At most two addresses are swept by one call
+		assert_ok!(ClaimsPallet::sweep_unclaimed(Origin::root()));
+		assert_ok!(ClaimsPallet::sweep_unclaimed(Origin::root()));
+		assert_noop!(
+			ClaimsPallet::sweep_unclaimed(Origin::root()),
+			Error::<Test>::NothingToSweep
+		);
+
+		assert_balance!(Balances, TREASURY, 3 * CLAIM_AMOUNT);
+		assert_eq!(Claims::<Test>::get(&first_eth_addr), 0);
+		assert_eq!(Claims::<Test>::get(&second_eth_addr), 0);
+
+		assert_event_emitted!(
+			Test,
+			crate::Event::<Test>::ClaimExpired(first_eth_addr, CLAIM_AMOUNT)
+		);
+		assert_event_emitted!(
+			Test,
+			crate::Event::<Test>::ClaimExpired(second_eth_addr, 2 * CLAIM_AMOUNT)
+		);
+
+		// Pending claim submitted before the end of the claim period is not swept
+		System::set_block_number(6);
+		ClaimsPallet::on_initialize(6);
+
+		assert_balance!(Balances, ALICE, CLAIM_AMOUNT);
+	})
+}
+
+#[test]
+fn signature_fixtures_recover_expected_address() {
+	for fixture in signature_fixtures() {
+		let message = claim_message(Prefix::get(), &fixture.account.encode());
+
+		assert_eq!(
+			EcdsaSignature(fixture.signature).recover(&message),
+			fixture.expected,
+			"{}",
+			fixture.name
+		);
+	}
+}
+
+#[test]
+fn recover_prehashed_works() {
+	let message = claim_message(Prefix::get(), &ALICE.encode());
+	assert_eq!(keccak_256(&message), ALICE_MESSAGE_HASH);
+
+	for fixture in signature_fixtures().into_iter().filter(|f| f.account == ALICE) {
+		assert_eq!(
+			EcdsaSignature(fixture.signature).recover_prehashed(&ALICE_MESSAGE_HASH),
+			fixture.expected,
+			"{}",
+			fixture.name
+		);
+	}
+}
+
+#[test]
+fn claim_with_fixture_signatures_works() {
+	for fixture in signature_fixtures() {
+		new_test_ext().execute_with(|| match fixture.expected {
+			Ok(address) => {
+				Claims::<Test>::insert(address, CLAIM_AMOUNT);
+
+				assert_ok!(ClaimsPallet::claim_for(
+					Origin::signed(TREASURY),
+					fixture.account,
+					EcdsaSignature(fixture.signature),
+					None
+				));
+
+				assert_eq!(Claims::<Test>::get(address), 0, "{}", fixture.name);
+				assert_event_emitted!(
+					Test,
+					crate::Event::<Test>::Claim(fixture.account, address, CLAIM_AMOUNT)
+				);
+			}
+			Err(error) => {
+				assert_noop!(
+					ClaimsPallet::claim(Origin::signed(fixture.account), EcdsaSignature(fixture.signature), None),
+					Error::<Test>::from(error)
+				);
+			}
+		});
+	}
+}
+
+#[test]
+fn malleable_signature_is_rejected() {
+	new_test_ext().execute_with(|| {
+		// (r, n - s) of the Alice's signature from `claiming_works`
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a35bcfbefd1219d68845aa90f4fc1988ef997da25947a00da0bf20cb1a2766ca69d1c"];
+
+		assert_noop!(
+			ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None),
+			Error::<Test>::HighSSignature
+		);
+
+		let call = <crate::Call<Test>>::claim(EcdsaSignature(signature), None).into();
+		let info = DispatchInfo::default();
+
+		assert_eq!(
+			ValidateClaim::<Test>(PhantomData).validate(&ALICE, &call, &info, 150),
+			InvalidTransaction::Custom(Error::<Test>::HighSSignature.as_u8()).into()
+		);
+	});
+}
+
+#[test]
+fn ledger_recovery_id_is_accepted() {
+	new_test_ext().execute_with(|| {
+		// Alice's signature from `claiming_works` with v = 0 instead of 27
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa400"];
+
+		assert_ok!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None));
+
+		assert_balance!(Balances, ALICE, CLAIM_AMOUNT);
+	})
+}
+
+fn merkle_claims() -> Vec<(EthereumAddress, Balance)> {
+	vec![
+		(EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]), 500),
+		(EthereumAddress(hex!["b3e7104ea029874c36da42ca115c8c90b5938ef5"]), 600),
+		(EthereumAddress(hex!["30503adcd76c9bf9d068a15be4a8cf6e874fef6c"]), 700),
+	]
+}
+
+fn alice_claim_proof(amount: Balance) -> ClaimProof {
+	ClaimProof {
+		amount,
+		path: merkle::merkle_proof(&merkle_claims(), 0).unwrap(),
+	}
+}
+
+#[test]
+fn claiming_with_merkle_proof_works() {
+	new_test_ext().execute_with(|| {
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+		let alice_eth_addr = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
+		let root = merkle::merkle_root(&merkle_claims()).unwrap();
+
+		Claims::<Test>::remove(&alice_eth_addr);
+
+		assert_noop!(
+			ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), Some(alice_claim_proof(500))),
+			Error::<Test>::NoClaimsRoot
+		);
+
+		assert_ok!(ClaimsPallet::set_claims_root(Origin::root(), Some(root)));
+
+		assert_ok!(ClaimsPallet::claim(
+			Origin::signed(ALICE),
+			EcdsaSignature(signature),
+			Some(alice_claim_proof(500))
+		));
+
+		assert_balance!(Balances, ALICE, 500);
+		assert!(ClaimsPallet::merkle_claimed(&alice_eth_addr));
+		assert_event_emitted!(Test, crate::Event::<Test>::Claim(ALICE, alice_eth_addr, 500));
+
+		assert_noop!(
+			ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), Some(alice_claim_proof(500))),
+			Error::<Test>::NoClaimOrAlreadyClaimed
+		);
+		assert_noop!(
+			ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None),
+			Error::<Test>::NoClaimOrAlreadyClaimed
+		);
+	})
+}
+
+#[test]
+fn claim_with_invalid_merkle_proof_fails() {
+	new_test_ext().execute_with(|| {
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+		let root = merkle::merkle_root(&merkle_claims()).unwrap();
+
+		assert_ok!(ClaimsPallet::set_claims_root(Origin::root(), Some(root)));
+
+		assert_noop!(
+			ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), Some(alice_claim_proof(501))),
+			Error::<Test>::InvalidMerkleProof
+		);
+
+		let call = <crate::Call<Test>>::claim(EcdsaSignature(signature), Some(alice_claim_proof(501))).into();
+		let info = DispatchInfo::default();
+
+		assert_eq!(
+			ValidateClaim::<Test>(PhantomData).validate(&ALICE, &call, &info, 150),
+			InvalidTransaction::Custom(Error::<Test>::InvalidMerkleProof.as_u8()).into()
+		);
+
+		assert_balance!(Balances, ALICE, 0);
+	})
+}
+
+#[test]
+fn merkle_claim_includes_stored_claim() {
+	new_test_ext().execute_with(|| {
+		set_claim_delay(5);
+
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+		let alice_eth_addr = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
+		let root = merkle::merkle_root(&merkle_claims()).unwrap();
+
+		assert_ok!(ClaimsPallet::set_claims_root(Origin::root(), Some(root)));
+
+		assert_ok!(ClaimsPallet::claim(
+			Origin::signed(ALICE),
+			EcdsaSignature(signature),
+			Some(alice_claim_proof(500))
+		));
+
+		// Proof cannot be used again while the claim is pending
+		assert!(ClaimsPallet::merkle_claimed(&alice_eth_addr));
+		assert_eq!(ClaimsPallet::pending_claims(&alice_eth_addr).unwrap().amount, CLAIM_AMOUNT + 500);
+
+		ClaimsPallet::on_initialize(6);
+
+		assert_balance!(Balances, ALICE, CLAIM_AMOUNT + 500);
+	})
+}
+
+#[test]
+fn set_claims_root_works() {
+	new_test_ext().execute_with(|| {
+		let root = merkle::merkle_root(&merkle_claims()).unwrap();
+
+		assert_noop!(
+			ClaimsPallet::set_claims_root(Origin::signed(ALICE), Some(root)),
+			sp_runtime::traits::BadOrigin
+		);
+
+		assert_ok!(ClaimsPallet::set_claims_root(Origin::root(), Some(root)));
+
+		assert_eq!(ClaimsPallet::claims_root(), Some(root));
+		assert_event_emitted!(Test, crate::Event::<Test>::ClaimsRootSet(Some(root)));
+
+		assert_ok!(ClaimsPallet::set_claims_root(Origin::root(), None));
+
+		assert_eq!(ClaimsPallet::claims_root(), None);
+	})
+}
+
+#[test]
+fn signed_extension_should_validate_execute_claim() {
+	new_test_ext().execute_with(|| {
+		set_claim_delay(5);
+
+		let signature = hex!["5b2b46b0162f4b4431f154c4b9fc5ba923690b98b0c2063720799da54cb35a354304102ede62977ba556f0b03e67710522d4b7523547c62fcdc5acea59c99aa41b"];
+		let alice_eth_addr = EthereumAddress(hex!["8202c0af5962b750123ce1a9b12e1c30a4973557"]);
+
+		let call = <crate::Call<Test>>::execute_claim(alice_eth_addr).into();
+		let info = DispatchInfo::default();
+
+		assert_eq!(
+			ValidateClaim::<Test>(PhantomData).validate(&BOB, &call, &info, 150),
+			InvalidTransaction::Custom(Error::<Test>::NoPendingClaim.as_u8()).into()
+		);
+
+		assert_ok!(ClaimsPallet::claim(Origin::signed(ALICE), EcdsaSignature(signature), None));
+
+		assert_eq!(
+			ValidateClaim::<Test>(PhantomData).validate(&BOB, &call, &info, 150),
+			InvalidTransaction::Custom(Error::<Test>::ClaimDelayNotPassed.as_u8()).into()
+		);
+
+		assert_ok!(ClaimsPallet::freeze_claims(Origin::root()));
+
+		System::set_block_number(6);
+		ClaimsPallet::on_initialize(6);
+
+		assert_eq!(
+			ValidateClaim::<Test>(PhantomData).validate(&BOB, &call, &info, 150),
+			InvalidTransaction::Custom(Error::<Test>::ClaimsFrozen.as_u8()).into()
+		);
+
+		assert_ok!(ClaimsPallet::unfreeze_claims(Origin::root()));
+
+		assert_eq!(
+			ValidateClaim::<Test>(PhantomData).validate(&BOB, &call, &info, 150),
+			Ok(ValidTransaction::default())
+		);
+	})
+}
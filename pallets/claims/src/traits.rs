@@ -20,7 +20,6 @@
 use codec::{Decode, Encode};
 
 use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
-use sp_std::vec::Vec;
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -75,30 +74,59 @@ impl sp_std::fmt::Debug for EcdsaSignature {
 	}
 }
 
+/// Half of the secp256k1 curve order. Signatures with `s` above this value are rejected, as for every valid
+/// signature `(r, s)` the signature `(r, n - s)` is valid as well.
+const SECP256K1_HALF_ORDER: [u8; 32] = [
+	0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x5d, 0x57, 0x6e,
+	0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+/// Reason why an address could not be recovered from an Ethereum signature.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SignatureError {
+	/// Recovery id `v` is not one of 0, 1, 27 or 28
+	InvalidRecoveryId,
+	/// `s` lies in the upper half of the curve order
+	HighS,
+	/// No public key can be recovered from the signature
+	RecoveryFailed,
+}
+
 impl EcdsaSignature {
-	// Constructs the message that Ethereum RPC's `personal_sign` and `eth_sign` would sign.
-	fn ethereum_signable_message(what: &[u8], prefix: &'static [u8]) -> Vec<u8> {
-		//let prefix = T::Prefix::get();
-		let mut l = prefix.len() + what.len();
-		let mut rev = Vec::new();
-		while l > 0 {
-			rev.push(b'0' + (l % 10) as u8);
-			l /= 10;
+	/// Returns the signature with `v` normalized to 0 or 1.
+	///
+	/// Accepts both `v` returned by MetaMask and Trezor (27 or 28) and raw recovery id returned by Ledger (0 or 1).
+	/// Recovery ids 2 and 3 and EIP-155 encoded `v` are rejected.
+	pub fn normalized(&self) -> Result<[u8; 65], SignatureError> {
+		let v = match self.0[64] {
+			v @ 0..=1 => v,
+			v @ 27..=28 => v - 27,
+			_ => return Err(SignatureError::InvalidRecoveryId),
+		};
+
+		if self.0[32..64] > SECP256K1_HALF_ORDER[..] {
+			return Err(SignatureError::HighS);
 		}
-		let mut v = b"\x19Ethereum Signed Message:\n".to_vec();
-		v.extend(rev.into_iter().rev());
-		v.extend_from_slice(&prefix[..]);
-		v.extend_from_slice(what);
-		v
+
+		let mut sig = self.0;
+		sig[64] = v;
+		Ok(sig)
 	}
 
-	// Attempts to recover the Ethereum address from a message signature signed by using
-	// the Ethereum RPC's `personal_sign` and `eth_sign`.
-	pub fn recover(&self, what: &[u8], prefix: &'static [u8]) -> Option<EthereumAddress> {
-		let msg = keccak_256(&Self::ethereum_signable_message(what, prefix));
+	/// Recovers the Ethereum address which signed the keccak-256 `hash`, the same way as `ecrecover` does.
+	pub fn recover_prehashed(&self, hash: &[u8; 32]) -> Result<EthereumAddress, SignatureError> {
+		let sig = self.normalized()?;
+		let pubkey = secp256k1_ecdsa_recover(&sig, hash).map_err(|_| SignatureError::RecoveryFailed)?;
 		let mut res = EthereumAddress::default();
-		res.0
-			.copy_from_slice(&keccak_256(&secp256k1_ecdsa_recover(&self.0, &msg).ok()?[..])[12..]);
-		Some(res)
+		res.0.copy_from_slice(&keccak_256(&pubkey[..])[12..]);
+		Ok(res)
+	}
+
+	/// Attempts to recover the Ethereum address from a signature of `message` signed by using
+	/// the Ethereum RPC's `personal_sign` and `eth_sign`.
+	///
+	/// `message` has to include the Ethereum message prefix, see `primitives::claim::ethereum_signable_message`.
+	pub fn recover(&self, message: &[u8]) -> Result<EthereumAddress, SignatureError> {
+		self.recover_prehashed(&keccak_256(message))
 	}
 }
@@ -48,6 +48,15 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for claims.
 pub trait WeightInfo {
 	fn claim() -> Weight;
+	fn claim_for() -> Weight;
+	fn freeze_claims() -> Weight;
+	fn unfreeze_claims() -> Weight;
+	fn unfreeze_address() -> Weight;
+	fn cancel_claim() -> Weight;
+	fn execute_claim() -> Weight;
+	fn set_end_claim_period() -> Weight;
+	fn sweep_unclaimed(n: u32) -> Weight;
+	fn set_claims_root() -> Weight;
 }
 
 /// Weights for claims using the hydraDX node and recommended hardware.
@@ -58,6 +67,48 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	fn claim_for() -> Weight {
+		(249_317_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn freeze_claims() -> Weight {
+		(18_402_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn unfreeze_claims() -> Weight {
+		(18_217_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn unfreeze_address() -> Weight {
+		(16_954_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_claim() -> Weight {
+		(45_310_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn execute_claim() -> Weight {
+		(98_742_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn set_end_claim_period() -> Weight {
+		(15_837_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn sweep_unclaimed(n: u32) -> Weight {
+		(52_116_000 as Weight)
+			.saturating_add((8_965_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn set_claims_root() -> Weight {
+		(16_102_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -67,4 +118,46 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	fn claim_for() -> Weight {
+		(249_317_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn freeze_claims() -> Weight {
+		(18_402_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn unfreeze_claims() -> Weight {
+		(18_217_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn unfreeze_address() -> Weight {
+		(16_954_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_claim() -> Weight {
+		(45_310_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn execute_claim() -> Weight {
+		(98_742_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn set_end_claim_period() -> Weight {
+		(15_837_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn sweep_unclaimed(n: u32) -> Weight {
+		(52_116_000 as Weight)
+			.saturating_add((8_965_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn set_claims_root() -> Weight {
+		(16_102_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 }
@@ -0,0 +1,72 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+use crate::Pallet as DCA;
+
+const SEED: u32 = 1;
+
+const ASSET_IN: AssetId = 1;
+const ASSET_OUT: AssetId = 2;
+const BUDGET: Balance = 1_000_000_000_000;
+
+benchmarks! {
+	terminate_schedule {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		T::Currency::deposit(ASSET_IN, &caller, BUDGET)?;
+		T::Currency::deposit(T::NativeAssetId::get(), &caller, T::ExecutionFee::get())?;
+		T::Currency::reserve(ASSET_IN, &caller, BUDGET)?;
+		T::Currency::reserve(T::NativeAssetId::get(), &caller, T::ExecutionFee::get())?;
+
+		let next_execution = T::BlockNumber::from(10u32);
+		crate::Schedules::<T>::insert(0, Schedule {
+			owner: caller.clone(),
+			assets: AssetPair { asset_in: ASSET_IN, asset_out: ASSET_OUT },
+			amount: BUDGET,
+			period: T::MinPeriod::get(),
+			remaining_budget: BUDGET,
+			bond: T::ExecutionFee::get(),
+			next_execution,
+		});
+		crate::ScheduleExecutions::<T>::append(next_execution, 0);
+
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+	verify {
+		assert!(DCA::<T>::schedules(0).is_none());
+		assert_eq!(T::Currency::reserved_balance(ASSET_IN, &caller), 0);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_terminate_schedule::<Test>());
+		});
+	}
+}
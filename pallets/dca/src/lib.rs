@@ -0,0 +1,417 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! # DCA Pallet
+//!
+//! ## Overview
+//!
+//! Dollar cost averaging pallet executes recurring sells of an asset via AMM pool. Owner of a schedule sells
+//! the same amount every period until the budget of the schedule is spent or the schedule is terminated.
+//!
+//! Executions are planned with a random offset, so they are harder to front-run, and paid by an execution fee
+//! from the bond reserved on schedule creation.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::{BalanceStatus, Get, Randomness},
+	transactional,
+	weights::Weight,
+};
+use frame_system::ensure_signed;
+use orml_traits::{MultiCurrencyExtended, MultiReservableCurrency};
+use primitives::{asset::AssetPair, traits::AMM, AssetId, Balance};
+use sp_runtime::{
+	traits::{One, Saturating, Zero},
+	DispatchError, PerThing, Permill, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// Schedule identifier
+pub type ScheduleId = u32;
+
+/// Recurring sell of `assets.asset_in` for `assets.asset_out`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Schedule<AccountId, BlockNumber> {
+	/// Account which created the schedule
+	pub owner: AccountId,
+	/// Sold and bought asset
+	pub assets: AssetPair,
+	/// Amount of asset in sold every period
+	pub amount: Balance,
+	/// Number of blocks between two executions
+	pub period: BlockNumber,
+	/// Reserved amount of asset in which was not sold yet
+	pub remaining_budget: Balance,
+	/// Reserved amount of native asset which pays execution fees
+	pub bond: Balance,
+	/// Block of the next execution
+	pub next_execution: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let schedules = <ScheduleExecutions<T>>::take(now);
+			let count = schedules.len() as u32;
+
+			for schedule_id in schedules {
+				Self::execute_schedule(schedule_id, now);
+			}
+
+			<T as Config>::WeightInfo::on_initialize(count)
+				.saturating_add(T::AMMSellWeight::get().saturating_mul(count.into()))
+		}
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Multi currency for reserving budgets and bonds
+		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = AssetId, Balance = Balance>
+			+ MultiReservableCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// AMM pool executing the sells
+		type AMMPool: AMM<Self::AccountId, AssetId, AssetPair, Balance>;
+
+		/// Weight of AMM sell of a single execution
+		#[pallet::constant]
+		type AMMSellWeight: Get<Weight>;
+
+		/// Native Asset Id, execution fees are paid in native asset
+		#[pallet::constant]
+		type NativeAssetId: Get<AssetId>;
+
+		/// Fee paid from the bond to the treasury account for each execution
+		#[pallet::constant]
+		type ExecutionFee: Get<Balance>;
+
+		/// Account which receives execution fees
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Minimal number of blocks between two executions
+		#[pallet::constant]
+		type MinPeriod: Get<Self::BlockNumber>;
+
+		/// Maximal random number of blocks by which an execution is delayed
+		#[pallet::constant]
+		type MaxExecutionOffset: Get<u32>;
+
+		/// Maximal number of executions in a block, further executions are moved to following blocks
+		#[pallet::constant]
+		type MaxExecutionsPerBlock: Get<u32>;
+
+		/// Maximal difference of amount bought by an execution from the spot price
+		#[pallet::constant]
+		type MaxSlippage: Get<Permill>;
+
+		/// Source of randomness of execution offsets
+		type RandomnessSource: Randomness<Self::Hash, Self::BlockNumber>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// It is not allowed to sell zero amount.
+		ZeroAmount,
+
+		/// Budget must cover at least one execution.
+		BudgetTooLow,
+
+		/// Period is shorter than `MinPeriod`.
+		PeriodTooShort,
+
+		/// It is not allowed to sell an asset for the same asset.
+		SameAssets,
+
+		/// Pool of the asset pair does not exist.
+		PoolNotFound,
+
+		/// Schedule does not exist.
+		ScheduleNotFound,
+
+		/// Account is not owner of the schedule.
+		NotScheduleOwner,
+
+		/// Overflow
+		Overflow,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Schedule was created. [schedule id, who, asset in, asset out, amount, period, budget]
+		Scheduled(
+			ScheduleId,
+			T::AccountId,
+			AssetId,
+			AssetId,
+			Balance,
+			T::BlockNumber,
+			Balance,
+		),
+
+		/// Schedule was executed. [schedule id, who, amount sold]
+		Executed(ScheduleId, T::AccountId, Balance),
+
+		/// Execution of the schedule failed and the schedule was terminated. [schedule id, who, error]
+		ExecutionFailed(ScheduleId, T::AccountId, DispatchError),
+
+		/// Whole budget of the schedule was sold. [schedule id, who]
+		Completed(ScheduleId, T::AccountId),
+
+		/// Schedule was terminated by its owner. [schedule id, who]
+		Terminated(ScheduleId, T::AccountId),
+	}
+
+	/// Next available schedule id.
+	#[pallet::storage]
+	#[pallet::getter(fn next_schedule_id)]
+	pub type NextScheduleId<T: Config> = StorageValue<_, ScheduleId, ValueQuery>;
+
+	/// Active schedules.
+	#[pallet::storage]
+	#[pallet::getter(fn schedules)]
+	pub type Schedules<T: Config> =
+		StorageMap<_, Blake2_128Concat, ScheduleId, Schedule<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	/// Schedules executed in a block.
+	#[pallet::storage]
+	#[pallet::getter(fn schedule_executions)]
+	pub type ScheduleExecutions<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, Vec<ScheduleId>, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create schedule selling `amount` of `asset_in` for `asset_out` every `period` blocks
+		/// until `budget` is sold.
+		///
+		/// Whole `budget` is reserved from origin's balance together with bond in native asset which covers
+		/// execution fees of all executions. Unused bond is returned when the schedule ends.
+		///
+		/// First execution is planned in the next block, each execution is delayed by a random offset.
+		///
+		/// Emits `Scheduled` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::schedule())]
+		#[transactional]
+		pub fn schedule(
+			origin: OriginFor<T>,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+			period: T::BlockNumber,
+			budget: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(budget >= amount, Error::<T>::BudgetTooLow);
+			ensure!(period >= T::MinPeriod::get(), Error::<T>::PeriodTooShort);
+			ensure!(asset_in != asset_out, Error::<T>::SameAssets);
+
+			let assets = AssetPair { asset_in, asset_out };
+
+			ensure!(T::AMMPool::exists(assets), Error::<T>::PoolNotFound);
+
+			let executions = budget.saturating_add(amount - 1) / amount;
+			let bond = T::ExecutionFee::get()
+				.checked_mul(executions)
+				.ok_or(Error::<T>::Overflow)?;
+
+			let schedule_id = Self::next_schedule_id();
+			let next_id = schedule_id.checked_add(1).ok_or(Error::<T>::Overflow)?;
+
+			T::Currency::reserve(asset_in, &who, budget)?;
+			T::Currency::reserve(T::NativeAssetId::get(), &who, bond)?;
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let next_execution = Self::plan_execution(schedule_id, now.saturating_add(One::one()));
+
+			<Schedules<T>>::insert(
+				schedule_id,
+				Schedule {
+					owner: who.clone(),
+					assets,
+					amount,
+					period,
+					remaining_budget: budget,
+					bond,
+					next_execution,
+				},
+			);
+			<NextScheduleId<T>>::put(next_id);
+
+			Self::deposit_event(Event::Scheduled(
+				schedule_id,
+				who,
+				asset_in,
+				asset_out,
+				amount,
+				period,
+				budget,
+			));
+
+			Ok(().into())
+		}
+
+		/// Terminate the schedule. Remaining budget and bond are returned to the owner.
+		///
+		/// Emits `Terminated` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::terminate_schedule())]
+		#[transactional]
+		pub fn terminate_schedule(origin: OriginFor<T>, schedule_id: ScheduleId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let schedule = Self::schedules(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+
+			ensure!(schedule.owner == who, Error::<T>::NotScheduleOwner);
+
+			<ScheduleExecutions<T>>::mutate(schedule.next_execution, |schedules| {
+				schedules.retain(|id| *id != schedule_id)
+			});
+
+			Self::remove_schedule(schedule_id, &schedule);
+
+			Self::deposit_event(Event::Terminated(schedule_id, who));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Plan execution of the schedule at random offset from `block` and return the planned block.
+	///
+	/// Execution is moved to the first following block with less than `MaxExecutionsPerBlock` executions.
+	fn plan_execution(schedule_id: ScheduleId, block: T::BlockNumber) -> T::BlockNumber {
+		let mut block = block.saturating_add(Self::random_offset(schedule_id).into());
+
+		while <ScheduleExecutions<T>>::decode_len(block).unwrap_or_default() >= T::MaxExecutionsPerBlock::get() as usize
+		{
+			block = block.saturating_add(One::one());
+		}
+
+		<ScheduleExecutions<T>>::append(block, schedule_id);
+
+		block
+	}
+
+	/// Return random number of blocks between zero and `MaxExecutionOffset`.
+	fn random_offset(schedule_id: ScheduleId) -> u32 {
+		let max_offset = T::MaxExecutionOffset::get();
+
+		if max_offset.is_zero() {
+			return 0;
+		}
+
+		let (seed, _) = T::RandomnessSource::random(&(b"dca", schedule_id).encode());
+
+		u32::decode(&mut seed.as_ref()).unwrap_or_default() % max_offset.saturating_add(1)
+	}
+
+	/// Execute the schedule and plan the next execution.
+	///
+	/// Failed schedule is terminated, the execution fee is paid anyway.
+	fn execute_schedule(schedule_id: ScheduleId, now: T::BlockNumber) {
+		let mut schedule = match Self::schedules(schedule_id) {
+			Some(schedule) => schedule,
+			None => return,
+		};
+
+		let fee = T::ExecutionFee::get().min(schedule.bond);
+		if !fee.is_zero() {
+			let _ = T::Currency::repatriate_reserved(
+				T::NativeAssetId::get(),
+				&schedule.owner,
+				&T::TreasuryAccount::get(),
+				fee,
+				BalanceStatus::Free,
+			);
+			schedule.bond = schedule.bond.saturating_sub(fee);
+		}
+
+		let amount = schedule.amount.min(schedule.remaining_budget);
+
+		if let Err(error) = Self::sell(&schedule, amount) {
+			Self::remove_schedule(schedule_id, &schedule);
+			Self::deposit_event(Event::ExecutionFailed(schedule_id, schedule.owner, error));
+			return;
+		}
+
+		schedule.remaining_budget = schedule.remaining_budget.saturating_sub(amount);
+
+		Self::deposit_event(Event::Executed(schedule_id, schedule.owner.clone(), amount));
+
+		if schedule.remaining_budget.is_zero() {
+			Self::remove_schedule(schedule_id, &schedule);
+			Self::deposit_event(Event::Completed(schedule_id, schedule.owner));
+		} else {
+			schedule.next_execution = Self::plan_execution(schedule_id, now.saturating_add(schedule.period));
+			<Schedules<T>>::insert(schedule_id, schedule);
+		}
+	}
+
+	/// Unreserve `amount` of the budget and sell it for at least spot price amount decreased by `MaxSlippage`.
+	#[transactional]
+	fn sell(schedule: &Schedule<T::AccountId, T::BlockNumber>, amount: Balance) -> DispatchResult {
+		let assets = schedule.assets;
+
+		T::Currency::unreserve(assets.asset_in, &schedule.owner, amount);
+
+		let spot_amount = T::AMMPool::get_spot_price_unchecked(assets.asset_in, assets.asset_out, amount);
+		let min_bought = T::MaxSlippage::get().left_from_one().mul_floor(spot_amount);
+
+		T::AMMPool::sell(&schedule.owner, assets, amount, min_bought, false)
+	}
+
+	/// Remove the schedule and return its remaining budget and bond to the owner.
+	fn remove_schedule(schedule_id: ScheduleId, schedule: &Schedule<T::AccountId, T::BlockNumber>) {
+		T::Currency::unreserve(schedule.assets.asset_in, &schedule.owner, schedule.remaining_budget);
+		T::Currency::unreserve(T::NativeAssetId::get(), &schedule.owner, schedule.bond);
+
+		<Schedules<T>>::remove(schedule_id);
+	}
+}
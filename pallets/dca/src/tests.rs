@@ -0,0 +1,228 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+pub use crate::mock::{
+	set_max_execution_offset, AccountId, Currency, ExtBuilder, Origin, System, Test, ALICE, BOB, CHARLIE, DCA, DOT,
+	EXECUTION_FEE, HDX, TREASURY, XYK,
+};
+use frame_support::{assert_noop, assert_ok, traits::OnInitialize};
+use primitives::Price;
+use test_utils::{assert_balance, expect_events};
+
+const POOL_LIQUIDITY: Balance = 100_000_000_000_000;
+const AMOUNT: Balance = 1_000_000;
+const BUDGET: Balance = 2_500_000;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			DOT,
+			POOL_LIQUIDITY,
+			Price::from(1)
+		));
+	});
+	ext
+}
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		DCA::on_initialize(System::block_number());
+	}
+}
+
+fn schedule_dot_sell(who: AccountId) {
+	assert_ok!(DCA::schedule(Origin::signed(who), DOT, HDX, AMOUNT, 10, BUDGET));
+}
+
+#[test]
+fn schedule_should_work() {
+	new_test_ext().execute_with(|| {
+		schedule_dot_sell(BOB);
+
+		assert_eq!(
+			DCA::schedules(0),
+			Some(Schedule {
+				owner: BOB,
+				assets: AssetPair {
+					asset_in: DOT,
+					asset_out: HDX
+				},
+				amount: AMOUNT,
+				period: 10,
+				remaining_budget: BUDGET,
+				bond: 3 * EXECUTION_FEE,
+				next_execution: 2,
+			})
+		);
+		assert_eq!(DCA::schedule_executions(2), vec![0]);
+
+		assert_eq!(Currency::reserved_balance(DOT, &BOB), BUDGET);
+		assert_eq!(Currency::reserved_balance(HDX, &BOB), 3 * EXECUTION_FEE);
+
+		expect_events::<Test>(vec![
+			Event::<Test>::Scheduled(0, BOB, DOT, HDX, AMOUNT, 10, BUDGET).into()
+		]);
+	});
+}
+
+#[test]
+fn schedule_should_not_work_with_invalid_params() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			DCA::schedule(Origin::signed(BOB), DOT, HDX, 0, 10, BUDGET),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			DCA::schedule(Origin::signed(BOB), DOT, HDX, AMOUNT, 10, AMOUNT - 1),
+			Error::<Test>::BudgetTooLow
+		);
+		assert_noop!(
+			DCA::schedule(Origin::signed(BOB), DOT, HDX, AMOUNT, 4, BUDGET),
+			Error::<Test>::PeriodTooShort
+		);
+		assert_noop!(
+			DCA::schedule(Origin::signed(BOB), DOT, DOT, AMOUNT, 10, BUDGET),
+			Error::<Test>::SameAssets
+		);
+		assert_noop!(
+			DCA::schedule(Origin::signed(BOB), DOT, 3000, AMOUNT, 10, BUDGET),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn schedule_should_be_executed_every_period_until_budget_is_sold() {
+	new_test_ext().execute_with(|| {
+		schedule_dot_sell(BOB);
+
+		let dot_balance = Currency::free_balance(DOT, &BOB);
+		let hdx_balance = Currency::free_balance(HDX, &BOB);
+
+		run_to_block(2);
+
+		assert_eq!(Currency::reserved_balance(DOT, &BOB), BUDGET - AMOUNT);
+		assert_eq!(Currency::free_balance(DOT, &BOB), dot_balance);
+		assert!(Currency::free_balance(HDX, &BOB) > hdx_balance);
+		assert_balance!(Currency, TREASURY, HDX, EXECUTION_FEE);
+		assert_eq!(DCA::schedules(0).unwrap().next_execution, 12);
+		assert_eq!(DCA::schedule_executions(12), vec![0]);
+
+		expect_events::<Test>(vec![Event::<Test>::Executed(0, BOB, AMOUNT).into()]);
+
+		run_to_block(22);
+
+		assert_eq!(DCA::schedules(0), None);
+		assert_eq!(Currency::reserved_balance(DOT, &BOB), 0);
+		assert_eq!(Currency::reserved_balance(HDX, &BOB), 0);
+		assert_balance!(Currency, TREASURY, HDX, 3 * EXECUTION_FEE);
+
+		expect_events::<Test>(vec![
+			Event::<Test>::Executed(0, BOB, BUDGET - 2 * AMOUNT).into(),
+			Event::<Test>::Completed(0, BOB).into(),
+		]);
+	});
+}
+
+#[test]
+fn terminate_schedule_should_return_budget_and_bond() {
+	new_test_ext().execute_with(|| {
+		schedule_dot_sell(BOB);
+
+		run_to_block(2);
+
+		assert_ok!(DCA::terminate_schedule(Origin::signed(BOB), 0));
+
+		assert_eq!(DCA::schedules(0), None);
+		assert_eq!(DCA::schedule_executions(12), Vec::<ScheduleId>::new());
+		assert_eq!(Currency::reserved_balance(DOT, &BOB), 0);
+		assert_eq!(Currency::reserved_balance(HDX, &BOB), 0);
+		assert_balance!(Currency, TREASURY, HDX, EXECUTION_FEE);
+
+		expect_events::<Test>(vec![Event::<Test>::Terminated(0, BOB).into()]);
+	});
+}
+
+#[test]
+fn terminate_schedule_should_not_work_when_not_allowed() {
+	new_test_ext().execute_with(|| {
+		schedule_dot_sell(BOB);
+
+		assert_noop!(
+			DCA::terminate_schedule(Origin::signed(CHARLIE), 0),
+			Error::<Test>::NotScheduleOwner
+		);
+		assert_noop!(
+			DCA::terminate_schedule(Origin::signed(BOB), 1),
+			Error::<Test>::ScheduleNotFound
+		);
+	});
+}
+
+#[test]
+fn failed_execution_should_terminate_schedule() {
+	new_test_ext().execute_with(|| {
+		let amount = POOL_LIQUIDITY / 2;
+		assert_ok!(DCA::schedule(Origin::signed(BOB), DOT, HDX, amount, 10, amount));
+
+		run_to_block(2);
+
+		assert_eq!(DCA::schedules(0), None);
+		assert_eq!(Currency::reserved_balance(DOT, &BOB), 0);
+		assert_eq!(Currency::reserved_balance(HDX, &BOB), 0);
+		assert_balance!(Currency, TREASURY, HDX, EXECUTION_FEE);
+
+		expect_events::<Test>(vec![Event::<Test>::ExecutionFailed(
+			0,
+			BOB,
+			pallet_xyk::Error::<Test>::MaxInRatioExceeded.into(),
+		)
+		.into()]);
+	});
+}
+
+#[test]
+fn executions_should_be_moved_when_block_is_full() {
+	new_test_ext().execute_with(|| {
+		schedule_dot_sell(BOB);
+		schedule_dot_sell(CHARLIE);
+		schedule_dot_sell(ALICE);
+
+		assert_eq!(DCA::schedule_executions(2), vec![0, 1]);
+		assert_eq!(DCA::schedule_executions(3), vec![2]);
+		assert_eq!(DCA::schedules(2).unwrap().next_execution, 3);
+	});
+}
+
+#[test]
+fn execution_should_be_planned_with_random_offset() {
+	new_test_ext().execute_with(|| {
+		set_max_execution_offset(10);
+
+		schedule_dot_sell(BOB);
+
+		let next_execution = DCA::schedules(0).unwrap().next_execution;
+
+		assert!((2..=12).contains(&next_execution));
+		assert_eq!(DCA::schedule_executions(next_execution), vec![0]);
+	});
+}
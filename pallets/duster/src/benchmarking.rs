@@ -0,0 +1,79 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+use crate::Pallet as Duster;
+
+const SEED: u32 = 1;
+
+const DUSTED_ASSET: AssetId = 1;
+
+benchmarks! {
+	dust_account {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let dusted: T::AccountId = account("dusted", 1, SEED);
+		let dust = T::MinCurrencyDeposits::get(&DUSTED_ASSET).saturating_sub(1).max(1);
+
+		T::Currency::deposit(DUSTED_ASSET, &dusted, dust)?;
+		T::Currency::deposit(T::NativeAssetId::get(), &T::TreasuryAccount::get(), T::Reward::get())?;
+
+	}: _(RawOrigin::Signed(caller.clone()), dusted.clone(), DUSTED_ASSET)
+	verify {
+		assert_eq!(T::Currency::free_balance(DUSTED_ASSET, &dusted), 0);
+		assert_eq!(T::Currency::free_balance(T::NativeAssetId::get(), &caller), T::Reward::get());
+	}
+
+	add_nondustable_account {
+		let account: T::AccountId = account("account", 0, SEED);
+
+	}: _(RawOrigin::Root, account.clone())
+	verify {
+		assert!(!Duster::<T>::is_dustable(&account));
+	}
+
+	remove_nondustable_account {
+		let account: T::AccountId = account("account", 0, SEED);
+		Duster::<T>::add_nondustable_account(RawOrigin::Root.into(), account.clone()).map_err(|e| e.error)?;
+
+	}: _(RawOrigin::Root, account.clone())
+	verify {
+		assert!(Duster::<T>::is_dustable(&account));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_dust_account::<Test>());
+			assert_ok!(test_benchmark_add_nondustable_account::<Test>());
+			assert_ok!(test_benchmark_remove_nondustable_account::<Test>());
+		});
+	}
+}
@@ -0,0 +1,239 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{dispatch::DispatchResult, ensure, transactional};
+use frame_system::ensure_signed;
+use orml_traits::{GetByKey, MultiCurrency};
+use primitives::{traits::DustRemovalAccountWhitelist, AssetId, Balance};
+use sp_runtime::traits::Zero;
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Multi currency for transferring dust and rewards
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// Minimal balance of each asset, accounts holding less can be dusted
+		type MinCurrencyDeposits: GetByKey<AssetId, Balance>;
+
+		/// Native Asset Id, native asset balances are never dusted
+		#[pallet::constant]
+		type NativeAssetId: Get<AssetId>;
+
+		/// Reward in native asset paid from the treasury account for dusting an account
+		#[pallet::constant]
+		type Reward: Get<Balance>;
+
+		/// Account which receives dust and pays rewards, it cannot be dusted
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Origin which can change the whitelist of accounts which cannot be dusted
+		type WhitelistUpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Account is whitelisted and cannot be dusted.
+		AccountWhitelisted,
+
+		/// Account is not whitelisted.
+		AccountNotWhitelisted,
+
+		/// Native asset balances cannot be dusted.
+		CannotDustNativeAsset,
+
+		/// Account does not hold any free balance of the asset.
+		ZeroBalance,
+
+		/// Balance of the account is not below minimal balance of the asset.
+		BalanceSufficient,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Account was dusted. [account, asset, amount]
+		Dusted(T::AccountId, AssetId, Balance),
+
+		/// Account was added to the whitelist. [account]
+		AccountWhitelisted(T::AccountId),
+
+		/// Account was removed from the whitelist. [account]
+		AccountRemovedFromWhitelist(T::AccountId),
+	}
+
+	/// Accounts which cannot be dusted.
+	#[pallet::storage]
+	#[pallet::getter(fn whitelisted)]
+	pub type AccountWhitelist<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub account_whitelist: Vec<T::AccountId>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			GenesisConfig {
+				account_whitelist: vec![],
+			}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			self.account_whitelist
+				.iter()
+				.for_each(|account| AccountWhitelist::<T>::insert(account, ()));
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Dust balance of `asset_id` held by `account` which is below minimal balance of the asset.
+		///
+		/// Free balance of the asset is transferred to the treasury account and origin receives `Reward`
+		/// in native asset from the treasury account. Whitelisted accounts, treasury account and native asset
+		/// balances cannot be dusted.
+		///
+		/// Emits `Dusted` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::dust_account())]
+		#[transactional]
+		pub fn dust_account(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			asset_id: AssetId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(asset_id != T::NativeAssetId::get(), Error::<T>::CannotDustNativeAsset);
+			ensure!(Self::is_dustable(&account), Error::<T>::AccountWhitelisted);
+
+			let dust = T::Currency::free_balance(asset_id, &account);
+
+			ensure!(!dust.is_zero(), Error::<T>::ZeroBalance);
+			ensure!(
+				T::Currency::total_balance(asset_id, &account) < T::MinCurrencyDeposits::get(&asset_id),
+				Error::<T>::BalanceSufficient
+			);
+
+			let treasury = T::TreasuryAccount::get();
+
+			T::Currency::transfer(asset_id, &account, &treasury, dust)?;
+
+			let reward = T::Reward::get();
+			if !reward.is_zero() {
+				T::Currency::transfer(T::NativeAssetId::get(), &treasury, &who, reward)?;
+			}
+
+			Self::deposit_event(Event::Dusted(account, asset_id, dust));
+
+			Ok(().into())
+		}
+
+		/// Add `account` to the whitelist of accounts which cannot be dusted.
+		///
+		/// Emits `AccountWhitelisted` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::add_nondustable_account())]
+		pub fn add_nondustable_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResultWithPostInfo {
+			T::WhitelistUpdateOrigin::ensure_origin(origin)?;
+
+			Self::add_account(&account)?;
+
+			Ok(().into())
+		}
+
+		/// Remove `account` from the whitelist of accounts which cannot be dusted.
+		///
+		/// Emits `AccountRemovedFromWhitelist` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_nondustable_account())]
+		pub fn remove_nondustable_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResultWithPostInfo {
+			T::WhitelistUpdateOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				AccountWhitelist::<T>::contains_key(&account),
+				Error::<T>::AccountNotWhitelisted
+			);
+
+			Self::remove_account(&account)?;
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Return true if `account` is neither whitelisted nor the treasury account.
+	pub fn is_dustable(account: &T::AccountId) -> bool {
+		*account != T::TreasuryAccount::get() && !AccountWhitelist::<T>::contains_key(account)
+	}
+}
+
+impl<T: Config> DustRemovalAccountWhitelist<T::AccountId> for Pallet<T> {
+	fn add_account(account: &T::AccountId) -> DispatchResult {
+		AccountWhitelist::<T>::insert(account, ());
+
+		Self::deposit_event(Event::AccountWhitelisted(account.clone()));
+
+		Ok(())
+	}
+
+	/// Remove `account` from the whitelist. Removing account which is not whitelisted does nothing.
+	fn remove_account(account: &T::AccountId) -> DispatchResult {
+		if AccountWhitelist::<T>::take(account).is_some() {
+			Self::deposit_event(Event::AccountRemovedFromWhitelist(account.clone()));
+		}
+
+		Ok(())
+	}
+}
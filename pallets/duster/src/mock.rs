@@ -0,0 +1,175 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as duster;
+use crate::Config;
+use frame_support::parameter_types;
+use frame_support::traits::GenesisBuild;
+use frame_system as system;
+use orml_traits::parameter_type_with_key;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup, Zero},
+};
+
+use primitives::{AssetId, Balance};
+
+pub type Amount = i128;
+pub type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const TREASURY: AccountId = 100;
+
+pub const HDX: AssetId = 0;
+pub const DOT: AssetId = 1;
+pub const ETH: AssetId = 2;
+
+pub const MIN_DEPOSIT: Balance = 1_000;
+pub const REWARD: Balance = 10_000;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+	 Block = Block,
+	 NodeBlock = Block,
+	 UncheckedExtrinsic = UncheckedExtrinsic,
+	 {
+		 System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		 Duster: duster::{Pallet, Call, Config<T>, Storage, Event<T>},
+		 Currency: orml_tokens::{Pallet, Event<T>},
+	 }
+
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub const NativeAssetId: AssetId = HDX;
+	pub const Reward: Balance = REWARD;
+	pub const TreasuryAccount: AccountId = TREASURY;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		Zero::zero()
+	};
+}
+
+parameter_type_with_key! {
+	pub MinCurrencyDeposits: |currency_id: AssetId| -> Balance {
+		if *currency_id == HDX {
+			Zero::zero()
+		} else {
+			MIN_DEPOSIT
+		}
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+}
+
+impl Config for Test {
+	type Event = Event;
+	type Currency = Currency;
+	type MinCurrencyDeposits = MinCurrencyDeposits;
+	type NativeAssetId = NativeAssetId;
+	type Reward = Reward;
+	type TreasuryAccount = TreasuryAccount;
+	type WhitelistUpdateOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	account_whitelist: Vec<AccountId>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			endowed_accounts: vec![
+				(ALICE, HDX, 1_000_000_000_000_000u128),
+				(ALICE, DOT, 1_000_000_000_000_000u128),
+				(BOB, DOT, 500u128),
+				(CHARLIE, DOT, 500u128),
+				(TREASURY, HDX, 1_000_000_000_000_000u128),
+				(TREASURY, DOT, 500u128),
+			],
+			account_whitelist: vec![CHARLIE],
+		}
+	}
+}
+
+impl ExtBuilder {
+	// builds genesis config
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			endowed_accounts: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		duster::GenesisConfig::<Test> {
+			account_whitelist: self.account_whitelist,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		t.into()
+	}
+}
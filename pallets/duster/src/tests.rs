@@ -0,0 +1,127 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+pub use crate::mock::{
+	Currency, Duster, ExtBuilder, Origin, System, Test, ALICE, BOB, CHARLIE, DOT, ETH, HDX, REWARD, TREASURY,
+};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError::BadOrigin;
+use test_utils::{assert_balance, expect_events};
+
+const INITIAL_BALANCE: Balance = 1_000_000_000_000_000;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+#[test]
+fn dust_account_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Duster::dust_account(Origin::signed(ALICE), BOB, DOT));
+
+		assert_balance!(Currency, BOB, DOT, 0);
+		assert_balance!(Currency, TREASURY, DOT, 1_000);
+
+		assert_balance!(Currency, ALICE, HDX, INITIAL_BALANCE + REWARD);
+		assert_balance!(Currency, TREASURY, HDX, INITIAL_BALANCE - REWARD);
+
+		expect_events::<Test>(vec![Event::Dusted(BOB, DOT, 500).into()]);
+	});
+}
+
+#[test]
+fn dust_account_should_not_work_when_balance_is_sufficient() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Duster::dust_account(Origin::signed(BOB), ALICE, DOT),
+			Error::<Test>::BalanceSufficient
+		);
+	});
+}
+
+#[test]
+fn dust_account_should_not_work_when_balance_is_zero() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Duster::dust_account(Origin::signed(ALICE), BOB, ETH),
+			Error::<Test>::ZeroBalance
+		);
+	});
+}
+
+#[test]
+fn native_asset_should_not_be_dusted() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Duster::dust_account(Origin::signed(BOB), ALICE, HDX),
+			Error::<Test>::CannotDustNativeAsset
+		);
+	});
+}
+
+#[test]
+fn whitelisted_accounts_should_not_be_dusted() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Duster::dust_account(Origin::signed(ALICE), CHARLIE, DOT),
+			Error::<Test>::AccountWhitelisted
+		);
+		assert_noop!(
+			Duster::dust_account(Origin::signed(ALICE), TREASURY, DOT),
+			Error::<Test>::AccountWhitelisted
+		);
+	});
+}
+
+#[test]
+fn whitelist_should_be_updated_by_whitelist_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Duster::add_nondustable_account(Origin::signed(ALICE), BOB), BadOrigin);
+
+		assert_ok!(Duster::add_nondustable_account(Origin::root(), BOB));
+
+		assert!(!Duster::is_dustable(&BOB));
+		assert_noop!(
+			Duster::dust_account(Origin::signed(ALICE), BOB, DOT),
+			Error::<Test>::AccountWhitelisted
+		);
+
+		assert_noop!(
+			Duster::remove_nondustable_account(Origin::signed(ALICE), BOB),
+			BadOrigin
+		);
+
+		assert_ok!(Duster::remove_nondustable_account(Origin::root(), BOB));
+
+		assert!(Duster::is_dustable(&BOB));
+		assert_ok!(Duster::dust_account(Origin::signed(ALICE), BOB, DOT));
+
+		assert_noop!(
+			Duster::remove_nondustable_account(Origin::root(), BOB),
+			Error::<Test>::AccountNotWhitelisted
+		);
+
+		expect_events::<Test>(vec![
+			Event::AccountWhitelisted(BOB).into(),
+			Event::AccountRemovedFromWhitelist(BOB).into(),
+			Event::Dusted(BOB, DOT, 500).into(),
+		]);
+	});
+}
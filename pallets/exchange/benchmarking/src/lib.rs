@@ -45,6 +45,8 @@ const INITIAL_ASSET_BALANCE: Balance = 1_000_000_000_000_000;
 
 const MAX_INTENTIONS_IN_BLOCK: u32 = 1000;
 
+const MAX_POOLS_WITH_INTENTIONS: u32 = 10;
+
 const SEED: u32 = 0;
 pub const MILLICENTS: Balance = 1_000_000_000;
 pub const CENTS: Balance = 1_000 * MILLICENTS;
@@ -94,6 +96,8 @@ fn feed_intentions<T: Config>(
 			amounts[idx as usize] as u128,
 			SELL_INTENTION_LIMIT,
 			false,
+			None,
+			0,
 		)?;
 
 		let buyer = funded_account::<T>("user", idx + number + 1);
@@ -104,6 +108,59 @@ fn feed_intentions<T: Config>(
 			amounts[idx as usize] as u128,
 			amounts[idx as usize] as u128 * 2u128,
 			false,
+			None,
+			0,
+		)?;
+	}
+
+	Ok(().into())
+}
+
+fn funded_trader<T: Config>(index: u32, asset_a: AssetId, asset_b: AssetId) -> T::AccountId {
+	let trader: T::AccountId = account("trader", index, SEED);
+
+	<T as xykpool::Config>::Currency::update_balance(asset_a, &trader, 1_000_000_000_000_000).unwrap();
+
+	<T as xykpool::Config>::Currency::update_balance(asset_b, &trader, 1_000_000_000_000_000).unwrap();
+
+	trader
+}
+
+/// Register sell and buy intentions of different amounts in the pool of `asset_a` and `asset_b`.
+///
+/// Intentions are partially matched directly, remaining amounts are traded via AMM pool.
+fn feed_intentions_in_pool<T: Config>(
+	asset_a: AssetId,
+	asset_b: AssetId,
+	number: u32,
+	first_trader: u32,
+	amounts: &[u32],
+) -> dispatch::DispatchResultWithPostInfo {
+	for idx in 0..number / 2 {
+		let amount = amounts[idx as usize] as u128;
+
+		let seller = funded_trader::<T>(first_trader + idx, asset_a, asset_b);
+		pallet_exchange::Pallet::<T>::sell(
+			RawOrigin::Signed(seller).into(),
+			asset_a,
+			asset_b,
+			amount,
+			SELL_INTENTION_LIMIT,
+			false,
+			None,
+			0,
+		)?;
+
+		let buyer = funded_trader::<T>(first_trader + number / 2 + idx, asset_a, asset_b);
+		pallet_exchange::Pallet::<T>::buy(
+			RawOrigin::Signed(buyer).into(),
+			asset_a,
+			asset_b,
+			amount,
+			amount * 2u128,
+			false,
+			None,
+			0,
 		)?;
 	}
 
@@ -143,7 +200,8 @@ benchmarks! {
 	sell_intention {
 		let caller = funded_account::<T>("caller", 1);
 
-		let nbr_intentions_appended: u32  = MAX_INTENTIONS_IN_BLOCK;
+		// Benchmarked intention must fit within `MaxIntentionsPerPair`
+		let nbr_intentions_appended: u32  = MAX_INTENTIONS_IN_BLOCK - 2;
 
 		let asset_a: AssetId = 1;
 		let asset_b: AssetId = 2;
@@ -156,7 +214,7 @@ benchmarks! {
 
 		assert_eq!(pallet_exchange::Pallet::<T>::get_intentions_count((asset_a, asset_b)), nbr_intentions_appended);
 
-	}: {  Exchange::<T>::sell(RawOrigin::Signed(caller.clone()).into(), asset_a, asset_b, amount ,limit, false)? }
+	}: {  Exchange::<T>::sell(RawOrigin::Signed(caller.clone()).into(), asset_a, asset_b, amount ,limit, false, None, 0)? }
 	verify{
 		assert_eq!(pallet_exchange::Pallet::<T>::get_intentions_count((asset_a, asset_b)), nbr_intentions_appended + 1);
 	}
@@ -169,7 +227,8 @@ benchmarks! {
 		let amount : Balance = DOLLARS;
 		let limit : Balance = DOLLARS;
 
-		let nbr_intentions_appended: u32  = MAX_INTENTIONS_IN_BLOCK;
+		// Benchmarked intention must fit within `MaxIntentionsPerPair`
+		let nbr_intentions_appended: u32  = MAX_INTENTIONS_IN_BLOCK - 2;
 
 		initialize_pool::<T>(caller.clone(), asset_a, asset_b, amount, Price::from(1))?;
 
@@ -177,7 +236,7 @@ benchmarks! {
 
 		assert_eq!(pallet_exchange::Pallet::<T>::get_intentions_count((asset_a, asset_b)), nbr_intentions_appended);
 
-	}: {  Exchange::<T>::buy(RawOrigin::Signed(caller.clone()).into(), asset_a, asset_b, amount / 10 ,limit, false)? }
+	}: {  Exchange::<T>::buy(RawOrigin::Signed(caller.clone()).into(), asset_a, asset_b, amount / 10 ,limit, false, None, 0)? }
 	verify{
 		assert_eq!(pallet_exchange::Pallet::<T>::get_intentions_count((asset_a, asset_b)), nbr_intentions_appended + 1);
 	}
@@ -207,6 +266,36 @@ benchmarks! {
 		validate_finalize::<T>(asset_a, asset_b, t, &INTENTION_AMOUNTS)?;
 	}
 
+	on_finalize_intentions_in_pools {
+		let t in 0 .. MAX_INTENTIONS_IN_BLOCK; // Intention component
+		let p in 1 .. MAX_POOLS_WITH_INTENTIONS; // Pool component
+
+		let caller = funded_account::<T>("caller", 1);
+
+		let asset_a: AssetId = 1;
+		let amount : Balance = 100_000_000_000_000;
+
+		let intentions_in_pool = t / p;
+
+		for idx in 0 .. p {
+			let asset_b: AssetId = idx + 2;
+
+			<T as xykpool::Config>::Currency::update_balance(asset_b, &caller, 1_000_000_000_000_000).unwrap();
+
+			initialize_pool::<T>(caller.clone(), asset_a, asset_b, amount, Price::from(1))?;
+
+			feed_intentions_in_pool::<T>(asset_a, asset_b, intentions_in_pool, idx * MAX_INTENTIONS_IN_BLOCK, &INTENTION_AMOUNTS)?;
+
+			assert_eq!(pallet_exchange::Pallet::<T>::get_intentions_count((asset_a, asset_b)), intentions_in_pool / 2 * 2);
+		}
+
+	}: {  Exchange::<T>::on_finalize(t.into()); }
+	verify {
+		for idx in 0 .. p {
+			assert_eq!(pallet_exchange::Pallet::<T>::get_intentions_count((asset_a, idx + 2)), 0);
+		}
+	}
+
 	on_finalize_buys_no_matches {
 		let t in 0 .. 100; // Intention component
 		let caller = funded_account::<T>("caller", 1);
@@ -226,6 +315,8 @@ benchmarks! {
 				BUY_INTENTION_AMOUNT,
 				BUY_INTENTION_LIMIT,
 				false,
+				None,
+				0,
 			)?;
 		}
 
@@ -259,6 +350,8 @@ benchmarks! {
 				SELL_INTENTION_AMOUNT,
 				SELL_INTENTION_LIMIT,
 				false,
+				None,
+				0,
 			)?;
 		}
 
@@ -309,6 +402,8 @@ benchmarks! {
 			SELL_INTENTION_AMOUNT,
 			SELL_INTENTION_LIMIT,
 			false,
+			None,
+			0,
 		)?;
 
 		assert_eq!(pallet_exchange::Pallet::<T>::get_intentions_count((asset_a, asset_b)), 1);
@@ -359,6 +454,8 @@ benchmarks! {
 			1_000_000_000,
 			max_sold,
 			false,
+			None,
+			0,
 		)?;
 
 		assert_eq!(pallet_exchange::Pallet::<T>::get_intentions_count((asset_a, asset_b)), 1);
@@ -369,6 +466,33 @@ benchmarks! {
 		assert_eq!(<T as xykpool::Config>::Currency::free_balance(asset_a, &buyer), 1000001000000000);
 		assert_eq!(<T as xykpool::Config>::Currency::free_balance(asset_b, &buyer), 999998886666666);
 	}
+
+	cancel_intention {
+		let caller = funded_account::<T>("caller", 1);
+
+		let asset_a: AssetId = 1;
+		let asset_b: AssetId = 2;
+		let amount : Balance = DOLLARS;
+		let limit : Balance = DOLLARS;
+
+		// Benchmarked intention must fit within `MaxIntentionsPerPair`
+		let nbr_intentions_appended: u32  = MAX_INTENTIONS_IN_BLOCK - 2;
+
+		initialize_pool::<T>(caller.clone(), asset_a, asset_b, amount, Price::from(10))?;
+
+		feed_intentions::<T>(asset_a, asset_b, nbr_intentions_appended, &INTENTION_AMOUNTS)?;
+
+		Exchange::<T>::sell(RawOrigin::Signed(caller.clone()).into(), asset_a, asset_b, amount, limit, false, None, 0)?;
+
+		let intention_id = pallet_exchange::Pallet::<T>::get_intentions((asset_a, asset_b))
+			.last()
+			.map(|intention| intention.intention_id)
+			.ok_or("Intention not registered")?;
+
+	}: {  Exchange::<T>::cancel_intention(RawOrigin::Signed(caller.clone()).into(), asset_a, asset_b, intention_id)? }
+	verify {
+		assert!(pallet_exchange::Pallet::<T>::get_intentions((asset_a, asset_b)).iter().all(|intention| intention.intention_id != intention_id));
+	}
 }
 
 #[cfg(test)]
@@ -384,12 +508,14 @@ mod tests {
 			assert_ok!(test_benchmark_sell_intention::<Test>());
 			assert_ok!(test_benchmark_buy_intention::<Test>());
 			assert_ok!(test_benchmark_on_finalize::<Test>());
+			assert_ok!(test_benchmark_on_finalize_intentions_in_pools::<Test>());
 			assert_ok!(test_benchmark_on_finalize_buys_no_matches::<Test>());
 			assert_ok!(test_benchmark_on_finalize_sells_no_matches::<Test>());
 			assert_ok!(test_benchmark_sell_extrinsic::<Test>());
 			assert_ok!(test_benchmark_on_finalize_for_one_sell_extrinsic::<Test>());
 			assert_ok!(test_benchmark_buy_extrinsic::<Test>());
 			assert_ok!(test_benchmark_on_finalize_for_one_buy_extrinsic::<Test>());
+			assert_ok!(test_benchmark_cancel_intention::<Test>());
 		});
 	}
 }
@@ -18,14 +18,15 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::parameter_types;
 use frame_support::traits::GenesisBuild;
+use frame_support::{parameter_types, weights::Weight};
 use frame_system as system;
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, IdentityLookup, Zero},
+	Permill,
 };
 
 use pallet_xyk::AssetPairAccountIdFor;
@@ -40,6 +41,7 @@ pub const CHARLIE: AccountId = 3;
 pub const DAVE: AccountId = 4;
 pub const FERDIE: AccountId = 5;
 pub const GEORGE: AccountId = 6;
+pub const TIP_RECEIVER: AccountId = 200;
 
 pub const HDX: AssetId = 1000;
 pub const DOT: AssetId = 2000;
@@ -58,7 +60,8 @@ frame_support::construct_runtime!(
 				 Exchange: pallet_exchange::{Pallet, Call, Storage, Event<T>},
 				 XYKPallet: pallet_xyk::{Pallet, Call, Storage, Event<T>},
 				 Currency: orml_tokens::{Pallet, Event<T>},
-				 AssetRegistry: pallet_asset_registry::{Pallet, Storage},
+				 AssetRegistry: pallet_asset_registry::{Pallet, Storage, Event<T>},
+				 Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
 		 }
 
 );
@@ -66,7 +69,16 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub const HDXAssetId: AssetId = HDX;
+	pub const MaxIntentionFee: Balance = 1_000_000_000;
+	pub const IntentionQueueCap: u32 = 10;
+	pub const MaxIntentionsPerPair: u32 = 1_000;
+	pub const MaxPriceDeviation: Permill = Permill::from_percent(100);
+	pub const MaxSignedIntentionsPerBatch: u32 = 5;
+	pub const MaxResolutionWeight: Weight = Weight::MAX;
 	pub ExchangeFeeRate: fee::Fee = fee::Fee::default();
+	pub ProtocolFeeRate: fee::Fee = fee::Fee { numerator: 0, denominator: 1 };
+	pub const TreasuryAccount: AccountId = 100;
+	pub const TipReceiverAccount: AccountId = TIP_RECEIVER;
 }
 
 impl system::Config for Test {
@@ -127,7 +139,11 @@ impl AssetPairAccountIdFor<AssetId, u64> for AssetPairAccountIdTest {
 }
 
 impl pallet_asset_registry::Config for Test {
+	type Event = Event;
 	type AssetId = AssetId;
+	type AssetNativeLocation = primitives::asset::AssetLocation;
+	type RegistryOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = ();
 }
 
 impl pallet_xyk::Config for Test {
@@ -137,6 +153,40 @@ impl pallet_xyk::Config for Test {
 	type NativeAssetId = HDXAssetId;
 	type WeightInfo = ();
 	type GetExchangeFee = ExchangeFeeRate;
+	type DefaultProtocolFee = ProtocolFeeRate;
+	type TreasuryAccount = TreasuryAccount;
+	type ProtocolFeeOrigin = frame_system::EnsureRoot<AccountId>;
+	type PoolOwnerInactivityPeriod = PoolOwnerInactivityPeriod;
+	type MaxPoolDust = MaxPoolDust;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type PoolGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type Scheduler = Scheduler;
+	type AuditLog = ();
+	type AMMHandler = ();
+	type FlashLoanFee = ExchangeFeeRate;
+	type NonDustableWhitelistHandler = ();
+	type FeeDiscount = ();
+}
+
+parameter_types! {
+	pub const PoolOwnerInactivityPeriod: u64 = 100;
+	pub const MaxPoolDust: Balance = 1_000;
+	pub const MinPoolLiquidity: Balance = 2_000;
+	pub const MaximumSchedulerWeight: u64 = 1_000_000_000;
+	pub const MaxScheduledPerBlock: u32 = 50;
+}
+
+impl pallet_scheduler::Config for Test {
+	type Event = Event;
+	type Origin = Origin;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type WeightInfo = ();
 }
 
 impl pallet_exchange::Config for Test {
@@ -144,7 +194,18 @@ impl pallet_exchange::Config for Test {
 	type AMMPool = XYKPallet;
 	type Currency = Currency;
 	type Resolver = pallet_exchange::Pallet<Test>;
+	type OrderBook = ();
 	type WeightInfo = ();
+	type NativeAssetId = HDXAssetId;
+	type MaxIntentionFee = MaxIntentionFee;
+	type IntentionQueueCap = IntentionQueueCap;
+	type MaxIntentionsPerPair = MaxIntentionsPerPair;
+	type MaxPriceDeviation = MaxPriceDeviation;
+	type IntentionSignature = TestSignature;
+	type IntentionSigner = UintAuthorityId;
+	type MaxSignedIntentionsPerBatch = MaxSignedIntentionsPerBatch;
+	type MaxResolutionWeight = MaxResolutionWeight;
+	type TipReceiver = TipReceiverAccount;
 }
 
 pub struct ExtBuilder {
@@ -20,17 +20,31 @@
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 #![allow(clippy::unnecessary_wraps)]
+#![allow(clippy::too_many_arguments)]
 
-use frame_support::{dispatch, ensure};
+use frame_support::{
+	dispatch, ensure,
+	traits::{BalanceStatus, Get, IsSubType},
+	transactional,
+};
 use frame_system::{self as system, ensure_signed};
 
-use codec::Encode;
+use codec::{Decode, Encode};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
+	traits::{DispatchInfoOf, IdentifyAccount, SignedExtension, Verify, Zero},
+	transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction},
+	PerThing, Permill,
+};
+use sp_std::marker::PhantomData;
 use sp_std::vec::Vec;
 
 use primitives::{
 	asset::AssetPair,
-	traits::{Resolver, AMM},
-	Amount, AssetId, Balance, ExchangeIntention, IntentionType, MIN_TRADING_LIMIT,
+	intention::SignedIntention,
+	traits::{AMMHandlers, LiquidityChange, OrderBook, Resolver, AMM},
+	units::TypedPrice,
+	Amount, AssetId, Balance, ExchangeIntention, IntentionType, Price, MIN_TRADING_LIMIT,
 };
 use sp_std::borrow::ToOwned;
 
@@ -38,10 +52,10 @@ use orml_traits::{MultiCurrency, MultiCurrencyExtended, MultiReservableCurrency}
 
 use direct::{DirectTradeData, Transfer};
 use frame_support::weights::Weight;
-use primitives::traits::AMMTransfer;
+use primitives::traits::{AMMTransfer, TradeSource};
 
 use frame_support::sp_runtime::offchain::storage_lock::BlockNumberProvider;
-use frame_support::sp_runtime::traits::Hash;
+use frame_support::sp_runtime::traits::{Hash, Saturating};
 
 #[cfg(test)]
 mod mock;
@@ -51,16 +65,33 @@ pub mod weights;
 use weights::WeightInfo;
 
 mod direct;
+mod migration;
 #[cfg(test)]
 mod tests;
 
+#[cfg(test)]
+mod matching_spec;
+
 /// Intention alias
 type IntentionId<T> = <T as system::Config>::Hash;
-pub type Intention<T> = ExchangeIntention<<T as system::Config>::AccountId, Balance, IntentionId<T>>;
+pub type Intention<T> =
+	ExchangeIntention<<T as system::Config>::AccountId, Balance, IntentionId<T>, <T as system::Config>::BlockNumber>;
+
+pub type SignedIntentionOf<T> = SignedIntention<
+	<T as system::Config>::AccountId,
+	<T as system::Config>::BlockNumber,
+	<T as Config>::IntentionSignature,
+>;
 
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
+/// Amount of the first asset of a pair for which the block start price of the pair is calculated.
+const PRICE_REFERENCE_AMOUNT: Balance = 1_000_000_000_000;
+
+/// Weight of verifying signature of one signed intention, based on sr25519 verification.
+const SIGNATURE_VERIFICATION_WEIGHT: Weight = 50_000_000;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -72,14 +103,45 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
-		/// Finalize and resolve all registered intentions.
+		fn on_runtime_upgrade() -> Weight {
+			migration::add_intention_tips::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			migration::check_intention_counts::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			migration::check_intention_counts::<T>()
+		}
+
+		/// Finalize and resolve registered intentions.
 		/// Group/match intentions which can be directly traded.
-		fn on_finalize(_n: T::BlockNumber) {
-			for ((asset_1, asset_2), count) in ExchangeAssetsIntentionCount::<T>::iter() {
+		///
+		/// Asset pairs are resolved until `MaxResolutionWeight` is consumed. Intentions of remaining pairs
+		/// are deferred to the next block, in which their pairs are resolved first. Intentions of at least
+		/// one pair are resolved in every block.
+		///
+		/// Complexity: `O(P * I^2)` where `P` is number of asset pairs with registered intentions
+		/// and `I` is bounded by `MaxIntentionsPerPair`.
+		fn on_finalize(n: T::BlockNumber) {
+			let mut retained = Vec::<Intention<T>>::new();
+			let mut deferred = Vec::<Intention<T>>::new();
+			let mut deferred_pairs = Vec::<(AssetId, AssetId)>::new();
+			let mut consumed: Weight = 0;
+
+			let mut pairs = ExchangeAssetsIntentionCount::<T>::iter()
 				// If no intention registered for asset1/2, move onto next one
-				if count == 0u32 {
-					continue;
-				}
+				.filter(|(_, count)| *count > 0u32)
+				.collect::<Vec<_>>();
+
+			// Pairs deferred in the previous block are resolved first.
+			let previously_deferred = DeferredPairs::<T>::take();
+			pairs.sort_by_key(|(pair, _)| !previously_deferred.contains(pair));
+
+			for ((asset_1, asset_2), count) in pairs {
 				let pair = AssetPair {
 					asset_in: asset_1,
 					asset_out: asset_2,
@@ -90,17 +152,79 @@ pub mod pallet {
 				let asset_a_ins = <ExchangeAssetsIntentions<T>>::get((asset_2, asset_1));
 				let asset_b_ins = <ExchangeAssetsIntentions<T>>::get((asset_1, asset_2));
 
+				let pair_weight = Self::pair_resolution_weight(count);
+
+				if consumed != 0 && consumed.saturating_add(pair_weight) > T::MaxResolutionWeight::get() {
+					deferred_pairs.push((asset_1, asset_2));
+					deferred.extend(asset_a_ins);
+					deferred.extend(asset_b_ins);
+					continue;
+				}
+
+				consumed = consumed.saturating_add(pair_weight);
+
 				//TODO: we can short circuit here if nothing in asset_b_sells and just resolve asset_a sells.
 
-				Self::process_exchange_intentions(&pair_account, &asset_a_ins, &asset_b_ins);
+				let pair_retained = Self::process_exchange_intentions(&pair_account, &asset_a_ins, &asset_b_ins, n);
+
+				// Priority fees of intentions which are not carried over are paid
+				for intention in asset_a_ins.iter().chain(asset_b_ins.iter()) {
+					if !pair_retained
+						.iter()
+						.any(|retained| retained.intention_id == intention.intention_id)
+					{
+						Self::pay_tip(intention);
+					}
+				}
+
+				retained.extend(pair_retained);
 			}
 
 			ExchangeAssetsIntentionCount::<T>::remove_all();
 			ExchangeAssetsIntentions::<T>::remove_all();
+			BlockStartPrices::<T>::remove_all();
+			IntentionIdNonce::<T>::kill();
+
+			for intention in deferred.iter() {
+				Self::deposit_event(Event::IntentionDeferred(intention.who.clone(), intention.intention_id));
+			}
+
+			if !deferred_pairs.is_empty() {
+				DeferredPairs::<T>::put(deferred_pairs);
+			}
+
+			// Unresolved intentions which are still valid and deferred intentions are carried over to the next block.
+			for intention in retained.into_iter().chain(deferred) {
+				ExchangeAssetsIntentionCount::<T>::mutate(intention.assets.ordered_pair(), |total| *total += 1u32);
+				<ExchangeAssetsIntentions<T>>::append(
+					(intention.assets.asset_in, intention.assets.asset_out),
+					intention,
+				);
+			}
 		}
 
+		/// Intentions registered in this block pay for their resolution. Weight of resolving intentions
+		/// carried over from previous blocks, up to `MaxResolutionWeight`, is accounted here.
 		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			let (intentions, pools) = ExchangeAssetsIntentionCount::<T>::iter()
+				.filter(|(_, count)| *count > 0u32)
+				.fold((0u32, 0u32), |(intentions, pools), (_, count)| {
+					(intentions.saturating_add(count), pools.saturating_add(1))
+				});
+
+			let retained_weight = if intentions > 0u32 {
+				T::WeightInfo::on_finalize_intentions_in_pools(intentions, pools)
+					.saturating_add(T::OrderBook::fill_orders_weight().saturating_mul(intentions as Weight))
+					.min(T::MaxResolutionWeight::get())
+			} else {
+				0
+			};
+
 			T::WeightInfo::known_overhead_for_on_finalize()
+				.saturating_add(retained_weight)
+				.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+				.saturating_add(T::DbWeight::get().reads(pools.saturating_add(1) as Weight))
+				.saturating_add(T::DbWeight::get().reads_writes(3 * pools as Weight, pools as Weight))
 		}
 	}
 
@@ -114,12 +238,55 @@ pub mod pallet {
 		/// Intention resolver
 		type Resolver: Resolver<Self::AccountId, Intention<Self>, Error<Self>>;
 
+		/// Book of resting limit orders which intentions are settled against before AMM trade
+		type OrderBook: OrderBook<Self::AccountId, AssetPair, Balance, Price>;
+
 		/// Currency for transfers
 		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = AssetId, Balance = Balance, Amount = Amount>
 			+ MultiReservableCurrency<Self::AccountId>;
 
 		/// Weight information for the extrinsics.
 		type WeightInfo: WeightInfo;
+
+		/// Asset in which intention fee is paid
+		#[pallet::constant]
+		type NativeAssetId: Get<AssetId>;
+
+		/// Intention fee charged when intention queue of an asset pair is full
+		#[pallet::constant]
+		type MaxIntentionFee: Get<Balance>;
+
+		/// Number of intentions of an asset pair at which intention fee reaches `MaxIntentionFee`
+		#[pallet::constant]
+		type IntentionQueueCap: Get<u32>;
+
+		/// Maximum number of intentions of an asset pair registered in one block, including both directions
+		/// and intentions carried over from previous blocks
+		#[pallet::constant]
+		type MaxIntentionsPerPair: Get<u32>;
+
+		/// Maximum deviation of AMM execution price of an intention to the detriment of its submitter
+		/// from the spot price of the pool at the start of the block
+		#[pallet::constant]
+		type MaxPriceDeviation: Get<Permill>;
+
+		/// Signature of intentions submitted by relayers on behalf of their owners
+		type IntentionSignature: Parameter + Verify<Signer = Self::IntentionSigner>;
+
+		/// Signer of intentions submitted by relayers, identifies the intention owner
+		type IntentionSigner: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Maximum number of signed intentions submitted in one batch
+		#[pallet::constant]
+		type MaxSignedIntentionsPerBatch: Get<u32>;
+
+		/// Maximum weight of intention resolution at the end of the block. Intentions of asset pairs
+		/// which do not fit are carried over to the next block
+		#[pallet::constant]
+		type MaxResolutionWeight: Get<Weight>;
+
+		/// Account which receives priority fees of resolved intentions, e.g. the block author
+		type TipReceiver: Get<Self::AccountId>;
 	}
 
 	#[pallet::event]
@@ -171,6 +338,35 @@ pub mod pallet {
 			IntentionId<T>,
 			dispatch::DispatchError,
 		),
+
+		/// Intention cancelled event
+		/// who, intention id
+		IntentionCancelled(T::AccountId, IntentionId<T>),
+
+		/// Intention nonce was incremented by its owner
+		/// who, new nonce
+		IntentionNonceIncremented(T::AccountId, u64),
+
+		/// Intention resolved against resting limit orders
+		/// who, intention type, intention id, amount paid, amount bought
+		IntentionResolvedOrderBookTrade(T::AccountId, IntentionType, IntentionId<T>, Balance, Balance),
+
+		/// Signed intention submitted by relayer has not been registered
+		/// relayer, who, nonce, error detail
+		SignedIntentionRejected(T::AccountId, T::AccountId, u64, dispatch::DispatchError),
+
+		/// Intention owner paid fee to relayer
+		/// who, relayer, amount
+		RelayerFeePaid(T::AccountId, T::AccountId, Balance),
+
+		/// Intention was not resolved because resolution weight limit of the block was reached,
+		/// it is carried over to the next block
+		/// who, intention id
+		IntentionDeferred(T::AccountId, IntentionId<T>),
+
+		/// Priority fee of resolved intention was paid
+		/// who, intention id, receiver, amount
+		IntentionTipPaid(T::AccountId, IntentionId<T>, T::AccountId, Balance),
 	}
 
 	#[pallet::error]
@@ -189,6 +385,33 @@ pub mod pallet {
 
 		/// Trade amount is too low.
 		MinimumTradeLimitNotReached,
+
+		/// Intention does not exist.
+		IntentionNotFound,
+
+		/// Intention has not been registered by the account.
+		NotIntentionOwner,
+
+		/// Maximum number of intentions of the asset pair has been reached in this block.
+		TooManyIntentions,
+
+		/// AMM execution price deviates from the spot price at the start of the block more than allowed.
+		PriceDeviationExceeded,
+
+		/// Signature of the intention payload is not valid for its owner.
+		InvalidSignature,
+
+		/// Nonce of the intention payload is not the next intention nonce of its owner.
+		InvalidNonce,
+
+		/// Deadline of the intention payload has passed.
+		SignedIntentionExpired,
+
+		/// Maximum number of signed intentions in one batch has been exceeded.
+		TooManySignedIntentions,
+
+		/// Insufficient native asset balance to reserve the priority fee.
+		InsufficientTipBalance,
 	}
 
 	/// Intention count for current block
@@ -197,6 +420,11 @@ pub mod pallet {
 	pub type ExchangeAssetsIntentionCount<T: Config> =
 		StorageMap<_, Blake2_128Concat, (AssetId, AssetId), u32, ValueQuery>;
 
+	/// Nonce used to generate unique intention ids in the current block
+	#[pallet::storage]
+	#[pallet::getter(fn intention_id_nonce)]
+	pub type IntentionIdNonce<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	/// Registered intentions for current block
 	/// Stored as ( asset_a, asset_b ) combination where asset_a is meant to be exchanged for asset_b ( asset_a < asset_b)
 	#[pallet::storage]
@@ -204,11 +432,37 @@ pub mod pallet {
 	pub type ExchangeAssetsIntentions<T: Config> =
 		StorageMap<_, Blake2_128Concat, (AssetId, AssetId), Vec<Intention<T>>, ValueQuery>;
 
+	/// Spot price of asset pairs traded in the current block, recorded before their first trade
+	/// Stored as ( asset_a, asset_b ) combination ( asset_a < asset_b ) with amount of asset_b for one asset_a
+	#[pallet::storage]
+	#[pallet::getter(fn block_start_price)]
+	pub type BlockStartPrices<T: Config> = StorageMap<_, Blake2_128Concat, (AssetId, AssetId), Price, OptionQuery>;
+
+	/// Asset pairs whose intentions were deferred to the current block because of the resolution weight limit
+	/// Stored as ordered ( asset_a, asset_b ) pairs ( asset_a < asset_b )
+	#[pallet::storage]
+	#[pallet::getter(fn deferred_pairs)]
+	pub type DeferredPairs<T: Config> = StorageValue<_, Vec<(AssetId, AssetId)>, ValueQuery>;
+
+	/// Next nonce of intentions signed by an account and submitted by relayers
+	#[pallet::storage]
+	#[pallet::getter(fn intention_nonce)]
+	pub type IntentionNonces<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create sell intention
 		/// Calculate current spot price, create an intention and store in ```ExchangeAssetsIntentions```
-		#[pallet::weight(< T as Config >::WeightInfo::sell_intention() + < T as Config >::WeightInfo::on_finalize_for_one_sell_extrinsic() - < T as Config >::WeightInfo::known_overhead_for_on_finalize())]
+		///
+		/// `valid_for` - optional number of blocks for which the intention is kept if it cannot be resolved.
+		/// Intention without it is resolved or dropped at the end of the current block.
+		///
+		/// `tip` - priority fee in native asset. Intentions with higher tip are resolved first. Tip is reserved
+		/// and paid to `TipReceiver` when the intention is resolved or dropped, it is returned if the intention
+		/// is cancelled.
+		#[pallet::weight(<T as Config>::WeightInfo::sell_intention().saturating_add(
+			Pallet::<T>::resolution_weight(<T as Config>::WeightInfo::on_finalize_for_one_sell_extrinsic())
+		).saturating_add(Pallet::<T>::tip_weight(*tip)))]
 		pub fn sell(
 			origin: OriginFor<T>,
 			asset_sell: AssetId,
@@ -216,38 +470,20 @@ pub mod pallet {
 			amount_sell: Balance,
 			min_bought: Balance,
 			discount: bool,
+			valid_for: Option<T::BlockNumber>,
+			tip: Balance,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			ensure! {
-				amount_sell >= MIN_TRADING_LIMIT,
-				Error::<T>::MinimumTradeLimitNotReached
-			};
-
-			let assets = AssetPair {
-				asset_in: asset_sell,
-				asset_out: asset_buy,
-			};
-
-			ensure!(T::AMMPool::exists(assets), Error::<T>::TokenPoolNotFound);
-
-			ensure!(
-				T::Currency::free_balance(asset_sell, &who) >= amount_sell,
-				Error::<T>::InsufficientAssetBalance
-			);
-
-			let amount_buy = T::AMMPool::get_spot_price_unchecked(asset_sell, asset_buy, amount_sell);
-
-			ensure!(amount_buy != 0, Error::<T>::ZeroSpotPrice);
-
-			Self::register_intention(
+			Self::do_sell(
 				&who,
-				IntentionType::SELL,
-				assets,
+				asset_sell,
+				asset_buy,
 				amount_sell,
-				amount_buy,
 				min_bought,
 				discount,
+				valid_for,
+				tip,
 			)?;
 
 			Ok(().into())
@@ -255,7 +491,14 @@ pub mod pallet {
 
 		/// Create buy intention
 		/// Calculate current spot price, create an intention and store in ```ExchangeAssetsIntentions```
-		#[pallet::weight(<T as Config>::WeightInfo::buy_intention() + <T as Config>::WeightInfo::on_finalize_for_one_buy_extrinsic() -  <T as Config>::WeightInfo::known_overhead_for_on_finalize())]
+		///
+		/// `valid_for` - optional number of blocks for which the intention is kept if it cannot be resolved.
+		/// Intention without it is resolved or dropped at the end of the current block.
+		///
+		/// `tip` - priority fee in native asset, see `sell`.
+		#[pallet::weight(<T as Config>::WeightInfo::buy_intention().saturating_add(
+			Pallet::<T>::resolution_weight(<T as Config>::WeightInfo::on_finalize_for_one_buy_extrinsic())
+		).saturating_add(Pallet::<T>::tip_weight(*tip)))]
 		pub fn buy(
 			origin: OriginFor<T>,
 			asset_buy: AssetId,
@@ -263,39 +506,111 @@ pub mod pallet {
 			amount_buy: Balance,
 			max_sold: Balance,
 			discount: bool,
+			valid_for: Option<T::BlockNumber>,
+			tip: Balance,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			ensure! {
-				amount_buy >= MIN_TRADING_LIMIT,
-				Error::<T>::MinimumTradeLimitNotReached
-			};
+			Self::do_buy(
+				&who, asset_buy, asset_sell, amount_buy, max_sold, discount, valid_for, tip,
+			)?;
 
-			let assets = AssetPair {
-				asset_in: asset_sell,
-				asset_out: asset_buy,
-			};
+			Ok(().into())
+		}
+
+		/// Cancel registered intention
+		/// Remove not yet resolved intention of the origin from ```ExchangeAssetsIntentions```
+		/// Priority fee of the intention is returned.
+		///
+		/// `asset_sell`, `asset_buy` - asset pair of the intention as registered by `sell` or `buy`.
+		///
+		/// Complexity: `O(I)` where `I` is bounded by `MaxIntentionsPerPair`.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_intention())]
+		pub fn cancel_intention(
+			origin: OriginFor<T>,
+			asset_sell: AssetId,
+			asset_buy: AssetId,
+			intention_id: IntentionId<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let assets = (asset_sell, asset_buy);
 
-			ensure!(T::AMMPool::exists(assets), Error::<T>::TokenPoolNotFound);
+			let intention = ExchangeAssetsIntentions::<T>::get(assets)
+				.into_iter()
+				.find(|intention| intention.intention_id == intention_id)
+				.ok_or(Error::<T>::IntentionNotFound)?;
+
+			ensure!(intention.who == who, Error::<T>::NotIntentionOwner);
+
+			ExchangeAssetsIntentions::<T>::mutate_exists(assets, |maybe_intentions| {
+				if let Some(intentions) = maybe_intentions {
+					intentions.retain(|intention| intention.intention_id != intention_id);
+
+					if intentions.is_empty() {
+						*maybe_intentions = None;
+					}
+				}
+			});
 
-			let amount_sell = T::AMMPool::get_spot_price_unchecked(asset_buy, asset_sell, amount_buy);
+			ExchangeAssetsIntentionCount::<T>::mutate(intention.assets.ordered_pair(), |total| {
+				*total = total.saturating_sub(1u32)
+			});
 
-			ensure!(amount_sell != 0, Error::<T>::ZeroSpotPrice);
+			T::Currency::unreserve(T::NativeAssetId::get(), &who, intention.tip);
+
+			Self::deposit_event(Event::IntentionCancelled(who, intention_id));
+
+			Ok(().into())
+		}
+
+		/// Increment intention nonce of the origin
+		/// Invalidates intentions signed off-chain with the current nonce which have not been submitted yet.
+		///
+		/// Emits `IntentionNonceIncremented` event when successful.
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn increment_intention_nonce(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let nonce = IntentionNonces::<T>::mutate(&who, |nonce| {
+				*nonce = nonce.saturating_add(1);
+				*nonce
+			});
+
+			Self::deposit_event(Event::IntentionNonceIncremented(who, nonce));
+
+			Ok(().into())
+		}
+
+		/// Submit intentions signed off-chain by their owners
+		///
+		/// Each intention is registered on behalf of its owner as if submitted by `sell` or `buy`. Owner pays
+		/// `relayer_fee` of the payload in native asset to the origin.
+		///
+		/// Intention with invalid signature or nonce, past its deadline, or which cannot be registered, is skipped
+		/// and `SignedIntentionRejected` event is emitted. Other intentions of the batch are still registered.
+		///
+		/// Intention fee of all intentions of the batch is paid by the origin.
+		#[pallet::weight(Pallet::<T>::signed_intentions_weight(intentions.len() as u32))]
+		pub fn submit_intentions_batch(
+			origin: OriginFor<T>,
+			intentions: Vec<SignedIntentionOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let relayer = ensure_signed(origin)?;
 
 			ensure!(
-				T::Currency::free_balance(asset_sell, &who) >= amount_sell,
-				Error::<T>::InsufficientAssetBalance
+				intentions.len() as u32 <= T::MaxSignedIntentionsPerBatch::get(),
+				Error::<T>::TooManySignedIntentions
 			);
 
-			Self::register_intention(
-				&who,
-				IntentionType::BUY,
-				assets,
-				amount_sell,
-				amount_buy,
-				max_sold,
-				discount,
-			)?;
+			for signed in intentions {
+				let who = signed.payload.who.clone();
+				let nonce = signed.payload.nonce;
+
+				if let Err(error) = Self::submit_signed_intention(&relayer, signed) {
+					Self::deposit_event(Event::SignedIntentionRejected(relayer.clone(), who, nonce, error));
+				}
+			}
 
 			Ok(().into())
 		}
@@ -304,7 +619,333 @@ pub mod pallet {
 
 // "Internal" functions, callable by code.
 impl<T: Config> Pallet<T> {
+	/// Return weight of resolving one more intention at the end of the block.
+	///
+	/// Intention pays for its share of matching intentions in a pool including the pool overhead,
+	/// or for a single AMM trade if it is higher.
+	fn resolution_weight(amm_trade_weight: Weight) -> Weight {
+		let matching_weight = T::WeightInfo::on_finalize_intentions_in_pools(1, 1)
+			.saturating_sub(T::WeightInfo::on_finalize_intentions_in_pools(0, 0));
+
+		matching_weight
+			.max(amm_trade_weight.saturating_sub(T::WeightInfo::known_overhead_for_on_finalize()))
+			.saturating_add(T::OrderBook::fill_orders_weight())
+	}
+
+	/// Return weight of reserving priority fee `tip` of an intention and paying it on resolution.
+	fn tip_weight(tip: Balance) -> Weight {
+		if tip.is_zero() {
+			0
+		} else {
+			T::DbWeight::get().reads_writes(3, 3)
+		}
+	}
+
+	/// Return part of priority fee `tip` paid in `asset`.
+	fn tip_in(asset: AssetId, tip: Balance) -> Balance {
+		if asset == T::NativeAssetId::get() {
+			tip
+		} else {
+			Balance::zero()
+		}
+	}
+
+	/// Pay reserved priority fee of the intention to `TipReceiver`.
+	fn pay_tip(intention: &Intention<T>) {
+		if intention.tip.is_zero() {
+			return;
+		}
+
+		let receiver = T::TipReceiver::get();
+
+		let unpaid = T::Currency::repatriate_reserved(
+			T::NativeAssetId::get(),
+			&intention.who,
+			&receiver,
+			intention.tip,
+			BalanceStatus::Free,
+		)
+		.unwrap_or(intention.tip);
+
+		let paid = intention.tip.saturating_sub(unpaid);
+
+		if !paid.is_zero() {
+			Self::deposit_event(Event::IntentionTipPaid(
+				intention.who.clone(),
+				intention.intention_id,
+				receiver,
+				paid,
+			));
+		}
+	}
+
+	/// Return weight of resolving `count` intentions of one asset pair at the end of the block.
+	fn pair_resolution_weight(count: u32) -> Weight {
+		T::WeightInfo::on_finalize_intentions_in_pools(count, 1)
+			.saturating_sub(T::WeightInfo::on_finalize_intentions_in_pools(0, 0))
+			.saturating_add(T::OrderBook::fill_orders_weight().saturating_mul(count as Weight))
+	}
+
+	/// Return weight of `submit_intentions_batch` with `count` signed intentions.
+	///
+	/// Each intention pays for the more expensive of sell and buy registration and resolution,
+	/// signature verification, nonce update, relayer fee transfer and priority fee payment.
+	fn signed_intentions_weight(count: u32) -> Weight {
+		let sell = T::WeightInfo::sell_intention().saturating_add(Self::resolution_weight(
+			T::WeightInfo::on_finalize_for_one_sell_extrinsic(),
+		));
+		let buy = T::WeightInfo::buy_intention().saturating_add(Self::resolution_weight(
+			T::WeightInfo::on_finalize_for_one_buy_extrinsic(),
+		));
+
+		sell.max(buy)
+			.saturating_add(SIGNATURE_VERIFICATION_WEIGHT)
+			.saturating_add(T::DbWeight::get().reads_writes(4, 3))
+			.saturating_add(Self::tip_weight(Balance::max_value()))
+			.saturating_mul(count as Weight)
+	}
+
+	/// Verify signed intention and register it on behalf of its owner.
+	///
+	/// Nothing is changed if any step fails.
+	#[transactional]
+	fn submit_signed_intention(relayer: &T::AccountId, signed: SignedIntentionOf<T>) -> dispatch::DispatchResult {
+		let payload = signed.payload;
+
+		ensure!(
+			payload.nonce == Self::intention_nonce(&payload.who),
+			Error::<T>::InvalidNonce
+		);
+
+		ensure!(
+			<system::Pallet<T>>::block_number() <= payload.deadline,
+			Error::<T>::SignedIntentionExpired
+		);
+
+		let genesis_hash = <system::Pallet<T>>::block_hash(T::BlockNumber::zero());
+		let message = payload.signing_message(&genesis_hash);
+
+		ensure!(
+			signed.signature.verify(message.as_slice(), &payload.who),
+			Error::<T>::InvalidSignature
+		);
+
+		IntentionNonces::<T>::insert(&payload.who, payload.nonce.saturating_add(1));
+
+		match payload.sell_or_buy {
+			IntentionType::SELL => Self::do_sell(
+				&payload.who,
+				payload.asset_sell,
+				payload.asset_buy,
+				payload.amount,
+				payload.limit,
+				payload.discount,
+				payload.valid_for,
+				payload.tip,
+			)?,
+			IntentionType::BUY => Self::do_buy(
+				&payload.who,
+				payload.asset_buy,
+				payload.asset_sell,
+				payload.amount,
+				payload.limit,
+				payload.discount,
+				payload.valid_for,
+				payload.tip,
+			)?,
+		}
+
+		if !payload.relayer_fee.is_zero() {
+			T::Currency::transfer(T::NativeAssetId::get(), &payload.who, relayer, payload.relayer_fee)?;
+
+			Self::deposit_event(Event::RelayerFeePaid(payload.who, relayer.clone(), payload.relayer_fee));
+		}
+
+		Ok(())
+	}
+
+	/// Validate sell intention and register it.
+	fn do_sell(
+		who: &T::AccountId,
+		asset_sell: AssetId,
+		asset_buy: AssetId,
+		amount_sell: Balance,
+		min_bought: Balance,
+		discount: bool,
+		valid_for: Option<T::BlockNumber>,
+		tip: Balance,
+	) -> dispatch::DispatchResult {
+		ensure! {
+			amount_sell >= MIN_TRADING_LIMIT,
+			Error::<T>::MinimumTradeLimitNotReached
+		};
+
+		let assets = AssetPair {
+			asset_in: asset_sell,
+			asset_out: asset_buy,
+		};
+
+		ensure!(T::AMMPool::exists(assets), Error::<T>::TokenPoolNotFound);
+
+		ensure!(
+			T::Currency::free_balance(asset_sell, who) >= amount_sell.saturating_add(Self::tip_in(asset_sell, tip)),
+			Error::<T>::InsufficientAssetBalance
+		);
+
+		let amount_buy = T::AMMPool::get_spot_price_unchecked(asset_sell, asset_buy, amount_sell);
+
+		ensure!(amount_buy != 0, Error::<T>::ZeroSpotPrice);
+
+		Self::register_intention(
+			who,
+			IntentionType::SELL,
+			assets,
+			amount_sell,
+			amount_buy,
+			min_bought,
+			discount,
+			valid_for,
+			tip,
+		)
+	}
+
+	/// Validate buy intention and register it.
+	fn do_buy(
+		who: &T::AccountId,
+		asset_buy: AssetId,
+		asset_sell: AssetId,
+		amount_buy: Balance,
+		max_sold: Balance,
+		discount: bool,
+		valid_for: Option<T::BlockNumber>,
+		tip: Balance,
+	) -> dispatch::DispatchResult {
+		ensure! {
+			amount_buy >= MIN_TRADING_LIMIT,
+			Error::<T>::MinimumTradeLimitNotReached
+		};
+
+		let assets = AssetPair {
+			asset_in: asset_sell,
+			asset_out: asset_buy,
+		};
+
+		ensure!(T::AMMPool::exists(assets), Error::<T>::TokenPoolNotFound);
+
+		let amount_sell = T::AMMPool::get_spot_price_unchecked(asset_buy, asset_sell, amount_buy);
+
+		ensure!(amount_sell != 0, Error::<T>::ZeroSpotPrice);
+
+		ensure!(
+			T::Currency::free_balance(asset_sell, who) >= amount_sell.saturating_add(Self::tip_in(asset_sell, tip)),
+			Error::<T>::InsufficientAssetBalance
+		);
+
+		Self::register_intention(
+			who,
+			IntentionType::BUY,
+			assets,
+			amount_sell,
+			amount_buy,
+			max_sold,
+			discount,
+			valid_for,
+			tip,
+		)
+	}
+
+	/// Settle intention against resting limit orders priced at least as well as the AMM pool.
+	///
+	/// Return rest of the intention with adjusted amounts and trade limit or `None` if it was settled completely.
+	fn settle_with_order_book(intention: &Intention<T>) -> Option<Intention<T>> {
+		let mut rest = intention.clone();
+
+		let amm_amount_in = T::AMMPool::get_spot_price_unchecked(
+			intention.assets.asset_out,
+			intention.assets.asset_in,
+			intention.amount_out,
+		);
+
+		let max_price = match TypedPrice::checked_from_rational(amm_amount_in.into(), intention.amount_out.into()) {
+			Some(price) if !price.is_zero() => price.to_price(),
+			_ => return Some(rest),
+		};
+
+		let (paid, bought) = match intention.sell_or_buy {
+			IntentionType::SELL => T::OrderBook::fill_orders(
+				&intention.who,
+				intention.assets,
+				intention.amount_in,
+				Balance::MAX,
+				max_price,
+			),
+			IntentionType::BUY => T::OrderBook::fill_orders(
+				&intention.who,
+				intention.assets,
+				intention.trade_limit,
+				intention.amount_out,
+				max_price,
+			),
+		};
+
+		if bought.is_zero() {
+			return Some(rest);
+		}
+
+		Self::deposit_event(Event::IntentionResolvedOrderBookTrade(
+			intention.who.clone(),
+			intention.sell_or_buy,
+			intention.intention_id,
+			paid,
+			bought,
+		));
+
+		rest.amount_in = rest.amount_in.saturating_sub(paid);
+		rest.amount_out = rest.amount_out.saturating_sub(bought);
+
+		match intention.sell_or_buy {
+			IntentionType::SELL => {
+				rest.trade_limit = rest.trade_limit.saturating_sub(bought);
+
+				if rest.amount_in.is_zero() {
+					return None;
+				}
+			}
+			IntentionType::BUY => {
+				rest.trade_limit = rest.trade_limit.saturating_sub(paid);
+
+				if rest.amount_out.is_zero() {
+					return None;
+				}
+			}
+		}
+
+		Some(rest)
+	}
+
+	/// Return fee for registering new intention of given asset pair.
+	///
+	/// Fee grows quadratically with number of intentions already registered for the pair - it is zero
+	/// when the queue is empty and reaches `MaxIntentionFee` at `IntentionQueueCap`.
+	pub fn intention_fee(assets: AssetPair) -> Balance {
+		let cap = T::IntentionQueueCap::get() as u128;
+
+		if cap.is_zero() {
+			return T::MaxIntentionFee::get();
+		}
+
+		let depth = (ExchangeAssetsIntentionCount::<T>::get(assets.ordered_pair()) as u128).min(cap);
+
+		multiply_by_rational(T::MaxIntentionFee::get(), depth * depth, cap * cap)
+			.unwrap_or_else(|_| T::MaxIntentionFee::get())
+	}
+
 	/// Register SELL or BUY intention
+	///
+	/// Fails if `MaxIntentionsPerPair` intentions of the asset pair have been already registered in this block.
+	/// Cancelled intentions are still counted.
+	///
+	/// Fails if priority fee of the intention cannot be reserved.
 	fn register_intention(
 		who: &T::AccountId,
 		intention_type: IntentionType,
@@ -313,10 +954,20 @@ impl<T: Config> Pallet<T> {
 		amount_out: Balance,
 		limit: Balance,
 		discount: bool,
+		valid_for: Option<T::BlockNumber>,
+		tip: Balance,
 	) -> dispatch::DispatchResult {
 		let intention_count = ExchangeAssetsIntentionCount::<T>::get(assets.ordered_pair());
 
-		let intention_id = Self::generate_intention_id(who, intention_count, &assets);
+		ensure!(
+			intention_count < T::MaxIntentionsPerPair::get(),
+			Error::<T>::TooManyIntentions
+		);
+
+		T::Currency::reserve(T::NativeAssetId::get(), who, tip).map_err(|_| Error::<T>::InsufficientTipBalance)?;
+
+		let nonce = IntentionIdNonce::<T>::get();
+		let intention_id = Self::generate_intention_id(who, nonce, &assets);
 
 		let intention = Intention::<T> {
 			who: who.clone(),
@@ -327,11 +978,15 @@ impl<T: Config> Pallet<T> {
 			sell_or_buy: intention_type,
 			intention_id,
 			trade_limit: limit,
+			valid_until: valid_for.map(|blocks| <system::Pallet<T>>::current_block_number().saturating_add(blocks)),
+			tip,
 		};
+
 		// Note: cannot use ordered tuple pair, as this must be stored as (in,out) pair
 		<ExchangeAssetsIntentions<T>>::append((assets.asset_in, assets.asset_out), intention);
 
 		ExchangeAssetsIntentionCount::<T>::mutate(assets.ordered_pair(), |total| *total += 1u32);
+		IntentionIdNonce::<T>::put(nonce.wrapping_add(1));
 
 		match intention_type {
 			IntentionType::SELL => {
@@ -366,21 +1021,31 @@ impl<T: Config> Pallet<T> {
 	/// satisfying  that sum( b_in_intentions.amount_sell ) <= a_in_intention.amount_sell
 	///
 	/// Intention A must be valid - that means that it is verified first by validating if it was possible to do AMM trade.
+	///
+	/// Returns intentions which could not be resolved and are still valid after block ```now```.
 	fn process_exchange_intentions(
 		pair_account: &T::AccountId,
 		a_in_intentions: &[Intention<T>],
 		b_in_intentions: &[Intention<T>],
-	) {
+		now: T::BlockNumber,
+	) -> Vec<Intention<T>> {
+		let mut retained = Vec::<Intention<T>>::new();
+
+		let seed = <system::Pallet<T>>::parent_hash();
+
 		let mut b_copy = b_in_intentions.to_owned();
 		let mut a_copy = a_in_intentions.to_owned();
 
-		b_copy.sort_by(|a, b| b.amount_in.cmp(&a.amount_in));
-		a_copy.sort_by(|a, b| b.amount_in.cmp(&a.amount_in));
+		Self::sort_intentions(&mut b_copy, &seed);
+		Self::sort_intentions(&mut a_copy, &seed);
 
 		b_copy.reverse();
 
 		for intention in a_copy {
 			if !Self::verify_intention(&intention) {
+				if Self::is_valid_after(&intention, now) {
+					retained.push(intention);
+				}
 				continue;
 			}
 
@@ -401,21 +1066,123 @@ impl<T: Config> Pallet<T> {
 
 		// If something left in b_in_intentions, just run it through AMM.
 		while let Some(b_intention) = b_copy.pop() {
+			if Self::is_valid_after(&b_intention, now) && !Self::verify_intention(&b_intention) {
+				retained.push(b_intention);
+				continue;
+			}
 			T::Resolver::resolve_single_intention(&b_intention);
 		}
+
+		retained
+	}
+
+	/// Sort intentions in matching order - by priority fee, highest first, and by sold amount, largest first.
+	///
+	/// Intentions with equal priority fee and sold amount are ordered by `matching_key`, so the result does not
+	/// depend on the order in which intentions were submitted.
+	fn sort_intentions(intentions: &mut Vec<Intention<T>>, seed: &T::Hash) {
+		intentions.sort_by_cached_key(|intention| {
+			(
+				sp_std::cmp::Reverse(intention.tip),
+				sp_std::cmp::Reverse(intention.amount_in),
+				Self::matching_key(seed, intention),
+			)
+		});
+	}
+
+	/// Deterministic key used to order intentions with equal sold amount.
+	///
+	/// The key is derived from intention content only and the ordering seed - hash of the parent block.
+	/// Intention id is not used as it depends on the position of the intention in the queue.
+	fn matching_key(seed: &T::Hash, intention: &Intention<T>) -> T::Hash {
+		(
+			seed,
+			&intention.who,
+			intention.assets,
+			intention.amount_in,
+			intention.amount_out,
+			intention.trade_limit,
+			intention.discount,
+			intention.sell_or_buy,
+			intention.valid_until,
+			intention.tip,
+		)
+			.using_encoded(T::Hashing::hash)
+	}
+
+	/// Check if intention is still valid after given block.
+	fn is_valid_after(intention: &Intention<T>, now: T::BlockNumber) -> bool {
+		matches!(intention.valid_until, Some(valid_until) if valid_until > now)
+	}
+
+	/// Return block start price of ordered asset pair.
+	///
+	/// Price is recorded from the current spot price of the pair if it is not known yet. This happens before
+	/// the first AMM trade of the pair in the block, whether the trade comes from this pallet or not.
+	fn snapshot_block_start_price(pair: (AssetId, AssetId)) -> Option<TypedPrice> {
+		if let Some(price) = BlockStartPrices::<T>::get(pair) {
+			return Some(TypedPrice::from(price));
+		}
+
+		let amount_b = T::AMMPool::get_spot_price_unchecked(pair.0, pair.1, PRICE_REFERENCE_AMOUNT);
+
+		let price = TypedPrice::checked_from_rational(amount_b.into(), PRICE_REFERENCE_AMOUNT.into())
+			.filter(|price| !price.is_zero())?;
+
+		BlockStartPrices::<T>::insert(pair, price.to_price());
+
+		Some(price)
+	}
+
+	/// Check that AMM execution price of the transfer is not worse for the trader than the block start price
+	/// of the asset pair by more than `MaxPriceDeviation`.
+	///
+	/// Check is skipped if block start price of the pair cannot be determined.
+	fn ensure_price_within_band(
+		amm_tranfer_type: IntentionType,
+		transfer: &AMMTransfer<T::AccountId, AssetPair, Balance>,
+	) -> dispatch::DispatchResult {
+		let (paid, received) = match amm_tranfer_type {
+			IntentionType::SELL => (transfer.amount, transfer.amount_out),
+			IntentionType::BUY => (transfer.amount_out, transfer.amount),
+		};
+
+		let pair = transfer.assets.ordered_pair();
+
+		let start_price = match Self::snapshot_block_start_price(pair) {
+			Some(price) if transfer.assets.asset_in == pair.0 => price,
+			Some(price) => match price.checked_reciprocal() {
+				Some(reciprocal) => reciprocal,
+				None => return Ok(()),
+			},
+			None => return Ok(()),
+		};
+
+		let min_received = T::MaxPriceDeviation::get()
+			.left_from_one()
+			.mul_floor(start_price.saturating_mul_floor(paid.into()).to_balance());
+
+		ensure!(received >= min_received, Error::<T>::PriceDeviationExceeded);
+
+		Ok(())
 	}
 
 	/// Execute AMM trade.
 	///
 	/// Perform AMM trade with given transfer details.
+	/// Trade fails if its execution price deviates from the block start price more than `MaxPriceDeviation`.
 	fn execute_amm_transfer(
 		amm_tranfer_type: IntentionType,
 		intention_id: IntentionId<T>,
-		transfer: &AMMTransfer<T::AccountId, AssetPair, Balance>,
+		mut transfer: AMMTransfer<T::AccountId, AssetPair, Balance>,
 	) -> dispatch::DispatchResult {
+		Self::ensure_price_within_band(amm_tranfer_type, &transfer)?;
+
+		transfer.source = TradeSource::Exchange;
+
 		match amm_tranfer_type {
 			IntentionType::SELL => {
-				T::AMMPool::execute_sell(transfer)?;
+				T::AMMPool::execute_sell(&transfer)?;
 
 				Self::deposit_event(Event::IntentionResolvedAMMTrade(
 					transfer.origin.clone(),
@@ -426,7 +1193,7 @@ impl<T: Config> Pallet<T> {
 				));
 			}
 			IntentionType::BUY => {
-				T::AMMPool::execute_buy(transfer)?;
+				T::AMMPool::execute_buy(&transfer)?;
 
 				Self::deposit_event(Event::IntentionResolvedAMMTrade(
 					transfer.origin.clone(),
@@ -512,6 +1279,11 @@ impl<T: Config> Pallet<T> {
 impl<T: Config> Resolver<T::AccountId, Intention<T>, Error<T>> for Pallet<T> {
 	/// Resolve intention via AMM pool.
 	fn resolve_single_intention(intention: &Intention<T>) {
+		let intention = match Self::settle_with_order_book(intention) {
+			Some(rest) => rest,
+			None => return,
+		};
+
 		let amm_transfer = match intention.sell_or_buy {
 			IntentionType::SELL => T::AMMPool::validate_sell(
 				&intention.who,
@@ -530,7 +1302,7 @@ impl<T: Config> Resolver<T::AccountId, Intention<T>, Error<T>> for Pallet<T> {
 		};
 
 		match amm_transfer {
-			Ok(x) => match Self::execute_amm_transfer(intention.sell_or_buy, intention.intention_id, &x) {
+			Ok(x) => match Self::execute_amm_transfer(intention.sell_or_buy, intention.intention_id, x) {
 				Ok(_) => {}
 				Err(error) => {
 					Self::send_intention_error_event(&intention, error);
@@ -696,7 +1468,7 @@ impl<T: Config> Resolver<T::AccountId, Intention<T>, Error<T>> for Pallet<T> {
 						match Self::execute_amm_transfer(
 							matched_intention.sell_or_buy,
 							matched_intention.intention_id,
-							&amm_transfer,
+							amm_transfer,
 						) {
 							Ok(_) => {
 								dt.execute();
@@ -784,3 +1556,117 @@ impl<T: Config> Resolver<T::AccountId, Intention<T>, Error<T>> for Pallet<T> {
 		}
 	}
 }
+
+/// Block start price of the pair is recorded before the first trade of the block changes pool reserves.
+impl<T: Config> AMMHandlers<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
+	fn on_before_trade(
+		_pool: &T::AccountId,
+		_trade_type: IntentionType,
+		transfer: &AMMTransfer<T::AccountId, AssetPair, Balance>,
+	) -> dispatch::DispatchResult {
+		Self::snapshot_block_start_price(transfer.assets.ordered_pair());
+		Ok(())
+	}
+
+	fn on_trade(
+		_pool: &T::AccountId,
+		_trade_type: IntentionType,
+		_transfer: &AMMTransfer<T::AccountId, AssetPair, Balance>,
+	) {
+	}
+
+	fn on_liquidity_changed(_pool: &T::AccountId, _change: &LiquidityChange<T::AccountId, AssetId, Balance>) {}
+
+	/// Block start price and pool state to calculate it are read, price is stored on the first trade of the pair.
+	fn on_trade_weight() -> Weight {
+		T::DbWeight::get().reads_writes(5, 1)
+	}
+}
+
+/// Signed extension that charges intention fee scaled by depth of intention queue of traded asset pair.
+#[derive(Encode, Decode, Clone, Eq, PartialEq)]
+pub struct ChargeIntentionFee<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for ChargeIntentionFee<T> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "ChargeIntentionFee")
+	}
+}
+
+impl<T: Config + Send + Sync> ChargeIntentionFee<T>
+where
+	<T as frame_system::Config>::Call: IsSubType<Call<T>>,
+{
+	#[cfg_attr(feature = "cargo-clippy", allow(clippy::new_without_default))]
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+
+	/// Return intention fee for the call if it registers an intention.
+	fn fee_for(call: &<T as frame_system::Config>::Call) -> Option<Balance> {
+		match call.is_sub_type() {
+			Some(Call::sell(asset_sell, asset_buy, ..)) => Some(Pallet::<T>::intention_fee(AssetPair {
+				asset_in: *asset_sell,
+				asset_out: *asset_buy,
+			})),
+			Some(Call::buy(asset_buy, asset_sell, ..)) => Some(Pallet::<T>::intention_fee(AssetPair {
+				asset_in: *asset_sell,
+				asset_out: *asset_buy,
+			})),
+			Some(Call::submit_intentions_batch(intentions)) => Some(
+				intentions
+					.iter()
+					.map(|signed| {
+						let (asset_in, asset_out) = (signed.payload.asset_sell, signed.payload.asset_buy);
+						Pallet::<T>::intention_fee(AssetPair { asset_in, asset_out })
+					})
+					.fold(Balance::zero(), |total, fee| total.saturating_add(fee)),
+			),
+			_ => None,
+		}
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for ChargeIntentionFee<T>
+where
+	<T as frame_system::Config>::Call: IsSubType<Call<T>>,
+{
+	const IDENTIFIER: &'static str = "ChargeIntentionFee";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::Call;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if let Some(fee) = Self::fee_for(call) {
+			T::Currency::ensure_can_withdraw(T::NativeAssetId::get(), who, fee)
+				.map_err(|_| InvalidTransaction::Payment)?;
+		}
+		Ok(ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		if let Some(fee) = Self::fee_for(call) {
+			if !fee.is_zero() {
+				T::Currency::withdraw(T::NativeAssetId::get(), who, fee).map_err(|_| InvalidTransaction::Payment)?;
+			}
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,319 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Matching specification tests.
+//!
+//! Scenarios are loaded from versioned JSON fixtures in `fixtures/matching` which are shared with the frontend.
+//! See `fixtures/README.md` for the format.
+
+use super::*;
+use crate::mock::{
+	AccountId, Currency, Event as TestEvent, Exchange, ExtBuilder, Origin, System, Test, ALICE, BOB, CHARLIE, DAVE,
+	DOT, ETH, FERDIE, GEORGE, HDX, XYK,
+};
+use frame_support::assert_ok;
+use frame_support::traits::OnFinalize;
+use frame_system::InitKind;
+use primitives::Price;
+use serde::{Deserialize, Deserializer};
+use sp_runtime::FixedPointNumber;
+
+/// Version of the fixture format supported by the runner.
+const FIXTURE_VERSION: u32 = 1;
+
+/// Balance encoded as decimal string, JSON numbers cannot represent all balances.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FixtureBalance(Balance);
+
+impl<'de> Deserialize<'de> for FixtureBalance {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let s = String::deserialize(deserializer)?;
+		s.parse().map(FixtureBalance).map_err(serde::de::Error::custom)
+	}
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Fixture {
+	version: u32,
+	name: String,
+	#[allow(dead_code)]
+	description: String,
+	pools: Vec<PoolSpec>,
+	intentions: Vec<IntentionSpec>,
+	expected: Expected,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PoolSpec {
+	provider: String,
+	asset_a: String,
+	asset_b: String,
+	reserve_a: FixtureBalance,
+	reserve_b: FixtureBalance,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct IntentionSpec {
+	who: String,
+	#[serde(rename = "type")]
+	kind: String,
+	asset_sell: String,
+	asset_buy: String,
+	amount: FixtureBalance,
+	limit: FixtureBalance,
+	#[serde(default)]
+	discount: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+	fills: Vec<Fill>,
+	balances: Vec<BalanceSpec>,
+	pool_reserves: Vec<ReservesSpec>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BalanceSpec {
+	who: String,
+	asset: String,
+	amount: FixtureBalance,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ReservesSpec {
+	asset_a: String,
+	asset_b: String,
+	reserve_a: FixtureBalance,
+	reserve_b: FixtureBalance,
+}
+
+/// Outcome of intention resolution, intentions are referred to by their index in the fixture.
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE", deny_unknown_fields)]
+enum Fill {
+	Amm {
+		intention: usize,
+		amounts: (FixtureBalance, FixtureBalance),
+	},
+	OrderBook {
+		intention: usize,
+		amounts: (FixtureBalance, FixtureBalance),
+	},
+	Direct {
+		intentions: (usize, usize),
+		amounts: (FixtureBalance, FixtureBalance),
+	},
+	DirectFee {
+		intention: usize,
+		asset: String,
+		amount: FixtureBalance,
+	},
+	Error {
+		intention: usize,
+	},
+}
+
+fn account(name: &str) -> AccountId {
+	match name {
+		"ALICE" => ALICE,
+		"BOB" => BOB,
+		"CHARLIE" => CHARLIE,
+		"DAVE" => DAVE,
+		"FERDIE" => FERDIE,
+		"GEORGE" => GEORGE,
+		_ => panic!("Unknown account {}", name),
+	}
+}
+
+fn asset(name: &str) -> AssetId {
+	match name {
+		"HDX" => HDX,
+		"DOT" => DOT,
+		"ETH" => ETH,
+		_ => panic!("Unknown asset {}", name),
+	}
+}
+
+fn asset_name(asset: AssetId) -> String {
+	match asset {
+		HDX => "HDX",
+		DOT => "DOT",
+		ETH => "ETH",
+		_ => panic!("Unknown asset {}", asset),
+	}
+	.to_string()
+}
+
+fn run_fixture(json: &str) {
+	let fixture: Fixture = serde_json::from_str(json).expect("Invalid fixture");
+
+	assert_eq!(
+		fixture.version, FIXTURE_VERSION,
+		"Unsupported version of fixture {}",
+		fixture.name
+	);
+
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		for pool in fixture.pools.iter() {
+			let price =
+				Price::checked_from_rational(pool.reserve_b.0, pool.reserve_a.0).expect("Invalid pool reserves");
+
+			assert_ok!(XYK::create_pool(
+				Origin::signed(account(&pool.provider)),
+				asset(&pool.asset_a),
+				asset(&pool.asset_b),
+				pool.reserve_a.0,
+				price
+			));
+		}
+
+		// Start with no events so that only resolution is compared
+		System::initialize(&1, &[0u8; 32].into(), &Default::default(), InitKind::Full);
+
+		let mut intention_ids = Vec::new();
+
+		for intention in fixture.intentions.iter() {
+			let origin = Origin::signed(account(&intention.who));
+			let asset_sell = asset(&intention.asset_sell);
+			let asset_buy = asset(&intention.asset_buy);
+
+			match intention.kind.as_str() {
+				"SELL" => assert_ok!(Exchange::sell(
+					origin,
+					asset_sell,
+					asset_buy,
+					intention.amount.0,
+					intention.limit.0,
+					intention.discount,
+					None,
+					0,
+				)),
+				"BUY" => assert_ok!(Exchange::buy(
+					origin,
+					asset_buy,
+					asset_sell,
+					intention.amount.0,
+					intention.limit.0,
+					intention.discount,
+					None,
+					0,
+				)),
+				kind => panic!("Unknown intention type {}", kind),
+			}
+
+			match test_utils::last_event::<Test>() {
+				TestEvent::exchange(Event::IntentionRegistered(.., intention_id)) => intention_ids.push(intention_id),
+				event => panic!("Intention registration expected, got {:?}", event),
+			}
+		}
+
+		<Exchange as OnFinalize<u64>>::on_finalize(9);
+
+		let index = |id: &IntentionId<Test>| intention_ids.iter().position(|i| i == id).expect("Unknown intention");
+
+		let fills: Vec<Fill> = test_utils::events::<Test>()
+			.into_iter()
+			.filter_map(|event| match event {
+				TestEvent::exchange(Event::IntentionResolvedAMMTrade(_, _, id, a, b)) => Some(Fill::Amm {
+					intention: index(&id),
+					amounts: (FixtureBalance(a), FixtureBalance(b)),
+				}),
+				TestEvent::exchange(Event::IntentionResolvedOrderBookTrade(_, _, id, a, b)) => Some(Fill::OrderBook {
+					intention: index(&id),
+					amounts: (FixtureBalance(a), FixtureBalance(b)),
+				}),
+				TestEvent::exchange(Event::IntentionResolvedDirectTrade(_, _, id_a, id_b, a, b)) => {
+					Some(Fill::Direct {
+						intentions: (index(&id_a), index(&id_b)),
+						amounts: (FixtureBalance(a), FixtureBalance(b)),
+					})
+				}
+				TestEvent::exchange(Event::IntentionResolvedDirectTradeFees(_, id, _, asset, amount)) => {
+					Some(Fill::DirectFee {
+						intention: index(&id),
+						asset: asset_name(asset),
+						amount: FixtureBalance(amount),
+					})
+				}
+				TestEvent::exchange(Event::IntentionResolveErrorEvent(_, _, _, id, _))
+				| TestEvent::exchange(Event::InsufficientAssetBalanceEvent(_, _, _, id, _)) => {
+					Some(Fill::Error { intention: index(&id) })
+				}
+				_ => None,
+			})
+			.collect();
+
+		assert_eq!(fills, fixture.expected.fills, "Fills of fixture {}", fixture.name);
+
+		for balance in fixture.expected.balances.iter() {
+			assert_eq!(
+				Currency::free_balance(asset(&balance.asset), &account(&balance.who)),
+				balance.amount.0,
+				"Balance of {} {} in fixture {}",
+				balance.who,
+				balance.asset,
+				fixture.name
+			);
+		}
+
+		for reserves in fixture.expected.pool_reserves.iter() {
+			let pair_account = XYK::get_pair_id(AssetPair {
+				asset_in: asset(&reserves.asset_a),
+				asset_out: asset(&reserves.asset_b),
+			});
+
+			assert_eq!(
+				(
+					Currency::free_balance(asset(&reserves.asset_a), &pair_account),
+					Currency::free_balance(asset(&reserves.asset_b), &pair_account)
+				),
+				(reserves.reserve_a.0, reserves.reserve_b.0),
+				"Reserves of {}/{} pool in fixture {}",
+				reserves.asset_a,
+				reserves.asset_b,
+				fixture.name
+			);
+		}
+	});
+}
+
+#[test]
+fn same_direction_sells() {
+	run_fixture(include_str!("../fixtures/matching/v1/same_direction_sells.json"));
+}
+
+#[test]
+fn sell_matched_by_buy() {
+	run_fixture(include_str!("../fixtures/matching/v1/sell_matched_by_buy.json"));
+}
+
+#[test]
+fn sell_matched_by_larger_sell() {
+	run_fixture(include_str!("../fixtures/matching/v1/sell_matched_by_larger_sell.json"));
+}
+
+#[test]
+fn exact_match() {
+	run_fixture(include_str!("../fixtures/matching/v1/exact_match.json"));
+}
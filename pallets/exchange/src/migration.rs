@@ -0,0 +1,132 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::traits::{GetPalletVersion, PalletVersion};
+
+/// Intention as stored before priority fees were introduced.
+#[derive(Encode, Decode)]
+pub struct OldIntention<AccountId, IntentionID, BlockNumber> {
+	pub who: AccountId,
+	pub assets: AssetPair,
+	pub amount_in: Balance,
+	pub amount_out: Balance,
+	pub trade_limit: Balance,
+	pub discount: bool,
+	pub sell_or_buy: IntentionType,
+	pub intention_id: IntentionID,
+	pub valid_until: Option<BlockNumber>,
+}
+
+type OldIntentionOf<T> =
+	OldIntention<<T as system::Config>::AccountId, IntentionId<T>, <T as system::Config>::BlockNumber>;
+
+/// Store intentions registered before priority fees were introduced with zero tip.
+pub fn add_intention_tips<T: Config>() -> Weight {
+	let version = <Pallet<T> as GetPalletVersion>::storage_version();
+	if version.map_or(true, |v| v < PalletVersion::new(3, 4, 0)) {
+		let mut count: Weight = 0;
+		ExchangeAssetsIntentions::<T>::translate::<Vec<OldIntentionOf<T>>, _>(|_, intentions| {
+			count += 1;
+			Some(
+				intentions
+					.into_iter()
+					.map(|old| Intention::<T> {
+						who: old.who,
+						assets: old.assets,
+						amount_in: old.amount_in,
+						amount_out: old.amount_out,
+						trade_limit: old.trade_limit,
+						discount: old.discount,
+						sell_or_buy: old.sell_or_buy,
+						intention_id: old.intention_id,
+						valid_until: old.valid_until,
+						tip: 0,
+					})
+					.collect(),
+			)
+		});
+		T::DbWeight::get().reads_writes(count.saturating_add(1), count)
+	} else {
+		0
+	}
+}
+
+/// Check that number of intentions registered for each asset pair matches the intentions stored
+/// for both directions of the pair.
+#[cfg(feature = "try-runtime")]
+pub fn check_intention_counts<T: Config>() -> Result<(), &'static str> {
+	for ((asset_a, asset_b), count) in ExchangeAssetsIntentionCount::<T>::iter() {
+		let stored = ExchangeAssetsIntentions::<T>::decode_len((asset_a, asset_b))
+			.unwrap_or_default()
+			.saturating_add(ExchangeAssetsIntentions::<T>::decode_len((asset_b, asset_a)).unwrap_or_default());
+
+		ensure!(
+			count as usize == stored,
+			"Intention count does not match stored intentions"
+		);
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{ExtBuilder, Test, ALICE, DOT, HDX};
+	use frame_support::storage::unhashed;
+
+	#[test]
+	fn add_intention_tips_migration_should_not_be_applied_to_current_version() {
+		ExtBuilder::default().build().execute_with(|| {
+			PalletVersion::new(3, 4, 0).put_into_storage::<<Test as system::Config>::PalletInfo, Pallet<Test>>();
+
+			assert_eq!(add_intention_tips::<Test>(), 0);
+		})
+	}
+
+	#[test]
+	fn add_intention_tips_migration_should_work() {
+		ExtBuilder::default().build().execute_with(|| {
+			let old = OldIntentionOf::<Test> {
+				who: ALICE,
+				assets: AssetPair {
+					asset_in: HDX,
+					asset_out: DOT,
+				},
+				amount_in: 1_000,
+				amount_out: 2_000,
+				trade_limit: 1_900,
+				discount: false,
+				sell_or_buy: IntentionType::SELL,
+				intention_id: Default::default(),
+				valid_until: Some(10),
+			};
+			let key = ExchangeAssetsIntentions::<Test>::hashed_key_for((HDX, DOT));
+			unhashed::put(&key, &vec![old]);
+
+			add_intention_tips::<Test>();
+
+			let intentions = ExchangeAssetsIntentions::<Test>::get((HDX, DOT));
+			assert_eq!(intentions.len(), 1);
+			assert_eq!(intentions[0].who, ALICE);
+			assert_eq!(intentions[0].amount_in, 1_000);
+			assert_eq!(intentions[0].trade_limit, 1_900);
+			assert_eq!(intentions[0].valid_until, Some(10));
+			assert_eq!(intentions[0].tip, 0);
+		})
+	}
+}
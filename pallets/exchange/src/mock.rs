@@ -18,14 +18,16 @@
 use crate as exchange;
 
 use crate::Config;
-use frame_support::parameter_types;
+use frame_support::{parameter_types, traits::Get, weights::Weight};
 use frame_system as system;
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, IdentityLookup, Zero},
+	Permill,
 };
+use std::cell::RefCell;
 
 use pallet_xyk as xyk;
 
@@ -42,6 +44,7 @@ pub const CHARLIE: AccountId = 3;
 pub const DAVE: AccountId = 4;
 pub const FERDIE: AccountId = 5;
 pub const GEORGE: AccountId = 6;
+pub const TIP_RECEIVER: AccountId = 200;
 
 pub const HDX: AssetId = 1000;
 pub const DOT: AssetId = 2000;
@@ -60,7 +63,9 @@ frame_support::construct_runtime!(
 		 Exchange: exchange::{Pallet, Call, Storage, Event<T>},
 		 XYK: pallet_xyk::{Pallet, Call, Storage, Event<T>},
 		 Currency: orml_tokens::{Pallet, Event<T>},
-		 AssetRegistry: pallet_asset_registry::{Pallet, Storage},
+		 AssetRegistry: pallet_asset_registry::{Pallet, Storage, Event<T>},
+		 Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
+		 OTC: pallet_otc::{Pallet, Call, Storage, Event<T>},
 	 }
 
 );
@@ -70,8 +75,16 @@ parameter_types! {
 	pub const SS58Prefix: u8 = 63;
 
 	pub const HDXAssetId: AssetId = HDX;
+	pub const MaxIntentionFee: Balance = 1_000_000_000;
+	pub const IntentionQueueCap: u32 = 10;
+	pub const MaxIntentionsPerPair: u32 = 20;
+	pub const MaxOrdersPerPair: u32 = 5;
+	pub const MaxSignedIntentionsPerBatch: u32 = 5;
 
 	pub ExchangeFeeRate: fee::Fee = fee::Fee::default();
+	pub ProtocolFeeRate: fee::Fee = fee::Fee { numerator: 0, denominator: 1 };
+	pub const TreasuryAccount: AccountId = 100;
+	pub const TipReceiverAccount: AccountId = TIP_RECEIVER;
 }
 impl system::Config for Test {
 	type BaseCallFilter = ();
@@ -116,7 +129,11 @@ impl orml_tokens::Config for Test {
 }
 
 impl pallet_asset_registry::Config for Test {
+	type Event = Event;
 	type AssetId = AssetId;
+	type AssetNativeLocation = primitives::asset::AssetLocation;
+	type RegistryOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = ();
 }
 
 pub struct AssetPairAccountIdTest();
@@ -141,6 +158,79 @@ impl xyk::Config for Test {
 	type NativeAssetId = HDXAssetId;
 	type WeightInfo = ();
 	type GetExchangeFee = ExchangeFeeRate;
+	type DefaultProtocolFee = ProtocolFeeRate;
+	type TreasuryAccount = TreasuryAccount;
+	type ProtocolFeeOrigin = frame_system::EnsureRoot<AccountId>;
+	type PoolOwnerInactivityPeriod = PoolOwnerInactivityPeriod;
+	type MaxPoolDust = MaxPoolDust;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type PoolGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type Scheduler = Scheduler;
+	type AuditLog = ();
+	type AMMHandler = Exchange;
+	type FlashLoanFee = ExchangeFeeRate;
+	type NonDustableWhitelistHandler = ();
+	type FeeDiscount = ();
+}
+
+parameter_types! {
+	pub const PoolOwnerInactivityPeriod: u64 = 100;
+	pub const MaxPoolDust: Balance = 1_000;
+	pub const MinPoolLiquidity: Balance = 2_000;
+	pub const MaximumSchedulerWeight: u64 = 1_000_000_000;
+	pub const MaxScheduledPerBlock: u32 = 50;
+}
+
+impl pallet_scheduler::Config for Test {
+	type Event = Event;
+	type Origin = Origin;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type WeightInfo = ();
+}
+
+impl pallet_otc::Config for Test {
+	type Event = Event;
+	type Currency = Currency;
+	type MaxOrdersPerPair = MaxOrdersPerPair;
+	type WeightInfo = ();
+}
+
+thread_local! {
+	static MAX_PRICE_DEVIATION: RefCell<Permill> = RefCell::new(Permill::from_percent(100));
+}
+
+pub struct MaxPriceDeviation;
+
+impl Get<Permill> for MaxPriceDeviation {
+	fn get() -> Permill {
+		MAX_PRICE_DEVIATION.with(|v| *v.borrow())
+	}
+}
+
+pub fn set_max_price_deviation(deviation: Permill) {
+	MAX_PRICE_DEVIATION.with(|v| *v.borrow_mut() = deviation);
+}
+
+thread_local! {
+	static MAX_RESOLUTION_WEIGHT: RefCell<Weight> = RefCell::new(Weight::MAX);
+}
+
+pub struct MaxResolutionWeight;
+
+impl Get<Weight> for MaxResolutionWeight {
+	fn get() -> Weight {
+		MAX_RESOLUTION_WEIGHT.with(|v| *v.borrow())
+	}
+}
+
+pub fn set_max_resolution_weight(weight: Weight) {
+	MAX_RESOLUTION_WEIGHT.with(|v| *v.borrow_mut() = weight);
 }
 
 impl Config for Test {
@@ -148,7 +238,18 @@ impl Config for Test {
 	type AMMPool = XYK;
 	type Currency = Currency;
 	type Resolver = exchange::Pallet<Test>;
+	type OrderBook = OTC;
 	type WeightInfo = ();
+	type NativeAssetId = HDXAssetId;
+	type MaxIntentionFee = MaxIntentionFee;
+	type IntentionQueueCap = IntentionQueueCap;
+	type MaxIntentionsPerPair = MaxIntentionsPerPair;
+	type MaxPriceDeviation = MaxPriceDeviation;
+	type IntentionSignature = TestSignature;
+	type IntentionSigner = UintAuthorityId;
+	type MaxSignedIntentionsPerBatch = MaxSignedIntentionsPerBatch;
+	type MaxResolutionWeight = MaxResolutionWeight;
+	type TipReceiver = TipReceiverAccount;
 }
 
 pub struct ExtBuilder {
@@ -17,15 +17,18 @@
 
 use super::*;
 pub use crate::mock::{
-	Currency, Event as TestEvent, Exchange, ExtBuilder, Origin, System, Test, ALICE, BOB, CHARLIE, DAVE, DOT, ETH,
-	FERDIE, GEORGE, HDX, XYK as XYKPallet,
+	set_max_price_deviation, set_max_resolution_weight, AccountId, Currency, Event as TestEvent, Exchange, ExtBuilder,
+	MaxIntentionsPerPair, Origin, System, Test, ALICE, BOB, CHARLIE, DAVE, DOT, ETH, FERDIE, GEORGE, HDX, OTC,
+	TIP_RECEIVER, XYK as XYKPallet,
 };
 use frame_support::sp_runtime::traits::Hash;
 use frame_support::sp_runtime::FixedPointNumber;
 use frame_support::traits::OnFinalize;
 use frame_support::{assert_noop, assert_ok};
 use frame_system::InitKind;
+use primitives::traits::{TradeFees, TradeSource};
 use primitives::Price;
+use sp_runtime::testing::TestSignature;
 use sp_runtime::DispatchError;
 
 use pallet_xyk as xyk;
@@ -38,26 +41,20 @@ fn new_test_ext() -> sp_io::TestExternalities {
 	ext
 }
 
-fn last_event() -> TestEvent {
-	system::Pallet::<Test>::events().pop().expect("Event expected").event
-}
-
 fn expect_event<E: Into<TestEvent>>(e: E) {
-	assert_eq!(last_event(), e.into());
+	assert_eq!(test_utils::last_event::<Test>(), e.into());
 }
 
-fn last_events(n: usize) -> Vec<TestEvent> {
-	system::Pallet::<Test>::events()
-		.into_iter()
-		.rev()
-		.take(n)
-		.rev()
-		.map(|e| e.event)
-		.collect()
+fn expect_events(e: Vec<TestEvent>) {
+	test_utils::expect_events::<Test>(e);
 }
 
-fn expect_events(e: Vec<TestEvent>) {
-	assert_eq!(last_events(e.len()), e);
+fn trade_fees(lp_fee: Balance, discount: Balance) -> TradeFees<Balance> {
+	TradeFees {
+		lp_fee,
+		protocol_fee: 0,
+		discount,
+	}
 }
 
 fn generate_intention_id(account: &<Test as system::Config>::AccountId, c: u32) -> crate::IntentionId<Test> {
@@ -99,8 +96,11 @@ fn initialize_pool(asset_a: u32, asset_b: u32, user: u64, amount: u128, price: P
 	assert_eq!(Currency::free_balance(asset_a, &pair_account), amount);
 	assert_eq!(Currency::free_balance(asset_b, &pair_account), amount_b);
 
-	// Check pool shares
-	assert_eq!(Currency::free_balance(share_token, &user), shares);
+	// Check pool shares, minimum liquidity is locked on pool creation
+	assert_eq!(
+		Currency::free_balance(share_token, &user),
+		shares - xyk::MINIMUM_LIQUIDITY
+	);
 
 	// Advance blockchain so that we kill old events
 	System::initialize(&1, &[0u8; 32].into(), &Default::default(), InitKind::Full);
@@ -131,6 +131,8 @@ fn sell_test_pool_finalization_states() {
 			2_000_000_000_000,
 			20000000000,
 			false,
+			None,
+			0,
 		));
 
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
@@ -142,6 +144,8 @@ fn sell_test_pool_finalization_states() {
 			1_000_000_000_000,
 			4_000_000_000_000,
 			false,
+			None,
+			0,
 		));
 
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
@@ -204,7 +208,16 @@ fn sell_test_pool_finalization_states() {
 				4000000000,
 			)
 			.into(),
-			xyk::Event::SellExecuted(user_2, 3000, 2000, 1000000000000, 1976336046259).into(),
+			xyk::Event::SellExecuted(
+				user_2,
+				3000,
+				2000,
+				1000000000000,
+				1976336046259,
+				TradeSource::Exchange,
+				trade_fees(2_000_000_000, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_2,
 				IntentionType::SELL,
@@ -256,6 +269,8 @@ fn sell_test_standard() {
 			2_000_000_000_000,
 			300_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
 
@@ -266,6 +281,8 @@ fn sell_test_standard() {
 			1_000_000_000_000,
 			4_000_000_000_000,
 			false,
+			None,
+			0,
 		));
 
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
@@ -334,7 +351,16 @@ fn sell_test_standard() {
 				4000000000,
 			)
 			.into(),
-			xyk::Event::SellExecuted(user_2, 3000, 2000, 1000000000000, 1976336046259).into(),
+			xyk::Event::SellExecuted(
+				user_2,
+				3000,
+				2000,
+				1000000000000,
+				1976336046259,
+				TradeSource::Exchange,
+				trade_fees(2_000_000_000, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_2,
 				IntentionType::SELL,
@@ -372,6 +398,8 @@ fn sell_test_inverse_standard() {
 			1_000_000_000_000,
 			100_000_000_000,
 			false,
+			None,
+			0,
 		));
 
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
@@ -383,6 +411,8 @@ fn sell_test_inverse_standard() {
 			4_000_000_000_000,
 			1_000_000_000_000,
 			false,
+			None,
+			0,
 		));
 
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
@@ -427,7 +457,16 @@ fn sell_test_inverse_standard() {
 				user_3_sell_intention_id,
 			)
 			.into(),
-			xyk::Event::SellExecuted(3, 2000, 3000, 2000000000000, 988138378978).into(),
+			xyk::Event::SellExecuted(
+				3,
+				2000,
+				3000,
+				2000000000000,
+				988138378978,
+				TradeSource::Exchange,
+				trade_fees(4_000_000_000, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_3,
 				IntentionType::SELL,
@@ -490,6 +529,8 @@ fn sell_test_exact_match() {
 			1_000_000_000_000,
 			1_500_000_000_000,
 			false,
+			None,
+			0,
 		));
 
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
@@ -501,6 +542,8 @@ fn sell_test_exact_match() {
 			2_000_000_000_000,
 			200_000_000_000,
 			false,
+			None,
+			0,
 		));
 
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
@@ -596,6 +639,8 @@ fn sell_test_single_eth_sells() {
 			1_000_000_000_000,
 			100_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
 		assert_ok!(Exchange::sell(
@@ -605,6 +650,8 @@ fn sell_test_single_eth_sells() {
 			2_000_000_000_000,
 			200_000_000_000,
 			false,
+			None,
+			0,
 		));
 
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
@@ -646,7 +693,16 @@ fn sell_test_single_eth_sells() {
 				user_3_sell_intention_id,
 			)
 			.into(),
-			xyk::Event::SellExecuted(user_3, asset_a, asset_b, 2000000000000, 3913878975647).into(),
+			xyk::Event::SellExecuted(
+				user_3,
+				asset_a,
+				asset_b,
+				2000000000000,
+				3913878975647,
+				TradeSource::Exchange,
+				trade_fees(4_000_000_000, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_3,
 				IntentionType::SELL,
@@ -655,7 +711,16 @@ fn sell_test_single_eth_sells() {
 				3913878975647,
 			)
 			.into(),
-			xyk::Event::SellExecuted(user_2, asset_a, asset_b, 1000000000000, 1899978143094).into(),
+			xyk::Event::SellExecuted(
+				user_2,
+				asset_a,
+				asset_b,
+				1000000000000,
+				1899978143094,
+				TradeSource::Exchange,
+				trade_fees(2_000_000_000, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_2,
 				IntentionType::SELL,
@@ -693,6 +758,8 @@ fn sell_test_single_dot_sells() {
 			1_000_000_000_000,
 			100_000_000_000,
 			false,
+			None,
+			0,
 		));
 
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
@@ -703,6 +770,8 @@ fn sell_test_single_dot_sells() {
 			2_000_000_000_000,
 			200_000_000_000,
 			false,
+			None,
+			0,
 		));
 
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
@@ -743,7 +812,16 @@ fn sell_test_single_dot_sells() {
 				user_3_sell_intention_id,
 			)
 			.into(),
-			xyk::Event::SellExecuted(user_3, asset_b, asset_a, 2000000000000, 988138378978).into(),
+			xyk::Event::SellExecuted(
+				user_3,
+				asset_b,
+				asset_a,
+				2000000000000,
+				988138378978,
+				TradeSource::Exchange,
+				trade_fees(4_000_000_000, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_3,
 				IntentionType::SELL,
@@ -752,7 +830,16 @@ fn sell_test_single_dot_sells() {
 				988138378978,
 			)
 			.into(),
-			xyk::Event::SellExecuted(user_2, asset_b, asset_a, 1000000000000, 486772470162).into(),
+			xyk::Event::SellExecuted(
+				user_2,
+				asset_b,
+				asset_a,
+				1000000000000,
+				486772470162,
+				TradeSource::Exchange,
+				trade_fees(2_000_000_000, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_2,
 				IntentionType::SELL,
@@ -786,6 +873,8 @@ fn sell_trade_limits_respected_for_matched_intention() {
 			1_000_000_000_000,
 			100_000_000_000,
 			false,
+			None,
+			0,
 		));
 
 		assert_ok!(Exchange::sell(
@@ -795,6 +884,8 @@ fn sell_trade_limits_respected_for_matched_intention() {
 			1_000_000_000_000,
 			100_000_000_000_000_000, // Limit set to absurd amount which can't go through
 			false,
+			None,
+			0,
 		));
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
 
@@ -837,7 +928,16 @@ fn sell_trade_limits_respected_for_matched_intention() {
 				},
 			)
 			.into(),
-			xyk::Event::SellExecuted(user_2, asset_a, asset_b, 1000000000000, 1976276757956).into(),
+			xyk::Event::SellExecuted(
+				user_2,
+				asset_a,
+				asset_b,
+				1000000000000,
+				1976276757956,
+				TradeSource::Exchange,
+				trade_fees(2_000_000_000, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_2,
 				IntentionType::SELL,
@@ -879,6 +979,8 @@ fn sell_test_single_multiple_sells() {
 			1_000_000_000_000,
 			100_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
 		assert_ok!(Exchange::sell(
@@ -888,6 +990,8 @@ fn sell_test_single_multiple_sells() {
 			1_000_000_000_000,
 			100_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
 		assert_ok!(Exchange::sell(
@@ -897,6 +1001,8 @@ fn sell_test_single_multiple_sells() {
 			1_000_000_000_000,
 			100_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_4_sell_intention_id = generate_intention_id(&user_4, 2);
 		assert_ok!(Exchange::sell(
@@ -906,6 +1012,8 @@ fn sell_test_single_multiple_sells() {
 			1_000_000_000_000,
 			100_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_5_sell_intention_id = generate_intention_id(&user_5, 3);
 		assert_ok!(Exchange::sell(
@@ -915,6 +1023,8 @@ fn sell_test_single_multiple_sells() {
 			2_000_000_000_000,
 			200_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_6_sell_intention_id = generate_intention_id(&user_6, 4);
 
@@ -923,15 +1033,18 @@ fn sell_test_single_multiple_sells() {
 		// Finalize block
 		<Exchange as OnFinalize<u64>>::on_finalize(9);
 
+		// Intentions of user_2 and user_4 (and user_3 and user_5) sell the same amount.
+		// Their matching order is given by the matching key, not by the order of submission.
+
 		// Check final account balances
-		assert_eq!(Currency::free_balance(asset_a, &user_2), 999000000000000);
-		assert_eq!(Currency::free_balance(asset_b, &user_2), 1001996000000000);
+		assert_eq!(Currency::free_balance(asset_a, &user_4), 999000000000000);
+		assert_eq!(Currency::free_balance(asset_b, &user_4), 1001996000000000);
 
 		assert_eq!(Currency::free_balance(asset_a, &user_3), 1000499000000000);
 		assert_eq!(Currency::free_balance(asset_b, &user_3), 999000000000000);
 
-		assert_eq!(Currency::free_balance(asset_a, &user_4), 999000000000000);
-		assert_eq!(Currency::free_balance(asset_b, &user_4), 1001991044854829);
+		assert_eq!(Currency::free_balance(asset_a, &user_2), 999000000000000);
+		assert_eq!(Currency::free_balance(asset_b, &user_2), 1001991044854829);
 
 		// Check final pool balances
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 100001517499067);
@@ -986,17 +1099,17 @@ fn sell_test_single_multiple_sells() {
 			)
 			.into(),
 			Event::IntentionResolvedDirectTrade(
-				user_2,
+				user_4,
 				user_6,
-				user_2_sell_intention_id,
+				user_4_sell_intention_id,
 				user_6_sell_intention_id,
 				1000000000000,
 				2000000000000,
 			)
 			.into(),
 			Event::IntentionResolvedDirectTradeFees(
-				user_2,
-				user_2_sell_intention_id,
+				user_4,
+				user_4_sell_intention_id,
 				pair_account,
 				asset_b,
 				4000000000,
@@ -1011,17 +1124,17 @@ fn sell_test_single_multiple_sells() {
 			)
 			.into(),
 			Event::IntentionResolvedDirectTrade(
-				user_4,
+				user_2,
 				user_3,
-				user_4_sell_intention_id,
+				user_2_sell_intention_id,
 				user_3_sell_intention_id,
 				500000000000,
 				1000000000000,
 			)
 			.into(),
 			Event::IntentionResolvedDirectTradeFees(
-				user_4,
-				user_4_sell_intention_id,
+				user_2,
+				user_2_sell_intention_id,
 				pair_account,
 				asset_b,
 				2000000000,
@@ -1035,16 +1148,34 @@ fn sell_test_single_multiple_sells() {
 				1000000000,
 			)
 			.into(),
-			xyk::Event::SellExecuted(user_4, asset_a, asset_b, 500000000000, 993044854829).into(),
+			xyk::Event::SellExecuted(
+				user_2,
+				asset_a,
+				asset_b,
+				500000000000,
+				993044854829,
+				TradeSource::Exchange,
+				trade_fees(1_000_000_000, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
-				user_4,
+				user_2,
 				IntentionType::SELL,
-				user_4_sell_intention_id,
+				user_2_sell_intention_id,
 				5_000_000_000_00,
 				993044854829,
 			)
 			.into(),
-			xyk::Event::SellExecuted(user_5, asset_b, asset_a, 1000000000000, 501482500933).into(),
+			xyk::Event::SellExecuted(
+				user_5,
+				asset_b,
+				asset_a,
+				1000000000000,
+				501482500933,
+				TradeSource::Exchange,
+				trade_fees(2_000_000_000, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_5,
 				IntentionType::SELL,
@@ -1057,6 +1188,62 @@ fn sell_test_single_multiple_sells() {
 	});
 }
 
+#[test]
+fn matching_should_not_depend_on_submission_order() {
+	let asset_a = ETH;
+	let asset_b = DOT;
+
+	let sells = vec![
+		(BOB, asset_a, asset_b, 1_000_000_000_000),
+		(CHARLIE, asset_b, asset_a, 1_000_000_000_000),
+		(DAVE, asset_a, asset_b, 1_000_000_000_000),
+		(FERDIE, asset_b, asset_a, 1_000_000_000_000),
+		(GEORGE, asset_b, asset_a, 2_000_000_000_000),
+	];
+
+	let resolve = |sells: Vec<(u64, AssetId, AssetId, Balance)>| {
+		new_test_ext().execute_with(|| {
+			initialize_pool(asset_a, asset_b, ALICE, 100_000_000_000_000, Price::from(2));
+
+			for (who, asset_sell, asset_buy, amount) in sells {
+				assert_ok!(Exchange::sell(
+					Origin::signed(who),
+					asset_sell,
+					asset_buy,
+					amount,
+					100_000_000_000,
+					false,
+					None,
+					0,
+				));
+			}
+
+			<Exchange as OnFinalize<u64>>::on_finalize(9);
+
+			assert_eq!(Exchange::get_intentions_count((asset_b, asset_a)), 0);
+
+			let pair_account = XYKPallet::get_pair_id(AssetPair {
+				asset_in: asset_a,
+				asset_out: asset_b,
+			});
+
+			vec![BOB, CHARLIE, DAVE, FERDIE, GEORGE, pair_account]
+				.into_iter()
+				.map(|who| {
+					(
+						Currency::free_balance(asset_a, &who),
+						Currency::free_balance(asset_b, &who),
+					)
+				})
+				.collect::<Vec<_>>()
+		})
+	};
+
+	let balances = resolve(sells.clone());
+
+	assert_eq!(resolve(sells.into_iter().rev().collect()), balances);
+}
+
 #[test]
 fn sell_test_group_sells() {
 	new_test_ext().execute_with(|| {
@@ -1084,6 +1271,8 @@ fn sell_test_group_sells() {
 			5_000_000_000_000,
 			200_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
 		assert_ok!(Exchange::sell(
@@ -1093,6 +1282,8 @@ fn sell_test_group_sells() {
 			3_000_000_000_000,
 			200_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
 		assert_ok!(Exchange::sell(
@@ -1102,6 +1293,8 @@ fn sell_test_group_sells() {
 			10_000_000_000_000,
 			200_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_4_sell_intention_id = generate_intention_id(&user_4, 2);
 
@@ -1204,7 +1397,16 @@ fn sell_test_group_sells() {
 				3000000000,
 			)
 			.into(),
-			xyk::Event::SellExecuted(user_4, asset_a, asset_b, 6000000000000, 11299443450697).into(),
+			xyk::Event::SellExecuted(
+				user_4,
+				asset_a,
+				asset_b,
+				6000000000000,
+				11299443450697,
+				TradeSource::Exchange,
+				trade_fees(12_000_000_000, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_4,
 				IntentionType::SELL,
@@ -1221,12 +1423,12 @@ fn sell_test_group_sells() {
 fn trades_without_pool_should_not_work() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			Exchange::sell(Origin::signed(ALICE), HDX, ETH, 1000, 200, false),
+			Exchange::sell(Origin::signed(ALICE), HDX, ETH, 1000, 200, false, None, 0),
 			Error::<Test>::TokenPoolNotFound
 		);
 
 		assert_noop!(
-			Exchange::buy(Origin::signed(ALICE), HDX, ETH, 1000, 200, false),
+			Exchange::buy(Origin::signed(ALICE), HDX, ETH, 1000, 200, false, None, 0),
 			Error::<Test>::TokenPoolNotFound
 		);
 	});
@@ -1236,12 +1438,12 @@ fn trades_without_pool_should_not_work() {
 fn trade_min_limit() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			Exchange::sell(Origin::signed(ALICE), HDX, ETH, 10, 200, false),
+			Exchange::sell(Origin::signed(ALICE), HDX, ETH, 10, 200, false, None, 0),
 			Error::<Test>::MinimumTradeLimitNotReached
 		);
 
 		assert_noop!(
-			Exchange::buy(Origin::signed(ALICE), HDX, ETH, 10, 200, false),
+			Exchange::buy(Origin::signed(ALICE), HDX, ETH, 10, 200, false, None, 0),
 			Error::<Test>::MinimumTradeLimitNotReached
 		);
 	});
@@ -1260,13 +1462,31 @@ fn sell_more_than_owner_should_not_work() {
 
 		// With SELL
 		assert_noop!(
-			Exchange::sell(Origin::signed(ALICE), HDX, ETH, 1000_000_000_000_000u128, 1, false),
+			Exchange::sell(
+				Origin::signed(ALICE),
+				HDX,
+				ETH,
+				1000_000_000_000_000u128,
+				1,
+				false,
+				None,
+				0
+			),
 			Error::<Test>::InsufficientAssetBalance
 		);
 
 		// With BUY
 		assert_noop!(
-			Exchange::buy(Origin::signed(ALICE), ETH, HDX, 3000_000_000_000_000u128, 1, false),
+			Exchange::buy(
+				Origin::signed(ALICE),
+				ETH,
+				HDX,
+				3000_000_000_000_000u128,
+				1,
+				false,
+				None,
+				0
+			),
 			Error::<Test>::InsufficientAssetBalance
 		);
 	});
@@ -1299,6 +1519,8 @@ fn sell_test_mixed_buy_sells() {
 			5_000_000_000_000,
 			20_000_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
 		assert_ok!(Exchange::sell(
@@ -1308,6 +1530,8 @@ fn sell_test_mixed_buy_sells() {
 			3_000_000_000_000,
 			1400_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
 		assert_ok!(Exchange::sell(
@@ -1317,6 +1541,8 @@ fn sell_test_mixed_buy_sells() {
 			10_000_000_000_000,
 			2000_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_4_sell_intention_id = generate_intention_id(&user_4, 2);
 
@@ -1394,7 +1620,16 @@ fn sell_test_mixed_buy_sells() {
 				3000000000,
 			)
 			.into(),
-			xyk::Event::SellExecuted(user_4, asset_a, asset_b, 8500000000000, 15639353446528).into(),
+			xyk::Event::SellExecuted(
+				user_4,
+				asset_a,
+				asset_b,
+				8500000000000,
+				15639353446528,
+				TradeSource::Exchange,
+				trade_fees(17_000_000_000, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_4,
 				IntentionType::SELL,
@@ -1403,7 +1638,16 @@ fn sell_test_mixed_buy_sells() {
 				15639353446528,
 			)
 			.into(),
-			xyk::Event::BuyExecuted(user_2, asset_b, asset_a, 5000000000000, 3030663952554).into(),
+			xyk::Event::BuyExecuted(
+				user_2,
+				asset_b,
+				asset_a,
+				5000000000000,
+				3030663952554,
+				TradeSource::Exchange,
+				trade_fees(6_049_229_446, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_2,
 				IntentionType::BUY,
@@ -1443,6 +1687,8 @@ fn discount_tests_no_discount() {
 			5_000_000_000_000,
 			20_000_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
 		assert_ok!(Exchange::sell(
@@ -1452,6 +1698,8 @@ fn discount_tests_no_discount() {
 			3_000_000_000_000,
 			1400_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
 		assert_ok!(Exchange::sell(
@@ -1461,6 +1709,8 @@ fn discount_tests_no_discount() {
 			10_000_000_000_000,
 			2000_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_4_sell_intention_id = generate_intention_id(&user_4, 2);
 
@@ -1538,7 +1788,16 @@ fn discount_tests_no_discount() {
 				3000000000,
 			)
 			.into(),
-			xyk::Event::SellExecuted(user_4, asset_a, asset_b, 8500000000000, 15639353446528).into(),
+			xyk::Event::SellExecuted(
+				user_4,
+				asset_a,
+				asset_b,
+				8500000000000,
+				15639353446528,
+				TradeSource::Exchange,
+				trade_fees(17_000_000_000, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_4,
 				IntentionType::SELL,
@@ -1547,7 +1806,16 @@ fn discount_tests_no_discount() {
 				15639353446528,
 			)
 			.into(),
-			xyk::Event::BuyExecuted(user_2, asset_b, asset_a, 5000000000000, 3030663952554).into(),
+			xyk::Event::BuyExecuted(
+				user_2,
+				asset_b,
+				asset_a,
+				5000000000000,
+				3030663952554,
+				TradeSource::Exchange,
+				trade_fees(6_049_229_446, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_2,
 				IntentionType::BUY,
@@ -1589,6 +1857,8 @@ fn discount_tests_with_discount() {
 			5_000_000_000_000,
 			20_000_000_000_000,
 			true,
+			None,
+			0,
 		));
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
 		assert_ok!(Exchange::sell(
@@ -1598,6 +1868,8 @@ fn discount_tests_with_discount() {
 			3_000_000_000_000,
 			1400_000_000_000,
 			true,
+			None,
+			0,
 		));
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
 		assert_ok!(Exchange::sell(
@@ -1607,6 +1879,8 @@ fn discount_tests_with_discount() {
 			10_000_000_000_000,
 			2000_000_000_000,
 			true,
+			None,
+			0,
 		));
 		let user_4_sell_intention_id = generate_intention_id(&user_4, 2);
 
@@ -1688,7 +1962,16 @@ fn discount_tests_with_discount() {
 				3000000000,
 			)
 			.into(),
-			xyk::Event::SellExecuted(user_4, asset_a, asset_b, 8500000000000, 15658130468064).into(),
+			xyk::Event::SellExecuted(
+				user_4,
+				asset_a,
+				asset_b,
+				8500000000000,
+				15658130468064,
+				TradeSource::Exchange,
+				trade_fees(5_950_000_000, 11_900_000_000),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_4,
 				IntentionType::SELL,
@@ -1697,7 +1980,16 @@ fn discount_tests_with_discount() {
 				15658130468064,
 			)
 			.into(),
-			xyk::Event::BuyExecuted(user_2, asset_b, asset_a, 5000000000000, 3027048840428).into(),
+			xyk::Event::BuyExecuted(
+				user_2,
+				asset_b,
+				asset_a,
+				5000000000000,
+				3027048840428,
+				TradeSource::Exchange,
+				trade_fees(2_117_451_971, 4_234_903_942),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_2,
 				IntentionType::BUY,
@@ -1735,6 +2027,8 @@ fn buy_test_exact_match() {
 			1_000_000_000_000,
 			4_000_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
 		assert_ok!(Exchange::buy(
@@ -1744,6 +2038,8 @@ fn buy_test_exact_match() {
 			2_000_000_000_000,
 			4_000_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
 
@@ -1840,6 +2136,8 @@ fn buy_test_group_buys() {
 			5_000_000_000_000,
 			20_000_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
 		assert_ok!(Exchange::buy(
@@ -1849,6 +2147,8 @@ fn buy_test_group_buys() {
 			3_000_000_000_000,
 			20_000_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
 		assert_ok!(Exchange::buy(
@@ -1858,6 +2158,8 @@ fn buy_test_group_buys() {
 			10_000_000_000_000,
 			22_000_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_4_sell_intention_id = generate_intention_id(&user_4, 2);
 
@@ -1910,7 +2212,16 @@ fn buy_test_group_buys() {
 				user_4_sell_intention_id,
 			)
 			.into(),
-			xyk::Event::BuyExecuted(user_4, asset_a, asset_b, 7500000000000, 16248648648649).into(),
+			xyk::Event::BuyExecuted(
+				user_4,
+				asset_a,
+				asset_b,
+				7500000000000,
+				16248648648649,
+				TradeSource::Exchange,
+				trade_fees(32_432_432_432, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_4,
 				IntentionType::BUY,
@@ -1944,7 +2255,16 @@ fn buy_test_group_buys() {
 				10000000000,
 			)
 			.into(),
-			xyk::Event::BuyExecuted(user_3, asset_b, asset_a, 3000000000000, 1303909744163).into(),
+			xyk::Event::BuyExecuted(
+				user_3,
+				asset_b,
+				asset_a,
+				3000000000000,
+				1303909744163,
+				TradeSource::Exchange,
+				trade_fees(2_602_614_259, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_3,
 				IntentionType::BUY,
@@ -1984,6 +2304,8 @@ fn discount_tests_with_error() {
 			5_000_000_000_000,
 			20_000_000_000_000,
 			true,
+			None,
+			0,
 		));
 		assert_ok!(Exchange::sell(
 			Origin::signed(user_3),
@@ -1992,6 +2314,8 @@ fn discount_tests_with_error() {
 			3_000_000_000_000,
 			20_000_000_000_000,
 			true,
+			None,
+			0,
 		));
 		assert_ok!(Exchange::sell(
 			Origin::signed(user_4),
@@ -2000,6 +2324,8 @@ fn discount_tests_with_error() {
 			10_000_000_000_000,
 			20_000_000_000_000,
 			true,
+			None,
+			0,
 		));
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
@@ -2132,6 +2458,8 @@ fn simple_sell_sell() {
 			2_000,
 			400,
 			false,
+			None,
+			0,
 		));
 		assert_ok!(Exchange::sell(
 			Origin::signed(user_3),
@@ -2140,6 +2468,8 @@ fn simple_sell_sell() {
 			1_000,
 			400,
 			false,
+			None,
+			0,
 		));
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
@@ -2188,7 +2518,7 @@ fn simple_sell_sell() {
 			.into(),
 			Event::IntentionResolvedDirectTradeFees(user_2, user_2_sell_intention_id, pair_account, asset_b, 2).into(),
 			Event::IntentionResolvedDirectTradeFees(user_3, user_3_sell_intention_id, pair_account, asset_a, 1).into(),
-			xyk::Event::SellExecuted(2, 3000, 2000, 1500, 2994).into(),
+			xyk::Event::SellExecuted(2, 3000, 2000, 1500, 2994, TradeSource::Exchange, trade_fees(3, 0)).into(),
 			Event::IntentionResolvedAMMTrade(user_2, IntentionType::SELL, user_2_sell_intention_id, 1500, 2994).into(),
 		]);
 	});
@@ -2219,6 +2549,8 @@ fn simple_buy_buy() {
 			2_000,
 			5000,
 			false,
+			None,
+			0,
 		));
 		assert_ok!(Exchange::buy(
 			Origin::signed(user_3),
@@ -2227,6 +2559,8 @@ fn simple_buy_buy() {
 			1_000,
 			5000,
 			false,
+			None,
+			0,
 		));
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
@@ -2264,7 +2598,7 @@ fn simple_buy_buy() {
 				user_3_sell_intention_id,
 			)
 			.into(),
-			xyk::Event::BuyExecuted(2, 3000, 2000, 1500, 3007).into(),
+			xyk::Event::BuyExecuted(2, 3000, 2000, 1500, 3007, TradeSource::Exchange, trade_fees(6, 0)).into(),
 			Event::IntentionResolvedAMMTrade(user_2, IntentionType::BUY, user_2_sell_intention_id, 1500, 3007).into(),
 			Event::IntentionResolvedDirectTrade(
 				user_3,
@@ -2306,6 +2640,8 @@ fn simple_sell_buy() {
 			2_000,
 			400,
 			false,
+			None,
+			0,
 		));
 		assert_ok!(Exchange::buy(
 			Origin::signed(user_3),
@@ -2314,6 +2650,8 @@ fn simple_sell_buy() {
 			1_000,
 			2_000,
 			false,
+			None,
+			0,
 		));
 
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
@@ -2363,7 +2701,7 @@ fn simple_sell_buy() {
 			.into(),
 			Event::IntentionResolvedDirectTradeFees(user_2, user_2_sell_intention_id, pair_account, asset_b, 2).into(),
 			Event::IntentionResolvedDirectTradeFees(user_3, user_3_sell_intention_id, pair_account, asset_b, 4).into(),
-			xyk::Event::SellExecuted(2, 3000, 2000, 1000, 1996).into(),
+			xyk::Event::SellExecuted(2, 3000, 2000, 1000, 1996, TradeSource::Exchange, trade_fees(2, 0)).into(),
 			Event::IntentionResolvedAMMTrade(user_2, IntentionType::SELL, user_2_sell_intention_id, 1000, 1996).into(),
 		]);
 	});
@@ -2394,6 +2732,8 @@ fn simple_buy_sell() {
 			2_000,
 			5000,
 			false,
+			None,
+			0,
 		));
 		assert_ok!(Exchange::sell(
 			Origin::signed(user_3),
@@ -2402,6 +2742,8 @@ fn simple_buy_sell() {
 			1_000,
 			1500,
 			false,
+			None,
+			0,
 		));
 
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
@@ -2440,7 +2782,7 @@ fn simple_buy_sell() {
 				user_3_sell_intention_id,
 			)
 			.into(),
-			xyk::Event::BuyExecuted(user_2, 3000, 2000, 1000, 2005).into(),
+			xyk::Event::BuyExecuted(user_2, 3000, 2000, 1000, 2005, TradeSource::Exchange, trade_fees(4, 0)).into(),
 			Event::IntentionResolvedAMMTrade(user_2, IntentionType::BUY, user_2_sell_intention_id, 1000, 2005).into(),
 			Event::IntentionResolvedDirectTrade(
 				user_3,
@@ -2481,6 +2823,8 @@ fn single_sell_intention_test() {
 			2_000_000_000_000,
 			400_000_000_000,
 			false,
+			None,
+			0,
 		));
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
 
@@ -2509,7 +2853,16 @@ fn single_sell_intention_test() {
 				user_2_sell_intention_id,
 			)
 			.into(),
-			xyk::Event::SellExecuted(2, 3000, 2000, 2000000000000, 3913878975647).into(),
+			xyk::Event::SellExecuted(
+				2,
+				3000,
+				2000,
+				2000000000000,
+				3913878975647,
+				TradeSource::Exchange,
+				trade_fees(4_000_000_000, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_2,
 				IntentionType::SELL,
@@ -2546,6 +2899,8 @@ fn single_buy_intention_test() {
 			2_000_000_000_000,
 			15000_000_000_000,
 			false,
+			None,
+			0,
 		));
 
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
@@ -2575,7 +2930,16 @@ fn single_buy_intention_test() {
 				user_2_sell_intention_id,
 			)
 			.into(),
-			xyk::Event::BuyExecuted(2, 3000, 2000, 2000000000000, 4089795918368).into(),
+			xyk::Event::BuyExecuted(
+				2,
+				3000,
+				2000,
+				2000000000000,
+				4089795918368,
+				TradeSource::Exchange,
+				trade_fees(8_163_265_306, 0),
+			)
+			.into(),
 			Event::IntentionResolvedAMMTrade(
 				user_2,
 				IntentionType::BUY,
@@ -2613,6 +2977,8 @@ fn simple_sell_sell_with_error_should_not_pass() {
 			2_000,
 			5_000,
 			false,
+			None,
+			0,
 		));
 
 		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
@@ -2624,6 +2990,8 @@ fn simple_sell_sell_with_error_should_not_pass() {
 			1_000,
 			5_000,
 			false,
+			None,
+			0,
 		));
 
 		let user_3_sell_intention_id = generate_intention_id(&user_3, 1);
@@ -2694,3 +3062,1155 @@ fn simple_sell_sell_with_error_should_not_pass() {
 		]);
 	});
 }
+
+#[test]
+fn cancel_intention_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = ALICE;
+		let user_2 = BOB;
+		let asset_a = ETH;
+		let asset_b = DOT;
+		let pool_amount = 100_000_000_000_000;
+		let initial_price = Price::from(2);
+
+		initialize_pool(asset_a, asset_b, user_1, pool_amount, initial_price);
+
+		assert_ok!(Exchange::sell(
+			Origin::signed(user_2),
+			asset_a,
+			asset_b,
+			2_000_000_000_000,
+			20_000_000_000,
+			false,
+			None,
+			0,
+		));
+
+		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
+
+		assert_ok!(Exchange::cancel_intention(
+			Origin::signed(user_2),
+			asset_a,
+			asset_b,
+			user_2_sell_intention_id
+		));
+
+		assert!(Exchange::get_intentions((asset_a, asset_b)).is_empty());
+		assert_eq!(Exchange::get_intentions_count((asset_b, asset_a)), 0);
+
+		expect_event(Event::IntentionCancelled(user_2, user_2_sell_intention_id));
+
+		// Intention registered after the cancellation gets a new id
+		assert_ok!(Exchange::sell(
+			Origin::signed(user_2),
+			asset_a,
+			asset_b,
+			2_000_000_000_000,
+			20_000_000_000,
+			false,
+			None,
+			0,
+		));
+
+		let intentions = Exchange::get_intentions((asset_a, asset_b));
+		assert_eq!(intentions.len(), 1);
+		assert_eq!(intentions[0].intention_id, generate_intention_id(&user_2, 1));
+		assert_ne!(intentions[0].intention_id, user_2_sell_intention_id);
+		assert_eq!(Exchange::get_intentions_count((asset_b, asset_a)), 1);
+
+		assert_ok!(Exchange::cancel_intention(
+			Origin::signed(user_2),
+			asset_a,
+			asset_b,
+			intentions[0].intention_id
+		));
+
+		// Finalize block
+		<Exchange as OnFinalize<u64>>::on_finalize(1);
+
+		assert_eq!(Currency::free_balance(asset_a, &user_2), ENDOWED_AMOUNT);
+		assert_eq!(Currency::free_balance(asset_b, &user_2), ENDOWED_AMOUNT);
+	});
+}
+
+#[test]
+fn cancel_intention_should_not_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = ALICE;
+		let user_2 = BOB;
+		let user_3 = CHARLIE;
+		let asset_a = ETH;
+		let asset_b = DOT;
+		let pool_amount = 100_000_000_000_000;
+		let initial_price = Price::from(2);
+
+		initialize_pool(asset_a, asset_b, user_1, pool_amount, initial_price);
+
+		assert_ok!(Exchange::sell(
+			Origin::signed(user_2),
+			asset_a,
+			asset_b,
+			2_000_000_000_000,
+			20_000_000_000,
+			false,
+			None,
+			0,
+		));
+
+		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
+
+		assert_noop!(
+			Exchange::cancel_intention(Origin::signed(user_3), asset_a, asset_b, user_2_sell_intention_id),
+			Error::<Test>::NotIntentionOwner
+		);
+
+		assert_noop!(
+			Exchange::cancel_intention(
+				Origin::signed(user_2),
+				asset_a,
+				asset_b,
+				generate_intention_id(&user_2, 1)
+			),
+			Error::<Test>::IntentionNotFound
+		);
+
+		assert_noop!(
+			Exchange::cancel_intention(Origin::signed(user_2), asset_b, asset_a, user_2_sell_intention_id),
+			Error::<Test>::IntentionNotFound
+		);
+
+		assert_eq!(Exchange::get_intentions((asset_a, asset_b)).len(), 1);
+	});
+}
+
+#[test]
+fn intention_with_validity_period_should_be_retained_until_expired() {
+	new_test_ext().execute_with(|| {
+		let user_1 = ALICE;
+		let user_2 = BOB;
+		let asset_a = ETH;
+		let asset_b = DOT;
+		let pool_amount = 100_000_000_000_000;
+		let initial_price = Price::from(2);
+
+		initialize_pool(asset_a, asset_b, user_1, pool_amount, initial_price);
+
+		// Limit cannot be satisfied by AMM trade
+		assert_ok!(Exchange::sell(
+			Origin::signed(user_2),
+			asset_a,
+			asset_b,
+			2_000_000_000_000,
+			10_000_000_000_000,
+			false,
+			Some(2),
+			0,
+		));
+
+		let user_2_sell_intention_id = generate_intention_id(&user_2, 0);
+
+		// Finalize block
+		<Exchange as OnFinalize<u64>>::on_finalize(1);
+
+		expect_event(Event::IntentionResolveErrorEvent(
+			user_2,
+			AssetPair {
+				asset_in: asset_a,
+				asset_out: asset_b,
+			},
+			IntentionType::SELL,
+			user_2_sell_intention_id,
+			DispatchError::Module {
+				index: 2,
+				error: 8,
+				message: None,
+			},
+		));
+
+		let intentions = Exchange::get_intentions((asset_a, asset_b));
+		assert_eq!(intentions.len(), 1);
+		assert_eq!(intentions[0].intention_id, user_2_sell_intention_id);
+		assert_eq!(intentions[0].valid_until, Some(3));
+		assert_eq!(Exchange::get_intentions_count((asset_b, asset_a)), 1);
+
+		// Intention is still valid in following block
+		<Exchange as OnFinalize<u64>>::on_finalize(2);
+
+		assert_eq!(Exchange::get_intentions((asset_a, asset_b)).len(), 1);
+
+		// Intention is dropped once expired
+		<Exchange as OnFinalize<u64>>::on_finalize(3);
+
+		assert!(Exchange::get_intentions((asset_a, asset_b)).is_empty());
+		assert_eq!(Exchange::get_intentions_count((asset_b, asset_a)), 0);
+
+		assert_eq!(Currency::free_balance(asset_a, &user_2), ENDOWED_AMOUNT);
+		assert_eq!(Currency::free_balance(asset_b, &user_2), ENDOWED_AMOUNT);
+	});
+}
+
+#[test]
+fn intention_fee_should_scale_with_queue_depth() {
+	new_test_ext().execute_with(|| {
+		let asset_a = ETH;
+		let asset_b = DOT;
+		let pair = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		initialize_pool(asset_a, asset_b, ALICE, 100_000_000_000_000, Price::from(2));
+
+		assert_eq!(Exchange::intention_fee(pair), 0);
+
+		for _ in 0..5 {
+			assert_ok!(Exchange::sell(
+				Origin::signed(BOB),
+				asset_a,
+				asset_b,
+				1_000_000_000,
+				1_000,
+				false,
+				None,
+				0,
+			));
+		}
+
+		// 1_000_000_000 * 5^2 / 10^2
+		assert_eq!(Exchange::intention_fee(pair), 250_000_000);
+
+		// Queue depth is shared by both directions of the pair
+		assert_eq!(
+			Exchange::intention_fee(AssetPair {
+				asset_in: asset_b,
+				asset_out: asset_a,
+			}),
+			250_000_000
+		);
+
+		for _ in 0..10 {
+			assert_ok!(Exchange::sell(
+				Origin::signed(BOB),
+				asset_a,
+				asset_b,
+				1_000_000_000,
+				1_000,
+				false,
+				None,
+				0,
+			));
+		}
+
+		// Fee is capped once the queue reaches its cap
+		assert_eq!(Exchange::intention_fee(pair), 1_000_000_000);
+	});
+}
+
+#[test]
+fn charge_intention_fee_should_work() {
+	new_test_ext().execute_with(|| {
+		let asset_a = ETH;
+		let asset_b = DOT;
+
+		initialize_pool(asset_a, asset_b, ALICE, 100_000_000_000_000, Price::from(2));
+
+		for _ in 0..5 {
+			assert_ok!(Exchange::sell(
+				Origin::signed(BOB),
+				asset_a,
+				asset_b,
+				1_000_000_000,
+				1_000,
+				false,
+				None,
+				0,
+			));
+		}
+
+		let call: crate::mock::Call = Call::buy(asset_b, asset_a, 1_000_000_000, 2_000_000_000, false, None, 0).into();
+		let info = Default::default();
+
+		assert_ok!(ChargeIntentionFee::<Test>::new().validate(&CHARLIE, &call, &info, 0));
+		assert_ok!(ChargeIntentionFee::<Test>::new().pre_dispatch(&CHARLIE, &call, &info, 0));
+
+		assert_eq!(Currency::free_balance(HDX, &CHARLIE), ENDOWED_AMOUNT - 250_000_000);
+
+		// Other calls are not charged
+		let call: crate::mock::Call = Call::cancel_intention(asset_a, asset_b, generate_intention_id(&BOB, 0)).into();
+
+		assert_ok!(ChargeIntentionFee::<Test>::new().pre_dispatch(&CHARLIE, &call, &info, 0));
+
+		assert_eq!(Currency::free_balance(HDX, &CHARLIE), ENDOWED_AMOUNT - 250_000_000);
+	});
+}
+
+#[test]
+fn charge_intention_fee_should_fail_when_fee_cannot_be_paid() {
+	new_test_ext().execute_with(|| {
+		let asset_a = ETH;
+		let asset_b = DOT;
+		let user = 1_234;
+
+		initialize_pool(asset_a, asset_b, ALICE, 100_000_000_000_000, Price::from(2));
+
+		assert_ok!(Exchange::sell(
+			Origin::signed(BOB),
+			asset_a,
+			asset_b,
+			1_000_000_000,
+			1_000,
+			false,
+			None,
+			0,
+		));
+
+		let call: crate::mock::Call = Call::sell(asset_a, asset_b, 1_000_000_000, 1_000, false, None, 0).into();
+
+		assert_eq!(
+			ChargeIntentionFee::<Test>::new().validate(&user, &call, &Default::default(), 0),
+			InvalidTransaction::Payment.into()
+		);
+	});
+}
+
+#[test]
+fn register_intention_should_not_work_when_pair_is_full() {
+	new_test_ext().execute_with(|| {
+		let asset_a = ETH;
+		let asset_b = DOT;
+
+		initialize_pool(asset_a, asset_b, ALICE, 100_000_000_000_000, Price::from(2));
+
+		for _ in 0..MaxIntentionsPerPair::get() {
+			assert_ok!(Exchange::sell(
+				Origin::signed(BOB),
+				asset_a,
+				asset_b,
+				1_000_000_000,
+				1_000,
+				false,
+				None,
+				0,
+			));
+		}
+
+		// Limit is shared by both directions of the pair
+		assert_noop!(
+			Exchange::buy(
+				Origin::signed(BOB),
+				asset_a,
+				asset_b,
+				1_000_000_000,
+				4_000_000_000,
+				false,
+				None,
+				0
+			),
+			Error::<Test>::TooManyIntentions
+		);
+		assert_noop!(
+			Exchange::sell(
+				Origin::signed(BOB),
+				asset_b,
+				asset_a,
+				1_000_000_000,
+				1_000,
+				false,
+				None,
+				0
+			),
+			Error::<Test>::TooManyIntentions
+		);
+
+		// Limit applies to one block only
+		<Exchange as OnFinalize<u64>>::on_finalize(1);
+
+		assert_ok!(Exchange::sell(
+			Origin::signed(BOB),
+			asset_a,
+			asset_b,
+			1_000_000_000,
+			1_000,
+			false,
+			None,
+			0,
+		));
+	});
+}
+
+#[test]
+fn sell_should_be_settled_with_order_book_before_amm() {
+	new_test_ext().execute_with(|| {
+		let asset_a = ETH;
+		let asset_b = DOT;
+		let pool_amount = 100_000_000_000_000;
+
+		let pair_account = XYKPallet::get_pair_id(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		});
+
+		initialize_pool(asset_a, asset_b, ALICE, pool_amount, Price::from(2));
+
+		// Pool sells DOT for 0.5 ETH, only the cheaper order is better than the pool
+		assert_ok!(OTC::place_order(
+			Origin::signed(DAVE),
+			asset_b,
+			asset_a,
+			1_000_000_000_000,
+			Price::from_float(0.4),
+			true
+		));
+		assert_ok!(OTC::place_order(
+			Origin::signed(FERDIE),
+			asset_b,
+			asset_a,
+			1_000_000_000_000,
+			Price::from_float(0.6),
+			true
+		));
+
+		assert_ok!(Exchange::sell(
+			Origin::signed(BOB),
+			asset_a,
+			asset_b,
+			1_000_000_000_000,
+			100_000_000_000,
+			false,
+			None,
+			0,
+		));
+		let bob_intention_id = generate_intention_id(&BOB, 0);
+
+		<Exchange as OnFinalize<u64>>::on_finalize(9);
+
+		test_utils::assert_event_emitted!(
+			Test,
+			pallet_otc::Event::OrderFilled(0, DAVE, BOB, 1_000_000_000_000, 400_000_000_000)
+		);
+		test_utils::assert_event_emitted!(
+			Test,
+			Event::IntentionResolvedOrderBookTrade(
+				BOB,
+				IntentionType::SELL,
+				bob_intention_id,
+				400_000_000_000,
+				1_000_000_000_000
+			)
+		);
+
+		// Rest of the intention is traded with the pool
+		assert_eq!(
+			Currency::free_balance(asset_a, &BOB),
+			ENDOWED_AMOUNT - 1_000_000_000_000
+		);
+		assert!(Currency::free_balance(asset_b, &BOB) > ENDOWED_AMOUNT + 1_000_000_000_000);
+		assert_eq!(
+			Currency::free_balance(asset_a, &pair_account),
+			pool_amount + 600_000_000_000
+		);
+
+		assert_eq!(Currency::free_balance(asset_a, &DAVE), ENDOWED_AMOUNT + 400_000_000_000);
+		assert_eq!(Currency::reserved_balance(asset_b, &DAVE), 0);
+		assert_eq!(OTC::orders(1).unwrap().amount, 1_000_000_000_000);
+		assert_eq!(Currency::reserved_balance(asset_b, &FERDIE), 1_000_000_000_000);
+
+		assert_eq!(Exchange::get_intentions_count((asset_b, asset_a)), 0);
+	});
+}
+
+#[test]
+fn block_start_price_should_be_recorded_before_first_trade_of_block() {
+	new_test_ext().execute_with(|| {
+		initialize_pool(ETH, DOT, ALICE, 100_000_000_000_000, Price::from(2));
+
+		assert_ok!(Exchange::sell(
+			Origin::signed(BOB),
+			ETH,
+			DOT,
+			2_000_000_000_000,
+			400_000_000_000,
+			false,
+			None,
+			0,
+		));
+
+		// Registering an intention does not record the price
+		assert_eq!(Exchange::block_start_price((DOT, ETH)), None);
+
+		assert_ok!(XYKPallet::sell(
+			Origin::signed(CHARLIE),
+			ETH,
+			DOT,
+			10_000_000_000_000,
+			0,
+			false
+		));
+
+		// Price before the first trade of the block is kept
+		assert_eq!(
+			Exchange::block_start_price((DOT, ETH)),
+			Some(Price::saturating_from_rational(1, 2))
+		);
+
+		assert_ok!(XYKPallet::sell(
+			Origin::signed(CHARLIE),
+			ETH,
+			DOT,
+			10_000_000_000_000,
+			0,
+			false
+		));
+
+		assert_eq!(
+			Exchange::block_start_price((DOT, ETH)),
+			Some(Price::saturating_from_rational(1, 2))
+		);
+
+		<Exchange as OnFinalize<u64>>::on_finalize(1);
+
+		assert_eq!(Exchange::block_start_price((DOT, ETH)), None);
+	});
+}
+
+#[test]
+fn intention_should_not_be_resolved_when_pool_was_traded_before_its_registration() {
+	new_test_ext().execute_with(|| {
+		set_max_price_deviation(Permill::from_percent(10));
+
+		let pair_account = XYKPallet::get_pair_id(AssetPair {
+			asset_in: ETH,
+			asset_out: DOT,
+		});
+
+		initialize_pool(ETH, DOT, ALICE, 100_000_000_000_000, Price::from(2));
+
+		// Large trade moves the pool price before any intention of the pair is registered in the block
+		assert_ok!(XYKPallet::sell(
+			Origin::signed(CHARLIE),
+			ETH,
+			DOT,
+			30_000_000_000_000,
+			0,
+			false
+		));
+
+		assert_ok!(Exchange::sell(
+			Origin::signed(BOB),
+			ETH,
+			DOT,
+			2_000_000_000_000,
+			400_000_000_000,
+			false,
+			None,
+			0,
+		));
+		let bob_intention_id = generate_intention_id(&BOB, 0);
+
+		let pool_eth = Currency::free_balance(ETH, &pair_account);
+		let pool_dot = Currency::free_balance(DOT, &pair_account);
+
+		<Exchange as OnFinalize<u64>>::on_finalize(1);
+
+		expect_event(Event::IntentionResolveErrorEvent(
+			BOB,
+			AssetPair {
+				asset_in: ETH,
+				asset_out: DOT,
+			},
+			IntentionType::SELL,
+			bob_intention_id,
+			Error::<Test>::PriceDeviationExceeded.into(),
+		));
+
+		assert_eq!(Currency::free_balance(ETH, &BOB), ENDOWED_AMOUNT);
+		assert_eq!(Currency::free_balance(DOT, &BOB), ENDOWED_AMOUNT);
+		assert_eq!(Currency::free_balance(ETH, &pair_account), pool_eth);
+		assert_eq!(Currency::free_balance(DOT, &pair_account), pool_dot);
+	});
+}
+
+#[test]
+fn sell_intention_should_not_be_resolved_when_pool_price_moved_in_block() {
+	new_test_ext().execute_with(|| {
+		set_max_price_deviation(Permill::from_percent(10));
+
+		let pair_account = XYKPallet::get_pair_id(AssetPair {
+			asset_in: ETH,
+			asset_out: DOT,
+		});
+
+		initialize_pool(ETH, DOT, ALICE, 100_000_000_000_000, Price::from(2));
+
+		assert_ok!(Exchange::sell(
+			Origin::signed(BOB),
+			ETH,
+			DOT,
+			2_000_000_000_000,
+			400_000_000_000,
+			false,
+			None,
+			0,
+		));
+		let bob_intention_id = generate_intention_id(&BOB, 0);
+
+		// Large trade moves the pool price after the intention was registered
+		assert_ok!(XYKPallet::sell(
+			Origin::signed(CHARLIE),
+			ETH,
+			DOT,
+			30_000_000_000_000,
+			0,
+			false
+		));
+
+		let pool_eth = Currency::free_balance(ETH, &pair_account);
+		let pool_dot = Currency::free_balance(DOT, &pair_account);
+
+		<Exchange as OnFinalize<u64>>::on_finalize(1);
+
+		expect_event(Event::IntentionResolveErrorEvent(
+			BOB,
+			AssetPair {
+				asset_in: ETH,
+				asset_out: DOT,
+			},
+			IntentionType::SELL,
+			bob_intention_id,
+			Error::<Test>::PriceDeviationExceeded.into(),
+		));
+
+		assert_eq!(Currency::free_balance(ETH, &BOB), ENDOWED_AMOUNT);
+		assert_eq!(Currency::free_balance(DOT, &BOB), ENDOWED_AMOUNT);
+		assert_eq!(Currency::free_balance(ETH, &pair_account), pool_eth);
+		assert_eq!(Currency::free_balance(DOT, &pair_account), pool_dot);
+	});
+}
+
+#[test]
+fn intention_should_be_resolved_only_when_execution_price_is_within_band() {
+	new_test_ext().execute_with(|| {
+		initialize_pool(ETH, DOT, ALICE, 100_000_000_000_000, Price::from(2));
+
+		// Execution price of the trade is ~2.2% worse than the spot price
+		set_max_price_deviation(Permill::from_percent(2));
+
+		assert_ok!(Exchange::sell(
+			Origin::signed(BOB),
+			ETH,
+			DOT,
+			2_000_000_000_000,
+			400_000_000_000,
+			false,
+			None,
+			0,
+		));
+		let bob_intention_id = generate_intention_id(&BOB, 0);
+
+		<Exchange as OnFinalize<u64>>::on_finalize(1);
+
+		expect_event(Event::IntentionResolveErrorEvent(
+			BOB,
+			AssetPair {
+				asset_in: ETH,
+				asset_out: DOT,
+			},
+			IntentionType::SELL,
+			bob_intention_id,
+			Error::<Test>::PriceDeviationExceeded.into(),
+		));
+		assert_eq!(Currency::free_balance(DOT, &BOB), ENDOWED_AMOUNT);
+
+		System::set_block_number(2);
+		set_max_price_deviation(Permill::from_percent(3));
+
+		assert_ok!(Exchange::sell(
+			Origin::signed(BOB),
+			ETH,
+			DOT,
+			2_000_000_000_000,
+			400_000_000_000,
+			false,
+			None,
+			0,
+		));
+		let bob_intention_id = generate_intention_id(&BOB, 0);
+
+		<Exchange as OnFinalize<u64>>::on_finalize(2);
+
+		expect_event(Event::IntentionResolvedAMMTrade(
+			BOB,
+			IntentionType::SELL,
+			bob_intention_id,
+			2_000_000_000_000,
+			3_913_878_975_647,
+		));
+		assert_eq!(Currency::free_balance(DOT, &BOB), ENDOWED_AMOUNT + 3_913_878_975_647);
+	});
+}
+
+fn signed_intention(
+	who: AccountId,
+	sell_or_buy: IntentionType,
+	amount: Balance,
+	limit: Balance,
+	relayer_fee: Balance,
+	nonce: u64,
+) -> SignedIntentionOf<Test> {
+	let payload = primitives::intention::IntentionPayload {
+		who,
+		sell_or_buy,
+		asset_sell: ETH,
+		asset_buy: DOT,
+		amount,
+		limit,
+		discount: false,
+		valid_for: None,
+		relayer_fee,
+		nonce,
+		deadline: 10,
+		tip: 0,
+	};
+	let message = payload.signing_message(&System::block_hash(0));
+
+	SignedIntention {
+		payload,
+		signature: TestSignature(who, message),
+	}
+}
+
+#[test]
+fn submit_intentions_batch_should_register_intentions_and_pay_relayer() {
+	new_test_ext().execute_with(|| {
+		initialize_pool(ETH, DOT, ALICE, 100_000_000_000_000, Price::from(2));
+
+		assert_ok!(Exchange::submit_intentions_batch(
+			Origin::signed(CHARLIE),
+			vec![
+				signed_intention(BOB, IntentionType::SELL, 2_000_000_000_000, 400_000_000_000, 1_000, 0),
+				signed_intention(DAVE, IntentionType::BUY, 1_000_000_000_000, 600_000_000_000, 0, 0),
+			]
+		));
+
+		assert_eq!(Exchange::get_intentions_count((DOT, ETH)), 2);
+		assert_eq!(Exchange::intention_nonce(BOB), 1);
+		assert_eq!(Exchange::intention_nonce(DAVE), 1);
+
+		assert_eq!(Currency::free_balance(HDX, &BOB), ENDOWED_AMOUNT - 1_000);
+		assert_eq!(Currency::free_balance(HDX, &CHARLIE), ENDOWED_AMOUNT + 1_000);
+
+		expect_events(vec![
+			Event::IntentionRegistered(
+				BOB,
+				ETH,
+				DOT,
+				2_000_000_000_000,
+				IntentionType::SELL,
+				generate_intention_id(&BOB, 0),
+			)
+			.into(),
+			Event::RelayerFeePaid(BOB, CHARLIE, 1_000).into(),
+			Event::IntentionRegistered(
+				DAVE,
+				DOT,
+				ETH,
+				1_000_000_000_000,
+				IntentionType::BUY,
+				generate_intention_id(&DAVE, 1),
+			)
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn submit_intentions_batch_should_reject_invalid_signature() {
+	new_test_ext().execute_with(|| {
+		initialize_pool(ETH, DOT, ALICE, 100_000_000_000_000, Price::from(2));
+
+		let mut signed = signed_intention(BOB, IntentionType::SELL, 2_000_000_000_000, 400_000_000_000, 1_000, 0);
+
+		// Payload tampered by the relayer
+		signed.payload.relayer_fee = 1_000_000;
+
+		assert_ok!(Exchange::submit_intentions_batch(Origin::signed(CHARLIE), vec![signed]));
+
+		expect_event(Event::SignedIntentionRejected(
+			CHARLIE,
+			BOB,
+			0,
+			Error::<Test>::InvalidSignature.into(),
+		));
+
+		assert_eq!(Exchange::get_intentions_count((DOT, ETH)), 0);
+		assert_eq!(Exchange::intention_nonce(BOB), 0);
+		assert_eq!(Currency::free_balance(HDX, &BOB), ENDOWED_AMOUNT);
+	});
+}
+
+#[test]
+fn submit_intentions_batch_should_reject_replayed_intention() {
+	new_test_ext().execute_with(|| {
+		initialize_pool(ETH, DOT, ALICE, 100_000_000_000_000, Price::from(2));
+
+		let signed = signed_intention(BOB, IntentionType::SELL, 2_000_000_000_000, 400_000_000_000, 1_000, 0);
+
+		assert_ok!(Exchange::submit_intentions_batch(
+			Origin::signed(CHARLIE),
+			vec![signed.clone()]
+		));
+		assert_ok!(Exchange::submit_intentions_batch(Origin::signed(DAVE), vec![signed]));
+
+		expect_event(Event::SignedIntentionRejected(
+			DAVE,
+			BOB,
+			0,
+			Error::<Test>::InvalidNonce.into(),
+		));
+
+		assert_eq!(Exchange::get_intentions_count((DOT, ETH)), 1);
+		assert_eq!(Exchange::intention_nonce(BOB), 1);
+		assert_eq!(Currency::free_balance(HDX, &DAVE), ENDOWED_AMOUNT);
+	});
+}
+
+#[test]
+fn submit_intentions_batch_should_reject_expired_intention() {
+	new_test_ext().execute_with(|| {
+		initialize_pool(ETH, DOT, ALICE, 100_000_000_000_000, Price::from(2));
+
+		let signed = signed_intention(BOB, IntentionType::SELL, 2_000_000_000_000, 400_000_000_000, 1_000, 0);
+
+		System::set_block_number(signed.payload.deadline + 1);
+
+		assert_ok!(Exchange::submit_intentions_batch(Origin::signed(CHARLIE), vec![signed]));
+
+		expect_event(Event::SignedIntentionRejected(
+			CHARLIE,
+			BOB,
+			0,
+			Error::<Test>::SignedIntentionExpired.into(),
+		));
+
+		assert_eq!(Exchange::get_intentions_count((DOT, ETH)), 0);
+		assert_eq!(Exchange::intention_nonce(BOB), 0);
+		assert_eq!(Currency::free_balance(HDX, &BOB), ENDOWED_AMOUNT);
+	});
+}
+
+#[test]
+fn increment_intention_nonce_should_invalidate_signed_intention() {
+	new_test_ext().execute_with(|| {
+		initialize_pool(ETH, DOT, ALICE, 100_000_000_000_000, Price::from(2));
+
+		let signed = signed_intention(BOB, IntentionType::SELL, 2_000_000_000_000, 400_000_000_000, 1_000, 0);
+
+		assert_ok!(Exchange::increment_intention_nonce(Origin::signed(BOB)));
+
+		expect_event(Event::IntentionNonceIncremented(BOB, 1));
+		assert_eq!(Exchange::intention_nonce(BOB), 1);
+
+		assert_ok!(Exchange::submit_intentions_batch(Origin::signed(CHARLIE), vec![signed]));
+
+		expect_event(Event::SignedIntentionRejected(
+			CHARLIE,
+			BOB,
+			0,
+			Error::<Test>::InvalidNonce.into(),
+		));
+
+		assert_eq!(Exchange::get_intentions_count((DOT, ETH)), 0);
+		assert_eq!(Currency::free_balance(HDX, &BOB), ENDOWED_AMOUNT);
+	});
+}
+
+#[test]
+fn submit_intentions_batch_should_not_change_state_when_intention_cannot_be_registered() {
+	new_test_ext().execute_with(|| {
+		initialize_pool(ETH, DOT, ALICE, 100_000_000_000_000, Price::from(2));
+
+		assert_ok!(Exchange::submit_intentions_batch(
+			Origin::signed(CHARLIE),
+			vec![signed_intention(BOB, IntentionType::SELL, 100, 1, 1_000, 0)]
+		));
+
+		expect_event(Event::SignedIntentionRejected(
+			CHARLIE,
+			BOB,
+			0,
+			Error::<Test>::MinimumTradeLimitNotReached.into(),
+		));
+
+		assert_eq!(Exchange::intention_nonce(BOB), 0);
+		assert_eq!(Currency::free_balance(HDX, &BOB), ENDOWED_AMOUNT);
+	});
+}
+
+#[test]
+fn submit_intentions_batch_should_not_work_when_batch_is_too_large() {
+	new_test_ext().execute_with(|| {
+		initialize_pool(ETH, DOT, ALICE, 100_000_000_000_000, Price::from(2));
+
+		let intentions = (0..6)
+			.map(|nonce| signed_intention(BOB, IntentionType::SELL, 1_000_000_000, 1_000, 0, nonce))
+			.collect();
+
+		assert_noop!(
+			Exchange::submit_intentions_batch(Origin::signed(CHARLIE), intentions),
+			Error::<Test>::TooManySignedIntentions
+		);
+	});
+}
+
+#[test]
+fn charge_intention_fee_should_charge_relayer_for_batch() {
+	new_test_ext().execute_with(|| {
+		initialize_pool(ETH, DOT, ALICE, 100_000_000_000_000, Price::from(2));
+
+		for _ in 0..5 {
+			assert_ok!(Exchange::sell(
+				Origin::signed(BOB),
+				ETH,
+				DOT,
+				1_000_000_000,
+				1_000,
+				false,
+				None,
+				0,
+			));
+		}
+
+		let call: crate::mock::Call = Call::submit_intentions_batch(vec![
+			signed_intention(DAVE, IntentionType::SELL, 1_000_000_000, 1_000, 0, 0),
+			signed_intention(DAVE, IntentionType::SELL, 1_000_000_000, 1_000, 0, 1),
+		])
+		.into();
+
+		assert_ok!(ChargeIntentionFee::<Test>::new().pre_dispatch(&CHARLIE, &call, &Default::default(), 0));
+
+		assert_eq!(Currency::free_balance(HDX, &CHARLIE), ENDOWED_AMOUNT - 500_000_000);
+	});
+}
+
+#[test]
+fn intentions_exceeding_resolution_weight_should_be_deferred_to_next_block() {
+	new_test_ext().execute_with(|| {
+		let pool_amount = 100_000_000_000_000;
+
+		initialize_pool(ETH, DOT, ALICE, pool_amount, Price::from(2));
+		initialize_pool(HDX, DOT, ALICE, pool_amount, Price::from(2));
+
+		assert_ok!(Exchange::sell(
+			Origin::signed(BOB),
+			ETH,
+			DOT,
+			1_000_000_000,
+			1_000,
+			false,
+			None,
+			0,
+		));
+		assert_ok!(Exchange::sell(
+			Origin::signed(CHARLIE),
+			HDX,
+			DOT,
+			1_000_000_000,
+			1_000,
+			false,
+			None,
+			0,
+		));
+
+		// Intentions of only one pair can be resolved in a block
+		set_max_resolution_weight(1);
+
+		<Exchange as OnFinalize<u64>>::on_finalize(1);
+
+		let deferred_pairs = Exchange::deferred_pairs();
+		assert_eq!(deferred_pairs.len(), 1);
+
+		let (resolved, deferred) = if deferred_pairs[0] == (DOT, ETH) {
+			((HDX, DOT, CHARLIE), (ETH, DOT, BOB))
+		} else {
+			((ETH, DOT, BOB), (HDX, DOT, CHARLIE))
+		};
+
+		assert!(Exchange::get_intentions((resolved.0, resolved.1)).is_empty());
+		assert_eq!(
+			Currency::free_balance(resolved.0, &resolved.2),
+			ENDOWED_AMOUNT - 1_000_000_000
+		);
+
+		let intentions = Exchange::get_intentions((deferred.0, deferred.1));
+		assert_eq!(intentions.len(), 1);
+		assert_eq!(intentions[0].valid_until, None);
+		assert_eq!(Currency::free_balance(deferred.0, &deferred.2), ENDOWED_AMOUNT);
+		assert_eq!(
+			Exchange::get_intentions_count(AssetPair::new(deferred.0, deferred.1).ordered_pair()),
+			1
+		);
+
+		expect_event(Event::IntentionDeferred(deferred.2, intentions[0].intention_id));
+
+		System::set_block_number(2);
+
+		assert_ok!(Exchange::sell(
+			Origin::signed(resolved.2),
+			resolved.0,
+			resolved.1,
+			1_000_000_000,
+			1_000,
+			false,
+			None,
+			0,
+		));
+
+		// Pair deferred in the previous block is resolved first
+		<Exchange as OnFinalize<u64>>::on_finalize(2);
+
+		assert!(Exchange::get_intentions((deferred.0, deferred.1)).is_empty());
+		assert_eq!(
+			Currency::free_balance(deferred.0, &deferred.2),
+			ENDOWED_AMOUNT - 1_000_000_000
+		);
+
+		assert_eq!(Exchange::get_intentions((resolved.0, resolved.1)).len(), 1);
+		assert_eq!(
+			Exchange::deferred_pairs(),
+			vec![AssetPair::new(resolved.0, resolved.1).ordered_pair()]
+		);
+
+		// Intentions are resolved as usual once the limit allows it
+		set_max_resolution_weight(Weight::MAX);
+
+		System::set_block_number(3);
+
+		<Exchange as OnFinalize<u64>>::on_finalize(3);
+
+		assert!(Exchange::get_intentions((resolved.0, resolved.1)).is_empty());
+		assert!(Exchange::deferred_pairs().is_empty());
+	});
+}
+
+#[test]
+fn intention_tip_should_be_reserved_and_paid_on_resolution() {
+	new_test_ext().execute_with(|| {
+		let tip = 1_000_000;
+
+		initialize_pool(ETH, DOT, ALICE, 100_000_000_000_000, Price::from(2));
+
+		assert_ok!(Exchange::sell(
+			Origin::signed(BOB),
+			ETH,
+			DOT,
+			1_000_000_000,
+			1_000,
+			false,
+			None,
+			tip,
+		));
+		let intention_id = generate_intention_id(&BOB, 0);
+
+		assert_eq!(Exchange::get_intentions((ETH, DOT))[0].tip, tip);
+		assert_eq!(Currency::free_balance(HDX, &BOB), ENDOWED_AMOUNT - tip);
+		assert_eq!(Currency::reserved_balance(HDX, &BOB), tip);
+
+		<Exchange as OnFinalize<u64>>::on_finalize(1);
+
+		assert_eq!(Currency::free_balance(HDX, &BOB), ENDOWED_AMOUNT - tip);
+		assert_eq!(Currency::reserved_balance(HDX, &BOB), 0);
+		assert_eq!(Currency::free_balance(HDX, &TIP_RECEIVER), tip);
+
+		test_utils::assert_event_emitted!(Test, Event::IntentionTipPaid(BOB, intention_id, TIP_RECEIVER, tip));
+	});
+}
+
+#[test]
+fn intention_with_higher_tip_should_be_resolved_first() {
+	new_test_ext().execute_with(|| {
+		initialize_pool(ETH, DOT, ALICE, 100_000_000_000_000, Price::from(2));
+
+		assert_ok!(Exchange::sell(
+			Origin::signed(BOB),
+			ETH,
+			DOT,
+			2_000_000_000,
+			1_000,
+			false,
+			None,
+			0,
+		));
+		assert_ok!(Exchange::sell(
+			Origin::signed(CHARLIE),
+			ETH,
+			DOT,
+			1_000_000_000,
+			1_000,
+			false,
+			None,
+			1_000,
+		));
+
+		<Exchange as OnFinalize<u64>>::on_finalize(1);
+
+		let resolved = test_utils::filter_events!(Test, TestEvent::exchange(Event::IntentionResolvedAMMTrade(..)))
+			.into_iter()
+			.filter_map(|event| match event {
+				TestEvent::exchange(Event::IntentionResolvedAMMTrade(who, ..)) => Some(who),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+
+		assert_eq!(resolved, vec![CHARLIE, BOB]);
+	});
+}
+
+#[test]
+fn cancel_intention_should_return_tip() {
+	new_test_ext().execute_with(|| {
+		let tip = 1_000_000;
+
+		initialize_pool(ETH, DOT, ALICE, 100_000_000_000_000, Price::from(2));
+
+		assert_ok!(Exchange::sell(
+			Origin::signed(BOB),
+			ETH,
+			DOT,
+			1_000_000_000,
+			1_000,
+			false,
+			None,
+			tip,
+		));
+		let intention_id = generate_intention_id(&BOB, 0);
+
+		assert_ok!(Exchange::cancel_intention(Origin::signed(BOB), ETH, DOT, intention_id));
+
+		<Exchange as OnFinalize<u64>>::on_finalize(1);
+
+		assert_eq!(Currency::free_balance(HDX, &BOB), ENDOWED_AMOUNT);
+		assert_eq!(Currency::reserved_balance(HDX, &BOB), 0);
+		assert_eq!(Currency::free_balance(HDX, &TIP_RECEIVER), 0);
+	});
+}
+
+#[test]
+fn register_intention_should_not_work_when_tip_cannot_be_reserved() {
+	new_test_ext().execute_with(|| {
+		initialize_pool(ETH, DOT, ALICE, 100_000_000_000_000, Price::from(2));
+
+		assert_noop!(
+			Exchange::sell(
+				Origin::signed(BOB),
+				ETH,
+				DOT,
+				1_000_000_000,
+				1_000,
+				false,
+				None,
+				ENDOWED_AMOUNT + 1,
+			),
+			Error::<Test>::InsufficientTipBalance
+		);
+	});
+}
@@ -51,12 +51,14 @@ pub trait WeightInfo {
 	fn sell_intention() -> Weight;
 	fn buy_intention() -> Weight;
 	fn on_finalize(t: u32) -> Weight;
+	fn on_finalize_intentions_in_pools(t: u32, p: u32) -> Weight;
 	fn on_finalize_buys_no_matches(t: u32) -> Weight;
 	fn on_finalize_sells_no_matches(t: u32) -> Weight;
 	fn sell_extrinsic() -> Weight;
 	fn on_finalize_for_one_sell_extrinsic() -> Weight;
 	fn buy_extrinsic() -> Weight;
 	fn on_finalize_for_one_buy_extrinsic() -> Weight;
+	fn cancel_intention() -> Weight;
 }
 
 /// Weights for exchange using the hydraDX node and recommended hardware.
@@ -85,6 +87,17 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(t as Weight)))
 	}
+	fn on_finalize_intentions_in_pools(t: u32, p: u32) -> Weight {
+		(0 as Weight)
+			.saturating_add((128_415_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add((31_870_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(t as Weight)))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(t as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(p as Weight)))
+	}
 	fn on_finalize_buys_no_matches(t: u32) -> Weight {
 		(0 as Weight)
 			// Standard Error: 79_000
@@ -123,6 +136,11 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(9 as Weight))
 			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 	}
+	fn cancel_intention() -> Weight {
+		(48_512_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -149,6 +167,17 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(t as Weight)))
 	}
+	fn on_finalize_intentions_in_pools(t: u32, p: u32) -> Weight {
+		(0 as Weight)
+			.saturating_add((128_415_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add((31_870_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(t as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(t as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(p as Weight)))
+	}
 	fn on_finalize_buys_no_matches(t: u32) -> Weight {
 		(0 as Weight)
 			// Standard Error: 79_000
@@ -187,4 +216,9 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
 	}
+	fn cancel_intention() -> Weight {
+		(48_512_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
 }
@@ -19,12 +19,13 @@
 #![allow(clippy::unused_unit)]
 
 use frame_support::{
-	ensure,
-	weights::{DispatchClass, Pays},
+	ensure, transactional,
+	weights::{DispatchClass, Pays, Weight},
 };
-use frame_system::ensure_signed;
+use frame_system::{ensure_root, ensure_signed};
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::{AssetId, Balance};
+use sp_runtime::traits::{One, Zero};
 use sp_std::vec::Vec;
 
 #[cfg(test)]
@@ -57,6 +58,18 @@ pub mod pallet {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
 		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = AssetId, Balance = Balance, Amount = i128>;
+
+		/// Number of blocks after which drip counters of all accounts are reset
+		#[pallet::constant]
+		type DripPeriod: Get<Self::BlockNumber>;
+
+		/// Maximum number of drips of an account within a drip period
+		#[pallet::constant]
+		type MaxDripsPerPeriod: Get<u32>;
+
+		/// Maximum number of assets in the drip bundle
+		#[pallet::constant]
+		type MaxDripBundleLength: Get<u32>;
 	}
 
 	#[pallet::event]
@@ -64,12 +77,27 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		RampageMint(T::AccountId, AssetId, Balance),
 		Mint(T::AccountId),
+
+		/// Drip bundle was dispensed. [who]
+		Dripped(T::AccountId),
+
+		/// Drip bundle was changed. [bundle]
+		DripBundleSet(Vec<(AssetId, Balance)>),
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		RampageMintNotAllowed,
 		MaximumMintLimitReached,
+
+		/// Account reached maximum number of drips in the current drip period.
+		DripLimitReached,
+
+		/// Drip bundle is empty.
+		DripBundleNotSet,
+
+		/// Drip bundle contains more than `MaxDripBundleLength` assets.
+		DripBundleTooLong,
 	}
 	#[pallet::storage]
 	#[pallet::getter(fn minted)]
@@ -87,11 +115,22 @@ pub mod pallet {
 	#[pallet::getter(fn mintable_currencies)]
 	pub type MintableCurrencies<T: Config> = StorageValue<_, Vec<AssetId>, ValueQuery>;
 
+	/// Assets and amounts dispensed by a single drip.
+	#[pallet::storage]
+	#[pallet::getter(fn drip_bundle)]
+	pub type DripBundle<T: Config> = StorageValue<_, Vec<(AssetId, Balance)>, ValueQuery>;
+
+	/// Drip period of the last drip of an account and number of drips within that period.
+	#[pallet::storage]
+	#[pallet::getter(fn drips)]
+	pub type Drips<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (T::BlockNumber, u32), ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig {
 		pub mint_limit: u8,
 		pub rampage: bool,
 		pub mintable_currencies: Vec<AssetId>,
+		pub drip_bundle: Vec<(AssetId, Balance)>,
 	}
 
 	#[cfg(feature = "std")]
@@ -101,6 +140,7 @@ pub mod pallet {
 				mint_limit: Default::default(),
 				rampage: Default::default(),
 				mintable_currencies: vec![],
+				drip_bundle: vec![],
 			}
 		}
 	}
@@ -124,6 +164,12 @@ pub mod pallet {
 			MintLimit::<T>::put(self.mint_limit);
 			Rampage::<T>::put(self.rampage);
 			MintableCurrencies::<T>::put(self.mintable_currencies.clone());
+
+			assert!(
+				self.drip_bundle.len() <= T::MaxDripBundleLength::get() as usize,
+				"Drip bundle exceeds MaxDripBundleLength"
+			);
+			DripBundle::<T>::put(self.drip_bundle.clone());
 		}
 	}
 
@@ -156,5 +202,75 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Dispense the drip bundle to origin.
+		///
+		/// Unless rampage is enabled, an account can drip at most `MaxDripsPerPeriod` times per `DripPeriod`.
+		///
+		/// Emits `Dripped` event when successful.
+		#[pallet::weight((
+			T::DbWeight::get().reads_writes(3, 1).saturating_add(
+				T::DbWeight::get().reads_writes(2, 2).saturating_mul(T::MaxDripBundleLength::get() as Weight)
+			),
+			DispatchClass::Normal,
+			Pays::No
+		))]
+		#[transactional]
+		pub fn drip(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let bundle = Self::drip_bundle();
+
+			ensure!(!bundle.is_empty(), Error::<T>::DripBundleNotSet);
+
+			if !Self::rampage() {
+				let period = Self::current_drip_period();
+				let (last_period, drips) = Self::drips(&who);
+				let drips = if last_period == period { drips } else { 0 };
+
+				ensure!(drips < T::MaxDripsPerPeriod::get(), Error::<T>::DripLimitReached);
+
+				Drips::<T>::insert(&who, (period, drips + 1));
+			}
+
+			for (asset, amount) in bundle {
+				T::Currency::deposit(asset, &who, amount)?;
+			}
+
+			Self::deposit_event(Event::Dripped(who));
+
+			Ok(().into())
+		}
+
+		/// Set assets and amounts dispensed by a single drip.
+		///
+		/// Bundle can contain at most `MaxDripBundleLength` assets.
+		///
+		/// Emits `DripBundleSet` event when successful.
+		#[pallet::weight((T::DbWeight::get().writes(1), DispatchClass::Operational))]
+		pub fn set_drip_bundle(origin: OriginFor<T>, bundle: Vec<(AssetId, Balance)>) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			ensure!(
+				bundle.len() <= T::MaxDripBundleLength::get() as usize,
+				Error::<T>::DripBundleTooLong
+			);
+
+			DripBundle::<T>::put(&bundle);
+
+			Self::deposit_event(Event::DripBundleSet(bundle));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Return index of the current drip period.
+	fn current_drip_period() -> T::BlockNumber {
+		let period = T::DripPeriod::get();
+		let period = if period.is_zero() { One::one() } else { period };
+
+		<frame_system::Pallet<T>>::block_number() / period
 	}
 }
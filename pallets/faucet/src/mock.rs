@@ -92,9 +92,18 @@ impl orml_tokens::Config for Test {
 	type OnDust = ();
 }
 
+parameter_types! {
+	pub const DripPeriod: u64 = 10;
+	pub const MaxDripsPerPeriod: u32 = 2;
+	pub const MaxDripBundleLength: u32 = 3;
+}
+
 impl Config for Test {
 	type Event = ();
 	type Currency = Currency;
+	type DripPeriod = DripPeriod;
+	type MaxDripsPerPeriod = MaxDripsPerPeriod;
+	type MaxDripBundleLength = MaxDripBundleLength;
 }
 
 pub type AccountId = u64;
@@ -130,6 +139,7 @@ impl ExtBuilder {
 			rampage: true,
 			mintable_currencies: vec![2000, 3000],
 			mint_limit: 5,
+			drip_bundle: vec![(2000, 1_000), (3000, 2_000)],
 		}
 		.assimilate_storage::<Test>(&mut t)
 		.unwrap();
@@ -144,6 +154,7 @@ impl ExtBuilder {
 			rampage: false,
 			mintable_currencies: vec![2000, 3000],
 			mint_limit: 5,
+			drip_bundle: vec![(2000, 1_000), (3000, 2_000)],
 		}
 		.assimilate_storage::<Test>(&mut t)
 		.unwrap();
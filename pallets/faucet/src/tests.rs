@@ -16,7 +16,7 @@
 // limitations under the License.
 
 use super::*;
-use crate::mock::{Currency, ExtBuilder, Faucet, Origin, Test, ALICE, HDX};
+use crate::mock::{Currency, ExtBuilder, Faucet, Origin, System, Test, ALICE, HDX};
 use frame_support::traits::OnFinalize;
 use frame_support::{assert_noop, assert_ok};
 
@@ -70,3 +70,106 @@ fn mint_limit() {
 		assert_eq!(Currency::free_balance(2000, &ALICE), 6_000_000_000_000_000);
 	});
 }
+
+#[test]
+fn drips() {
+	ExtBuilder::default().build_live().execute_with(|| {
+		assert_ok!(Faucet::drip(Origin::signed(ALICE)));
+
+		assert_eq!(Currency::free_balance(2000, &ALICE), 1_000);
+		assert_eq!(Currency::free_balance(3000, &ALICE), 2_000);
+		assert_eq!(Currency::free_balance(HDX, &ALICE), 1_000);
+		assert_eq!(Faucet::drips(ALICE), (0, 1));
+	});
+}
+
+#[test]
+fn drip_limit() {
+	ExtBuilder::default().build_live().execute_with(|| {
+		System::set_block_number(3);
+
+		assert_ok!(Faucet::drip(Origin::signed(ALICE)));
+		assert_ok!(Faucet::drip(Origin::signed(ALICE)));
+
+		assert_noop!(Faucet::drip(Origin::signed(ALICE)), Error::<Test>::DripLimitReached);
+
+		System::set_block_number(9);
+
+		assert_noop!(Faucet::drip(Origin::signed(ALICE)), Error::<Test>::DripLimitReached);
+
+		assert_eq!(Currency::free_balance(2000, &ALICE), 2_000);
+	});
+}
+
+#[test]
+fn drip_limit_resets_in_next_period() {
+	ExtBuilder::default().build_live().execute_with(|| {
+		System::set_block_number(5);
+
+		assert_ok!(Faucet::drip(Origin::signed(ALICE)));
+		assert_ok!(Faucet::drip(Origin::signed(ALICE)));
+
+		assert_noop!(Faucet::drip(Origin::signed(ALICE)), Error::<Test>::DripLimitReached);
+
+		System::set_block_number(10);
+
+		assert_ok!(Faucet::drip(Origin::signed(ALICE)));
+
+		assert_eq!(Faucet::drips(ALICE), (1, 1));
+		assert_eq!(Currency::free_balance(2000, &ALICE), 3_000);
+	});
+}
+
+#[test]
+fn rampage_drips_without_limit() {
+	ExtBuilder::default().build_rampage().execute_with(|| {
+		for _ in 0..5 {
+			assert_ok!(Faucet::drip(Origin::signed(ALICE)));
+		}
+
+		assert_eq!(Currency::free_balance(2000, &ALICE), 5_000);
+		assert_eq!(Currency::free_balance(3000, &ALICE), 10_000);
+	});
+}
+
+#[test]
+fn drip_without_bundle_fails() {
+	ExtBuilder::default().build_live().execute_with(|| {
+		assert_ok!(Faucet::set_drip_bundle(Origin::root(), vec![]));
+
+		assert_noop!(Faucet::drip(Origin::signed(ALICE)), Error::<Test>::DripBundleNotSet);
+	});
+}
+
+#[test]
+fn set_drip_bundle_works() {
+	ExtBuilder::default().build_live().execute_with(|| {
+		assert_noop!(
+			Faucet::set_drip_bundle(Origin::signed(ALICE), vec![(HDX, 100)]),
+			sp_runtime::traits::BadOrigin
+		);
+
+		assert_ok!(Faucet::set_drip_bundle(Origin::root(), vec![(HDX, 100)]));
+		assert_eq!(Faucet::drip_bundle(), vec![(HDX, 100)]);
+
+		assert_ok!(Faucet::drip(Origin::signed(ALICE)));
+
+		assert_eq!(Currency::free_balance(HDX, &ALICE), 1_100);
+		assert_eq!(Currency::free_balance(2000, &ALICE), 0);
+	});
+}
+
+#[test]
+fn set_drip_bundle_should_not_work_when_bundle_is_too_long() {
+	ExtBuilder::default().build_live().execute_with(|| {
+		assert_noop!(
+			Faucet::set_drip_bundle(Origin::root(), vec![(HDX, 100), (2000, 100), (3000, 100), (4000, 100)]),
+			Error::<Test>::DripBundleTooLong
+		);
+
+		assert_ok!(Faucet::set_drip_bundle(
+			Origin::root(),
+			vec![(HDX, 100), (2000, 100), (3000, 100)]
+		));
+	});
+}
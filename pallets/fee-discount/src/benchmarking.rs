@@ -0,0 +1,95 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use orml_traits::MultiCurrency;
+use sp_std::prelude::*;
+
+use crate::Pallet as FeeDiscount;
+
+const SEED: u32 = 1;
+
+const AMOUNT: Balance = 1_000_000_000_000;
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let caller: T::AccountId = account(name, index, SEED);
+	T::Currency::deposit(T::NativeAssetId::get(), &caller, 1_000_000_000_000_000).unwrap();
+	caller
+}
+
+fn tiers(n: u32) -> Vec<DiscountTier> {
+	(1..=n)
+		.map(|i| DiscountTier {
+			min_locked: AMOUNT * i as Balance,
+			fee: Fee {
+				numerator: 1,
+				denominator: 1_000 * i,
+			},
+		})
+		.collect()
+}
+
+benchmarks! {
+	lock {
+		let caller = funded_account::<T>("caller", 0);
+
+	}: _(RawOrigin::Signed(caller.clone()), AMOUNT)
+	verify {
+		assert_eq!(FeeDiscount::<T>::locked(&caller), AMOUNT);
+	}
+
+	unlock {
+		let caller = funded_account::<T>("caller", 0);
+		FeeDiscount::<T>::lock(RawOrigin::Signed(caller.clone()).into(), AMOUNT).map_err(|e| e.error)?;
+
+	}: _(RawOrigin::Signed(caller.clone()), AMOUNT)
+	verify {
+		assert_eq!(FeeDiscount::<T>::locked(&caller), 0);
+	}
+
+	set_tiers {
+		let n in 1 .. T::MaxTiers::get();
+
+		let tiers = tiers(n);
+
+	}: _(RawOrigin::Root, tiers)
+	verify {
+		assert_eq!(FeeDiscount::<T>::tiers().len(), n as usize);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::Test;
+	use crate::tests::new_test_ext;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_lock::<Test>());
+			assert_ok!(test_benchmark_unlock::<Test>());
+			assert_ok!(test_benchmark_set_tiers::<Test>());
+		});
+	}
+}
@@ -0,0 +1,284 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Fee Discount Pallet
+//!
+//! ## Overview
+//!
+//! Fee discount pallet maps native currency locked by an account to a tier of discounted AMM trade fees.
+//!
+//! Account locks native currency by reserving it in this pallet. Tiers are set by `UpdateOrigin` as a list of
+//! minimal locked amounts and trade fees, the account gets the fee of the highest tier whose minimal amount
+//! it has locked.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{ensure, traits::Get};
+use frame_system::ensure_signed;
+use orml_traits::MultiReservableCurrency;
+use primitives::{fee::Fee, traits::FeeDiscount, AssetId, Balance};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// Trade fee applied to accounts which have locked at least `min_locked` of native currency.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct DiscountTier {
+	/// Minimal amount of locked native currency
+	pub min_locked: Balance,
+	/// Trade fee of the tier
+	pub fee: Fee,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Multi currency for reserving locked native currency
+		type Currency: MultiReservableCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// Native Asset Id
+		#[pallet::constant]
+		type NativeAssetId: Get<AssetId>;
+
+		/// Origin which can set discount tiers
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Maximum number of discount tiers
+		#[pallet::constant]
+		type MaxTiers: Get<u32>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// It is not allowed to lock or unlock zero amount.
+		ZeroAmount,
+
+		/// Balance of native currency is too low to lock the amount.
+		InsufficientBalance,
+
+		/// Locked amount is lower than the amount to unlock.
+		InsufficientLockedBalance,
+
+		/// Number of tiers exceeds `MaxTiers`.
+		TooManyTiers,
+
+		/// Tiers are not ordered by strictly increasing minimal locked amount, fee of a higher tier is higher than
+		/// fee of a lower tier, minimal locked amount is zero or a fee is not valid.
+		InvalidTiers,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Native currency was locked for fee discount. [who, amount, total locked]
+		Locked(T::AccountId, Balance, Balance),
+
+		/// Native currency was unlocked. [who, amount, total locked]
+		Unlocked(T::AccountId, Balance, Balance),
+
+		/// Discount tiers were set. [tiers]
+		TiersSet(Vec<DiscountTier>),
+	}
+
+	/// Amount of native currency locked by an account for fee discount.
+	#[pallet::storage]
+	#[pallet::getter(fn locked)]
+	pub type Locked<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
+	/// Discount tiers ordered by minimal locked amount.
+	#[pallet::storage]
+	#[pallet::getter(fn tiers)]
+	pub type Tiers<T: Config> = StorageValue<_, Vec<DiscountTier>, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig {
+		pub tiers: Vec<DiscountTier>,
+	}
+
+	#[cfg(feature = "std")]
+	impl Default for GenesisConfig {
+		fn default() -> Self {
+			GenesisConfig { tiers: vec![] }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig {
+		fn build(&self) {
+			assert!(
+				Pallet::<T>::validate_tiers(&self.tiers).is_ok(),
+				"Invalid discount tiers"
+			);
+
+			Tiers::<T>::put(self.tiers.clone());
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Lock `amount` of native currency to qualify for a fee discount tier.
+		///
+		/// Locked amount is reserved and added to the amount already locked by the origin.
+		///
+		/// Emits `Locked` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::lock())]
+		pub fn lock(origin: OriginFor<T>, amount: Balance) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			T::Currency::reserve(T::NativeAssetId::get(), &who, amount).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+			let total = Locked::<T>::mutate(&who, |locked| {
+				*locked = locked.saturating_add(amount);
+				*locked
+			});
+
+			Self::deposit_event(Event::Locked(who, amount, total));
+
+			Ok(().into())
+		}
+
+		/// Unlock `amount` of native currency locked by the origin.
+		///
+		/// Emits `Unlocked` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::unlock())]
+		pub fn unlock(origin: OriginFor<T>, amount: Balance) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			let locked = Self::locked(&who);
+
+			ensure!(locked >= amount, Error::<T>::InsufficientLockedBalance);
+
+			T::Currency::unreserve(T::NativeAssetId::get(), &who, amount);
+
+			let total = locked.saturating_sub(amount);
+
+			if total.is_zero() {
+				Locked::<T>::remove(&who);
+			} else {
+				Locked::<T>::insert(&who, total);
+			}
+
+			Self::deposit_event(Event::Unlocked(who, amount, total));
+
+			Ok(().into())
+		}
+
+		/// Set discount tiers.
+		///
+		/// Tiers must be ordered by strictly increasing minimal locked amount and fee of a higher tier must not be
+		/// higher than fee of a lower tier. Empty list disables the discounts.
+		///
+		/// Emits `TiersSet` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::set_tiers(tiers.len() as u32))]
+		pub fn set_tiers(origin: OriginFor<T>, tiers: Vec<DiscountTier>) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			ensure!(tiers.len() <= T::MaxTiers::get() as usize, Error::<T>::TooManyTiers);
+
+			Self::validate_tiers(&tiers)?;
+
+			Tiers::<T>::put(tiers.clone());
+
+			Self::deposit_event(Event::TiersSet(tiers));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Check that tiers are ordered and their fees are valid.
+	fn validate_tiers(tiers: &[DiscountTier]) -> Result<(), Error<T>> {
+		ensure!(
+			tiers.iter().all(|tier| !tier.min_locked.is_zero()
+				&& tier.fee.denominator > 0
+				&& tier.fee.numerator <= tier.fee.denominator),
+			Error::<T>::InvalidTiers
+		);
+
+		ensure!(
+			tiers.windows(2).all(|pair| {
+				let (lower, higher) = (pair[0], pair[1]);
+				lower.min_locked < higher.min_locked
+					&& (higher.fee.numerator as u64) * (lower.fee.denominator as u64)
+						<= (lower.fee.numerator as u64) * (higher.fee.denominator as u64)
+			}),
+			Error::<T>::InvalidTiers
+		);
+
+		Ok(())
+	}
+
+	/// Return the highest discount tier of `who`. None if `who` has not locked enough for any tier.
+	pub fn tier_of(who: &T::AccountId) -> Option<DiscountTier> {
+		let locked = Self::locked(who);
+
+		if locked.is_zero() {
+			return None;
+		}
+
+		Self::tiers().into_iter().rev().find(|tier| tier.min_locked <= locked)
+	}
+}
+
+impl<T: Config> FeeDiscount<T::AccountId> for Pallet<T> {
+	fn trade_fee(who: &T::AccountId) -> Option<Fee> {
+		Self::tier_of(who).map(|tier| tier.fee)
+	}
+}
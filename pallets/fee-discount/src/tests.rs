@@ -0,0 +1,214 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+pub use crate::mock::{
+	Currency, Event as TestEvent, ExtBuilder, FeeDiscount, Origin, System, Test, ALICE, BOB, GOLD, HDX, ONE, SILVER,
+};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiReservableCurrency;
+use sp_runtime::DispatchError::BadOrigin;
+use test_utils::assert_balance;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn last_event() -> TestEvent {
+	frame_system::Pallet::<Test>::events()
+		.pop()
+		.expect("An event expected")
+		.event
+}
+
+#[test]
+fn lock_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(FeeDiscount::lock(Origin::signed(ALICE), 100 * ONE));
+
+		assert_eq!(FeeDiscount::locked(&ALICE), 100 * ONE);
+		assert_eq!(Currency::reserved_balance(HDX, &ALICE), 100 * ONE);
+		assert_balance!(Currency, ALICE, HDX, 9_900 * ONE);
+		assert_eq!(
+			last_event(),
+			TestEvent::fee_discount(Event::Locked(ALICE, 100 * ONE, 100 * ONE))
+		);
+
+		assert_ok!(FeeDiscount::lock(Origin::signed(ALICE), 50 * ONE));
+
+		assert_eq!(FeeDiscount::locked(&ALICE), 150 * ONE);
+		assert_eq!(
+			last_event(),
+			TestEvent::fee_discount(Event::Locked(ALICE, 50 * ONE, 150 * ONE))
+		);
+	});
+}
+
+#[test]
+fn lock_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(FeeDiscount::lock(Origin::signed(ALICE), 0), Error::<Test>::ZeroAmount);
+		assert_noop!(
+			FeeDiscount::lock(Origin::signed(BOB), 51 * ONE),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn unlock_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(FeeDiscount::lock(Origin::signed(ALICE), 100 * ONE));
+
+		assert_noop!(
+			FeeDiscount::unlock(Origin::signed(ALICE), 101 * ONE),
+			Error::<Test>::InsufficientLockedBalance
+		);
+		assert_noop!(FeeDiscount::unlock(Origin::signed(ALICE), 0), Error::<Test>::ZeroAmount);
+
+		assert_ok!(FeeDiscount::unlock(Origin::signed(ALICE), 40 * ONE));
+
+		assert_eq!(FeeDiscount::locked(&ALICE), 60 * ONE);
+		assert_eq!(Currency::reserved_balance(HDX, &ALICE), 60 * ONE);
+		assert_eq!(
+			last_event(),
+			TestEvent::fee_discount(Event::Unlocked(ALICE, 40 * ONE, 60 * ONE))
+		);
+
+		assert_ok!(FeeDiscount::unlock(Origin::signed(ALICE), 60 * ONE));
+
+		assert!(!Locked::<Test>::contains_key(&ALICE));
+		assert_balance!(Currency, ALICE, HDX, 10_000 * ONE);
+	});
+}
+
+#[test]
+fn tier_should_depend_on_locked_amount() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(FeeDiscount::tier_of(&ALICE), None);
+
+		assert_ok!(FeeDiscount::lock(Origin::signed(ALICE), 99 * ONE));
+		assert_eq!(FeeDiscount::tier_of(&ALICE), None);
+
+		assert_ok!(FeeDiscount::lock(Origin::signed(ALICE), ONE));
+		assert_eq!(FeeDiscount::tier_of(&ALICE), Some(SILVER));
+		assert_eq!(
+			<FeeDiscount as primitives::traits::FeeDiscount<_>>::trade_fee(&ALICE),
+			Some(SILVER.fee)
+		);
+
+		assert_ok!(FeeDiscount::lock(Origin::signed(ALICE), 2_000 * ONE));
+		assert_eq!(FeeDiscount::tier_of(&ALICE), Some(GOLD));
+
+		assert_ok!(FeeDiscount::unlock(Origin::signed(ALICE), 1_950 * ONE));
+		assert_eq!(FeeDiscount::tier_of(&ALICE), Some(SILVER));
+
+		// Locked balance is kept when tiers are removed
+		assert_ok!(FeeDiscount::set_tiers(Origin::root(), vec![]));
+		assert_eq!(FeeDiscount::tier_of(&ALICE), None);
+		assert_eq!(FeeDiscount::locked(&ALICE), 150 * ONE);
+	});
+}
+
+#[test]
+fn set_tiers_should_work() {
+	new_test_ext().execute_with(|| {
+		let tiers = vec![
+			DiscountTier {
+				min_locked: ONE,
+				fee: Fee {
+					numerator: 1,
+					denominator: 500,
+				},
+			},
+			SILVER,
+		];
+
+		assert_noop!(FeeDiscount::set_tiers(Origin::signed(ALICE), tiers.clone()), BadOrigin);
+
+		assert_ok!(FeeDiscount::set_tiers(Origin::root(), tiers.clone()));
+
+		assert_eq!(FeeDiscount::tiers(), tiers);
+		assert_eq!(last_event(), TestEvent::fee_discount(Event::TiersSet(tiers)));
+	});
+}
+
+#[test]
+fn set_tiers_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			FeeDiscount::set_tiers(Origin::root(), vec![SILVER; 5]),
+			Error::<Test>::TooManyTiers
+		);
+
+		// Not ordered by minimal locked amount
+		assert_noop!(
+			FeeDiscount::set_tiers(Origin::root(), vec![GOLD, SILVER]),
+			Error::<Test>::InvalidTiers
+		);
+		assert_noop!(
+			FeeDiscount::set_tiers(Origin::root(), vec![SILVER, SILVER]),
+			Error::<Test>::InvalidTiers
+		);
+
+		// Higher tier with higher fee
+		let worse_gold = DiscountTier {
+			min_locked: GOLD.min_locked,
+			fee: Fee {
+				numerator: 2,
+				denominator: 1_000,
+			},
+		};
+		assert_noop!(
+			FeeDiscount::set_tiers(Origin::root(), vec![SILVER, worse_gold]),
+			Error::<Test>::InvalidTiers
+		);
+
+		let zero_denominator = DiscountTier {
+			min_locked: ONE,
+			fee: Fee {
+				numerator: 0,
+				denominator: 0,
+			},
+		};
+		assert_noop!(
+			FeeDiscount::set_tiers(Origin::root(), vec![zero_denominator]),
+			Error::<Test>::InvalidTiers
+		);
+
+		let zero_min_locked = DiscountTier {
+			min_locked: 0,
+			fee: SILVER.fee,
+		};
+		assert_noop!(
+			FeeDiscount::set_tiers(Origin::root(), vec![zero_min_locked]),
+			Error::<Test>::InvalidTiers
+		);
+	});
+}
+
+#[test]
+fn locked_balance_should_not_be_transferable() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(FeeDiscount::lock(Origin::signed(BOB), 50 * ONE));
+
+		assert!(<Currency as orml_traits::MultiCurrency<_>>::transfer(HDX, &BOB, &ALICE, ONE).is_err());
+		assert_balance!(Currency, BOB, HDX, 0);
+	});
+}
@@ -0,0 +1,55 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_system::RawOrigin;
+use sp_runtime::FixedPointNumber;
+
+use crate::Pallet as FeeMultiplier;
+
+benchmarks! {
+	set_params {
+		let params = FeeMultiplierParams {
+			target_block_fullness: Perquintill::from_percent(50),
+			adjustment_variable: Multiplier::saturating_from_rational(1, 1_000),
+			minimum_multiplier: Multiplier::saturating_from_rational(1, 2),
+		};
+
+	}: _(RawOrigin::Root, params)
+	verify {
+		assert_eq!(FeeMultiplier::<T>::params(), params);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::Test;
+	use crate::tests::new_test_ext;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_set_params::<Test>());
+		});
+	}
+}
@@ -0,0 +1,189 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! # Fee Multiplier Pallet
+//!
+//! ## Overview
+//!
+//! Fee multiplier pallet keeps parameters of the targeted fee adjustment of transaction fees - target block
+//! fullness, adjustment variable and minimum multiplier - and lets `UpdateOrigin` tune them without a runtime
+//! upgrade.
+//!
+//! Multiplier is updated at the end of each block by `TargetedFeeAdjustment` of the transaction payment pallet.
+//! It grows when normal dispatch class of the block is fuller than the target and decreases otherwise,
+//! but never below the minimum multiplier.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{ensure, traits::Get};
+use pallet_transaction_payment::Multiplier;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{traits::Zero, Perquintill, RuntimeDebug};
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// Parameters of the targeted fee adjustment.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct FeeMultiplierParams {
+	/// Fullness of normal dispatch class of a block at which the multiplier does not change
+	pub target_block_fullness: Perquintill,
+	/// Speed of the multiplier adjustment relative to the difference of block fullness from the target
+	pub adjustment_variable: Multiplier,
+	/// Lower bound of the multiplier
+	pub minimum_multiplier: Multiplier,
+}
+
+/// Targeted fee adjustment of the transaction payment pallet using parameters kept in this pallet.
+pub type TargetedFeeAdjustment<T> = pallet_transaction_payment::TargetedFeeAdjustment<
+	T,
+	TargetBlockFullness<T>,
+	AdjustmentVariable<T>,
+	MinimumMultiplier<T>,
+>;
+
+/// Target block fullness set in this pallet.
+pub struct TargetBlockFullness<T>(PhantomData<T>);
+
+impl<T: Config> Get<Perquintill> for TargetBlockFullness<T> {
+	fn get() -> Perquintill {
+		Pallet::<T>::params().target_block_fullness
+	}
+}
+
+/// Adjustment variable set in this pallet.
+pub struct AdjustmentVariable<T>(PhantomData<T>);
+
+impl<T: Config> Get<Multiplier> for AdjustmentVariable<T> {
+	fn get() -> Multiplier {
+		Pallet::<T>::params().adjustment_variable
+	}
+}
+
+/// Minimum multiplier set in this pallet.
+pub struct MinimumMultiplier<T>(PhantomData<T>);
+
+impl<T: Config> Get<Multiplier> for MinimumMultiplier<T> {
+	fn get() -> Multiplier {
+		Pallet::<T>::params().minimum_multiplier
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Origin which can set the parameters
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Parameters used until they are set by `UpdateOrigin`
+		#[pallet::constant]
+		type DefaultParams: Get<FeeMultiplierParams>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Target block fullness, adjustment variable or minimum multiplier is zero.
+		InvalidParams,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Parameters of the fee adjustment were set. [params]
+		ParamsSet(FeeMultiplierParams),
+	}
+
+	#[pallet::type_value]
+	pub fn DefaultFeeMultiplierParams<T: Config>() -> FeeMultiplierParams {
+		T::DefaultParams::get()
+	}
+
+	/// Parameters of the fee adjustment.
+	#[pallet::storage]
+	#[pallet::getter(fn params)]
+	pub type Params<T: Config> = StorageValue<_, FeeMultiplierParams, ValueQuery, DefaultFeeMultiplierParams<T>>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set parameters of the fee adjustment.
+		///
+		/// New parameters are applied from the multiplier update at the end of the current block. Multiplier
+		/// lower than the new minimum is raised to it by the update.
+		///
+		/// Emits `ParamsSet` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::set_params())]
+		pub fn set_params(origin: OriginFor<T>, params: FeeMultiplierParams) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			Self::validate_params(&params)?;
+
+			Params::<T>::put(params);
+
+			Self::deposit_event(Event::ParamsSet(params));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Check that none of the parameters is zero.
+	///
+	/// Zero minimum multiplier would let the multiplier drop to zero, from which it never recovers.
+	fn validate_params(params: &FeeMultiplierParams) -> Result<(), Error<T>> {
+		ensure!(
+			!params.target_block_fullness.is_zero()
+				&& !params.adjustment_variable.is_zero()
+				&& !params.minimum_multiplier.is_zero(),
+			Error::<T>::InvalidParams
+		);
+
+		Ok(())
+	}
+}
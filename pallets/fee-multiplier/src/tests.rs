@@ -0,0 +1,147 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+pub use crate::mock::{
+	BlockWeights, DefaultParams, Event as TestEvent, ExtBuilder, FeeMultiplier, Origin, System, Test, ALICE,
+};
+use frame_support::{assert_noop, assert_ok, weights::DispatchClass};
+use sp_runtime::{traits::Convert, DispatchError::BadOrigin, FixedPointNumber};
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn last_event() -> TestEvent {
+	frame_system::Pallet::<Test>::events()
+		.pop()
+		.expect("An event expected")
+		.event
+}
+
+fn params(target_percent: u64, adjustment_variable: Multiplier, minimum_multiplier: Multiplier) -> FeeMultiplierParams {
+	FeeMultiplierParams {
+		target_block_fullness: Perquintill::from_percent(target_percent),
+		adjustment_variable,
+		minimum_multiplier,
+	}
+}
+
+/// Update `multiplier` as at the end of a block whose normal dispatch class is `fullness` full.
+fn next_multiplier(multiplier: Multiplier, fullness: Perquintill) -> Multiplier {
+	let max_normal = BlockWeights::get().get(DispatchClass::Normal).max_total.unwrap();
+
+	System::set_block_limits(fullness * max_normal, 0);
+
+	TargetedFeeAdjustment::<Test>::convert(multiplier)
+}
+
+#[test]
+fn default_params_should_be_used_until_set() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(FeeMultiplier::params(), DefaultParams::get());
+		assert_eq!(TargetBlockFullness::<Test>::get(), Perquintill::from_percent(25));
+		assert_eq!(
+			AdjustmentVariable::<Test>::get(),
+			Multiplier::saturating_from_rational(3, 10_000)
+		);
+		assert_eq!(MinimumMultiplier::<Test>::get(), Multiplier::from(1));
+	});
+}
+
+#[test]
+fn set_params_should_work() {
+	new_test_ext().execute_with(|| {
+		let new_params = params(50, Multiplier::saturating_from_rational(1, 1_000), Multiplier::from(2));
+
+		assert_ok!(FeeMultiplier::set_params(Origin::root(), new_params));
+
+		assert_eq!(FeeMultiplier::params(), new_params);
+		assert_eq!(last_event(), Event::ParamsSet(new_params).into());
+	});
+}
+
+#[test]
+fn set_params_should_not_work_with_invalid_params() {
+	new_test_ext().execute_with(|| {
+		let one = Multiplier::from(1);
+
+		assert_noop!(
+			FeeMultiplier::set_params(Origin::signed(ALICE), params(50, one, one)),
+			BadOrigin
+		);
+		assert_noop!(
+			FeeMultiplier::set_params(Origin::root(), params(0, one, one)),
+			Error::<Test>::InvalidParams
+		);
+		assert_noop!(
+			FeeMultiplier::set_params(Origin::root(), params(50, Multiplier::zero(), one)),
+			Error::<Test>::InvalidParams
+		);
+		assert_noop!(
+			FeeMultiplier::set_params(Origin::root(), params(50, one, Multiplier::zero())),
+			Error::<Test>::InvalidParams
+		);
+	});
+}
+
+#[test]
+fn multiplier_should_follow_block_fullness_relative_to_target() {
+	new_test_ext().execute_with(|| {
+		let multiplier = Multiplier::from(2);
+
+		assert!(next_multiplier(multiplier, Perquintill::from_percent(100)) > multiplier);
+		assert_eq!(next_multiplier(multiplier, Perquintill::from_percent(25)), multiplier);
+		assert!(next_multiplier(multiplier, Perquintill::from_percent(10)) < multiplier);
+	});
+}
+
+#[test]
+fn multiplier_should_use_params_set_by_governance() {
+	new_test_ext().execute_with(|| {
+		let one = Multiplier::from(1);
+		let full = Perquintill::from_percent(100);
+
+		let default_growth = next_multiplier(one, full);
+
+		assert_ok!(FeeMultiplier::set_params(
+			Origin::root(),
+			params(50, Multiplier::saturating_from_rational(3, 1_000), Multiplier::from(2))
+		));
+
+		// Multiplier below the new minimum is raised to it
+		assert_eq!(next_multiplier(one, Perquintill::from_percent(50)), Multiplier::from(2));
+
+		// Faster adjustment
+		assert!(next_multiplier(Multiplier::from(2), full) - Multiplier::from(2) > default_growth - one);
+	});
+}
+
+#[test]
+fn multiplier_should_never_fall_below_minimum() {
+	new_test_ext().execute_with(|| {
+		let minimum = MinimumMultiplier::<Test>::get();
+
+		let multiplier = (0..20_000).fold(Multiplier::from(2), |multiplier, _| {
+			next_multiplier(multiplier, Perquintill::zero())
+		});
+
+		assert_eq!(multiplier, minimum);
+	});
+}
@@ -0,0 +1,34 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for inflation pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+pub use pallet_inflation::{PoolRewardProjection, RewardProjection, RewardSimulationParams};
+
+sp_api::decl_runtime_apis! {
+	pub trait InflationApi<PoolId> where
+		PoolId: Codec,
+	{
+		/// Simulate yearly rewards and APRs of hypothetical inflation parameters against current stake
+		/// and pool liquidity.
+		fn simulate_rewards(params: RewardSimulationParams<PoolId>) -> Option<RewardProjection<PoolId>>;
+	}
+}
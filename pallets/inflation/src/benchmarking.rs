@@ -0,0 +1,56 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+use crate::Pallet as Inflation;
+
+benchmarks! {
+	set_schedule {
+		let schedule = InflationSchedule {
+			initial_rate: Perbill::from_percent(8),
+			step_decay: Perbill::from_percent(1),
+			step_duration: MILLISECS_PER_YEAR,
+			minimum_rate: Perbill::from_percent(3),
+			stakers_share: Perbill::from_percent(50),
+		};
+
+	}: _(RawOrigin::Root, schedule)
+	verify {
+		assert_eq!(Inflation::<T>::schedule(), schedule);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_set_schedule::<Test>());
+		});
+	}
+}
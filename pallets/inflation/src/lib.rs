@@ -0,0 +1,295 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Inflation Pallet
+//!
+//! ## Overview
+//!
+//! Inflation pallet calculates staking rewards following HydraDX inflation schedule.
+//!
+//! Yearly inflation rate decreases by a fixed step decay after each step of the schedule until it reaches
+//! the minimum rate. Schedule parameters can be changed by governance.
+//!
+//! Rewards of a hypothetical schedule can be simulated against current stake and pool liquidity so governance
+//! proposals can reference projected APRs computed by the chain.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::ensure;
+use primitives::Balance;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
+	traits::{Saturating, Zero},
+	FixedPointNumber, FixedU128, Perbill, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// Number of milliseconds in a year.
+pub const MILLISECS_PER_YEAR: u64 = 1000 * 3600 * 24 * 36525 / 100;
+
+/// Inflation schedule with fixed-step decay of yearly inflation rate.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct InflationSchedule {
+	/// Yearly inflation rate of the first step
+	pub initial_rate: Perbill,
+	/// Decrease of yearly inflation rate after each step
+	pub step_decay: Perbill,
+	/// Duration of a step in milliseconds
+	pub step_duration: u64,
+	/// Yearly inflation rate which is not decreased anymore
+	pub minimum_rate: Perbill,
+	/// Part of the inflation paid to stakers, the rest is sent to treasury
+	pub stakers_share: Perbill,
+}
+
+impl InflationSchedule {
+	/// Return yearly inflation rate after `elapsed` milliseconds.
+	pub fn rate_at(&self, elapsed: u64) -> Perbill {
+		let steps = elapsed.checked_div(self.step_duration).unwrap_or_default();
+		let decrease = (self.step_decay.deconstruct() as u64).saturating_mul(steps);
+		let rate = (self.initial_rate.deconstruct() as u64).saturating_sub(decrease);
+
+		Perbill::from_parts(rate as u32).max(self.minimum_rate)
+	}
+
+	/// Check that schedule parameters are consistent.
+	pub fn is_valid(&self) -> bool {
+		self.step_duration > 0 && self.minimum_rate <= self.initial_rate
+	}
+}
+
+/// Hypothetical reward parameters to be simulated.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct RewardSimulationParams<PoolId> {
+	/// Inflation schedule to be simulated
+	pub schedule: InflationSchedule,
+	/// Part of the inflation distributed to liquidity pools
+	pub pools_share: Perbill,
+	/// Pools and their weights in the distribution of the pools share
+	pub pool_weights: Vec<(PoolId, u32)>,
+}
+
+/// Projected yearly rewards of a pool.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct PoolRewardProjection<PoolId> {
+	/// Pool identifier
+	pub pool: PoolId,
+	/// Projected yearly rewards of the pool
+	pub rewards: Balance,
+	/// Current value of liquidity in the pool
+	pub tvl: Balance,
+	/// Projected APR, `None` if there is no liquidity in the pool
+	pub apr: Option<FixedU128>,
+}
+
+/// Projected yearly rewards and APRs of a simulated schedule.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct RewardProjection<PoolId> {
+	/// Yearly inflation rate at the current step of the schedule
+	pub rate: Perbill,
+	/// Projected yearly emission
+	pub yearly_emission: Balance,
+	/// Projected yearly rewards of stakers
+	pub stakers_rewards: Balance,
+	/// Projected staking APR, `None` if nothing is staked
+	pub staking_apr: Option<FixedU128>,
+	/// Projected rewards of pools
+	pub pools: Vec<PoolRewardProjection<PoolId>>,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Inflation schedule used until changed by governance
+		#[pallet::constant]
+		type DefaultSchedule: Get<InflationSchedule>;
+
+		/// Origin which can change the inflation schedule
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Step duration is zero or minimum rate is above initial rate.
+		InvalidSchedule,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event {
+		/// Inflation schedule was changed. [schedule]
+		ScheduleSet(InflationSchedule),
+	}
+
+	#[pallet::type_value]
+	pub fn DefaultInflationSchedule<T: Config>() -> InflationSchedule {
+		T::DefaultSchedule::get()
+	}
+
+	/// Current inflation schedule.
+	#[pallet::storage]
+	#[pallet::getter(fn schedule)]
+	pub type Schedule<T: Config> = StorageValue<_, InflationSchedule, ValueQuery, DefaultInflationSchedule<T>>;
+
+	/// Milliseconds elapsed in all paid out eras.
+	#[pallet::storage]
+	#[pallet::getter(fn elapsed_time)]
+	pub type ElapsedTime<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set new inflation schedule.
+		///
+		/// Current step of the new schedule is determined by time elapsed since the first era payout.
+		///
+		/// Emits `ScheduleSet` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::set_schedule())]
+		pub fn set_schedule(origin: OriginFor<T>, schedule: InflationSchedule) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			ensure!(schedule.is_valid(), Error::<T>::InvalidSchedule);
+
+			<Schedule<T>>::put(schedule);
+
+			Self::deposit_event(Event::ScheduleSet(schedule));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Return payout to stakers and remainder of an era which lasted `era_duration_millis`.
+	///
+	/// Rate of the step in which the era started is used. Duration of the era is added to the elapsed time.
+	pub fn era_payout(total_issuance: Balance, era_duration_millis: u64) -> (Balance, Balance) {
+		let schedule = Self::schedule();
+		let elapsed = Self::elapsed_time();
+
+		<ElapsedTime<T>>::put(elapsed.saturating_add(era_duration_millis));
+
+		let yearly_payout = schedule.rate_at(elapsed) * total_issuance;
+		let payout = multiply_by_rational(
+			yearly_payout,
+			era_duration_millis as Balance,
+			MILLISECS_PER_YEAR as Balance,
+		)
+		.unwrap_or_default();
+
+		let stakers_payout = schedule.stakers_share * payout;
+
+		(stakers_payout, payout.saturating_sub(stakers_payout))
+	}
+
+	/// Simulate yearly rewards of a hypothetical schedule against current stake and pool liquidity.
+	///
+	/// Rate of the step given by the elapsed time of the current schedule is used. Pools share is distributed
+	/// to pools proportionally to their weights. `pool_tvl` returns current value of liquidity in a pool
+	/// denominated in the same asset as `total_issuance`.
+	///
+	/// Complexity: O(P) where P is the number of simulated pools.
+	///
+	/// Returns `None` if the schedule is invalid or stakers and pools shares exceed the whole inflation.
+	pub fn simulate_rewards<PoolId>(
+		params: RewardSimulationParams<PoolId>,
+		total_issuance: Balance,
+		total_staked: Balance,
+		pool_tvl: impl Fn(&PoolId) -> Balance,
+	) -> Option<RewardProjection<PoolId>> {
+		let schedule = params.schedule;
+
+		let shares = schedule
+			.stakers_share
+			.deconstruct()
+			.saturating_add(params.pools_share.deconstruct());
+
+		if !schedule.is_valid() || shares > Perbill::from_percent(100).deconstruct() {
+			return None;
+		}
+
+		let rate = schedule.rate_at(Self::elapsed_time());
+		let yearly_emission = rate * total_issuance;
+		let stakers_rewards = schedule.stakers_share * yearly_emission;
+		let pools_rewards = params.pools_share * yearly_emission;
+
+		let total_weight = params
+			.pool_weights
+			.iter()
+			.fold(0u128, |acc, (_, weight)| acc.saturating_add(*weight as u128));
+
+		let pools = params
+			.pool_weights
+			.into_iter()
+			.map(|(pool, weight)| {
+				let rewards = if total_weight.is_zero() {
+					Balance::zero()
+				} else {
+					multiply_by_rational(pools_rewards, weight as u128, total_weight).unwrap_or_default()
+				};
+				let tvl = pool_tvl(&pool);
+
+				PoolRewardProjection {
+					pool,
+					rewards,
+					tvl,
+					apr: FixedU128::checked_from_rational(rewards, tvl),
+				}
+			})
+			.collect();
+
+		Some(RewardProjection {
+			rate,
+			yearly_emission,
+			stakers_rewards,
+			staking_apr: FixedU128::checked_from_rational(stakers_rewards, total_staked),
+			pools,
+		})
+	}
+}
@@ -0,0 +1,233 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+pub use crate::mock::{Event as TestEvent, ExtBuilder, Inflation, Origin, System, Test, ALICE, STEP};
+use frame_support::{assert_noop, assert_ok};
+
+const TOTAL_ISSUANCE: Balance = 1_000_000_000_000_000_000;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn last_events(n: usize) -> Vec<TestEvent> {
+	frame_system::Pallet::<Test>::events()
+		.into_iter()
+		.rev()
+		.take(n)
+		.rev()
+		.map(|e| e.event)
+		.collect()
+}
+
+fn expect_events(e: Vec<TestEvent>) {
+	assert_eq!(last_events(e.len()), e);
+}
+
+fn schedule() -> InflationSchedule {
+	InflationSchedule {
+		initial_rate: Perbill::from_percent(8),
+		step_decay: Perbill::from_percent(1),
+		step_duration: STEP,
+		minimum_rate: Perbill::from_percent(3),
+		stakers_share: Perbill::from_percent(50),
+	}
+}
+
+#[test]
+fn rate_should_decay_by_steps() {
+	new_test_ext().execute_with(|| {
+		let schedule = Inflation::schedule();
+
+		assert_eq!(schedule.rate_at(0), Perbill::from_percent(10));
+		assert_eq!(schedule.rate_at(STEP - 1), Perbill::from_percent(10));
+		assert_eq!(schedule.rate_at(STEP), Perbill::from_percent(7));
+		assert_eq!(schedule.rate_at(2 * STEP + 1), Perbill::from_percent(4));
+		assert_eq!(schedule.rate_at(3 * STEP), Perbill::from_percent(2));
+		assert_eq!(schedule.rate_at(100 * STEP), Perbill::from_percent(2));
+		assert_eq!(schedule.rate_at(u64::MAX), Perbill::from_percent(2));
+	});
+}
+
+#[test]
+fn era_payout_should_work() {
+	new_test_ext().execute_with(|| {
+		let era_duration = STEP / 4;
+
+		for _ in 0..4 {
+			assert_eq!(
+				Inflation::era_payout(TOTAL_ISSUANCE, era_duration),
+				(20_000_000_000_000_000, 5_000_000_000_000_000)
+			);
+		}
+
+		assert_eq!(Inflation::elapsed_time(), STEP);
+
+		assert_eq!(
+			Inflation::era_payout(TOTAL_ISSUANCE, era_duration),
+			(14_000_000_000_000_000, 3_500_000_000_000_000)
+		);
+
+		assert_eq!(Inflation::elapsed_time(), STEP + era_duration);
+	});
+}
+
+#[test]
+fn set_schedule_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Inflation::set_schedule(Origin::root(), schedule()));
+
+		assert_eq!(Inflation::schedule(), schedule());
+
+		expect_events(vec![Event::ScheduleSet(schedule()).into()]);
+
+		assert_eq!(
+			Inflation::era_payout(TOTAL_ISSUANCE, STEP),
+			(40_000_000_000_000_000, 40_000_000_000_000_000)
+		);
+		assert_eq!(
+			Inflation::era_payout(TOTAL_ISSUANCE, STEP),
+			(35_000_000_000_000_000, 35_000_000_000_000_000)
+		);
+	});
+}
+
+#[test]
+fn set_schedule_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Inflation::set_schedule(Origin::signed(ALICE), schedule()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_noop!(
+			Inflation::set_schedule(
+				Origin::root(),
+				InflationSchedule {
+					step_duration: 0,
+					..schedule()
+				}
+			),
+			Error::<Test>::InvalidSchedule
+		);
+
+		assert_noop!(
+			Inflation::set_schedule(
+				Origin::root(),
+				InflationSchedule {
+					minimum_rate: Perbill::from_percent(9),
+					..schedule()
+				}
+			),
+			Error::<Test>::InvalidSchedule
+		);
+	});
+}
+
+#[test]
+fn simulate_rewards_should_work() {
+	new_test_ext().execute_with(|| {
+		let params = RewardSimulationParams {
+			schedule: schedule(),
+			pools_share: Perbill::from_percent(30),
+			pool_weights: vec![(1u32, 1), (2u32, 3), (3u32, 0)],
+		};
+
+		let pool_tvl = |pool: &u32| match pool {
+			1 => 60_000_000_000_000_000,
+			3 => 10_000_000_000_000_000,
+			_ => 0,
+		};
+
+		assert_eq!(
+			Inflation::simulate_rewards(params, TOTAL_ISSUANCE, 250_000_000_000_000_000, pool_tvl),
+			Some(RewardProjection {
+				rate: Perbill::from_percent(8),
+				yearly_emission: 80_000_000_000_000_000,
+				stakers_rewards: 40_000_000_000_000_000,
+				staking_apr: Some(FixedU128::saturating_from_rational(16, 100)),
+				pools: vec![
+					PoolRewardProjection {
+						pool: 1,
+						rewards: 6_000_000_000_000_000,
+						tvl: 60_000_000_000_000_000,
+						apr: Some(FixedU128::saturating_from_rational(10, 100)),
+					},
+					PoolRewardProjection {
+						pool: 2,
+						rewards: 18_000_000_000_000_000,
+						tvl: 0,
+						apr: None,
+					},
+					PoolRewardProjection {
+						pool: 3,
+						rewards: 0,
+						tvl: 10_000_000_000_000_000,
+						apr: Some(FixedU128::zero()),
+					},
+				],
+			})
+		);
+	});
+}
+
+#[test]
+fn simulate_rewards_should_use_elapsed_time() {
+	new_test_ext().execute_with(|| {
+		Inflation::era_payout(TOTAL_ISSUANCE, STEP);
+
+		let params = RewardSimulationParams::<u32> {
+			schedule: schedule(),
+			pools_share: Perbill::from_percent(0),
+			pool_weights: vec![],
+		};
+
+		let projection = Inflation::simulate_rewards(params, TOTAL_ISSUANCE, 0, |_| 0).unwrap();
+
+		assert_eq!(projection.rate, Perbill::from_percent(7));
+		assert_eq!(projection.yearly_emission, 70_000_000_000_000_000);
+		assert_eq!(projection.staking_apr, None);
+		assert!(projection.pools.is_empty());
+	});
+}
+
+#[test]
+fn simulate_rewards_should_reject_invalid_params() {
+	new_test_ext().execute_with(|| {
+		let params = RewardSimulationParams::<u32> {
+			schedule: schedule(),
+			pools_share: Perbill::from_percent(60),
+			pool_weights: vec![(1, 1)],
+		};
+
+		assert_eq!(Inflation::simulate_rewards(params, TOTAL_ISSUANCE, 1, |_| 1), None);
+
+		let params = RewardSimulationParams::<u32> {
+			schedule: InflationSchedule {
+				step_duration: 0,
+				..schedule()
+			},
+			pools_share: Perbill::from_percent(10),
+			pool_weights: vec![(1, 1)],
+		};
+
+		assert_eq!(Inflation::simulate_rewards(params, TOTAL_ISSUANCE, 1, |_| 1), None);
+	});
+}
@@ -0,0 +1,117 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use orml_traits::MultiCurrency;
+use sp_std::prelude::*;
+
+use crate::Pallet as Listing;
+
+const SEED: u32 = 1;
+
+const PAIRED_ASSET: AssetId = 1;
+const AMOUNT: Balance = 1_000_000_000_000;
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let caller: T::AccountId = account(name, index, SEED);
+	T::MultiCurrency::deposit(PAIRED_ASSET, &caller, 1_000_000_000_000_000).unwrap();
+	caller
+}
+
+fn propose_listing<T: Config>(proposer: &T::AccountId, name: Vec<u8>) -> Result<ListingId, DispatchError> {
+	let listing_id = Listing::<T>::next_listing_id();
+	Listing::<T>::propose_listing(
+		RawOrigin::Signed(proposer.clone()).into(),
+		name,
+		AMOUNT,
+		PAIRED_ASSET,
+		AMOUNT,
+	)
+	.map_err(|e| e.error)?;
+	Ok(listing_id)
+}
+
+benchmarks! {
+	propose_listing {
+		let caller = funded_account::<T>("caller", 0);
+
+	}: _(RawOrigin::Signed(caller.clone()), b"NEW".to_vec(), AMOUNT, PAIRED_ASSET, AMOUNT)
+	verify {
+		assert_eq!(T::MultiCurrency::reserved_balance(PAIRED_ASSET, &caller), AMOUNT);
+	}
+
+	cancel_listing {
+		let caller = funded_account::<T>("caller", 0);
+		let listing_id = propose_listing::<T>(&caller, b"NEW".to_vec())?;
+
+	}: _(RawOrigin::Signed(caller.clone()), listing_id)
+	verify {
+		assert_eq!(T::MultiCurrency::reserved_balance(PAIRED_ASSET, &caller), 0);
+	}
+
+	reject_listing {
+		let caller = funded_account::<T>("caller", 0);
+		let listing_id = propose_listing::<T>(&caller, b"NEW".to_vec())?;
+
+	}: _(RawOrigin::Root, listing_id)
+	verify {
+		assert!(Listing::<T>::listings(listing_id).is_none());
+	}
+
+	approve_listing {
+		let caller = funded_account::<T>("caller", 0);
+		let listing_id = propose_listing::<T>(&caller, b"NEW".to_vec())?;
+
+	}: _(RawOrigin::Root, listing_id)
+	verify {
+		assert!(matches!(Listing::<T>::listings(listing_id).map(|l| l.status), Some(ListingStatus::Bootstrap(..))));
+	}
+
+	open_trading {
+		let caller = funded_account::<T>("caller", 0);
+		let listing_id = propose_listing::<T>(&caller, b"NEW".to_vec())?;
+		Listing::<T>::approve_listing(RawOrigin::Root.into(), listing_id).map_err(|e| e.error)?;
+
+		frame_system::Pallet::<T>::set_block_number(T::BootstrapPeriod::get().saturating_add(1u32.into()));
+
+	}: _(RawOrigin::Signed(caller), listing_id)
+	verify {
+		assert!(Listing::<T>::listings(listing_id).is_none());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_propose_listing::<Test>());
+			assert_ok!(test_benchmark_cancel_listing::<Test>());
+			assert_ok!(test_benchmark_reject_listing::<Test>());
+			assert_ok!(test_benchmark_approve_listing::<Test>());
+			assert_ok!(test_benchmark_open_trading::<Test>());
+		});
+	}
+}
@@ -0,0 +1,397 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! # Listing Pallet
+//!
+//! ## Overview
+//!
+//! Listing pallet provides council managed process of listing new assets.
+//!
+//! Proposer submits asset metadata together with seed liquidity - amount of the new asset to be issued
+//! into the pool and amount of paired asset which is reserved until the proposal is decided.
+//!
+//! Once approved by the council, the asset is registered in the asset registry, new pool is created with
+//! the seed liquidity and trading is restricted to holders of bootstrap access asset for the bootstrap period.
+//! After the bootstrap period anyone can open the pool for trading.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{ensure, transactional};
+use frame_system::ensure_signed;
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use primitives::{
+	asset::AssetPair,
+	audit::PrivilegedAction,
+	traits::{AuditLog, AMM},
+	AssetId, Balance, Price,
+};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchError, FixedPointNumber, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// Listing identifier
+pub type ListingId = u32;
+
+/// Stage of the listing.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum ListingStatus<BlockNumber> {
+	/// Listing waits for council decision
+	Proposed,
+	/// Pool of registered asset is in bootstrap phase until given block
+	Bootstrap(AssetId, BlockNumber),
+}
+
+/// Proposal of a new asset listing.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Listing<AccountId, BlockNumber> {
+	/// Account which proposed the listing and provides seed liquidity
+	pub proposer: AccountId,
+	/// Name of the asset to be registered
+	pub name: Vec<u8>,
+	/// Amount of the new asset issued into the pool
+	pub amount: Balance,
+	/// Asset the new asset is paired with
+	pub paired_asset: AssetId,
+	/// Amount of paired asset reserved as seed liquidity
+	pub paired_amount: Balance,
+	/// Current stage of the listing
+	pub status: ListingStatus<BlockNumber>,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_xyk::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Multi currency for reserving seed liquidity and issuing listed assets
+		type MultiCurrency: MultiReservableCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// Origin which approves or rejects listings
+		type ListingOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Asset required to trade in a pool during its bootstrap phase
+		#[pallet::constant]
+		type BootstrapAccessAsset: Get<AssetId>;
+
+		/// Duration of the bootstrap phase
+		#[pallet::constant]
+		type BootstrapPeriod: Get<Self::BlockNumber>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// It is not allowed to propose a listing without seed liquidity.
+		ZeroSeedLiquidity,
+
+		/// Asset with given name is already registered.
+		AssetAlreadyRegistered,
+
+		/// Listing does not exist.
+		ListingNotFound,
+
+		/// Listing is not in the stage required by the action.
+		InvalidListingStatus,
+
+		/// Account is not allowed to perform this action on the listing.
+		NotAllowed,
+
+		/// Initial price of the pool cannot be determined from seed liquidity.
+		InvalidInitialPrice,
+
+		/// Bootstrap phase of the pool has not finished yet.
+		BootstrapNotFinished,
+
+		/// Overflow
+		ListingIdOverflow,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Listing was proposed. [listing id, proposer, name, paired asset]
+		ListingProposed(ListingId, T::AccountId, Vec<u8>, AssetId),
+
+		/// Listing was cancelled by proposer. [listing id]
+		ListingCancelled(ListingId),
+
+		/// Listing was rejected. [listing id]
+		ListingRejected(ListingId),
+
+		/// Listing was approved. [listing id]
+		ListingApproved(ListingId),
+
+		/// Listed asset was registered. [listing id, asset id]
+		AssetRegistered(ListingId, AssetId),
+
+		/// Pool of listed asset was created and entered bootstrap phase. [listing id, pool, bootstrap end]
+		BootstrapStarted(ListingId, T::AccountId, T::BlockNumber),
+
+		/// Pool of listed asset was opened for trading. [listing id, pool]
+		TradingOpened(ListingId, T::AccountId),
+	}
+
+	/// Next available listing id.
+	#[pallet::storage]
+	#[pallet::getter(fn next_listing_id)]
+	pub type NextListingId<T: Config> = StorageValue<_, ListingId, ValueQuery>;
+
+	/// Listings which are proposed or in bootstrap phase.
+	#[pallet::storage]
+	#[pallet::getter(fn listings)]
+	pub type Listings<T: Config> =
+		StorageMap<_, Blake2_128Concat, ListingId, Listing<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Propose listing of new asset with given `name`.
+		///
+		/// `amount` of the new asset is issued into the pool once the listing is approved.
+		/// `paired_amount` of `paired_asset` is reserved from origin's balance as the other side of seed liquidity.
+		///
+		/// Emits `ListingProposed` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::propose_listing())]
+		#[transactional]
+		pub fn propose_listing(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			amount: Balance,
+			paired_asset: AssetId,
+			paired_amount: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				!amount.is_zero() && !paired_amount.is_zero(),
+				Error::<T>::ZeroSeedLiquidity
+			);
+			ensure!(
+				pallet_asset_registry::Pallet::<T>::asset_by_name(&name).is_none(),
+				Error::<T>::AssetAlreadyRegistered
+			);
+
+			let listing_id = Self::next_listing_id();
+			let next_id = listing_id.checked_add(1).ok_or(Error::<T>::ListingIdOverflow)?;
+
+			T::MultiCurrency::reserve(paired_asset, &who, paired_amount)?;
+
+			<Listings<T>>::insert(
+				listing_id,
+				Listing {
+					proposer: who.clone(),
+					name: name.clone(),
+					amount,
+					paired_asset,
+					paired_amount,
+					status: ListingStatus::Proposed,
+				},
+			);
+			<NextListingId<T>>::put(next_id);
+
+			Self::deposit_event(Event::ListingProposed(listing_id, who, name, paired_asset));
+
+			Ok(().into())
+		}
+
+		/// Cancel proposed listing and return reserved seed liquidity.
+		///
+		/// Can be performed only by the proposer before the listing is decided.
+		///
+		/// Emits `ListingCancelled` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_listing())]
+		#[transactional]
+		pub fn cancel_listing(origin: OriginFor<T>, listing_id: ListingId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let listing = Self::proposed_listing(listing_id)?;
+
+			ensure!(listing.proposer == who, Error::<T>::NotAllowed);
+
+			T::MultiCurrency::unreserve(listing.paired_asset, &listing.proposer, listing.paired_amount);
+
+			<Listings<T>>::remove(listing_id);
+
+			Self::deposit_event(Event::ListingCancelled(listing_id));
+
+			Ok(().into())
+		}
+
+		/// Reject proposed listing and return reserved seed liquidity.
+		///
+		/// Can be performed only by `ListingOrigin`.
+		///
+		/// Emits `ListingRejected` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::reject_listing())]
+		#[transactional]
+		pub fn reject_listing(origin: OriginFor<T>, listing_id: ListingId) -> DispatchResultWithPostInfo {
+			T::ListingOrigin::ensure_origin(origin)?;
+
+			let listing = Self::proposed_listing(listing_id)?;
+
+			T::MultiCurrency::unreserve(listing.paired_asset, &listing.proposer, listing.paired_amount);
+
+			<Listings<T>>::remove(listing_id);
+
+			Self::deposit_event(Event::ListingRejected(listing_id));
+
+			<T as pallet_xyk::Config>::AuditLog::record(None, PrivilegedAction::ListingRejected(listing_id));
+
+			Ok(().into())
+		}
+
+		/// Approve proposed listing.
+		///
+		/// Registers the asset, issues its seed amount to the proposer and creates pool with seed liquidity
+		/// provided by the proposer. Trading in the pool is restricted to holders of `BootstrapAccessAsset`
+		/// until the end of bootstrap period.
+		///
+		/// Can be performed only by `ListingOrigin`.
+		///
+		/// Emits `ListingApproved`, `AssetRegistered` and `BootstrapStarted` events when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::approve_listing())]
+		#[transactional]
+		pub fn approve_listing(origin: OriginFor<T>, listing_id: ListingId) -> DispatchResultWithPostInfo {
+			T::ListingOrigin::ensure_origin(origin)?;
+
+			let mut listing = Self::proposed_listing(listing_id)?;
+
+			Self::deposit_event(Event::ListingApproved(listing_id));
+
+			ensure!(
+				pallet_asset_registry::Pallet::<T>::asset_by_name(&listing.name).is_none(),
+				Error::<T>::AssetAlreadyRegistered
+			);
+
+			let asset: AssetId = pallet_asset_registry::Pallet::<T>::get_or_create_asset(listing.name.clone())?.into();
+
+			Self::deposit_event(Event::AssetRegistered(listing_id, asset));
+
+			let initial_price = Price::checked_from_rational(listing.paired_amount, listing.amount)
+				.ok_or(Error::<T>::InvalidInitialPrice)?;
+
+			T::MultiCurrency::unreserve(listing.paired_asset, &listing.proposer, listing.paired_amount);
+			T::MultiCurrency::deposit(asset, &listing.proposer, listing.amount)?;
+
+			pallet_xyk::Pallet::<T>::do_create_pool(
+				listing.proposer.clone(),
+				asset,
+				listing.paired_asset,
+				listing.amount,
+				initial_price,
+			)?;
+
+			let pool = pallet_xyk::Pallet::<T>::get_pair_id(AssetPair {
+				asset_in: asset,
+				asset_out: listing.paired_asset,
+			});
+
+			pallet_xyk::Pallet::<T>::set_access_asset(&pool, Some(T::BootstrapAccessAsset::get()));
+
+			let bootstrap_end = <frame_system::Pallet<T>>::block_number().saturating_add(T::BootstrapPeriod::get());
+
+			listing.status = ListingStatus::Bootstrap(asset, bootstrap_end);
+			<Listings<T>>::insert(listing_id, listing);
+
+			Self::deposit_event(Event::BootstrapStarted(listing_id, pool, bootstrap_end));
+
+			<T as pallet_xyk::Config>::AuditLog::record(None, PrivilegedAction::ListingApproved(listing_id));
+
+			Ok(().into())
+		}
+
+		/// Open pool of listed asset for trading once its bootstrap phase has finished.
+		///
+		/// Can be performed by anyone.
+		///
+		/// Emits `TradingOpened` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::open_trading())]
+		#[transactional]
+		pub fn open_trading(origin: OriginFor<T>, listing_id: ListingId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let listing = Self::listings(listing_id).ok_or(Error::<T>::ListingNotFound)?;
+
+			let (asset, bootstrap_end) = match listing.status {
+				ListingStatus::Bootstrap(asset, bootstrap_end) => (asset, bootstrap_end),
+				_ => return Err(Error::<T>::InvalidListingStatus.into()),
+			};
+
+			ensure!(
+				bootstrap_end <= <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::BootstrapNotFinished
+			);
+
+			let pool = pallet_xyk::Pallet::<T>::get_pair_id(AssetPair {
+				asset_in: asset,
+				asset_out: listing.paired_asset,
+			});
+
+			pallet_xyk::Pallet::<T>::set_access_asset(&pool, None);
+
+			<Listings<T>>::remove(listing_id);
+
+			Self::deposit_event(Event::TradingOpened(listing_id, pool));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Return listing which waits for council decision.
+	fn proposed_listing(listing_id: ListingId) -> Result<Listing<T::AccountId, T::BlockNumber>, DispatchError> {
+		let listing = Self::listings(listing_id).ok_or(Error::<T>::ListingNotFound)?;
+
+		ensure!(
+			listing.status == ListingStatus::Proposed,
+			Error::<T>::InvalidListingStatus
+		);
+
+		Ok(listing)
+	}
+}
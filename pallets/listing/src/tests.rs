@@ -0,0 +1,274 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+pub use crate::mock::{
+	AccountId, AssetRegistry, Currency, Event as TestEvent, ExtBuilder, Listing as ListingPallet, Origin, System, Test,
+	ACCESS, ALICE, BOB, DOT, XYK,
+};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError::BadOrigin;
+use test_utils::assert_event_emitted;
+
+const NAME: &[u8] = b"NEW";
+const NEW_ASSET: AssetId = 3;
+const AMOUNT: Balance = 1_000_000;
+const PAIRED_AMOUNT: Balance = 2_000_000;
+const INITIAL_BALANCE: Balance = 1_000_000_000_000_000;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn last_events(n: usize) -> Vec<TestEvent> {
+	frame_system::Pallet::<Test>::events()
+		.into_iter()
+		.rev()
+		.take(n)
+		.rev()
+		.map(|e| e.event)
+		.collect()
+}
+
+fn expect_events(e: Vec<TestEvent>) {
+	assert_eq!(last_events(e.len()), e);
+}
+
+fn propose() -> ListingId {
+	let listing_id = ListingPallet::next_listing_id();
+	assert_ok!(ListingPallet::propose_listing(
+		Origin::signed(ALICE),
+		NAME.to_vec(),
+		AMOUNT,
+		DOT,
+		PAIRED_AMOUNT
+	));
+	listing_id
+}
+
+fn new_pool() -> AccountId {
+	XYK::get_pair_id(AssetPair {
+		asset_in: NEW_ASSET,
+		asset_out: DOT,
+	})
+}
+
+#[test]
+fn propose_listing_should_work() {
+	new_test_ext().execute_with(|| {
+		let listing_id = propose();
+
+		assert_eq!(
+			ListingPallet::listings(listing_id),
+			Some(Listing {
+				proposer: ALICE,
+				name: NAME.to_vec(),
+				amount: AMOUNT,
+				paired_asset: DOT,
+				paired_amount: PAIRED_AMOUNT,
+				status: ListingStatus::Proposed,
+			})
+		);
+		assert_eq!(ListingPallet::next_listing_id(), listing_id + 1);
+		assert_eq!(Currency::reserved_balance(DOT, &ALICE), PAIRED_AMOUNT);
+
+		expect_events(vec![
+			Event::ListingProposed(listing_id, ALICE, NAME.to_vec(), DOT).into()
+		]);
+	});
+}
+
+#[test]
+fn propose_listing_with_invalid_params_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ListingPallet::propose_listing(Origin::signed(ALICE), NAME.to_vec(), 0, DOT, PAIRED_AMOUNT),
+			Error::<Test>::ZeroSeedLiquidity
+		);
+		assert_noop!(
+			ListingPallet::propose_listing(Origin::signed(ALICE), NAME.to_vec(), AMOUNT, DOT, 0),
+			Error::<Test>::ZeroSeedLiquidity
+		);
+		assert_noop!(
+			ListingPallet::propose_listing(Origin::signed(ALICE), b"DOT".to_vec(), AMOUNT, DOT, PAIRED_AMOUNT),
+			Error::<Test>::AssetAlreadyRegistered
+		);
+		assert_noop!(
+			ListingPallet::propose_listing(Origin::signed(ALICE), NAME.to_vec(), AMOUNT, DOT, INITIAL_BALANCE + 1),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn cancel_listing_should_work() {
+	new_test_ext().execute_with(|| {
+		let listing_id = propose();
+
+		assert_noop!(
+			ListingPallet::cancel_listing(Origin::signed(BOB), listing_id),
+			Error::<Test>::NotAllowed
+		);
+
+		assert_ok!(ListingPallet::cancel_listing(Origin::signed(ALICE), listing_id));
+
+		assert_eq!(ListingPallet::listings(listing_id), None);
+		assert_eq!(Currency::reserved_balance(DOT, &ALICE), 0);
+		assert_eq!(Currency::free_balance(DOT, &ALICE), INITIAL_BALANCE);
+
+		expect_events(vec![Event::ListingCancelled(listing_id).into()]);
+
+		assert_noop!(
+			ListingPallet::cancel_listing(Origin::signed(ALICE), listing_id),
+			Error::<Test>::ListingNotFound
+		);
+	});
+}
+
+#[test]
+fn reject_listing_should_work() {
+	new_test_ext().execute_with(|| {
+		let listing_id = propose();
+
+		assert_noop!(
+			ListingPallet::reject_listing(Origin::signed(ALICE), listing_id),
+			BadOrigin
+		);
+
+		assert_ok!(ListingPallet::reject_listing(Origin::root(), listing_id));
+
+		assert_eq!(ListingPallet::listings(listing_id), None);
+		assert_eq!(Currency::reserved_balance(DOT, &ALICE), 0);
+		assert_eq!(Currency::free_balance(DOT, &ALICE), INITIAL_BALANCE);
+		assert_eq!(AssetRegistry::asset_by_name(NAME), None);
+
+		expect_events(vec![Event::ListingRejected(listing_id).into()]);
+	});
+}
+
+#[test]
+fn approve_listing_should_work() {
+	new_test_ext().execute_with(|| {
+		let listing_id = propose();
+
+		assert_noop!(
+			ListingPallet::approve_listing(Origin::signed(ALICE), listing_id),
+			BadOrigin
+		);
+
+		assert_ok!(ListingPallet::approve_listing(Origin::root(), listing_id));
+
+		let pool = new_pool();
+
+		assert_eq!(AssetRegistry::asset_by_name(NAME), Some(NEW_ASSET));
+		assert!(XYK::exists(AssetPair {
+			asset_in: NEW_ASSET,
+			asset_out: DOT,
+		}));
+		assert_eq!(XYK::pool_access_asset(&pool), Some(ACCESS));
+
+		assert_eq!(Currency::free_balance(NEW_ASSET, &pool), AMOUNT);
+		assert_eq!(Currency::free_balance(DOT, &pool), PAIRED_AMOUNT);
+		assert_eq!(Currency::free_balance(NEW_ASSET, &ALICE), 0);
+		assert_eq!(Currency::reserved_balance(DOT, &ALICE), 0);
+		assert_eq!(Currency::free_balance(DOT, &ALICE), INITIAL_BALANCE - PAIRED_AMOUNT);
+		assert_eq!(
+			Currency::free_balance(XYK::share_token(&pool), &ALICE),
+			PAIRED_AMOUNT - pallet_xyk::MINIMUM_LIQUIDITY
+		);
+
+		assert_eq!(
+			ListingPallet::listings(listing_id).map(|l| l.status),
+			Some(ListingStatus::Bootstrap(NEW_ASSET, 11))
+		);
+
+		assert_event_emitted!(Test, Event::ListingApproved(listing_id));
+		assert_event_emitted!(Test, Event::AssetRegistered(listing_id, NEW_ASSET));
+		expect_events(vec![Event::BootstrapStarted(listing_id, pool, 11).into()]);
+
+		// Only holders of the access asset can trade during bootstrap
+		assert_noop!(
+			XYK::sell(Origin::signed(ALICE), DOT, NEW_ASSET, 10_000, 1, false),
+			pallet_xyk::Error::<Test>::AccessAssetRequired
+		);
+		assert_ok!(XYK::sell(Origin::signed(BOB), DOT, NEW_ASSET, 10_000, 1, false));
+
+		assert_noop!(
+			ListingPallet::approve_listing(Origin::root(), listing_id),
+			Error::<Test>::InvalidListingStatus
+		);
+	});
+}
+
+#[test]
+fn approve_listing_of_registered_asset_should_not_work() {
+	new_test_ext().execute_with(|| {
+		let listing_id = propose();
+
+		assert_ok!(AssetRegistry::get_or_create_asset(NAME.to_vec()));
+
+		assert_noop!(
+			ListingPallet::approve_listing(Origin::root(), listing_id),
+			Error::<Test>::AssetAlreadyRegistered
+		);
+
+		// Seed liquidity can be still returned
+		assert_ok!(ListingPallet::reject_listing(Origin::root(), listing_id));
+		assert_eq!(Currency::reserved_balance(DOT, &ALICE), 0);
+	});
+}
+
+#[test]
+fn open_trading_should_work() {
+	new_test_ext().execute_with(|| {
+		let listing_id = propose();
+
+		assert_noop!(
+			ListingPallet::open_trading(Origin::signed(BOB), listing_id),
+			Error::<Test>::InvalidListingStatus
+		);
+
+		assert_ok!(ListingPallet::approve_listing(Origin::root(), listing_id));
+
+		assert_noop!(
+			ListingPallet::open_trading(Origin::signed(BOB), listing_id),
+			Error::<Test>::BootstrapNotFinished
+		);
+
+		System::set_block_number(11);
+
+		assert_ok!(ListingPallet::open_trading(Origin::signed(BOB), listing_id));
+
+		let pool = new_pool();
+
+		assert_eq!(XYK::pool_access_asset(&pool), None);
+		assert_eq!(ListingPallet::listings(listing_id), None);
+
+		expect_events(vec![
+			pallet_xyk::Event::PoolAccessAssetRemoved(pool).into(),
+			Event::TradingOpened(listing_id, pool).into(),
+		]);
+
+		assert_ok!(XYK::sell(Origin::signed(ALICE), DOT, NEW_ASSET, 10_000, 1, false));
+
+		assert_noop!(
+			ListingPallet::open_trading(Origin::signed(BOB), listing_id),
+			Error::<Test>::ListingNotFound
+		);
+	});
+}
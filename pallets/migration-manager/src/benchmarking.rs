@@ -0,0 +1,76 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+use crate::Pallet as MigrationManager;
+
+const MAX_STEPS: u32 = 100;
+
+fn remark_call<T: Config>() -> Box<<T as Config>::Call> {
+	Box::new(frame_system::Call::<T>::remark(vec![]).into())
+}
+
+fn fill_queue<T: Config>() -> Result<(), DispatchError> {
+	for _ in 1..T::MaxOperations::get() {
+		MigrationManager::<T>::schedule_operation(RawOrigin::Root.into(), remark_call::<T>(), MAX_STEPS)
+			.map_err(|e| e.error)?;
+	}
+	Ok(())
+}
+
+benchmarks! {
+	schedule_operation {
+		fill_queue::<T>()?;
+		let operation_id = MigrationManager::<T>::next_operation_id();
+
+	}: _(RawOrigin::Root, remark_call::<T>(), MAX_STEPS)
+	verify {
+		assert!(MigrationManager::<T>::operations(operation_id).is_some());
+	}
+
+	cancel_operation {
+		fill_queue::<T>()?;
+		let operation_id = MigrationManager::<T>::next_operation_id();
+		MigrationManager::<T>::schedule_operation(RawOrigin::Root.into(), remark_call::<T>(), MAX_STEPS)
+			.map_err(|e| e.error)?;
+
+	}: _(RawOrigin::Root, operation_id)
+	verify {
+		assert!(MigrationManager::<T>::operations(operation_id).is_none());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_schedule_operation::<Test>());
+			assert_ok!(test_benchmark_cancel_operation::<Test>());
+		});
+	}
+}
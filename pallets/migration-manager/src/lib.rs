@@ -0,0 +1,297 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! # Migration Manager Pallet
+//!
+//! ## Overview
+//!
+//! Migration manager executes privileged bulk operations which do not fit into a single block, e.g. migrating all
+//! claims, re-denominating an asset or draining a deprecated pool.
+//!
+//! Operation is a call which processes a limited part of the work, e.g. one page of storage entries, and fails once
+//! there is nothing left to process. The call is dispatched with root origin repeatedly in `on_initialize`
+//! of the following blocks, as many times as fits into `MaxWeightPerBlock`, until it fails or its maximum number of
+//! steps is executed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::{Dispatchable, PostDispatchInfo},
+	ensure,
+	traits::Get,
+	weights::{extract_actual_weight, GetDispatchInfo, Weight},
+	Parameter,
+};
+use frame_system::RawOrigin;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchError, RuntimeDebug,
+};
+use sp_std::{boxed::Box, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// Operation identifier
+pub type OperationId = u32;
+
+/// Bulk operation executed in steps across multiple blocks.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Operation<Call> {
+	/// Call dispatched with root origin in each step
+	pub call: Call,
+	/// Maximum number of steps
+	pub max_steps: u32,
+	/// Number of executed steps
+	pub executed_steps: u32,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Execute steps of scheduled operations in the order of scheduling within `MaxWeightPerBlock`.
+		fn on_initialize(_now: T::BlockNumber) -> Weight {
+			let queue = Self::operation_queue();
+
+			if queue.is_empty() {
+				return T::DbWeight::get().reads(1);
+			}
+
+			Self::execute_operations(queue).saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		}
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Runtime call type dispatched by operations
+		type Call: Parameter
+			+ Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>;
+
+		/// Origin which can schedule and cancel operations
+		type ScheduleOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Maximum weight of operation steps executed in one block
+		#[pallet::constant]
+		type MaxWeightPerBlock: Get<Weight>;
+
+		/// Maximum number of scheduled operations
+		#[pallet::constant]
+		type MaxOperations: Get<u32>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Maximum number of scheduled operations has been reached.
+		TooManyOperations,
+
+		/// Operation does not exist.
+		OperationNotFound,
+
+		/// Operation has to have at least one step.
+		ZeroSteps,
+
+		/// Weight of the operation call exceeds `MaxWeightPerBlock`.
+		CallTooHeavy,
+
+		/// Overflow
+		OperationIdOverflow,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Operation was scheduled. [operation id, max steps]
+		OperationScheduled(OperationId, u32),
+
+		/// Operation executed its maximum number of steps. [operation id, executed steps]
+		OperationCompleted(OperationId, u32),
+
+		/// Operation was stopped because its call failed. [operation id, successfully executed steps, error]
+		OperationStopped(OperationId, u32, DispatchError),
+
+		/// Operation was cancelled. [operation id, executed steps]
+		OperationCancelled(OperationId, u32),
+	}
+
+	/// Next available operation id.
+	#[pallet::storage]
+	#[pallet::getter(fn next_operation_id)]
+	pub type NextOperationId<T: Config> = StorageValue<_, OperationId, ValueQuery>;
+
+	/// Scheduled operations.
+	#[pallet::storage]
+	#[pallet::getter(fn operations)]
+	pub type Operations<T: Config> =
+		StorageMap<_, Twox64Concat, OperationId, Operation<<T as Config>::Call>, OptionQuery>;
+
+	/// Ids of scheduled operations in the order of execution.
+	#[pallet::storage]
+	#[pallet::getter(fn operation_queue)]
+	pub type OperationQueue<T: Config> = StorageValue<_, Vec<OperationId>, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Schedule operation which dispatches `call` with root origin at most `max_steps` times.
+		///
+		/// Steps are executed in `on_initialize` of the following blocks after steps of previously scheduled
+		/// operations. Operation ends when `call` fails or `max_steps` is reached.
+		///
+		/// Emits `OperationScheduled` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_operation())]
+		pub fn schedule_operation(
+			origin: OriginFor<T>,
+			call: Box<<T as Config>::Call>,
+			max_steps: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ScheduleOrigin::ensure_origin(origin)?;
+
+			ensure!(!max_steps.is_zero(), Error::<T>::ZeroSteps);
+			ensure!(
+				call.get_dispatch_info().weight <= T::MaxWeightPerBlock::get(),
+				Error::<T>::CallTooHeavy
+			);
+			ensure!(
+				OperationQueue::<T>::decode_len().unwrap_or(0) < T::MaxOperations::get() as usize,
+				Error::<T>::TooManyOperations
+			);
+
+			let operation_id = Self::next_operation_id();
+			let next_id = operation_id.checked_add(1).ok_or(Error::<T>::OperationIdOverflow)?;
+
+			Operations::<T>::insert(
+				operation_id,
+				Operation {
+					call: *call,
+					max_steps,
+					executed_steps: 0,
+				},
+			);
+			OperationQueue::<T>::append(operation_id);
+			NextOperationId::<T>::put(next_id);
+
+			Self::deposit_event(Event::OperationScheduled(operation_id, max_steps));
+
+			Ok(().into())
+		}
+
+		/// Cancel scheduled operation. Already executed steps are not reverted.
+		///
+		/// Emits `OperationCancelled` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_operation())]
+		pub fn cancel_operation(origin: OriginFor<T>, operation_id: OperationId) -> DispatchResultWithPostInfo {
+			T::ScheduleOrigin::ensure_origin(origin)?;
+
+			let operation = Operations::<T>::take(operation_id).ok_or(Error::<T>::OperationNotFound)?;
+
+			OperationQueue::<T>::mutate(|queue| queue.retain(|id| *id != operation_id));
+
+			Self::deposit_event(Event::OperationCancelled(operation_id, operation.executed_steps));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Execute steps of operations in `queue` until the weight budget of the block is exhausted.
+	///
+	/// Finished operations are removed from the queue. Return weight of executed steps and storage access.
+	fn execute_operations(queue: Vec<OperationId>) -> Weight {
+		let budget = T::MaxWeightPerBlock::get();
+		let mut used: Weight = 0;
+		let mut remaining = Vec::with_capacity(queue.len());
+
+		for operation_id in queue.into_iter() {
+			let mut operation = match Self::operations(operation_id) {
+				Some(operation) if used < budget => operation,
+				Some(_) => {
+					remaining.push(operation_id);
+					continue;
+				}
+				None => continue,
+			};
+
+			used = used.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+			let info = operation.call.get_dispatch_info();
+			let mut finished = false;
+
+			while used.saturating_add(info.weight) <= budget {
+				let result = operation.call.clone().dispatch(RawOrigin::Root.into());
+
+				used = used.saturating_add(extract_actual_weight(&result, &info));
+
+				if let Err(error) = result {
+					Self::deposit_event(Event::OperationStopped(
+						operation_id,
+						operation.executed_steps,
+						error.error,
+					));
+					finished = true;
+					break;
+				}
+
+				operation.executed_steps = operation.executed_steps.saturating_add(1);
+
+				if operation.executed_steps >= operation.max_steps {
+					Self::deposit_event(Event::OperationCompleted(operation_id, operation.executed_steps));
+					finished = true;
+					break;
+				}
+			}
+
+			if finished {
+				Operations::<T>::remove(operation_id);
+			} else {
+				Operations::<T>::insert(operation_id, operation);
+				remaining.push(operation_id);
+			}
+		}
+
+		OperationQueue::<T>::put(remaining);
+
+		used
+	}
+}
@@ -0,0 +1,154 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate as migration_manager;
+use crate::Config;
+use frame_support::{parameter_types, weights::Weight};
+use frame_system as system;
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+
+pub type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+
+/// Weight of one step of the dummy bulk operation
+pub const STEP_WEIGHT: Weight = 1_000;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+	 Block = Block,
+	 NodeBlock = Block,
+	 UncheckedExtrinsic = UncheckedExtrinsic,
+	 {
+		 System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		 MigrationManager: migration_manager::{Pallet, Call, Storage, Event<T>},
+		 Dummy: dummy::{Pallet, Call, Storage},
+	 }
+
+);
+
+/// Pallet with a paged bulk operation processing one item per call.
+#[frame_support::pallet]
+pub mod dummy {
+	use super::STEP_WEIGHT;
+	use frame_support::pallet_prelude::*;
+	use frame_system::{ensure_root, pallet_prelude::OriginFor};
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// There are no items left to process.
+		NothingToProcess,
+	}
+
+	/// Number of items left to process.
+	#[pallet::storage]
+	#[pallet::getter(fn items)]
+	pub type Items<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Process one item.
+		#[pallet::weight(STEP_WEIGHT)]
+		pub fn process_item(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			let items = Self::items();
+
+			ensure!(items > 0, Error::<T>::NothingToProcess);
+
+			Items::<T>::put(items - 1);
+
+			Ok(().into())
+		}
+	}
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub const MaxWeightPerBlock: Weight = 2 * STEP_WEIGHT;
+	pub const MaxOperations: u32 = 2;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+impl dummy::Config for Test {}
+
+impl Config for Test {
+	type Event = Event;
+	type Call = Call;
+	type ScheduleOrigin = EnsureRoot<AccountId>;
+	type MaxWeightPerBlock = MaxWeightPerBlock;
+	type MaxOperations = MaxOperations;
+	type WeightInfo = ();
+}
+
+pub struct ExtBuilder;
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		t.into()
+	}
+}
@@ -0,0 +1,214 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+pub use crate::mock::{dummy, Call, Dummy, ExtBuilder, MigrationManager, Origin, System, Test, ALICE};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_runtime::DispatchError::BadOrigin;
+use test_utils::expect_events;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn process_item_call() -> Box<Call> {
+	Box::new(Call::Dummy(dummy::Call::process_item()))
+}
+
+#[test]
+fn schedule_operation_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MigrationManager::schedule_operation(
+			Origin::root(),
+			process_item_call(),
+			10
+		));
+
+		assert_eq!(
+			MigrationManager::operations(0),
+			Some(Operation {
+				call: *process_item_call(),
+				max_steps: 10,
+				executed_steps: 0,
+			})
+		);
+		assert_eq!(MigrationManager::operation_queue(), vec![0]);
+		assert_eq!(MigrationManager::next_operation_id(), 1);
+
+		expect_events(vec![Event::OperationScheduled(0, 10).into()]);
+	});
+}
+
+#[test]
+fn schedule_operation_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			MigrationManager::schedule_operation(Origin::signed(ALICE), process_item_call(), 10),
+			BadOrigin
+		);
+		assert_noop!(
+			MigrationManager::schedule_operation(Origin::root(), process_item_call(), 0),
+			Error::<Test>::ZeroSteps
+		);
+		assert_noop!(
+			MigrationManager::schedule_operation(
+				Origin::root(),
+				Box::new(Call::System(frame_system::Call::set_code(vec![]))),
+				10
+			),
+			Error::<Test>::CallTooHeavy
+		);
+
+		assert_ok!(MigrationManager::schedule_operation(
+			Origin::root(),
+			process_item_call(),
+			10
+		));
+		assert_ok!(MigrationManager::schedule_operation(
+			Origin::root(),
+			process_item_call(),
+			10
+		));
+		assert_noop!(
+			MigrationManager::schedule_operation(Origin::root(), process_item_call(), 10),
+			Error::<Test>::TooManyOperations
+		);
+	});
+}
+
+#[test]
+fn operation_should_be_executed_across_blocks_until_call_fails() {
+	new_test_ext().execute_with(|| {
+		dummy::Items::<Test>::put(5);
+
+		assert_ok!(MigrationManager::schedule_operation(
+			Origin::root(),
+			process_item_call(),
+			10
+		));
+
+		MigrationManager::on_initialize(2);
+
+		assert_eq!(Dummy::items(), 3);
+		assert_eq!(MigrationManager::operations(0).unwrap().executed_steps, 2);
+
+		MigrationManager::on_initialize(3);
+
+		assert_eq!(Dummy::items(), 1);
+		assert_eq!(MigrationManager::operations(0).unwrap().executed_steps, 4);
+
+		MigrationManager::on_initialize(4);
+
+		assert_eq!(Dummy::items(), 0);
+		assert_eq!(MigrationManager::operations(0), None);
+		assert!(MigrationManager::operation_queue().is_empty());
+
+		expect_events(vec![
+			Event::OperationScheduled(0, 10).into(),
+			Event::OperationStopped(0, 5, dummy::Error::<Test>::NothingToProcess.into()).into(),
+		]);
+	});
+}
+
+#[test]
+fn operation_should_complete_after_max_steps() {
+	new_test_ext().execute_with(|| {
+		dummy::Items::<Test>::put(5);
+
+		assert_ok!(MigrationManager::schedule_operation(
+			Origin::root(),
+			process_item_call(),
+			3
+		));
+
+		MigrationManager::on_initialize(2);
+		MigrationManager::on_initialize(3);
+
+		assert_eq!(Dummy::items(), 2);
+		assert_eq!(MigrationManager::operations(0), None);
+
+		expect_events(vec![
+			Event::OperationScheduled(0, 3).into(),
+			Event::OperationCompleted(0, 3).into(),
+		]);
+
+		MigrationManager::on_initialize(4);
+
+		assert_eq!(Dummy::items(), 2);
+	});
+}
+
+#[test]
+fn operations_should_be_executed_in_order_of_scheduling() {
+	new_test_ext().execute_with(|| {
+		dummy::Items::<Test>::put(5);
+
+		assert_ok!(MigrationManager::schedule_operation(
+			Origin::root(),
+			process_item_call(),
+			1
+		));
+		assert_ok!(MigrationManager::schedule_operation(
+			Origin::root(),
+			process_item_call(),
+			3
+		));
+
+		MigrationManager::on_initialize(2);
+
+		assert_eq!(MigrationManager::operations(0), None);
+		assert_eq!(MigrationManager::operations(1).unwrap().executed_steps, 1);
+		assert_eq!(MigrationManager::operation_queue(), vec![1]);
+		assert_eq!(Dummy::items(), 3);
+	});
+}
+
+#[test]
+fn cancel_operation_should_work() {
+	new_test_ext().execute_with(|| {
+		dummy::Items::<Test>::put(5);
+
+		assert_ok!(MigrationManager::schedule_operation(
+			Origin::root(),
+			process_item_call(),
+			10
+		));
+
+		MigrationManager::on_initialize(2);
+
+		assert_noop!(MigrationManager::cancel_operation(Origin::signed(ALICE), 0), BadOrigin);
+		assert_noop!(
+			MigrationManager::cancel_operation(Origin::root(), 1),
+			Error::<Test>::OperationNotFound
+		);
+
+		assert_ok!(MigrationManager::cancel_operation(Origin::root(), 0));
+
+		assert_eq!(MigrationManager::operations(0), None);
+		assert!(MigrationManager::operation_queue().is_empty());
+
+		MigrationManager::on_initialize(3);
+
+		assert_eq!(Dummy::items(), 3);
+
+		expect_events(vec![
+			Event::OperationScheduled(0, 10).into(),
+			Event::OperationCancelled(0, 2).into(),
+		]);
+	});
+}
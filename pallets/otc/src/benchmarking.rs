@@ -0,0 +1,97 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+use crate::Pallet as OTC;
+
+const SEED: u32 = 1;
+
+const ASSET_SELL: AssetId = 1;
+const ASSET_BUY: AssetId = 2;
+const AMOUNT: Balance = 1_000_000_000_000;
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let caller: T::AccountId = account(name, index, SEED);
+	T::Currency::deposit(ASSET_SELL, &caller, 1_000_000_000_000_000).unwrap();
+	T::Currency::deposit(ASSET_BUY, &caller, 1_000_000_000_000_000).unwrap();
+	caller
+}
+
+fn place_order<T: Config>(maker: &T::AccountId) -> Result<OrderId, DispatchError> {
+	let order_id = OTC::<T>::next_order_id();
+	OTC::<T>::place_order(
+		RawOrigin::Signed(maker.clone()).into(),
+		ASSET_SELL,
+		ASSET_BUY,
+		AMOUNT,
+		Price::from(2),
+		true,
+	)
+	.map_err(|e| e.error)?;
+	Ok(order_id)
+}
+
+benchmarks! {
+	place_order {
+		let caller = funded_account::<T>("caller", 0);
+
+	}: _(RawOrigin::Signed(caller.clone()), ASSET_SELL, ASSET_BUY, AMOUNT, Price::from(2), true)
+	verify {
+		assert_eq!(T::Currency::reserved_balance(ASSET_SELL, &caller), AMOUNT);
+	}
+
+	fill_order {
+		let maker = funded_account::<T>("maker", 0);
+		let taker = funded_account::<T>("taker", 1);
+		let order_id = place_order::<T>(&maker)?;
+
+	}: _(RawOrigin::Signed(taker), order_id, AMOUNT / 2)
+	verify {
+		assert_eq!(OTC::<T>::orders(order_id).unwrap().amount, AMOUNT / 2);
+	}
+
+	cancel_order {
+		let caller = funded_account::<T>("caller", 0);
+		let order_id = place_order::<T>(&caller)?;
+
+	}: _(RawOrigin::Signed(caller.clone()), order_id)
+	verify {
+		assert_eq!(T::Currency::reserved_balance(ASSET_SELL, &caller), 0);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_place_order::<Test>());
+			assert_ok!(test_benchmark_fill_order::<Test>());
+			assert_ok!(test_benchmark_cancel_order::<Test>());
+		});
+	}
+}
@@ -0,0 +1,440 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! # OTC Pallet
+//!
+//! ## Overview
+//!
+//! OTC pallet provides book of resting limit orders.
+//!
+//! Maker places an order to sell given amount of an asset for another asset at given price or better.
+//! Sold amount is reserved until the order is filled or cancelled.
+//!
+//! Takers can fill orders directly. Orders are also used by the exchange to settle intentions before they are
+//! traded with the AMM pool, as long as the order price is at least as good as the current pool price.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{ensure, traits::BalanceStatus, transactional, weights::Weight};
+use frame_system::ensure_signed;
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use primitives::{asset::AssetPair, traits::OrderBook, AssetId, Balance, Price, MIN_TRADING_LIMIT};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
+	traits::{Saturating, Zero},
+	DispatchError, FixedPointNumber, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// Order identifier
+pub type OrderId = u32;
+
+/// Resting limit order.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Order<AccountId> {
+	/// Account which placed the order
+	pub owner: AccountId,
+	/// Asset offered by the order
+	pub asset_sell: AssetId,
+	/// Asset requested in exchange
+	pub asset_buy: AssetId,
+	/// Remaining amount of `asset_sell` offered
+	pub amount: Balance,
+	/// Minimum amount of `asset_buy` per unit of `asset_sell`
+	pub price: Price,
+	/// Whether the order can be filled in multiple parts
+	pub partially_fillable: bool,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Multi currency for reserving and transferring order amounts
+		type Currency: MultiReservableCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// Maximum number of resting orders of single asset pair
+		#[pallet::constant]
+		type MaxOrdersPerPair: Get<u32>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// It is not allowed to trade an asset for itself.
+		CannotTradeSameAssets,
+
+		/// Order amount is lower than minimum trading limit.
+		InsufficientOrderAmount,
+
+		/// Order price cannot be zero.
+		ZeroPrice,
+
+		/// Asset pair has reached maximum number of resting orders.
+		TooManyOrders,
+
+		/// Order does not exist.
+		OrderNotFound,
+
+		/// Account is not allowed to perform this action on the order.
+		NotOrderOwner,
+
+		/// Fill amount is zero or exceeds remaining order amount.
+		InvalidFillAmount,
+
+		/// Order can only be filled completely.
+		OrderNotPartiallyFillable,
+
+		/// Taker's balance is too low to pay for the fill.
+		InsufficientBalance,
+
+		/// Overflow
+		OrderIdOverflow,
+
+		/// Overflow
+		CostOverflow,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Order was placed. [order id, owner, asset sell, asset buy, amount, price, partially fillable]
+		OrderPlaced(OrderId, T::AccountId, AssetId, AssetId, Balance, Price, bool),
+
+		/// Order was completely filled. [order id, owner, taker, amount sold, amount paid]
+		OrderFilled(OrderId, T::AccountId, T::AccountId, Balance, Balance),
+
+		/// Order was partially filled. [order id, owner, taker, amount sold, amount paid]
+		OrderPartiallyFilled(OrderId, T::AccountId, T::AccountId, Balance, Balance),
+
+		/// Order was cancelled by its owner. [order id, owner]
+		OrderCancelled(OrderId, T::AccountId),
+	}
+
+	/// Next available order id.
+	#[pallet::storage]
+	#[pallet::getter(fn next_order_id)]
+	pub type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+	/// Resting orders.
+	#[pallet::storage]
+	#[pallet::getter(fn orders)]
+	pub type Orders<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, Order<T::AccountId>, OptionQuery>;
+
+	/// Resting orders of asset pair, keyed by (asset sell, asset buy).
+	#[pallet::storage]
+	#[pallet::getter(fn pair_orders)]
+	pub type PairOrders<T: Config> = StorageMap<_, Blake2_128Concat, (AssetId, AssetId), Vec<OrderId>, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Place order to sell `amount` of `asset_sell` for `asset_buy` at `price` or better.
+		///
+		/// `price` is amount of `asset_buy` requested per unit of `asset_sell`.
+		/// `amount` is reserved from origin's balance until the order is filled or cancelled.
+		///
+		/// Emits `OrderPlaced` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::place_order())]
+		#[transactional]
+		pub fn place_order(
+			origin: OriginFor<T>,
+			asset_sell: AssetId,
+			asset_buy: AssetId,
+			amount: Balance,
+			price: Price,
+			partially_fillable: bool,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(asset_sell != asset_buy, Error::<T>::CannotTradeSameAssets);
+			ensure!(amount >= MIN_TRADING_LIMIT, Error::<T>::InsufficientOrderAmount);
+			ensure!(!price.is_zero(), Error::<T>::ZeroPrice);
+			ensure!(
+				(Self::pair_orders((asset_sell, asset_buy)).len() as u32) < T::MaxOrdersPerPair::get(),
+				Error::<T>::TooManyOrders
+			);
+
+			let order_id = Self::next_order_id();
+			let next_id = order_id.checked_add(1).ok_or(Error::<T>::OrderIdOverflow)?;
+
+			T::Currency::reserve(asset_sell, &who, amount)?;
+
+			<Orders<T>>::insert(
+				order_id,
+				Order {
+					owner: who.clone(),
+					asset_sell,
+					asset_buy,
+					amount,
+					price,
+					partially_fillable,
+				},
+			);
+			<PairOrders<T>>::append((asset_sell, asset_buy), order_id);
+			<NextOrderId<T>>::put(next_id);
+
+			Self::deposit_event(Event::OrderPlaced(
+				order_id,
+				who,
+				asset_sell,
+				asset_buy,
+				amount,
+				price,
+				partially_fillable,
+			));
+
+			Ok(().into())
+		}
+
+		/// Buy `amount` of asset sold by the order.
+		///
+		/// Origin pays order price for the amount, rounded up. Orders which are not partially fillable
+		/// can only be filled by their whole remaining amount.
+		///
+		/// Emits `OrderFilled` or `OrderPartiallyFilled` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::fill_order())]
+		pub fn fill_order(origin: OriginFor<T>, order_id: OrderId, amount: Balance) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let order = Self::orders(order_id).ok_or(Error::<T>::OrderNotFound)?;
+
+			ensure!(
+				!amount.is_zero() && amount <= order.amount,
+				Error::<T>::InvalidFillAmount
+			);
+			ensure!(
+				order.partially_fillable || amount == order.amount,
+				Error::<T>::OrderNotPartiallyFillable
+			);
+
+			Self::do_fill(order_id, order, &who, amount)?;
+
+			Ok(().into())
+		}
+
+		/// Cancel order and return its remaining reserved amount.
+		///
+		/// Can be performed only by the order owner.
+		///
+		/// Emits `OrderCancelled` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_order())]
+		#[transactional]
+		pub fn cancel_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let order = Self::orders(order_id).ok_or(Error::<T>::OrderNotFound)?;
+
+			ensure!(order.owner == who, Error::<T>::NotOrderOwner);
+
+			T::Currency::unreserve(order.asset_sell, &order.owner, order.amount);
+
+			Self::remove_order(order_id, &order);
+
+			Self::deposit_event(Event::OrderCancelled(order_id, who));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Check invariants of the order book.
+	///
+	/// - every order is listed exactly once under its asset pair and every listed order exists
+	/// - remaining amount of every order is reserved by its owner
+	#[cfg(feature = "try-runtime")]
+	pub fn do_try_state() -> Result<(), &'static str> {
+		let mut listed: u32 = 0;
+
+		for ((asset_sell, asset_buy), order_ids) in <PairOrders<T>>::iter() {
+			for order_id in order_ids.iter() {
+				let order = Self::orders(order_id).ok_or("Listed order does not exist")?;
+				ensure!(
+					order.asset_sell == asset_sell && order.asset_buy == asset_buy,
+					"Order is listed under wrong asset pair"
+				);
+				listed = listed.saturating_add(1);
+			}
+		}
+
+		let mut count: u32 = 0;
+
+		for (order_id, order) in <Orders<T>>::iter() {
+			ensure!(
+				Self::pair_orders((order.asset_sell, order.asset_buy)).contains(&order_id),
+				"Order is not listed under its asset pair"
+			);
+			ensure!(
+				T::Currency::reserved_balance(order.asset_sell, &order.owner) >= order.amount,
+				"Order amount is not reserved"
+			);
+			count = count.saturating_add(1);
+		}
+
+		ensure!(listed == count, "Order is listed more than once");
+
+		Ok(())
+	}
+
+	/// Amount of asset buy paid for `amount` of asset sell at `price`, rounded up in favour of the maker.
+	pub fn order_cost(amount: Balance, price: Price) -> Option<Balance> {
+		let cost = multiply_by_rational(amount, price.into_inner(), Price::accuracy()).ok()?;
+
+		if multiply_by_rational(cost, Price::accuracy(), price.into_inner()).ok()? == amount {
+			Some(cost)
+		} else {
+			cost.checked_add(1)
+		}
+	}
+
+	/// Maximum amount of asset sell which can be bought for `cost` of asset buy at `price`.
+	fn affordable_amount(cost: Balance, price: Price) -> Balance {
+		multiply_by_rational(cost, Price::accuracy(), price.into_inner()).unwrap_or(Balance::MAX)
+	}
+
+	/// Transfer `amount` of reserved asset sell from maker to `taker` and the cost from `taker` to maker.
+	///
+	/// Return the amount paid by `taker`.
+	#[transactional]
+	fn do_fill(
+		order_id: OrderId,
+		mut order: Order<T::AccountId>,
+		taker: &T::AccountId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let cost = Self::order_cost(amount, order.price).ok_or(Error::<T>::CostOverflow)?;
+
+		T::Currency::ensure_can_withdraw(order.asset_buy, taker, cost).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+		T::Currency::transfer(order.asset_buy, taker, &order.owner, cost)?;
+		let not_repatriated =
+			T::Currency::repatriate_reserved(order.asset_sell, &order.owner, taker, amount, BalanceStatus::Free)?;
+		ensure!(not_repatriated.is_zero(), Error::<T>::InvalidFillAmount);
+
+		order.amount = order.amount.saturating_sub(amount);
+
+		if order.amount.is_zero() {
+			Self::remove_order(order_id, &order);
+			Self::deposit_event(Event::OrderFilled(order_id, order.owner, taker.clone(), amount, cost));
+		} else {
+			let owner = order.owner.clone();
+			<Orders<T>>::insert(order_id, order);
+			Self::deposit_event(Event::OrderPartiallyFilled(
+				order_id,
+				owner,
+				taker.clone(),
+				amount,
+				cost,
+			));
+		}
+
+		Ok(cost)
+	}
+
+	fn remove_order(order_id: OrderId, order: &Order<T::AccountId>) {
+		<Orders<T>>::remove(order_id);
+		<PairOrders<T>>::mutate((order.asset_sell, order.asset_buy), |ids| {
+			ids.retain(|id| *id != order_id)
+		});
+	}
+}
+
+impl<T: Config> OrderBook<T::AccountId, AssetPair, Balance, Price> for Pallet<T> {
+	fn fill_orders(
+		who: &T::AccountId,
+		assets: AssetPair,
+		max_amount_in: Balance,
+		max_amount_out: Balance,
+		max_price: Price,
+	) -> (Balance, Balance) {
+		let mut orders: Vec<(OrderId, Order<T::AccountId>)> = Self::pair_orders((assets.asset_out, assets.asset_in))
+			.into_iter()
+			.filter_map(|id| Self::orders(id).map(|order| (id, order)))
+			.filter(|(_, order)| order.price <= max_price && &order.owner != who)
+			.collect();
+
+		// Best price first, older orders first within the same price
+		orders.sort_by_key(|(id, order)| (order.price, *id));
+
+		let mut paid: Balance = 0;
+		let mut bought: Balance = 0;
+
+		for (order_id, order) in orders {
+			let amount = order
+				.amount
+				.min(max_amount_out.saturating_sub(bought))
+				.min(Self::affordable_amount(max_amount_in.saturating_sub(paid), order.price));
+
+			if amount.is_zero() {
+				break;
+			}
+
+			if amount < order.amount && !order.partially_fillable {
+				continue;
+			}
+
+			match Self::do_fill(order_id, order, who, amount) {
+				Ok(cost) => {
+					paid = paid.saturating_add(cost);
+					bought = bought.saturating_add(amount);
+				}
+				Err(_) => break,
+			}
+		}
+
+		(paid, bought)
+	}
+
+	fn fill_orders_weight() -> Weight {
+		T::WeightInfo::fill_order().saturating_mul(T::MaxOrdersPerPair::get() as Weight)
+	}
+}
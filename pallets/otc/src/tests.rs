@@ -0,0 +1,327 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+pub use crate::mock::{
+	Currency, Event as TestEvent, ExtBuilder, Origin, System, Test, ALICE, BOB, CHARLIE, DOT, HDX, OTC,
+};
+use frame_support::{assert_noop, assert_ok};
+use test_utils::{assert_balance, expect_events};
+
+const INITIAL_BALANCE: Balance = 1_000_000_000_000_000;
+const AMOUNT: Balance = 1_000_000;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn place(who: u64, amount: Balance, price: Price, partially_fillable: bool) -> OrderId {
+	let order_id = OTC::next_order_id();
+	assert_ok!(OTC::place_order(
+		Origin::signed(who),
+		DOT,
+		HDX,
+		amount,
+		price,
+		partially_fillable
+	));
+	order_id
+}
+
+#[test]
+fn place_order_should_work() {
+	new_test_ext().execute_with(|| {
+		let order_id = place(ALICE, AMOUNT, Price::from(2), true);
+
+		assert_eq!(
+			OTC::orders(order_id),
+			Some(Order {
+				owner: ALICE,
+				asset_sell: DOT,
+				asset_buy: HDX,
+				amount: AMOUNT,
+				price: Price::from(2),
+				partially_fillable: true,
+			})
+		);
+		assert_eq!(OTC::pair_orders((DOT, HDX)), vec![order_id]);
+		assert_eq!(OTC::next_order_id(), order_id + 1);
+		assert_eq!(Currency::reserved_balance(DOT, &ALICE), AMOUNT);
+		assert_balance!(Currency, ALICE, DOT, INITIAL_BALANCE - AMOUNT);
+
+		expect_events::<Test>(vec![Event::OrderPlaced(
+			order_id,
+			ALICE,
+			DOT,
+			HDX,
+			AMOUNT,
+			Price::from(2),
+			true,
+		)
+		.into()]);
+	});
+}
+
+#[test]
+fn place_order_with_invalid_parameters_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			OTC::place_order(Origin::signed(ALICE), DOT, DOT, AMOUNT, Price::from(2), true),
+			Error::<Test>::CannotTradeSameAssets
+		);
+		assert_noop!(
+			OTC::place_order(
+				Origin::signed(ALICE),
+				DOT,
+				HDX,
+				MIN_TRADING_LIMIT - 1,
+				Price::from(2),
+				true
+			),
+			Error::<Test>::InsufficientOrderAmount
+		);
+		assert_noop!(
+			OTC::place_order(Origin::signed(ALICE), DOT, HDX, AMOUNT, Price::zero(), true),
+			Error::<Test>::ZeroPrice
+		);
+		assert_noop!(
+			OTC::place_order(
+				Origin::signed(ALICE),
+				DOT,
+				HDX,
+				INITIAL_BALANCE + 1,
+				Price::from(2),
+				true
+			),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn place_order_should_be_limited_per_pair() {
+	new_test_ext().execute_with(|| {
+		place(ALICE, AMOUNT, Price::from(2), true);
+		place(ALICE, AMOUNT, Price::from(2), true);
+		place(BOB, AMOUNT, Price::from(2), true);
+
+		assert_noop!(
+			OTC::place_order(Origin::signed(BOB), DOT, HDX, AMOUNT, Price::from(2), true),
+			Error::<Test>::TooManyOrders
+		);
+
+		// Opposite direction is a different book
+		assert_ok!(OTC::place_order(
+			Origin::signed(BOB),
+			HDX,
+			DOT,
+			AMOUNT,
+			Price::from(2),
+			true
+		));
+	});
+}
+
+#[test]
+fn fill_order_should_work() {
+	new_test_ext().execute_with(|| {
+		let order_id = place(ALICE, AMOUNT, Price::from(2), false);
+
+		assert_ok!(OTC::fill_order(Origin::signed(BOB), order_id, AMOUNT));
+
+		assert_eq!(OTC::orders(order_id), None);
+		assert!(OTC::pair_orders((DOT, HDX)).is_empty());
+		assert_eq!(Currency::reserved_balance(DOT, &ALICE), 0);
+		assert_balance!(Currency, ALICE, DOT, INITIAL_BALANCE - AMOUNT);
+		assert_balance!(Currency, ALICE, HDX, INITIAL_BALANCE + 2 * AMOUNT);
+		assert_balance!(Currency, BOB, DOT, INITIAL_BALANCE + AMOUNT);
+		assert_balance!(Currency, BOB, HDX, INITIAL_BALANCE - 2 * AMOUNT);
+
+		expect_events::<Test>(vec![Event::OrderFilled(order_id, ALICE, BOB, AMOUNT, 2 * AMOUNT).into()]);
+	});
+}
+
+#[test]
+fn partial_fill_should_work() {
+	new_test_ext().execute_with(|| {
+		let order_id = place(ALICE, AMOUNT, Price::from(2), true);
+
+		assert_ok!(OTC::fill_order(Origin::signed(BOB), order_id, 400_000));
+
+		assert_eq!(OTC::orders(order_id).unwrap().amount, 600_000);
+		assert_eq!(Currency::reserved_balance(DOT, &ALICE), 600_000);
+		assert_balance!(Currency, ALICE, HDX, INITIAL_BALANCE + 800_000);
+		assert_balance!(Currency, BOB, DOT, INITIAL_BALANCE + 400_000);
+
+		expect_events::<Test>(vec![Event::OrderPartiallyFilled(
+			order_id, ALICE, BOB, 400_000, 800_000,
+		)
+		.into()]);
+
+		assert_ok!(OTC::fill_order(Origin::signed(CHARLIE), order_id, 600_000));
+
+		assert_eq!(OTC::orders(order_id), None);
+		assert_eq!(Currency::reserved_balance(DOT, &ALICE), 0);
+
+		expect_events::<Test>(vec![
+			Event::OrderFilled(order_id, ALICE, CHARLIE, 600_000, 1_200_000).into()
+		]);
+	});
+}
+
+#[test]
+fn fill_order_cost_should_be_rounded_up() {
+	new_test_ext().execute_with(|| {
+		let order_id = place(ALICE, AMOUNT, Price::from_float(0.5), true);
+
+		assert_ok!(OTC::fill_order(Origin::signed(BOB), order_id, 1));
+
+		assert_balance!(Currency, ALICE, HDX, INITIAL_BALANCE + 1);
+		assert_balance!(Currency, BOB, DOT, INITIAL_BALANCE + 1);
+
+		assert_eq!(OTC::order_cost(AMOUNT, Price::from_float(0.5)), Some(AMOUNT / 2));
+	});
+}
+
+#[test]
+fn fill_order_with_invalid_amount_should_not_work() {
+	new_test_ext().execute_with(|| {
+		let order_id = place(ALICE, AMOUNT, Price::from(2), false);
+
+		assert_noop!(
+			OTC::fill_order(Origin::signed(BOB), order_id + 1, AMOUNT),
+			Error::<Test>::OrderNotFound
+		);
+		assert_noop!(
+			OTC::fill_order(Origin::signed(BOB), order_id, 0),
+			Error::<Test>::InvalidFillAmount
+		);
+		assert_noop!(
+			OTC::fill_order(Origin::signed(BOB), order_id, AMOUNT + 1),
+			Error::<Test>::InvalidFillAmount
+		);
+		assert_noop!(
+			OTC::fill_order(Origin::signed(BOB), order_id, AMOUNT - 1),
+			Error::<Test>::OrderNotPartiallyFillable
+		);
+	});
+}
+
+#[test]
+fn fill_order_with_insufficient_balance_should_not_work() {
+	new_test_ext().execute_with(|| {
+		let order_id = place(ALICE, AMOUNT, Price::from(INITIAL_BALANCE), false);
+
+		assert_noop!(
+			OTC::fill_order(Origin::signed(BOB), order_id, AMOUNT),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn cancel_order_should_work() {
+	new_test_ext().execute_with(|| {
+		let order_id = place(ALICE, AMOUNT, Price::from(2), true);
+
+		assert_noop!(
+			OTC::cancel_order(Origin::signed(BOB), order_id),
+			Error::<Test>::NotOrderOwner
+		);
+
+		assert_ok!(OTC::cancel_order(Origin::signed(ALICE), order_id));
+
+		assert_eq!(OTC::orders(order_id), None);
+		assert!(OTC::pair_orders((DOT, HDX)).is_empty());
+		assert_eq!(Currency::reserved_balance(DOT, &ALICE), 0);
+		assert_balance!(Currency, ALICE, DOT, INITIAL_BALANCE);
+
+		expect_events::<Test>(vec![Event::OrderCancelled(order_id, ALICE).into()]);
+
+		assert_noop!(
+			OTC::cancel_order(Origin::signed(ALICE), order_id),
+			Error::<Test>::OrderNotFound
+		);
+	});
+}
+
+#[test]
+fn fill_orders_should_fill_best_price_first() {
+	new_test_ext().execute_with(|| {
+		let expensive = place(ALICE, AMOUNT, Price::from(3), true);
+		let cheap = place(BOB, AMOUNT, Price::from(2), true);
+
+		let assets = AssetPair {
+			asset_in: HDX,
+			asset_out: DOT,
+		};
+
+		assert_eq!(
+			<OTC as OrderBook<_, _, _, _>>::fill_orders(&CHARLIE, assets, 5 * AMOUNT, Balance::MAX, Price::from(3)),
+			(5 * AMOUNT, 2 * AMOUNT)
+		);
+
+		assert_eq!(OTC::orders(cheap), None);
+		assert_eq!(OTC::orders(expensive), None);
+		assert_balance!(Currency, CHARLIE, DOT, INITIAL_BALANCE + 2 * AMOUNT);
+		assert_balance!(Currency, CHARLIE, HDX, INITIAL_BALANCE - 5 * AMOUNT);
+
+		expect_events::<Test>(vec![
+			Event::OrderFilled(cheap, BOB, CHARLIE, AMOUNT, 2 * AMOUNT).into(),
+			Event::OrderFilled(expensive, ALICE, CHARLIE, AMOUNT, 3 * AMOUNT).into(),
+		]);
+	});
+}
+
+#[test]
+fn fill_orders_should_respect_limits() {
+	new_test_ext().execute_with(|| {
+		let partial = place(ALICE, AMOUNT, Price::from(2), true);
+		let whole = place(ALICE, AMOUNT, Price::from(2), false);
+		let expensive = place(BOB, AMOUNT, Price::from(4), true);
+
+		let assets = AssetPair {
+			asset_in: HDX,
+			asset_out: DOT,
+		};
+
+		// Paid amount is limited, order which is not partially fillable is skipped
+		assert_eq!(
+			<OTC as OrderBook<_, _, _, _>>::fill_orders(&CHARLIE, assets, AMOUNT, Balance::MAX, Price::from(3)),
+			(AMOUNT, AMOUNT / 2)
+		);
+		assert_eq!(OTC::orders(partial).unwrap().amount, AMOUNT / 2);
+		assert_eq!(OTC::orders(whole).unwrap().amount, AMOUNT);
+
+		// Bought amount is limited and price limit excludes the expensive order
+		assert_eq!(
+			<OTC as OrderBook<_, _, _, _>>::fill_orders(&CHARLIE, assets, Balance::MAX, 2 * AMOUNT, Price::from(3)),
+			(3 * AMOUNT, 3 * AMOUNT / 2)
+		);
+		assert_eq!(OTC::orders(partial), None);
+		assert_eq!(OTC::orders(whole), None);
+		assert_eq!(OTC::orders(expensive).unwrap().amount, AMOUNT);
+
+		// Own orders are not filled
+		assert_eq!(
+			<OTC as OrderBook<_, _, _, _>>::fill_orders(&BOB, assets, Balance::MAX, Balance::MAX, Price::from(4)),
+			(0, 0)
+		);
+		assert_eq!(OTC::orders(expensive).unwrap().amount, AMOUNT);
+	});
+}
@@ -0,0 +1,138 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+use crate::Pallet as PricePublisher;
+
+type GenericPublic<T> = <<T as Config>::AuthorityId as AppCrypto<
+	<T as SigningTypes>::Public,
+	<T as SigningTypes>::Signature,
+>>::GenericPublic;
+type GenericSignature<T> = <<T as Config>::AuthorityId as AppCrypto<
+	<T as SigningTypes>::Public,
+	<T as SigningTypes>::Signature,
+>>::GenericSignature;
+
+/// Whitelist `n` pairs, register new publisher key and return its payload with prices of all pairs.
+fn signed_payload<T: Config>(n: u32) -> Result<(PricePayload<T::Public, T::BlockNumber>, T::Signature), &'static str> {
+	let key = <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::generate_pair(None);
+	let generic: GenericPublic<T> = key.clone().into();
+	let public: T::Public = generic.into();
+
+	Publishers::<T>::insert(public.clone().into_account(), true);
+
+	let prices: Vec<(AssetPair, Price)> = (0..n)
+		.map(|i| {
+			(
+				AssetPair {
+					asset_in: i,
+					asset_out: i + 1,
+				},
+				Price::from(2),
+			)
+		})
+		.collect();
+
+	Pairs::<T>::put(prices.iter().map(|(pair, _)| *pair).collect::<Vec<_>>());
+
+	let payload = PricePayload {
+		block_number: 1u32.into(),
+		prices,
+		public,
+	};
+
+	let signature = payload
+		.using_encoded(|p| key.sign(&p))
+		.ok_or("Failed to sign price payload")?;
+	let generic: GenericSignature<T> = signature.into();
+
+	Ok((payload, generic.into()))
+}
+
+benchmarks! {
+	submit_prices {
+		let n in 1 .. T::MaxPairs::get();
+
+		let (payload, signature) = signed_payload::<T>(n)?;
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+
+	}: _(RawOrigin::None, payload, signature)
+	verify {
+		assert_eq!(PricePublisher::<T>::last_published(), 1u32.into());
+		assert!(PricePublisher::<T>::prices((0, 1)).is_some());
+	}
+
+	add_pair {
+		let pairs: Vec<AssetPair> = (1..T::MaxPairs::get()).map(|i| AssetPair { asset_in: i, asset_out: i + 1 }).collect();
+		Pairs::<T>::put(pairs);
+
+	}: _(RawOrigin::Root, 0, 1)
+	verify {
+		assert!(PricePublisher::<T>::pairs().contains(&AssetPair { asset_in: 0, asset_out: 1 }));
+	}
+
+	remove_pair {
+		let pairs: Vec<AssetPair> = (0..T::MaxPairs::get()).map(|i| AssetPair { asset_in: i, asset_out: i + 1 }).collect();
+		Pairs::<T>::put(pairs);
+
+	}: _(RawOrigin::Root, 0, 1)
+	verify {
+		assert!(!PricePublisher::<T>::pairs().contains(&AssetPair { asset_in: 0, asset_out: 1 }));
+	}
+
+	add_publisher {
+		let publisher: T::AccountId = frame_benchmarking::account("publisher", 0, 0);
+
+	}: _(RawOrigin::Root, publisher.clone())
+	verify {
+		assert!(PricePublisher::<T>::publishers(publisher));
+	}
+
+	remove_publisher {
+		let publisher: T::AccountId = frame_benchmarking::account("publisher", 0, 0);
+		Publishers::<T>::insert(&publisher, true);
+
+	}: _(RawOrigin::Root, publisher.clone())
+	verify {
+		assert!(!PricePublisher::<T>::publishers(publisher));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_submit_prices::<Test>());
+			assert_ok!(test_benchmark_add_pair::<Test>());
+			assert_ok!(test_benchmark_remove_pair::<Test>());
+			assert_ok!(test_benchmark_add_publisher::<Test>());
+			assert_ok!(test_benchmark_remove_publisher::<Test>());
+		});
+	}
+}
@@ -0,0 +1,508 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Price Publisher Pallet
+//!
+//! ## Overview
+//!
+//! Price publisher pallet records spot prices of whitelisted asset pairs on-chain, so bridges and external keepers
+//! can follow a chain-native reference price stream.
+//!
+//! Prices are read by an off-chain worker every `PublishInterval` blocks and submitted as unsigned transactions
+//! with payload signed by a publisher key of type `hdxp` from the node keystore. Only payloads signed by keys
+//! of registered publishers are accepted.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{dispatch::DispatchResult, ensure, weights::Pays};
+use frame_system::{
+	ensure_none,
+	offchain::{AppCrypto, CreateSignedTransaction, SendUnsignedTransaction, SignedPayload, Signer, SigningTypes},
+};
+use primitives::{asset::AssetPair, traits::PriceProvider, AssetId, Price};
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::{
+	traits::{IdentifyAccount, One, Zero},
+	transaction_validity::{InvalidTransaction, TransactionPriority, TransactionValidity, ValidTransaction},
+	FixedPointNumber, RuntimeAppPublic, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// Key type of price publisher keys in the node keystore.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"hdxp");
+
+/// Number of blocks for which submitted prices are valid in the transaction pool.
+const UNSIGNED_TXS_LONGEVITY: u64 = 5;
+
+/// Crypto of price publisher keys.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_core::sr25519::Signature as Sr25519Signature;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		traits::Verify,
+		MultiSignature, MultiSigner,
+	};
+
+	app_crypto!(sr25519, KEY_TYPE);
+
+	/// Identifier of price publisher keys used to sign price payloads.
+	pub struct PricePublisherAuthId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for PricePublisherAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+
+	impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature>
+		for PricePublisherAuthId
+	{
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
+/// Prices read by the off-chain worker at `block_number` signed by `public` key of the publisher.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct PricePayload<Public, BlockNumber> {
+	/// Block at which the prices were read
+	pub block_number: BlockNumber,
+	/// Price of `asset_in` denominated in `asset_out` of each pair
+	pub prices: Vec<(AssetPair, Price)>,
+	/// Key of the publisher
+	pub public: Public,
+}
+
+impl<T: SigningTypes> SignedPayload<T> for PricePayload<T::Public, T::BlockNumber> {
+	fn public(&self) -> T::Public {
+		self.public.clone()
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Read prices of whitelisted pairs and submit them every `PublishInterval` blocks.
+		fn offchain_worker(now: T::BlockNumber) {
+			let interval = T::PublishInterval::get().max(One::one());
+
+			if !(now % interval).is_zero() {
+				return;
+			}
+
+			if let Err(e) = Self::publish_prices(now) {
+				frame_support::log::warn!("Price publisher off-chain worker failed: {}", e);
+			}
+		}
+	}
+
+	#[pallet::config]
+	pub trait Config: CreateSignedTransaction<Call<Self>> + frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Crypto of publisher keys used to sign price payloads
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+		/// Provider of published prices
+		type PriceProvider: PriceProvider<AssetId, Price>;
+
+		/// Origin which can manage whitelisted pairs and publishers
+		type ManageOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Number of blocks between two price publications
+		#[pallet::constant]
+		type PublishInterval: Get<Self::BlockNumber>;
+
+		/// Maximum number of whitelisted pairs
+		#[pallet::constant]
+		type MaxPairs: Get<u32>;
+
+		/// Priority of unsigned transactions submitting prices
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Pair is already whitelisted.
+		PairAlreadyWhitelisted,
+
+		/// Pair is not whitelisted.
+		PairNotWhitelisted,
+
+		/// Maximum number of whitelisted pairs has been reached.
+		TooManyPairs,
+
+		/// Account is already a publisher.
+		AlreadyPublisher,
+
+		/// Account is not a publisher.
+		NotPublisher,
+
+		/// Prices of the same or newer block have been already published.
+		StalePrices,
+
+		/// Prices are signed for a block which has not been reached yet.
+		FuturePrices,
+
+		/// Payload contains the same pair more than once.
+		DuplicatePair,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Prices were published. [publisher, block number, number of prices]
+		PricesPublished(T::AccountId, T::BlockNumber, u32),
+
+		/// Pair was whitelisted. [asset in, asset out]
+		PairWhitelisted(AssetId, AssetId),
+
+		/// Pair was removed from whitelist. [asset in, asset out]
+		PairRemoved(AssetId, AssetId),
+
+		/// Publisher was added. [who]
+		PublisherAdded(T::AccountId),
+
+		/// Publisher was removed. [who]
+		PublisherRemoved(T::AccountId),
+	}
+
+	/// Pairs whose prices are published.
+	#[pallet::storage]
+	#[pallet::getter(fn pairs)]
+	pub type Pairs<T: Config> = StorageValue<_, Vec<AssetPair>, ValueQuery>;
+
+	/// Accounts of publisher keys allowed to submit prices.
+	#[pallet::storage]
+	#[pallet::getter(fn publishers)]
+	pub type Publishers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+	/// Last published price of a pair and block at which it was read.
+	#[pallet::storage]
+	#[pallet::getter(fn prices)]
+	pub type Prices<T: Config> =
+		StorageMap<_, Blake2_128Concat, (AssetId, AssetId), (Price, T::BlockNumber), OptionQuery>;
+
+	/// Block of the last published prices.
+	#[pallet::storage]
+	#[pallet::getter(fn last_published)]
+	pub type LastPublished<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub pairs: Vec<(AssetId, AssetId)>,
+		pub publishers: Vec<T::AccountId>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			GenesisConfig {
+				pairs: vec![],
+				publishers: vec![],
+			}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			assert!(
+				self.pairs.len() <= T::MaxPairs::get() as usize,
+				"Number of pairs exceeds MaxPairs"
+			);
+
+			Pairs::<T>::put(
+				self.pairs
+					.iter()
+					.map(|(asset_in, asset_out)| AssetPair {
+						asset_in: *asset_in,
+						asset_out: *asset_out,
+					})
+					.collect::<Vec<_>>(),
+			);
+
+			for publisher in self.publishers.iter() {
+				Publishers::<T>::insert(publisher, true);
+			}
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		/// Accept only prices signed by a registered publisher which are newer than the last published prices.
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			if let Call::submit_prices(ref payload, ref signature) = call {
+				if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+					return InvalidTransaction::BadProof.into();
+				}
+
+				if payload.block_number > frame_system::Pallet::<T>::block_number() {
+					return InvalidTransaction::Future.into();
+				}
+
+				Self::validate_payload(payload).map_err(|_| InvalidTransaction::Stale)?;
+
+				ValidTransaction::with_tag_prefix("PricePublisher")
+					.priority(T::UnsignedPriority::get())
+					.and_provides(payload.block_number)
+					.longevity(UNSIGNED_TXS_LONGEVITY)
+					.propagate(true)
+					.build()
+			} else {
+				InvalidTransaction::Call.into()
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Record prices submitted by the off-chain worker.
+		///
+		/// Signature of the payload is verified when the transaction is validated.
+		///
+		/// Emits `PricesPublished` event when successful.
+		#[pallet::weight((
+			<T as Config>::WeightInfo::submit_prices(payload.prices.len() as u32),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+		pub fn submit_prices(
+			origin: OriginFor<T>,
+			payload: PricePayload<T::Public, T::BlockNumber>,
+			_signature: T::Signature,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+
+			Self::validate_payload(&payload)?;
+
+			for (pair, price) in payload.prices.iter() {
+				Prices::<T>::insert((pair.asset_in, pair.asset_out), (price, payload.block_number));
+			}
+
+			LastPublished::<T>::put(payload.block_number);
+
+			Self::deposit_event(Event::PricesPublished(
+				payload.public.into_account(),
+				payload.block_number,
+				payload.prices.len() as u32,
+			));
+
+			Ok(().into())
+		}
+
+		/// Add pair to the list of pairs whose prices are published.
+		///
+		/// Emits `PairWhitelisted` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::add_pair())]
+		pub fn add_pair(origin: OriginFor<T>, asset_in: AssetId, asset_out: AssetId) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin)?;
+
+			let pair = AssetPair { asset_in, asset_out };
+
+			Pairs::<T>::try_mutate(|pairs| -> DispatchResult {
+				ensure!(!pairs.contains(&pair), Error::<T>::PairAlreadyWhitelisted);
+				ensure!(pairs.len() < T::MaxPairs::get() as usize, Error::<T>::TooManyPairs);
+
+				pairs.push(pair);
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PairWhitelisted(asset_in, asset_out));
+
+			Ok(().into())
+		}
+
+		/// Remove pair from the list of pairs whose prices are published together with its last price.
+		///
+		/// Emits `PairRemoved` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_pair())]
+		pub fn remove_pair(origin: OriginFor<T>, asset_in: AssetId, asset_out: AssetId) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin)?;
+
+			let pair = AssetPair { asset_in, asset_out };
+
+			Pairs::<T>::try_mutate(|pairs| -> DispatchResult {
+				ensure!(pairs.contains(&pair), Error::<T>::PairNotWhitelisted);
+
+				pairs.retain(|p| *p != pair);
+
+				Ok(())
+			})?;
+
+			Prices::<T>::remove((asset_in, asset_out));
+
+			Self::deposit_event(Event::PairRemoved(asset_in, asset_out));
+
+			Ok(().into())
+		}
+
+		/// Allow account of a publisher key to submit prices.
+		///
+		/// Emits `PublisherAdded` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::add_publisher())]
+		pub fn add_publisher(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin)?;
+
+			ensure!(!Self::publishers(&who), Error::<T>::AlreadyPublisher);
+
+			Publishers::<T>::insert(&who, true);
+
+			Self::deposit_event(Event::PublisherAdded(who));
+
+			Ok(().into())
+		}
+
+		/// Disallow account of a publisher key to submit prices.
+		///
+		/// Emits `PublisherRemoved` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_publisher())]
+		pub fn remove_publisher(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin)?;
+
+			ensure!(Self::publishers(&who), Error::<T>::NotPublisher);
+
+			Publishers::<T>::remove(&who);
+
+			Self::deposit_event(Event::PublisherRemoved(who));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Check that payload is submitted by a publisher, contains only distinct whitelisted pairs and its block
+	/// is newer than the last published prices but not ahead of the current block.
+	fn validate_payload(payload: &PricePayload<T::Public, T::BlockNumber>) -> DispatchResult {
+		ensure!(
+			Self::publishers(payload.public.clone().into_account()),
+			Error::<T>::NotPublisher
+		);
+
+		ensure!(payload.block_number > Self::last_published(), Error::<T>::StalePrices);
+		ensure!(
+			payload.block_number <= frame_system::Pallet::<T>::block_number(),
+			Error::<T>::FuturePrices
+		);
+
+		let pairs = Self::pairs();
+
+		ensure!(
+			payload.prices.iter().all(|(pair, _)| pairs.contains(pair)),
+			Error::<T>::PairNotWhitelisted
+		);
+
+		ensure!(
+			payload
+				.prices
+				.iter()
+				.enumerate()
+				.all(|(i, (pair, _))| payload.prices[..i].iter().all(|(other, _)| other != pair)),
+			Error::<T>::DuplicatePair
+		);
+
+		Ok(())
+	}
+
+	/// Return public keys of registered publishers available in the local keystore.
+	fn local_publishers() -> Vec<T::Public> {
+		<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::all()
+			.into_iter()
+			.map(|key| {
+				let generic: <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic = key.into();
+				generic.into()
+			})
+			.filter(|public: &T::Public| Self::publishers(public.clone().into_account()))
+			.collect()
+	}
+
+	/// Read prices of whitelisted pairs and submit them signed by a local publisher key.
+	fn publish_prices(now: T::BlockNumber) -> Result<(), &'static str> {
+		let prices: Vec<(AssetPair, Price)> = Self::pairs()
+			.into_iter()
+			.filter_map(|pair| T::PriceProvider::price(pair.asset_in, pair.asset_out).map(|price| (pair, price)))
+			.collect();
+
+		if prices.is_empty() {
+			return Ok(());
+		}
+
+		let publishers = Self::local_publishers();
+
+		if publishers.is_empty() {
+			return Ok(());
+		}
+
+		let (_, result) = Signer::<T, T::AuthorityId>::any_account()
+			.with_filter(publishers)
+			.send_unsigned_transaction(
+				|account| PricePayload {
+					block_number: now,
+					prices: prices.clone(),
+					public: account.public.clone(),
+				},
+				|payload, signature| Call::submit_prices(payload, signature),
+			)
+			.ok_or("No publisher key available")?;
+
+		result.map_err(|_| "Failed to submit prices")
+	}
+}
+
+/// Last published price of a pair. Price of the reversed pair is used if only the reversed pair is published.
+impl<T: Config> PriceProvider<AssetId, Price> for Pallet<T> {
+	fn price(asset_a: AssetId, asset_b: AssetId) -> Option<Price> {
+		Self::prices((asset_a, asset_b))
+			.map(|(price, _)| price)
+			.or_else(|| Self::prices((asset_b, asset_a)).and_then(|(price, _)| price.reciprocal()))
+	}
+}
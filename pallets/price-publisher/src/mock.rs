@@ -0,0 +1,235 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as price_publisher;
+use crate::Config;
+use frame_support::parameter_types;
+use frame_support::traits::GenesisBuild;
+use frame_system as system;
+use primitives::{traits::PriceProvider, AssetId, Price};
+use sp_core::{
+	offchain::{testing, OffchainExt, TransactionPoolExt},
+	sr25519::Signature,
+	H256,
+};
+use sp_keystore::{testing::KeyStore, KeystoreExt, SyncCryptoStore};
+use sp_runtime::{
+	testing::{Header, TestXt},
+	traits::{BlakeTwo256, Extrinsic as ExtrinsicT, IdentifyAccount, IdentityLookup, Verify},
+	RuntimeAppPublic,
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
+pub type Extrinsic = TestXt<Call, ()>;
+
+pub const HDX: AssetId = 0;
+pub const DOT: AssetId = 1;
+pub const ACA: AssetId = 2;
+
+const PHRASE: &str = "news slush supreme milk chapter athlete soap sausage put clutch what kitten";
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+	 Block = Block,
+	 NodeBlock = Block,
+	 UncheckedExtrinsic = UncheckedExtrinsic,
+	 {
+		 System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		 PricePublisher: price_publisher::{Pallet, Call, Storage, Event<T>, ValidateUnsigned},
+	 }
+
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+
+	pub const PublishInterval: u64 = 2;
+	pub const MaxPairs: u32 = 2;
+	pub const UnsignedPriority: u64 = 1 << 20;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+	Call: From<LocalCall>,
+{
+	type OverarchingCall = Call;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+	Call: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: Call,
+		_public: <Signature as Verify>::Signer,
+		_account: AccountId,
+		nonce: u64,
+	) -> Option<(Call, <Extrinsic as ExtrinsicT>::SignaturePayload)> {
+		Some((call, (nonce, ())))
+	}
+}
+
+thread_local! {
+	static PRICES: RefCell<HashMap<(AssetId, AssetId), Price>> = RefCell::new(HashMap::new());
+}
+
+pub struct MockPriceProvider;
+
+impl PriceProvider<AssetId, Price> for MockPriceProvider {
+	fn price(asset_a: AssetId, asset_b: AssetId) -> Option<Price> {
+		PRICES.with(|v| v.borrow().get(&(asset_a, asset_b)).copied())
+	}
+}
+
+pub fn set_price(asset_a: AssetId, asset_b: AssetId, price: Price) {
+	PRICES.with(|v| v.borrow_mut().insert((asset_a, asset_b), price));
+}
+
+impl Config for Test {
+	type Event = Event;
+	type AuthorityId = crate::crypto::PricePublisherAuthId;
+	type PriceProvider = MockPriceProvider;
+	type ManageOrigin = frame_system::EnsureRoot<AccountId>;
+	type PublishInterval = PublishInterval;
+	type MaxPairs = MaxPairs;
+	type UnsignedPriority = UnsignedPriority;
+	type WeightInfo = ();
+}
+
+/// Account of a key which is not in the keystore.
+pub fn account(seed: u8) -> AccountId {
+	sp_core::sr25519::Public::from_raw([seed; 32])
+}
+
+pub struct ExtBuilder {
+	pairs: Vec<(AssetId, AssetId)>,
+	local_publisher: bool,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			pairs: vec![],
+			local_publisher: false,
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_pairs(mut self, pairs: Vec<(AssetId, AssetId)>) -> Self {
+		self.pairs = pairs;
+		self
+	}
+
+	/// Register key in the keystore as a publisher.
+	pub fn with_local_publisher(mut self) -> Self {
+		self.local_publisher = true;
+		self
+	}
+
+	/// Build externalities with offchain extensions and return them together with transaction pool state
+	/// and public key in the keystore.
+	pub fn build_with_offchain(
+		self,
+	) -> (
+		sp_io::TestExternalities,
+		Arc<parking_lot::RwLock<testing::PoolState>>,
+		<Signature as Verify>::Signer,
+	) {
+		PRICES.with(|v| v.borrow_mut().clear());
+
+		let keystore = KeyStore::new();
+		SyncCryptoStore::sr25519_generate_new(
+			&keystore,
+			crate::crypto::Public::ID,
+			Some(&format!("{}/hunter1", PHRASE)),
+		)
+		.unwrap();
+		let public = *SyncCryptoStore::sr25519_public_keys(&keystore, crate::crypto::Public::ID)
+			.get(0)
+			.unwrap();
+
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		let publishers = if self.local_publisher {
+			vec![public.into_account()]
+		} else {
+			vec![]
+		};
+
+		price_publisher::GenesisConfig::<Test> {
+			pairs: self.pairs,
+			publishers,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let (offchain, _) = testing::TestOffchainExt::new();
+		let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+
+		let mut ext: sp_io::TestExternalities = t.into();
+		ext.register_extension(OffchainExt::new(offchain));
+		ext.register_extension(TransactionPoolExt::new(pool));
+		ext.register_extension(KeystoreExt(Arc::new(keystore)));
+		ext.execute_with(|| System::set_block_number(1));
+
+		(ext, pool_state, public)
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		self.build_with_offchain().0
+	}
+}
@@ -0,0 +1,380 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+pub use crate::mock::{
+	account, set_price, Call as TestCall, Event as TestEvent, ExtBuilder, Extrinsic, Origin, PricePublisher, System,
+	Test, ACA, DOT, HDX,
+};
+use frame_support::{assert_noop, assert_ok, traits::OffchainWorker, unsigned::ValidateUnsigned};
+use sp_runtime::transaction_validity::TransactionSource;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default().build()
+}
+
+fn last_events(n: usize) -> Vec<TestEvent> {
+	frame_system::Pallet::<Test>::events()
+		.into_iter()
+		.rev()
+		.take(n)
+		.rev()
+		.map(|e| e.event)
+		.collect()
+}
+
+fn expect_events(e: Vec<TestEvent>) {
+	assert_eq!(last_events(e.len()), e);
+}
+
+fn pair(asset_in: AssetId, asset_out: AssetId) -> AssetPair {
+	AssetPair { asset_in, asset_out }
+}
+
+fn sign(
+	public: sp_core::sr25519::Public,
+	payload: &PricePayload<sp_core::sr25519::Public, u64>,
+) -> sp_core::sr25519::Signature {
+	let key: crypto::Public = public.into();
+	payload.using_encoded(|p| key.sign(&p)).unwrap().into()
+}
+
+#[test]
+fn add_pair_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PricePublisher::add_pair(Origin::signed(account(1)), HDX, DOT),
+			sp_runtime::traits::BadOrigin
+		);
+
+		assert_ok!(PricePublisher::add_pair(Origin::root(), HDX, DOT));
+		assert_eq!(PricePublisher::pairs(), vec![pair(HDX, DOT)]);
+
+		assert_noop!(
+			PricePublisher::add_pair(Origin::root(), HDX, DOT),
+			Error::<Test>::PairAlreadyWhitelisted
+		);
+
+		assert_ok!(PricePublisher::add_pair(Origin::root(), DOT, HDX));
+		assert_noop!(
+			PricePublisher::add_pair(Origin::root(), HDX, ACA),
+			Error::<Test>::TooManyPairs
+		);
+
+		expect_events(vec![
+			Event::PairWhitelisted(HDX, DOT).into(),
+			Event::PairWhitelisted(DOT, HDX).into(),
+		]);
+	});
+}
+
+#[test]
+fn remove_pair_should_remove_its_price() {
+	ExtBuilder::default()
+		.with_pairs(vec![(HDX, DOT)])
+		.build()
+		.execute_with(|| {
+			Prices::<Test>::insert((HDX, DOT), (Price::from(2), 1));
+
+			assert_noop!(
+				PricePublisher::remove_pair(Origin::root(), HDX, ACA),
+				Error::<Test>::PairNotWhitelisted
+			);
+
+			assert_ok!(PricePublisher::remove_pair(Origin::root(), HDX, DOT));
+
+			assert!(PricePublisher::pairs().is_empty());
+			assert_eq!(PricePublisher::prices((HDX, DOT)), None);
+
+			expect_events(vec![Event::PairRemoved(HDX, DOT).into()]);
+		});
+}
+
+#[test]
+fn add_and_remove_publisher_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PricePublisher::add_publisher(Origin::signed(account(1)), account(1)),
+			sp_runtime::traits::BadOrigin
+		);
+
+		assert_ok!(PricePublisher::add_publisher(Origin::root(), account(1)));
+		assert!(PricePublisher::publishers(account(1)));
+
+		assert_noop!(
+			PricePublisher::add_publisher(Origin::root(), account(1)),
+			Error::<Test>::AlreadyPublisher
+		);
+
+		assert_ok!(PricePublisher::remove_publisher(Origin::root(), account(1)));
+		assert!(!PricePublisher::publishers(account(1)));
+
+		assert_noop!(
+			PricePublisher::remove_publisher(Origin::root(), account(1)),
+			Error::<Test>::NotPublisher
+		);
+
+		expect_events(vec![
+			Event::PublisherAdded(account(1)).into(),
+			Event::PublisherRemoved(account(1)).into(),
+		]);
+	});
+}
+
+#[test]
+fn submit_prices_should_work() {
+	let (mut ext, _, public) = ExtBuilder::default()
+		.with_pairs(vec![(HDX, DOT), (DOT, ACA)])
+		.with_local_publisher()
+		.build_with_offchain();
+
+	ext.execute_with(|| {
+		let payload = PricePayload {
+			block_number: 1,
+			prices: vec![(pair(HDX, DOT), Price::from(2)), (pair(DOT, ACA), Price::from(3))],
+			public,
+		};
+		let signature = sign(public, &payload);
+
+		assert_noop!(
+			PricePublisher::submit_prices(Origin::signed(public), payload.clone(), signature.clone()),
+			sp_runtime::traits::BadOrigin
+		);
+
+		assert_ok!(PricePublisher::submit_prices(Origin::none(), payload, signature));
+
+		assert_eq!(PricePublisher::prices((HDX, DOT)), Some((Price::from(2), 1)));
+		assert_eq!(PricePublisher::prices((DOT, ACA)), Some((Price::from(3), 1)));
+		assert_eq!(PricePublisher::last_published(), 1);
+
+		expect_events(vec![Event::PricesPublished(public, 1, 2).into()]);
+	});
+}
+
+#[test]
+fn published_prices_should_be_provided() {
+	let (mut ext, _, public) = ExtBuilder::default()
+		.with_pairs(vec![(HDX, DOT)])
+		.with_local_publisher()
+		.build_with_offchain();
+
+	ext.execute_with(|| {
+		let payload = PricePayload {
+			block_number: 1,
+			prices: vec![(pair(HDX, DOT), Price::from(2))],
+			public,
+		};
+		let signature = sign(public, &payload);
+
+		assert_ok!(PricePublisher::submit_prices(Origin::none(), payload, signature));
+
+		assert_eq!(
+			<PricePublisher as PriceProvider<_, _>>::price(HDX, DOT),
+			Some(Price::from(2))
+		);
+		assert_eq!(
+			<PricePublisher as PriceProvider<_, _>>::price(DOT, HDX),
+			Some(Price::saturating_from_rational(1, 2))
+		);
+		assert_eq!(<PricePublisher as PriceProvider<_, _>>::price(HDX, ACA), None);
+	});
+}
+
+#[test]
+fn submit_prices_should_not_work_with_invalid_payload() {
+	let (mut ext, _, public) = ExtBuilder::default()
+		.with_pairs(vec![(HDX, DOT)])
+		.with_local_publisher()
+		.build_with_offchain();
+
+	ext.execute_with(|| {
+		System::set_block_number(3);
+
+		let payload = PricePayload {
+			block_number: 2,
+			prices: vec![(pair(HDX, ACA), Price::from(2))],
+			public,
+		};
+		let signature = sign(public, &payload);
+
+		assert_noop!(
+			PricePublisher::submit_prices(Origin::none(), payload, signature),
+			Error::<Test>::PairNotWhitelisted
+		);
+
+		let payload = PricePayload {
+			block_number: 2,
+			prices: vec![(pair(HDX, DOT), Price::from(2)), (pair(HDX, DOT), Price::from(3))],
+			public,
+		};
+		let signature = sign(public, &payload);
+
+		assert_noop!(
+			PricePublisher::submit_prices(Origin::none(), payload, signature),
+			Error::<Test>::DuplicatePair
+		);
+
+		let payload = PricePayload {
+			block_number: 4,
+			prices: vec![(pair(HDX, DOT), Price::from(2))],
+			public,
+		};
+		let signature = sign(public, &payload);
+
+		assert_noop!(
+			PricePublisher::submit_prices(Origin::none(), payload.clone(), signature.clone()),
+			Error::<Test>::FuturePrices
+		);
+		assert_eq!(
+			PricePublisher::validate_unsigned(TransactionSource::External, &Call::submit_prices(payload, signature)),
+			InvalidTransaction::Future.into()
+		);
+
+		let payload = PricePayload {
+			block_number: 2,
+			prices: vec![(pair(HDX, DOT), Price::from(2))],
+			public,
+		};
+		let signature = sign(public, &payload);
+
+		assert_ok!(PricePublisher::submit_prices(
+			Origin::none(),
+			payload.clone(),
+			signature.clone()
+		));
+
+		assert_noop!(
+			PricePublisher::submit_prices(Origin::none(), payload, signature),
+			Error::<Test>::StalePrices
+		);
+
+		assert_ok!(PricePublisher::remove_publisher(Origin::root(), public));
+
+		let payload = PricePayload {
+			block_number: 3,
+			prices: vec![(pair(HDX, DOT), Price::from(2))],
+			public,
+		};
+		let signature = sign(public, &payload);
+
+		assert_noop!(
+			PricePublisher::submit_prices(Origin::none(), payload, signature),
+			Error::<Test>::NotPublisher
+		);
+	});
+}
+
+#[test]
+fn validate_unsigned_should_check_signature() {
+	let (mut ext, _, public) = ExtBuilder::default()
+		.with_pairs(vec![(HDX, DOT)])
+		.with_local_publisher()
+		.build_with_offchain();
+
+	ext.execute_with(|| {
+		let payload = PricePayload {
+			block_number: 1,
+			prices: vec![(pair(HDX, DOT), Price::from(2))],
+			public,
+		};
+		let signature = sign(public, &payload);
+
+		assert!(PricePublisher::validate_unsigned(
+			TransactionSource::External,
+			&Call::submit_prices(payload.clone(), signature.clone())
+		)
+		.is_ok());
+
+		let tampered = PricePayload {
+			prices: vec![(pair(HDX, DOT), Price::from(3))],
+			..payload.clone()
+		};
+
+		assert_eq!(
+			PricePublisher::validate_unsigned(TransactionSource::External, &Call::submit_prices(tampered, signature)),
+			InvalidTransaction::BadProof.into()
+		);
+
+		let signed_by_other = PricePayload {
+			public: account(1),
+			..payload
+		};
+		let signature = sign(public, &signed_by_other);
+
+		assert_eq!(
+			PricePublisher::validate_unsigned(
+				TransactionSource::External,
+				&Call::submit_prices(signed_by_other, signature)
+			),
+			InvalidTransaction::BadProof.into()
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_should_submit_prices() {
+	let (mut ext, pool_state, public) = ExtBuilder::default()
+		.with_pairs(vec![(HDX, DOT), (DOT, ACA)])
+		.with_local_publisher()
+		.build_with_offchain();
+
+	ext.execute_with(|| {
+		set_price(HDX, DOT, Price::from(2));
+
+		// Prices are published every `PublishInterval` blocks
+		<PricePublisher as OffchainWorker<u64>>::offchain_worker(1);
+
+		assert!(pool_state.read().transactions.is_empty());
+
+		<PricePublisher as OffchainWorker<u64>>::offchain_worker(2);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.signature, None);
+
+		if let TestCall::PricePublisher(Call::submit_prices(payload, signature)) = tx.call {
+			assert_eq!(
+				payload,
+				PricePayload {
+					block_number: 2,
+					prices: vec![(pair(HDX, DOT), Price::from(2))],
+					public,
+				}
+			);
+			assert!(SignedPayload::<Test>::verify::<crypto::PricePublisherAuthId>(
+				&payload, signature
+			));
+		} else {
+			panic!("Unexpected call: {:?}", tx.call);
+		}
+	});
+}
+
+#[test]
+fn offchain_worker_should_not_submit_prices_without_publisher_key() {
+	let (mut ext, pool_state, _) = ExtBuilder::default().with_pairs(vec![(HDX, DOT)]).build_with_offchain();
+
+	ext.execute_with(|| {
+		set_price(HDX, DOT, Price::from(2));
+
+		<PricePublisher as OffchainWorker<u64>>::offchain_worker(2);
+
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
@@ -0,0 +1,83 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+use crate::Pallet as Referrals;
+
+const SEED: u32 = 1;
+
+const REWARD_ASSET: AssetId = 1;
+
+fn code(length: u32) -> Vec<u8> {
+	vec![b'A'; length as usize]
+}
+
+benchmarks! {
+	register_code {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let code = code(T::MaxCodeLength::get());
+
+	}: _(RawOrigin::Signed(caller.clone()), code.clone())
+	verify {
+		assert_eq!(Referrals::<T>::referral_code(code), Some(caller));
+	}
+
+	link_code {
+		let referrer: T::AccountId = account("referrer", 0, SEED);
+		let caller: T::AccountId = account("caller", 1, SEED);
+		let code = code(T::MaxCodeLength::get());
+		Referrals::<T>::register_code(RawOrigin::Signed(referrer.clone()).into(), code.clone()).map_err(|e| e.error)?;
+
+	}: _(RawOrigin::Signed(caller.clone()), code)
+	verify {
+		assert_eq!(Referrals::<T>::linked_account(caller), Some(referrer));
+	}
+
+	claim_rewards {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let reward: Balance = 1_000_000_000;
+
+		T::Currency::deposit(REWARD_ASSET, &Referrals::<T>::pot_account(), reward)?;
+		Rewards::<T>::insert(&caller, REWARD_ASSET, reward);
+
+	}: _(RawOrigin::Signed(caller.clone()), REWARD_ASSET)
+	verify {
+		assert_eq!(T::Currency::free_balance(REWARD_ASSET, &caller), reward);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_register_code::<Test>());
+			assert_ok!(test_benchmark_link_code::<Test>());
+			assert_ok!(test_benchmark_claim_rewards::<Test>());
+		});
+	}
+}
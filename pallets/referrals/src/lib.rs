@@ -0,0 +1,349 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::{Get, GetPalletVersion},
+	transactional,
+	weights::Weight,
+	PalletId,
+};
+use frame_system::ensure_signed;
+use orml_traits::{GetByKey, MultiCurrency};
+use primitives::{
+	asset::AssetPair,
+	traits::{AMMHandlers, AMMTransfer, DustRemovalAccountWhitelist, LiquidityChange},
+	AssetId, Balance, IntentionType,
+};
+use sp_runtime::{
+	traits::{AccountIdConversion, Zero},
+	Permill, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+pub use pallet::*;
+
+/// Referrer tier given by number of accounts linked to the referral code.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug)]
+pub enum Tier {
+	Bronze,
+	Silver,
+	Gold,
+}
+
+/// Referral code registered by a referrer and number of accounts linked to it.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct ReferrerInfo {
+	pub code: Vec<u8>,
+	pub referees: u32,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Whitelist the pot account when the pallet is added to a running chain.
+		fn on_runtime_upgrade() -> Weight {
+			if <Pallet<T> as GetPalletVersion>::storage_version().is_none() {
+				let _ = T::NonDustableWhitelistHandler::add_account(&Self::pot_account());
+				T::DbWeight::get().reads_writes(1, 1)
+			} else {
+				T::DbWeight::get().reads(1)
+			}
+		}
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Multi currency for transferring trade fee shares
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// Pallet id of the account holding accrued rewards until they are claimed
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Minimal length of a referral code
+		#[pallet::constant]
+		type MinCodeLength: Get<u32>;
+
+		/// Maximal length of a referral code
+		#[pallet::constant]
+		type MaxCodeLength: Get<u32>;
+
+		/// Minimal number of linked accounts for a referrer to reach a tier
+		type TierReferees: GetByKey<Tier, u32>;
+
+		/// Share of the trade fee of linked accounts accrued to the referrer of a tier
+		type TierRewardShare: GetByKey<Tier, Permill>;
+
+		/// Whitelist of accounts which cannot be dusted, the pot account is added to it
+		type NonDustableWhitelistHandler: DustRemovalAccountWhitelist<Self::AccountId>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Referral code is shorter than `MinCodeLength`.
+		CodeTooShort,
+
+		/// Referral code is longer than `MaxCodeLength`.
+		CodeTooLong,
+
+		/// Referral code contains characters other than ASCII letters and digits.
+		InvalidCharacter,
+
+		/// Referral code is already registered.
+		CodeAlreadyExists,
+
+		/// Account already registered a referral code.
+		AlreadyRegistered,
+
+		/// Referral code is not registered.
+		InvalidCode,
+
+		/// Account is already linked to a referral code.
+		AlreadyLinked,
+
+		/// Referrer cannot link own referral code.
+		LinkNotAllowed,
+
+		/// Account has no rewards of the asset to claim.
+		NoRewards,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Referral code was registered. [referrer, code]
+		CodeRegistered(T::AccountId, Vec<u8>),
+
+		/// Account was linked to a referral code. [who, code, referrer]
+		CodeLinked(T::AccountId, Vec<u8>, T::AccountId),
+
+		/// Share of the trade fee of a linked account accrued to the referrer. [referrer, asset, amount]
+		RewardAccrued(T::AccountId, AssetId, Balance),
+
+		/// Referrer claimed accrued rewards. [referrer, asset, amount]
+		RewardsClaimed(T::AccountId, AssetId, Balance),
+	}
+
+	/// Referrers of registered referral codes.
+	#[pallet::storage]
+	#[pallet::getter(fn referral_code)]
+	pub type ReferralCodes<T: Config> = StorageMap<_, Blake2_128Concat, Vec<u8>, T::AccountId, OptionQuery>;
+
+	/// Referral codes registered by referrers.
+	#[pallet::storage]
+	#[pallet::getter(fn referrer)]
+	pub type Referrers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ReferrerInfo, OptionQuery>;
+
+	/// Referrers of accounts linked to a referral code.
+	#[pallet::storage]
+	#[pallet::getter(fn linked_account)]
+	pub type LinkedAccounts<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	/// Accrued rewards of referrers which were not claimed yet.
+	#[pallet::storage]
+	#[pallet::getter(fn rewards)]
+	pub type Rewards<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, AssetId, Balance, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(Default)]
+	pub struct GenesisConfig {}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig {
+		fn build(&self) {
+			let _ = T::NonDustableWhitelistHandler::add_account(&Pallet::<T>::pot_account());
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register referral `code` of `origin`.
+		///
+		/// Code must be unique, between `MinCodeLength` and `MaxCodeLength` long and contain only ASCII letters
+		/// and digits. Each account can register one code.
+		///
+		/// Emits `CodeRegistered` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::register_code())]
+		pub fn register_code(origin: OriginFor<T>, code: Vec<u8>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(code.len() >= T::MinCodeLength::get() as usize, Error::<T>::CodeTooShort);
+			ensure!(code.len() <= T::MaxCodeLength::get() as usize, Error::<T>::CodeTooLong);
+			ensure!(
+				code.iter().all(|c| c.is_ascii_alphanumeric()),
+				Error::<T>::InvalidCharacter
+			);
+
+			ensure!(!Referrers::<T>::contains_key(&who), Error::<T>::AlreadyRegistered);
+			ensure!(!ReferralCodes::<T>::contains_key(&code), Error::<T>::CodeAlreadyExists);
+
+			ReferralCodes::<T>::insert(&code, &who);
+			Referrers::<T>::insert(
+				&who,
+				ReferrerInfo {
+					code: code.clone(),
+					referees: 0,
+				},
+			);
+
+			Self::deposit_event(Event::CodeRegistered(who, code));
+
+			Ok(().into())
+		}
+
+		/// Link `origin` to referral `code`.
+		///
+		/// Referrer of the code accrues share of the trade fee of all trades of `origin` given by the tier
+		/// of the referrer. Account can be linked only once and referrer cannot link own code.
+		///
+		/// Emits `CodeLinked` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::link_code())]
+		pub fn link_code(origin: OriginFor<T>, code: Vec<u8>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let referrer = ReferralCodes::<T>::get(&code).ok_or(Error::<T>::InvalidCode)?;
+
+			ensure!(referrer != who, Error::<T>::LinkNotAllowed);
+			ensure!(!LinkedAccounts::<T>::contains_key(&who), Error::<T>::AlreadyLinked);
+
+			LinkedAccounts::<T>::insert(&who, &referrer);
+			Referrers::<T>::mutate(&referrer, |maybe_info| {
+				if let Some(info) = maybe_info {
+					info.referees = info.referees.saturating_add(1);
+				}
+			});
+
+			Self::deposit_event(Event::CodeLinked(who, code, referrer));
+
+			Ok(().into())
+		}
+
+		/// Claim all accrued rewards of `asset_id` of `origin`.
+		///
+		/// Emits `RewardsClaimed` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::claim_rewards())]
+		#[transactional]
+		pub fn claim_rewards(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let amount = Rewards::<T>::take(&who, asset_id);
+
+			ensure!(!amount.is_zero(), Error::<T>::NoRewards);
+
+			T::Currency::transfer(asset_id, &Self::pot_account(), &who, amount)?;
+
+			Self::deposit_event(Event::RewardsClaimed(who, asset_id, amount));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account holding accrued rewards until they are claimed.
+	pub fn pot_account() -> T::AccountId {
+		T::PalletId::get().into_account()
+	}
+
+	/// Return the highest tier whose `TierReferees` is reached by `referees` linked accounts.
+	pub fn tier(referees: u32) -> Tier {
+		[Tier::Gold, Tier::Silver]
+			.iter()
+			.find(|tier| referees >= T::TierReferees::get(tier))
+			.copied()
+			.unwrap_or(Tier::Bronze)
+	}
+
+	/// Transfer share of the trade `fee` in `asset` from `pool` to the pot account and accrue it to the referrer
+	/// of `who`, if any.
+	fn accrue_reward(who: &T::AccountId, pool: &T::AccountId, asset: AssetId, fee: Balance) {
+		let referrer = match LinkedAccounts::<T>::get(who) {
+			Some(referrer) => referrer,
+			None => return,
+		};
+
+		let referees = Referrers::<T>::get(&referrer)
+			.map(|info| info.referees)
+			.unwrap_or_default();
+		let reward = T::TierRewardShare::get(&Self::tier(referees)).mul_floor(fee);
+
+		if reward.is_zero() || T::Currency::transfer(asset, pool, &Self::pot_account(), reward).is_err() {
+			return;
+		}
+
+		Rewards::<T>::mutate(&referrer, asset, |accrued| *accrued = accrued.saturating_add(reward));
+
+		Self::deposit_event(Event::RewardAccrued(referrer, asset, reward));
+	}
+}
+
+/// Trade fee is paid in `asset_in` of the transfer for both sells and buys.
+impl<T: Config> AMMHandlers<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
+	fn on_before_trade(
+		_pool: &T::AccountId,
+		_trade_type: IntentionType,
+		_transfer: &AMMTransfer<T::AccountId, AssetPair, Balance>,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn on_trade(
+		pool: &T::AccountId,
+		_trade_type: IntentionType,
+		transfer: &AMMTransfer<T::AccountId, AssetPair, Balance>,
+	) {
+		Self::accrue_reward(&transfer.origin, pool, transfer.assets.asset_in, transfer.fee);
+	}
+
+	fn on_liquidity_changed(_pool: &T::AccountId, _change: &LiquidityChange<T::AccountId, AssetId, Balance>) {}
+
+	/// Linked account and referrer are read, reward is transferred to the pot and accrued.
+	fn on_trade_weight() -> Weight {
+		T::DbWeight::get().reads_writes(5, 3)
+	}
+}
@@ -0,0 +1,220 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+pub use crate::mock::{
+	AccountId, Currency, ExtBuilder, Origin, Referrals, System, Test, ALICE, BOB, CHARLIE, DAVE, DOT, HDX, POOL,
+};
+use frame_support::{assert_noop, assert_ok};
+use primitives::traits::TradeSource;
+use test_utils::{assert_balance, expect_events};
+
+const INITIAL_BALANCE: Balance = 1_000_000_000_000;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn trade(who: AccountId, asset_in: AssetId, asset_out: AssetId, fee: Balance) {
+	Referrals::on_trade(
+		&POOL,
+		IntentionType::SELL,
+		&AMMTransfer {
+			origin: who,
+			assets: AssetPair { asset_in, asset_out },
+			amount: 1_000_000,
+			amount_out: 1_000_000,
+			discount: false,
+			discount_amount: 0,
+			fee,
+			source: TradeSource::Direct,
+			fees: None,
+		},
+	);
+}
+
+#[test]
+fn register_code_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Referrals::register_code(Origin::signed(ALICE), b"HYDRA".to_vec()));
+
+		assert_eq!(Referrals::referral_code(b"HYDRA".to_vec()), Some(ALICE));
+		assert_eq!(
+			Referrals::referrer(ALICE),
+			Some(ReferrerInfo {
+				code: b"HYDRA".to_vec(),
+				referees: 0
+			})
+		);
+
+		expect_events::<Test>(vec![Event::CodeRegistered(ALICE, b"HYDRA".to_vec()).into()]);
+	});
+}
+
+#[test]
+fn register_invalid_code_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Referrals::register_code(Origin::signed(ALICE), b"HDX".to_vec()),
+			Error::<Test>::CodeTooShort
+		);
+		assert_noop!(
+			Referrals::register_code(Origin::signed(ALICE), b"HYDRADXHDX".to_vec()),
+			Error::<Test>::CodeTooLong
+		);
+		assert_noop!(
+			Referrals::register_code(Origin::signed(ALICE), b"HYDRA-DX".to_vec()),
+			Error::<Test>::InvalidCharacter
+		);
+	});
+}
+
+#[test]
+fn register_code_twice_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Referrals::register_code(Origin::signed(ALICE), b"HYDRA".to_vec()));
+
+		assert_noop!(
+			Referrals::register_code(Origin::signed(ALICE), b"BASILISK".to_vec()),
+			Error::<Test>::AlreadyRegistered
+		);
+		assert_noop!(
+			Referrals::register_code(Origin::signed(BOB), b"HYDRA".to_vec()),
+			Error::<Test>::CodeAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn link_code_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Referrals::register_code(Origin::signed(ALICE), b"HYDRA".to_vec()));
+
+		assert_ok!(Referrals::link_code(Origin::signed(BOB), b"HYDRA".to_vec()));
+
+		assert_eq!(Referrals::linked_account(BOB), Some(ALICE));
+		assert_eq!(Referrals::referrer(ALICE).unwrap().referees, 1);
+
+		expect_events::<Test>(vec![Event::CodeLinked(BOB, b"HYDRA".to_vec(), ALICE).into()]);
+	});
+}
+
+#[test]
+fn link_code_should_not_work_when_not_allowed() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Referrals::link_code(Origin::signed(BOB), b"HYDRA".to_vec()),
+			Error::<Test>::InvalidCode
+		);
+
+		assert_ok!(Referrals::register_code(Origin::signed(ALICE), b"HYDRA".to_vec()));
+		assert_ok!(Referrals::register_code(Origin::signed(CHARLIE), b"BASILISK".to_vec()));
+
+		assert_noop!(
+			Referrals::link_code(Origin::signed(ALICE), b"HYDRA".to_vec()),
+			Error::<Test>::LinkNotAllowed
+		);
+
+		assert_ok!(Referrals::link_code(Origin::signed(BOB), b"HYDRA".to_vec()));
+
+		assert_noop!(
+			Referrals::link_code(Origin::signed(BOB), b"BASILISK".to_vec()),
+			Error::<Test>::AlreadyLinked
+		);
+	});
+}
+
+#[test]
+fn tier_should_depend_on_referees() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Referrals::tier(0), Tier::Bronze);
+		assert_eq!(Referrals::tier(1), Tier::Bronze);
+		assert_eq!(Referrals::tier(2), Tier::Silver);
+		assert_eq!(Referrals::tier(3), Tier::Gold);
+		assert_eq!(Referrals::tier(100), Tier::Gold);
+	});
+}
+
+#[test]
+fn trade_of_linked_account_should_accrue_reward() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Referrals::register_code(Origin::signed(ALICE), b"HYDRA".to_vec()));
+		assert_ok!(Referrals::link_code(Origin::signed(BOB), b"HYDRA".to_vec()));
+
+		trade(BOB, DOT, HDX, 1_000);
+
+		assert_eq!(Referrals::rewards(ALICE, DOT), 100);
+		assert_balance!(Currency, Referrals::pot_account(), DOT, 100);
+		assert_balance!(Currency, POOL, DOT, INITIAL_BALANCE - 100);
+
+		expect_events::<Test>(vec![Event::RewardAccrued(ALICE, DOT, 100).into()]);
+	});
+}
+
+#[test]
+fn reward_share_should_depend_on_tier() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Referrals::register_code(Origin::signed(ALICE), b"HYDRA".to_vec()));
+		assert_ok!(Referrals::link_code(Origin::signed(BOB), b"HYDRA".to_vec()));
+		assert_ok!(Referrals::link_code(Origin::signed(CHARLIE), b"HYDRA".to_vec()));
+
+		trade(BOB, DOT, HDX, 1_000);
+		assert_eq!(Referrals::rewards(ALICE, DOT), 200);
+
+		assert_ok!(Referrals::link_code(Origin::signed(DAVE), b"HYDRA".to_vec()));
+
+		trade(BOB, HDX, DOT, 1_000);
+		assert_eq!(Referrals::rewards(ALICE, HDX), 500);
+	});
+}
+
+#[test]
+fn trade_of_account_without_referrer_should_not_accrue_reward() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Referrals::register_code(Origin::signed(ALICE), b"HYDRA".to_vec()));
+
+		trade(BOB, DOT, HDX, 1_000);
+
+		assert_eq!(Referrals::rewards(ALICE, DOT), 0);
+		assert_balance!(Currency, POOL, DOT, INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn claim_rewards_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Referrals::register_code(Origin::signed(ALICE), b"HYDRA".to_vec()));
+		assert_ok!(Referrals::link_code(Origin::signed(BOB), b"HYDRA".to_vec()));
+
+		trade(BOB, DOT, HDX, 1_000);
+		trade(BOB, DOT, HDX, 2_000);
+
+		assert_ok!(Referrals::claim_rewards(Origin::signed(ALICE), DOT));
+
+		assert_balance!(Currency, ALICE, DOT, 300);
+		assert_balance!(Currency, Referrals::pot_account(), DOT, 0);
+		assert_eq!(Referrals::rewards(ALICE, DOT), 0);
+
+		expect_events::<Test>(vec![Event::RewardsClaimed(ALICE, DOT, 300).into()]);
+
+		assert_noop!(
+			Referrals::claim_rewards(Origin::signed(ALICE), DOT),
+			Error::<Test>::NoRewards
+		);
+	});
+}
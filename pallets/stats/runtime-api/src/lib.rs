@@ -0,0 +1,33 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for stats pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+pub use pallet_stats::PeriodStats;
+use primitives::AssetId;
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	pub trait StatsApi {
+		/// Return statistics of the pool of `asset_a` and `asset_b` in the retained periods ordered from
+		/// the oldest one. Periods without any trade or liquidity change of the pool are missing.
+		fn pool_stats(asset_a: AssetId, asset_b: AssetId) -> Vec<PeriodStats>;
+	}
+}
@@ -0,0 +1,208 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! # Stats Pallet
+//!
+//! ## Overview
+//!
+//! Stats pallet keeps on-chain history of basic pool statistics - traded volume, trade fees, number of trades
+//! and reserves at the end of each period - for the last `RetentionPeriods` periods of `PeriodLength` blocks.
+//!
+//! Statistics are updated by AMM handlers on each trade and liquidity change and stored in a ring buffer,
+//! so data older than the retention window are overwritten without any explicit pruning.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{dispatch::DispatchResult, traits::Get, weights::Weight};
+use orml_traits::MultiCurrency;
+use primitives::{
+	asset::{AssetPair, OrderedAssetPair},
+	traits::{AMMHandlers, AMMTransfer, LiquidityChange},
+	AssetId, Balance, IntentionType,
+};
+use sp_runtime::{traits::SaturatedConversion, RuntimeDebug};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// Statistics of a pool in a single period.
+///
+/// Values of `asset_a` and `asset_b` follow the order of `OrderedAssetPair` of the pool.
+#[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct PeriodStats {
+	/// Index of the period, block number divided by `PeriodLength`
+	pub period: u32,
+	/// Traded amount of `asset_a`, both sold and bought
+	pub volume_a: Balance,
+	/// Traded amount of `asset_b`, both sold and bought
+	pub volume_b: Balance,
+	/// Trade fees paid in `asset_a`
+	pub fee_a: Balance,
+	/// Trade fees paid in `asset_b`
+	pub fee_b: Balance,
+	/// Number of trades
+	pub trades: u32,
+	/// Reserve of `asset_a` after the last change of the pool in the period
+	pub reserve_a: Balance,
+	/// Reserve of `asset_b` after the last change of the pool in the period
+	pub reserve_b: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Multi currency used to read pool reserves
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// Number of blocks of a single period, e.g. one day
+		#[pallet::constant]
+		type PeriodLength: Get<Self::BlockNumber>;
+
+		/// Number of the most recent periods kept for each pool
+		#[pallet::constant]
+		type RetentionPeriods: Get<u32>;
+	}
+
+	/// Statistics of pools stored in a ring buffer of `RetentionPeriods` slots per pool.
+	#[pallet::storage]
+	#[pallet::getter(fn period_stats)]
+	pub type PoolStats<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, OrderedAssetPair, Twox64Concat, u32, PeriodStats, OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Return index of the current period.
+	pub fn current_period() -> u32 {
+		let period_length = T::PeriodLength::get().max(1u32.into());
+
+		(<frame_system::Pallet<T>>::block_number() / period_length).saturated_into()
+	}
+
+	/// Return statistics of the pool of `asset_a` and `asset_b` in the retained periods ordered from the oldest one.
+	///
+	/// Periods without any change of the pool are missing, reserves of such periods are equal to reserves
+	/// of the preceding period.
+	pub fn pool_stats(asset_a: AssetId, asset_b: AssetId) -> Vec<PeriodStats> {
+		let retention = T::RetentionPeriods::get();
+
+		if retention == 0 {
+			return Vec::new();
+		}
+
+		let assets = OrderedAssetPair::new(asset_a, asset_b);
+		let current = Self::current_period();
+
+		(current.saturating_sub(retention - 1)..=current)
+			.filter_map(|period| Self::period_stats(assets, period % retention).filter(|stats| stats.period == period))
+			.collect()
+	}
+
+	/// Apply `f` to statistics of the current period of `pool` and record its reserves after the change.
+	///
+	/// Slot of the ring buffer holding statistics of an expired period is reset first.
+	fn update(pool: &T::AccountId, assets: OrderedAssetPair, f: impl FnOnce(&mut PeriodStats)) {
+		let retention = T::RetentionPeriods::get();
+
+		if retention == 0 {
+			return;
+		}
+
+		let period = Self::current_period();
+
+		<PoolStats<T>>::mutate(assets, period % retention, |maybe_stats| {
+			let mut stats = match maybe_stats {
+				Some(stats) if stats.period == period => *stats,
+				_ => PeriodStats {
+					period,
+					..Default::default()
+				},
+			};
+
+			f(&mut stats);
+
+			stats.reserve_a = T::Currency::free_balance(assets.asset_a, pool);
+			stats.reserve_b = T::Currency::free_balance(assets.asset_b, pool);
+
+			*maybe_stats = Some(stats);
+		});
+	}
+}
+
+/// Trade fee is paid in `asset_in` of the transfer for both sells and buys.
+impl<T: Config> AMMHandlers<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
+	fn on_before_trade(
+		_pool: &T::AccountId,
+		_trade_type: IntentionType,
+		_transfer: &AMMTransfer<T::AccountId, AssetPair, Balance>,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn on_trade(
+		pool: &T::AccountId,
+		trade_type: IntentionType,
+		transfer: &AMMTransfer<T::AccountId, AssetPair, Balance>,
+	) {
+		let asset_in = transfer.assets.asset_in;
+		let assets = transfer.assets.ordered();
+
+		let (amount_in, amount_out) = match trade_type {
+			IntentionType::SELL => (transfer.amount, transfer.amount_out),
+			IntentionType::BUY => (transfer.amount_out, transfer.amount),
+		};
+		let (volume_a, volume_b) = assets.order(asset_in, (amount_in, amount_out));
+		let (fee_a, fee_b) = assets.order(asset_in, (transfer.fee, 0));
+
+		Self::update(pool, assets, |stats| {
+			stats.volume_a = stats.volume_a.saturating_add(volume_a);
+			stats.volume_b = stats.volume_b.saturating_add(volume_b);
+			stats.fee_a = stats.fee_a.saturating_add(fee_a);
+			stats.fee_b = stats.fee_b.saturating_add(fee_b);
+			stats.trades = stats.trades.saturating_add(1);
+		});
+	}
+
+	fn on_liquidity_changed(pool: &T::AccountId, change: &LiquidityChange<T::AccountId, AssetId, Balance>) {
+		Self::update(pool, OrderedAssetPair::new(change.asset_a, change.asset_b), |_| {});
+	}
+
+	/// Period stats and pool reserves are read, period stats are written.
+	fn on_trade_weight() -> Weight {
+		T::DbWeight::get().reads_writes(3, 1)
+	}
+}
@@ -0,0 +1,163 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+pub use crate::mock::{
+	Currency, ExtBuilder, Stats, System, Test, ALICE, DOT, HDX, INITIAL_RESERVE, PERIOD_LENGTH, POOL,
+};
+use primitives::traits::{LiquidityChangeKind, TradeSource};
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Move reserves of the pool as the trade would and notify the handler.
+fn trade(trade_type: IntentionType, asset_in: AssetId, asset_out: AssetId, amount: Balance, amount_out: Balance) {
+	let (paid, received) = match trade_type {
+		IntentionType::SELL => (amount, amount_out),
+		IntentionType::BUY => (amount_out, amount),
+	};
+	assert!(Currency::deposit(asset_in, &POOL, paid).is_ok());
+	assert!(Currency::withdraw(asset_out, &POOL, received).is_ok());
+
+	Stats::on_trade(
+		&POOL,
+		trade_type,
+		&AMMTransfer {
+			origin: ALICE,
+			assets: AssetPair { asset_in, asset_out },
+			amount,
+			amount_out,
+			discount: false,
+			discount_amount: 0,
+			fee: 10,
+			source: TradeSource::Direct,
+			fees: None,
+		},
+	);
+}
+
+fn add_liquidity(amount_hdx: Balance, amount_dot: Balance) {
+	assert!(Currency::deposit(HDX, &POOL, amount_hdx).is_ok());
+	assert!(Currency::deposit(DOT, &POOL, amount_dot).is_ok());
+
+	Stats::on_liquidity_changed(
+		&POOL,
+		&LiquidityChange {
+			who: ALICE,
+			kind: LiquidityChangeKind::Added,
+			asset_a: DOT,
+			asset_b: HDX,
+			amount_a: amount_dot,
+			amount_b: amount_hdx,
+			shares: amount_dot,
+		},
+	);
+}
+
+fn start_period(period: u64) {
+	System::set_block_number(period * PERIOD_LENGTH);
+}
+
+#[test]
+fn trades_should_be_aggregated_in_period_stats() {
+	new_test_ext().execute_with(|| {
+		trade(IntentionType::SELL, DOT, HDX, 1_000, 900);
+		trade(IntentionType::BUY, HDX, DOT, 500, 600);
+
+		assert_eq!(
+			Stats::pool_stats(HDX, DOT),
+			vec![PeriodStats {
+				period: 0,
+				volume_a: 900 + 600,
+				volume_b: 1_000 + 500,
+				fee_a: 10,
+				fee_b: 10,
+				trades: 2,
+				reserve_a: INITIAL_RESERVE - 900 + 600,
+				reserve_b: INITIAL_RESERVE + 1_000 - 500,
+			}]
+		);
+
+		// Order of assets does not matter
+		assert_eq!(Stats::pool_stats(DOT, HDX), Stats::pool_stats(HDX, DOT));
+	});
+}
+
+#[test]
+fn liquidity_change_should_update_reserves_only() {
+	new_test_ext().execute_with(|| {
+		add_liquidity(2_000, 3_000);
+
+		assert_eq!(
+			Stats::pool_stats(HDX, DOT),
+			vec![PeriodStats {
+				period: 0,
+				reserve_a: INITIAL_RESERVE + 2_000,
+				reserve_b: INITIAL_RESERVE + 3_000,
+				..Default::default()
+			}]
+		);
+	});
+}
+
+#[test]
+fn stats_should_be_kept_per_period() {
+	new_test_ext().execute_with(|| {
+		trade(IntentionType::SELL, HDX, DOT, 1_000, 900);
+
+		start_period(2);
+		trade(IntentionType::SELL, HDX, DOT, 2_000, 1_800);
+
+		let stats = Stats::pool_stats(HDX, DOT);
+
+		// Period without any change of the pool is missing
+		assert_eq!(stats.iter().map(|s| s.period).collect::<Vec<_>>(), vec![0, 2]);
+		assert_eq!(stats[0].volume_a, 1_000);
+		assert_eq!(stats[0].trades, 1);
+		assert_eq!(stats[1].volume_a, 2_000);
+		assert_eq!(stats[1].trades, 1);
+		assert_eq!(stats[1].reserve_a, INITIAL_RESERVE + 3_000);
+	});
+}
+
+#[test]
+fn stats_older_than_retention_window_should_be_overwritten() {
+	new_test_ext().execute_with(|| {
+		for period in 0..5 {
+			start_period(period);
+			trade(IntentionType::SELL, HDX, DOT, 1_000 * (period as Balance + 1), 900);
+		}
+
+		let stats = Stats::pool_stats(HDX, DOT);
+
+		assert_eq!(stats.iter().map(|s| s.period).collect::<Vec<_>>(), vec![2, 3, 4]);
+		assert_eq!(stats[0].volume_a, 3_000);
+		assert_eq!(stats[0].trades, 1);
+		assert_eq!(<PoolStats<Test>>::iter().count(), 3);
+
+		// Stale slots are not returned once the window moves past them
+		start_period(6);
+
+		assert_eq!(
+			Stats::pool_stats(HDX, DOT).iter().map(|s| s.period).collect::<Vec<_>>(),
+			vec![4]
+		);
+	});
+}
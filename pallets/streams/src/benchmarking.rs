@@ -0,0 +1,105 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+use crate::Pallet as Streams;
+
+const SEED: u32 = 1;
+
+const ASSET: AssetId = 1;
+const DEPOSIT: Balance = 1_000_000_000_000;
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let caller: T::AccountId = account(name, index, SEED);
+	T::Currency::update_balance(ASSET, &caller, 1_000_000_000_000_000).unwrap();
+	caller
+}
+
+fn create_stream<T: Config>(payer: &T::AccountId, payee: &T::AccountId) -> Result<StreamId, DispatchError> {
+	let stream_id = Streams::<T>::next_stream_id();
+	Streams::<T>::create_stream(
+		RawOrigin::Signed(payer.clone()).into(),
+		payee.clone(),
+		ASSET,
+		DEPOSIT,
+		10u32.into(),
+		20u32.into(),
+	)
+	.map_err(|e| e.error)?;
+	Ok(stream_id)
+}
+
+benchmarks! {
+	create_stream {
+		let caller = funded_account::<T>("caller", 0);
+		let payee: T::AccountId = account("payee", 0, SEED);
+
+	}: _(RawOrigin::Signed(caller.clone()), payee, ASSET, DEPOSIT, 10u32.into(), 20u32.into())
+	verify {
+		assert_eq!(T::Currency::reserved_balance(ASSET, &caller), DEPOSIT);
+	}
+
+	withdraw {
+		let payer = funded_account::<T>("payer", 0);
+		let caller = funded_account::<T>("caller", 0);
+
+		let stream_id = create_stream::<T>(&payer, &caller)?;
+
+		frame_system::Pallet::<T>::set_block_number(15u32.into());
+
+	}: _(RawOrigin::Signed(caller.clone()), stream_id, DEPOSIT / 2, None)
+	verify {
+		assert_eq!(T::Currency::reserved_balance(ASSET, &payer), DEPOSIT / 2);
+	}
+
+	cancel_stream {
+		let payer = funded_account::<T>("payer", 1);
+		let payee: T::AccountId = account("payee", 1, SEED);
+
+		let stream_id = create_stream::<T>(&payer, &payee)?;
+
+		frame_system::Pallet::<T>::set_block_number(15u32.into());
+
+	}: _(RawOrigin::Signed(payer.clone()), stream_id)
+	verify {
+		assert_eq!(T::Currency::reserved_balance(ASSET, &payer), 0);
+		assert_eq!(T::Currency::free_balance(ASSET, &payee), DEPOSIT / 2);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_create_stream::<Test>());
+			assert_ok!(test_benchmark_withdraw::<Test>());
+			assert_ok!(test_benchmark_cancel_stream::<Test>());
+		});
+	}
+}
@@ -0,0 +1,356 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Streams Pallet
+//!
+//! ## Overview
+//!
+//! Streams pallet provides streaming payments. Payer locks a deposit of an asset which is continuously
+//! released to payee block by block between start and end of the stream.
+//!
+//! Payee can withdraw released amount at any time and optionally convert it to another asset
+//! via AMM pool within a slippage bound.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{dispatch::DispatchResult, ensure, traits::BalanceStatus, transactional, weights::Weight};
+use frame_system::ensure_signed;
+use orml_traits::{MultiCurrencyExtended, MultiReservableCurrency};
+use primitives::{asset::AssetPair, traits::AMM, AssetId, Balance};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
+	traits::{One, SaturatedConversion, Saturating, Zero},
+	DispatchError, RuntimeDebug,
+};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// Stream identifier
+pub type StreamId = u64;
+
+/// Streaming payment of an asset from payer to payee.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Stream<AccountId, BlockNumber> {
+	/// Account which funds the stream
+	pub payer: AccountId,
+	/// Account which receives the stream
+	pub payee: AccountId,
+	/// Streamed asset
+	pub asset: AssetId,
+	/// Total amount streamed over the whole duration
+	pub deposit: Balance,
+	/// Amount already withdrawn by payee
+	pub withdrawn: Balance,
+	/// Block from which the deposit starts to be released
+	pub start: BlockNumber,
+	/// Block at which the whole deposit is released
+	pub end: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Multi currency for reserving and transferring streamed assets
+		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = AssetId, Balance = Balance>
+			+ MultiReservableCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// AMM pool to convert withdrawn assets
+		type AMMPool: AMM<Self::AccountId, AssetId, AssetPair, Balance>;
+
+		/// Weight of AMM sell used to convert withdrawn assets
+		#[pallet::constant]
+		type AMMSellWeight: Get<Weight>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// It is not allowed to create a stream with zero deposit.
+		ZeroDeposit,
+
+		/// Stream end must be after its start and in the future.
+		InvalidStreamPeriod,
+
+		/// It is not allowed to stream to yourself.
+		CannotStreamToSelf,
+
+		/// Stream does not exist.
+		StreamNotFound,
+
+		/// Account is not allowed to perform this action on the stream.
+		NotAllowed,
+
+		/// It is not allowed to withdraw zero amount.
+		ZeroWithdrawAmount,
+
+		/// Amount released by the stream is not sufficient.
+		InsufficientStreamBalance,
+
+		/// Overflow
+		StreamIdOverflow,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Stream was created. [stream id, payer, payee, asset, deposit]
+		StreamCreated(StreamId, T::AccountId, T::AccountId, AssetId, Balance),
+
+		/// Released amount was withdrawn from the stream. [stream id, payee, asset, amount]
+		Withdrawn(StreamId, T::AccountId, AssetId, Balance),
+
+		/// Withdrawn amount was converted via AMM. [stream id, payee, asset in, asset out, amount]
+		WithdrawalConverted(StreamId, T::AccountId, AssetId, AssetId, Balance),
+
+		/// Stream was cancelled. [stream id, amount paid to payee, amount returned to payer]
+		StreamCancelled(StreamId, Balance, Balance),
+	}
+
+	/// Next available stream id.
+	#[pallet::storage]
+	#[pallet::getter(fn next_stream_id)]
+	pub type NextStreamId<T: Config> = StorageValue<_, StreamId, ValueQuery>;
+
+	/// Active streams.
+	#[pallet::storage]
+	#[pallet::getter(fn streams)]
+	pub type Streams<T: Config> =
+		StorageMap<_, Blake2_128Concat, StreamId, Stream<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create new stream of `asset` from origin to `payee`.
+		///
+		/// Whole `deposit` is reserved from origin's balance and released linearly to payee
+		/// between `start` and `end` blocks.
+		///
+		/// Emits `StreamCreated` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::create_stream())]
+		#[transactional]
+		pub fn create_stream(
+			origin: OriginFor<T>,
+			payee: T::AccountId,
+			asset: AssetId,
+			deposit: Balance,
+			start: T::BlockNumber,
+			end: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!deposit.is_zero(), Error::<T>::ZeroDeposit);
+			ensure!(who != payee, Error::<T>::CannotStreamToSelf);
+			ensure!(
+				start < end && end > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidStreamPeriod
+			);
+
+			let stream_id = Self::next_stream_id();
+			let next_id = stream_id.checked_add(1).ok_or(Error::<T>::StreamIdOverflow)?;
+
+			T::Currency::reserve(asset, &who, deposit)?;
+
+			<Streams<T>>::insert(
+				stream_id,
+				Stream {
+					payer: who.clone(),
+					payee: payee.clone(),
+					asset,
+					deposit,
+					withdrawn: Balance::zero(),
+					start,
+					end,
+				},
+			);
+			<NextStreamId<T>>::put(next_id);
+
+			Self::deposit_event(Event::StreamCreated(stream_id, who, payee, asset, deposit));
+
+			Ok(().into())
+		}
+
+		/// Withdraw released `amount` from the stream.
+		///
+		/// Only payee of the stream can withdraw.
+		///
+		/// `convert_to` - optional asset and minimum amount of it to be bought for withdrawn amount via AMM.
+		///
+		/// Stream is removed once the whole deposit is withdrawn.
+		///
+		/// Emits `Withdrawn` event when successful and `WithdrawalConverted` if withdrawn amount was converted.
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw().saturating_add(
+			if convert_to.is_some() { T::AMMSellWeight::get() } else { 0 }
+		))]
+		#[transactional]
+		pub fn withdraw(
+			origin: OriginFor<T>,
+			stream_id: StreamId,
+			amount: Balance,
+			convert_to: Option<(AssetId, Balance)>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroWithdrawAmount);
+
+			let mut stream = Self::streams(stream_id).ok_or(Error::<T>::StreamNotFound)?;
+
+			ensure!(stream.payee == who, Error::<T>::NotAllowed);
+
+			let available = Self::available_balance(&stream)?;
+
+			ensure!(amount <= available, Error::<T>::InsufficientStreamBalance);
+
+			Self::pay_out(&stream, amount)?;
+
+			stream.withdrawn = stream.withdrawn.saturating_add(amount);
+
+			if stream.withdrawn == stream.deposit {
+				<Streams<T>>::remove(stream_id);
+			} else {
+				<Streams<T>>::insert(stream_id, &stream);
+			}
+
+			Self::deposit_event(Event::Withdrawn(stream_id, who.clone(), stream.asset, amount));
+
+			if let Some((asset_out, min_bought)) = convert_to {
+				T::AMMPool::sell(
+					&who,
+					AssetPair {
+						asset_in: stream.asset,
+						asset_out,
+					},
+					amount,
+					min_bought,
+					false,
+				)?;
+
+				Self::deposit_event(Event::WithdrawalConverted(
+					stream_id,
+					who,
+					stream.asset,
+					asset_out,
+					amount,
+				));
+			}
+
+			Ok(().into())
+		}
+
+		/// Cancel the stream.
+		///
+		/// Can be performed by payer or payee of the stream. Released but not yet withdrawn amount
+		/// is paid to payee, remaining deposit is returned to payer.
+		///
+		/// Emits `StreamCancelled` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_stream())]
+		#[transactional]
+		pub fn cancel_stream(origin: OriginFor<T>, stream_id: StreamId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let stream = Self::streams(stream_id).ok_or(Error::<T>::StreamNotFound)?;
+
+			ensure!(stream.payer == who || stream.payee == who, Error::<T>::NotAllowed);
+
+			let payee_amount = Self::available_balance(&stream)?;
+
+			if !payee_amount.is_zero() {
+				Self::pay_out(&stream, payee_amount)?;
+			}
+
+			let payer_amount = stream
+				.deposit
+				.saturating_sub(stream.withdrawn)
+				.saturating_sub(payee_amount);
+
+			T::Currency::unreserve(stream.asset, &stream.payer, payer_amount);
+
+			<Streams<T>>::remove(stream_id);
+
+			Self::deposit_event(Event::StreamCancelled(stream_id, payee_amount, payer_amount));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Return amount released by the stream up to current block.
+	pub fn released_balance(stream: &Stream<T::AccountId, T::BlockNumber>) -> Result<Balance, DispatchError> {
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		if now <= stream.start {
+			return Ok(Balance::zero());
+		}
+		if now >= stream.end {
+			return Ok(stream.deposit);
+		}
+
+		let elapsed: u128 = now.saturating_sub(stream.start).saturated_into();
+		let duration: u128 = stream
+			.end
+			.saturating_sub(stream.start)
+			.max(T::BlockNumber::one())
+			.saturated_into();
+
+		multiply_by_rational(stream.deposit, elapsed, duration)
+			.map_err(|_| Error::<T>::InsufficientStreamBalance.into())
+	}
+
+	/// Return amount released by the stream which has not been withdrawn yet.
+	pub fn available_balance(stream: &Stream<T::AccountId, T::BlockNumber>) -> Result<Balance, DispatchError> {
+		Ok(Self::released_balance(stream)?.saturating_sub(stream.withdrawn))
+	}
+
+	/// Move reserved amount from payer to free balance of payee.
+	fn pay_out(stream: &Stream<T::AccountId, T::BlockNumber>, amount: Balance) -> DispatchResult {
+		let remaining =
+			T::Currency::repatriate_reserved(stream.asset, &stream.payer, &stream.payee, amount, BalanceStatus::Free)?;
+
+		ensure!(remaining.is_zero(), Error::<T>::InsufficientStreamBalance);
+
+		Ok(())
+	}
+}
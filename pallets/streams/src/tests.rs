@@ -0,0 +1,298 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+pub use crate::mock::{
+	Currency, Event as TestEvent, ExtBuilder, Origin, Streams, System, Test, ALICE, BOB, CHARLIE, DOT, HDX, XYK,
+};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use primitives::Price;
+
+const DEPOSIT: Balance = 1_000_000_000_000;
+const INITIAL_BALANCE: Balance = 1_000_000_000_000_000;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn last_events(n: usize) -> Vec<TestEvent> {
+	frame_system::Pallet::<Test>::events()
+		.into_iter()
+		.rev()
+		.take(n)
+		.rev()
+		.map(|e| e.event)
+		.collect()
+}
+
+fn expect_events(e: Vec<TestEvent>) {
+	assert_eq!(last_events(e.len()), e);
+}
+
+#[test]
+fn create_stream_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Streams::create_stream(Origin::signed(ALICE), BOB, HDX, DEPOSIT, 10, 20));
+
+		assert_eq!(
+			Streams::streams(0),
+			Some(Stream {
+				payer: ALICE,
+				payee: BOB,
+				asset: HDX,
+				deposit: DEPOSIT,
+				withdrawn: 0,
+				start: 10,
+				end: 20,
+			})
+		);
+		assert_eq!(Streams::next_stream_id(), 1);
+
+		assert_eq!(Currency::free_balance(HDX, &ALICE), INITIAL_BALANCE - DEPOSIT);
+		assert_eq!(Currency::reserved_balance(HDX, &ALICE), DEPOSIT);
+
+		expect_events(vec![Event::StreamCreated(0, ALICE, BOB, HDX, DEPOSIT).into()]);
+	});
+}
+
+#[test]
+fn create_stream_with_invalid_params_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Streams::create_stream(Origin::signed(ALICE), BOB, HDX, 0, 10, 20),
+			Error::<Test>::ZeroDeposit
+		);
+		assert_noop!(
+			Streams::create_stream(Origin::signed(ALICE), ALICE, HDX, DEPOSIT, 10, 20),
+			Error::<Test>::CannotStreamToSelf
+		);
+		assert_noop!(
+			Streams::create_stream(Origin::signed(ALICE), BOB, HDX, DEPOSIT, 20, 10),
+			Error::<Test>::InvalidStreamPeriod
+		);
+
+		System::set_block_number(30);
+
+		assert_noop!(
+			Streams::create_stream(Origin::signed(ALICE), BOB, HDX, DEPOSIT, 10, 20),
+			Error::<Test>::InvalidStreamPeriod
+		);
+	});
+}
+
+#[test]
+fn create_stream_with_stream_id_overflow_should_not_work() {
+	new_test_ext().execute_with(|| {
+		<NextStreamId<Test>>::put(StreamId::MAX);
+
+		assert_noop!(
+			Streams::create_stream(Origin::signed(ALICE), BOB, HDX, DEPOSIT, 10, 20),
+			Error::<Test>::StreamIdOverflow
+		);
+	});
+}
+
+#[test]
+fn create_stream_with_insufficient_balance_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Streams::create_stream(Origin::signed(ALICE), BOB, HDX, INITIAL_BALANCE + 1, 10, 20),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn withdraw_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Streams::create_stream(Origin::signed(ALICE), BOB, HDX, DEPOSIT, 10, 20));
+
+		System::set_block_number(15);
+
+		let stream = Streams::streams(0).unwrap();
+		assert_eq!(Streams::available_balance(&stream), Ok(DEPOSIT / 2));
+
+		assert_ok!(Streams::withdraw(Origin::signed(BOB), 0, DEPOSIT / 4, None));
+
+		assert_eq!(Currency::free_balance(HDX, &BOB), INITIAL_BALANCE + DEPOSIT / 4);
+		assert_eq!(Currency::reserved_balance(HDX, &ALICE), DEPOSIT - DEPOSIT / 4);
+		assert_eq!(Streams::streams(0).unwrap().withdrawn, DEPOSIT / 4);
+
+		expect_events(vec![Event::Withdrawn(0, BOB, HDX, DEPOSIT / 4).into()]);
+
+		assert_noop!(
+			Streams::withdraw(Origin::signed(BOB), 0, DEPOSIT / 4 + 1, None),
+			Error::<Test>::InsufficientStreamBalance
+		);
+	});
+}
+
+#[test]
+fn withdraw_whole_deposit_should_remove_stream() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Streams::create_stream(Origin::signed(ALICE), BOB, HDX, DEPOSIT, 10, 20));
+
+		System::set_block_number(25);
+
+		assert_ok!(Streams::withdraw(Origin::signed(BOB), 0, DEPOSIT, None));
+
+		assert_eq!(Streams::streams(0), None);
+		assert_eq!(Currency::free_balance(HDX, &BOB), INITIAL_BALANCE + DEPOSIT);
+		assert_eq!(Currency::reserved_balance(HDX, &ALICE), 0);
+	});
+}
+
+#[test]
+fn withdraw_before_start_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Streams::create_stream(Origin::signed(ALICE), BOB, HDX, DEPOSIT, 10, 20));
+
+		assert_noop!(
+			Streams::withdraw(Origin::signed(BOB), 0, 1, None),
+			Error::<Test>::InsufficientStreamBalance
+		);
+	});
+}
+
+#[test]
+fn withdraw_with_invalid_params_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Streams::create_stream(Origin::signed(ALICE), BOB, HDX, DEPOSIT, 10, 20));
+
+		System::set_block_number(15);
+
+		assert_noop!(
+			Streams::withdraw(Origin::signed(BOB), 0, 0, None),
+			Error::<Test>::ZeroWithdrawAmount
+		);
+		assert_noop!(
+			Streams::withdraw(Origin::signed(BOB), 1, 1, None),
+			Error::<Test>::StreamNotFound
+		);
+		assert_noop!(
+			Streams::withdraw(Origin::signed(ALICE), 0, 1, None),
+			Error::<Test>::NotAllowed
+		);
+		assert_noop!(
+			Streams::withdraw(Origin::signed(CHARLIE), 0, 1, None),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn withdraw_with_conversion_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(CHARLIE),
+			HDX,
+			DOT,
+			100_000_000_000_000,
+			Price::from(2)
+		));
+
+		assert_ok!(Streams::create_stream(Origin::signed(ALICE), BOB, HDX, DEPOSIT, 10, 20));
+
+		System::set_block_number(20);
+
+		assert_ok!(Streams::withdraw(Origin::signed(BOB), 0, DEPOSIT, Some((DOT, 1))));
+
+		assert_eq!(Currency::free_balance(HDX, &BOB), INITIAL_BALANCE);
+		assert!(Currency::free_balance(DOT, &BOB) > INITIAL_BALANCE);
+		assert_eq!(Streams::streams(0), None);
+
+		expect_events(vec![Event::WithdrawalConverted(0, BOB, HDX, DOT, DEPOSIT).into()]);
+	});
+}
+
+#[test]
+fn withdraw_with_conversion_exceeding_limit_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(CHARLIE),
+			HDX,
+			DOT,
+			100_000_000_000_000,
+			Price::from(2)
+		));
+
+		assert_ok!(Streams::create_stream(Origin::signed(ALICE), BOB, HDX, DEPOSIT, 10, 20));
+
+		System::set_block_number(20);
+
+		assert_noop!(
+			Streams::withdraw(Origin::signed(BOB), 0, DEPOSIT, Some((DOT, 4 * DEPOSIT))),
+			pallet_xyk::Error::<Test>::AssetBalanceLimitExceeded
+		);
+
+		assert_eq!(Streams::streams(0).unwrap().withdrawn, 0);
+	});
+}
+
+#[test]
+fn cancel_stream_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Streams::create_stream(Origin::signed(ALICE), BOB, HDX, DEPOSIT, 10, 20));
+
+		System::set_block_number(15);
+
+		assert_ok!(Streams::withdraw(Origin::signed(BOB), 0, DEPOSIT / 4, None));
+
+		assert_ok!(Streams::cancel_stream(Origin::signed(ALICE), 0));
+
+		assert_eq!(Streams::streams(0), None);
+		assert_eq!(Currency::free_balance(HDX, &BOB), INITIAL_BALANCE + DEPOSIT / 2);
+		assert_eq!(Currency::free_balance(HDX, &ALICE), INITIAL_BALANCE - DEPOSIT / 2);
+		assert_eq!(Currency::reserved_balance(HDX, &ALICE), 0);
+
+		expect_events(vec![Event::StreamCancelled(0, DEPOSIT / 4, DEPOSIT / 2).into()]);
+	});
+}
+
+#[test]
+fn cancel_stream_by_payee_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Streams::create_stream(Origin::signed(ALICE), BOB, HDX, DEPOSIT, 10, 20));
+
+		assert_ok!(Streams::cancel_stream(Origin::signed(BOB), 0));
+
+		assert_eq!(Streams::streams(0), None);
+		assert_eq!(Currency::free_balance(HDX, &BOB), INITIAL_BALANCE);
+		assert_eq!(Currency::free_balance(HDX, &ALICE), INITIAL_BALANCE);
+
+		expect_events(vec![Event::StreamCancelled(0, 0, DEPOSIT).into()]);
+	});
+}
+
+#[test]
+fn cancel_stream_by_other_account_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Streams::create_stream(Origin::signed(ALICE), BOB, HDX, DEPOSIT, 10, 20));
+
+		assert_noop!(
+			Streams::cancel_stream(Origin::signed(CHARLIE), 0),
+			Error::<Test>::NotAllowed
+		);
+		assert_noop!(
+			Streams::cancel_stream(Origin::signed(ALICE), 1),
+			Error::<Test>::StreamNotFound
+		);
+	});
+}
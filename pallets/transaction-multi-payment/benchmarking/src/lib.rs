@@ -128,6 +128,34 @@ benchmarks! {
 	verify {
 		assert_eq!(MultiPaymentModule::<T>::authorities(), vec![]);
 	}
+
+	sponsor {
+		let sponsor = funded_account::<T>("sponsor", 1);
+		let who: T::AccountId = account("who", 2, SEED);
+	}: { MultiPaymentModule::<T>::sponsor(RawOrigin::Signed(sponsor.clone()).into(), who.clone(), 1_000)? }
+	verify {
+		assert_eq!(MultiPaymentModule::<T>::sponsorship(sponsor, who).map(|s| s.allowance), Some(1_000));
+	}
+
+	remove_sponsorship {
+		let sponsor = funded_account::<T>("sponsor", 1);
+		let who = funded_account::<T>("who", 2);
+		MultiPaymentModule::<T>::sponsor(RawOrigin::Signed(sponsor.clone()).into(), who.clone(), 1_000)?;
+		MultiPaymentModule::<T>::set_fee_payer(RawOrigin::Signed(who.clone()).into(), Some(sponsor.clone()))?;
+	}: { MultiPaymentModule::<T>::remove_sponsorship(RawOrigin::Signed(sponsor.clone()).into(), who.clone())? }
+	verify {
+		assert!(MultiPaymentModule::<T>::sponsorship(sponsor, who.clone()).is_none());
+		assert!(MultiPaymentModule::<T>::fee_payer(who).is_none());
+	}
+
+	set_fee_payer {
+		let sponsor = funded_account::<T>("sponsor", 1);
+		let who = funded_account::<T>("who", 2);
+		MultiPaymentModule::<T>::sponsor(RawOrigin::Signed(sponsor.clone()).into(), who.clone(), 1_000)?;
+	}: { MultiPaymentModule::<T>::set_fee_payer(RawOrigin::Signed(who.clone()).into(), Some(sponsor.clone()))? }
+	verify {
+		assert_eq!(MultiPaymentModule::<T>::fee_payer(who), Some(sponsor));
+	}
 }
 
 #[cfg(test)]
@@ -145,6 +173,9 @@ mod tests {
 			assert_ok!(test_benchmark_remove_currency::<Test>());
 			assert_ok!(test_benchmark_add_member::<Test>());
 			assert_ok!(test_benchmark_remove_member::<Test>());
+			assert_ok!(test_benchmark_sponsor::<Test>());
+			assert_ok!(test_benchmark_remove_sponsorship::<Test>());
+			assert_ok!(test_benchmark_set_fee_payer::<Test>());
 		});
 	}
 }
@@ -28,6 +28,7 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+use codec::{Decode, Encode};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
@@ -36,10 +37,11 @@ use frame_support::{
 	weights::DispatchClass,
 	weights::WeightToFeePolynomial,
 };
-use frame_system::{ensure_root, ensure_signed};
+use frame_system::ensure_signed;
 use sp_runtime::{
-	traits::{DispatchInfoOf, PostDispatchInfoOf, Saturating, Zero},
+	traits::{CheckedDiv, DispatchInfoOf, PostDispatchInfoOf, Saturating, Zero},
 	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	DispatchError, FixedPointNumber, PerThing, Permill, RuntimeDebug,
 };
 use sp_std::prelude::*;
 
@@ -49,15 +51,32 @@ use sp_std::marker::PhantomData;
 use frame_support::weights::{Pays, Weight};
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::asset::AssetPair;
-use primitives::traits::{CurrencySwap, AMM};
-use primitives::{Amount, AssetId, Balance, CORE_ASSET_ID};
+use primitives::audit::PrivilegedAction;
+pub use primitives::traits::PriceProvider;
+use primitives::traits::{AuditLog, CurrencySwap, AMM};
+use primitives::{Amount, AssetId, Balance, Price, CORE_ASSET_ID};
 
 use orml_utilities::OrderedSet;
 
 type NegativeImbalanceOf<C, T> = <C as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+
+/// Number of blocks over which oracle price of accepted currency is averaged.
+pub const ORACLE_PRICE_PERIOD: u32 = 10;
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
+/// Fees of an account paid by its sponsor.
+#[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct Sponsorship<BlockNumber> {
+	/// Maximal amount of native asset paid in fees in one period
+	pub allowance: Balance,
+	/// Amount of native asset paid in fees in `period`
+	pub spent: Balance,
+	/// Index of the period in which `spent` was paid
+	pub period: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -68,7 +87,21 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Update oracle prices of accepted currencies.
+		///
+		/// Complexity: `O(C)` where `C` is number of accepted currencies bounded by `MaxAcceptedCurrencies`.
+		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			let currencies = Self::currencies();
+
+			for currency in currencies.0.iter() {
+				Self::update_oracle_price(*currency);
+			}
+
+			let count = currencies.0.len() as Weight;
+			T::DbWeight::get().reads_writes(1 + 4 * count, count)
+		}
+	}
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_transaction_payment::Config {
@@ -93,6 +126,30 @@ pub mod pallet {
 
 		/// Convert a weight value into a deductible fee based on the currency type.
 		type WeightToFee: WeightToFeePolynomial<Balance = Balance>;
+
+		/// Origin which can add or remove members of authorities
+		type ManageMembersOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Instantaneous price of accepted currencies
+		type SpotPriceProvider: PriceProvider<AssetId, Price>;
+
+		/// Price used to convert fees to accepted currencies
+		type FeePriceProvider: PriceProvider<AssetId, Price>;
+
+		/// Maximum deviation of spot price from the fee price for fee to be paid in accepted currency
+		#[pallet::constant]
+		type MaxPriceDeviation: Get<Permill>;
+
+		/// Log of privileged actions
+		type AuditLog: AuditLog<Self::AccountId>;
+
+		/// Maximum number of accepted currencies. Prices of all accepted currencies are updated in every block.
+		#[pallet::constant]
+		type MaxAcceptedCurrencies: Get<u32>;
+
+		/// Number of blocks after which allowances of sponsorships are renewed
+		#[pallet::constant]
+		type SponsorshipPeriod: Get<Self::BlockNumber>;
 	}
 
 	#[pallet::event]
@@ -117,6 +174,22 @@ pub mod pallet {
 		/// Member removed
 		/// [who]
 		MemberRemoved(T::AccountId),
+
+		/// Sponsor set fee allowance of an account
+		/// [sponsor, who, allowance]
+		SponsorshipSet(T::AccountId, T::AccountId, Balance),
+
+		/// Sponsor stopped paying fees of an account
+		/// [sponsor, who]
+		SponsorshipRemoved(T::AccountId, T::AccountId),
+
+		/// Account designated sponsor paying its fees, `None` if it pays fees itself
+		/// [who, sponsor]
+		FeePayerSet(T::AccountId, Option<T::AccountId>),
+
+		/// Fee of an account was paid by its sponsor
+		/// [sponsor, who, fee]
+		FeeSponsored(T::AccountId, T::AccountId, Balance),
 	}
 
 	#[pallet::error]
@@ -141,6 +214,21 @@ pub mod pallet {
 
 		/// Account is not a member of authorities.
 		NotAMember,
+
+		/// Price of the currency is not available.
+		PriceNotAvailable,
+
+		/// Spot price of the currency deviates from the fee price more than allowed.
+		PriceDeviationExceeded,
+
+		/// Maximum number of accepted currencies has been reached.
+		TooManyCurrencies,
+
+		/// Account cannot sponsor itself.
+		CannotSponsorSelf,
+
+		/// Sponsor does not pay fees of the account.
+		SponsorshipNotFound,
 	}
 
 	/// Account currency map
@@ -157,6 +245,30 @@ pub mod pallet {
 	#[pallet::getter(fn authorities)]
 	pub type Authorities<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
 
+	/// Prices of core asset in accepted currencies recorded at the beginning of blocks,
+	/// averaged over `ORACLE_PRICE_PERIOD` blocks
+	#[pallet::storage]
+	#[pallet::getter(fn oracle_price)]
+	pub type OraclePrices<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, Price, OptionQuery>;
+
+	/// Fee allowances of accounts sponsored by sponsors
+	#[pallet::storage]
+	#[pallet::getter(fn sponsorship)]
+	pub type Sponsorships<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		Sponsorship<T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// Sponsors designated by accounts to pay their fees
+	#[pallet::storage]
+	#[pallet::getter(fn fee_payer)]
+	pub type FeePayers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub currencies: OrderedSet<AssetId>,
@@ -221,6 +333,7 @@ pub mod pallet {
 		/// Only member can perform this action.
 		///
 		/// Currency must not be already accepted. Core asset id cannot be explicitly added.
+		/// At most `MaxAcceptedCurrencies` currencies can be accepted.
 		///
 		/// Emits `CurrencyAdded` event when successful.
 		#[pallet::weight((<T as Config>::WeightInfo::add_currency(), DispatchClass::Normal, Pays::No))]
@@ -232,8 +345,17 @@ pub mod pallet {
 			// Only selected accounts can perform this action
 			ensure!(Self::authorities().contains(&who), Error::<T>::NotAllowed);
 
+			let currencies = Self::currencies();
+
+			ensure!(
+				currencies.contains(&currency) || currencies.0.len() < T::MaxAcceptedCurrencies::get() as usize,
+				Error::<T>::TooManyCurrencies
+			);
+
 			if AcceptedCurrencies::<T>::mutate(|x| x.insert(currency)) {
-				Self::deposit_event(Event::CurrencyAdded(who, currency));
+				Self::update_oracle_price(currency);
+				Self::deposit_event(Event::CurrencyAdded(who.clone(), currency));
+				T::AuditLog::record(Some(who), PrivilegedAction::FeeCurrencyAdded(currency));
 				return Ok(().into());
 			}
 			Err(Error::<T>::AlreadyAccepted.into())
@@ -255,7 +377,9 @@ pub mod pallet {
 			ensure!(Self::authorities().contains(&who), Error::<T>::NotAllowed);
 
 			if AcceptedCurrencies::<T>::mutate(|x| x.remove(&currency)) {
-				Self::deposit_event(Event::CurrencyRemoved(who, currency));
+				OraclePrices::<T>::remove(currency);
+				Self::deposit_event(Event::CurrencyRemoved(who.clone(), currency));
+				T::AuditLog::record(Some(who), PrivilegedAction::FeeCurrencyRemoved(currency));
 				return Ok(().into());
 			}
 
@@ -266,12 +390,12 @@ pub mod pallet {
 		///
 		/// Members can be add or removed a currency from a list of accepted currencies.
 		///
-		/// Only root can be perform this action.
+		/// Only `ManageMembersOrigin` can be perform this action.
 		///
 		/// Emits `MemberAdded` when successful.
 		#[pallet::weight((<T as Config>::WeightInfo::add_member(), DispatchClass::Normal, Pays::No))]
 		pub fn add_member(origin: OriginFor<T>, member: T::AccountId) -> DispatchResultWithPostInfo {
-			ensure_root(origin)?;
+			T::ManageMembersOrigin::ensure_origin(origin)?;
 
 			ensure!(!Self::authorities().contains(&member), Error::<T>::AlreadyMember);
 
@@ -284,12 +408,12 @@ pub mod pallet {
 
 		/// Rmove account from list of authorities who can manage list of accepted currencies
 		///
-		/// Only root can be perform this action.
+		/// Only `ManageMembersOrigin` can be perform this action.
 		///
 		/// Emits `MemberRemoved` when successful.
 		#[pallet::weight((<T as Config>::WeightInfo::remove_member(), DispatchClass::Normal, Pays::No))]
 		pub fn remove_member(origin: OriginFor<T>, member: T::AccountId) -> DispatchResultWithPostInfo {
-			ensure_root(origin)?;
+			T::ManageMembersOrigin::ensure_origin(origin)?;
 
 			ensure!(Self::authorities().contains(&member), Error::<T>::NotAMember);
 
@@ -299,6 +423,80 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Pay fees of `who` up to `allowance` of native asset in every `SponsorshipPeriod`.
+		///
+		/// Fees are paid by the origin only if `who` designates it as its fee payer by `set_fee_payer`.
+		/// Amount already paid in the current period is kept when the allowance is changed.
+		///
+		/// Emits `SponsorshipSet` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::sponsor())]
+		pub fn sponsor(origin: OriginFor<T>, who: T::AccountId, allowance: Balance) -> DispatchResultWithPostInfo {
+			let sponsor = ensure_signed(origin)?;
+
+			ensure!(sponsor != who, Error::<T>::CannotSponsorSelf);
+
+			Sponsorships::<T>::mutate(&sponsor, &who, |maybe_sponsorship| {
+				let sponsorship = maybe_sponsorship.get_or_insert_with(Default::default);
+				sponsorship.allowance = allowance;
+			});
+
+			Self::deposit_event(Event::SponsorshipSet(sponsor, who, allowance));
+
+			Ok(().into())
+		}
+
+		/// Stop paying fees of `who`.
+		///
+		/// Fees of `who` are paid by itself if it designated the origin as its fee payer.
+		///
+		/// Emits `SponsorshipRemoved` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_sponsorship())]
+		pub fn remove_sponsorship(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
+			let sponsor = ensure_signed(origin)?;
+
+			ensure!(
+				Sponsorships::<T>::contains_key(&sponsor, &who),
+				Error::<T>::SponsorshipNotFound
+			);
+
+			Sponsorships::<T>::remove(&sponsor, &who);
+
+			if Self::fee_payer(&who) == Some(sponsor.clone()) {
+				FeePayers::<T>::remove(&who);
+			}
+
+			Self::deposit_event(Event::SponsorshipRemoved(sponsor, who));
+
+			Ok(().into())
+		}
+
+		/// Designate `sponsor` to pay fees of the origin, or pay fees by the origin itself if `None`.
+		///
+		/// Sponsor must sponsor the origin. Fees which exceed remaining allowance of the sponsorship
+		/// in the current period are paid by the origin.
+		///
+		/// Emits `FeePayerSet` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_payer())]
+		pub fn set_fee_payer(origin: OriginFor<T>, sponsor: Option<T::AccountId>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match sponsor {
+				Some(ref sponsor) => {
+					ensure!(
+						Sponsorships::<T>::contains_key(sponsor, &who),
+						Error::<T>::SponsorshipNotFound
+					);
+
+					FeePayers::<T>::insert(&who, sponsor);
+				}
+				None => FeePayers::<T>::remove(&who),
+			}
+
+			Self::deposit_event(Event::FeePayerSet(who, sponsor));
+
+			Ok(().into())
+		}
 	}
 }
 
@@ -313,6 +511,8 @@ impl<T: Config> Pallet<T> {
 
 		// If not native currency, let's buy CORE asset first and then pay with that.
 		if fee_currency != CORE_ASSET_ID {
+			let max_sold = Self::max_fee_amount_in(fee_currency, fee)?;
+
 			T::AMMPool::buy(
 				&who,
 				AssetPair {
@@ -320,7 +520,7 @@ impl<T: Config> Pallet<T> {
 					asset_in: fee_currency,
 				},
 				fee,
-				2u128 * fee,
+				max_sold,
 				false,
 			)?;
 		}
@@ -328,6 +528,47 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Return maximum amount of `currency` which can be sold to buy `fee` of core asset.
+	///
+	/// Amount is derived from the fee price rather than the spot price, so the fee cannot be paid
+	/// at a rate of a momentarily skewed pool. Spot price must not deviate from the fee price by more than
+	/// `MaxPriceDeviation`.
+	pub fn max_fee_amount_in(currency: AssetId, fee: Balance) -> Result<Balance, DispatchError> {
+		let fee_price = T::FeePriceProvider::price(CORE_ASSET_ID, currency).ok_or(Error::<T>::PriceNotAvailable)?;
+		let spot_price = T::SpotPriceProvider::price(CORE_ASSET_ID, currency).ok_or(Error::<T>::PriceNotAvailable)?;
+
+		let max_deviation = Price::from(T::MaxPriceDeviation::get());
+		let deviation = if spot_price > fee_price {
+			spot_price.saturating_sub(fee_price)
+		} else {
+			fee_price.saturating_sub(spot_price)
+		};
+
+		ensure!(
+			deviation <= fee_price.saturating_mul(max_deviation),
+			Error::<T>::PriceDeviationExceeded
+		);
+
+		let amount_in = fee_price.checked_mul_int(fee).ok_or(Error::<T>::PriceNotAvailable)?;
+
+		Ok(amount_in.saturating_add(T::MaxPriceDeviation::get().mul_ceil(amount_in)))
+	}
+
+	/// Record current spot price of core asset in `currency` into its oracle price.
+	fn update_oracle_price(currency: AssetId) {
+		if let Some(spot_price) = T::SpotPriceProvider::price(CORE_ASSET_ID, currency) {
+			let price = match OraclePrices::<T>::get(currency) {
+				Some(previous) => previous
+					.saturating_mul(Price::saturating_from_integer(ORACLE_PRICE_PERIOD - 1))
+					.saturating_add(spot_price)
+					.checked_div(&Price::saturating_from_integer(ORACLE_PRICE_PERIOD))
+					.unwrap_or(spot_price),
+				None => spot_price,
+			};
+			OraclePrices::<T>::insert(currency, price);
+		}
+	}
+
 	pub fn add_new_member(who: &T::AccountId) {
 		Authorities::<T>::mutate(|x| x.push(who.clone()));
 	}
@@ -343,6 +584,61 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Return sponsor of `who` if it has at least `fee` of allowance left in the current period.
+	pub fn sponsor_for(who: &T::AccountId, fee: Balance) -> Option<T::AccountId> {
+		let sponsor = Self::fee_payer(who)?;
+		let sponsorship = Self::sponsorship(&sponsor, who)?;
+
+		let spent = Self::spent_in_current_period(&sponsorship);
+
+		if spent.saturating_add(fee) <= sponsorship.allowance {
+			Some(sponsor)
+		} else {
+			None
+		}
+	}
+
+	/// Add `fee` paid by `sponsor` to amount spent by the sponsorship of `who` in the current period.
+	fn record_sponsored_fee(sponsor: &T::AccountId, who: &T::AccountId, fee: Balance) {
+		let period = Self::current_sponsorship_period();
+
+		Sponsorships::<T>::mutate(sponsor, who, |maybe_sponsorship| {
+			if let Some(sponsorship) = maybe_sponsorship {
+				sponsorship.spent = Self::spent_in_current_period(sponsorship).saturating_add(fee);
+				sponsorship.period = period;
+			}
+		});
+	}
+
+	/// Subtract refunded `amount` from amount spent by the sponsorship of `who` in the current period.
+	fn refund_sponsored_fee(sponsor: &T::AccountId, who: &T::AccountId, amount: Balance) {
+		Sponsorships::<T>::mutate(sponsor, who, |maybe_sponsorship| {
+			if let Some(sponsorship) = maybe_sponsorship {
+				sponsorship.spent = Self::spent_in_current_period(sponsorship).saturating_sub(amount);
+			}
+		});
+	}
+
+	/// Return amount spent by the sponsorship in the current period.
+	fn spent_in_current_period(sponsorship: &Sponsorship<T::BlockNumber>) -> Balance {
+		if sponsorship.period == Self::current_sponsorship_period() {
+			sponsorship.spent
+		} else {
+			Balance::zero()
+		}
+	}
+
+	/// Return index of the current sponsorship period.
+	fn current_sponsorship_period() -> T::BlockNumber {
+		let period = T::SponsorshipPeriod::get();
+
+		if period.is_zero() {
+			return T::BlockNumber::zero();
+		}
+
+		<frame_system::Pallet<T>>::block_number() / period
+	}
+
 	fn weight_to_fee(weight: Weight) -> Balance {
 		// cap the weight to the maximum defined in runtime, otherwise it will be the
 		// `Bounded` maximum of its data type, which is not desired.
@@ -357,6 +653,48 @@ impl<T: Config> CurrencySwap<<T as frame_system::Config>::AccountId, Balance> fo
 	}
 }
 
+/// Spot price of an asset provided by AMM pool.
+pub struct SpotPrice<AccountId, A>(PhantomData<(AccountId, A)>);
+
+/// Amount of asset used to determine spot price with sufficient precision.
+const SPOT_PRICE_AMOUNT: Balance = 1_000_000_000_000;
+
+impl<AccountId, A> PriceProvider<AssetId, Price> for SpotPrice<AccountId, A>
+where
+	A: AMM<AccountId, AssetId, AssetPair, Balance>,
+{
+	fn price(asset_a: AssetId, asset_b: AssetId) -> Option<Price> {
+		if !A::exists(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		}) {
+			return None;
+		}
+
+		match A::get_spot_price_unchecked(asset_a, asset_b, SPOT_PRICE_AMOUNT) {
+			0 => None,
+			amount => Price::checked_from_rational(amount, SPOT_PRICE_AMOUNT),
+		}
+	}
+}
+
+/// Oracle price of accepted currencies recorded by the pallet.
+///
+/// Only prices between core asset and accepted currencies are available.
+pub struct OraclePrice<T>(PhantomData<T>);
+
+impl<T: Config> PriceProvider<AssetId, Price> for OraclePrice<T> {
+	fn price(asset_a: AssetId, asset_b: AssetId) -> Option<Price> {
+		if asset_a == CORE_ASSET_ID {
+			OraclePrices::<T>::get(asset_b)
+		} else if asset_b == CORE_ASSET_ID {
+			OraclePrices::<T>::get(asset_a).and_then(|p| p.reciprocal())
+		} else {
+			None
+		}
+	}
+}
+
 /// Implements the transaction payment for native as well as non-native currencies
 pub struct MultiCurrencyAdapter<C, OU, SW>(PhantomData<(C, OU, SW)>);
 
@@ -373,10 +711,12 @@ where
 	C::Balance: Into<Balance>,
 	SW: CurrencySwap<T::AccountId, Balance>,
 {
-	type LiquidityInfo = Option<NegativeImbalanceOf<C, T>>;
+	/// Withdrawn fee and the account which paid it.
+	type LiquidityInfo = Option<(NegativeImbalanceOf<C, T>, T::AccountId)>;
 	type Balance = <C as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
-	/// Withdraw the predicted fee from the transaction origin.
+	/// Withdraw the predicted fee from the sponsor of the transaction origin, if it has enough allowance left
+	/// and can pay the fee, or from the transaction origin.
 	///
 	/// Note: The `fee` already includes the `tip`.
 	fn withdraw_fee(
@@ -396,14 +736,21 @@ where
 			WithdrawReasons::TRANSACTION_PAYMENT | WithdrawReasons::TIP
 		};
 
-		if SW::swap_currency(&who, fee.into()).is_err() {
-			return Err(InvalidTransaction::Payment.into());
-		}
+		let withdraw = |payer: &T::AccountId| -> Result<NegativeImbalanceOf<C, T>, TransactionValidityError> {
+			SW::swap_currency(payer, fee.into()).map_err(|_| InvalidTransaction::Payment)?;
 
-		match C::withdraw(who, fee, withdraw_reason, ExistenceRequirement::KeepAlive) {
-			Ok(imbalance) => Ok(Some(imbalance)),
-			Err(_) => Err(InvalidTransaction::Payment.into()),
+			C::withdraw(payer, fee, withdraw_reason, ExistenceRequirement::KeepAlive)
+				.map_err(|_| InvalidTransaction::Payment.into())
+		};
+
+		if let Some(sponsor) = Pallet::<T>::sponsor_for(who, fee.into()) {
+			if let Ok(imbalance) = withdraw(&sponsor) {
+				Pallet::<T>::record_sponsored_fee(&sponsor, who, fee.into());
+				return Ok(Some((imbalance, sponsor)));
+			}
 		}
+
+		withdraw(who).map(|imbalance| Some((imbalance, who.clone())))
 	}
 
 	/// Hand the fee and the tip over to the `[OnUnbalanced]` implementation.
@@ -420,14 +767,20 @@ where
 		tip: Self::Balance,
 		already_withdrawn: Self::LiquidityInfo,
 	) -> Result<(), TransactionValidityError> {
-		if let Some(paid) = already_withdrawn {
+		if let Some((paid, payer)) = already_withdrawn {
 			// Calculate how much refund we should return
 			let refund_amount = paid.peek().saturating_sub(corrected_fee);
 			// refund to the the account that paid the fees. If this fails, the
 			// account might have dropped below the existential balance. In
 			// that case we don't refund anything.
 			let refund_imbalance =
-				C::deposit_into_existing(&who, refund_amount).unwrap_or_else(|_| C::PositiveImbalance::zero());
+				C::deposit_into_existing(&payer, refund_amount).unwrap_or_else(|_| C::PositiveImbalance::zero());
+
+			if payer != *who {
+				Pallet::<T>::refund_sponsored_fee(&payer, who, refund_imbalance.peek().into());
+				Pallet::<T>::deposit_event(Event::FeeSponsored(payer, who.clone(), corrected_fee.into()));
+			}
+
 			// merge the imbalance caused by paying the fees and refunding parts of it again.
 			let adjusted_paid = paid
 				.offset(refund_imbalance)
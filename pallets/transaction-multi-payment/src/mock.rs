@@ -25,7 +25,7 @@ use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup, Zero},
-	Perbill,
+	Perbill, Permill,
 };
 
 use frame_support::weights::IdentityFee;
@@ -79,8 +79,9 @@ frame_support::construct_runtime!(
 		 XYKPallet: pallet_xyk::{Pallet, Call, Storage, Event<T>},
 		 Balances: pallet_balances::{Pallet,Call, Storage,Config<T>, Event<T>},
 		 Currencies: orml_currencies::{Pallet, Event<T>},
-		 AssetRegistry: pallet_asset_registry::{Pallet, Storage},
+		 AssetRegistry: pallet_asset_registry::{Pallet, Storage, Event<T>},
 		 Tokens: orml_tokens::{Pallet, Event<T>},
+		 Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
 	 }
 
 );
@@ -112,7 +113,12 @@ parameter_types! {
 		.build_or_panic();
 
 	pub ExchangeFeeRate: fee::Fee = fee::Fee::default();
+	pub ProtocolFeeRate: fee::Fee = fee::Fee { numerator: 0, denominator: 1 };
+	pub const TreasuryAccount: AccountId = 100;
 	 pub PayForSetCurrency : Pays = Pays::No;
+	pub const MaxPriceDeviation: Permill = Permill::from_percent(5);
+	pub const MaxAcceptedCurrencies: u32 = 3;
+	pub const SponsorshipPeriod: u64 = 10;
 }
 
 impl system::Config for Test {
@@ -149,10 +155,21 @@ impl Config for Test {
 	type WeightInfo = ();
 	type WithdrawFeeForSetCurrency = PayForSetCurrency;
 	type WeightToFee = IdentityFee<Balance>;
+	type ManageMembersOrigin = frame_system::EnsureRoot<AccountId>;
+	type SpotPriceProvider = crate::SpotPrice<AccountId, XYKPallet>;
+	type FeePriceProvider = crate::OraclePrice<Test>;
+	type MaxPriceDeviation = MaxPriceDeviation;
+	type AuditLog = ();
+	type MaxAcceptedCurrencies = MaxAcceptedCurrencies;
+	type SponsorshipPeriod = SponsorshipPeriod;
 }
 
 impl pallet_asset_registry::Config for Test {
+	type Event = Event;
 	type AssetId = AssetId;
+	type AssetNativeLocation = primitives::asset::AssetLocation;
+	type RegistryOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = ();
 }
 
 impl pallet_balances::Config for Test {
@@ -195,6 +212,40 @@ impl pallet_xyk::Config for Test {
 	type NativeAssetId = HdxAssetId;
 	type WeightInfo = ();
 	type GetExchangeFee = ExchangeFeeRate;
+	type DefaultProtocolFee = ProtocolFeeRate;
+	type TreasuryAccount = TreasuryAccount;
+	type ProtocolFeeOrigin = frame_system::EnsureRoot<AccountId>;
+	type PoolOwnerInactivityPeriod = PoolOwnerInactivityPeriod;
+	type MaxPoolDust = MaxPoolDust;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type PoolGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type Scheduler = Scheduler;
+	type AuditLog = ();
+	type AMMHandler = ();
+	type FlashLoanFee = ExchangeFeeRate;
+	type NonDustableWhitelistHandler = ();
+	type FeeDiscount = ();
+}
+
+parameter_types! {
+	pub const PoolOwnerInactivityPeriod: u64 = 100;
+	pub const MaxPoolDust: Balance = 1_000;
+	pub const MinPoolLiquidity: Balance = 2_000;
+	pub const MaximumSchedulerWeight: u64 = 1_000_000_000;
+	pub const MaxScheduledPerBlock: u32 = 50;
+}
+
+impl pallet_scheduler::Config for Test {
+	type Event = Event;
+	type Origin = Origin;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type WeightInfo = ();
 }
 
 parameter_type_with_key! {
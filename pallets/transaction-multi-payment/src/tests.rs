@@ -16,15 +16,18 @@
 // limitations under the License.
 
 pub use crate::{mock::*, Error};
+use crate::{PriceProvider, Sponsorship, SpotPrice};
+use frame_support::traits::OnInitialize;
 use frame_support::{assert_noop, assert_ok};
 use pallet_transaction_payment::ChargeTransactionPayment;
 use sp_runtime::traits::SignedExtension;
 
-use frame_support::weights::DispatchInfo;
+use frame_support::weights::{DispatchInfo, PostDispatchInfo};
 use orml_traits::MultiCurrency;
 use orml_utilities::OrderedSet;
 use pallet_balances::Call as BalancesCall;
 use primitives::Price;
+use sp_runtime::traits::CheckedDiv;
 
 const CALL: &<Test as frame_system::Config>::Call = &Call::Balances(BalancesCall::transfer(2, 69));
 
@@ -150,6 +153,7 @@ fn fee_payment_in_non_native_currency() {
 				100000,
 				Price::from(1)
 			));
+			PaymentPallet::on_initialize(1);
 			assert_ok!(PaymentPallet::set_currency(
 				Origin::signed(CHARLIE),
 				SUPPORTED_CURRENCY_WITH_BALANCE
@@ -193,6 +197,7 @@ fn fee_payment_non_native_insufficient_balance() {
 				100000,
 				Price::from(1)
 			));
+			PaymentPallet::on_initialize(1);
 
 			assert_ok!(PaymentPallet::set_currency(
 				Origin::signed(CHARLIE),
@@ -232,6 +237,25 @@ fn add_new_accepted_currency() {
 	});
 }
 
+#[test]
+fn add_currency_should_not_work_when_limit_is_reached() {
+	ExtBuilder::default().base_weight(5).build().execute_with(|| {
+		assert_ok!(PaymentPallet::add_currency(Origin::signed(BOB), 100));
+
+		assert_noop!(
+			PaymentPallet::add_currency(Origin::signed(BOB), 200),
+			Error::<Test>::TooManyCurrencies
+		);
+		assert_noop!(
+			PaymentPallet::add_currency(Origin::signed(BOB), 100),
+			Error::<Test>::AlreadyAccepted
+		);
+
+		assert_ok!(PaymentPallet::remove_currency(Origin::signed(BOB), 100));
+		assert_ok!(PaymentPallet::add_currency(Origin::signed(BOB), 200));
+	});
+}
+
 #[test]
 fn removed_accepted_currency() {
 	ExtBuilder::default().base_weight(5).build().execute_with(|| {
@@ -298,3 +322,297 @@ fn add_member() {
 		);
 	});
 }
+
+#[test]
+fn oracle_price_should_be_averaged() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(pallet_xyk::Pallet::<Test>::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			SUPPORTED_CURRENCY_WITH_BALANCE,
+			100000,
+			Price::from(1)
+		));
+
+		assert_eq!(PaymentPallet::oracle_price(SUPPORTED_CURRENCY_WITH_BALANCE), None);
+
+		PaymentPallet::on_initialize(1);
+
+		assert_eq!(
+			PaymentPallet::oracle_price(SUPPORTED_CURRENCY_WITH_BALANCE),
+			Some(Price::from(1))
+		);
+
+		assert_ok!(pallet_xyk::Pallet::<Test>::sell(
+			Origin::signed(ALICE),
+			SUPPORTED_CURRENCY_WITH_BALANCE,
+			HDX,
+			10000,
+			1,
+			false
+		));
+
+		let spot_price =
+			<SpotPrice<AccountId, XYKPallet> as PriceProvider<_, _>>::price(HDX, SUPPORTED_CURRENCY_WITH_BALANCE)
+				.unwrap();
+
+		PaymentPallet::on_initialize(2);
+
+		assert_eq!(
+			PaymentPallet::oracle_price(SUPPORTED_CURRENCY_WITH_BALANCE),
+			(Price::from(9) + spot_price).checked_div(&Price::from(10))
+		);
+
+		// Price of currency without pool is not recorded
+		assert_eq!(PaymentPallet::oracle_price(SUPPORTED_CURRENCY_NO_BALANCE), None);
+	});
+}
+
+#[test]
+fn fee_payment_should_fail_when_spot_price_deviates_from_oracle_price() {
+	const CHARLIE: AccountId = 5;
+
+	ExtBuilder::default()
+		.base_weight(5)
+		.account_native_balance(CHARLIE, 0)
+		.account_tokens(CHARLIE, SUPPORTED_CURRENCY_WITH_BALANCE, 1000)
+		.build()
+		.execute_with(|| {
+			assert_ok!(pallet_xyk::Pallet::<Test>::create_pool(
+				Origin::signed(ALICE),
+				HDX,
+				SUPPORTED_CURRENCY_WITH_BALANCE,
+				100000,
+				Price::from(1)
+			));
+			PaymentPallet::on_initialize(1);
+
+			assert_ok!(PaymentPallet::set_currency(
+				Origin::signed(CHARLIE),
+				SUPPORTED_CURRENCY_WITH_BALANCE
+			));
+
+			// Skew the pool within the block
+			assert_ok!(pallet_xyk::Pallet::<Test>::sell(
+				Origin::signed(ALICE),
+				SUPPORTED_CURRENCY_WITH_BALANCE,
+				HDX,
+				100000,
+				1,
+				false
+			));
+
+			assert_noop!(
+				PaymentPallet::swap_currency(&CHARLIE, 20),
+				Error::<Test>::PriceDeviationExceeded
+			);
+
+			let info = DispatchInfo {
+				weight: 5,
+				..Default::default()
+			};
+
+			assert!(ChargeTransactionPayment::<Test>::from(0)
+				.pre_dispatch(&CHARLIE, CALL, &info, 10)
+				.is_err());
+
+			assert_eq!(Tokens::free_balance(SUPPORTED_CURRENCY_WITH_BALANCE, &CHARLIE), 1000);
+		});
+}
+
+#[test]
+fn fee_payment_should_fail_without_oracle_price() {
+	const CHARLIE: AccountId = 5;
+
+	ExtBuilder::default()
+		.account_tokens(CHARLIE, SUPPORTED_CURRENCY_WITH_BALANCE, 1000)
+		.build()
+		.execute_with(|| {
+			assert_ok!(pallet_xyk::Pallet::<Test>::create_pool(
+				Origin::signed(ALICE),
+				HDX,
+				SUPPORTED_CURRENCY_WITH_BALANCE,
+				100000,
+				Price::from(1)
+			));
+
+			assert_ok!(PaymentPallet::set_currency(
+				Origin::signed(CHARLIE),
+				SUPPORTED_CURRENCY_WITH_BALANCE
+			));
+
+			assert_noop!(
+				PaymentPallet::swap_currency(&CHARLIE, 20),
+				Error::<Test>::PriceNotAvailable
+			);
+		});
+}
+
+#[test]
+fn sponsor_should_work() {
+	const CHARLIE: AccountId = 5;
+
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PaymentPallet::sponsor(Origin::signed(ALICE), ALICE, 100),
+			Error::<Test>::CannotSponsorSelf
+		);
+
+		assert_ok!(PaymentPallet::sponsor(Origin::signed(ALICE), CHARLIE, 100));
+
+		assert_eq!(
+			PaymentPallet::sponsorship(ALICE, CHARLIE),
+			Some(Sponsorship {
+				allowance: 100,
+				spent: 0,
+				period: 0,
+			})
+		);
+
+		assert_ok!(PaymentPallet::sponsor(Origin::signed(ALICE), CHARLIE, 50));
+
+		assert_eq!(
+			PaymentPallet::sponsorship(ALICE, CHARLIE).map(|s| s.allowance),
+			Some(50)
+		);
+	});
+}
+
+#[test]
+fn set_fee_payer_should_work() {
+	const CHARLIE: AccountId = 5;
+
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PaymentPallet::set_fee_payer(Origin::signed(CHARLIE), Some(ALICE)),
+			Error::<Test>::SponsorshipNotFound
+		);
+
+		assert_ok!(PaymentPallet::sponsor(Origin::signed(ALICE), CHARLIE, 100));
+		assert_ok!(PaymentPallet::set_fee_payer(Origin::signed(CHARLIE), Some(ALICE)));
+
+		assert_eq!(PaymentPallet::fee_payer(CHARLIE), Some(ALICE));
+
+		assert_ok!(PaymentPallet::set_fee_payer(Origin::signed(CHARLIE), None));
+
+		assert_eq!(PaymentPallet::fee_payer(CHARLIE), None);
+	});
+}
+
+#[test]
+fn remove_sponsorship_should_remove_fee_payer() {
+	const CHARLIE: AccountId = 5;
+
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PaymentPallet::remove_sponsorship(Origin::signed(ALICE), CHARLIE),
+			Error::<Test>::SponsorshipNotFound
+		);
+
+		assert_ok!(PaymentPallet::sponsor(Origin::signed(ALICE), CHARLIE, 100));
+		assert_ok!(PaymentPallet::set_fee_payer(Origin::signed(CHARLIE), Some(ALICE)));
+
+		assert_ok!(PaymentPallet::remove_sponsorship(Origin::signed(ALICE), CHARLIE));
+
+		assert_eq!(PaymentPallet::sponsorship(ALICE, CHARLIE), None);
+		assert_eq!(PaymentPallet::fee_payer(CHARLIE), None);
+	});
+}
+
+#[test]
+fn fee_payment_by_sponsor() {
+	const CHARLIE: AccountId = 5;
+
+	ExtBuilder::default()
+		.base_weight(5)
+		.account_native_balance(CHARLIE, 100)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(PaymentPallet::sponsor(Origin::signed(ALICE), CHARLIE, 30));
+			assert_ok!(PaymentPallet::set_fee_payer(Origin::signed(CHARLIE), Some(ALICE)));
+
+			let len = 10;
+			let info = DispatchInfo {
+				weight: 5,
+				..Default::default()
+			};
+
+			assert!(ChargeTransactionPayment::<Test>::from(0)
+				.pre_dispatch(&CHARLIE, CALL, &info, len)
+				.is_ok());
+
+			assert_eq!(Balances::free_balance(CHARLIE), 100);
+			assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 20);
+			assert_eq!(PaymentPallet::sponsorship(ALICE, CHARLIE).map(|s| s.spent), Some(20));
+
+			// Remaining allowance does not cover the fee so it is paid by the account itself
+			assert!(ChargeTransactionPayment::<Test>::from(0)
+				.pre_dispatch(&CHARLIE, CALL, &info, len)
+				.is_ok());
+
+			assert_eq!(Balances::free_balance(CHARLIE), 100 - 20);
+			assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 20);
+
+			// Allowance is renewed in the next period
+			System::set_block_number(10);
+
+			assert!(ChargeTransactionPayment::<Test>::from(0)
+				.pre_dispatch(&CHARLIE, CALL, &info, len)
+				.is_ok());
+
+			assert_eq!(Balances::free_balance(CHARLIE), 100 - 20);
+			assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 40);
+			assert_eq!(
+				PaymentPallet::sponsorship(ALICE, CHARLIE),
+				Some(Sponsorship {
+					allowance: 30,
+					spent: 20,
+					period: 1,
+				})
+			);
+		});
+}
+
+#[test]
+fn fee_refund_should_be_returned_to_sponsor() {
+	const CHARLIE: AccountId = 5;
+
+	ExtBuilder::default()
+		.base_weight(5)
+		.account_native_balance(CHARLIE, 100)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(PaymentPallet::sponsor(Origin::signed(ALICE), CHARLIE, 30));
+			assert_ok!(PaymentPallet::set_fee_payer(Origin::signed(CHARLIE), Some(ALICE)));
+
+			let len = 10;
+			let info = DispatchInfo {
+				weight: 5,
+				..Default::default()
+			};
+			let post_info = PostDispatchInfo {
+				actual_weight: Some(2),
+				pays_fee: Default::default(),
+			};
+
+			let pre = ChargeTransactionPayment::<Test>::from(0)
+				.pre_dispatch(&CHARLIE, CALL, &info, len)
+				.unwrap();
+
+			assert_ok!(ChargeTransactionPayment::<Test>::post_dispatch(
+				pre,
+				&info,
+				&post_info,
+				len,
+				&Ok(())
+			));
+
+			assert_eq!(Balances::free_balance(CHARLIE), 100);
+			assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 17);
+			assert_eq!(PaymentPallet::sponsorship(ALICE, CHARLIE).map(|s| s.spent), Some(17));
+		});
+}
@@ -53,6 +53,9 @@ pub trait WeightInfo {
 	fn remove_currency() -> Weight;
 	fn add_member() -> Weight;
 	fn remove_member() -> Weight;
+	fn sponsor() -> Weight;
+	fn remove_sponsorship() -> Weight;
+	fn set_fee_payer() -> Weight;
 }
 
 /// Weights for transaction_multi_payment using the hydraDX node and recommended hardware.
@@ -89,6 +92,21 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn sponsor() -> Weight {
+		(25_184_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_sponsorship() -> Weight {
+		(28_347_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn set_fee_payer() -> Weight {
+		(22_716_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -123,4 +141,19 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn sponsor() -> Weight {
+		(25_184_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn remove_sponsorship() -> Weight {
+		(28_347_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn set_fee_payer() -> Weight {
+		(22_716_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 }
@@ -0,0 +1,171 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::traits::{CallMetadata, Filter, GetCallMetadata, PalletInfo as PalletInfoT};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Origin which can pause and unpause transactions
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Transactions of this pallet cannot be paused.
+		CannotPause,
+
+		/// Pallet or function name is not valid UTF-8.
+		InvalidCharacter,
+
+		/// Transaction is already paused.
+		AlreadyPaused,
+
+		/// Transaction is not paused.
+		NotPaused,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Transaction was paused. [pallet name, function name]
+		TransactionPaused(Vec<u8>, Vec<u8>),
+
+		/// Transaction was unpaused. [pallet name, function name]
+		TransactionUnpaused(Vec<u8>, Vec<u8>),
+	}
+
+	/// Paused transactions, keyed by pallet name and function name.
+	#[pallet::storage]
+	#[pallet::getter(fn paused_transactions)]
+	pub type PausedTransactions<T: Config> = StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), (), OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Pause transaction `function_name` of pallet `pallet_name`.
+		///
+		/// Names are the pallet name used in the runtime and the name of the dispatchable function, e.g. `XYK` and
+		/// `sell`. Paused transactions are rejected by `PausedTransactionFilter`. Transactions of this pallet
+		/// cannot be paused.
+		///
+		/// Emits `TransactionPaused` event when successful.
+		#[pallet::weight((<T as Config>::WeightInfo::pause_transaction(), DispatchClass::Operational))]
+		pub fn pause_transaction(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let pallet_name_str = sp_std::str::from_utf8(&pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+			sp_std::str::from_utf8(&function_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+
+			ensure!(
+				Some(pallet_name_str) != <T as frame_system::Config>::PalletInfo::name::<Self>(),
+				Error::<T>::CannotPause
+			);
+
+			let key = (pallet_name, function_name);
+
+			ensure!(!PausedTransactions::<T>::contains_key(&key), Error::<T>::AlreadyPaused);
+
+			PausedTransactions::<T>::insert(&key, ());
+
+			Self::deposit_event(Event::TransactionPaused(key.0, key.1));
+
+			Ok(().into())
+		}
+
+		/// Unpause transaction `function_name` of pallet `pallet_name`.
+		///
+		/// Emits `TransactionUnpaused` event when successful.
+		#[pallet::weight((<T as Config>::WeightInfo::unpause_transaction(), DispatchClass::Operational))]
+		pub fn unpause_transaction(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let key = (pallet_name, function_name);
+
+			ensure!(PausedTransactions::<T>::take(&key).is_some(), Error::<T>::NotPaused);
+
+			Self::deposit_event(Event::TransactionUnpaused(key.0, key.1));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Return true if transaction `function_name` of pallet `pallet_name` is paused.
+	pub fn is_paused(pallet_name: &str, function_name: &str) -> bool {
+		PausedTransactions::<T>::contains_key((pallet_name.as_bytes().to_vec(), function_name.as_bytes().to_vec()))
+	}
+}
+
+/// Call filter rejecting paused transactions, to be used in `BaseCallFilter` of the runtime.
+pub struct PausedTransactionFilter<T>(PhantomData<T>);
+
+impl<T: Config> Filter<<T as frame_system::Config>::Call> for PausedTransactionFilter<T>
+where
+	<T as frame_system::Config>::Call: GetCallMetadata,
+{
+	fn filter(call: &<T as frame_system::Config>::Call) -> bool {
+		let CallMetadata {
+			function_name,
+			pallet_name,
+		} = call.get_call_metadata();
+
+		!Pallet::<T>::is_paused(pallet_name, function_name)
+	}
+}
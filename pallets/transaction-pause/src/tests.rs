@@ -0,0 +1,129 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+pub use crate::mock::{Call, ExtBuilder, Origin, System, Test, TransactionPause, ALICE};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{traits::Dispatchable, DispatchError::BadOrigin};
+use test_utils::expect_events;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn remark_call() -> Call {
+	Call::System(frame_system::Call::remark(vec![]))
+}
+
+#[test]
+fn pause_transaction_should_work() {
+	new_test_ext().execute_with(|| {
+		assert!(PausedTransactionFilter::<Test>::filter(&remark_call()));
+
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::root(),
+			b"System".to_vec(),
+			b"remark".to_vec()
+		));
+
+		assert!(TransactionPause::is_paused("System", "remark"));
+		assert!(!PausedTransactionFilter::<Test>::filter(&remark_call()));
+		assert!(remark_call().dispatch(Origin::signed(ALICE)).is_err());
+
+		// Other functions of the pallet are not affected
+		assert!(PausedTransactionFilter::<Test>::filter(&Call::System(
+			frame_system::Call::set_heap_pages(0)
+		)));
+
+		expect_events::<Test>(vec![Event::<Test>::TransactionPaused(
+			b"System".to_vec(),
+			b"remark".to_vec(),
+		)
+		.into()]);
+	});
+}
+
+#[test]
+fn pause_transaction_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TransactionPause::pause_transaction(Origin::signed(ALICE), b"System".to_vec(), b"remark".to_vec()),
+			BadOrigin
+		);
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				Origin::root(),
+				b"TransactionPause".to_vec(),
+				b"unpause_transaction".to_vec()
+			),
+			Error::<Test>::CannotPause
+		);
+		assert_noop!(
+			TransactionPause::pause_transaction(Origin::root(), vec![0xff], b"remark".to_vec()),
+			Error::<Test>::InvalidCharacter
+		);
+
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::root(),
+			b"System".to_vec(),
+			b"remark".to_vec()
+		));
+
+		assert_noop!(
+			TransactionPause::pause_transaction(Origin::root(), b"System".to_vec(), b"remark".to_vec()),
+			Error::<Test>::AlreadyPaused
+		);
+	});
+}
+
+#[test]
+fn unpause_transaction_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::root(),
+			b"System".to_vec(),
+			b"remark".to_vec()
+		));
+
+		assert_noop!(
+			TransactionPause::unpause_transaction(Origin::signed(ALICE), b"System".to_vec(), b"remark".to_vec()),
+			BadOrigin
+		);
+
+		assert_ok!(TransactionPause::unpause_transaction(
+			Origin::root(),
+			b"System".to_vec(),
+			b"remark".to_vec()
+		));
+
+		assert!(!TransactionPause::is_paused("System", "remark"));
+		assert!(PausedTransactionFilter::<Test>::filter(&remark_call()));
+		assert_ok!(remark_call().dispatch(Origin::signed(ALICE)));
+
+		assert_noop!(
+			TransactionPause::unpause_transaction(Origin::root(), b"System".to_vec(), b"remark".to_vec()),
+			Error::<Test>::NotPaused
+		);
+
+		expect_events::<Test>(vec![
+			Event::<Test>::TransactionPaused(b"System".to_vec(), b"remark".to_vec()).into(),
+			Event::<Test>::TransactionUnpaused(b"System".to_vec(), b"remark".to_vec()).into(),
+		]);
+	});
+}
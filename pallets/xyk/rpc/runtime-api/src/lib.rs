@@ -24,9 +24,13 @@
 #![allow(clippy::upper_case_acronyms)]
 
 use codec::{Codec, Decode, Encode};
+use primitives::asset::AssetPair;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+use sp_runtime::{
+	traits::{MaybeDisplay, MaybeFromStr},
+	Permill,
+};
 use sp_std::prelude::*;
 
 #[derive(Eq, PartialEq, Encode, Decode, Default)]
@@ -42,6 +46,85 @@ pub struct BalanceInfo<AssetId, Balance> {
 	pub asset: Option<AssetId>,
 }
 
+/// Trade fee of specific account.
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TradeFeeInfo<Balance> {
+	/// Standard trade fee
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub fee: Balance,
+
+	/// Discounted trade fee, available only if account is eligible for the discount
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_option_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_option_from_string"))]
+	pub discounted_fee: Option<Balance>,
+}
+
+/// Quote of a trade including the trade fee.
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TradeQuote<Balance> {
+	/// Amount received for a sell or amount paid including the fee for a buy
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub amount: Balance,
+
+	/// Trade fee paid in the sold asset
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub fee: Balance,
+
+	/// Difference between the trade price and spot price of the pool
+	pub price_impact: Permill,
+}
+
+/// Amounts of pool assets returned for removed liquidity.
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct LiquidityOut<Balance> {
+	/// Amount of the first asset of the pool
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub amount_a: Balance,
+
+	/// Amount of the second asset of the pool
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub amount_b: Balance,
+}
+
+/// Best route of a sell through pools.
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Route<AssetId, Balance> {
+	/// Assets of the route, starting with the sold and ending with the bought asset
+	pub assets: Vec<AssetId>,
+
+	/// Amount of the bought asset received including trade fees of all pools of the route
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub amount: Balance,
+}
+
 #[cfg(feature = "std")]
 fn serialize_as_string<S: Serializer, T: std::fmt::Display>(t: &T, serializer: S) -> Result<S::Ok, S::Error> {
 	serializer.serialize_str(&t.to_string())
@@ -54,6 +137,29 @@ fn deserialize_from_string<'de, D: Deserializer<'de>, T: std::str::FromStr>(dese
 		.map_err(|_| serde::de::Error::custom("Parse from string failed"))
 }
 
+#[cfg(feature = "std")]
+fn serialize_option_as_string<S: Serializer, T: std::fmt::Display>(
+	t: &Option<T>,
+	serializer: S,
+) -> Result<S::Ok, S::Error> {
+	match t {
+		Some(t) => serializer.serialize_some(&t.to_string()),
+		None => serializer.serialize_none(),
+	}
+}
+
+#[cfg(feature = "std")]
+fn deserialize_option_from_string<'de, D: Deserializer<'de>, T: std::str::FromStr>(
+	deserializer: D,
+) -> Result<Option<T>, D::Error> {
+	Option::<String>::deserialize(deserializer)?
+		.map(|s| {
+			s.parse::<T>()
+				.map_err(|_| serde::de::Error::custom("Parse from string failed"))
+		})
+		.transpose()
+}
+
 sp_api::decl_runtime_apis! {
 	pub trait XYKApi<AccountId, AssetId, Balance> where
 		AccountId: Codec,
@@ -63,5 +169,70 @@ sp_api::decl_runtime_apis! {
 		fn get_pool_balances(
 			pool_address: AccountId,
 		) -> Vec<BalanceInfo<AssetId, Balance>>;
+
+		fn get_pool_storage_keys(
+			pool_address: AccountId,
+		) -> Vec<Vec<u8>>;
+
+		fn query_trade_fee(
+			account: AccountId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+		) -> Option<TradeFeeInfo<Balance>>;
+
+		fn get_spot_price(
+			asset_a: AssetId,
+			asset_b: AssetId,
+			amount: Balance,
+		) -> Option<Balance>;
+
+		fn calculate_sell_price(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+		) -> Option<TradeQuote<Balance>>;
+
+		fn calculate_buy_price(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+		) -> Option<TradeQuote<Balance>>;
+
+		fn calculate_sell_price_for(
+			account: AccountId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+			discount: bool,
+		) -> Option<TradeQuote<Balance>>;
+
+		fn calculate_buy_price_for(
+			account: AccountId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+			discount: bool,
+		) -> Option<TradeQuote<Balance>>;
+
+		fn calculate_liquidity_out(
+			asset_a: AssetId,
+			asset_b: AssetId,
+			share_amount: Balance,
+		) -> Option<LiquidityOut<Balance>>;
+
+		fn get_pool_id(
+			asset_pair: AssetPair,
+		) -> Option<AccountId>;
+
+		fn get_pool_asset_pair(
+			pool_address: AccountId,
+		) -> Option<AssetPair>;
+
+		fn find_best_route(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+		) -> Option<Route<AssetId, Balance>>;
 	}
 }
@@ -20,10 +20,13 @@
 use codec::Codec;
 use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
-use pallet_xyk_rpc_runtime_api::BalanceInfo;
+use pallet_xyk_rpc_runtime_api::{BalanceInfo, LiquidityOut, Route, TradeFeeInfo, TradeQuote};
+use primitives::asset::AssetPair;
+use sc_client_api::ProofProvider;
 use serde::{Deserialize, Serialize};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
 use sp_runtime::{
 	generic::BlockId,
 	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
@@ -32,6 +35,11 @@ use std::sync::Arc;
 
 pub use self::gen_client::Client as XYKClient;
 pub use pallet_xyk_rpc_runtime_api::XYKApi as XYKRuntimeApi;
+pub use pool_changes::{XYKPoolChanges, XYKPoolChangesApi};
+pub use watchlist::{XYKWatchlist, XYKWatchlistApi};
+
+pub mod pool_changes;
+pub mod watchlist;
 
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -40,10 +48,93 @@ pub struct BalanceRequest<Balance> {
 	amount: Balance,
 }
 
+/// Storage proof of pool state at given block.
+///
+/// Contains proof of pool's share token, total liquidity, pool assets and reserve balances,
+/// which can be verified against state root of the block header.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolStorageProof<Hash> {
+	/// Block hash used to generate the proof
+	pub at: Hash,
+	/// Storage keys covered by the proof
+	pub keys: Vec<Bytes>,
+	/// A proof used to prove that storage entries are included in the storage trie
+	pub proof: Vec<Bytes>,
+}
+
 #[rpc]
 pub trait XYKApi<BlockHash, AccountId, AssetId, Balance, ResponseType> {
 	#[rpc(name = "xyk_getPoolBalances")]
 	fn get_pool_balances(&self, pool_address: AccountId, at: Option<BlockHash>) -> Result<Vec<ResponseType>>;
+
+	#[rpc(name = "xyk_getPoolStorageProof")]
+	fn get_pool_storage_proof(
+		&self,
+		pool_address: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<PoolStorageProof<BlockHash>>;
+
+	#[rpc(name = "xyk_queryTradeFee")]
+	fn query_trade_fee(
+		&self,
+		account: AccountId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount: Balance,
+		at: Option<BlockHash>,
+	) -> Result<Option<TradeFeeInfo<Balance>>>;
+
+	/// Quote selling `amount` of `asset_in`. Return received amount of `asset_out`, trade fee and price impact.
+	#[rpc(name = "xyk_calculateSellPrice")]
+	fn calculate_sell_price(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount: Balance,
+		at: Option<BlockHash>,
+	) -> Result<Option<TradeQuote<Balance>>>;
+
+	/// Quote buying `amount` of `asset_out`. Return paid amount of `asset_in` including trade fee, the fee and
+	/// price impact.
+	#[rpc(name = "xyk_calculateBuyPrice")]
+	fn calculate_buy_price(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount: Balance,
+		at: Option<BlockHash>,
+	) -> Result<Option<TradeQuote<Balance>>>;
+
+	/// Preview removing `share_amount` of liquidity from the pool of `asset_a` and `asset_b`.
+	/// Return exact amounts of both assets returned at current reserves.
+	#[rpc(name = "xyk_calculateLiquidityOut")]
+	fn calculate_liquidity_out(
+		&self,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		share_amount: Balance,
+		at: Option<BlockHash>,
+	) -> Result<Option<LiquidityOut<Balance>>>;
+
+	/// Return account of the pool of given asset pair, e.g. `"0-1"`. Both directions identify the same pool.
+	#[rpc(name = "xyk_getPoolId")]
+	fn get_pool_id(&self, asset_pair: AssetPair, at: Option<BlockHash>) -> Result<Option<AccountId>>;
+
+	/// Return canonical asset pair of the pool.
+	#[rpc(name = "xyk_getPoolAssetPair")]
+	fn get_pool_asset_pair(&self, pool_address: AccountId, at: Option<BlockHash>) -> Result<Option<AssetPair>>;
+
+	/// Find route selling `amount` of `asset_in` for the highest amount of `asset_out` through direct pool or up to
+	/// two intermediate assets. Return assets of the route and received amount including trade fees.
+	#[rpc(name = "xyk_findBestRoute")]
+	fn find_best_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount: Balance,
+		at: Option<BlockHash>,
+	) -> Result<Option<Route<AssetId, Balance>>>;
 }
 
 /// A struct that implements the [`XYKApi`].
@@ -65,12 +156,21 @@ impl<C, B> XYK<C, B> {
 pub enum Error {
 	/// The call to runtime failed.
 	RuntimeError,
+	/// Failed to generate storage proof.
+	StorageProofError,
+	/// Pool of given asset pair does not exist.
+	PoolNotFound,
+	/// Failed to subscribe to storage changes.
+	StorageSubscriptionError,
 }
 
 impl From<Error> for i64 {
 	fn from(e: Error) -> i64 {
 		match e {
 			Error::RuntimeError => 1,
+			Error::StorageProofError => 2,
+			Error::PoolNotFound => 3,
+			Error::StorageSubscriptionError => 4,
 		}
 	}
 }
@@ -79,7 +179,7 @@ impl<C, Block, AccountId, AssetId, Balance>
 	XYKApi<<Block as BlockT>::Hash, AccountId, AssetId, Balance, BalanceInfo<AssetId, Balance>> for XYK<C, Block>
 where
 	Block: BlockT,
-	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + ProofProvider<Block>,
 	C::Api: XYKRuntimeApi<Block, AccountId, AssetId, Balance>,
 	AccountId: Codec,
 	AssetId: Codec,
@@ -101,4 +201,168 @@ where
 			data: Some(format!("{:?}", e).into()),
 		})
 	}
+
+	fn get_pool_storage_proof(
+		&self,
+		pool_address: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<PoolStorageProof<<Block as BlockT>::Hash>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+		let at = BlockId::hash(at_hash);
+
+		let keys = api.get_pool_storage_keys(&at, pool_address).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to retrieve pool storage keys.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?;
+
+		let proof = self
+			.client
+			.read_proof(&at, &mut keys.iter().map(|key| key.as_ref()))
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::StorageProofError.into()),
+				message: "Unable to generate pool storage proof.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})?;
+
+		Ok(PoolStorageProof {
+			at: at_hash,
+			keys: keys.into_iter().map(Bytes::from).collect(),
+			proof: proof.iter_nodes().map(Bytes::from).collect(),
+		})
+	}
+
+	fn query_trade_fee(
+		&self,
+		account: AccountId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<TradeFeeInfo<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.query_trade_fee(&at, account, asset_in, asset_out, amount)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to query trade fee.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+
+	fn calculate_sell_price(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<TradeQuote<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.calculate_sell_price(&at, asset_in, asset_out, amount)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to calculate sell price.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+
+	fn calculate_buy_price(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<TradeQuote<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.calculate_buy_price(&at, asset_in, asset_out, amount)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to calculate buy price.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+
+	fn calculate_liquidity_out(
+		&self,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		share_amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<LiquidityOut<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.calculate_liquidity_out(&at, asset_a, asset_b, share_amount)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to calculate liquidity out.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+
+	fn get_pool_id(&self, asset_pair: AssetPair, at: Option<<Block as BlockT>::Hash>) -> Result<Option<AccountId>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.get_pool_id(&at, asset_pair).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to retrieve pool id.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+
+	fn get_pool_asset_pair(
+		&self,
+		pool_address: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<AssetPair>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.get_pool_asset_pair(&at, pool_address).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to retrieve pool asset pair.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+
+	fn find_best_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<Route<AssetId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.find_best_route(&at, asset_in, asset_out, amount)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to find best route.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
 }
@@ -0,0 +1,265 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pool changes subscription.
+//!
+//! Client subscribes to the pool of an asset pair. Pool reserves and spot price are sent on subscription and then
+//! whenever a new best block changes storage of the pool, i.e. the block contains trades or liquidity changes
+//! of the pool, so clients do not have to query pool state every block.
+
+use crate::{Error, XYKRuntimeApi};
+use codec::Codec;
+use futures::{future, StreamExt, TryStreamExt};
+use jsonrpc_core::futures::{Future, Sink, Stream};
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use jsonrpc_pubsub::{manager::SubscriptionManager, typed::Subscriber, SubscriptionId};
+use log::warn;
+use primitives::asset::AssetPair;
+use sc_client_api::BlockchainEvents;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::storage::StorageKey;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+};
+use std::sync::Arc;
+
+pub use self::gen_client::Client as XYKPoolChangesClient;
+
+/// Reserve of a pool asset.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolReserve<AssetId, Balance> {
+	/// Pool asset
+	pub asset: AssetId,
+	/// Balance of the asset held by the pool
+	pub amount: Balance,
+}
+
+/// Notification about state of subscribed pool.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolChange<Hash, AccountId, AssetId, Balance> {
+	/// Best block which changed the pool
+	pub block: Hash,
+	/// Account of the pool
+	pub pool: AccountId,
+	/// Reserves of pool assets at `block`, empty if the pool was destroyed
+	pub reserves: Vec<PoolReserve<AssetId, Balance>>,
+	/// Price of subscribed amount of `asset_in` in `asset_out` at `block`, `None` if the pool was destroyed
+	pub spot_price: Option<Balance>,
+}
+
+#[rpc]
+pub trait XYKPoolChangesApi<BlockHash, AccountId, AssetId, Balance> {
+	/// RPC metadata
+	type Metadata;
+
+	/// Subscribe to reserves and spot price of `amount` of `asset_in` of the pool of given asset pair, e.g. `"0-1"`.
+	#[pubsub(subscription = "xyk_poolChange", subscribe, name = "xyk_subscribePoolChanges")]
+	fn subscribe_pool_changes(
+		&self,
+		metadata: Self::Metadata,
+		subscriber: Subscriber<PoolChange<BlockHash, AccountId, AssetId, Balance>>,
+		asset_pair: AssetPair,
+		amount: Balance,
+	);
+
+	/// Unsubscribe from pool changes.
+	#[pubsub(subscription = "xyk_poolChange", unsubscribe, name = "xyk_unsubscribePoolChanges")]
+	fn unsubscribe_pool_changes(&self, metadata: Option<Self::Metadata>, id: SubscriptionId) -> Result<bool>;
+}
+
+/// A struct that implements the [`XYKPoolChangesApi`].
+pub struct XYKPoolChanges<C, B> {
+	client: Arc<C>,
+	manager: SubscriptionManager,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> XYKPoolChanges<C, B> {
+	/// Create new `XYKPoolChanges` with the given reference to the client and executor driving the subscriptions.
+	pub fn new<E>(client: Arc<C>, executor: E) -> Self
+	where
+		E: jsonrpc_core::futures::future::Executor<Box<dyn Future<Item = (), Error = ()> + Send>>
+			+ Send
+			+ Sync
+			+ 'static,
+	{
+		XYKPoolChanges {
+			client,
+			manager: SubscriptionManager::new(Arc::new(executor)),
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Return account and storage keys of the pool of `asset_pair` at given block.
+fn pool_storage_keys<C, Block, AccountId, AssetId, Balance>(
+	client: &C,
+	at: <Block as BlockT>::Hash,
+	asset_pair: AssetPair,
+) -> Result<(AccountId, Vec<StorageKey>)>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>,
+	C::Api: XYKRuntimeApi<Block, AccountId, AssetId, Balance>,
+	AccountId: Codec + Clone,
+	AssetId: Codec,
+	Balance: Codec + MaybeDisplay + MaybeFromStr,
+{
+	let runtime_error = |e| RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: "Unable to retrieve pool.".into(),
+		data: Some(format!("{:?}", e).into()),
+	};
+
+	let pool = client
+		.runtime_api()
+		.get_pool_id(&BlockId::hash(at), asset_pair)
+		.map_err(runtime_error)?
+		.ok_or(RpcError {
+			code: ErrorCode::ServerError(Error::PoolNotFound.into()),
+			message: "Pool of given asset pair does not exist.".into(),
+			data: None,
+		})?;
+
+	let keys = client
+		.runtime_api()
+		.get_pool_storage_keys(&BlockId::hash(at), pool.clone())
+		.map_err(runtime_error)?;
+
+	Ok((pool, keys.into_iter().map(StorageKey).collect()))
+}
+
+/// Query state of the pool at given block.
+fn pool_change<C, Block, AccountId, AssetId, Balance>(
+	client: &C,
+	at: <Block as BlockT>::Hash,
+	pool: &AccountId,
+	asset_pair: (&AssetId, &AssetId),
+	amount: &Balance,
+) -> PoolChange<<Block as BlockT>::Hash, AccountId, AssetId, Balance>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>,
+	C::Api: XYKRuntimeApi<Block, AccountId, AssetId, Balance>,
+	AccountId: Codec + Clone,
+	AssetId: Codec + Clone,
+	Balance: Codec + Clone + MaybeDisplay + MaybeFromStr,
+{
+	let (asset_in, asset_out) = asset_pair;
+
+	// Balances of a destroyed pool cannot be retrieved.
+	let reserves = client
+		.runtime_api()
+		.get_pool_balances(&BlockId::hash(at), pool.clone())
+		.unwrap_or_default()
+		.into_iter()
+		.filter_map(|balance| {
+			balance.asset.map(|asset| PoolReserve {
+				asset,
+				amount: balance.amount,
+			})
+		})
+		.collect();
+
+	let spot_price = client
+		.runtime_api()
+		.get_spot_price(&BlockId::hash(at), asset_in.clone(), asset_out.clone(), amount.clone())
+		.ok()
+		.flatten();
+
+	PoolChange {
+		block: at,
+		pool: pool.clone(),
+		reserves,
+		spot_price,
+	}
+}
+
+impl<C, Block, AccountId, AssetId, Balance> XYKPoolChangesApi<<Block as BlockT>::Hash, AccountId, AssetId, Balance>
+	for XYKPoolChanges<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block>,
+	C::Api: XYKRuntimeApi<Block, AccountId, AssetId, Balance>,
+	AccountId: Codec + Clone + Serialize + Send + Sync + 'static,
+	AssetId: Codec + Clone + From<primitives::AssetId> + Serialize + Send + Sync + 'static,
+	Balance: Codec + Clone + MaybeDisplay + MaybeFromStr + Serialize + Send + Sync + 'static,
+{
+	type Metadata = sc_rpc_api::Metadata;
+
+	fn subscribe_pool_changes(
+		&self,
+		_metadata: Self::Metadata,
+		subscriber: Subscriber<PoolChange<<Block as BlockT>::Hash, AccountId, AssetId, Balance>>,
+		asset_pair: AssetPair,
+		amount: Balance,
+	) {
+		let client = self.client.clone();
+		let best_hash = client.info().best_hash;
+
+		let (pool, keys) = match pool_storage_keys::<_, Block, _, AssetId, Balance>(&*client, best_hash, asset_pair) {
+			Ok(pool_keys) => pool_keys,
+			Err(e) => {
+				let _ = subscriber.reject(e);
+				return;
+			}
+		};
+
+		// Storage keys of the pool cover its reserves, so the stream is notified about trades and liquidity changes.
+		let changes = match client.storage_changes_notification_stream(Some(&keys), None) {
+			Ok(changes) => changes,
+			Err(e) => {
+				let _ = subscriber.reject(RpcError {
+					code: ErrorCode::ServerError(Error::StorageSubscriptionError.into()),
+					message: "Unable to subscribe to pool storage changes.".into(),
+					data: Some(format!("{:?}", e).into()),
+				});
+				return;
+			}
+		};
+
+		let asset_in: AssetId = asset_pair.asset_in.into();
+		let asset_out: AssetId = asset_pair.asset_out.into();
+
+		// State of the pool at the best block at the time of subscription is sent first.
+		let initial = pool_change::<_, Block, _, _, _>(&*client, best_hash, &pool, (&asset_in, &asset_out), &amount);
+
+		let stream = futures::stream::once(future::ready(initial))
+			.chain(changes.map(move |(block, _)| {
+				pool_change::<_, Block, _, _, _>(&*client, block, &pool, (&asset_in, &asset_out), &amount)
+			}))
+			.map(|change| Ok::<_, ()>(Ok(change)))
+			.compat();
+
+		self.manager.add(subscriber, |sink| {
+			sink.sink_map_err(|e| warn!("Error sending pool change: {:?}", e))
+				.send_all(stream)
+				// we ignore the resulting Stream (if the first stream is over we are unsubscribed)
+				.map(|_| ())
+		});
+	}
+
+	fn unsubscribe_pool_changes(&self, _metadata: Option<Self::Metadata>, id: SubscriptionId) -> Result<bool> {
+		Ok(self.manager.cancel(id))
+	}
+}
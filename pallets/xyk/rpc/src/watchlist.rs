@@ -0,0 +1,256 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Price watchlist subscription.
+//!
+//! Client registers a watchlist of pairs with price thresholds. Spot prices of watched pairs are checked
+//! on every new best block and a notification is sent whenever a price crosses its threshold,
+//! so clients do not have to query pool state every block.
+
+use crate::XYKRuntimeApi;
+use codec::Codec;
+use futures::{future, StreamExt, TryStreamExt};
+use jsonrpc_core::futures::{Future, Sink, Stream};
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use jsonrpc_pubsub::{manager::SubscriptionManager, typed::Subscriber, SubscriptionId};
+use log::warn;
+use sc_client_api::BlockchainEvents;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+};
+use std::sync::Arc;
+
+pub use self::gen_client::Client as XYKWatchlistClient;
+
+/// Pair watched by a subscription.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct WatchedPair<AssetId, Balance> {
+	/// Asset which price is watched
+	pub asset_a: AssetId,
+	/// Asset in which the price is expressed
+	pub asset_b: AssetId,
+	/// Amount of `asset_a` to be priced
+	pub amount: Balance,
+	/// Price of `amount` of `asset_a` in `asset_b` which triggers the notification when crossed
+	pub threshold: Balance,
+}
+
+/// Direction in which the price crossed the threshold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Crossing {
+	/// Price rose to or above the threshold
+	Above,
+	/// Price dropped to or below the threshold
+	Below,
+}
+
+/// Notification about price of watched pair crossing its threshold.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceAlert<Hash, AssetId, Balance> {
+	/// Best block in which the threshold was crossed
+	pub block: Hash,
+	/// Asset which price is watched
+	pub asset_a: AssetId,
+	/// Asset in which the price is expressed
+	pub asset_b: AssetId,
+	/// Amount of `asset_a` which was priced
+	pub amount: Balance,
+	/// Crossed threshold
+	pub threshold: Balance,
+	/// Price of `amount` of `asset_a` in `asset_b` at `block`
+	pub price: Balance,
+	/// Direction of the crossing
+	pub crossing: Crossing,
+}
+
+#[rpc]
+pub trait XYKWatchlistApi<BlockHash, AssetId, Balance> {
+	/// RPC metadata
+	type Metadata;
+
+	/// Subscribe to notifications about spot prices of watched pairs crossing their thresholds.
+	#[pubsub(subscription = "xyk_priceAlert", subscribe, name = "xyk_subscribePriceAlerts")]
+	fn subscribe_price_alerts(
+		&self,
+		metadata: Self::Metadata,
+		subscriber: Subscriber<PriceAlert<BlockHash, AssetId, Balance>>,
+		watchlist: Vec<WatchedPair<AssetId, Balance>>,
+	);
+
+	/// Unsubscribe from price alerts.
+	#[pubsub(subscription = "xyk_priceAlert", unsubscribe, name = "xyk_unsubscribePriceAlerts")]
+	fn unsubscribe_price_alerts(&self, metadata: Option<Self::Metadata>, id: SubscriptionId) -> Result<bool>;
+}
+
+/// A struct that implements the [`XYKWatchlistApi`].
+pub struct XYKWatchlist<C, B, AccountId> {
+	client: Arc<C>,
+	manager: SubscriptionManager,
+	_marker: std::marker::PhantomData<(B, AccountId)>,
+}
+
+impl<C, B, AccountId> XYKWatchlist<C, B, AccountId> {
+	/// Create new `XYKWatchlist` with the given reference to the client and executor driving the subscriptions.
+	pub fn new<E>(client: Arc<C>, executor: E) -> Self
+	where
+		E: jsonrpc_core::futures::future::Executor<Box<dyn Future<Item = (), Error = ()> + Send>>
+			+ Send
+			+ Sync
+			+ 'static,
+	{
+		XYKWatchlist {
+			client,
+			manager: SubscriptionManager::new(Arc::new(executor)),
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Return direction in which the price crossed the threshold between two blocks, if it did.
+fn crossing<Balance: PartialOrd>(previous: &Balance, current: &Balance, threshold: &Balance) -> Option<Crossing> {
+	if previous < threshold && current >= threshold {
+		Some(Crossing::Above)
+	} else if previous > threshold && current <= threshold {
+		Some(Crossing::Below)
+	} else {
+		None
+	}
+}
+
+/// Query spot price of watched pair at given block. Returns `None` if the pool does not exist.
+fn spot_price<C, Block, AccountId, AssetId, Balance>(
+	client: &C,
+	at: <Block as BlockT>::Hash,
+	pair: &WatchedPair<AssetId, Balance>,
+) -> Option<Balance>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>,
+	C::Api: XYKRuntimeApi<Block, AccountId, AssetId, Balance>,
+	AccountId: Codec,
+	AssetId: Codec + Clone,
+	Balance: Codec + Clone + MaybeDisplay + MaybeFromStr,
+{
+	client
+		.runtime_api()
+		.get_spot_price(
+			&BlockId::hash(at),
+			pair.asset_a.clone(),
+			pair.asset_b.clone(),
+			pair.amount.clone(),
+		)
+		.ok()
+		.flatten()
+}
+
+impl<C, Block, AccountId, AssetId, Balance> XYKWatchlistApi<<Block as BlockT>::Hash, AssetId, Balance>
+	for XYKWatchlist<C, Block, AccountId>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block>,
+	C::Api: XYKRuntimeApi<Block, AccountId, AssetId, Balance>,
+	AccountId: Codec + Send + Sync + 'static,
+	AssetId: Codec + Clone + Serialize + Send + Sync + 'static,
+	Balance: Codec + Clone + PartialOrd + MaybeDisplay + MaybeFromStr + Serialize + Send + Sync + 'static,
+{
+	type Metadata = sc_rpc_api::Metadata;
+
+	fn subscribe_price_alerts(
+		&self,
+		_metadata: Self::Metadata,
+		subscriber: Subscriber<PriceAlert<<Block as BlockT>::Hash, AssetId, Balance>>,
+		watchlist: Vec<WatchedPair<AssetId, Balance>>,
+	) {
+		let client = self.client.clone();
+		let best_hash = client.info().best_hash;
+
+		// Prices at the best block at the time of subscription are the starting point of the watch.
+		let mut last_prices: Vec<Option<Balance>> = watchlist
+			.iter()
+			.map(|pair| spot_price::<_, Block, AccountId, _, _>(&*client, best_hash, pair))
+			.collect();
+
+		let stream = client
+			.import_notification_stream()
+			.filter(|notification| future::ready(notification.is_new_best))
+			.map(move |notification| {
+				let mut alerts = Vec::new();
+
+				for (pair, last_price) in watchlist.iter().zip(last_prices.iter_mut()) {
+					let price = spot_price::<_, Block, AccountId, _, _>(&*client, notification.hash, pair);
+
+					if let (Some(previous), Some(current)) = (last_price.as_ref(), price.as_ref()) {
+						if let Some(crossing) = crossing(previous, current, &pair.threshold) {
+							alerts.push(PriceAlert {
+								block: notification.hash,
+								asset_a: pair.asset_a.clone(),
+								asset_b: pair.asset_b.clone(),
+								amount: pair.amount.clone(),
+								threshold: pair.threshold.clone(),
+								price: current.clone(),
+								crossing,
+							});
+						}
+					}
+
+					*last_price = price;
+				}
+
+				futures::stream::iter(alerts)
+			})
+			.flatten()
+			.map(|alert| Ok::<_, ()>(Ok(alert)))
+			.compat();
+
+		self.manager.add(subscriber, |sink| {
+			sink.sink_map_err(|e| warn!("Error sending price alert: {:?}", e))
+				.send_all(stream)
+				// we ignore the resulting Stream (if the first stream is over we are unsubscribed)
+				.map(|_| ())
+		});
+	}
+
+	fn unsubscribe_price_alerts(&self, _metadata: Option<Self::Metadata>, id: SubscriptionId) -> Result<bool> {
+		Ok(self.manager.cancel(id))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn crossing_should_work() {
+		assert_eq!(crossing(&90u128, &100u128, &100u128), Some(Crossing::Above));
+		assert_eq!(crossing(&90u128, &110u128, &100u128), Some(Crossing::Above));
+		assert_eq!(crossing(&110u128, &100u128, &100u128), Some(Crossing::Below));
+		assert_eq!(crossing(&110u128, &90u128, &100u128), Some(Crossing::Below));
+		assert_eq!(crossing(&90u128, &95u128, &100u128), None);
+		assert_eq!(crossing(&110u128, &105u128, &100u128), None);
+		assert_eq!(crossing(&100u128, &110u128, &100u128), None);
+		assert_eq!(crossing(&100u128, &90u128, &100u128), None);
+	}
+}
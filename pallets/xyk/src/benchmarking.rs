@@ -20,12 +20,13 @@
 use super::*;
 
 use frame_benchmarking::{account, benchmarks};
+use frame_support::sp_runtime::FixedPointNumber;
 use frame_system::RawOrigin;
 use sp_std::prelude::*;
 
 use crate::Pallet as XYK;
 
-use primitives::{AssetId, Balance, Price};
+use primitives::{AssetId, Balance, PoolState, Price};
 
 const SEED: u32 = 1;
 
@@ -50,6 +51,48 @@ benchmarks! {
 		assert_eq!(T::Currency::free_balance(asset_a, &caller), 999990000000000);
 	}
 
+	create_pool_with_amounts {
+		let caller = funded_account::<T>("caller", 0);
+
+		let asset_a: AssetId = 1;
+		let asset_b: AssetId = 2;
+		let amount_a : Balance = 10 * 1_000_000_000;
+		let amount_b : Balance = 20 * 1_000_000_000;
+
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount_a, amount_b)
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_a, &caller), 999990000000000);
+		assert_eq!(T::Currency::free_balance(asset_b, &caller), 999980000000000);
+	}
+
+	create_permissioned_pool {
+		let caller = funded_account::<T>("caller", 0);
+
+		let asset_a: AssetId = 1;
+		let asset_b: AssetId = 2;
+		let amount : Balance = 10 * 1_000_000_000;
+		let initial_price : Price = Price::from(2);
+
+		let pair_account = XYK::<T>::get_pair_id(AssetPair { asset_in: asset_a, asset_out: asset_b });
+
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount, initial_price, 3)
+	verify {
+		assert_eq!(XYK::<T>::pool_access_asset(&pair_account), Some(3));
+	}
+
+	create_weighted_pool {
+		let caller = funded_account::<T>("caller", 0);
+
+		let asset_a: AssetId = 1;
+		let asset_b: AssetId = 2;
+		let amount : Balance = 10 * 1_000_000_000;
+		let initial_price : Price = Price::from(2);
+
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount, initial_price, 80)
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_a, &caller), 999990000000000);
+	}
+
 	add_liquidity {
 		let maker = funded_account::<T>("maker", 0);
 		let caller = funded_account::<T>("caller", 0);
@@ -81,7 +124,7 @@ benchmarks! {
 		assert_eq!(T::Currency::free_balance(asset_a, &caller), 999995000000000);
 		assert_eq!(T::Currency::free_balance(asset_b, &caller), 999990000000000);
 
-	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount)
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount, 0, 0)
 	verify {
 		assert_eq!(T::Currency::free_balance(asset_a, &caller), 999996000000000);
 		assert_eq!(T::Currency::free_balance(asset_b, &caller), 999992000000000);
@@ -124,6 +167,138 @@ benchmarks! {
 		assert_eq!(T::Currency::free_balance(asset_a, &caller), 1000001000000000);
 		assert_eq!(T::Currency::free_balance(asset_b, &caller), 999996990990990);
 	}
+
+	set_protocol_fee {
+		let protocol_fee = fee::Fee { numerator: 1, denominator: 6 };
+
+	}: _(RawOrigin::Root, protocol_fee)
+	verify {
+		assert_eq!(XYK::<T>::protocol_fee(), protocol_fee);
+	}
+
+	set_pool_access_asset {
+		let caller = funded_account::<T>("caller", 0);
+
+		let asset_a: AssetId = 1;
+		let asset_b: AssetId = 2;
+
+		XYK::<T>::create_permissioned_pool(RawOrigin::Signed(caller.clone()).into(), asset_a, asset_b, 10 * 1_000_000_000, Price::from(2), 3)?;
+
+		let pair_account = XYK::<T>::get_pair_id(AssetPair { asset_in: asset_a, asset_out: asset_b });
+
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, Some(4))
+	verify {
+		assert_eq!(XYK::<T>::pool_access_asset(&pair_account), Some(4));
+	}
+
+	renew_pool_ownership {
+		let caller = funded_account::<T>("caller", 0);
+
+		let asset_a: AssetId = 1;
+		let asset_b: AssetId = 2;
+
+		XYK::<T>::create_permissioned_pool(RawOrigin::Signed(caller.clone()).into(), asset_a, asset_b, 10 * 1_000_000_000, Price::from(2), 3)?;
+
+		let pair_account = XYK::<T>::get_pair_id(AssetPair { asset_in: asset_a, asset_out: asset_b });
+
+		let now = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+		frame_system::Pallet::<T>::set_block_number(now);
+
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b)
+	verify {
+		assert_eq!(XYK::<T>::pool_owner(&pair_account), Some((caller, now.saturating_add(T::PoolOwnerInactivityPeriod::get()))));
+	}
+
+	expire_pool_owner {
+		let caller = funded_account::<T>("caller", 0);
+
+		let asset_a: AssetId = 1;
+		let asset_b: AssetId = 2;
+
+		XYK::<T>::create_permissioned_pool(RawOrigin::Signed(caller.clone()).into(), asset_a, asset_b, 10 * 1_000_000_000, Price::from(2), 3)?;
+
+		let pair_account = XYK::<T>::get_pair_id(AssetPair { asset_in: asset_a, asset_out: asset_b });
+
+		let (_, deadline) = XYK::<T>::pool_owner(&pair_account).ok_or("Pool owner not set")?;
+		frame_system::Pallet::<T>::set_block_number(deadline);
+
+	}: _(RawOrigin::Root, pair_account.clone())
+	verify {
+		assert_eq!(XYK::<T>::pool_owner(&pair_account), None);
+	}
+
+	destroy_pool {
+		let caller = funded_account::<T>("caller", 0);
+
+		let asset_a: AssetId = 1;
+		let asset_b: AssetId = 2;
+
+		let amount = T::MinPoolLiquidity::get().max(MINIMUM_LIQUIDITY + 1);
+		XYK::<T>::create_permissioned_pool(RawOrigin::Signed(caller.clone()).into(), asset_a, asset_b, amount, Price::from(1), 3)?;
+
+		let pair_account = XYK::<T>::get_pair_id(AssetPair { asset_in: asset_a, asset_out: asset_b });
+
+		// Drain reserves down to dust.
+		T::Currency::withdraw(asset_a, &pair_account, amount.saturating_sub(T::MaxPoolDust::get()))?;
+		T::Currency::withdraw(asset_b, &pair_account, amount.saturating_sub(T::MaxPoolDust::get()))?;
+
+	}: _(RawOrigin::Root, asset_a, asset_b)
+	verify {
+		assert!(!XYK::<T>::exists(AssetPair { asset_in: asset_a, asset_out: asset_b }));
+		assert_eq!(T::Currency::free_balance(asset_a, &pair_account), 0);
+	}
+
+	resolve_quarantined_pool {
+		let caller = funded_account::<T>("caller", 0);
+
+		let asset_a: AssetId = 1;
+		let asset_b: AssetId = 2;
+
+		// Amount of asset b rounds down to zero, so the pool is quarantined right after creation.
+		let amount = T::MinPoolLiquidity::get().max(MINIMUM_LIQUIDITY + 1);
+		XYK::<T>::create_permissioned_pool(RawOrigin::Signed(caller.clone()).into(), asset_a, asset_b, amount, Price::saturating_from_rational(1, amount + 1), 3)?;
+
+		let pair_account = XYK::<T>::get_pair_id(AssetPair { asset_in: asset_a, asset_out: asset_b });
+
+		assert!(XYK::<T>::is_quarantined(&pair_account));
+
+	}: _(RawOrigin::Root, asset_a, asset_b)
+	verify {
+		assert!(!XYK::<T>::exists(AssetPair { asset_in: asset_a, asset_out: asset_b }));
+	}
+
+	set_pool_state {
+		let caller = funded_account::<T>("caller", 0);
+
+		let asset_a: AssetId = 1;
+		let asset_b: AssetId = 2;
+
+		XYK::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), asset_a, asset_b, 10 * 1_000_000_000, Price::from(2))?;
+
+		let pair_account = XYK::<T>::get_pair_id(AssetPair { asset_in: asset_a, asset_out: asset_b });
+
+	}: _(RawOrigin::Root, asset_a, asset_b, PoolState::Frozen)
+	verify {
+		assert_eq!(XYK::<T>::pool_state(&pair_account), PoolState::Frozen);
+	}
+
+	flash_loan {
+		let maker = funded_account::<T>("maker", 0);
+		let caller = funded_account::<T>("caller", 0);
+
+		let asset_a: AssetId = 1;
+		let asset_b: AssetId = 2;
+		let amount : Balance = 1_000_000_000;
+
+		XYK::<T>::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, asset_b, 1_000_000_000_000, Price::from(3))?;
+
+		let call: Box<<T as Config>::Call> = Box::new(frame_system::Call::<T>::remark(vec![]).into());
+
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, asset_a, amount, call)
+	verify {
+		assert!(T::Currency::free_balance(asset_a, &caller) < 1_000_000_000_000_000);
+		assert_eq!(XYK::<T>::flash_loan_pool(), None);
+	}
 }
 
 #[cfg(test)]
@@ -136,10 +311,21 @@ mod tests {
 	fn test_benchmarks() {
 		new_test_ext().execute_with(|| {
 			assert_ok!(test_benchmark_create_pool::<Test>());
+			assert_ok!(test_benchmark_create_pool_with_amounts::<Test>());
+			assert_ok!(test_benchmark_create_permissioned_pool::<Test>());
+			assert_ok!(test_benchmark_create_weighted_pool::<Test>());
 			assert_ok!(test_benchmark_add_liquidity::<Test>());
 			assert_ok!(test_benchmark_remove_liquidity::<Test>());
 			assert_ok!(test_benchmark_sell::<Test>());
 			assert_ok!(test_benchmark_buy::<Test>());
+			assert_ok!(test_benchmark_set_protocol_fee::<Test>());
+			assert_ok!(test_benchmark_set_pool_access_asset::<Test>());
+			assert_ok!(test_benchmark_renew_pool_ownership::<Test>());
+			assert_ok!(test_benchmark_expire_pool_owner::<Test>());
+			assert_ok!(test_benchmark_destroy_pool::<Test>());
+			assert_ok!(test_benchmark_resolve_quarantined_pool::<Test>());
+			assert_ok!(test_benchmark_set_pool_state::<Test>());
+			assert_ok!(test_benchmark_flash_loan::<Test>());
 		});
 	}
 }
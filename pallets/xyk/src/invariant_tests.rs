@@ -0,0 +1,197 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Randomized tests of AMM invariants.
+//!
+//! Random sequences of liquidity changes and trades are applied to a pool and global invariants are checked
+//! after each step, whether the operation succeeded or not.
+
+use super::*;
+use crate::mock::{AccountId, Currency, ExtBuilder, Origin, System, ACA, ALICE, BOB, DOT, TREASURY, XYK};
+use frame_support::assert_ok;
+use primitive_types::U256;
+use proptest::prelude::*;
+use sp_runtime::{FixedPointNumber, PerThing};
+
+/// Issuance of each pool asset endowed by `ExtBuilder`.
+const ASSET_ISSUANCE: Balance = 2_000_000_000_000_000;
+
+#[derive(Debug, Clone)]
+enum Operation {
+	/// Add liquidity - amount of `ACA` is given as a fraction of the pool reserve.
+	AddLiquidity(AccountId, Permill),
+	/// Remove liquidity - amount is given as a fraction of shares of the account.
+	RemoveLiquidity(AccountId, Permill),
+	/// Sell `ACA` (true) or `DOT` (false) - amount is given as a fraction of the pool reserve of sold asset.
+	Sell(AccountId, bool, Permill),
+	/// Buy `ACA` (true) or `DOT` (false) - amount is given as a fraction of the pool reserve of bought asset.
+	Buy(AccountId, bool, Permill),
+}
+
+fn account() -> impl Strategy<Value = AccountId> {
+	prop_oneof![Just(ALICE), Just(BOB)]
+}
+
+fn fraction(max: u32) -> impl Strategy<Value = Permill> {
+	(0..=max).prop_map(Permill::from_parts)
+}
+
+fn operation() -> impl Strategy<Value = Operation> {
+	prop_oneof![
+		(account(), fraction(500_000)).prop_map(|(who, amount)| Operation::AddLiquidity(who, amount)),
+		(account(), fraction(1_000_000)).prop_map(|(who, amount)| Operation::RemoveLiquidity(who, amount)),
+		(account(), any::<bool>(), fraction(500_000)).prop_map(|(who, aca, amount)| Operation::Sell(who, aca, amount)),
+		(account(), any::<bool>(), fraction(500_000)).prop_map(|(who, aca, amount)| Operation::Buy(who, aca, amount)),
+	]
+}
+
+fn other(asset: AssetId) -> AssetId {
+	if asset == ACA {
+		DOT
+	} else {
+		ACA
+	}
+}
+
+fn reserves(pool: &AccountId) -> (Balance, Balance) {
+	(Currency::free_balance(ACA, pool), Currency::free_balance(DOT, pool))
+}
+
+fn product(reserves: (Balance, Balance)) -> U256 {
+	U256::from(reserves.0) * U256::from(reserves.1)
+}
+
+/// Apply `operation` to the pool of `ACA` and `DOT`. Return true if it was executed.
+fn apply(pool: &AccountId, share_token: AssetId, operation: &Operation) -> bool {
+	match *operation {
+		Operation::AddLiquidity(who, amount) => {
+			let amount_a = amount.mul_floor(Currency::free_balance(ACA, pool));
+			let max_b = Currency::free_balance(DOT, &who);
+			XYK::add_liquidity(Origin::signed(who), ACA, DOT, amount_a, max_b).is_ok()
+		}
+		Operation::RemoveLiquidity(who, amount) => {
+			let shares = amount.mul_floor(Currency::free_balance(share_token, &who));
+			XYK::remove_liquidity(Origin::signed(who), ACA, DOT, shares, 0, 0).is_ok()
+		}
+		Operation::Sell(who, aca, amount) => {
+			let asset_in = if aca { ACA } else { DOT };
+			let amount = amount.mul_floor(Currency::free_balance(asset_in, pool));
+			XYK::sell(Origin::signed(who), asset_in, other(asset_in), amount, 0, false).is_ok()
+		}
+		Operation::Buy(who, aca, amount) => {
+			let asset_out = if aca { ACA } else { DOT };
+			let amount = amount.mul_floor(Currency::free_balance(asset_out, pool));
+			let max_sold = Currency::free_balance(other(asset_out), &who);
+			XYK::buy(
+				Origin::signed(who),
+				asset_out,
+				other(asset_out),
+				amount,
+				max_sold,
+				false,
+			)
+			.is_ok()
+		}
+	}
+}
+
+/// Assert invariants which hold whether the pool exists or not.
+fn assert_invariants(pool: &AccountId, share_token: AssetId) {
+	let accounts = [ALICE, BOB, TREASURY, *pool];
+
+	// Assets are only moved between accounts, never minted or burned.
+	for asset in [ACA, DOT].iter() {
+		assert_eq!(Currency::total_issuance(*asset), ASSET_ISSUANCE);
+		assert_eq!(
+			accounts
+				.iter()
+				.map(|who| Currency::total_balance(*asset, who))
+				.sum::<Balance>(),
+			ASSET_ISSUANCE
+		);
+	}
+
+	// Every issued share is accounted for in total liquidity of the pool.
+	let share_issuance = Currency::total_issuance(share_token);
+	let shares = [ALICE, BOB, XYK::locked_liquidity_account()]
+		.iter()
+		.map(|who| Currency::total_balance(share_token, who))
+		.sum::<Balance>();
+
+	assert_eq!(shares, share_issuance);
+	assert_eq!(XYK::total_liquidity(pool), share_issuance);
+
+	if XYK::exists(AssetPair {
+		asset_in: ACA,
+		asset_out: DOT,
+	}) && !XYK::is_quarantined(pool)
+	{
+		let (reserve_a, reserve_b) = reserves(pool);
+		assert!(!reserve_a.is_zero() && !reserve_b.is_zero());
+		assert!(share_issuance >= MINIMUM_LIQUIDITY);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(200))]
+
+	#[test]
+	fn amm_invariants_should_hold(
+		initial_amount in 1_000_000..1_000_000_000_000u128,
+		price_n in 1..100u128,
+		price_d in 1..100u128,
+		operations in prop::collection::vec(operation(), 1..50),
+	) {
+		ExtBuilder::default().build().execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(XYK::create_pool(
+				Origin::signed(ALICE),
+				ACA,
+				DOT,
+				initial_amount,
+				Price::saturating_from_rational(price_n, price_d)
+			));
+
+			let pool = XYK::get_pair_id(AssetPair {
+				asset_in: ACA,
+				asset_out: DOT,
+			});
+			let share_token = XYK::share_token(&pool);
+
+			assert_invariants(&pool, share_token);
+
+			for operation in operations.iter() {
+				let is_trade = matches!(operation, Operation::Sell(..) | Operation::Buy(..));
+				let before = reserves(&pool);
+
+				let executed = apply(&pool, share_token, operation);
+
+				// Trade fees stay in the pool, so the product of reserves can only grow.
+				if executed && is_trade {
+					assert!(
+						product(reserves(&pool)) >= product(before),
+						"Reserves product decreased by {:?}",
+						operation
+					);
+				}
+
+				assert_invariants(&pool, share_token);
+			}
+		});
+	}
+}
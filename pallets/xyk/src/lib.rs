@@ -28,17 +28,38 @@
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
+use codec::Encode;
 use frame_support::sp_runtime::{
-	traits::{Hash, Zero},
-	DispatchError,
+	helpers_128bit::multiply_by_rational,
+	traits::{AccountIdConversion, Dispatchable, Hash, Saturating, Zero},
+	DispatchError, Permill,
 };
-use frame_support::{dispatch::DispatchResult, ensure, traits::Get, transactional};
-use frame_system::ensure_signed;
-use primitives::{asset::AssetPair, fee, traits::AMM, AssetId, Balance, Price, MAX_IN_RATIO, MAX_OUT_RATIO};
-use sp_std::{marker::PhantomData, vec, vec::Vec};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::{
+		schedule::{DispatchTime, Named as ScheduleNamed, LOWEST_PRIORITY},
+		Get,
+	},
+	transactional,
+	weights::GetDispatchInfo,
+	PalletId,
+};
+use frame_system::{ensure_root, ensure_signed};
+use primitives::{
+	asset::{AssetPair, OrderedAssetPair},
+	audit::PrivilegedAction,
+	fee,
+	traits::{
+		AMMHandlers, AuditLog, DustRemovalAccountWhitelist, FeeDiscount, FlashLoanReceiver, LiquidityChange,
+		LiquidityChangeKind, TradeFees, TradeSource, AMM,
+	},
+	units::TypedPrice,
+	AssetId, Balance, IntentionType, PoolState, Price, MAX_IN_RATIO, MAX_OUT_RATIO,
+};
+use sp_std::{boxed::Box, marker::PhantomData, vec, vec::Vec};
 
 use frame_support::sp_runtime::app_crypto::sp_core::crypto::UncheckedFrom;
-use frame_support::sp_runtime::FixedPointNumber;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::fee::WithFee;
 use primitives::traits::AMMTransfer;
@@ -50,8 +71,13 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(test)]
+mod invariant_tests;
+
 mod benchmarking;
 
+pub mod math;
+mod migration;
 pub mod weights;
 
 use weights::WeightInfo;
@@ -59,6 +85,20 @@ use weights::WeightInfo;
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
+/// Prefix of scheduler task id used to expire pool owner.
+const POOL_OWNER_EXPIRY_ID: &[u8] = b"xyk/pool_owner";
+
+/// Amount of shares permanently locked on pool creation.
+///
+/// Locked shares make the share price manipulation of tiny pools prohibitively expensive.
+pub const MINIMUM_LIQUIDITY: Balance = 1_000;
+
+/// Id of the account holding locked shares of all pools.
+const LOCKED_LIQUIDITY_ID: PalletId = PalletId(*b"xyk/lock");
+
+/// Maximum number of intermediate assets of a route searched by `find_best_route`.
+pub const MAX_ROUTE_INTERMEDIATE_ASSETS: usize = 2;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -69,7 +109,25 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_runtime_upgrade() -> frame_support::weights::Weight {
+			migration::populate_pool_weights::<T>()
+				.saturating_add(migration::lock_minimum_liquidity::<T>())
+				.saturating_add(migration::order_pool_assets::<T>())
+				.saturating_add(migration::whitelist_pool_accounts::<T>())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			migration::pre_order_pool_assets::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			migration::post_order_pool_assets::<T>()?;
+			Self::do_try_state()
+		}
+	}
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_asset_registry::Config {
@@ -88,9 +146,63 @@ pub mod pallet {
 		/// Weight information for the extrinsics.
 		type WeightInfo: WeightInfo;
 
+		/// Minimum amount of shares issued on pool creation, must be greater than `MINIMUM_LIQUIDITY`
+		#[pallet::constant]
+		type MinPoolLiquidity: Get<Balance>;
+
 		/// Trading fee rate
 		#[pallet::constant]
 		type GetExchangeFee: Get<fee::Fee>;
+
+		/// Default share of the trading fee which is transferred to the treasury
+		#[pallet::constant]
+		type DefaultProtocolFee: Get<fee::Fee>;
+
+		/// Account which receives the protocol fee
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Origin which can change the protocol fee
+		type ProtocolFeeOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Number of blocks of owner's inactivity after which control of pool parameters passes to governance
+		#[pallet::constant]
+		type PoolOwnerInactivityPeriod: Get<Self::BlockNumber>;
+
+		/// Maximum reserve of each pool asset for the pool to be considered dust and destroyable by governance
+		#[pallet::constant]
+		type MaxPoolDust: Get<Balance>;
+
+		/// Origin which controls parameters of pools
+		type PoolGovernanceOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Overarching type of all pallets origins
+		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+
+		/// Runtime call type used to schedule pool owner expiry and to use flash loans
+		type Call: Parameter
+			+ Dispatchable<Origin = Self::Origin>
+			+ GetDispatchInfo
+			+ From<Call<Self>>
+			+ From<frame_system::Call<Self>>;
+
+		/// Scheduler used to expire inactive pool owners
+		type Scheduler: ScheduleNamed<Self::BlockNumber, <Self as Config>::Call, Self::PalletsOrigin>;
+
+		/// Log of privileged actions
+		type AuditLog: AuditLog<Self::AccountId>;
+
+		/// Handlers notified about executed trades and liquidity changes
+		type AMMHandler: AMMHandlers<Self::AccountId, AssetId, AssetPair, Balance>;
+
+		/// Fee paid to the pool for a flash loan
+		#[pallet::constant]
+		type FlashLoanFee: Get<fee::Fee>;
+
+		/// Whitelist of accounts which cannot be dusted, pool accounts are added on pool creation
+		type NonDustableWhitelistHandler: DustRemovalAccountWhitelist<Self::AccountId>;
+
+		/// Provider of discounted trade fees by native currency locked by the trader
+		type FeeDiscount: FeeDiscount<Self::AccountId>;
 	}
 
 	#[pallet::error]
@@ -113,11 +225,11 @@ pub mod pallet {
 		/// It is not allowed to add zero liquidity.
 		CannotAddZeroLiquidity,
 
-		/// Overflow
-		InvalidMintedLiquidity, // No tests - but it is currently not possible this error to occur due to previous checks in the code.
+		/// Added liquidity is too small to mint any shares.
+		InvalidMintedLiquidity,
 
 		/// Overflow
-		InvalidLiquidityAmount, // no tests
+		InvalidLiquidityAmount,
 
 		/// Given trading limit has been exceeded (Sell) or has Not been reached (buy).
 		AssetBalanceLimitExceeded,
@@ -126,10 +238,10 @@ pub mod pallet {
 		InsufficientAssetBalance,
 
 		/// Not enough asset liquidity in the pool.
-		InsufficientPoolAssetBalance, // No tests
+		InsufficientPoolAssetBalance,
 
 		/// Not enough core asset liquidity in the pool.
-		InsufficientNativeCurrencyBalance, // No tests
+		InsufficientNativeCurrencyBalance,
 
 		/// Liquidity pool for given assets does not exist.
 		TokenPoolNotFound,
@@ -138,22 +250,68 @@ pub mod pallet {
 		TokenPoolAlreadyExists,
 
 		/// Overflow
-		AddAssetAmountInvalid, // no tests
-		/// Overflow
-		RemoveAssetAmountInvalid, // no tests
+		AddAssetAmountInvalid,
 		/// Overflow
-		SellAssetAmountInvalid, // no tests
+		SellAssetAmountInvalid,
 		/// Overflow
-		BuyAssetAmountInvalid, // no tests
+		BuyAssetAmountInvalid,
 		/// Overflow
-		FeeAmountInvalid, // no tests
+		FeeAmountInvalid,
 		/// Overflow
 		CannotApplyDiscount,
 
 		/// Max fraction of pool to buy in single transaction has been exceeded.
 		MaxOutRatioExceeded,
-		/// Max fraction of pool to sell in single transaction has been exceeded.
+		/// Max fraction of pool to sell (or pay when buying) in single transaction has been exceeded.
 		MaxInRatioExceeded,
+
+		/// Protocol fee must be a fraction of the trading fee not greater than 1.
+		InvalidProtocolFee,
+
+		/// Account does not hold access asset required by the pool.
+		AccessAssetRequired,
+
+		/// Account is not owner of the pool.
+		NotPoolOwner,
+
+		/// Pool does not have an owner.
+		PoolOwnerNotFound,
+
+		/// Pool owner has been active within the inactivity period.
+		PoolOwnerNotExpired,
+
+		/// Scheduling of pool owner expiry failed.
+		CannotScheduleOwnerExpiry, // No tests - it fails only with zero PoolOwnerInactivityPeriod.
+
+		/// Pool reserves exceed the dust limit.
+		PoolNotDust,
+
+		/// Pool is quarantined because reserve of one of its assets is depleted.
+		PoolQuarantined,
+
+		/// Pool is not quarantined.
+		PoolNotQuarantined,
+
+		/// Pool asset weight is out of allowed range.
+		InvalidPoolWeights,
+
+		/// Initial liquidity of the pool is below the minimum.
+		InsufficientLiquidity,
+
+		/// It is not allowed to borrow zero amount.
+		CannotFlashLoanZero,
+
+		/// Reserves of the pool are lent by a flash loan.
+		FlashLoanInProgress,
+
+		/// Borrowed amount plus fee was not returned to the pool.
+		FlashLoanNotRepaid,
+
+		/// Trading and adding liquidity are paused in the pool.
+		PoolTradingPaused,
+
+		/// Pool is frozen, no operations are allowed.
+		PoolFrozen,
 	}
 
 	#[pallet::event]
@@ -168,14 +326,69 @@ pub mod pallet {
 		/// Pool was created. [who, asset a, asset b, initial shares amount]
 		PoolCreated(T::AccountId, AssetId, AssetId, Balance),
 
-		/// Pool was destroyed. [who, asset a, asset b]
+		/// Pool was destroyed. Residual reserves backing locked shares were transferred to treasury. [who, asset a, asset b]
 		PoolDestroyed(T::AccountId, AssetId, AssetId),
 
-		/// Asset sale executed. [who, asset in, asset out, amount, sale price]
-		SellExecuted(T::AccountId, AssetId, AssetId, Balance, Balance),
+		/// Asset sale executed. [who, asset in, asset out, amount, sale price, source, fees]
+		SellExecuted(
+			T::AccountId,
+			AssetId,
+			AssetId,
+			Balance,
+			Balance,
+			TradeSource,
+			TradeFees<Balance>,
+		),
+
+		/// Asset purchase executed. [who, asset out, asset in, amount, buy price, source, fees]
+		BuyExecuted(
+			T::AccountId,
+			AssetId,
+			AssetId,
+			Balance,
+			Balance,
+			TradeSource,
+			TradeFees<Balance>,
+		),
+
+		/// Protocol fee was changed. [protocol fee]
+		ProtocolFeeUpdated(fee::Fee),
+
+		/// Pool was restricted to holders of access asset. [pool, access asset]
+		PoolAccessAssetSet(T::AccountId, AssetId),
+
+		/// Access restriction was removed from the pool. [pool]
+		PoolAccessAssetRemoved(T::AccountId),
+
+		/// Pool ownership was renewed. [pool, owner, deadline]
+		PoolOwnershipRenewed(T::AccountId, T::AccountId, T::BlockNumber),
+
+		/// Pool owner was inactive and control passed to governance. [pool, owner]
+		PoolOwnerExpired(T::AccountId, T::AccountId),
+
+		/// Dust pool was destroyed and its reserves transferred to treasury. [pool, asset a, asset b, amount a, amount b]
+		DustPoolDestroyed(T::AccountId, AssetId, AssetId, Balance, Balance),
+
+		/// Reserve of one of the pool assets is depleted, trading in the pool is blocked. [pool, asset a, asset b]
+		PoolQuarantined(T::AccountId, AssetId, AssetId),
+
+		/// Reserves of both pool assets are available again and quarantine was lifted. [pool]
+		PoolQuarantineLifted(T::AccountId),
+
+		/// Quarantined pool was destroyed and its reserves transferred to treasury. [pool, asset a, asset b, amount a, amount b]
+		QuarantinedPoolDestroyed(T::AccountId, AssetId, AssetId, Balance, Balance),
+
+		/// Weighted pool was created. [pool, asset a, asset b, weight a, weight b]
+		PoolWeightsSet(T::AccountId, AssetId, AssetId, u32, u32),
+
+		/// Flash loan was borrowed and repaid. [who, pool, asset, amount, fee]
+		FlashLoanExecuted(T::AccountId, T::AccountId, AssetId, Balance, Balance),
 
-		/// Asset purchase executed. [who, asset out, asset in, amount, buy price]
-		BuyExecuted(T::AccountId, AssetId, AssetId, Balance, Balance),
+		/// State of the pool was changed. [pool, state]
+		PoolStateChanged(T::AccountId, PoolState),
+
+		/// Pool was moved to the pool of migrated asset. [old pool, new pool, old asset, new asset, other asset]
+		PoolAssetMigrated(T::AccountId, T::AccountId, AssetId, AssetId, AssetId),
 	}
 
 	/// Asset id storage for shared pool tokens
@@ -188,10 +401,77 @@ pub mod pallet {
 	#[pallet::getter(fn total_liquidity)]
 	pub type TotalLiquidity<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
 
-	/// Asset pair in a pool.
+	/// Asset pair in a pool, ordered by asset id.
 	#[pallet::storage]
 	#[pallet::getter(fn pool_assets)]
-	pub type PoolAssets<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (AssetId, AssetId), ValueQuery>;
+	pub type PoolAssets<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, OrderedAssetPair, ValueQuery>;
+
+	/// Asset which is required to trade or provide liquidity in a permissioned pool.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_access_asset)]
+	pub type PoolAccessAsset<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, AssetId, OptionQuery>;
+
+	/// Owner of a pool with owner-settable parameters and block until which the owner must be active.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_owner)]
+	pub type PoolOwner<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (T::AccountId, T::BlockNumber), OptionQuery>;
+
+	/// Pools with depleted reserve of one of the assets and block in which they were quarantined.
+	/// It is not allowed to trade or add liquidity in a quarantined pool, liquidity can be removed.
+	#[pallet::storage]
+	#[pallet::getter(fn quarantined_pool)]
+	pub type QuarantinedPools<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber, OptionQuery>;
+
+	/// State of pools which are paused or frozen. Pools without an entry are active.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_state)]
+	pub type PoolStates<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, PoolState, ValueQuery>;
+
+	/// Weights of pool assets in the order of pool assets. Pools without weights have equal weights.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_weights)]
+	pub type PoolWeights<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (u32, u32), OptionQuery>;
+
+	/// Pool whose reserves are lent by the flash loan in progress.
+	#[pallet::storage]
+	#[pallet::getter(fn flash_loan_pool)]
+	pub type FlashLoanPool<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+	#[pallet::type_value]
+	pub fn DefaultProtocolFee<T: Config>() -> fee::Fee {
+		T::DefaultProtocolFee::get()
+	}
+
+	/// Share of the trading fee which is transferred to the treasury.
+	#[pallet::storage]
+	#[pallet::getter(fn protocol_fee)]
+	pub type ProtocolFee<T: Config> = StorageValue<_, fee::Fee, ValueQuery, DefaultProtocolFee<T>>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Pools created at genesis. [creator, asset a, asset b, amount of asset a, initial price]
+		pub pools: Vec<(T::AccountId, AssetId, AssetId, Balance, Price)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			GenesisConfig { pools: vec![] }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			self.pools
+				.iter()
+				.for_each(|(who, asset_a, asset_b, amount, initial_price)| {
+					Pallet::<T>::do_create_pool(who.clone(), *asset_a, *asset_b, *amount, *initial_price)
+						.expect("Failed to create pool at genesis")
+				})
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -215,54 +495,197 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			ensure!(!amount.is_zero(), Error::<T>::CannotCreatePoolWithZeroLiquidity);
-			ensure!(
-				!(initial_price == Price::zero()),
-				Error::<T>::CannotCreatePoolWithZeroInitialPrice
-			);
+			Self::do_create_pool(who, asset_a, asset_b, amount, initial_price)?;
+
+			Ok(().into())
+		}
+
+		/// Create new pool for given asset pair with explicit amounts of both assets.
+		///
+		/// Pool is created the same way as by `create_pool`, but initial liquidity is given by `amount_a` of `asset_a`
+		/// and `amount_b` of `asset_b` instead of amount of `asset_a` and initial price.
+		/// Initial spot price of `asset_a` in `asset_b` is `amount_b / amount_a`.
+		///
+		/// Emits `PoolCreated` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool_with_amounts())]
+		#[transactional]
+		pub fn create_pool_with_amounts(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			amount_a: Balance,
+			amount_b: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::do_create_pool_with_amounts(who, asset_a, asset_b, amount_a, amount_b)?;
+
+			Ok(().into())
+		}
+
+		/// Create new permissioned pool for given asset pair.
+		///
+		/// Pool is created the same way as by `create_pool`, but only holders of `access_asset`
+		/// are allowed to trade in the pool or add liquidity to it. Removing liquidity is always allowed.
+		///
+		/// `origin` becomes owner of the pool. If owner is inactive for `PoolOwnerInactivityPeriod`,
+		/// control of pool parameters passes to governance.
+		///
+		/// Emits `PoolCreated`, `PoolAccessAssetSet` and `PoolOwnershipRenewed` events when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::create_permissioned_pool())]
+		#[transactional]
+		pub fn create_permissioned_pool(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			amount: Balance,
+			initial_price: Price,
+			access_asset: AssetId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin.clone())?;
+
+			Self::create_pool(origin, asset_a, asset_b, amount, initial_price)?;
+
+			let pair_account = Self::get_pair_id(AssetPair {
+				asset_in: asset_a,
+				asset_out: asset_b,
+			});
+
+			Self::set_access_asset(&pair_account, Some(access_asset));
+
+			Self::renew_ownership(&pair_account, who)?;
+
+			Ok(().into())
+		}
+
+		/// Create new weighted pool for given asset pair.
+		///
+		/// Pool is created the same way as by `create_pool`, but trades are calculated with Balancer-style invariant
+		/// with `weight_a` percent of the pool value in `asset_a` and the rest in `asset_b`.
+		///
+		/// `initial_price` is spot price of `asset_a` in `asset_b`, amount of `asset_b` is adjusted by the weights.
+		///
+		/// Emits `PoolCreated` and `PoolWeightsSet` events when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::create_weighted_pool())]
+		#[transactional]
+		pub fn create_weighted_pool(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			amount: Balance,
+			initial_price: Price,
+			weight_a: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(math::is_valid_pool_weight(weight_a), Error::<T>::InvalidPoolWeights);
+
+			let weights = (weight_a, math::POOL_WEIGHT_TOTAL - weight_a);
 
-			ensure!(asset_a != asset_b, Error::<T>::CannotCreatePoolWithSameAssets);
+			Self::do_create_weighted_pool(who, asset_a, asset_b, amount, initial_price, weights)?;
 
+			let pair_account = Self::get_pair_id(AssetPair {
+				asset_in: asset_a,
+				asset_out: asset_b,
+			});
+
+			Self::deposit_event(Event::PoolWeightsSet(
+				pair_account,
+				asset_a,
+				asset_b,
+				weights.0,
+				weights.1,
+			));
+
+			Ok(().into())
+		}
+
+		/// Set or remove access asset of the pool.
+		///
+		/// Can be called by the pool owner, which also renews the ownership, or by `PoolGovernanceOrigin`.
+		///
+		/// Emits `PoolAccessAssetSet` or `PoolAccessAssetRemoved` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::set_pool_access_asset())]
+		#[transactional]
+		pub fn set_pool_access_asset(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			access_asset: Option<AssetId>,
+		) -> DispatchResultWithPostInfo {
 			let asset_pair = AssetPair {
 				asset_in: asset_a,
 				asset_out: asset_b,
 			};
 
-			ensure!(!Self::exists(asset_pair), Error::<T>::TokenPoolAlreadyExists);
+			ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
 
-			let asset_b_amount = initial_price
-				.checked_mul_int(amount)
-				.ok_or(Error::<T>::CreatePoolAssetAmountInvalid)?;
+			let pair_account = Self::get_pair_id(asset_pair);
 
-			let shares_added = if asset_a < asset_b { amount } else { asset_b_amount };
+			let proposer = match T::PoolGovernanceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => {
+					let who = ensure_signed(origin)?;
+					Self::ensure_pool_owner(&pair_account, &who)?;
+					Self::renew_ownership(&pair_account, who.clone())?;
+					Some(who)
+				}
+			};
 
-			ensure!(
-				T::Currency::free_balance(asset_a, &who) >= amount,
-				Error::<T>::InsufficientAssetBalance
-			);
+			Self::set_access_asset(&pair_account, access_asset);
 
-			ensure!(
-				T::Currency::free_balance(asset_b, &who) >= asset_b_amount,
-				Error::<T>::InsufficientAssetBalance
+			T::AuditLog::record(
+				proposer,
+				PrivilegedAction::PoolAccessAssetSet(asset_a, asset_b, access_asset),
 			);
 
-			let pair_account = Self::get_pair_id(asset_pair);
+			Ok(().into())
+		}
+
+		/// Renew ownership of the pool without changing its parameters.
+		///
+		/// Pool owner must be active at least once per `PoolOwnerInactivityPeriod`.
+		///
+		/// Emits `PoolOwnershipRenewed` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::renew_pool_ownership())]
+		#[transactional]
+		pub fn renew_pool_ownership(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let pair_account = Self::get_pair_id(AssetPair {
+				asset_in: asset_a,
+				asset_out: asset_b,
+			});
 
-			let token_name = asset_pair.name();
+			Self::ensure_pool_owner(&pair_account, &who)?;
+			Self::renew_ownership(&pair_account, who)?;
 
-			let share_token = <pallet_asset_registry::Pallet<T>>::get_or_create_asset(token_name)?.into();
+			Ok(().into())
+		}
 
-			<ShareToken<T>>::insert(&pair_account, &share_token);
-			<PoolAssets<T>>::insert(&pair_account, (asset_a, asset_b));
+		/// Remove owner of the pool who has been inactive past the deadline.
+		///
+		/// Scheduled automatically when ownership is renewed. Control of pool parameters passes to governance.
+		///
+		/// Emits `PoolOwnerExpired` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::expire_pool_owner())]
+		pub fn expire_pool_owner(origin: OriginFor<T>, pool: T::AccountId) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
 
-			T::Currency::transfer(asset_a, &who, &pair_account, amount)?;
-			T::Currency::transfer(asset_b, &who, &pair_account, asset_b_amount)?;
+			let (owner, deadline) = Self::pool_owner(&pool).ok_or(Error::<T>::PoolOwnerNotFound)?;
 
-			T::Currency::deposit(share_token, &who, shares_added)?;
+			ensure!(
+				deadline <= <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::PoolOwnerNotExpired
+			);
 
-			<TotalLiquidity<T>>::insert(&pair_account, shares_added);
+			<PoolOwner<T>>::remove(&pool);
 
-			Self::deposit_event(Event::PoolCreated(who, asset_a, asset_b, shares_added));
+			Self::deposit_event(Event::PoolOwnerExpired(pool, owner));
 
 			Ok(().into())
 		}
@@ -290,6 +713,23 @@ pub mod pallet {
 
 			ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
 
+			ensure!(
+				!Self::is_quarantined(&Self::get_pair_id(asset_pair)),
+				Error::<T>::PoolQuarantined
+			);
+
+			Self::ensure_pool_active(&Self::get_pair_id(asset_pair))?;
+
+			ensure!(
+				!Self::is_flash_loan_active(&Self::get_pair_id(asset_pair)),
+				Error::<T>::FlashLoanInProgress
+			);
+
+			ensure!(
+				Self::has_pool_access(&Self::get_pair_id(asset_pair), &who),
+				Error::<T>::AccessAssetRequired
+			);
+
 			ensure!(!amount_a.is_zero(), Error::<T>::CannotAddZeroLiquidity);
 
 			ensure!(!amount_b_max_limit.is_zero(), Error::<T>::CannotAddZeroLiquidity);
@@ -315,7 +755,7 @@ pub mod pallet {
 			let amount_b_required = hydra_dx_math::calculate_liquidity_in(asset_a_reserve, asset_b_reserve, amount_a)
 				.map_err(|_| Error::<T>::AddAssetAmountInvalid)?;
 
-			let shares_added = if asset_a < asset_b { amount_a } else { amount_b_required };
+			let shares_added = Self::liquidity_shares(asset_a, asset_b, amount_a, amount_b_required);
 
 			ensure!(
 				amount_b_required <= amount_b_max_limit,
@@ -342,6 +782,19 @@ pub mod pallet {
 
 			<TotalLiquidity<T>>::insert(&pair_account, liquidity_amount);
 
+			T::AMMHandler::on_liquidity_changed(
+				&pair_account,
+				&LiquidityChange {
+					who: who.clone(),
+					kind: LiquidityChangeKind::Added,
+					asset_a,
+					asset_b,
+					amount_a,
+					amount_b: amount_b_required,
+					shares: shares_added,
+				},
+			);
+
 			Self::deposit_event(Event::LiquidityAdded(
 				who,
 				asset_a,
@@ -355,7 +808,10 @@ pub mod pallet {
 
 		/// Remove liquidity from specific liquidity pool in the form of burning shares.
 		///
-		/// If liquidity in the pool reaches 0, it is destroyed.
+		/// `min_amount_a` / `min_amount_b` - minimum amounts of `asset_a` / `asset_b` to be received for burned shares.
+		///
+		/// If only locked liquidity remains in the pool, it is destroyed. Locked shares are burned and residual
+		/// reserves are transferred to the treasury.
 		///
 		/// Emits 'LiquidityRemoved' when successful.
 		/// Emits 'PoolDestroyed' when pool is destroyed.
@@ -366,6 +822,8 @@ pub mod pallet {
 			asset_a: AssetId,
 			asset_b: AssetId,
 			liquidity_amount: Balance,
+			min_amount_a: Balance,
+			min_amount_b: Balance,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
@@ -380,45 +838,37 @@ pub mod pallet {
 
 			let pair_account = Self::get_pair_id(asset_pair);
 
+			ensure!(
+				!Self::is_flash_loan_active(&pair_account),
+				Error::<T>::FlashLoanInProgress
+			);
+
+			ensure!(
+				Self::pool_state(&pair_account) != PoolState::Frozen,
+				Error::<T>::PoolFrozen
+			);
+
 			let share_token = Self::share_token(&pair_account);
 
 			let total_shares = Self::total_liquidity(&pair_account);
 
-			ensure!(total_shares >= liquidity_amount, Error::<T>::InsufficientAssetBalance);
+			let liquidity_left = total_shares
+				.checked_sub(liquidity_amount)
+				.ok_or(Error::<T>::InsufficientAssetBalance)?;
 
 			ensure!(
 				T::Currency::free_balance(share_token, &who) >= liquidity_amount,
 				Error::<T>::InsufficientAssetBalance
 			);
 
-			ensure!(!total_shares.is_zero(), Error::<T>::CannotRemoveLiquidityWithZero);
-
-			let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
-			let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
-
-			let liquidity_out = hydra_dx_math::calculate_liquidity_out(
-				asset_a_reserve,
-				asset_b_reserve,
-				liquidity_amount,
-				total_shares,
-			)
-			.map_err(|_| Error::<T>::RemoveAssetAmountInvalid)?;
-
-			let (remove_amount_a, remove_amount_b) = liquidity_out;
+			let (remove_amount_a, remove_amount_b) =
+				Self::liquidity_out(&pair_account, asset_pair, liquidity_amount, total_shares);
 
 			ensure!(
-				T::Currency::free_balance(asset_a, &pair_account) >= remove_amount_a,
-				Error::<T>::InsufficientPoolAssetBalance
-			);
-			ensure!(
-				T::Currency::free_balance(asset_b, &pair_account) >= remove_amount_b,
-				Error::<T>::InsufficientPoolAssetBalance
+				remove_amount_a >= min_amount_a && remove_amount_b >= min_amount_b,
+				Error::<T>::AssetBalanceLimitExceeded
 			);
 
-			let liquidity_left = total_shares
-				.checked_sub(liquidity_amount)
-				.ok_or(Error::<T>::InvalidLiquidityAmount)?;
-
 			T::Currency::transfer(asset_a, &pair_account, &who, remove_amount_a)?;
 			T::Currency::transfer(asset_b, &pair_account, &who, remove_amount_b)?;
 
@@ -426,13 +876,34 @@ pub mod pallet {
 
 			<TotalLiquidity<T>>::insert(&pair_account, liquidity_left);
 
+			T::AMMHandler::on_liquidity_changed(
+				&pair_account,
+				&LiquidityChange {
+					who: who.clone(),
+					kind: LiquidityChangeKind::Removed,
+					asset_a,
+					asset_b,
+					amount_a: remove_amount_a,
+					amount_b: remove_amount_b,
+					shares: liquidity_amount,
+				},
+			);
+
 			Self::deposit_event(Event::LiquidityRemoved(who.clone(), asset_a, asset_b, liquidity_amount));
 
-			if liquidity_left == 0 {
-				<ShareToken<T>>::remove(&pair_account);
-				<PoolAssets<T>>::remove(&pair_account);
+			if liquidity_left <= MINIMUM_LIQUIDITY {
+				let treasury = T::TreasuryAccount::get();
+				let residual_a = T::Currency::free_balance(asset_a, &pair_account);
+				let residual_b = T::Currency::free_balance(asset_b, &pair_account);
+
+				T::Currency::transfer(asset_a, &pair_account, &treasury, residual_a)?;
+				T::Currency::transfer(asset_b, &pair_account, &treasury, residual_b)?;
+
+				Self::remove_pool(&pair_account)?;
 
 				Self::deposit_event(Event::PoolDestroyed(who, asset_a, asset_b));
+			} else {
+				Self::quarantine_if_depleted(&pair_account, asset_a, asset_b);
 			}
 
 			Ok(().into())
@@ -445,7 +916,7 @@ pub mod pallet {
 		/// `max_limit` - minimum amount of `asset_out` / amount of asset_out to be obtained from the pool in exchange for `asset_in`.
 		///
 		/// Emits `SellExecuted` when successful.
-		#[pallet::weight(<T as Config>::WeightInfo::sell())]
+		#[pallet::weight(<T as Config>::WeightInfo::sell().saturating_add(T::AMMHandler::on_trade_weight()))]
 		pub fn sell(
 			origin: OriginFor<T>,
 			asset_in: AssetId,
@@ -456,7 +927,16 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			<Self as AMM<_, _, _, _>>::sell(&who, AssetPair { asset_in, asset_out }, amount, max_limit, discount)?;
+			let mut transfer = <Self as AMM<_, _, _, _>>::validate_sell(
+				&who,
+				AssetPair { asset_in, asset_out },
+				amount,
+				max_limit,
+				discount,
+			)?;
+			transfer.source = TradeSource::Direct;
+
+			<Self as AMM<_, _, _, _>>::execute_sell(&transfer)?;
 
 			Ok(().into())
 		}
@@ -468,7 +948,7 @@ pub mod pallet {
 		/// `max_limit` - maximum amount of `asset_in` to be sold in exchange for `asset_out`.
 		///
 		/// Emits `BuyExecuted` when successful.
-		#[pallet::weight(<T as Config>::WeightInfo::buy())]
+		#[pallet::weight(<T as Config>::WeightInfo::buy().saturating_add(T::AMMHandler::on_trade_weight()))]
 		pub fn buy(
 			origin: OriginFor<T>,
 			asset_out: AssetId,
@@ -479,50 +959,1028 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			<Self as AMM<_, _, _, _>>::buy(&who, AssetPair { asset_in, asset_out }, amount, max_limit, discount)?;
+			let mut transfer = <Self as AMM<_, _, _, _>>::validate_buy(
+				&who,
+				AssetPair { asset_in, asset_out },
+				amount,
+				max_limit,
+				discount,
+			)?;
+			transfer.source = TradeSource::Direct;
+
+			<Self as AMM<_, _, _, _>>::execute_buy(&transfer)?;
 
 			Ok(().into())
 		}
-	}
-}
 
-pub trait AssetPairAccountIdFor<AssetId: Sized, AccountId: Sized> {
-	fn from_assets(asset_a: AssetId, asset_b: AssetId) -> AccountId;
-}
+		/// Destroy pool whose liquidity drained to dust.
+		///
+		/// Can be called only by `PoolGovernanceOrigin`. Reserves of both pool assets must not exceed `MaxPoolDust`.
+		///
+		/// Residual reserves are transferred to the treasury and all pool entries are removed.
+		/// Outstanding shares of the pool are no longer redeemable.
+		///
+		/// Emits `DustPoolDestroyed` when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::destroy_pool())]
+		#[transactional]
+		pub fn destroy_pool(origin: OriginFor<T>, asset_a: AssetId, asset_b: AssetId) -> DispatchResultWithPostInfo {
+			T::PoolGovernanceOrigin::ensure_origin(origin)?;
 
-pub struct AssetPairAccountId<T: Config>(PhantomData<T>);
+			let asset_pair = AssetPair {
+				asset_in: asset_a,
+				asset_out: asset_b,
+			};
 
-impl<T: Config> AssetPairAccountIdFor<AssetId, T::AccountId> for AssetPairAccountId<T>
-where
-	T::AccountId: UncheckedFrom<T::Hash> + AsRef<[u8]>,
-{
-	fn from_assets(asset_a: AssetId, asset_b: AssetId) -> T::AccountId {
-		let mut buf = Vec::new();
-		buf.extend_from_slice(b"hydradx");
-		if asset_a < asset_b {
-			buf.extend_from_slice(&asset_a.to_le_bytes());
-			buf.extend_from_slice(&asset_b.to_le_bytes());
-		} else {
-			buf.extend_from_slice(&asset_b.to_le_bytes());
-			buf.extend_from_slice(&asset_a.to_le_bytes());
+			ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
+
+			let pair_account = Self::get_pair_id(asset_pair);
+
+			let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
+			let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
+
+			ensure!(
+				asset_a_reserve <= T::MaxPoolDust::get() && asset_b_reserve <= T::MaxPoolDust::get(),
+				Error::<T>::PoolNotDust
+			);
+
+			let treasury = T::TreasuryAccount::get();
+
+			T::Currency::transfer(asset_a, &pair_account, &treasury, asset_a_reserve)?;
+			T::Currency::transfer(asset_b, &pair_account, &treasury, asset_b_reserve)?;
+
+			Self::remove_pool(&pair_account)?;
+
+			Self::deposit_event(Event::DustPoolDestroyed(
+				pair_account,
+				asset_a,
+				asset_b,
+				asset_a_reserve,
+				asset_b_reserve,
+			));
+
+			T::AuditLog::record(None, PrivilegedAction::PoolDestroyed(asset_a, asset_b));
+
+			Ok(().into())
+		}
+
+		/// Resolve quarantined pool.
+		///
+		/// Can be called only by `PoolGovernanceOrigin`.
+		///
+		/// If reserves of both pool assets are available again, quarantine is lifted. Otherwise the pool is destroyed -
+		/// remaining reserves are transferred to the treasury and all pool entries are removed.
+		///
+		/// Emits `PoolQuarantineLifted` or `QuarantinedPoolDestroyed` when successful.
+		#[pallet::weight((<T as Config>::WeightInfo::resolve_quarantined_pool(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn resolve_quarantined_pool(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+		) -> DispatchResultWithPostInfo {
+			T::PoolGovernanceOrigin::ensure_origin(origin)?;
+
+			let asset_pair = AssetPair {
+				asset_in: asset_a,
+				asset_out: asset_b,
+			};
+
+			ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
+
+			let pair_account = Self::get_pair_id(asset_pair);
+
+			ensure!(Self::is_quarantined(&pair_account), Error::<T>::PoolNotQuarantined);
+
+			let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
+			let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
+
+			if !asset_a_reserve.is_zero() && !asset_b_reserve.is_zero() {
+				<QuarantinedPools<T>>::remove(&pair_account);
+
+				Self::deposit_event(Event::PoolQuarantineLifted(pair_account));
+
+				return Ok(().into());
+			}
+
+			let treasury = T::TreasuryAccount::get();
+
+			T::Currency::transfer(asset_a, &pair_account, &treasury, asset_a_reserve)?;
+			T::Currency::transfer(asset_b, &pair_account, &treasury, asset_b_reserve)?;
+
+			Self::remove_pool(&pair_account)?;
+
+			Self::deposit_event(Event::QuarantinedPoolDestroyed(
+				pair_account,
+				asset_a,
+				asset_b,
+				asset_a_reserve,
+				asset_b_reserve,
+			));
+
+			T::AuditLog::record(None, PrivilegedAction::PoolDestroyed(asset_a, asset_b));
+
+			Ok(().into())
+		}
+
+		/// Set state of the pool.
+		///
+		/// Can be called only by `PoolGovernanceOrigin`.
+		///
+		/// It is not allowed to trade, flash loan or add liquidity in `TradingPaused` pool, liquidity can be removed.
+		/// No user operations are allowed in `Frozen` pool. Governance can destroy dust or quarantined pool in any state.
+		///
+		/// Emits `PoolStateChanged` when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::set_pool_state())]
+		pub fn set_pool_state(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			state: PoolState,
+		) -> DispatchResultWithPostInfo {
+			T::PoolGovernanceOrigin::ensure_origin(origin)?;
+
+			let asset_pair = AssetPair {
+				asset_in: asset_a,
+				asset_out: asset_b,
+			};
+
+			ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
+
+			let pair_account = Self::get_pair_id(asset_pair);
+
+			if state == PoolState::Active {
+				<PoolStates<T>>::remove(&pair_account);
+			} else {
+				<PoolStates<T>>::insert(&pair_account, state);
+			}
+
+			Self::deposit_event(Event::PoolStateChanged(pair_account, state));
+
+			T::AuditLog::record(None, PrivilegedAction::PoolStateSet(asset_a, asset_b, state));
+
+			Ok(().into())
+		}
+
+		/// Set share of the trading fee which is transferred to the treasury.
+		///
+		/// Remaining part of the trading fee stays in the pool and accrues to liquidity providers.
+		///
+		/// Emits `ProtocolFeeUpdated` when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::set_protocol_fee())]
+		pub fn set_protocol_fee(origin: OriginFor<T>, protocol_fee: fee::Fee) -> DispatchResultWithPostInfo {
+			T::ProtocolFeeOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				protocol_fee.denominator != 0 && protocol_fee.numerator <= protocol_fee.denominator,
+				Error::<T>::InvalidProtocolFee
+			);
+
+			<ProtocolFee<T>>::put(protocol_fee);
+
+			Self::deposit_event(Event::ProtocolFeeUpdated(protocol_fee));
+
+			T::AuditLog::record(None, PrivilegedAction::ProtocolFeeSet(protocol_fee));
+
+			Ok(().into())
+		}
+
+		/// Borrow `amount` of `asset` from the pool of `asset_a` and `asset_b` for the duration of `call`.
+		///
+		/// Borrowed amount is transferred to the origin and `call` is dispatched with the same origin.
+		/// Borrowed amount plus `FlashLoanFee` is transferred back to the pool afterwards. The whole transaction
+		/// is reverted if the origin cannot repay it. The pool cannot be traded or its liquidity changed
+		/// until the loan is repaid.
+		///
+		/// Emits `FlashLoanExecuted` when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::flash_loan().saturating_add(call.get_dispatch_info().weight))]
+		#[transactional]
+		pub fn flash_loan(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			asset: AssetId,
+			amount: Balance,
+			call: Box<<T as Config>::Call>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let assets = AssetPair {
+				asset_in: asset_a,
+				asset_out: asset_b,
+			};
+
+			Self::execute_flash_loan(&who, assets, asset, amount, |_| {
+				(*call)
+					.dispatch(frame_system::RawOrigin::Signed(who.clone()).into())
+					.map(|_| ())
+					.map_err(|e| e.error)
+			})?;
+
+			Ok(().into())
+		}
+	}
+}
+
+pub trait AssetPairAccountIdFor<AssetId: Sized, AccountId: Sized> {
+	fn from_assets(asset_a: AssetId, asset_b: AssetId) -> AccountId;
+}
+
+pub struct AssetPairAccountId<T: Config>(PhantomData<T>);
+
+impl<T: Config> AssetPairAccountIdFor<AssetId, T::AccountId> for AssetPairAccountId<T>
+where
+	T::AccountId: UncheckedFrom<T::Hash> + AsRef<[u8]>,
+{
+	fn from_assets(asset_a: AssetId, asset_b: AssetId) -> T::AccountId {
+		let mut buf = Vec::new();
+		let assets = OrderedAssetPair::new(asset_a, asset_b);
+		buf.extend_from_slice(b"hydradx");
+		buf.extend_from_slice(&assets.asset_a.to_le_bytes());
+		buf.extend_from_slice(&assets.asset_b.to_le_bytes());
+		T::AccountId::unchecked_from(T::Hashing::hash(&buf[..]))
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Check invariants of all pools.
+	///
+	/// - share token issuance covers total liquidity of the pool
+	/// - pool which is not quarantined has non-zero reserves of both assets
+	/// - weights of weighted pool sum up to `POOL_WEIGHT_TOTAL`
+	#[cfg(feature = "try-runtime")]
+	pub fn do_try_state() -> Result<(), &'static str> {
+		for (pool, assets) in <PoolAssets<T>>::iter() {
+			let share_token = Self::share_token(&pool);
+			ensure!(
+				T::Currency::total_issuance(share_token) >= Self::total_liquidity(&pool),
+				"Share token issuance does not cover total liquidity"
+			);
+
+			if !Self::is_quarantined(&pool) {
+				let reserve_a = T::Currency::free_balance(assets.asset_a, &pool);
+				let reserve_b = T::Currency::free_balance(assets.asset_b, &pool);
+				ensure!(
+					!reserve_a.is_zero() && !reserve_b.is_zero(),
+					"Reserve product of active pool is zero"
+				);
+			}
+
+			if let Some((weight_a, weight_b)) = Self::pool_weights(&pool) {
+				ensure!(
+					weight_a.checked_add(weight_b) == Some(math::POOL_WEIGHT_TOTAL),
+					"Pool weights do not sum up to total weight"
+				);
+			}
+		}
+
+		ensure!(Self::flash_loan_pool().is_none(), "Flash loan was not finished");
+
+		Ok(())
+	}
+
+	/// Return balance of each asset in selected liquidity pool.
+	pub fn get_pool_balances(pool_address: T::AccountId) -> Option<Vec<(AssetId, Balance)>> {
+		let mut balances = Vec::new();
+
+		if let Some(assets) = Self::get_pool_assets(&pool_address) {
+			for item in &assets {
+				let reserve = T::Currency::free_balance(*item, &pool_address);
+				balances.push((*item, reserve));
+			}
+		}
+		Some(balances)
+	}
+
+	/// Return storage keys of selected liquidity pool state kept by this pallet
+	/// ( share token, total liquidity, pool assets and access asset ).
+	///
+	/// Reserve balance keys depend on the currency implementation and are not included.
+	pub fn get_pool_storage_keys(pool_address: &T::AccountId) -> Vec<Vec<u8>> {
+		vec![
+			<ShareToken<T>>::hashed_key_for(pool_address),
+			<TotalLiquidity<T>>::hashed_key_for(pool_address),
+			<PoolAssets<T>>::hashed_key_for(pool_address),
+			<PoolAccessAsset<T>>::hashed_key_for(pool_address),
+		]
+	}
+
+	/// Return true if account is allowed to trade or provide liquidity in the pool.
+	///
+	/// Permissionless pools are accessible by anyone, permissioned pools only by holders of access asset.
+	pub fn has_pool_access(pool_address: &T::AccountId, who: &T::AccountId) -> bool {
+		match Self::pool_access_asset(pool_address) {
+			Some(access_asset) => !T::Currency::free_balance(access_asset, who).is_zero(),
+			None => true,
+		}
+	}
+
+	/// Return amount of `asset` which `who` owns in all pools via pool shares.
+	///
+	/// Shares of each pool containing `asset` are decomposed to pool reserves the same way as when removing liquidity.
+	///
+	/// Complexity: `O(P)` where `P` is number of pools, all pools are read.
+	pub fn pooled_asset_balance(who: &T::AccountId, asset: AssetId) -> Balance {
+		<PoolAssets<T>>::iter()
+			.filter(|(_, assets)| assets.contains(asset))
+			.fold(Balance::zero(), |acc, (pool, OrderedAssetPair { asset_a, asset_b })| {
+				let shares = T::Currency::total_balance(Self::share_token(&pool), who);
+				let total_shares = Self::total_liquidity(&pool);
+
+				if shares.is_zero() || total_shares.is_zero() {
+					return acc;
+				}
+
+				let (amount_a, amount_b) = math::calculate_liquidity_out(
+					T::Currency::free_balance(asset_a, &pool),
+					T::Currency::free_balance(asset_b, &pool),
+					shares,
+					total_shares,
+				);
+
+				if asset_a == asset {
+					acc.saturating_add(amount_a)
+				} else {
+					acc.saturating_add(amount_b)
+				}
+			})
+	}
+
+	/// Create new pool for given asset pair with initial liquidity provided by `who`.
+	pub fn do_create_pool(
+		who: T::AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount: Balance,
+		initial_price: Price,
+	) -> DispatchResult {
+		Self::do_create_weighted_pool(who, asset_a, asset_b, amount, initial_price, math::EQUAL_POOL_WEIGHTS)
+	}
+
+	/// Create new pool for given asset pair with initial liquidity of `amount_a` of asset a and `amount_b` of asset b
+	/// provided by `who`.
+	pub fn do_create_pool_with_amounts(
+		who: T::AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b: Balance,
+	) -> DispatchResult {
+		ensure!(
+			!amount_a.is_zero() && !amount_b.is_zero(),
+			Error::<T>::CannotCreatePoolWithZeroLiquidity
+		);
+
+		Self::do_create_weighted_pool_with_amounts(who, asset_a, asset_b, amount_a, amount_b, math::EQUAL_POOL_WEIGHTS)
+	}
+
+	/// Create new pool for given asset pair with asset `weights` and initial liquidity provided by `who`.
+	///
+	/// Amount of asset b is `initial_price * amount * weight_b / weight_a`, rounded down.
+	pub fn do_create_weighted_pool(
+		who: T::AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount: Balance,
+		initial_price: Price,
+		weights: (u32, u32),
+	) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::CannotCreatePoolWithZeroLiquidity);
+		let initial_price = TypedPrice::from(initial_price);
+		ensure!(
+			!initial_price.is_zero(),
+			Error::<T>::CannotCreatePoolWithZeroInitialPrice
+		);
+
+		let asset_b_amount = initial_price
+			.checked_mul_floor(amount.into())
+			.ok_or(Error::<T>::CreatePoolAssetAmountInvalid)?
+			.to_balance();
+
+		let asset_b_amount = if weights.0 == weights.1 {
+			asset_b_amount
+		} else {
+			multiply_by_rational(asset_b_amount, weights.1 as u128, weights.0 as u128)
+				.map_err(|_| Error::<T>::CreatePoolAssetAmountInvalid)?
+		};
+
+		Self::do_create_weighted_pool_with_amounts(who, asset_a, asset_b, amount, asset_b_amount, weights)
+	}
+
+	/// Create new pool for given asset pair with asset `weights` and initial liquidity of `amount` of asset a
+	/// and `asset_b_amount` of asset b provided by `who`.
+	fn do_create_weighted_pool_with_amounts(
+		who: T::AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount: Balance,
+		asset_b_amount: Balance,
+		weights: (u32, u32),
+	) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::CannotCreatePoolWithZeroLiquidity);
+		ensure!(asset_a != asset_b, Error::<T>::CannotCreatePoolWithSameAssets);
+
+		let asset_pair = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		ensure!(!Self::exists(asset_pair), Error::<T>::TokenPoolAlreadyExists);
+
+		let shares_added = Self::liquidity_shares(asset_a, asset_b, amount, asset_b_amount);
+
+		ensure!(
+			shares_added >= T::MinPoolLiquidity::get().max(MINIMUM_LIQUIDITY.saturating_add(1)),
+			Error::<T>::InsufficientLiquidity
+		);
+
+		ensure!(
+			T::Currency::free_balance(asset_a, &who) >= amount,
+			Error::<T>::InsufficientAssetBalance
+		);
+
+		ensure!(
+			T::Currency::free_balance(asset_b, &who) >= asset_b_amount,
+			Error::<T>::InsufficientAssetBalance
+		);
+
+		let pair_account = Self::get_pair_id(asset_pair);
+
+		let token_name = asset_pair.name();
+
+		let share_token = <pallet_asset_registry::Pallet<T>>::get_or_create_asset(token_name)?.into();
+
+		<ShareToken<T>>::insert(&pair_account, &share_token);
+		// Weights are stored in the order of pool assets
+		let pool_assets = OrderedAssetPair::new(asset_a, asset_b);
+		let pool_weights = pool_assets.order(asset_a, weights);
+
+		<PoolAssets<T>>::insert(&pair_account, pool_assets);
+		<PoolWeights<T>>::insert(&pair_account, pool_weights);
+
+		T::NonDustableWhitelistHandler::add_account(&pair_account)?;
+
+		T::Currency::transfer(asset_a, &who, &pair_account, amount)?;
+		T::Currency::transfer(asset_b, &who, &pair_account, asset_b_amount)?;
+
+		// First `MINIMUM_LIQUIDITY` shares are locked forever, creator receives the rest.
+		T::Currency::deposit(share_token, &Self::locked_liquidity_account(), MINIMUM_LIQUIDITY)?;
+		T::Currency::deposit(share_token, &who, shares_added - MINIMUM_LIQUIDITY)?;
+
+		<TotalLiquidity<T>>::insert(&pair_account, shares_added);
+
+		T::AMMHandler::on_liquidity_changed(
+			&pair_account,
+			&LiquidityChange {
+				who: who.clone(),
+				kind: LiquidityChangeKind::Added,
+				asset_a,
+				asset_b,
+				amount_a: amount,
+				amount_b: asset_b_amount,
+				shares: shares_added,
+			},
+		);
+
+		Self::deposit_event(Event::PoolCreated(who, asset_a, asset_b, shares_added));
+
+		// Initial price may round the amount of asset b down to zero.
+		Self::quarantine_if_depleted(&pair_account, asset_a, asset_b);
+
+		Ok(())
+	}
+
+	/// Account holding shares locked on pool creation.
+	pub fn locked_liquidity_account() -> T::AccountId {
+		LOCKED_LIQUIDITY_ID.into_account()
+	}
+
+	/// Return weight of `asset` and weight of the other asset of the pool.
+	pub fn asset_weights(pool: &T::AccountId, asset: AssetId) -> (u32, u32) {
+		let weights = Self::pool_weights(pool).unwrap_or(math::EQUAL_POOL_WEIGHTS);
+
+		Self::pool_assets(pool).order(asset, weights)
+	}
+
+	/// Return shares issued for `amount_a` of `asset_a` and `amount_b` of `asset_b` added to a pool.
+	///
+	/// Shares are denominated in the pool asset with lower id, so they do not depend on the order of the assets.
+	fn liquidity_shares(asset_a: AssetId, asset_b: AssetId, amount_a: Balance, amount_b: Balance) -> Balance {
+		let (shares, _) = OrderedAssetPair::new(asset_a, asset_b).order(asset_a, (amount_a, amount_b));
+		shares
+	}
+
+	/// Calculate value of `amount` of `asset_a` in `asset_b` by spot price of the pool.
+	fn calculate_spot_price(
+		pool: &T::AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount: Balance,
+	) -> Option<Balance> {
+		let (weight_a, weight_b) = Self::asset_weights(pool, asset_a);
+
+		math::calculate_weighted_spot_price(
+			T::Currency::free_balance(asset_a, pool),
+			weight_a,
+			T::Currency::free_balance(asset_b, pool),
+			weight_b,
+			amount,
+		)
+	}
+
+	/// Return true if the pool is quarantined.
+	pub fn is_quarantined(pool: &T::AccountId) -> bool {
+		<QuarantinedPools<T>>::contains_key(pool)
+	}
+
+	/// Ensure that trading and adding liquidity is allowed in the pool.
+	fn ensure_pool_active(pool: &T::AccountId) -> DispatchResult {
+		match Self::pool_state(pool) {
+			PoolState::Active => Ok(()),
+			PoolState::TradingPaused => Err(Error::<T>::PoolTradingPaused.into()),
+			PoolState::Frozen => Err(Error::<T>::PoolFrozen.into()),
+		}
+	}
+
+	/// Quarantine the pool if reserve of one of its assets is depleted.
+	fn quarantine_if_depleted(pool: &T::AccountId, asset_a: AssetId, asset_b: AssetId) {
+		if Self::is_quarantined(pool) {
+			return;
+		}
+
+		if T::Currency::free_balance(asset_a, pool).is_zero() || T::Currency::free_balance(asset_b, pool).is_zero() {
+			<QuarantinedPools<T>>::insert(pool, <frame_system::Pallet<T>>::block_number());
+
+			Self::deposit_event(Event::PoolQuarantined(pool.clone(), asset_a, asset_b));
+		}
+	}
+
+	/// Remove all storage entries of the pool and burn its locked shares.
+	fn remove_pool(pool: &T::AccountId) -> DispatchResult {
+		let share_token = <ShareToken<T>>::take(pool);
+		let locked_account = Self::locked_liquidity_account();
+		let locked = T::Currency::free_balance(share_token, &locked_account).min(MINIMUM_LIQUIDITY);
+		T::Currency::withdraw(share_token, &locked_account, locked)?;
+
+		<PoolAssets<T>>::remove(pool);
+		<PoolWeights<T>>::remove(pool);
+		<TotalLiquidity<T>>::remove(pool);
+		<PoolAccessAsset<T>>::remove(pool);
+		<QuarantinedPools<T>>::remove(pool);
+		<PoolStates<T>>::remove(pool);
+
+		<PoolOwner<T>>::remove(pool);
+
+		T::NonDustableWhitelistHandler::remove_account(pool)?;
+
+		// Expiry task may have been already executed, nothing to cancel then.
+		let _ = T::Scheduler::cancel_named(Self::owner_expiry_id(pool));
+
+		Ok(())
+	}
+
+	/// Set or remove access asset of the pool.
+	pub fn set_access_asset(pool: &T::AccountId, access_asset: Option<AssetId>) {
+		match access_asset {
+			Some(asset) => {
+				<PoolAccessAsset<T>>::insert(pool, asset);
+				Self::deposit_event(Event::PoolAccessAssetSet(pool.clone(), asset));
+			}
+			None => {
+				<PoolAccessAsset<T>>::remove(pool);
+				Self::deposit_event(Event::PoolAccessAssetRemoved(pool.clone()));
+			}
+		}
+	}
+
+	/// Ensure that `who` is owner of the pool.
+	fn ensure_pool_owner(pool: &T::AccountId, who: &T::AccountId) -> DispatchResult {
+		let (owner, _) = Self::pool_owner(pool).ok_or(Error::<T>::PoolOwnerNotFound)?;
+		ensure!(&owner == who, Error::<T>::NotPoolOwner);
+		Ok(())
+	}
+
+	/// Scheduler task id of pool owner expiry.
+	fn owner_expiry_id(pool: &T::AccountId) -> Vec<u8> {
+		(POOL_OWNER_EXPIRY_ID, pool).encode()
+	}
+
+	/// Set new deadline of pool ownership and reschedule owner expiry accordingly.
+	fn renew_ownership(pool: &T::AccountId, owner: T::AccountId) -> DispatchResult {
+		let deadline = <frame_system::Pallet<T>>::block_number().saturating_add(T::PoolOwnerInactivityPeriod::get());
+
+		Self::set_ownership(pool, owner.clone(), deadline)?;
+
+		Self::deposit_event(Event::PoolOwnershipRenewed(pool.clone(), owner, deadline));
+
+		Ok(())
+	}
+
+	/// Set owner of the pool and schedule owner expiry at `deadline`.
+	fn set_ownership(pool: &T::AccountId, owner: T::AccountId, deadline: T::BlockNumber) -> DispatchResult {
+		let task_id = Self::owner_expiry_id(pool);
+
+		// Expiry task may still be pending even if there is no owner - e.g. when the owner was expired by governance
+		// before the task has been executed.
+		let _ = T::Scheduler::cancel_named(task_id.clone());
+
+		T::Scheduler::schedule_named(
+			task_id,
+			DispatchTime::At(deadline),
+			None,
+			LOWEST_PRIORITY,
+			frame_system::RawOrigin::<T::AccountId>::Root.into(),
+			Call::<T>::expire_pool_owner(pool.clone()).into(),
+		)
+		.map_err(|_| Error::<T>::CannotScheduleOwnerExpiry)?;
+
+		<PoolOwner<T>>::insert(pool, (owner, deadline));
+
+		Ok(())
+	}
+
+	/// Move pool of `asset_old` and `other` to the pool of `asset_new` and `other`.
+	///
+	/// Reserve of `asset_old` has to be already replaced by `asset_new` in the pool account. Reserves and
+	/// parameters of the pool are moved to the account of the new pool, share token stays the same, so liquidity
+	/// providers keep their shares.
+	///
+	/// Return account of the new pool.
+	pub fn migrate_pool_asset(
+		asset_old: AssetId,
+		asset_new: AssetId,
+		other: AssetId,
+	) -> Result<T::AccountId, DispatchError> {
+		let old_assets = AssetPair::new(asset_old, other);
+		let new_assets = AssetPair::new(asset_new, other);
+
+		ensure!(Self::exists(old_assets), Error::<T>::TokenPoolNotFound);
+		ensure!(asset_new != other, Error::<T>::CannotCreatePoolWithSameAssets);
+		ensure!(!Self::exists(new_assets), Error::<T>::TokenPoolAlreadyExists);
+
+		let old_pool = Self::get_pair_id(old_assets);
+		let new_pool = Self::get_pair_id(new_assets);
+
+		ensure!(!Self::is_flash_loan_active(&old_pool), Error::<T>::FlashLoanInProgress);
+
+		let reserve_new = T::Currency::free_balance(asset_new, &old_pool);
+		let reserve_other = T::Currency::free_balance(other, &old_pool);
+
+		T::NonDustableWhitelistHandler::add_account(&new_pool)?;
+
+		T::Currency::transfer(asset_new, &old_pool, &new_pool, reserve_new)?;
+		T::Currency::transfer(other, &old_pool, &new_pool, reserve_other)?;
+
+		T::NonDustableWhitelistHandler::remove_account(&old_pool)?;
+
+		// Weights are stored in the order of pool assets, which may change with the new asset.
+		let pool_assets = OrderedAssetPair::new(asset_new, other);
+		if let Some(weights) = <PoolWeights<T>>::take(&old_pool) {
+			let (weight_old, weight_other) = Self::pool_assets(&old_pool).order(asset_old, weights);
+			<PoolWeights<T>>::insert(&new_pool, pool_assets.order(asset_new, (weight_old, weight_other)));
+		}
+
+		<PoolAssets<T>>::remove(&old_pool);
+		<PoolAssets<T>>::insert(&new_pool, pool_assets);
+		<ShareToken<T>>::insert(&new_pool, <ShareToken<T>>::take(&old_pool));
+		<TotalLiquidity<T>>::insert(&new_pool, <TotalLiquidity<T>>::take(&old_pool));
+		<PoolStates<T>>::insert(&new_pool, <PoolStates<T>>::take(&old_pool));
+
+		if let Some(access_asset) = <PoolAccessAsset<T>>::take(&old_pool) {
+			<PoolAccessAsset<T>>::insert(&new_pool, access_asset);
+		}
+
+		if let Some(quarantined) = <QuarantinedPools<T>>::take(&old_pool) {
+			<QuarantinedPools<T>>::insert(&new_pool, quarantined);
+		}
+
+		// Owner keeps the deadline, expiry is rescheduled for the new pool. Expiry which is due is rescheduled
+		// to the next block, as it cannot be scheduled in the past.
+		if let Some((owner, deadline)) = <PoolOwner<T>>::take(&old_pool) {
+			let _ = T::Scheduler::cancel_named(Self::owner_expiry_id(&old_pool));
+			let next_block = <frame_system::Pallet<T>>::block_number().saturating_add(1u32.into());
+			Self::set_ownership(&new_pool, owner, deadline.max(next_block))?;
 		}
-		T::AccountId::unchecked_from(T::Hashing::hash(&buf[..]))
+
+		Self::deposit_event(Event::PoolAssetMigrated(
+			old_pool,
+			new_pool.clone(),
+			asset_old,
+			asset_new,
+			other,
+		));
+
+		Ok(new_pool)
 	}
-}
 
-impl<T: Config> Pallet<T> {
-	/// Return balance of each asset in selected liquidity pool.
-	pub fn get_pool_balances(pool_address: T::AccountId) -> Option<Vec<(AssetId, Balance)>> {
-		let mut balances = Vec::new();
+	/// Calculate trade fee of selling `amount` of `assets.asset_in` by `who`.
+	///
+	/// Return trade fee of `who` and discounted trade fee if `who` is eligible for the discount - pool of sold asset
+	/// and native asset exists and `who` has enough native asset to pay the discounted fee. Trade fee of `who` is the
+	/// fee of the discount tier of `who` if it is lower than the standard fee.
+	pub fn calculate_trade_fees(
+		who: &T::AccountId,
+		assets: AssetPair,
+		amount: Balance,
+	) -> Result<(Balance, Option<Balance>), DispatchError> {
+		ensure!(Self::exists(assets), Error::<T>::TokenPoolNotFound);
 
-		if let Some(assets) = Self::get_pool_assets(&pool_address) {
-			for item in &assets {
-				let reserve = T::Currency::free_balance(*item, &pool_address);
-				balances.push((*item, reserve));
+		let fee = Self::calculate_fee(who, amount)?;
+		let discounted_fee = Self::calculate_discounted_fee(amount)?;
+
+		let native_asset = T::NativeAssetId::get();
+		let native_pair = AssetPair {
+			asset_in: assets.asset_in,
+			asset_out: native_asset,
+		};
+
+		if !Self::exists(native_pair) {
+			return Ok((fee, None));
+		}
+
+		let native_pair_account = Self::get_pair_id(native_pair);
+
+		let eligible = Self::calculate_spot_price(&native_pair_account, assets.asset_in, native_asset, discounted_fee)
+			.map(|native_fee| T::Currency::free_balance(native_asset, who) >= native_fee)
+			.unwrap_or(false);
+
+		Ok((fee, if eligible { Some(discounted_fee) } else { None }))
+	}
+
+	/// Quote selling `amount` of `assets.asset_in` for `assets.asset_out` with standard trade fee.
+	///
+	/// Return amount of `asset_out` received, trade fee paid in `asset_in` and price impact of the trade -
+	/// relative difference between the received amount and value of sold amount without fee at spot price.
+	pub fn calculate_sell_price(
+		assets: AssetPair,
+		amount: Balance,
+	) -> Result<(Balance, Balance, Permill), DispatchError> {
+		Self::quote_sell(assets, amount, T::GetExchangeFee::get())
+	}
+
+	/// Quote selling `amount` of `assets.asset_in` for `assets.asset_out` with trade fee of `who`.
+	///
+	/// Same as `calculate_sell_price`, but the fee is the one applied to a sell of `who` with given `discount` flag.
+	pub fn calculate_sell_price_for(
+		who: &T::AccountId,
+		assets: AssetPair,
+		amount: Balance,
+		discount: bool,
+	) -> Result<(Balance, Balance, Permill), DispatchError> {
+		Self::quote_sell(assets, amount, Self::trade_fee(who, discount))
+	}
+
+	fn quote_sell(
+		assets: AssetPair,
+		amount: Balance,
+		trade_fee: fee::Fee,
+	) -> Result<(Balance, Balance, Permill), DispatchError> {
+		ensure!(Self::exists(assets), Error::<T>::TokenPoolNotFound);
+
+		let pair_account = Self::get_pair_id(assets);
+
+		ensure!(!Self::is_quarantined(&pair_account), Error::<T>::PoolQuarantined);
+
+		Self::ensure_pool_active(&pair_account)?;
+
+		let asset_in_reserve = T::Currency::free_balance(assets.asset_in, &pair_account);
+		let asset_out_reserve = T::Currency::free_balance(assets.asset_out, &pair_account);
+
+		Self::ensure_max_in_ratio(&pair_account, assets.asset_in, amount)?;
+
+		let (asset_in_weight, asset_out_weight) = Self::asset_weights(&pair_account, assets.asset_in);
+
+		let (amount_out, fee) = math::calculate_weighted_out_given_in_with_fee(
+			asset_in_reserve,
+			asset_in_weight,
+			asset_out_reserve,
+			asset_out_weight,
+			amount,
+			trade_fee.numerator,
+			trade_fee.denominator,
+		)
+		.ok_or(Error::<T>::SellAssetAmountInvalid)?;
+
+		ensure!(asset_out_reserve > amount_out, Error::<T>::InsufficientAssetBalance);
+
+		let spot_amount_out = Self::calculate_spot_price(
+			&pair_account,
+			assets.asset_in,
+			assets.asset_out,
+			amount.saturating_sub(fee),
+		)
+		.ok_or(Error::<T>::SellAssetAmountInvalid)?;
+
+		Ok((amount_out, fee, Self::price_impact(spot_amount_out, amount_out)))
+	}
+
+	/// Quote buying `amount` of `assets.asset_out` for `assets.asset_in` with standard trade fee.
+	///
+	/// Return amount of `asset_in` paid including the fee, trade fee paid in `asset_in` and price impact of the trade -
+	/// relative difference between the paid amount without fee and value of bought amount at spot price.
+	pub fn calculate_buy_price(
+		assets: AssetPair,
+		amount: Balance,
+	) -> Result<(Balance, Balance, Permill), DispatchError> {
+		Self::quote_buy(assets, amount, T::GetExchangeFee::get())
+	}
+
+	/// Quote buying `amount` of `assets.asset_out` for `assets.asset_in` with trade fee of `who`.
+	///
+	/// Same as `calculate_buy_price`, but the fee is the one applied to a buy of `who` with given `discount` flag.
+	pub fn calculate_buy_price_for(
+		who: &T::AccountId,
+		assets: AssetPair,
+		amount: Balance,
+		discount: bool,
+	) -> Result<(Balance, Balance, Permill), DispatchError> {
+		Self::quote_buy(assets, amount, Self::trade_fee(who, discount))
+	}
+
+	fn quote_buy(
+		assets: AssetPair,
+		amount: Balance,
+		trade_fee: fee::Fee,
+	) -> Result<(Balance, Balance, Permill), DispatchError> {
+		ensure!(Self::exists(assets), Error::<T>::TokenPoolNotFound);
+
+		let pair_account = Self::get_pair_id(assets);
+
+		ensure!(!Self::is_quarantined(&pair_account), Error::<T>::PoolQuarantined);
+
+		Self::ensure_pool_active(&pair_account)?;
+
+		let asset_out_reserve = T::Currency::free_balance(assets.asset_out, &pair_account);
+		let asset_in_reserve = T::Currency::free_balance(assets.asset_in, &pair_account);
+
+		Self::ensure_max_out_ratio(&pair_account, assets.asset_out, amount)?;
+
+		let (asset_out_weight, asset_in_weight) = Self::asset_weights(&pair_account, assets.asset_out);
+
+		let (amount_in, fee) = math::calculate_weighted_in_given_out_with_fee(
+			asset_out_reserve,
+			asset_out_weight,
+			asset_in_reserve,
+			asset_in_weight,
+			amount,
+			trade_fee.numerator,
+			trade_fee.denominator,
+		)
+		.ok_or(Error::<T>::BuyAssetAmountInvalid)?;
+
+		Self::ensure_max_in_ratio(&pair_account, assets.asset_in, amount_in)?;
+
+		let spot_amount_in = Self::calculate_spot_price(&pair_account, assets.asset_out, assets.asset_in, amount)
+			.ok_or(Error::<T>::BuyAssetAmountInvalid)?;
+
+		Ok((
+			amount_in,
+			fee,
+			Self::price_impact(amount_in.saturating_sub(fee), spot_amount_in),
+		))
+	}
+
+	/// Find route selling `amount` of `asset_in` for the highest amount of `asset_out` including trade fees.
+	///
+	/// Searches direct pool of the assets and routes through up to `MAX_ROUTE_INTERMEDIATE_ASSETS` intermediate
+	/// assets, e.g. HDX. Pools which could not execute the trade (quarantined, inactive, max in ratio exceeded)
+	/// are skipped. Shorter route is preferred when routes yield the same amount.
+	///
+	/// Return assets of the route starting with `asset_in` and ending with `asset_out` and the received amount.
+	pub fn find_best_route(asset_in: AssetId, asset_out: AssetId, amount: Balance) -> Option<(Vec<AssetId>, Balance)> {
+		if asset_in == asset_out || amount.is_zero() {
+			return None;
+		}
+
+		let pools: Vec<OrderedAssetPair> = <PoolAssets<T>>::iter_values().collect();
+
+		let mut route = vec![asset_in];
+		let mut best_route = None;
+
+		Self::search_routes(&pools, asset_out, amount, &mut route, &mut best_route);
+
+		best_route
+	}
+
+	/// Extend `route` by each pool of its last asset and keep the route with the highest amount of `asset_out`
+	/// in `best_route`.
+	fn search_routes(
+		pools: &[OrderedAssetPair],
+		asset_out: AssetId,
+		amount: Balance,
+		route: &mut Vec<AssetId>,
+		best_route: &mut Option<(Vec<AssetId>, Balance)>,
+	) {
+		let asset_in = match route.last() {
+			Some(asset) => *asset,
+			None => return,
+		};
+
+		for next in pools.iter().filter_map(|pool| pool.other(asset_in)) {
+			if route.contains(&next) || (next != asset_out && route.len() > MAX_ROUTE_INTERMEDIATE_ASSETS) {
+				continue;
+			}
+
+			let amount_out = match Self::calculate_sell_price(
+				AssetPair {
+					asset_in,
+					asset_out: next,
+				},
+				amount,
+			) {
+				Ok((amount_out, _, _)) if !amount_out.is_zero() => amount_out,
+				_ => continue,
+			};
+
+			route.push(next);
+
+			if next == asset_out {
+				let is_better = match best_route {
+					Some((best, best_amount)) => {
+						amount_out > *best_amount || (amount_out == *best_amount && route.len() < best.len())
+					}
+					None => true,
+				};
+
+				if is_better {
+					*best_route = Some((route.clone(), amount_out));
+				}
+			} else {
+				Self::search_routes(pools, asset_out, amount_out, route, best_route);
 			}
+
+			route.pop();
 		}
-		Some(balances)
 	}
+
+	/// Ensure `amount` paid into the pool does not exceed `MAX_IN_RATIO` of its current `asset_in` reserve.
+	fn ensure_max_in_ratio(pair_account: &T::AccountId, asset_in: AssetId, amount: Balance) -> DispatchResult {
+		let asset_in_reserve = T::Currency::free_balance(asset_in, pair_account);
+
+		ensure!(
+			amount <= asset_in_reserve / MAX_IN_RATIO,
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		Ok(())
+	}
+
+	/// Ensure `amount` taken from the pool does not exceed `MAX_OUT_RATIO` of its current `asset_out` reserve.
+	fn ensure_max_out_ratio(pair_account: &T::AccountId, asset_out: AssetId, amount: Balance) -> DispatchResult {
+		let asset_out_reserve = T::Currency::free_balance(asset_out, pair_account);
+
+		ensure!(asset_out_reserve > amount, Error::<T>::InsufficientPoolAssetBalance);
+
+		ensure!(
+			amount <= asset_out_reserve / MAX_OUT_RATIO,
+			Error::<T>::MaxOutRatioExceeded
+		);
+
+		Ok(())
+	}
+
+	/// Preview removing `share_amount` of liquidity from the pool of `assets` at current reserves.
+	///
+	/// Return exact amounts of `assets.asset_in` and `assets.asset_out` which `remove_liquidity` would return
+	/// to the liquidity provider. Removing liquidity is not subject to any fee, so the amounts are final.
+	pub fn calculate_liquidity_out_for(
+		assets: AssetPair,
+		share_amount: Balance,
+	) -> Result<(Balance, Balance), DispatchError> {
+		ensure!(!share_amount.is_zero(), Error::<T>::CannotRemoveLiquidityWithZero);
+
+		ensure!(Self::exists(assets), Error::<T>::TokenPoolNotFound);
+
+		let pair_account = Self::get_pair_id(assets);
+
+		let total_shares = Self::total_liquidity(&pair_account);
+
+		ensure!(share_amount <= total_shares, Error::<T>::InsufficientAssetBalance);
+
+		Ok(Self::liquidity_out(&pair_account, assets, share_amount, total_shares))
+	}
+
+	/// Amounts of both pool assets corresponding to `share_amount` of `total_shares` at current reserves.
+	fn liquidity_out(
+		pair_account: &T::AccountId,
+		assets: AssetPair,
+		share_amount: Balance,
+		total_shares: Balance,
+	) -> (Balance, Balance) {
+		let asset_a_reserve = T::Currency::free_balance(assets.asset_in, pair_account);
+		let asset_b_reserve = T::Currency::free_balance(assets.asset_out, pair_account);
+
+		math::calculate_liquidity_out(asset_a_reserve, asset_b_reserve, share_amount, total_shares)
+	}
+
+	/// Relative difference between `expected` and `actual` amount, zero if `actual` is better than expected.
+	fn price_impact(expected: Balance, actual: Balance) -> Permill {
+		if expected.is_zero() {
+			return Permill::zero();
+		}
+
+		Permill::from_rational_approximation(expected.saturating_sub(actual), expected)
+	}
+
 	/// Calculate discounted trade fee
 	fn calculate_discounted_fee(amount: Balance) -> Result<Balance, DispatchError> {
 		Ok(amount
@@ -530,12 +1988,137 @@ impl<T: Config> Pallet<T> {
 			.ok_or::<Error<T>>(Error::<T>::FeeAmountInvalid)?)
 	}
 
-	/// Calculate trade fee
-	fn calculate_fee(amount: Balance) -> Result<Balance, DispatchError> {
+	/// Calculate trade fee of `who`
+	fn calculate_fee(who: &T::AccountId, amount: Balance) -> Result<Balance, DispatchError> {
 		Ok(amount
-			.just_fee(T::GetExchangeFee::get())
+			.just_fee(Self::trade_fee(who, false))
+			.ok_or::<Error<T>>(Error::<T>::FeeAmountInvalid)?)
+	}
+
+	/// Trade fee rate applied to sells and buys of `who`
+	///
+	/// Boolean `discount` is kept for compatibility - if set, `DISCOUNTED_FEE` applies and the fee is paid in native
+	/// asset. Otherwise fee of the discount tier of `who` applies if it is lower than the standard fee.
+	fn trade_fee(who: &T::AccountId, discount: bool) -> fee::Fee {
+		if discount {
+			return fee::DISCOUNTED_FEE;
+		}
+
+		let standard_fee = T::GetExchangeFee::get();
+
+		match T::FeeDiscount::trade_fee(who) {
+			Some(tier_fee)
+				if (tier_fee.numerator as u64) * (standard_fee.denominator as u64)
+					< (standard_fee.numerator as u64) * (tier_fee.denominator as u64) =>
+			{
+				tier_fee
+			}
+			_ => standard_fee,
+		}
+	}
+
+	/// Calculate part of the trade fee which goes to the treasury
+	fn calculate_protocol_fee(trade_fee: Balance) -> Result<Balance, DispatchError> {
+		Ok(trade_fee
+			.just_fee(Self::protocol_fee())
 			.ok_or::<Error<T>>(Error::<T>::FeeAmountInvalid)?)
 	}
+
+	/// Split the trade fee into liquidity provider and protocol part.
+	fn trade_fees(trade_fee: Balance, discount_fee: Balance) -> Result<TradeFees<Balance>, DispatchError> {
+		let protocol_fee = Self::calculate_protocol_fee(trade_fee)?;
+
+		Ok(TradeFees {
+			lp_fee: trade_fee.saturating_sub(protocol_fee),
+			protocol_fee,
+			discount: discount_fee,
+		})
+	}
+
+	/// Transfer protocol part of the trade fee from the pool to the treasury
+	fn transfer_protocol_fee(asset: AssetId, pair_account: &T::AccountId, protocol_fee: Balance) -> DispatchResult {
+		if !protocol_fee.is_zero() {
+			T::Currency::transfer(asset, pair_account, &T::TreasuryAccount::get(), protocol_fee)?;
+		}
+
+		Ok(())
+	}
+
+	/// Return true if reserves of `pool` are lent by the flash loan in progress.
+	pub fn is_flash_loan_active(pool: &T::AccountId) -> bool {
+		Self::flash_loan_pool().as_ref() == Some(pool)
+	}
+
+	/// Lend `amount` of `asset` from the pool of `assets` to `who` and call `R::on_flash_loan`.
+	///
+	/// Borrowed amount plus fee is transferred back to the pool when `R::on_flash_loan` returns.
+	/// All changes are reverted if the loan cannot be repaid.
+	#[transactional]
+	pub fn flash_loan_with<R: FlashLoanReceiver<T::AccountId, AssetId, Balance>>(
+		who: &T::AccountId,
+		assets: AssetPair,
+		asset: AssetId,
+		amount: Balance,
+	) -> DispatchResult {
+		Self::execute_flash_loan(who, assets, asset, amount, |fee| {
+			R::on_flash_loan(who, asset, amount, fee)
+		})
+	}
+
+	/// Lend `amount` of `asset` from the pool of `assets` to `who`, execute `receive` with the loan fee
+	/// and transfer borrowed amount plus fee back to the pool.
+	///
+	/// Has to be executed in a transactional context so that the loan is reverted when it is not repaid.
+	fn execute_flash_loan<F: FnOnce(Balance) -> DispatchResult>(
+		who: &T::AccountId,
+		assets: AssetPair,
+		asset: AssetId,
+		amount: Balance,
+		receive: F,
+	) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::CannotFlashLoanZero);
+
+		ensure!(Self::exists(assets), Error::<T>::TokenPoolNotFound);
+
+		ensure!(
+			asset == assets.asset_in || asset == assets.asset_out,
+			Error::<T>::TokenPoolNotFound
+		);
+
+		ensure!(Self::flash_loan_pool().is_none(), Error::<T>::FlashLoanInProgress);
+
+		let pair_account = Self::get_pair_id(assets);
+
+		ensure!(!Self::is_quarantined(&pair_account), Error::<T>::PoolQuarantined);
+
+		Self::ensure_pool_active(&pair_account)?;
+
+		let reserve = T::Currency::free_balance(asset, &pair_account);
+
+		ensure!(reserve > amount, Error::<T>::InsufficientPoolAssetBalance);
+
+		let fee = amount
+			.just_fee(T::FlashLoanFee::get())
+			.ok_or(Error::<T>::FeeAmountInvalid)?;
+
+		<FlashLoanPool<T>>::put(&pair_account);
+
+		T::Currency::transfer(asset, &pair_account, who, amount)?;
+
+		receive(fee)?;
+
+		let repayment = amount.checked_add(fee).ok_or(Error::<T>::FeeAmountInvalid)?;
+
+		T::Currency::transfer(asset, who, &pair_account, repayment).map_err(|_| Error::<T>::FlashLoanNotRepaid)?;
+
+		<FlashLoanPool<T>>::kill();
+
+		Self::transfer_protocol_fee(asset, &pair_account, Self::calculate_protocol_fee(fee)?)?;
+
+		Self::deposit_event(Event::FlashLoanExecuted(who.clone(), pair_account, asset, amount, fee));
+
+		Ok(())
+	}
 }
 
 // Implementation of AMM API which makes possible to plug the AMM pool into the exchange pallet.
@@ -553,7 +2136,7 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 		match <PoolAssets<T>>::contains_key(pool_account_id) {
 			true => {
 				let assets = Self::pool_assets(pool_account_id);
-				Some(vec![assets.0, assets.1])
+				Some(vec![assets.asset_a, assets.asset_b])
 			}
 			false => None,
 		}
@@ -565,11 +2148,11 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			asset_in: asset_b,
 		});
 
-		let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
-		let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
+		if Self::is_quarantined(&pair_account) {
+			return Balance::zero();
+		}
 
-		hydra_dx_math::calculate_spot_price(asset_a_reserve, asset_b_reserve, amount)
-			.unwrap_or_else(|_| Balance::zero())
+		Self::calculate_spot_price(&pair_account, asset_a, asset_b, amount).unwrap_or_else(Balance::zero)
 	}
 
 	/// Validate a sell. Perform all necessary checks and calculations.
@@ -603,27 +2186,39 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 
 		let pair_account = Self::get_pair_id(assets);
 
-		let asset_in_reserve = T::Currency::free_balance(assets.asset_in, &pair_account);
-		let asset_out_reserve = T::Currency::free_balance(assets.asset_out, &pair_account);
+		ensure!(!Self::is_quarantined(&pair_account), Error::<T>::PoolQuarantined);
+
+		Self::ensure_pool_active(&pair_account)?;
 
 		ensure!(
-			amount <= asset_in_reserve / MAX_IN_RATIO,
-			Error::<T>::MaxInRatioExceeded
+			!Self::is_flash_loan_active(&pair_account),
+			Error::<T>::FlashLoanInProgress
 		);
 
-		let transfer_fee = if discount {
-			Self::calculate_discounted_fee(amount)?
-		} else {
-			Self::calculate_fee(amount)?
-		};
+		ensure!(
+			Self::has_pool_access(&pair_account, who),
+			Error::<T>::AccessAssetRequired
+		);
+
+		let asset_in_reserve = T::Currency::free_balance(assets.asset_in, &pair_account);
+		let asset_out_reserve = T::Currency::free_balance(assets.asset_out, &pair_account);
 
-		let amount_without_fee = amount
-			.checked_sub(transfer_fee)
-			.ok_or(Error::<T>::SellAssetAmountInvalid)?;
+		Self::ensure_max_in_ratio(&pair_account, assets.asset_in, amount)?;
 
-		let sale_price =
-			hydra_dx_math::calculate_out_given_in(asset_in_reserve, asset_out_reserve, amount_without_fee)
-				.map_err(|_| Error::<T>::SellAssetAmountInvalid)?;
+		let trade_fee = Self::trade_fee(who, discount);
+
+		let (asset_in_weight, asset_out_weight) = Self::asset_weights(&pair_account, assets.asset_in);
+
+		let (sale_price, transfer_fee) = math::calculate_weighted_out_given_in_with_fee(
+			asset_in_reserve,
+			asset_in_weight,
+			asset_out_reserve,
+			asset_out_weight,
+			amount,
+			trade_fee.numerator,
+			trade_fee.denominator,
+		)
+		.ok_or(Error::<T>::SellAssetAmountInvalid)?;
 
 		ensure!(asset_out_reserve > sale_price, Error::<T>::InsufficientAssetBalance);
 
@@ -637,12 +2232,9 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 				asset_out: native_asset,
 			});
 
-			let native_reserve = T::Currency::free_balance(native_asset, &native_pair_account);
-			let asset_reserve = T::Currency::free_balance(assets.asset_in, &native_pair_account);
-
 			let native_fee_spot_price =
-				hydra_dx_math::calculate_spot_price(asset_reserve, native_reserve, transfer_fee)
-					.map_err(|_| Error::<T>::CannotApplyDiscount)?;
+				Self::calculate_spot_price(&native_pair_account, assets.asset_in, native_asset, transfer_fee)
+					.ok_or(Error::<T>::CannotApplyDiscount)?;
 
 			ensure!(
 				T::Currency::free_balance(native_asset, who) >= native_fee_spot_price,
@@ -661,6 +2253,9 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			amount_out: sale_price,
 			discount,
 			discount_amount: discount_fee,
+			fee: transfer_fee,
+			source: TradeSource::Router,
+			fees: Some(Self::trade_fees(transfer_fee, discount_fee)?),
 		};
 
 		Ok(transfer)
@@ -669,10 +2264,16 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 	/// Execute sell. validate_sell must be called first.
 	/// Perform necessary storage/state changes.
 	/// Note : the execution should not return error as everything was previously verified and validated.
+	/// Ratio limits are verified again against current reserves, as the pool could have been traded
+	/// between validation and execution (e.g. when the exchange pallet resolves a batch of intentions).
 	#[transactional]
 	fn execute_sell(transfer: &AMMTransfer<T::AccountId, AssetPair, Balance>) -> DispatchResult {
 		let pair_account = Self::get_pair_id(transfer.assets);
 
+		Self::ensure_max_in_ratio(&pair_account, transfer.assets.asset_in, transfer.amount)?;
+
+		T::AMMHandler::on_before_trade(&pair_account, IntentionType::SELL, transfer)?;
+
 		if transfer.discount && transfer.discount_amount > 0u128 {
 			let native_asset = T::NativeAssetId::get();
 			T::Currency::withdraw(native_asset, &transfer.origin, transfer.discount_amount)?;
@@ -691,14 +2292,27 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			transfer.amount_out,
 		)?;
 
+		let fees = match transfer.fees {
+			Some(fees) => fees,
+			None => Self::trade_fees(transfer.fee, transfer.discount_amount)?,
+		};
+
+		Self::transfer_protocol_fee(transfer.assets.asset_in, &pair_account, fees.protocol_fee)?;
+
+		T::AMMHandler::on_trade(&pair_account, IntentionType::SELL, transfer);
+
 		Self::deposit_event(Event::<T>::SellExecuted(
 			transfer.origin.clone(),
 			transfer.assets.asset_in,
 			transfer.assets.asset_out,
 			transfer.amount,
 			transfer.amount_out,
+			transfer.source,
+			fees,
 		));
 
+		Self::quarantine_if_depleted(&pair_account, transfer.assets.asset_in, transfer.assets.asset_out);
+
 		Ok(())
 	}
 
@@ -717,16 +2331,25 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 
 		let pair_account = Self::get_pair_id(assets);
 
-		let asset_out_reserve = T::Currency::free_balance(assets.asset_out, &pair_account);
-		let asset_in_reserve = T::Currency::free_balance(assets.asset_in, &pair_account);
+		ensure!(!Self::is_quarantined(&pair_account), Error::<T>::PoolQuarantined);
 
-		ensure!(asset_out_reserve > amount, Error::<T>::InsufficientPoolAssetBalance);
+		Self::ensure_pool_active(&pair_account)?;
 
 		ensure!(
-			amount <= asset_out_reserve / MAX_OUT_RATIO,
-			Error::<T>::MaxOutRatioExceeded
+			!Self::is_flash_loan_active(&pair_account),
+			Error::<T>::FlashLoanInProgress
 		);
 
+		ensure!(
+			Self::has_pool_access(&pair_account, who),
+			Error::<T>::AccessAssetRequired
+		);
+
+		let asset_out_reserve = T::Currency::free_balance(assets.asset_out, &pair_account);
+		let asset_in_reserve = T::Currency::free_balance(assets.asset_in, &pair_account);
+
+		Self::ensure_max_out_ratio(&pair_account, assets.asset_out, amount)?;
+
 		// If discount, pool for Sell asset and native asset must exist
 		if discount {
 			ensure!(
@@ -738,18 +2361,22 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			);
 		}
 
-		let buy_price = hydra_dx_math::calculate_in_given_out(asset_out_reserve, asset_in_reserve, amount)
-			.map_err(|_| Error::<T>::BuyAssetAmountInvalid)?;
+		let trade_fee = Self::trade_fee(who, discount);
 
-		let transfer_fee = if discount {
-			Self::calculate_discounted_fee(buy_price)?
-		} else {
-			Self::calculate_fee(buy_price)?
-		};
+		let (asset_out_weight, asset_in_weight) = Self::asset_weights(&pair_account, assets.asset_out);
 
-		let buy_price_with_fee = buy_price
-			.checked_add(transfer_fee)
-			.ok_or(Error::<T>::BuyAssetAmountInvalid)?;
+		let (buy_price_with_fee, transfer_fee) = math::calculate_weighted_in_given_out_with_fee(
+			asset_out_reserve,
+			asset_out_weight,
+			asset_in_reserve,
+			asset_in_weight,
+			amount,
+			trade_fee.numerator,
+			trade_fee.denominator,
+		)
+		.ok_or(Error::<T>::BuyAssetAmountInvalid)?;
+
+		Self::ensure_max_in_ratio(&pair_account, assets.asset_in, buy_price_with_fee)?;
 
 		ensure!(max_limit >= buy_price_with_fee, Error::<T>::AssetBalanceLimitExceeded);
 
@@ -766,12 +2393,9 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 				asset_out: native_asset,
 			});
 
-			let native_reserve = T::Currency::free_balance(native_asset, &native_pair_account);
-			let asset_reserve = T::Currency::free_balance(assets.asset_out, &native_pair_account);
-
 			let native_fee_spot_price =
-				hydra_dx_math::calculate_spot_price(asset_reserve, native_reserve, transfer_fee)
-					.map_err(|_| Error::<T>::CannotApplyDiscount)?;
+				Self::calculate_spot_price(&native_pair_account, assets.asset_out, native_asset, transfer_fee)
+					.ok_or(Error::<T>::CannotApplyDiscount)?;
 
 			ensure!(
 				T::Currency::free_balance(native_asset, who) >= native_fee_spot_price,
@@ -789,6 +2413,9 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			amount_out: buy_price_with_fee,
 			discount,
 			discount_amount: discount_fee,
+			fee: transfer_fee,
+			source: TradeSource::Router,
+			fees: Some(Self::trade_fees(transfer_fee, discount_fee)?),
 		};
 
 		Ok(transfer)
@@ -797,10 +2424,17 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 	/// Execute buy. validate_buy must be called first.
 	/// Perform necessary storage/state changes.
 	/// Note : the execution should not return error as everything was previously verified and validated.
+	/// Ratio limits are verified again against current reserves, as the pool could have been traded
+	/// between validation and execution (e.g. when the exchange pallet resolves a batch of intentions).
 	#[transactional]
 	fn execute_buy(transfer: &AMMTransfer<T::AccountId, AssetPair, Balance>) -> DispatchResult {
 		let pair_account = Self::get_pair_id(transfer.assets);
 
+		Self::ensure_max_out_ratio(&pair_account, transfer.assets.asset_out, transfer.amount)?;
+		Self::ensure_max_in_ratio(&pair_account, transfer.assets.asset_in, transfer.amount_out)?;
+
+		T::AMMHandler::on_before_trade(&pair_account, IntentionType::BUY, transfer)?;
+
 		if transfer.discount && transfer.discount_amount > 0 {
 			let native_asset = T::NativeAssetId::get();
 			T::Currency::withdraw(native_asset, &transfer.origin, transfer.discount_amount)?;
@@ -819,14 +2453,27 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			transfer.amount_out,
 		)?;
 
+		let fees = match transfer.fees {
+			Some(fees) => fees,
+			None => Self::trade_fees(transfer.fee, transfer.discount_amount)?,
+		};
+
+		Self::transfer_protocol_fee(transfer.assets.asset_in, &pair_account, fees.protocol_fee)?;
+
+		T::AMMHandler::on_trade(&pair_account, IntentionType::BUY, transfer);
+
 		Self::deposit_event(Event::<T>::BuyExecuted(
 			transfer.origin.clone(),
 			transfer.assets.asset_out,
 			transfer.assets.asset_in,
 			transfer.amount,
 			transfer.amount_out,
+			transfer.source,
+			fees,
 		));
 
+		Self::quarantine_if_depleted(&pair_account, transfer.assets.asset_in, transfer.assets.asset_out);
+
 		Ok(())
 	}
 }
@@ -0,0 +1,589 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Trade calculations with explicit fee.
+//!
+//! Curve calculations are provided by `hydra_dx_math`. Fee application and rounding of the results
+//! are done here, so that round-off never goes against the pool.
+//!
+//! Weighted pools use Balancer-style invariant `in_reserve^in_weight * out_reserve^out_weight`. Pools with
+//! equal weights use the constant product calculations.
+
+use primitive_types::U256;
+use primitives::Balance;
+use sp_std::convert::TryFrom;
+
+/// Sum of weights of both pool assets.
+pub const POOL_WEIGHT_TOTAL: u32 = 100;
+
+/// Minimum weight of a pool asset.
+pub const MIN_POOL_WEIGHT: u32 = 2;
+
+/// Weights of pool assets of a constant product pool.
+pub const EQUAL_POOL_WEIGHTS: (u32, u32) = (POOL_WEIGHT_TOTAL / 2, POOL_WEIGHT_TOTAL / 2);
+
+/// Fixed point one used by weighted pool calculations.
+const FIXED_ONE: u128 = 1_000_000_000_000_000_000;
+
+/// Check that `weight` of one pool asset is allowed. Weight of the other asset is `POOL_WEIGHT_TOTAL - weight`.
+pub fn is_valid_pool_weight(weight: u32) -> bool {
+	(MIN_POOL_WEIGHT..=POOL_WEIGHT_TOTAL - MIN_POOL_WEIGHT).contains(&weight)
+}
+
+/// Calculate `fee_numerator / fee_denominator` of `amount`, rounded down.
+pub fn calculate_fee(amount: Balance, fee_numerator: u32, fee_denominator: u32) -> Option<Balance> {
+	if fee_denominator == 0 {
+		return None;
+	}
+
+	let fee = U256::from(amount) * U256::from(fee_numerator) / U256::from(fee_denominator);
+
+	Balance::try_from(fee).ok()
+}
+
+/// Divide and round the result up. Returns `None` for zero divisor.
+fn div_ceil(numerator: U256, denominator: U256) -> Option<U256> {
+	if denominator.is_zero() {
+		return None;
+	}
+
+	let (result, remainder) = numerator.div_mod(denominator);
+
+	if remainder.is_zero() {
+		Some(result)
+	} else {
+		result.checked_add(U256::one())
+	}
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+	if b == 0 {
+		a
+	} else {
+		gcd(b, a % b)
+	}
+}
+
+/// Reduce ratio of weights to lowest terms. Returns `None` if any of the weights is zero.
+fn reduce_weights(numerator: u32, denominator: u32) -> Option<(u32, u32)> {
+	if numerator == 0 || denominator == 0 {
+		return None;
+	}
+
+	let divisor = gcd(numerator, denominator);
+
+	Some((numerator / divisor, denominator / divisor))
+}
+
+/// Multiply fixed point numbers, rounded up or down.
+fn mul_fixed(a: U256, b: U256, round_up: bool) -> Option<U256> {
+	let product = a.checked_mul(b)?;
+
+	if round_up {
+		div_ceil(product, U256::from(FIXED_ONE))
+	} else {
+		Some(product / U256::from(FIXED_ONE))
+	}
+}
+
+/// Raise fixed point number to integer power, every multiplication is rounded up or down.
+fn pow_fixed(base: U256, exp: u32, round_up: bool) -> Option<U256> {
+	let mut result = U256::from(FIXED_ONE);
+	let mut base = base;
+	let mut exp = exp;
+
+	while exp > 0 {
+		if exp & 1 == 1 {
+			result = mul_fixed(result, base, round_up)?;
+		}
+		exp >>= 1;
+		if exp > 0 {
+			base = mul_fixed(base, base, round_up)?;
+		}
+	}
+
+	Some(result)
+}
+
+/// Return the smallest fixed point number whose `n`-th power is not lower than `value`.
+///
+/// Power is rounded down during the search, so the result is never lower than the exact root.
+fn root_fixed_ceil(value: U256, n: u32) -> Option<U256> {
+	if n == 0 {
+		return None;
+	}
+	if n == 1 {
+		return Some(value);
+	}
+
+	let mut low = U256::zero();
+	let mut high = value.max(U256::from(FIXED_ONE));
+
+	while low < high {
+		let mid = low + (high - low) / 2;
+
+		match pow_fixed(mid, n, false) {
+			Some(power) if power < value => low = mid + 1,
+			// Overflow means the power is above the value.
+			_ => high = mid,
+		}
+	}
+
+	Some(low)
+}
+
+/// Return `(numerator / denominator) ^ (exp_numerator / exp_denominator)` as fixed point number rounded up.
+fn ratio_pow_ceil(numerator: Balance, denominator: Balance, exp_numerator: u32, exp_denominator: u32) -> Option<U256> {
+	let (exp_numerator, exp_denominator) = reduce_weights(exp_numerator, exp_denominator)?;
+
+	let ratio = div_ceil(U256::from(numerator) * U256::from(FIXED_ONE), U256::from(denominator))?;
+
+	root_fixed_ceil(pow_fixed(ratio, exp_numerator, true)?, exp_denominator)
+}
+
+/// Calculate amount of asset out received for selling `amount` of asset in.
+///
+/// Trade fee is part of `amount`. It is rounded down and stays in the pool, remaining amount is swapped
+/// along the curve. Amount out is capped so that the product of pool reserves after the trade is never
+/// lower than before the trade.
+///
+/// Returns amount out and the fee or `None` if the trade cannot be calculated.
+pub fn calculate_out_given_in_with_fee(
+	in_reserve: Balance,
+	out_reserve: Balance,
+	amount: Balance,
+	fee_numerator: u32,
+	fee_denominator: u32,
+) -> Option<(Balance, Balance)> {
+	let fee = calculate_fee(amount, fee_numerator, fee_denominator)?;
+
+	let amount_without_fee = amount.checked_sub(fee)?;
+
+	let amount_out = hydra_dx_math::calculate_out_given_in(in_reserve, out_reserve, amount_without_fee).ok()?;
+
+	let min_out_reserve = div_ceil(
+		U256::from(in_reserve) * U256::from(out_reserve),
+		U256::from(in_reserve) + U256::from(amount),
+	)?;
+
+	let max_amount_out = out_reserve.checked_sub(Balance::try_from(min_out_reserve).ok()?)?;
+
+	Some((amount_out.min(max_amount_out), fee))
+}
+
+/// Calculate amount of asset in which has to be paid for buying `amount` of asset out.
+///
+/// Amount in is rounded up so that the product of pool reserves after the trade is never lower than
+/// before the trade. Trade fee is calculated from the amount in, rounded down and added on top of it.
+///
+/// Returns amount in including the fee and the fee or `None` if the trade cannot be calculated.
+pub fn calculate_in_given_out_with_fee(
+	out_reserve: Balance,
+	in_reserve: Balance,
+	amount: Balance,
+	fee_numerator: u32,
+	fee_denominator: u32,
+) -> Option<(Balance, Balance)> {
+	let out_reserve_left = out_reserve.checked_sub(amount)?;
+
+	let amount_in = hydra_dx_math::calculate_in_given_out(out_reserve, in_reserve, amount).ok()?;
+
+	let min_amount_in = div_ceil(
+		U256::from(in_reserve) * U256::from(amount),
+		U256::from(out_reserve_left),
+	)?;
+
+	let amount_in = amount_in.max(Balance::try_from(min_amount_in).ok()?);
+
+	let fee = calculate_fee(amount_in, fee_numerator, fee_denominator)?;
+
+	Some((amount_in.checked_add(fee)?, fee))
+}
+
+/// Calculate amount of asset out received for selling `amount` of asset in in a weighted pool.
+///
+/// Trade fee is part of `amount` the same way as in `calculate_out_given_in_with_fee`, which is used for pools
+/// with equal weights. Intermediate results are rounded so that amount out is never higher than the exact one.
+///
+/// Returns amount out and the fee or `None` if the trade cannot be calculated.
+pub fn calculate_weighted_out_given_in_with_fee(
+	in_reserve: Balance,
+	in_weight: u32,
+	out_reserve: Balance,
+	out_weight: u32,
+	amount: Balance,
+	fee_numerator: u32,
+	fee_denominator: u32,
+) -> Option<(Balance, Balance)> {
+	if in_weight == out_weight {
+		return calculate_out_given_in_with_fee(in_reserve, out_reserve, amount, fee_numerator, fee_denominator);
+	}
+
+	if in_reserve == 0 || out_reserve == 0 {
+		return None;
+	}
+
+	let fee = calculate_fee(amount, fee_numerator, fee_denominator)?;
+
+	let amount_without_fee = amount.checked_sub(fee)?;
+
+	// out_reserve * (1 - (in_reserve / (in_reserve + amount)) ^ (in_weight / out_weight))
+	let ratio = ratio_pow_ceil(
+		in_reserve,
+		in_reserve.checked_add(amount_without_fee)?,
+		in_weight,
+		out_weight,
+	)?;
+
+	let amount_out = U256::from(out_reserve) * U256::from(FIXED_ONE).saturating_sub(ratio) / U256::from(FIXED_ONE);
+
+	Some((Balance::try_from(amount_out).ok()?, fee))
+}
+
+/// Calculate amount of asset in which has to be paid for buying `amount` of asset out in a weighted pool.
+///
+/// Trade fee is added on top of amount in the same way as in `calculate_in_given_out_with_fee`, which is used
+/// for pools with equal weights. Intermediate results are rounded so that amount in is never lower than the exact one.
+///
+/// Returns amount in including the fee and the fee or `None` if the trade cannot be calculated.
+pub fn calculate_weighted_in_given_out_with_fee(
+	out_reserve: Balance,
+	out_weight: u32,
+	in_reserve: Balance,
+	in_weight: u32,
+	amount: Balance,
+	fee_numerator: u32,
+	fee_denominator: u32,
+) -> Option<(Balance, Balance)> {
+	if in_weight == out_weight {
+		return calculate_in_given_out_with_fee(out_reserve, in_reserve, amount, fee_numerator, fee_denominator);
+	}
+
+	if in_reserve == 0 || out_reserve == 0 {
+		return None;
+	}
+
+	let out_reserve_left = out_reserve.checked_sub(amount)?;
+
+	// in_reserve * ((out_reserve / (out_reserve - amount)) ^ (out_weight / in_weight) - 1)
+	let ratio = ratio_pow_ceil(out_reserve, out_reserve_left, out_weight, in_weight)?;
+
+	let amount_in = div_ceil(
+		U256::from(in_reserve).checked_mul(ratio.checked_sub(U256::from(FIXED_ONE))?)?,
+		U256::from(FIXED_ONE),
+	)?;
+
+	let amount_in = Balance::try_from(amount_in).ok()?;
+
+	let fee = calculate_fee(amount_in, fee_numerator, fee_denominator)?;
+
+	Some((amount_in.checked_add(fee)?, fee))
+}
+
+/// Calculate value of `amount` of asset a in asset b in a weighted pool, rounded down.
+///
+/// Pools with equal weights use `hydra_dx_math::calculate_spot_price`.
+pub fn calculate_weighted_spot_price(
+	a_reserve: Balance,
+	a_weight: u32,
+	b_reserve: Balance,
+	b_weight: u32,
+	amount: Balance,
+) -> Option<Balance> {
+	if a_weight == b_weight {
+		return hydra_dx_math::calculate_spot_price(a_reserve, b_reserve, amount).ok();
+	}
+
+	// amount * (b_reserve / b_weight) / (a_reserve / a_weight)
+	let denominator = U256::from(a_reserve).checked_mul(U256::from(b_weight))?;
+
+	if denominator.is_zero() {
+		return None;
+	}
+
+	let price = U256::from(amount)
+		.checked_mul(U256::from(b_reserve))?
+		.checked_mul(U256::from(a_weight))?
+		/ denominator;
+
+	Balance::try_from(price).ok()
+}
+
+/// Calculate amounts of both pool assets corresponding to `share_amount` of `total_shares`, rounded down.
+///
+/// `share_amount` is capped at `total_shares`, so the amounts never exceed the reserves.
+pub fn calculate_liquidity_out(
+	a_reserve: Balance,
+	b_reserve: Balance,
+	share_amount: Balance,
+	total_shares: Balance,
+) -> (Balance, Balance) {
+	if total_shares == 0 {
+		return (0, 0);
+	}
+
+	let share_of = |reserve: Balance| {
+		(U256::from(reserve) * U256::from(share_amount.min(total_shares)) / U256::from(total_shares)).low_u128()
+	};
+
+	(share_of(a_reserve), share_of(b_reserve))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const RESERVES: [Balance; 8] = [
+		1,
+		2,
+		7,
+		1_000,
+		99_999,
+		1_000_000_000_000,
+		123_456_789_012_345,
+		1_000_000_000_000_000_000,
+	];
+
+	const AMOUNTS: [Balance; 8] = [0, 1, 2, 3, 499, 500, 1_001, 33_333_333_333];
+
+	const FEES: [(u32, u32); 4] = [(0, 1), (2, 1000), (7, 10000), (1, 3)];
+
+	fn product(a: Balance, b: Balance) -> U256 {
+		U256::from(a) * U256::from(b)
+	}
+
+	#[test]
+	fn calculate_fee_should_round_down() {
+		assert_eq!(calculate_fee(1_000, 2, 1000), Some(2));
+		assert_eq!(calculate_fee(1_499, 2, 1000), Some(2));
+		assert_eq!(calculate_fee(499, 2, 1000), Some(0));
+		assert_eq!(calculate_fee(Balance::MAX, 1, 1), Some(Balance::MAX));
+		assert_eq!(calculate_fee(Balance::MAX, 2, 1), None);
+		assert_eq!(calculate_fee(1_000, 2, 0), None);
+	}
+
+	#[test]
+	fn sell_should_never_decrease_pool_invariant() {
+		for &in_reserve in RESERVES.iter() {
+			for &out_reserve in RESERVES.iter() {
+				for &amount in AMOUNTS.iter() {
+					for &(numerator, denominator) in FEES.iter() {
+						if let Some((amount_out, fee)) =
+							calculate_out_given_in_with_fee(in_reserve, out_reserve, amount, numerator, denominator)
+						{
+							assert_eq!(fee, calculate_fee(amount, numerator, denominator).unwrap());
+							assert!(amount_out < out_reserve);
+							assert!(
+								product(in_reserve + amount, out_reserve - amount_out)
+									>= product(in_reserve, out_reserve),
+								"sell {} with reserves {}/{} and fee {}/{} decreased the invariant",
+								amount,
+								in_reserve,
+								out_reserve,
+								numerator,
+								denominator
+							);
+						}
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn buy_should_never_decrease_pool_invariant() {
+		for &in_reserve in RESERVES.iter() {
+			for &out_reserve in RESERVES.iter() {
+				for &amount in AMOUNTS.iter().filter(|&&a| a < out_reserve) {
+					for &(numerator, denominator) in FEES.iter() {
+						if let Some((amount_in, fee)) =
+							calculate_in_given_out_with_fee(out_reserve, in_reserve, amount, numerator, denominator)
+						{
+							assert_eq!(fee, calculate_fee(amount_in - fee, numerator, denominator).unwrap());
+
+							// Fee is excluded so that the curve alone is proven to round in favor of the pool.
+							assert!(
+								product(in_reserve + amount_in - fee, out_reserve - amount)
+									>= product(in_reserve, out_reserve),
+								"buy {} with reserves {}/{} and fee {}/{} decreased the invariant",
+								amount,
+								in_reserve,
+								out_reserve,
+								numerator,
+								denominator
+							);
+						}
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn buy_of_whole_reserve_should_not_work() {
+		assert_eq!(calculate_in_given_out_with_fee(1_000, 1_000, 1_000, 2, 1000), None);
+		assert_eq!(calculate_in_given_out_with_fee(1_000, 1_000, 1_001, 2, 1000), None);
+	}
+
+	const WEIGHTS: [(u32, u32); 5] = [(80, 20), (20, 80), (2, 98), (98, 2), (60, 40)];
+
+	#[test]
+	fn weighted_trade_with_equal_weights_should_be_constant_product() {
+		for &(in_reserve, out_reserve, amount) in [(1_000, 2_000, 10), (1_000_000_000_000, 7, 499)].iter() {
+			assert_eq!(
+				calculate_weighted_out_given_in_with_fee(in_reserve, 50, out_reserve, 50, amount, 2, 1000),
+				calculate_out_given_in_with_fee(in_reserve, out_reserve, amount, 2, 1000)
+			);
+			assert_eq!(
+				calculate_weighted_in_given_out_with_fee(out_reserve, 50, in_reserve, 50, amount, 2, 1000),
+				calculate_in_given_out_with_fee(out_reserve, in_reserve, amount, 2, 1000)
+			);
+		}
+	}
+
+	#[test]
+	fn weighted_trade_should_work() {
+		// (1000 / 2000) ^ (80 / 20) = 1 / 16
+		assert_eq!(
+			calculate_weighted_out_given_in_with_fee(1_000, 80, 1_600, 20, 1_000, 0, 1),
+			Some((1_500, 0))
+		);
+		// (1000 / 16000) ^ (20 / 80) = 1 / 2
+		assert_eq!(
+			calculate_weighted_out_given_in_with_fee(1_000, 20, 1_600, 80, 15_000, 0, 1),
+			Some((800, 0))
+		);
+		// (2000 / 1000) ^ (80 / 20) = 16
+		assert_eq!(
+			calculate_weighted_in_given_out_with_fee(2_000, 80, 1_000, 20, 1_000, 0, 1),
+			Some((15_000, 0))
+		);
+		// (1600 / 100) ^ (20 / 80) = 2
+		assert_eq!(
+			calculate_weighted_in_given_out_with_fee(1_600, 20, 1_000, 80, 1_500, 0, 1),
+			Some((1_000, 0))
+		);
+		assert_eq!(
+			calculate_weighted_out_given_in_with_fee(1_000, 80, 1_600, 20, 1_000, 2, 1000),
+			calculate_weighted_out_given_in_with_fee(1_000, 80, 1_600, 20, 998, 0, 1).map(|(amount, _)| (amount, 2))
+		);
+	}
+
+	#[test]
+	fn weighted_sell_should_not_exceed_spot_price() {
+		for &in_reserve in RESERVES.iter() {
+			for &out_reserve in RESERVES.iter() {
+				for &amount in AMOUNTS.iter() {
+					for &(in_weight, out_weight) in WEIGHTS.iter() {
+						if let Some((amount_out, _)) = calculate_weighted_out_given_in_with_fee(
+							in_reserve,
+							in_weight,
+							out_reserve,
+							out_weight,
+							amount,
+							0,
+							1,
+						) {
+							assert!(amount_out < out_reserve);
+							assert!(
+								product(amount_out, in_reserve) * U256::from(out_weight)
+									<= product(amount, out_reserve) * U256::from(in_weight),
+								"sell {} with reserves {}/{} and weights {}/{} exceeded spot price",
+								amount,
+								in_reserve,
+								out_reserve,
+								in_weight,
+								out_weight
+							);
+						}
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn weighted_buy_should_not_be_below_spot_price() {
+		for &in_reserve in RESERVES.iter() {
+			for &out_reserve in RESERVES.iter() {
+				for &amount in AMOUNTS.iter().filter(|&&a| a < out_reserve) {
+					for &(in_weight, out_weight) in WEIGHTS.iter() {
+						if let Some((amount_in, _)) = calculate_weighted_in_given_out_with_fee(
+							out_reserve,
+							out_weight,
+							in_reserve,
+							in_weight,
+							amount,
+							0,
+							1,
+						) {
+							assert!(
+								product(amount_in, out_reserve) * U256::from(in_weight)
+									>= product(amount, in_reserve) * U256::from(out_weight),
+								"buy {} with reserves {}/{} and weights {}/{} was below spot price",
+								amount,
+								in_reserve,
+								out_reserve,
+								in_weight,
+								out_weight
+							);
+						}
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn weighted_spot_price_should_work() {
+		assert_eq!(calculate_weighted_spot_price(1_000, 80, 1_000, 20, 10), Some(40));
+		assert_eq!(calculate_weighted_spot_price(1_000, 20, 1_000, 80, 10), Some(2));
+		assert_eq!(calculate_weighted_spot_price(0, 20, 1_000, 80, 10), None);
+		assert_eq!(
+			calculate_weighted_spot_price(1_000, 50, 3_000, 50, 10),
+			hydra_dx_math::calculate_spot_price(1_000, 3_000, 10).ok()
+		);
+	}
+
+	#[test]
+	fn pool_weight_should_be_validated() {
+		assert!(is_valid_pool_weight(50));
+		assert!(is_valid_pool_weight(MIN_POOL_WEIGHT));
+		assert!(is_valid_pool_weight(POOL_WEIGHT_TOTAL - MIN_POOL_WEIGHT));
+		assert!(!is_valid_pool_weight(MIN_POOL_WEIGHT - 1));
+		assert!(!is_valid_pool_weight(POOL_WEIGHT_TOTAL - MIN_POOL_WEIGHT + 1));
+		assert!(!is_valid_pool_weight(POOL_WEIGHT_TOTAL));
+	}
+
+	#[test]
+	fn liquidity_out_should_not_exceed_reserves() {
+		assert_eq!(calculate_liquidity_out(1_000, 3_000, 10, 100), (100, 300));
+		assert_eq!(calculate_liquidity_out(1_000, 3_000, 1, 3), (333, 1_000));
+		assert_eq!(calculate_liquidity_out(1_000, 3_000, 200, 100), (1_000, 3_000));
+		assert_eq!(
+			calculate_liquidity_out(Balance::MAX, Balance::MAX, Balance::MAX, Balance::MAX),
+			(Balance::MAX, Balance::MAX)
+		);
+		assert_eq!(calculate_liquidity_out(1_000, 3_000, 10, 0), (0, 0));
+	}
+
+	#[test]
+	fn trade_with_zero_fee_denominator_should_not_work() {
+		assert_eq!(calculate_out_given_in_with_fee(1_000, 1_000, 10, 2, 0), None);
+		assert_eq!(calculate_in_given_out_with_fee(1_000, 1_000, 10, 2, 0), None);
+	}
+}
@@ -0,0 +1,196 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::traits::{GetPalletVersion, PalletVersion};
+use frame_support::weights::Weight;
+
+/// Populate equal asset weights of pools created before weighted pools were introduced.
+pub fn populate_pool_weights<T: Config>() -> Weight {
+	let version = <Pallet<T> as GetPalletVersion>::storage_version();
+	if version.map_or(true, |v| v < PalletVersion::new(1, 2, 0)) {
+		let mut count: Weight = 0;
+		for (pool, _) in PoolAssets::<T>::iter() {
+			if !PoolWeights::<T>::contains_key(&pool) {
+				PoolWeights::<T>::insert(&pool, math::EQUAL_POOL_WEIGHTS);
+				count += 1;
+			}
+		}
+		T::DbWeight::get().reads_writes(count.saturating_mul(2).saturating_add(1), count)
+	} else {
+		0
+	}
+}
+
+/// Lock `MINIMUM_LIQUIDITY` shares of pools created before shares were locked on pool creation.
+///
+/// Locked shares are newly issued, so existing liquidity providers are diluted by `MINIMUM_LIQUIDITY` shares.
+pub fn lock_minimum_liquidity<T: Config>() -> Weight {
+	let version = <Pallet<T> as GetPalletVersion>::storage_version();
+	if version.map_or(true, |v| v < PalletVersion::new(1, 3, 0)) {
+		let locked_account = Pallet::<T>::locked_liquidity_account();
+		let mut count: Weight = 0;
+		for (pool, total_liquidity) in TotalLiquidity::<T>::iter() {
+			let share_token = ShareToken::<T>::get(&pool);
+			if T::Currency::deposit(share_token, &locked_account, MINIMUM_LIQUIDITY).is_ok() {
+				TotalLiquidity::<T>::insert(&pool, total_liquidity.saturating_add(MINIMUM_LIQUIDITY));
+			}
+			count += 1;
+		}
+		T::DbWeight::get().reads_writes(count.saturating_mul(3).saturating_add(1), count.saturating_mul(3))
+	} else {
+		0
+	}
+}
+
+/// Store pool assets as `OrderedAssetPair` ordered by asset id.
+///
+/// Weights of pools whose assets were stored in reverse order are swapped to keep following the pool assets.
+pub fn order_pool_assets<T: Config>() -> Weight {
+	let version = <Pallet<T> as GetPalletVersion>::storage_version();
+	if version.map_or(true, |v| v < PalletVersion::new(1, 4, 0)) {
+		let mut count: Weight = 0;
+		PoolAssets::<T>::translate::<(AssetId, AssetId), _>(|pool, (asset_a, asset_b)| {
+			count += 1;
+			if asset_a > asset_b {
+				PoolWeights::<T>::mutate(&pool, |weights| {
+					*weights = weights.map(|(weight_a, weight_b)| (weight_b, weight_a))
+				});
+			}
+			Some(OrderedAssetPair::new(asset_a, asset_b))
+		});
+		T::DbWeight::get().reads_writes(count.saturating_mul(2).saturating_add(1), count.saturating_mul(2))
+	} else {
+		0
+	}
+}
+
+/// Add accounts of pools created before pool accounts were whitelisted to the whitelist of non-dustable accounts.
+pub fn whitelist_pool_accounts<T: Config>() -> Weight {
+	let version = <Pallet<T> as GetPalletVersion>::storage_version();
+	if version.map_or(true, |v| v < PalletVersion::new(1, 5, 0)) {
+		let mut count: Weight = 0;
+		for (pool, _) in PoolAssets::<T>::iter() {
+			let _ = T::NonDustableWhitelistHandler::add_account(&pool);
+			count += 1;
+		}
+		T::DbWeight::get().reads_writes(count.saturating_add(1), count)
+	} else {
+		0
+	}
+}
+
+/// Check that pools have two different assets before pool assets are ordered.
+#[cfg(feature = "try-runtime")]
+pub fn pre_order_pool_assets<T: Config>() -> Result<(), &'static str> {
+	for (_, assets) in PoolAssets::<T>::iter() {
+		ensure!(assets.asset_a != assets.asset_b, "Pool assets must be different");
+	}
+	Ok(())
+}
+
+/// Check that pool assets are ordered and pool weights follow them after the migration.
+#[cfg(feature = "try-runtime")]
+pub fn post_order_pool_assets<T: Config>() -> Result<(), &'static str> {
+	for (pool, assets) in PoolAssets::<T>::iter() {
+		ensure!(assets.asset_a < assets.asset_b, "Pool assets must be ordered");
+		ensure!(
+			PoolWeights::<T>::get(&pool).map_or(false, |(weight_a, weight_b)| weight_a.saturating_add(weight_b)
+				== math::POOL_WEIGHT_TOTAL),
+			"Pool weights must be set"
+		);
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{non_dustable_accounts, ExtBuilder, Test};
+
+	#[test]
+	fn pool_weights_migration_should_work() {
+		ExtBuilder::default().build().execute_with(|| {
+			PoolAssets::<Test>::insert(1u64, OrderedAssetPair::new(1, 2));
+			PoolAssets::<Test>::insert(2u64, OrderedAssetPair::new(1, 3));
+			PoolWeights::<Test>::insert(2u64, (80u32, 20u32));
+
+			populate_pool_weights::<Test>();
+
+			assert_eq!(PoolWeights::<Test>::get(1u64), Some(math::EQUAL_POOL_WEIGHTS));
+			assert_eq!(PoolWeights::<Test>::get(2u64), Some((80, 20)));
+		})
+	}
+
+	#[test]
+	fn lock_minimum_liquidity_migration_should_work() {
+		ExtBuilder::default().build().execute_with(|| {
+			let share_token = 100u32;
+			ShareToken::<Test>::insert(1u64, share_token);
+			TotalLiquidity::<Test>::insert(1u64, 5_000u128);
+
+			lock_minimum_liquidity::<Test>();
+
+			assert_eq!(TotalLiquidity::<Test>::get(1u64), 5_000 + MINIMUM_LIQUIDITY);
+			assert_eq!(
+				<Test as Config>::Currency::free_balance(share_token, &Pallet::<Test>::locked_liquidity_account()),
+				MINIMUM_LIQUIDITY
+			);
+		})
+	}
+
+	#[test]
+	fn whitelist_pool_accounts_migration_should_work() {
+		ExtBuilder::default().build().execute_with(|| {
+			PoolAssets::<Test>::insert(1u64, OrderedAssetPair::new(1, 2));
+			PoolAssets::<Test>::insert(2u64, OrderedAssetPair::new(1, 3));
+
+			whitelist_pool_accounts::<Test>();
+
+			let mut accounts = non_dustable_accounts();
+			accounts.sort();
+			assert_eq!(accounts, vec![1, 2]);
+		})
+	}
+
+	#[test]
+	fn order_pool_assets_migration_should_work() {
+		ExtBuilder::default().build().execute_with(|| {
+			// Unordered pair has the same encoding as the tuple stored before v2
+			PoolAssets::<Test>::insert(1u64, OrderedAssetPair { asset_a: 2, asset_b: 1 });
+			PoolAssets::<Test>::insert(2u64, OrderedAssetPair { asset_a: 1, asset_b: 3 });
+			PoolWeights::<Test>::insert(1u64, (80u32, 20u32));
+			PoolWeights::<Test>::insert(2u64, (80u32, 20u32));
+
+			order_pool_assets::<Test>();
+
+			assert_eq!(PoolAssets::<Test>::get(1u64), OrderedAssetPair::new(1, 2));
+			assert_eq!(PoolAssets::<Test>::get(2u64), OrderedAssetPair::new(1, 3));
+			assert_eq!(PoolWeights::<Test>::get(1u64), Some((20, 80)));
+			assert_eq!(PoolWeights::<Test>::get(2u64), Some((80, 20)));
+
+			// Migration is not applied again once the pallet version is updated
+			PalletVersion::new(1, 4, 0).put_into_storage::<<Test as frame_system::Config>::PalletInfo, Pallet<Test>>();
+			PoolWeights::<Test>::insert(1u64, (80u32, 20u32));
+			PoolAssets::<Test>::insert(1u64, OrderedAssetPair { asset_a: 2, asset_b: 1 });
+
+			order_pool_assets::<Test>();
+
+			assert_eq!(PoolWeights::<Test>::get(1u64), Some((80, 20)));
+		})
+	}
+}
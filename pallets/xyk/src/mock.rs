@@ -18,6 +18,7 @@
 use crate as xyk;
 use crate::{AssetPairAccountIdFor, Config};
 use frame_support::parameter_types;
+use frame_support::weights::Weight;
 use frame_system as system;
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
@@ -27,18 +28,136 @@ use sp_runtime::{
 };
 
 use frame_support::traits::GenesisBuild;
-use primitives::{fee, AssetId, Balance};
+use primitives::{
+	asset::AssetPair,
+	fee,
+	traits::{
+		AMMHandlers, AMMTransfer, DustRemovalAccountWhitelist, FeeDiscount, LiquidityChange, LiquidityChangeKind,
+	},
+	AssetId, Balance, IntentionType, Price,
+};
+use sp_runtime::{DispatchError, DispatchResult};
+use std::cell::RefCell;
 
 pub type Amount = i128;
 pub type AccountId = u64;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const TREASURY: AccountId = 3;
 
 pub const HDX: AssetId = 1000;
 pub const DOT: AssetId = 2000;
 pub const ACA: AssetId = 3000;
 
+/// Call of AMM handler recorded by `TestAMMHandler`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AMMHandlerCall {
+	/// [pool, trade type, who, assets, amount, amount out]
+	Trade(AccountId, IntentionType, AccountId, AssetPair, Balance, Balance),
+	/// [pool, kind, who, amount a, amount b, shares]
+	LiquidityChanged(AccountId, LiquidityChangeKind, AccountId, Balance, Balance, Balance),
+}
+
+thread_local! {
+	static AMM_HANDLER_CALLS: RefCell<Vec<AMMHandlerCall>> = RefCell::new(vec![]);
+}
+
+pub fn amm_handler_calls() -> Vec<AMMHandlerCall> {
+	AMM_HANDLER_CALLS.with(|calls| calls.borrow().clone())
+}
+
+pub const AMM_HANDLER_TRADE_WEIGHT: Weight = 1_000;
+
+thread_local! {
+	static AMM_HANDLER_REJECTS_TRADES: RefCell<bool> = RefCell::new(false);
+}
+
+/// Make `TestAMMHandler` reject all trades in `on_before_trade`.
+pub fn set_amm_handler_rejects_trades(reject: bool) {
+	AMM_HANDLER_REJECTS_TRADES.with(|v| *v.borrow_mut() = reject);
+}
+
+pub struct TestAMMHandler;
+impl AMMHandlers<AccountId, AssetId, AssetPair, Balance> for TestAMMHandler {
+	fn on_before_trade(
+		_pool: &AccountId,
+		_trade_type: IntentionType,
+		_transfer: &AMMTransfer<AccountId, AssetPair, Balance>,
+	) -> DispatchResult {
+		if AMM_HANDLER_REJECTS_TRADES.with(|v| *v.borrow()) {
+			return Err(DispatchError::Other("trade rejected"));
+		}
+		Ok(())
+	}
+
+	fn on_trade(pool: &AccountId, trade_type: IntentionType, transfer: &AMMTransfer<AccountId, AssetPair, Balance>) {
+		AMM_HANDLER_CALLS.with(|calls| {
+			calls.borrow_mut().push(AMMHandlerCall::Trade(
+				*pool,
+				trade_type,
+				transfer.origin,
+				transfer.assets,
+				transfer.amount,
+				transfer.amount_out,
+			))
+		});
+	}
+
+	fn on_liquidity_changed(pool: &AccountId, change: &LiquidityChange<AccountId, AssetId, Balance>) {
+		AMM_HANDLER_CALLS.with(|calls| {
+			calls.borrow_mut().push(AMMHandlerCall::LiquidityChanged(
+				*pool,
+				change.kind,
+				change.who,
+				change.amount_a,
+				change.amount_b,
+				change.shares,
+			))
+		});
+	}
+
+	fn on_trade_weight() -> Weight {
+		AMM_HANDLER_TRADE_WEIGHT
+	}
+}
+
+thread_local! {
+	static NON_DUSTABLE_ACCOUNTS: RefCell<Vec<AccountId>> = RefCell::new(vec![]);
+}
+
+pub fn non_dustable_accounts() -> Vec<AccountId> {
+	NON_DUSTABLE_ACCOUNTS.with(|accounts| accounts.borrow().clone())
+}
+
+pub struct TestDustRemovalWhitelist;
+impl DustRemovalAccountWhitelist<AccountId> for TestDustRemovalWhitelist {
+	fn add_account(account: &AccountId) -> DispatchResult {
+		NON_DUSTABLE_ACCOUNTS.with(|accounts| accounts.borrow_mut().push(*account));
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> DispatchResult {
+		NON_DUSTABLE_ACCOUNTS.with(|accounts| accounts.borrow_mut().retain(|a| a != account));
+		Ok(())
+	}
+}
+
+thread_local! {
+	static DISCOUNTED_ACCOUNTS: RefCell<Vec<(AccountId, fee::Fee)>> = RefCell::new(vec![]);
+}
+
+pub fn set_fee_discount(who: AccountId, fee: fee::Fee) {
+	DISCOUNTED_ACCOUNTS.with(|accounts| accounts.borrow_mut().push((who, fee)));
+}
+
+pub struct TestFeeDiscount;
+impl FeeDiscount<AccountId> for TestFeeDiscount {
+	fn trade_fee(who: &AccountId) -> Option<fee::Fee> {
+		DISCOUNTED_ACCOUNTS.with(|accounts| accounts.borrow().iter().find(|(a, _)| a == who).map(|(_, f)| *f))
+	}
+}
+
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -49,9 +168,10 @@ frame_support::construct_runtime!(
 	 UncheckedExtrinsic = UncheckedExtrinsic,
 	 {
 		 System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		 XYK: xyk::{Pallet, Call, Storage, Event<T>},
+		 XYK: xyk::{Pallet, Call, Storage, Event<T>, Config<T>},
 		 Currency: orml_tokens::{Pallet, Event<T>},
-		 AssetRegistry: pallet_asset_registry::{Pallet, Storage},
+		 AssetRegistry: pallet_asset_registry::{Pallet, Storage, Event<T>},
+		 Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
 	 }
 
 );
@@ -61,10 +181,17 @@ parameter_types! {
 	pub const SS58Prefix: u8 = 63;
 	pub const NativeAssetId: AssetId = HDX;
 	pub ExchangeFeeRate: fee::Fee = fee::Fee::default();
+	pub ProtocolFeeRate: fee::Fee = fee::Fee { numerator: 0, denominator: 1 };
+	pub FlashLoanFeeRate: fee::Fee = fee::Fee { numerator: 1, denominator: 100 };
+	pub const TreasuryAccount: AccountId = TREASURY;
 }
 
 impl pallet_asset_registry::Config for Test {
+	type Event = Event;
 	type AssetId = AssetId;
+	type AssetNativeLocation = primitives::asset::AssetLocation;
+	type RegistryOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = ();
 }
 
 impl system::Config for Test {
@@ -131,10 +258,45 @@ impl Config for Test {
 	type NativeAssetId = NativeAssetId;
 	type WeightInfo = ();
 	type GetExchangeFee = ExchangeFeeRate;
+	type DefaultProtocolFee = ProtocolFeeRate;
+	type TreasuryAccount = TreasuryAccount;
+	type ProtocolFeeOrigin = frame_system::EnsureRoot<AccountId>;
+	type PoolOwnerInactivityPeriod = PoolOwnerInactivityPeriod;
+	type MaxPoolDust = MaxPoolDust;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type PoolGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type Scheduler = Scheduler;
+	type AuditLog = ();
+	type AMMHandler = TestAMMHandler;
+	type FlashLoanFee = FlashLoanFeeRate;
+	type NonDustableWhitelistHandler = TestDustRemovalWhitelist;
+	type FeeDiscount = TestFeeDiscount;
+}
+
+parameter_types! {
+	pub const PoolOwnerInactivityPeriod: u64 = 100;
+	pub const MaxPoolDust: Balance = 2_000;
+	pub const MinPoolLiquidity: Balance = 2_000;
+	pub const MaximumSchedulerWeight: u64 = 1_000_000_000;
+	pub const MaxScheduledPerBlock: u32 = 50;
+}
+
+impl pallet_scheduler::Config for Test {
+	type Event = Event;
+	type Origin = Origin;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type WeightInfo = ();
 }
 
 pub struct ExtBuilder {
 	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	pools: Vec<(AccountId, AssetId, AssetId, Balance, Price)>,
 }
 
 // Returns default values for genesis config
@@ -149,6 +311,7 @@ impl Default for ExtBuilder {
 				(ALICE, DOT, 1000_000_000_000_000u128),
 				(BOB, DOT, 1000_000_000_000_000u128),
 			],
+			pools: vec![],
 		}
 	}
 }
@@ -161,6 +324,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_pools(mut self, pools: Vec<(AccountId, AssetId, AssetId, Balance, Price)>) -> Self {
+		self.pools = pools;
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
 
@@ -170,6 +338,10 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		xyk::GenesisConfig::<Test> { pools: self.pools }
+			.assimilate_storage(&mut t)
+			.unwrap();
+
 		t.into()
 	}
 }
@@ -16,10 +16,17 @@
 // limitations under the License.
 
 use super::*;
-pub use crate::mock::{Currency, Event as TestEvent, ExtBuilder, Origin, System, Test, ACA, ALICE, BOB, DOT, HDX, XYK};
+pub use crate::mock::{
+	amm_handler_calls, non_dustable_accounts, set_amm_handler_rejects_trades, set_fee_discount, AMMHandlerCall,
+	Call as TestCall, Currency, Event as TestEvent, ExchangeFeeRate, ExtBuilder, Origin, Scheduler, System, Test, ACA,
+	ALICE, AMM_HANDLER_TRADE_WEIGHT, BOB, DOT, HDX, TREASURY, XYK,
+};
+use frame_support::sp_runtime::FixedPointNumber;
+use frame_support::traits::OnInitialize;
 use frame_support::{assert_noop, assert_ok};
 use hydra_dx_math::MathError;
 use primitives::traits::AMM as AmmPool;
+use test_utils::{assert_balance, assert_event_emitted, assert_pool_reserves};
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut ext = ExtBuilder::default().build();
@@ -27,18 +34,26 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	ext
 }
 
-fn last_events(n: usize) -> Vec<TestEvent> {
-	frame_system::Pallet::<Test>::events()
-		.into_iter()
-		.rev()
-		.take(n)
-		.rev()
-		.map(|e| e.event)
-		.collect()
+fn expect_events(e: Vec<TestEvent>) {
+	test_utils::expect_events::<Test>(e);
 }
 
-fn expect_events(e: Vec<TestEvent>) {
-	assert_eq!(last_events(e.len()), e);
+fn trade_fees(lp_fee: Balance, discount: Balance) -> TradeFees<Balance> {
+	TradeFees {
+		lp_fee,
+		protocol_fee: 0,
+		discount,
+	}
+}
+
+fn last_sell_fees() -> Option<TradeFees<Balance>> {
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|record| match record.event {
+			TestEvent::xyk(Event::SellExecuted(_, _, _, _, _, _, fees)) => Some(fees),
+			_ => None,
+		})
 }
 
 #[test]
@@ -64,7 +79,11 @@ fn create_pool_should_work() {
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 1000000000000000);
 		assert_eq!(Currency::free_balance(asset_a, &ALICE), 900000000000000);
 		assert_eq!(Currency::free_balance(asset_b, &ALICE), 0);
-		assert_eq!(Currency::free_balance(share_token, &ALICE), 100000000000000);
+		assert_eq!(Currency::free_balance(share_token, &ALICE), 99999999999000);
+		assert_eq!(
+			Currency::free_balance(share_token, &XYK::locked_liquidity_account()),
+			MINIMUM_LIQUIDITY
+		);
 		assert_eq!(XYK::total_liquidity(&pair_account), 100000000000000);
 
 		expect_events(vec![Event::PoolCreated(ALICE, asset_a, asset_b, 100000000000000).into()]);
@@ -82,14 +101,14 @@ fn create_same_pool_should_not_work() {
 			Origin::signed(user),
 			asset_b,
 			asset_a,
-			100,
+			100_000,
 			Price::from(2)
 		));
 		assert_noop!(
-			XYK::create_pool(Origin::signed(user), asset_b, asset_a, 100, Price::from(2)),
+			XYK::create_pool(Origin::signed(user), asset_b, asset_a, 100_000, Price::from(2)),
 			Error::<Test>::TokenPoolAlreadyExists
 		);
-		expect_events(vec![Event::PoolCreated(ALICE, asset_b, asset_a, 200).into()]);
+		expect_events(vec![Event::PoolCreated(ALICE, asset_b, asset_a, 200_000).into()]);
 	});
 }
 
@@ -145,7 +164,7 @@ fn add_liquidity_should_work() {
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 1004000000000);
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 100400000);
 		assert_eq!(Currency::free_balance(asset_a, &user), 999999899600000);
-		assert_eq!(Currency::free_balance(share_token, &user), 1004000000000);
+		assert_eq!(Currency::free_balance(share_token, &user), 1003999999000);
 		assert_eq!(XYK::total_liquidity(&pair_account), 1004000000000);
 
 		expect_events(vec![
@@ -185,7 +204,7 @@ fn add_liquidity_as_another_user_should_work() {
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 1004000000000);
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 100400000);
 		assert_eq!(Currency::free_balance(asset_b, &user), 999999899600000);
-		assert_eq!(Currency::free_balance(share_token, &user), 1004000000000);
+		assert_eq!(Currency::free_balance(share_token, &user), 1003999999000);
 		assert_eq!(XYK::total_liquidity(&pair_account), 1004000000000);
 
 		assert_ok!(XYK::add_liquidity(
@@ -200,7 +219,7 @@ fn add_liquidity_as_another_user_should_work() {
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 101400000);
 		assert_eq!(Currency::free_balance(asset_b, &user), 999999899600000);
 		assert_eq!(Currency::free_balance(asset_b, &BOB), 999999999000000);
-		assert_eq!(Currency::free_balance(share_token, &user), 1004000000000);
+		assert_eq!(Currency::free_balance(share_token, &user), 1003999999000);
 		assert_eq!(Currency::free_balance(share_token, &BOB), 10000000000);
 		assert_eq!(XYK::total_liquidity(&pair_account), 1014000000000);
 
@@ -233,17 +252,24 @@ fn remove_liquidity_should_work() {
 		});
 		let share_token = XYK::share_token(pair_account);
 
-		assert_eq!(Currency::free_balance(share_token, &user), 100000000);
+		assert_eq!(Currency::free_balance(share_token, &user), 99999000);
 		assert_eq!(Currency::free_balance(asset_a, &user), 999999900000000);
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 100000000);
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 1000000000000);
 
-		assert_ok!(XYK::remove_liquidity(Origin::signed(user), asset_a, asset_b, 355_000));
+		assert_ok!(XYK::remove_liquidity(
+			Origin::signed(user),
+			asset_a,
+			asset_b,
+			355_000,
+			0,
+			0
+		));
 
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 996450000000);
 		assert_eq!(Currency::free_balance(asset_a, &user), 999999900355000);
 
-		assert_eq!(Currency::free_balance(share_token, &user), 99645000);
+		assert_eq!(Currency::free_balance(share_token, &user), 99644000);
 		assert_eq!(XYK::total_liquidity(&pair_account), 99645000);
 
 		expect_events(vec![
@@ -253,6 +279,120 @@ fn remove_liquidity_should_work() {
 	});
 }
 
+#[test]
+fn calculate_liquidity_out_should_match_removed_liquidity() {
+	new_test_ext().execute_with(|| {
+		let user = ALICE;
+		let asset_a = HDX;
+		let asset_b = DOT;
+		let assets = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(user),
+			asset_a,
+			asset_b,
+			100_000_000,
+			Price::from(10_000)
+		));
+		assert_ok!(XYK::sell(Origin::signed(BOB), asset_a, asset_b, 1_000_000, 0, false));
+
+		let (amount_a, amount_b) = XYK::calculate_liquidity_out_for(assets, 355_000).unwrap();
+
+		let balance_a = Currency::free_balance(asset_a, &user);
+		let balance_b = Currency::free_balance(asset_b, &user);
+
+		assert_ok!(XYK::remove_liquidity(
+			Origin::signed(user),
+			asset_a,
+			asset_b,
+			355_000,
+			0,
+			0
+		));
+
+		assert_eq!(Currency::free_balance(asset_a, &user), balance_a + amount_a);
+		assert_eq!(Currency::free_balance(asset_b, &user), balance_b + amount_b);
+	});
+}
+
+#[test]
+fn calculate_liquidity_out_should_fail_when_withdrawal_is_not_possible() {
+	new_test_ext().execute_with(|| {
+		let assets = AssetPair {
+			asset_in: HDX,
+			asset_out: DOT,
+		};
+
+		assert_noop!(
+			XYK::calculate_liquidity_out_for(assets, 1_000),
+			Error::<Test>::TokenPoolNotFound
+		);
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			DOT,
+			100_000_000,
+			Price::from(10_000)
+		));
+
+		assert_noop!(
+			XYK::calculate_liquidity_out_for(assets, 0),
+			Error::<Test>::CannotRemoveLiquidityWithZero
+		);
+		assert_noop!(
+			XYK::calculate_liquidity_out_for(assets, 100_000_001),
+			Error::<Test>::InsufficientAssetBalance
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_with_min_amounts_should_work() {
+	new_test_ext().execute_with(|| {
+		let user = ALICE;
+		let asset_a = HDX;
+		let asset_b = DOT;
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(user),
+			asset_a,
+			asset_b,
+			100_000_000,
+			Price::from(10_000)
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		});
+
+		assert_noop!(
+			XYK::remove_liquidity(Origin::signed(user), asset_a, asset_b, 355_000, 355_001, 0),
+			Error::<Test>::AssetBalanceLimitExceeded
+		);
+		assert_noop!(
+			XYK::remove_liquidity(Origin::signed(user), asset_a, asset_b, 355_000, 0, 3_550_000_001),
+			Error::<Test>::AssetBalanceLimitExceeded
+		);
+
+		assert_ok!(XYK::remove_liquidity(
+			Origin::signed(user),
+			asset_a,
+			asset_b,
+			355_000,
+			355_000,
+			3_550_000_000
+		));
+
+		assert_eq!(Currency::free_balance(asset_a, &pair_account), 99645000);
+		assert_eq!(Currency::free_balance(asset_b, &pair_account), 996450000000);
+	});
+}
+
 #[test]
 fn add_liquidity_more_than_owner_should_not_work() {
 	new_test_ext().execute_with(|| {
@@ -276,7 +416,13 @@ fn add_liquidity_more_than_owner_should_not_work() {
 #[test]
 fn add_zero_liquidity_should_not_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(XYK::create_pool(Origin::signed(ALICE), HDX, ACA, 100, Price::from(1)));
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			ACA,
+			100_000,
+			Price::from(1)
+		));
 
 		assert_noop!(
 			XYK::add_liquidity(Origin::signed(ALICE), HDX, ACA, 0, 0),
@@ -294,7 +440,7 @@ fn add_zero_liquidity_should_not_work() {
 fn remove_zero_liquidity_should_not_work() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			XYK::remove_liquidity(Origin::signed(ALICE), HDX, ACA, 0),
+			XYK::remove_liquidity(Origin::signed(ALICE), HDX, ACA, 0, 0, 0),
 			Error::<Test>::CannotRemoveLiquidityWithZero
 		);
 	});
@@ -323,7 +469,7 @@ fn sell_test() {
 
 		assert_eq!(Currency::free_balance(asset_a, &user_1), 999800000000000);
 		assert_eq!(Currency::free_balance(asset_b, &user_1), 400000000000000);
-		assert_eq!(Currency::free_balance(share_token, &user_1), 600000000000000);
+		assert_eq!(Currency::free_balance(share_token, &user_1), 599999999999000);
 
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200000000000);
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 600000000000000);
@@ -339,17 +485,280 @@ fn sell_test() {
 
 		assert_eq!(Currency::free_balance(asset_a, &user_1), 999799543555322);
 		assert_eq!(Currency::free_balance(asset_b, &user_1), 401363489802256);
-		assert_eq!(Currency::free_balance(share_token, &user_1), 600000000000000);
+		assert_eq!(Currency::free_balance(share_token, &user_1), 599999999999000);
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200456444678);
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 598636510197744);
 
 		expect_events(vec![
 			Event::PoolCreated(ALICE, asset_a, asset_b, 600000000000000).into(),
-			Event::SellExecuted(ALICE, asset_a, asset_b, 456444678, 1363489802256).into(),
+			Event::SellExecuted(
+				ALICE,
+				asset_a,
+				asset_b,
+				456444678,
+				1363489802256,
+				TradeSource::Direct,
+				trade_fees(912_889, 0),
+			)
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn amm_handler_should_be_notified_about_trades_and_liquidity_changes() {
+	new_test_ext().execute_with(|| {
+		let asset_a = ACA;
+		let asset_b = DOT;
+		let assets = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			200_000_000_000,
+			Price::from(3000)
+		));
+
+		let pair_account = XYK::get_pair_id(assets);
+
+		assert_ok!(XYK::sell(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			456_444_678,
+			1000000000000,
+			false,
+		));
+
+		let buy = XYK::validate_buy(&ALICE, assets, 1_000_000_000, 1_000_000_000, false).unwrap();
+
+		assert_ok!(XYK::buy(
+			Origin::signed(ALICE),
+			asset_b,
+			asset_a,
+			1_000_000_000,
+			1_000_000_000,
+			false,
+		));
+
+		let balance_a = Currency::free_balance(asset_a, &ALICE);
+		let balance_b = Currency::free_balance(asset_b, &ALICE);
+
+		assert_ok!(XYK::remove_liquidity(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			599999999999000,
+			0,
+			0
+		));
+
+		let removed_a = Currency::free_balance(asset_a, &ALICE) - balance_a;
+		let removed_b = Currency::free_balance(asset_b, &ALICE) - balance_b;
+
+		assert_eq!(
+			amm_handler_calls(),
+			vec![
+				AMMHandlerCall::LiquidityChanged(
+					pair_account,
+					LiquidityChangeKind::Added,
+					ALICE,
+					200_000_000_000,
+					600000000000000,
+					600000000000000
+				),
+				AMMHandlerCall::Trade(
+					pair_account,
+					IntentionType::SELL,
+					ALICE,
+					assets,
+					456444678,
+					1363489802256
+				),
+				AMMHandlerCall::Trade(
+					pair_account,
+					IntentionType::BUY,
+					ALICE,
+					assets,
+					buy.amount,
+					buy.amount_out
+				),
+				AMMHandlerCall::LiquidityChanged(
+					pair_account,
+					LiquidityChangeKind::Removed,
+					ALICE,
+					removed_a,
+					removed_b,
+					599999999999000
+				),
+			]
+		);
+	});
+}
+
+#[test]
+fn trade_weight_should_include_amm_handler_weight() {
+	assert_eq!(
+		Call::<Test>::sell(HDX, DOT, 1_000, 0, false).get_dispatch_info().weight,
+		<() as WeightInfo>::sell() + AMM_HANDLER_TRADE_WEIGHT
+	);
+	assert_eq!(
+		Call::<Test>::buy(HDX, DOT, 1_000, 1_000_000, false)
+			.get_dispatch_info()
+			.weight,
+		<() as WeightInfo>::buy() + AMM_HANDLER_TRADE_WEIGHT
+	);
+}
+
+#[test]
+fn trade_rejected_by_amm_handler_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			DOT,
+			200_000_000_000,
+			Price::from(3000)
+		));
+
+		set_amm_handler_rejects_trades(true);
+
+		assert_noop!(
+			XYK::sell(Origin::signed(ALICE), HDX, DOT, 456_444_678, 0, false),
+			DispatchError::Other("trade rejected")
+		);
+		assert_noop!(
+			XYK::buy(Origin::signed(ALICE), HDX, DOT, 66_666_666, 1_000_000_000_000, false),
+			DispatchError::Other("trade rejected")
+		);
+
+		set_amm_handler_rejects_trades(false);
+
+		assert_ok!(XYK::sell(Origin::signed(ALICE), HDX, DOT, 456_444_678, 0, false));
+	});
+}
+
+#[test]
+fn sell_with_protocol_fee_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = ALICE;
+		let asset_a = ACA;
+		let asset_b = DOT;
+
+		assert_ok!(XYK::set_protocol_fee(
+			Origin::root(),
+			fee::Fee {
+				numerator: 1,
+				denominator: 6
+			}
+		));
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(user_1),
+			asset_a,
+			asset_b,
+			200_000_000_000,
+			Price::from(3000)
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		});
+
+		assert_ok!(XYK::sell(
+			Origin::signed(user_1),
+			asset_a,
+			asset_b,
+			456_444_678,
+			1000000000000,
+			false,
+		));
+
+		assert_eq!(Currency::free_balance(asset_a, &user_1), 999799543555322);
+		assert_eq!(Currency::free_balance(asset_b, &user_1), 401363489802256);
+		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200456292530);
+		assert_eq!(Currency::free_balance(asset_b, &pair_account), 598636510197744);
+		assert_eq!(Currency::free_balance(asset_a, &TREASURY), 152148);
+
+		expect_events(vec![
+			Event::PoolCreated(ALICE, asset_a, asset_b, 600000000000000).into(),
+			Event::SellExecuted(
+				ALICE,
+				asset_a,
+				asset_b,
+				456444678,
+				1363489802256,
+				TradeSource::Direct,
+				trade_fees(912_889, 0),
+			)
+			.into(),
 		]);
 	});
 }
 
+#[test]
+fn set_protocol_fee_should_work() {
+	new_test_ext().execute_with(|| {
+		let protocol_fee = fee::Fee {
+			numerator: 1,
+			denominator: 6,
+		};
+
+		assert_ok!(XYK::set_protocol_fee(Origin::root(), protocol_fee));
+
+		assert_eq!(XYK::protocol_fee(), protocol_fee);
+
+		expect_events(vec![Event::ProtocolFeeUpdated(protocol_fee).into()]);
+	});
+}
+
+#[test]
+fn set_protocol_fee_with_invalid_fee_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::set_protocol_fee(
+				Origin::root(),
+				fee::Fee {
+					numerator: 7,
+					denominator: 6
+				}
+			),
+			Error::<Test>::InvalidProtocolFee
+		);
+		assert_noop!(
+			XYK::set_protocol_fee(
+				Origin::root(),
+				fee::Fee {
+					numerator: 0,
+					denominator: 0
+				}
+			),
+			Error::<Test>::InvalidProtocolFee
+		);
+	});
+}
+
+#[test]
+fn set_protocol_fee_by_non_root_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::set_protocol_fee(
+				Origin::signed(ALICE),
+				fee::Fee {
+					numerator: 1,
+					denominator: 6
+				}
+			),
+			sp_runtime::traits::BadOrigin
+		);
+	});
+}
+
 #[test]
 fn work_flow_happy_path_should_work() {
 	new_test_ext().execute_with(|| {
@@ -396,7 +805,7 @@ fn work_flow_happy_path_should_work() {
 		assert_eq!(Currency::free_balance(asset_a, &user_2), 1_000_000_000_000_000);
 		assert_eq!(Currency::free_balance(asset_b, &user_2), 1_000_000_000_000_000);
 
-		assert_eq!(Currency::free_balance(share_token, &user_1), 350_000_000_000);
+		assert_eq!(Currency::free_balance(share_token, &user_1), 349_999_999_000);
 		assert_eq!(Currency::free_balance(share_token, &user_2), 0);
 
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 350_000_000_000);
@@ -421,7 +830,7 @@ fn work_flow_happy_path_should_work() {
 		assert_eq!(Currency::free_balance(asset_a, &user_2), 999_700_000_000_000);
 		assert_eq!(Currency::free_balance(asset_b, &user_2), 988_000_000_000_000);
 
-		assert_eq!(Currency::free_balance(share_token, &user_1), 350_000_000_000);
+		assert_eq!(Currency::free_balance(share_token, &user_1), 349_999_999_000);
 		assert_eq!(Currency::free_balance(share_token, &user_2), 300_000_000_000);
 
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 650_000_000_000);
@@ -443,7 +852,7 @@ fn work_flow_happy_path_should_work() {
 		assert_eq!(Currency::free_balance(asset_a, &user_2), 999_483_333_333_334);
 		assert_eq!(Currency::free_balance(asset_b, &user_2), 994_490_245_122_554);
 
-		assert_eq!(Currency::free_balance(share_token, &user_1), 350_000_000_000);
+		assert_eq!(Currency::free_balance(share_token, &user_1), 349_999_999_000);
 		assert_eq!(Currency::free_balance(share_token, &user_2), 300_000_000_000);
 
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 866_666_666_666);
@@ -468,13 +877,20 @@ fn work_flow_happy_path_should_work() {
 		assert_eq!(user_2_original_balance_1, 999_483_333_333_334);
 		assert_eq!(user_2_original_balance_2, 994_490_245_122_554);
 
-		assert_eq!(Currency::free_balance(share_token, &user_1), 350_000_000_000);
+		assert_eq!(Currency::free_balance(share_token, &user_1), 349_999_999_000);
 		assert_eq!(Currency::free_balance(share_token, &user_2), 300_000_000_000);
 
 		// User 2 removes liquidity
 
-		assert_ok!(XYK::remove_liquidity(Origin::signed(user_2), asset_a, asset_b, 10_000));
-
+		assert_ok!(XYK::remove_liquidity(
+			Origin::signed(user_2),
+			asset_a,
+			asset_b,
+			10_000,
+			0,
+			0
+		));
+
 		let user_2_remove_1_balance_1 = Currency::free_balance(asset_a, &user_2);
 		let user_2_remove_1_balance_2 = Currency::free_balance(asset_b, &user_2);
 
@@ -482,7 +898,14 @@ fn work_flow_happy_path_should_work() {
 		assert_eq!(user_2_remove_1_balance_2, 994_490_245_347_779);
 		assert_eq!(Currency::free_balance(share_token, &user_2), 299_999_990_000);
 
-		assert_ok!(XYK::remove_liquidity(Origin::signed(user_2), asset_b, asset_a, 10_000));
+		assert_ok!(XYK::remove_liquidity(
+			Origin::signed(user_2),
+			asset_b,
+			asset_a,
+			10_000,
+			0,
+			0
+		));
 
 		let user_2_remove_2_balance_1 = Currency::free_balance(asset_a, &user_2);
 		let user_2_remove_2_balance_2 = Currency::free_balance(asset_b, &user_2);
@@ -505,7 +928,14 @@ fn work_flow_happy_path_should_work() {
 
 		assert_eq!(XYK::total_liquidity(&pair_account), 649_999_980_000);
 
-		assert_ok!(XYK::remove_liquidity(Origin::signed(user_2), asset_a, asset_b, 18_000));
+		assert_ok!(XYK::remove_liquidity(
+			Origin::signed(user_2),
+			asset_a,
+			asset_b,
+			18_000,
+			0,
+			0
+		));
 		assert_eq!(Currency::free_balance(share_token, &user_2), 299_999_962_000);
 
 		assert_eq!(XYK::total_liquidity(&pair_account), 649_999_962_000);
@@ -513,8 +943,26 @@ fn work_flow_happy_path_should_work() {
 		expect_events(vec![
 			Event::PoolCreated(user_1, asset_a, asset_b, 350_000_000_000).into(),
 			Event::LiquidityAdded(user_2, asset_a, asset_b, 300_000_000_000, 12_000_000_000_000).into(),
-			Event::SellExecuted(user_2, asset_a, asset_b, 216_666_666_666, 6_490_245_122_554).into(),
-			Event::SellExecuted(ALICE, asset_a, asset_b, 288_888_888_888, 4_870_118_901_375).into(),
+			Event::SellExecuted(
+				user_2,
+				asset_a,
+				asset_b,
+				216_666_666_666,
+				6_490_245_122_554,
+				TradeSource::Direct,
+				trade_fees(433_333_333, 0),
+			)
+			.into(),
+			Event::SellExecuted(
+				ALICE,
+				asset_a,
+				asset_b,
+				288_888_888_888,
+				4_870_118_901_375,
+				TradeSource::Direct,
+				trade_fees(577_777_777, 0),
+			)
+			.into(),
 			Event::LiquidityRemoved(user_2, asset_a, asset_b, 10_000).into(),
 			Event::LiquidityRemoved(user_2, asset_b, asset_a, 10_000).into(),
 			Event::LiquidityRemoved(user_2, asset_a, asset_b, 18_000).into(),
@@ -568,7 +1016,7 @@ fn sell_with_correct_fees_should_work() {
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 10000000);
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 2000000000);
 
-		assert_eq!(Currency::free_balance(share_token, &user_1), 2000000000);
+		assert_eq!(Currency::free_balance(share_token, &user_1), 1999999000);
 
 		assert_ok!(XYK::sell(
 			Origin::signed(user_1),
@@ -586,7 +1034,16 @@ fn sell_with_correct_fees_should_work() {
 		assert_eq!(Currency::free_balance(asset_b, &user_1), 999998019762768,);
 		expect_events(vec![
 			Event::PoolCreated(user_1, asset_a, asset_b, 2000000000).into(),
-			Event::SellExecuted(user_1, asset_a, asset_b, 100000, 19762768).into(),
+			Event::SellExecuted(
+				user_1,
+				asset_a,
+				asset_b,
+				100000,
+				19762768,
+				TradeSource::Direct,
+				trade_fees(200, 0),
+			)
+			.into(),
 		]);
 	});
 }
@@ -656,7 +1113,16 @@ fn discount_sell_fees_should_work() {
 			Event::PoolCreated(user_1, asset_a, HDX, 10_000).into(),
 			frame_system::Event::NewAccount(pair_account).into(),
 			Event::PoolCreated(user_1, asset_a, asset_b, 60_000).into(),
-			Event::SellExecuted(user_1, asset_a, asset_b, 10_000, 14_993).into(),
+			Event::SellExecuted(
+				user_1,
+				asset_a,
+				asset_b,
+				10_000,
+				14_993,
+				TradeSource::Direct,
+				trade_fees(7, 14),
+			)
+			.into(),
 		]);
 	});
 }
@@ -684,7 +1150,7 @@ fn single_buy_should_work() {
 
 		assert_eq!(Currency::free_balance(asset_a, &user_1), 999_999_800_000_000);
 		assert_eq!(Currency::free_balance(asset_b, &user_1), 999_360_000_000_000);
-		assert_eq!(Currency::free_balance(share_token, &user_1), 640_000_000_000);
+		assert_eq!(Currency::free_balance(share_token, &user_1), 639_999_999_000);
 
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200_000_000);
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 640_000_000_000);
@@ -693,20 +1159,29 @@ fn single_buy_should_work() {
 			Origin::signed(user_1),
 			asset_a,
 			asset_b,
-			66_666_666,
+			40_000_001,
 			1_000_000_000_000,
 			false,
 		));
 
-		assert_eq!(Currency::free_balance(asset_a, &user_1), 999_999_866_666_666);
-		assert_eq!(Currency::free_balance(asset_b, &user_1), 999_039_360_004_809);
-		assert_eq!(Currency::free_balance(share_token, &user_1), 640_000_000_000);
-		assert_eq!(Currency::free_balance(asset_a, &pair_account), 133_333_334);
-		assert_eq!(Currency::free_balance(asset_b, &pair_account), 960_639_995_191);
+		assert_eq!(Currency::free_balance(asset_a, &user_1), 999_999_840_000_001);
+		assert_eq!(Currency::free_balance(asset_b, &user_1), 999_199_679_994_989);
+		assert_eq!(Currency::free_balance(share_token, &user_1), 639_999_999_000);
+		assert_eq!(Currency::free_balance(asset_a, &pair_account), 159_999_999);
+		assert_eq!(Currency::free_balance(asset_b, &pair_account), 800_320_005_011);
 
 		expect_events(vec![
 			Event::PoolCreated(user_1, asset_a, asset_b, 640000000000).into(),
-			Event::BuyExecuted(user_1, asset_a, asset_b, 66666666, 320639995191).into(),
+			Event::BuyExecuted(
+				user_1,
+				asset_a,
+				asset_b,
+				40000001,
+				160320005011,
+				TradeSource::Direct,
+				trade_fees(320_000_010, 0),
+			)
+			.into(),
 		]);
 	});
 }
@@ -750,7 +1225,7 @@ fn single_buy_with_discount_should_work() {
 
 		assert_eq!(Currency::free_balance(HDX, &user_1), 999_900_000_000_000);
 
-		assert_eq!(Currency::free_balance(share_token, &user_1), 640_000_000_000);
+		assert_eq!(Currency::free_balance(share_token, &user_1), 639_999_999_000);
 
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200_000_000);
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 640_000_000_000);
@@ -759,25 +1234,34 @@ fn single_buy_with_discount_should_work() {
 			Origin::signed(user_1),
 			asset_a,
 			asset_b,
-			66_666_666,
+			40_000_001,
 			1_000_000_000_000,
 			true,
 		));
 
-		assert_eq!(Currency::free_balance(asset_a, &user_1), 999_949_866_666_666);
-		assert_eq!(Currency::free_balance(asset_b, &user_1), 999_039_776_004_803); // compare to values in previous test to see difference!
+		assert_eq!(Currency::free_balance(asset_a, &user_1), 999_949_840_000_001);
+		assert_eq!(Currency::free_balance(asset_b, &user_1), 999_199_887_994_996); // compare to values in previous test to see difference!
 
-		assert_eq!(Currency::free_balance(share_token, &user_1), 640_000_000_000);
+		assert_eq!(Currency::free_balance(share_token, &user_1), 639_999_999_000);
 
-		assert_eq!(Currency::free_balance(asset_a, &pair_account), 133_333_334);
-		assert_eq!(Currency::free_balance(asset_b, &pair_account), 960_223_995_197);
-		assert_eq!(Currency::free_balance(HDX, &user_1), 999_899_552_000_008);
+		assert_eq!(Currency::free_balance(asset_a, &pair_account), 159_999_999);
+		assert_eq!(Currency::free_balance(asset_b, &pair_account), 800_112_005_004);
+		assert_eq!(Currency::free_balance(HDX, &user_1), 999_899_775_999_994);
 
 		expect_events(vec![
 			Event::PoolCreated(user_1, asset_a, asset_b, 640_000_000_000).into(),
 			frame_system::Event::NewAccount(native_pair_account).into(),
 			Event::PoolCreated(user_1, asset_a, HDX, 100_000_000_000).into(),
-			Event::BuyExecuted(user_1, asset_a, asset_b, 66_666_666, 320_223_995_197).into(),
+			Event::BuyExecuted(
+				user_1,
+				asset_a,
+				asset_b,
+				40_000_001,
+				160_112_005_004,
+				TradeSource::Direct,
+				trade_fees(112_000_003, 224_000_006),
+			)
+			.into(),
 		]);
 	});
 }
@@ -797,6 +1281,76 @@ fn create_pool_with_zero_liquidity_should_not_work() {
 	});
 }
 
+#[test]
+fn create_pool_with_amounts_should_work() {
+	new_test_ext().execute_with(|| {
+		let asset_a = HDX;
+		let asset_b = ACA;
+		assert_ok!(XYK::create_pool_with_amounts(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000_000_000_000,
+			333_333_333_333_333
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		});
+		let share_token = XYK::share_token(pair_account);
+
+		assert_eq!(Currency::free_balance(asset_a, &pair_account), 100_000_000_000_000);
+		assert_eq!(Currency::free_balance(asset_b, &pair_account), 333_333_333_333_333);
+		assert_eq!(Currency::free_balance(asset_a, &ALICE), 900_000_000_000_000);
+		assert_eq!(Currency::free_balance(asset_b, &ALICE), 666_666_666_666_667);
+		assert_eq!(Currency::free_balance(share_token, &ALICE), 99_999_999_999_000);
+		assert_eq!(XYK::total_liquidity(&pair_account), 100_000_000_000_000);
+
+		expect_events(vec![
+			Event::PoolCreated(ALICE, asset_a, asset_b, 100_000_000_000_000).into()
+		]);
+	});
+}
+
+#[test]
+fn create_pool_with_zero_amounts_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::create_pool_with_amounts(Origin::signed(ALICE), ACA, HDX, 0, 1_000_000),
+			Error::<Test>::CannotCreatePoolWithZeroLiquidity
+		);
+
+		assert_noop!(
+			XYK::create_pool_with_amounts(Origin::signed(ALICE), ACA, HDX, 1_000_000, 0),
+			Error::<Test>::CannotCreatePoolWithZeroLiquidity
+		);
+	});
+}
+
+#[test]
+fn create_pool_with_amounts_should_be_validated_as_create_pool() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::create_pool_with_amounts(Origin::signed(ALICE), HDX, HDX, 1_000_000, 1_000_000),
+			Error::<Test>::CannotCreatePoolWithSameAssets
+		);
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			ACA,
+			1_000_000,
+			Price::from(2)
+		));
+
+		assert_noop!(
+			XYK::create_pool_with_amounts(Origin::signed(ALICE), ACA, HDX, 1_000_000, 1_000_000),
+			Error::<Test>::TokenPoolAlreadyExists
+		);
+	});
+}
+
 #[test]
 fn add_liquidity_to_non_existing_pool_should_not_work() {
 	new_test_ext().execute_with(|| {
@@ -811,7 +1365,7 @@ fn add_liquidity_to_non_existing_pool_should_not_work() {
 fn remove_zero_liquidity_from_non_existing_pool_should_not_work() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			XYK::remove_liquidity(Origin::signed(ALICE), HDX, ACA, 100),
+			XYK::remove_liquidity(Origin::signed(ALICE), HDX, ACA, 100, 0, 0),
 			Error::<Test>::TokenPoolNotFound
 		);
 	});
@@ -897,7 +1451,7 @@ fn create_pool_small_fixed_point_amount_should_work() {
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 1000000000);
 		assert_eq!(Currency::free_balance(asset_a, &ALICE), 900000000000000);
 		assert_eq!(Currency::free_balance(asset_b, &ALICE), 999999000000000);
-		assert_eq!(Currency::free_balance(share_token, &ALICE), 100000000000000);
+		assert_eq!(Currency::free_balance(share_token, &ALICE), 99999999999000);
 		assert_eq!(XYK::total_liquidity(&pair_account), 100000000000000);
 
 		expect_events(vec![Event::PoolCreated(ALICE, asset_a, asset_b, 100000000000000).into()]);
@@ -927,7 +1481,7 @@ fn create_pool_fixed_point_amount_should_work() {
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 456023454299999);
 		assert_eq!(Currency::free_balance(asset_a, &ALICE), 999900000000000);
 		assert_eq!(Currency::free_balance(asset_b, &ALICE), 543976545700001);
-		assert_eq!(Currency::free_balance(share_token, &ALICE), 100000000000);
+		assert_eq!(Currency::free_balance(share_token, &ALICE), 99999999000);
 		assert_eq!(XYK::total_liquidity(&pair_account), 100000000000);
 
 		expect_events(vec![Event::PoolCreated(ALICE, asset_a, asset_b, 100000000000).into()]);
@@ -958,15 +1512,26 @@ fn destroy_pool_on_remove_liquidity_and_recreate_should_work() {
 
 		assert_eq!(XYK::exists(asset_pair), true);
 
+		let share_token = XYK::share_token(&pair_account);
+
+		// Only locked minimum liquidity remains in the pool once the creator removes all shares
 		assert_ok!(XYK::remove_liquidity(
 			Origin::signed(user),
 			asset_a,
 			asset_b,
-			100_000_000
+			99_999_000,
+			0,
+			0
 		));
 
 		assert_eq!(XYK::total_liquidity(&pair_account), 0);
 
+		// Residual reserves backing locked shares are transferred to treasury and locked shares are burned
+		assert_pool_reserves!(Currency, pair_account, (asset_a, 0), (asset_b, 0));
+		assert_balance!(Currency, TREASURY, asset_a, 1_000);
+		assert_balance!(Currency, TREASURY, asset_b, 10_000_000);
+		assert_balance!(Currency, XYK::locked_liquidity_account(), share_token, 0);
+
 		assert_eq!(XYK::exists(asset_pair), false);
 
 		// It should be possible to recreate the pool again
@@ -981,10 +1546,13 @@ fn destroy_pool_on_remove_liquidity_and_recreate_should_work() {
 
 		expect_events(vec![
 			Event::PoolCreated(user, asset_a, asset_b, 100_000_000).into(),
+			Event::LiquidityRemoved(user, asset_a, asset_b, 99_999_000).into(),
+			frame_system::Event::NewAccount(TREASURY).into(),
 			frame_system::Event::KilledAccount(pair_account).into(),
-			Event::LiquidityRemoved(user, asset_a, asset_b, 100_000_000).into(),
+			frame_system::Event::KilledAccount(XYK::locked_liquidity_account()).into(),
 			Event::PoolDestroyed(user, asset_a, asset_b).into(),
 			frame_system::Event::NewAccount(pair_account).into(),
+			frame_system::Event::NewAccount(XYK::locked_liquidity_account()).into(),
 			Event::PoolCreated(user, asset_a, asset_b, 100_000_000).into(),
 		]);
 	});
@@ -1026,7 +1594,7 @@ fn sell_test_exceeding_max_limit() {
 
 		assert_eq!(Currency::free_balance(asset_a, &user_1), 999800000000000);
 		assert_eq!(Currency::free_balance(asset_b, &user_1), 400000000000000);
-		assert_eq!(Currency::free_balance(share_token, &user_1), 600000000000000);
+		assert_eq!(Currency::free_balance(share_token, &user_1), 599999999999000);
 
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200000000000);
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 600000000000000);
@@ -1074,7 +1642,7 @@ fn buy_test_exceeding_max_limit() {
 
 		assert_eq!(Currency::free_balance(asset_a, &user_1), 999800000000000);
 		assert_eq!(Currency::free_balance(asset_b, &user_1), 400000000000000);
-		assert_eq!(Currency::free_balance(share_token, &user_1), 600000000000000);
+		assert_eq!(Currency::free_balance(share_token, &user_1), 599999999999000);
 
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200000000000);
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 600000000000000);
@@ -1122,7 +1690,7 @@ fn single_buy_more_than_ratio_out_should_not_work() {
 
 		assert_eq!(Currency::free_balance(asset_a, &user_1), 999_999_800_000_000);
 		assert_eq!(Currency::free_balance(asset_b, &user_1), 999_360_000_000_000);
-		assert_eq!(Currency::free_balance(share_token, &user_1), 640_000_000_000);
+		assert_eq!(Currency::free_balance(share_token, &user_1), 639_999_999_000);
 
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200_000_000);
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 640_000_000_000);
@@ -1141,6 +1709,81 @@ fn single_buy_more_than_ratio_out_should_not_work() {
 	});
 }
 
+#[test]
+fn single_buy_paying_more_than_ratio_in_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			200_000_000,
+			Price::from(3200)
+		));
+
+		// Bought amount is within max out ratio but its price is more than third of DOT reserve
+		assert_noop!(
+			XYK::buy(Origin::signed(ALICE), ACA, DOT, 60_000_000, 1_000_000_000_000, false),
+			Error::<Test>::MaxInRatioExceeded
+		);
+	});
+}
+
+#[test]
+fn trade_should_not_work_when_pool_math_fails() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			200_000_000,
+			Price::from(3200)
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: ACA,
+			asset_out: DOT,
+		});
+
+		// Zero weight is never stored by the pallet, weighted trade cannot be calculated with it
+		PoolWeights::<Test>::insert(&pair_account, (0u32, 100u32));
+
+		assert_noop!(
+			XYK::sell(Origin::signed(ALICE), ACA, DOT, 1_000_000, 0, false),
+			Error::<Test>::SellAssetAmountInvalid
+		);
+		assert_noop!(
+			XYK::buy(Origin::signed(ALICE), ACA, DOT, 1_000_000, 1_000_000_000_000, false),
+			Error::<Test>::BuyAssetAmountInvalid
+		);
+	});
+}
+
+#[test]
+fn execution_should_check_ratios_against_current_reserves() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			200_000_000,
+			Price::from(3200)
+		));
+
+		let assets = AssetPair {
+			asset_in: DOT,
+			asset_out: ACA,
+		};
+
+		let first = XYK::validate_buy(&ALICE, assets, 45_000_001, 1_000_000_000_000, false).unwrap();
+		let second = XYK::validate_buy(&BOB, assets, 45_000_001, 1_000_000_000_000, false).unwrap();
+
+		assert_ok!(XYK::execute_buy(&first));
+
+		// Second buy was validated against reserves before the first one was executed
+		assert_noop!(XYK::execute_buy(&second), Error::<Test>::MaxInRatioExceeded);
+	});
+}
+
 #[test]
 fn single_sell_more_than_ratio_in_should_not_work() {
 	new_test_ext().execute_with(|| {
@@ -1164,7 +1807,7 @@ fn single_sell_more_than_ratio_in_should_not_work() {
 
 		assert_eq!(Currency::free_balance(asset_a, &user_1), 999_800_000_000_000);
 		assert_eq!(Currency::free_balance(asset_b, &user_1), 400_000_000_000_000);
-		assert_eq!(Currency::free_balance(share_token, &user_1), 600_000_000_000_000);
+		assert_eq!(Currency::free_balance(share_token, &user_1), 599_999_999_999_000);
 
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200_000_000_000);
 		assert_eq!(Currency::free_balance(asset_b, &pair_account), 600_000_000_000_000);
@@ -1226,3 +1869,2024 @@ fn test_calculate_in_given_out() {
 		assert_eq!(result, Ok(1111111111112));
 	});
 }
+
+#[test]
+fn get_pool_storage_keys_should_work() {
+	new_test_ext().execute_with(|| {
+		let asset_a = HDX;
+		let asset_b = ACA;
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000_000_000_000,
+			Price::from(10)
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		});
+
+		let keys = XYK::get_pool_storage_keys(&pair_account);
+
+		assert_eq!(
+			keys,
+			vec![
+				<ShareToken<Test>>::hashed_key_for(&pair_account),
+				<TotalLiquidity<Test>>::hashed_key_for(&pair_account),
+				<PoolAssets<Test>>::hashed_key_for(&pair_account),
+				<PoolAccessAsset<Test>>::hashed_key_for(&pair_account),
+			]
+		);
+		assert_eq!(
+			frame_support::storage::unhashed::get::<(AssetId, AssetId)>(&keys[2]),
+			Some((asset_a, asset_b))
+		);
+	});
+}
+
+#[test]
+fn create_permissioned_pool_should_work() {
+	new_test_ext().execute_with(|| {
+		let asset_a = HDX;
+		let asset_b = ACA;
+		let access_asset = DOT;
+
+		assert_ok!(XYK::create_permissioned_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000_000_000_000,
+			Price::from(10),
+			access_asset
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		});
+
+		assert_eq!(XYK::pool_access_asset(&pair_account), Some(access_asset));
+		assert_eq!(XYK::total_liquidity(&pair_account), 100000000000000);
+		assert_eq!(XYK::pool_owner(&pair_account), Some((ALICE, 101)));
+
+		expect_events(vec![
+			Event::PoolCreated(ALICE, asset_a, asset_b, 100000000000000).into(),
+			Event::PoolAccessAssetSet(pair_account, access_asset).into(),
+			pallet_scheduler::Event::<Test>::Scheduled(101, 0).into(),
+			Event::PoolOwnershipRenewed(pair_account, ALICE, 101).into(),
+		]);
+	});
+}
+
+#[test]
+fn trade_in_permissioned_pool_without_access_asset_should_not_work() {
+	ExtBuilder::default()
+		.with_accounts(vec![
+			(ALICE, HDX, 1_000_000_000_000_000),
+			(ALICE, ACA, 1_000_000_000_000_000),
+			(ALICE, DOT, 1),
+			(BOB, HDX, 1_000_000_000_000_000),
+			(BOB, ACA, 1_000_000_000_000_000),
+		])
+		.build()
+		.execute_with(|| {
+			let asset_a = HDX;
+			let asset_b = ACA;
+			let access_asset = DOT;
+
+			assert_ok!(XYK::create_permissioned_pool(
+				Origin::signed(ALICE),
+				asset_a,
+				asset_b,
+				10_000_000_000_000,
+				Price::from(10),
+				access_asset
+			));
+
+			assert_noop!(
+				XYK::sell(Origin::signed(BOB), asset_a, asset_b, 1_000_000_000, 1, false),
+				Error::<Test>::AccessAssetRequired
+			);
+			assert_noop!(
+				XYK::buy(
+					Origin::signed(BOB),
+					asset_b,
+					asset_a,
+					1_000_000_000,
+					1_000_000_000_000,
+					false
+				),
+				Error::<Test>::AccessAssetRequired
+			);
+			assert_noop!(
+				XYK::add_liquidity(Origin::signed(BOB), asset_a, asset_b, 1_000_000_000, 1_000_000_000_000),
+				Error::<Test>::AccessAssetRequired
+			);
+
+			assert_ok!(XYK::sell(
+				Origin::signed(ALICE),
+				asset_a,
+				asset_b,
+				1_000_000_000,
+				1,
+				false
+			));
+			assert_ok!(XYK::add_liquidity(
+				Origin::signed(ALICE),
+				asset_a,
+				asset_b,
+				1_000_000_000,
+				1_000_000_000_000
+			));
+		});
+}
+
+#[test]
+fn remove_liquidity_from_permissioned_pool_without_access_asset_should_work() {
+	new_test_ext().execute_with(|| {
+		let asset_a = HDX;
+		let asset_b = ACA;
+		let access_asset = DOT;
+
+		assert_ok!(XYK::create_permissioned_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000_000_000_000,
+			Price::from(10),
+			access_asset
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		});
+
+		let access_balance = Currency::free_balance(access_asset, &ALICE);
+		assert_ok!(Currency::transfer(access_asset, &ALICE, &BOB, access_balance));
+
+		assert_ok!(XYK::remove_liquidity(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			99_999_999_999_000,
+			0,
+			0
+		));
+
+		assert_eq!(XYK::pool_access_asset(&pair_account), None);
+	});
+}
+
+#[test]
+fn calculate_trade_fees_should_work() {
+	let accounts = vec![
+		(ALICE, HDX, 1_000_000u128),
+		(ALICE, ACA, 1_000_000u128),
+		(ALICE, DOT, 1_000_000u128),
+		(BOB, ACA, 1_000_000u128),
+	];
+
+	let mut ext: sp_io::TestExternalities = ExtBuilder::default().with_accounts(accounts).build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext.execute_with(|| {
+		let asset_a = ACA;
+		let asset_b = DOT;
+		let assets = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		assert_noop!(
+			XYK::calculate_trade_fees(&ALICE, assets, 10_000),
+			Error::<Test>::TokenPoolNotFound
+		);
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			30_000,
+			Price::from(2)
+		));
+
+		// No native pool - discount cannot be applied
+		assert_eq!(XYK::calculate_trade_fees(&ALICE, assets, 10_000), Ok((20, None)));
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			HDX,
+			5_000,
+			Price::from(2)
+		));
+
+		assert_eq!(XYK::calculate_trade_fees(&ALICE, assets, 10_000), Ok((20, Some(7))));
+
+		// No native asset to pay the discounted fee
+		assert_eq!(XYK::calculate_trade_fees(&BOB, assets, 10_000), Ok((20, None)));
+	});
+}
+
+#[test]
+fn set_pool_access_asset_should_work() {
+	new_test_ext().execute_with(|| {
+		let asset_a = HDX;
+		let asset_b = ACA;
+
+		assert_ok!(XYK::create_permissioned_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000_000_000_000,
+			Price::from(10),
+			DOT
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		});
+
+		System::set_block_number(50);
+
+		assert_ok!(XYK::set_pool_access_asset(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			Some(HDX)
+		));
+
+		assert_eq!(XYK::pool_access_asset(&pair_account), Some(HDX));
+		assert_eq!(XYK::pool_owner(&pair_account), Some((ALICE, 150)));
+
+		assert_ok!(XYK::set_pool_access_asset(Origin::root(), asset_a, asset_b, None));
+
+		assert_eq!(XYK::pool_access_asset(&pair_account), None);
+		// Governance does not renew the ownership
+		assert_eq!(XYK::pool_owner(&pair_account), Some((ALICE, 150)));
+
+		expect_events(vec![Event::PoolAccessAssetRemoved(pair_account).into()]);
+	});
+}
+
+#[test]
+fn set_pool_access_asset_should_not_work() {
+	new_test_ext().execute_with(|| {
+		let asset_a = HDX;
+		let asset_b = ACA;
+
+		assert_noop!(
+			XYK::set_pool_access_asset(Origin::signed(ALICE), asset_a, asset_b, Some(DOT)),
+			Error::<Test>::TokenPoolNotFound
+		);
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000_000_000_000,
+			Price::from(10)
+		));
+
+		// Pool without owner is controlled only by governance
+		assert_noop!(
+			XYK::set_pool_access_asset(Origin::signed(ALICE), asset_a, asset_b, Some(DOT)),
+			Error::<Test>::PoolOwnerNotFound
+		);
+
+		assert_ok!(XYK::create_permissioned_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			DOT,
+			100_000_000_000_000,
+			Price::from(10),
+			ACA
+		));
+
+		assert_noop!(
+			XYK::set_pool_access_asset(Origin::signed(BOB), asset_a, DOT, None),
+			Error::<Test>::NotPoolOwner
+		);
+	});
+}
+
+#[test]
+fn inactive_pool_owner_should_expire() {
+	new_test_ext().execute_with(|| {
+		let asset_a = HDX;
+		let asset_b = ACA;
+
+		assert_ok!(XYK::create_permissioned_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000_000_000_000,
+			Price::from(10),
+			DOT
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		});
+
+		assert_noop!(
+			XYK::expire_pool_owner(Origin::root(), pair_account),
+			Error::<Test>::PoolOwnerNotExpired
+		);
+		assert_noop!(
+			XYK::expire_pool_owner(Origin::signed(BOB), pair_account),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		System::set_block_number(60);
+
+		assert_ok!(XYK::renew_pool_ownership(Origin::signed(ALICE), asset_a, asset_b));
+		assert_eq!(XYK::pool_owner(&pair_account), Some((ALICE, 160)));
+
+		// Original expiry was rescheduled
+		System::set_block_number(101);
+		Scheduler::on_initialize(101);
+
+		assert_eq!(XYK::pool_owner(&pair_account), Some((ALICE, 160)));
+
+		System::set_block_number(160);
+		Scheduler::on_initialize(160);
+
+		assert_eq!(XYK::pool_owner(&pair_account), None);
+		assert_eq!(XYK::pool_access_asset(&pair_account), Some(DOT));
+
+		assert_noop!(
+			XYK::renew_pool_ownership(Origin::signed(ALICE), asset_a, asset_b),
+			Error::<Test>::PoolOwnerNotFound
+		);
+
+		// Control passed to governance
+		assert_ok!(XYK::set_pool_access_asset(Origin::root(), asset_a, asset_b, None));
+		assert_eq!(XYK::pool_access_asset(&pair_account), None);
+	});
+}
+
+#[test]
+fn pooled_asset_balance_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			ACA,
+			100_000_000_000,
+			Price::from(2)
+		));
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			DOT,
+			HDX,
+			50_000_000_000,
+			Price::from(2)
+		));
+		assert_ok!(XYK::add_liquidity(
+			Origin::signed(BOB),
+			HDX,
+			ACA,
+			50_000_000_000,
+			200_000_000_000
+		));
+
+		assert_event_emitted!(Test, Event::PoolCreated(ALICE, HDX, ACA, 100_000_000_000));
+		assert_event_emitted!(Test, Event::PoolCreated(ALICE, DOT, HDX, 100_000_000_000));
+
+		// Locked minimum liquidity is not part of creator's pooled balance
+		assert_eq!(XYK::pooled_asset_balance(&ALICE, HDX), 199_999_998_000);
+		assert_eq!(XYK::pooled_asset_balance(&ALICE, ACA), 199_999_998_000);
+		assert_eq!(XYK::pooled_asset_balance(&ALICE, DOT), 49_999_999_500);
+		assert_eq!(XYK::pooled_asset_balance(&BOB, HDX), 50_000_000_000);
+		assert_eq!(XYK::pooled_asset_balance(&BOB, DOT), 0);
+	});
+}
+
+#[test]
+fn genesis_pools_should_be_created() {
+	ExtBuilder::default()
+		.with_pools(vec![(ALICE, HDX, ACA, 100_000_000_000, Price::from(2))])
+		.build()
+		.execute_with(|| {
+			let asset_pair = AssetPair {
+				asset_in: HDX,
+				asset_out: ACA,
+			};
+
+			assert!(XYK::exists(asset_pair));
+
+			let pair_account = XYK::get_pair_id(asset_pair);
+			let share_token = XYK::share_token(pair_account);
+
+			assert_pool_reserves!(Currency, pair_account, (HDX, 100_000_000_000), (ACA, 200_000_000_000));
+			assert_balance!(Currency, ALICE, HDX, 999_900_000_000_000);
+			assert_balance!(Currency, ALICE, ACA, 999_800_000_000_000);
+			assert_balance!(Currency, ALICE, share_token, 99_999_999_000);
+			assert_eq!(XYK::total_liquidity(&pair_account), 100_000_000_000);
+		});
+}
+
+#[test]
+fn destroy_dust_pool_should_work() {
+	new_test_ext().execute_with(|| {
+		let asset_a = HDX;
+		let asset_b = DOT;
+		let asset_pair = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000,
+			Price::from(1)
+		));
+
+		let pair_account = XYK::get_pair_id(asset_pair);
+		let share_token = XYK::share_token(&pair_account);
+
+		assert_ok!(XYK::remove_liquidity(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			98_500,
+			0,
+			0
+		));
+
+		assert_pool_reserves!(Currency, pair_account, (asset_a, 1_500), (asset_b, 1_500));
+
+		let treasury_a = Currency::free_balance(asset_a, &TREASURY);
+		let treasury_b = Currency::free_balance(asset_b, &TREASURY);
+
+		assert_ok!(XYK::destroy_pool(Origin::root(), asset_a, asset_b));
+
+		assert!(!XYK::exists(asset_pair));
+		assert_eq!(XYK::share_token(&pair_account), 0);
+		assert_eq!(XYK::total_liquidity(&pair_account), 0);
+		assert_eq!(XYK::pool_assets(&pair_account), OrderedAssetPair::default());
+
+		assert_pool_reserves!(Currency, pair_account, (asset_a, 0), (asset_b, 0));
+		assert_balance!(Currency, TREASURY, asset_a, treasury_a + 1_500);
+		assert_balance!(Currency, TREASURY, asset_b, treasury_b + 1_500);
+
+		// Remaining shares are left with the liquidity provider, locked shares are burned
+		assert_balance!(Currency, ALICE, share_token, 500);
+		assert_balance!(Currency, XYK::locked_liquidity_account(), share_token, 0);
+
+		assert_event_emitted!(
+			Test,
+			Event::DustPoolDestroyed(pair_account, asset_a, asset_b, 1_500, 1_500)
+		);
+
+		// It should be possible to recreate the pool again
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000,
+			Price::from(1)
+		));
+	});
+}
+
+#[test]
+fn destroy_pool_should_not_work() {
+	new_test_ext().execute_with(|| {
+		let asset_a = HDX;
+		let asset_b = DOT;
+
+		assert_noop!(
+			XYK::destroy_pool(Origin::root(), asset_a, asset_b),
+			Error::<Test>::TokenPoolNotFound
+		);
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000,
+			Price::from(1)
+		));
+
+		assert_noop!(
+			XYK::destroy_pool(Origin::signed(ALICE), asset_a, asset_b),
+			sp_runtime::traits::BadOrigin
+		);
+
+		assert_noop!(
+			XYK::destroy_pool(Origin::root(), asset_a, asset_b),
+			Error::<Test>::PoolNotDust
+		);
+	});
+}
+
+#[test]
+fn pool_with_depleted_reserve_should_be_quarantined() {
+	new_test_ext().execute_with(|| {
+		let asset_a = HDX;
+		let asset_b = DOT;
+
+		// Amount of asset b rounds down to zero
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000,
+			Price::saturating_from_rational(1, 1_000_000)
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		});
+
+		assert_pool_reserves!(Currency, pair_account, (asset_a, 100_000), (asset_b, 0));
+		assert!(XYK::is_quarantined(&pair_account));
+		assert_eq!(XYK::quarantined_pool(&pair_account), Some(1));
+		assert_event_emitted!(Test, Event::PoolQuarantined(pair_account, asset_a, asset_b));
+
+		assert_eq!(XYK::get_spot_price_unchecked(asset_a, asset_b, 1_000), 0);
+
+		assert_noop!(
+			XYK::sell(Origin::signed(BOB), asset_a, asset_b, 1_000, 0, false),
+			Error::<Test>::PoolQuarantined
+		);
+		assert_noop!(
+			XYK::buy(Origin::signed(BOB), asset_a, asset_b, 1_000, 1_000_000, false),
+			Error::<Test>::PoolQuarantined
+		);
+		assert_noop!(
+			XYK::add_liquidity(Origin::signed(BOB), asset_a, asset_b, 1_000, 1_000),
+			Error::<Test>::PoolQuarantined
+		);
+
+		// Liquidity can still be removed
+		assert_ok!(XYK::remove_liquidity(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			99_000,
+			0,
+			0
+		));
+
+		assert!(!XYK::exists(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		}));
+		assert!(!XYK::is_quarantined(&pair_account));
+	});
+}
+
+#[test]
+fn resolve_quarantined_pool_should_lift_quarantine_when_reserves_are_available() {
+	new_test_ext().execute_with(|| {
+		let asset_a = HDX;
+		let asset_b = DOT;
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000,
+			Price::saturating_from_rational(1, 1_000_000)
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		});
+
+		assert_ok!(Currency::deposit(asset_b, &pair_account, 100_000));
+
+		assert_noop!(
+			XYK::resolve_quarantined_pool(Origin::signed(ALICE), asset_a, asset_b),
+			sp_runtime::traits::BadOrigin
+		);
+
+		assert_ok!(XYK::resolve_quarantined_pool(Origin::root(), asset_a, asset_b));
+
+		assert!(!XYK::is_quarantined(&pair_account));
+		assert_pool_reserves!(Currency, pair_account, (asset_a, 100_000), (asset_b, 100_000));
+		assert_event_emitted!(Test, Event::PoolQuarantineLifted(pair_account));
+
+		assert_ok!(XYK::sell(Origin::signed(BOB), asset_a, asset_b, 1_000, 0, false));
+
+		assert_noop!(
+			XYK::resolve_quarantined_pool(Origin::root(), asset_a, asset_b),
+			Error::<Test>::PoolNotQuarantined
+		);
+	});
+}
+
+#[test]
+fn resolve_quarantined_pool_should_destroy_pool_with_depleted_reserve() {
+	new_test_ext().execute_with(|| {
+		let asset_a = HDX;
+		let asset_b = DOT;
+		let asset_pair = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		assert_noop!(
+			XYK::resolve_quarantined_pool(Origin::root(), asset_a, asset_b),
+			Error::<Test>::TokenPoolNotFound
+		);
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000,
+			Price::saturating_from_rational(1, 1_000_000)
+		));
+
+		let pair_account = XYK::get_pair_id(asset_pair);
+		let share_token = XYK::share_token(&pair_account);
+
+		let treasury_a = Currency::free_balance(asset_a, &TREASURY);
+
+		assert_ok!(XYK::resolve_quarantined_pool(Origin::root(), asset_a, asset_b));
+
+		assert!(!XYK::exists(asset_pair));
+		assert!(!XYK::is_quarantined(&pair_account));
+		assert_eq!(XYK::total_liquidity(&pair_account), 0);
+
+		assert_pool_reserves!(Currency, pair_account, (asset_a, 0), (asset_b, 0));
+		assert_balance!(Currency, TREASURY, asset_a, treasury_a + 100_000);
+
+		// Remaining shares are left with the liquidity provider
+		assert_balance!(Currency, ALICE, share_token, 99_000);
+
+		assert_event_emitted!(
+			Test,
+			Event::QuarantinedPoolDestroyed(pair_account, asset_a, asset_b, 100_000, 0)
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_minting_no_shares_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			DOT,
+			100_000,
+			Price::from(1_000_000)
+		));
+
+		// Shares are minted in HDX which rounds down to zero for such a small amount of DOT
+		assert_noop!(
+			XYK::add_liquidity(Origin::signed(BOB), DOT, HDX, 1_000, 1_000),
+			Error::<Test>::InvalidMintedLiquidity
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_overflow_should_not_work() {
+	new_test_ext().execute_with(|| {
+		let asset_pair = AssetPair {
+			asset_in: HDX,
+			asset_out: DOT,
+		};
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			DOT,
+			100_000,
+			Price::from(1_000_000)
+		));
+
+		let amount = 1_000_000_000_000_000_000_000_000_000_000_000u128;
+		assert_ok!(Currency::deposit(HDX, &BOB, amount));
+
+		assert_noop!(
+			XYK::add_liquidity(Origin::signed(BOB), HDX, DOT, amount, 1),
+			Error::<Test>::AddAssetAmountInvalid
+		);
+
+		<TotalLiquidity<Test>>::insert(XYK::get_pair_id(asset_pair), Balance::MAX);
+
+		assert_noop!(
+			XYK::add_liquidity(Origin::signed(BOB), HDX, DOT, 1_000, 1_000_000_000),
+			Error::<Test>::InvalidLiquidityAmount
+		);
+	});
+}
+
+#[test]
+fn buy_more_than_pool_reserve_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			100_000,
+			Price::from(2)
+		));
+
+		assert_noop!(
+			XYK::buy(Origin::signed(BOB), ACA, DOT, 100_000, 1_000_000_000, false),
+			Error::<Test>::InsufficientPoolAssetBalance
+		);
+	});
+}
+
+#[test]
+fn discount_trade_with_insufficient_native_balance_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			HDX,
+			5_000_000,
+			Price::from(2)
+		));
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			30_000_000,
+			Price::from(2)
+		));
+
+		assert_ok!(Currency::transfer(HDX, &BOB, &ALICE, Currency::free_balance(HDX, &BOB)));
+
+		assert_noop!(
+			XYK::sell(Origin::signed(BOB), ACA, DOT, 10_000, 1_500, true),
+			Error::<Test>::InsufficientNativeCurrencyBalance
+		);
+		assert_noop!(
+			XYK::buy(Origin::signed(BOB), ACA, DOT, 10_000, 1_000_000_000, true),
+			Error::<Test>::InsufficientNativeCurrencyBalance
+		);
+	});
+}
+
+#[test]
+fn calculate_trade_fees_overflow_should_not_work() {
+	new_test_ext().execute_with(|| {
+		let asset_pair = AssetPair {
+			asset_in: ACA,
+			asset_out: DOT,
+		};
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			100_000,
+			Price::from(2)
+		));
+
+		assert_eq!(
+			XYK::calculate_trade_fees(&ALICE, asset_pair, Balance::MAX),
+			Err(Error::<Test>::FeeAmountInvalid.into())
+		);
+	});
+}
+
+#[test]
+fn recreate_permissioned_pool_after_owner_expired_by_governance_should_work() {
+	new_test_ext().execute_with(|| {
+		let asset_a = HDX;
+		let asset_b = ACA;
+
+		assert_ok!(XYK::create_permissioned_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000,
+			Price::from(10),
+			DOT
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		});
+
+		// Owner is expired before the scheduled expiry is executed
+		System::set_block_number(101);
+		assert_ok!(XYK::expire_pool_owner(Origin::root(), pair_account));
+
+		assert_ok!(XYK::remove_liquidity(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			99_000,
+			0,
+			0
+		));
+		assert!(!XYK::exists(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		}));
+
+		assert_ok!(XYK::create_permissioned_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000,
+			Price::from(10),
+			DOT
+		));
+		assert_eq!(XYK::pool_owner(&pair_account), Some((ALICE, 201)));
+	});
+}
+
+#[test]
+fn create_weighted_pool_should_work() {
+	new_test_ext().execute_with(|| {
+		let asset_a = HDX;
+		let asset_b = ACA;
+		assert_ok!(XYK::create_weighted_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000_000_000_000,
+			Price::from(10),
+			80
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		});
+		let share_token = XYK::share_token(pair_account);
+
+		assert_eq!(Currency::free_balance(asset_a, &pair_account), 100000000000000);
+		assert_eq!(Currency::free_balance(asset_b, &pair_account), 250000000000000);
+		assert_eq!(Currency::free_balance(share_token, &ALICE), 99999999999000);
+		assert_eq!(XYK::pool_weights(&pair_account), Some((80, 20)));
+		assert_eq!(XYK::asset_weights(&pair_account, asset_a), (80, 20));
+		assert_eq!(XYK::asset_weights(&pair_account, asset_b), (20, 80));
+
+		expect_events(vec![
+			Event::PoolCreated(ALICE, asset_a, asset_b, 100000000000000).into(),
+			Event::PoolWeightsSet(pair_account, asset_a, asset_b, 80, 20).into(),
+		]);
+	});
+}
+
+#[test]
+fn create_weighted_pool_should_store_weights_in_order_of_pool_assets() {
+	new_test_ext().execute_with(|| {
+		let asset_a = ACA;
+		let asset_b = HDX;
+		assert_ok!(XYK::create_weighted_pool(
+			Origin::signed(ALICE),
+			asset_a,
+			asset_b,
+			100_000_000_000_000,
+			Price::from(10),
+			80
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		});
+
+		assert_eq!(
+			XYK::pool_assets(&pair_account),
+			OrderedAssetPair {
+				asset_a: HDX,
+				asset_b: ACA
+			}
+		);
+		assert_eq!(XYK::pool_weights(&pair_account), Some((20, 80)));
+		assert_eq!(XYK::asset_weights(&pair_account, asset_a), (80, 20));
+		assert_eq!(XYK::asset_weights(&pair_account, asset_b), (20, 80));
+	});
+}
+
+#[test]
+fn create_pool_should_set_equal_weights() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			ACA,
+			100_000_000_000_000,
+			Price::from(10)
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: HDX,
+			asset_out: ACA,
+		});
+
+		assert_eq!(XYK::pool_weights(&pair_account), Some(math::EQUAL_POOL_WEIGHTS));
+	});
+}
+
+#[test]
+fn create_weighted_pool_with_invalid_weight_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::create_weighted_pool(Origin::signed(ALICE), HDX, ACA, 100_000, Price::from(10), 1),
+			Error::<Test>::InvalidPoolWeights
+		);
+		assert_noop!(
+			XYK::create_weighted_pool(Origin::signed(ALICE), HDX, ACA, 100_000, Price::from(10), 99),
+			Error::<Test>::InvalidPoolWeights
+		);
+		assert_noop!(
+			XYK::create_weighted_pool(Origin::signed(ALICE), HDX, ACA, 100_000, Price::from(10), 100),
+			Error::<Test>::InvalidPoolWeights
+		);
+	});
+}
+
+#[test]
+fn weighted_pool_spot_price_should_respect_weights() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_weighted_pool(
+			Origin::signed(ALICE),
+			HDX,
+			ACA,
+			100_000_000_000_000,
+			Price::from(10),
+			80
+		));
+
+		assert_eq!(
+			XYK::get_spot_price_unchecked(HDX, ACA, 1_000_000_000_000),
+			10_000_000_000_000
+		);
+		assert_eq!(
+			XYK::get_spot_price_unchecked(ACA, HDX, 10_000_000_000_000),
+			1_000_000_000_000
+		);
+	});
+}
+
+#[test]
+fn sell_in_weighted_pool_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_weighted_pool(
+			Origin::signed(ALICE),
+			HDX,
+			ACA,
+			100_000_000_000_000,
+			Price::from(10),
+			80
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: HDX,
+			asset_out: ACA,
+		});
+
+		let fee = ExchangeFeeRate::get();
+		let amount = 1_000_000_000_000;
+
+		let (expected, _) = math::calculate_weighted_out_given_in_with_fee(
+			100_000_000_000_000,
+			80,
+			250_000_000_000_000,
+			20,
+			amount,
+			fee.numerator,
+			fee.denominator,
+		)
+		.unwrap();
+		let (constant_product, _) = math::calculate_out_given_in_with_fee(
+			100_000_000_000_000,
+			250_000_000_000_000,
+			amount,
+			fee.numerator,
+			fee.denominator,
+		)
+		.unwrap();
+
+		assert!(expected > constant_product);
+
+		let bob_aca = Currency::free_balance(ACA, &BOB);
+
+		assert_ok!(XYK::sell(Origin::signed(BOB), HDX, ACA, amount, 0, false));
+
+		assert_eq!(Currency::free_balance(ACA, &BOB), bob_aca + expected);
+		assert_eq!(Currency::free_balance(HDX, &pair_account), 100_000_000_000_000 + amount);
+		assert_eq!(
+			Currency::free_balance(ACA, &pair_account),
+			250_000_000_000_000 - expected
+		);
+	});
+}
+
+#[test]
+fn buy_in_weighted_pool_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_weighted_pool(
+			Origin::signed(ALICE),
+			HDX,
+			ACA,
+			100_000_000_000_000,
+			Price::from(10),
+			80
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: HDX,
+			asset_out: ACA,
+		});
+
+		let fee = ExchangeFeeRate::get();
+		let amount = 1_000_000_000_000;
+
+		let (expected, _) = math::calculate_weighted_in_given_out_with_fee(
+			100_000_000_000_000,
+			80,
+			250_000_000_000_000,
+			20,
+			amount,
+			fee.numerator,
+			fee.denominator,
+		)
+		.unwrap();
+
+		let bob_aca = Currency::free_balance(ACA, &BOB);
+
+		assert_ok!(XYK::buy(Origin::signed(BOB), HDX, ACA, amount, expected, false));
+
+		assert_eq!(Currency::free_balance(ACA, &BOB), bob_aca - expected);
+		assert_eq!(Currency::free_balance(HDX, &pair_account), 100_000_000_000_000 - amount);
+		assert_eq!(
+			Currency::free_balance(ACA, &pair_account),
+			250_000_000_000_000 + expected
+		);
+	});
+}
+
+#[test]
+fn remove_weighted_pool_should_remove_weights() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_weighted_pool(
+			Origin::signed(ALICE),
+			HDX,
+			ACA,
+			100_000_000_000_000,
+			Price::from(10),
+			80
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: HDX,
+			asset_out: ACA,
+		});
+
+		assert_ok!(XYK::remove_liquidity(
+			Origin::signed(ALICE),
+			HDX,
+			ACA,
+			99_999_999_999_000,
+			0,
+			0
+		));
+
+		assert_eq!(XYK::pool_weights(&pair_account), None);
+	});
+}
+
+#[test]
+fn calculate_sell_price_should_match_executed_sell() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			200_000_000_000,
+			Price::from(3000)
+		));
+
+		let assets = AssetPair {
+			asset_in: ACA,
+			asset_out: DOT,
+		};
+
+		assert_eq!(
+			XYK::calculate_sell_price(assets, 456_444_678),
+			Ok((1_363_489_802_256, 912_889, Permill::from_parts(2_272)))
+		);
+
+		assert_ok!(XYK::sell(Origin::signed(ALICE), ACA, DOT, 456_444_678, 0, false));
+
+		assert_event_emitted!(
+			Test,
+			Event::SellExecuted(
+				ALICE,
+				ACA,
+				DOT,
+				456_444_678,
+				1_363_489_802_256,
+				TradeSource::Direct,
+				trade_fees(912_889, 0)
+			)
+		);
+	});
+}
+
+#[test]
+fn calculate_sell_price_for_should_apply_fee_of_account() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			200_000_000_000,
+			Price::from(3000)
+		));
+
+		let assets = AssetPair {
+			asset_in: ACA,
+			asset_out: DOT,
+		};
+
+		assert_eq!(
+			XYK::calculate_sell_price_for(&BOB, assets, 456_444_678, false),
+			XYK::calculate_sell_price(assets, 456_444_678)
+		);
+
+		set_fee_discount(
+			ALICE,
+			fee::Fee {
+				numerator: 1,
+				denominator: 1000,
+			},
+		);
+
+		let (amount_out, fee, _) = XYK::calculate_sell_price_for(&ALICE, assets, 456_444_678, false).unwrap();
+
+		assert_eq!(fee, 456_444);
+
+		assert_ok!(XYK::sell(Origin::signed(ALICE), ACA, DOT, 456_444_678, 0, false));
+
+		assert_event_emitted!(
+			Test,
+			Event::SellExecuted(
+				ALICE,
+				ACA,
+				DOT,
+				456_444_678,
+				amount_out,
+				TradeSource::Direct,
+				trade_fees(fee, 0)
+			)
+		);
+	});
+}
+
+#[test]
+fn calculate_buy_price_should_match_executed_buy() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			200_000_000,
+			Price::from(3200)
+		));
+
+		let assets = AssetPair {
+			asset_in: DOT,
+			asset_out: ACA,
+		};
+
+		assert_eq!(
+			XYK::calculate_buy_price(assets, 40_000_001),
+			Ok((160_320_005_011, 320_000_010, Permill::from_parts(200_000)))
+		);
+
+		assert_ok!(XYK::buy(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			40_000_001,
+			1_000_000_000_000,
+			false
+		));
+
+		assert_event_emitted!(
+			Test,
+			Event::BuyExecuted(
+				ALICE,
+				ACA,
+				DOT,
+				40_000_001,
+				160_320_005_011,
+				TradeSource::Direct,
+				trade_fees(320_000_010, 0)
+			)
+		);
+	});
+}
+
+#[test]
+fn find_best_route_should_prefer_direct_pool_when_it_is_the_only_route() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			1_000_000_000_000,
+			Price::from(1)
+		));
+
+		let (amount_out, _, _) = XYK::calculate_sell_price(
+			AssetPair {
+				asset_in: ACA,
+				asset_out: DOT,
+			},
+			100_000_000_000,
+		)
+		.unwrap();
+
+		assert_eq!(
+			XYK::find_best_route(ACA, DOT, 100_000_000_000),
+			Some((vec![ACA, DOT], amount_out))
+		);
+	});
+}
+
+#[test]
+fn find_best_route_should_route_through_intermediate_asset_when_it_yields_more() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			1_000_000_000_000,
+			Price::from(1)
+		));
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			HDX,
+			1_000_000_000_000_000,
+			Price::from(1)
+		));
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			DOT,
+			1_000_000_000_000_000,
+			Price::from(1)
+		));
+
+		let (direct_out, _, _) = XYK::calculate_sell_price(
+			AssetPair {
+				asset_in: ACA,
+				asset_out: DOT,
+			},
+			100_000_000_000,
+		)
+		.unwrap();
+		let (hdx_out, _, _) = XYK::calculate_sell_price(
+			AssetPair {
+				asset_in: ACA,
+				asset_out: HDX,
+			},
+			100_000_000_000,
+		)
+		.unwrap();
+		let (routed_out, _, _) = XYK::calculate_sell_price(
+			AssetPair {
+				asset_in: HDX,
+				asset_out: DOT,
+			},
+			hdx_out,
+		)
+		.unwrap();
+
+		assert!(routed_out > direct_out);
+		assert_eq!(
+			XYK::find_best_route(ACA, DOT, 100_000_000_000),
+			Some((vec![ACA, HDX, DOT], routed_out))
+		);
+
+		// Small trade has lower price impact in the direct pool than two fees of the routed trade
+		let (direct_out, _, _) = XYK::calculate_sell_price(
+			AssetPair {
+				asset_in: ACA,
+				asset_out: DOT,
+			},
+			1_000_000,
+		)
+		.unwrap();
+
+		assert_eq!(
+			XYK::find_best_route(ACA, DOT, 1_000_000),
+			Some((vec![ACA, DOT], direct_out))
+		);
+	});
+}
+
+#[test]
+fn find_best_route_should_return_none_when_there_is_no_route() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(XYK::find_best_route(ACA, DOT, 1_000_000), None);
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			HDX,
+			1_000_000_000_000,
+			Price::from(1)
+		));
+
+		assert_eq!(XYK::find_best_route(ACA, DOT, 1_000_000), None);
+		assert_eq!(XYK::find_best_route(ACA, ACA, 1_000_000), None);
+		assert_eq!(XYK::find_best_route(ACA, HDX, 0), None);
+
+		// Trade exceeding max in ratio of the only pool
+		assert_eq!(XYK::find_best_route(ACA, HDX, 500_000_000_000), None);
+	});
+}
+
+#[test]
+fn calculate_price_should_fail_when_trade_is_not_possible() {
+	new_test_ext().execute_with(|| {
+		let assets = AssetPair {
+			asset_in: ACA,
+			asset_out: DOT,
+		};
+
+		assert_noop!(
+			XYK::calculate_sell_price(assets, 1_000),
+			Error::<Test>::TokenPoolNotFound
+		);
+		assert_noop!(
+			XYK::calculate_buy_price(assets, 1_000),
+			Error::<Test>::TokenPoolNotFound
+		);
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			200_000_000,
+			Price::from(3200)
+		));
+
+		assert_noop!(
+			XYK::calculate_sell_price(assets, 200_000_000),
+			Error::<Test>::MaxInRatioExceeded
+		);
+		assert_noop!(
+			XYK::calculate_buy_price(assets, 640_000_000_000),
+			Error::<Test>::InsufficientPoolAssetBalance
+		);
+		assert_noop!(
+			XYK::calculate_buy_price(assets, 60_000_000),
+			Error::<Test>::MaxInRatioExceeded
+		);
+	});
+}
+
+fn remark_call() -> Box<TestCall> {
+	Box::new(TestCall::System(frame_system::Call::remark(vec![])))
+}
+
+#[test]
+fn flash_loan_should_work() {
+	new_test_ext().execute_with(|| {
+		let assets = AssetPair {
+			asset_in: HDX,
+			asset_out: DOT,
+		};
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			DOT,
+			100_000_000,
+			Price::from(2)
+		));
+
+		let pair_account = XYK::get_pair_id(assets);
+
+		assert_ok!(XYK::flash_loan(
+			Origin::signed(BOB),
+			HDX,
+			DOT,
+			HDX,
+			1_000_000,
+			remark_call()
+		));
+
+		assert_balance!(Currency, BOB, HDX, 1_000_000_000_000_000 - 10_000);
+		assert_pool_reserves!(Currency, pair_account, (HDX, 100_010_000), (DOT, 200_000_000));
+		assert_eq!(XYK::flash_loan_pool(), None);
+
+		assert_event_emitted!(
+			Test,
+			Event::FlashLoanExecuted(BOB, pair_account, HDX, 1_000_000, 10_000)
+		);
+	});
+}
+
+#[test]
+fn flash_loan_should_lend_reserves_for_the_duration_of_the_call() {
+	ExtBuilder::default()
+		.with_accounts(vec![
+			(ALICE, HDX, 1_000_000_000_000),
+			(ALICE, ACA, 1_000_000_000_000),
+			(ALICE, DOT, 1_000_000_000_000),
+			(BOB, DOT, 1_000_000),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(XYK::create_pool(
+				Origin::signed(ALICE),
+				HDX,
+				DOT,
+				100_000_000_000,
+				Price::from(1)
+			));
+			assert_ok!(XYK::create_pool(
+				Origin::signed(ALICE),
+				ACA,
+				DOT,
+				100_000_000_000,
+				Price::from(2)
+			));
+
+			// Bob borrows DOT, sells it for ACA in the other pool and has nothing left to repay the loan
+			let sell_call = Box::new(TestCall::XYK(Call::sell(DOT, ACA, 10_000_000, 0, false)));
+
+			assert_noop!(
+				XYK::flash_loan(Origin::signed(BOB), HDX, DOT, DOT, 10_000_000, sell_call),
+				Error::<Test>::FlashLoanNotRepaid
+			);
+
+			// The same trade funded by his own balance succeeds
+			let sell_call = Box::new(TestCall::XYK(Call::sell(DOT, ACA, 500_000, 0, false)));
+
+			assert_ok!(XYK::flash_loan(
+				Origin::signed(BOB),
+				HDX,
+				DOT,
+				DOT,
+				10_000_000,
+				sell_call
+			));
+
+			assert_balance!(Currency, BOB, DOT, 1_000_000 - 500_000 - 100_000);
+			assert!(Currency::free_balance(ACA, &BOB) > 0);
+		});
+}
+
+#[test]
+fn pool_should_be_locked_during_flash_loan() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			DOT,
+			100_000_000,
+			Price::from(2)
+		));
+
+		let sell_call = Box::new(TestCall::XYK(Call::sell(HDX, DOT, 1_000, 0, false)));
+
+		assert_noop!(
+			XYK::flash_loan(Origin::signed(BOB), HDX, DOT, HDX, 1_000_000, sell_call),
+			Error::<Test>::FlashLoanInProgress
+		);
+
+		let add_liquidity_call = Box::new(TestCall::XYK(Call::add_liquidity(HDX, DOT, 1_000, 1_000_000)));
+
+		assert_noop!(
+			XYK::flash_loan(Origin::signed(BOB), HDX, DOT, HDX, 1_000_000, add_liquidity_call),
+			Error::<Test>::FlashLoanInProgress
+		);
+
+		let flash_loan_call = Box::new(TestCall::XYK(Call::flash_loan(HDX, DOT, DOT, 1_000, remark_call())));
+
+		assert_noop!(
+			XYK::flash_loan(Origin::signed(BOB), HDX, DOT, HDX, 1_000_000, flash_loan_call),
+			Error::<Test>::FlashLoanInProgress
+		);
+	});
+}
+
+#[test]
+fn flash_loan_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::flash_loan(Origin::signed(BOB), HDX, DOT, HDX, 1_000, remark_call()),
+			Error::<Test>::TokenPoolNotFound
+		);
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			DOT,
+			100_000_000,
+			Price::from(2)
+		));
+
+		assert_noop!(
+			XYK::flash_loan(Origin::signed(BOB), HDX, DOT, HDX, 0, remark_call()),
+			Error::<Test>::CannotFlashLoanZero
+		);
+		assert_noop!(
+			XYK::flash_loan(Origin::signed(BOB), HDX, DOT, ACA, 1_000, remark_call()),
+			Error::<Test>::TokenPoolNotFound
+		);
+		assert_noop!(
+			XYK::flash_loan(Origin::signed(BOB), HDX, DOT, HDX, 100_000_000, remark_call()),
+			Error::<Test>::InsufficientPoolAssetBalance
+		);
+	});
+}
+
+struct TestFlashLoanReceiver;
+
+impl FlashLoanReceiver<u64, AssetId, Balance> for TestFlashLoanReceiver {
+	fn on_flash_loan(who: &u64, asset: AssetId, amount: Balance, fee: Balance) -> DispatchResult {
+		assert_eq!(Currency::free_balance(asset, who), 1_000_000_000_000_000 + amount);
+		assert_eq!(fee, 10_000);
+		assert!(XYK::flash_loan_pool().is_some());
+		Ok(())
+	}
+}
+
+#[test]
+fn flash_loan_with_receiver_should_work() {
+	new_test_ext().execute_with(|| {
+		let assets = AssetPair {
+			asset_in: HDX,
+			asset_out: DOT,
+		};
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			DOT,
+			100_000_000,
+			Price::from(2)
+		));
+
+		assert_ok!(XYK::flash_loan_with::<TestFlashLoanReceiver>(
+			&BOB, assets, DOT, 1_000_000
+		));
+
+		assert_balance!(Currency, BOB, DOT, 1_000_000_000_000_000 - 10_000);
+		assert_pool_reserves!(
+			Currency,
+			XYK::get_pair_id(assets),
+			(HDX, 100_000_000),
+			(DOT, 200_010_000)
+		);
+	});
+}
+
+#[test]
+fn pool_account_should_be_non_dustable_while_pool_exists() {
+	new_test_ext().execute_with(|| {
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: ACA,
+			asset_out: DOT,
+		});
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			200_000_000_000,
+			Price::from(3000)
+		));
+
+		assert_eq!(non_dustable_accounts(), vec![pair_account]);
+
+		assert_ok!(XYK::remove_liquidity(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			599_999_999_999_000,
+			0,
+			0
+		));
+
+		assert!(!XYK::exists(AssetPair {
+			asset_in: ACA,
+			asset_out: DOT,
+		}));
+		assert!(non_dustable_accounts().is_empty());
+	});
+}
+
+#[test]
+fn set_pool_state_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::set_pool_state(Origin::root(), HDX, DOT, PoolState::TradingPaused),
+			Error::<Test>::TokenPoolNotFound
+		);
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			DOT,
+			100_000_000,
+			Price::from(2)
+		));
+
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: HDX,
+			asset_out: DOT,
+		});
+
+		assert_noop!(
+			XYK::set_pool_state(Origin::signed(ALICE), HDX, DOT, PoolState::TradingPaused),
+			sp_runtime::traits::BadOrigin
+		);
+
+		assert_ok!(XYK::set_pool_state(Origin::root(), DOT, HDX, PoolState::TradingPaused));
+
+		assert_eq!(XYK::pool_state(&pair_account), PoolState::TradingPaused);
+		assert_event_emitted!(Test, Event::PoolStateChanged(pair_account, PoolState::TradingPaused));
+
+		assert_ok!(XYK::set_pool_state(Origin::root(), HDX, DOT, PoolState::Active));
+
+		assert_eq!(XYK::pool_state(&pair_account), PoolState::Active);
+		assert!(!PoolStates::<Test>::contains_key(&pair_account));
+		assert_event_emitted!(Test, Event::PoolStateChanged(pair_account, PoolState::Active));
+	});
+}
+
+#[test]
+fn trading_paused_pool_should_allow_only_liquidity_removal() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			DOT,
+			100_000_000,
+			Price::from(2)
+		));
+
+		assert_ok!(XYK::set_pool_state(Origin::root(), HDX, DOT, PoolState::TradingPaused));
+
+		assert_noop!(
+			XYK::sell(Origin::signed(BOB), HDX, DOT, 1_000, 0, false),
+			Error::<Test>::PoolTradingPaused
+		);
+		assert_noop!(
+			XYK::buy(Origin::signed(BOB), DOT, HDX, 1_000, 1_000_000, false),
+			Error::<Test>::PoolTradingPaused
+		);
+		assert_noop!(
+			XYK::add_liquidity(Origin::signed(BOB), HDX, DOT, 1_000, 10_000),
+			Error::<Test>::PoolTradingPaused
+		);
+		assert_noop!(
+			XYK::flash_loan(Origin::signed(BOB), HDX, DOT, HDX, 1_000, remark_call()),
+			Error::<Test>::PoolTradingPaused
+		);
+		assert_noop!(
+			XYK::calculate_sell_price(
+				AssetPair {
+					asset_in: HDX,
+					asset_out: DOT
+				},
+				1_000
+			),
+			Error::<Test>::PoolTradingPaused
+		);
+
+		assert_ok!(XYK::remove_liquidity(Origin::signed(ALICE), HDX, DOT, 1_000_000, 0, 0));
+
+		assert_ok!(XYK::set_pool_state(Origin::root(), HDX, DOT, PoolState::Active));
+
+		assert_ok!(XYK::sell(Origin::signed(BOB), HDX, DOT, 1_000, 0, false));
+	});
+}
+
+#[test]
+fn frozen_pool_should_not_allow_any_user_operation() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			DOT,
+			100_000_000,
+			Price::from(2)
+		));
+
+		assert_ok!(XYK::set_pool_state(Origin::root(), HDX, DOT, PoolState::Frozen));
+
+		assert_noop!(
+			XYK::sell(Origin::signed(BOB), HDX, DOT, 1_000, 0, false),
+			Error::<Test>::PoolFrozen
+		);
+		assert_noop!(
+			XYK::buy(Origin::signed(BOB), DOT, HDX, 1_000, 1_000_000, false),
+			Error::<Test>::PoolFrozen
+		);
+		assert_noop!(
+			XYK::add_liquidity(Origin::signed(BOB), HDX, DOT, 1_000, 10_000),
+			Error::<Test>::PoolFrozen
+		);
+		assert_noop!(
+			XYK::remove_liquidity(Origin::signed(ALICE), HDX, DOT, 1_000_000, 0, 0),
+			Error::<Test>::PoolFrozen
+		);
+		assert_noop!(
+			XYK::flash_loan(Origin::signed(BOB), HDX, DOT, HDX, 1_000, remark_call()),
+			Error::<Test>::PoolFrozen
+		);
+		assert_eq!(
+			XYK::validate_sell(
+				&BOB,
+				AssetPair {
+					asset_in: HDX,
+					asset_out: DOT
+				},
+				1_000,
+				0,
+				false
+			)
+			.err(),
+			Some(Error::<Test>::PoolFrozen.into())
+		);
+	});
+}
+
+#[test]
+fn governance_should_be_able_to_destroy_frozen_pool() {
+	new_test_ext().execute_with(|| {
+		let asset_pair = AssetPair {
+			asset_in: HDX,
+			asset_out: DOT,
+		};
+
+		// Amount of DOT rounds down to zero, so the pool is quarantined right after creation.
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			DOT,
+			100_000,
+			Price::saturating_from_rational(1, 1_000_000)
+		));
+
+		let pair_account = XYK::get_pair_id(asset_pair);
+
+		assert_ok!(XYK::set_pool_state(Origin::root(), HDX, DOT, PoolState::Frozen));
+
+		assert_ok!(XYK::resolve_quarantined_pool(Origin::root(), HDX, DOT));
+
+		assert!(!XYK::exists(asset_pair));
+		assert!(!PoolStates::<Test>::contains_key(&pair_account));
+	});
+}
+
+#[test]
+fn sell_should_apply_fee_of_discount_tier() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			200_000_000_000,
+			Price::from(3000)
+		));
+
+		set_fee_discount(
+			ALICE,
+			fee::Fee {
+				numerator: 1,
+				denominator: 1000,
+			},
+		);
+
+		assert_ok!(XYK::sell(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			456_444_678,
+			1000000000000,
+			false,
+		));
+
+		assert_eq!(last_sell_fees(), Some(trade_fees(456_444, 0)));
+	});
+}
+
+#[test]
+fn sell_should_not_apply_fee_of_discount_tier_higher_than_standard_fee() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			200_000_000_000,
+			Price::from(3000)
+		));
+
+		set_fee_discount(
+			ALICE,
+			fee::Fee {
+				numerator: 3,
+				denominator: 1000,
+			},
+		);
+
+		assert_ok!(XYK::sell(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			456_444_678,
+			1000000000000,
+			false,
+		));
+
+		assert_eq!(last_sell_fees(), Some(trade_fees(912_889, 0)));
+	});
+}
+
+#[test]
+fn calculate_trade_fees_should_apply_fee_of_discount_tier() {
+	new_test_ext().execute_with(|| {
+		let assets = AssetPair {
+			asset_in: ACA,
+			asset_out: DOT,
+		};
+
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			ACA,
+			DOT,
+			30_000,
+			Price::from(2)
+		));
+
+		set_fee_discount(
+			ALICE,
+			fee::Fee {
+				numerator: 1,
+				denominator: 1000,
+			},
+		);
+
+		assert_eq!(XYK::calculate_trade_fees(&ALICE, assets, 10_000), Ok((10, None)));
+		assert_eq!(XYK::calculate_trade_fees(&BOB, assets, 10_000), Ok((20, None)));
+	});
+}
+
+#[test]
+fn create_pool_shares_should_not_depend_on_order_of_assets() {
+	let shares_of_pool = |asset_x: AssetId, asset_y: AssetId, amount_x: Balance, amount_y: Balance| {
+		new_test_ext().execute_with(|| {
+			assert_ok!(XYK::create_pool_with_amounts(
+				Origin::signed(ALICE),
+				asset_x,
+				asset_y,
+				amount_x,
+				amount_y
+			));
+
+			let pair_account = XYK::get_pair_id(AssetPair::new(asset_x, asset_y));
+			let share_token = XYK::share_token(&pair_account);
+
+			(
+				XYK::total_liquidity(&pair_account),
+				Currency::free_balance(share_token, &ALICE),
+			)
+		})
+	};
+
+	let shares = shares_of_pool(HDX, ACA, 100_000_000_000, 200_000_000_000);
+
+	assert_eq!(shares, (100_000_000_000, 100_000_000_000 - MINIMUM_LIQUIDITY));
+	assert_eq!(shares_of_pool(ACA, HDX, 200_000_000_000, 100_000_000_000), shares);
+}
+
+#[test]
+fn add_liquidity_shares_should_not_depend_on_order_of_assets() {
+	let shares_added = |asset_x: AssetId, asset_y: AssetId, amount_x: Balance| {
+		new_test_ext().execute_with(|| {
+			assert_ok!(XYK::create_pool_with_amounts(
+				Origin::signed(ALICE),
+				HDX,
+				ACA,
+				100_000_000_000,
+				200_000_000_000
+			));
+
+			let pair_account = XYK::get_pair_id(AssetPair::new(asset_x, asset_y));
+			let share_token = XYK::share_token(&pair_account);
+
+			assert_ok!(XYK::add_liquidity(
+				Origin::signed(BOB),
+				asset_x,
+				asset_y,
+				amount_x,
+				1_000_000_000_000
+			));
+
+			(
+				Currency::free_balance(share_token, &BOB),
+				Currency::free_balance(HDX, &pair_account),
+				Currency::free_balance(ACA, &pair_account),
+			)
+		})
+	};
+
+	let added = shares_added(HDX, ACA, 1_000_000);
+
+	assert_eq!(added, (1_000_000, 100_001_000_000, 200_002_000_000));
+	assert_eq!(shares_added(ACA, HDX, 2_000_000), added);
+}
+
+#[test]
+fn migrate_pool_asset_should_move_pool_to_new_asset() {
+	new_test_ext().execute_with(|| {
+		let asset_new = 500;
+
+		assert_ok!(XYK::create_weighted_pool(
+			Origin::signed(ALICE),
+			HDX,
+			ACA,
+			100_000_000_000_000,
+			Price::from(10),
+			80
+		));
+
+		let old_pool = XYK::get_pair_id(AssetPair::new(HDX, ACA));
+		let share_token = XYK::share_token(old_pool);
+		let reserve = Currency::free_balance(ACA, &old_pool);
+
+		// Reserve of the migrated asset is replaced by the caller
+		assert_ok!(Currency::withdraw(ACA, &old_pool, reserve));
+		assert_ok!(Currency::deposit(asset_new, &old_pool, 2 * reserve));
+
+		let new_pool = XYK::migrate_pool_asset(ACA, asset_new, HDX).unwrap();
+
+		assert_eq!(new_pool, XYK::get_pair_id(AssetPair::new(asset_new, HDX)));
+		assert!(!XYK::exists(AssetPair::new(HDX, ACA)));
+		assert!(XYK::exists(AssetPair::new(HDX, asset_new)));
+
+		assert_eq!(Currency::free_balance(HDX, &new_pool), 100_000_000_000_000);
+		assert_eq!(Currency::free_balance(asset_new, &new_pool), 2 * reserve);
+		assert_eq!(Currency::free_balance(HDX, &old_pool), 0);
+		assert_eq!(Currency::free_balance(asset_new, &old_pool), 0);
+
+		assert_eq!(XYK::pool_assets(&new_pool), OrderedAssetPair::new(asset_new, HDX));
+		assert_eq!(XYK::pool_weights(&new_pool), Some((20, 80)));
+		assert_eq!(XYK::asset_weights(&new_pool, HDX), (80, 20));
+		assert_eq!(XYK::share_token(&new_pool), share_token);
+		assert_eq!(XYK::total_liquidity(&new_pool), 100_000_000_000_000);
+		assert_eq!(XYK::total_liquidity(&old_pool), 0);
+		assert_eq!(non_dustable_accounts(), vec![new_pool]);
+
+		expect_events(vec![
+			Event::PoolAssetMigrated(old_pool, new_pool, ACA, asset_new, HDX).into()
+		]);
+	});
+}
+
+#[test]
+fn migrate_pool_asset_should_not_work_with_invalid_pools() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			ACA,
+			100_000_000_000,
+			Price::from(2)
+		));
+		assert_ok!(XYK::create_pool(
+			Origin::signed(ALICE),
+			HDX,
+			DOT,
+			100_000_000_000,
+			Price::from(2)
+		));
+
+		assert_noop!(
+			XYK::migrate_pool_asset(ACA, DOT, HDX),
+			Error::<Test>::TokenPoolAlreadyExists
+		);
+		assert_noop!(
+			XYK::migrate_pool_asset(ACA, HDX, HDX),
+			Error::<Test>::CannotCreatePoolWithSameAssets
+		);
+		assert_noop!(XYK::migrate_pool_asset(DOT, 500, ACA), Error::<Test>::TokenPoolNotFound);
+	});
+}
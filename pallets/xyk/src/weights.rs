@@ -48,10 +48,21 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for amm.
 pub trait WeightInfo {
 	fn create_pool() -> Weight;
+	fn create_pool_with_amounts() -> Weight;
+	fn create_permissioned_pool() -> Weight;
+	fn create_weighted_pool() -> Weight;
 	fn add_liquidity() -> Weight;
 	fn remove_liquidity() -> Weight;
 	fn sell() -> Weight;
 	fn buy() -> Weight;
+	fn set_protocol_fee() -> Weight;
+	fn set_pool_access_asset() -> Weight;
+	fn renew_pool_ownership() -> Weight;
+	fn expire_pool_owner() -> Weight;
+	fn destroy_pool() -> Weight;
+	fn resolve_quarantined_pool() -> Weight;
+	fn set_pool_state() -> Weight;
+	fn flash_loan() -> Weight;
 }
 
 /// Weights for amm using the hydraDX node and recommended hardware.
@@ -63,6 +74,24 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(11 as Weight))
 			.saturating_add(T::DbWeight::get().writes(13 as Weight))
 	}
+	// Placeholder: estimated from `create_pool`, not measured yet. Regenerate with `scripts/generate_weights.sh xyk`.
+	fn create_pool_with_amounts() -> Weight {
+		(187_921_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+	}
+	// Placeholder: estimated from `create_pool`, not measured yet. Regenerate with `scripts/generate_weights.sh xyk`.
+	fn create_permissioned_pool() -> Weight {
+		(203_117_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(17 as Weight))
+	}
+	// Placeholder: estimated from `create_pool`, not measured yet. Regenerate with `scripts/generate_weights.sh xyk`.
+	fn create_weighted_pool() -> Weight {
+		(196_384_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(14 as Weight))
+	}
 	fn add_liquidity() -> Weight {
 		(171_602_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(9 as Weight))
@@ -83,6 +112,44 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5 as Weight))
 			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
+	fn set_protocol_fee() -> Weight {
+		(18_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_pool_access_asset() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn renew_pool_ownership() -> Weight {
+		(46_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn expire_pool_owner() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn destroy_pool() -> Weight {
+		(98_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	fn resolve_quarantined_pool() -> Weight {
+		(101_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	fn flash_loan() -> Weight {
+		(94_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	fn set_pool_state() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -92,6 +159,24 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
 	}
+	// Placeholder: estimated from `create_pool`, not measured yet. Regenerate with `scripts/generate_weights.sh xyk`.
+	fn create_pool_with_amounts() -> Weight {
+		(187_921_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
+	}
+	// Placeholder: estimated from `create_pool`, not measured yet. Regenerate with `scripts/generate_weights.sh xyk`.
+	fn create_permissioned_pool() -> Weight {
+		(203_117_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(17 as Weight))
+	}
+	// Placeholder: estimated from `create_pool`, not measured yet. Regenerate with `scripts/generate_weights.sh xyk`.
+	fn create_weighted_pool() -> Weight {
+		(196_384_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(14 as Weight))
+	}
 	fn add_liquidity() -> Weight {
 		(171_602_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
@@ -112,4 +197,42 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 	}
+	fn set_protocol_fee() -> Weight {
+		(18_000_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_pool_access_asset() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn renew_pool_ownership() -> Weight {
+		(46_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn expire_pool_owner() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn destroy_pool() -> Weight {
+		(98_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn resolve_quarantined_pool() -> Weight {
+		(101_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	fn flash_loan() -> Weight {
+		(94_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	fn set_pool_state() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
 }
@@ -0,0 +1,154 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! XCM configuration of the parachain.
+//!
+//! Assets reserved on other chains are routed through the asset registry: a foreign asset is accepted only if its
+//! `MultiLocation`, as seen from this parachain and SCALE encoded, is registered as the native location of a local
+//! asset. The relay chain currency is registered under `X1(Parent)`, tokens of sibling parachains under
+//! `X3(Parent, Parachain { id }, GeneralKey(key))`.
+
+use super::*;
+
+use codec::Encode;
+use orml_xcm_support::{CurrencyIdConversion, MultiCurrencyAdapter};
+use polkadot_parachain::primitives::Sibling;
+use primitives::{asset::AssetLocation, traits::AssetRegistryTrait};
+use sp_runtime::traits::Convert;
+use xcm::v0::{Junction, MultiAsset, MultiLocation, NetworkId};
+use xcm_builder::{
+	AccountId32Aliases, LocationInverter, ParentIsDefault, RelayChainAsNative, SiblingParachainAsNative,
+	SiblingParachainConvertsVia, SignedAccountId32AsNative, SovereignSignedViaLocation,
+};
+use xcm_executor::{
+	traits::{MatchesFungible, NativeAsset},
+	Config, XcmExecutor,
+};
+
+parameter_types! {
+	pub const RelayNetwork: NetworkId = NetworkId::Polkadot;
+	pub RelayChainOrigin: Origin = cumulus_pallet_xcm_handler::Origin::Relay.into();
+	pub Ancestry: MultiLocation = Junction::Parachain {
+		id: ParachainInfo::parachain_id().into()
+	}.into();
+}
+
+/// Conversion of locations to local accounts: the relay chain maps to the default account, sibling parachains
+/// to their sovereign accounts and `AccountId32` junctions on the relay network to the same account here.
+pub type LocationConverter = (
+	ParentIsDefault<AccountId>,
+	SiblingParachainConvertsVia<Sibling, AccountId>,
+	AccountId32Aliases<RelayNetwork, AccountId>,
+);
+
+/// Conversion of XCM origins to local dispatch origins.
+pub type LocalOriginConverter = (
+	SovereignSignedViaLocation<LocationConverter, Origin>,
+	RelayChainAsNative<RelayChainOrigin, Origin>,
+	SiblingParachainAsNative<cumulus_pallet_xcm_handler::Origin, Origin>,
+	SignedAccountId32AsNative<RelayNetwork, Origin>,
+);
+
+/// Location of a concrete asset in the format stored by the asset registry.
+fn asset_location(location: &MultiLocation) -> AssetLocation {
+	AssetLocation(location.encode())
+}
+
+/// Local asset registered for the location of `asset`.
+pub struct AssetRegistryConvert;
+impl CurrencyIdConversion<AssetId> for AssetRegistryConvert {
+	fn from_asset(asset: &MultiAsset) -> Option<AssetId> {
+		match asset {
+			MultiAsset::ConcreteFungible { id, .. } => AssetRegistry::location_to_asset(asset_location(id)),
+			_ => None,
+		}
+	}
+}
+
+/// Matches fungible assets with location registered in the asset registry.
+pub struct IsRegisteredAsset;
+impl MatchesFungible<Balance> for IsRegisteredAsset {
+	fn matches_fungible(asset: &MultiAsset) -> Option<Balance> {
+		match asset {
+			MultiAsset::ConcreteFungible { id, amount }
+				if AssetRegistry::location_to_asset(asset_location(id)).is_some() =>
+			{
+				Some(*amount)
+			}
+			_ => None,
+		}
+	}
+}
+
+/// Deposits and withdrawals of registered assets are applied to `Currencies`.
+pub type LocalAssetTransactor =
+	MultiCurrencyAdapter<Currencies, IsRegisteredAsset, LocationConverter, AccountId, AssetRegistryConvert, AssetId>;
+
+pub struct XcmConfig;
+impl Config for XcmConfig {
+	type Call = Call;
+	type XcmSender = XcmHandler;
+	type AssetTransactor = LocalAssetTransactor;
+	type OriginConverter = LocalOriginConverter;
+	type IsReserve = NativeAsset;
+	type IsTeleporter = ();
+	type LocationInverter = LocationInverter<Ancestry>;
+}
+
+impl cumulus_pallet_xcm_handler::Config for Runtime {
+	type Event = Event;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type UpwardMessageSender = ParachainSystem;
+	type HrmpMessageSender = ParachainSystem;
+	type SendXcmOrigin = EnsureRoot<AccountId>;
+	type AccountIdConverter = LocationConverter;
+}
+
+pub struct AccountId32Convert;
+impl Convert<AccountId, [u8; 32]> for AccountId32Convert {
+	fn convert(account_id: AccountId) -> [u8; 32] {
+		account_id.into()
+	}
+}
+
+/// Relay chain currency has the same precision as balances here, amounts are transferred as they are.
+pub struct RelayChainBalanceConvert;
+impl Convert<Balance, Balance> for RelayChainBalanceConvert {
+	fn convert(balance: Balance) -> Balance {
+		balance
+	}
+}
+
+impl orml_xtokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type ToRelayChainBalance = RelayChainBalanceConvert;
+	type AccountId32Convert = AccountId32Convert;
+	type RelayChainNetworkId = RelayNetwork;
+	type ParaId = ParachainInfo;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+}
+
+impl cumulus_pallet_parachain_system::Config for Runtime {
+	type Event = Event;
+	type OnValidationData = ();
+	type SelfParaId = ParachainInfo;
+	type DownwardMessageHandlers = XcmHandler;
+	type HrmpMessageHandlers = XcmHandler;
+}
+
+impl parachain_info::Config for Runtime {}
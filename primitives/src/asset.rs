@@ -24,12 +24,28 @@ use sp_std::vec::Vec;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
+/// Separator of assets in the string representation of asset pair.
+pub const PAIR_SEPARATOR: char = '-';
+
+/// Location of an asset on its native chain, e.g. SCALE encoded cross-chain multilocation.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, Default)]
+pub struct AssetLocation(pub Vec<u8>);
+
 /// Asset Pair representation for AMM trades
 /// ( asset_a, asset_b ) combination where asset_a is meant to be exchanged for asset_b
 ///
 /// asset_in represents asset coming into the pool
 /// asset_out represents asset coming out of the pool
+///
+/// Canonical representations, used by events, runtime APIs and RPC:
+/// - SCALE encoding: `asset_in` followed by `asset_out`, both as little endian `u32`.
+/// - String and JSON: `"<asset_in>-<asset_out>"`, e.g. `"0-1"`.
+/// - Pool identifier: `canonical()` pair, which is the same for both trade directions.
+///
+/// Human readable symbol (e.g. `"HDX-DOT"`) is built from asset names by `symbol()`.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(try_from = "String", into = "String"))]
 #[derive(Debug, Encode, Decode, Copy, Clone, PartialEq, Eq, Default)]
 pub struct AssetPair {
 	pub asset_in: AssetId,
@@ -49,6 +65,17 @@ impl AssetPair {
 		}
 	}
 
+	/// Return canonical pair identifying the pool - assets ordered so that asset_in < asset_out.
+	pub fn canonical(&self) -> Self {
+		let (asset_in, asset_out) = self.ordered_pair();
+		Self { asset_in, asset_out }
+	}
+
+	/// Return assets of the pair ordered by asset id.
+	pub fn ordered(&self) -> OrderedAssetPair {
+		OrderedAssetPair::new(self.asset_in, self.asset_out)
+	}
+
 	/// Return share token name
 	pub fn name(&self) -> Vec<u8> {
 		let mut buf: Vec<u8> = Vec::new();
@@ -61,4 +88,291 @@ impl AssetPair {
 
 		buf
 	}
+
+	/// Return canonical pair of share token `name`, inverse of `name()`.
+	pub fn from_name(name: &[u8]) -> Option<Self> {
+		use sp_std::convert::TryInto;
+
+		if name.len() != 11 || &name[4..7] != b"HDT" {
+			return None;
+		}
+
+		let asset_a = AssetId::from_le_bytes(name[..4].try_into().ok()?);
+		let asset_b = AssetId::from_le_bytes(name[7..].try_into().ok()?);
+
+		Some(Self::new(asset_a, asset_b))
+	}
+
+	/// Return human readable symbol of the pair, e.g. "HDX-DOT".
+	///
+	/// `name_of` provides asset names. Asset id is used for assets without a name.
+	pub fn symbol<F: Fn(AssetId) -> Option<Vec<u8>>>(&self, name_of: F) -> Vec<u8> {
+		let part = |asset: AssetId| name_of(asset).unwrap_or_else(|| decimal(asset));
+
+		let mut buf = part(self.asset_in);
+		let mut separator = [0u8; 4];
+		buf.extend_from_slice(PAIR_SEPARATOR.encode_utf8(&mut separator).as_bytes());
+		buf.extend_from_slice(&part(self.asset_out));
+
+		buf
+	}
+}
+
+/// Asset pair ordered by asset id, `asset_a` is the asset with lower id.
+///
+/// Identifies the pool of the pair regardless of the order in which the assets were given.
+/// Values related to the assets of the pair (amounts, weights) are ordered the same way by `order()`.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Debug, Encode, Decode, Copy, Clone, PartialEq, Eq, Default)]
+pub struct OrderedAssetPair {
+	pub asset_a: AssetId,
+	pub asset_b: AssetId,
+}
+
+impl OrderedAssetPair {
+	/// Create ordered pair of given assets.
+	pub fn new(asset_x: AssetId, asset_y: AssetId) -> Self {
+		if asset_x <= asset_y {
+			Self {
+				asset_a: asset_x,
+				asset_b: asset_y,
+			}
+		} else {
+			Self {
+				asset_a: asset_y,
+				asset_b: asset_x,
+			}
+		}
+	}
+
+	/// Return true if `asset` is one of the assets of the pair.
+	pub fn contains(&self, asset: AssetId) -> bool {
+		self.asset_a == asset || self.asset_b == asset
+	}
+
+	/// Return the other asset of the pair, `None` if `asset` is not in the pair.
+	pub fn other(&self, asset: AssetId) -> Option<AssetId> {
+		if asset == self.asset_a {
+			Some(self.asset_b)
+		} else if asset == self.asset_b {
+			Some(self.asset_a)
+		} else {
+			None
+		}
+	}
+
+	/// Return trade direction of selling `asset_in` for the other asset, `None` if `asset_in` is not in the pair.
+	pub fn direction(&self, asset_in: AssetId) -> Option<AssetPair> {
+		self.other(asset_in)
+			.map(|asset_out| AssetPair::new(asset_in, asset_out))
+	}
+
+	/// Reorder `values` of `asset` and the other asset of the pair to the order of the pair assets.
+	///
+	/// Values are swapped unless `asset` is `asset_a`. Reordering is its own inverse, so values stored in the order
+	/// of the pair assets are returned as (value of `asset`, value of the other asset).
+	pub fn order<V>(&self, asset: AssetId, values: (V, V)) -> (V, V) {
+		if asset == self.asset_a {
+			values
+		} else {
+			(values.1, values.0)
+		}
+	}
+}
+
+impl From<AssetPair> for OrderedAssetPair {
+	fn from(pair: AssetPair) -> Self {
+		pair.ordered()
+	}
+}
+
+/// Decimal representation of asset id.
+fn decimal(mut asset: AssetId) -> Vec<u8> {
+	let mut buf = Vec::new();
+
+	loop {
+		buf.push(b'0' + (asset % 10) as u8);
+		asset /= 10;
+		if asset == 0 {
+			break;
+		}
+	}
+	buf.reverse();
+
+	buf
+}
+
+/// Error returned when parsing asset pair from string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseAssetPairError {
+	/// Separator is missing or there are more than two assets.
+	InvalidFormat,
+	/// Asset is not a valid asset id.
+	InvalidAssetId,
+}
+
+impl sp_std::fmt::Display for ParseAssetPairError {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		match self {
+			ParseAssetPairError::InvalidFormat => write!(f, "expected <asset_in>{}<asset_out>", PAIR_SEPARATOR),
+			ParseAssetPairError::InvalidAssetId => write!(f, "invalid asset id"),
+		}
+	}
+}
+
+impl sp_std::fmt::Display for AssetPair {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "{}{}{}", self.asset_in, PAIR_SEPARATOR, self.asset_out)
+	}
+}
+
+impl sp_std::str::FromStr for AssetPair {
+	type Err = ParseAssetPairError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut parts = s.split(PAIR_SEPARATOR);
+
+		let (asset_in, asset_out) = match (parts.next(), parts.next(), parts.next()) {
+			(Some(asset_in), Some(asset_out), None) => (asset_in, asset_out),
+			_ => return Err(ParseAssetPairError::InvalidFormat),
+		};
+
+		let parse = |asset: &str| {
+			// Only plain decimal form is canonical - reject signs, whitespace and leading zeros.
+			if asset.is_empty()
+				|| (asset.len() > 1 && asset.starts_with('0'))
+				|| !asset.bytes().all(|b| b.is_ascii_digit())
+			{
+				return Err(ParseAssetPairError::InvalidAssetId);
+			}
+			asset
+				.parse::<AssetId>()
+				.map_err(|_| ParseAssetPairError::InvalidAssetId)
+		};
+
+		Ok(Self {
+			asset_in: parse(asset_in)?,
+			asset_out: parse(asset_out)?,
+		})
+	}
+}
+
+#[cfg(feature = "std")]
+impl From<AssetPair> for String {
+	fn from(pair: AssetPair) -> String {
+		pair.to_string()
+	}
+}
+
+#[cfg(feature = "std")]
+impl sp_std::convert::TryFrom<String> for AssetPair {
+	type Error = ParseAssetPairError;
+
+	fn try_from(s: String) -> Result<Self, Self::Error> {
+		s.parse()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn scale_encoding_should_be_canonical() {
+		let pair = AssetPair::new(1, 258);
+
+		assert_eq!(pair.encode(), vec![1, 0, 0, 0, 2, 1, 0, 0]);
+		assert_eq!(AssetPair::decode(&mut &pair.encode()[..]), Ok(pair));
+	}
+
+	#[test]
+	fn string_representation_should_round_trip() {
+		let pair = AssetPair::new(1000, 2);
+
+		assert_eq!(pair.to_string(), "1000-2");
+		assert_eq!("1000-2".parse::<AssetPair>(), Ok(pair));
+	}
+
+	#[test]
+	fn parsing_non_canonical_string_should_fail() {
+		assert_eq!("1000".parse::<AssetPair>(), Err(ParseAssetPairError::InvalidFormat));
+		assert_eq!("1-2-3".parse::<AssetPair>(), Err(ParseAssetPairError::InvalidFormat));
+		assert_eq!("1-".parse::<AssetPair>(), Err(ParseAssetPairError::InvalidAssetId));
+		assert_eq!(" 1-2".parse::<AssetPair>(), Err(ParseAssetPairError::InvalidAssetId));
+		assert_eq!("+1-2".parse::<AssetPair>(), Err(ParseAssetPairError::InvalidAssetId));
+		assert_eq!("01-2".parse::<AssetPair>(), Err(ParseAssetPairError::InvalidAssetId));
+		assert_eq!(
+			"1-4294967296".parse::<AssetPair>(),
+			Err(ParseAssetPairError::InvalidAssetId)
+		);
+	}
+
+	#[test]
+	fn json_representation_should_be_string() {
+		let pair = AssetPair::new(0, 1);
+
+		assert_eq!(serde_json::to_string(&pair).unwrap(), "\"0-1\"");
+		assert_eq!(serde_json::from_str::<AssetPair>("\"0-1\"").unwrap(), pair);
+		assert!(serde_json::from_str::<AssetPair>("\"0/1\"").is_err());
+	}
+
+	#[test]
+	fn canonical_pair_should_not_depend_on_direction() {
+		assert_eq!(AssetPair::new(2, 1).canonical(), AssetPair::new(1, 2));
+		assert_eq!(AssetPair::new(1, 2).canonical(), AssetPair::new(1, 2));
+	}
+
+	#[test]
+	fn ordered_pair_should_not_depend_on_argument_order() {
+		let pair = OrderedAssetPair::new(1000, 2);
+
+		assert_eq!(pair, OrderedAssetPair::new(2, 1000));
+		assert_eq!((pair.asset_a, pair.asset_b), (2, 1000));
+		assert_eq!(OrderedAssetPair::from(AssetPair::new(1000, 2)), pair);
+		assert_eq!(pair.encode(), AssetPair::new(2, 1000).encode());
+	}
+
+	#[test]
+	fn ordered_pair_direction_helpers_should_work() {
+		let pair = OrderedAssetPair::new(1000, 2);
+
+		assert!(pair.contains(2) && pair.contains(1000) && !pair.contains(3));
+		assert_eq!(pair.other(2), Some(1000));
+		assert_eq!(pair.other(1000), Some(2));
+		assert_eq!(pair.other(3), None);
+		assert_eq!(pair.direction(1000), Some(AssetPair::new(1000, 2)));
+		assert_eq!(pair.direction(3), None);
+
+		assert_eq!(pair.order(2, (10, 20)), (10, 20));
+		assert_eq!(pair.order(1000, (10, 20)), (20, 10));
+		assert_eq!(pair.order(1000, pair.order(1000, (10, 20))), (10, 20));
+	}
+
+	#[test]
+	fn share_token_name_should_round_trip() {
+		assert_eq!(
+			AssetPair::from_name(&AssetPair::new(2, 1).name()),
+			Some(AssetPair::new(1, 2))
+		);
+		assert_eq!(
+			AssetPair::from_name(&AssetPair::new(0, 1000).name()),
+			Some(AssetPair::new(0, 1000))
+		);
+		assert_eq!(AssetPair::from_name(b"HDX"), None);
+		assert_eq!(AssetPair::from_name(b"\0\0\0\0DOT\x01\0\0\0"), None);
+	}
+
+	#[test]
+	fn symbol_should_use_asset_names() {
+		let name_of = |asset: AssetId| match asset {
+			0 => Some(b"HDX".to_vec()),
+			1 => Some(b"DOT".to_vec()),
+			_ => None,
+		};
+
+		assert_eq!(AssetPair::new(0, 1).symbol(name_of), b"HDX-DOT".to_vec());
+		assert_eq!(AssetPair::new(1, 0).symbol(name_of), b"DOT-HDX".to_vec());
+		assert_eq!(AssetPair::new(0, 7).symbol(name_of), b"HDX-7".to_vec());
+		assert_eq!(AssetPair::new(1000, 0).symbol(name_of), b"1000-HDX".to_vec());
+	}
 }
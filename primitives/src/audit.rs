@@ -0,0 +1,59 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Types describing privileged actions recorded in the audit log.
+
+use super::*;
+
+/// Privileged action affecting economic parameters of the chain.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq)]
+pub enum PrivilegedAction {
+	/// Protocol part of AMM trading fee was changed.
+	ProtocolFeeSet(fee::Fee),
+	/// Access asset of a pool was set or removed. [asset a, asset b, access asset]
+	PoolAccessAssetSet(AssetId, AssetId, Option<AssetId>),
+	/// Dust pool was destroyed. [asset a, asset b]
+	PoolDestroyed(AssetId, AssetId),
+	/// Currency was accepted for transaction fee payment.
+	FeeCurrencyAdded(AssetId),
+	/// Currency was removed from currencies accepted for transaction fee payment.
+	FeeCurrencyRemoved(AssetId),
+	/// Asset listing was approved. [listing id]
+	ListingApproved(u32),
+	/// Asset listing was rejected. [listing id]
+	ListingRejected(u32),
+	/// State of a pool was changed. [asset a, asset b, state]
+	PoolStateSet(AssetId, AssetId, PoolState),
+	/// Migration of an asset to another asset was registered. [old asset, new asset]
+	AssetMigrationRegistered(AssetId, AssetId),
+	/// Migration of an asset was removed. [old asset]
+	AssetMigrationRemoved(AssetId),
+}
+
+/// Entry of the audit log.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq)]
+pub struct AuditEntry<AccountId, BlockNumber> {
+	/// Sequence number of the entry
+	pub index: u32,
+	/// Account which performed the action, `None` if enacted by root or collective origin
+	pub proposer: Option<AccountId>,
+	/// Block in which the action was enacted
+	pub enacted: BlockNumber,
+	/// Recorded action
+	pub action: PrivilegedAction,
+}
@@ -0,0 +1,91 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Message signed by an Ethereum account to claim its balance.
+//!
+//! Shared by the claims pallet and front-ends, so that both build byte-for-byte the same message.
+
+use sp_std::vec::Vec;
+
+/// Prefix which Ethereum RPC's `personal_sign` and `eth_sign` prepend to every signed message.
+pub const ETHEREUM_SIGNED_MESSAGE_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n";
+
+/// Converts the given binary data into ASCII-encoded hex. It will be twice the length.
+pub fn to_ascii_hex(data: &[u8]) -> Vec<u8> {
+	let mut r = Vec::with_capacity(data.len() * 2);
+	let mut push_nibble = |n| r.push(if n < 10 { b'0' + n } else { b'a' - 10 + n });
+	for &b in data.iter() {
+		push_nibble(b / 16);
+		push_nibble(b % 16);
+	}
+	r
+}
+
+/// Constructs the message that Ethereum RPC's `personal_sign` and `eth_sign` would sign.
+pub fn ethereum_signable_message(what: &[u8], prefix: &[u8]) -> Vec<u8> {
+	let mut l = prefix.len() + what.len();
+	let mut rev = Vec::new();
+	while l > 0 {
+		rev.push(b'0' + (l % 10) as u8);
+		l /= 10;
+	}
+	let mut v = ETHEREUM_SIGNED_MESSAGE_PREFIX.to_vec();
+	v.extend(rev.into_iter().rev());
+	v.extend_from_slice(prefix);
+	v.extend_from_slice(what);
+	v
+}
+
+/// Constructs the message which has to be signed to claim balance to the account with SCALE encoded `account_id`.
+///
+/// The account is included as lowercase hex without `0x`, right after `prefix`.
+pub fn claim_message(prefix: &[u8], account_id: &[u8]) -> Vec<u8> {
+	ethereum_signable_message(&to_ascii_hex(account_id), prefix)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn to_ascii_hex_works() {
+		assert_eq!(to_ascii_hex(&[]), b"".to_vec());
+		assert_eq!(to_ascii_hex(&[0x00, 0x2a, 0xff]), b"002aff".to_vec());
+	}
+
+	#[test]
+	fn claim_message_works() {
+		let prefix = b"I hereby claim all my xHDX tokens to wallet:";
+		let account_id = 42u64.to_le_bytes();
+
+		assert_eq!(
+			claim_message(prefix, &account_id),
+			b"\x19Ethereum Signed Message:\n60I hereby claim all my xHDX tokens to wallet:2a00000000000000".to_vec()
+		);
+	}
+
+	#[test]
+	fn message_length_is_encoded_in_decimal() {
+		let message = ethereum_signable_message(&[b'a'; 95], &[b'b'; 10]);
+
+		assert_eq!(
+			&message[..ETHEREUM_SIGNED_MESSAGE_PREFIX.len() + 3],
+			b"\x19Ethereum Signed Message:\n105"
+		);
+		assert_eq!(message.len(), ETHEREUM_SIGNED_MESSAGE_PREFIX.len() + 3 + 105);
+	}
+}
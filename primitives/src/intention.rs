@@ -0,0 +1,108 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{AssetId, Balance, IntentionType};
+
+use codec::{Decode, Encode};
+
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Prefix of every signed intention message. Prevents the signature from being valid for any other payload.
+pub const SIGNED_INTENTION_DOMAIN: &[u8] = b"HydraDX:signed-intention";
+
+/// Intention signed by its owner off-chain and submitted on-chain by a relayer.
+///
+/// `nonce` must match the next intention nonce of `who` so the payload cannot be replayed.
+/// `deadline` is the last block in which the payload can be submitted.
+/// `relayer_fee` is paid in native asset by `who` to the account which submits the intention.
+/// `tip` is the priority fee of the registered intention.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq)]
+pub struct IntentionPayload<AccountId, BlockNumber> {
+	pub who: AccountId,
+	pub sell_or_buy: IntentionType,
+	pub asset_sell: AssetId,
+	pub asset_buy: AssetId,
+	/// Amount sold (SELL) or bought (BUY).
+	pub amount: Balance,
+	/// Minimum amount bought (SELL) or maximum amount sold (BUY).
+	pub limit: Balance,
+	pub discount: bool,
+	pub valid_for: Option<BlockNumber>,
+	pub relayer_fee: Balance,
+	pub nonce: u64,
+	pub deadline: BlockNumber,
+	pub tip: Balance,
+}
+
+impl<AccountId: Encode, BlockNumber: Encode> IntentionPayload<AccountId, BlockNumber> {
+	/// Return message which is signed by the intention owner.
+	///
+	/// Message is the SCALE encoded tuple of `SIGNED_INTENTION_DOMAIN`, genesis hash of the chain and the payload.
+	pub fn signing_message<Hash: Encode>(&self, genesis_hash: &Hash) -> Vec<u8> {
+		(SIGNED_INTENTION_DOMAIN, genesis_hash, self).encode()
+	}
+}
+
+/// Intention payload together with the signature of its owner.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq)]
+pub struct SignedIntention<AccountId, BlockNumber, Signature> {
+	pub payload: IntentionPayload<AccountId, BlockNumber>,
+	pub signature: Signature,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn payload(nonce: u64) -> IntentionPayload<u64, u64> {
+		IntentionPayload {
+			who: 1,
+			sell_or_buy: IntentionType::SELL,
+			asset_sell: 0,
+			asset_buy: 1,
+			amount: 1_000,
+			limit: 900,
+			discount: false,
+			valid_for: None,
+			relayer_fee: 10,
+			nonce,
+			deadline: 10,
+			tip: 0,
+		}
+	}
+
+	#[test]
+	fn signing_message_should_be_domain_separated() {
+		let message = payload(0).signing_message(&[1u8; 32]);
+
+		assert!(message.starts_with(&SIGNED_INTENTION_DOMAIN.encode()));
+		assert_ne!(message, payload(0).encode());
+	}
+
+	#[test]
+	fn signing_message_should_depend_on_genesis_hash_and_nonce() {
+		let message = payload(0).signing_message(&[1u8; 32]);
+
+		assert_ne!(message, payload(0).signing_message(&[2u8; 32]));
+		assert_ne!(message, payload(1).signing_message(&[1u8; 32]));
+	}
+}
@@ -27,7 +27,11 @@ use serde::{Deserialize, Serialize};
 use frame_support::sp_runtime::FixedU128;
 
 pub mod asset;
+pub mod audit;
+pub mod claim;
+pub mod intention;
 pub mod traits;
+pub mod units;
 
 /// An index to a block.
 pub type BlockNumber = u32;
@@ -76,9 +80,27 @@ impl Default for IntentionType {
 	}
 }
 
+/// Operations allowed in an AMM pool.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Debug, Encode, Decode, Clone, Copy, PartialEq, Eq)]
+pub enum PoolState {
+	/// Trading and liquidity operations are allowed.
+	Active,
+	/// Trading and adding liquidity are paused, liquidity can be removed.
+	TradingPaused,
+	/// All user operations are disabled, only governance can act on the pool.
+	Frozen,
+}
+
+impl Default for PoolState {
+	fn default() -> PoolState {
+		PoolState::Active
+	}
+}
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq)]
-pub struct ExchangeIntention<AccountId, Balance, IntentionID> {
+pub struct ExchangeIntention<AccountId, Balance, IntentionID, BlockNumber> {
 	pub who: AccountId,
 	pub assets: asset::AssetPair,
 	pub amount_in: Balance,
@@ -87,6 +109,9 @@ pub struct ExchangeIntention<AccountId, Balance, IntentionID> {
 	pub discount: bool,
 	pub sell_or_buy: IntentionType,
 	pub intention_id: IntentionID,
+	pub valid_until: Option<BlockNumber>,
+	/// Priority fee in native asset reserved on registration and paid when the intention is resolved.
+	pub tip: Balance,
 }
 
 pub mod fee {
@@ -99,6 +124,12 @@ pub mod fee {
 		pub denominator: u32,
 	}
 
+	/// Trade fee applied when it is paid in native currency.
+	pub const DISCOUNTED_FEE: Fee = Fee {
+		numerator: 7,
+		denominator: 10000,
+	};
+
 	impl Default for Fee {
 		fn default() -> Self {
 			Fee {
@@ -135,11 +166,7 @@ pub mod fee {
 		}
 
 		fn discounted_fee(&self) -> Option<Self> {
-			let fee = Fee {
-				numerator: 7,
-				denominator: 10000,
-			};
-			self.just_fee(fee)
+			self.just_fee(DISCOUNTED_FEE)
 		}
 	}
 }
@@ -17,10 +17,46 @@
 
 #![allow(clippy::upper_case_acronyms)]
 
+use crate::audit::PrivilegedAction;
+use crate::IntentionType;
+use codec::{Decode, Encode};
 use frame_support::dispatch;
 use frame_support::dispatch::DispatchResult;
+use frame_support::weights::Weight;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_std::vec::Vec;
 
+/// Origin of an AMM trade.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Debug, Encode, Decode, Clone, Copy, PartialEq, Eq)]
+pub enum TradeSource {
+	/// Trade extrinsic of the AMM pallet.
+	Direct,
+	/// Intention resolved by the exchange pallet.
+	Exchange,
+	/// Trade routed through the `AMM` trait by another pallet, e.g. DCA, buyback or fee payment.
+	Router,
+}
+
+impl Default for TradeSource {
+	fn default() -> TradeSource {
+		TradeSource::Direct
+	}
+}
+
+/// Breakdown of fees paid in an AMM trade.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Debug, Encode, Decode, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TradeFees<Balance> {
+	/// Part of the trade fee left in the pool to liquidity providers.
+	pub lp_fee: Balance,
+	/// Part of the trade fee transferred to the treasury.
+	pub protocol_fee: Balance,
+	/// Amount of native asset paid for the discounted trade fee.
+	pub discount: Balance,
+}
+
 /// Hold information to perform amm transfer
 /// Contains also exact amount which will be sold/bought
 pub struct AMMTransfer<AccountId, AssetPair, Balance> {
@@ -30,6 +66,11 @@ pub struct AMMTransfer<AccountId, AssetPair, Balance> {
 	pub amount_out: Balance,
 	pub discount: bool,
 	pub discount_amount: Balance,
+	pub fee: Balance,
+	/// Who initiated the trade. Set by the caller if it is not a direct trade.
+	pub source: TradeSource,
+	/// Fee breakdown, if provided by the AMM.
+	pub fees: Option<TradeFees<Balance>>,
 }
 
 /// Traits for handling AMM Pool trades.
@@ -98,6 +139,83 @@ pub trait AMM<AccountId, AssetId, AssetPair, Amount> {
 	}
 }
 
+/// Direction of liquidity change in AMM pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LiquidityChangeKind {
+	Added,
+	Removed,
+}
+
+/// Hold information about liquidity added to or removed from AMM pool
+pub struct LiquidityChange<AccountId, AssetId, Balance> {
+	pub who: AccountId,
+	pub kind: LiquidityChangeKind,
+	pub asset_a: AssetId,
+	pub asset_b: AssetId,
+	pub amount_a: Balance,
+	pub amount_b: Balance,
+	pub shares: Balance,
+}
+
+/// Handlers of AMM pool changes, e.g. price oracle, liquidity mining or circuit breaker.
+///
+/// Handlers are called after the change is applied, so pool reserves already reflect it,
+/// except `on_before_trade` which is called while reserves still reflect the state before the trade.
+pub trait AMMHandlers<AccountId, AssetId, AssetPair, Balance> {
+	/// Called before `transfer` of `trade_type` is executed in `pool`.
+	///
+	/// Trade is not executed and its changes are reverted if any handler returns an error.
+	fn on_before_trade(
+		pool: &AccountId,
+		trade_type: IntentionType,
+		transfer: &AMMTransfer<AccountId, AssetPair, Balance>,
+	) -> DispatchResult;
+
+	/// Called when `transfer` of `trade_type` was executed in `pool`.
+	fn on_trade(pool: &AccountId, trade_type: IntentionType, transfer: &AMMTransfer<AccountId, AssetPair, Balance>);
+
+	/// Called when liquidity of `pool` changed.
+	fn on_liquidity_changed(pool: &AccountId, change: &LiquidityChange<AccountId, AssetId, Balance>);
+
+	/// Weight of `on_before_trade` and `on_trade` of a single trade.
+	fn on_trade_weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(5)]
+impl<AccountId, AssetId, AssetPair, Balance> AMMHandlers<AccountId, AssetId, AssetPair, Balance> for Tuple {
+	fn on_before_trade(
+		pool: &AccountId,
+		trade_type: IntentionType,
+		transfer: &AMMTransfer<AccountId, AssetPair, Balance>,
+	) -> DispatchResult {
+		for_tuples!( #( Tuple::on_before_trade(pool, trade_type, transfer)?; )* );
+		Ok(())
+	}
+
+	fn on_trade(pool: &AccountId, trade_type: IntentionType, transfer: &AMMTransfer<AccountId, AssetPair, Balance>) {
+		for_tuples!( #( Tuple::on_trade(pool, trade_type, transfer); )* );
+	}
+
+	fn on_liquidity_changed(pool: &AccountId, change: &LiquidityChange<AccountId, AssetId, Balance>) {
+		for_tuples!( #( Tuple::on_liquidity_changed(pool, change); )* );
+	}
+
+	fn on_trade_weight() -> Weight {
+		let mut weight: Weight = 0;
+		for_tuples!( #( weight = weight.saturating_add(Tuple::on_trade_weight()); )* );
+		weight
+	}
+}
+
+/// Receiver of AMM pool reserves borrowed for the duration of a single transaction.
+pub trait FlashLoanReceiver<AccountId, AssetId, Balance> {
+	/// Called after `amount` of `asset` was transferred to `who`.
+	///
+	/// `amount` plus `fee` has to be available in the free balance of `who` when the call returns,
+	/// otherwise the whole flash loan is reverted.
+	fn on_flash_loan(who: &AccountId, asset: AssetId, amount: Balance, fee: Balance) -> DispatchResult;
+}
+
 pub trait Resolver<AccountId, Intention, E> {
 	/// Resolve an intention directl via AMM pool.
 	fn resolve_single_intention(intention: &Intention);
@@ -110,3 +228,100 @@ pub trait Resolver<AccountId, Intention, E> {
 pub trait CurrencySwap<AccountId, Balance> {
 	fn swap_currency(who: &AccountId, fee: Balance) -> DispatchResult;
 }
+
+/// Conversion between assets and their locations on native chains, used by cross-chain transfers.
+pub trait AssetRegistryTrait<AssetId, AssetNativeLocation> {
+	/// Return native location of `asset_id`.
+	fn asset_to_location(asset_id: AssetId) -> Option<AssetNativeLocation>;
+
+	/// Return asset registered for native `location`.
+	fn location_to_asset(location: AssetNativeLocation) -> Option<AssetId>;
+}
+
+/// Log of privileged actions affecting economic parameters.
+pub trait AuditLog<AccountId> {
+	/// Record `action` performed by `proposer` - `None` if it was enacted by root or collective origin.
+	fn record(proposer: Option<AccountId>, action: PrivilegedAction);
+}
+
+impl<AccountId> AuditLog<AccountId> for () {
+	fn record(_proposer: Option<AccountId>, _action: PrivilegedAction) {}
+}
+
+/// Provider of voting weight of an account used by governance.
+pub trait VoteWeight<AccountId, Balance> {
+	/// Return voting weight of `who`.
+	fn vote_weight(who: &AccountId) -> Balance;
+}
+
+/// Provides price of an asset denominated in another asset.
+pub trait PriceProvider<AssetId, Price> {
+	/// Return amount of `asset_b` equivalent to one unit of `asset_a`.
+	fn price(asset_a: AssetId, asset_b: AssetId) -> Option<Price>;
+}
+
+/// Book of resting limit orders which exchange intentions are settled against before the AMM.
+pub trait OrderBook<AccountId, AssetPair, Balance, Price> {
+	/// Buy `assets.asset_out` for `assets.asset_in` on behalf of `who` from resting orders priced
+	/// at most `max_price` (amount of `asset_in` per unit of `asset_out`), best price first.
+	///
+	/// At most `max_amount_in` is paid and at most `max_amount_out` is bought.
+	/// Return amount paid and amount bought.
+	fn fill_orders(
+		who: &AccountId,
+		assets: AssetPair,
+		max_amount_in: Balance,
+		max_amount_out: Balance,
+		max_price: Price,
+	) -> (Balance, Balance);
+
+	/// Weight of filling orders of single asset pair.
+	fn fill_orders_weight() -> Weight;
+}
+
+impl<AccountId, AssetPair, Balance: Default, Price> OrderBook<AccountId, AssetPair, Balance, Price> for () {
+	fn fill_orders(
+		_who: &AccountId,
+		_assets: AssetPair,
+		_max_amount_in: Balance,
+		_max_amount_out: Balance,
+		_max_price: Price,
+	) -> (Balance, Balance) {
+		(Balance::default(), Balance::default())
+	}
+
+	fn fill_orders_weight() -> Weight {
+		0
+	}
+}
+
+/// Whitelist of accounts which cannot be dusted, e.g. pool accounts.
+pub trait DustRemovalAccountWhitelist<AccountId> {
+	/// Add `account` to the whitelist.
+	fn add_account(account: &AccountId) -> DispatchResult;
+
+	/// Remove `account` from the whitelist.
+	fn remove_account(account: &AccountId) -> DispatchResult;
+}
+
+impl<AccountId> DustRemovalAccountWhitelist<AccountId> for () {
+	fn add_account(_account: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+
+	fn remove_account(_account: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Provider of discounted trade fees based on native currency locked by an account.
+pub trait FeeDiscount<AccountId> {
+	/// Return trade fee of the discount tier of `who`. None if `who` is not eligible for any tier.
+	fn trade_fee(who: &AccountId) -> Option<crate::fee::Fee>;
+}
+
+impl<AccountId> FeeDiscount<AccountId> for () {
+	fn trade_fee(_who: &AccountId) -> Option<crate::fee::Fee> {
+		None
+	}
+}
@@ -0,0 +1,223 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Strongly-typed balance and price values.
+//!
+//! `Balance` and `Price` stay plain aliases because currency traits require primitive balances. Code which
+//! converts between the two should go through these newtypes instead, so that every multiplication and
+//! division is checked and the rounding direction is explicit.
+
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use frame_support::sp_runtime::{FixedPointNumber, FixedU128};
+use primitive_types::U256;
+
+use crate::{Balance, Price};
+
+/// Amount of an asset. Encodes exactly like `Balance`.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Debug, Encode, Decode, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TypedBalance(Balance);
+
+impl TypedBalance {
+	pub const fn from_balance(balance: Balance) -> Self {
+		Self(balance)
+	}
+
+	pub const fn to_balance(self) -> Balance {
+		self.0
+	}
+
+	pub fn is_zero(self) -> bool {
+		self.0 == 0
+	}
+
+	pub fn checked_add(self, other: Self) -> Option<Self> {
+		self.0.checked_add(other.0).map(Self)
+	}
+
+	pub fn checked_sub(self, other: Self) -> Option<Self> {
+		self.0.checked_sub(other.0).map(Self)
+	}
+
+	pub fn checked_mul(self, other: Self) -> Option<Self> {
+		self.0.checked_mul(other.0).map(Self)
+	}
+
+	pub fn checked_div(self, other: Self) -> Option<Self> {
+		self.0.checked_div(other.0).map(Self)
+	}
+
+	pub fn saturating_add(self, other: Self) -> Self {
+		Self(self.0.saturating_add(other.0))
+	}
+
+	pub fn saturating_sub(self, other: Self) -> Self {
+		Self(self.0.saturating_sub(other.0))
+	}
+}
+
+impl From<Balance> for TypedBalance {
+	fn from(balance: Balance) -> Self {
+		Self(balance)
+	}
+}
+
+impl From<TypedBalance> for Balance {
+	fn from(balance: TypedBalance) -> Self {
+		balance.0
+	}
+}
+
+/// Price of one unit of an asset expressed in another asset. Encodes exactly like `Price`.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Debug, Encode, Decode, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TypedPrice(Price);
+
+impl TypedPrice {
+	pub const fn from_price(price: Price) -> Self {
+		Self(price)
+	}
+
+	pub const fn to_price(self) -> Price {
+		self.0
+	}
+
+	/// Price of `n / d`, `None` if `d` is zero or the result does not fit.
+	pub fn checked_from_rational(n: TypedBalance, d: TypedBalance) -> Option<Self> {
+		FixedU128::checked_from_rational(n.0, d.0).map(Self)
+	}
+
+	pub fn is_zero(self) -> bool {
+		self.0.into_inner() == 0
+	}
+
+	/// Price of the asset pair in the opposite direction.
+	pub fn checked_reciprocal(self) -> Option<Self> {
+		self.0.reciprocal().map(Self)
+	}
+
+	/// Value of `amount` at this price, rounded down. `None` on overflow.
+	pub fn checked_mul_floor(self, amount: TypedBalance) -> Option<TypedBalance> {
+		self.0.checked_mul_int(amount.0).map(TypedBalance)
+	}
+
+	/// Value of `amount` at this price, rounded up. `None` on overflow.
+	pub fn checked_mul_ceil(self, amount: TypedBalance) -> Option<TypedBalance> {
+		let product = U256::from(self.0.into_inner()) * U256::from(amount.0);
+		let accuracy = U256::from(FixedU128::accuracy());
+		let mut value = product / accuracy;
+		if !(product % accuracy).is_zero() {
+			value = value.checked_add(U256::one())?;
+		}
+		if value > U256::from(Balance::MAX) {
+			return None;
+		}
+		Some(TypedBalance(value.low_u128()))
+	}
+
+	/// Value of `amount` at this price, `None` on overflow or if the value is not a whole amount.
+	pub fn checked_mul_exact(self, amount: TypedBalance) -> Option<TypedBalance> {
+		let floor = self.checked_mul_floor(amount)?;
+		let ceil = self.checked_mul_ceil(amount)?;
+		if floor == ceil {
+			Some(floor)
+		} else {
+			None
+		}
+	}
+
+	/// Value of `amount` at this price, rounded down and capped at `Balance::MAX`.
+	pub fn saturating_mul_floor(self, amount: TypedBalance) -> TypedBalance {
+		TypedBalance(self.0.saturating_mul_int(amount.0))
+	}
+}
+
+impl From<Price> for TypedPrice {
+	fn from(price: Price) -> Self {
+		Self(price)
+	}
+}
+
+impl From<TypedPrice> for Price {
+	fn from(price: TypedPrice) -> Self {
+		price.0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn encoding_matches_plain_types() {
+		let balance: Balance = 1_000_000_000_000;
+		let price = Price::saturating_from_rational(3, 2);
+
+		assert_eq!(TypedBalance::from(balance).encode(), balance.encode());
+		assert_eq!(TypedPrice::from(price).encode(), price.encode());
+		assert_eq!(
+			TypedBalance::decode(&mut &balance.encode()[..]),
+			Ok(TypedBalance::from(balance))
+		);
+	}
+
+	#[test]
+	fn balance_arithmetic_is_checked() {
+		let one = TypedBalance::from_balance(1);
+		let max = TypedBalance::from_balance(Balance::MAX);
+
+		assert_eq!(max.checked_add(one), None);
+		assert_eq!(TypedBalance::default().checked_sub(one), None);
+		assert_eq!(max.checked_mul(TypedBalance::from_balance(2)), None);
+		assert_eq!(one.checked_div(TypedBalance::default()), None);
+		assert_eq!(max.saturating_add(one), max);
+		assert_eq!(one.checked_add(one).map(TypedBalance::to_balance), Some(2));
+	}
+
+	#[test]
+	fn price_multiplication_rounding_is_explicit() {
+		let price = TypedPrice::checked_from_rational(1.into(), 3.into()).unwrap();
+		let amount = TypedBalance::from_balance(100);
+
+		assert_eq!(price.checked_mul_floor(amount), Some(33.into()));
+		assert_eq!(price.checked_mul_ceil(amount), Some(34.into()));
+		assert_eq!(price.checked_mul_exact(amount), None);
+
+		let price = TypedPrice::checked_from_rational(3.into(), 2.into()).unwrap();
+		assert_eq!(price.checked_mul_exact(amount), Some(150.into()));
+	}
+
+	#[test]
+	fn price_multiplication_overflow_is_reported() {
+		let price = TypedPrice::from_price(Price::saturating_from_integer(2));
+		let amount = TypedBalance::from_balance(Balance::MAX);
+
+		assert_eq!(price.checked_mul_floor(amount), None);
+		assert_eq!(price.checked_mul_ceil(amount), None);
+		assert_eq!(price.saturating_mul_floor(amount), amount);
+	}
+
+	#[test]
+	fn zero_price_is_rejected() {
+		assert_eq!(TypedPrice::checked_from_rational(1.into(), 0.into()), None);
+		assert!(TypedPrice::checked_from_rational(0.into(), 1.into()).unwrap().is_zero());
+		assert_eq!(TypedPrice::default().checked_reciprocal(), None);
+	}
+}
@@ -1,6 +1,9 @@
 use substrate_wasm_builder::WasmBuilder;
 
 fn main() {
+	// Hash of runtime metadata embedded into the runtime, see `metadata_hash` module.
+	println!("cargo:rerun-if-env-changed=RUNTIME_METADATA_HASH");
+
 	WasmBuilder::new()
 		.with_current_project()
 		.export_heap_base()
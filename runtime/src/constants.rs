@@ -24,6 +24,11 @@ pub mod currency {
 	pub const MILLICENTS: Balance = CENTS / 1_000;
 
 	pub const FORTUNE: Balance = u128::MAX;
+
+	/// Deposit for storing `items` storage items of total size `bytes`.
+	pub const fn deposit(items: u32, bytes: u32) -> Balance {
+		items as Balance * 2 * DOLLARS + (bytes as Balance) * 10 * CENTS
+	}
 }
 
 pub mod time {
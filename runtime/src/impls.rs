@@ -15,9 +15,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::Balances;
-use primitives::Balance;
-use sp_runtime::traits::Convert;
+use crate::{AccountId, AssetRegistry, Balances, HDXAssetId, Inflation, PricePublisher, XYK};
+use frame_support::traits::{Currency, Get};
+use primitives::{
+	asset::AssetPair,
+	traits::{PriceProvider, VoteWeight, AMM},
+	AssetId, Balance, Price,
+};
+use sp_runtime::{
+	traits::{Convert, Saturating, Zero},
+	FixedPointNumber,
+};
 
 /// Struct that handles the conversion of Balance -> `u64`. This is used for staking's election
 /// calculation.
@@ -40,3 +48,94 @@ impl Convert<u128, Balance> for CurrencyToVoteHandler {
 		x * Self::factor()
 	}
 }
+
+/// Voting weight of an account for governance.
+///
+/// Counts HDX held directly including staked HDX, which stays in the stash account under a lock,
+/// and HDX provided to XYK pools by decomposing held pool shares.
+pub struct HydraVoteWeight;
+
+impl VoteWeight<AccountId, Balance> for HydraVoteWeight {
+	fn vote_weight(who: &AccountId) -> Balance {
+		Balances::total_balance(who).saturating_add(XYK::pooled_asset_balance(who, HDXAssetId::get()))
+	}
+}
+
+/// Staking era payout following HydraDX inflation schedule instead of NPoS reward curve.
+pub struct HydraEraPayout;
+
+impl pallet_staking::EraPayout<Balance> for HydraEraPayout {
+	fn era_payout(_total_staked: Balance, total_issuance: Balance, era_duration_millis: u64) -> (Balance, Balance) {
+		Inflation::era_payout(total_issuance, era_duration_millis)
+	}
+}
+
+/// Value of liquidity in XYK pool denominated in HDX.
+///
+/// Pools without HDX are valued by spot price of one of their assets in its HDX pool.
+/// Zero if the pool does not exist or none of its assets can be priced in HDX.
+pub fn pool_value_in_native(assets: &AssetPair) -> Balance {
+	let native = HDXAssetId::get();
+
+	XYK::get_pool_balances(XYK::get_pair_id(*assets))
+		.unwrap_or_default()
+		.into_iter()
+		.find_map(|(asset, reserve)| {
+			if asset == native {
+				Some(reserve)
+			} else if XYK::exists(AssetPair {
+				asset_in: asset,
+				asset_out: native,
+			}) {
+				Some(XYK::get_spot_price_unchecked(asset, native, reserve))
+			} else {
+				None
+			}
+		})
+		.map(|value| value.saturating_mul(2))
+		.unwrap_or_default()
+}
+
+/// Oracle price of assets accepted by bond programs.
+///
+/// XYK share tokens are valued by published prices of pool assets, other assets by their published price.
+pub struct BondPriceProvider;
+
+impl PriceProvider<AssetId, Price> for BondPriceProvider {
+	fn price(asset_a: AssetId, asset_b: AssetId) -> Option<Price> {
+		match share_token_pool(asset_a) {
+			Some(pool) => share_token_price(&pool, asset_b),
+			None => PricePublisher::price(asset_a, asset_b),
+		}
+	}
+}
+
+/// Return pool of XYK share token `asset`, `None` if `asset` is not a share token.
+fn share_token_pool(asset: AssetId) -> Option<AccountId> {
+	let pair = AssetRegistry::asset_names(asset).and_then(|name| AssetPair::from_name(&name))?;
+
+	let pool = XYK::get_pair_id(pair);
+
+	if XYK::exists(pair) && XYK::share_token(&pool) == asset {
+		Some(pool)
+	} else {
+		None
+	}
+}
+
+/// Value of one share of `pool` in `asset` given by published prices of pool assets.
+fn share_token_price(pool: &AccountId, asset: AssetId) -> Option<Price> {
+	let value =
+		XYK::get_pool_balances(pool.clone())?
+			.into_iter()
+			.try_fold(Balance::zero(), |acc, (pool_asset, reserve)| {
+				let value = if pool_asset == asset {
+					reserve
+				} else {
+					PricePublisher::price(pool_asset, asset)?.checked_mul_int(reserve)?
+				};
+				Some(acc.saturating_add(value))
+			})?;
+
+	Price::checked_from_rational(value, XYK::total_liquidity(pool))
+}
@@ -38,8 +38,8 @@ use sp_core::{
 	OpaqueMetadata,
 };
 use sp_runtime::traits::{
-	BlakeTwo256, Block as BlockT, Extrinsic as ExtrinsicT, IdentifyAccount, IdentityLookup, NumberFor, OpaqueKeys,
-	SaturatedConversion, Verify,
+	AccountIdConversion, BlakeTwo256, Block as BlockT, Extrinsic as ExtrinsicT, IdentifyAccount, IdentityLookup,
+	NumberFor, OpaqueKeys, SaturatedConversion, Verify,
 };
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
@@ -52,6 +52,7 @@ use sp_std::prelude::*;
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
+use frame_support::traits::Get;
 use frame_system::{limits, EnsureOneOf, EnsureRoot};
 // A few exports that help ease life for downstream crates.
 pub use frame_support::{
@@ -67,10 +68,11 @@ pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
-pub use sp_runtime::{curve::PiecewiseLinear, Perbill, Permill, Perquintill};
+pub use sp_runtime::{Perbill, Permill, Perquintill};
 
 use pallet_session::historical as session_historical;
 
+use pallet_asset_registry_rpc_runtime_api as asset_registry_rpc;
 use pallet_xyk_rpc_runtime_api as xyk_rpc;
 
 use orml_currencies::BasicCurrencyAdapter;
@@ -148,10 +150,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("hydra-dx"),
 	impl_name: create_runtime_str!("hydra-dx"),
 	authoring_version: 1,
-	spec_version: 15,
+	spec_version: 16,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 };
 
 /// The BABE epoch configuration at genesis.
@@ -182,6 +184,10 @@ pub fn native_version() -> NativeVersion {
 pub struct BaseFilter;
 impl Filter<Call> for BaseFilter {
 	fn filter(call: &Call) -> bool {
+		if !pallet_transaction_pause::PausedTransactionFilter::<Runtime>::filter(call) {
+			return false;
+		}
+
 		match call {
 			Call::AuthorityDiscovery(_)
 			| Call::Authorship(_)
@@ -202,11 +208,19 @@ impl Filter<Call> for BaseFilter {
 			| Call::TechnicalCommittee(_)
 			| Call::Timestamp(_)
 			| Call::Tips(_)
+			| Call::Bounties(_)
 			| Call::Treasury(_)
 			| Call::Identity(_)
+			| Call::Inflation(_)
 			| Call::Offences(_)
 			| Call::Utility(_)
-			| Call::Sudo(_) => true,
+			| Call::Proxy(_)
+			| Call::Multisig(_)
+			| Call::Sudo(_)
+			| Call::TransactionPause(_)
+			| Call::FeeMultiplier(_)
+			| Call::CircuitBreaker(_)
+			| Call::PricePublisher(pallet_price_publisher::Call::submit_prices(..)) => true,
 
 			Call::XYK(_)
 			| Call::AssetRegistry(_)
@@ -214,6 +228,18 @@ impl Filter<Call> for BaseFilter {
 			| Call::Exchange(_)
 			| Call::Faucet(_)
 			| Call::MultiTransactionPayment(_)
+			| Call::Streams(_)
+			| Call::Listing(_)
+			| Call::PricePublisher(_)
+			| Call::OTC(_)
+			| Call::MigrationManager(_)
+			| Call::Duster(_)
+			| Call::Referrals(_)
+			| Call::Bonds(_)
+			| Call::DCA(_)
+			| Call::Buyback(_)
+			| Call::FeeDiscount(_)
+			| Call::AssetMigration(_)
 			| Call::Tokens(_) => false,
 		}
 	}
@@ -238,6 +264,8 @@ parameter_types! {
 			weights.max_total = Some(MAXIMUM_BLOCK_WEIGHT);
 			// Operational transactions have an extra reserved space, so that they
 			// are included even if block reached `MAXIMUM_BLOCK_WEIGHT`.
+			// Time critical calls which must not be crowded out by regular trades are declared as operational,
+			// see `priority::PrioritizeOperational`.
 			weights.reserved = Some(
 				MAXIMUM_BLOCK_WEIGHT - NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT,
 			);
@@ -248,6 +276,8 @@ parameter_types! {
 	pub ExtrinsicPaymentExtraWeight: Weight =  <Runtime as pallet_transaction_multi_payment::Config>::WeightInfo::swap_currency();
 	pub ExtrinsicBaseWeight: Weight = frame_support::weights::constants::ExtrinsicBaseWeight::get() + ExtrinsicPaymentExtraWeight::get();
 	pub const SS58Prefix: u8 = 63;
+	/// Priority added to operational transactions on top of the fee based priority.
+	pub const OperationalTransactionPriority: TransactionPriority = TransactionPriority::max_value() / 8;
 }
 
 // Configure FRAME pallets to include in runtime.
@@ -351,17 +381,34 @@ impl pallet_balances::Config for Runtime {
 parameter_types! {
 	pub const TransactionByteFee: Balance = 1;
 	pub const MultiPaymentCurrencySetFee: Pays = Pays::No;
-
-	pub const TargetBlockFullness: Perquintill = Perquintill::from_percent(25);
-	pub AdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(1, 100_000);
-	pub MinimumMultiplier: Multiplier = Multiplier::saturating_from_rational(1, 1_000_000_000u128);
+	/// Maximum deviation of spot price from oracle price of a currency in which fees are paid
+	pub const MaxPriceDeviation: Permill = Permill::from_percent(5);
+	pub const MaxAcceptedCurrencies: u32 = 20;
+	/// Number of blocks after which fee allowances of sponsored accounts are renewed
+	pub const SponsorshipPeriod: BlockNumber = DAYS;
+
+	/// Fee adjustment used until changed by governance. Sustained full blocks raise fees ~25x per day
+	/// and fees never drop below the base fee, so a quiet period does not make the next trading burst cheap.
+	pub FeeMultiplierDefaultParams: pallet_fee_multiplier::FeeMultiplierParams =
+		pallet_fee_multiplier::FeeMultiplierParams {
+			target_block_fullness: Perquintill::from_percent(25),
+			adjustment_variable: Multiplier::saturating_from_rational(3, 10_000),
+			minimum_multiplier: Multiplier::from(1),
+		};
 }
 
 impl pallet_transaction_payment::Config for Runtime {
 	type OnChargeTransaction = MultiCurrencyAdapter<Balances, (), MultiTransactionPayment>;
 	type TransactionByteFee = TransactionByteFee;
 	type WeightToFee = IdentityFee<Balance>;
-	type FeeMultiplierUpdate = TargetedFeeAdjustment<Self, TargetBlockFullness, AdjustmentVariable, MinimumMultiplier>;
+	type FeeMultiplierUpdate = pallet_fee_multiplier::TargetedFeeAdjustment<Self>;
+}
+
+impl pallet_fee_multiplier::Config for Runtime {
+	type Event = Event;
+	type UpdateOrigin = EnsureRootOrHalfCouncil;
+	type DefaultParams = FeeMultiplierDefaultParams;
+	type WeightInfo = pallet_fee_multiplier::weights::HydraWeight<Runtime>;
 }
 
 impl pallet_transaction_multi_payment::Config for Runtime {
@@ -372,6 +419,13 @@ impl pallet_transaction_multi_payment::Config for Runtime {
 	type WeightInfo = pallet_transaction_multi_payment::weights::HydraWeight<Runtime>;
 	type WithdrawFeeForSetCurrency = MultiPaymentCurrencySetFee;
 	type WeightToFee = IdentityFee<Balance>;
+	type ManageMembersOrigin = EnsureRootOrHalfCouncil;
+	type SpotPriceProvider = pallet_transaction_multi_payment::SpotPrice<AccountId, XYK>;
+	type FeePriceProvider = pallet_transaction_multi_payment::OraclePrice<Runtime>;
+	type MaxPriceDeviation = MaxPriceDeviation;
+	type AuditLog = AuditLog;
+	type MaxAcceptedCurrencies = MaxAcceptedCurrencies;
+	type SponsorshipPeriod = SponsorshipPeriod;
 }
 
 impl pallet_genesis_history::Config for Runtime {}
@@ -387,15 +441,36 @@ parameter_type_with_key! {
 	};
 }
 
+// Governance origins
+
 type EnsureRootOrHalfCouncil = EnsureOneOf<
 	AccountId,
 	EnsureRoot<AccountId>,
 	pallet_collective::EnsureProportionMoreThan<_1, _2, AccountId, CouncilCollective>,
 >;
 
+type EnsureRootOrTwoThirdsCouncil = EnsureOneOf<
+	AccountId,
+	EnsureRoot<AccountId>,
+	pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, CouncilCollective>,
+>;
+
+type EnsureRootOrTwoThirdsTechnicalCommittee = EnsureOneOf<
+	AccountId,
+	EnsureRoot<AccountId>,
+	pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, TechnicalCollective>,
+>;
+
+// On-chain identities of validators and council candidates.
+//
+// Registrars providing judgements are added by `EnsureRootOrHalfCouncil`.
+// Operators running several nodes can link them to one identity as sub-identities.
 parameter_types! {
+	/// Deposit reserved for setting an identity.
 	pub const BasicDeposit: Balance = 5 * DOLLARS;
+	/// Deposit reserved for each additional field of an identity.
 	pub const FieldDeposit: Balance = DOLLARS;
+	/// Deposit reserved for each sub-identity.
 	pub const SubAccountDeposit: Balance = 5 * DOLLARS;
 	pub const MaxSubAccounts: u32 = 100;
 	pub const MaxAdditionalFields: u32 = 100;
@@ -423,6 +498,52 @@ impl pallet_utility::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	/// Deposit reserved for having a proxy - storage item of 8 bytes.
+	pub const ProxyDepositBase: Balance = deposit(1, 8);
+	/// Deposit reserved for each additional proxy - 33 bytes of account id and proxy type.
+	pub const ProxyDepositFactor: Balance = deposit(0, 33);
+	pub const MaxProxies: u16 = 32;
+	/// Deposit reserved for having an announcement - storage item of 8 bytes.
+	pub const AnnouncementDepositBase: Balance = deposit(1, 8);
+	/// Deposit reserved for each announcement - 68 bytes of account id, call hash and block number.
+	pub const AnnouncementDepositFactor: Balance = deposit(0, 68);
+	pub const MaxPending: u32 = 32;
+}
+
+impl pallet_proxy::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type Currency = Balances;
+	type ProxyType = proxy::ProxyType;
+	type ProxyDepositBase = ProxyDepositBase;
+	type ProxyDepositFactor = ProxyDepositFactor;
+	type MaxProxies = MaxProxies;
+	type WeightInfo = ();
+	type MaxPending = MaxPending;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = AnnouncementDepositBase;
+	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+}
+
+parameter_types! {
+	/// Deposit reserved for creating a multisig operation - storage item of 88 bytes.
+	pub const MultisigDepositBase: Balance = deposit(1, 88);
+	/// Deposit reserved for each signatory - 32 bytes of account id.
+	pub const MultisigDepositFactor: Balance = deposit(0, 32);
+	pub const MaxSignatories: u16 = 100;
+}
+
+impl pallet_multisig::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type Currency = Balances;
+	type DepositBase = MultisigDepositBase;
+	type DepositFactor = MultisigDepositFactor;
+	type MaxSignatories = MaxSignatories;
+	type WeightInfo = ();
+}
+
 /// ORML Configurations
 
 impl orml_tokens::Config for Runtime {
@@ -446,11 +567,22 @@ impl orml_currencies::Config for Runtime {
 /// HydraDX Pallets configurations
 
 impl pallet_asset_registry::Config for Runtime {
+	type Event = Event;
 	type AssetId = AssetId;
+	type AssetNativeLocation = primitives::asset::AssetLocation;
+	type RegistryOrigin = EnsureRootOrHalfCouncil;
+	type WeightInfo = pallet_asset_registry::weights::HydraWeight<Runtime>;
 }
 
 parameter_types! {
 	pub ExchangeFee: fee::Fee = fee::Fee::default();
+	pub ProtocolFee: fee::Fee = fee::Fee { numerator: 1, denominator: 6 };
+	pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account();
+	pub const PoolOwnerInactivityPeriod: BlockNumber = 30 * DAYS;
+	/// Maximum reserves of a pool which can be destroyed by governance
+	pub const MaxPoolDust: Balance = 1_000_000;
+	pub const MinPoolLiquidity: Balance = 1_000_000;
+	pub FlashLoanFee: fee::Fee = fee::Fee { numerator: 9, denominator: 10_000 };
 }
 
 impl pallet_xyk::Config for Runtime {
@@ -460,10 +592,28 @@ impl pallet_xyk::Config for Runtime {
 	type NativeAssetId = HDXAssetId;
 	type WeightInfo = pallet_xyk::weights::HydraWeight<Runtime>;
 	type GetExchangeFee = ExchangeFee;
+	type DefaultProtocolFee = ProtocolFee;
+	type TreasuryAccount = TreasuryAccount;
+	type ProtocolFeeOrigin = EnsureRootOrTwoThirdsCouncil;
+	type PoolOwnerInactivityPeriod = PoolOwnerInactivityPeriod;
+	type MaxPoolDust = MaxPoolDust;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type PoolGovernanceOrigin = EnsureRootOrHalfCouncil;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type Scheduler = Scheduler;
+	type AuditLog = AuditLog;
+	type AMMHandler = (CircuitBreaker, Referrals, Stats, Exchange);
+	type FlashLoanFee = FlashLoanFee;
+	type NonDustableWhitelistHandler = Duster;
+	type FeeDiscount = FeeDiscount;
 }
 
 parameter_types! {
 	pub ClaimMessagePrefix: &'static [u8] = b"I hereby claim all my HDX tokens to wallet:";
+	pub const ClaimDelay: BlockNumber = 10 * MINUTES;
+	pub const MaxClaimsPerBlock: u32 = 100;
+	pub const MaxClaimsSweptPerCall: u32 = 500;
 }
 
 impl pallet_claims::Config for Runtime {
@@ -472,28 +622,353 @@ impl pallet_claims::Config for Runtime {
 	type Prefix = ClaimMessagePrefix;
 	type WeightInfo = pallet_claims::weights::HydraWeight<Runtime>;
 	type CurrencyBalance = Balance;
+	type FreezeOrigin = EnsureRootOrHalfCouncil;
+	type CancelOrigin = EnsureRootOrTwoThirdsTechnicalCommittee;
+	type ClaimDelay = ClaimDelay;
+	type MaxClaimsPerBlock = MaxClaimsPerBlock;
+	type TreasuryAccount = TreasuryAccount;
+	type MaxClaimsSweptPerCall = MaxClaimsSweptPerCall;
+}
+
+parameter_types! {
+	/// Intention fee charged when intention queue of an asset pair is full
+	pub const MaxIntentionFee: Balance = 10 * DOLLARS;
+	pub const IntentionQueueCap: u32 = 100;
+	/// Maximum number of intentions of an asset pair in a block, on_finalize is benchmarked up to this number
+	pub const MaxIntentionsPerPair: u32 = 1_000;
+	/// Maximum deviation of intention execution price from spot price at the start of the block
+	pub const MaxIntentionPriceDeviation: Permill = Permill::from_percent(10);
+	/// Maximum number of signed intentions a relayer can submit in one batch
+	pub const MaxSignedIntentionsPerBatch: u32 = 50;
+	/// Maximum weight of intention resolution at the end of a block, remaining intentions are carried over
+	pub MaxIntentionResolutionWeight: Weight = Perbill::from_percent(25) * MAXIMUM_BLOCK_WEIGHT;
+}
+
+/// Priority fees of intentions are paid to the author of the block in which they are resolved.
+/// Treasury receives them when the author is not known.
+pub struct IntentionTipReceiver;
+impl Get<AccountId> for IntentionTipReceiver {
+	fn get() -> AccountId {
+		let author = Authorship::author();
+		if author == AccountId::default() {
+			TreasuryAccount::get()
+		} else {
+			author
+		}
+	}
 }
 
 impl pallet_exchange::Config for Runtime {
 	type Event = Event;
 	type AMMPool = XYK;
 	type Resolver = Exchange;
+	type OrderBook = OTC;
 	type Currency = Currencies;
 	type WeightInfo = pallet_exchange::weights::HydraWeight<Runtime>;
+	type NativeAssetId = HDXAssetId;
+	type MaxIntentionFee = MaxIntentionFee;
+	type IntentionQueueCap = IntentionQueueCap;
+	type MaxIntentionsPerPair = MaxIntentionsPerPair;
+	type MaxPriceDeviation = MaxIntentionPriceDeviation;
+	type IntentionSignature = Signature;
+	type IntentionSigner = <Signature as Verify>::Signer;
+	type MaxSignedIntentionsPerBatch = MaxSignedIntentionsPerBatch;
+	type MaxResolutionWeight = MaxIntentionResolutionWeight;
+	type TipReceiver = IntentionTipReceiver;
+}
+
+parameter_types! {
+	pub const DripPeriod: BlockNumber = EPOCH_DURATION_IN_BLOCKS;
+	pub const MaxDripsPerPeriod: u32 = 3;
+	pub const MaxDripBundleLength: u32 = 10;
 }
 
 impl pallet_faucet::Config for Runtime {
 	type Event = Event;
 	type Currency = Currencies;
+	type DripPeriod = DripPeriod;
+	type MaxDripsPerPeriod = MaxDripsPerPeriod;
+	type MaxDripBundleLength = MaxDripBundleLength;
+}
+
+parameter_types! {
+	pub AMMSellWeight: Weight = <pallet_xyk::weights::HydraWeight<Runtime> as pallet_xyk::weights::WeightInfo>::sell();
+}
+
+impl pallet_streams::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type AMMPool = XYK;
+	type AMMSellWeight = AMMSellWeight;
+	type WeightInfo = pallet_streams::weights::HydraWeight<Runtime>;
+}
+
+parameter_types! {
+	/// Only native asset holders can trade in a newly listed pool during its bootstrap phase
+	pub const BootstrapAccessAsset: AssetId = CORE_ASSET_ID;
+	pub const BootstrapPeriod: BlockNumber = 3 * DAYS;
+}
+
+impl pallet_listing::Config for Runtime {
+	type Event = Event;
+	type MultiCurrency = Currencies;
+	type ListingOrigin = EnsureRootOrHalfCouncil;
+	type BootstrapAccessAsset = BootstrapAccessAsset;
+	type BootstrapPeriod = BootstrapPeriod;
+	type WeightInfo = pallet_listing::weights::HydraWeight<Runtime>;
+}
+
+impl pallet_asset_migration::Config for Runtime {
+	type Event = Event;
+	type MultiCurrency = Currencies;
+	type MigrationOrigin = EnsureRootOrHalfCouncil;
+	type WeightInfo = pallet_asset_migration::weights::HydraWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxAuditLogEntries: u32 = 1000;
+}
+
+impl pallet_audit_log::Config for Runtime {
+	type Event = Event;
+	type MaxEntries = MaxAuditLogEntries;
+}
+
+parameter_types! {
+	pub const StatsPeriodLength: BlockNumber = DAYS;
+	pub const StatsRetentionPeriods: u32 = 30;
+}
+
+impl pallet_stats::Config for Runtime {
+	type Currency = Currencies;
+	type PeriodLength = StatsPeriodLength;
+	type RetentionPeriods = StatsRetentionPeriods;
+}
+
+parameter_types! {
+	pub const MaxVolumePerBlock: Permill = Permill::from_percent(10);
+	pub const KeeperVolumePerBlock: Permill = Permill::from_percent(2);
+}
+
+impl pallet_circuit_breaker::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type MaxVolumePerBlock = MaxVolumePerBlock;
+	type KeeperVolumePerBlock = KeeperVolumePerBlock;
+	type UpdateOrigin = EnsureRootOrHalfCouncil;
+	type WeightInfo = pallet_circuit_breaker::weights::HydraWeight<Runtime>;
+}
+
+parameter_types! {
+	pub InflationDefaultSchedule: pallet_inflation::InflationSchedule = pallet_inflation::InflationSchedule {
+		initial_rate: Perbill::from_percent(8),
+		step_decay: Perbill::from_percent(1),
+		step_duration: pallet_inflation::MILLISECS_PER_YEAR,
+		minimum_rate: Perbill::from_percent(2),
+		stakers_share: Perbill::from_percent(80),
+	};
+}
+
+impl pallet_inflation::Config for Runtime {
+	type Event = Event;
+	type DefaultSchedule = InflationDefaultSchedule;
+	type UpdateOrigin = EnsureRootOrTwoThirdsCouncil;
+	type WeightInfo = pallet_inflation::weights::HydraWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const PricePublishInterval: BlockNumber = 1;
+	pub const MaxPricePairs: u32 = 20;
+	pub const PricePublisherUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 4;
+}
+
+impl pallet_price_publisher::Config for Runtime {
+	type Event = Event;
+	type AuthorityId = pallet_price_publisher::crypto::PricePublisherAuthId;
+	type PriceProvider = pallet_transaction_multi_payment::SpotPrice<AccountId, XYK>;
+	type ManageOrigin = EnsureRootOrHalfCouncil;
+	type PublishInterval = PricePublishInterval;
+	type MaxPairs = MaxPricePairs;
+	type UnsignedPriority = PricePublisherUnsignedPriority;
+	type WeightInfo = pallet_price_publisher::weights::HydraWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxOrdersPerPair: u32 = 20;
+}
+
+impl pallet_otc::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type MaxOrdersPerPair = MaxOrdersPerPair;
+	type WeightInfo = pallet_otc::weights::HydraWeight<Runtime>;
+}
+
+parameter_types! {
+	pub MigrationMaxWeightPerBlock: Weight = Perbill::from_percent(25) * BlockWeights::get().max_block;
+	pub const MaxMigrationOperations: u32 = 10;
+}
+
+impl pallet_migration_manager::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type ScheduleOrigin = EnsureRoot<AccountId>;
+	type MaxWeightPerBlock = MigrationMaxWeightPerBlock;
+	type MaxOperations = MaxMigrationOperations;
+	type WeightInfo = pallet_migration_manager::weights::HydraWeight<Runtime>;
+}
+
+// Minimal balances of assets are set in the asset registry, accounts holding less can be dusted
+parameter_type_with_key! {
+	pub MinCurrencyDeposits: |currency_id: AssetId| -> Balance {
+		AssetRegistry::minimal_balance(*currency_id)
+	};
+}
+
+parameter_types! {
+	pub const DustingReward: Balance = 10 * CENTS;
+}
+
+impl pallet_duster::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type MinCurrencyDeposits = MinCurrencyDeposits;
+	type NativeAssetId = HDXAssetId;
+	type Reward = DustingReward;
+	type TreasuryAccount = TreasuryAccount;
+	type WhitelistUpdateOrigin = EnsureRootOrHalfCouncil;
+	type WeightInfo = pallet_duster::weights::HydraWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const ReferralsPalletId: PalletId = PalletId(*b"referral");
+	pub const MinReferralCodeLength: u32 = 4;
+	pub const MaxReferralCodeLength: u32 = 16;
+}
+
+parameter_type_with_key! {
+	pub ReferralTierReferees: |tier: pallet_referrals::Tier| -> u32 {
+		match tier {
+			pallet_referrals::Tier::Bronze => 0,
+			pallet_referrals::Tier::Silver => 10,
+			pallet_referrals::Tier::Gold => 100,
+		}
+	};
+}
+
+parameter_type_with_key! {
+	pub ReferralTierRewardShare: |tier: pallet_referrals::Tier| -> Permill {
+		match tier {
+			pallet_referrals::Tier::Bronze => Permill::from_percent(5),
+			pallet_referrals::Tier::Silver => Permill::from_percent(10),
+			pallet_referrals::Tier::Gold => Permill::from_percent(20),
+		}
+	};
+}
+
+impl pallet_referrals::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type PalletId = ReferralsPalletId;
+	type MinCodeLength = MinReferralCodeLength;
+	type MaxCodeLength = MaxReferralCodeLength;
+	type TierReferees = ReferralTierReferees;
+	type TierRewardShare = ReferralTierRewardShare;
+	type NonDustableWhitelistHandler = Duster;
+	type WeightInfo = pallet_referrals::weights::HydraWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const BondsPalletId: PalletId = PalletId(*b"py/bonds");
+}
+
+impl pallet_bonds::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type NativeAssetId = HDXAssetId;
+	type PriceProvider = impls::BondPriceProvider;
+	type TreasuryAccount = TreasuryAccount;
+	type PalletId = BondsPalletId;
+	type ProgramOrigin = EnsureRootOrHalfCouncil;
+	type WeightInfo = pallet_bonds::weights::HydraWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const DCAExecutionFee: Balance = 10 * DOLLARS;
+	pub const DCAMinPeriod: BlockNumber = HOURS;
+	pub const DCAMaxExecutionOffset: u32 = 10;
+	pub const DCAMaxExecutionsPerBlock: u32 = 20;
+	pub const DCAMaxSlippage: Permill = Permill::from_percent(5);
+}
+
+impl pallet_dca::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type AMMPool = XYK;
+	type AMMSellWeight = AMMSellWeight;
+	type NativeAssetId = HDXAssetId;
+	type ExecutionFee = DCAExecutionFee;
+	type TreasuryAccount = TreasuryAccount;
+	type MinPeriod = DCAMinPeriod;
+	type MaxExecutionOffset = DCAMaxExecutionOffset;
+	type MaxExecutionsPerBlock = DCAMaxExecutionsPerBlock;
+	type MaxSlippage = DCAMaxSlippage;
+	type RandomnessSource = RandomnessCollectiveFlip;
+	type WeightInfo = pallet_dca::weights::HydraWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const BuybackPeriod: BlockNumber = DAYS;
+	pub const MaxBuybackAssets: u32 = 10;
+	pub const BuybackMaxSlippage: Permill = Permill::from_percent(3);
+}
+
+impl pallet_buyback::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type AMMPool = XYK;
+	type AMMSellWeight = AMMSellWeight;
+	type NativeAssetId = HDXAssetId;
+	type TreasuryAccount = TreasuryAccount;
+	type BuybackPeriod = BuybackPeriod;
+	type MaxBuybackAssets = MaxBuybackAssets;
+	type MaxSlippage = BuybackMaxSlippage;
+	type UpdateOrigin = EnsureRootOrHalfCouncil;
+	type WeightInfo = pallet_buyback::weights::HydraWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxFeeDiscountTiers: u32 = 10;
+}
+
+impl pallet_fee_discount::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type NativeAssetId = HDXAssetId;
+	type UpdateOrigin = EnsureRootOrHalfCouncil;
+	type MaxTiers = MaxFeeDiscountTiers;
+	type WeightInfo = pallet_fee_discount::weights::HydraWeight<Runtime>;
+}
+
+impl pallet_transaction_pause::Config for Runtime {
+	type Event = Event;
+	type UpdateOrigin = EnsureRootOrTwoThirdsTechnicalCommittee;
+	type WeightInfo = pallet_transaction_pause::weights::HydraWeight<Runtime>;
 }
 
 pub mod constants;
 /// Staking pallets configurations
 pub mod impls;
+/// Verification of runtime metadata by offline signers
+pub mod metadata_hash;
+/// Transaction pool priority of operational transactions
+pub mod priority;
+
+pub mod proxy;
 use constants::{currency::*, time::*};
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
 pub use pallet_staking::StakerStatus;
-use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
+use pallet_transaction_payment::Multiplier;
 use primitives::fee;
 
 parameter_types! {
@@ -507,17 +982,6 @@ impl pallet_authorship::Config for Runtime {
 	type EventHandler = (Staking, ImOnline);
 }
 
-pallet_staking_reward_curve::build! {
-	const REWARD_CURVE: PiecewiseLinear<'static> = curve!(
-		min_inflation: 0_040_000,
-		max_inflation: 0_080_000,
-		ideal_stake: 0_160_000,
-		falloff: 1_000_000,
-		max_piece_count: 40,
-		test_precision: 0_005_000,
-	);
-}
-
 sp_npos_elections::generate_solution_type!(
 	#[compact]
 	pub struct NposCompactSolution16::<
@@ -534,7 +998,6 @@ parameter_types! {
 	// SlashDeferDuration should be less than BondingDuration
 	// https://github.com/paritytech/substrate/blob/49a4103f4bfef55be20a5c6d26e18ff3003c3353/frame/staking/src/lib.rs#L1402
 	pub const SlashDeferDuration: pallet_staking::EraIndex =  28 - 1;
-	pub const RewardCurve: &'static PiecewiseLinear<'static> = &REWARD_CURVE;
 	pub const MaxNominatorRewardedPerValidator: u32 = 64;
 	pub const ElectionLookahead: BlockNumber = EPOCH_DURATION_IN_BLOCKS / 4;
 	pub const MaxIterations: u32 = 10;
@@ -563,7 +1026,7 @@ impl pallet_staking::Config for Runtime {
 	// A super-majority of the council can cancel the slash.
 	type SlashCancelOrigin = SlashCancelOrigin;
 	type SessionInterface = Self;
-	type EraPayout = pallet_staking::ConvertCurve<RewardCurve>;
+	type EraPayout = impls::HydraEraPayout;
 	type NextNewSession = Session;
 	type MaxNominatorRewardedPerValidator = MaxNominatorRewardedPerValidator;
 	type WeightInfo = ();
@@ -611,11 +1074,7 @@ impl pallet_democracy::Config for Runtime {
 	>;
 	/// Two thirds of the technical committee can have an ExternalMajority/ExternalDefault vote
 	/// be tabled immediately and with a shorter voting/enactment period.
-	type FastTrackOrigin = frame_system::EnsureOneOf<
-		AccountId,
-		pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, TechnicalCollective>,
-		frame_system::EnsureRoot<AccountId>,
-	>;
+	type FastTrackOrigin = EnsureRootOrTwoThirdsTechnicalCommittee;
 	type InstantOrigin = frame_system::EnsureOneOf<
 		AccountId,
 		pallet_collective::EnsureProportionAtLeast<_1, _1, AccountId, TechnicalCollective>,
@@ -624,11 +1083,7 @@ impl pallet_democracy::Config for Runtime {
 	type InstantAllowed = InstantAllowed;
 	type FastTrackVotingPeriod = FastTrackVotingPeriod;
 	// To cancel a proposal which has been passed, 2/3 of the council must agree to it.
-	type CancellationOrigin = EnsureOneOf<
-		AccountId,
-		pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, CouncilCollective>,
-		EnsureRoot<AccountId>,
-	>;
+	type CancellationOrigin = EnsureRootOrTwoThirdsCouncil;
 	// To cancel a proposal before it has been passed, the technical committee must be unanimous or
 	// Root must agree.
 	type CancelProposalOrigin = EnsureOneOf<
@@ -715,8 +1170,8 @@ impl pallet_treasury::Config for Runtime {
 	type SpendPeriod = SpendPeriod;
 	type Burn = Burn;
 	type BurnDestination = ();
-	type WeightInfo = ();
-	type SpendFunds = ();
+	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+	type SpendFunds = Bounties;
 }
 
 parameter_types! {
@@ -736,7 +1191,27 @@ impl pallet_tips::Config for Runtime {
 	type TipCountdown = TipCountdown;
 	type TipFindersFee = TipFindersFee;
 	type TipReportDepositBase = TipReportDepositBase;
-	type WeightInfo = ();
+	type WeightInfo = pallet_tips::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const BountyDepositBase: Balance = 10 * DOLLARS;
+	pub const BountyDepositPayoutDelay: BlockNumber = 4 * DAYS;
+	pub const BountyUpdatePeriod: BlockNumber = 35 * DAYS;
+	pub const BountyCuratorDeposit: Permill = Permill::from_percent(50);
+	pub const BountyValueMinimum: Balance = 100 * DOLLARS;
+}
+
+impl pallet_bounties::Config for Runtime {
+	type Event = Event;
+	type BountyDepositBase = BountyDepositBase;
+	type BountyDepositPayoutDelay = BountyDepositPayoutDelay;
+	type BountyUpdatePeriod = BountyUpdatePeriod;
+	type BountyCuratorDeposit = BountyCuratorDeposit;
+	type BountyValueMinimum = BountyValueMinimum;
+	type DataDepositPerByte = DataDepositPerByte;
+	type MaximumReasonLength = MaximumReasonLength;
+	type WeightInfo = pallet_bounties::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -762,15 +1237,15 @@ impl pallet_session::historical::Config for Runtime {
 }
 
 parameter_types! {
-	// Don't allow runner-ups
-	pub const CandidacyBond: Balance = 5_000_000 * DOLLARS;
+	// Candidates bond HDX which is slashed to treasury if they don't become a member or a runner-up.
+	pub const CandidacyBond: Balance = 10_000 * DOLLARS;
 	// 1 storage item created, key size is 32 bytes, value size is 16+16.
-	pub const VotingBondBase: Balance = CENTS;
+	pub const VotingBondBase: Balance = deposit(1, 64);
 	// additional data per vote is 32 bytes (account id).
-	pub const VotingBondFactor: Balance = CENTS;
+	pub const VotingBondFactor: Balance = deposit(0, 32);
 	pub const TermDuration: BlockNumber = 7 * DAYS;
-	pub const DesiredMembers: u32 = 1;
-	pub const DesiredRunnersUp: u32 = 0;
+	pub const DesiredMembers: u32 = 13;
+	pub const DesiredRunnersUp: u32 = 7;
 	pub const ElectionsPhragmenPalletId: LockIdentifier = *b"phrelect";
 }
 
@@ -789,7 +1264,7 @@ impl pallet_elections_phragmen::Config for Runtime {
 	type DesiredMembers = DesiredMembers;
 	type DesiredRunnersUp = DesiredRunnersUp;
 	type TermDuration = TermDuration;
-	type WeightInfo = ();
+	type WeightInfo = pallet_elections_phragmen::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -820,7 +1295,7 @@ parameter_types! {
 	pub const CouncilMotionDuration: BlockNumber = 5 * DAYS;
 	pub const CouncilMaxProposals: u32 = 20;
 	pub const ProposalVotesRequired: u32 = 1;
-	pub const CouncilMaxMembers: u32 = 1;
+	pub const CouncilMaxMembers: u32 = DesiredMembers::get();
 }
 
 type CouncilCollective = pallet_collective::Instance1;
@@ -898,6 +1373,9 @@ where
 			frame_system::CheckWeight::<Runtime>::new(),
 			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
 			pallet_claims::ValidateClaim::<Runtime>::new(),
+			metadata_hash::CheckMetadataHash::<Runtime>::new(false),
+			pallet_exchange::ChargeIntentionFee::<Runtime>::new(),
+			priority::PrioritizeOperational::<Runtime>::new(),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -1004,13 +1482,34 @@ construct_runtime!(
 		Currencies: orml_currencies::{Pallet, Call, Event<T>},
 
 		// HydraDX related modules
-		AssetRegistry: pallet_asset_registry::{Pallet, Call, Storage, Config<T>},
-		XYK: pallet_xyk::{Pallet, Call, Storage, Event<T>},
+		AssetRegistry: pallet_asset_registry::{Pallet, Call, Storage, Config<T>, Event<T>},
+		XYK: pallet_xyk::{Pallet, Call, Storage, Event<T>, Config<T>},
 		Claims: pallet_claims::{Pallet, Call, Storage, Event<T>, Config<T>},
 		Exchange: pallet_exchange::{Pallet, Call, Storage, Event<T>},
 		Faucet: pallet_faucet::{Pallet, Call, Storage, Config, Event<T>},
 		MultiTransactionPayment: pallet_transaction_multi_payment::{Pallet, Call, Storage, Event<T>},
 		GenesisHistory: pallet_genesis_history::{Pallet, Storage, Config},
+		Streams: pallet_streams::{Pallet, Call, Storage, Event<T>},
+		Inflation: pallet_inflation::{Pallet, Call, Storage, Event},
+		Listing: pallet_listing::{Pallet, Call, Storage, Event<T>},
+		AuditLog: pallet_audit_log::{Pallet, Storage, Event<T>},
+		PricePublisher: pallet_price_publisher::{Pallet, Call, Storage, Event<T>, ValidateUnsigned, Config<T>},
+		OTC: pallet_otc::{Pallet, Call, Storage, Event<T>},
+		MigrationManager: pallet_migration_manager::{Pallet, Call, Storage, Event<T>},
+		Duster: pallet_duster::{Pallet, Call, Storage, Event<T>},
+		TransactionPause: pallet_transaction_pause::{Pallet, Call, Storage, Event<T>},
+		Referrals: pallet_referrals::{Pallet, Call, Config, Storage, Event<T>},
+		Bonds: pallet_bonds::{Pallet, Call, Storage, Event<T>},
+		DCA: pallet_dca::{Pallet, Call, Storage, Event<T>},
+		Buyback: pallet_buyback::{Pallet, Call, Storage, Event<T>},
+		FeeDiscount: pallet_fee_discount::{Pallet, Call, Storage, Config, Event<T>},
+		AssetMigration: pallet_asset_migration::{Pallet, Call, Storage, Event<T>},
+		Stats: pallet_stats::{Pallet, Storage},
+		FeeMultiplier: pallet_fee_multiplier::{Pallet, Call, Storage, Event<T>},
+		Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>},
+		Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>},
+		Bounties: pallet_bounties::{Pallet, Call, Storage, Event<T>},
+		CircuitBreaker: pallet_circuit_breaker::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -1034,6 +1533,9 @@ pub type SignedExtra = (
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 	pallet_claims::ValidateClaim<Runtime>,
+	metadata_hash::CheckMetadataHash<Runtime>,
+	pallet_exchange::ChargeIntentionFee<Runtime>,
+	priority::PrioritizeOperational<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
@@ -1250,6 +1752,188 @@ impl_runtime_apis! {
 			vec
 		}
 
+		fn get_pool_storage_keys(
+			pool_address: AccountId,
+		) -> Vec<Vec<u8>> {
+			use primitives::traits::AMM;
+
+			let mut keys = XYK::get_pool_storage_keys(&pool_address);
+
+			if let Some(assets) = XYK::get_pool_assets(&pool_address) {
+				for asset in assets {
+					// Native asset balance is kept in system account data, others in orml tokens.
+					if asset == HDXAssetId::get() {
+						keys.push(frame_system::Account::<Runtime>::hashed_key_for(&pool_address));
+					} else {
+						keys.push(orml_tokens::Accounts::<Runtime>::hashed_key_for(&pool_address, &asset));
+					}
+				}
+			}
+
+			keys
+		}
+
+		fn query_trade_fee(
+			account: AccountId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+		) -> Option<xyk_rpc::TradeFeeInfo<Balance>> {
+			XYK::calculate_trade_fees(&account, primitives::asset::AssetPair { asset_in, asset_out }, amount)
+				.ok()
+				.map(|(fee, discounted_fee)| xyk_rpc::TradeFeeInfo { fee, discounted_fee })
+		}
+
+		fn get_spot_price(
+			asset_a: AssetId,
+			asset_b: AssetId,
+			amount: Balance,
+		) -> Option<Balance> {
+			use primitives::traits::AMM;
+
+			if XYK::exists(primitives::asset::AssetPair { asset_in: asset_a, asset_out: asset_b }) {
+				Some(XYK::get_spot_price_unchecked(asset_a, asset_b, amount))
+			} else {
+				None
+			}
+		}
+
+		fn calculate_sell_price(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+		) -> Option<xyk_rpc::TradeQuote<Balance>> {
+			XYK::calculate_sell_price(primitives::asset::AssetPair { asset_in, asset_out }, amount)
+				.ok()
+				.map(|(amount, fee, price_impact)| xyk_rpc::TradeQuote { amount, fee, price_impact })
+		}
+
+		fn calculate_buy_price(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+		) -> Option<xyk_rpc::TradeQuote<Balance>> {
+			XYK::calculate_buy_price(primitives::asset::AssetPair { asset_in, asset_out }, amount)
+				.ok()
+				.map(|(amount, fee, price_impact)| xyk_rpc::TradeQuote { amount, fee, price_impact })
+		}
+
+		fn calculate_sell_price_for(
+			account: AccountId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+			discount: bool,
+		) -> Option<xyk_rpc::TradeQuote<Balance>> {
+			XYK::calculate_sell_price_for(&account, primitives::asset::AssetPair { asset_in, asset_out }, amount, discount)
+				.ok()
+				.map(|(amount, fee, price_impact)| xyk_rpc::TradeQuote { amount, fee, price_impact })
+		}
+
+		fn calculate_buy_price_for(
+			account: AccountId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+			discount: bool,
+		) -> Option<xyk_rpc::TradeQuote<Balance>> {
+			XYK::calculate_buy_price_for(&account, primitives::asset::AssetPair { asset_in, asset_out }, amount, discount)
+				.ok()
+				.map(|(amount, fee, price_impact)| xyk_rpc::TradeQuote { amount, fee, price_impact })
+		}
+
+		fn calculate_liquidity_out(
+			asset_a: AssetId,
+			asset_b: AssetId,
+			share_amount: Balance,
+		) -> Option<xyk_rpc::LiquidityOut<Balance>> {
+			XYK::calculate_liquidity_out_for(primitives::asset::AssetPair { asset_in: asset_a, asset_out: asset_b }, share_amount)
+				.ok()
+				.map(|(amount_a, amount_b)| xyk_rpc::LiquidityOut { amount_a, amount_b })
+		}
+
+		fn get_pool_id(
+			asset_pair: primitives::asset::AssetPair,
+		) -> Option<AccountId> {
+			use primitives::traits::AMM;
+
+			if XYK::exists(asset_pair) {
+				Some(XYK::get_pair_id(asset_pair))
+			} else {
+				None
+			}
+		}
+
+		fn get_pool_asset_pair(
+			pool_address: AccountId,
+		) -> Option<primitives::asset::AssetPair> {
+			use primitives::traits::AMM;
+
+			XYK::get_pool_assets(&pool_address).and_then(|assets| match assets[..] {
+				[asset_a, asset_b] => Some(primitives::asset::AssetPair::new(asset_a, asset_b).canonical()),
+				_ => None,
+			})
+		}
+
+		fn find_best_route(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+		) -> Option<xyk_rpc::Route<AssetId, Balance>> {
+			XYK::find_best_route(asset_in, asset_out, amount)
+				.map(|(assets, amount)| xyk_rpc::Route { assets, amount })
+		}
+	}
+
+	impl asset_registry_rpc::AssetRegistryApi<Block, AssetId> for Runtime {
+		fn get_asset_by_name(name: Vec<u8>) -> Option<AssetId> {
+			AssetRegistry::asset_by_name(&name)
+		}
+
+		fn get_asset_name(asset_id: AssetId) -> Option<Vec<u8>> {
+			AssetRegistry::asset_name(asset_id)
+		}
+
+		fn list_assets() -> Vec<(AssetId, Vec<u8>)> {
+			AssetRegistry::list_assets()
+		}
+	}
+
+	impl pallet_audit_log_runtime_api::AuditLogApi<Block, AccountId, BlockNumber> for Runtime {
+		fn audit_log() -> Vec<primitives::audit::AuditEntry<AccountId, BlockNumber>> {
+			AuditLog::entries()
+		}
+	}
+
+	impl pallet_stats_runtime_api::StatsApi<Block> for Runtime {
+		fn pool_stats(asset_a: AssetId, asset_b: AssetId) -> Vec<pallet_stats::PeriodStats> {
+			Stats::pool_stats(asset_a, asset_b)
+		}
+	}
+
+	impl pallet_inflation_runtime_api::InflationApi<Block, primitives::asset::AssetPair> for Runtime {
+		fn simulate_rewards(
+			params: pallet_inflation::RewardSimulationParams<primitives::asset::AssetPair>,
+		) -> Option<pallet_inflation::RewardProjection<primitives::asset::AssetPair>> {
+			let total_staked = Staking::active_era()
+				.map(|era| Staking::eras_total_stake(era.index))
+				.unwrap_or_default();
+
+			Inflation::simulate_rewards(
+				params,
+				Balances::total_issuance(),
+				total_staked,
+				impls::pool_value_in_native,
+			)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	impl frame_try_runtime::TryRuntime<Block> for Runtime {
+		fn on_runtime_upgrade() -> Result<(Weight, Weight), sp_runtime::RuntimeString> {
+			let weight = Executive::try_runtime_upgrade()?;
+			Ok((weight, BlockWeights::get().max_block))
+		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1284,14 +1968,37 @@ impl_runtime_apis! {
 			let params = (&config, &whitelist);
 
 			add_benchmark!(params, batches, xyk, XYK);
+			add_benchmark!(params, batches, asset_registry, AssetRegistry);
 			add_benchmark!(params, batches, claims, Claims);
+			add_benchmark!(params, batches, streams, Streams);
+			add_benchmark!(params, batches, inflation, Inflation);
+			add_benchmark!(params, batches, listing, Listing);
+			add_benchmark!(params, batches, price_publisher, PricePublisher);
+			add_benchmark!(params, batches, otc, OTC);
+			add_benchmark!(params, batches, migration_manager, MigrationManager);
+			add_benchmark!(params, batches, duster, Duster);
+			add_benchmark!(params, batches, transaction_pause, TransactionPause);
+			add_benchmark!(params, batches, referrals, Referrals);
+			add_benchmark!(params, batches, bonds, Bonds);
+			add_benchmark!(params, batches, dca, DCA);
+			add_benchmark!(params, batches, buyback, Buyback);
+			add_benchmark!(params, batches, fee_discount, FeeDiscount);
+			add_benchmark!(params, batches, asset_migration, AssetMigration);
+			add_benchmark!(params, batches, fee_multiplier, FeeMultiplier);
+			add_benchmark!(params, batches, circuit_breaker, CircuitBreaker);
 			add_benchmark!(params, batches, transaction_multi_payment, MultiBench::<Runtime>);
 			add_benchmark!(params, batches, frame_system, SystemBench::<Runtime>);
 			add_benchmark!(params, batches, exchange, ExchangeBench::<Runtime>);
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
 			add_benchmark!(params, batches, pallet_collective, Council);
+			add_benchmark!(params, batches, pallet_elections_phragmen, Elections);
 			add_benchmark!(params, batches, pallet_identity, Identity);
+			add_benchmark!(params, batches, pallet_treasury, Treasury);
+			add_benchmark!(params, batches, pallet_tips, Tips);
+			add_benchmark!(params, batches, pallet_bounties, Bounties);
+			add_benchmark!(params, batches, pallet_proxy, Proxy);
+			add_benchmark!(params, batches, pallet_multisig, Multisig);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
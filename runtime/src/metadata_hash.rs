@@ -0,0 +1,126 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Signed extension verifying that transaction was signed against authentic runtime metadata.
+//!
+//! Hash of runtime metadata is embedded into the runtime at build time from `RUNTIME_METADATA_HASH`
+//! environment variable ( hex encoded blake2-256 hash of SCALE encoded metadata ).
+//!
+//! Offline signer which enables the check includes the metadata hash it used to decode the transaction
+//! in the signed payload. Signature is then valid only if it matches the hash embedded in the runtime.
+
+use codec::{Decode, Encode};
+use sp_runtime::{
+	traits::SignedExtension,
+	transaction_validity::{TransactionValidityError, UnknownTransaction},
+	RuntimeDebug,
+};
+use sp_std::marker::PhantomData;
+
+/// Hex encoded hash of runtime metadata provided at build time.
+const METADATA_HASH: Option<&str> = option_env!("RUNTIME_METADATA_HASH");
+
+/// Return hash of runtime metadata embedded at build time.
+pub fn metadata_hash() -> Option<[u8; 32]> {
+	METADATA_HASH.and_then(decode_hash)
+}
+
+fn decode_hash(hex: &str) -> Option<[u8; 32]> {
+	let hex = hex.trim().trim_start_matches("0x");
+
+	if hex.len() != 64 || !hex.is_ascii() {
+		return None;
+	}
+
+	let mut hash = [0u8; 32];
+	for (i, byte) in hash.iter_mut().enumerate() {
+		*byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).ok()?;
+	}
+
+	Some(hash)
+}
+
+/// Whether signer requested verification of metadata hash.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub enum Mode {
+	Disabled,
+	Enabled,
+}
+
+/// Include hash of runtime metadata in the signed payload if requested by signer.
+#[derive(Encode, Decode, Clone, Eq, PartialEq)]
+pub struct CheckMetadataHash<T: frame_system::Config + Send + Sync> {
+	mode: Mode,
+	_phantom: PhantomData<T>,
+}
+
+impl<T: frame_system::Config + Send + Sync> sp_std::fmt::Debug for CheckMetadataHash<T> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckMetadataHash({:?})", self.mode)
+	}
+}
+
+impl<T: frame_system::Config + Send + Sync> CheckMetadataHash<T> {
+	pub fn new(enabled: bool) -> Self {
+		Self {
+			mode: if enabled { Mode::Enabled } else { Mode::Disabled },
+			_phantom: PhantomData,
+		}
+	}
+}
+
+impl<T: frame_system::Config + Send + Sync> SignedExtension for CheckMetadataHash<T> {
+	const IDENTIFIER: &'static str = "CheckMetadataHash";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::Call;
+	type AdditionalSigned = Option<[u8; 32]>;
+	type Pre = ();
+
+	fn additional_signed(&self) -> sp_std::result::Result<Self::AdditionalSigned, TransactionValidityError> {
+		match self.mode {
+			Mode::Disabled => Ok(None),
+			// Runtime built without metadata hash cannot verify it.
+			Mode::Enabled => metadata_hash()
+				.map(Some)
+				.ok_or_else(|| UnknownTransaction::CannotLookup.into()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn decode_hash_should_work() {
+		let hash = "0x0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20";
+		let expected: Vec<u8> = (1u8..=32).collect();
+
+		assert_eq!(decode_hash(hash).map(|h| h.to_vec()), Some(expected.clone()));
+		assert_eq!(decode_hash(&hash[2..]).map(|h| h.to_vec()), Some(expected));
+		assert_eq!(decode_hash("0x0102"), None);
+		assert_eq!(decode_hash(&hash.replace("0a", "zz")), None);
+	}
+
+	#[test]
+	fn disabled_check_should_not_include_hash() {
+		assert_eq!(
+			CheckMetadataHash::<crate::Runtime>::new(false).additional_signed(),
+			Ok(None)
+		);
+	}
+}
@@ -0,0 +1,121 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Signed extension raising priority of operational transactions.
+//!
+//! Time critical calls which must not be crowded out of full blocks by regular trades - e.g. circuit breaker
+//! triggers - are declared with `DispatchClass::Operational`. Besides the block weight reserved for operational
+//! transactions, they get `OperationalTransactionPriority` on top of the fee based priority, so they are taken
+//! from the transaction pool before regular transactions. Oracle price submissions are unsigned and have their
+//! own priority.
+
+use codec::{Decode, Encode};
+use frame_support::{traits::Get, weights::DispatchClass};
+use sp_runtime::{
+	traits::{DispatchInfoOf, SignedExtension},
+	transaction_validity::{TransactionValidity, TransactionValidityError, ValidTransaction},
+};
+use sp_std::marker::PhantomData;
+
+/// Add `OperationalTransactionPriority` to the priority of operational transactions.
+#[derive(Encode, Decode, Clone, Eq, PartialEq)]
+pub struct PrioritizeOperational<T: frame_system::Config + Send + Sync>(PhantomData<T>);
+
+impl<T: frame_system::Config + Send + Sync> sp_std::fmt::Debug for PrioritizeOperational<T> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "PrioritizeOperational")
+	}
+}
+
+impl<T: frame_system::Config + Send + Sync> PrioritizeOperational<T> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: frame_system::Config + Send + Sync> Default for PrioritizeOperational<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: frame_system::Config + Send + Sync> SignedExtension for PrioritizeOperational<T> {
+	const IDENTIFIER: &'static str = "PrioritizeOperational";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::Call;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		_call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		let priority = match info.class {
+			DispatchClass::Operational => crate::OperationalTransactionPriority::get(),
+			_ => 0,
+		};
+
+		Ok(ValidTransaction {
+			priority,
+			..Default::default()
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{AccountId, Call, Runtime};
+	use frame_support::weights::{DispatchInfo, GetDispatchInfo};
+
+	#[test]
+	fn operational_transaction_should_get_priority() {
+		let call = Call::TransactionPause(pallet_transaction_pause::Call::pause_transaction(
+			b"XYK".to_vec(),
+			b"sell".to_vec(),
+		));
+		let info = call.get_dispatch_info();
+
+		assert_eq!(info.class, DispatchClass::Operational);
+		assert_eq!(
+			PrioritizeOperational::<Runtime>::new()
+				.validate(&AccountId::default(), &call, &info, 0)
+				.map(|v| v.priority),
+			Ok(crate::OperationalTransactionPriority::get())
+		);
+	}
+
+	#[test]
+	fn normal_transaction_should_not_get_priority() {
+		let call = Call::System(frame_system::Call::remark(vec![]));
+		let info = DispatchInfo::default();
+
+		assert_eq!(
+			PrioritizeOperational::<Runtime>::new()
+				.validate(&AccountId::default(), &call, &info, 0)
+				.map(|v| v.priority),
+			Ok(0)
+		);
+	}
+}
@@ -0,0 +1,119 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Proxy types which can be used to delegate part of account's rights to another account.
+//!
+//! Exchanges and custodians can delegate trading without delegating transfers of funds out of the account.
+
+use crate::Call;
+use codec::{Decode, Encode};
+use frame_support::traits::InstanceFilter;
+use sp_runtime::RuntimeDebug;
+
+/// The type used to represent the kinds of proxying allowed.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug)]
+pub enum ProxyType {
+	/// Any call
+	Any,
+	/// Governance related calls - voting, proposals, treasury and tips
+	Governance,
+	/// Trading and providing liquidity - funds stay with the proxied account
+	Exchange,
+	/// Transfers of funds and claims
+	Transfer,
+}
+
+impl Default for ProxyType {
+	fn default() -> Self {
+		Self::Any
+	}
+}
+
+impl InstanceFilter<Call> for ProxyType {
+	fn filter(&self, c: &Call) -> bool {
+		match self {
+			ProxyType::Any => true,
+			ProxyType::Governance => matches!(
+				c,
+				Call::Democracy(..)
+					| Call::Council(..) | Call::TechnicalCommittee(..)
+					| Call::Elections(..)
+					| Call::Treasury(..) | Call::Tips(..)
+					| Call::Utility(..)
+			),
+			ProxyType::Exchange => matches!(
+				c,
+				Call::Exchange(..)
+					| Call::XYK(pallet_xyk::Call::create_pool(..))
+					| Call::XYK(pallet_xyk::Call::add_liquidity(..))
+					| Call::XYK(pallet_xyk::Call::remove_liquidity(..))
+					| Call::XYK(pallet_xyk::Call::sell(..))
+					| Call::XYK(pallet_xyk::Call::buy(..))
+					| Call::MultiTransactionPayment(pallet_transaction_multi_payment::Call::set_currency(..))
+					| Call::Utility(..)
+			),
+			ProxyType::Transfer => matches!(
+				c,
+				Call::Balances(..)
+					| Call::Currencies(..)
+					| Call::Tokens(..) | Call::Claims(..)
+					| Call::Streams(..) | Call::Utility(..)
+			),
+		}
+	}
+
+	fn is_superset(&self, o: &Self) -> bool {
+		match (self, o) {
+			(x, y) if x == y => true,
+			(ProxyType::Any, _) => true,
+			_ => false,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn exchange_proxy_should_not_transfer() {
+		let sell = Call::XYK(pallet_xyk::Call::sell(0, 1, 1_000, 0, false));
+		let intention = Call::Exchange(pallet_exchange::Call::sell(0, 1, 1_000, 0, false, None, 0));
+		let transfer = Call::Balances(pallet_balances::Call::transfer(Default::default(), 1_000));
+		let protocol_fee = Call::XYK(pallet_xyk::Call::set_protocol_fee(Default::default()));
+		let access_asset = Call::XYK(pallet_xyk::Call::set_pool_access_asset(0, 1, None));
+
+		assert!(ProxyType::Exchange.filter(&sell));
+		assert!(ProxyType::Exchange.filter(&intention));
+		assert!(!ProxyType::Exchange.filter(&transfer));
+		assert!(!ProxyType::Exchange.filter(&protocol_fee));
+		assert!(!ProxyType::Exchange.filter(&access_asset));
+
+		assert!(ProxyType::Transfer.filter(&transfer));
+		assert!(!ProxyType::Transfer.filter(&sell));
+		assert!(!ProxyType::Governance.filter(&sell));
+		assert!(ProxyType::Any.filter(&sell));
+	}
+
+	#[test]
+	fn proxy_type_superset_should_work() {
+		assert!(ProxyType::Any.is_superset(&ProxyType::Exchange));
+		assert!(ProxyType::Exchange.is_superset(&ProxyType::Exchange));
+		assert!(!ProxyType::Exchange.is_superset(&ProxyType::Any));
+		assert!(!ProxyType::Exchange.is_superset(&ProxyType::Transfer));
+		assert!(!ProxyType::Governance.is_superset(&ProxyType::Transfer));
+	}
+}
@@ -0,0 +1,67 @@
+#!/bin/bash
+
+# Need to run from the top-level node directory
+[ -d ".maintain" ] || {
+  echo "This script must be executed from the top level node directory"
+  exit 1
+}
+
+NODE=./target/release/hydra-dx
+STEPS=${STEPS:-50}
+REPEAT=${REPEAT:-20}
+
+# Benchmarked pallet name and its weights file
+PALLETS=(
+  "xyk:pallets/xyk/src/weights.rs"
+  "exchange:pallets/exchange/src/weights.rs"
+  "claims:pallets/claims/src/weights.rs"
+  "asset_registry:pallets/asset-registry/src/weights.rs"
+  "transaction_multi_payment:pallets/transaction-multi-payment/src/weights.rs"
+  "streams:pallets/streams/src/weights.rs"
+  "inflation:pallets/inflation/src/weights.rs"
+  "listing:pallets/listing/src/weights.rs"
+  "price_publisher:pallets/price-publisher/src/weights.rs"
+  "otc:pallets/otc/src/weights.rs"
+  "migration_manager:pallets/migration-manager/src/weights.rs"
+  "duster:pallets/duster/src/weights.rs"
+  "transaction_pause:pallets/transaction-pause/src/weights.rs"
+  "referrals:pallets/referrals/src/weights.rs"
+  "bonds:pallets/bonds/src/weights.rs"
+  "dca:pallets/dca/src/weights.rs"
+  "buyback:pallets/buyback/src/weights.rs"
+  "fee_discount:pallets/fee-discount/src/weights.rs"
+  "asset_migration:pallets/asset-migration/src/weights.rs"
+  "fee_multiplier:pallets/fee-multiplier/src/weights.rs"
+  "circuit_breaker:pallets/circuit-breaker/src/weights.rs"
+)
+
+[ -x "$NODE" ] || {
+  echo "Node binary not found. Build it first: cargo build --release --features runtime-benchmarks"
+  exit 1
+}
+
+for entry in "${PALLETS[@]}"; do
+  PALLET=${entry%%:*}
+  OUTPUT=${entry#*:}
+
+  if [ -n "$1" ] && [ "$1" != "$PALLET" ]; then
+    continue
+  fi
+
+  echo "Generating weights of $PALLET into $OUTPUT"
+
+  $NODE benchmark \
+    --chain=dev \
+    --steps="$STEPS" \
+    --repeat="$REPEAT" \
+    --pallet="$PALLET" \
+    --extrinsic='*' \
+    --execution=wasm \
+    --wasm-execution=compiled \
+    --heap-pages=4096 \
+    --output="$OUTPUT" \
+    --template=.maintain/pallet-weight-template.hbs || {
+    echo "Benchmarking of $PALLET failed"
+    exit 1
+  }
+done
@@ -0,0 +1,27 @@
+#!/bin/bash
+
+# Compute hash of runtime metadata of a running node.
+#
+# The hash is embedded into the runtime by building it with RUNTIME_METADATA_HASH environment variable.
+# Embedding the hash does not change the metadata, therefore running this script against a node with
+# the rebuilt runtime must print the same hash.
+
+RPC=${1:-http://localhost:9933}
+
+for cmd in curl jq xxd b2sum; do
+  command -v $cmd >/dev/null 2>&1 || {
+    echo "$cmd required. Please install first"
+    exit 1
+  }
+done
+
+METADATA=$(curl -s -H "Content-Type: application/json" \
+  -d '{"id":1, "jsonrpc":"2.0", "method": "state_getMetadata", "params":[]}' \
+  "$RPC" | jq -r .result)
+
+[ -n "$METADATA" ] && [ "$METADATA" != "null" ] || {
+  echo "Failed to retrieve metadata from $RPC"
+  exit 1
+}
+
+echo "0x$(echo "${METADATA#0x}" | xxd -r -p | b2sum -l 256 | cut -d ' ' -f 1)"
@@ -0,0 +1,136 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Assertion helpers shared by HydraDX pallet tests.
+//!
+//! Event helpers work with any runtime implementing `frame_system::Config`:
+//!
+//! ```ignore
+//! expect_events::<Test>(vec![Event::PoolCreated(ALICE, HDX, DOT, 100).into()]);
+//! assert_event_emitted!(Test, Event::PoolCreated(ALICE, HDX, DOT, 100));
+//! assert_event_not_emitted!(Test, TestEvent::xyk(Event::PoolDestroyed(..)));
+//! let trades = filter_events!(Test, TestEvent::xyk(Event::SellExecuted(..)));
+//! ```
+//!
+//! Balance helpers accept either native currency or multi-currency:
+//!
+//! ```ignore
+//! assert_balance!(Balances, ALICE, 1_000);
+//! assert_balance!(Currency, ALICE, DOT, 1_000);
+//! assert_pool_reserves!(Currency, pair_account, (HDX, 1_000), (DOT, 2_000));
+//! ```
+
+pub use frame_support::traits::Currency;
+pub use frame_system;
+pub use orml_traits::MultiCurrency;
+
+/// Return events deposited in current block, oldest first.
+pub fn events<T: frame_system::Config>() -> Vec<T::Event> {
+	frame_system::Pallet::<T>::events()
+		.into_iter()
+		.map(|e| e.event)
+		.collect()
+}
+
+/// Return last event deposited in current block.
+pub fn last_event<T: frame_system::Config>() -> T::Event {
+	events::<T>().pop().expect("Event expected")
+}
+
+/// Return last `n` events deposited in current block, oldest first.
+pub fn last_events<T: frame_system::Config>(n: usize) -> Vec<T::Event> {
+	let events = events::<T>();
+	events[events.len().saturating_sub(n)..].to_vec()
+}
+
+/// Assert that last events deposited in current block are `e`.
+pub fn expect_events<T: frame_system::Config>(e: Vec<T::Event>) {
+	assert_eq!(last_events::<T>(e.len()), e);
+}
+
+/// Assert that event was deposited in current block.
+#[macro_export]
+macro_rules! assert_event_emitted {
+	($runtime:ty, $event:expr) => {{
+		let event: <$runtime as $crate::frame_system::Config>::Event = $event.into();
+		assert!(
+			$crate::events::<$runtime>().contains(&event),
+			"Event {:?} was not emitted",
+			event
+		);
+	}};
+}
+
+/// Assert that no event matching the pattern was deposited in current block.
+#[macro_export]
+macro_rules! assert_event_not_emitted {
+	($runtime:ty, $pattern:pat) => {{
+		let events = $crate::filter_events!($runtime, $pattern);
+		assert!(events.is_empty(), "Unexpected events {:?} were emitted", events);
+	}};
+}
+
+/// Return events deposited in current block matching the pattern, oldest first.
+#[macro_export]
+macro_rules! filter_events {
+	($runtime:ty, $pattern:pat) => {
+		$crate::events::<$runtime>()
+			.into_iter()
+			.filter(|e| matches!(e, $pattern))
+			.collect::<Vec<_>>()
+	};
+}
+
+/// Assert free balance of an account.
+///
+/// Balance of native currency is checked when asset is not given.
+#[macro_export]
+macro_rules! assert_balance {
+	($currency:ty, $who:expr, $asset:expr, $expected:expr) => {
+		assert_eq!(
+			<$currency as $crate::MultiCurrency<_>>::free_balance($asset, &$who),
+			$expected,
+			"Unexpected balance of asset {:?} of {:?}",
+			$asset,
+			$who
+		);
+	};
+	($currency:ty, $who:expr, $expected:expr) => {
+		assert_eq!(
+			<$currency as $crate::Currency<_>>::free_balance(&$who),
+			$expected,
+			"Unexpected balance of {:?}",
+			$who
+		);
+	};
+}
+
+/// Assert reserves of a pool given as `(asset, reserve)` pairs.
+#[macro_export]
+macro_rules! assert_pool_reserves {
+	($currency:ty, $pool:expr, $(($asset:expr, $reserve:expr)),+ $(,)?) => {
+		$(
+			assert_eq!(
+				<$currency as $crate::MultiCurrency<_>>::free_balance($asset, &$pool),
+				$reserve,
+				"Unexpected reserve of asset {:?} in pool {:?}",
+				$asset,
+				$pool
+			);
+		)+
+	};
+}